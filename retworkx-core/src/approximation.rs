@@ -0,0 +1,239 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+//! Module for heuristics approximating NP-hard node set problems.
+//!
+//! The functions in this module find good but not optimal solutions in
+//! close to linear time, with the ties between equally good choices broken
+//! at random. Self loops are ignored by all of them.
+
+use std::collections::BinaryHeap;
+use std::hash::Hash;
+
+use hashbrown::HashSet;
+use petgraph::visit::{IntoNeighbors, IntoNodeIdentifiers, NodeIndexable};
+use rand::prelude::*;
+use rand_pcg::Pcg64;
+
+fn rng_from_seed(seed: Option<u64>) -> Pcg64 {
+    match seed {
+        Some(seed) => Pcg64::seed_from_u64(seed),
+        None => Pcg64::from_entropy(),
+    }
+}
+
+/// The neighbors of every node indexed from 0 in the order of the node
+/// identifiers, without self loops.
+fn compact_neighbors<G>(graph: G, nodes: &[G::NodeId]) -> Vec<Vec<usize>>
+where
+    G: IntoNeighbors + NodeIndexable,
+{
+    let mut position: Vec<usize> = vec![usize::MAX; graph.node_bound()];
+    for (index, node) in nodes.iter().enumerate() {
+        position[graph.to_index(*node)] = index;
+    }
+    nodes
+        .iter()
+        .enumerate()
+        .map(|(index, node)| {
+            graph
+                .neighbors(*node)
+                .map(|neighbor| position[graph.to_index(neighbor)])
+                .filter(|&neighbor| neighbor != index)
+                .collect()
+        })
+        .collect()
+}
+
+/// Find a dominating set with the greedy heuristic.
+///
+/// A dominating set is a set of nodes such that every node of the graph is
+/// in the set or is a neighbor of a node of the set. The heuristic
+/// repeatedly adds the node which dominates the most nodes that aren't
+/// dominated yet, until every node is dominated [1]. Nodes dominating as
+/// many nodes are picked in a random order. The set is at most
+/// `ln(Δ + 1) + 1` times larger than the smallest dominating set, where `Δ`
+/// is the largest degree. This takes `O((n + m) log n)` time for `n` nodes and `m`
+/// edges.
+///
+/// Arguments:
+///
+/// * `graph` - The graph to find the dominating set of. If it's directed a
+///   node dominates its successors.
+/// * `seed` - An optional seed to use for the random number generator
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::approximation::greedy_dominating_set;
+///
+/// // A path of 2 stars
+/// let graph = petgraph::graph::UnGraph::<(), ()>::from_edges(&[
+///     (0, 1), (0, 2), (0, 3), (0, 4), (4, 5), (5, 6), (5, 7), (5, 8),
+/// ]);
+/// let dominating = greedy_dominating_set(&graph, Some(42));
+/// assert_eq!(dominating.len(), 2);
+/// assert!(dominating.contains(&0.into()));
+/// assert!(dominating.contains(&5.into()));
+/// ```
+///
+/// [1] Johnson, D. S.
+///     "Approximation algorithms for combinatorial problems"
+///     Journal of Computer and System Sciences 9 (3), 256–278 (1974).
+///     <https://doi.org/10.1016/S0022-0000(74)80044-9>
+pub fn greedy_dominating_set<G>(graph: G, seed: Option<u64>) -> HashSet<G::NodeId>
+where
+    G: IntoNeighbors + IntoNodeIdentifiers + NodeIndexable,
+    G::NodeId: Eq + Hash,
+{
+    let nodes: Vec<G::NodeId> = graph.node_identifiers().collect();
+    let successors = compact_neighbors(graph, &nodes);
+    let n = nodes.len();
+    let mut predecessors: Vec<Vec<usize>> = vec![Vec::new(); n];
+    for (node, succs) in successors.iter().enumerate() {
+        for &succ in succs {
+            predecessors[succ].push(node);
+        }
+    }
+    let mut rng = rng_from_seed(seed);
+    let ties: Vec<u64> = (0..n).map(|_| rng.gen()).collect();
+
+    // The number of nodes a node would newly dominate, parallel edges
+    // counted once
+    let mut gain: Vec<usize> = vec![1; n];
+    let mut seen: Vec<usize> = vec![usize::MAX; n];
+    for (node, succs) in successors.iter().enumerate() {
+        seen[node] = node;
+        for &succ in succs {
+            if seen[succ] != node {
+                seen[succ] = node;
+                gain[node] += 1;
+            }
+        }
+    }
+    // The gains only decrease, so the entries of the heap whose gain is
+    // outdated are put back with their current gain when they're popped
+    let mut heap: BinaryHeap<(usize, u64, usize)> =
+        (0..n).map(|node| (gain[node], ties[node], node)).collect();
+    let mut dominated = vec![false; n];
+    let mut dominating: HashSet<G::NodeId> = HashSet::new();
+    while let Some((node_gain, tie, node)) = heap.pop() {
+        if node_gain == 0 {
+            break;
+        }
+        if node_gain != gain[node] {
+            heap.push((gain[node], tie, node));
+            continue;
+        }
+        dominating.insert(nodes[node]);
+        for newly in std::iter::once(node).chain(successors[node].iter().copied()) {
+            if dominated[newly] {
+                continue;
+            }
+            dominated[newly] = true;
+            // Every node dominating it, including itself, gains one less
+            for dominator in std::iter::once(newly).chain(predecessors[newly].iter().copied()) {
+                if seen[dominator] != n + newly {
+                    seen[dominator] = n + newly;
+                    gain[dominator] -= 1;
+                }
+            }
+        }
+    }
+    dominating
+}
+
+/// Find a maximal independent set with Luby's randomized algorithm.
+///
+/// An independent set is a set of nodes without edges between them, it's
+/// maximal if no other node can be added to it. Every round of Luby's
+/// algorithm draws a random number for every node left, adds the nodes
+/// whose number is lower than the numbers of all their neighbors left to
+/// the set, and removes them and their neighbors from the nodes left [1].
+/// It takes `O(log n)` rounds of `O(n + m)` time on average for `n` nodes
+/// and `m` edges. The set is maximal but not necessarily of maximum size.
+///
+/// Arguments:
+///
+/// * `graph` - The graph to find the independent set of. If it's directed
+///   the direction of the edges is ignored, so `graph` should include the
+///   incoming neighbors of a node in its neighbors.
+/// * `seed` - An optional seed to use for the random number generator
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::petgraph::visit::EdgeRef;
+/// use retworkx_core::approximation::maximal_independent_set;
+///
+/// let graph = petgraph::graph::UnGraph::<(), ()>::from_edges(&[
+///     (0, 1), (1, 2), (2, 3), (3, 4), (4, 5), (5, 0), (0, 3),
+/// ]);
+/// let independent = maximal_independent_set(&graph, Some(42));
+/// // No edge is between 2 nodes of the set
+/// for edge in graph.edge_references() {
+///     assert!(!independent.contains(&edge.source()) || !independent.contains(&edge.target()));
+/// }
+/// // Every other node has a neighbor in the set
+/// for node in graph.node_indices() {
+///     assert!(
+///         independent.contains(&node)
+///             || graph.neighbors(node).any(|neighbor| independent.contains(&neighbor))
+///     );
+/// }
+/// ```
+///
+/// [1] Luby, M.
+///     "A Simple Parallel Algorithm for the Maximal Independent Set Problem"
+///     SIAM Journal on Computing 15 (4), 1036–1053 (1986).
+///     <https://doi.org/10.1137/0215074>
+pub fn maximal_independent_set<G>(graph: G, seed: Option<u64>) -> HashSet<G::NodeId>
+where
+    G: IntoNeighbors + IntoNodeIdentifiers + NodeIndexable,
+    G::NodeId: Eq + Hash,
+{
+    let nodes: Vec<G::NodeId> = graph.node_identifiers().collect();
+    let neighbors = compact_neighbors(graph, &nodes);
+    let n = nodes.len();
+    let mut rng = rng_from_seed(seed);
+    let mut left: Vec<usize> = (0..n).collect();
+    let mut removed = vec![false; n];
+    let mut priority: Vec<u64> = vec![0; n];
+    let mut independent: HashSet<G::NodeId> = HashSet::new();
+    while !left.is_empty() {
+        for &node in &left {
+            priority[node] = rng.gen();
+        }
+        // Ties between equal numbers are broken by position
+        let key = |node: usize| (priority[node], node);
+        let selected: Vec<usize> = left
+            .iter()
+            .copied()
+            .filter(|&node| {
+                neighbors[node]
+                    .iter()
+                    .all(|&neighbor| removed[neighbor] || key(node) < key(neighbor))
+            })
+            .collect();
+        for &node in &selected {
+            independent.insert(nodes[node]);
+            removed[node] = true;
+        }
+        for &node in &selected {
+            for &neighbor in &neighbors[node] {
+                removed[neighbor] = true;
+            }
+        }
+        left.retain(|&node| !removed[node]);
+    }
+    independent
+}
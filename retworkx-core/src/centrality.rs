@@ -10,151 +10,1841 @@
 // License for the specific language governing permissions and limitations
 // under the License.
 
-use std::collections::VecDeque;
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, VecDeque};
+use std::convert::Infallible;
 use std::sync::RwLock;
 
 use hashbrown::HashMap;
 use petgraph::graph::NodeIndex;
 use petgraph::visit::{
+    EdgeRef,
     GraphBase,
     GraphProp, // allows is_directed
+    IntoEdgeReferences,
+    IntoEdges,
     IntoNeighborsDirected,
     IntoNodeIdentifiers,
     NodeCount,
     NodeIndexable,
 };
+use petgraph::Direction::{Incoming, Outgoing};
+use petgraph::Undirected;
+use rand::prelude::*;
+use rand_pcg::Pcg64;
+#[cfg(feature = "parallel")]
 use rayon::prelude::*;
 
+use crate::connectivity::FlowNetwork;
+use crate::err::InvalidInputError;
+use crate::min_scored::MinScored;
+use crate::Convergence;
+
+/// Call `f` on every node, in parallel if there are at least
+/// `parallel_threshold` nodes and the `parallel` feature is enabled.
+fn for_each_node<F>(nodes: &[NodeIndex], parallel_threshold: usize, f: F)
+where
+    F: Fn(&NodeIndex) + Sync + Send,
+{
+    #[cfg(feature = "parallel")]
+    if nodes.len() >= parallel_threshold {
+        nodes.par_iter().for_each(f);
+        return;
+    }
+    #[cfg(not(feature = "parallel"))]
+    let _ = parallel_threshold;
+    nodes.iter().for_each(f);
+}
+
+/// Map `f` over every node, in parallel if there are at least
+/// `parallel_threshold` nodes and the `parallel` feature is enabled.
+fn map_nodes<T, F>(nodes: &[usize], parallel_threshold: usize, f: F) -> Vec<T>
+where
+    T: Send,
+    F: Fn(&usize) -> T + Sync + Send,
+{
+    #[cfg(feature = "parallel")]
+    if nodes.len() >= parallel_threshold {
+        return nodes.par_iter().map(f).collect();
+    }
+    #[cfg(not(feature = "parallel"))]
+    let _ = parallel_threshold;
+    nodes.iter().map(f).collect()
+}
+
 /// Compute the betweenness centrality of all nodes in a graph.
 ///
-/// The algorithm used in this function is based on:
+/// The algorithm used in this function is based on:
+///
+/// Ulrik Brandes, A Faster Algorithm for Betweenness Centrality.
+/// Journal of Mathematical Sociology 25(2):163-177, 2001.
+///
+/// This function is multithreaded and will run in parallel if the number
+/// of nodes in the graph is above the value of ``parallel_threshold``. If the
+/// function will be running in parallel the env var ``RAYON_NUM_THREADS`` can
+/// be used to adjust how many threads will be used.
+///
+/// Arguments:
+///
+/// * `graph` - The graph object to run the algorithm on
+/// * `endpoints` - Whether to include the endpoints of paths in the path
+///     lengths used to compute the betweenness
+/// * `normalized` - Whether to normalize the betweenness scores by the number
+///     of distinct paths between all pairs of nodes
+/// * `parallel_threshold` - The number of nodes to calculate the betweenness
+///     centrality in parallel at, if the number of nodes in `graph` is less
+///     than this value it will run in a single thread. A good default to use
+///     here if you're not sure is `50` as that was found to be roughly the
+///     number of nodes where parallelism improves performance
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::centrality::betweenness_centrality;
+///
+/// let g = petgraph::graph::UnGraph::<i32, ()>::from_edges(&[
+///     (0, 4), (1, 2), (2, 3), (3, 4), (1, 4)
+/// ]);
+/// // Calculate the betweeness centrality
+/// let output = betweenness_centrality(&g, true, true, 200);
+/// assert_eq!(
+///     vec![Some(0.4), Some(0.5), Some(0.45), Some(0.5), Some(0.75)],
+///     output
+/// );
+/// ```
+pub fn betweenness_centrality<G>(
+    graph: G,
+    endpoints: bool,
+    normalized: bool,
+    parallel_threshold: usize,
+) -> Vec<Option<f64>>
+where
+    G: NodeIndexable
+        + IntoNodeIdentifiers
+        + IntoNeighborsDirected
+        + NodeCount
+        + GraphProp
+        + GraphBase<NodeId = NodeIndex>
+        + std::marker::Sync,
+    // rustfmt deletes the following comments if placed inline above
+    // + IntoNodeIdentifiers // for node_identifiers()
+    // + IntoNeighborsDirected // for neighbors()
+    // + NodeCount // for node_count
+    // + GraphProp // for is_directed
+{
+    // Correspondence of variable names to quantities in the paper is as follows:
+    //
+    // P -- predecessors
+    // S -- verts_sorted_by_distance,
+    //      vertices in order of non-decreasing distance from s
+    // Q -- Q
+    // sigma -- sigma
+    // delta -- delta
+    // d -- distance
+    let max_index = graph.node_bound();
+
+    let mut betweenness: Vec<Option<f64>> = vec![None; max_index];
+    for node_s in graph.node_identifiers() {
+        let is: usize = graph.to_index(node_s);
+        betweenness[is] = Some(0.0);
+    }
+    let locked_betweenness = RwLock::new(&mut betweenness);
+    let node_indices: Vec<NodeIndex> = graph.node_identifiers().collect();
+    let accumulate = |node_s: &NodeIndex| {
+        let mut shortest_path_calc = shortest_path_for_centrality(&graph, node_s);
+        let is = graph.to_index(*node_s);
+        if endpoints {
+            _accumulate_endpoints(&locked_betweenness, max_index, &mut shortest_path_calc, is);
+        } else {
+            _accumulate_basic(&locked_betweenness, max_index, &mut shortest_path_calc, is);
+        }
+    };
+    for_each_node(&node_indices, parallel_threshold, accumulate);
+    _rescale(
+        &mut betweenness,
+        graph.node_count(),
+        normalized,
+        graph.is_directed(),
+        endpoints,
+    );
+
+    betweenness
+}
+
+/// Compute the betweenness centrality of all nodes in a graph counting only
+/// the shortest paths from a subset of source nodes to a subset of target
+/// nodes.
+///
+/// The betweenness of a node `v` is the sum over all the pairs of a source
+/// `s` and a target `t` of the fraction of the shortest `(s, t)` paths that
+/// pass through `v` [1]. It uses the same algorithm as
+/// [`betweenness_centrality`], except the path dependencies are only
+/// accumulated from the sources and towards the targets.
+///
+/// This function is multithreaded and will run in parallel if the number
+/// of sources is above the value of ``parallel_threshold``. If the
+/// function will be running in parallel the env var ``RAYON_NUM_THREADS`` can
+/// be used to adjust how many threads will be used.
+///
+/// Arguments:
+///
+/// * `graph` - The graph object to run the algorithm on
+/// * `sources` - The nodes the counted shortest paths start at
+/// * `targets` - The nodes the counted shortest paths end at
+/// * `normalized` - Whether to normalize the betweenness scores by the number
+///   of distinct paths between all pairs of nodes
+/// * `parallel_threshold` - The number of sources to calculate the
+///   betweenness centrality in parallel at, if the number of sources is less
+///   than this value it will run in a single thread.
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::petgraph::graph::NodeIndex;
+/// use retworkx_core::centrality::betweenness_centrality_subset;
+///
+/// let g = petgraph::graph::DiGraph::<(), ()>::from_edges(&[
+///     (0, 1), (1, 2), (1, 3), (2, 4), (3, 4)
+/// ]);
+/// let output = betweenness_centrality_subset(
+///     &g, &[NodeIndex::new(0)], &[NodeIndex::new(4)], false, 50
+/// );
+/// // Half of the shortest paths from 0 to 4 pass through each of 2 and 3
+/// assert_eq!(
+///     vec![Some(0.0), Some(1.0), Some(0.5), Some(0.5), Some(0.0)],
+///     output
+/// );
+/// ```
+///
+/// [1] Ulrik Brandes, "On Variants of Shortest-Path Betweenness Centrality
+///   and their Generic Computation." Social Networks 30(2):136-145, 2008.
+pub fn betweenness_centrality_subset<G>(
+    graph: G,
+    sources: &[G::NodeId],
+    targets: &[G::NodeId],
+    normalized: bool,
+    parallel_threshold: usize,
+) -> Vec<Option<f64>>
+where
+    G: NodeIndexable
+        + IntoNodeIdentifiers
+        + IntoNeighborsDirected
+        + NodeCount
+        + GraphProp
+        + GraphBase<NodeId = NodeIndex>
+        + std::marker::Sync,
+{
+    subset_betweenness(
+        graph,
+        sources,
+        targets,
+        normalized,
+        parallel_threshold,
+        |node_s| shortest_path_for_centrality(&graph, node_s),
+    )
+}
+
+/// Compute the betweenness centrality of all nodes in a weighted graph
+/// counting only the shortest paths from a subset of source nodes to a subset
+/// of target nodes.
+///
+/// This is the same as [`betweenness_centrality_subset`] except the shortest
+/// paths are found with Dijkstra's algorithm using the edge weights.
+///
+/// Arguments:
+///
+/// * `graph` - The graph object to run the algorithm on
+/// * `sources` - The nodes the counted shortest paths start at
+/// * `targets` - The nodes the counted shortest paths end at
+/// * `weight_fn` - A callable that receives an edge and returns its weight,
+///   which must be non-negative.
+/// * `normalized` - Whether to normalize the betweenness scores by the number
+///   of distinct paths between all pairs of nodes
+/// * `parallel_threshold` - The number of sources to calculate the
+///   betweenness centrality in parallel at, if the number of sources is less
+///   than this value it will run in a single thread.
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::petgraph::graph::NodeIndex;
+/// use retworkx_core::centrality::weighted_betweenness_centrality_subset;
+/// use retworkx_core::Result;
+///
+/// let g = petgraph::graph::DiGraph::<(), f64>::from_edges(&[
+///     (0, 1, 1.0), (1, 2, 1.0), (1, 3, 2.0), (2, 4, 1.0), (3, 4, 1.0)
+/// ]);
+/// let output: Result<Vec<Option<f64>>> = weighted_betweenness_centrality_subset(
+///     &g, &[NodeIndex::new(0)], &[NodeIndex::new(4)], |e| Ok(*e.weight()), false, 50
+/// );
+/// // The only shortest path from 0 to 4 passes through 2
+/// assert_eq!(
+///     vec![Some(0.0), Some(1.0), Some(1.0), Some(0.0), Some(0.0)],
+///     output.unwrap()
+/// );
+/// ```
+pub fn weighted_betweenness_centrality_subset<G, F, E>(
+    graph: G,
+    sources: &[G::NodeId],
+    targets: &[G::NodeId],
+    weight_fn: F,
+    normalized: bool,
+    parallel_threshold: usize,
+) -> Result<Vec<Option<f64>>, E>
+where
+    G: NodeIndexable
+        + IntoNodeIdentifiers
+        + IntoEdges
+        + NodeCount
+        + GraphProp
+        + GraphBase<NodeId = NodeIndex>,
+    F: FnMut(G::EdgeRef) -> Result<f64, E>,
+{
+    let links = weighted_links(graph, weight_fn)?;
+    Ok(subset_betweenness(
+        graph,
+        sources,
+        targets,
+        normalized,
+        parallel_threshold,
+        |node_s| dijkstra_for_centrality(&links, node_s),
+    ))
+}
+
+/// Accumulate the betweenness from every source towards the targets, with
+/// `path_fn` computing the shortest paths from a source.
+fn subset_betweenness<G, P>(
+    graph: G,
+    sources: &[NodeIndex],
+    targets: &[NodeIndex],
+    normalized: bool,
+    parallel_threshold: usize,
+    path_fn: P,
+) -> Vec<Option<f64>>
+where
+    G: NodeIndexable + IntoNodeIdentifiers + NodeCount + GraphProp,
+    P: Fn(&NodeIndex) -> ShortestPathData + Sync,
+{
+    let max_index = graph.node_bound();
+    let mut betweenness: Vec<Option<f64>> = vec![None; max_index];
+    for node_s in graph.node_identifiers() {
+        betweenness[graph.to_index(node_s)] = Some(0.0);
+    }
+    let mut is_target = vec![false; max_index];
+    for node_t in targets {
+        is_target[node_t.index()] = true;
+    }
+    let locked_betweenness = RwLock::new(&mut betweenness);
+    let accumulate = |node_s: &NodeIndex| {
+        let mut shortest_path_calc = path_fn(node_s);
+        _accumulate_subset(
+            &locked_betweenness,
+            max_index,
+            &mut shortest_path_calc,
+            node_s.index(),
+            &is_target,
+        );
+    };
+    for_each_node(sources, parallel_threshold, accumulate);
+    _rescale(
+        &mut betweenness,
+        graph.node_count(),
+        normalized,
+        graph.is_directed(),
+        false,
+    );
+    betweenness
+}
+
+/// Collect the outgoing edges of every node as `(target, weight)` pairs,
+/// indexed by node index. Undirected edges are collected in both directions.
+fn weighted_links<G, F, E>(graph: G, mut weight_fn: F) -> Result<Vec<Vec<(usize, f64)>>, E>
+where
+    G: IntoEdges + IntoNodeIdentifiers + NodeIndexable + GraphProp,
+    F: FnMut(G::EdgeRef) -> Result<f64, E>,
+{
+    let mut links: Vec<Vec<(usize, f64)>> = vec![Vec::new(); graph.node_bound()];
+    for node in graph.node_identifiers() {
+        let index = graph.to_index(node);
+        for edge in graph.edges(node) {
+            let target = if graph.is_directed() || edge.source() == node {
+                edge.target()
+            } else {
+                edge.source()
+            };
+            links[index].push((graph.to_index(target), weight_fn(edge)?));
+        }
+    }
+    Ok(links)
+}
+
+/// Scale `values` over the nodes in `nodes` to have a euclidean norm of 1,
+/// leaving all zero values as is.
+fn normalize(values: &mut [f64], nodes: &[usize]) {
+    let norm = nodes.iter().map(|n| values[*n].powi(2)).sum::<f64>().sqrt();
+    if norm > 0.0 {
+        for n in nodes {
+            values[*n] /= norm;
+        }
+    }
+}
+
+/// Compute the eigenvector centrality of all nodes in a graph.
+///
+/// The eigenvector centrality of a node is proportional to the sum of the
+/// centralities of the nodes linking to it, weighted by the edge weights,
+/// which makes it the left eigenvector of the weighted adjacency matrix for
+/// its largest eigenvalue [1]. It's computed with power iteration on the
+/// adjacency matrix shifted by the identity, so the iteration also converges
+/// on bipartite graphs. For directed graphs the centrality of a node is
+/// based on its incoming edges.
+///
+/// Arguments:
+///
+/// * `graph` - The graph object to run the algorithm on
+/// * `weight_fn` - A callable that receives an edge and returns its weight,
+///   which must be non-negative. Parallel edges have their weights added.
+/// * `max_iter` - The maximum number of iterations of the power method
+/// * `tol` - The error tolerance used when checking for convergence, the
+///   iteration stops once the change of the centralities sums to less than
+///   the number of nodes times `tol`.
+///
+/// Returns a [`Vec`] indexed by node index with the centrality of every node
+/// normalized to a euclidean norm of 1 (removed indices are set to 0), or
+/// [`None`] if the power iteration didn't converge within `max_iter`
+/// iterations.
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::centrality::eigenvector_centrality;
+/// use retworkx_core::Result;
+///
+/// let g = petgraph::graph::UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2)]);
+/// let output: Result<Option<Vec<f64>>> = eigenvector_centrality(&g, |_| Ok(1.), 100, 1e-6);
+/// let centralities = output.unwrap().unwrap();
+/// assert!((centralities[0] - 0.5).abs() < 1e-4);
+/// assert!((centralities[1] - 0.5f64.sqrt()).abs() < 1e-4);
+/// assert!((centralities[2] - 0.5).abs() < 1e-4);
+/// ```
+///
+/// [1] M. E. J. Newman, "Networks: An Introduction." Oxford University
+///   Press, 2010, pp. 169.
+pub fn eigenvector_centrality<G, F, E>(
+    graph: G,
+    weight_fn: F,
+    max_iter: usize,
+    tol: f64,
+) -> Result<Option<Vec<f64>>, E>
+where
+    G: IntoEdges + IntoNodeIdentifiers + NodeIndexable + GraphProp,
+    F: FnMut(G::EdgeRef) -> Result<f64, E>,
+{
+    let (centralities, convergence) =
+        eigenvector_centrality_with_convergence(graph, weight_fn, max_iter, tol)?;
+    Ok(if convergence.converged {
+        Some(centralities)
+    } else {
+        None
+    })
+}
+
+/// Compute the eigenvector centrality of all nodes in a graph along with the
+/// convergence of the power iteration.
+///
+/// This takes the same arguments as [`eigenvector_centrality`], but returns
+/// the centralities of the last iteration along with its [`Convergence`]
+/// instead of [`None`] if the power iteration didn't converge within
+/// `max_iter` iterations. The error of an iteration is the mean absolute
+/// change of the centralities.
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::centrality::eigenvector_centrality_with_convergence;
+/// use retworkx_core::{Convergence, Result};
+///
+/// let g = petgraph::graph::UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2)]);
+/// let output: Result<(Vec<f64>, Convergence)> =
+///     eigenvector_centrality_with_convergence(&g, |_| Ok(1.), 100, 1e-6);
+/// let (centralities, convergence) = output.unwrap();
+/// assert!(convergence.converged);
+/// assert!(convergence.iterations > 1);
+/// assert!((centralities[1] - 0.5f64.sqrt()).abs() < 1e-4);
+/// ```
+pub fn eigenvector_centrality_with_convergence<G, F, E>(
+    graph: G,
+    weight_fn: F,
+    max_iter: usize,
+    tol: f64,
+) -> Result<(Vec<f64>, Convergence), E>
+where
+    G: IntoEdges + IntoNodeIdentifiers + NodeIndexable + GraphProp,
+    F: FnMut(G::EdgeRef) -> Result<f64, E>,
+{
+    let bound = graph.node_bound();
+    let nodes: Vec<usize> = graph
+        .node_identifiers()
+        .map(|n| graph.to_index(n))
+        .collect();
+    if nodes.is_empty() {
+        return Ok((
+            vec![0.0; bound],
+            Convergence {
+                iterations: 0,
+                error: 0.0,
+                converged: true,
+            },
+        ));
+    }
+    let links = weighted_links(graph, weight_fn)?;
+
+    let mut x = vec![0.0; bound];
+    for n in &nodes {
+        x[*n] = 1.0 / nodes.len() as f64;
+    }
+    let mut convergence = Convergence {
+        iterations: 0,
+        error: f64::INFINITY,
+        converged: false,
+    };
+    for _ in 0..max_iter {
+        // start from the last vector to iterate with the shifted matrix
+        let last = x.clone();
+        for n in &nodes {
+            for (target, weight) in &links[*n] {
+                x[*target] += last[*n] * weight;
+            }
+        }
+        normalize(&mut x, &nodes);
+        convergence.iterations += 1;
+        convergence.error =
+            nodes.iter().map(|n| (x[*n] - last[*n]).abs()).sum::<f64>() / nodes.len() as f64;
+        if convergence.error < tol {
+            convergence.converged = true;
+            break;
+        }
+    }
+    Ok((x, convergence))
+}
+
+/// Compute the Katz centrality of all nodes in a graph.
+///
+/// The Katz centrality of a node counts the walks ending at the node, with
+/// the walks of length `k` attenuated by a factor of `alpha^k`, plus a
+/// base centrality `beta` of every node [1]. It's the solution of
+/// `x = alpha * A^T x + beta` for the weighted adjacency matrix `A`, which
+/// is computed with power iteration. The iteration only converges if `alpha`
+/// is less than the inverse of the largest eigenvalue of `A`. For directed
+/// graphs the centrality of a node is based on its incoming edges.
+///
+/// Arguments:
+///
+/// * `graph` - The graph object to run the algorithm on
+/// * `weight_fn` - A callable that receives an edge and returns its weight.
+///   Parallel edges have their weights added.
+/// * `alpha` - The attenuation factor
+/// * `beta_map` - An optional slice indexed by node index with the base
+///   centrality of every node
+/// * `beta_scalar` - The base centrality of every node if `beta_map` is not
+///   specified
+/// * `max_iter` - The maximum number of iterations of the power method
+/// * `tol` - The error tolerance used when checking for convergence, the
+///   iteration stops once the change of the centralities sums to less than
+///   the number of nodes times `tol`.
+/// * `normalized` - If `true` the centralities are normalized to a euclidean
+///   norm of 1.
+///
+/// Returns a [`Vec`] indexed by node index with the centrality of every node
+/// (removed indices are set to 0), or [`None`] if the power iteration didn't
+/// converge within `max_iter` iterations.
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::centrality::katz_centrality;
+/// use retworkx_core::Result;
+///
+/// let g = petgraph::graph::DiGraph::<(), ()>::from_edges(&[(0, 1), (1, 2)]);
+/// let output: Result<Option<Vec<f64>>> =
+///     katz_centrality(&g, |_| Ok(1.), 0.5, None, 1.0, 100, 1e-9, false);
+/// let centralities = output.unwrap().unwrap();
+/// // The walks ending at node 2 have lengths 0, 1 and 2
+/// assert!((centralities[2] - 1.75).abs() < 1e-6);
+/// ```
+///
+/// [1] Leo Katz, "A New Status Index Derived from Sociometric Index."
+///   Psychometrika 18(1):39-43, 1953.
+#[allow(clippy::too_many_arguments)]
+pub fn katz_centrality<G, F, E>(
+    graph: G,
+    weight_fn: F,
+    alpha: f64,
+    beta_map: Option<&[f64]>,
+    beta_scalar: f64,
+    max_iter: usize,
+    tol: f64,
+    normalized: bool,
+) -> Result<Option<Vec<f64>>, E>
+where
+    G: IntoEdges + IntoNodeIdentifiers + NodeIndexable + GraphProp,
+    F: FnMut(G::EdgeRef) -> Result<f64, E>,
+{
+    let (centralities, convergence) = katz_centrality_with_convergence(
+        graph,
+        weight_fn,
+        alpha,
+        beta_map,
+        beta_scalar,
+        max_iter,
+        tol,
+        normalized,
+    )?;
+    Ok(if convergence.converged {
+        Some(centralities)
+    } else {
+        None
+    })
+}
+
+/// Compute the Katz centrality of all nodes in a graph along with the
+/// convergence of the power iteration.
+///
+/// This takes the same arguments as [`katz_centrality`], but returns the
+/// centralities of the last iteration along with its [`Convergence`]
+/// instead of [`None`] if the power iteration didn't converge within
+/// `max_iter` iterations. The error of an iteration is the mean absolute
+/// change of the centralities.
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::centrality::katz_centrality_with_convergence;
+/// use retworkx_core::{Convergence, Result};
+///
+/// // The iteration diverges since alpha is larger than the inverse of the
+/// // largest eigenvalue of the adjacency matrix of a cycle
+/// let g = petgraph::graph::DiGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 0)]);
+/// let output: Result<(Vec<f64>, Convergence)> =
+///     katz_centrality_with_convergence(&g, |_| Ok(1.), 2.0, None, 1.0, 10, 1e-9, false);
+/// let (_, convergence) = output.unwrap();
+/// assert!(!convergence.converged);
+/// assert_eq!(convergence.iterations, 10);
+/// ```
+#[allow(clippy::too_many_arguments)]
+pub fn katz_centrality_with_convergence<G, F, E>(
+    graph: G,
+    weight_fn: F,
+    alpha: f64,
+    beta_map: Option<&[f64]>,
+    beta_scalar: f64,
+    max_iter: usize,
+    tol: f64,
+    normalized: bool,
+) -> Result<(Vec<f64>, Convergence), E>
+where
+    G: IntoEdges + IntoNodeIdentifiers + NodeIndexable + GraphProp,
+    F: FnMut(G::EdgeRef) -> Result<f64, E>,
+{
+    let bound = graph.node_bound();
+    let nodes: Vec<usize> = graph
+        .node_identifiers()
+        .map(|n| graph.to_index(n))
+        .collect();
+    if nodes.is_empty() {
+        return Ok((
+            vec![0.0; bound],
+            Convergence {
+                iterations: 0,
+                error: 0.0,
+                converged: true,
+            },
+        ));
+    }
+    let links = weighted_links(graph, weight_fn)?;
+    let beta = |n: usize| match beta_map {
+        Some(beta_map) => beta_map[n],
+        None => beta_scalar,
+    };
+
+    let mut x = vec![0.0; bound];
+    let mut convergence = Convergence {
+        iterations: 0,
+        error: f64::INFINITY,
+        converged: false,
+    };
+    for _ in 0..max_iter {
+        let last = x;
+        x = vec![0.0; bound];
+        for n in &nodes {
+            for (target, weight) in &links[*n] {
+                x[*target] += last[*n] * weight;
+            }
+        }
+        for n in &nodes {
+            x[*n] = alpha * x[*n] + beta(*n);
+        }
+        convergence.iterations += 1;
+        convergence.error =
+            nodes.iter().map(|n| (x[*n] - last[*n]).abs()).sum::<f64>() / nodes.len() as f64;
+        if convergence.error < tol {
+            convergence.converged = true;
+            break;
+        }
+    }
+    if normalized {
+        normalize(&mut x, &nodes);
+    }
+    Ok((x, convergence))
+}
+
+/// Reverse the links collected by [`weighted_links`] so the links of every
+/// node are its incoming edges as `(source, weight)` pairs.
+fn transpose(links: &[Vec<(usize, f64)>]) -> Vec<Vec<(usize, f64)>> {
+    let mut out: Vec<Vec<(usize, f64)>> = vec![Vec::new(); links.len()];
+    for (source, node_links) in links.iter().enumerate() {
+        for (target, weight) in node_links {
+            out[*target].push((source, *weight));
+        }
+    }
+    out
+}
+
+/// Find the number of edges on the shortest path from `source` to every
+/// node with a breadth first search, ignoring the weights of the links.
+fn bfs_distances(links: &[Vec<(usize, f64)>], source: usize) -> Vec<Option<f64>> {
+    let mut distance: Vec<Option<f64>> = vec![None; links.len()];
+    let mut queue: VecDeque<usize> = VecDeque::new();
+    distance[source] = Some(0.0);
+    queue.push_back(source);
+    while let Some(node) = queue.pop_front() {
+        let next = distance[node].unwrap() + 1.0;
+        for (neighbor, _) in &links[node] {
+            if distance[*neighbor].is_none() {
+                distance[*neighbor] = Some(next);
+                queue.push_back(*neighbor);
+            }
+        }
+    }
+    distance
+}
+
+/// Find the length of the shortest path from `source` to every node with
+/// Dijkstra's algorithm.
+fn dijkstra_distances(links: &[Vec<(usize, f64)>], source: usize) -> Vec<Option<f64>> {
+    let mut distance: Vec<Option<f64>> = vec![None; links.len()];
+    let mut visited = vec![false; links.len()];
+    let mut heap = BinaryHeap::new();
+    distance[source] = Some(0.0);
+    heap.push(MinScored(0.0, source));
+    while let Some(MinScored(score, node)) = heap.pop() {
+        if visited[node] {
+            continue;
+        }
+        visited[node] = true;
+        for (neighbor, weight) in &links[node] {
+            let next = score + weight;
+            if distance[*neighbor].is_none() || Some(next) < distance[*neighbor] {
+                distance[*neighbor] = Some(next);
+                heap.push(MinScored(next, *neighbor));
+            }
+        }
+    }
+    distance
+}
+
+/// Compute a centrality from the shortest path lengths from every node to
+/// each node. `score` receives the distances to a node (indexed by node
+/// index) and returns its centrality.
+fn distance_centrality<S>(
+    links: Vec<Vec<(usize, f64)>>,
+    nodes: &[usize],
+    weighted: bool,
+    parallel_threshold: usize,
+    score: S,
+) -> Vec<Option<f64>>
+where
+    S: Fn(&[Option<f64>]) -> f64 + Sync,
+{
+    // the shortest paths to a node are the shortest paths from it following
+    // the edges in reverse
+    let links = transpose(&links);
+    let centrality = |node: &usize| {
+        let distance = if weighted {
+            dijkstra_distances(&links, *node)
+        } else {
+            bfs_distances(&links, *node)
+        };
+        (*node, score(&distance))
+    };
+    let scores: Vec<(usize, f64)> = map_nodes(nodes, parallel_threshold, centrality);
+    let mut out = vec![None; links.len()];
+    for (node, value) in scores {
+        out[node] = Some(value);
+    }
+    out
+}
+
+/// The closeness of a node given the distances from every node to it.
+fn closeness(distance: &[Option<f64>], node_count: usize, wf_improved: bool) -> f64 {
+    let reachable = distance.iter().flatten().count();
+    let total: f64 = distance.iter().flatten().sum();
+    closeness_value(reachable, total, node_count, wf_improved)
+}
+
+/// The closeness of a node with `reachable` nodes which can reach it,
+/// including itself, and a sum of distances `total`.
+fn closeness_value(reachable: usize, total: f64, node_count: usize, wf_improved: bool) -> f64 {
+    if total <= 0.0 || node_count <= 1 {
+        return 0.0;
+    }
+    let mut out = (reachable - 1) as f64 / total;
+    if wf_improved {
+        out *= (reachable - 1) as f64 / (node_count - 1) as f64;
+    }
+    out
+}
+
+/// The harmonic centrality of a node given the distances from every node to
+/// it.
+fn harmonic(distance: &[Option<f64>]) -> f64 {
+    distance
+        .iter()
+        .flatten()
+        .filter(|d| **d > 0.0)
+        .map(|d| 1.0 / d)
+        .sum()
+}
+
+/// Compute the closeness centrality of all nodes in a graph.
+///
+/// The closeness centrality of a node `u` is the reciprocal of the average
+/// shortest path distance to `u` over all the `n - 1` nodes which can reach
+/// it [1], where the distances are the number of edges on the shortest
+/// paths. For directed graphs the incoming distances are used. If the graph
+/// isn't connected the Wasserman and Faust improved formula [2] can be used,
+/// which scales the centrality by the fraction of the nodes which can reach
+/// `u`, so nodes in small components don't get a high centrality.
+///
+/// This function is multithreaded and will run in parallel if the number
+/// of nodes in the graph is above the value of ``parallel_threshold``. If the
+/// function will be running in parallel the env var ``RAYON_NUM_THREADS`` can
+/// be used to adjust how many threads will be used.
+///
+/// Arguments:
+///
+/// * `graph` - The graph object to run the algorithm on
+/// * `wf_improved` - Whether to use the Wasserman and Faust improved formula
+/// * `parallel_threshold` - The number of nodes to calculate the closeness
+///   centrality in parallel at, if the number of nodes in `graph` is less
+///   than this value it will run in a single thread.
+///
+/// Returns a [`Vec`] indexed by node index with the centrality of every node
+/// (removed indices are [`None`]).
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::centrality::closeness_centrality;
+///
+/// let g = petgraph::graph::UnGraph::<(), ()>::from_edges(&[(0, 1), (2, 3), (3, 4)]);
+/// let output = closeness_centrality(&g, false, 50);
+/// assert_eq!(output, vec![Some(1.0), Some(1.0), Some(2.0 / 3.0), Some(1.0), Some(2.0 / 3.0)]);
+/// // With the improved formula the nodes in the smaller component are less central
+/// let output = closeness_centrality(&g, true, 50);
+/// assert_eq!(output[0], Some(0.25));
+/// assert_eq!(output[3], Some(0.5));
+/// ```
+///
+/// [1] Linton C. Freeman, "Centrality in networks: I. Conceptual
+///   clarification." Social Networks 1(3):215-239, 1979.
+/// [2] Stanley Wasserman and Katherine Faust, "Social Network Analysis:
+///   Methods and Applications." Cambridge University Press, 1994, pp. 201.
+pub fn closeness_centrality<G>(
+    graph: G,
+    wf_improved: bool,
+    parallel_threshold: usize,
+) -> Vec<Option<f64>>
+where
+    G: IntoEdges + IntoNodeIdentifiers + NodeIndexable + GraphProp,
+{
+    let links = weighted_links(graph, |_| Ok::<f64, Infallible>(1.0)).unwrap();
+    let nodes: Vec<usize> = graph
+        .node_identifiers()
+        .map(|n| graph.to_index(n))
+        .collect();
+    let node_count = nodes.len();
+    distance_centrality(links, &nodes, false, parallel_threshold, |distance| {
+        closeness(distance, node_count, wf_improved)
+    })
+}
+
+/// Compute the closeness centrality of all nodes in a weighted graph.
+///
+/// This is the same as [`closeness_centrality`] except the distances are the
+/// lengths of the shortest paths with the edge weights, which are found with
+/// Dijkstra's algorithm.
+///
+/// Arguments:
+///
+/// * `graph` - The graph object to run the algorithm on
+/// * `weight_fn` - A callable that receives an edge and returns its weight,
+///   which must be non-negative.
+/// * `wf_improved` - Whether to use the Wasserman and Faust improved formula
+/// * `parallel_threshold` - The number of nodes to calculate the closeness
+///   centrality in parallel at, if the number of nodes in `graph` is less
+///   than this value it will run in a single thread.
+///
+/// Returns a [`Vec`] indexed by node index with the centrality of every node
+/// (removed indices are [`None`]).
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::centrality::weighted_closeness_centrality;
+/// use retworkx_core::Result;
+///
+/// let g = petgraph::graph::DiGraph::<(), f64>::from_edges(&[
+///     (0, 1, 2.0), (1, 2, 2.0), (0, 2, 1.0)
+/// ]);
+/// let output: Result<Vec<Option<f64>>> =
+///     weighted_closeness_centrality(&g, |e| Ok(*e.weight()), false, 50);
+/// // Node 2 is reached from node 0 at distance 1 and from node 1 at distance 2
+/// assert_eq!(output.unwrap(), vec![Some(0.0), Some(0.5), Some(2.0 / 3.0)]);
+/// ```
+pub fn weighted_closeness_centrality<G, F, E>(
+    graph: G,
+    weight_fn: F,
+    wf_improved: bool,
+    parallel_threshold: usize,
+) -> Result<Vec<Option<f64>>, E>
+where
+    G: IntoEdges + IntoNodeIdentifiers + NodeIndexable + GraphProp,
+    F: FnMut(G::EdgeRef) -> Result<f64, E>,
+{
+    let links = weighted_links(graph, weight_fn)?;
+    let nodes: Vec<usize> = graph
+        .node_identifiers()
+        .map(|n| graph.to_index(n))
+        .collect();
+    let node_count = nodes.len();
+    Ok(distance_centrality(
+        links,
+        &nodes,
+        true,
+        parallel_threshold,
+        |distance| closeness(distance, node_count, wf_improved),
+    ))
+}
+
+/// Compute the harmonic centrality of all nodes in a graph.
+///
+/// The harmonic centrality of a node `u` is the sum of the reciprocals of the
+/// shortest path distances from all the other nodes to `u` [1], where the
+/// distances are the number of edges on the shortest paths. Unreachable
+/// nodes contribute 0, so unlike closeness centrality it's well defined for
+/// graphs which aren't connected. For directed graphs the incoming distances
+/// are used.
+///
+/// This function is multithreaded and will run in parallel if the number
+/// of nodes in the graph is above the value of ``parallel_threshold``. If the
+/// function will be running in parallel the env var ``RAYON_NUM_THREADS`` can
+/// be used to adjust how many threads will be used.
+///
+/// Arguments:
+///
+/// * `graph` - The graph object to run the algorithm on
+/// * `parallel_threshold` - The number of nodes to calculate the harmonic
+///   centrality in parallel at, if the number of nodes in `graph` is less
+///   than this value it will run in a single thread.
+///
+/// Returns a [`Vec`] indexed by node index with the centrality of every node
+/// (removed indices are [`None`]).
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::centrality::harmonic_centrality;
+///
+/// let g = petgraph::graph::UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 3)]);
+/// let output = harmonic_centrality(&g, 50);
+/// let end = 1.0 + 1.0 / 2.0 + 1.0 / 3.0;
+/// assert_eq!(output, vec![Some(end), Some(2.5), Some(2.5), Some(end)]);
+/// ```
+///
+/// [1] Paolo Boldi and Sebastiano Vigna, "Axioms for centrality." Internet
+///   Mathematics 10(3-4):222-262, 2014.
+pub fn harmonic_centrality<G>(graph: G, parallel_threshold: usize) -> Vec<Option<f64>>
+where
+    G: IntoEdges + IntoNodeIdentifiers + NodeIndexable + GraphProp,
+{
+    let links = weighted_links(graph, |_| Ok::<f64, Infallible>(1.0)).unwrap();
+    let nodes: Vec<usize> = graph
+        .node_identifiers()
+        .map(|n| graph.to_index(n))
+        .collect();
+    distance_centrality(links, &nodes, false, parallel_threshold, harmonic)
+}
+
+/// Compute the harmonic centrality of all nodes in a weighted graph.
+///
+/// This is the same as [`harmonic_centrality`] except the distances are the
+/// lengths of the shortest paths with the edge weights, which are found with
+/// Dijkstra's algorithm. Nodes at distance 0 are ignored.
+///
+/// Arguments:
+///
+/// * `graph` - The graph object to run the algorithm on
+/// * `weight_fn` - A callable that receives an edge and returns its weight,
+///   which must be non-negative.
+/// * `parallel_threshold` - The number of nodes to calculate the harmonic
+///   centrality in parallel at, if the number of nodes in `graph` is less
+///   than this value it will run in a single thread.
+///
+/// Returns a [`Vec`] indexed by node index with the centrality of every node
+/// (removed indices are [`None`]).
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::centrality::weighted_harmonic_centrality;
+/// use retworkx_core::Result;
+///
+/// let g = petgraph::graph::UnGraph::<(), f64>::from_edges(&[(0, 1, 0.5), (1, 2, 2.0)]);
+/// let output: Result<Vec<Option<f64>>> =
+///     weighted_harmonic_centrality(&g, |e| Ok(*e.weight()), 50);
+/// assert_eq!(output.unwrap(), vec![Some(2.4), Some(2.5), Some(0.9)]);
+/// ```
+pub fn weighted_harmonic_centrality<G, F, E>(
+    graph: G,
+    weight_fn: F,
+    parallel_threshold: usize,
+) -> Result<Vec<Option<f64>>, E>
+where
+    G: IntoEdges + IntoNodeIdentifiers + NodeIndexable + GraphProp,
+    F: FnMut(G::EdgeRef) -> Result<f64, E>,
+{
+    let links = weighted_links(graph, weight_fn)?;
+    let nodes: Vec<usize> = graph
+        .node_identifiers()
+        .map(|n| graph.to_index(n))
+        .collect();
+    Ok(distance_centrality(
+        links,
+        &nodes,
+        true,
+        parallel_threshold,
+        harmonic,
+    ))
+}
+
+/// The `k` nodes with the highest scores out of the pushed nodes, with ties
+/// broken by node index.
+struct TopK {
+    k: usize,
+    // the worst kept score is at the top of the heap
+    heap: BinaryHeap<MinScored<(f64, Reverse<usize>), ()>>,
+}
+
+impl TopK {
+    fn new(k: usize) -> Self {
+        TopK {
+            k,
+            heap: BinaryHeap::new(),
+        }
+    }
+
+    /// The lowest kept score, if `k` scores are kept.
+    fn threshold(&self) -> Option<f64> {
+        if self.k > 0 && self.heap.len() == self.k {
+            self.heap.peek().map(|worst| worst.0 .0)
+        } else {
+            None
+        }
+    }
+
+    fn push(&mut self, node: usize, score: f64) {
+        self.heap.push(MinScored((score, Reverse(node)), ()));
+        if self.heap.len() > self.k {
+            self.heap.pop();
+        }
+    }
+
+    fn into_sorted_vec<G: NodeIndexable>(self, graph: G) -> Vec<(G::NodeId, f64)> {
+        self.heap
+            .into_sorted_vec()
+            .into_iter()
+            .map(|MinScored((score, Reverse(node)), ())| (graph.from_index(node), score))
+            .collect()
+    }
+}
+
+/// Find the `k` nodes with the highest degree centrality in a graph.
+///
+/// The degree centrality of a node is its degree divided by `n - 1`, the
+/// maximum degree of a node in a simple graph with `n` nodes. For directed
+/// graphs the degree is the sum of the in degree and the out degree, and a
+/// self loop counts twice in the degree of its node. Only the `k` best
+/// nodes are kept while the degrees are counted, so this takes
+/// `O(m + n log k)` time and `O(n)` memory.
+///
+/// Arguments:
+///
+/// * `graph` - The graph object to run the algorithm on
+/// * `k` - The number of nodes to return
+///
+/// Returns the `min(k, n)` nodes with the highest centrality and their
+/// centrality, sorted by decreasing centrality. Ties are broken by node
+/// index, the lower index first.
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::centrality::top_k_degree_centrality;
+///
+/// let g = petgraph::graph::UnGraph::<(), ()>::from_edges(&[(0, 1), (0, 2), (0, 3), (3, 4)]);
+/// let output: Vec<(usize, f64)> = top_k_degree_centrality(&g, 2)
+///     .into_iter()
+///     .map(|(n, score)| (n.index(), score))
+///     .collect();
+/// assert_eq!(output, vec![(0, 0.75), (3, 0.5)]);
+/// ```
+pub fn top_k_degree_centrality<G>(graph: G, k: usize) -> Vec<(G::NodeId, f64)>
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable + NodeCount,
+{
+    let mut degree = vec![0_usize; graph.node_bound()];
+    for edge in graph.edge_references() {
+        degree[graph.to_index(edge.source())] += 1;
+        degree[graph.to_index(edge.target())] += 1;
+    }
+    let scale = if graph.node_count() > 1 {
+        1.0 / (graph.node_count() - 1) as f64
+    } else {
+        1.0
+    };
+    let mut top_k = TopK::new(k);
+    for node in graph.node_identifiers() {
+        let index = graph.to_index(node);
+        top_k.push(index, degree[index] as f64 * scale);
+    }
+    top_k.into_sorted_vec(graph)
+}
+
+/// Label every node with its connected component in `links`, ignoring the
+/// direction of the links, and return the labels and the size of every
+/// component.
+fn weak_components(links: &[Vec<(usize, f64)>]) -> (Vec<usize>, Vec<usize>) {
+    let mut component = vec![usize::MAX; links.len()];
+    let mut sizes = Vec::new();
+    let reverse = transpose(links);
+    let mut stack = Vec::new();
+    for start in 0..links.len() {
+        if component[start] != usize::MAX {
+            continue;
+        }
+        let label = sizes.len();
+        let mut size = 0;
+        component[start] = label;
+        stack.push(start);
+        while let Some(node) = stack.pop() {
+            size += 1;
+            for (neighbor, _) in links[node].iter().chain(reverse[node].iter()) {
+                if component[*neighbor] == usize::MAX {
+                    component[*neighbor] = label;
+                    stack.push(*neighbor);
+                }
+            }
+        }
+        sizes.push(size);
+    }
+    (component, sizes)
+}
+
+/// Find the `k` nodes with the highest closeness centrality in a graph.
+///
+/// This computes the same centrality as [`closeness_centrality`], but it
+/// stops the breadth first search from a node as soon as the node can't be
+/// one of the `k` best nodes [1]. Once `k` nodes have been found, the search
+/// from every other node computes an upper bound of its closeness after
+/// every level, assuming all the nodes which weren't reached yet but may
+/// reach the node are on the next level. The nodes are searched in order of
+/// decreasing degree so the best nodes tend to be found first. On large
+/// graphs with a small diameter most searches stop after a few levels, which
+/// is much faster than computing the closeness of every node.
 ///
-/// Ulrik Brandes, A Faster Algorithm for Betweenness Centrality.
-/// Journal of Mathematical Sociology 25(2):163-177, 2001.
+/// Arguments:
 ///
-/// This function is multithreaded and will run in parallel if the number
-/// of nodes in the graph is above the value of ``parallel_threshold``. If the
-/// function will be running in parallel the env var ``RAYON_NUM_THREADS`` can
-/// be used to adjust how many threads will be used.
+/// * `graph` - The graph object to run the algorithm on
+/// * `k` - The number of nodes to return
+/// * `wf_improved` - Whether to use the Wasserman and Faust improved formula
+///
+/// Returns the `min(k, n)` nodes with the highest centrality and their
+/// centrality, sorted by decreasing centrality. Nodes tied with the `k`-th
+/// node may be returned in any order of node index.
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::centrality::top_k_closeness_centrality;
+///
+/// let g = petgraph::graph::UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 3), (3, 4)]);
+/// let output: Vec<(usize, f64)> = top_k_closeness_centrality(&g, 1, true)
+///     .into_iter()
+///     .map(|(n, score)| (n.index(), score))
+///     .collect();
+/// assert_eq!(output, vec![(2, 4.0 / 6.0)]);
+/// ```
+///
+/// [1] Elisabetta Bergamini, Michele Borassi, Pierluigi Crescenzi, Andrea
+///   Marino and Henning Meyerhenke, "Computing top-k closeness centrality
+///   faster in unweighted graphs." ACM Transactions on Knowledge Discovery
+///   from Data 13(5):1-40, 2019.
+pub fn top_k_closeness_centrality<G>(graph: G, k: usize, wf_improved: bool) -> Vec<(G::NodeId, f64)>
+where
+    G: IntoEdges + IntoNodeIdentifiers + NodeIndexable + GraphProp,
+{
+    let links = weighted_links(graph, |_| Ok::<f64, Infallible>(1.0)).unwrap();
+    let (component, component_sizes) = weak_components(&links);
+    // the shortest paths to a node are the shortest paths from it following
+    // the edges in reverse
+    let links = transpose(&links);
+    let mut nodes: Vec<usize> = graph
+        .node_identifiers()
+        .map(|n| graph.to_index(n))
+        .collect();
+    let node_count = nodes.len();
+    nodes.sort_by_key(|n| Reverse(links[*n].len()));
+
+    let mut top_k = TopK::new(k);
+    let mut visited = vec![false; links.len()];
+    let mut touched: Vec<usize> = Vec::new();
+    let mut level: Vec<usize> = Vec::new();
+    let mut next_level: Vec<usize> = Vec::new();
+    for node in nodes {
+        // every node which can reach the node is in its weak component
+        let max_reachable = component_sizes[component[node]];
+        let threshold = top_k.threshold();
+        visited[node] = true;
+        touched.push(node);
+        level.push(node);
+        let mut depth = 0;
+        let mut reachable = 1;
+        let mut total = 0.0;
+        let mut pruned = false;
+        while !level.is_empty() {
+            depth += 1;
+            for source in level.drain(..) {
+                for (neighbor, _) in &links[source] {
+                    if !visited[*neighbor] {
+                        visited[*neighbor] = true;
+                        touched.push(*neighbor);
+                        next_level.push(*neighbor);
+                    }
+                }
+            }
+            reachable += next_level.len();
+            total += (next_level.len() * depth) as f64;
+            std::mem::swap(&mut level, &mut next_level);
+            if let Some(threshold) = threshold {
+                if level.is_empty() {
+                    break;
+                }
+                // The closeness is a convex function of the number of nodes
+                // which can reach the node, so it's the largest at either end
+                // of the range
+                let bound = |r: usize| {
+                    let total = total + ((r - reachable) * (depth + 1)) as f64;
+                    closeness_value(r, total, node_count, wf_improved)
+                };
+                if bound(reachable).max(bound(max_reachable)) < threshold {
+                    pruned = true;
+                    break;
+                }
+            }
+        }
+        level.clear();
+        for index in touched.drain(..) {
+            visited[index] = false;
+        }
+        if !pruned {
+            top_k.push(
+                node,
+                closeness_value(reachable, total, node_count, wf_improved),
+            );
+        }
+    }
+    top_k.into_sorted_vec(graph)
+}
+
+/// Find the `k` nodes with the highest betweenness centrality in a graph
+/// with an approximation of the betweenness centrality.
+///
+/// The betweenness centrality of every node is estimated from a sample of
+/// shortest paths [1]. Each sample picks a pair of distinct nodes `s` and
+/// `t` and a shortest path from `s` to `t` uniformly at random, and counts
+/// the nodes on the path other than `s` and `t`. The breadth first search
+/// from `s` stops at the level of `t`, and the number of samples only
+/// depends on the number of nodes logarithmically: it's
+/// `ceil(ln(2 n / delta) / (2 epsilon^2))`. By Hoeffding's inequality and the
+/// union bound, with probability at least `1 - delta` the estimated
+/// betweenness of every node divided by the number of ordered pairs of
+/// nodes, `n (n - 1)`, is within `epsilon` of the exact value.
+///
+/// The estimates are scaled like the output of [`betweenness_centrality`]
+/// without the endpoints, so they are estimates of its values.
 ///
 /// Arguments:
 ///
 /// * `graph` - The graph object to run the algorithm on
-/// * `endpoints` - Whether to include the endpoints of paths in the path
-///     lengths used to compute the betweenness
+/// * `k` - The number of nodes to return
 /// * `normalized` - Whether to normalize the betweenness scores by the number
-///     of distinct paths between all pairs of nodes
-/// * `parallel_threshold` - The number of nodes to calculate the betweenness
-///     centrality in parallel at, if the number of nodes in `graph` is less
-///     than this value it will run in a single thread. A good default to use
-///     here if you're not sure is `50` as that was found to be roughly the
-///     number of nodes where parallelism improves performance
+///   of distinct paths between all pairs of nodes
+/// * `epsilon` - The maximum error of the estimates, as a fraction of the
+///   number of ordered pairs of nodes
+/// * `delta` - The probability that an estimate has a larger error
+/// * `seed` - An optional seed to use for the random number generator
+///
+/// Returns the `min(k, n)` nodes with the highest estimated centrality and
+/// their estimated centrality, sorted by decreasing centrality. Ties are
+/// broken by node index, the lower index first.
+///
+/// Returns an [`InvalidInputError`] if `epsilon` or `delta` isn't between 0
+/// and 1, exclusive.
 ///
 /// # Example
 /// ```rust
 /// use retworkx_core::petgraph;
-/// use retworkx_core::centrality::betweenness_centrality;
+/// use retworkx_core::centrality::top_k_betweenness_centrality;
 ///
-/// let g = petgraph::graph::UnGraph::<i32, ()>::from_edges(&[
-///     (0, 4), (1, 2), (2, 3), (3, 4), (1, 4)
-/// ]);
-/// // Calculate the betweeness centrality
-/// let output = betweenness_centrality(&g, true, true, 200);
-/// assert_eq!(
-///     vec![Some(0.4), Some(0.5), Some(0.45), Some(0.5), Some(0.75)],
-///     output
-/// );
+/// // Every shortest path between 2 leaves of a star passes through its center
+/// let g = petgraph::graph::UnGraph::<(), ()>::from_edges(&[(0, 1), (0, 2), (0, 3), (0, 4)]);
+/// let output = top_k_betweenness_centrality(&g, 2, true, 0.05, 0.1, Some(42)).unwrap();
+/// assert_eq!(output[0].0.index(), 0);
+/// assert!((output[0].1 - 1.0).abs() <= 0.05 * 5.0 / 3.0);
+/// assert_eq!(output[1].1, 0.0);
 /// ```
-pub fn betweenness_centrality<G>(
+///
+/// [1] Matteo Riondato and Evgenios M. Kornaropoulos, "Fast approximation of
+///   betweenness centrality through sampling." Data Mining and Knowledge
+///   Discovery 30(2):438-475, 2016.
+pub fn top_k_betweenness_centrality<G>(
     graph: G,
-    endpoints: bool,
+    k: usize,
     normalized: bool,
-    parallel_threshold: usize,
-) -> Vec<Option<f64>>
+    epsilon: f64,
+    delta: f64,
+    seed: Option<u64>,
+) -> Result<Vec<(G::NodeId, f64)>, InvalidInputError>
 where
-    G: NodeIndexable
-        + IntoNodeIdentifiers
-        + IntoNeighborsDirected
-        + NodeCount
-        + GraphProp
-        + GraphBase<NodeId = NodeIndex>
-        + std::marker::Sync,
-    // rustfmt deletes the following comments if placed inline above
-    // + IntoNodeIdentifiers // for node_identifiers()
-    // + IntoNeighborsDirected // for neighbors()
-    // + NodeCount // for node_count
-    // + GraphProp // for is_directed
+    G: IntoNeighborsDirected + IntoNodeIdentifiers + NodeIndexable + GraphProp,
 {
-    // Correspondence of variable names to quantities in the paper is as follows:
-    //
-    // P -- predecessors
-    // S -- verts_sorted_by_distance,
-    //      vertices in order of non-decreasing distance from s
-    // Q -- Q
-    // sigma -- sigma
-    // delta -- delta
-    // d -- distance
-    let max_index = graph.node_bound();
-
-    let mut betweenness: Vec<Option<f64>> = vec![None; max_index];
-    for node_s in graph.node_identifiers() {
-        let is: usize = graph.to_index(node_s);
-        betweenness[is] = Some(0.0);
+    if !(epsilon > 0.0 && epsilon < 1.0) {
+        return Err(InvalidInputError::InvalidParameter {
+            name: "epsilon",
+            reason: "must be between 0 and 1",
+        });
     }
-    let locked_betweenness = RwLock::new(&mut betweenness);
-    let node_indices: Vec<NodeIndex> = graph.node_identifiers().collect();
-    if graph.node_count() < parallel_threshold {
-        node_indices
-            .iter()
-            .map(|node_s| {
-                (
-                    shortest_path_for_centrality(&graph, node_s),
-                    graph.to_index(*node_s),
-                )
-            })
-            .for_each(|(mut shortest_path_calc, is)| {
-                if endpoints {
-                    _accumulate_endpoints(
-                        &locked_betweenness,
-                        max_index,
-                        &mut shortest_path_calc,
-                        is,
+    if !(delta > 0.0 && delta < 1.0) {
+        return Err(InvalidInputError::InvalidParameter {
+            name: "delta",
+            reason: "must be between 0 and 1",
+        });
+    }
+    let nodes: Vec<G::NodeId> = graph.node_identifiers().collect();
+    let node_count = nodes.len();
+    let mut counts = vec![0_usize; graph.node_bound()];
+    let mut num_samples = 0;
+    if node_count > 2 {
+        num_samples =
+            ((2.0 * node_count as f64 / delta).ln() / (2.0 * epsilon * epsilon)).ceil() as usize;
+        let mut rng: Pcg64 = match seed {
+            Some(seed) => Pcg64::seed_from_u64(seed),
+            None => Pcg64::from_entropy(),
+        };
+        let mut distance = vec![usize::MAX; graph.node_bound()];
+        let mut sigma = vec![0.0; graph.node_bound()];
+        let mut touched: Vec<G::NodeId> = Vec::new();
+        let mut level: Vec<G::NodeId> = Vec::new();
+        let mut next_level: Vec<G::NodeId> = Vec::new();
+        let mut predecessors: Vec<(G::NodeId, f64)> = Vec::new();
+        for _ in 0..num_samples {
+            let s = rng.gen_range(0..node_count);
+            let mut t = rng.gen_range(0..node_count - 1);
+            if t >= s {
+                t += 1;
+            }
+            let (s, t) = (nodes[s], nodes[t]);
+            let t_index = graph.to_index(t);
+            distance[graph.to_index(s)] = 0;
+            sigma[graph.to_index(s)] = 1.0;
+            touched.push(s);
+            level.push(s);
+            let mut depth = 0;
+            // search the levels until the level of t is found
+            while !level.is_empty() && distance[t_index] == usize::MAX {
+                depth += 1;
+                for v in level.drain(..) {
+                    let sigma_v = sigma[graph.to_index(v)];
+                    for w in graph.neighbors_directed(v, Outgoing) {
+                        let w_index = graph.to_index(w);
+                        if distance[w_index] == usize::MAX {
+                            distance[w_index] = depth;
+                            touched.push(w);
+                            next_level.push(w);
+                        }
+                        if distance[w_index] == depth {
+                            sigma[w_index] += sigma_v;
+                        }
+                    }
+                }
+                std::mem::swap(&mut level, &mut next_level);
+            }
+            if distance[t_index] != usize::MAX {
+                // walk back from t picking every predecessor with a
+                // probability proportional to its number of shortest paths
+                let mut v = t;
+                while distance[graph.to_index(v)] > 1 {
+                    let v_index = graph.to_index(v);
+                    predecessors.extend(
+                        graph
+                            .neighbors_directed(v, Incoming)
+                            .map(|p| graph.to_index(p))
+                            .filter(|p| distance[*p] == distance[v_index] - 1)
+                            .map(|p| (graph.from_index(p), sigma[p])),
                     );
-                } else {
-                    _accumulate_basic(&locked_betweenness, max_index, &mut shortest_path_calc, is);
+                    let mut pick = rng.gen::<f64>() * sigma[v_index];
+                    let mut next = predecessors[predecessors.len() - 1].0;
+                    for (p, sigma_p) in predecessors.drain(..) {
+                        if pick < sigma_p {
+                            next = p;
+                            break;
+                        }
+                        pick -= sigma_p;
+                    }
+                    v = next;
+                    counts[graph.to_index(v)] += 1;
                 }
-            });
+            }
+            level.clear();
+            for node in touched.drain(..) {
+                distance[graph.to_index(node)] = usize::MAX;
+                sigma[graph.to_index(node)] = 0.0;
+            }
+        }
+    }
+
+    let pairs = (node_count * node_count.saturating_sub(1)) as f64;
+    let scale = if num_samples == 0 {
+        0.0
+    } else if normalized {
+        pairs / ((node_count - 1) * (node_count - 2)) as f64 / num_samples as f64
+    } else if graph.is_directed() {
+        pairs / num_samples as f64
     } else {
-        node_indices
-            .par_iter()
-            .map(|node_s| {
-                (
-                    shortest_path_for_centrality(&graph, node_s),
-                    graph.to_index(*node_s),
-                )
-            })
-            .for_each(|(mut shortest_path_calc, is)| {
-                if endpoints {
-                    _accumulate_endpoints(
-                        &locked_betweenness,
-                        max_index,
-                        &mut shortest_path_calc,
-                        is,
-                    );
+        pairs / 2.0 / num_samples as f64
+    };
+    let mut top_k = TopK::new(k);
+    for node in nodes {
+        let index = graph.to_index(node);
+        top_k.push(index, counts[index] as f64 * scale);
+    }
+    Ok(top_k.into_sorted_vec(graph))
+}
+
+/// The nodes other than `source` which dominate at least one other node in
+/// the shortest path DAG from `source`, removing any other node doesn't
+/// change the distances from `source`. Returns [`None`] if the DAG has links
+/// of weight 0, where every node has to be assumed to be a dominator.
+fn shortest_path_dominators(
+    links: &[Vec<(usize, f64)>],
+    distance: &[Option<f64>],
+    source: usize,
+) -> Option<Vec<bool>> {
+    // a node dominates another node if and only if it dominates one which
+    // has it as its only predecessor, because the predecessors of a node
+    // are closer to the source
+    let mut predecessor: Vec<Option<usize>> = vec![None; links.len()];
+    let mut multiple = vec![false; links.len()];
+    for (node, node_links) in links.iter().enumerate() {
+        let node_distance = match distance[node] {
+            Some(d) => d,
+            None => continue,
+        };
+        for (target, weight) in node_links {
+            if *target == node || distance[*target] != Some(node_distance + weight) {
+                continue;
+            }
+            if *weight == 0.0 {
+                return None;
+            }
+            match predecessor[*target] {
+                None => predecessor[*target] = Some(node),
+                Some(other) if other != node => multiple[*target] = true,
+                Some(_) => (),
+            }
+        }
+    }
+    let mut dominators = vec![false; links.len()];
+    for (node, pred) in predecessor.iter().enumerate() {
+        if let Some(pred) = pred {
+            if !multiple[node] && *pred != source {
+                dominators[*pred] = true;
+            }
+        }
+    }
+    Some(dominators)
+}
+
+/// The sum of the finite distances in `distance` over `nodes` other than
+/// `removed`, and the number of those nodes which aren't reachable.
+fn distance_sum(distance: &[Option<f64>], nodes: &[usize], removed: usize) -> (f64, usize) {
+    let mut sum = 0.0;
+    let mut unreachable = 0;
+    for node in nodes {
+        if *node == removed {
+            continue;
+        }
+        match distance[*node] {
+            Some(d) => sum += d,
+            None => unreachable += 1,
+        }
+    }
+    (sum, unreachable)
+}
+
+/// Compute the closeness vitality from the links of a graph, see
+/// [`closeness_vitality`].
+fn links_closeness_vitality(
+    links: &[Vec<(usize, f64)>],
+    nodes: &[usize],
+    weighted: bool,
+    directed: bool,
+) -> Vec<Option<f64>> {
+    let bound = links.len();
+    let distances = |links: &[Vec<(usize, f64)>], source: usize| {
+        if weighted {
+            dijkstra_distances(links, source)
+        } else {
+            bfs_distances(links, source)
+        }
+    };
+    // the sum of the distances, and the number of unreachable pairs, in the
+    // graph and in the graph without each node
+    let mut total = (0.0, 0);
+    let mut without: Vec<(f64, usize)> = vec![(0.0, 0); bound];
+    // the sources whose distances change when a node is removed
+    let mut changed: Vec<Vec<usize>> = vec![Vec::new(); bound];
+    for source in nodes {
+        let distance = distances(links, *source);
+        let (sum, unreachable) = distance_sum(&distance, nodes, bound);
+        total.0 += sum;
+        total.1 += unreachable;
+        let dominators = shortest_path_dominators(links, &distance, *source);
+        for node in nodes {
+            if node == source {
+                continue;
+            }
+            let dominator = match &dominators {
+                Some(dominators) => dominators[*node],
+                None => true,
+            };
+            if dominator {
+                changed[*node].push(*source);
+                continue;
+            }
+            let (d, missing) = match distance[*node] {
+                Some(d) => (d, 0),
+                None => (0.0, 1),
+            };
+            without[*node].0 += sum - d;
+            without[*node].1 += unreachable - missing;
+        }
+    }
+    for node in nodes {
+        if changed[*node].is_empty() {
+            continue;
+        }
+        let removed_links: Vec<Vec<(usize, f64)>> = links
+            .iter()
+            .enumerate()
+            .map(|(source, node_links)| {
+                if source == *node {
+                    Vec::new()
                 } else {
-                    _accumulate_basic(&locked_betweenness, max_index, &mut shortest_path_calc, is);
+                    node_links
+                        .iter()
+                        .filter(|(target, _)| target != node)
+                        .copied()
+                        .collect()
                 }
-            });
+            })
+            .collect();
+        for source in &changed[*node] {
+            let distance = distances(&removed_links, *source);
+            let (sum, unreachable) = distance_sum(&distance, nodes, *node);
+            without[*node].0 += sum;
+            without[*node].1 += unreachable;
+        }
     }
-    _rescale(
-        &mut betweenness,
-        graph.node_count(),
-        normalized,
+    let wiener_index = |(sum, unreachable): (f64, usize)| {
+        if unreachable > 0 {
+            f64::INFINITY
+        } else if directed {
+            sum
+        } else {
+            sum / 2.0
+        }
+    };
+    let mut out = vec![None; bound];
+    for node in nodes {
+        out[*node] = Some(wiener_index(total) - wiener_index(without[*node]));
+    }
+    out
+}
+
+/// Compute the closeness vitality of all nodes in a graph.
+///
+/// The closeness vitality of a node is the decrease of the Wiener index of
+/// the graph, the sum of the shortest path distances between all pairs of
+/// nodes, when the node is removed [1]. For undirected graphs every pair of
+/// nodes is counted once. The distances are the number of edges on the
+/// shortest paths. If the graph isn't (strongly) connected its Wiener index
+/// is infinite and the closeness vitality of every node is NaN, otherwise
+/// the closeness vitality of a node whose removal disconnects the graph is
+/// negative infinity.
+///
+/// Instead of computing the shortest paths of the graph without each node
+/// from scratch, the shortest paths from a source are only computed again
+/// when the removed node is the only predecessor of another node on the
+/// shortest paths from that source.
+///
+/// Arguments:
+///
+/// * `graph` - The graph object to run the algorithm on
+///
+/// Returns a [`Vec`] indexed by node index with the closeness vitality of
+/// every node (removed indices are [`None`]).
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::centrality::closeness_vitality;
+///
+/// let g = petgraph::graph::UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 3)]);
+/// let output = closeness_vitality(&g);
+/// assert_eq!(
+///     output,
+///     vec![Some(6.0), Some(f64::NEG_INFINITY), Some(f64::NEG_INFINITY), Some(6.0)]
+/// );
+/// ```
+///
+/// [1] Ulrik Brandes and Thomas Erlebach (Eds.), "Network Analysis:
+///   Methodological Foundations." Springer, 2005, pp. 36-37.
+pub fn closeness_vitality<G>(graph: G) -> Vec<Option<f64>>
+where
+    G: IntoEdges + IntoNodeIdentifiers + NodeIndexable + GraphProp,
+{
+    let links = weighted_links(graph, |_| Ok::<f64, Infallible>(1.0)).unwrap();
+    let nodes: Vec<usize> = graph
+        .node_identifiers()
+        .map(|n| graph.to_index(n))
+        .collect();
+    links_closeness_vitality(&links, &nodes, false, graph.is_directed())
+}
+
+/// Compute the closeness vitality of all nodes in a weighted graph.
+///
+/// This is the same as [`closeness_vitality`] except the distances are the
+/// lengths of the shortest paths with the edge weights, which are found with
+/// Dijkstra's algorithm.
+///
+/// Arguments:
+///
+/// * `graph` - The graph object to run the algorithm on
+/// * `weight_fn` - A callable that receives an edge and returns its weight,
+///   which must be non-negative.
+///
+/// Returns a [`Vec`] indexed by node index with the closeness vitality of
+/// every node (removed indices are [`None`]).
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::centrality::weighted_closeness_vitality;
+/// use retworkx_core::Result;
+///
+/// let g = petgraph::graph::UnGraph::<(), f64>::from_edges(&[
+///     (0, 1, 1.0), (1, 2, 1.0), (0, 2, 3.0)
+/// ]);
+/// let output: Result<Vec<Option<f64>>> = weighted_closeness_vitality(&g, |e| Ok(*e.weight()));
+/// // Without node 1 the distance from 0 to 2 goes from 2 to 3
+/// assert_eq!(output.unwrap(), vec![Some(3.0), Some(1.0), Some(3.0)]);
+/// ```
+pub fn weighted_closeness_vitality<G, F, E>(graph: G, weight_fn: F) -> Result<Vec<Option<f64>>, E>
+where
+    G: IntoEdges + IntoNodeIdentifiers + NodeIndexable + GraphProp,
+    F: FnMut(G::EdgeRef) -> Result<f64, E>,
+{
+    let links = weighted_links(graph, weight_fn)?;
+    let nodes: Vec<usize> = graph
+        .node_identifiers()
+        .map(|n| graph.to_index(n))
+        .collect();
+    Ok(links_closeness_vitality(
+        &links,
+        &nodes,
+        true,
         graph.is_directed(),
-        endpoints,
-    );
+    ))
+}
 
-    betweenness
+/// Build a flow equivalent tree of the nodes `0..node_count` other than
+/// `removed` with Gusfield's algorithm, as a list of tree edges with the
+/// maximum flow between their endpoints.
+fn flow_equivalent_tree(
+    node_count: usize,
+    edges: &[(usize, usize, usize)],
+    removed: Option<usize>,
+) -> Vec<(usize, usize, usize)> {
+    let mut network = FlowNetwork::new(node_count);
+    for (u, v, capacity) in edges {
+        if Some(*u) != removed && Some(*v) != removed {
+            network.add_arc(*u, *v, *capacity, *capacity);
+        }
+    }
+    let nodes: Vec<usize> = (0..node_count).filter(|n| Some(*n) != removed).collect();
+    let mut parent: Vec<usize> = vec![0; nodes.len()];
+    let mut tree = Vec::with_capacity(nodes.len());
+    for s in 1..nodes.len() {
+        let t = parent[s];
+        let mut residual = network.clone();
+        let flow = residual.max_flow(nodes[s], nodes[t]);
+        let side = residual.residual_reachable(nodes[s]);
+        for i in s + 1..nodes.len() {
+            if side[nodes[i]] && parent[i] == t {
+                parent[i] = s;
+            }
+        }
+        tree.push((nodes[s], nodes[t], flow));
+    }
+    tree
+}
+
+/// The sum of the maximum flows between all pairs of nodes of a flow
+/// equivalent tree, which is the smallest flow on the path between them.
+fn tree_flow_sum(node_count: usize, tree: &[(usize, usize, usize)]) -> usize {
+    // adding the edges from the largest flow, the smallest flow between the
+    // nodes joined by an edge is the flow of that edge
+    let mut edges = tree.to_vec();
+    edges.sort_unstable_by_key(|edge| std::cmp::Reverse(edge.2));
+    let mut root: Vec<usize> = (0..node_count).collect();
+    let mut size = vec![1; node_count];
+    fn find(root: &mut [usize], mut node: usize) -> usize {
+        while root[node] != node {
+            root[node] = root[root[node]];
+            node = root[node];
+        }
+        node
+    }
+    let mut sum = 0;
+    for (u, v, flow) in edges {
+        let u = find(&mut root, u);
+        let v = find(&mut root, v);
+        sum += flow * size[u] * size[v];
+        root[v] = u;
+        size[u] += size[v];
+    }
+    sum
+}
+
+/// Compute the flow vitality of all nodes in an undirected graph.
+///
+/// The flow vitality of a node is the decrease of the sum of the maximum
+/// flows between all pairs of other nodes when the node is removed [1]. The
+/// capacity of every edge is given by `capacity_fn`.
+///
+/// The maximum flows between all pairs of nodes are found from a flow
+/// equivalent tree, built with Gusfield's algorithm [2] from only `n - 1`
+/// maximum flow computations. So this only needs `O(n^2)` maximum flow
+/// computations in total, instead of one for every pair of nodes in the
+/// graph without each node.
+///
+/// Arguments:
+///
+/// * `graph` - The graph object to run the algorithm on
+/// * `capacity_fn` - A callable that receives an edge and returns its
+///   capacity.
+///
+/// Returns a [`Vec`] indexed by node index with the flow vitality of every
+/// node (removed indices are [`None`]).
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::centrality::flow_vitality;
+/// use retworkx_core::Result;
+///
+/// // A square with a diagonal from 0 to 2
+/// let g = petgraph::graph::UnGraph::<(), ()>::from_edges(&[
+///     (0, 1), (1, 2), (2, 3), (3, 0), (0, 2)
+/// ]);
+/// let output: Result<Vec<Option<usize>>> = flow_vitality(&g, |_| Ok(1));
+/// assert_eq!(output.unwrap(), vec![Some(3), Some(1), Some(3), Some(1)]);
+/// ```
+///
+/// [1] Ulrik Brandes and Thomas Erlebach (Eds.), "Network Analysis:
+///   Methodological Foundations." Springer, 2005, pp. 37-38.
+/// [2] Dan Gusfield, "Very simple methods for all pairs network flow
+///   analysis." SIAM Journal on Computing 19(1):143-155, 1990.
+pub fn flow_vitality<G, F, E>(graph: G, mut capacity_fn: F) -> Result<Vec<Option<usize>>, E>
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable + GraphProp<EdgeType = Undirected>,
+    F: FnMut(G::EdgeRef) -> Result<usize, E>,
+{
+    let nodes: Vec<G::NodeId> = graph.node_identifiers().collect();
+    let mut index = vec![0; graph.node_bound()];
+    for (i, node) in nodes.iter().enumerate() {
+        index[graph.to_index(*node)] = i;
+    }
+    let mut edges = Vec::new();
+    for edge in graph.edge_references() {
+        let u = index[graph.to_index(edge.source())];
+        let v = index[graph.to_index(edge.target())];
+        let capacity = capacity_fn(edge)?;
+        if u != v {
+            edges.push((u, v, capacity));
+        }
+    }
+    let node_count = nodes.len();
+    let tree = flow_equivalent_tree(node_count, &edges, None);
+    let total = tree_flow_sum(node_count, &tree);
+    let mut adjacency: Vec<Vec<(usize, usize)>> = vec![Vec::new(); node_count];
+    for (u, v, flow) in &tree {
+        adjacency[*u].push((*v, *flow));
+        adjacency[*v].push((*u, *flow));
+    }
+    let mut out = vec![None; graph.node_bound()];
+    for (node, id) in nodes.iter().enumerate() {
+        // the flows from ``node`` to all the other nodes, the smallest flow
+        // on the tree path to them
+        let mut node_flows = 0;
+        let mut stack = vec![(node, node, usize::MAX)];
+        while let Some((current, parent, flow)) = stack.pop() {
+            for (next, edge_flow) in &adjacency[current] {
+                if *next != parent {
+                    let next_flow = flow.min(*edge_flow);
+                    node_flows += next_flow;
+                    stack.push((*next, current, next_flow));
+                }
+            }
+        }
+        let removed_tree = flow_equivalent_tree(node_count, &edges, Some(node));
+        // the removed node is left as a node without any tree edges
+        let removed_total = tree_flow_sum(node_count, &removed_tree);
+        out[graph.to_index(*id)] = Some(total - node_flows - removed_total);
+    }
+    Ok(out)
 }
 
 fn _rescale(
@@ -242,6 +1932,36 @@ fn _accumulate_endpoints(
     }
 }
 
+fn _accumulate_subset(
+    locked_betweenness: &RwLock<&mut Vec<Option<f64>>>,
+    max_index: usize,
+    path_calc: &mut ShortestPathData,
+    is: usize,
+    is_target: &[bool],
+) {
+    let mut delta = vec![0.0; max_index];
+    for w in &path_calc.verts_sorted_by_distance {
+        let iw = w.index();
+        let coeff = if is_target[iw] && iw != is {
+            (1.0 + delta[iw]) / path_calc.sigma[w]
+        } else {
+            delta[iw] / path_calc.sigma[w]
+        };
+        let p_w = path_calc.predecessors.get(w).unwrap();
+        for v in p_w {
+            let iv = (*v).index();
+            delta[iv] += path_calc.sigma[v] * coeff;
+        }
+    }
+    let mut betweenness = locked_betweenness.write().unwrap();
+    for w in &path_calc.verts_sorted_by_distance {
+        let iw = w.index();
+        if iw != is {
+            betweenness[iw] = betweenness[iw].map(|x| x + delta[iw]);
+        }
+    }
+}
+
 struct ShortestPathData {
     verts_sorted_by_distance: Vec<NodeIndex>,
     predecessors: HashMap<NodeIndex, Vec<NodeIndex>>,
@@ -297,3 +2017,47 @@ where
         sigma,
     }
 }
+
+/// The weighted counterpart of [`shortest_path_for_centrality`], using
+/// Dijkstra's algorithm on the links collected by [`weighted_links`].
+fn dijkstra_for_centrality(links: &[Vec<(usize, f64)>], node_s: &NodeIndex) -> ShortestPathData {
+    let mut verts_sorted_by_distance: Vec<NodeIndex> = Vec::new(); // a stack
+    let mut predecessors = HashMap::<NodeIndex, Vec<NodeIndex>>::new();
+    let mut sigma = HashMap::<NodeIndex, f64>::new();
+    let mut distance: Vec<Option<f64>> = vec![None; links.len()];
+    let mut done = vec![false; links.len()];
+    let mut heap = BinaryHeap::new();
+
+    let i_s = node_s.index();
+    predecessors.insert(*node_s, Vec::new());
+    sigma.insert(*node_s, 1.0);
+    distance[i_s] = Some(0.0);
+    heap.push(MinScored(0.0, i_s));
+    while let Some(MinScored(distance_v, iv)) = heap.pop() {
+        if done[iv] {
+            continue;
+        }
+        done[iv] = true;
+        let v = NodeIndex::new(iv);
+        verts_sorted_by_distance.push(v);
+        for (iw, weight) in &links[iv] {
+            let w = NodeIndex::new(*iw);
+            let distance_w = distance_v + weight;
+            if !done[*iw] && (distance[*iw].is_none() || Some(distance_w) < distance[*iw]) {
+                distance[*iw] = Some(distance_w);
+                heap.push(MinScored(distance_w, *iw));
+                sigma.insert(w, sigma[&v]);
+                predecessors.insert(w, vec![v]);
+            } else if distance[*iw] == Some(distance_w) {
+                sigma.insert(w, sigma[&w] + sigma[&v]);
+                predecessors.get_mut(&w).unwrap().push(v);
+            }
+        }
+    }
+    verts_sorted_by_distance.reverse(); // will be effectively popping from the stack
+    ShortestPathData {
+        verts_sorted_by_distance,
+        predecessors,
+        sigma,
+    }
+}
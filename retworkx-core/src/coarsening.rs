@@ -0,0 +1,325 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+//! Module for the multilevel coarsening of graphs, the first phase of
+//! multilevel layout and partitioning algorithms.
+
+use petgraph::visit::{EdgeRef, IntoEdgeReferences, IntoNodeIdentifiers, NodeIndexable};
+use rand::prelude::*;
+use rand_pcg::Pcg64;
+
+/// Coarsening stops when a level keeps more than this fraction of the nodes
+/// of the previous level.
+const MAX_LEVEL_RATIO: f64 = 0.95;
+
+/// A weighted undirected graph of a coarsening hierarchy, where every node
+/// is a group of nodes of the input graph.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CoarseGraph {
+    /// The number of nodes of the input graph merged into every node.
+    pub sizes: Vec<usize>,
+    /// The neighbors of every node with the total weight of the edges
+    /// between them, in order of neighbor. Every edge is stored at both of
+    /// its nodes and there are no self loops.
+    pub adjacency: Vec<Vec<(usize, f64)>>,
+}
+
+impl CoarseGraph {
+    /// Build the first level of a hierarchy from a graph, with a node of
+    /// size 1 for every node of the graph. The edges are considered
+    /// undirected, parallel edges are merged and self loops are ignored.
+    /// Returns the nodes of the graph in the order of their node indices,
+    /// node `i` of the level is the `i`th one, along with the level.
+    pub fn from_graph<G, F, E>(graph: G, mut weight_fn: F) -> Result<(Vec<G::NodeId>, Self), E>
+    where
+        G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable,
+        F: FnMut(G::EdgeRef) -> Result<f64, E>,
+    {
+        let mut nodes: Vec<G::NodeId> = graph.node_identifiers().collect();
+        nodes.sort_by_key(|node| graph.to_index(*node));
+        let mut position = vec![usize::MAX; graph.node_bound()];
+        for (i, node) in nodes.iter().enumerate() {
+            position[graph.to_index(*node)] = i;
+        }
+        let mut adjacency: Vec<Vec<(usize, f64)>> = vec![Vec::new(); nodes.len()];
+        for edge in graph.edge_references() {
+            let source = position[graph.to_index(edge.source())];
+            let target = position[graph.to_index(edge.target())];
+            let weight = weight_fn(edge)?;
+            if source != target {
+                adjacency[source].push((target, weight));
+                adjacency[target].push((source, weight));
+            }
+        }
+        // Contracting the graph onto itself merges the parallel edges
+        let identity: Vec<usize> = (0..nodes.len()).collect();
+        let level = CoarseGraph {
+            sizes: vec![1; nodes.len()],
+            adjacency,
+        }
+        .contract(&identity, nodes.len());
+        Ok((nodes, level))
+    }
+
+    /// The number of nodes.
+    pub fn node_count(&self) -> usize {
+        self.sizes.len()
+    }
+
+    /// The edges as `(source, target, weight)` tuples with `source` lower
+    /// than `target`, in order.
+    pub fn edges(&self) -> impl Iterator<Item = (usize, usize, f64)> + '_ {
+        self.adjacency
+            .iter()
+            .enumerate()
+            .flat_map(|(source, neighbors)| {
+                neighbors
+                    .iter()
+                    .filter(move |(target, _)| source < *target)
+                    .map(move |(target, weight)| (source, *target, *weight))
+            })
+    }
+
+    /// Merge the nodes of the graph into the nodes of a coarser graph,
+    /// `map` is the node of the coarser graph every node is merged into and
+    /// `count` the number of nodes of the coarser graph. The weights of the
+    /// edges between the same nodes of the coarser graph are added up and
+    /// the edges between nodes merged together are dropped.
+    pub fn contract(&self, map: &[usize], count: usize) -> CoarseGraph {
+        let mut sizes = vec![0; count];
+        let mut members: Vec<Vec<usize>> = vec![Vec::new(); count];
+        for (node, coarse) in map.iter().enumerate() {
+            sizes[*coarse] += self.sizes[node];
+            members[*coarse].push(node);
+        }
+        // The position of every neighbor in the adjacency list being built
+        let mut position = vec![usize::MAX; count];
+        let adjacency = members
+            .iter()
+            .enumerate()
+            .map(|(coarse, nodes)| {
+                let mut neighbors: Vec<(usize, f64)> = Vec::new();
+                for node in nodes {
+                    for (neighbor, weight) in &self.adjacency[*node] {
+                        let other = map[*neighbor];
+                        if other == coarse {
+                            continue;
+                        }
+                        if position[other] == usize::MAX {
+                            position[other] = neighbors.len();
+                            neighbors.push((other, 0.0));
+                        }
+                        neighbors[position[other]].1 += weight;
+                    }
+                }
+                for (other, _) in &neighbors {
+                    position[*other] = usize::MAX;
+                }
+                neighbors.sort_unstable_by_key(|(other, _)| *other);
+                neighbors
+            })
+            .collect();
+        CoarseGraph { sizes, adjacency }
+    }
+}
+
+/// Match the nodes of a graph by heavy edge matching.
+///
+/// The nodes are visited in a random order and every node not matched yet
+/// is matched with the neighbor not matched yet with the heaviest edge
+/// between them, preferring the neighbor with the smallest size on ties.
+/// Returns the node of the coarser graph every node is merged into, with a
+/// node for every matched pair and for every node left unmatched, numbered
+/// in the order of their lowest node, and the number of nodes of the
+/// coarser graph.
+pub fn heavy_edge_matching<R: Rng>(graph: &CoarseGraph, rng: &mut R) -> (Vec<usize>, usize) {
+    let n = graph.node_count();
+    let mut order: Vec<usize> = (0..n).collect();
+    order.shuffle(rng);
+    let mut mate: Vec<usize> = vec![usize::MAX; n];
+    for node in order {
+        if mate[node] != usize::MAX {
+            continue;
+        }
+        mate[node] = node;
+        let mut best: Option<(usize, f64)> = None;
+        for (neighbor, weight) in &graph.adjacency[node] {
+            if mate[*neighbor] != usize::MAX {
+                continue;
+            }
+            let better = match best {
+                None => true,
+                Some((other, best_weight)) => {
+                    *weight > best_weight
+                        || (*weight == best_weight && graph.sizes[*neighbor] < graph.sizes[other])
+                }
+            };
+            if better {
+                best = Some((*neighbor, *weight));
+            }
+        }
+        if let Some((neighbor, _)) = best {
+            mate[node] = neighbor;
+            mate[neighbor] = node;
+        }
+    }
+    let mut map = vec![usize::MAX; n];
+    let mut count = 0;
+    for node in 0..n {
+        if map[node] == usize::MAX {
+            map[node] = count;
+            map[mate[node]] = count;
+            count += 1;
+        }
+    }
+    (map, count)
+}
+
+/// Build the levels of a hierarchy after `first`, with the stopping rules
+/// of [`coarsen`]. Returns the levels, starting with `first`, and the
+/// prolongation maps between them.
+pub(crate) fn coarsen_levels<R: Rng>(
+    first: CoarseGraph,
+    min_nodes: usize,
+    max_levels: Option<usize>,
+    rng: &mut R,
+) -> (Vec<CoarseGraph>, Vec<Vec<usize>>) {
+    let mut levels = vec![first];
+    let mut maps: Vec<Vec<usize>> = Vec::new();
+    loop {
+        let current = levels.last().unwrap();
+        let n = current.node_count();
+        if n <= min_nodes || max_levels.map_or(false, |max| maps.len() >= max) {
+            break;
+        }
+        let (map, count) = heavy_edge_matching(current, rng);
+        if count as f64 > MAX_LEVEL_RATIO * n as f64 {
+            break;
+        }
+        let next = current.contract(&map, count);
+        maps.push(map);
+        levels.push(next);
+    }
+    (levels, maps)
+}
+
+/// A hierarchy of progressively coarser graphs built from a graph.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Coarsening<N> {
+    /// The nodes of the input graph in the order of their node indices,
+    /// node `i` of the first level is `nodes[i]`.
+    pub nodes: Vec<N>,
+    /// The graphs from the finest, the input graph, to the coarsest.
+    pub levels: Vec<CoarseGraph>,
+    /// The prolongation maps between the levels: `maps[i][v]` is the node of
+    /// `levels[i + 1]` that node `v` of `levels[i]` is merged into.
+    pub maps: Vec<Vec<usize>>,
+}
+
+impl<N> Coarsening<N> {
+    /// The node of `levels[level]` every node of the first level is merged
+    /// into.
+    pub fn level_map(&self, level: usize) -> Vec<usize> {
+        let mut map: Vec<usize> = (0..self.nodes.len()).collect();
+        for level_map in &self.maps[..level] {
+            for node in map.iter_mut() {
+                *node = level_map[*node];
+            }
+        }
+        map
+    }
+
+    /// Prolong values of the nodes of `levels[level]` to the nodes of
+    /// `levels[level - 1]`, every node gets the value of the node it is
+    /// merged into.
+    ///
+    /// # Panics
+    ///
+    /// If `level` is 0.
+    pub fn prolong<T: Clone>(&self, level: usize, values: &[T]) -> Vec<T> {
+        self.maps[level - 1]
+            .iter()
+            .map(|coarse| values[*coarse].clone())
+            .collect()
+    }
+}
+
+/// Coarsen a graph into a hierarchy of progressively smaller graphs.
+///
+/// Every level is built by merging the pairs of nodes of a heavy edge
+/// matching of the previous level [1], adding up the weights of the edges
+/// between the same nodes. The edges are considered undirected, parallel
+/// edges are merged and self loops are ignored. The first level is the
+/// input graph, with its nodes numbered in order of node index.
+///
+/// Coarsening stops when a level has at most `min_nodes` nodes, when
+/// `max_levels` levels were built after the first one, or when a matching
+/// merges less than 5% of the nodes, for example when only isolated nodes
+/// are left or for star-like graphs, where only a few nodes can be matched.
+///
+/// Arguments:
+///
+/// * `graph` - The graph to coarsen
+/// * `weight_fn` - A callable that receives an edge and returns its weight,
+///   or an error which is returned right away
+/// * `min_nodes` - The number of nodes at or below which coarsening stops
+/// * `max_levels` - The optional maximum number of levels to build after the
+///   first one
+/// * `seed` - An optional seed to use for the random number generator
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::coarsening::coarsen;
+/// use retworkx_core::Result;
+///
+/// // A path of 8 nodes with heavy edges (0, 1), (2, 3), (4, 5) and (6, 7)
+/// let g = petgraph::graph::UnGraph::<(), f64>::from_edges(&[
+///     (0, 1, 5.0), (1, 2, 1.0), (2, 3, 5.0), (3, 4, 1.0),
+///     (4, 5, 5.0), (5, 6, 1.0), (6, 7, 5.0),
+/// ]);
+/// let coarsening = coarsen(&g, |e| Ok(*e.weight()), 4, Some(1), Some(7));
+/// let coarsening: Result<_> = coarsening;
+/// let coarsening = coarsening.unwrap();
+/// assert_eq!(coarsening.levels.len(), 2);
+/// assert_eq!(coarsening.maps[0], vec![0, 0, 1, 1, 2, 2, 3, 3]);
+/// assert_eq!(coarsening.levels[1].sizes, vec![2, 2, 2, 2]);
+/// let edges: Vec<_> = coarsening.levels[1].edges().collect();
+/// assert_eq!(edges, vec![(0, 1, 1.0), (1, 2, 1.0), (2, 3, 1.0)]);
+/// ```
+///
+/// [1] B. Hendrickson and R. Leland, "A Multi-Level Algorithm For
+///   Partitioning Graphs", Proceedings of the 1995 ACM/IEEE Conference on
+///   Supercomputing, 1995.
+pub fn coarsen<G, F, E>(
+    graph: G,
+    weight_fn: F,
+    min_nodes: usize,
+    max_levels: Option<usize>,
+    seed: Option<u64>,
+) -> Result<Coarsening<G::NodeId>, E>
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable,
+    F: FnMut(G::EdgeRef) -> Result<f64, E>,
+{
+    let (nodes, first) = CoarseGraph::from_graph(graph, weight_fn)?;
+    let mut rng: Pcg64 = match seed {
+        Some(seed) => Pcg64::seed_from_u64(seed),
+        None => Pcg64::from_entropy(),
+    };
+    let (levels, maps) = coarsen_levels(first, min_nodes, max_levels, &mut rng);
+    Ok(Coarsening {
+        nodes,
+        levels,
+        maps,
+    })
+}
@@ -0,0 +1,448 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+//! Module for community detection algorithms.
+
+use hashbrown::HashMap;
+
+use petgraph::visit::{EdgeRef, IntoEdgeReferences, IntoNodeIdentifiers, NodeIndexable};
+use rand::prelude::*;
+use rand_pcg::Pcg64;
+
+/// Compute the modularity of a partition of the nodes of a graph.
+///
+/// The modularity of a partition into communities `C` is [1]:
+///
+/// ```text
+/// Q = sum(L_c / m - resolution * (d_c / (2 m))^2 for c in C)
+/// ```
+///
+/// where `m` is the total weight of the edges, `L_c` is the total weight of
+/// the edges between the nodes of `c` and `d_c` is the sum of the weighted
+/// degrees of the nodes of `c`. The edges are considered undirected and a
+/// self loop counts twice in the degree of its node. The nodes which aren't
+/// in any community are each in a community of their own. The modularity of
+/// a graph without edges is 0.
+///
+/// Arguments:
+///
+/// * `graph` - The graph the partition is of
+/// * `communities` - The nodes of every community, a node must not be in
+///   more than one community
+/// * `weight_fn` - A callable that receives an edge and returns its weight,
+///   which must be non-negative.
+/// * `resolution` - The resolution, a value below 1 favors larger communities
+///   and a value above 1 favors smaller communities
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::petgraph::graph::NodeIndex;
+/// use retworkx_core::community::modularity;
+/// use retworkx_core::Result;
+///
+/// // Two triangles joined by the edge (2, 3)
+/// let g = petgraph::graph::UnGraph::<(), ()>::from_edges(&[
+///     (0, 1), (1, 2), (0, 2), (2, 3), (3, 4), (4, 5), (3, 5)
+/// ]);
+/// let communities: Vec<Vec<NodeIndex>> = vec![
+///     (0..3).map(NodeIndex::new).collect(),
+///     (3..6).map(NodeIndex::new).collect(),
+/// ];
+/// let q: Result<f64> = modularity(&g, &communities, |_| Ok(1.0), 1.0);
+/// assert!((q.unwrap() - 5.0 / 14.0).abs() < 1e-12);
+/// ```
+///
+/// [1] M. E. J. Newman, "Modularity and community structure in networks",
+///   Proceedings of the National Academy of Sciences, 103(23):8577-8582,
+///   2006.
+pub fn modularity<G, F, E>(
+    graph: G,
+    communities: &[Vec<G::NodeId>],
+    mut weight_fn: F,
+    resolution: f64,
+) -> Result<f64, E>
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable,
+    F: FnMut(G::EdgeRef) -> Result<f64, E>,
+{
+    let mut community = vec![usize::MAX; graph.node_bound()];
+    for (label, nodes) in communities.iter().enumerate() {
+        for node in nodes {
+            community[graph.to_index(*node)] = label;
+        }
+    }
+    let mut next_label = communities.len();
+    for node in graph.node_identifiers() {
+        let index = graph.to_index(node);
+        if community[index] == usize::MAX {
+            community[index] = next_label;
+            next_label += 1;
+        }
+    }
+    let mut internal = vec![0.0; next_label];
+    let mut degree = vec![0.0; next_label];
+    let mut total = 0.0;
+    for edge in graph.edge_references() {
+        let weight = weight_fn(edge)?;
+        let source = community[graph.to_index(edge.source())];
+        let target = community[graph.to_index(edge.target())];
+        if source == target {
+            internal[source] += weight;
+        }
+        degree[source] += weight;
+        degree[target] += weight;
+        total += weight;
+    }
+    if total <= 0.0 {
+        return Ok(0.0);
+    }
+    Ok(internal
+        .iter()
+        .zip(degree.iter())
+        .map(|(l, d)| l / total - resolution * (d / (2.0 * total)).powi(2))
+        .sum())
+}
+
+/// A graph where every node is a group of nodes of the previous level.
+struct Level {
+    /// The weights of the links between different nodes, every link is
+    /// stored at both of its nodes
+    links: Vec<Vec<(usize, f64)>>,
+    /// The total weight of the edges inside every node
+    internal: Vec<f64>,
+    /// The weighted degree of every node
+    degree: Vec<f64>,
+    /// The nodes every node can't share a community with
+    cannot_link: Vec<Vec<usize>>,
+}
+
+impl Level {
+    fn len(&self) -> usize {
+        self.links.len()
+    }
+
+    /// The modularity of the partition where `community` is the community
+    /// of every node of the level.
+    fn modularity(&self, community: &[usize], total: f64, resolution: f64) -> f64 {
+        let mut internal = vec![0.0; self.len()];
+        let mut degree = vec![0.0; self.len()];
+        for node in 0..self.len() {
+            let label = community[node];
+            internal[label] += self.internal[node];
+            degree[label] += self.degree[node];
+            for (neighbor, weight) in &self.links[node] {
+                if community[*neighbor] == label {
+                    // every link is seen from both of its nodes
+                    internal[label] += weight / 2.0;
+                }
+            }
+        }
+        internal
+            .iter()
+            .zip(degree.iter())
+            .map(|(l, d)| l / total - resolution * (d / (2.0 * total)).powi(2))
+            .sum()
+    }
+
+    /// Move every node to the neighboring community which increases the
+    /// modularity the most, until a pass over all the nodes increases it by
+    /// `threshold` or less. Returns the community of every node, labeled
+    /// from 0.
+    fn move_nodes(
+        &self,
+        total: f64,
+        resolution: f64,
+        threshold: f64,
+        rng: &mut Pcg64,
+    ) -> Vec<usize> {
+        let mut community: Vec<usize> = (0..self.len()).collect();
+        let mut community_degree = self.degree.clone();
+        let mut order: Vec<usize> = (0..self.len()).collect();
+        order.shuffle(rng);
+        let mut link_weight = vec![0.0; self.len()];
+        let mut neighbor_communities: Vec<usize> = Vec::new();
+        let mut current = self.modularity(&community, total, resolution);
+        loop {
+            for node in &order {
+                let node = *node;
+                let old = community[node];
+                let degree = self.degree[node];
+                for (neighbor, weight) in &self.links[node] {
+                    let label = community[*neighbor];
+                    if link_weight[label] == 0.0 {
+                        neighbor_communities.push(label);
+                    }
+                    link_weight[label] += weight;
+                }
+                community_degree[old] -= degree;
+                let gain = |label: usize| {
+                    link_weight[label]
+                        - resolution * community_degree[label] * degree / (2.0 * total)
+                };
+                let mut best = old;
+                let mut best_gain = gain(old);
+                for label in &neighbor_communities {
+                    if *label == old
+                        || self.cannot_link[node]
+                            .iter()
+                            .any(|other| community[*other] == *label)
+                    {
+                        continue;
+                    }
+                    let label_gain = gain(*label);
+                    if label_gain > best_gain {
+                        best = *label;
+                        best_gain = label_gain;
+                    }
+                }
+                community_degree[best] += degree;
+                community[node] = best;
+                for label in neighbor_communities.drain(..) {
+                    link_weight[label] = 0.0;
+                }
+            }
+            let next = self.modularity(&community, total, resolution);
+            if next - current <= threshold {
+                break;
+            }
+            current = next;
+        }
+        let mut labels = vec![usize::MAX; self.len()];
+        let mut next_label = 0;
+        for label in community.iter_mut() {
+            if labels[*label] == usize::MAX {
+                labels[*label] = next_label;
+                next_label += 1;
+            }
+            *label = labels[*label];
+        }
+        community
+    }
+
+    /// The level where every community of this level is a node.
+    fn aggregate(&self, community: &[usize]) -> Level {
+        let count = community.iter().max().map_or(0, |max| max + 1);
+        let mut links: Vec<HashMap<usize, f64>> = vec![HashMap::new(); count];
+        let mut internal = vec![0.0; count];
+        let mut degree = vec![0.0; count];
+        let mut cannot_link: Vec<Vec<usize>> = vec![Vec::new(); count];
+        for node in 0..self.len() {
+            let label = community[node];
+            internal[label] += self.internal[node];
+            degree[label] += self.degree[node];
+            for (neighbor, weight) in &self.links[node] {
+                let other = community[*neighbor];
+                if other == label {
+                    internal[label] += weight / 2.0;
+                } else {
+                    *links[label].entry(other).or_insert(0.0) += weight;
+                }
+            }
+            cannot_link[label].extend(self.cannot_link[node].iter().map(|n| community[*n]));
+        }
+        for nodes in cannot_link.iter_mut() {
+            nodes.sort_unstable();
+            nodes.dedup();
+        }
+        Level {
+            links: links
+                .into_iter()
+                .map(|node_links| node_links.into_iter().collect())
+                .collect(),
+            internal,
+            degree,
+            cannot_link,
+        }
+    }
+}
+
+fn find(parent: &mut [usize], mut node: usize) -> usize {
+    while parent[node] != node {
+        parent[node] = parent[parent[node]];
+        node = parent[node];
+    }
+    node
+}
+
+/// Find the communities of a graph with the Louvain method, with optional
+/// must-link and cannot-link constraints.
+///
+/// The Louvain method [1] greedily maximizes the [`modularity`] of the
+/// partition. Starting with every node in its own community, it repeatedly
+/// moves every node, in a random order, to the neighboring community which
+/// increases the modularity the most, until a pass over the nodes increases
+/// the modularity by `threshold` or less. Then every community becomes a
+/// node of a new graph, and the process is repeated on it until the
+/// modularity increases by `threshold` or less. The edges are considered
+/// undirected.
+///
+/// The constraints are enforced during the whole search rather than by
+/// fixing the partition afterwards [2]:
+///
+/// * the nodes of every must-link pair are merged into a single node before
+///   the first pass, so they are always in the same community.
+/// * a node is never moved to a community with a node it has a cannot-link
+///   pair with, and the cannot-link pairs are carried over to the communities
+///   when they become nodes, so the nodes of a cannot-link pair are never in
+///   the same community.
+///
+/// Arguments:
+///
+/// * `graph` - The graph to find the communities of
+/// * `weight_fn` - A callable that receives an edge and returns its weight,
+///   which must be non-negative.
+/// * `resolution` - The resolution of the modularity, a value below 1 favors
+///   larger communities and a value above 1 favors smaller communities
+/// * `threshold` - The minimum increase of the modularity to keep going
+/// * `must_link` - Pairs of nodes which must be in the same community
+/// * `cannot_link` - Pairs of nodes which must be in different communities
+/// * `seed` - An optional seed to use for the random number generator
+///
+/// Returns the communities, each sorted by node index, in order of their
+/// lowest node index. Returns `None` if the constraints can't be satisfied,
+/// because a cannot-link pair is in the same group of nodes connected by
+/// must-link pairs.
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::petgraph::graph::NodeIndex;
+/// use retworkx_core::community::louvain_communities;
+/// use retworkx_core::Result;
+///
+/// // Two triangles joined by the edge (2, 3)
+/// let g = petgraph::graph::UnGraph::<(), ()>::from_edges(&[
+///     (0, 1), (1, 2), (0, 2), (2, 3), (3, 4), (4, 5), (3, 5)
+/// ]);
+/// let communities: Result<Option<Vec<Vec<NodeIndex>>>> =
+///     louvain_communities(&g, |_| Ok(1.0), 1.0, 1e-7, &[], &[], Some(42));
+/// let communities = communities.unwrap().unwrap();
+/// assert_eq!(communities.len(), 2);
+/// assert_eq!(communities[0], vec![0.into(), 1.into(), 2.into()]);
+///
+/// // Node 2 can't be with node 0 but must be with node 3
+/// let (n0, n2, n3) = (NodeIndex::new(0), NodeIndex::new(2), NodeIndex::new(3));
+/// let communities: Result<Option<Vec<Vec<NodeIndex>>>> =
+///     louvain_communities(&g, |_| Ok(1.0), 1.0, 1e-7, &[(n2, n3)], &[(n0, n2)], Some(42));
+/// let communities = communities.unwrap().unwrap();
+/// assert!(communities.iter().any(|c| c.contains(&n2) && c.contains(&n3)));
+/// assert!(communities.iter().all(|c| !(c.contains(&n0) && c.contains(&n2))));
+/// ```
+///
+/// [1] V. D. Blondel, J.-L. Guillaume, R. Lambiotte and E. Lefebvre, "Fast
+///   unfolding of communities in large networks", Journal of Statistical
+///   Mechanics: Theory and Experiment, 2008(10):P10008, 2008.
+/// [2] S. Basu, I. Davidson and K. Wagstaff, "Constrained Clustering:
+///   Advances in Algorithms, Theory, and Applications", Chapman & Hall/CRC,
+///   2008.
+pub fn louvain_communities<G, F, E>(
+    graph: G,
+    mut weight_fn: F,
+    resolution: f64,
+    threshold: f64,
+    must_link: &[(G::NodeId, G::NodeId)],
+    cannot_link: &[(G::NodeId, G::NodeId)],
+    seed: Option<u64>,
+) -> Result<Option<Vec<Vec<G::NodeId>>>, E>
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable,
+    F: FnMut(G::EdgeRef) -> Result<f64, E>,
+{
+    // Merge the nodes of the must-link pairs into groups, the nodes of the
+    // first level
+    let mut parent: Vec<usize> = (0..graph.node_bound()).collect();
+    for (a, b) in must_link {
+        let a = find(&mut parent, graph.to_index(*a));
+        let b = find(&mut parent, graph.to_index(*b));
+        parent[a.max(b)] = a.min(b);
+    }
+    let mut group = vec![usize::MAX; graph.node_bound()];
+    let mut group_count = 0;
+    for node in graph.node_identifiers() {
+        let index = graph.to_index(node);
+        let root = find(&mut parent, index);
+        if group[root] == usize::MAX {
+            group[root] = group_count;
+            group_count += 1;
+        }
+        group[index] = group[root];
+    }
+
+    let mut level_cannot_link: Vec<Vec<usize>> = vec![Vec::new(); group_count];
+    for (a, b) in cannot_link {
+        let a = group[graph.to_index(*a)];
+        let b = group[graph.to_index(*b)];
+        if a == b {
+            return Ok(None);
+        }
+        level_cannot_link[a].push(b);
+        level_cannot_link[b].push(a);
+    }
+    let mut links: Vec<Vec<(usize, f64)>> = vec![Vec::new(); group_count];
+    let mut internal = vec![0.0; group_count];
+    let mut degree = vec![0.0; group_count];
+    let mut total = 0.0;
+    for edge in graph.edge_references() {
+        let weight = weight_fn(edge)?;
+        let source = group[graph.to_index(edge.source())];
+        let target = group[graph.to_index(edge.target())];
+        if source == target {
+            internal[source] += weight;
+        } else {
+            links[source].push((target, weight));
+            links[target].push((source, weight));
+        }
+        degree[source] += weight;
+        degree[target] += weight;
+        total += weight;
+    }
+    let mut level = Level {
+        links,
+        internal,
+        degree,
+        cannot_link: level_cannot_link,
+    };
+    // The community of every group of the first level
+    let mut partition: Vec<usize> = (0..group_count).collect();
+
+    if total > 0.0 {
+        let mut rng: Pcg64 = match seed {
+            Some(seed) => Pcg64::seed_from_u64(seed),
+            None => Pcg64::from_entropy(),
+        };
+        let identity: Vec<usize> = (0..level.len()).collect();
+        let mut current = level.modularity(&identity, total, resolution);
+        loop {
+            let community = level.move_nodes(total, resolution, threshold, &mut rng);
+            let next = level.modularity(&community, total, resolution);
+            if next - current <= threshold {
+                break;
+            }
+            current = next;
+            for label in partition.iter_mut() {
+                *label = community[*label];
+            }
+            level = level.aggregate(&community);
+        }
+    }
+
+    let mut communities: Vec<Vec<G::NodeId>> = vec![Vec::new(); level.len()];
+    let mut nodes: Vec<G::NodeId> = graph.node_identifiers().collect();
+    nodes.sort_by_key(|node| graph.to_index(*node));
+    for node in nodes {
+        communities[partition[group[graph.to_index(node)]]].push(node);
+    }
+    communities.retain(|nodes| !nodes.is_empty());
+    communities.sort_by_key(|nodes| graph.to_index(nodes[0]));
+    Ok(Some(communities))
+}
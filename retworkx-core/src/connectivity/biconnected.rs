@@ -80,6 +80,133 @@ pub fn articulation_points<G>(
     graph: G,
     components: Option<&mut HashMap<Edge<G>, usize>>,
 ) -> HashSet<G::NodeId>
+where
+    G: GraphProp<EdgeType = Undirected>
+        + EdgeCount
+        + IntoEdges
+        + Visitable
+        + NodeIndexable
+        + IntoNodeIdentifiers,
+    G::NodeId: Eq + Hash,
+{
+    biconnected_dfs(graph, components, None)
+}
+
+/// Return the bridges of an undirected graph.
+///
+/// A bridge or cut edge is any edge whose removal increases the number of
+/// connected components of a graph. Every bridge is returned as the pair of
+/// its endpoints `(u, v)` where `u` is the endpoint visited first by the
+/// depth-first search.
+///
+/// # Note
+/// The function implicitly assumes that there are no parallel edges
+/// or self loops. It may produce incorrect/unexpected results if the
+/// input graph has self loops or parallel edges.
+///
+/// # Example:
+/// ```rust
+/// use std::iter::FromIterator;
+/// use hashbrown::HashSet;
+///
+/// use retworkx_core::connectivity::bridges;
+/// use retworkx_core::petgraph::graph::UnGraph;
+/// use retworkx_core::petgraph::graph::node_index as nx;
+///
+/// let graph = UnGraph::<(), ()>::from_edges(&[
+///    (0, 1), (0, 2), (1, 2), (1, 3),
+/// ]);
+///
+/// assert_eq!(bridges(&graph), HashSet::from_iter([(nx(1), nx(3))]));
+/// ```
+pub fn bridges<G>(graph: G) -> HashSet<Edge<G>>
+where
+    G: GraphProp<EdgeType = Undirected>
+        + EdgeCount
+        + IntoEdges
+        + Visitable
+        + NodeIndexable
+        + IntoNodeIdentifiers,
+    G::NodeId: Eq + Hash,
+{
+    let mut bridges = HashSet::new();
+    biconnected_dfs(graph, None, Some(&mut bridges));
+    bridges
+}
+
+/// The biconnectivity structure of an undirected graph, as returned by
+/// [`biconnectivity`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Biconnectivity<N: Eq + Hash> {
+    /// The articulation points (cut vertices) of the graph.
+    pub articulation_points: HashSet<N>,
+    /// The bridges (cut edges) of the graph as `(u, v)` pairs, where `u` is
+    /// the endpoint visited first by the depth-first search.
+    pub bridges: HashSet<(N, N)>,
+    /// The label of the biconnected component of every edge, keyed by the
+    /// endpoints of the edge in the direction the depth-first search
+    /// traversed it.
+    pub components: HashMap<(N, N), usize>,
+}
+
+/// Compute the articulation points, the bridges and the biconnected
+/// components of an undirected graph with a single depth-first search.
+///
+/// This is equivalent to calling [`articulation_points`] (with
+/// `components`) and [`bridges`] but only traverses the graph once. Every
+/// bridge is a biconnected component with a single edge.
+///
+/// # Note
+/// The function implicitly assumes that there are no parallel edges
+/// or self loops. It may produce incorrect/unexpected results if the
+/// input graph has self loops or parallel edges.
+///
+/// # Example:
+/// ```rust
+/// use std::iter::FromIterator;
+/// use hashbrown::HashSet;
+///
+/// use retworkx_core::connectivity::biconnectivity;
+/// use retworkx_core::petgraph::graph::UnGraph;
+/// use retworkx_core::petgraph::graph::node_index as nx;
+///
+/// // Two triangles joined by the edge (2, 3)
+/// let graph = UnGraph::<(), ()>::from_edges(&[
+///    (0, 1), (1, 2), (2, 0), (2, 3), (3, 4), (4, 5), (5, 3),
+/// ]);
+///
+/// let result = biconnectivity(&graph);
+/// assert_eq!(result.articulation_points, HashSet::from_iter([nx(2), nx(3)]));
+/// assert_eq!(result.bridges, HashSet::from_iter([(nx(2), nx(3))]));
+/// assert_eq!(result.components.len(), 7);
+/// let labels: HashSet<usize> = result.components.values().copied().collect();
+/// assert_eq!(labels.len(), 3);
+/// ```
+pub fn biconnectivity<G>(graph: G) -> Biconnectivity<G::NodeId>
+where
+    G: GraphProp<EdgeType = Undirected>
+        + EdgeCount
+        + IntoEdges
+        + Visitable
+        + NodeIndexable
+        + IntoNodeIdentifiers,
+    G::NodeId: Eq + Hash,
+{
+    let mut components = HashMap::new();
+    let mut bridges = HashSet::new();
+    let articulation_points = biconnected_dfs(graph, Some(&mut components), Some(&mut bridges));
+    Biconnectivity {
+        articulation_points,
+        bridges,
+        components,
+    }
+}
+
+fn biconnected_dfs<G>(
+    graph: G,
+    components: Option<&mut HashMap<Edge<G>, usize>>,
+    mut bridges: Option<&mut HashSet<Edge<G>>>,
+) -> HashSet<G::NodeId>
 where
     G: GraphProp<EdgeType = Undirected>
         + EdgeCount
@@ -148,6 +275,14 @@ where
                 let pu_id = graph.from_index(pu);
                 low[pu] = low[pu].min(low[u]);
 
+                // no back edge from the subtree of ``u`` reaches above it, so the
+                // tree edge ``(pu, u)`` is the only path out of that subtree.
+                if low[u] > disc[pu] {
+                    if let Some(ref mut bridges) = bridges {
+                        bridges.insert((pu_id, u_id));
+                    }
+                }
+
                 if !is_root(&parent, pu) && low[u] >= disc[pu] {
                     points.insert(pu_id);
                     // now find a biconnected component that the
@@ -0,0 +1,197 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use std::hash::Hash;
+
+use petgraph::visit::{GraphProp, IntoNeighborsDirected, IntoNodeIdentifiers, NodeIndexable};
+use petgraph::{Incoming, Outgoing};
+
+use crate::dictmap::*;
+
+/// Return the core number for each node in the graph.
+///
+/// A k-core is a maximal subgraph that contains nodes of degree k or more.
+/// The core number of a node is the largest value k of a k-core containing
+/// that node. For directed graphs, the degree is calculated as
+/// in_degree + out_degree.
+///
+/// The cores are computed with the O(m) bucket algorithm from Batagelj and
+/// Zaversnik [1].
+///
+/// # Note
+/// The function implicitly assumes that there are no parallel edges
+/// or self loops. It may produce incorrect/unexpected results if the
+/// input graph has self loops or parallel edges.
+///
+/// Arguments:
+///
+/// * `graph` - The graph to get the core numbers of
+///
+/// Returns a [`DictMap`] with the core number of every node, in the order
+/// of [`IntoNodeIdentifiers::node_identifiers`].
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::connectivity::core_number;
+///
+/// // A triangle with a tail
+/// let g = petgraph::graph::UnGraph::<(), ()>::from_edges(&[
+///     (0, 1), (1, 2), (2, 0), (2, 3)
+/// ]);
+/// let cores = core_number(&g);
+/// let output: Vec<usize> = cores.values().copied().collect();
+/// assert_eq!(output, vec![2, 2, 2, 1]);
+/// ```
+///
+/// [1] Vladimir Batagelj and Matjaz Zaversnik, "An O(m) Algorithm for Cores
+///   Decomposition of Networks", 2003. <https://arxiv.org/abs/cs/0310049>
+pub fn core_number<G>(graph: G) -> DictMap<G::NodeId, usize>
+where
+    G: IntoNeighborsDirected + IntoNodeIdentifiers + NodeIndexable + GraphProp,
+    G::NodeId: Eq + Hash,
+{
+    let nodes: Vec<G::NodeId> = graph.node_identifiers().collect();
+    let node_count = nodes.len();
+    let mut compact = vec![0; graph.node_bound()];
+    for (index, node) in nodes.iter().enumerate() {
+        compact[graph.to_index(*node)] = index;
+    }
+    // the distinct neighbors of every node, ignoring edge direction
+    let neighbors: Vec<Vec<usize>> = nodes
+        .iter()
+        .map(|node| {
+            let mut node_neighbors: Vec<usize> = if graph.is_directed() {
+                graph
+                    .neighbors_directed(*node, Outgoing)
+                    .chain(graph.neighbors_directed(*node, Incoming))
+                    .map(|n| compact[graph.to_index(n)])
+                    .collect()
+            } else {
+                graph
+                    .neighbors(*node)
+                    .map(|n| compact[graph.to_index(n)])
+                    .collect()
+            };
+            node_neighbors.sort_unstable();
+            node_neighbors.dedup();
+            node_neighbors
+        })
+        .collect();
+
+    let mut core: Vec<usize> = neighbors.iter().map(|n| n.len()).collect();
+    let max_degree = core.iter().copied().max().unwrap_or(0);
+    // sort the nodes by degree with a bucket sort, ``bin[d]`` is the position
+    // in ``vert`` of the first node with degree ``d``
+    let mut bin = vec![0; max_degree + 1];
+    for degree in &core {
+        bin[*degree] += 1;
+    }
+    let mut start = 0;
+    for count in bin.iter_mut() {
+        let num = *count;
+        *count = start;
+        start += num;
+    }
+    let mut pos = vec![0; node_count];
+    let mut vert = vec![0; node_count];
+    for v in 0..node_count {
+        pos[v] = bin[core[v]];
+        vert[pos[v]] = v;
+        bin[core[v]] += 1;
+    }
+    for degree in (1..=max_degree).rev() {
+        bin[degree] = bin[degree - 1];
+    }
+    bin[0] = 0;
+
+    // process the nodes in order of increasing current degree, moving every
+    // neighbor with a larger degree one bucket down
+    for i in 0..node_count {
+        let v = vert[i];
+        for u in &neighbors[v] {
+            let u = *u;
+            if core[u] > core[v] {
+                let degree = core[u];
+                let pu = pos[u];
+                let pw = bin[degree];
+                let w = vert[pw];
+                if u != w {
+                    pos[u] = pw;
+                    vert[pu] = w;
+                    pos[w] = pu;
+                    vert[pw] = u;
+                }
+                bin[degree] += 1;
+                core[u] -= 1;
+            }
+        }
+    }
+
+    let mut out = DictMap::with_capacity(node_count);
+    for (node, node_core) in nodes.into_iter().zip(core) {
+        out.insert(node, node_core);
+    }
+    out
+}
+
+/// Return the nodes of the k-core of a graph.
+///
+/// A k-core is a maximal subgraph that contains nodes of degree k or more,
+/// it's made up of the nodes with a core number of at least `k` as returned
+/// by [`core_number`]. For directed graphs, the degree is calculated as
+/// in_degree + out_degree.
+///
+/// # Note
+/// The function implicitly assumes that there are no parallel edges
+/// or self loops. It may produce incorrect/unexpected results if the
+/// input graph has self loops or parallel edges.
+///
+/// Arguments:
+///
+/// * `graph` - The graph to find the k-core of
+/// * `k` - The order of the core, if not specified the largest core number
+///   of any node in the graph is used (the main core).
+///
+/// Returns a [`Vec`] of the nodes in the k-core, in the order of
+/// [`IntoNodeIdentifiers::node_identifiers`].
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::petgraph::graph::node_index as nx;
+/// use retworkx_core::connectivity::k_core;
+///
+/// // A triangle with a tail
+/// let g = petgraph::graph::UnGraph::<(), ()>::from_edges(&[
+///     (0, 1), (1, 2), (2, 0), (2, 3)
+/// ]);
+/// assert_eq!(k_core(&g, None), vec![nx(0), nx(1), nx(2)]);
+/// assert_eq!(k_core(&g, Some(1)).len(), 4);
+/// assert!(k_core(&g, Some(3)).is_empty());
+/// ```
+pub fn k_core<G>(graph: G, k: Option<usize>) -> Vec<G::NodeId>
+where
+    G: IntoNeighborsDirected + IntoNodeIdentifiers + NodeIndexable + GraphProp,
+    G::NodeId: Eq + Hash,
+{
+    let cores = core_number(graph);
+    let k = match k {
+        Some(k) => k,
+        None => cores.values().copied().max().unwrap_or(0),
+    };
+    cores
+        .into_iter()
+        .filter(|(_, core)| *core >= k)
+        .map(|(node, _)| node)
+        .collect()
+}
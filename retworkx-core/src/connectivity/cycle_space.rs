@@ -0,0 +1,467 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use std::cmp::Ordering;
+
+use fixedbitset::FixedBitSet;
+use hashbrown::HashMap;
+
+use petgraph::unionfind::UnionFind;
+use petgraph::visit::{
+    EdgeIndexable, EdgeRef, GraphProp, IntoEdgeReferences, IntoNodeIdentifiers, NodeIndexable,
+};
+use petgraph::Undirected;
+
+use crate::min_scored::MinScored;
+
+const NONE: usize = usize::MAX;
+
+/// Collect edge indices into a set, an index given twice cancels out.
+fn edge_set<I: IntoIterator<Item = usize>>(edges: I) -> FixedBitSet {
+    let mut set = FixedBitSet::with_capacity(0);
+    for edge in edges {
+        if edge >= set.len() {
+            set.grow(edge + 1);
+        }
+        set.toggle(edge);
+    }
+    set
+}
+
+/// Return the sum of two elements of the cycle space of a graph, the
+/// symmetric difference of their edges.
+///
+/// The edges are given and returned as edge indices, and the returned ones
+/// are sorted. An edge given twice in the same argument cancels out.
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::connectivity::cycle_sum;
+///
+/// // Two triangles sharing the edge 1 sum to a square
+/// assert_eq!(cycle_sum(vec![0, 1, 2], vec![1, 3, 4]), vec![0, 2, 3, 4]);
+/// ```
+pub fn cycle_sum<I, J>(a: I, b: J) -> Vec<usize>
+where
+    I: IntoIterator<Item = usize>,
+    J: IntoIterator<Item = usize>,
+{
+    let mut sum = edge_set(a);
+    sum.symmetric_difference_with(&edge_set(b));
+    sum.ones().collect()
+}
+
+/// Return if a set of edges is an element of the cycle space of a graph.
+///
+/// The cycle space of an undirected graph is the vector space over GF(2) of
+/// the sets of edges in which every node has an even degree, so this checks
+/// the degrees of the nodes in `edges`. These sets are exactly the sums of
+/// cycles of the graph. A self loop adds 2 to the degree of its node.
+///
+/// Arguments:
+///
+/// * `graph` - The graph the edges are from.
+/// * `edges` - The edge indices of the set, an index given twice cancels
+///   out. Indices of edges which aren't in the graph are ignored.
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::connectivity::is_in_cycle_space;
+///
+/// // Two triangles sharing the edge (1, 2)
+/// let graph = petgraph::graph::UnGraph::<(), ()>::from_edges(&[
+///     (0, 1), (1, 2), (2, 0), (1, 3), (3, 2)
+/// ]);
+/// assert!(is_in_cycle_space(&graph, vec![0, 1, 2]));
+/// assert!(is_in_cycle_space(&graph, vec![0, 2, 3, 4]));
+/// assert!(!is_in_cycle_space(&graph, vec![0, 1]));
+/// ```
+pub fn is_in_cycle_space<G, I>(graph: G, edges: I) -> bool
+where
+    G: GraphProp<EdgeType = Undirected> + IntoEdgeReferences + NodeIndexable + EdgeIndexable,
+    I: IntoIterator<Item = usize>,
+{
+    let edges = edge_set(edges);
+    let mut odd = FixedBitSet::with_capacity(graph.node_bound());
+    for edge in graph.edge_references() {
+        if edges.contains(EdgeIndexable::to_index(&graph, edge.id())) {
+            odd.toggle(NodeIndexable::to_index(&graph, edge.source()));
+            odd.toggle(NodeIndexable::to_index(&graph, edge.target()));
+        }
+    }
+    odd.count_ones(..) == 0
+}
+
+#[derive(Clone, Debug)]
+struct Row {
+    pivot: usize,
+    edges: FixedBitSet,
+    /// the inserted cycles which sum to `edges`
+    combination: FixedBitSet,
+}
+
+/// A subspace of the cycle space of a graph, spanned by the cycles inserted
+/// into it.
+///
+/// The cycle space of an undirected graph is the vector space over GF(2) of
+/// the sets of edges in which every node has an even degree, where the sum
+/// of two sets is their symmetric difference (see [`cycle_sum`]). Sets of
+/// edges are given as edge indices, and an index given twice cancels out.
+///
+/// Only the cycles which are independent of the ones already inserted are
+/// kept, and they are reduced with Gaussian elimination as they're inserted.
+/// So with `d` cycles and edge indices less than `m`, inserting a cycle,
+/// testing if a set of edges is in the span of the cycles, or decomposing it
+/// into a sum of them takes `O(d * m / 32)` time.
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::connectivity::CycleSpace;
+///
+/// // Two triangles sharing the edge 1
+/// let mut space = CycleSpace::new();
+/// assert!(space.insert(vec![0, 1, 2]));
+/// assert!(space.insert(vec![1, 3, 4]));
+/// // The outer square is the sum of the triangles
+/// assert!(!space.insert(vec![0, 2, 3, 4]));
+/// assert_eq!(space.dimension(), 2);
+/// assert!(space.contains(vec![4, 3, 2, 0]));
+/// assert_eq!(space.decompose(vec![0, 2, 3, 4]), Some(vec![0, 1]));
+/// assert_eq!(space.decompose(vec![0, 1]), None);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct CycleSpace {
+    cycles: Vec<Vec<usize>>,
+    rows: Vec<Row>,
+}
+
+impl CycleSpace {
+    /// Create an empty `CycleSpace`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Return the dimension of the space, the number of independent cycles
+    /// inserted into it.
+    pub fn dimension(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// Return the cycles spanning the space, as sorted edge indices in the
+    /// order they were inserted.
+    pub fn cycles(&self) -> &[Vec<usize>] {
+        &self.cycles
+    }
+
+    /// Reduce a set of edges by the rows, returning what's left of it and
+    /// the inserted cycles which sum to the removed part.
+    fn reduce(&self, mut edges: FixedBitSet) -> (FixedBitSet, FixedBitSet) {
+        let mut combination = FixedBitSet::with_capacity(self.cycles.len());
+        for row in &self.rows {
+            if edges.contains(row.pivot) {
+                edges.symmetric_difference_with(&row.edges);
+                combination.symmetric_difference_with(&row.combination);
+            }
+        }
+        (edges, combination)
+    }
+
+    /// Insert a cycle into the space.
+    ///
+    /// Returns `true` if the cycle was inserted, or `false` if it's already
+    /// in the span of the inserted cycles, in which case the space isn't
+    /// changed. The edges don't need to form a cycle, any set of edges can
+    /// be inserted.
+    pub fn insert<I: IntoIterator<Item = usize>>(&mut self, edges: I) -> bool {
+        let edges = edge_set(edges);
+        let (reduced, mut combination) = self.reduce(edges.clone());
+        let pivot = match reduced.ones().next() {
+            Some(pivot) => pivot,
+            None => return false,
+        };
+        combination.grow(self.cycles.len() + 1);
+        combination.toggle(self.cycles.len());
+        self.cycles.push(edges.ones().collect());
+        self.rows.push(Row {
+            pivot,
+            edges: reduced,
+            combination,
+        });
+        true
+    }
+
+    /// Return if a set of edges is in the span of the inserted cycles.
+    pub fn contains<I: IntoIterator<Item = usize>>(&self, edges: I) -> bool {
+        self.reduce(edge_set(edges)).0.count_ones(..) == 0
+    }
+
+    /// Decompose a set of edges into a sum of the inserted cycles.
+    ///
+    /// Returns the sorted indices in [`CycleSpace::cycles`] of the cycles
+    /// which sum to `edges`, or `None` if it isn't in the span of the
+    /// inserted cycles.
+    pub fn decompose<I: IntoIterator<Item = usize>>(&self, edges: I) -> Option<Vec<usize>> {
+        let (reduced, combination) = self.reduce(edge_set(edges));
+        if reduced.count_ones(..) != 0 {
+            return None;
+        }
+        Some(combination.ones().collect())
+    }
+}
+
+/// Find a minimum weight cycle basis of an undirected graph.
+///
+/// A cycle basis is a set of cycles which is a basis of the cycle space of
+/// the graph (see [`CycleSpace`]), and a minimum weight cycle basis is one
+/// with the smallest total weight of its cycles. This uses Horton's
+/// algorithm [1]: a shortest path tree is found from every node, and the
+/// candidate cycles made of an edge and the tree paths from its endpoints to
+/// the root are inserted into a [`CycleSpace`] from the lightest one, until
+/// it has the dimension of the cycle space, `m - n + c` for a graph with `m`
+/// edges, `n` nodes and `c` connected components. The shortest path trees
+/// take `O(n^2)` memory.
+///
+/// Arguments:
+///
+/// * `graph` - The graph to find the cycle basis of.
+/// * `weight_fn` - A callable that returns the weight of an edge, which must
+///   be non-negative. If it returns an error it is returned by this
+///   function.
+///
+/// Returns the cycles of the basis in non-decreasing order of weight. Each
+/// cycle is a list of edge indices in the order of a walk around the cycle.
+/// Parallel edges form cycles of 2 edges, and a self loop is a cycle of 1
+/// edge.
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::connectivity::minimum_cycle_basis;
+/// use retworkx_core::Result;
+///
+/// // A square with a diagonal of weight 1 and sides of weight 2
+/// let graph = petgraph::graph::UnGraph::<(), f64>::from_edges(&[
+///     (0, 1, 2.), (1, 2, 2.), (2, 3, 2.), (3, 0, 2.), (0, 2, 1.)
+/// ]);
+/// let res: Result<Vec<Vec<usize>>> = minimum_cycle_basis(&graph, |e| Ok(*e.weight()));
+/// let mut basis: Vec<Vec<usize>> = res
+///     .unwrap()
+///     .into_iter()
+///     .map(|mut cycle| {
+///         cycle.sort_unstable();
+///         cycle
+///     })
+///     .collect();
+/// basis.sort();
+/// // The two triangles, and not the square
+/// assert_eq!(basis, vec![vec![0, 1, 4], vec![2, 3, 4]]);
+/// ```
+///
+/// [1] Horton, J. D. A polynomial-time algorithm to find the shortest cycle
+///   basis of a graph. SIAM Journal on Computing 16, 2 (1987), 358-366.
+pub fn minimum_cycle_basis<G, F, E>(graph: G, mut weight_fn: F) -> Result<Vec<Vec<usize>>, E>
+where
+    G: GraphProp<EdgeType = Undirected>
+        + IntoEdgeReferences
+        + IntoNodeIdentifiers
+        + NodeIndexable
+        + EdgeIndexable,
+    F: FnMut(G::EdgeRef) -> Result<f64, E>,
+{
+    let node_bound = graph.node_bound();
+    // (edge index, source, target, weight) of each edge
+    let mut edges: Vec<(usize, usize, usize, f64)> = Vec::new();
+    // (neighbor, position in `edges`) of each node
+    let mut adjacency: Vec<Vec<(usize, usize)>> = vec![Vec::new(); node_bound];
+    let mut components = UnionFind::new(node_bound);
+    let mut dimension = 0;
+    for edge in graph.edge_references() {
+        let source = NodeIndexable::to_index(&graph, edge.source());
+        let target = NodeIndexable::to_index(&graph, edge.target());
+        let weight = weight_fn(edge)?;
+        adjacency[source].push((target, edges.len()));
+        if source != target {
+            adjacency[target].push((source, edges.len()));
+        }
+        if !components.union(source, target) {
+            dimension += 1;
+        }
+        edges.push((
+            EdgeIndexable::to_index(&graph, edge.id()),
+            source,
+            target,
+            weight,
+        ));
+    }
+    if dimension == 0 {
+        return Ok(Vec::new());
+    }
+
+    // The edge to the parent of each node in the shortest path tree from
+    // each root
+    let mut parent_edges: HashMap<usize, Vec<usize>> = HashMap::new();
+    let mut candidates: Vec<(f64, usize, usize)> = Vec::new();
+    for root in graph.node_identifiers() {
+        let root = NodeIndexable::to_index(&graph, root);
+        let mut distance: Vec<Option<f64>> = vec![None; node_bound];
+        let mut parent_edge = vec![NONE; node_bound];
+        // the child of the root on the path to each node
+        let mut branch = vec![NONE; node_bound];
+        let mut done = FixedBitSet::with_capacity(node_bound);
+        let mut heap = std::collections::BinaryHeap::new();
+        distance[root] = Some(0.);
+        heap.push(MinScored(0., root));
+        while let Some(MinScored(score, node)) = heap.pop() {
+            if done.put(node) {
+                continue;
+            }
+            for &(next, position) in &adjacency[node] {
+                let next_score = score + edges[position].3;
+                let shorter = match distance[next] {
+                    Some(current) => next_score < current,
+                    None => true,
+                };
+                if !done.contains(next) && shorter {
+                    distance[next] = Some(next_score);
+                    parent_edge[next] = position;
+                    branch[next] = if node == root { next } else { branch[node] };
+                    heap.push(MinScored(next_score, next));
+                }
+            }
+        }
+        for (position, &(_, source, target, weight)) in edges.iter().enumerate() {
+            let (source_distance, target_distance) = match (distance[source], distance[target]) {
+                (Some(s), Some(t)) => (s, t),
+                _ => continue,
+            };
+            if parent_edge[source] == position || parent_edge[target] == position {
+                continue;
+            }
+            // The tree paths from the endpoints must only meet at the root
+            // for the candidate to be a cycle
+            if source != root && target != root && branch[source] == branch[target] {
+                continue;
+            }
+            candidates.push((source_distance + target_distance + weight, root, position));
+        }
+        parent_edges.insert(root, parent_edge);
+    }
+    candidates.sort_by(|a, b| {
+        a.0.partial_cmp(&b.0)
+            .unwrap_or(Ordering::Equal)
+            .then(a.1.cmp(&b.1))
+            .then(a.2.cmp(&b.2))
+    });
+
+    let mut space = CycleSpace::new();
+    let mut basis: Vec<Vec<usize>> = Vec::with_capacity(dimension);
+    for (_, root, position) in candidates {
+        let parent_edge = &parent_edges[&root];
+        let path_to_root = |mut node: usize| {
+            let mut path = Vec::new();
+            while node != root {
+                let edge = &edges[parent_edge[node]];
+                path.push(parent_edge[node]);
+                node = if edge.1 == node { edge.2 } else { edge.1 };
+            }
+            path
+        };
+        let (_, source, target, _) = edges[position];
+        let mut cycle = path_to_root(source);
+        cycle.reverse();
+        cycle.push(position);
+        cycle.extend(path_to_root(target));
+        let cycle: Vec<usize> = cycle
+            .into_iter()
+            .map(|position| edges[position].0)
+            .collect();
+        if space.insert(cycle.iter().copied()) {
+            basis.push(cycle);
+            if basis.len() == dimension {
+                break;
+            }
+        }
+    }
+    Ok(basis)
+}
+
+/// Find a cycle cover of an undirected graph from its minimum weight cycle
+/// basis.
+///
+/// A cycle cover is a set of cycles such that every edge which is on a
+/// cycle of the graph, every edge which isn't a bridge, is on one of them.
+/// Every such edge is on a cycle of a cycle basis, so this takes the cycles
+/// of the [`minimum_cycle_basis`] from the lightest one, keeping the ones
+/// with an edge which isn't covered yet, and then removes the heaviest kept
+/// cycles whose edges are all covered by other kept cycles.
+///
+/// Arguments:
+///
+/// * `graph` - The graph to find the cycle cover of.
+/// * `weight_fn` - A callable that returns the weight of an edge, which must
+///   be non-negative. If it returns an error it is returned by this
+///   function.
+///
+/// Returns the cycles of the cover in non-decreasing order of weight, each
+/// as a list of edge indices in the order of a walk around the cycle.
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::connectivity::minimum_cycle_cover;
+/// use retworkx_core::Result;
+///
+/// // Three triangles around the node 0, and a bridge (0, 5)
+/// let graph = petgraph::graph::UnGraph::<(), ()>::from_edges(&[
+///     (0, 1), (1, 2), (2, 0), (2, 3), (3, 0), (3, 4), (4, 0), (0, 5)
+/// ]);
+/// let res: Result<Vec<Vec<usize>>> = minimum_cycle_cover(&graph, |_| Ok(1.));
+/// let cover = res.unwrap();
+/// assert_eq!(cover.len(), 3);
+/// // The bridge isn't on any cycle
+/// assert!(cover.iter().all(|cycle| !cycle.contains(&7)));
+/// ```
+pub fn minimum_cycle_cover<G, F, E>(graph: G, weight_fn: F) -> Result<Vec<Vec<usize>>, E>
+where
+    G: GraphProp<EdgeType = Undirected>
+        + IntoEdgeReferences
+        + IntoNodeIdentifiers
+        + NodeIndexable
+        + EdgeIndexable,
+    F: FnMut(G::EdgeRef) -> Result<f64, E>,
+{
+    let basis = minimum_cycle_basis(graph, weight_fn)?;
+    let mut count: HashMap<usize, usize> = HashMap::new();
+    let mut keep = vec![false; basis.len()];
+    for (index, cycle) in basis.iter().enumerate() {
+        if cycle.iter().any(|edge| !count.contains_key(edge)) {
+            keep[index] = true;
+            for edge in cycle {
+                *count.entry(*edge).or_insert(0) += 1;
+            }
+        }
+    }
+    for (index, cycle) in basis.iter().enumerate().rev() {
+        if keep[index] && cycle.iter().all(|edge| count[edge] > 1) {
+            keep[index] = false;
+            for edge in cycle {
+                *count.get_mut(edge).unwrap() -= 1;
+            }
+        }
+    }
+    Ok(basis
+        .into_iter()
+        .zip(keep)
+        .filter_map(|(cycle, keep)| if keep { Some(cycle) } else { None })
+        .collect())
+}
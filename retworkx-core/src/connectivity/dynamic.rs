@@ -0,0 +1,320 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use std::collections::VecDeque;
+
+use hashbrown::{HashMap, HashSet};
+
+/// The connected components of an undirected graph whose nodes and edges
+/// are inserted and removed over time.
+///
+/// The nodes are identified by the `usize` indices they're inserted with,
+/// such as the indices of the nodes of a graph it's kept in sync with.
+/// Parallel edges and self loops are allowed, an edge is removed once per
+/// insertion.
+///
+/// A spanning forest of the graph is kept along with the component of every
+/// node. Inserting an edge between two components merges the smaller one
+/// into the larger one, so a sequence of insertions takes `O(log(n))`
+/// amortized time per node, like a union-find. Removing an edge which isn't
+/// in the forest takes constant time. Removing an edge of the forest splits
+/// its tree in two, which are searched in parallel from the endpoints of
+/// the edge until the smaller one is found, and the edges of the smaller
+/// tree are then scanned for a replacement edge reconnecting it to the other
+/// one, so it takes time proportional to the size of the smaller side. The
+/// queries of the component of a node take constant time.
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::connectivity::DynamicConnectivity;
+///
+/// let mut connectivity = DynamicConnectivity::new();
+/// for node in 0..4 {
+///     connectivity.add_node(node);
+/// }
+/// assert!(connectivity.add_edge(0, 1));
+/// assert!(connectivity.add_edge(1, 2));
+/// // 0 and 2 are already connected
+/// assert!(!connectivity.add_edge(2, 0));
+/// assert_eq!(connectivity.num_components(), 2);
+/// // The edge from 2 to 0 replaces the edge from 0 to 1 in the forest
+/// assert_eq!(connectivity.remove_edge(0, 1), Some(false));
+/// assert!(connectivity.connected(0, 1));
+/// assert_eq!(connectivity.remove_edge(1, 2), Some(true));
+/// assert!(!connectivity.connected(0, 1));
+/// assert_eq!(connectivity.num_components(), 3);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct DynamicConnectivity {
+    /// The number of edges from every node to each of its neighbors, `None`
+    /// for an index which isn't a node
+    adjacency: Vec<Option<HashMap<usize, usize>>>,
+    /// The neighbors of every node in the spanning forest
+    forest: Vec<HashSet<usize>>,
+    /// The component of every node, as an index into `components`
+    labels: Vec<usize>,
+    /// The nodes of every component, empty for an unused label
+    components: Vec<HashSet<usize>>,
+    unused_labels: Vec<usize>,
+    node_count: usize,
+    edge_count: usize,
+    num_components: usize,
+}
+
+impl DynamicConnectivity {
+    /// Create an empty structure, without nodes.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The number of nodes.
+    pub fn node_count(&self) -> usize {
+        self.node_count
+    }
+
+    /// The number of edges, including the parallel edges and self loops.
+    pub fn edge_count(&self) -> usize {
+        self.edge_count
+    }
+
+    /// The number of connected components.
+    pub fn num_components(&self) -> usize {
+        self.num_components
+    }
+
+    /// Return if `node` is a node.
+    pub fn contains_node(&self, node: usize) -> bool {
+        matches!(self.adjacency.get(node), Some(Some(_)))
+    }
+
+    /// Return the number of edges between `a` and `b`.
+    pub fn edge_multiplicity(&self, a: usize, b: usize) -> usize {
+        match self.adjacency.get(a) {
+            Some(Some(neighbors)) => neighbors.get(&b).copied().unwrap_or(0),
+            _ => 0,
+        }
+    }
+
+    /// Insert a node, in a new component.
+    ///
+    /// Returns `false` without changing anything if `node` is already a
+    /// node.
+    pub fn add_node(&mut self, node: usize) -> bool {
+        if self.contains_node(node) {
+            return false;
+        }
+        if node >= self.adjacency.len() {
+            self.adjacency.resize(node + 1, None);
+            self.forest.resize(node + 1, HashSet::new());
+            self.labels.resize(node + 1, 0);
+        }
+        self.adjacency[node] = Some(HashMap::new());
+        let label = self.new_label();
+        self.components[label].insert(node);
+        self.labels[node] = label;
+        self.node_count += 1;
+        true
+    }
+
+    /// Remove a node and all its edges.
+    ///
+    /// Returns `false` if `node` isn't a node.
+    pub fn remove_node(&mut self, node: usize) -> bool {
+        if !self.contains_node(node) {
+            return false;
+        }
+        let neighbors: Vec<(usize, usize)> = self.adjacency[node]
+            .as_ref()
+            .unwrap()
+            .iter()
+            .map(|(neighbor, count)| (*neighbor, *count))
+            .collect();
+        for (neighbor, count) in neighbors {
+            for _ in 0..count {
+                self.remove_edge(node, neighbor);
+            }
+        }
+        // The node is alone in its component now
+        let label = self.labels[node];
+        self.components[label].clear();
+        self.unused_labels.push(label);
+        self.num_components -= 1;
+        self.adjacency[node] = None;
+        self.node_count -= 1;
+        true
+    }
+
+    /// Insert an edge between the nodes `a` and `b`.
+    ///
+    /// Returns `true` if the edge connected two components, which were
+    /// merged.
+    ///
+    /// # Panics
+    ///
+    /// If `a` or `b` isn't a node.
+    pub fn add_edge(&mut self, a: usize, b: usize) -> bool {
+        assert!(
+            self.contains_node(a) && self.contains_node(b),
+            "The endpoints of the edge must be nodes"
+        );
+        *self.neighbors_mut(a).entry(b).or_insert(0) += 1;
+        if a != b {
+            *self.neighbors_mut(b).entry(a).or_insert(0) += 1;
+        }
+        self.edge_count += 1;
+        let (label_a, label_b) = (self.labels[a], self.labels[b]);
+        if label_a == label_b {
+            return false;
+        }
+        self.forest[a].insert(b);
+        self.forest[b].insert(a);
+        // Move the nodes of the smaller component to the larger one
+        let (from, to) = if self.components[label_a].len() < self.components[label_b].len() {
+            (label_a, label_b)
+        } else {
+            (label_b, label_a)
+        };
+        let moved = std::mem::take(&mut self.components[from]);
+        for node in moved.iter() {
+            self.labels[*node] = to;
+        }
+        self.components[to].extend(moved);
+        self.unused_labels.push(from);
+        self.num_components -= 1;
+        true
+    }
+
+    /// Remove an edge between the nodes `a` and `b`.
+    ///
+    /// Returns `None` if there is no edge between `a` and `b`, otherwise
+    /// `Some(true)` if removing the edge split its component in two.
+    pub fn remove_edge(&mut self, a: usize, b: usize) -> Option<bool> {
+        match self.edge_multiplicity(a, b) {
+            0 => return None,
+            1 => {
+                self.neighbors_mut(a).remove(&b);
+                if a != b {
+                    self.neighbors_mut(b).remove(&a);
+                }
+            }
+            _ => {
+                *self.neighbors_mut(a).get_mut(&b).unwrap() -= 1;
+                if a != b {
+                    *self.neighbors_mut(b).get_mut(&a).unwrap() -= 1;
+                }
+            }
+        }
+        self.edge_count -= 1;
+        // A parallel edge still connects the endpoints
+        if self.edge_multiplicity(a, b) > 0 || !self.forest[a].remove(&b) {
+            return Some(false);
+        }
+        self.forest[b].remove(&a);
+        let side = self.smaller_tree(a, b);
+        let replacement = side.iter().find_map(|node| {
+            self.adjacency[*node]
+                .as_ref()
+                .unwrap()
+                .keys()
+                .find(|neighbor| !side.contains(*neighbor))
+                .map(|neighbor| (*node, *neighbor))
+        });
+        if let Some((node, neighbor)) = replacement {
+            self.forest[node].insert(neighbor);
+            self.forest[neighbor].insert(node);
+            return Some(false);
+        }
+        let old_label = self.labels[a];
+        let label = self.new_label();
+        for node in side.iter() {
+            self.components[old_label].remove(node);
+            self.labels[*node] = label;
+        }
+        self.components[label] = side;
+        Some(true)
+    }
+
+    /// Return if the nodes `a` and `b` are in the same component.
+    ///
+    /// # Panics
+    ///
+    /// If `a` or `b` isn't a node.
+    pub fn connected(&self, a: usize, b: usize) -> bool {
+        assert!(
+            self.contains_node(a) && self.contains_node(b),
+            "The nodes must be nodes of the structure"
+        );
+        self.labels[a] == self.labels[b]
+    }
+
+    /// Return the nodes of the component of `node`, in arbitrary order.
+    ///
+    /// # Panics
+    ///
+    /// If `node` isn't a node.
+    pub fn component(&self, node: usize) -> impl Iterator<Item = usize> + '_ {
+        assert!(
+            self.contains_node(node),
+            "The node must be a node of the structure"
+        );
+        self.components[self.labels[node]].iter().copied()
+    }
+
+    /// Return the nodes of every component, in arbitrary order.
+    pub fn components(&self) -> Vec<Vec<usize>> {
+        self.components
+            .iter()
+            .filter(|component| !component.is_empty())
+            .map(|component| component.iter().copied().collect())
+            .collect()
+    }
+
+    fn neighbors_mut(&mut self, node: usize) -> &mut HashMap<usize, usize> {
+        self.adjacency[node].as_mut().unwrap()
+    }
+
+    fn new_label(&mut self) -> usize {
+        self.num_components += 1;
+        match self.unused_labels.pop() {
+            Some(label) => label,
+            None => {
+                self.components.push(HashSet::new());
+                self.components.len() - 1
+            }
+        }
+    }
+
+    /// Return the nodes of the smaller of the trees of the forest containing
+    /// `a` and `b`, which must be different trees, searching both in
+    /// parallel so it only takes time proportional to the smaller one.
+    fn smaller_tree(&self, a: usize, b: usize) -> HashSet<usize> {
+        let search = |start: usize| {
+            let mut visited = HashSet::new();
+            visited.insert(start);
+            (VecDeque::from(vec![start]), visited)
+        };
+        let mut searches = [search(a), search(b)];
+        loop {
+            for (queue, visited) in searches.iter_mut() {
+                let node = match queue.pop_front() {
+                    Some(node) => node,
+                    None => return std::mem::take(visited),
+                };
+                for neighbor in self.forest[node].iter() {
+                    if visited.insert(*neighbor) {
+                        queue.push_back(*neighbor);
+                    }
+                }
+            }
+        }
+    }
+}
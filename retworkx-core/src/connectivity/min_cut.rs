@@ -0,0 +1,577 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use std::collections::VecDeque;
+use std::hash::Hash;
+
+use hashbrown::HashSet;
+
+use petgraph::visit::{
+    EdgeRef, GraphProp, IntoEdgeReferences, IntoNeighborsDirected, IntoNodeIdentifiers,
+    NodeIndexable,
+};
+use petgraph::{Incoming, Outgoing};
+
+/// A flow network with integer capacities, every arc is stored next to its
+/// reverse arc so the reverse of arc `a` is `a ^ 1`.
+#[derive(Clone)]
+pub(crate) struct FlowNetwork {
+    adjacency: Vec<Vec<usize>>,
+    heads: Vec<usize>,
+    capacities: Vec<usize>,
+}
+
+impl FlowNetwork {
+    pub(crate) fn new(node_count: usize) -> Self {
+        FlowNetwork {
+            adjacency: vec![Vec::new(); node_count],
+            heads: Vec::new(),
+            capacities: Vec::new(),
+        }
+    }
+
+    /// Add an arc from `u` to `v`, with a reverse arc of capacity `reverse`.
+    pub(crate) fn add_arc(&mut self, u: usize, v: usize, capacity: usize, reverse: usize) {
+        self.adjacency[u].push(self.heads.len());
+        self.heads.push(v);
+        self.capacities.push(capacity);
+        self.adjacency[v].push(self.heads.len());
+        self.heads.push(u);
+        self.capacities.push(reverse);
+    }
+
+    /// Compute the maximum flow from `source` to `sink` with shortest
+    /// augmenting paths, leaving the residual capacities in the network.
+    pub(crate) fn max_flow(&mut self, source: usize, sink: usize) -> usize {
+        let mut flow = 0;
+        loop {
+            let mut pred_arc: Vec<Option<usize>> = vec![None; self.adjacency.len()];
+            let mut queue = VecDeque::from(vec![source]);
+            let mut found = false;
+            while let Some(u) = queue.pop_front() {
+                for arc in &self.adjacency[u] {
+                    let v = self.heads[*arc];
+                    if self.capacities[*arc] > 0 && v != source && pred_arc[v].is_none() {
+                        pred_arc[v] = Some(*arc);
+                        if v == sink {
+                            found = true;
+                            break;
+                        }
+                        queue.push_back(v);
+                    }
+                }
+                if found {
+                    break;
+                }
+            }
+            if !found {
+                return flow;
+            }
+            let mut bottleneck = usize::MAX;
+            let mut v = sink;
+            while let Some(arc) = pred_arc[v] {
+                bottleneck = bottleneck.min(self.capacities[arc]);
+                v = self.heads[arc ^ 1];
+            }
+            let mut v = sink;
+            while let Some(arc) = pred_arc[v] {
+                self.capacities[arc] -= bottleneck;
+                self.capacities[arc ^ 1] += bottleneck;
+                v = self.heads[arc ^ 1];
+            }
+            flow += bottleneck;
+        }
+    }
+
+    /// Return which nodes are reachable from `source` in the residual network.
+    pub(crate) fn residual_reachable(&self, source: usize) -> Vec<bool> {
+        let mut seen = vec![false; self.adjacency.len()];
+        seen[source] = true;
+        let mut stack = vec![source];
+        while let Some(u) = stack.pop() {
+            for arc in &self.adjacency[u] {
+                let v = self.heads[*arc];
+                if self.capacities[*arc] > 0 && !seen[v] {
+                    seen[v] = true;
+                    stack.push(v);
+                }
+            }
+        }
+        seen
+    }
+}
+
+/// Maps the nodes of a graph to contiguous indices.
+struct Compact<G: NodeIndexable> {
+    nodes: Vec<G::NodeId>,
+    index: Vec<usize>,
+}
+
+impl<G> Compact<G>
+where
+    G: NodeIndexable + IntoNodeIdentifiers,
+{
+    fn new(graph: G) -> Self {
+        let nodes: Vec<G::NodeId> = graph.node_identifiers().collect();
+        let mut index = vec![0; graph.node_bound()];
+        for (i, node) in nodes.iter().enumerate() {
+            index[graph.to_index(*node)] = i;
+        }
+        Compact { nodes, index }
+    }
+}
+
+/// Compute a minimum cut between `source` and `target` counting edges, as
+/// the number of edges in the cut and the edges themselves (if `want_cut`).
+fn st_edge_cut<G>(
+    graph: G,
+    compact: &Compact<G>,
+    source: usize,
+    target: usize,
+    want_cut: bool,
+) -> (usize, Vec<G::EdgeRef>)
+where
+    G: IntoEdgeReferences + NodeIndexable + GraphProp,
+{
+    let mut network = FlowNetwork::new(compact.nodes.len());
+    for edge in graph.edge_references() {
+        let u = compact.index[graph.to_index(edge.source())];
+        let v = compact.index[graph.to_index(edge.target())];
+        if u != v {
+            let reverse = if graph.is_directed() { 0 } else { 1 };
+            network.add_arc(u, v, 1, reverse);
+        }
+    }
+    let flow = network.max_flow(source, target);
+    if !want_cut {
+        return (flow, Vec::new());
+    }
+    let side = network.residual_reachable(source);
+    let cut = graph
+        .edge_references()
+        .filter(|edge| {
+            let u = compact.index[graph.to_index(edge.source())];
+            let v = compact.index[graph.to_index(edge.target())];
+            if graph.is_directed() {
+                side[u] && !side[v]
+            } else {
+                side[u] != side[v]
+            }
+        })
+        .collect();
+    (flow, cut)
+}
+
+/// Compute a minimum cut between the non-adjacent nodes `source` and
+/// `target` counting nodes, as the number of nodes in the cut and the nodes
+/// themselves (if `want_cut`). The edges from `source` to `target` are
+/// ignored, the number of them is returned as the third element.
+fn st_node_cut<G>(
+    graph: G,
+    compact: &Compact<G>,
+    source: usize,
+    target: usize,
+    want_cut: bool,
+) -> (usize, Vec<G::NodeId>, usize)
+where
+    G: IntoEdgeReferences + NodeIndexable + GraphProp,
+{
+    // every node ``i`` is split into ``2 i`` (in) and ``2 i + 1`` (out)
+    // joined by an arc of capacity 1, the arcs for the edges can't be cut
+    let node_count = compact.nodes.len();
+    let infinite = node_count + 1;
+    let mut network = FlowNetwork::new(2 * node_count);
+    for i in 0..node_count {
+        network.add_arc(2 * i, 2 * i + 1, 1, 0);
+    }
+    let mut direct = 0;
+    for edge in graph.edge_references() {
+        let u = compact.index[graph.to_index(edge.source())];
+        let v = compact.index[graph.to_index(edge.target())];
+        if u == v {
+            continue;
+        }
+        let forward = (u, v) == (source, target);
+        let backward = (v, u) == (source, target);
+        if forward || (!graph.is_directed() && backward) {
+            direct += 1;
+            continue;
+        }
+        network.add_arc(2 * u + 1, 2 * v, infinite, 0);
+        if !graph.is_directed() {
+            network.add_arc(2 * v + 1, 2 * u, infinite, 0);
+        }
+    }
+    let flow = network.max_flow(2 * source + 1, 2 * target);
+    if !want_cut {
+        return (flow, Vec::new(), direct);
+    }
+    let side = network.residual_reachable(2 * source + 1);
+    let cut = (0..node_count)
+        .filter(|i| side[2 * i] && !side[2 * i + 1])
+        .map(|i| compact.nodes[i])
+        .collect();
+    (flow, cut, direct)
+}
+
+fn edge_cut<G>(
+    graph: G,
+    source_target: Option<(G::NodeId, G::NodeId)>,
+    want_cut: bool,
+) -> (usize, Vec<G::EdgeRef>)
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable + GraphProp,
+{
+    let compact = Compact::new(graph);
+    if let Some((source, target)) = source_target {
+        let source = compact.index[graph.to_index(source)];
+        let target = compact.index[graph.to_index(target)];
+        assert!(source != target, "source and target must be different");
+        return st_edge_cut(graph, &compact, source, target, want_cut);
+    }
+    let node_count = compact.nodes.len();
+    if node_count < 2 {
+        return (0, Vec::new());
+    }
+    // start from the edges around a node with the minimum degree
+    let mut degree = vec![0; node_count];
+    for edge in graph.edge_references() {
+        let u = compact.index[graph.to_index(edge.source())];
+        let v = compact.index[graph.to_index(edge.target())];
+        if u != v {
+            degree[u] += 1;
+            degree[v] += 1;
+        }
+    }
+    let mut best: Option<(usize, Vec<G::EdgeRef>)> = None;
+    if !graph.is_directed() {
+        let min_node = (0..node_count).min_by_key(|i| degree[*i]).unwrap();
+        let cut: Vec<G::EdgeRef> = graph
+            .edge_references()
+            .filter(|edge| {
+                let u = compact.index[graph.to_index(edge.source())];
+                let v = compact.index[graph.to_index(edge.target())];
+                u != v && (u == min_node || v == min_node)
+            })
+            .collect();
+        best = Some((degree[min_node], cut));
+    }
+    // the minimum cut of an undirected graph separates the first node from
+    // some other node, the one of a directed graph separates some pair of
+    // consecutive nodes in a cyclic order of the nodes
+    let pairs: Vec<(usize, usize)> = if graph.is_directed() {
+        (0..node_count).map(|i| (i, (i + 1) % node_count)).collect()
+    } else {
+        (1..node_count).map(|i| (0, i)).collect()
+    };
+    for (source, target) in pairs {
+        let (flow, cut) = st_edge_cut(graph, &compact, source, target, want_cut);
+        if best.is_none() || flow < best.as_ref().unwrap().0 {
+            best = Some((flow, cut));
+        }
+    }
+    best.unwrap()
+}
+
+fn node_cut<G>(
+    graph: G,
+    source_target: Option<(G::NodeId, G::NodeId)>,
+    want_cut: bool,
+) -> (usize, Option<Vec<G::NodeId>>)
+where
+    G: IntoEdgeReferences + IntoNeighborsDirected + IntoNodeIdentifiers + NodeIndexable + GraphProp,
+    G::NodeId: Eq + Hash,
+{
+    let compact = Compact::new(graph);
+    if let Some((source, target)) = source_target {
+        let source = compact.index[graph.to_index(source)];
+        let target = compact.index[graph.to_index(target)];
+        assert!(source != target, "source and target must be different");
+        let (flow, cut, direct) = st_node_cut(graph, &compact, source, target, want_cut);
+        // no set of nodes separates adjacent nodes
+        let cut = if direct > 0 { None } else { Some(cut) };
+        return (flow + direct, cut);
+    }
+    let node_count = compact.nodes.len();
+    if node_count < 2 {
+        return (0, Some(Vec::new()));
+    }
+    let neighbors = |node: G::NodeId, direction| -> HashSet<usize> {
+        graph
+            .neighbors_directed(node, direction)
+            .map(|n| compact.index[graph.to_index(n)])
+            .filter(|n| *n != compact.index[graph.to_index(node)])
+            .collect()
+    };
+    let successors: Vec<HashSet<usize>> = compact
+        .nodes
+        .iter()
+        .map(|node| neighbors(*node, Outgoing))
+        .collect();
+    let predecessors: Vec<HashSet<usize>> = if graph.is_directed() {
+        compact
+            .nodes
+            .iter()
+            .map(|node| neighbors(*node, Incoming))
+            .collect()
+    } else {
+        successors.clone()
+    };
+
+    // Even's algorithm: start from the neighbors of a node ``v`` with the
+    // minimum degree, then a smaller cut either separates ``v`` from one of
+    // its non-neighbors or two non-adjacent neighbors of ``v``
+    let smaller = |i: usize| -> &HashSet<usize> {
+        if successors[i].len() <= predecessors[i].len() {
+            &successors[i]
+        } else {
+            &predecessors[i]
+        }
+    };
+    let v = (0..node_count).min_by_key(|i| smaller(*i).len()).unwrap();
+    let mut best_flow = smaller(v).len();
+    let mut best_cut: Vec<usize> = smaller(v).iter().copied().collect();
+    best_cut.sort_unstable();
+    let mut best_cut: Vec<G::NodeId> = best_cut.into_iter().map(|i| compact.nodes[i]).collect();
+
+    let mut pairs: Vec<(usize, usize)> = Vec::new();
+    let v_neighbors: Vec<usize> = {
+        let mut all: Vec<usize> = successors[v].union(&predecessors[v]).copied().collect();
+        all.sort_unstable();
+        all
+    };
+    for w in 0..node_count {
+        if w != v && !successors[v].contains(&w) && !predecessors[v].contains(&w) {
+            pairs.push((v, w));
+            if graph.is_directed() {
+                pairs.push((w, v));
+            }
+        }
+    }
+    for x in &v_neighbors {
+        for y in &v_neighbors {
+            let ordered = graph.is_directed() || x < y;
+            if x != y && ordered && !successors[*x].contains(y) {
+                pairs.push((*x, *y));
+            }
+        }
+    }
+    for (source, target) in pairs {
+        let (flow, cut, _) = st_node_cut(graph, &compact, source, target, want_cut);
+        if flow < best_flow {
+            best_flow = flow;
+            best_cut = cut;
+        }
+    }
+    (best_flow, Some(best_cut))
+}
+
+/// Compute the edge connectivity of a graph.
+///
+/// The edge connectivity of two nodes `s` and `t` is the minimum number of
+/// edges that need to be removed to disconnect `t` from `s`, which by
+/// Menger's theorem is equal to the maximum number of edge disjoint paths
+/// from `s` to `t`. The edge connectivity of a graph is the minimum edge
+/// connectivity of all the pairs of nodes, for directed graphs the paths
+/// follow the direction of the edges.
+///
+/// The local edge connectivity is computed as a maximum flow where every
+/// edge has a capacity of 1. The global edge connectivity of an undirected
+/// graph is found with `n - 1` maximum flow computations from a fixed node,
+/// and the one of a directed graph with `n` computations between consecutive
+/// nodes in a cyclic order [1].
+///
+/// Arguments:
+///
+/// * `graph` - The graph to compute the edge connectivity of
+/// * `source_target` - An optional pair of nodes `(s, t)` to compute the
+///   local edge connectivity of, if not specified the global edge
+///   connectivity of the graph is computed.
+///
+/// Self loops are ignored and parallel edges are counted separately. A graph
+/// with fewer than 2 nodes has an edge connectivity of 0.
+///
+/// # Panics
+///
+/// If `s` and `t` are the same node.
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::petgraph::graph::node_index as nx;
+/// use retworkx_core::connectivity::edge_connectivity;
+///
+/// // Two squares that share the node 3
+/// let g = petgraph::graph::UnGraph::<(), ()>::from_edges(&[
+///     (0, 1), (1, 2), (2, 3), (3, 0), (3, 4), (4, 5), (5, 6), (6, 3)
+/// ]);
+/// assert_eq!(edge_connectivity(&g, None), 2);
+/// assert_eq!(edge_connectivity(&g, Some((nx(3), nx(6)))), 2);
+/// ```
+///
+/// [1] Abdol-Hossein Esfahanian, "Connectivity Algorithms".
+///   <http://www.cse.msu.edu/~cse835/Papers/Graph_connectivity_revised.pdf>
+pub fn edge_connectivity<G>(graph: G, source_target: Option<(G::NodeId, G::NodeId)>) -> usize
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable + GraphProp,
+{
+    edge_cut(graph, source_target, false).0
+}
+
+/// Find a minimum set of edges that disconnects a graph.
+///
+/// With `source_target` set to `(s, t)` the edges returned disconnect `t`
+/// from `s`, otherwise they disconnect some pair of nodes in the graph (for
+/// directed graphs, the graph is no longer strongly connected without
+/// them). The number of edges in the cut is the edge connectivity as
+/// returned by [`edge_connectivity`].
+///
+/// Arguments:
+///
+/// * `graph` - The graph to find a minimum edge cut of
+/// * `source_target` - An optional pair of nodes `(s, t)` to separate
+///
+/// Returns a [`Vec`] of the edges in the cut. If the graph is already
+/// disconnected (or has fewer than 2 nodes) the cut is empty.
+///
+/// # Panics
+///
+/// If `s` and `t` are the same node.
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::petgraph::visit::EdgeRef;
+/// use retworkx_core::connectivity::minimum_edge_cut;
+///
+/// // Two triangles joined by the edge (2, 3)
+/// let g = petgraph::graph::UnGraph::<(), ()>::from_edges(&[
+///     (0, 1), (1, 2), (2, 0), (2, 3), (3, 4), (4, 5), (5, 3)
+/// ]);
+/// let cut: Vec<(usize, usize)> = minimum_edge_cut(&g, None)
+///     .into_iter()
+///     .map(|e| (e.source().index(), e.target().index()))
+///     .collect();
+/// assert_eq!(cut, vec![(2, 3)]);
+/// ```
+pub fn minimum_edge_cut<G>(
+    graph: G,
+    source_target: Option<(G::NodeId, G::NodeId)>,
+) -> Vec<G::EdgeRef>
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable + GraphProp,
+{
+    edge_cut(graph, source_target, true).1
+}
+
+/// Compute the node connectivity of a graph.
+///
+/// The node connectivity of two non-adjacent nodes `s` and `t` is the
+/// minimum number of nodes (other than `s` and `t`) that need to be removed
+/// to disconnect `t` from `s`, which by Menger's theorem is equal to the
+/// maximum number of internally node disjoint paths from `s` to `t`. For
+/// adjacent nodes it's the maximum number of internally node disjoint paths,
+/// counting the edges between them as one path. The node connectivity of a
+/// graph is the minimum node connectivity of all the pairs of nodes, for
+/// directed graphs the paths follow the direction of the edges.
+///
+/// The local node connectivity is computed as a maximum flow in a network
+/// where every node is split in two nodes joined by an arc with a capacity
+/// of 1. The global node connectivity is found with Even's algorithm [1],
+/// which only computes the local node connectivity of the pairs of a node
+/// with the minimum degree and its non-neighbors and of the pairs of its
+/// non-adjacent neighbors.
+///
+/// Arguments:
+///
+/// * `graph` - The graph to compute the node connectivity of
+/// * `source_target` - An optional pair of nodes `(s, t)` to compute the
+///   local node connectivity of, if not specified the global node
+///   connectivity of the graph is computed.
+///
+/// Self loops are ignored. A complete graph with `n` nodes has a node
+/// connectivity of `n - 1` and a graph with fewer than 2 nodes has a node
+/// connectivity of 0.
+///
+/// # Panics
+///
+/// If `s` and `t` are the same node.
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::petgraph::graph::node_index as nx;
+/// use retworkx_core::connectivity::node_connectivity;
+///
+/// // Two squares that share the node 3
+/// let g = petgraph::graph::UnGraph::<(), ()>::from_edges(&[
+///     (0, 1), (1, 2), (2, 3), (3, 0), (3, 4), (4, 5), (5, 6), (6, 3)
+/// ]);
+/// assert_eq!(node_connectivity(&g, None), 1);
+/// assert_eq!(node_connectivity(&g, Some((nx(0), nx(2)))), 2);
+/// ```
+///
+/// [1] Abdol-Hossein Esfahanian, "Connectivity Algorithms".
+///   <http://www.cse.msu.edu/~cse835/Papers/Graph_connectivity_revised.pdf>
+pub fn node_connectivity<G>(graph: G, source_target: Option<(G::NodeId, G::NodeId)>) -> usize
+where
+    G: IntoEdgeReferences + IntoNeighborsDirected + IntoNodeIdentifiers + NodeIndexable + GraphProp,
+    G::NodeId: Eq + Hash,
+{
+    node_cut(graph, source_target, false).0
+}
+
+/// Find a minimum set of nodes that disconnects a graph.
+///
+/// With `source_target` set to `(s, t)` the nodes returned disconnect `t`
+/// from `s`, otherwise they disconnect some pair of the remaining nodes (for
+/// directed graphs, the graph is no longer strongly connected without
+/// them). The number of nodes in the cut is the node connectivity as
+/// returned by [`node_connectivity`]. A complete graph has no node cut, in
+/// that case the neighbors of a node with the minimum degree are returned.
+///
+/// Arguments:
+///
+/// * `graph` - The graph to find a minimum node cut of
+/// * `source_target` - An optional pair of nodes `(s, t)` to separate
+///
+/// Returns a [`Vec`] of the nodes in the cut, or [`None`] if `s` and `t`
+/// are adjacent and no set of nodes separates them. If the graph is already
+/// disconnected (or has fewer than 2 nodes) the cut is empty.
+///
+/// # Panics
+///
+/// If `s` and `t` are the same node.
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::petgraph::graph::node_index as nx;
+/// use retworkx_core::connectivity::minimum_node_cut;
+///
+/// // Two squares that share the node 3
+/// let g = petgraph::graph::UnGraph::<(), ()>::from_edges(&[
+///     (0, 1), (1, 2), (2, 3), (3, 0), (3, 4), (4, 5), (5, 6), (6, 3)
+/// ]);
+/// assert_eq!(minimum_node_cut(&g, None), Some(vec![nx(3)]));
+/// assert_eq!(minimum_node_cut(&g, Some((nx(0), nx(1)))), None);
+/// ```
+pub fn minimum_node_cut<G>(
+    graph: G,
+    source_target: Option<(G::NodeId, G::NodeId)>,
+) -> Option<Vec<G::NodeId>>
+where
+    G: IntoEdgeReferences + IntoNeighborsDirected + IntoNodeIdentifiers + NodeIndexable + GraphProp,
+    G::NodeId: Eq + Hash,
+{
+    node_cut(graph, source_target, true).1
+}
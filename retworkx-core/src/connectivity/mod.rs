@@ -14,6 +14,17 @@
 
 mod biconnected;
 mod chain;
+mod core_number;
+mod cycle_space;
+mod dynamic;
+mod min_cut;
 
-pub use biconnected::articulation_points;
+pub use biconnected::{articulation_points, biconnectivity, bridges, Biconnectivity};
 pub use chain::chain_decomposition;
+pub use core_number::{core_number, k_core};
+pub use cycle_space::{
+    cycle_sum, is_in_cycle_space, minimum_cycle_basis, minimum_cycle_cover, CycleSpace,
+};
+pub use dynamic::DynamicConnectivity;
+pub(crate) use min_cut::FlowNetwork;
+pub use min_cut::{edge_connectivity, minimum_edge_cut, minimum_node_cut, node_connectivity};
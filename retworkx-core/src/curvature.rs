@@ -0,0 +1,308 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use std::collections::BinaryHeap;
+
+use hashbrown::HashMap;
+
+use petgraph::visit::{
+    EdgeIndexable, EdgeRef, GraphProp, IntoEdgeReferences, IntoEdgesDirected, NodeIndexable,
+};
+use petgraph::{Incoming, Outgoing};
+
+use crate::dictmap::*;
+use crate::min_scored::MinScored;
+
+/// Compute the Forman-Ricci curvature of every edge of a graph.
+///
+/// The Forman-Ricci curvature is a combinatorial notion of curvature that
+/// only depends on the edges adjacent to an edge [1]. With every node having
+/// a weight of 1 the curvature of an edge `e = (u, v)` with weight `w(e)` is:
+///
+/// ```text
+/// F(e) = 2 - sum(sqrt(w(e) / w(e_u)) for e_u adjacent to u)
+///          - sum(sqrt(w(e) / w(e_v)) for e_v adjacent to v)
+/// ```
+///
+/// where the sums are over the other edges incident to `u` and `v`, so for an
+/// unweighted graph it's `4 - deg(u) - deg(v)`. For directed graphs only the
+/// incoming edges of `u` and the outgoing edges of `v` are considered [2].
+/// Negatively curved edges act as bridges between parts of the graph while
+/// positively curved edges are within tightly connected parts of the graph.
+///
+/// Arguments:
+///
+/// * `graph` - The graph to compute the curvature of
+/// * `weight_fn` - A callable that receives an edge and returns its weight,
+///   which should be positive.
+///
+/// Returns a [`Vec`] indexed by edge index with the curvature of every edge.
+/// Self loops and removed edge indices are set to `None`.
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::curvature::forman_curvature;
+/// use retworkx_core::Result;
+///
+/// let g = petgraph::graph::UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (1, 3)]);
+/// let output: Result<Vec<Option<f64>>> = forman_curvature(&g, |_| Ok(1.));
+/// assert_eq!(output.unwrap(), vec![Some(0.), Some(0.), Some(0.)]);
+/// ```
+///
+/// [1] Sreejith, R. P., Karthikeyan Mohanraj, Jürgen Jost, Emil Saucan, and
+///   Areejit Samal. "Forman curvature for complex networks." Journal of
+///   Statistical Mechanics: Theory and Experiment 2016, no. 6 (2016): 063206.
+/// [2] Sreejith, R. P., Jürgen Jost, Emil Saucan, and Areejit Samal. "Forman
+///   curvature for directed networks." arXiv:1605.04662 (2016).
+pub fn forman_curvature<G, F, E>(graph: G, mut weight_fn: F) -> Result<Vec<Option<f64>>, E>
+where
+    G: IntoEdgesDirected + NodeIndexable + EdgeIndexable + GraphProp,
+    F: FnMut(G::EdgeRef) -> Result<f64, E>,
+{
+    let mut weights: Vec<f64> = vec![0.0; graph.edge_bound()];
+    for edge in graph.edge_references() {
+        weights[EdgeIndexable::to_index(&graph, edge.id())] = weight_fn(edge)?;
+    }
+    let mut out: Vec<Option<f64>> = vec![None; graph.edge_bound()];
+    for edge in graph.edge_references() {
+        if edge.source() == edge.target() {
+            continue;
+        }
+        let index = EdgeIndexable::to_index(&graph, edge.id());
+        let weight = weights[index];
+        let adjacent = |other: G::EdgeRef| -> f64 {
+            if other.id() == edge.id() || other.source() == other.target() {
+                0.0
+            } else {
+                (weight / weights[EdgeIndexable::to_index(&graph, other.id())]).sqrt()
+            }
+        };
+        let (source_edges, target_edges): (f64, f64) = if graph.is_directed() {
+            (
+                graph
+                    .edges_directed(edge.source(), Incoming)
+                    .map(adjacent)
+                    .sum(),
+                graph
+                    .edges_directed(edge.target(), Outgoing)
+                    .map(adjacent)
+                    .sum(),
+            )
+        } else {
+            (
+                graph.edges(edge.source()).map(adjacent).sum(),
+                graph.edges(edge.target()).map(adjacent).sum(),
+            )
+        };
+        out[index] = Some(2.0 - source_edges - target_edges);
+    }
+    Ok(out)
+}
+
+/// Compute `log(sum(exp(x) for x in values))` without overflowing.
+fn log_sum_exp<I: Iterator<Item = f64> + Clone>(values: I) -> f64 {
+    let max = values.clone().fold(f64::NEG_INFINITY, f64::max);
+    if max == f64::NEG_INFINITY {
+        return max;
+    }
+    max + values.map(|x| (x - max).exp()).sum::<f64>().ln()
+}
+
+/// Approximate the Wasserstein distance between the distributions `a` and
+/// `b` for the given cost matrix with the Sinkhorn algorithm. The iterations
+/// are computed in the log domain so that small `reg` values don't underflow.
+fn sinkhorn_distance(
+    a: &[f64],
+    b: &[f64],
+    cost: &[Vec<f64>],
+    reg: f64,
+    max_iter: usize,
+    tol: f64,
+) -> f64 {
+    let mut f = vec![0.0; a.len()];
+    let mut g = vec![0.0; b.len()];
+    let plan = |f: &[f64], g: &[f64], i: usize, j: usize| ((f[i] + g[j] - cost[i][j]) / reg).exp();
+    for _ in 0..max_iter {
+        for i in 0..a.len() {
+            f[i] = reg * (a[i].ln() - log_sum_exp((0..b.len()).map(|j| (g[j] - cost[i][j]) / reg)));
+        }
+        for j in 0..b.len() {
+            g[j] = reg * (b[j].ln() - log_sum_exp((0..a.len()).map(|i| (f[i] - cost[i][j]) / reg)));
+        }
+        // the column sums of the plan match b after updating g
+        let err: f64 = (0..a.len())
+            .map(|i| ((0..b.len()).map(|j| plan(&f, &g, i, j)).sum::<f64>() - a[i]).abs())
+            .sum();
+        if err < tol {
+            break;
+        }
+    }
+    (0..a.len())
+        .flat_map(|i| (0..b.len()).map(move |j| (i, j)))
+        .map(|(i, j)| plan(&f, &g, i, j) * cost[i][j])
+        .sum()
+}
+
+/// The shortest path distances from `start` to every node of `adjacency`.
+fn distances(adjacency: &[DictMap<usize, f64>], start: usize) -> Vec<f64> {
+    let mut dist = vec![f64::INFINITY; adjacency.len()];
+    let mut heap = BinaryHeap::new();
+    dist[start] = 0.0;
+    heap.push(MinScored(0.0, start));
+    while let Some(MinScored(score, node)) = heap.pop() {
+        if score > dist[node] {
+            continue;
+        }
+        for (next, weight) in &adjacency[node] {
+            let next_score = score + weight;
+            if next_score < dist[*next] {
+                dist[*next] = next_score;
+                heap.push(MinScored(next_score, *next));
+            }
+        }
+    }
+    dist
+}
+
+/// Compute the Ollivier-Ricci curvature of every edge of a graph.
+///
+/// The Ollivier-Ricci curvature of an edge `(u, v)` compares the distance
+/// between `u` and `v` with the distance between their neighborhoods [1]:
+///
+/// ```text
+/// k(u, v) = 1 - W(m_u, m_v) / d(u, v)
+/// ```
+///
+/// where `d` is the shortest path distance, `W` is the Wasserstein (earth
+/// mover's) distance and `m_x` is the probability distribution that puts a
+/// mass of `alpha` on `x` and spreads the remaining mass uniformly over the
+/// neighbors of `x` [2]. The Wasserstein distance is approximated with the
+/// Sinkhorn algorithm for entropy regularized optimal transport [3], which
+/// converges to the exact distance as `reg` goes to 0. Edge weights are used
+/// as edge lengths and the direction of the edges of directed graphs is
+/// ignored.
+///
+/// The shortest path distances from every node in the neighborhood of an
+/// edge are needed so the memory usage can grow up to quadratically with the
+/// number of nodes.
+///
+/// Arguments:
+///
+/// * `graph` - The graph to compute the curvature of
+/// * `weight_fn` - A callable that receives an edge and returns its length,
+///   which should be non-negative. For parallel edges the shortest is used.
+/// * `alpha` - The probability mass of the distributions kept on the node
+///   itself, it must be between 0 and 1.
+/// * `reg` - The entropic regularization of the Sinkhorn algorithm, smaller
+///   values give a better approximation but require more iterations.
+/// * `max_iter` - The maximum number of Sinkhorn iterations for every edge
+/// * `tol` - The Sinkhorn iterations for an edge stop once the marginals of
+///   the transport plan differ from the distributions by less than `tol`.
+///
+/// Returns a [`Vec`] indexed by edge index with the curvature of every edge.
+/// Self loops, edges with a length of 0 and removed edge indices are set to
+/// `None`.
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::curvature::ollivier_ricci_curvature;
+/// use retworkx_core::Result;
+///
+/// // A complete graph with 4 nodes
+/// let g = petgraph::graph::UnGraph::<(), ()>::from_edges(&[
+///     (0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3)
+/// ]);
+/// let output: Result<Vec<Option<f64>>> =
+///     ollivier_ricci_curvature(&g, |_| Ok(1.), 0., 0.01, 1000, 1e-9);
+/// for curvature in output.unwrap() {
+///     assert!((curvature.unwrap() - 2. / 3.).abs() < 1e-3);
+/// }
+/// ```
+///
+/// [1] Ollivier, Yann. "Ricci curvature of Markov chains on metric spaces."
+///   Journal of Functional Analysis 256, no. 3 (2009): 810-864.
+/// [2] Lin, Yong, Linyuan Lu, and Shing-Tung Yau. "Ricci curvature of graphs."
+///   Tohoku Mathematical Journal 63, no. 4 (2011): 605-627.
+/// [3] Cuturi, Marco. "Sinkhorn distances: Lightspeed computation of optimal
+///   transport." Advances in Neural Information Processing Systems 26 (2013).
+pub fn ollivier_ricci_curvature<G, F, E>(
+    graph: G,
+    mut weight_fn: F,
+    alpha: f64,
+    reg: f64,
+    max_iter: usize,
+    tol: f64,
+) -> Result<Vec<Option<f64>>, E>
+where
+    G: IntoEdgeReferences + NodeIndexable + EdgeIndexable,
+    F: FnMut(G::EdgeRef) -> Result<f64, E>,
+{
+    // the neighbors of every node with the length of the shortest edge to them
+    let mut adjacency: Vec<DictMap<usize, f64>> = vec![DictMap::new(); graph.node_bound()];
+    for edge in graph.edge_references() {
+        let weight = weight_fn(edge)?;
+        let source = NodeIndexable::to_index(&graph, edge.source());
+        let target = NodeIndexable::to_index(&graph, edge.target());
+        if source == target {
+            continue;
+        }
+        for &(a, b) in &[(source, target), (target, source)] {
+            let length = adjacency[a].entry(b).or_insert(weight);
+            *length = length.min(weight);
+        }
+    }
+    // the distributions of every node as (node, probability mass) pairs
+    let distribution = |node: usize| -> Vec<(usize, f64)> {
+        let neighbors = &adjacency[node];
+        let mut out = Vec::with_capacity(neighbors.len() + 1);
+        if alpha > 0.0 {
+            out.push((node, alpha));
+        }
+        if alpha < 1.0 {
+            let mass = (1.0 - alpha) / neighbors.len() as f64;
+            out.extend(neighbors.keys().map(|neighbor| (*neighbor, mass)));
+        }
+        out
+    };
+
+    let mut cache: HashMap<usize, Vec<f64>> = HashMap::new();
+    let mut out: Vec<Option<f64>> = vec![None; graph.edge_bound()];
+    for edge in graph.edge_references() {
+        let source = NodeIndexable::to_index(&graph, edge.source());
+        let target = NodeIndexable::to_index(&graph, edge.target());
+        if source == target {
+            continue;
+        }
+        let source_distribution = distribution(source);
+        let target_distribution = distribution(target);
+        let cost: Vec<Vec<f64>> = source_distribution
+            .iter()
+            .map(|(x, _)| {
+                let dist = cache.entry(*x).or_insert_with(|| distances(&adjacency, *x));
+                target_distribution.iter().map(|(y, _)| dist[*y]).collect()
+            })
+            .collect();
+        let length = cache
+            .entry(source)
+            .or_insert_with(|| distances(&adjacency, source))[target];
+        if length <= 0.0 {
+            continue;
+        }
+        let a: Vec<f64> = source_distribution.iter().map(|(_, mass)| *mass).collect();
+        let b: Vec<f64> = target_distribution.iter().map(|(_, mass)| *mass).collect();
+        let transport = sinkhorn_distance(&a, &b, &cost, reg, max_iter, tol);
+        out[EdgeIndexable::to_index(&graph, edge.id())] = Some(1.0 - transport / length);
+    }
+    Ok(out)
+}
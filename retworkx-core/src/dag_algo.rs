@@ -0,0 +1,502 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+//! Module for algorithms on directed acyclic graphs.
+
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::hash::Hash;
+
+use hashbrown::HashMap;
+
+use petgraph::algo::{toposort, Measure};
+use petgraph::visit::{
+    EdgeRef, GraphBase, GraphProp, IntoEdgesDirected, IntoNeighborsDirected, IntoNodeIdentifiers,
+    NodeIndexable, Visitable,
+};
+use petgraph::{Directed, Direction, Incoming, Outgoing};
+
+/// Find the longest path in a DAG.
+///
+/// The length of a path is the sum of the weights of its edges, as returned
+/// by `weight_fn` for each edge. If several paths have the longest length,
+/// the one ending at the earliest node in topological order is returned.
+///
+/// Arguments:
+///
+/// * `graph` - The DAG to find the longest path in.
+/// * `weight_fn` - A callable that returns the weight of an edge. If it
+///   returns an error it is returned by this function.
+///
+/// Returns the nodes of the longest path, from its start to its end, along
+/// with its length. If the graph has a cycle `None` is returned instead. For
+/// an empty graph the path is empty and its length is the default value of
+/// the weight type.
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::petgraph::graph::NodeIndex;
+/// use retworkx_core::dag_algo::longest_path;
+/// use retworkx_core::Result;
+///
+/// let graph = petgraph::graph::DiGraph::<(), u32>::from_edges(&[
+///     (0, 1, 1), (1, 2, 1), (0, 2, 3), (2, 3, 1)
+/// ]);
+/// let res: Result<Option<(Vec<NodeIndex>, u32)>> =
+///     longest_path(&graph, |e| Ok(*e.weight()));
+/// let (path, length) = res.unwrap().unwrap();
+/// assert_eq!(path, vec![NodeIndex::new(0), NodeIndex::new(2), NodeIndex::new(3)]);
+/// assert_eq!(length, 4);
+///
+/// // A graph with a cycle has no longest path
+/// let cycle = petgraph::graph::DiGraph::<(), u32>::from_edges(&[(0, 1, 1), (1, 0, 1)]);
+/// let res: Result<Option<(Vec<NodeIndex>, u32)>> =
+///     longest_path(&cycle, |e| Ok(*e.weight()));
+/// assert!(res.unwrap().is_none());
+/// ```
+#[allow(clippy::type_complexity)]
+pub fn longest_path<G, F, T, E>(
+    graph: G,
+    mut weight_fn: F,
+) -> Result<Option<(Vec<G::NodeId>, T)>, E>
+where
+    G: GraphProp<EdgeType = Directed>
+        + IntoNodeIdentifiers
+        + IntoNeighborsDirected
+        + IntoEdgesDirected
+        + Visitable,
+    G::NodeId: Eq + Hash,
+    F: FnMut(G::EdgeRef) -> Result<T, E>,
+    T: Measure + Copy,
+{
+    let nodes = match toposort(graph, None) {
+        Ok(nodes) => nodes,
+        Err(_) => return Ok(None),
+    };
+    // The length of the longest path ending at each node and the node before
+    // it on that path
+    let mut dist: HashMap<G::NodeId, (T, G::NodeId)> = HashMap::with_capacity(nodes.len());
+    let mut last: Option<(T, G::NodeId)> = None;
+    for &node in &nodes {
+        let mut best = (T::default(), node);
+        let mut first = true;
+        for edge in graph.edges_directed(node, Incoming) {
+            let length = dist[&edge.source()].0 + weight_fn(edge)?;
+            if first || length >= best.0 {
+                best = (length, edge.source());
+                first = false;
+            }
+        }
+        dist.insert(node, best);
+        match last {
+            Some((length, _)) if length >= best.0 => (),
+            _ => last = Some((best.0, node)),
+        }
+    }
+    let (length, mut node) = match last {
+        Some(last) => last,
+        None => return Ok(Some((Vec::new(), T::default()))),
+    };
+    let mut path = vec![node];
+    loop {
+        let prev = dist[&node].1;
+        if prev == node {
+            break;
+        }
+        path.push(prev);
+        node = prev;
+    }
+    path.reverse();
+    Ok(Some((path, length)))
+}
+
+/// Sort the nodes of a DAG topologically, breaking ties with a key.
+///
+/// Whenever several nodes could come next in the order, the one with the
+/// smallest key is chosen, and nodes with the same key are chosen in the
+/// order of their indices. So the order only depends on the keys and the
+/// node indices, and not on the order of the edges. A binary heap holds the
+/// nodes which are ready, so this runs in `O((n + m) log n)` time.
+///
+/// Arguments:
+///
+/// * `graph` - The DAG to sort.
+/// * `key` - A callable that returns the key of a node, it's called once
+///   for each node. If it returns an error it is returned by this function.
+/// * `reverse` - If `true` the nodes are sorted as if every edge of the
+///   graph was reversed, so every node comes after all of its successors.
+///
+/// Returns the nodes in topological order, or `None` if the graph has a
+/// cycle.
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::petgraph::graph::NodeIndex;
+/// use retworkx_core::dag_algo::topological_sort_by;
+/// use retworkx_core::Result;
+///
+/// let graph = petgraph::graph::DiGraph::<&str, ()>::from_edges(&[(0, 1), (2, 1)]);
+/// let names = ["c", "b", "a"];
+/// let res: Result<Option<Vec<NodeIndex>>> =
+///     topological_sort_by(&graph, |n| Ok(names[n.index()]), false);
+/// let order: Vec<usize> = res.unwrap().unwrap().iter().map(|n| n.index()).collect();
+/// assert_eq!(order, vec![2, 0, 1]);
+///
+/// let res: Result<Option<Vec<NodeIndex>>> =
+///     topological_sort_by(&graph, |n| Ok(names[n.index()]), true);
+/// let order: Vec<usize> = res.unwrap().unwrap().iter().map(|n| n.index()).collect();
+/// assert_eq!(order, vec![1, 2, 0]);
+/// ```
+pub fn topological_sort_by<G, F, K, E>(
+    graph: G,
+    mut key: F,
+    reverse: bool,
+) -> Result<Option<Vec<G::NodeId>>, E>
+where
+    G: GraphProp<EdgeType = Directed> + IntoNodeIdentifiers + IntoNeighborsDirected + NodeIndexable,
+    F: FnMut(G::NodeId) -> Result<K, E>,
+    K: Ord,
+{
+    let (forward, backward) = if reverse {
+        (Incoming, Outgoing)
+    } else {
+        (Outgoing, Incoming)
+    };
+    // the number of edges from nodes which aren't sorted yet to each node
+    let mut in_degree: Vec<usize> = vec![0; graph.node_bound()];
+    let mut ready = BinaryHeap::new();
+    let mut node_count = 0;
+    for node in graph.node_identifiers() {
+        node_count += 1;
+        let degree = graph.neighbors_directed(node, backward).count();
+        in_degree[graph.to_index(node)] = degree;
+        if degree == 0 {
+            ready.push(Reverse((key(node)?, graph.to_index(node))));
+        }
+    }
+    let mut order = Vec::with_capacity(node_count);
+    while let Some(Reverse((_, index))) = ready.pop() {
+        let node = graph.from_index(index);
+        order.push(node);
+        for next in graph.neighbors_directed(node, forward) {
+            let next_index = graph.to_index(next);
+            in_degree[next_index] -= 1;
+            if in_degree[next_index] == 0 {
+                ready.push(Reverse((key(next)?, next_index)));
+            }
+        }
+    }
+    if order.len() < node_count {
+        return Ok(None);
+    }
+    Ok(Some(order))
+}
+
+/// Iterate over the topological generations of a DAG.
+///
+/// The first generation is the set of nodes without any predecessors, and
+/// each following generation is the set of nodes whose predecessors are all
+/// in earlier generations. So every generation is an antichain, no node in it
+/// can reach another, and the generations in order partition the nodes of
+/// the graph.
+///
+/// The generations are computed lazily: the in-degree of every node is
+/// counted when the iterator is created, but each generation is only found
+/// when the one before it is returned, so at most two generations are held
+/// in memory at once. The nodes of a generation are in the order they become
+/// ready, and the first generation is in the order of `node_identifiers()`.
+///
+/// If the graph has a cycle, the nodes on the cycle and all the nodes
+/// reachable from it are never returned, and
+/// [`TopologicalGenerations::remaining`] is not `0` once the iterator is
+/// exhausted.
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::dag_algo::topological_generations;
+///
+/// let graph = petgraph::graph::DiGraph::<(), ()>::from_edges(&[
+///     (0, 1), (0, 2), (1, 3), (2, 3), (4, 3)
+/// ]);
+/// let generations: Vec<Vec<usize>> = topological_generations(&graph)
+///     .map(|generation| {
+///         let mut indices: Vec<usize> = generation.iter().map(|n| n.index()).collect();
+///         indices.sort_unstable();
+///         indices
+///     })
+///     .collect();
+/// assert_eq!(generations, vec![vec![0, 4], vec![1, 2], vec![3]]);
+///
+/// // The nodes on and after a cycle are never returned
+/// let cycle = petgraph::graph::DiGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 1)]);
+/// let mut generations = topological_generations(&cycle);
+/// assert_eq!(generations.by_ref().count(), 1);
+/// assert_eq!(generations.remaining(), 2);
+/// ```
+pub fn topological_generations<G>(graph: G) -> TopologicalGenerations<G>
+where
+    G: GraphProp<EdgeType = Directed> + IntoNodeIdentifiers + IntoNeighborsDirected + NodeIndexable,
+{
+    let mut in_degree: Vec<usize> = vec![0; graph.node_bound()];
+    let mut generation = Vec::new();
+    let mut remaining = 0;
+    for node in graph.node_identifiers() {
+        remaining += 1;
+        let degree = graph.neighbors_directed(node, Incoming).count();
+        in_degree[graph.to_index(node)] = degree;
+        if degree == 0 {
+            generation.push(node);
+        }
+    }
+    TopologicalGenerations {
+        graph,
+        in_degree,
+        generation,
+        remaining,
+    }
+}
+
+/// An iterator over the topological generations of a DAG, created by
+/// [`topological_generations`].
+pub struct TopologicalGenerations<G: GraphBase> {
+    graph: G,
+    /// the number of edges from nodes which aren't returned yet to each node
+    in_degree: Vec<usize>,
+    generation: Vec<G::NodeId>,
+    remaining: usize,
+}
+
+impl<G: GraphBase> TopologicalGenerations<G> {
+    /// Return the number of nodes which haven't been returned yet.
+    ///
+    /// Once the iterator is exhausted this is `0` if and only if the graph is
+    /// acyclic.
+    pub fn remaining(&self) -> usize {
+        self.remaining
+    }
+}
+
+impl<G> Iterator for TopologicalGenerations<G>
+where
+    G: IntoNeighborsDirected + NodeIndexable,
+{
+    type Item = Vec<G::NodeId>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.generation.is_empty() {
+            return None;
+        }
+        let generation = std::mem::take(&mut self.generation);
+        for &node in &generation {
+            for next in self.graph.neighbors_directed(node, Outgoing) {
+                let next_index = self.graph.to_index(next);
+                self.in_degree[next_index] -= 1;
+                if self.in_degree[next_index] == 0 {
+                    self.generation.push(next);
+                }
+            }
+        }
+        self.remaining -= generation.len();
+        Some(generation)
+    }
+}
+
+/// A topological order of a DAG which is updated as edges are inserted,
+/// instead of being computed again.
+///
+/// Every node has a distinct position, and every edge goes from a node to a
+/// node with a later position. This is the dynamic topological sort
+/// algorithm of Pearce and Kelly [1]: inserting an edge which agrees with the
+/// order takes constant time, otherwise the nodes with a position between
+/// the ones of the endpoints of the edge are searched, forward from its
+/// target and backward from its source. Either the search finds a cycle or
+/// the nodes it found are reordered among their own positions, so only the
+/// part of the order affected by the edge is changed.
+///
+/// The nodes are identified by their indices, a node which wasn't seen yet
+/// gets a position after all the others, and a new node with no incoming
+/// edges can be put before all the others with
+/// [`IncrementalTopologicalOrder::insert_first`]. Removing nodes or edges from the
+/// graph doesn't change the order, which stays valid, but the edges must be
+/// inserted through [`IncrementalTopologicalOrder::add_edge`], otherwise the
+/// order has to be created again with
+/// [`IncrementalTopologicalOrder::from_graph`].
+///
+/// [1] D. J. Pearce and P. H. J. Kelly, "A Dynamic Topological Sort Algorithm
+/// for Directed Acyclic Graphs", ACM Journal of Experimental Algorithmics,
+/// 11, 1.7, 2006.
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::petgraph::graph::NodeIndex;
+/// use retworkx_core::dag_algo::IncrementalTopologicalOrder;
+///
+/// let mut graph = petgraph::graph::DiGraph::<(), ()>::from_edges(&[(0, 1), (2, 3)]);
+/// let mut order = IncrementalTopologicalOrder::from_graph(&graph).unwrap();
+/// // The edge from 3 to 0 moves 2 and 3 before 0 and 1
+/// assert!(order.add_edge(&graph, NodeIndex::new(3), NodeIndex::new(0)));
+/// graph.add_edge(NodeIndex::new(3), NodeIndex::new(0), ());
+/// let sorted: Vec<usize> = order.sort(&graph).iter().map(|n| n.index()).collect();
+/// assert_eq!(sorted, vec![2, 3, 0, 1]);
+/// // The edge from 1 to 2 would close a cycle
+/// assert!(!order.add_edge(&graph, NodeIndex::new(1), NodeIndex::new(2)));
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct IncrementalTopologicalOrder {
+    /// The position of every node index, `None` if it wasn't seen yet
+    positions: Vec<Option<isize>>,
+    first_position: isize,
+    next_position: isize,
+}
+
+impl IncrementalTopologicalOrder {
+    /// Create an empty order, where every node gets a position the first
+    /// time it's seen. This is only valid for a graph without edges.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create the order of the nodes of a graph from a topological sort.
+    ///
+    /// Returns `None` if the graph has a cycle.
+    pub fn from_graph<G>(graph: G) -> Option<Self>
+    where
+        G: IntoNeighborsDirected + IntoNodeIdentifiers + NodeIndexable + Visitable,
+    {
+        let sorted = toposort(graph, None).ok()?;
+        let mut positions = vec![None; graph.node_bound()];
+        for (position, node) in sorted.iter().enumerate() {
+            positions[graph.to_index(*node)] = Some(position as isize);
+        }
+        Some(IncrementalTopologicalOrder {
+            positions,
+            first_position: 0,
+            next_position: sorted.len() as isize,
+        })
+    }
+
+    /// Return the position of the node with index `index`, or `None` if it
+    /// wasn't seen yet.
+    ///
+    /// The positions are only comparable with each other, they aren't
+    /// contiguous once nodes are removed from the graph.
+    pub fn position(&self, index: usize) -> Option<isize> {
+        self.positions.get(index).copied().flatten()
+    }
+
+    /// Put the node with index `index` before all the others in constant
+    /// time, which is only valid if it has no incoming edges, such as a new
+    /// node which is the source of the next edge inserted.
+    pub fn insert_first(&mut self, index: usize) {
+        if index >= self.positions.len() {
+            self.positions.resize(index + 1, None);
+        }
+        self.first_position -= 1;
+        self.positions[index] = Some(self.first_position);
+    }
+
+    /// Update the order for an edge from `source` to `target`, which can be
+    /// inserted in `graph` before or after this is called.
+    ///
+    /// Returns `false` without changing the order if the edge would close a
+    /// cycle, including a self loop, in which case it must not be inserted.
+    pub fn add_edge<G>(&mut self, graph: G, source: G::NodeId, target: G::NodeId) -> bool
+    where
+        G: IntoNeighborsDirected + NodeIndexable,
+    {
+        let source = graph.to_index(source);
+        let target = graph.to_index(target);
+        if source == target {
+            return false;
+        }
+        let upper = self.position_or_insert(source);
+        let lower = self.position_or_insert(target);
+        if lower > upper {
+            return true;
+        }
+        // The nodes reachable from the target which aren't after the source
+        let forward = self.search(graph, target, Outgoing, |position| position <= upper);
+        if forward.contains(&source) {
+            return false;
+        }
+        // The nodes which can reach the source and aren't before the target
+        let backward = self.search(graph, source, Incoming, |position| position >= lower);
+        let mut free: Vec<isize> = backward
+            .iter()
+            .chain(forward.iter())
+            .map(|node| self.positions[*node].unwrap())
+            .collect();
+        free.sort_unstable();
+        for (node, position) in backward.into_iter().chain(forward).zip(free) {
+            self.positions[node] = Some(position);
+        }
+        true
+    }
+
+    /// Return the nodes of `graph` sorted by their positions.
+    pub fn sort<G>(&self, graph: G) -> Vec<G::NodeId>
+    where
+        G: IntoNodeIdentifiers + NodeIndexable,
+    {
+        let mut nodes: Vec<G::NodeId> = graph.node_identifiers().collect();
+        nodes.sort_by_key(|node| {
+            let index = graph.to_index(*node);
+            (self.position(index).unwrap_or(isize::MAX), index)
+        });
+        nodes
+    }
+
+    fn position_or_insert(&mut self, index: usize) -> isize {
+        if index >= self.positions.len() {
+            self.positions.resize(index + 1, None);
+        }
+        if self.positions[index].is_none() {
+            self.positions[index] = Some(self.next_position);
+            self.next_position += 1;
+        }
+        self.positions[index].unwrap()
+    }
+
+    /// Search the nodes from `start` in `direction` whose positions satisfy
+    /// `bound`, and return them sorted by position.
+    fn search<G, F>(&self, graph: G, start: usize, direction: Direction, bound: F) -> Vec<usize>
+    where
+        G: IntoNeighborsDirected + NodeIndexable,
+        F: Fn(isize) -> bool,
+    {
+        let mut seen: HashMap<usize, isize> = HashMap::new();
+        seen.insert(start, self.positions[start].unwrap());
+        let mut stack = vec![start];
+        while let Some(node) = stack.pop() {
+            for next in graph.neighbors_directed(graph.from_index(node), direction) {
+                let next = graph.to_index(next);
+                if seen.contains_key(&next) {
+                    continue;
+                }
+                match self.position(next) {
+                    Some(position) if bound(position) => {
+                        seen.insert(next, position);
+                        stack.push(next);
+                    }
+                    _ => (),
+                }
+            }
+        }
+        let mut nodes: Vec<usize> = seen.keys().copied().collect();
+        nodes.sort_unstable_by_key(|node| seen[node]);
+        nodes
+    }
+}
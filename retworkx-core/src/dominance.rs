@@ -0,0 +1,228 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+//! Module for the dominance relation of the nodes of a directed graph
+//! reachable from a root, such as the basic blocks of a control flow graph.
+
+use std::hash::Hash;
+
+use hashbrown::HashSet;
+
+use petgraph::visit::{IntoNeighborsDirected, NodeIndexable};
+use petgraph::{Incoming, Outgoing};
+
+use crate::dictmap::*;
+
+/// Find the immediate dominator of every node reachable from `root`.
+///
+/// A node `d` dominates a node `n` if every path from `root` to `n` goes
+/// through `d`, and strictly dominates it if `d` is also different from `n`.
+/// The immediate dominator of a node is the one of its strict dominators
+/// which is dominated by all the others, every node but `root` has one.
+///
+/// This is the iterative algorithm of Cooper, Harvey and Kennedy [1], which
+/// visits the nodes in reverse postorder until the dominators don't change.
+/// It takes `O(n + m)` time per pass, and a few passes are enough for the
+/// graphs found in practice, such as control flow graphs.
+///
+/// Arguments:
+///
+/// * `graph` - The directed graph
+/// * `root` - The node every path starts from, such as the entry of a
+///   control flow graph
+///
+/// Returns a [`DictMap`] of the immediate dominator of every node reachable
+/// from `root`, in reverse postorder from `root`, where `root` is its own
+/// immediate dominator. The nodes which aren't reachable from `root` aren't
+/// included.
+///
+/// [1] K. D. Cooper, T. J. Harvey and K. Kennedy, "A Simple, Fast Dominance
+/// Algorithm", Software Practice and Experience, 4, 1-10, 2001.
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::petgraph::graph::NodeIndex;
+/// use retworkx_core::dominance::immediate_dominators;
+///
+/// // An if/else from 0 with a loop on 3
+/// let g = petgraph::graph::DiGraph::<(), ()>::from_edges(&[
+///     (0, 1), (0, 2), (1, 3), (2, 3), (3, 4), (4, 3), (4, 5),
+/// ]);
+/// let idom = immediate_dominators(&g, NodeIndex::new(0));
+/// let idom: Vec<(usize, usize)> = (0..6)
+///     .map(|n| (n, idom[&NodeIndex::new(n)].index()))
+///     .collect();
+/// assert_eq!(idom, vec![(0, 0), (1, 0), (2, 0), (3, 0), (4, 3), (5, 4)]);
+/// ```
+pub fn immediate_dominators<G>(graph: G, root: G::NodeId) -> DictMap<G::NodeId, G::NodeId>
+where
+    G: IntoNeighborsDirected + NodeIndexable,
+    G::NodeId: Eq + Hash,
+{
+    let root_index = graph.to_index(root);
+    // The position of every node reachable from the root in a postorder
+    let mut positions: Vec<usize> = vec![usize::MAX; graph.node_bound()];
+    let mut postorder: Vec<usize> = Vec::new();
+    let mut visited: Vec<bool> = vec![false; graph.node_bound()];
+    visited[root_index] = true;
+    let mut stack = vec![(root_index, graph.neighbors_directed(root, Outgoing))];
+    while let Some((_, neighbors)) = stack.last_mut() {
+        match neighbors.next() {
+            Some(next) => {
+                let index = graph.to_index(next);
+                if !visited[index] {
+                    visited[index] = true;
+                    stack.push((index, graph.neighbors_directed(next, Outgoing)));
+                }
+            }
+            None => {
+                let (index, _) = stack.pop().unwrap();
+                positions[index] = postorder.len();
+                postorder.push(index);
+            }
+        }
+    }
+
+    let mut idom: Vec<Option<usize>> = vec![None; graph.node_bound()];
+    idom[root_index] = Some(root_index);
+    let mut changed = true;
+    while changed {
+        changed = false;
+        // The root is the last node of the postorder
+        for &node in postorder.iter().rev().skip(1) {
+            let mut new_idom: Option<usize> = None;
+            for pred in graph.neighbors_directed(graph.from_index(node), Incoming) {
+                let pred = graph.to_index(pred);
+                // Skip the unreachable predecessors and the ones without a
+                // dominator yet
+                if idom[pred].is_none() {
+                    continue;
+                }
+                new_idom = Some(match new_idom {
+                    Some(current) => intersect(&idom, &positions, pred, current),
+                    None => pred,
+                });
+            }
+            if new_idom != idom[node] {
+                idom[node] = new_idom;
+                changed = true;
+            }
+        }
+    }
+    postorder
+        .iter()
+        .rev()
+        .map(|node| {
+            (
+                graph.from_index(*node),
+                graph.from_index(idom[*node].unwrap()),
+            )
+        })
+        .collect()
+}
+
+/// Return the closest common dominator of `a` and `b`, walking up the
+/// dominator tree from the one with the earlier position in postorder.
+fn intersect(idom: &[Option<usize>], positions: &[usize], mut a: usize, mut b: usize) -> usize {
+    while a != b {
+        while positions[a] < positions[b] {
+            a = idom[a].unwrap();
+        }
+        while positions[b] < positions[a] {
+            b = idom[b].unwrap();
+        }
+    }
+    a
+}
+
+/// Find the dominance frontier of every node reachable from `root`.
+///
+/// The dominance frontier of a node `d` is the set of the nodes `n` such
+/// that `d` dominates a predecessor of `n` but doesn't strictly dominate
+/// `n`, so it's where the paths dominated by `d` join other paths. These are
+/// the nodes where static single assignment form places phi functions for the
+/// variables assigned in `d`. See [`immediate_dominators`] for the
+/// definition of dominance.
+///
+/// For every edge to a node `n` the dominator tree is walked up from the
+/// source of the edge until the immediate dominator of `n`, which takes time
+/// proportional to the total size of the frontiers [1].
+///
+/// Returns a [`DictMap`] of the dominance frontier of every node reachable
+/// from `root`, in reverse postorder from `root`. The nodes which aren't
+/// reachable from `root` aren't included, and neither are the edges from
+/// them.
+///
+/// [1] K. D. Cooper, T. J. Harvey and K. Kennedy, "A Simple, Fast Dominance
+/// Algorithm", Software Practice and Experience, 4, 1-10, 2001.
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::petgraph::graph::NodeIndex;
+/// use retworkx_core::dominance::dominance_frontiers;
+///
+/// // An if/else from 0 with a loop on 3
+/// let g = petgraph::graph::DiGraph::<(), ()>::from_edges(&[
+///     (0, 1), (0, 2), (1, 3), (2, 3), (3, 4), (4, 3), (4, 5),
+/// ]);
+/// let frontiers = dominance_frontiers(&g, NodeIndex::new(0));
+/// let frontier = |n: usize| {
+///     let mut nodes: Vec<usize> = frontiers[&NodeIndex::new(n)]
+///         .iter()
+///         .map(|node| node.index())
+///         .collect();
+///     nodes.sort_unstable();
+///     nodes
+/// };
+/// assert_eq!(frontier(0), vec![]);
+/// assert_eq!(frontier(1), vec![3]);
+/// assert_eq!(frontier(2), vec![3]);
+/// assert_eq!(frontier(3), vec![3]);
+/// assert_eq!(frontier(4), vec![3]);
+/// assert_eq!(frontier(5), vec![]);
+/// ```
+pub fn dominance_frontiers<G>(graph: G, root: G::NodeId) -> DictMap<G::NodeId, HashSet<G::NodeId>>
+where
+    G: IntoNeighborsDirected + NodeIndexable,
+    G::NodeId: Eq + Hash,
+{
+    let idom = immediate_dominators(graph, root);
+    let mut frontiers: DictMap<G::NodeId, HashSet<G::NodeId>> =
+        idom.keys().map(|node| (*node, HashSet::new())).collect();
+    for (node, node_idom) in idom.iter() {
+        // The root doesn't have an immediate dominator, so the walks from its
+        // predecessors go up to the root included
+        let stop = if *node == root {
+            None
+        } else {
+            Some(*node_idom)
+        };
+        for pred in graph.neighbors_directed(*node, Incoming) {
+            if !idom.contains_key(&pred) {
+                continue;
+            }
+            let mut runner = Some(pred);
+            while runner != stop {
+                let current = runner.unwrap();
+                frontiers.get_mut(&current).unwrap().insert(*node);
+                runner = if current == root {
+                    None
+                } else {
+                    Some(idom[&current])
+                };
+            }
+        }
+    }
+    frontiers
+}
@@ -0,0 +1,396 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+//! Module for evaluating node embeddings against a graph.
+//!
+//! Every function takes the similarity of the embeddings of two nodes as a
+//! `score` callable, for example the dot product of their embedding vectors,
+//! and measures how well ranking the pairs of nodes by their score recovers
+//! the edges of the graph.
+
+use std::cmp::Ordering;
+
+use hashbrown::HashSet;
+use petgraph::visit::{
+    EdgeRef, GraphBase, GraphProp, IntoEdgeReferences, IntoNodeIdentifiers, NodeIndexable,
+};
+use rand::prelude::*;
+use rand_pcg::Pcg64;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// Map `f` over every item, in parallel if there are at least
+/// `parallel_threshold` items and the `parallel` feature is enabled.
+fn map_items<I, T, F>(items: &[I], parallel_threshold: usize, f: F) -> Vec<T>
+where
+    I: Sync,
+    T: Send,
+    F: Fn(&I) -> T + Sync + Send,
+{
+    #[cfg(feature = "parallel")]
+    if items.len() >= parallel_threshold {
+        return items.par_iter().map(f).collect();
+    }
+    #[cfg(not(feature = "parallel"))]
+    let _ = parallel_threshold;
+    items.iter().map(f).collect()
+}
+
+/// The nodes of a graph, by position, and its edges between the positions.
+///
+/// The edges of an undirected graph are stored with the lower position
+/// first, self loops are left out.
+struct PositionedGraph<N> {
+    nodes: Vec<N>,
+    position: Vec<usize>,
+    edges: HashSet<(usize, usize)>,
+    directed: bool,
+}
+
+impl<N: Copy + PartialEq> PositionedGraph<N> {
+    fn new<G>(graph: G) -> Self
+    where
+        G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable + GraphProp,
+        G: GraphBase<NodeId = N>,
+    {
+        let nodes: Vec<N> = graph.node_identifiers().collect();
+        let mut position = vec![usize::MAX; graph.node_bound()];
+        for (i, node) in nodes.iter().enumerate() {
+            position[graph.to_index(*node)] = i;
+        }
+        let mut pairs = PositionedGraph {
+            nodes,
+            position,
+            edges: HashSet::new(),
+            directed: graph.is_directed(),
+        };
+        for edge in graph.edge_references() {
+            let u = pairs.position[graph.to_index(edge.source())];
+            let v = pairs.position[graph.to_index(edge.target())];
+            if u != v {
+                let pair = pairs.pair(u, v);
+                pairs.edges.insert(pair);
+            }
+        }
+        pairs
+    }
+
+    /// The key of the pair of positions `u` and `v` in `edges`.
+    fn pair(&self, u: usize, v: usize) -> (usize, usize) {
+        if self.directed || u < v {
+            (u, v)
+        } else {
+            (v, u)
+        }
+    }
+
+    fn is_edge(&self, u: usize, v: usize) -> bool {
+        self.edges.contains(&self.pair(u, v))
+    }
+
+    /// The number of distinct pairs of different nodes.
+    fn num_pairs(&self) -> usize {
+        let n = self.nodes.len();
+        let pairs = n * n.saturating_sub(1);
+        if self.directed {
+            pairs
+        } else {
+            pairs / 2
+        }
+    }
+}
+
+/// Call `score`, treating a NaN as the lowest possible score.
+fn checked_score<N, F>(score: &F, u: N, v: N) -> f64
+where
+    F: Fn(N, N) -> f64,
+{
+    let value = score(u, v);
+    if value.is_nan() {
+        f64::NEG_INFINITY
+    } else {
+        value
+    }
+}
+
+/// Order scored pairs by decreasing score, then by increasing positions.
+fn by_score(a: &(f64, usize, usize), b: &(f64, usize, usize)) -> Ordering {
+    b.0.partial_cmp(&a.0)
+        .unwrap_or(Ordering::Equal)
+        .then((a.1, a.2).cmp(&(b.1, b.2)))
+}
+
+/// Compute the precision at `k` of the reconstruction of a graph from the
+/// scores of the pairs of its nodes.
+///
+/// All the pairs of different nodes are ranked by decreasing score, and the
+/// precision at `k` is the fraction of the `k` best ranked pairs which are
+/// edges of the graph. A pair of an undirected graph is only ranked once, a
+/// directed graph ranks both orders of the nodes, with `score(u, v)` the
+/// score of the edge from `u` to `v`. The pairs with equal scores are ranked
+/// by the order of their nodes in `graph.node_identifiers()`, a NaN score is
+/// ranked last. If there are less than `k` pairs the precision is over all
+/// of them, and with no pair or if `k` is 0 it's 0.
+///
+/// The best pairs of each node are found on multiple threads if there are
+/// at least `parallel_threshold` nodes and the `parallel` feature is enabled.
+///
+/// Arguments:
+///
+/// * `graph` - The graph to reconstruct
+/// * `score` - The score of a pair of nodes, usually the similarity of their
+///   embeddings
+/// * `k` - The number of best ranked pairs to check
+/// * `parallel_threshold` - The number of nodes to run in parallel from
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::embedding::reconstruction_precision_at_k;
+///
+/// let graph = petgraph::graph::UnGraph::<(), ()>::from_edges(&[(0, 1), (2, 3)]);
+/// let embeddings = [[1.0, 0.0], [0.9, 0.1], [0.0, 1.0], [0.2, 0.8]];
+/// let score = |u: petgraph::graph::NodeIndex, v: petgraph::graph::NodeIndex| {
+///     let (a, b) = (embeddings[u.index()], embeddings[v.index()]);
+///     a[0] * b[0] + a[1] * b[1]
+/// };
+/// assert_eq!(reconstruction_precision_at_k(&graph, score, 2, 100), 1.0);
+/// assert_eq!(reconstruction_precision_at_k(&graph, score, 4, 100), 0.5);
+/// ```
+pub fn reconstruction_precision_at_k<G, F>(
+    graph: G,
+    score: F,
+    k: usize,
+    parallel_threshold: usize,
+) -> f64
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable + GraphProp,
+    G::NodeId: Send + Sync,
+    F: Fn(G::NodeId, G::NodeId) -> f64 + Sync,
+{
+    let pairs = PositionedGraph::new(graph);
+    let n = pairs.nodes.len();
+    let rows: Vec<usize> = (0..n).collect();
+    let best_of_rows = map_items(&rows, parallel_threshold, |&u| {
+        let first = if pairs.directed { 0 } else { u + 1 };
+        let mut row: Vec<(f64, usize, usize)> = (first..n)
+            .filter(|&v| v != u)
+            .map(|v| (checked_score(&score, pairs.nodes[u], pairs.nodes[v]), u, v))
+            .collect();
+        row.sort_unstable_by(by_score);
+        row.truncate(k);
+        row
+    });
+    let mut best: Vec<(f64, usize, usize)> = best_of_rows.into_iter().flatten().collect();
+    best.sort_unstable_by(by_score);
+    best.truncate(k);
+    if best.is_empty() {
+        return 0.0;
+    }
+    let hits = best
+        .iter()
+        .filter(|(_, u, v)| pairs.is_edge(*u, *v))
+        .count();
+    hits as f64 / best.len() as f64
+}
+
+/// Compute the mean average precision of the reconstruction of a graph from
+/// the scores of the pairs of its nodes.
+///
+/// For every node `u` with at least one neighbor, the other nodes `v` are
+/// ranked by decreasing `score(u, v)`, and the average precision of `u` is
+/// the mean of the precisions at the ranks of its neighbors. The neighbors
+/// of a node of a directed graph are its successors. The mean average
+/// precision is the mean of the average precisions of these nodes, it's 0
+/// if no node has a neighbor. The nodes with equal scores are ranked by
+/// their order in `graph.node_identifiers()`, a NaN score is ranked last.
+///
+/// The nodes are ranked on multiple threads if there are at least
+/// `parallel_threshold` nodes and the `parallel` feature is enabled.
+///
+/// Arguments:
+///
+/// * `graph` - The graph to reconstruct
+/// * `score` - The score of a pair of nodes, usually the similarity of their
+///   embeddings
+/// * `parallel_threshold` - The number of nodes to run in parallel from
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::embedding::reconstruction_mean_average_precision;
+///
+/// let graph = petgraph::graph::UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2)]);
+/// // Node 1 ranks its neighbors first, nodes 0 and 2 rank each other first
+/// let embeddings: [f64; 3] = [0.0, 1.0, 0.0];
+/// let score = |u: petgraph::graph::NodeIndex, v: petgraph::graph::NodeIndex| {
+///     -(embeddings[u.index()] - embeddings[v.index()]).abs()
+/// };
+/// let map = reconstruction_mean_average_precision(&graph, score, 100);
+/// assert!((map - (0.5 + 1.0 + 0.5) / 3.0).abs() < 1e-12);
+/// ```
+pub fn reconstruction_mean_average_precision<G, F>(
+    graph: G,
+    score: F,
+    parallel_threshold: usize,
+) -> f64
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable + GraphProp,
+    G::NodeId: Send + Sync,
+    F: Fn(G::NodeId, G::NodeId) -> f64 + Sync,
+{
+    let pairs = PositionedGraph::new(graph);
+    let n = pairs.nodes.len();
+    let rows: Vec<usize> = (0..n).collect();
+    let average_precisions = map_items(&rows, parallel_threshold, |&u| {
+        if !(0..n).any(|v| v != u && pairs.is_edge(u, v)) {
+            return None;
+        }
+        let mut row: Vec<(f64, usize, usize)> = (0..n)
+            .filter(|&v| v != u)
+            .map(|v| (checked_score(&score, pairs.nodes[u], pairs.nodes[v]), u, v))
+            .collect();
+        row.sort_unstable_by(by_score);
+        let mut hits = 0;
+        let mut total = 0.0;
+        for (rank, (_, _, v)) in row.iter().enumerate() {
+            if pairs.is_edge(u, *v) {
+                hits += 1;
+                total += hits as f64 / (rank + 1) as f64;
+            }
+        }
+        Some(total / hits as f64)
+    });
+    let (count, sum) = average_precisions
+        .into_iter()
+        .flatten()
+        .fold((0, 0.0), |(count, sum), ap| (count + 1, sum + ap));
+    if count == 0 {
+        0.0
+    } else {
+        sum / count as f64
+    }
+}
+
+/// Compute the ROC-AUC of the prediction of held out edges from the scores
+/// of the pairs of nodes of a graph.
+///
+/// The `positive_edges` are the held out edges, usually removed from the
+/// graph before computing the embeddings. They're compared to
+/// `num_negative` negative pairs, drawn uniformly with replacement among the
+/// pairs of different nodes which are neither an edge of the graph nor a
+/// positive edge. The ROC-AUC is the probability that a random positive edge
+/// has a higher score than a random negative pair, a tie counts as half. It
+/// is 1 for a perfect ranking and about 0.5 for a random one.
+///
+/// The scores are computed on multiple threads if there are at least
+/// `parallel_threshold` positive edges and negative pairs and the `parallel`
+/// feature is enabled.
+///
+/// Arguments:
+///
+/// * `graph` - The graph the edges are held out of
+/// * `score` - The score of a pair of nodes, usually the similarity of their
+///   embeddings
+/// * `positive_edges` - The held out edges to predict, their nodes must be
+///   in the graph
+/// * `num_negative` - The number of negative pairs to draw
+/// * `seed` - An optional seed to use for the random number generator
+/// * `parallel_threshold` - The number of scores to compute in parallel from
+///
+/// Returns `None` if there are no positive edges, if `num_negative` is 0 or
+/// if there's no negative pair to draw.
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::petgraph::graph::NodeIndex;
+/// use retworkx_core::embedding::link_prediction_roc_auc;
+///
+/// // The edge (2, 3) is held out of the graph
+/// let mut graph = petgraph::graph::UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2)]);
+/// graph.add_node(());
+/// let embeddings: [f64; 4] = [0.0, 0.1, 0.2, 0.25];
+/// let score = |u: NodeIndex, v: NodeIndex| {
+///     -(embeddings[u.index()] - embeddings[v.index()]).abs()
+/// };
+/// let positive = [(NodeIndex::new(2), NodeIndex::new(3))];
+/// let auc = link_prediction_roc_auc(&graph, score, &positive, 10, Some(42), 100);
+/// assert_eq!(auc, Some(1.0));
+/// ```
+pub fn link_prediction_roc_auc<G, F>(
+    graph: G,
+    score: F,
+    positive_edges: &[(G::NodeId, G::NodeId)],
+    num_negative: usize,
+    seed: Option<u64>,
+    parallel_threshold: usize,
+) -> Option<f64>
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable + GraphProp,
+    G::NodeId: Send + Sync,
+    F: Fn(G::NodeId, G::NodeId) -> f64 + Sync,
+{
+    let mut pairs = PositionedGraph::new(graph);
+    let n = pairs.nodes.len();
+    for (u, v) in positive_edges {
+        let u = pairs.position[graph.to_index(*u)];
+        let v = pairs.position[graph.to_index(*v)];
+        if u != v {
+            let pair = pairs.pair(u, v);
+            pairs.edges.insert(pair);
+        }
+    }
+    if positive_edges.is_empty() || num_negative == 0 || pairs.edges.len() >= pairs.num_pairs() {
+        return None;
+    }
+    let mut rng: Pcg64 = match seed {
+        Some(seed) => Pcg64::seed_from_u64(seed),
+        None => Pcg64::from_entropy(),
+    };
+    let mut negative: Vec<(G::NodeId, G::NodeId)> = Vec::with_capacity(num_negative);
+    while negative.len() < num_negative {
+        let u = rng.gen_range(0..n);
+        let v = rng.gen_range(0..n);
+        if u != v && !pairs.is_edge(u, v) {
+            negative.push((pairs.nodes[u], pairs.nodes[v]));
+        }
+    }
+    let score_pair = |&(u, v): &(G::NodeId, G::NodeId)| checked_score(&score, u, v);
+    let mut scores: Vec<(f64, bool)> = map_items(positive_edges, parallel_threshold, score_pair)
+        .into_iter()
+        .map(|s| (s, true))
+        .chain(
+            map_items(&negative, parallel_threshold, score_pair)
+                .into_iter()
+                .map(|s| (s, false)),
+        )
+        .collect();
+    scores.sort_unstable_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(Ordering::Equal));
+    // The Mann–Whitney U statistic, from the sum of the ranks of the
+    // positive edges where tied scores share their average rank
+    let mut positive_rank_sum = 0.0;
+    let mut start = 0;
+    while start < scores.len() {
+        let mut end = start + 1;
+        while end < scores.len() && scores[end].0 == scores[start].0 {
+            end += 1;
+        }
+        let rank = (start + end + 1) as f64 / 2.0;
+        let tied_positive = scores[start..end].iter().filter(|(_, p)| *p).count();
+        positive_rank_sum += rank * tied_positive as f64;
+        start = end;
+    }
+    let num_positive = positive_edges.len() as f64;
+    let u_statistic = positive_rank_sum - num_positive * (num_positive + 1.0) / 2.0;
+    Some(u_statistic / (num_positive * num_negative as f64))
+}
@@ -0,0 +1,93 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+//! Module for the errors returned by the retworkx-core functions.
+
+use std::convert::Infallible;
+use std::{error::Error, fmt};
+
+/// Error returned when the input arguments are an invalid combination,
+/// describing which argument was rejected and why.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum InvalidInputError {
+    /// The value of the argument `name` is out of its valid range.
+    InvalidParameter {
+        name: &'static str,
+        reason: &'static str,
+    },
+    /// None of the arguments that would determine the argument `name` (for
+    /// example the number of nodes or a list of node weights) were given.
+    MissingParameter { name: &'static str },
+    /// The length of the weights passed in the argument `name` doesn't match
+    /// the number of nodes or edges being created.
+    WeightsLengthMismatch {
+        name: &'static str,
+        expected: usize,
+        got: usize,
+    },
+    /// The text being parsed isn't valid at byte `position`.
+    InvalidSyntax {
+        position: usize,
+        reason: &'static str,
+    },
+}
+
+impl Error for InvalidInputError {}
+
+impl fmt::Display for InvalidInputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InvalidInputError::InvalidParameter { name, reason } => {
+                write!(f, "Invalid value for {}: {}", name, reason)
+            }
+            InvalidInputError::MissingParameter { name } => {
+                write!(f, "{} must be specified", name)
+            }
+            InvalidInputError::WeightsLengthMismatch {
+                name,
+                expected,
+                got,
+            } => write!(f, "{} has length {}, expected {}", name, got, expected),
+            InvalidInputError::InvalidSyntax { position, reason } => {
+                write!(f, "Invalid syntax at position {}: {}", position, reason)
+            }
+        }
+    }
+}
+
+/// Error returned by the functions which take a callback returning a
+/// [`Result`], either for invalid arguments or from the callback.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CallbackError<E = Infallible> {
+    /// The arguments are an invalid combination.
+    InvalidInput(InvalidInputError),
+    /// The error returned by the callback.
+    Callback(E),
+}
+
+impl<E> From<E> for CallbackError<E> {
+    fn from(err: E) -> Self {
+        CallbackError::Callback(err)
+    }
+}
+
+impl<E: Error> Error for CallbackError<E> {}
+
+impl<E: fmt::Display> fmt::Display for CallbackError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CallbackError::InvalidInput(err) => err.fmt(f),
+            CallbackError::Callback(err) => err.fmt(f),
+        }
+    }
+}
@@ -0,0 +1,113 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use petgraph::data::{Build, Create};
+use petgraph::visit::{Data, GraphProp};
+use petgraph::EdgeType;
+
+use super::InvalidInputError;
+
+// MAX_ORDER is determined based on the pointer width of the target platform
+#[cfg(target_pointer_width = "64")]
+const MAX_ORDER: u32 = 60;
+#[cfg(not(target_pointer_width = "64"))]
+const MAX_ORDER: u32 = 29;
+
+/// Generate a binomial tree of order `n` with `2^n` nodes and `2^n - 1`
+/// edges.
+///
+/// The binomial tree of order 0 is a single node, and the binomial tree of
+/// order `k + 1` is two copies of the binomial tree of order `k`, the
+/// second one with its nodes after the nodes of the first one, with an edge
+/// between their roots. The root is node 0, and the depth of node `i` in
+/// the tree is the number of ones in the binary representation of `i`. For
+/// a directed graph the edges go away from the root, and if `bidirectional`
+/// is `true` the edges in the other direction are added too.
+///
+/// Arguments:
+///
+/// * `order` - The order of the binomial tree, it must be less than 60 on a
+///   64 bit platform and less than 29 otherwise.
+/// * `default_node_weight` - A callable that will be passed the depth of a
+///   node in the tree and will return the weight to use for it.
+/// * `default_edge_weight` - A callable that will return the weight object
+///   to use for newly created edges.
+/// * `bidirectional` - Whether edges are added in both directions, it's
+///   ignored for an undirected graph.
+///
+/// An [`InvalidInputError::InvalidParameter`] is returned if `order` is too
+/// large.
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::generators::binomial_tree_graph;
+///
+/// let g: petgraph::graph::DiGraph<usize, ()> =
+///     binomial_tree_graph(3, |depth| depth, || (), false).unwrap();
+/// assert_eq!(g.node_count(), 8);
+/// assert_eq!(g.edge_count(), 7);
+/// assert_eq!(g[petgraph::graph::NodeIndex::new(6)], 2);
+/// assert!(g.contains_edge(0.into(), 4.into()));
+/// assert!(g.contains_edge(4.into(), 6.into()));
+/// assert!(g.contains_edge(6.into(), 7.into()));
+/// ```
+pub fn binomial_tree_graph<G, T, F, H, M>(
+    order: u32,
+    mut default_node_weight: F,
+    mut default_edge_weight: H,
+    bidirectional: bool,
+) -> Result<G, InvalidInputError>
+where
+    G: Build + Create + Data<NodeWeight = T, EdgeWeight = M> + GraphProp,
+    F: FnMut(usize) -> T,
+    H: FnMut() -> M,
+{
+    if order >= MAX_ORDER {
+        return Err(InvalidInputError::InvalidParameter {
+            name: "order",
+            reason: "the number of nodes 2^order is too large",
+        });
+    }
+    let num_nodes: usize = 1 << order;
+    let bidirectional = bidirectional && G::EdgeType::is_directed();
+    let mut graph = G::with_capacity(
+        num_nodes,
+        if bidirectional {
+            2 * (num_nodes - 1)
+        } else {
+            num_nodes - 1
+        },
+    );
+    let nodes: Vec<G::NodeId> = (0..num_nodes)
+        .map(|node| graph.add_node(default_node_weight(node.count_ones() as usize)))
+        .collect();
+    // The tree of order k + 1 repeats the edges of the tree of order k in
+    // the same order shifted by n = 2^k, and then connects the two roots.
+    let mut edges: Vec<(usize, usize)> = Vec::with_capacity(num_nodes - 1);
+    let mut n = 1;
+    for _ in 0..order {
+        for i in 0..edges.len() {
+            let (source, target) = edges[i];
+            edges.push((source + n, target + n));
+        }
+        edges.push((0, n));
+        n *= 2;
+    }
+    for (source, target) in edges {
+        graph.add_edge(nodes[source], nodes[target], default_edge_weight());
+        if bidirectional {
+            graph.add_edge(nodes[target], nodes[source], default_edge_weight());
+        }
+    }
+    Ok(graph)
+}
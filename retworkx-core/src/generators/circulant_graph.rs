@@ -0,0 +1,121 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use petgraph::data::{Build, Create};
+use petgraph::visit::{Data, GraphProp};
+use petgraph::EdgeType;
+
+use super::InvalidInputError;
+
+/// Generate a circulant graph `C_n(offsets)`.
+///
+/// The graph has `n` nodes and node `i` is connected to node
+/// `(i + s) mod n` for every offset `s` of `offsets`, so an undirected graph
+/// is also connected to node `(i - s) mod n`. For a directed graph the edges
+/// go from node `i` to node `(i + s) mod n`, and if `bidirectional` is
+/// `true` the edges in the other direction are added too. An offset of
+/// `n / 2` connects the opposite nodes with a single edge if `n` is even,
+/// or a single edge in each direction for a directed graph.
+///
+/// With the offsets `[1]` the circulant graph is a cycle, and with the
+/// offsets `1..=n / 2` it's a complete graph.
+///
+/// Arguments:
+///
+/// * `n` - The number of nodes
+/// * `offsets` - The offsets of the nodes connected to each node, they must
+///   be distinct and between 1 and `n / 2`.
+/// * `default_node_weight` - A callable that will be passed the index of a node and
+///   will return the weight to use for it.
+/// * `default_edge_weight` - A callable that will return the weight object
+///   to use for newly created edges.
+/// * `bidirectional` - Whether edges are added in both directions, it's
+///   ignored for an undirected graph.
+///
+/// An [`InvalidInputError::InvalidParameter`] is returned if an offset is
+/// out of range or repeated.
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::generators::circulant_graph;
+///
+/// let g: petgraph::graph::UnGraph<(), ()> =
+///     circulant_graph(8, &[1, 4], |_| (), || (), false).unwrap();
+/// assert_eq!(g.node_count(), 8);
+/// assert_eq!(g.edge_count(), 8 + 4);
+/// assert!(g.contains_edge(7.into(), 0.into()));
+/// assert!(g.contains_edge(2.into(), 6.into()));
+/// ```
+pub fn circulant_graph<G, T, F, H, M>(
+    n: usize,
+    offsets: &[usize],
+    mut default_node_weight: F,
+    mut default_edge_weight: H,
+    bidirectional: bool,
+) -> Result<G, InvalidInputError>
+where
+    G: Build + Create + Data<NodeWeight = T, EdgeWeight = M> + GraphProp,
+    F: FnMut(usize) -> T,
+    H: FnMut() -> M,
+{
+    let mut seen = vec![false; n / 2 + 1];
+    for &offset in offsets {
+        if offset == 0 || 2 * offset > n {
+            return Err(InvalidInputError::InvalidParameter {
+                name: "offsets",
+                reason: "must be between 1 and n/2",
+            });
+        }
+        if seen[offset] {
+            return Err(InvalidInputError::InvalidParameter {
+                name: "offsets",
+                reason: "must be distinct",
+            });
+        }
+        seen[offset] = true;
+    }
+    // An offset of n / 2 gives each edge twice when iterating over all the
+    // nodes, which is a single edge per pair of nodes if the graph is
+    // undirected and already both directions if it's directed
+    let num_edges: usize = offsets
+        .iter()
+        .map(|&offset| if 2 * offset == n { n / 2 } else { n })
+        .sum();
+    let directed = G::EdgeType::is_directed();
+    let bidirectional = bidirectional && directed;
+    let mut graph = G::with_capacity(
+        n,
+        if bidirectional {
+            2 * num_edges
+        } else if directed {
+            offsets.len() * n
+        } else {
+            num_edges
+        },
+    );
+    let nodes: Vec<G::NodeId> = (0..n)
+        .map(|i| graph.add_node(default_node_weight(i)))
+        .collect();
+    for &offset in offsets {
+        let opposite = 2 * offset == n;
+        let sources = if opposite && !directed { n / 2 } else { n };
+        for i in 0..sources {
+            let j = (i + offset) % n;
+            graph.add_edge(nodes[i], nodes[j], default_edge_weight());
+            if bidirectional && !opposite {
+                graph.add_edge(nodes[j], nodes[i], default_edge_weight());
+            }
+        }
+    }
+    Ok(graph)
+}
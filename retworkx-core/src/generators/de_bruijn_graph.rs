@@ -0,0 +1,116 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use petgraph::data::{Build, Create};
+use petgraph::visit::{Data, GraphProp};
+
+use super::InvalidInputError;
+
+/// Generate a de Bruijn graph `B(k, n)`.
+///
+/// The nodes are the `k^n` words of length `n` over an alphabet of `k`
+/// symbols, numbered from 0 to `k - 1`. There is an edge from every word
+/// `s_1 s_2 ... s_n` to each of the `k` words `s_2 ... s_n s` obtained by
+/// shifting it one symbol to the left and appending a symbol `s`, so every
+/// node has `k` outgoing and `k` incoming edges. The words made of a single
+/// repeated symbol have a self loop. The index of a node is the number
+/// formed by its word in base `k`, the first symbol being the most
+/// significant digit, so the nodes are in the lexicographic order of their
+/// words.
+///
+/// The de Bruijn graph is directed, in an undirected graph the edges are
+/// added all the same, so two words which shift into each other are joined
+/// by two parallel edges.
+///
+/// Arguments:
+///
+/// * `num_symbols` - The number of symbols `k` of the alphabet
+/// * `length` - The length `n` of the words
+/// * `default_node_weight` - A callable that will be passed the word of a
+///   node, as the numbers of its symbols, and will return the weight to use
+///   for it.
+/// * `default_edge_weight` - A callable that will return the weight object
+///   to use for newly created edges.
+///
+/// An [`InvalidInputError::InvalidParameter`] is returned if the number of
+/// nodes `k^n` or of edges `k^(n + 1)` overflows a `usize`.
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::generators::de_bruijn_graph;
+///
+/// let g: petgraph::graph::DiGraph<String, ()> = de_bruijn_graph(
+///     2,
+///     3,
+///     |word| word.iter().map(|symbol| symbol.to_string()).collect(),
+///     || (),
+/// )
+/// .unwrap();
+/// assert_eq!(g.node_count(), 8);
+/// assert_eq!(g.edge_count(), 16);
+/// assert_eq!(g[petgraph::graph::NodeIndex::new(3)], "011");
+/// // 011 -> 110 and 011 -> 111
+/// assert!(g.contains_edge(3.into(), 6.into()));
+/// assert!(g.contains_edge(3.into(), 7.into()));
+/// assert!(g.contains_edge(0.into(), 0.into()));
+/// ```
+pub fn de_bruijn_graph<G, T, F, H, M>(
+    num_symbols: usize,
+    length: usize,
+    mut default_node_weight: F,
+    mut default_edge_weight: H,
+) -> Result<G, InvalidInputError>
+where
+    G: Build + Create + Data<NodeWeight = T, EdgeWeight = M> + GraphProp,
+    F: FnMut(&[usize]) -> T,
+    H: FnMut() -> M,
+{
+    let too_large = InvalidInputError::InvalidParameter {
+        name: "length",
+        reason: "the number of edges num_symbols^(length + 1) is too large",
+    };
+    let mut num_nodes: usize = 1;
+    for _ in 0..length {
+        num_nodes = num_nodes
+            .checked_mul(num_symbols)
+            .ok_or_else(|| too_large.clone())?;
+    }
+    let num_edges = num_nodes.checked_mul(num_symbols).ok_or(too_large)?;
+    let mut graph = G::with_capacity(num_nodes, num_edges);
+    let mut word = vec![0; length];
+    let nodes: Vec<G::NodeId> = (0..num_nodes)
+        .map(|node| {
+            let mut rest = node;
+            for symbol in word.iter_mut().rev() {
+                *symbol = rest % num_symbols;
+                rest /= num_symbols;
+            }
+            graph.add_node(default_node_weight(&word))
+        })
+        .collect();
+    // Shifting the word to the left drops its first symbol, the number of
+    // the remaining suffix is the node index modulo k^(n - 1)
+    let num_suffixes = if length == 0 || num_symbols == 0 {
+        1
+    } else {
+        num_nodes / num_symbols
+    };
+    for u in 0..num_nodes {
+        let shifted = (u % num_suffixes) * num_symbols;
+        for symbol in 0..num_symbols {
+            let v = if length == 0 { 0 } else { shifted + symbol };
+            graph.add_edge(nodes[u], nodes[v], default_edge_weight());
+        }
+    }
+    Ok(graph)
+}
@@ -0,0 +1,93 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use petgraph::data::{Build, Create};
+use petgraph::visit::{Data, GraphProp};
+use petgraph::EdgeType;
+
+/// Generate a grid graph with `rows` rows and `cols` columns.
+///
+/// The nodes are added row by row, the node in row `i` and column `j` has
+/// index `i * cols + j`, and every node is connected to the next node of
+/// its row and to the node below it in the next row. For a directed graph
+/// the edges go towards the right and the bottom of the grid, and if
+/// `bidirectional` is `true` the edges in the other direction are added
+/// too.
+///
+/// Arguments:
+///
+/// * `rows` - The number of rows of the grid
+/// * `cols` - The number of columns of the grid
+/// * `default_node_weight` - A callable that will be passed the row and the
+///   column of a node and will return the weight to use for it.
+/// * `default_edge_weight` - A callable that will return the weight object
+///   to use for newly created edges.
+/// * `bidirectional` - Whether edges are added in both directions, it's
+///   ignored for an undirected graph.
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::generators::grid_graph;
+///
+/// let g: petgraph::graph::UnGraph<(usize, usize), ()> =
+///     grid_graph(2, 3, |row, col| (row, col), || (), false);
+/// assert_eq!(g.node_count(), 6);
+/// assert_eq!(g.edge_count(), 7);
+/// assert_eq!(g[petgraph::graph::NodeIndex::new(4)], (1, 1));
+/// assert!(g.contains_edge(1.into(), 4.into()));
+/// assert!(g.contains_edge(4.into(), 5.into()));
+/// ```
+pub fn grid_graph<G, T, F, H, M>(
+    rows: usize,
+    cols: usize,
+    mut default_node_weight: F,
+    mut default_edge_weight: H,
+    bidirectional: bool,
+) -> G
+where
+    G: Build + Create + Data<NodeWeight = T, EdgeWeight = M> + GraphProp,
+    F: FnMut(usize, usize) -> T,
+    H: FnMut() -> M,
+{
+    let num_nodes = rows * cols;
+    let bidirectional = bidirectional && G::EdgeType::is_directed();
+    let num_edges = (rows.saturating_sub(1) * cols + rows * cols.saturating_sub(1))
+        * if bidirectional { 2 } else { 1 };
+    let mut graph = G::with_capacity(num_nodes, num_edges);
+    let mut nodes: Vec<G::NodeId> = Vec::with_capacity(num_nodes);
+    for i in 0..rows {
+        for j in 0..cols {
+            nodes.push(graph.add_node(default_node_weight(i, j)));
+        }
+    }
+    for i in 0..rows {
+        for j in 0..cols {
+            let node = nodes[i * cols + j];
+            if i + 1 < rows {
+                let below = nodes[(i + 1) * cols + j];
+                graph.add_edge(node, below, default_edge_weight());
+                if bidirectional {
+                    graph.add_edge(below, node, default_edge_weight());
+                }
+            }
+            if j + 1 < cols {
+                let right = nodes[i * cols + j + 1];
+                graph.add_edge(node, right, default_edge_weight());
+                if bidirectional {
+                    graph.add_edge(right, node, default_edge_weight());
+                }
+            }
+        }
+    }
+    graph
+}
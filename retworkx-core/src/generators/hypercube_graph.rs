@@ -0,0 +1,107 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use std::mem;
+
+use petgraph::data::{Build, Create};
+use petgraph::visit::{Data, GraphProp};
+use petgraph::EdgeType;
+
+use super::InvalidInputError;
+
+/// Generate an `n`-dimensional hypercube graph.
+///
+/// The nodes are the `2^n` points of `{0, 1}^n`, and two nodes are
+/// connected if their coordinates differ in exactly one dimension. The
+/// index of a node is the binary number formed by its coordinates, the
+/// first coordinate being the most significant bit, so the nodes are in the
+/// lexicographic order of their coordinates. For a directed graph the edges
+/// go from the lower node index to the higher one, and if `bidirectional`
+/// is `true` the edges in the other direction are added too.
+///
+/// Arguments:
+///
+/// * `dim` - The number of dimensions of the hypercube
+/// * `default_node_weight` - A callable that will be passed the coordinates
+///   of a node and will return the weight to use for it.
+/// * `default_edge_weight` - A callable that will return the weight object
+///   to use for newly created edges.
+/// * `bidirectional` - Whether edges are added in both directions, it's
+///   ignored for an undirected graph.
+///
+/// An [`InvalidInputError::InvalidParameter`] is returned if the number of
+/// edges `dim * 2^(dim - 1)` overflows a `usize`.
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::generators::hypercube_graph;
+///
+/// let g: petgraph::graph::UnGraph<Vec<usize>, ()> =
+///     hypercube_graph(3, |coordinates| coordinates.to_vec(), || (), false).unwrap();
+/// assert_eq!(g.node_count(), 8);
+/// assert_eq!(g.edge_count(), 12);
+/// assert_eq!(g[petgraph::graph::NodeIndex::new(6)], vec![1, 1, 0]);
+/// assert!(g.contains_edge(6.into(), 2.into()));
+/// assert!(!g.contains_edge(6.into(), 1.into()));
+/// ```
+pub fn hypercube_graph<G, T, F, H, M>(
+    dim: usize,
+    mut default_node_weight: F,
+    mut default_edge_weight: H,
+    bidirectional: bool,
+) -> Result<G, InvalidInputError>
+where
+    G: Build + Create + Data<NodeWeight = T, EdgeWeight = M> + GraphProp,
+    F: FnMut(&[usize]) -> T,
+    H: FnMut() -> M,
+{
+    let too_large = InvalidInputError::InvalidParameter {
+        name: "dim",
+        reason: "the number of edges dim * 2^(dim - 1) is too large",
+    };
+    if dim >= mem::size_of::<usize>() * 8 {
+        return Err(too_large);
+    }
+    let num_nodes: usize = 1 << dim;
+    let bidirectional = bidirectional && G::EdgeType::is_directed();
+    let num_edges = dim.checked_mul(num_nodes / 2).ok_or(too_large)?;
+    let mut graph = G::with_capacity(
+        num_nodes,
+        if bidirectional {
+            2 * num_edges
+        } else {
+            num_edges
+        },
+    );
+    let mut coordinates = vec![0; dim];
+    let nodes: Vec<G::NodeId> = (0..num_nodes)
+        .map(|node| {
+            for (i, coordinate) in coordinates.iter_mut().enumerate() {
+                *coordinate = (node >> (dim - 1 - i)) & 1;
+            }
+            graph.add_node(default_node_weight(&coordinates))
+        })
+        .collect();
+    for u in 0..num_nodes {
+        for bit in 0..dim {
+            let v = u ^ (1 << bit);
+            if u < v {
+                graph.add_edge(nodes[u], nodes[v], default_edge_weight());
+                if bidirectional {
+                    graph.add_edge(nodes[v], nodes[u], default_edge_weight());
+                }
+            }
+        }
+    }
+    Ok(graph)
+}
@@ -0,0 +1,39 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+//! Module for graph generator functions.
+
+mod binomial_tree_graph;
+mod circulant_graph;
+mod de_bruijn_graph;
+mod grid_graph;
+mod hypercube_graph;
+mod multipartite;
+mod petersen_graph;
+mod random_graph;
+mod smiles;
+
+pub use crate::err::InvalidInputError;
+
+pub use binomial_tree_graph::binomial_tree_graph;
+pub use circulant_graph::circulant_graph;
+pub use de_bruijn_graph::de_bruijn_graph;
+pub use grid_graph::grid_graph;
+pub use hypercube_graph::hypercube_graph;
+pub use multipartite::{complete_multipartite_graph, turan_graph};
+pub use petersen_graph::generalized_petersen_graph;
+pub use random_graph::{
+    barabasi_albert_graph, configuration_model, connected_watts_strogatz_graph, gnm_random_graph,
+    gnp_random_graph, is_graphical, powerlaw_cluster_graph, random_bipartite_gnm_graph,
+    random_bipartite_graph, stochastic_block_model, watts_strogatz_graph,
+};
+pub use smiles::{smiles_graph, Bond};
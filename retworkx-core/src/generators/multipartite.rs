@@ -0,0 +1,163 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use petgraph::data::{Build, Create};
+use petgraph::visit::{Data, GraphProp};
+
+use super::InvalidInputError;
+
+/// Generate a complete multipartite graph.
+///
+/// The nodes are split into subsets of the sizes in `subset_sizes`, the
+/// first `subset_sizes[0]` nodes are in the first subset, the next
+/// `subset_sizes[1]` nodes in the second one and so on. Every node is
+/// connected to every node of the other subsets, and to no node of its own
+/// subset. For a directed graph the edges go from the node of the earlier
+/// subset to the node of the later one, and if `bidirectional` is `true`
+/// the edges in the other direction are added too.
+///
+/// Arguments:
+///
+/// * `subset_sizes` - The number of nodes in each subset
+/// * `default_node_weight` - A callable that will be passed the index of the
+///   subset of a node and
+///   will return the weight to use for it.
+/// * `default_edge_weight` - A callable that will return the weight object
+///   to use for newly created edges.
+/// * `bidirectional` - Whether edges are added in both directions, it's
+///   ignored for an undirected graph.
+///
+/// Returns the graph and the index of the subset of every node, in the order
+/// of the node indices.
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::generators::complete_multipartite_graph;
+///
+/// let (g, subsets): (petgraph::graph::UnGraph<(), ()>, Vec<usize>) =
+///     complete_multipartite_graph(&[1, 2, 3], |_| (), || (), false);
+/// assert_eq!(g.node_count(), 6);
+/// assert_eq!(g.edge_count(), 1 * 2 + 1 * 3 + 2 * 3);
+/// assert_eq!(subsets, vec![0, 1, 1, 2, 2, 2]);
+/// ```
+pub fn complete_multipartite_graph<G, T, F, H, M>(
+    subset_sizes: &[usize],
+    mut default_node_weight: F,
+    mut default_edge_weight: H,
+    bidirectional: bool,
+) -> (G, Vec<usize>)
+where
+    G: Build + Create + Data<NodeWeight = T, EdgeWeight = M> + GraphProp,
+    F: FnMut(usize) -> T,
+    H: FnMut() -> M,
+{
+    let num_nodes: usize = subset_sizes.iter().sum();
+    let subsets: Vec<usize> = subset_sizes
+        .iter()
+        .enumerate()
+        .flat_map(|(subset, size)| (0..*size).map(move |_| subset))
+        .collect();
+    let same_size_pairs: usize = subset_sizes.iter().map(|size| size * size).sum();
+    let num_edges = (num_nodes * num_nodes - same_size_pairs) / 2;
+    let mut graph = G::with_capacity(
+        num_nodes,
+        if bidirectional {
+            2 * num_edges
+        } else {
+            num_edges
+        },
+    );
+    let bidirectional = bidirectional && graph.is_directed();
+    let nodes: Vec<G::NodeId> = subsets
+        .iter()
+        .map(|&subset| graph.add_node(default_node_weight(subset)))
+        .collect();
+    for u in 0..num_nodes {
+        for v in u + 1..num_nodes {
+            if subsets[u] != subsets[v] {
+                graph.add_edge(nodes[u], nodes[v], default_edge_weight());
+                if bidirectional {
+                    graph.add_edge(nodes[v], nodes[u], default_edge_weight());
+                }
+            }
+        }
+    }
+    (graph, subsets)
+}
+
+/// Generate a Turán graph.
+///
+/// The Turán graph `T(n, r)` is the complete multipartite graph with `n`
+/// nodes split into `r` subsets whose sizes differ by at most 1, the
+/// `n mod r` larger subsets come last. It's the graph with `n` nodes and the
+/// most edges that doesn't contain a complete subgraph of `r + 1` nodes
+/// [1]. See [`complete_multipartite_graph`] for the direction of the edges
+/// of a directed graph.
+///
+/// Arguments:
+///
+/// * `n` - The number of nodes
+/// * `r` - The number of subsets, it must be between 1 and `n`
+/// * `default_node_weight` - A callable that will be passed the index of the
+///   subset of a node and
+///   will return the weight to use for it.
+/// * `default_edge_weight` - A callable that will return the weight object
+///   to use for newly created edges.
+/// * `bidirectional` - Whether edges are added in both directions, it's
+///   ignored for an undirected graph.
+///
+/// Returns the graph and the index of the subset of every node, in the order
+/// of the node indices. An [`InvalidInputError::InvalidParameter`] is
+/// returned if `r` is out of range.
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::generators::turan_graph;
+///
+/// let (g, subsets): (petgraph::graph::UnGraph<(), ()>, Vec<usize>) =
+///     turan_graph(7, 3, |_| (), || (), false).unwrap();
+/// assert_eq!(g.edge_count(), 16);
+/// assert_eq!(subsets, vec![0, 0, 1, 1, 2, 2, 2]);
+/// ```
+///
+/// [1] P. Turán, "On an extremal problem in graph theory", Matematikai és
+///     Fizikai Lapok, 48, pp. 436–452, 1941.
+pub fn turan_graph<G, T, F, H, M>(
+    n: usize,
+    r: usize,
+    default_node_weight: F,
+    default_edge_weight: H,
+    bidirectional: bool,
+) -> Result<(G, Vec<usize>), InvalidInputError>
+where
+    G: Build + Create + Data<NodeWeight = T, EdgeWeight = M> + GraphProp,
+    F: FnMut(usize) -> T,
+    H: FnMut() -> M,
+{
+    if r < 1 || r > n {
+        return Err(InvalidInputError::InvalidParameter {
+            name: "r",
+            reason: "must be 1 <= r <= n",
+        });
+    }
+    let subset_sizes: Vec<usize> = (0..r)
+        .map(|subset| n / r + usize::from(subset >= r - n % r))
+        .collect();
+    Ok(complete_multipartite_graph(
+        &subset_sizes,
+        default_node_weight,
+        default_edge_weight,
+        bidirectional,
+    ))
+}
@@ -0,0 +1,108 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use petgraph::data::{Build, Create};
+use petgraph::visit::{Data, GraphProp};
+
+use super::InvalidInputError;
+
+/// Generate a generalized Petersen graph `G(n, k)` with `2n` nodes and `3n`
+/// edges. See Watkins [1] for more details.
+///
+/// The first `n` nodes form the internal star, node `i` is connected to node
+/// `(i + k) mod n`. The next `n` nodes form the external regular polygon,
+/// node `n + i` is connected to node `n + (i + 1) mod n` and to node `i` of
+/// the star. The Petersen graph itself is `G(5, 2)`. For a directed graph
+/// the edges go from the nodes listed first to the nodes listed second
+/// above.
+///
+/// Arguments:
+///
+/// * `n` - The number of nodes in the internal star and in the external
+///   regular polygon, it must be at least 3.
+/// * `k` - The shift of the internal star, it must be positive and less than
+///   `n / 2`.
+/// * `default_node_weight` - A callable that will be passed the index of a node and
+///   will return the weight to use for it.
+/// * `default_edge_weight` - A callable that will return the weight object
+///   to use for newly created edges.
+///
+/// An [`InvalidInputError::InvalidParameter`] is returned if `n` or `k` is
+/// out of range.
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::generators::generalized_petersen_graph;
+///
+/// let g: petgraph::graph::UnGraph<(), ()> =
+///     generalized_petersen_graph(5, 2, |_| (), || ()).unwrap();
+/// assert_eq!(g.node_count(), 10);
+/// assert_eq!(g.edge_count(), 15);
+/// assert!(g.contains_edge(0.into(), 2.into()));
+/// assert!(g.contains_edge(5.into(), 6.into()));
+/// assert!(g.contains_edge(5.into(), 0.into()));
+/// ```
+///
+/// [1] Watkins, Mark E.
+///     "A theorem on tait colorings with an application to the generalized Petersen graphs"
+///     Journal of Combinatorial Theory 6 (2), 152–164 (1969).
+///     <https://doi.org/10.1016/S0021-9800(69)80116-X>
+pub fn generalized_petersen_graph<G, T, F, H, M>(
+    n: usize,
+    k: usize,
+    mut default_node_weight: F,
+    mut default_edge_weight: H,
+) -> Result<G, InvalidInputError>
+where
+    G: Build + Create + Data<NodeWeight = T, EdgeWeight = M> + GraphProp,
+    F: FnMut(usize) -> T,
+    H: FnMut() -> M,
+{
+    if n < 3 {
+        return Err(InvalidInputError::InvalidParameter {
+            name: "n",
+            reason: "must be at least 3",
+        });
+    }
+    if k == 0 || 2 * k >= n {
+        return Err(InvalidInputError::InvalidParameter {
+            name: "k",
+            reason: "must be positive and less than n/2",
+        });
+    }
+    let mut graph = G::with_capacity(2 * n, 3 * n);
+    let star_nodes: Vec<G::NodeId> = (0..n)
+        .map(|i| graph.add_node(default_node_weight(i)))
+        .collect();
+    let polygon_nodes: Vec<G::NodeId> = (n..2 * n)
+        .map(|i| graph.add_node(default_node_weight(i)))
+        .collect();
+    for i in 0..n {
+        graph.add_edge(
+            star_nodes[i],
+            star_nodes[(i + k) % n],
+            default_edge_weight(),
+        );
+    }
+    for i in 0..n {
+        graph.add_edge(
+            polygon_nodes[i],
+            polygon_nodes[(i + 1) % n],
+            default_edge_weight(),
+        );
+    }
+    for i in 0..n {
+        graph.add_edge(polygon_nodes[i], star_nodes[i], default_edge_weight());
+    }
+    Ok(graph)
+}
@@ -0,0 +1,1293 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use hashbrown::HashSet;
+
+use petgraph::data::{Build, Create};
+use petgraph::visit::{
+    Data, EdgeRef, GraphBase, GraphProp, IntoEdgeReferences, IntoNodeIdentifiers, NodeIndexable,
+};
+
+use rand::distributions::{Distribution, Uniform};
+use rand::prelude::*;
+use rand_pcg::Pcg64;
+
+use super::InvalidInputError;
+
+fn rng_from_seed(seed: Option<u64>) -> Pcg64 {
+    match seed {
+        Some(seed) => Pcg64::seed_from_u64(seed),
+        None => Pcg64::from_entropy(),
+    }
+}
+
+/// Generate a G<sub>np</sub> random graph, also known as an
+/// Erdős-Rényi graph or a binomial graph.
+///
+/// For number of nodes `n` and probability `p`, the G<sub>np</sub>
+/// graph algorithm creates `n` nodes, and for all the `n (n - 1)` possible
+/// edges (or `n (n - 1) / 2` if the graph is undirected) each edge is created
+/// independently with probability `p`. The run time is `O(n + m)` where `m`
+/// is the expected number of edges, the algorithm is based on [1].
+///
+/// Every edge drawn is first passed to `edge_filter` and only created if it
+/// returns `true`, so edges forbidden by some external constraint are never
+/// materialized. Use `|_, _| true` to accept every edge.
+///
+/// Arguments:
+///
+/// * `num_nodes` - The number of nodes to create in the graph
+/// * `probability` - The probability of creating an edge between two nodes,
+///   it must be between 0 and 1
+/// * `seed` - An optional seed to use for the random number generator
+/// * `default_node_weight` - A callable that will return the weight to use
+///   for newly created nodes.
+/// * `default_edge_weight` - A callable that will return the weight object
+///   to use for newly created edges.
+/// * `edge_filter` - A callable that takes the source and target node
+///   indices (in the order the nodes were created, starting at 0) of a
+///   candidate edge and returns `true` if the edge should be created.
+///
+/// An [`InvalidInputError::InvalidParameter`] is returned if `num_nodes` is 0
+/// or the probability is out of range.
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::generators::gnp_random_graph;
+///
+/// // Never connect an even node to an odd node
+/// let g: petgraph::graph::UnGraph<(), ()> = gnp_random_graph(
+///     20,
+///     1.0,
+///     None,
+///     || (),
+///     || (),
+///     |a, b| a % 2 == b % 2,
+/// ).unwrap();
+/// assert_eq!(g.node_count(), 20);
+/// assert_eq!(g.edge_count(), 2 * 45);
+/// ```
+///
+/// [1] Vladimir Batagelj and Ulrik Brandes,
+///    "Efficient generation of large random networks",
+///    Phys. Rev. E, 71, 036113, 2005.
+pub fn gnp_random_graph<G, T, F, H, M, P>(
+    num_nodes: usize,
+    probability: f64,
+    seed: Option<u64>,
+    mut default_node_weight: F,
+    mut default_edge_weight: H,
+    mut edge_filter: P,
+) -> Result<G, InvalidInputError>
+where
+    G: Build + Create + Data<NodeWeight = T, EdgeWeight = M> + NodeIndexable + GraphProp,
+    F: FnMut() -> T,
+    H: FnMut() -> M,
+    P: FnMut(usize, usize) -> bool,
+{
+    if num_nodes == 0 {
+        return Err(InvalidInputError::InvalidParameter {
+            name: "num_nodes",
+            reason: "must be > 0",
+        });
+    }
+    if !(0.0..=1.0).contains(&probability) {
+        return Err(InvalidInputError::InvalidParameter {
+            name: "probability",
+            reason: "must be 0 <= p <= 1",
+        });
+    }
+    let mut rng = rng_from_seed(seed);
+    let mut graph = G::with_capacity(num_nodes, num_nodes);
+    let directed = graph.is_directed();
+    let nodes: Vec<G::NodeId> = (0..num_nodes)
+        .map(|_| graph.add_node(default_node_weight()))
+        .collect();
+    let mut add_edge = |graph: &mut G, u: usize, v: usize| {
+        if edge_filter(u, v) {
+            graph.add_edge(nodes[u], nodes[v], default_edge_weight());
+        }
+    };
+    if probability == 0.0 {
+        return Ok(graph);
+    }
+    if (probability - 1.0).abs() < f64::EPSILON {
+        for u in 0..num_nodes {
+            let start = if directed { 0 } else { u + 1 };
+            for v in start..num_nodes {
+                // exclude self-loops
+                if u != v {
+                    add_edge(&mut graph, u, v);
+                }
+            }
+        }
+        return Ok(graph);
+    }
+
+    let num_nodes = num_nodes as isize;
+    let mut v: isize = if directed { 0 } else { 1 };
+    let mut w: isize = -1;
+    let lp: f64 = (1.0 - probability).ln();
+    let between = Uniform::new(0.0, 1.0);
+    while v < num_nodes {
+        let random: f64 = between.sample(&mut rng);
+        let lr: f64 = (1.0 - random).ln();
+        let ratio: isize = (lr / lp) as isize;
+        w = w + 1 + ratio;
+        if directed {
+            // avoid self loops
+            if v == w {
+                w += 1;
+            }
+            while v < num_nodes && num_nodes <= w {
+                w -= v;
+                v += 1;
+                // avoid self loops
+                if v == w {
+                    w -= v;
+                    v += 1;
+                }
+            }
+        } else {
+            while w >= v && v < num_nodes {
+                w -= v;
+                v += 1;
+            }
+        }
+        if v < num_nodes {
+            add_edge(&mut graph, v as usize, w as usize);
+        }
+    }
+    Ok(graph)
+}
+
+/// Generate a G<sub>nm</sub> random graph, also known as an
+/// Erdős-Rényi graph.
+///
+/// Generates a random graph out of all the possible graphs with `n` nodes
+/// and `m` edges. The generated graph will not be a multigraph and will not
+/// have self loops. For `n` nodes the maximum number of edges is `n (n - 1)`
+/// (or `n (n - 1) / 2` if the graph is undirected), passing a larger `m` will
+/// return a complete graph.
+///
+/// Every edge drawn is first passed to `edge_filter` and only created if it
+/// returns `true`, a rejected edge is redrawn and never materialized. If
+/// fewer than `m` edges are accepted by `edge_filter` the graph will have
+/// every accepted edge. Use `|_, _| true` to accept every edge.
+///
+/// Arguments:
+///
+/// * `num_nodes` - The number of nodes to create in the graph
+/// * `num_edges` - The number of edges to create in the graph
+/// * `seed` - An optional seed to use for the random number generator
+/// * `default_node_weight` - A callable that will return the weight to use
+///   for newly created nodes.
+/// * `default_edge_weight` - A callable that will return the weight object
+///   to use for newly created edges.
+/// * `edge_filter` - A callable that takes the source and target node
+///   indices (in the order the nodes were created, starting at 0) of a
+///   candidate edge and returns `true` if the edge should be created.
+///
+/// An [`InvalidInputError::InvalidParameter`] is returned if `num_nodes` is 0.
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::generators::gnm_random_graph;
+///
+/// // Never create an edge out of node 0
+/// let g: petgraph::graph::DiGraph<(), ()> = gnm_random_graph(
+///     20,
+///     12,
+///     Some(42),
+///     || (),
+///     || (),
+///     |a, _| a != 0,
+/// ).unwrap();
+/// assert_eq!(g.node_count(), 20);
+/// assert_eq!(g.edge_count(), 12);
+/// assert_eq!(g.neighbors(0.into()).count(), 0);
+/// ```
+pub fn gnm_random_graph<G, T, F, H, M, P>(
+    num_nodes: usize,
+    num_edges: usize,
+    seed: Option<u64>,
+    mut default_node_weight: F,
+    mut default_edge_weight: H,
+    mut edge_filter: P,
+) -> Result<G, InvalidInputError>
+where
+    G: Build + Create + Data<NodeWeight = T, EdgeWeight = M> + NodeIndexable + GraphProp,
+    F: FnMut() -> T,
+    H: FnMut() -> M,
+    P: FnMut(usize, usize) -> bool,
+{
+    if num_nodes == 0 {
+        return Err(InvalidInputError::InvalidParameter {
+            name: "num_nodes",
+            reason: "must be > 0",
+        });
+    }
+    let mut rng = rng_from_seed(seed);
+    let mut graph = G::with_capacity(num_nodes, num_edges);
+    let directed = graph.is_directed();
+    let nodes: Vec<G::NodeId> = (0..num_nodes)
+        .map(|_| graph.add_node(default_node_weight()))
+        .collect();
+    let max_edges = if directed {
+        num_nodes * (num_nodes - 1)
+    } else {
+        num_nodes * (num_nodes - 1) / 2
+    };
+    // if number of edges to be created is >= max,
+    // avoid randomly missed trials and directly add edges between every node
+    if num_edges >= max_edges {
+        for u in 0..num_nodes {
+            let start = if directed { 0 } else { u + 1 };
+            for v in start..num_nodes {
+                // avoid self-loops
+                if u != v && edge_filter(u, v) {
+                    graph.add_edge(nodes[u], nodes[v], default_edge_weight());
+                }
+            }
+        }
+        return Ok(graph);
+    }
+
+    let mut created: HashSet<(usize, usize)> = HashSet::with_capacity(num_edges);
+    let mut rejected: HashSet<(usize, usize)> = HashSet::new();
+    let between = Uniform::new(0, num_nodes);
+    while created.len() < num_edges && created.len() + rejected.len() < max_edges {
+        let u = between.sample(&mut rng);
+        let v = between.sample(&mut rng);
+        // avoid self-loops
+        if u == v {
+            continue;
+        }
+        let pair = if directed || u < v { (u, v) } else { (v, u) };
+        // avoid multi-graphs
+        if created.contains(&pair) || rejected.contains(&pair) {
+            continue;
+        }
+        if edge_filter(u, v) {
+            graph.add_edge(nodes[u], nodes[v], default_edge_weight());
+            created.insert(pair);
+        } else {
+            rejected.insert(pair);
+        }
+    }
+    Ok(graph)
+}
+
+/// The nodes of a graph grown by preferential attachment, with the
+/// adjacency of every node and the list of node ends of every edge, in which
+/// a node is repeated once per incident edge so that sampling it uniformly
+/// picks a node with a probability proportional to its degree.
+struct PreferentialAttachment<N> {
+    nodes: Vec<N>,
+    neighbors: Vec<Vec<usize>>,
+    repeated_nodes: Vec<usize>,
+}
+
+impl<N: Copy + PartialEq> PreferentialAttachment<N> {
+    /// Start from an existing graph, the positions of its nodes in `nodes`
+    /// are their order in `node_identifiers`.
+    fn from_graph<G>(graph: &G) -> Self
+    where
+        G: NodeIndexable + GraphBase<NodeId = N>,
+        for<'b> &'b G: IntoEdgeReferences + IntoNodeIdentifiers + GraphBase<NodeId = N>,
+    {
+        let nodes: Vec<N> = graph.node_identifiers().collect();
+        let mut position = vec![0; graph.node_bound()];
+        for (i, node) in nodes.iter().enumerate() {
+            position[graph.to_index(*node)] = i;
+        }
+        let mut out = PreferentialAttachment {
+            neighbors: vec![Vec::new(); nodes.len()],
+            nodes,
+            repeated_nodes: Vec::new(),
+        };
+        for edge in graph.edge_references() {
+            let u = position[graph.to_index(edge.source())];
+            let v = position[graph.to_index(edge.target())];
+            out.neighbors[u].push(v);
+            out.neighbors[v].push(u);
+            out.repeated_nodes.push(u);
+            out.repeated_nodes.push(v);
+        }
+        out
+    }
+
+    fn add_node(&mut self, node: N) -> usize {
+        self.nodes.push(node);
+        self.neighbors.push(Vec::new());
+        self.nodes.len() - 1
+    }
+
+    fn add_edge<G, M, H>(&mut self, graph: &mut G, u: usize, v: usize, default_edge_weight: &mut H)
+    where
+        G: Build + GraphBase<NodeId = N> + Data<EdgeWeight = M>,
+        H: FnMut() -> M,
+    {
+        graph.add_edge(self.nodes[u], self.nodes[v], default_edge_weight());
+        self.neighbors[u].push(v);
+        self.neighbors[v].push(u);
+        self.repeated_nodes.push(u);
+        self.repeated_nodes.push(v);
+    }
+
+    /// Draw a node with a probability proportional to its degree that isn't
+    /// `source` and isn't in `exclude`.
+    fn draw(&self, rng: &mut Pcg64, source: usize, exclude: &HashSet<usize>) -> usize {
+        loop {
+            let node = *self.repeated_nodes.choose(rng).unwrap();
+            if node != source && !exclude.contains(&node) {
+                return node;
+            }
+        }
+    }
+}
+
+/// Validate the arguments of a preferential attachment generator and set up
+/// the graph to grow, a star graph with `m + 1` nodes by default.
+fn preferential_attachment_start<G, T, F, H, M>(
+    n: usize,
+    m: usize,
+    initial_graph: Option<G>,
+    default_node_weight: &mut F,
+    default_edge_weight: &mut H,
+) -> Result<(G, PreferentialAttachment<G::NodeId>), InvalidInputError>
+where
+    G: Build + Create + Data<NodeWeight = T, EdgeWeight = M> + NodeIndexable,
+    for<'b> &'b G: IntoEdgeReferences + IntoNodeIdentifiers + GraphBase<NodeId = G::NodeId>,
+    F: FnMut() -> T,
+    H: FnMut() -> M,
+{
+    if m == 0 {
+        return Err(InvalidInputError::InvalidParameter {
+            name: "m",
+            reason: "must be > 0",
+        });
+    }
+    match initial_graph {
+        Some(graph) => {
+            let attachment = PreferentialAttachment::from_graph(&graph);
+            if attachment.nodes.len() > n {
+                return Err(InvalidInputError::InvalidParameter {
+                    name: "initial_graph",
+                    reason: "must have at most n nodes",
+                });
+            }
+            let connected = attachment
+                .neighbors
+                .iter()
+                .filter(|neighbors| !neighbors.is_empty())
+                .count();
+            if connected < m {
+                return Err(InvalidInputError::InvalidParameter {
+                    name: "initial_graph",
+                    reason: "must have at least m nodes with an edge",
+                });
+            }
+            Ok((graph, attachment))
+        }
+        None => {
+            if m >= n {
+                return Err(InvalidInputError::InvalidParameter {
+                    name: "m",
+                    reason: "must be < n",
+                });
+            }
+            let mut graph = G::with_capacity(n, n * m);
+            let mut attachment = PreferentialAttachment {
+                nodes: Vec::with_capacity(n),
+                neighbors: Vec::with_capacity(n),
+                repeated_nodes: Vec::with_capacity(2 * n * m),
+            };
+            for _ in 0..=m {
+                attachment.add_node(graph.add_node(default_node_weight()));
+            }
+            for v in 1..=m {
+                attachment.add_edge(&mut graph, 0, v, default_edge_weight);
+            }
+            Ok((graph, attachment))
+        }
+    }
+}
+
+/// Generate a random graph with the Barabási–Albert preferential attachment
+/// model.
+///
+/// Starting from `initial_graph`, or from a star graph with `m + 1` nodes
+/// if it's not given, nodes are added one at a time until the graph has `n`
+/// nodes. Every new node gets an edge to each of `m` distinct existing
+/// nodes, drawn with a probability proportional to their degree, so the
+/// degree distribution of the graph follows a power law [1]. For a directed
+/// graph the edges go from the new node to the existing nodes and the total
+/// degree is used. The nodes are drawn from a list of the ends of every edge,
+/// where a node is repeated once per incident edge, so drawing a node takes
+/// constant time.
+///
+/// Arguments:
+///
+/// * `n` - The number of nodes of the generated graph
+/// * `m` - The number of edges to attach from every new node
+/// * `seed` - An optional seed to use for the random number generator
+/// * `initial_graph` - An optional graph to grow, it must have at most `n`
+///   nodes and at least `m` nodes with an edge. Its nodes and edges are kept
+///   as is.
+/// * `default_node_weight` - A callable that will return the weight to use
+///   for newly created nodes.
+/// * `default_edge_weight` - A callable that will return the weight object
+///   to use for newly created edges.
+///
+/// An [`InvalidInputError::InvalidParameter`] is returned if `m` is 0, if
+/// `initial_graph` isn't given and `m` isn't less than `n`, or if
+/// `initial_graph` has more than `n` nodes or fewer than `m` nodes with an
+/// edge.
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::generators::barabasi_albert_graph;
+///
+/// let g: petgraph::graph::UnGraph<(), ()> =
+///     barabasi_albert_graph(20, 3, Some(42), None, || (), || ()).unwrap();
+/// assert_eq!(g.node_count(), 20);
+/// // 3 edges of the initial star graph and 3 more for every other node
+/// assert_eq!(g.edge_count(), 3 + (20 - 4) * 3);
+/// ```
+///
+/// [1] A. L. Barabási and R. Albert, "Emergence of scaling in random
+///    networks", Science 286, pp 509-512, 1999.
+pub fn barabasi_albert_graph<G, T, F, H, M>(
+    n: usize,
+    m: usize,
+    seed: Option<u64>,
+    initial_graph: Option<G>,
+    mut default_node_weight: F,
+    mut default_edge_weight: H,
+) -> Result<G, InvalidInputError>
+where
+    G: Build + Create + Data<NodeWeight = T, EdgeWeight = M> + NodeIndexable,
+    for<'b> &'b G: IntoEdgeReferences + IntoNodeIdentifiers + GraphBase<NodeId = G::NodeId>,
+    F: FnMut() -> T,
+    H: FnMut() -> M,
+{
+    let (mut graph, mut attachment) = preferential_attachment_start(
+        n,
+        m,
+        initial_graph,
+        &mut default_node_weight,
+        &mut default_edge_weight,
+    )?;
+    let mut rng = rng_from_seed(seed);
+    let mut targets: HashSet<usize> = HashSet::with_capacity(m);
+    while attachment.nodes.len() < n {
+        // Draw every target before adding the edges of the new node so
+        // they're drawn from the degrees of the existing graph
+        targets.clear();
+        let mut ordered_targets = Vec::with_capacity(m);
+        while targets.len() < m {
+            let target = attachment.draw(&mut rng, usize::MAX, &targets);
+            targets.insert(target);
+            ordered_targets.push(target);
+        }
+        let source = attachment.add_node(graph.add_node(default_node_weight()));
+        for target in ordered_targets {
+            attachment.add_edge(&mut graph, source, target, &mut default_edge_weight);
+        }
+    }
+    Ok(graph)
+}
+
+/// Generate a random graph with the Holme–Kim model, an extension of the
+/// Barabási–Albert preferential attachment model with triad formation.
+///
+/// Nodes are added one at a time until the graph has `n` nodes, each one
+/// with `m` edges. Like [`barabasi_albert_graph`] the first edge of a new
+/// node goes to an existing node drawn with a probability proportional to
+/// its degree. Every following edge is, with probability `p`, a triad
+/// formation step, which goes to a random neighbor of the node the previous
+/// preferential attachment edge went to and so closes a triangle, and
+/// otherwise another preferential attachment edge [1]. The triad formation
+/// steps raise the clustering of the graph while keeping the power law degree
+/// distribution. If the previous node has no neighbor the new node isn't
+/// already connected to, a preferential attachment edge is added instead.
+///
+/// Arguments:
+///
+/// * `n` - The number of nodes of the generated graph
+/// * `m` - The number of edges to attach from every new node
+/// * `p` - The probability of a triad formation step after an edge, it must
+///   be between 0 and 1
+/// * `seed` - An optional seed to use for the random number generator
+/// * `initial_graph` - An optional graph to grow, it must have at most `n`
+///   nodes and at least `m` nodes with an edge. Its nodes and edges are kept
+///   as is. A star graph with `m + 1` nodes is used if it's not given.
+/// * `default_node_weight` - A callable that will return the weight to use
+///   for newly created nodes.
+/// * `default_edge_weight` - A callable that will return the weight object
+///   to use for newly created edges.
+///
+/// An [`InvalidInputError::InvalidParameter`] is returned for the same
+/// arguments as [`barabasi_albert_graph`] or if `p` is out of range.
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::generators::powerlaw_cluster_graph;
+///
+/// let g: petgraph::graph::UnGraph<(), ()> =
+///     powerlaw_cluster_graph(20, 3, 0.5, Some(42), None, || (), || ()).unwrap();
+/// assert_eq!(g.node_count(), 20);
+/// assert_eq!(g.edge_count(), 3 + (20 - 4) * 3);
+/// ```
+///
+/// [1] P. Holme and B. J. Kim, "Growing scale-free networks with tunable
+///    clustering", Phys. Rev. E, 65, 026107, 2002.
+pub fn powerlaw_cluster_graph<G, T, F, H, M>(
+    n: usize,
+    m: usize,
+    p: f64,
+    seed: Option<u64>,
+    initial_graph: Option<G>,
+    mut default_node_weight: F,
+    mut default_edge_weight: H,
+) -> Result<G, InvalidInputError>
+where
+    G: Build + Create + Data<NodeWeight = T, EdgeWeight = M> + NodeIndexable,
+    for<'b> &'b G: IntoEdgeReferences + IntoNodeIdentifiers + GraphBase<NodeId = G::NodeId>,
+    F: FnMut() -> T,
+    H: FnMut() -> M,
+{
+    if !(0.0..=1.0).contains(&p) {
+        return Err(InvalidInputError::InvalidParameter {
+            name: "p",
+            reason: "must be 0 <= p <= 1",
+        });
+    }
+    let (mut graph, mut attachment) = preferential_attachment_start(
+        n,
+        m,
+        initial_graph,
+        &mut default_node_weight,
+        &mut default_edge_weight,
+    )?;
+    let mut rng = rng_from_seed(seed);
+    let between = Uniform::new(0.0, 1.0);
+    while attachment.nodes.len() < n {
+        let source = attachment.add_node(graph.add_node(default_node_weight()));
+        let mut connected: HashSet<usize> = HashSet::with_capacity(m);
+        let mut target = attachment.draw(&mut rng, source, &connected);
+        attachment.add_edge(&mut graph, source, target, &mut default_edge_weight);
+        connected.insert(target);
+        while connected.len() < m {
+            if between.sample(&mut rng) < p {
+                let neighborhood: Vec<usize> = attachment.neighbors[target]
+                    .iter()
+                    .copied()
+                    .filter(|&v| v != source && !connected.contains(&v))
+                    .collect();
+                if let Some(&neighbor) = neighborhood.choose(&mut rng) {
+                    attachment.add_edge(&mut graph, source, neighbor, &mut default_edge_weight);
+                    connected.insert(neighbor);
+                    continue;
+                }
+            }
+            target = attachment.draw(&mut rng, source, &connected);
+            attachment.add_edge(&mut graph, source, target, &mut default_edge_weight);
+            connected.insert(target);
+        }
+    }
+    Ok(graph)
+}
+
+/// Draw the edges of a Watts–Strogatz graph, as pairs of node positions.
+fn watts_strogatz_edges(n: usize, k: usize, p: f64, rng: &mut Pcg64) -> Vec<(usize, usize)> {
+    if k == n {
+        return (0..n)
+            .flat_map(|u| (u + 1..n).map(move |v| (u, v)))
+            .collect();
+    }
+    // The ring lattice, with the edges to the nodes at distance j from every
+    // node before the edges at distance j + 1
+    let mut edges: Vec<(usize, usize)> = (1..=k / 2)
+        .flat_map(|j| (0..n).map(move |u| (u, (u + j) % n)))
+        .collect();
+    let mut neighbors: Vec<HashSet<usize>> = vec![HashSet::with_capacity(k); n];
+    for &(u, v) in &edges {
+        neighbors[u].insert(v);
+        neighbors[v].insert(u);
+    }
+    let between = Uniform::new(0.0, 1.0);
+    let nodes = Uniform::new(0, n);
+    for edge in edges.iter_mut() {
+        let (u, v) = *edge;
+        // Skip the nodes already connected to every other node
+        if between.sample(rng) >= p || neighbors[u].len() >= n - 1 {
+            continue;
+        }
+        let mut w = nodes.sample(rng);
+        while w == u || neighbors[u].contains(&w) {
+            w = nodes.sample(rng);
+        }
+        neighbors[u].remove(&v);
+        neighbors[v].remove(&u);
+        neighbors[u].insert(w);
+        neighbors[w].insert(u);
+        *edge = (u, w);
+    }
+    edges
+}
+
+/// Check if the edges connect all the `n` nodes.
+fn edges_connected(n: usize, edges: &[(usize, usize)]) -> bool {
+    let mut parents: Vec<usize> = (0..n).collect();
+    fn find(parents: &mut [usize], mut node: usize) -> usize {
+        while parents[node] != node {
+            parents[node] = parents[parents[node]];
+            node = parents[node];
+        }
+        node
+    }
+    let mut components = n;
+    for &(u, v) in edges {
+        let (u, v) = (find(&mut parents, u), find(&mut parents, v));
+        if u != v {
+            parents[u] = v;
+            components -= 1;
+        }
+    }
+    components <= 1
+}
+
+fn check_watts_strogatz_parameters(n: usize, k: usize, p: f64) -> Result<(), InvalidInputError> {
+    if n == 0 {
+        return Err(InvalidInputError::InvalidParameter {
+            name: "n",
+            reason: "must be > 0",
+        });
+    }
+    if k > n {
+        return Err(InvalidInputError::InvalidParameter {
+            name: "k",
+            reason: "must be <= n",
+        });
+    }
+    if !(0.0..=1.0).contains(&p) {
+        return Err(InvalidInputError::InvalidParameter {
+            name: "p",
+            reason: "must be 0 <= p <= 1",
+        });
+    }
+    Ok(())
+}
+
+fn build_graph<G, T, F, H, M>(
+    n: usize,
+    edges: &[(usize, usize)],
+    mut default_node_weight: F,
+    mut default_edge_weight: H,
+) -> G
+where
+    G: Build + Create + Data<NodeWeight = T, EdgeWeight = M>,
+    F: FnMut() -> T,
+    H: FnMut() -> M,
+{
+    let mut graph = G::with_capacity(n, edges.len());
+    let nodes: Vec<G::NodeId> = (0..n)
+        .map(|_| graph.add_node(default_node_weight()))
+        .collect();
+    for &(u, v) in edges {
+        graph.add_edge(nodes[u], nodes[v], default_edge_weight());
+    }
+    graph
+}
+
+/// Generate a random graph with the Watts–Strogatz small-world model.
+///
+/// The graph starts as a ring lattice of `n` nodes, where every node is
+/// connected to its `k / 2` nearest neighbors on each side. Then for every
+/// edge `(u, v)` of the lattice, with probability `p` it's rewired to
+/// `(u, w)` where `w` is a node drawn uniformly among the nodes `u` isn't
+/// connected to [1]. A small `p` keeps the high clustering of the lattice
+/// while the rewired edges act as shortcuts that make the average shortest
+/// path length small. The number of edges is always `n * (k / 2)`, the graph
+/// has no self loops or parallel edges. For a directed graph the edges go
+/// from `u`, the node that keeps the edge when it's rewired. If `k` equals
+/// `n` a complete graph is returned.
+///
+/// Arguments:
+///
+/// * `n` - The number of nodes of the generated graph
+/// * `k` - The number of nearest neighbors every node is connected to in the
+///   ring lattice, the lattice uses `k / 2` rounded down on each side
+/// * `p` - The probability of rewiring every edge, it must be between 0 and 1
+/// * `seed` - An optional seed to use for the random number generator
+/// * `default_node_weight` - A callable that will return the weight to use
+///   for newly created nodes.
+/// * `default_edge_weight` - A callable that will return the weight object
+///   to use for newly created edges.
+///
+/// An [`InvalidInputError::InvalidParameter`] is returned if `n` is 0, if `k`
+/// is larger than `n` or if `p` is out of range.
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::generators::watts_strogatz_graph;
+///
+/// let g: petgraph::graph::UnGraph<(), ()> =
+///     watts_strogatz_graph(20, 4, 0.1, Some(42), || (), || ()).unwrap();
+/// assert_eq!(g.node_count(), 20);
+/// assert_eq!(g.edge_count(), 20 * 2);
+/// ```
+///
+/// [1] D. J. Watts and S. H. Strogatz, "Collective dynamics of
+///    'small-world' networks", Nature, 393, pp. 440–442, 1998.
+pub fn watts_strogatz_graph<G, T, F, H, M>(
+    n: usize,
+    k: usize,
+    p: f64,
+    seed: Option<u64>,
+    default_node_weight: F,
+    default_edge_weight: H,
+) -> Result<G, InvalidInputError>
+where
+    G: Build + Create + Data<NodeWeight = T, EdgeWeight = M>,
+    F: FnMut() -> T,
+    H: FnMut() -> M,
+{
+    check_watts_strogatz_parameters(n, k, p)?;
+    let mut rng = rng_from_seed(seed);
+    let edges = watts_strogatz_edges(n, k, p, &mut rng);
+    Ok(build_graph(
+        n,
+        &edges,
+        default_node_weight,
+        default_edge_weight,
+    ))
+}
+
+/// Generate a connected random graph with the Watts–Strogatz small-world
+/// model.
+///
+/// This draws the edges of a graph like [`watts_strogatz_graph`] until they
+/// connect all the nodes, ignoring the direction of the edges, for up to
+/// `max_attempts` attempts. Only the connected graph is built. A
+/// small `k` and a large `p` make a disconnected graph more likely, with `k`
+/// less than 2 the graph is only connected if it has a single node.
+///
+/// Arguments:
+///
+/// * `n` - The number of nodes of the generated graph
+/// * `k` - The number of nearest neighbors every node is connected to in the
+///   ring lattice, the lattice uses `k / 2` rounded down on each side
+/// * `p` - The probability of rewiring every edge, it must be between 0 and 1
+/// * `max_attempts` - The maximum number of graphs drawn
+/// * `seed` - An optional seed to use for the random number generator
+/// * `default_node_weight` - A callable that will return the weight to use
+///   for newly created nodes.
+/// * `default_edge_weight` - A callable that will return the weight object
+///   to use for newly created edges.
+///
+/// An [`InvalidInputError::InvalidParameter`] is returned for the same
+/// arguments as [`watts_strogatz_graph`], or for `max_attempts` if none of
+/// the graphs drawn was connected.
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::generators::connected_watts_strogatz_graph;
+///
+/// let g: petgraph::graph::UnGraph<(), ()> =
+///     connected_watts_strogatz_graph(20, 2, 0.5, 100, Some(42), || (), || ()).unwrap();
+/// assert_eq!(petgraph::algo::connected_components(&g), 1);
+/// ```
+pub fn connected_watts_strogatz_graph<G, T, F, H, M>(
+    n: usize,
+    k: usize,
+    p: f64,
+    max_attempts: usize,
+    seed: Option<u64>,
+    default_node_weight: F,
+    default_edge_weight: H,
+) -> Result<G, InvalidInputError>
+where
+    G: Build + Create + Data<NodeWeight = T, EdgeWeight = M>,
+    F: FnMut() -> T,
+    H: FnMut() -> M,
+{
+    check_watts_strogatz_parameters(n, k, p)?;
+    let mut rng = rng_from_seed(seed);
+    for _ in 0..max_attempts {
+        let edges = watts_strogatz_edges(n, k, p, &mut rng);
+        if edges_connected(n, &edges) {
+            return Ok(build_graph(
+                n,
+                &edges,
+                default_node_weight,
+                default_edge_weight,
+            ));
+        }
+    }
+    Err(InvalidInputError::InvalidParameter {
+        name: "max_attempts",
+        reason: "no connected graph was generated within max_attempts attempts",
+    })
+}
+
+/// Call `f` with every index in `0..total`, each one drawn independently
+/// with probability `p`, in increasing order. The gaps between the drawn
+/// indices are sampled from a geometric distribution, so this takes time
+/// proportional to the number of drawn indices rather than `total` [1].
+///
+/// [1] Vladimir Batagelj and Ulrik Brandes,
+///    "Efficient generation of large random networks",
+///    Phys. Rev. E, 71, 036113, 2005.
+fn geometric_sample<F: FnMut(usize)>(total: usize, p: f64, rng: &mut Pcg64, mut f: F) {
+    if p <= 0.0 || total == 0 {
+        return;
+    }
+    if p >= 1.0 {
+        (0..total).for_each(f);
+        return;
+    }
+    let lp = (1.0 - p).ln();
+    let between = Uniform::new(0.0, 1.0);
+    let mut index: usize = 0;
+    loop {
+        let random: f64 = between.sample(rng);
+        let skip = ((1.0 - random).ln() / lp) as usize;
+        index = index.saturating_add(skip);
+        if index >= total {
+            return;
+        }
+        f(index);
+        index += 1;
+    }
+}
+
+/// Generate a random graph with the stochastic block model.
+///
+/// The nodes are split into consecutive blocks, with `sizes[i]` nodes in
+/// block `i`: the first `sizes[0]` nodes are in block 0, the next
+/// `sizes[1]` nodes in block 1 and so on. Every edge between a node of block
+/// `i` and a node of block `j` is created independently with probability
+/// `probability_matrix[i][j]`, so a matrix with larger probabilities on its
+/// diagonal plants a community structure in the graph. The graph has no
+/// self loops or parallel edges. The edges of every pair of blocks are drawn
+/// by skipping over the rejected edges with a geometric distribution, so the
+/// run time is `O(n + m + b^2)` for `b` blocks, where `m` is the expected
+/// number of edges.
+///
+/// Arguments:
+///
+/// * `sizes` - The number of nodes of every block
+/// * `probability_matrix` - The probability of creating an edge between
+///   every pair of blocks, a square matrix with a row and a column per block. It
+///   must be symmetric if the graph is undirected, for a directed graph
+///   `probability_matrix[i][j]` is the probability of an edge from a node of
+///   block `i` to a node of block `j`.
+/// * `seed` - An optional seed to use for the random number generator
+/// * `default_node_weight` - A callable that will return the weight to use
+///   for newly created nodes.
+/// * `default_edge_weight` - A callable that will return the weight object
+///   to use for newly created edges.
+///
+/// An [`InvalidInputError::InvalidParameter`] is returned if
+/// `probability_matrix` isn't a square matrix with a row per block, if a
+/// probability is out of range, or if the graph is undirected and
+/// `probability_matrix` isn't symmetric.
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::generators::stochastic_block_model;
+///
+/// // Two complete blocks of 5 nodes without any edge between them
+/// let probability_matrix = vec![vec![1.0, 0.0], vec![0.0, 1.0]];
+/// let g: petgraph::graph::UnGraph<(), ()> =
+///     stochastic_block_model(&[5, 5], &probability_matrix, Some(42), || (), || ()).unwrap();
+/// assert_eq!(g.node_count(), 10);
+/// assert_eq!(g.edge_count(), 2 * 10);
+/// assert_eq!(petgraph::algo::connected_components(&g), 2);
+/// ```
+pub fn stochastic_block_model<G, T, F, H, M>(
+    sizes: &[usize],
+    probability_matrix: &[Vec<f64>],
+    seed: Option<u64>,
+    mut default_node_weight: F,
+    mut default_edge_weight: H,
+) -> Result<G, InvalidInputError>
+where
+    G: Build + Create + Data<NodeWeight = T, EdgeWeight = M> + GraphProp,
+    F: FnMut() -> T,
+    H: FnMut() -> M,
+{
+    let num_blocks = sizes.len();
+    if probability_matrix.len() != num_blocks
+        || probability_matrix.iter().any(|row| row.len() != num_blocks)
+    {
+        return Err(InvalidInputError::InvalidParameter {
+            name: "probability_matrix",
+            reason: "must be a square matrix with a row and a column per block",
+        });
+    }
+    if probability_matrix
+        .iter()
+        .flatten()
+        .any(|p| !(0.0..=1.0).contains(p))
+    {
+        return Err(InvalidInputError::InvalidParameter {
+            name: "probability_matrix",
+            reason: "must be 0 <= p <= 1",
+        });
+    }
+    let num_nodes: usize = sizes.iter().sum();
+    let mut graph = G::with_capacity(num_nodes, 0);
+    let directed = graph.is_directed();
+    let symmetric = probability_matrix.iter().enumerate().all(|(i, row)| {
+        row.iter()
+            .enumerate()
+            .all(|(j, p)| *p == probability_matrix[j][i])
+    });
+    if !directed && !symmetric {
+        return Err(InvalidInputError::InvalidParameter {
+            name: "probability_matrix",
+            reason: "must be symmetric for an undirected graph",
+        });
+    }
+
+    let mut rng = rng_from_seed(seed);
+    let nodes: Vec<G::NodeId> = (0..num_nodes)
+        .map(|_| graph.add_node(default_node_weight()))
+        .collect();
+    let mut starts = Vec::with_capacity(num_blocks);
+    let mut start = 0;
+    for size in sizes {
+        starts.push(start);
+        start += size;
+    }
+    for a in 0..num_blocks {
+        let (start_a, size_a) = (starts[a], sizes[a]);
+        let first_b = if directed { 0 } else { a };
+        for b in first_b..num_blocks {
+            let (start_b, size_b) = (starts[b], sizes[b]);
+            let p = probability_matrix[a][b];
+            let mut add_edge = |u: usize, v: usize| {
+                graph.add_edge(nodes[u], nodes[v], default_edge_weight());
+            };
+            if a != b {
+                geometric_sample(size_a * size_b, p, &mut rng, |i| {
+                    add_edge(start_a + i / size_b, start_b + i % size_b)
+                });
+            } else if directed {
+                // Every ordered pair of distinct nodes, the targets of a
+                // source skip over the source itself
+                let others = size_a.saturating_sub(1);
+                geometric_sample(size_a * others, p, &mut rng, |i| {
+                    let (u, r) = (i / others, i % others);
+                    let v = if r >= u { r + 1 } else { r };
+                    add_edge(start_a + u, start_a + v)
+                });
+            } else {
+                // Every pair u < v, row by row, the indices are drawn in
+                // increasing order so the row only moves forward
+                let (mut u, mut row_start) = (0, 0);
+                geometric_sample(size_a * size_a.saturating_sub(1) / 2, p, &mut rng, |i| {
+                    while i >= row_start + (size_a - 1 - u) {
+                        row_start += size_a - 1 - u;
+                        u += 1;
+                    }
+                    add_edge(start_a + u, start_a + u + 1 + i - row_start)
+                });
+            }
+        }
+    }
+    Ok(graph)
+}
+
+/// Generate a random bipartite graph, where every possible edge between the
+/// two sets of nodes is created independently with a probability.
+///
+/// The first `n_left` nodes of the graph are the left set and the next
+/// `n_right` nodes are the right set. Each of the `n_left * n_right` edges
+/// between a node of the left set and a node of the right set is created
+/// with probability `probability`, the edges go from the left node to the
+/// right node. The rejected edges are skipped with a geometric distribution,
+/// so the run time is `O(n + m)` where `m` is the expected number of edges.
+///
+/// Arguments:
+///
+/// * `n_left` - The number of nodes in the left set
+/// * `n_right` - The number of nodes in the right set
+/// * `probability` - The probability of creating an edge between a left and
+///   a right node, it must be between 0 and 1
+/// * `seed` - An optional seed to use for the random number generator
+/// * `default_node_weight` - A callable that will return the weight to use
+///   for newly created nodes.
+/// * `default_edge_weight` - A callable that will return the weight object
+///   to use for newly created edges.
+///
+/// An [`InvalidInputError::InvalidParameter`] is returned if the probability
+/// is out of range.
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::generators::random_bipartite_graph;
+///
+/// let g: petgraph::graph::UnGraph<(), ()> =
+///     random_bipartite_graph(4, 6, 0.5, Some(42), || (), || ()).unwrap();
+/// assert_eq!(g.node_count(), 10);
+/// assert!(g.edge_indices().all(|e| {
+///     let (u, v) = g.edge_endpoints(e).unwrap();
+///     u.index() < 4 && v.index() >= 4
+/// }));
+/// ```
+pub fn random_bipartite_graph<G, T, F, H, M>(
+    n_left: usize,
+    n_right: usize,
+    probability: f64,
+    seed: Option<u64>,
+    default_node_weight: F,
+    default_edge_weight: H,
+) -> Result<G, InvalidInputError>
+where
+    G: Build + Create + Data<NodeWeight = T, EdgeWeight = M>,
+    F: FnMut() -> T,
+    H: FnMut() -> M,
+{
+    if !(0.0..=1.0).contains(&probability) {
+        return Err(InvalidInputError::InvalidParameter {
+            name: "probability",
+            reason: "must be 0 <= p <= 1",
+        });
+    }
+    let mut rng = rng_from_seed(seed);
+    let mut edges = Vec::new();
+    geometric_sample(n_left * n_right, probability, &mut rng, |i| {
+        edges.push((i / n_right, n_left + i % n_right))
+    });
+    Ok(build_graph(
+        n_left + n_right,
+        &edges,
+        default_node_weight,
+        default_edge_weight,
+    ))
+}
+
+/// Generate a random bipartite graph with a given number of edges.
+///
+/// The first `n_left` nodes of the graph are the left set and the next
+/// `n_right` nodes are the right set. The graph is drawn uniformly out of all
+/// the bipartite graphs with `num_edges` edges between the left and the right
+/// set, the edges go from the left node to the right node. Passing a
+/// `num_edges` larger than `n_left * n_right` returns a complete bipartite
+/// graph.
+///
+/// Arguments:
+///
+/// * `n_left` - The number of nodes in the left set
+/// * `n_right` - The number of nodes in the right set
+/// * `num_edges` - The number of edges to create in the graph
+/// * `seed` - An optional seed to use for the random number generator
+/// * `default_node_weight` - A callable that will return the weight to use
+///   for newly created nodes.
+/// * `default_edge_weight` - A callable that will return the weight object
+///   to use for newly created edges.
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::generators::random_bipartite_gnm_graph;
+///
+/// let g: petgraph::graph::UnGraph<(), ()> =
+///     random_bipartite_gnm_graph(4, 6, 10, Some(42), || (), || ()).unwrap();
+/// assert_eq!(g.node_count(), 10);
+/// assert_eq!(g.edge_count(), 10);
+/// ```
+pub fn random_bipartite_gnm_graph<G, T, F, H, M>(
+    n_left: usize,
+    n_right: usize,
+    num_edges: usize,
+    seed: Option<u64>,
+    default_node_weight: F,
+    default_edge_weight: H,
+) -> Result<G, InvalidInputError>
+where
+    G: Build + Create + Data<NodeWeight = T, EdgeWeight = M>,
+    F: FnMut() -> T,
+    H: FnMut() -> M,
+{
+    let mut rng = rng_from_seed(seed);
+    let max_edges = n_left * n_right;
+    let mut indices =
+        rand::seq::index::sample(&mut rng, max_edges, num_edges.min(max_edges)).into_vec();
+    indices.sort_unstable();
+    let edges: Vec<(usize, usize)> = indices
+        .into_iter()
+        .map(|i| (i / n_right, n_left + i % n_right))
+        .collect();
+    Ok(build_graph(
+        n_left + n_right,
+        &edges,
+        default_node_weight,
+        default_edge_weight,
+    ))
+}
+
+/// Return `true` if `degree_sequence` is the degree sequence of a simple
+/// graph, a graph without self loops or parallel edges.
+///
+/// This uses the Erdős–Gallai theorem [1]: a sequence of degrees
+/// `d_1 >= d_2 >= ... >= d_n` is graphical if and only if its sum is even and
+/// for every `k` in `1..=n`:
+///
+/// `d_1 + ... + d_k <= k (k - 1) + min(d_(k+1), k) + ... + min(d_n, k)`
+///
+/// The sequence doesn't need to be sorted, it's sorted in `O(n log n)` time
+/// and then every inequality is checked in constant time.
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::generators::is_graphical;
+///
+/// assert!(is_graphical(&[3, 3, 2, 2, 1, 1]));
+/// // The sum of the degrees is odd
+/// assert!(!is_graphical(&[2, 2, 1]));
+/// // Node 0 would need 3 distinct neighbors out of 2 nodes
+/// assert!(!is_graphical(&[3, 1, 1]));
+/// ```
+///
+/// [1] P. Erdős and T. Gallai, "Graphs with prescribed degrees of
+///    vertices", Mat. Lapok, 11, pp. 264–274, 1960.
+pub fn is_graphical(degree_sequence: &[usize]) -> bool {
+    let mut degrees = degree_sequence.to_vec();
+    degrees.sort_unstable_by(|a, b| b.cmp(a));
+    let n = degrees.len();
+    let mut prefix = vec![0_u128; n + 1];
+    for (i, degree) in degrees.iter().enumerate() {
+        prefix[i + 1] = prefix[i] + *degree as u128;
+    }
+    if prefix[n] & 1 == 1 {
+        return false;
+    }
+    // The number of degrees which are at least k
+    let mut at_least_k = n;
+    for k in 1..=n {
+        while at_least_k > 0 && degrees[at_least_k - 1] < k {
+            at_least_k -= 1;
+        }
+        // Past index k, the degrees up to index at_least_k are capped at k
+        let capped = at_least_k.max(k);
+        let right = (capped - k) as u128 * k as u128 + prefix[n] - prefix[capped];
+        if prefix[k] > (k * (k - 1)) as u128 + right {
+            return false;
+        }
+    }
+    true
+}
+
+/// Generate a random graph with a given degree sequence with the
+/// configuration model.
+///
+/// Every node `i` gets `degree_sequence[i]` stubs, or half edges, and the
+/// stubs are matched uniformly at random into edges [1]. The result is a
+/// multigraph with exactly the given degrees, where a self loop counts twice
+/// in the degree of its node, and it's the usual null model of a graph which
+/// preserves the degrees. If `simple` is `true` the self loops are removed
+/// and the parallel edges are collapsed into a single edge, so the degrees
+/// of the simple graph may be lower than the given degrees.
+///
+/// The degree sequence is checked with the Erdős–Gallai theorem, see
+/// [`is_graphical`], so a simple graph with the given degrees exists.
+///
+/// Arguments:
+///
+/// * `degree_sequence` - The degree of every node of the generated graph
+/// * `simple` - Whether to remove the self loops and parallel edges
+/// * `seed` - An optional seed to use for the random number generator
+/// * `default_node_weight` - A callable that will return the weight to use
+///   for newly created nodes.
+/// * `default_edge_weight` - A callable that will return the weight object
+///   to use for newly created edges.
+///
+/// An [`InvalidInputError::InvalidParameter`] is returned if the degree
+/// sequence isn't graphical.
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::generators::configuration_model;
+///
+/// let degrees = [3, 3, 2, 2, 1, 1];
+/// let g: petgraph::graph::UnGraph<(), ()> =
+///     configuration_model(&degrees, false, Some(42), || (), || ()).unwrap();
+/// assert_eq!(g.edge_count(), 6);
+/// for node in g.node_indices() {
+///     let self_loops = g.edges_connecting(node, node).count();
+///     assert_eq!(g.edges(node).count() + self_loops, degrees[node.index()]);
+/// }
+/// ```
+///
+/// [1] M. E. J. Newman, "The structure and function of complex networks",
+///    SIAM Review, 45, pp. 167–256, 2003.
+pub fn configuration_model<G, T, F, H, M>(
+    degree_sequence: &[usize],
+    simple: bool,
+    seed: Option<u64>,
+    default_node_weight: F,
+    default_edge_weight: H,
+) -> Result<G, InvalidInputError>
+where
+    G: Build + Create + Data<NodeWeight = T, EdgeWeight = M>,
+    F: FnMut() -> T,
+    H: FnMut() -> M,
+{
+    if !is_graphical(degree_sequence) {
+        return Err(InvalidInputError::InvalidParameter {
+            name: "degree_sequence",
+            reason: "must be graphical",
+        });
+    }
+    let mut rng = rng_from_seed(seed);
+    let mut stubs: Vec<usize> = degree_sequence
+        .iter()
+        .enumerate()
+        .flat_map(|(node, degree)| (0..*degree).map(move |_| node))
+        .collect();
+    stubs.shuffle(&mut rng);
+    let mut edges: Vec<(usize, usize)> = stubs
+        .chunks_exact(2)
+        .map(|pair| (pair[0], pair[1]))
+        .collect();
+    if simple {
+        edges.retain(|(u, v)| u != v);
+        for edge in edges.iter_mut() {
+            if edge.0 > edge.1 {
+                *edge = (edge.1, edge.0);
+            }
+        }
+        edges.sort_unstable();
+        edges.dedup();
+    }
+    Ok(build_graph(
+        degree_sequence.len(),
+        &edges,
+        default_node_weight,
+        default_edge_weight,
+    ))
+}
@@ -0,0 +1,271 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use hashbrown::HashMap;
+
+use petgraph::data::{Build, Create};
+use petgraph::visit::{Data, NodeIndexable};
+
+use super::InvalidInputError;
+
+/// The type of a bond in a SMILES string.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Bond {
+    Single,
+    Double,
+    Triple,
+    Quadruple,
+    Aromatic,
+}
+
+impl Bond {
+    /// Return the SMILES symbol of the bond, `-`, `=`, `#`, `$` or `:`.
+    pub fn symbol(&self) -> char {
+        match self {
+            Bond::Single => '-',
+            Bond::Double => '=',
+            Bond::Triple => '#',
+            Bond::Quadruple => '$',
+            Bond::Aromatic => ':',
+        }
+    }
+
+    fn from_symbol(symbol: u8) -> Option<Bond> {
+        match symbol {
+            // the directional bonds ``/`` and ``\`` are single bonds
+            b'-' | b'/' | b'\\' => Some(Bond::Single),
+            b'=' => Some(Bond::Double),
+            b'#' => Some(Bond::Triple),
+            b'$' => Some(Bond::Quadruple),
+            b':' => Some(Bond::Aromatic),
+            _ => None,
+        }
+    }
+}
+
+fn syntax_error(position: usize, reason: &'static str) -> InvalidInputError {
+    InvalidInputError::InvalidSyntax { position, reason }
+}
+
+/// Generate a graph from a SMILES string.
+///
+/// Every atom of the molecule is a node and every bond an edge. The subset
+/// of SMILES [1] supported is:
+///
+/// * Atoms of the organic subset `B`, `C`, `N`, `O`, `P`, `S`, `F`, `Cl`,
+///   `Br` and `I`, their aromatic forms `b`, `c`, `n`, `o`, `p` and `s`,
+///   the wildcard `*`, and any atom in brackets such as `[NH4+]`.
+/// * The bonds `-`, `=`, `#`, `$` and `:`, and `/` and `\` which are read as
+///   single bonds. A bond which isn't written is aromatic between two
+///   aromatic atoms and single otherwise.
+/// * Branches in parentheses, ring bonds written as a digit or `%` followed
+///   by two digits, and `.` to separate disconnected parts.
+///
+/// Hydrogen atoms which aren't written aren't added, and the contents of
+/// brackets (isotope, chirality, hydrogen count and charge) aren't
+/// interpreted.
+///
+/// Arguments:
+///
+/// * `smiles` - The SMILES string to parse
+/// * `node_weight` - A callable that receives the text of an atom, its
+///   symbol or the contents of its brackets, and returns the weight of its
+///   node.
+/// * `edge_weight` - A callable that receives a [`Bond`] and returns the
+///   weight of its edge.
+///
+/// An [`InvalidInputError::InvalidSyntax`] with the position (in bytes) of
+/// the error is returned if the string isn't valid.
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::generators::{smiles_graph, Bond};
+///
+/// // Acetic acid
+/// let g: petgraph::graph::UnGraph<String, Bond> =
+///     smiles_graph("CC(=O)O", |atom| atom.to_string(), |bond| bond).unwrap();
+/// assert_eq!(g.node_weights().collect::<Vec<_>>(), vec!["C", "C", "O", "O"]);
+/// assert_eq!(
+///     g.raw_edges().iter().map(|e| (e.source().index(), e.target().index(), e.weight))
+///         .collect::<Vec<_>>(),
+///     vec![(0, 1, Bond::Single), (1, 2, Bond::Double), (1, 3, Bond::Single)],
+/// );
+///
+/// // Benzene
+/// let g: petgraph::graph::UnGraph<String, Bond> =
+///     smiles_graph("c1ccccc1", |atom| atom.to_string(), |bond| bond).unwrap();
+/// assert_eq!(g.edge_count(), 6);
+/// assert!(g.edge_weights().all(|bond| *bond == Bond::Aromatic));
+/// ```
+///
+/// [1] OpenSMILES specification, <http://opensmiles.org/opensmiles.html>
+pub fn smiles_graph<G, T, F, H, M>(
+    smiles: &str,
+    mut node_weight: F,
+    mut edge_weight: H,
+) -> Result<G, InvalidInputError>
+where
+    G: Build + Create + Data<NodeWeight = T, EdgeWeight = M> + NodeIndexable,
+    F: FnMut(&str) -> T,
+    H: FnMut(Bond) -> M,
+{
+    let bytes = smiles.as_bytes();
+    let mut graph = G::with_capacity(bytes.len(), bytes.len());
+    // whether each node (in creation order) is an aromatic atom
+    let mut aromatic: Vec<bool> = Vec::new();
+    let mut nodes: Vec<G::NodeId> = Vec::new();
+    // the atom the next atom is bonded to, and the bond if it's written
+    let mut previous: Option<usize> = None;
+    let mut bond: Option<Bond> = None;
+    let mut branches: Vec<usize> = Vec::new();
+    let mut rings: HashMap<u32, (usize, Option<Bond>)> = HashMap::new();
+    let default_bond = |aromatic: &[bool], u: usize, v: usize| {
+        if aromatic[u] && aromatic[v] {
+            Bond::Aromatic
+        } else {
+            Bond::Single
+        }
+    };
+
+    let mut position = 0;
+    while position < bytes.len() {
+        let start = position;
+        let c = bytes[position];
+        position += 1;
+        let atom: Option<(&str, bool)> = match c {
+            b'[' => {
+                let end = match bytes[position..].iter().position(|b| *b == b']') {
+                    Some(offset) => position + offset,
+                    None => return Err(syntax_error(start, "unclosed '['")),
+                };
+                let text = &smiles[position..end];
+                position = end + 1;
+                match text.bytes().find(|b| !b.is_ascii_digit()) {
+                    Some(b) if b.is_ascii_alphabetic() || b == b'*' => {
+                        Some((text, b.is_ascii_lowercase()))
+                    }
+                    _ => return Err(syntax_error(start, "expected an element in brackets")),
+                }
+            }
+            b'B' | b'C' => {
+                let two = match (c, bytes.get(position)) {
+                    (b'B', Some(b'r')) | (b'C', Some(b'l')) => 2,
+                    _ => 1,
+                };
+                position = start + two;
+                Some((&smiles[start..position], false))
+            }
+            b'N' | b'O' | b'P' | b'S' | b'F' | b'I' | b'*' => {
+                Some((&smiles[start..position], false))
+            }
+            b'b' | b'c' | b'n' | b'o' | b'p' | b's' => Some((&smiles[start..position], true)),
+            _ => None,
+        };
+        if let Some((text, is_aromatic)) = atom {
+            let node = nodes.len();
+            nodes.push(graph.add_node(node_weight(text)));
+            aromatic.push(is_aromatic);
+            match previous {
+                Some(previous) => {
+                    let kind = bond
+                        .take()
+                        .unwrap_or_else(|| default_bond(&aromatic, previous, node));
+                    graph.add_edge(nodes[previous], nodes[node], edge_weight(kind));
+                }
+                None if bond.is_some() => {
+                    return Err(syntax_error(start, "bond without a preceding atom"))
+                }
+                None => (),
+            }
+            previous = Some(node);
+            continue;
+        }
+        if let Some(kind) = Bond::from_symbol(c) {
+            if previous.is_none() {
+                return Err(syntax_error(start, "bond without a preceding atom"));
+            }
+            if bond.is_some() {
+                return Err(syntax_error(start, "two bonds in a row"));
+            }
+            bond = Some(kind);
+            continue;
+        }
+        match c {
+            b'(' => match previous {
+                Some(previous) if bond.is_none() => branches.push(previous),
+                _ => return Err(syntax_error(start, "branch without a preceding atom")),
+            },
+            b')' => {
+                if bond.is_some() {
+                    return Err(syntax_error(start, "bond without a following atom"));
+                }
+                match branches.pop() {
+                    Some(node) => previous = Some(node),
+                    None => return Err(syntax_error(start, "unmatched ')'")),
+                }
+            }
+            b'.' => {
+                if bond.is_some() {
+                    return Err(syntax_error(start, "bond without a following atom"));
+                }
+                previous = None;
+            }
+            b'0'..=b'9' | b'%' => {
+                let number = if c == b'%' {
+                    match bytes.get(position..position + 2) {
+                        Some(digits) if digits.iter().all(|b| b.is_ascii_digit()) => {
+                            position += 2;
+                            (digits[0] - b'0') as u32 * 10 + (digits[1] - b'0') as u32
+                        }
+                        _ => return Err(syntax_error(start, "expected two digits after '%'")),
+                    }
+                } else {
+                    (c - b'0') as u32
+                };
+                let node = match previous {
+                    Some(node) => node,
+                    None => return Err(syntax_error(start, "ring bond without a preceding atom")),
+                };
+                match rings.remove(&number) {
+                    Some((other, other_bond)) => {
+                        if other == node {
+                            return Err(syntax_error(start, "ring bond to the same atom"));
+                        }
+                        let kind = match (bond.take(), other_bond) {
+                            (Some(a), Some(b)) if a != b => {
+                                return Err(syntax_error(start, "conflicting ring bonds"))
+                            }
+                            (Some(kind), _) | (None, Some(kind)) => kind,
+                            (None, None) => default_bond(&aromatic, other, node),
+                        };
+                        graph.add_edge(nodes[other], nodes[node], edge_weight(kind));
+                    }
+                    None => {
+                        rings.insert(number, (node, bond.take()));
+                    }
+                }
+            }
+            _ => return Err(syntax_error(start, "unexpected character")),
+        }
+    }
+    if bond.is_some() {
+        return Err(syntax_error(bytes.len(), "bond without a following atom"));
+    }
+    if !branches.is_empty() {
+        return Err(syntax_error(bytes.len(), "unclosed '('"));
+    }
+    if !rings.is_empty() {
+        return Err(syntax_error(bytes.len(), "unclosed ring bond"));
+    }
+    Ok(graph)
+}
@@ -0,0 +1,513 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+//! Encoding and decoding of the graph6, sparse6 and digraph6 formats [1]
+//! used by nauty and its tools like `geng`.
+//!
+//! [1] <https://users.cecs.anu.edu.au/~bdm/data/formats.txt>
+
+use fixedbitset::FixedBitSet;
+
+use petgraph::data::{Build, Create};
+use petgraph::visit::{
+    Data, EdgeRef, GraphProp, IntoEdgeReferences, IntoNodeIdentifiers, NodeIndexable,
+};
+use petgraph::EdgeType;
+
+use crate::err::InvalidInputError;
+
+/// The format of an encoded graph.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Graph6Format {
+    /// An undirected simple graph, the adjacency matrix is stored.
+    Graph6,
+    /// An undirected graph with self loops and parallel edges, the list of
+    /// edges is stored. It's more compact than graph6 for sparse graphs.
+    Sparse6,
+    /// A directed graph with self loops, the adjacency matrix is stored.
+    Digraph6,
+}
+
+impl Graph6Format {
+    /// Return the optional header of the format, like `>>graph6<<`.
+    pub fn header(&self) -> &'static str {
+        match self {
+            Graph6Format::Graph6 => ">>graph6<<",
+            Graph6Format::Sparse6 => ">>sparse6<<",
+            Graph6Format::Digraph6 => ">>digraph6<<",
+        }
+    }
+
+    /// Return the format of the encoded graph `data`, found from its first
+    /// character after leading whitespace and the optional header.
+    pub fn detect(data: &[u8]) -> Graph6Format {
+        let start = data
+            .iter()
+            .take_while(|byte| byte.is_ascii_whitespace())
+            .count();
+        match data.get(start + strip_header(&data[start..])) {
+            Some(b':') => Graph6Format::Sparse6,
+            Some(b'&') => Graph6Format::Digraph6,
+            _ => Graph6Format::Graph6,
+        }
+    }
+}
+
+/// Return the position of the data after the optional header.
+fn strip_header(data: &[u8]) -> usize {
+    [
+        Graph6Format::Graph6,
+        Graph6Format::Sparse6,
+        Graph6Format::Digraph6,
+    ]
+    .iter()
+    .map(|format| format.header().as_bytes())
+    .find(|header| data.starts_with(header))
+    .map_or(0, |header| header.len())
+}
+
+fn syntax_error(position: usize, reason: &'static str) -> InvalidInputError {
+    InvalidInputError::InvalidSyntax { position, reason }
+}
+
+/// Writes bits as characters of 6 bits each, from `?` to `~`.
+struct SixBitWriter {
+    out: Vec<u8>,
+    current: u8,
+    len: u8,
+}
+
+impl SixBitWriter {
+    fn new(prefix: &[u8], num_nodes: usize) -> Self {
+        let mut out = prefix.to_vec();
+        if num_nodes <= 62 {
+            out.push(num_nodes as u8 + 63);
+        } else if num_nodes <= 258047 {
+            out.push(126);
+            out.extend(
+                (0..3)
+                    .rev()
+                    .map(|i| ((num_nodes >> (6 * i)) & 63) as u8 + 63),
+            );
+        } else {
+            out.extend([126, 126].iter());
+            out.extend(
+                (0..6)
+                    .rev()
+                    .map(|i| ((num_nodes >> (6 * i)) & 63) as u8 + 63),
+            );
+        }
+        SixBitWriter {
+            out,
+            current: 0,
+            len: 0,
+        }
+    }
+
+    fn push(&mut self, bit: bool) {
+        self.current = (self.current << 1) | bit as u8;
+        self.len += 1;
+        if self.len == 6 {
+            self.out.push(self.current + 63);
+            self.current = 0;
+            self.len = 0;
+        }
+    }
+
+    fn push_value(&mut self, value: usize, bits: usize) {
+        for i in (0..bits).rev() {
+            self.push((value >> i) & 1 == 1);
+        }
+    }
+
+    /// The number of bits needed to complete the last character.
+    fn padding(&self) -> usize {
+        (6 - self.len as usize) % 6
+    }
+
+    fn finish(mut self, pad: bool) -> String {
+        while self.len != 0 {
+            self.push(pad);
+        }
+        // Every character is in the range `?` to `~`
+        String::from_utf8(self.out).unwrap()
+    }
+}
+
+/// Reads the bits of characters of 6 bits each.
+struct SixBitReader<'a> {
+    data: &'a [u8],
+    position: usize,
+    len: u8,
+}
+
+impl<'a> SixBitReader<'a> {
+    fn bit(&mut self) -> Option<bool> {
+        if self.len == 0 {
+            if self.position >= self.data.len() {
+                return None;
+            }
+            self.position += 1;
+            self.len = 6;
+        }
+        self.len -= 1;
+        Some(((self.data[self.position - 1] - 63) >> self.len) & 1 == 1)
+    }
+
+    fn value(&mut self, bits: usize) -> Option<usize> {
+        let mut value = 0;
+        for _ in 0..bits {
+            value = (value << 1) | self.bit()? as usize;
+        }
+        Some(value)
+    }
+}
+
+/// Return the number of nodes and the position after it.
+fn read_num_nodes(data: &[u8], start: usize) -> Result<(usize, usize), InvalidInputError> {
+    let value = |from: usize, len: usize| -> Result<usize, InvalidInputError> {
+        let bytes = data
+            .get(from..from + len)
+            .ok_or_else(|| syntax_error(data.len(), "expected the number of nodes"))?;
+        Ok(bytes
+            .iter()
+            .fold(0, |value, byte| (value << 6) | (byte - 63) as usize))
+    };
+    let (num_nodes, end) = match (data.get(start), data.get(start + 1)) {
+        (Some(126), Some(126)) => (value(start + 2, 6)?, start + 8),
+        (Some(126), _) => (value(start + 1, 3)?, start + 4),
+        (Some(_), _) => (value(start, 1)?, start + 1),
+        (None, _) => return Err(syntax_error(start, "expected the number of nodes")),
+    };
+    // the 6 byte form can encode up to 2^36 - 1 nodes, more than a graph
+    // with the default u32 indices can hold
+    if num_nodes > u32::MAX as usize {
+        return Err(syntax_error(start, "too many nodes"));
+    }
+    Ok((num_nodes, end))
+}
+
+/// The number of bits needed to write a node index of a sparse6 graph.
+fn sparse6_bits(num_nodes: usize) -> usize {
+    let mut bits = 1;
+    while (1 << bits) < num_nodes {
+        bits += 1;
+    }
+    bits
+}
+
+/// Generate a graph from graph6, sparse6 or digraph6 data.
+///
+/// The format is found from the first character of `data`, `:` for sparse6,
+/// `&` for digraph6 and any other for graph6, and the optional header like
+/// `>>graph6<<` is skipped. Leading and trailing whitespace, like the
+/// newline ending a line of a file, is ignored. The graph has a node for
+/// every node index of the encoded graph, in the same order. A graph6 or
+/// sparse6 graph can be generated into a directed graph, in which case each
+/// edge goes from its lower node index to its higher one.
+///
+/// Arguments:
+///
+/// * `data` - The encoded graph
+/// * `default_node_weight` - A callable that will return the weight to use
+///   for newly created nodes.
+/// * `default_edge_weight` - A callable that will return the weight object
+///   to use for newly created edges.
+///
+/// An [`InvalidInputError::InvalidSyntax`] with the position (in bytes) of
+/// the error is returned if the data isn't valid or has more than
+/// `u32::MAX` nodes, and an [`InvalidInputError::InvalidParameter`] if
+/// digraph6 data is generated into an undirected graph.
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::graph6::from_graph6;
+///
+/// // A path of 3 nodes
+/// let g: petgraph::graph::UnGraph<(), ()> = from_graph6(b"Bg", || (), || ()).unwrap();
+/// assert_eq!(g.node_count(), 3);
+/// assert_eq!(
+///     g.edge_indices()
+///         .map(|e| g.edge_endpoints(e).unwrap())
+///         .map(|(a, b)| (a.index(), b.index()))
+///         .collect::<Vec<_>>(),
+///     vec![(0, 1), (1, 2)],
+/// );
+/// ```
+pub fn from_graph6<G, T, F, H, M>(
+    data: &[u8],
+    mut default_node_weight: F,
+    mut default_edge_weight: H,
+) -> Result<G, InvalidInputError>
+where
+    G: Build + Create + Data<NodeWeight = T, EdgeWeight = M> + GraphProp,
+    F: FnMut() -> T,
+    H: FnMut() -> M,
+{
+    let end = data.len()
+        - data
+            .iter()
+            .rev()
+            .take_while(|byte| byte.is_ascii_whitespace())
+            .count();
+    let data = &data[..end];
+    let mut start = data
+        .iter()
+        .take_while(|byte| byte.is_ascii_whitespace())
+        .count();
+    start += strip_header(&data[start..]);
+    let format = Graph6Format::detect(&data[start..]);
+    if format != Graph6Format::Graph6 {
+        start += 1;
+    }
+    if format == Graph6Format::Digraph6 && !G::EdgeType::is_directed() {
+        return Err(InvalidInputError::InvalidParameter {
+            name: "data",
+            reason: "digraph6 data can only be generated into a directed graph",
+        });
+    }
+    if let Some(offset) = data[start..]
+        .iter()
+        .position(|byte| !(63..=126).contains(byte))
+    {
+        return Err(syntax_error(
+            start + offset,
+            "expected a character from '?' to '~'",
+        ));
+    }
+    let (num_nodes, start) = read_num_nodes(data, start)?;
+    let mut reader = SixBitReader {
+        data,
+        position: start,
+        len: 0,
+    };
+    let mut edges: Vec<(usize, usize)> = Vec::new();
+    match format {
+        Graph6Format::Graph6 | Graph6Format::Digraph6 => {
+            let num_bits = if format == Graph6Format::Graph6 {
+                num_nodes
+                    .checked_mul(num_nodes.saturating_sub(1))
+                    .map(|bits| bits / 2)
+            } else {
+                num_nodes.checked_mul(num_nodes)
+            }
+            .ok_or_else(|| syntax_error(start, "too many nodes"))?;
+            if data.len() - start != num_bits / 6 + (num_bits % 6 != 0) as usize {
+                return Err(syntax_error(
+                    data.len(),
+                    "the length of the data doesn't match the number of nodes",
+                ));
+            }
+            if format == Graph6Format::Graph6 {
+                for target in 1..num_nodes {
+                    for source in 0..target {
+                        if reader.bit() == Some(true) {
+                            edges.push((source, target));
+                        }
+                    }
+                }
+            } else {
+                for source in 0..num_nodes {
+                    for target in 0..num_nodes {
+                        if reader.bit() == Some(true) {
+                            edges.push((source, target));
+                        }
+                    }
+                }
+            }
+        }
+        Graph6Format::Sparse6 => {
+            let bits = sparse6_bits(num_nodes);
+            let mut current = 0;
+            while let Some(bit) = reader.bit() {
+                let node = match reader.value(bits) {
+                    Some(node) => node,
+                    None => break,
+                };
+                if bit {
+                    current += 1;
+                }
+                // The padding at the end can only read as a node index out
+                // of range
+                if node >= num_nodes || current >= num_nodes {
+                    break;
+                }
+                if node > current {
+                    current = node;
+                } else {
+                    edges.push((node, current));
+                }
+            }
+        }
+    }
+
+    let mut graph = G::with_capacity(num_nodes, edges.len());
+    let nodes: Vec<G::NodeId> = (0..num_nodes)
+        .map(|_| graph.add_node(default_node_weight()))
+        .collect();
+    for (source, target) in edges {
+        graph.add_edge(nodes[source], nodes[target], default_edge_weight());
+    }
+    Ok(graph)
+}
+
+/// Return the position of every node in the order of the node
+/// identifiers, indexed by the node index, and the number of nodes.
+fn node_positions<G>(graph: G) -> (Vec<usize>, usize)
+where
+    G: IntoNodeIdentifiers + NodeIndexable,
+{
+    let mut positions = vec![0; graph.node_bound()];
+    let mut num_nodes = 0;
+    for node in graph.node_identifiers() {
+        positions[graph.to_index(node)] = num_nodes;
+        num_nodes += 1;
+    }
+    (positions, num_nodes)
+}
+
+/// Encode a graph in the graph6 format, without the optional header.
+///
+/// The nodes are numbered from 0 in the order of their identifiers. The
+/// graph6 format only stores undirected simple graphs, so the direction of
+/// the edges is ignored, self loops are dropped and parallel edges are
+/// stored once.
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::graph6::to_graph6;
+///
+/// let g = petgraph::graph::UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2)]);
+/// assert_eq!(to_graph6(&g), "Bg");
+/// ```
+pub fn to_graph6<G>(graph: G) -> String
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable,
+{
+    let (positions, num_nodes) = node_positions(graph);
+    // The bits of the upper triangle of the adjacency matrix, column by
+    // column
+    let mut matrix = FixedBitSet::with_capacity(num_nodes * num_nodes.saturating_sub(1) / 2);
+    for edge in graph.edge_references() {
+        let source = positions[graph.to_index(edge.source())];
+        let target = positions[graph.to_index(edge.target())];
+        let (source, target) = if source < target {
+            (source, target)
+        } else {
+            (target, source)
+        };
+        if source != target {
+            matrix.insert(target * (target - 1) / 2 + source);
+        }
+    }
+    let mut writer = SixBitWriter::new(b"", num_nodes);
+    for bit in 0..matrix.len() {
+        writer.push(matrix[bit]);
+    }
+    writer.finish(false)
+}
+
+/// Encode a graph in the digraph6 format, without the optional header.
+///
+/// The nodes are numbered from 0 in the order of their identifiers. The
+/// digraph6 format stores directed graphs with self loops, so parallel
+/// edges are stored once, and every edge of an undirected graph is stored
+/// in both directions.
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::graph6::to_digraph6;
+///
+/// let g = petgraph::graph::DiGraph::<(), ()>::from_edges(&[(0, 1), (1, 2)]);
+/// assert_eq!(to_digraph6(&g), "&BP?");
+/// ```
+pub fn to_digraph6<G>(graph: G) -> String
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable + GraphProp,
+{
+    let (positions, num_nodes) = node_positions(graph);
+    let mut matrix = FixedBitSet::with_capacity(num_nodes * num_nodes);
+    for edge in graph.edge_references() {
+        let source = positions[graph.to_index(edge.source())];
+        let target = positions[graph.to_index(edge.target())];
+        matrix.insert(source * num_nodes + target);
+        if !graph.is_directed() {
+            matrix.insert(target * num_nodes + source);
+        }
+    }
+    let mut writer = SixBitWriter::new(b"&", num_nodes);
+    for bit in 0..matrix.len() {
+        writer.push(matrix[bit]);
+    }
+    writer.finish(false)
+}
+
+/// Encode a graph in the sparse6 format, without the optional header.
+///
+/// The nodes are numbered from 0 in the order of their identifiers. The
+/// sparse6 format stores undirected graphs with self loops and parallel
+/// edges, so only the direction of the edges is ignored.
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::graph6::to_sparse6;
+///
+/// let g = petgraph::graph::UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (1, 2)]);
+/// assert_eq!(to_sparse6(&g), ":BdN");
+/// ```
+pub fn to_sparse6<G>(graph: G) -> String
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable,
+{
+    let (positions, num_nodes) = node_positions(graph);
+    let mut edges: Vec<(usize, usize)> = graph
+        .edge_references()
+        .map(|edge| {
+            let source = positions[graph.to_index(edge.source())];
+            let target = positions[graph.to_index(edge.target())];
+            if source < target {
+                (target, source)
+            } else {
+                (source, target)
+            }
+        })
+        .collect();
+    edges.sort_unstable();
+    let bits = sparse6_bits(num_nodes);
+    let mut writer = SixBitWriter::new(b":", num_nodes);
+    let mut current = 0;
+    for (larger, smaller) in edges {
+        if larger == current {
+            writer.push(false);
+        } else if larger == current + 1 {
+            current = larger;
+            writer.push(true);
+        } else {
+            current = larger;
+            writer.push(true);
+            writer.push_value(larger, bits);
+            writer.push(false);
+        }
+        writer.push_value(smaller, bits);
+    }
+    // Padding with ones could read as an extra edge to the last node, when
+    // the number of nodes is a power of two and the padding holds a node
+    // index, a zero bit first avoids it
+    if bits < 6 && num_nodes == 1 << bits && writer.padding() >= bits && current + 1 < num_nodes {
+        writer.push(false);
+    }
+    writer.finish(true)
+}
@@ -0,0 +1,340 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+//! Module for Hamiltonian path search.
+//!
+//! A Hamiltonian path visits every node of a graph exactly once. Deciding
+//! whether a graph has one is NP-complete, so the search in this module is
+//! a backtracking search which prunes the partial paths that can't be
+//! completed and can be bounded by a number of expansions or a time limit.
+
+use std::error::Error;
+use std::fmt;
+use std::time::{Duration, Instant};
+
+use petgraph::visit::{GraphProp, IntoNeighborsDirected, IntoNodeIdentifiers, NodeIndexable};
+use petgraph::{Incoming, Outgoing};
+
+/// Error returned by [`hamiltonian_path`] when the search reached its
+/// expansion or time limit before finding a path or proving that there is
+/// none.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BudgetExhausted;
+
+impl Error for BudgetExhausted {}
+
+impl fmt::Display for BudgetExhausted {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "The search budget was exhausted")
+    }
+}
+
+/// The state of the search on the nodes indexed from 0 in the order of the
+/// node identifiers.
+struct Search {
+    successors: Vec<Vec<usize>>,
+    predecessors: Vec<Vec<usize>>,
+    target: Option<usize>,
+    path: Vec<usize>,
+    on_path: Vec<bool>,
+    expansions: usize,
+    max_expansions: Option<usize>,
+    deadline: Option<Instant>,
+}
+
+impl Search {
+    /// Extend the path with `node`, or return an error if it exceeds the
+    /// budget.
+    fn push(&mut self, node: usize) -> Result<(), BudgetExhausted> {
+        self.expansions += 1;
+        if let Some(max_expansions) = self.max_expansions {
+            if self.expansions > max_expansions {
+                return Err(BudgetExhausted);
+            }
+        }
+        if let Some(deadline) = self.deadline {
+            if Instant::now() >= deadline {
+                return Err(BudgetExhausted);
+            }
+        }
+        self.path.push(node);
+        self.on_path[node] = true;
+        Ok(())
+    }
+
+    fn pop(&mut self) {
+        if let Some(node) = self.path.pop() {
+            self.on_path[node] = false;
+        }
+    }
+
+    /// Whether the path ending at `current` can still be completed as far
+    /// as the degree and connectivity checks can tell: every node left must
+    /// have a way in and a way out, and be reachable from `current` through
+    /// the nodes left.
+    fn feasible(&self, current: usize) -> bool {
+        let mut only_after_current = 0;
+        let mut ends = 0;
+        for node in 0..self.on_path.len() {
+            if self.on_path[node] {
+                continue;
+            }
+            if !self.predecessors[node]
+                .iter()
+                .any(|&pred| !self.on_path[pred])
+            {
+                // It has to come right after the current node
+                if self.predecessors[node].binary_search(&current).is_err() {
+                    return false;
+                }
+                only_after_current += 1;
+                if only_after_current > 1 {
+                    return false;
+                }
+            }
+            if !self.successors[node]
+                .iter()
+                .any(|&succ| !self.on_path[succ])
+            {
+                // It has to be the end of the path
+                if matches!(self.target, Some(target) if target != node) {
+                    return false;
+                }
+                ends += 1;
+                if ends > 1 {
+                    return false;
+                }
+            }
+        }
+        let mut reached = vec![false; self.on_path.len()];
+        let mut stack = vec![current];
+        let mut count = 0;
+        while let Some(node) = stack.pop() {
+            for &succ in &self.successors[node] {
+                if !self.on_path[succ] && !reached[succ] {
+                    reached[succ] = true;
+                    count += 1;
+                    stack.push(succ);
+                }
+            }
+        }
+        count == self.on_path.len() - self.path.len()
+    }
+
+    /// The nodes the path can be extended with after `current`, in the
+    /// reverse of the order they're tried in: the ones with the fewest
+    /// successors left first (Warnsdorff's rule). The target is only used
+    /// as the last node.
+    fn candidates(&self, current: usize) -> Vec<usize> {
+        let last = self.path.len() + 1 == self.on_path.len();
+        let mut candidates: Vec<(usize, usize)> = self.successors[current]
+            .iter()
+            .filter(|&&succ| !self.on_path[succ] && (last || self.target != Some(succ)))
+            .map(|&succ| {
+                let degree = self.successors[succ]
+                    .iter()
+                    .filter(|&&next| !self.on_path[next])
+                    .count();
+                (degree, succ)
+            })
+            .collect();
+        candidates.sort_unstable_by(|a, b| b.cmp(a));
+        candidates.into_iter().map(|(_, node)| node).collect()
+    }
+
+    /// Backtrack from `start`, returning the first Hamiltonian path found.
+    fn run(&mut self, start: usize) -> Result<Option<Vec<usize>>, BudgetExhausted> {
+        let n = self.on_path.len();
+        if self.target == Some(start) && n > 1 {
+            return Ok(None);
+        }
+        self.push(start)?;
+        if n == 1 {
+            return Ok(Some(self.path.clone()));
+        }
+        let mut stack: Vec<Vec<usize>> = Vec::new();
+        if self.feasible(start) {
+            stack.push(self.candidates(start));
+        } else {
+            self.pop();
+        }
+        while !stack.is_empty() {
+            let next = stack.last_mut().and_then(|candidates| candidates.pop());
+            match next {
+                Some(next) => {
+                    self.push(next)?;
+                    if self.path.len() == n {
+                        return Ok(Some(self.path.clone()));
+                    }
+                    if self.feasible(next) {
+                        stack.push(self.candidates(next));
+                    } else {
+                        self.pop();
+                    }
+                }
+                None => {
+                    stack.pop();
+                    self.pop();
+                }
+            }
+        }
+        Ok(None)
+    }
+}
+
+/// Search for a Hamiltonian path, a path visiting every node of the graph
+/// exactly once.
+///
+/// This is a backtracking search: the path is extended one node at a time
+/// and the last node is replaced by the next candidate when the path can't
+/// be completed. Before a path is extended it's checked that every node
+/// left still has an edge from the path or another node left, an edge to
+/// another node left unless it's the only possible end of the path, and
+/// can be reached from the end of the path through the nodes left, so the
+/// partial paths which can't be completed are mostly pruned early. The
+/// candidates with the fewest edges to the nodes left are tried first
+/// (Warnsdorff's rule). The search still takes exponential time in the
+/// worst case, so it can be bounded with `max_expansions` and `timeout`.
+///
+/// Arguments:
+///
+/// * `graph` - The graph to search in. If it's directed the path follows
+///   the direction of the edges.
+/// * `source` - The first node of the path, if it's `None` any node can be
+///   the first node
+/// * `target` - The last node of the path, if it's `None` any node can be
+///   the last node
+/// * `max_expansions` - The maximum number of times a path is extended with
+///   a node, if it's `None` there is no limit
+/// * `timeout` - The maximum duration of the search, if it's `None` there
+///   is no limit
+///
+/// Returns the nodes of a path in order, or `None` if there is no such
+/// path. If the search reached `max_expansions` or `timeout` before finding
+/// a path or proving that there is none, [`BudgetExhausted`] is returned.
+///
+/// # Panics
+///
+/// If `source` or `target` isn't a node of `graph`.
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::hamiltonian::hamiltonian_path;
+///
+/// // A cycle with a chord
+/// let graph = petgraph::graph::UnGraph::<(), ()>::from_edges(&[
+///     (0, 1), (1, 2), (2, 3), (3, 4), (4, 5), (5, 0), (0, 3),
+/// ]);
+/// let path = hamiltonian_path(&graph, Some(0.into()), Some(1.into()), None, None);
+/// let nodes: Vec<usize> = path.unwrap().unwrap().iter().map(|n| n.index()).collect();
+/// assert_eq!(nodes, vec![0, 5, 4, 3, 2, 1]);
+/// // Every path between the ends of the chord misses a node
+/// let path = hamiltonian_path(&graph, Some(0.into()), Some(3.into()), None, None);
+/// assert_eq!(path, Ok(None));
+/// // The star has no Hamiltonian path
+/// let star = petgraph::graph::UnGraph::<(), ()>::from_edges(&[(0, 1), (0, 2), (0, 3)]);
+/// assert_eq!(hamiltonian_path(&star, None, None, None, None), Ok(None));
+/// ```
+pub fn hamiltonian_path<G>(
+    graph: G,
+    source: Option<G::NodeId>,
+    target: Option<G::NodeId>,
+    max_expansions: Option<usize>,
+    timeout: Option<Duration>,
+) -> Result<Option<Vec<G::NodeId>>, BudgetExhausted>
+where
+    G: IntoNeighborsDirected + IntoNodeIdentifiers + NodeIndexable + GraphProp,
+{
+    let nodes: Vec<G::NodeId> = graph.node_identifiers().collect();
+    let n = nodes.len();
+    if n == 0 {
+        return Ok(Some(Vec::new()));
+    }
+    let mut position: Vec<Option<usize>> = vec![None; graph.node_bound()];
+    for (index, node) in nodes.iter().enumerate() {
+        position[graph.to_index(*node)] = Some(index);
+    }
+    let index_of = |node: G::NodeId| -> usize {
+        position[graph.to_index(node)].expect("The node must be a node of the graph")
+    };
+    let mut source = source.map(index_of);
+    let mut target = target.map(index_of);
+    let adjacency = |direction| -> Vec<Vec<usize>> {
+        nodes
+            .iter()
+            .enumerate()
+            .map(|(index, node)| {
+                let mut neighbors: Vec<usize> = graph
+                    .neighbors_directed(*node, direction)
+                    .map(index_of)
+                    .filter(|&neighbor| neighbor != index)
+                    .collect();
+                neighbors.sort_unstable();
+                neighbors.dedup();
+                neighbors
+            })
+            .collect()
+    };
+    let successors = adjacency(Outgoing);
+    let predecessors = adjacency(Incoming);
+
+    // A path in an undirected graph can be reversed, so the search can
+    // start from either end
+    let mut reverse = false;
+    if !graph.is_directed() && source.is_none() && target.is_some() {
+        std::mem::swap(&mut source, &mut target);
+        reverse = true;
+    }
+    let starts: Vec<usize> = match source {
+        Some(source) => vec![source],
+        None => {
+            // The path has to start at a node without predecessors, or at
+            // an end of an undirected graph
+            let forced: Vec<usize> = if graph.is_directed() {
+                (0..n)
+                    .filter(|&node| predecessors[node].is_empty())
+                    .collect()
+            } else {
+                (0..n)
+                    .filter(|&node| predecessors[node].len() <= 1)
+                    .take(1)
+                    .collect()
+            };
+            match forced.len() {
+                0 => (0..n).collect(),
+                1 => forced,
+                _ => return Ok(None),
+            }
+        }
+    };
+
+    let mut search = Search {
+        successors,
+        predecessors,
+        target,
+        path: Vec::with_capacity(n),
+        on_path: vec![false; n],
+        expansions: 0,
+        max_expansions,
+        deadline: timeout.map(|timeout| Instant::now() + timeout),
+    };
+    for start in starts {
+        if let Some(mut path) = search.run(start)? {
+            if reverse {
+                path.reverse();
+            }
+            return Ok(Some(path.into_iter().map(|node| nodes[node]).collect()));
+        }
+    }
+    Ok(None)
+}
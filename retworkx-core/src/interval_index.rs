@@ -0,0 +1,197 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+//! Module for indexing items by time intervals, such as the edges of a
+//! temporal graph which are only active during some time.
+//!
+//! The [`IntervalIndex`] answers which intervals overlap a time window
+//! without scanning all of them, so sliding a window over a large temporal
+//! graph only costs the edges found in each window.
+
+use std::cmp::Ordering;
+
+/// A static interval tree over items which are active from a start time to
+/// an end time, both included.
+///
+/// The intervals are sorted by start time and stored in an implicit
+/// balanced binary search tree over the sorted array, where every subtree
+/// knows the latest end time of its intervals. A window query visits the
+/// intervals which start before the end of the window and skips the
+/// subtrees which all end before the start of the window, so it takes
+/// `O(log(n) + k)` time to find the `k` intervals in the window among `n`.
+///
+/// The index can't be modified once built, build a new one if the intervals
+/// change.
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::interval_index::IntervalIndex;
+///
+/// let index = IntervalIndex::new(vec![
+///     ("a", 0.0, 2.0),
+///     ("b", 1.0, 5.0),
+///     ("c", 3.0, 4.0),
+///     ("d", 6.0, 8.0),
+/// ]);
+/// let mut active: Vec<&str> = index.overlapping(2.5, 3.5).map(|(key, _, _)| *key).collect();
+/// active.sort_unstable();
+/// assert_eq!(active, vec!["b", "c"]);
+/// assert_eq!(index.overlapping(5.5, 5.9).count(), 0);
+/// assert_eq!(index.containing(2.0).count(), 2);
+/// ```
+#[derive(Clone, Debug)]
+pub struct IntervalIndex<K, T> {
+    intervals: Vec<(K, T, T)>,
+    /// The latest end time of the subtree rooted at each position
+    max_end: Vec<T>,
+    /// The number of non-empty intervals, which are sorted first
+    non_empty: usize,
+}
+
+impl<K, T> IntervalIndex<K, T>
+where
+    T: PartialOrd + Copy,
+{
+    /// Build the index of the intervals `(key, start, end)`.
+    ///
+    /// An interval whose start time is after its end time, or whose times
+    /// can't be compared (such as a `NaN` float), is empty and is never
+    /// returned by a query, but it's still counted by [`len`](Self::len).
+    pub fn new<I>(intervals: I) -> Self
+    where
+        I: IntoIterator<Item = (K, T, T)>,
+    {
+        let mut intervals: Vec<(K, T, T)> = intervals.into_iter().collect();
+        // Sort the empty intervals last so they're never in a query range
+        intervals.sort_by(|a, b| match (is_empty(a), is_empty(b)) {
+            (false, false) => a.1.partial_cmp(&b.1).unwrap_or(Ordering::Equal),
+            (empty_a, empty_b) => empty_a.cmp(&empty_b),
+        });
+        let mut max_end: Vec<T> = intervals.iter().map(|interval| interval.2).collect();
+        let non_empty = intervals.iter().filter(|i| !is_empty(i)).count();
+        build_max_end(&intervals, &mut max_end, 0, non_empty);
+        IntervalIndex {
+            intervals,
+            max_end,
+            non_empty,
+        }
+    }
+
+    /// The number of intervals in the index.
+    pub fn len(&self) -> usize {
+        self.intervals.len()
+    }
+
+    /// Whether the index has no interval.
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// Iterate over the intervals `(key, start, end)` which overlap the
+    /// window from `start` to `end`, both included, in order of their start
+    /// time.
+    ///
+    /// An interval overlaps the window if it starts at or before `end` and
+    /// ends at or after `start`. If `start` is after `end` the window is
+    /// empty and nothing is returned.
+    pub fn overlapping(&self, start: T, end: T) -> impl Iterator<Item = &(K, T, T)> {
+        let mut found = Vec::new();
+        if start <= end {
+            // The intervals which start at or before the end of the window
+            // are a prefix of the sorted non-empty intervals
+            let (mut lo, mut hi) = (0, self.non_empty);
+            while lo < hi {
+                let mid = lo + (hi - lo) / 2;
+                if self.intervals[mid].1 <= end {
+                    lo = mid + 1;
+                } else {
+                    hi = mid;
+                }
+            }
+            self.collect_overlapping(start, lo, 0, self.non_empty, &mut found);
+        }
+        found.into_iter().map(move |pos| &self.intervals[pos])
+    }
+
+    /// Iterate over the intervals `(key, start, end)` which contain `time`,
+    /// in order of their start time.
+    pub fn containing(&self, time: T) -> impl Iterator<Item = &(K, T, T)> {
+        self.overlapping(time, time)
+    }
+
+    /// Iterate over all the intervals `(key, start, end)` of the index.
+    pub fn iter(&self) -> impl Iterator<Item = &(K, T, T)> {
+        self.intervals.iter()
+    }
+
+    /// Add to `found` the positions below `upper` in the subtree over the
+    /// positions `lo..hi` whose intervals end at or after `start`.
+    fn collect_overlapping(
+        &self,
+        start: T,
+        upper: usize,
+        lo: usize,
+        hi: usize,
+        found: &mut Vec<usize>,
+    ) {
+        if lo >= hi || lo >= upper {
+            return;
+        }
+        let mid = lo + (hi - lo) / 2;
+        if self.max_end[mid] < start {
+            return;
+        }
+        self.collect_overlapping(start, upper, lo, mid, found);
+        if mid < upper && self.intervals[mid].2 >= start {
+            found.push(mid);
+        }
+        self.collect_overlapping(start, upper, mid + 1, hi, found);
+    }
+}
+
+fn is_empty<K, T: PartialOrd>(interval: &(K, T, T)) -> bool {
+    !matches!(
+        interval.1.partial_cmp(&interval.2),
+        Some(Ordering::Less) | Some(Ordering::Equal)
+    )
+}
+
+/// Set the latest end time of the subtree over the positions `lo..hi` at
+/// its root `(lo + hi) / 2` and return it.
+fn build_max_end<K, T>(
+    intervals: &[(K, T, T)],
+    max_end: &mut [T],
+    lo: usize,
+    hi: usize,
+) -> Option<T>
+where
+    T: PartialOrd + Copy,
+{
+    if lo >= hi {
+        return None;
+    }
+    let mid = lo + (hi - lo) / 2;
+    let mut latest = intervals[mid].2;
+    for child in [
+        build_max_end(intervals, max_end, lo, mid),
+        build_max_end(intervals, max_end, mid + 1, hi),
+    ]
+    .iter()
+    .flatten()
+    {
+        if *child > latest {
+            latest = *child;
+        }
+    }
+    max_end[mid] = latest;
+    Some(latest)
+}
@@ -0,0 +1,338 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use petgraph::visit::{EdgeRef, GraphProp, IntoEdgeReferences, IntoNodeIdentifiers, NodeIndexable};
+
+/// An ordered partition of the nodes, each cell holds compact node indices.
+type Partition = Vec<Vec<usize>>;
+
+struct CanonicalSearch {
+    directed: bool,
+    edges: Vec<(usize, usize)>,
+    succ: Vec<Vec<usize>>,
+    pred: Vec<Vec<usize>>,
+    /// Labeling (node -> label) and certificate of the first leaf reached.
+    first: Option<(Vec<usize>, Vec<usize>)>,
+    first_path: Vec<usize>,
+    /// Labeling (node -> label) and certificate of the best leaf so far.
+    best: Option<(Vec<usize>, Vec<usize>)>,
+    best_path: Vec<usize>,
+    automorphisms: Vec<Vec<usize>>,
+}
+
+impl CanonicalSearch {
+    fn new(node_count: usize, directed: bool, edges: Vec<(usize, usize)>) -> Self {
+        let mut succ = vec![Vec::new(); node_count];
+        let mut pred = vec![Vec::new(); node_count];
+        for &(source, target) in &edges {
+            succ[source].push(target);
+            if directed {
+                pred[target].push(source);
+            } else if source != target {
+                succ[target].push(source);
+            }
+        }
+        CanonicalSearch {
+            directed,
+            edges,
+            succ,
+            pred,
+            first: None,
+            first_path: Vec::new(),
+            best: None,
+            best_path: Vec::new(),
+            automorphisms: Vec::new(),
+        }
+    }
+
+    /// Refine an ordered partition until it is equitable, that is until
+    /// all the nodes of a cell have the same number of neighbors in every
+    /// cell. Cells are split in an order that only depends on the structure
+    /// of the graph so the result is invariant under relabeling.
+    fn refine(&self, mut partition: Partition) -> Partition {
+        let mut cell_of = vec![0; self.succ.len()];
+        loop {
+            for (index, cell) in partition.iter().enumerate() {
+                for &node in cell {
+                    cell_of[node] = index;
+                }
+            }
+            let signature = |node: usize| -> (Vec<usize>, Vec<usize>) {
+                let mut out: Vec<usize> = self.succ[node].iter().map(|n| cell_of[*n]).collect();
+                let mut inc: Vec<usize> = self.pred[node].iter().map(|n| cell_of[*n]).collect();
+                out.sort_unstable();
+                inc.sort_unstable();
+                (out, inc)
+            };
+            let mut refined: Partition = Vec::with_capacity(partition.len());
+            for cell in &partition {
+                if cell.len() == 1 {
+                    refined.push(cell.clone());
+                    continue;
+                }
+                let mut keyed: Vec<_> = cell.iter().map(|&n| (signature(n), n)).collect();
+                keyed.sort_unstable();
+                let mut start = 0;
+                for end in 1..=keyed.len() {
+                    if end == keyed.len() || keyed[end].0 != keyed[start].0 {
+                        refined.push(keyed[start..end].iter().map(|(_, n)| *n).collect());
+                        start = end;
+                    }
+                }
+            }
+            if refined.len() == partition.len() {
+                return refined;
+            }
+            partition = refined;
+        }
+    }
+
+    /// The certificate of the graph relabeled by `labeling`.
+    fn certificate(&self, labeling: &[usize]) -> Vec<usize> {
+        let mut edges: Vec<(usize, usize)> = self
+            .edges
+            .iter()
+            .map(|&(source, target)| {
+                let (source, target) = (labeling[source], labeling[target]);
+                if !self.directed && target < source {
+                    (target, source)
+                } else {
+                    (source, target)
+                }
+            })
+            .collect();
+        edges.sort_unstable();
+        let mut certificate = Vec::with_capacity(3 + 2 * edges.len());
+        certificate.push(self.directed as usize);
+        certificate.push(labeling.len());
+        certificate.push(edges.len());
+        for (source, target) in edges {
+            certificate.push(source);
+            certificate.push(target);
+        }
+        certificate
+    }
+
+    /// Group the nodes in orbits of the automorphisms found so far that
+    /// fix every node in `path`.
+    fn orbits(&self, path: &[usize]) -> Vec<usize> {
+        fn find(parent: &mut [usize], mut node: usize) -> usize {
+            while parent[node] != node {
+                parent[node] = parent[parent[node]];
+                node = parent[node];
+            }
+            node
+        }
+        let mut parent: Vec<usize> = (0..self.succ.len()).collect();
+        for automorphism in &self.automorphisms {
+            if path.iter().any(|&node| automorphism[node] != node) {
+                continue;
+            }
+            for (node, &image) in automorphism.iter().enumerate() {
+                let (a, b) = (find(&mut parent, node), find(&mut parent, image));
+                if a != b {
+                    parent[a.max(b)] = a.min(b);
+                }
+            }
+        }
+        (0..parent.len())
+            .map(|node| find(&mut parent, node))
+            .collect()
+    }
+
+    /// Record the automorphism mapping the leaf `from` onto the leaf `to`.
+    fn add_automorphism(&mut self, from: &[usize], to: &[usize]) {
+        let mut node_at = vec![0; to.len()];
+        for (node, &label) in to.iter().enumerate() {
+            node_at[label] = node;
+        }
+        self.automorphisms
+            .push(from.iter().map(|&label| node_at[label]).collect());
+    }
+
+    /// Explore the search tree below `partition`. If an automorphism shows
+    /// that the rest of the subtree of an ancestor has already been explored
+    /// the depth of that ancestor is returned so the search can jump back.
+    fn search(&mut self, partition: Partition, path: &mut Vec<usize>) -> Option<usize> {
+        let target = match partition.iter().position(|cell| cell.len() > 1) {
+            Some(target) => target,
+            None => return self.leaf(&partition, path),
+        };
+        let mut explored: Vec<usize> = Vec::new();
+        for &node in &partition[target] {
+            let orbits = self.orbits(path);
+            if explored.iter().any(|&seen| orbits[seen] == orbits[node]) {
+                continue;
+            }
+            explored.push(node);
+            let mut child: Partition = Vec::with_capacity(partition.len() + 1);
+            child.extend_from_slice(&partition[..target]);
+            child.push(vec![node]);
+            child.push(
+                partition[target]
+                    .iter()
+                    .copied()
+                    .filter(|&n| n != node)
+                    .collect(),
+            );
+            child.extend_from_slice(&partition[target + 1..]);
+            let child = self.refine(child);
+            path.push(node);
+            let jump = self.search(child, path);
+            path.pop();
+            if let Some(depth) = jump {
+                if depth < path.len() {
+                    return Some(depth);
+                }
+            }
+        }
+        None
+    }
+
+    fn leaf(&mut self, partition: &[Vec<usize>], path: &[usize]) -> Option<usize> {
+        let mut labeling = vec![0; partition.len()];
+        for (label, cell) in partition.iter().enumerate() {
+            labeling[cell[0]] = label;
+        }
+        let certificate = self.certificate(&labeling);
+        let common = |other: &[usize]| path.iter().zip(other).take_while(|(a, b)| a == b).count();
+        let (first_labeling, first_certificate) = match &self.first {
+            Some(first) => first.clone(),
+            None => {
+                self.first = Some((labeling.clone(), certificate.clone()));
+                self.first_path = path.to_vec();
+                self.best = Some((labeling, certificate));
+                self.best_path = path.to_vec();
+                return None;
+            }
+        };
+        if certificate == first_certificate {
+            self.add_automorphism(&first_labeling, &labeling);
+            return Some(common(&self.first_path));
+        }
+        let (best_labeling, best_certificate) = self.best.clone().unwrap();
+        match certificate.cmp(&best_certificate) {
+            std::cmp::Ordering::Equal => {
+                self.add_automorphism(&best_labeling, &labeling);
+                Some(common(&self.best_path))
+            }
+            std::cmp::Ordering::Less => {
+                self.best = Some((labeling, certificate));
+                self.best_path = path.to_vec();
+                None
+            }
+            std::cmp::Ordering::Greater => None,
+        }
+    }
+}
+
+fn canonical_search<G>(graph: G) -> (Vec<G::NodeId>, Vec<usize>, Vec<usize>)
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable + GraphProp,
+{
+    let nodes: Vec<G::NodeId> = graph.node_identifiers().collect();
+    let mut compact = vec![usize::MAX; graph.node_bound()];
+    for (index, node) in nodes.iter().enumerate() {
+        compact[graph.to_index(*node)] = index;
+    }
+    let edges: Vec<(usize, usize)> = graph
+        .edge_references()
+        .map(|edge| {
+            (
+                compact[graph.to_index(edge.source())],
+                compact[graph.to_index(edge.target())],
+            )
+        })
+        .collect();
+    let mut search = CanonicalSearch::new(nodes.len(), graph.is_directed(), edges);
+    let partition = if nodes.is_empty() {
+        Vec::new()
+    } else {
+        search.refine(vec![(0..nodes.len()).collect()])
+    };
+    search.search(partition, &mut Vec::new());
+    let (labeling, certificate) = match search.best.take() {
+        Some(best) => best,
+        None => (Vec::new(), search.certificate(&[])),
+    };
+    (nodes, labeling, certificate)
+}
+
+/// Compute a canonical labeling of a graph.
+///
+/// Two graphs are isomorphic if and only if relabeling each of them with its
+/// canonical labeling produces exactly the same graph. The labeling is found
+/// with an individualization-refinement search in the style of nauty: the
+/// nodes are partitioned by color refinement and the search tree of
+/// individualized nodes is pruned with the automorphisms discovered along
+/// the way.
+///
+/// Only the structure of the graph is considered, node and edge weights are
+/// ignored. Parallel edges and self loops are taken into account.
+///
+/// Returns a [`Vec`] of the node ids of `graph` in canonical order, the node
+/// at position `i` gets the canonical label `i`.
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::isomorphism::canonical_labeling;
+///
+/// let graph = petgraph::graph::UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2)]);
+/// let order = canonical_labeling(&graph);
+/// // The center of the path is always placed at the same position
+/// let other = petgraph::graph::UnGraph::<(), ()>::from_edges(&[(2, 0), (0, 1)]);
+/// let other_order = canonical_labeling(&other);
+/// let center = order.iter().position(|n| n.index() == 1);
+/// let other_center = other_order.iter().position(|n| n.index() == 0);
+/// assert_eq!(center, other_center);
+/// ```
+pub fn canonical_labeling<G>(graph: G) -> Vec<G::NodeId>
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable + GraphProp,
+{
+    let (nodes, labeling, _) = canonical_search(graph);
+    let mut order = nodes.clone();
+    for (node, label) in nodes.into_iter().zip(labeling) {
+        order[label] = node;
+    }
+    order
+}
+
+/// Compute a canonical certificate of a graph.
+///
+/// The certificate is a compact encoding of the graph relabeled with its
+/// [`canonical_labeling`]: two graphs have equal certificates if and only if
+/// they are isomorphic. As it can be hashed it is useful to deduplicate a
+/// large number of graphs without pairwise isomorphism checks.
+///
+/// Only the structure of the graph is considered, node and edge weights are
+/// ignored. The certificate of a directed graph is never equal to the
+/// certificate of an undirected graph.
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::isomorphism::canonical_certificate;
+///
+/// let cycle = petgraph::graph::UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 3), (3, 0)]);
+/// let shuffled = petgraph::graph::UnGraph::<(), ()>::from_edges(&[(0, 2), (2, 1), (1, 3), (3, 0)]);
+/// let paw = petgraph::graph::UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 3), (0, 2)]);
+/// assert_eq!(canonical_certificate(&cycle), canonical_certificate(&shuffled));
+/// assert_ne!(canonical_certificate(&cycle), canonical_certificate(&paw));
+/// ```
+pub fn canonical_certificate<G>(graph: G) -> Vec<usize>
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable + GraphProp,
+{
+    canonical_search(graph).2
+}
@@ -0,0 +1,233 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+// Built-in semantic matchers for numeric weights and combinators of matchers.
+
+use petgraph::visit::Data;
+
+use super::vf2pp::{EdgeMatcher, NodeMatcher};
+
+/// Return `true` if `a` and `b` are equal within a relative tolerance
+/// `rel_tol` or an absolute tolerance `abs_tol`.
+///
+/// This is the same comparison as Python's `math.isclose`:
+/// `|a - b| <= max(rel_tol * max(|a|, |b|), abs_tol)`. With both tolerances
+/// set to 0 it's an exact comparison. NaN is never close to anything, and an
+/// infinity is only close to itself.
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::isomorphism::is_close;
+///
+/// assert!(is_close(0.1 + 0.2, 0.3, 1e-9, 0.0));
+/// assert!(!is_close(0.1 + 0.2, 0.3, 0.0, 0.0));
+/// assert!(is_close(1.0, 1.05, 0.0, 0.1));
+/// ```
+pub fn is_close(a: f64, b: f64, rel_tol: f64, abs_tol: f64) -> bool {
+    if a == b {
+        return true;
+    }
+    if a.is_infinite() || b.is_infinite() {
+        return false;
+    }
+    let diff = (a - b).abs();
+    diff <= rel_tol * a.abs().max(b.abs()) || diff <= abs_tol
+}
+
+/// A matcher comparing numeric weights within a tolerance, see
+/// [`is_close`].
+///
+/// It can be used as a [`NodeMatcher`] or an [`EdgeMatcher`] for any weight
+/// type that converts into an `f64`, so graphs with floating point weights
+/// computed in a different order can still be matched.
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::isomorphism::{subgraph_isomorphism_mappings, NoSemanticMatch, Tolerance};
+/// use retworkx_core::Result;
+///
+/// let first = petgraph::graph::UnGraph::<(), f64>::from_edges(&[(0, 1, 0.1 + 0.2)]);
+/// let second = petgraph::graph::UnGraph::<(), f64>::from_edges(&[(0, 1, 0.3)]);
+/// let exact: Vec<Result<_>> = subgraph_isomorphism_mappings(
+///     &first, &second, NoSemanticMatch, Tolerance::absolute(0.0), true
+/// ).collect();
+/// assert!(exact.is_empty());
+/// let close: Vec<Result<_>> = subgraph_isomorphism_mappings(
+///     &first, &second, NoSemanticMatch, Tolerance::absolute(1e-9), true
+/// ).collect();
+/// assert_eq!(close.len(), 2);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Tolerance {
+    /// The tolerance relative to the larger of the absolute values of the
+    /// two weights.
+    pub rel_tol: f64,
+    /// The absolute tolerance.
+    pub abs_tol: f64,
+}
+
+impl Tolerance {
+    /// A matcher with both a relative and an absolute tolerance.
+    pub fn new(rel_tol: f64, abs_tol: f64) -> Self {
+        Tolerance { rel_tol, abs_tol }
+    }
+
+    /// A matcher with only an absolute tolerance.
+    pub fn absolute(abs_tol: f64) -> Self {
+        Tolerance::new(0.0, abs_tol)
+    }
+
+    /// A matcher with only a relative tolerance.
+    pub fn relative(rel_tol: f64) -> Self {
+        Tolerance::new(rel_tol, 0.0)
+    }
+
+    /// Return `true` if `a` and `b` are equal within the tolerances.
+    pub fn is_close(&self, a: f64, b: f64) -> bool {
+        is_close(a, b, self.rel_tol, self.abs_tol)
+    }
+}
+
+impl<G0, G1, E> NodeMatcher<G0, G1, E> for Tolerance
+where
+    G0: Data,
+    G1: Data,
+    G0::NodeWeight: Copy + Into<f64>,
+    G1::NodeWeight: Copy + Into<f64>,
+{
+    #[inline]
+    fn enabled(&self) -> bool {
+        true
+    }
+    #[inline]
+    fn eq(&mut self, first: &G0::NodeWeight, second: &G1::NodeWeight) -> Result<bool, E> {
+        Ok(self.is_close((*first).into(), (*second).into()))
+    }
+}
+
+impl<G0, G1, E> EdgeMatcher<G0, G1, E> for Tolerance
+where
+    G0: Data,
+    G1: Data,
+    G0::EdgeWeight: Copy + Into<f64>,
+    G1::EdgeWeight: Copy + Into<f64>,
+{
+    #[inline]
+    fn enabled(&self) -> bool {
+        true
+    }
+    #[inline]
+    fn eq(&mut self, first: &G0::EdgeWeight, second: &G1::EdgeWeight) -> Result<bool, E> {
+        Ok(self.is_close((*first).into(), (*second).into()))
+    }
+}
+
+/// A matcher that matches when both of its matchers match.
+///
+/// The second matcher is only called if the first one matches. Nest it to
+/// combine more matchers, a disabled matcher such as
+/// [`NoSemanticMatch`](super::NoSemanticMatch) matches everything.
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::isomorphism::{subgraph_isomorphism_mappings, AllOf, NoSemanticMatch};
+/// use retworkx_core::Result;
+///
+/// let first = petgraph::graph::UnGraph::<i32, ()>::from_edges(&[(0, 1)]);
+/// let second = petgraph::graph::UnGraph::<i32, ()>::from_edges(&[(0, 1)]);
+/// let positive = |a: &i32, _: &i32| -> Result<bool> { Ok(*a >= 0) };
+/// let even = |a: &i32, _: &i32| -> Result<bool> { Ok(a % 2 == 0) };
+/// let mappings: Vec<Result<_>> = subgraph_isomorphism_mappings(
+///     &first, &second, AllOf(positive, even), NoSemanticMatch, true
+/// ).collect();
+/// assert_eq!(mappings.len(), 2);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct AllOf<A, B>(pub A, pub B);
+
+/// A matcher that matches when either of its matchers matches.
+///
+/// The second matcher is only called if the first one doesn't match. Nest it
+/// to combine more matchers, a disabled matcher such as
+/// [`NoSemanticMatch`](super::NoSemanticMatch) matches everything.
+#[derive(Clone, Copy, Debug)]
+pub struct AnyOf<A, B>(pub A, pub B);
+
+impl<G0, G1, E, A, B> NodeMatcher<G0, G1, E> for AllOf<A, B>
+where
+    G0: Data,
+    G1: Data,
+    A: NodeMatcher<G0, G1, E>,
+    B: NodeMatcher<G0, G1, E>,
+{
+    #[inline]
+    fn enabled(&self) -> bool {
+        self.0.enabled() || self.1.enabled()
+    }
+    #[inline]
+    fn eq(&mut self, first: &G0::NodeWeight, second: &G1::NodeWeight) -> Result<bool, E> {
+        Ok(self.0.eq(first, second)? && self.1.eq(first, second)?)
+    }
+}
+
+impl<G0, G1, E, A, B> EdgeMatcher<G0, G1, E> for AllOf<A, B>
+where
+    G0: Data,
+    G1: Data,
+    A: EdgeMatcher<G0, G1, E>,
+    B: EdgeMatcher<G0, G1, E>,
+{
+    #[inline]
+    fn enabled(&self) -> bool {
+        self.0.enabled() || self.1.enabled()
+    }
+    #[inline]
+    fn eq(&mut self, first: &G0::EdgeWeight, second: &G1::EdgeWeight) -> Result<bool, E> {
+        Ok(self.0.eq(first, second)? && self.1.eq(first, second)?)
+    }
+}
+
+impl<G0, G1, E, A, B> NodeMatcher<G0, G1, E> for AnyOf<A, B>
+where
+    G0: Data,
+    G1: Data,
+    A: NodeMatcher<G0, G1, E>,
+    B: NodeMatcher<G0, G1, E>,
+{
+    #[inline]
+    fn enabled(&self) -> bool {
+        self.0.enabled() && self.1.enabled()
+    }
+    #[inline]
+    fn eq(&mut self, first: &G0::NodeWeight, second: &G1::NodeWeight) -> Result<bool, E> {
+        Ok(self.0.eq(first, second)? || self.1.eq(first, second)?)
+    }
+}
+
+impl<G0, G1, E, A, B> EdgeMatcher<G0, G1, E> for AnyOf<A, B>
+where
+    G0: Data,
+    G1: Data,
+    A: EdgeMatcher<G0, G1, E>,
+    B: EdgeMatcher<G0, G1, E>,
+{
+    #[inline]
+    fn enabled(&self) -> bool {
+        self.0.enabled() && self.1.enabled()
+    }
+    #[inline]
+    fn eq(&mut self, first: &G0::EdgeWeight, second: &G1::EdgeWeight) -> Result<bool, E> {
+        Ok(self.0.eq(first, second)? || self.1.eq(first, second)?)
+    }
+}
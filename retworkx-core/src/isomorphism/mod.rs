@@ -0,0 +1,25 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+//! Module for graph isomorphism algorithms.
+
+mod canonical;
+mod matchers;
+mod vf2pp;
+mod weisfeiler_lehman;
+
+pub use canonical::{canonical_certificate, canonical_labeling};
+pub use matchers::{is_close, AllOf, AnyOf, Tolerance};
+pub use vf2pp::{
+    subgraph_isomorphism_mappings, EdgeMatcher, NoSemanticMatch, NodeMatcher, Vf2ppMappings,
+};
+pub use weisfeiler_lehman::weisfeiler_lehman_graph_hash;
@@ -0,0 +1,740 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+// This module is a generic implementation of the VF2++ algorithm from:
+//
+// Alpár Jüttner and Péter Madarasi. VF2++ — An improved subgraph
+// isomorphism algorithm. Discrete Applied Mathematics, 242:69–81, 2018.
+//
+// The node ordering heuristic is shared with the VF2 implementation used
+// by the retworkx python package, the matching itself is done over a compact
+// adjacency representation so that any petgraph graph type can be used.
+
+use std::cmp::Reverse;
+use std::hash::Hash;
+use std::marker::PhantomData;
+
+use hashbrown::HashMap;
+use petgraph::data::DataMap;
+use petgraph::visit::{
+    Data, EdgeRef, GraphProp, IntoEdgeReferences, IntoNodeIdentifiers, NodeIndexable,
+};
+
+use crate::dictmap::*;
+
+const NONE: usize = usize::MAX;
+
+/// A semantic matcher for the weights of a pair of nodes.
+///
+/// It is implemented for any closure of the form
+/// `FnMut(&G0::NodeWeight, &G1::NodeWeight) -> Result<bool, E>` and for
+/// [`NoSemanticMatch`] which disables the node weight comparison.
+pub trait NodeMatcher<G0: Data, G1: Data, E> {
+    /// Whether the matcher should be called at all.
+    fn enabled(&self) -> bool;
+    /// Return `true` if the node weight of the first graph matches the
+    /// node weight of the second graph.
+    fn eq(&mut self, first: &G0::NodeWeight, second: &G1::NodeWeight) -> Result<bool, E>;
+}
+
+/// A semantic matcher for the weights of a pair of edges.
+///
+/// It is implemented for any closure of the form
+/// `FnMut(&G0::EdgeWeight, &G1::EdgeWeight) -> Result<bool, E>` and for
+/// [`NoSemanticMatch`] which disables the edge weight comparison.
+pub trait EdgeMatcher<G0: Data, G1: Data, E> {
+    /// Whether the matcher should be called at all.
+    fn enabled(&self) -> bool;
+    /// Return `true` if the edge weight of the first graph matches the
+    /// edge weight of the second graph.
+    fn eq(&mut self, first: &G0::EdgeWeight, second: &G1::EdgeWeight) -> Result<bool, E>;
+}
+
+/// A matcher that only considers the structure of the graphs, the node and
+/// edge weights are never compared.
+#[derive(Clone, Copy, Debug)]
+pub struct NoSemanticMatch;
+
+impl<G0: Data, G1: Data, E> NodeMatcher<G0, G1, E> for NoSemanticMatch {
+    #[inline]
+    fn enabled(&self) -> bool {
+        false
+    }
+    #[inline]
+    fn eq(&mut self, _: &G0::NodeWeight, _: &G1::NodeWeight) -> Result<bool, E> {
+        Ok(true)
+    }
+}
+
+impl<G0, G1, F, E> NodeMatcher<G0, G1, E> for F
+where
+    G0: Data,
+    G1: Data,
+    F: FnMut(&G0::NodeWeight, &G1::NodeWeight) -> Result<bool, E>,
+{
+    #[inline]
+    fn enabled(&self) -> bool {
+        true
+    }
+    #[inline]
+    fn eq(&mut self, first: &G0::NodeWeight, second: &G1::NodeWeight) -> Result<bool, E> {
+        self(first, second)
+    }
+}
+
+impl<G0: Data, G1: Data, E> EdgeMatcher<G0, G1, E> for NoSemanticMatch {
+    #[inline]
+    fn enabled(&self) -> bool {
+        false
+    }
+    #[inline]
+    fn eq(&mut self, _: &G0::EdgeWeight, _: &G1::EdgeWeight) -> Result<bool, E> {
+        Ok(true)
+    }
+}
+
+impl<G0, G1, F, E> EdgeMatcher<G0, G1, E> for F
+where
+    G0: Data,
+    G1: Data,
+    F: FnMut(&G0::EdgeWeight, &G1::EdgeWeight) -> Result<bool, E>,
+{
+    #[inline]
+    fn enabled(&self) -> bool {
+        true
+    }
+    #[inline]
+    fn eq(&mut self, first: &G0::EdgeWeight, second: &G1::EdgeWeight) -> Result<bool, E> {
+        self(first, second)
+    }
+}
+
+/// Compact adjacency representation of one of the graphs being matched.
+struct GraphData<G>
+where
+    G: IntoEdgeReferences,
+{
+    graph: G,
+    /// Compact index -> node id
+    nodes: Vec<G::NodeId>,
+    /// Distinct successors of each node (all neighbors if undirected)
+    succ: Vec<Vec<usize>>,
+    /// Distinct predecessors of each node (empty if undirected)
+    pred: Vec<Vec<usize>>,
+    /// The edges between each ordered pair of nodes, pairs are stored
+    /// sorted for undirected graphs.
+    edges: HashMap<(usize, usize), Vec<G::EdgeRef>>,
+    directed: bool,
+}
+
+impl<G> GraphData<G>
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable + GraphProp,
+{
+    fn new(graph: G) -> Self {
+        let directed = graph.is_directed();
+        let nodes: Vec<G::NodeId> = graph.node_identifiers().collect();
+        let mut compact = vec![NONE; graph.node_bound()];
+        for (i, node) in nodes.iter().enumerate() {
+            compact[graph.to_index(*node)] = i;
+        }
+        let n = nodes.len();
+        let mut succ: Vec<Vec<usize>> = vec![Vec::new(); n];
+        let mut pred: Vec<Vec<usize>> = vec![Vec::new(); if directed { n } else { 0 }];
+        let mut edges: HashMap<(usize, usize), Vec<G::EdgeRef>> = HashMap::new();
+        for edge in graph.edge_references() {
+            let a = compact[graph.to_index(edge.source())];
+            let b = compact[graph.to_index(edge.target())];
+            let key = if directed || a <= b { (a, b) } else { (b, a) };
+            let entry = edges.entry(key).or_insert_with(Vec::new);
+            if entry.is_empty() {
+                succ[a].push(b);
+                if directed {
+                    pred[b].push(a);
+                } else if a != b {
+                    succ[b].push(a);
+                }
+            }
+            entry.push(edge);
+        }
+        GraphData {
+            graph,
+            nodes,
+            succ,
+            pred,
+            edges,
+            directed,
+        }
+    }
+
+    #[inline]
+    fn key(&self, a: usize, b: usize) -> (usize, usize) {
+        if self.directed || a <= b {
+            (a, b)
+        } else {
+            (b, a)
+        }
+    }
+
+    /// Number of edges from `a` to `b`.
+    #[inline]
+    fn multiplicity(&self, a: usize, b: usize) -> usize {
+        self.edges.get(&self.key(a, b)).map_or(0, |e| e.len())
+    }
+
+    #[inline]
+    fn edges_between(&self, a: usize, b: usize) -> &[G::EdgeRef] {
+        self.edges
+            .get(&self.key(a, b))
+            .map_or(&[], |e| e.as_slice())
+    }
+
+    /// VF2++ matching order: nodes are processed in BFS levels starting
+    /// from the node of largest degree, and inside a level the node with
+    /// the most connections to already ordered nodes goes first.
+    fn matching_order(&self) -> Vec<usize> {
+        let n = self.nodes.len();
+        let dout: Vec<usize> = self.succ.iter().map(|s| s.len()).collect();
+        let din: Vec<usize> = if self.directed {
+            self.pred.iter().map(|p| p.len()).collect()
+        } else {
+            vec![0; n]
+        };
+        let mut conn: Vec<usize> = vec![0; n];
+        let mut seen: Vec<bool> = vec![false; n];
+        let mut order: Vec<usize> = Vec::with_capacity(n);
+
+        let mut roots: Vec<usize> = (0..n).collect();
+        roots.sort_by_key(|&node| Reverse((dout[node] + din[node], Reverse(node))));
+        for root in roots {
+            if seen[root] {
+                continue;
+            }
+            seen[root] = true;
+            let mut level = vec![root];
+            while !level.is_empty() {
+                for i in 0..level.len() {
+                    let (index, &node) = level[i..]
+                        .iter()
+                        .enumerate()
+                        .max_by_key(|&(_, &node)| {
+                            (conn[node], dout[node], din[node], Reverse(node))
+                        })
+                        .unwrap();
+                    level.swap(i, i + index);
+                    order.push(node);
+                    for &neigh in self.neighbors(node) {
+                        conn[neigh] += 1;
+                    }
+                }
+                let mut next_level = Vec::new();
+                for &node in &level {
+                    for &neigh in self.neighbors(node) {
+                        if !seen[neigh] {
+                            seen[neigh] = true;
+                            next_level.push(neigh);
+                        }
+                    }
+                }
+                level = next_level;
+            }
+        }
+        order
+    }
+
+    /// All distinct neighbors of a node regardless of direction.
+    fn neighbors(&self, node: usize) -> impl Iterator<Item = &usize> {
+        let pred: &[usize] = if self.directed { &self.pred[node] } else { &[] };
+        self.succ[node].iter().chain(pred.iter())
+    }
+}
+
+/// Returns `true` if we can map every element of `xs` to a unique
+/// element of `ys` while using `matcher` func to compare two elements.
+fn is_subset<A: Copy, B: Copy, F, E>(xs: &[A], ys: &[B], mut matcher: F) -> Result<bool, E>
+where
+    F: FnMut(A, B) -> Result<bool, E>,
+{
+    let mut valid = vec![true; ys.len()];
+    for &a in xs {
+        let mut found = false;
+        for (&b, free) in ys.iter().zip(valid.iter_mut()) {
+            if *free && matcher(a, b)? {
+                found = true;
+                *free = false;
+                break;
+            }
+        }
+        if !found {
+            return Ok(false);
+        }
+    }
+    Ok(true)
+}
+
+/// How candidates for a node in the matching order are generated.
+#[derive(Clone, Copy)]
+enum Parent {
+    /// The node has no neighbor earlier in the matching order.
+    Root,
+    /// The node is a successor of the given (earlier) node.
+    Succ(usize),
+    /// The node is a predecessor of the given (earlier) node.
+    Pred(usize),
+}
+
+struct Frame {
+    candidates: Vec<usize>,
+    position: usize,
+    current: usize,
+}
+
+/// An iterator over the subgraph isomorphisms between two graphs, created
+/// by [`subgraph_isomorphism_mappings`].
+pub struct Vf2ppMappings<G0, G1, NM, EM, E>
+where
+    G0: IntoEdgeReferences,
+    G1: IntoEdgeReferences,
+{
+    first: GraphData<G0>,
+    second: GraphData<G1>,
+    node_match: NM,
+    edge_match: EM,
+    induced: bool,
+    order: Vec<usize>,
+    parents: Vec<Parent>,
+    /// second compact index -> first compact index
+    mapping: Vec<usize>,
+    /// first compact index -> second compact index
+    inverse: Vec<usize>,
+    stack: Vec<Frame>,
+    done: bool,
+    _error: PhantomData<fn() -> E>,
+}
+
+impl<G0, G1, NM, EM, E> Vf2ppMappings<G0, G1, NM, EM, E>
+where
+    G0: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable + GraphProp + DataMap,
+    G1: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable + GraphProp + DataMap,
+    G0::NodeId: Hash + Eq,
+{
+    fn new(first: G0, second: G1, node_match: NM, edge_match: EM, induced: bool) -> Self {
+        let first = GraphData::new(first);
+        let second = GraphData::new(second);
+        let order = second.matching_order();
+        let mut position = vec![NONE; order.len()];
+        for (i, &node) in order.iter().enumerate() {
+            position[node] = i;
+        }
+        let parents = order
+            .iter()
+            .enumerate()
+            .map(|(i, &node)| {
+                let earliest = |neighbors: &[usize]| {
+                    neighbors
+                        .iter()
+                        .filter(|&&n| position[n] < i)
+                        .min_by_key(|&&n| position[n])
+                        .copied()
+                };
+                let pred: &[usize] = if second.directed {
+                    &second.pred[node]
+                } else {
+                    &[]
+                };
+                match (earliest(&second.succ[node]), earliest(pred)) {
+                    (Some(s), Some(p)) if position[p] < position[s] => Parent::Succ(p),
+                    (Some(s), _) => Parent::Pred(s),
+                    (None, Some(p)) => Parent::Succ(p),
+                    (None, None) => Parent::Root,
+                }
+            })
+            .collect();
+        let too_large = second.nodes.len() > first.nodes.len()
+            || second.graph.edge_references().count() > first.graph.edge_references().count();
+        let mapping = vec![NONE; second.nodes.len()];
+        let inverse = vec![NONE; first.nodes.len()];
+        Vf2ppMappings {
+            first,
+            second,
+            node_match,
+            edge_match,
+            induced,
+            order,
+            parents,
+            mapping,
+            inverse,
+            stack: Vec::new(),
+            done: too_large,
+            _error: PhantomData,
+        }
+    }
+
+    /// Candidates in the first graph for the node at `depth` of the matching
+    /// order.
+    fn candidates(&self, depth: usize) -> Vec<usize> {
+        match self.parents[depth] {
+            Parent::Root => (0..self.first.nodes.len())
+                .filter(|&n| self.inverse[n] == NONE)
+                .collect(),
+            // The node is a successor of `parent` in the second graph, so
+            // its image has to be a successor of the image of `parent`.
+            Parent::Succ(parent) => self.first.succ[self.mapping[parent]]
+                .iter()
+                .copied()
+                .filter(|&n| self.inverse[n] == NONE)
+                .collect(),
+            Parent::Pred(parent) => {
+                let image = self.mapping[parent];
+                let neighbors = if self.first.directed {
+                    &self.first.pred[image]
+                } else {
+                    &self.first.succ[image]
+                };
+                neighbors
+                    .iter()
+                    .copied()
+                    .filter(|&n| self.inverse[n] == NONE)
+                    .collect()
+            }
+        }
+    }
+
+    /// Check the structural consistency of mapping `second` to `first` with
+    /// the partial mapping built so far.
+    fn is_consistent(&self, first: usize, second: usize) -> bool {
+        let f = &self.first;
+        let s = &self.second;
+        // Cutting rule: all the neighbors of a node need an image among the
+        // neighbors of its image.
+        if f.succ[first].len() < s.succ[second].len() {
+            return false;
+        }
+        if s.directed && f.pred[first].len() < s.pred[second].len() {
+            return false;
+        }
+        let self_loops = (f.multiplicity(first, first), s.multiplicity(second, second));
+        if self_loops.0 < self_loops.1 || (self.induced && self_loops.0 != self_loops.1) {
+            return false;
+        }
+        let compare = |a: usize, b: usize, n0: usize, n1: usize| {
+            let count = (f.multiplicity(a, b), s.multiplicity(n0, n1));
+            if self.induced {
+                count.0 == count.1
+            } else {
+                count.0 >= count.1
+            }
+        };
+        let mut free_succ = 0;
+        for &neigh in &s.succ[second] {
+            match self.mapping[neigh] {
+                NONE => free_succ += 1,
+                image => {
+                    if !compare(first, image, second, neigh) {
+                        return false;
+                    }
+                }
+            }
+        }
+        let mut free_pred = 0;
+        if s.directed {
+            for &neigh in &s.pred[second] {
+                match self.mapping[neigh] {
+                    NONE => free_pred += 1,
+                    image => {
+                        if !compare(image, first, neigh, second) {
+                            return false;
+                        }
+                    }
+                }
+            }
+        }
+        // Look ahead: the unmapped neighbors need distinct unmapped images
+        let unmapped = |neighbors: &[usize]| -> usize {
+            neighbors
+                .iter()
+                .filter(|&&n| n != first && self.inverse[n] == NONE)
+                .count()
+        };
+        if unmapped(&f.succ[first]) < free_succ - usize::from(self_loops.1 > 0) {
+            return false;
+        }
+        if s.directed && unmapped(&f.pred[first]) < free_pred - usize::from(self_loops.1 > 0) {
+            return false;
+        }
+        if self.induced {
+            // No extra edges are allowed between already mapped nodes.
+            for &neigh in &f.succ[first] {
+                let pre = self.inverse[neigh];
+                if pre != NONE && s.multiplicity(second, pre) == 0 {
+                    return false;
+                }
+            }
+            if f.directed {
+                for &neigh in &f.pred[first] {
+                    let pre = self.inverse[neigh];
+                    if pre != NONE && s.multiplicity(pre, second) == 0 {
+                        return false;
+                    }
+                }
+            }
+        }
+        true
+    }
+
+    /// Check the semantic feasibility of mapping `second` to `first`.
+    fn is_semantic_match(&mut self, first: usize, second: usize) -> Result<bool, E>
+    where
+        NM: NodeMatcher<G0, G1, E>,
+        EM: EdgeMatcher<G0, G1, E>,
+    {
+        if self.node_match.enabled() {
+            let first_weight = self
+                .first
+                .graph
+                .node_weight(self.first.nodes[first])
+                .unwrap();
+            let second_weight = self
+                .second
+                .graph
+                .node_weight(self.second.nodes[second])
+                .unwrap();
+            if !self.node_match.eq(first_weight, second_weight)? {
+                return Ok(false);
+            }
+        }
+        if self.edge_match.enabled() {
+            let s = &self.second;
+            let f = &self.first;
+            let edge_match = &mut self.edge_match;
+            let mut check = |a1: usize, b1: usize, a0: usize, b0: usize| -> Result<bool, E> {
+                is_subset(
+                    s.edges_between(a1, b1),
+                    f.edges_between(a0, b0),
+                    |e1: G1::EdgeRef, e0: G0::EdgeRef| edge_match.eq(e0.weight(), e1.weight()),
+                )
+            };
+            for &neigh in s.succ[second].iter() {
+                let image = if neigh == second {
+                    first
+                } else {
+                    self.mapping[neigh]
+                };
+                if image != NONE && !check(second, neigh, first, image)? {
+                    return Ok(false);
+                }
+            }
+            if s.directed {
+                for &neigh in s.pred[second].iter() {
+                    let image = self.mapping[neigh];
+                    if neigh != second && image != NONE && !check(neigh, second, image, first)? {
+                        return Ok(false);
+                    }
+                }
+            }
+        }
+        Ok(true)
+    }
+
+    fn push_mapping(&mut self, first: usize, second: usize) {
+        self.mapping[second] = first;
+        self.inverse[first] = second;
+    }
+
+    fn pop_mapping(&mut self, first: usize, second: usize) {
+        self.mapping[second] = NONE;
+        self.inverse[first] = NONE;
+    }
+
+    fn current_mapping(&self) -> DictMap<G0::NodeId, G1::NodeId> {
+        let mut result = DictMap::with_capacity(self.mapping.len());
+        for (second, &first) in self.mapping.iter().enumerate() {
+            result.insert(self.first.nodes[first], self.second.nodes[second]);
+        }
+        result
+    }
+}
+
+impl<G0, G1, NM, EM, E> Iterator for Vf2ppMappings<G0, G1, NM, EM, E>
+where
+    G0: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable + GraphProp + DataMap,
+    G1: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable + GraphProp + DataMap,
+    G0::NodeId: Hash + Eq,
+    NM: NodeMatcher<G0, G1, E>,
+    EM: EdgeMatcher<G0, G1, E>,
+{
+    type Item = Result<DictMap<G0::NodeId, G1::NodeId>, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        if self.order.is_empty() {
+            self.done = true;
+            return Some(Ok(DictMap::new()));
+        }
+        if self.stack.is_empty() {
+            self.stack.push(Frame {
+                candidates: self.candidates(0),
+                position: 0,
+                current: NONE,
+            });
+        }
+        while let Some(mut frame) = self.stack.pop() {
+            let depth = self.stack.len();
+            let second = self.order[depth];
+            if frame.current != NONE {
+                self.pop_mapping(frame.current, second);
+                frame.current = NONE;
+            }
+            while frame.position < frame.candidates.len() {
+                let first = frame.candidates[frame.position];
+                frame.position += 1;
+                if self.inverse[first] != NONE || !self.is_consistent(first, second) {
+                    continue;
+                }
+                match self.is_semantic_match(first, second) {
+                    Ok(true) => {
+                        frame.current = first;
+                        break;
+                    }
+                    Ok(false) => continue,
+                    Err(err) => {
+                        self.done = true;
+                        return Some(Err(err));
+                    }
+                }
+            }
+            if frame.current == NONE {
+                continue;
+            }
+            self.push_mapping(frame.current, second);
+            self.stack.push(frame);
+            if depth + 1 == self.order.len() {
+                return Some(Ok(self.current_mapping()));
+            }
+            self.stack.push(Frame {
+                candidates: self.candidates(depth + 1),
+                position: 0,
+                current: NONE,
+            });
+        }
+        self.done = true;
+        None
+    }
+}
+
+/// Lazily find all the subgraph isomorphisms of `second` into `first` with
+/// the VF2++ algorithm.
+///
+/// The algorithm used is based on:
+///
+/// Alpár Jüttner and Péter Madarasi. VF2++ — An improved subgraph
+/// isomorphism algorithm. Discrete Applied Mathematics, 242:69–81, 2018.
+///
+/// Each mapping yielded by the returned iterator is a [`DictMap`] whose keys
+/// are nodes of `first` and whose values are the corresponding nodes of
+/// `second`. Mappings are computed on demand, so stopping the iteration early
+/// also stops the search.
+///
+/// Both graphs are expected to have the same directionality. Parallel edges
+/// and self loops are supported: every edge of `second` needs a distinct
+/// counterpart in `first`.
+///
+/// Arguments:
+///
+/// * `first` - The graph to search for subgraphs in
+/// * `second` - The pattern graph to look for in `first`
+/// * `node_match` - A matcher that is called with the weight of a node of
+///   `first` and a node of `second` and returns whether they can be mapped
+///   to each other. Use [`NoSemanticMatch`] to only match the structure.
+/// * `edge_match` - A matcher that is called with the weight of an edge of
+///   `first` and an edge of `second` and returns whether they can be mapped
+///   to each other. Use [`NoSemanticMatch`] to only match the structure.
+/// * `induced` - If `true` the mapped nodes of `first` must induce exactly the
+///   edges of `second` (induced subgraph isomorphism), otherwise `first` may
+///   have extra edges between the mapped nodes (monomorphism).
+///
+/// If a matcher returns an error, the error is yielded by the iterator and the
+/// search stops.
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::dictmap::DictMap;
+/// use retworkx_core::isomorphism::{subgraph_isomorphism_mappings, NoSemanticMatch};
+/// use retworkx_core::Result;
+///
+/// // A triangle with a pendant node
+/// let first = petgraph::graph::UnGraph::<&str, ()>::from_edges(&[
+///     (0, 1), (1, 2), (2, 0), (2, 3)
+/// ]);
+/// let path = petgraph::graph::UnGraph::<&str, ()>::from_edges(&[(0, 1), (1, 2)]);
+///
+/// // There are 10 ways to embed a path of length 2 as a monomorphism ...
+/// let mono: Vec<Result<DictMap<_, _>>> = subgraph_isomorphism_mappings(
+///     &first, &path, NoSemanticMatch, NoSemanticMatch, false
+/// ).collect();
+/// assert_eq!(mono.len(), 10);
+/// // ... but only 4 of them are induced subgraphs.
+/// let induced: Vec<Result<DictMap<_, _>>> = subgraph_isomorphism_mappings(
+///     &first, &path, NoSemanticMatch, NoSemanticMatch, true
+/// ).collect();
+/// assert_eq!(induced.len(), 4);
+/// ```
+///
+/// Node and edge weights can be compared with closures:
+///
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::isomorphism::{subgraph_isomorphism_mappings, NoSemanticMatch};
+/// use retworkx_core::Result;
+///
+/// let mut first = petgraph::graph::DiGraph::<&str, ()>::new();
+/// let a = first.add_node("a");
+/// let b = first.add_node("b");
+/// let c = first.add_node("a");
+/// first.extend_with_edges(&[(a, b), (b, c)]);
+///
+/// let mut second = petgraph::graph::DiGraph::<&str, ()>::new();
+/// let x = second.add_node("b");
+/// let y = second.add_node("a");
+/// second.add_edge(x, y, ());
+///
+/// let mut mappings = subgraph_isomorphism_mappings(
+///     &first,
+///     &second,
+///     |n0: &&str, n1: &&str| -> Result<bool> { Ok(n0 == n1) },
+///     NoSemanticMatch,
+///     true,
+/// );
+/// let mapping = mappings.next().unwrap().unwrap();
+/// assert_eq!(mapping[&b], x);
+/// assert_eq!(mapping[&c], y);
+/// assert!(mappings.next().is_none());
+/// ```
+pub fn subgraph_isomorphism_mappings<G0, G1, NM, EM, E>(
+    first: G0,
+    second: G1,
+    node_match: NM,
+    edge_match: EM,
+    induced: bool,
+) -> Vf2ppMappings<G0, G1, NM, EM, E>
+where
+    G0: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable + GraphProp + DataMap,
+    G1: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable + GraphProp + DataMap,
+    G0::NodeId: Hash + Eq,
+    NM: NodeMatcher<G0, G1, E>,
+    EM: EdgeMatcher<G0, G1, E>,
+{
+    Vf2ppMappings::new(first, second, node_match, edge_match, induced)
+}
@@ -0,0 +1,186 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use petgraph::visit::{EdgeRef, GraphProp, IntoEdgesDirected, IntoNodeIdentifiers, NodeIndexable};
+use petgraph::{Incoming, Outgoing};
+
+use crate::err::{CallbackError, InvalidInputError};
+
+const FNV_OFFSET_BASIS: u128 = 0x6c62272e07bb014262b821756295c58d;
+const FNV_PRIME: u128 = 0x0000000001000000000000000000013b;
+
+/// The 128 bit FNV-1a hash of `data` as a hex string. It's not a
+/// cryptographic hash, but unlike the hashers in the standard library its
+/// output is guaranteed to be the same on every platform and release.
+fn hex_digest(data: &str) -> String {
+    let mut hash = FNV_OFFSET_BASIS;
+    for byte in data.bytes() {
+        hash ^= byte as u128;
+        hash = hash.wrapping_mul(FNV_PRIME);
+    }
+    format!("{:032x}", hash)
+}
+
+/// Append `label` to `buffer` prefixed by its length so that the
+/// concatenation of several labels is unambiguous.
+fn push_label(buffer: &mut String, label: &str) {
+    buffer.push_str(&label.len().to_string());
+    buffer.push(':');
+    buffer.push_str(label);
+}
+
+/// Compute the Weisfeiler-Lehman graph hash of a graph.
+///
+/// The hash is built by iteratively relabeling every node with a hash of
+/// its own label and the sorted labels of its neighbors (combined with the
+/// label of the edge to each neighbor), then hashing the counts of the node
+/// labels found at each iteration. This is the same scheme as in [1]:
+/// isomorphic graphs always have the same hash while non-isomorphic graphs
+/// get different hashes with high probability, which makes it useful as a
+/// fast pre-filter before running a full isomorphism check. For directed
+/// graphs the labels of predecessors and successors are kept apart.
+///
+/// The returned digest is a hex string that is stable across platforms and
+/// releases so it can be persisted, but it is not a cryptographic hash.
+///
+/// Arguments:
+///
+/// * `graph` - The graph to hash
+/// * `node_label` - A callable that receives a node and returns its initial
+///   label, or an error which is returned right away as a
+///   [`CallbackError::Callback`]. To only consider the structure of the
+///   graph a common choice is the node degree.
+/// * `edge_label` - A callable that receives an edge and returns its label,
+///   return an empty string to ignore edge labels.
+/// * `iterations` - The number of relabeling iterations to run, higher
+///   values take more of the neighborhood of every node into account.
+///
+/// Returns a [`CallbackError::InvalidInput`] if `iterations` is 0.
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::err::CallbackError;
+/// use retworkx_core::isomorphism::weisfeiler_lehman_graph_hash;
+///
+/// let first = petgraph::graph::UnGraph::<&str, ()>::from_edges(&[(0, 1), (1, 2), (2, 3)]);
+/// let second = petgraph::graph::UnGraph::<&str, ()>::from_edges(&[(3, 2), (2, 0), (0, 1)]);
+/// let star = petgraph::graph::UnGraph::<&str, ()>::from_edges(&[(0, 1), (0, 2), (0, 3)]);
+/// let hash = |graph: &petgraph::graph::UnGraph<&str, ()>, iterations| {
+///     let hash: Result<String, CallbackError> = weisfeiler_lehman_graph_hash(
+///         graph,
+///         |n| Ok(graph.neighbors(n).count().to_string()),
+///         |_| Ok(String::new()),
+///         iterations,
+///     );
+///     hash
+/// };
+/// assert_eq!(hash(&first, 3).unwrap(), hash(&second, 3).unwrap());
+/// assert_ne!(hash(&first, 3).unwrap(), hash(&star, 3).unwrap());
+/// assert!(matches!(hash(&first, 0), Err(CallbackError::InvalidInput(_))));
+/// ```
+///
+/// [1] Shervashidze, Nino, Pascal Schweitzer, Erik Jan Van Leeuwen,
+///   Kurt Mehlhorn, and Karsten M. Borgwardt. "Weisfeiler Lehman graph
+///   kernels." Journal of Machine Learning Research. 12 (2011): 2539-2561.
+pub fn weisfeiler_lehman_graph_hash<G, F, H, E>(
+    graph: G,
+    mut node_label: F,
+    mut edge_label: H,
+    iterations: usize,
+) -> Result<String, CallbackError<E>>
+where
+    G: IntoEdgesDirected + IntoNodeIdentifiers + NodeIndexable + GraphProp,
+    F: FnMut(G::NodeId) -> Result<String, E>,
+    H: FnMut(G::EdgeRef) -> Result<String, E>,
+{
+    if iterations == 0 {
+        return Err(CallbackError::InvalidInput(
+            InvalidInputError::InvalidParameter {
+                name: "iterations",
+                reason: "must be at least 1",
+            },
+        ));
+    }
+    let nodes: Vec<G::NodeId> = graph.node_identifiers().collect();
+    let mut compact = vec![0; graph.node_bound()];
+    for (index, node) in nodes.iter().enumerate() {
+        compact[graph.to_index(*node)] = index;
+    }
+
+    let mut labels: Vec<String> = Vec::with_capacity(nodes.len());
+    // the neighbors of every node along with the label of the edge to them,
+    // which is prefixed by the direction of the edge for directed graphs
+    let mut neighborhoods: Vec<Vec<(String, usize)>> = Vec::with_capacity(nodes.len());
+    for node in &nodes {
+        labels.push(node_label(*node)?);
+        let mut neighborhood = Vec::new();
+        if graph.is_directed() {
+            for edge in graph.edges_directed(*node, Outgoing) {
+                let label = format!(">{}", edge_label(edge)?);
+                neighborhood.push((label, compact[graph.to_index(edge.target())]));
+            }
+            for edge in graph.edges_directed(*node, Incoming) {
+                let label = format!("<{}", edge_label(edge)?);
+                neighborhood.push((label, compact[graph.to_index(edge.source())]));
+            }
+        } else {
+            for edge in graph.edges(*node) {
+                let other = if edge.source() == *node {
+                    edge.target()
+                } else {
+                    edge.source()
+                };
+                neighborhood.push((edge_label(edge)?, compact[graph.to_index(other)]));
+            }
+        }
+        neighborhoods.push(neighborhood);
+    }
+
+    let mut subgraph_hash_counts = String::new();
+    for _ in 0..iterations {
+        let new_labels: Vec<String> = neighborhoods
+            .iter()
+            .zip(&labels)
+            .map(|(neighborhood, label)| {
+                let mut neighbor_labels: Vec<String> = neighborhood
+                    .iter()
+                    .map(|(edge, neighbor)| {
+                        let mut buffer = String::new();
+                        push_label(&mut buffer, edge);
+                        push_label(&mut buffer, &labels[*neighbor]);
+                        buffer
+                    })
+                    .collect();
+                neighbor_labels.sort_unstable();
+                let mut buffer = String::new();
+                push_label(&mut buffer, label);
+                for neighbor_label in neighbor_labels {
+                    buffer.push_str(&neighbor_label);
+                }
+                hex_digest(&buffer)
+            })
+            .collect();
+        let mut counts = new_labels.clone();
+        counts.sort_unstable();
+        let mut start = 0;
+        for end in 1..=counts.len() {
+            if end == counts.len() || counts[end] != counts[start] {
+                subgraph_hash_counts.push_str(&format!("{}:{};", counts[start], end - start));
+                start = end;
+            }
+        }
+        subgraph_hash_counts.push('|');
+        labels = new_labels;
+    }
+    Ok(hex_digest(&subgraph_hash_counts))
+}
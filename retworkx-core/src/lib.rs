@@ -48,8 +48,39 @@
 //!
 //! * [`centrality`](./centrality/index.html)
 //! * [`connectivity`](./connectivity/index.html)
+//! * [`curvature`](./curvature/index.html)
+//! * [`dag_algo`](./dag_algo/index.html)
+//! * [`dominance`](./dominance/index.html)
+//! * [`generators`](./generators/index.html)
+//! * [`interval_index`](./interval_index/index.html)
+//! * [`isomorphism`](./isomorphism/index.html)
+//! * [`linear_operator`](./linear_operator/index.html)
+//! * [`link_analysis`](./link_analysis/index.html)
 //! * [`max_weight_matching`](./max_weight_matching/index.html)
+//! * [`neighbors`](./neighbors/index.html)
+//! * [`operators`](./operators/index.html)
+//! * [`partition`](./partition/index.html)
+//! * [`path_ranking`](./path_ranking/index.html)
+//! * [`pipeline`](./pipeline/index.html)
+//! * [`routing`](./routing/index.html)
+//! * [`sampling`](./sampling/index.html)
 //! * [`shortest_path`](./shortest_path/index.html)
+//! * [`summary`](./summary/index.html)
+//! * [`temporal`](./temporal/index.html)
+//! * [`tree`](./tree/index.html)
+//! * [`views`](./views/index.html)
+//! * [`weights`](./weights/index.html)
+//!
+//! ## Crate Features
+//!
+//! * `parallel` (enabled by default) - Run the functions that take a
+//!   `parallel_threshold` argument on multiple threads with [rayon] once the
+//!   input is large enough. Disable it with `default-features = false` to
+//!   build without rayon, for example for targets without threads such as
+//!   `wasm32-unknown-unknown`. The functions then always run on the calling
+//!   thread and `parallel_threshold` is ignored.
+//!
+//! [rayon]: https://docs.rs/rayon
 //!
 //! ## Release Notes
 //!
@@ -67,13 +98,70 @@ use std::convert::Infallible;
 /// error can happen.
 pub type Result<T, E = Infallible> = core::result::Result<T, E>;
 
+/// The convergence of an iterative algorithm.
+///
+/// This is returned along with the result of the last iteration by the
+/// `*_with_convergence` variants of the functions computed with power
+/// iteration, such as [`link_analysis::pagerank_with_convergence`], so the
+/// run can be recorded even if it didn't converge.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Convergence {
+    /// The number of iterations run.
+    pub iterations: usize,
+    /// The error of the last iteration, the value compared against the
+    /// tolerance to check for convergence. It's infinite if no iteration
+    /// was run.
+    pub error: f64,
+    /// Whether the error dropped below the tolerance within the maximum
+    /// number of iterations.
+    pub converged: bool,
+}
+
+pub mod approximation;
 /// Module for centrality algorithms
 pub mod centrality;
+pub mod clique;
+pub mod coarsening;
+pub mod community;
 pub mod connectivity;
+/// Module for graph curvature measures
+pub mod curvature;
+pub mod dag_algo;
+pub mod dominance;
+pub mod embedding;
+pub mod err;
+pub mod generators;
+pub mod graph6;
+pub mod hamiltonian;
+pub mod interval_index;
+pub mod isomorphism;
+pub mod linear_operator;
+/// Module for link analysis algorithms
+pub mod link_analysis;
 /// Module for maximum weight matching algorithmss
 pub mod max_weight_matching;
+pub mod motifs;
+pub mod neighbors;
+pub mod operators;
+pub mod partition;
+pub mod path_ranking;
+pub mod pipeline;
+pub mod planar;
+pub mod routing;
+pub mod sampling;
 pub mod shortest_path;
+pub mod spectral;
+pub mod steiner_tree;
+/// Module for summary statistics of graphs
+pub mod summary;
+pub mod temporal;
+pub mod transitivity;
 pub mod traversal;
+/// Module for spanning tree algorithms
+pub mod tree;
+pub mod tsp;
+pub mod views;
+pub mod weights;
 // These modules define additional data structures
 pub mod dictmap;
 pub mod distancemap;
@@ -0,0 +1,261 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+//! Module for multiplying a vector by a matrix of a graph without building
+//! the matrix.
+//!
+//! The products are computed row by row from the edges of the graph, so they
+//! can be used in iterative solvers and eigensolvers on graphs too large to
+//! export as a matrix.
+
+use petgraph::visit::{EdgeRef, IntoEdgesDirected, IntoNodeIdentifiers, NodeIndexable};
+use petgraph::Direction::{self, Incoming, Outgoing};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+use crate::err::{CallbackError, InvalidInputError};
+
+/// The matrix of a graph to multiply by.
+///
+/// The rows and columns of the matrices are indexed by node index, for an
+/// undirected graph every edge goes both ways.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GraphMatrix {
+    /// The weighted adjacency matrix `A`, where `A[i][j]` is the sum of the
+    /// weights of the edges from node `i` to node `j`.
+    Adjacency,
+    /// The Laplacian matrix `L = D - A`, where `D` is the diagonal matrix of
+    /// the weighted out degrees of the nodes, the row sums of `A`.
+    Laplacian,
+    /// The random walk transition matrix `P = D^-1 A`, where the row of a
+    /// node with a weighted out degree of 0 is 0.
+    Transition,
+}
+
+/// Map `f` over every node, in parallel if there are at least
+/// `parallel_threshold` nodes and the `parallel` feature is enabled.
+fn map_nodes<T, E, F>(nodes: &[usize], parallel_threshold: usize, f: F) -> Result<Vec<T>, E>
+where
+    T: Send,
+    E: Send,
+    F: Fn(&usize) -> Result<T, E> + Sync + Send,
+{
+    #[cfg(feature = "parallel")]
+    if nodes.len() >= parallel_threshold {
+        return nodes.par_iter().map(f).collect();
+    }
+    #[cfg(not(feature = "parallel"))]
+    let _ = parallel_threshold;
+    nodes.iter().map(f).collect()
+}
+
+/// Sum `term(weight, other)` over the edges of `node` in direction `dir`,
+/// where `other` is the node index at the other end of the edge.
+fn edge_sum<G, F, E, T>(
+    graph: G,
+    node: usize,
+    dir: Direction,
+    weight_fn: &F,
+    term: T,
+) -> Result<f64, E>
+where
+    G: IntoEdgesDirected + NodeIndexable,
+    F: Fn(G::EdgeRef) -> Result<f64, E>,
+    T: Fn(f64, usize) -> f64,
+{
+    let mut sum = 0.0;
+    for edge in graph.edges_directed(graph.from_index(node), dir) {
+        let source = graph.to_index(edge.source());
+        let other = if source == node {
+            graph.to_index(edge.target())
+        } else {
+            source
+        };
+        sum += term(weight_fn(edge)?, other);
+    }
+    Ok(sum)
+}
+
+fn product<G, F, E>(
+    graph: G,
+    matrix: GraphMatrix,
+    weight_fn: F,
+    x: &[f64],
+    transpose: bool,
+    parallel_threshold: usize,
+) -> Result<Vec<f64>, CallbackError<E>>
+where
+    G: IntoEdgesDirected + IntoNodeIdentifiers + NodeIndexable + Sync,
+    G::NodeId: Send + Sync,
+    F: Fn(G::EdgeRef) -> Result<f64, E> + Sync,
+    E: Send,
+{
+    let bound = graph.node_bound();
+    if x.len() != bound {
+        return Err(CallbackError::InvalidInput(
+            InvalidInputError::InvalidParameter {
+                name: "x",
+                reason: "must have an entry for every node index of the graph",
+            },
+        ));
+    }
+    let nodes: Vec<usize> = graph
+        .node_identifiers()
+        .map(|n| graph.to_index(n))
+        .collect();
+
+    let mut degrees = vec![0.0; bound];
+    if matrix != GraphMatrix::Adjacency {
+        let node_degrees = map_nodes(&nodes, parallel_threshold, |&node| {
+            edge_sum(graph, node, Outgoing, &weight_fn, |weight, _| weight)
+        })?;
+        for (node, degree) in nodes.iter().zip(node_degrees) {
+            degrees[*node] = degree;
+        }
+    }
+    let inverse = |degree: f64| if degree == 0.0 { 0.0 } else { 1.0 / degree };
+
+    let dir = if transpose { Incoming } else { Outgoing };
+    let values = map_nodes(&nodes, parallel_threshold, |&node| -> Result<f64, E> {
+        Ok(match matrix {
+            GraphMatrix::Adjacency => edge_sum(graph, node, dir, &weight_fn, |weight, other| {
+                weight * x[other]
+            })?,
+            GraphMatrix::Laplacian => {
+                degrees[node] * x[node]
+                    - edge_sum(graph, node, dir, &weight_fn, |weight, other| {
+                        weight * x[other]
+                    })?
+            }
+            GraphMatrix::Transition if transpose => {
+                edge_sum(graph, node, dir, &weight_fn, |weight, other| {
+                    weight * x[other] * inverse(degrees[other])
+                })?
+            }
+            GraphMatrix::Transition => {
+                inverse(degrees[node])
+                    * edge_sum(graph, node, dir, &weight_fn, |weight, other| {
+                        weight * x[other]
+                    })?
+            }
+        })
+    })?;
+    let mut y = vec![0.0; bound];
+    for (node, value) in nodes.iter().zip(values) {
+        y[*node] = value;
+    }
+    Ok(y)
+}
+
+/// Multiply a vector by a matrix of a graph, without building the matrix.
+///
+/// This computes `y = M x` where `M` is the matrix of `graph` selected by
+/// `matrix`. Every entry of `y` is computed from the edges of its node, so
+/// this takes `O(n + m)` time and only allocates vectors with an entry per
+/// node, such as the weighted degrees of the nodes for the Laplacian and
+/// transition matrices.
+///
+/// This function is multithreaded and will run in parallel if the number
+/// of nodes in the graph is above the value of `parallel_threshold`. If the
+/// function will be running in parallel the env var `RAYON_NUM_THREADS` can
+/// be used to adjust how many threads will be used.
+///
+/// Arguments:
+///
+/// * `graph` - The graph whose matrix to multiply by
+/// * `matrix` - The matrix of the graph to multiply by
+/// * `weight_fn` - A callable that returns the weight of an edge, or an
+///   error which is returned by this function as a
+///   [`CallbackError::Callback`]
+/// * `x` - The vector to multiply, indexed by node index. Its length must be
+///   the node bound of the graph, the entries of the indices without a node
+///   are ignored.
+/// * `parallel_threshold` - The number of nodes from which the product is
+///   computed in parallel
+///
+/// Returns `y`, indexed by node index with 0 for the indices without a node,
+/// or a [`CallbackError::InvalidInput`] if the length of `x` isn't the node
+/// bound of the graph.
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::linear_operator::{matvec, GraphMatrix};
+/// use retworkx_core::err::CallbackError;
+///
+/// let g = petgraph::graph::UnGraph::<(), f64>::from_edges(&[(0, 1, 2.), (1, 2, 1.)]);
+/// let x = [1., 2., 3.];
+/// let adjacency: Result<Vec<f64>, CallbackError> =
+///     matvec(&g, GraphMatrix::Adjacency, |e| Ok(*e.weight()), &x, 300);
+/// assert_eq!(adjacency.unwrap(), vec![4., 5., 2.]);
+/// let laplacian: Result<Vec<f64>, CallbackError> =
+///     matvec(&g, GraphMatrix::Laplacian, |e| Ok(*e.weight()), &x, 300);
+/// assert_eq!(laplacian.unwrap(), vec![-2., 1., 1.]);
+/// // x needs an entry for every node
+/// let error: Result<Vec<f64>, CallbackError> =
+///     matvec(&g, GraphMatrix::Adjacency, |e| Ok(*e.weight()), &x[..2], 300);
+/// assert!(matches!(error, Err(CallbackError::InvalidInput(_))));
+/// ```
+pub fn matvec<G, F, E>(
+    graph: G,
+    matrix: GraphMatrix,
+    weight_fn: F,
+    x: &[f64],
+    parallel_threshold: usize,
+) -> Result<Vec<f64>, CallbackError<E>>
+where
+    G: IntoEdgesDirected + IntoNodeIdentifiers + NodeIndexable + Sync,
+    G::NodeId: Send + Sync,
+    F: Fn(G::EdgeRef) -> Result<f64, E> + Sync,
+    E: Send,
+{
+    product(graph, matrix, weight_fn, x, false, parallel_threshold)
+}
+
+/// Multiply a vector by the transpose of a matrix of a graph, without
+/// building the matrix.
+///
+/// This computes `y = M^T x` where `M` is the matrix of `graph` selected by
+/// `matrix`, it takes the same arguments as [`matvec`]. For a directed graph
+/// the entry of a node is computed from its incoming edges. For an undirected
+/// graph the adjacency and Laplacian matrices are symmetric and this returns
+/// the same vector as [`matvec`], but the transition matrix isn't: `P^T x`
+/// is the distribution of a random walk after one step from the distribution
+/// `x`.
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::linear_operator::{rmatvec, GraphMatrix};
+/// use retworkx_core::err::CallbackError;
+///
+/// // A random walk from node 0 moves to node 1 or 2 with the same probability
+/// let g = petgraph::graph::DiGraph::<(), ()>::from_edges(&[(0, 1), (0, 2), (1, 2)]);
+/// let output: Result<Vec<f64>, CallbackError> =
+///     rmatvec(&g, GraphMatrix::Transition, |_| Ok(1.), &[1., 0., 0.], 300);
+/// assert_eq!(output.unwrap(), vec![0., 0.5, 0.5]);
+/// ```
+pub fn rmatvec<G, F, E>(
+    graph: G,
+    matrix: GraphMatrix,
+    weight_fn: F,
+    x: &[f64],
+    parallel_threshold: usize,
+) -> Result<Vec<f64>, CallbackError<E>>
+where
+    G: IntoEdgesDirected + IntoNodeIdentifiers + NodeIndexable + Sync,
+    G::NodeId: Send + Sync,
+    F: Fn(G::EdgeRef) -> Result<f64, E> + Sync,
+    E: Send,
+{
+    product(graph, matrix, weight_fn, x, true, parallel_threshold)
+}
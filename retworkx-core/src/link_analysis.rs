@@ -0,0 +1,447 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use petgraph::visit::{EdgeRef, GraphProp, IntoEdges, IntoNodeIdentifiers, NodeIndexable};
+
+use crate::err::{CallbackError, InvalidInputError};
+use crate::Convergence;
+
+type HitsOutput = Option<(Vec<f64>, Vec<f64>)>;
+
+/// Normalize `values` (indexed by node index) over the nodes in `nodes` so
+/// they sum to 1, or return the uniform distribution if `values` is `None`.
+/// The values of the argument `name` must have a positive sum.
+fn distribution<E>(
+    values: Option<&[f64]>,
+    nodes: &[usize],
+    bound: usize,
+    name: &'static str,
+) -> Result<Vec<f64>, CallbackError<E>> {
+    let mut out = vec![0.0; bound];
+    match values {
+        Some(values) => {
+            let total: f64 = nodes.iter().map(|n| values[*n]).sum();
+            if total.is_nan() || total <= 0.0 {
+                return Err(CallbackError::InvalidInput(
+                    InvalidInputError::InvalidParameter {
+                        name,
+                        reason: "must have a positive sum over the nodes of the graph",
+                    },
+                ));
+            }
+            for n in nodes {
+                out[*n] = values[*n] / total;
+            }
+        }
+        None => {
+            for n in nodes {
+                out[*n] = 1.0 / nodes.len() as f64;
+            }
+        }
+    }
+    Ok(out)
+}
+
+/// Compute the PageRank of the nodes of a graph.
+///
+/// PageRank computes a ranking of the nodes in the graph based on the
+/// structure of the incoming links [1]. It's computed with power iteration
+/// on the random walk that follows an outgoing edge (chosen with probability
+/// proportional to its weight) with probability `alpha` and otherwise
+/// teleports to a node drawn from the personalization distribution. Dangling
+/// nodes, the nodes without outgoing edges (or whose outgoing edges all have
+/// a weight of 0), teleport to a node drawn from the dangling distribution.
+/// Undirected graphs are treated as directed graphs with an edge in both
+/// directions for every edge.
+///
+/// Arguments:
+///
+/// * `graph` - The graph object to run the algorithm on
+/// * `weight_fn` - A callable that receives an edge and returns its weight,
+///   which must be non-negative, or an error which is returned right away
+///   as a [`CallbackError::Callback`]. Parallel edges have their weights
+///   added.
+/// * `alpha` - The damping factor, typically `0.85`
+/// * `personalization` - An optional slice indexed by node index with the
+///   (unnormalized) teleportation probability of every node. If not
+///   specified a uniform distribution is used.
+/// * `nstart` - An optional slice indexed by node index with the starting
+///   value of the iteration for every node. If not specified a uniform
+///   distribution is used.
+/// * `dangling` - An optional slice indexed by node index with the
+///   (unnormalized) probability of jumping to every node from a dangling
+///   node. If not specified the personalization distribution is used.
+/// * `max_iter` - The maximum number of iterations of the power method
+/// * `tol` - The error tolerance used when checking for convergence, the
+///   iteration stops once the change of the ranks sums to less than the
+///   number of nodes times `tol`.
+///
+/// Returns a [`Vec`] indexed by node index with the PageRank of every node
+/// (removed indices are set to 0), or [`None`] if the power iteration didn't
+/// converge within `max_iter` iterations. Returns a
+/// [`CallbackError::InvalidInput`] if `personalization`, `nstart` or
+/// `dangling` don't have a positive sum over the nodes of the graph.
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::err::CallbackError;
+/// use retworkx_core::link_analysis::pagerank;
+///
+/// let g = petgraph::graph::DiGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 0), (3, 2)]);
+/// let output: Result<Option<Vec<f64>>, CallbackError> =
+///     pagerank(&g, |_| Ok(1.), 0.85, None, None, None, 100, 1e-6);
+/// let ranks = output.unwrap().unwrap();
+/// assert!((ranks.iter().sum::<f64>() - 1.0).abs() < 1e-9);
+/// // Nothing links to node 3
+/// assert!((ranks[3] - 0.15 / 4.0).abs() < 1e-9);
+///
+/// let output: Result<Option<Vec<f64>>, CallbackError> =
+///     pagerank(&g, |_| Ok(1.), 0.85, Some(&[0.0; 4]), None, None, 100, 1e-6);
+/// assert!(matches!(output, Err(CallbackError::InvalidInput(_))));
+/// ```
+///
+/// [1] A. Langville and C. Meyer, "A survey of eigenvector methods of web
+///   information retrieval." <http://citeseer.ist.psu.edu/713792.html>
+#[allow(clippy::too_many_arguments)]
+pub fn pagerank<G, F, E>(
+    graph: G,
+    weight_fn: F,
+    alpha: f64,
+    personalization: Option<&[f64]>,
+    nstart: Option<&[f64]>,
+    dangling: Option<&[f64]>,
+    max_iter: usize,
+    tol: f64,
+) -> Result<Option<Vec<f64>>, CallbackError<E>>
+where
+    G: IntoEdges + IntoNodeIdentifiers + NodeIndexable + GraphProp,
+    F: FnMut(G::EdgeRef) -> Result<f64, E>,
+{
+    let (ranks, convergence) = pagerank_with_convergence(
+        graph,
+        weight_fn,
+        alpha,
+        personalization,
+        nstart,
+        dangling,
+        max_iter,
+        tol,
+    )?;
+    Ok(if convergence.converged {
+        Some(ranks)
+    } else {
+        None
+    })
+}
+
+/// Compute the PageRank of the nodes of a graph along with the convergence
+/// of the power iteration.
+///
+/// This takes the same arguments as [`pagerank`], but returns the ranks of
+/// the last iteration along with its [`Convergence`] instead of [`None`] if
+/// the power iteration didn't converge within `max_iter` iterations. The
+/// error of an iteration is the mean absolute change of the ranks.
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::err::CallbackError;
+/// use retworkx_core::link_analysis::pagerank_with_convergence;
+/// use retworkx_core::Convergence;
+///
+/// let g = petgraph::graph::DiGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 0), (3, 2)]);
+/// let output: Result<(Vec<f64>, Convergence), CallbackError> =
+///     pagerank_with_convergence(&g, |_| Ok(1.), 0.85, None, None, None, 2, 1e-6);
+/// let (_, convergence) = output.unwrap();
+/// assert_eq!(convergence.iterations, 2);
+/// assert!(!convergence.converged);
+/// ```
+#[allow(clippy::too_many_arguments)]
+pub fn pagerank_with_convergence<G, F, E>(
+    graph: G,
+    mut weight_fn: F,
+    alpha: f64,
+    personalization: Option<&[f64]>,
+    nstart: Option<&[f64]>,
+    dangling: Option<&[f64]>,
+    max_iter: usize,
+    tol: f64,
+) -> Result<(Vec<f64>, Convergence), CallbackError<E>>
+where
+    G: IntoEdges + IntoNodeIdentifiers + NodeIndexable + GraphProp,
+    F: FnMut(G::EdgeRef) -> Result<f64, E>,
+{
+    let bound = graph.node_bound();
+    let nodes: Vec<usize> = graph
+        .node_identifiers()
+        .map(|n| graph.to_index(n))
+        .collect();
+    if nodes.is_empty() {
+        return Ok((
+            vec![0.0; bound],
+            Convergence {
+                iterations: 0,
+                error: 0.0,
+                converged: true,
+            },
+        ));
+    }
+
+    // the outgoing edges of every node as (target, transition probability)
+    let mut transitions: Vec<Vec<(usize, f64)>> = vec![Vec::new(); bound];
+    for node in graph.node_identifiers() {
+        let index = graph.to_index(node);
+        for edge in graph.edges(node) {
+            let target = if graph.is_directed() || edge.source() == node {
+                edge.target()
+            } else {
+                edge.source()
+            };
+            transitions[index].push((graph.to_index(target), weight_fn(edge)?));
+        }
+        let total: f64 = transitions[index].iter().map(|(_, w)| w).sum();
+        if total > 0.0 {
+            for (_, weight) in transitions[index].iter_mut() {
+                *weight /= total;
+            }
+        } else {
+            // all the outgoing edges have a weight of 0 so it's dangling
+            transitions[index].clear();
+        }
+    }
+    let dangling_nodes: Vec<usize> = nodes
+        .iter()
+        .copied()
+        .filter(|n| transitions[*n].is_empty())
+        .collect();
+
+    let personalization = distribution(personalization, &nodes, bound, "personalization")?;
+    let dangling_weights = match dangling {
+        Some(_) => distribution(dangling, &nodes, bound, "dangling")?,
+        None => personalization.clone(),
+    };
+    let mut x = distribution(nstart, &nodes, bound, "nstart")?;
+    let mut convergence = Convergence {
+        iterations: 0,
+        error: f64::INFINITY,
+        converged: false,
+    };
+    for _ in 0..max_iter {
+        let last = x;
+        x = vec![0.0; bound];
+        let dangle_sum: f64 = alpha * dangling_nodes.iter().map(|n| last[*n]).sum::<f64>();
+        for n in &nodes {
+            for (target, weight) in &transitions[*n] {
+                x[*target] += alpha * last[*n] * weight;
+            }
+        }
+        for n in &nodes {
+            x[*n] += dangle_sum * dangling_weights[*n] + (1.0 - alpha) * personalization[*n];
+        }
+        convergence.iterations += 1;
+        convergence.error =
+            nodes.iter().map(|n| (x[*n] - last[*n]).abs()).sum::<f64>() / nodes.len() as f64;
+        if convergence.error < tol {
+            convergence.converged = true;
+            break;
+        }
+    }
+    Ok((x, convergence))
+}
+
+/// Compute the HITS hubs and authorities scores of the nodes of a graph.
+///
+/// The HITS algorithm [1] computes two scores for every node: the authority
+/// score estimates the value of the content of a node based on its incoming
+/// links and the hub score estimates the value of its outgoing links. A good
+/// hub links to many good authorities and a good authority is linked from
+/// many good hubs. The scores are computed with power iteration, for
+/// undirected graphs the hubs and authorities scores are the same.
+///
+/// Arguments:
+///
+/// * `graph` - The graph object to run the algorithm on
+/// * `weight_fn` - A callable that receives an edge and returns its weight,
+///   which must be non-negative, or an error which is returned right away
+///   as a [`CallbackError::Callback`]. Parallel edges have their weights
+///   added.
+/// * `nstart` - An optional slice indexed by node index with the starting
+///   value of the hub score of every node. If not specified a uniform
+///   distribution is used.
+/// * `max_iter` - The maximum number of iterations of the power method
+/// * `tol` - The error tolerance used when checking for convergence, the
+///   iteration stops once the change of the hub scores sums to less than
+///   `tol`.
+/// * `normalized` - If `true` the scores are normalized to sum to 1,
+///   otherwise they're normalized so the largest score is 1.
+///
+/// Returns a tuple of [`Vec`]s indexed by node index with the hub and the
+/// authority scores of every node (removed indices are set to 0), or
+/// [`None`] if the power iteration didn't converge within `max_iter`
+/// iterations. Returns a [`CallbackError::InvalidInput`] if `nstart`
+/// doesn't have a positive sum over the nodes of the graph.
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::err::CallbackError;
+/// use retworkx_core::link_analysis::hits;
+///
+/// let g = petgraph::graph::DiGraph::<(), ()>::from_edges(&[(0, 2), (1, 2), (1, 3)]);
+/// let output: Result<Option<(Vec<f64>, Vec<f64>)>, CallbackError> =
+///     hits(&g, |_| Ok(1.), None, 100, 1e-8, false);
+/// let (hubs, authorities) = output.unwrap().unwrap();
+/// // Node 1 links to both authorities and node 2 is linked from both hubs
+/// assert!((hubs[1] - 1.0).abs() < 1e-6);
+/// assert!((authorities[2] - 1.0).abs() < 1e-6);
+/// assert_eq!(hubs[2], 0.0);
+/// assert_eq!(authorities[1], 0.0);
+/// ```
+///
+/// [1] Jon Kleinberg, "Authoritative sources in a hyperlinked environment."
+///   Journal of the ACM 46 (5): 604-32, 1999. doi:10.1145/324133.324140.
+pub fn hits<G, F, E>(
+    graph: G,
+    weight_fn: F,
+    nstart: Option<&[f64]>,
+    max_iter: usize,
+    tol: f64,
+    normalized: bool,
+) -> Result<HitsOutput, CallbackError<E>>
+where
+    G: IntoEdges + IntoNodeIdentifiers + NodeIndexable + GraphProp,
+    F: FnMut(G::EdgeRef) -> Result<f64, E>,
+{
+    let (hubs, authorities, convergence) =
+        hits_with_convergence(graph, weight_fn, nstart, max_iter, tol, normalized)?;
+    Ok(if convergence.converged {
+        Some((hubs, authorities))
+    } else {
+        None
+    })
+}
+
+/// Compute the HITS hubs and authorities scores of the nodes of a graph
+/// along with the convergence of the power iteration.
+///
+/// This takes the same arguments as [`hits`], but returns the hub and the
+/// authority scores of the last iteration along with its [`Convergence`]
+/// instead of [`None`] if the power iteration didn't converge within
+/// `max_iter` iterations. The error of an iteration is the summed absolute
+/// change of the hub scores.
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::err::CallbackError;
+/// use retworkx_core::link_analysis::hits_with_convergence;
+/// use retworkx_core::Convergence;
+///
+/// let g = petgraph::graph::DiGraph::<(), ()>::from_edges(&[(0, 2), (1, 2), (1, 3)]);
+/// let output: Result<(Vec<f64>, Vec<f64>, Convergence), CallbackError> =
+///     hits_with_convergence(&g, |_| Ok(1.), None, 100, 1e-8, false);
+/// let (hubs, _, convergence) = output.unwrap();
+/// assert!(convergence.converged);
+/// assert!(convergence.error < 1e-8);
+/// assert!((hubs[1] - 1.0).abs() < 1e-6);
+/// ```
+pub fn hits_with_convergence<G, F, E>(
+    graph: G,
+    mut weight_fn: F,
+    nstart: Option<&[f64]>,
+    max_iter: usize,
+    tol: f64,
+    normalized: bool,
+) -> Result<(Vec<f64>, Vec<f64>, Convergence), CallbackError<E>>
+where
+    G: IntoEdges + IntoNodeIdentifiers + NodeIndexable + GraphProp,
+    F: FnMut(G::EdgeRef) -> Result<f64, E>,
+{
+    let bound = graph.node_bound();
+    let nodes: Vec<usize> = graph
+        .node_identifiers()
+        .map(|n| graph.to_index(n))
+        .collect();
+    if nodes.is_empty() {
+        return Ok((
+            vec![0.0; bound],
+            vec![0.0; bound],
+            Convergence {
+                iterations: 0,
+                error: 0.0,
+                converged: true,
+            },
+        ));
+    }
+
+    // the outgoing edges of every node as (target, weight)
+    let mut links: Vec<Vec<(usize, f64)>> = vec![Vec::new(); bound];
+    for node in graph.node_identifiers() {
+        let index = graph.to_index(node);
+        for edge in graph.edges(node) {
+            let target = if graph.is_directed() || edge.source() == node {
+                edge.target()
+            } else {
+                edge.source()
+            };
+            links[index].push((graph.to_index(target), weight_fn(edge)?));
+        }
+    }
+    // scale the scores so the largest is 1, leaving all zero scores as is
+    let scale_to_max = |scores: &mut Vec<f64>| {
+        let max = scores.iter().copied().fold(0.0, f64::max);
+        if max > 0.0 {
+            scores.iter_mut().for_each(|x| *x /= max);
+        }
+    };
+
+    let mut hubs = distribution(nstart, &nodes, bound, "nstart")?;
+    let mut authorities = vec![0.0; bound];
+    let mut convergence = Convergence {
+        iterations: 0,
+        error: f64::INFINITY,
+        converged: false,
+    };
+    for _ in 0..max_iter {
+        let last = hubs;
+        hubs = vec![0.0; bound];
+        authorities = vec![0.0; bound];
+        for n in &nodes {
+            for (target, weight) in &links[*n] {
+                authorities[*target] += last[*n] * weight;
+            }
+        }
+        for n in &nodes {
+            for (target, weight) in &links[*n] {
+                hubs[*n] += authorities[*target] * weight;
+            }
+        }
+        scale_to_max(&mut hubs);
+        scale_to_max(&mut authorities);
+        convergence.iterations += 1;
+        convergence.error = nodes.iter().map(|n| (hubs[*n] - last[*n]).abs()).sum();
+        if convergence.error < tol {
+            convergence.converged = true;
+            break;
+        }
+    }
+    if normalized {
+        for scores in [&mut hubs, &mut authorities].iter_mut() {
+            let total: f64 = scores.iter().sum();
+            if total > 0.0 {
+                scores.iter_mut().for_each(|x| *x /= total);
+            }
+        }
+    }
+    Ok((hubs, authorities, convergence))
+}
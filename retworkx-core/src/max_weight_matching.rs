@@ -19,6 +19,7 @@
 
 use std::cmp::max;
 use std::mem;
+use std::ops::{Add, AddAssign, Div, Neg, Rem, Sub, SubAssign};
 
 use hashbrown::{HashMap, HashSet};
 
@@ -28,10 +29,49 @@ use petgraph::visit::{
 };
 use petgraph::Undirected;
 
+/// The arithmetic needed on the edge weights of [`max_weight_matching`].
+///
+/// The matching is computed exactly, so this is meant for integer types. It's
+/// implemented for all the primitive signed integer types and for any other
+/// type with the required operations, like an arbitrary precision integer
+/// type. Rational weights can be used by scaling them to integers by a common
+/// denominator.
+pub trait MatchingWeight:
+    Clone
+    + Ord
+    + Default
+    + From<u8>
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Neg<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+    + AddAssign
+    + SubAssign
+{
+}
+
+impl<T> MatchingWeight for T where
+    T: Clone
+        + Ord
+        + Default
+        + From<u8>
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Neg<Output = T>
+        + Div<Output = T>
+        + Rem<Output = T>
+        + AddAssign
+        + SubAssign
+{
+}
+
 /// Return 2 * slack of edge k (does not work inside blossoms).
-fn slack(edge_index: usize, dual_var: &[i128], edges: &[(usize, usize, i128)]) -> i128 {
-    let (source_index, target_index, weight) = edges[edge_index];
-    dual_var[source_index] + dual_var[target_index] - 2 * weight
+fn slack<W: MatchingWeight>(edge_index: usize, dual_var: &[W], edges: &[(usize, usize, W)]) -> W {
+    let (source_index, target_index, weight) = &edges[edge_index];
+    dual_var[*source_index].clone() + dual_var[*target_index].clone()
+        - weight.clone()
+        - weight.clone()
 }
 
 /// Generate the leaf vertices of a blossom.
@@ -170,14 +210,14 @@ fn scan_blossom(
 /// Construct a new blossom with given base, containing edge k which
 /// connects a pair of S vertices. Label the new blossom as S; set its dual
 /// variable to zero; relabel its T-vertices to S and add them to the queue.
-fn add_blossom<E>(
+fn add_blossom<W: MatchingWeight, E>(
     base: usize,
     edge: usize,
     blossom_children: &mut Vec<Vec<usize>>,
     num_nodes: usize,
-    edges: &[(usize, usize, i128)],
+    edges: &[(usize, usize, W)],
     in_blossoms: &mut Vec<usize>,
-    dual_var: &mut Vec<i128>,
+    dual_var: &mut Vec<W>,
     labels: &mut Vec<Option<usize>>,
     label_ends: &mut Vec<Option<usize>>,
     best_edge: &mut Vec<Option<usize>>,
@@ -191,7 +231,7 @@ fn add_blossom<E>(
     neighbor_endpoints: &[Vec<usize>],
     mate: &HashMap<usize, usize>,
 ) -> Result<(), E> {
-    let (mut v, mut w, _weight) = edges[edge];
+    let (mut v, mut w, _) = edges[edge];
     let blossom_b = in_blossoms[base];
     let mut blossom_v = in_blossoms[v];
     let mut blossom_w = in_blossoms[w];
@@ -249,7 +289,7 @@ fn add_blossom<E>(
     labels[blossom] = Some(1);
     label_ends[blossom] = label_ends[blossom_b];
     // Set dual variable to 0
-    dual_var[blossom] = 0;
+    dual_var[blossom] = W::default();
     // Relabel vertices
     for node in blossom_leaves(blossom, num_nodes, blossom_children)? {
         if labels[in_blossoms[node]] == Some(2) {
@@ -277,7 +317,7 @@ fn add_blossom<E>(
         };
         for nblist in nblists {
             for edge_index in nblist {
-                let (mut i, mut j, _edge_weight) = edges[edge_index];
+                let (mut i, mut j, _) = edges[edge_index];
                 if in_blossoms[j] == blossom {
                     mem::swap(&mut i, &mut j);
                 }
@@ -311,14 +351,14 @@ fn add_blossom<E>(
 }
 
 /// Expand the given top level blossom
-fn expand_blossom<E>(
+fn expand_blossom<W: MatchingWeight, E>(
     blossom: usize,
     end_stage: bool,
     num_nodes: usize,
     blossom_children: &mut Vec<Vec<usize>>,
     blossom_parents: &mut Vec<Option<usize>>,
     in_blossoms: &mut Vec<usize>,
-    dual_var: &[i128],
+    dual_var: &[W],
     labels: &mut Vec<Option<usize>>,
     label_ends: &mut Vec<Option<usize>>,
     best_edge: &mut Vec<Option<usize>>,
@@ -335,7 +375,7 @@ fn expand_blossom<E>(
         blossom_parents[s] = None;
         if s < num_nodes {
             in_blossoms[s] = s
-        } else if end_stage && dual_var[s] == 0 {
+        } else if end_stage && dual_var[s] == W::default() {
             // Recursively expand this sub-blossom
             expand_blossom(
                 s,
@@ -651,10 +691,10 @@ fn augment_blossom(
 /// Swap matched/unmatched edges over an alternating path between two
 /// single vertices. The augmenting path runs through edge k, which
 /// connects a pair of S vertices.
-fn augment_matching(
+fn augment_matching<W>(
     edge: usize,
     num_nodes: usize,
-    edges: &[(usize, usize, i128)],
+    edges: &[(usize, usize, W)],
     in_blossoms: &[usize],
     labels: &[Option<usize>],
     label_ends: &[Option<usize>],
@@ -665,7 +705,7 @@ fn augment_matching(
     blossom_base: &mut Vec<Option<usize>>,
     mate: &mut HashMap<usize, usize>,
 ) {
-    let (v, w, _weight) = edges[edge];
+    let (v, w, _) = edges[edge];
     for (s_ref, p_ref) in [(v, 2 * edge + 1), (w, 2 * edge)].iter() {
         // Match vertex s to remote endpoint p. Then trace back from s
         // until we find a single vertex, swapping matched and unmatched
@@ -731,32 +771,33 @@ fn augment_matching(
 /// Swap matched/unmatched edges over an alternating path between two
 /// single vertices. The augmenting path runs through the edge, which
 /// connects a pair of S vertices.
-fn verify_optimum(
+fn verify_optimum<W: MatchingWeight>(
     max_cardinality: bool,
     num_nodes: usize,
     num_edges: usize,
-    edges: &[(usize, usize, i128)],
+    edges: &[(usize, usize, W)],
     endpoints: &[usize],
-    dual_var: &[i128],
+    dual_var: &[W],
     blossom_parents: &[Option<usize>],
     blossom_endpoints: &[Vec<usize>],
     blossom_base: &[Option<usize>],
     mate: &HashMap<usize, usize>,
 ) {
-    let dual_var_node_min: i128 = *dual_var[..num_nodes].iter().min().unwrap();
-    let node_dual_offset: i128 = if max_cardinality {
+    let zero = W::default();
+    let dual_var_node_min: W = dual_var[..num_nodes].iter().min().unwrap().clone();
+    let node_dual_offset: W = if max_cardinality {
         // Vertices may have negative dual;
         // find a constant non-negative number to add to all vertex duals.
-        max(0, -dual_var_node_min)
+        max(zero.clone(), -dual_var_node_min.clone())
     } else {
-        0
+        zero.clone()
     };
-    assert!(dual_var_node_min + node_dual_offset >= 0);
-    assert!(*dual_var[num_nodes..].iter().min().unwrap() >= 0);
+    assert!(dual_var_node_min + node_dual_offset.clone() >= zero);
+    assert!(*dual_var[num_nodes..].iter().min().unwrap() >= zero);
     // 0. all edges have non-negative slack and
     // 1. all matched edges have zero slack;
     for (edge, (i, j, weight)) in edges.iter().enumerate().take(num_edges) {
-        let mut s = dual_var[*i] + dual_var[*j] - 2 * weight;
+        let mut s = dual_var[*i].clone() + dual_var[*j].clone() - weight.clone() - weight.clone();
         let mut i_blossoms: Vec<usize> = vec![*i];
         let mut j_blossoms: Vec<usize> = vec![*j];
         while blossom_parents[*i_blossoms.last().unwrap()].is_some() {
@@ -771,24 +812,26 @@ fn verify_optimum(
             if blossom_i != blossom_j {
                 break;
             }
-            s += 2 * dual_var[*blossom_i];
+            s += dual_var[*blossom_i].clone() + dual_var[*blossom_i].clone();
         }
-        assert!(s >= 0);
+        assert!(s >= zero);
 
         if (mate.get(i).is_some() && mate.get(i).unwrap() / 2 == edge)
             || (mate.get(j).is_some() && mate.get(j).unwrap() / 2 == edge)
         {
             assert!(mate[i] / 2 == edge && mate[j] / 2 == edge);
-            assert!(s == 0);
+            assert!(s == zero);
         }
     }
     // 2. all single vertices have zero dual value;
     for (node, dual_var_node) in dual_var.iter().enumerate().take(num_nodes) {
-        assert!(mate.get(&node).is_some() || dual_var_node + node_dual_offset == 0);
+        assert!(
+            mate.get(&node).is_some() || dual_var_node.clone() + node_dual_offset.clone() == zero
+        );
     }
     // 3. all blossoms with positive dual value are full.
     for blossom in num_nodes..2 * num_nodes {
-        if blossom_base[blossom].is_some() && dual_var[blossom] > 0 {
+        if blossom_base[blossom].is_some() && dual_var[blossom] > zero {
             assert!(blossom_endpoints[blossom].len() % 2 == 1);
             for p in blossom_endpoints[blossom].iter().skip(1).step_by(2) {
                 assert!(mate.get(&endpoints[*p]).copied() == Some(p ^ 1));
@@ -821,7 +864,7 @@ fn verify_optimum(
 /// * `max_cardinality` - If set to true compute the maximum-cardinality matching
 ///     with maximum weight among all maximum-cardinality matchings
 /// * `weight_fn` - A callback function that will be give a edge reference and
-///     expected to return a `i128` representing the weight of the edge
+///     expected to return the weight of the edge as a [`MatchingWeight`]
 /// * `verify_optimum_flag`: If true an prior to returning an additional routine
 ///     to verify the optimal solution was found will be run after computing
 ///     the maximum weight matching. If it's true and the found matching is not
@@ -859,7 +902,33 @@ fn verify_optimum(
 /// assert!(maxc_matching.contains(&(1, 2)) || maxc_matching.contains(&(2, 1)));
 /// assert!(maxc_matching.contains(&(3, 4)) || maxc_matching.contains(&(4, 3)));
 /// ```
-pub fn max_weight_matching<G, F, E>(
+///
+/// With arbitrary precision weights:
+/// ```rust
+/// use num_bigint::BigInt;
+/// use retworkx_core::petgraph;
+/// use retworkx_core::max_weight_matching::max_weight_matching;
+/// use retworkx_core::Result;
+///
+/// use hashbrown::HashSet;
+///
+/// // The weights of the outer edges add up to one more than the middle edge,
+/// // which doesn't fit in an i128
+/// let g = petgraph::graph::UnGraph::<(), u32>::from_edges(&[
+///     (0, 1, 0), (1, 2, 1), (2, 3, 0)
+/// ]);
+/// let big: BigInt = BigInt::from(1u8) << 200;
+/// let res: Result<HashSet<(usize, usize)>> = max_weight_matching(
+///     &g,
+///     false,
+///     |e| Ok(if *e.weight() == 1 { big.clone() * 2 } else { big.clone() + 1 }),
+///     true,
+/// );
+/// let matching = res.unwrap();
+/// assert_eq!(matching.len(), 2);
+/// assert!(matching.contains(&(0, 1)) || matching.contains(&(1, 0)));
+/// ```
+pub fn max_weight_matching<G, F, W, E>(
     graph: G,
     max_cardinality: bool,
     mut weight_fn: F,
@@ -872,7 +941,8 @@ where
         + GraphProp<EdgeType = Undirected>
         + GraphBase<NodeId = NodeIndex>
         + IntoEdges,
-    F: FnMut(G::EdgeRef) -> Result<i128, E>,
+    F: FnMut(G::EdgeRef) -> Result<W, E>,
+    W: MatchingWeight,
 {
     let num_edges = graph.edge_count();
     let num_nodes = graph.node_count();
@@ -890,12 +960,12 @@ where
         .enumerate()
         .map(|(index, node_index)| (node_index, index))
         .collect();
-    let mut edges: Vec<(usize, usize, i128)> = Vec::with_capacity(num_edges);
-    let mut max_weight: i128 = 0;
+    let mut edges: Vec<(usize, usize, W)> = Vec::with_capacity(num_edges);
+    let mut max_weight = W::default();
     for edge in graph.edge_references() {
-        let edge_weight: i128 = weight_fn(edge)?;
+        let edge_weight: W = weight_fn(edge)?;
         if edge_weight > max_weight {
-            max_weight = edge_weight;
+            max_weight = edge_weight.clone();
         };
         edges.push((
             node_map[&edge.source()],
@@ -907,7 +977,7 @@ where
     // endpoints[p] is the node index to which endpoint p is attached
     let endpoints: Vec<usize> = (0..2 * num_edges)
         .map(|endpoint| {
-            let edge_tuple = edges[endpoint / 2];
+            let edge_tuple = &edges[endpoint / 2];
             let out_value: usize = if endpoint % 2 == 0 {
                 edge_tuple.0
             } else {
@@ -993,8 +1063,8 @@ where
     // problem.
     // dual_var is for vertex v in 0..num_nodes and blossom b in
     // num_nodes..2* num nodes
-    let mut dual_var: Vec<i128> = vec![max_weight; num_nodes];
-    dual_var.append(&mut vec![0; num_nodes]);
+    let mut dual_var: Vec<W> = vec![max_weight; num_nodes];
+    dual_var.append(&mut vec![W::default(); num_nodes]);
     // If allowed_edge[k] is true, edge k has zero slack in the optimization
     // problem; if allowed_edge[k] is false, the edge's slack may or may not
     // be zero.
@@ -1059,7 +1129,7 @@ where
                 // Scan its neighbors
                 for p in &neighbor_endpoints[v] {
                     let k = *p / 2;
-                    let mut kslack = 0;
+                    let mut kslack = W::default();
                     let w = endpoints[*p];
                     // w is a neighbor of v
                     if in_blossoms[v] == in_blossoms[w] {
@@ -1068,7 +1138,7 @@ where
                     }
                     if !allowed_edge[k] {
                         kslack = slack(k, &dual_var, &edges);
-                        if kslack <= 0 {
+                        if kslack <= W::default() {
                             // edge k has zero slack -> it is allowable
                             allowed_edge[k] = true;
                         }
@@ -1189,14 +1259,14 @@ where
             // (Note that our vertex dual variables, edge slacks and delta's
             // are pre-multiplied by two.)
             let mut delta_type = -1;
-            let mut delta: Option<i128> = None;
+            let mut delta: Option<W> = None;
             let mut delta_edge: Option<usize> = None;
             let mut delta_blossom: Option<usize> = None;
 
             // Compute delta1: the minimum value of any vertex dual.
             if !max_cardinality {
                 delta_type = 1;
-                delta = Some(*dual_var[..num_nodes].iter().min().unwrap());
+                delta = Some(dual_var[..num_nodes].iter().min().unwrap().clone());
             }
 
             // Compute delta2: the minimum slack on any edge between
@@ -1204,7 +1274,7 @@ where
             for v in 0..num_nodes {
                 if labels[in_blossoms[v]] == Some(0) && best_edge[v].is_some() {
                     let d = slack(best_edge[v].unwrap(), &dual_var, &edges);
-                    if delta_type == -1 || Some(d) < delta {
+                    if delta_type == -1 || Some(&d) < delta.as_ref() {
                         delta = Some(d);
                         delta_type = 2;
                         delta_edge = best_edge[v];
@@ -1220,8 +1290,8 @@ where
                     && best_edge[blossom].is_some()
                 {
                     let kslack = slack(best_edge[blossom].unwrap(), &dual_var, &edges);
-                    assert!(kslack % 2 == 0);
-                    let d = Some(kslack / 2);
+                    assert!(kslack.clone() % W::from(2) == W::default());
+                    let d = Some(kslack / W::from(2));
                     if delta_type == -1 || d < delta {
                         delta = d;
                         delta_type = 3;
@@ -1235,9 +1305,9 @@ where
                 if blossom_base[blossom].is_some()
                     && blossom_parents[blossom].is_none()
                     && labels[blossom] == Some(2)
-                    && (delta_type == -1 || dual_var[blossom] < delta.unwrap())
+                    && (delta_type == -1 || Some(&dual_var[blossom]) < delta.as_ref())
                 {
-                    delta = Some(dual_var[blossom]);
+                    delta = Some(dual_var[blossom].clone());
                     delta_type = 4;
                     delta_blossom = Some(blossom);
                 }
@@ -1248,27 +1318,30 @@ where
                 // verifyable
                 assert!(max_cardinality);
                 delta_type = 1;
-                delta = Some(max(0, *dual_var[..num_nodes].iter().min().unwrap()));
+                delta = Some(max(
+                    W::default(),
+                    dual_var[..num_nodes].iter().min().unwrap().clone(),
+                ));
             }
 
             // Update dual variables according to delta.
             for v in 0..num_nodes {
                 if labels[in_blossoms[v]] == Some(1) {
                     // S-vertex: 2*u = 2*u - 2*delta
-                    dual_var[v] -= delta.unwrap();
+                    dual_var[v] -= delta.clone().unwrap();
                 } else if labels[in_blossoms[v]] == Some(2) {
                     // T-vertex: 2*u = 2*u + 2*delta
-                    dual_var[v] += delta.unwrap();
+                    dual_var[v] += delta.clone().unwrap();
                 }
             }
             for b in num_nodes..2 * num_nodes {
                 if blossom_base[b].is_some() && blossom_parents[b].is_none() {
                     if labels[b] == Some(1) {
                         // top-level S-blossom: z = z + 2*delta
-                        dual_var[b] += delta.unwrap();
+                        dual_var[b] += delta.clone().unwrap();
                     } else if labels[b] == Some(2) {
                         // top-level T-blossom: z = z - 2*delta
-                        dual_var[b] -= delta.unwrap();
+                        dual_var[b] -= delta.clone().unwrap();
                     }
                 }
             }
@@ -1279,7 +1352,7 @@ where
             } else if delta_type == 2 {
                 // Use the least-slack edge to continue the search.
                 allowed_edge[delta_edge.unwrap()] = true;
-                let (mut i, mut j, _weight) = edges[delta_edge.unwrap()];
+                let (mut i, mut j, _) = edges[delta_edge.unwrap()];
                 if labels[in_blossoms[i]] == Some(0) {
                     mem::swap(&mut i, &mut j);
                 }
@@ -1288,7 +1361,7 @@ where
             } else if delta_type == 3 {
                 // Use the least-slack edge to continue the search.
                 allowed_edge[delta_edge.unwrap()] = true;
-                let (i, _j, _weight) = edges[delta_edge.unwrap()];
+                let (i, _j, _) = edges[delta_edge.unwrap()];
                 assert!(labels[in_blossoms[i]] == Some(1));
                 queue.push(i);
             } else if delta_type == 4 {
@@ -1325,7 +1398,7 @@ where
             if blossom_parents[blossom].is_none()
                 && blossom_base[blossom].is_some()
                 && labels[blossom] == Some(1)
-                && dual_var[blossom] == 0
+                && dual_var[blossom] == W::default()
             {
                 expand_blossom(
                     blossom,
@@ -0,0 +1,573 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+//! Module for counting the small subgraphs, or motifs, of a graph.
+//!
+//! For undirected graphs the census counts the connected induced subgraphs
+//! with 3 and 4 nodes, the graphlets, and the orbits every node is in [1].
+//! They are found with the ESU algorithm [2], which visits every connected
+//! induced subgraph once from its node of lowest index, so the cost grows
+//! with the number of such subgraphs. The work is split by node over
+//! multiple threads if the graph is large enough and the `parallel` feature
+//! is enabled. For directed graphs the triad census counts the 16 types of
+//! subgraphs induced by the triples of nodes [3] in `O(m Δ)` time, where
+//! `Δ` is the largest number of neighbors of a node. Self loops and
+//! parallel edges are ignored.
+//!
+//! [1] N. Pržulj, "Biological network comparison using graphlet degree
+//! distribution", Bioinformatics 23(2), 2007, pp. e177-e183,
+//! <https://doi.org/10.1093/bioinformatics/btl301>
+//!
+//! [2] S. Wernicke, "Efficient Detection of Network Motifs", IEEE/ACM
+//! Transactions on Computational Biology and Bioinformatics 3(4), 2006,
+//! pp. 347-359, <https://doi.org/10.1109/TCBB.2006.51>
+//!
+//! [3] V. Batagelj and A. Mrvar, "A subquadratic triad census algorithm for
+//! large sparse networks with small maximum degree", Social Networks 23(3),
+//! 2001, pp. 237-243, <https://doi.org/10.1016/S0378-8733(01)00035-1>
+
+use hashbrown::HashMap;
+
+use petgraph::visit::{GraphProp, IntoNeighborsDirected, IntoNodeIdentifiers, NodeIndexable};
+use petgraph::{Directed, Incoming, Outgoing, Undirected};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+use crate::neighbors::{union, SortedNeighbors};
+
+/// The connected undirected graphs with 3 or 4 nodes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Graphlet {
+    /// A path through 3 nodes
+    Path3,
+    /// A cycle through 3 nodes
+    Triangle,
+    /// A path through 4 nodes
+    Path4,
+    /// A node with 3 leaves
+    Star,
+    /// A cycle through 4 nodes
+    Cycle4,
+    /// A triangle with a leaf on one of its nodes
+    TailedTriangle,
+    /// A cycle through 4 nodes with one chord
+    Diamond,
+    /// 4 nodes all connected to each other
+    Clique4,
+}
+
+impl Graphlet {
+    /// All the graphlets in order.
+    pub const ALL: [Graphlet; 8] = [
+        Graphlet::Path3,
+        Graphlet::Triangle,
+        Graphlet::Path4,
+        Graphlet::Star,
+        Graphlet::Cycle4,
+        Graphlet::TailedTriangle,
+        Graphlet::Diamond,
+        Graphlet::Clique4,
+    ];
+
+    /// The name of the graphlet.
+    pub fn name(self) -> &'static str {
+        match self {
+            Graphlet::Path3 => "3-path",
+            Graphlet::Triangle => "triangle",
+            Graphlet::Path4 => "4-path",
+            Graphlet::Star => "3-star",
+            Graphlet::Cycle4 => "4-cycle",
+            Graphlet::TailedTriangle => "tailed-triangle",
+            Graphlet::Diamond => "diamond",
+            Graphlet::Clique4 => "4-clique",
+        }
+    }
+
+    /// The number of nodes of the graphlet.
+    pub fn num_nodes(self) -> usize {
+        match self {
+            Graphlet::Path3 | Graphlet::Triangle => 3,
+            _ => 4,
+        }
+    }
+}
+
+/// The number of node orbits in the graphlets with 2 to 4 nodes.
+pub const NUM_ORBITS: usize = 15;
+
+/// Classify the connected subgraph induced by `nodes`, 3 or 4 of them, and
+/// return its graphlet and the orbit of every node.
+fn classify(neighbors: &SortedNeighbors, nodes: &[usize]) -> (Graphlet, [usize; 4]) {
+    let mut degrees = [0; 4];
+    let mut edges = 0;
+    for i in 0..nodes.len() {
+        for j in i + 1..nodes.len() {
+            if neighbors.get(nodes[i]).binary_search(&nodes[j]).is_ok() {
+                degrees[i] += 1;
+                degrees[j] += 1;
+                edges += 1;
+            }
+        }
+    }
+    let max_degree = degrees.iter().copied().max().unwrap_or(0);
+    let graphlet = match (nodes.len(), edges) {
+        (3, 2) => Graphlet::Path3,
+        (3, _) => Graphlet::Triangle,
+        (_, 3) if max_degree == 3 => Graphlet::Star,
+        (_, 3) => Graphlet::Path4,
+        (_, 4) if max_degree == 3 => Graphlet::TailedTriangle,
+        (_, 4) => Graphlet::Cycle4,
+        (_, 5) => Graphlet::Diamond,
+        _ => Graphlet::Clique4,
+    };
+    let mut orbits = [0; 4];
+    for (orbit, &degree) in orbits.iter_mut().zip(degrees.iter()) {
+        *orbit = match (graphlet, degree) {
+            (Graphlet::Path3, 1) => 1,
+            (Graphlet::Path3, _) => 2,
+            (Graphlet::Triangle, _) => 3,
+            (Graphlet::Path4, 1) => 4,
+            (Graphlet::Path4, _) => 5,
+            (Graphlet::Star, 1) => 6,
+            (Graphlet::Star, _) => 7,
+            (Graphlet::Cycle4, _) => 8,
+            (Graphlet::TailedTriangle, 1) => 9,
+            (Graphlet::TailedTriangle, 2) => 10,
+            (Graphlet::TailedTriangle, _) => 11,
+            (Graphlet::Diamond, 2) => 12,
+            (Graphlet::Diamond, _) => 13,
+            (Graphlet::Clique4, _) => 14,
+        };
+    }
+    (graphlet, orbits)
+}
+
+/// Call `f` with the nodes of every connected induced subgraph with 3 or 4
+/// nodes whose lowest node index is `root`.
+fn for_each_subgraph<F>(neighbors: &SortedNeighbors, root: usize, f: &mut F)
+where
+    F: FnMut(&[usize]),
+{
+    let extension = neighbors
+        .get(root)
+        .iter()
+        .copied()
+        .filter(|&node| node > root)
+        .collect();
+    extend_subgraph(neighbors, root, &mut vec![root], extension, f);
+}
+
+fn extend_subgraph<F>(
+    neighbors: &SortedNeighbors,
+    root: usize,
+    subgraph: &mut Vec<usize>,
+    mut extension: Vec<usize>,
+    f: &mut F,
+) where
+    F: FnMut(&[usize]),
+{
+    if subgraph.len() >= 3 {
+        f(subgraph);
+    }
+    if subgraph.len() == 4 {
+        return;
+    }
+    while let Some(node) = extension.pop() {
+        // Only the exclusive neighbors of the new node are added, the others
+        // are already in the subgraph or its extension
+        let mut next = extension.clone();
+        next.extend(neighbors.get(node).iter().copied().filter(|&neighbor| {
+            neighbor > root
+                && subgraph.iter().all(|&member| {
+                    member != neighbor && neighbors.get(member).binary_search(&neighbor).is_err()
+                })
+        }));
+        subgraph.push(node);
+        extend_subgraph(neighbors, root, subgraph, next, f);
+        subgraph.pop();
+    }
+}
+
+/// Call `fold` with every node which roots connected induced subgraphs, in
+/// parallel if the graph is large enough, and merge the results.
+fn fold_nodes<T, I, F, M>(
+    nodes: &[usize],
+    parallel_threshold: usize,
+    init: I,
+    fold: F,
+    merge: M,
+) -> T
+where
+    T: Send,
+    I: Fn() -> T + Sync + Send,
+    F: Fn(T, usize) -> T + Sync + Send,
+    M: Fn(T, T) -> T + Sync + Send,
+{
+    #[cfg(feature = "parallel")]
+    if nodes.len() >= parallel_threshold {
+        return nodes
+            .par_iter()
+            .fold(&init, |acc, &node| fold(acc, node))
+            .reduce(&init, merge);
+    }
+    #[cfg(not(feature = "parallel"))]
+    let _ = (parallel_threshold, merge);
+    nodes.iter().fold(init(), |acc, &node| fold(acc, node))
+}
+
+/// Count the connected induced subgraphs with 3 and 4 nodes of an
+/// undirected graph by graphlet.
+///
+/// Arguments:
+///
+/// * `graph` - The undirected graph to count the graphlets of
+/// * `parallel_threshold` - The number of nodes to count the graphlets of
+///   in parallel at, if the `parallel` feature is enabled
+///
+/// Returns the number of subgraphs of every [`Graphlet`], including the
+/// ones which don't occur.
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::motifs::{graphlet_census, Graphlet};
+///
+/// // A triangle with a leaf
+/// let graph = petgraph::graph::UnGraph::<(), ()>::from_edges(&[
+///     (0, 1), (1, 2), (2, 0), (2, 3),
+/// ]);
+/// let census = graphlet_census(&graph, 100);
+/// assert_eq!(census[&Graphlet::Path3], 2);
+/// assert_eq!(census[&Graphlet::Triangle], 1);
+/// assert_eq!(census[&Graphlet::TailedTriangle], 1);
+/// assert_eq!(census[&Graphlet::Diamond], 0);
+/// ```
+pub fn graphlet_census<G>(graph: G, parallel_threshold: usize) -> HashMap<Graphlet, usize>
+where
+    G: IntoNeighborsDirected
+        + IntoNodeIdentifiers
+        + NodeIndexable
+        + GraphProp<EdgeType = Undirected>,
+{
+    let nodes: Vec<usize> = graph
+        .node_identifiers()
+        .map(|node| graph.to_index(node))
+        .collect();
+    let neighbors = SortedNeighbors::new(graph, Outgoing);
+    let counts = fold_nodes(
+        &nodes,
+        parallel_threshold,
+        || [0; 8],
+        |mut counts, root| {
+            for_each_subgraph(&neighbors, root, &mut |subgraph| {
+                let (graphlet, _) = classify(&neighbors, subgraph);
+                counts[graphlet as usize] += 1;
+            });
+            counts
+        },
+        |mut a, b| {
+            for (count, other) in a.iter_mut().zip(b.iter()) {
+                *count += other;
+            }
+            a
+        },
+    );
+    Graphlet::ALL
+        .iter()
+        .map(|&graphlet| (graphlet, counts[graphlet as usize]))
+        .collect()
+}
+
+/// Count the orbits of the graphlets with 2 to 4 nodes every node of an
+/// undirected graph is in, its graphlet degree vector.
+///
+/// The orbits are numbered as in [1]:
+///
+/// * 0 - an edge
+/// * 1 and 2 - the end and middle of a [`Graphlet::Path3`]
+/// * 3 - a [`Graphlet::Triangle`]
+/// * 4 and 5 - an end and an inner node of a [`Graphlet::Path4`]
+/// * 6 and 7 - a leaf and the center of a [`Graphlet::Star`]
+/// * 8 - a [`Graphlet::Cycle4`]
+/// * 9, 10 and 11 - the leaf, a triangle node with 2 neighbors, and the
+///   node with 3 neighbors of a [`Graphlet::TailedTriangle`]
+/// * 12 and 13 - a node with 2 and with 3 neighbors of a
+///   [`Graphlet::Diamond`]
+/// * 14 - a [`Graphlet::Clique4`]
+///
+/// Arguments:
+///
+/// * `graph` - The undirected graph to count the orbits of
+/// * `parallel_threshold` - The number of nodes to count the orbits of in
+///   parallel at, if the `parallel` feature is enabled
+///
+/// Returns the number of times every node is in each orbit as a [`Vec`]
+/// indexed by node index, `None` for an index without a node.
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::motifs::graphlet_orbits;
+///
+/// // A star with 3 leaves
+/// let graph = petgraph::graph::UnGraph::<(), ()>::from_edges(&[
+///     (0, 1), (0, 2), (0, 3),
+/// ]);
+/// let orbits = graphlet_orbits(&graph, 100);
+/// assert_eq!(orbits[0], Some([3, 0, 3, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0]));
+/// assert_eq!(orbits[1], Some([1, 2, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0]));
+/// ```
+pub fn graphlet_orbits<G>(graph: G, parallel_threshold: usize) -> Vec<Option<[usize; NUM_ORBITS]>>
+where
+    G: IntoNeighborsDirected
+        + IntoNodeIdentifiers
+        + NodeIndexable
+        + GraphProp<EdgeType = Undirected>,
+{
+    let nodes: Vec<usize> = graph
+        .node_identifiers()
+        .map(|node| graph.to_index(node))
+        .collect();
+    let neighbors = SortedNeighbors::new(graph, Outgoing);
+    let node_bound = graph.node_bound();
+    let mut counts = fold_nodes(
+        &nodes,
+        parallel_threshold,
+        || vec![[0; NUM_ORBITS]; node_bound],
+        |mut counts, root| {
+            for_each_subgraph(&neighbors, root, &mut |subgraph| {
+                let (_, orbits) = classify(&neighbors, subgraph);
+                for (&node, &orbit) in subgraph.iter().zip(orbits.iter()) {
+                    counts[node][orbit] += 1;
+                }
+            });
+            counts
+        },
+        |mut a, b| {
+            for (node_counts, other) in a.iter_mut().zip(b.iter()) {
+                for (count, other) in node_counts.iter_mut().zip(other.iter()) {
+                    *count += other;
+                }
+            }
+            a
+        },
+    );
+    let mut out = vec![None; node_bound];
+    for &node in &nodes {
+        counts[node][0] = neighbors.get(node).len();
+        out[node] = Some(counts[node]);
+    }
+    out
+}
+
+/// The types of the subgraphs induced by 3 nodes of a directed graph, named
+/// by the number of mutual, asymmetric and null pairs of nodes, and for the
+/// ones which have several types with the same numbers, whether the edges
+/// point down, up, are cyclic, or transitive.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Triad {
+    /// No edges
+    T003,
+    /// One edge
+    T012,
+    /// One mutual pair
+    T102,
+    /// A node with edges to the 2 others
+    T021D,
+    /// A node with edges from the 2 others
+    T021U,
+    /// A path through the 3 nodes
+    T021C,
+    /// A mutual pair with an edge from the third node
+    T111D,
+    /// A mutual pair with an edge to the third node
+    T111U,
+    /// A transitive triangle
+    T030T,
+    /// A cycle through the 3 nodes
+    T030C,
+    /// A node in mutual pairs with the 2 others
+    T201,
+    /// A mutual pair with edges from the third node
+    T120D,
+    /// A mutual pair with edges to the third node
+    T120U,
+    /// A mutual pair with a path through the third node
+    T120C,
+    /// 2 mutual pairs and an edge
+    T210,
+    /// All the pairs mutual
+    T300,
+}
+
+impl Triad {
+    /// All the triad types in order.
+    pub const ALL: [Triad; 16] = [
+        Triad::T003,
+        Triad::T012,
+        Triad::T102,
+        Triad::T021D,
+        Triad::T021U,
+        Triad::T021C,
+        Triad::T111D,
+        Triad::T111U,
+        Triad::T030T,
+        Triad::T030C,
+        Triad::T201,
+        Triad::T120D,
+        Triad::T120U,
+        Triad::T120C,
+        Triad::T210,
+        Triad::T300,
+    ];
+
+    /// The name of the triad type, such as `"021D"`.
+    pub fn name(self) -> &'static str {
+        match self {
+            Triad::T003 => "003",
+            Triad::T012 => "012",
+            Triad::T102 => "102",
+            Triad::T021D => "021D",
+            Triad::T021U => "021U",
+            Triad::T021C => "021C",
+            Triad::T111D => "111D",
+            Triad::T111U => "111U",
+            Triad::T030T => "030T",
+            Triad::T030C => "030C",
+            Triad::T201 => "201",
+            Triad::T120D => "120D",
+            Triad::T120U => "120U",
+            Triad::T120C => "120C",
+            Triad::T210 => "210",
+            Triad::T300 => "300",
+        }
+    }
+
+    /// The type of the triad with edges `edges[a][b]` from node `a` to `b`.
+    fn from_edges(edges: [[bool; 3]; 3]) -> Self {
+        let out_degree = |a: usize| (0..3).filter(|&b| edges[a][b]).count();
+        let in_degree = |b: usize| (0..3).filter(|&a| edges[a][b]).count();
+        let (mut mutual, mut asymmetric) = (0, 0);
+        // The node not in a mutual pair, if there's only one such pair
+        let mut outside = 0;
+        for &(a, b, c) in &[(0, 1, 2), (0, 2, 1), (1, 2, 0)] {
+            match (edges[a][b], edges[b][a]) {
+                (true, true) => {
+                    mutual += 1;
+                    outside = c;
+                }
+                (false, false) => (),
+                _ => asymmetric += 1,
+            }
+        }
+        match (mutual, asymmetric) {
+            (0, 0) => Triad::T003,
+            (0, 1) => Triad::T012,
+            (1, 0) => Triad::T102,
+            (0, 2) if (0..3).any(|a| out_degree(a) == 2) => Triad::T021D,
+            (0, 2) if (0..3).any(|b| in_degree(b) == 2) => Triad::T021U,
+            (0, 2) => Triad::T021C,
+            (1, 1) if out_degree(outside) == 1 => Triad::T111D,
+            (1, 1) => Triad::T111U,
+            (0, 3) if (0..3).any(|a| out_degree(a) == 2) => Triad::T030T,
+            (0, 3) => Triad::T030C,
+            (2, 0) => Triad::T201,
+            (1, 2) if out_degree(outside) == 2 => Triad::T120D,
+            (1, 2) if in_degree(outside) == 2 => Triad::T120U,
+            (1, 2) => Triad::T120C,
+            (2, 1) => Triad::T210,
+            _ => Triad::T300,
+        }
+    }
+}
+
+/// Count the subgraphs induced by the triples of nodes of a directed graph
+/// by [`Triad`] type.
+///
+/// Arguments:
+///
+/// * `graph` - The directed graph to take the triad census of
+///
+/// Returns the number of triples of nodes of every [`Triad`] type,
+/// including the ones which don't occur.
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::motifs::{triad_census, Triad};
+///
+/// // A cycle through 3 nodes and an isolated node
+/// let mut graph = petgraph::graph::DiGraph::<(), ()>::from_edges(&[
+///     (0, 1), (1, 2), (2, 0),
+/// ]);
+/// graph.add_node(());
+/// let census = triad_census(&graph);
+/// assert_eq!(census[&Triad::T030C], 1);
+/// assert_eq!(census[&Triad::T012], 3);
+/// assert_eq!(census[&Triad::T003], 0);
+/// ```
+pub fn triad_census<G>(graph: G) -> HashMap<Triad, usize>
+where
+    G: IntoNeighborsDirected + IntoNodeIdentifiers + NodeIndexable + GraphProp<EdgeType = Directed>,
+{
+    let out_neighbors = SortedNeighbors::new(graph, Outgoing);
+    let in_neighbors = SortedNeighbors::new(graph, Incoming);
+    let nodes: Vec<usize> = graph
+        .node_identifiers()
+        .map(|node| graph.to_index(node))
+        .collect();
+    let all_neighbors: Vec<Vec<usize>> = (0..graph.node_bound())
+        .map(|node| union(out_neighbors.get(node), in_neighbors.get(node)))
+        .collect();
+    let has_edge = |a: usize, b: usize| out_neighbors.get(a).binary_search(&b).is_ok();
+    let is_neighbor = |a: usize, b: usize| all_neighbors[a].binary_search(&b).is_ok();
+
+    let num_nodes = nodes.len();
+    let mut counts: HashMap<Triad, usize> = Triad::ALL.iter().map(|&triad| (triad, 0)).collect();
+    for &v in &nodes {
+        for &u in all_neighbors[v].iter().filter(|&&u| u > v) {
+            let linked: Vec<usize> = union(&all_neighbors[v], &all_neighbors[u])
+                .into_iter()
+                .filter(|&w| w != u && w != v)
+                .collect();
+            // The triads of the pair with a node linked to neither of them
+            let dyad = if has_edge(v, u) && has_edge(u, v) {
+                Triad::T102
+            } else {
+                Triad::T012
+            };
+            *counts.get_mut(&dyad).unwrap() += num_nodes - linked.len() - 2;
+            // Every connected triad is counted once, from its pair of nodes
+            // with the lowest indices
+            for &w in &linked {
+                if u < w || (v < w && w < u && !is_neighbor(v, w)) {
+                    let triple = [v, u, w];
+                    let mut edges = [[false; 3]; 3];
+                    for (a, row) in edges.iter_mut().enumerate() {
+                        for (b, edge) in row.iter_mut().enumerate() {
+                            *edge = a != b && has_edge(triple[a], triple[b]);
+                        }
+                    }
+                    *counts.get_mut(&Triad::from_edges(edges)).unwrap() += 1;
+                }
+            }
+        }
+    }
+    let total = if num_nodes < 3 {
+        0
+    } else {
+        num_nodes * (num_nodes - 1) / 2 * (num_nodes - 2) / 3
+    };
+    let counted: usize = counts.values().sum();
+    counts.insert(Triad::T003, total - counted);
+    counts
+}
@@ -0,0 +1,353 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+//! Module for set operations on sorted lists of neighbors.
+//!
+//! The neighbors of a node are stored as a sorted list of node indices
+//! without duplicates, which makes joining the neighborhoods of two nodes a
+//! merge of two sorted lists instead of hash set lookups. The set
+//! operations switch to a galloping (exponential) search in the longer list
+//! when one list is much shorter than the other, so intersecting the few
+//! neighbors of a node with the many neighbors of a hub only costs a few
+//! binary searches.
+
+use std::cmp::Ordering;
+
+use petgraph::visit::{IntoNeighbors, IntoNeighborsDirected, IntoNodeIdentifiers, NodeIndexable};
+use petgraph::Direction;
+
+/// Gallop through the longer list once it's this many times longer than the
+/// shorter one, below the ratio a linear merge is faster.
+const GALLOP_RATIO: usize = 16;
+
+/// Return the position of the first item of `items` which isn't less than
+/// `target`, searching from `start` with exponentially growing steps before
+/// a binary search.
+fn gallop<T: Ord>(items: &[T], start: usize, target: &T) -> usize {
+    let mut lo = start;
+    let mut step = 1;
+    let mut hi = start;
+    while hi < items.len() && items[hi] < *target {
+        lo = hi + 1;
+        hi = start + step;
+        step *= 2;
+    }
+    let mut hi = hi.min(items.len());
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+        if items[mid] < *target {
+            lo = mid + 1;
+        } else {
+            hi = mid;
+        }
+    }
+    lo
+}
+
+/// Call `f` with every item of both sorted lists `a` and `b`, in order.
+fn for_each_common<T, F>(a: &[T], b: &[T], mut f: F)
+where
+    T: Ord,
+    F: FnMut(&T),
+{
+    let (short, long) = if a.len() <= b.len() { (a, b) } else { (b, a) };
+    if short.is_empty() {
+        return;
+    }
+    if short.len() * GALLOP_RATIO < long.len() {
+        let mut pos = 0;
+        for item in short {
+            pos = gallop(long, pos, item);
+            if pos == long.len() {
+                return;
+            }
+            if long[pos] == *item {
+                f(item);
+                pos += 1;
+            }
+        }
+    } else {
+        let (mut i, mut j) = (0, 0);
+        while i < short.len() && j < long.len() {
+            match short[i].cmp(&long[j]) {
+                Ordering::Less => i += 1,
+                Ordering::Greater => j += 1,
+                Ordering::Equal => {
+                    f(&short[i]);
+                    i += 1;
+                    j += 1;
+                }
+            }
+        }
+    }
+}
+
+/// Return the items of both sorted lists `a` and `b`, sorted.
+///
+/// The lists must be sorted in increasing order without duplicates.
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::neighbors::intersection;
+///
+/// assert_eq!(intersection(&[1, 3, 5, 7], &[2, 3, 4, 7, 8]), vec![3, 7]);
+/// ```
+pub fn intersection<T: Ord + Copy>(a: &[T], b: &[T]) -> Vec<T> {
+    let mut common = Vec::new();
+    for_each_common(a, b, |item| common.push(*item));
+    common
+}
+
+/// Return the number of items of both sorted lists `a` and `b`, without
+/// building the intersection.
+///
+/// The lists must be sorted in increasing order without duplicates.
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::neighbors::intersection_len;
+///
+/// let hub: Vec<usize> = (0..1000).collect();
+/// assert_eq!(intersection_len(&[5, 500, 5000], &hub), 2);
+/// ```
+pub fn intersection_len<T: Ord>(a: &[T], b: &[T]) -> usize {
+    let mut len = 0;
+    for_each_common(a, b, |_| len += 1);
+    len
+}
+
+/// Return the items of either sorted list `a` or `b`, sorted and without
+/// duplicates.
+///
+/// The lists must be sorted in increasing order without duplicates.
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::neighbors::union;
+///
+/// assert_eq!(union(&[1, 3, 5], &[2, 3, 6]), vec![1, 2, 3, 5, 6]);
+/// ```
+pub fn union<T: Ord + Copy>(a: &[T], b: &[T]) -> Vec<T> {
+    let mut all = Vec::with_capacity(a.len() + b.len());
+    let (mut i, mut j) = (0, 0);
+    while i < a.len() && j < b.len() {
+        match a[i].cmp(&b[j]) {
+            Ordering::Less => {
+                all.push(a[i]);
+                i += 1;
+            }
+            Ordering::Greater => {
+                all.push(b[j]);
+                j += 1;
+            }
+            Ordering::Equal => {
+                all.push(a[i]);
+                i += 1;
+                j += 1;
+            }
+        }
+    }
+    all.extend_from_slice(&a[i..]);
+    all.extend_from_slice(&b[j..]);
+    all
+}
+
+/// Return the items of the sorted list `a` which aren't in the sorted list
+/// `b`, sorted.
+///
+/// The lists must be sorted in increasing order without duplicates.
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::neighbors::difference;
+///
+/// assert_eq!(difference(&[1, 3, 5, 7], &[3, 4, 7]), vec![1, 5]);
+/// ```
+pub fn difference<T: Ord + Copy>(a: &[T], b: &[T]) -> Vec<T> {
+    let mut rest = Vec::with_capacity(a.len());
+    if a.len() * GALLOP_RATIO < b.len() {
+        let mut pos = 0;
+        for item in a {
+            pos = gallop(b, pos, item);
+            if pos == b.len() || b[pos] != *item {
+                rest.push(*item);
+            }
+        }
+    } else {
+        let mut j = 0;
+        for item in a {
+            while j < b.len() && b[j] < *item {
+                j += 1;
+            }
+            if j == b.len() || b[j] != *item {
+                rest.push(*item);
+            }
+        }
+    }
+    rest
+}
+
+/// Return the sorted node indices of the neighbors of `node`, without
+/// duplicates and without `node` itself.
+fn sorted_neighbors<I>(
+    graph: impl NodeIndexable<NodeId = I>,
+    node: I,
+    neighbors: impl Iterator<Item = I>,
+) -> Vec<usize>
+where
+    I: Copy + PartialEq,
+{
+    let index = graph.to_index(node);
+    let mut sorted: Vec<usize> = neighbors
+        .map(|neighbor| graph.to_index(neighbor))
+        .filter(|neighbor| *neighbor != index)
+        .collect();
+    sorted.sort_unstable();
+    sorted.dedup();
+    sorted
+}
+
+/// The neighbors of every node of a graph, as sorted lists of node indices.
+///
+/// The lists are built once from the graph, without duplicates for parallel
+/// edges and without self loops, so the neighborhoods of many pairs of nodes
+/// can then be joined with the sorted list operations of this module.
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::neighbors::SortedNeighbors;
+///
+/// let g = petgraph::graph::UnGraph::<(), ()>::from_edges(&[
+///     (0, 1), (0, 2), (1, 2), (2, 3), (3, 3)
+/// ]);
+/// let neighbors = SortedNeighbors::new(&g, petgraph::Direction::Outgoing);
+/// assert_eq!(neighbors.get(2), &[0, 1, 3]);
+/// assert_eq!(neighbors.get(3), &[2]);
+/// assert_eq!(neighbors.common_neighbors(0, 1), vec![2]);
+/// assert_eq!(neighbors.jaccard_similarity(0, 3), 0.5);
+/// ```
+#[derive(Clone, Debug)]
+pub struct SortedNeighbors {
+    neighbors: Vec<Vec<usize>>,
+}
+
+impl SortedNeighbors {
+    /// Build the sorted neighbors in direction `dir` of every node of
+    /// `graph`, which are all the neighbors for an undirected graph.
+    pub fn new<G>(graph: G, dir: Direction) -> Self
+    where
+        G: IntoNeighborsDirected + IntoNodeIdentifiers + NodeIndexable,
+    {
+        let mut neighbors = vec![Vec::new(); graph.node_bound()];
+        for node in graph.node_identifiers() {
+            neighbors[graph.to_index(node)] =
+                sorted_neighbors(graph, node, graph.neighbors_directed(node, dir));
+        }
+        SortedNeighbors { neighbors }
+    }
+
+    /// Return the sorted neighbors of the node with index `node`, which are
+    /// empty if there's no such node.
+    pub fn get(&self, node: usize) -> &[usize] {
+        self.neighbors.get(node).map_or(&[], |neighbors| neighbors)
+    }
+
+    /// Return the sorted indices of the nodes which are neighbors of both
+    /// `a` and `b`, other than `a` and `b`.
+    pub fn common_neighbors(&self, a: usize, b: usize) -> Vec<usize> {
+        let mut common = Vec::new();
+        for_each_common(self.get(a), self.get(b), |node| {
+            if *node != a && *node != b {
+                common.push(*node);
+            }
+        });
+        common
+    }
+
+    /// Return the Jaccard similarity of the neighbors of `a` and `b`, the
+    /// number of their common neighbors divided by the number of nodes which
+    /// are a neighbor of either, or 0 if neither has a neighbor.
+    pub fn jaccard_similarity(&self, a: usize, b: usize) -> f64 {
+        jaccard(self.get(a), self.get(b))
+    }
+}
+
+fn jaccard(a: &[usize], b: &[usize]) -> f64 {
+    let common = intersection_len(a, b);
+    let all = a.len() + b.len() - common;
+    if all == 0 {
+        0.0
+    } else {
+        common as f64 / all as f64
+    }
+}
+
+/// Return the nodes which are neighbors of both `a` and `b`, other than `a`
+/// and `b`, in increasing order of their node indices.
+///
+/// For a directed graph the neighbors of a node are its successors.
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::neighbors::common_neighbors;
+///
+/// let g = petgraph::graph::UnGraph::<(), ()>::from_edges(&[
+///     (0, 2), (0, 3), (1, 3), (1, 2), (1, 4), (0, 1)
+/// ]);
+/// assert_eq!(common_neighbors(&g, 0.into(), 1.into()), vec![2.into(), 3.into()]);
+/// ```
+pub fn common_neighbors<G>(graph: G, a: G::NodeId, b: G::NodeId) -> Vec<G::NodeId>
+where
+    G: IntoNeighbors + NodeIndexable,
+{
+    let (a_index, b_index) = (graph.to_index(a), graph.to_index(b));
+    let a_neighbors = sorted_neighbors(graph, a, graph.neighbors(a));
+    let b_neighbors = sorted_neighbors(graph, b, graph.neighbors(b));
+    let mut common = Vec::new();
+    for_each_common(&a_neighbors, &b_neighbors, |node| {
+        if *node != a_index && *node != b_index {
+            common.push(graph.from_index(*node));
+        }
+    });
+    common
+}
+
+/// Return the Jaccard similarity of the neighbors of `a` and `b`, the number
+/// of their common neighbors divided by the number of nodes which are a
+/// neighbor of either, or 0 if neither has a neighbor.
+///
+/// For a directed graph the neighbors of a node are its successors. Self
+/// loops are ignored. Use [`SortedNeighbors`] to compute the similarity of
+/// many pairs of nodes without sorting the neighbors of a node every time.
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::neighbors::jaccard_similarity;
+///
+/// let g = petgraph::graph::UnGraph::<(), ()>::from_edges(&[
+///     (0, 2), (0, 3), (1, 3), (1, 4)
+/// ]);
+/// // {2, 3} and {3, 4} share 1 of 3 nodes
+/// assert_eq!(jaccard_similarity(&g, 0.into(), 1.into()), 1.0 / 3.0);
+/// ```
+pub fn jaccard_similarity<G>(graph: G, a: G::NodeId, b: G::NodeId) -> f64
+where
+    G: IntoNeighbors + NodeIndexable,
+{
+    jaccard(
+        &sorted_neighbors(graph, a, graph.neighbors(a)),
+        &sorted_neighbors(graph, b, graph.neighbors(b)),
+    )
+}
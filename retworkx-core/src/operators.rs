@@ -0,0 +1,70 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+//! Module for graph operators, which derive a new graph from another one.
+
+use petgraph::visit::{GraphProp, IntoNeighbors, IntoNodeIdentifiers, NodeIndexable};
+
+use crate::traversal::NeighborhoodExpansion;
+
+/// Return the edges of the `k`-th power of a graph.
+///
+/// The `k`-th power of a graph has the same nodes, and an edge between every
+/// pair of distinct nodes with a path of at most `k` edges between them in
+/// the graph. For a directed graph the paths follow the edge directions, so
+/// there is an edge from `u` to `v` if `v` can be reached from `u` in at most
+/// `k` hops. The neighborhood of every node is expanded with a
+/// [`NeighborhoodExpansion`] which is reused between nodes, so this takes
+/// time proportional to the sizes of the `k`-hop neighborhoods and the
+/// edges out of them, and a dense matrix power is never computed.
+///
+/// Arguments:
+///
+/// * `graph` - The graph to get the power of.
+/// * `k` - The power, the maximum number of hops between the endpoints of
+///   an edge.
+///
+/// Returns the edges of the power. For an undirected graph each edge is
+/// returned once, with the endpoint with the lower index first. The edges
+/// are grouped by their first endpoint in the order of `node_identifiers()`.
+/// Self loops are never returned, and a `k` of 0 has no edges.
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::operators::power_edges;
+///
+/// let graph = petgraph::graph::UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 3)]);
+/// let mut edges: Vec<(usize, usize)> = power_edges(&graph, 2)
+///     .iter()
+///     .map(|(u, v)| (u.index(), v.index()))
+///     .collect();
+/// edges.sort_unstable();
+/// assert_eq!(edges, vec![(0, 1), (0, 2), (1, 2), (1, 3), (2, 3)]);
+/// ```
+pub fn power_edges<G>(graph: G, k: usize) -> Vec<(G::NodeId, G::NodeId)>
+where
+    G: GraphProp + IntoNodeIdentifiers + IntoNeighbors + NodeIndexable,
+{
+    let directed = graph.is_directed();
+    let mut expansion = NeighborhoodExpansion::new();
+    let mut edges = Vec::new();
+    for source in graph.node_identifiers() {
+        let source_index = graph.to_index(source);
+        expansion.expand(graph, source, k, |node, depth| {
+            if depth > 0 && (directed || source_index < graph.to_index(node)) {
+                edges.push((source, node));
+            }
+        });
+    }
+    edges
+}
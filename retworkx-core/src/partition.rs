@@ -0,0 +1,773 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+//! Module for partitioning graphs, for splitting a partitioned graph into
+//! parts for distributed processing, and for aggregating the groups of a
+//! partition into the nodes of a smaller graph.
+
+use std::collections::{BinaryHeap, VecDeque};
+
+use hashbrown::HashMap;
+use petgraph::visit::{
+    EdgeRef, GraphProp, IntoEdgeReferences, IntoNeighborsDirected, IntoNodeIdentifiers,
+    NodeIndexable,
+};
+use petgraph::Direction::{Incoming, Outgoing};
+use rand::prelude::*;
+use rand_pcg::Pcg64;
+
+use crate::coarsening::{coarsen_levels, CoarseGraph};
+use crate::err::{CallbackError, InvalidInputError};
+use crate::min_scored::MinScored;
+
+/// A part of a partitioned graph.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GraphPart<N, E> {
+    /// The nodes of the part, in the order of their node indices.
+    pub nodes: Vec<N>,
+    /// The halo (or ghost) nodes of the part: the nodes of other parts
+    /// within the halo depth of a node of the part, in order of distance.
+    pub halo_nodes: Vec<N>,
+    /// The cut edges with an endpoint in the part, in the order of their
+    /// edge indices.
+    pub cut_edges: Vec<E>,
+}
+
+/// A graph split into parts along the edges between them.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EdgeCutPartition<N, E> {
+    /// The parts, indexed by part id.
+    pub parts: Vec<GraphPart<N, E>>,
+    /// The cut edges, the edges with endpoints in different parts, in the
+    /// order of their edge indices.
+    pub cut_edges: Vec<E>,
+}
+
+/// Split a partitioned graph into parts along the edges between them.
+///
+/// For every part this collects its nodes, its halo nodes and its cut edges.
+/// The halo nodes of a part are the nodes of other parts with a path of at
+/// most `halo_depth` edges from a node of the part, ignoring the direction
+/// of the edges. They are the nodes a distributed job processing the part
+/// needs a copy of, for example to compute a `halo_depth` hop neighborhood
+/// aggregation of every node of the part. A `halo_depth` of 0 gives no halo
+/// nodes.
+///
+/// Arguments:
+///
+/// * `graph` - The graph to split
+/// * `num_parts` - The number of parts
+/// * `part_fn` - A callable that returns the part id of a node, which must be
+///   less than `num_parts`
+/// * `halo_depth` - The maximum number of edges from a node of a part to its
+///   halo nodes
+///
+/// Returns an [`InvalidInputError`] if `part_fn` returns a part id of
+/// `num_parts` or more.
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::partition::edge_cut_partition;
+///
+/// // Split the path 0 - 1 - 2 - 3 - 4 into parts [0, 1] and [2, 3, 4]
+/// let g = petgraph::graph::UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 3), (3, 4)]);
+/// let partition = edge_cut_partition(&g, 2, |n| if n.index() < 2 { 0 } else { 1 }, 2).unwrap();
+/// let halo: Vec<usize> = partition.parts[0].halo_nodes.iter().map(|n| n.index()).collect();
+/// assert_eq!(halo, vec![2, 3]);
+/// let cut: Vec<usize> = partition.cut_edges.iter().map(|e| e.index()).collect();
+/// assert_eq!(cut, vec![1]);
+/// ```
+pub fn edge_cut_partition<G, F>(
+    graph: G,
+    num_parts: usize,
+    part_fn: F,
+    halo_depth: usize,
+) -> Result<EdgeCutPartition<G::NodeId, G::EdgeId>, InvalidInputError>
+where
+    G: IntoEdgeReferences + IntoNeighborsDirected + IntoNodeIdentifiers + NodeIndexable,
+    F: Fn(G::NodeId) -> usize,
+{
+    let mut parts: Vec<GraphPart<G::NodeId, G::EdgeId>> = (0..num_parts)
+        .map(|_| GraphPart {
+            nodes: Vec::new(),
+            halo_nodes: Vec::new(),
+            cut_edges: Vec::new(),
+        })
+        .collect();
+    let mut node_parts = vec![usize::MAX; graph.node_bound()];
+    for node in graph.node_identifiers() {
+        let part = part_fn(node);
+        if part >= num_parts {
+            return Err(InvalidInputError::InvalidParameter {
+                name: "part_fn",
+                reason: "a part id is not less than num_parts",
+            });
+        }
+        node_parts[graph.to_index(node)] = part;
+        parts[part].nodes.push(node);
+    }
+    for part in parts.iter_mut() {
+        part.nodes.sort_by_key(|n| graph.to_index(*n));
+    }
+
+    let mut cut_edges = Vec::new();
+    for edge in graph.edge_references() {
+        let source_part = node_parts[graph.to_index(edge.source())];
+        let target_part = node_parts[graph.to_index(edge.target())];
+        if source_part != target_part {
+            parts[source_part].cut_edges.push(edge.id());
+            parts[target_part].cut_edges.push(edge.id());
+            cut_edges.push(edge.id());
+        }
+    }
+
+    if halo_depth > 0 {
+        // The part id plus 1 of the last part whose search visited a node
+        let mut visited = vec![0; graph.node_bound()];
+        let mut queue = VecDeque::new();
+        for (part_id, part) in parts.iter_mut().enumerate() {
+            for node in &part.nodes {
+                visited[graph.to_index(*node)] = part_id + 1;
+                queue.push_back((*node, 0));
+            }
+            while let Some((node, depth)) = queue.pop_front() {
+                if depth == halo_depth {
+                    continue;
+                }
+                let neighbors = graph
+                    .neighbors_directed(node, Outgoing)
+                    .chain(graph.neighbors_directed(node, Incoming));
+                for neighbor in neighbors {
+                    let index = graph.to_index(neighbor);
+                    if visited[index] != part_id + 1 {
+                        visited[index] = part_id + 1;
+                        part.halo_nodes.push(neighbor);
+                        queue.push_back((neighbor, depth + 1));
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(EdgeCutPartition { parts, cut_edges })
+}
+
+/// The summary of a group of nodes aggregated into a single node.
+#[derive(Clone, Debug, PartialEq)]
+pub struct GroupSummary<N> {
+    /// The nodes of the group, in the order of their node indices.
+    pub nodes: Vec<N>,
+    /// The number of edges between two nodes of the group, including self
+    /// loops.
+    pub internal_edges: usize,
+    /// The sum of the weights of the edges between two nodes of the group.
+    pub internal_weight: f64,
+    /// The number of internal edges divided by the number of pairs of
+    /// distinct nodes of the group (ordered pairs for a directed graph), or
+    /// 0 if the group has less than 2 nodes. It can exceed 1 if the graph
+    /// has parallel edges or self loops.
+    pub density: f64,
+}
+
+/// The summary of the edges between two groups of nodes, aggregated into a
+/// single edge.
+#[derive(Clone, Debug, PartialEq)]
+pub struct GroupEdgeSummary {
+    /// The group of the sources of the edges.
+    pub source: usize,
+    /// The group of the targets of the edges.
+    pub target: usize,
+    /// The number of edges.
+    pub count: usize,
+    /// The sum of the weights of the edges.
+    pub weight: f64,
+}
+
+/// A graph whose groups of nodes are aggregated into single nodes.
+#[derive(Clone, Debug, PartialEq)]
+pub struct AggregatedGraph<N> {
+    /// The summary of every group, indexed by group id.
+    pub groups: Vec<GroupSummary<N>>,
+    /// The summary of the edges between every pair of groups with at least
+    /// one edge between them, sorted by source and target group.
+    pub edges: Vec<GroupEdgeSummary>,
+}
+
+/// Aggregate the groups of nodes of a graph into the nodes of a smaller
+/// graph, with summary statistics of every group and of the edges between
+/// every pair of groups.
+///
+/// This is the quotient graph of the grouping, the edges between two nodes
+/// of different groups are merged into a single edge between the groups
+/// with their count and their total weight. For an undirected graph the
+/// source group of an aggregated edge is the lower of the two groups. The
+/// edges between nodes of the same group are summarized in the group.
+///
+/// Arguments:
+///
+/// * `graph` - The graph to aggregate
+/// * `num_groups` - The number of groups
+/// * `group_fn` - A callable that returns the group id of a node, which must
+///   be less than `num_groups`
+/// * `weight_fn` - A callable that returns the weight of an edge, or an
+///   error which is returned right away
+///
+/// # Panics
+///
+/// If `group_fn` returns a group id of `num_groups` or more.
+///
+/// # Example
+/// ```rust
+/// use std::convert::Infallible;
+///
+/// use retworkx_core::petgraph;
+/// use retworkx_core::partition::aggregate_graph;
+///
+/// // Two triangles joined by two edges
+/// let g = petgraph::graph::UnGraph::<(), f64>::from_edges(&[
+///     (0, 1, 1.0), (1, 2, 1.0), (2, 0, 1.0),
+///     (3, 4, 2.0), (4, 5, 2.0), (5, 3, 2.0),
+///     (0, 3, 0.5), (1, 4, 0.5),
+/// ]);
+/// let aggregated = aggregate_graph(
+///     &g,
+///     2,
+///     |n| n.index() / 3,
+///     |e| Ok::<f64, Infallible>(*e.weight()),
+/// ).unwrap();
+/// assert_eq!(aggregated.groups[1].internal_edges, 3);
+/// assert_eq!(aggregated.groups[1].internal_weight, 6.0);
+/// assert_eq!(aggregated.groups[1].density, 1.0);
+/// assert_eq!(aggregated.edges.len(), 1);
+/// assert_eq!(aggregated.edges[0].count, 2);
+/// assert_eq!(aggregated.edges[0].weight, 1.0);
+/// ```
+pub fn aggregate_graph<G, F, W, E>(
+    graph: G,
+    num_groups: usize,
+    group_fn: F,
+    mut weight_fn: W,
+) -> Result<AggregatedGraph<G::NodeId>, E>
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable + GraphProp,
+    F: Fn(G::NodeId) -> usize,
+    W: FnMut(G::EdgeRef) -> Result<f64, E>,
+{
+    let mut groups: Vec<GroupSummary<G::NodeId>> = (0..num_groups)
+        .map(|_| GroupSummary {
+            nodes: Vec::new(),
+            internal_edges: 0,
+            internal_weight: 0.0,
+            density: 0.0,
+        })
+        .collect();
+    let mut node_groups = vec![usize::MAX; graph.node_bound()];
+    for node in graph.node_identifiers() {
+        let group = group_fn(node);
+        assert!(group < num_groups, "group id {} out of range", group);
+        node_groups[graph.to_index(node)] = group;
+        groups[group].nodes.push(node);
+    }
+    for group in groups.iter_mut() {
+        group.nodes.sort_by_key(|n| graph.to_index(*n));
+    }
+
+    let directed = graph.is_directed();
+    let mut group_edges: HashMap<(usize, usize), (usize, f64)> = HashMap::new();
+    for edge in graph.edge_references() {
+        let source = node_groups[graph.to_index(edge.source())];
+        let target = node_groups[graph.to_index(edge.target())];
+        let weight = weight_fn(edge)?;
+        if source == target {
+            groups[source].internal_edges += 1;
+            groups[source].internal_weight += weight;
+        } else {
+            let key = if directed || source < target {
+                (source, target)
+            } else {
+                (target, source)
+            };
+            let entry = group_edges.entry(key).or_insert((0, 0.0));
+            entry.0 += 1;
+            entry.1 += weight;
+        }
+    }
+    for group in groups.iter_mut() {
+        let size = group.nodes.len();
+        if size >= 2 {
+            let pairs = if directed {
+                size * (size - 1)
+            } else {
+                size * (size - 1) / 2
+            };
+            group.density = group.internal_edges as f64 / pairs as f64;
+        }
+    }
+    let mut edges: Vec<GroupEdgeSummary> = group_edges
+        .into_iter()
+        .map(|((source, target), (count, weight))| GroupEdgeSummary {
+            source,
+            target,
+            count,
+            weight,
+        })
+        .collect();
+    edges.sort_unstable_by_key(|edge| (edge.source, edge.target));
+    Ok(AggregatedGraph { groups, edges })
+}
+
+/// A partition of the nodes of a graph into parts.
+#[derive(Clone, Debug, PartialEq)]
+pub struct GraphPartition<N> {
+    /// The nodes of every part, in the order of their node indices.
+    pub parts: Vec<Vec<N>>,
+    /// The total weight of the edges between nodes of different parts.
+    pub cut_weight: f64,
+}
+
+/// The total weight of the edges between nodes of different parts.
+fn cut_weight(graph: &CoarseGraph, node_parts: &[usize]) -> f64 {
+    graph
+        .edges()
+        .filter(|(source, target, _)| node_parts[*source] != node_parts[*target])
+        .map(|(_, _, weight)| weight)
+        .sum()
+}
+
+/// Collect the parts of the nodes of the first level of a hierarchy, with
+/// the weight of the cut.
+fn graph_partition<N: Copy>(
+    nodes: &[N],
+    graph: &CoarseGraph,
+    node_parts: &[usize],
+    num_parts: usize,
+) -> GraphPartition<N> {
+    let mut parts: Vec<Vec<N>> = vec![Vec::new(); num_parts];
+    for (node, part) in nodes.iter().zip(node_parts) {
+        parts[*part].push(*node);
+    }
+    GraphPartition {
+        parts,
+        cut_weight: cut_weight(graph, node_parts),
+    }
+}
+
+fn seeded_rng(seed: Option<u64>) -> Pcg64 {
+    match seed {
+        Some(seed) => Pcg64::seed_from_u64(seed),
+        None => Pcg64::from_entropy(),
+    }
+}
+
+/// Run a pass of the Kernighan-Lin algorithm on a bisection, moving nodes
+/// alternately from each side and keeping the moves up to the pair of moves
+/// with the highest total gain. Returns that gain, the decrease of the
+/// weight of the cut.
+fn kernighan_lin_pass(graph: &CoarseGraph, sides: &mut [usize]) -> f64 {
+    let n = graph.node_count();
+    // The decrease of the weight of the cut if a node changes sides
+    let mut gains: Vec<f64> = (0..n)
+        .map(|node| {
+            graph.adjacency[node]
+                .iter()
+                .map(|(neighbor, weight)| {
+                    if sides[*neighbor] == sides[node] {
+                        -weight
+                    } else {
+                        *weight
+                    }
+                })
+                .sum()
+        })
+        .collect();
+    let mut heaps: [BinaryHeap<MinScored<f64, usize>>; 2] = [BinaryHeap::new(), BinaryHeap::new()];
+    for (node, (side, gain)) in sides.iter().zip(&gains).enumerate() {
+        heaps[*side].push(MinScored(-gain, node));
+    }
+    let mut locked = vec![false; n];
+    let mut moves: Vec<usize> = Vec::with_capacity(n);
+    let mut total_gain = 0.0;
+    let mut best_gain = 0.0;
+    let mut best_moves = 0;
+    'pass: loop {
+        for side in 0..2 {
+            // The heaps have an entry for every update of a gain, only the
+            // last one of a node not moved yet is current.
+            let node = loop {
+                match heaps[side].pop() {
+                    Some(MinScored(gain, node)) => {
+                        if !locked[node] && -gain == gains[node] {
+                            break node;
+                        }
+                    }
+                    None => break 'pass,
+                }
+            };
+            locked[node] = true;
+            sides[node] = 1 - side;
+            total_gain += gains[node];
+            moves.push(node);
+            for (neighbor, weight) in &graph.adjacency[node] {
+                if locked[*neighbor] {
+                    continue;
+                }
+                if sides[*neighbor] == side {
+                    gains[*neighbor] += 2.0 * weight;
+                } else {
+                    gains[*neighbor] -= 2.0 * weight;
+                }
+                heaps[sides[*neighbor]].push(MinScored(-gains[*neighbor], *neighbor));
+            }
+        }
+        if total_gain > best_gain {
+            best_gain = total_gain;
+            best_moves = moves.len();
+        }
+    }
+    for node in &moves[best_moves..] {
+        sides[*node] = 1 - sides[*node];
+    }
+    best_gain
+}
+
+/// Split the nodes of a graph into two parts of equal size (or sizes
+/// differing by 1) with a small weight of the edges between them, using the
+/// Kernighan-Lin algorithm [1].
+///
+/// Starting from a random bisection, every pass of the algorithm moves every
+/// node to the other part once, alternately from each part and always the
+/// node whose move decreases the weight of the cut the most, and then keeps
+/// the moves up to the pair of moves that decreased it the most. The passes
+/// stop when a pass doesn't decrease the weight of the cut, or after
+/// `max_iter` passes. The edges are considered undirected and self loops
+/// are ignored.
+///
+/// Arguments:
+///
+/// * `graph` - The graph to bisect
+/// * `weight_fn` - A callable that receives an edge and returns its weight,
+///   or an error which is returned right away
+/// * `max_iter` - The maximum number of passes
+/// * `seed` - An optional seed to use for the random number generator that
+///   builds the initial bisection
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::partition::kernighan_lin_bisection;
+/// use retworkx_core::Result;
+///
+/// // Two complete graphs of 4 nodes joined by an edge
+/// let g = petgraph::graph::UnGraph::<(), ()>::from_edges(&[
+///     (0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3),
+///     (4, 5), (4, 6), (4, 7), (5, 6), (5, 7), (6, 7),
+///     (3, 4),
+/// ]);
+/// let partition: Result<_> = kernighan_lin_bisection(&g, |_| Ok(1.0), 10, Some(1));
+/// let partition = partition.unwrap();
+/// assert_eq!(partition.cut_weight, 1.0);
+/// let mut parts: Vec<Vec<usize>> = partition
+///     .parts
+///     .iter()
+///     .map(|part| part.iter().map(|n| n.index()).collect())
+///     .collect();
+/// parts.sort();
+/// assert_eq!(parts, vec![vec![0, 1, 2, 3], vec![4, 5, 6, 7]]);
+/// ```
+///
+/// [1] B. W. Kernighan and S. Lin, "An efficient heuristic procedure for
+///   partitioning graphs", The Bell System Technical Journal, 49(2),
+///   291-307, 1970.
+pub fn kernighan_lin_bisection<G, F, E>(
+    graph: G,
+    weight_fn: F,
+    max_iter: usize,
+    seed: Option<u64>,
+) -> Result<GraphPartition<G::NodeId>, E>
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable,
+    F: FnMut(G::EdgeRef) -> Result<f64, E>,
+{
+    let (nodes, level) = CoarseGraph::from_graph(graph, weight_fn)?;
+    let mut rng = seeded_rng(seed);
+    let n = nodes.len();
+    let mut order: Vec<usize> = (0..n).collect();
+    order.shuffle(&mut rng);
+    let mut sides = vec![0; n];
+    for node in &order[n / 2..] {
+        sides[*node] = 1;
+    }
+    for _ in 0..max_iter {
+        if kernighan_lin_pass(&level, &mut sides) <= 0.0 {
+            break;
+        }
+    }
+    Ok(graph_partition(&nodes, &level, &sides, 2))
+}
+
+/// Split the nodes of the coarsest graph of a hierarchy into parts by
+/// growing the parts one at a time from a random node, adding the node most
+/// connected to the part until it reaches its share of the remaining size.
+/// The last part gets all the nodes left.
+fn grow_parts<R: Rng>(graph: &CoarseGraph, num_parts: usize, rng: &mut R) -> Vec<usize> {
+    let n = graph.node_count();
+    let mut order: Vec<usize> = (0..n).collect();
+    order.shuffle(rng);
+    let mut order = order.into_iter();
+    let mut node_parts = vec![num_parts - 1; n];
+    let mut assigned = vec![false; n];
+    let mut remaining: usize = graph.sizes.iter().sum();
+    for part in 0..num_parts - 1 {
+        let target = remaining as f64 / (num_parts - part) as f64;
+        let mut size = 0;
+        let mut connection = vec![0.0; n];
+        let mut heap: BinaryHeap<MinScored<f64, usize>> = BinaryHeap::new();
+        loop {
+            // Every update of a connection adds an entry, they only grow so
+            // the current one of a node is popped first.
+            let node = match heap.pop() {
+                Some(MinScored(_, node)) if assigned[node] => continue,
+                Some(MinScored(_, node)) => node,
+                // Start from a random node, and restart from another one if
+                // the part has no more neighbors
+                None => match order.find(|node| !assigned[*node]) {
+                    Some(node) => node,
+                    None => break,
+                },
+            };
+            let new_size = size + graph.sizes[node];
+            if size > 0 && new_size as f64 - target > target - size as f64 {
+                break;
+            }
+            assigned[node] = true;
+            node_parts[node] = part;
+            size = new_size;
+            for (neighbor, weight) in &graph.adjacency[node] {
+                if !assigned[*neighbor] {
+                    connection[*neighbor] += weight;
+                    heap.push(MinScored(-connection[*neighbor], *neighbor));
+                }
+            }
+            if size as f64 >= target {
+                break;
+            }
+        }
+        remaining -= size;
+    }
+    node_parts
+}
+
+/// The number of initial partitions of the coarsest graph to choose from.
+const INITIAL_PARTITIONS: usize = 8;
+
+/// The maximum number of passes of refinement at every level.
+const REFINE_PASSES: usize = 10;
+
+/// Refine a k-way partition of a graph by moving nodes at the boundary of
+/// their part to the neighboring part with the most connection to them, if
+/// this decreases the weight of the cut, or keeps it and improves the
+/// balance, without making the part larger than `max_size`. The nodes of a
+/// part larger than `max_size` move to the part with room for them that
+/// increases the weight of the cut the least.
+fn refine_parts<R: Rng>(
+    graph: &CoarseGraph,
+    node_parts: &mut [usize],
+    num_parts: usize,
+    max_size: usize,
+    rng: &mut R,
+) {
+    let mut part_sizes = vec![0; num_parts];
+    for (part, size) in node_parts.iter().zip(&graph.sizes) {
+        part_sizes[*part] += size;
+    }
+    let all_parts: Vec<usize> = (0..num_parts).collect();
+    let mut order: Vec<usize> = (0..graph.node_count()).collect();
+    // The total weight of the edges of a node to every part, and the parts
+    // with an edge to the node
+    let mut connection = vec![0.0; num_parts];
+    let mut listed = vec![false; num_parts];
+    let mut neighbor_parts: Vec<usize> = Vec::new();
+    for _ in 0..REFINE_PASSES {
+        order.shuffle(rng);
+        let mut moved = false;
+        for node in &order {
+            let node = *node;
+            let part = node_parts[node];
+            let size = graph.sizes[node];
+            for (neighbor, weight) in &graph.adjacency[node] {
+                let other = node_parts[*neighbor];
+                if !listed[other] {
+                    listed[other] = true;
+                    neighbor_parts.push(other);
+                }
+                connection[other] += weight;
+            }
+            let overweight = part_sizes[part] > max_size;
+            let candidates = if overweight {
+                &all_parts
+            } else {
+                &neighbor_parts
+            };
+            let mut best: Option<usize> = None;
+            for other in candidates.iter().copied() {
+                if other == part || part_sizes[other] + size > max_size {
+                    continue;
+                }
+                let better = match best {
+                    None => true,
+                    Some(best) => {
+                        connection[other] > connection[best]
+                            || (connection[other] == connection[best]
+                                && part_sizes[other] < part_sizes[best])
+                    }
+                };
+                if better {
+                    best = Some(other);
+                }
+            }
+            if let Some(best) = best {
+                let gain = connection[best] - connection[part];
+                if overweight
+                    || gain > 0.0
+                    || (gain == 0.0 && part_sizes[best] + size < part_sizes[part])
+                {
+                    node_parts[node] = best;
+                    part_sizes[part] -= size;
+                    part_sizes[best] += size;
+                    moved = true;
+                }
+            }
+            for other in neighbor_parts.drain(..) {
+                connection[other] = 0.0;
+                listed[other] = false;
+            }
+        }
+        if !moved {
+            break;
+        }
+    }
+}
+
+/// Split the nodes of a graph into `num_parts` parts of about the same size
+/// with a small weight of the edges between them, using a multilevel
+/// algorithm [1].
+///
+/// The graph is coarsened by heavy edge matching as with
+/// [`coarsen`](crate::coarsening::coarsen), the coarsest graph is split a
+/// few times by growing the parts from random nodes, keeping the best
+/// split, and the partition is projected back level by level, moving the
+/// nodes at the boundary of the parts to decrease the weight of the cut at
+/// every level. The edges are considered undirected and self loops are
+/// ignored.
+///
+/// The refinement keeps every part at or below
+/// `max(ceil(n / num_parts), (1 + imbalance) * n / num_parts)` nodes, for
+/// the `n` nodes of the graph, and moves the nodes of larger parts to parts
+/// with room for them, but the balance is not guaranteed: the nodes of the
+/// coarse graphs can be too large to fit.
+///
+/// Arguments:
+///
+/// * `graph` - The graph to partition
+/// * `weight_fn` - A callable that receives an edge and returns its weight,
+///   or an error which is returned right away as a
+///   [`CallbackError::Callback`]
+/// * `num_parts` - The number of parts
+/// * `imbalance` - The fraction of the average part size a part can exceed
+///   it by
+/// * `seed` - An optional seed to use for the random number generator
+///
+/// Returns a [`CallbackError::InvalidInput`] if `num_parts` is 0 or
+/// `imbalance` is negative.
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::err::CallbackError;
+/// use retworkx_core::partition::multilevel_partition;
+///
+/// // Three triangles joined in a cycle by single edges
+/// let g = petgraph::graph::UnGraph::<(), ()>::from_edges(&[
+///     (0, 1), (1, 2), (2, 0),
+///     (3, 4), (4, 5), (5, 3),
+///     (6, 7), (7, 8), (8, 6),
+///     (2, 3), (5, 6), (8, 0),
+/// ]);
+/// let partition: Result<_, CallbackError> = multilevel_partition(&g, |_| Ok(1.0), 3, 0.0, Some(1));
+/// let partition = partition.unwrap();
+/// assert_eq!(partition.parts.len(), 3);
+/// assert!(partition.parts.iter().all(|part| part.len() == 3));
+/// ```
+///
+/// [1] G. Karypis and V. Kumar, "A Fast and High Quality Multilevel Scheme
+///   for Partitioning Irregular Graphs", SIAM Journal on Scientific
+///   Computing, 20(1), 359-392, 1998.
+pub fn multilevel_partition<G, F, E>(
+    graph: G,
+    weight_fn: F,
+    num_parts: usize,
+    imbalance: f64,
+    seed: Option<u64>,
+) -> Result<GraphPartition<G::NodeId>, CallbackError<E>>
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable,
+    F: FnMut(G::EdgeRef) -> Result<f64, E>,
+{
+    if num_parts == 0 {
+        return Err(CallbackError::InvalidInput(
+            InvalidInputError::InvalidParameter {
+                name: "num_parts",
+                reason: "must be at least 1",
+            },
+        ));
+    }
+    if imbalance < 0.0 {
+        return Err(CallbackError::InvalidInput(
+            InvalidInputError::InvalidParameter {
+                name: "imbalance",
+                reason: "can't be negative",
+            },
+        ));
+    }
+    let (nodes, first) = CoarseGraph::from_graph(graph, weight_fn)?;
+    let mut rng = seeded_rng(seed);
+    let n = nodes.len();
+    let average = n as f64 / num_parts as f64;
+    let max_size = (((1.0 + imbalance) * average).floor() as usize).max(average.ceil() as usize);
+    let min_nodes = (15 * num_parts).max(30);
+    let (levels, maps) = coarsen_levels(first, min_nodes, None, &mut rng);
+
+    // Keep the best of a few initial partitions, the one exceeding the
+    // maximum part size the least and then with the lightest cut
+    let coarsest = levels.last().unwrap();
+    let mut best: Option<((usize, f64), Vec<usize>)> = None;
+    for _ in 0..INITIAL_PARTITIONS {
+        let mut node_parts = grow_parts(coarsest, num_parts, &mut rng);
+        refine_parts(coarsest, &mut node_parts, num_parts, max_size, &mut rng);
+        let mut part_sizes = vec![0; num_parts];
+        for (part, size) in node_parts.iter().zip(&coarsest.sizes) {
+            part_sizes[*part] += size;
+        }
+        let excess = part_sizes.iter().max().unwrap().saturating_sub(max_size);
+        let key = (excess, cut_weight(coarsest, &node_parts));
+        if best.as_ref().map_or(true, |(best_key, _)| key < *best_key) {
+            best = Some((key, node_parts));
+        }
+    }
+    let mut node_parts = best.unwrap().1;
+    for (level, map) in levels.iter().zip(&maps).rev() {
+        node_parts = map.iter().map(|coarse| node_parts[*coarse]).collect();
+        refine_parts(level, &mut node_parts, num_parts, max_size, &mut rng);
+    }
+    Ok(graph_partition(&nodes, &levels[0], &node_parts, num_parts))
+}
@@ -0,0 +1,311 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+//! Module for scoring and ranking sets of paths.
+//!
+//! The functions in this module post-process a set of paths between nodes
+//! of a graph, such as the output of [`k_shortest_path`] or of a search for
+//! all simple paths, to pick the paths worth keeping. They rank the paths by
+//! their total cost while penalizing paths which share many edges, since an
+//! alternative route is only useful if it's actually different.
+//!
+//! [`k_shortest_path`]: crate::shortest_path::k_shortest_path
+
+use std::collections::HashSet;
+
+use petgraph::visit::{EdgeRef, GraphProp, GraphRef, IntoEdges, NodeIndexable};
+
+/// Compute the total cost of the edges of a path.
+///
+/// When there are parallel edges between two consecutive nodes of the path
+/// the cheapest one is used.
+///
+/// Arguments:
+///
+/// * `graph` - The graph the path is in
+/// * `path` - The nodes of the path in order
+/// * `edge_cost` - A callable that will be passed an edge reference and
+///   returns the cost of the edge, or an error that is returned immediately
+///
+/// Returns `None` if there is no edge between two consecutive nodes of
+/// `path`, following the edge directions for a directed graph.
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::petgraph::graph::NodeIndex;
+/// use retworkx_core::path_ranking::path_cost;
+/// use retworkx_core::Result;
+///
+/// let graph = petgraph::graph::UnGraph::<(), f64>::from_edges(&[
+///     (0, 1, 1.), (1, 2, 2.), (1, 2, 0.5),
+/// ]);
+/// let path: Vec<NodeIndex> = (0..3).map(NodeIndex::new).collect();
+/// let res: Result<Option<f64>> = path_cost(&graph, &path, |e| Ok(*e.weight()));
+/// assert_eq!(res.unwrap(), Some(1.5));
+/// let res: Result<Option<f64>> = path_cost(&graph, &path[..1], |e| Ok(*e.weight()));
+/// assert_eq!(res.unwrap(), Some(0.));
+/// ```
+pub fn path_cost<G, F, E>(graph: G, path: &[G::NodeId], mut edge_cost: F) -> Result<Option<f64>, E>
+where
+    G: IntoEdges,
+    G::NodeId: Eq,
+    F: FnMut(G::EdgeRef) -> Result<f64, E>,
+{
+    let mut total = 0.;
+    for pair in path.windows(2) {
+        let mut cheapest: Option<f64> = None;
+        for edge in graph.edges(pair[0]).filter(|edge| edge.target() == pair[1]) {
+            let cost = edge_cost(edge)?;
+            cheapest = match cheapest {
+                Some(cheapest) if cheapest <= cost => Some(cheapest),
+                _ => Some(cost),
+            };
+        }
+        match cheapest {
+            Some(cost) => total += cost,
+            None => return Ok(None),
+        }
+    }
+    Ok(Some(total))
+}
+
+/// The edges of a path as pairs of node indices, sorted for an undirected
+/// graph so an edge is the same in both directions.
+fn path_edges<G>(graph: G, path: &[G::NodeId]) -> HashSet<(usize, usize)>
+where
+    G: GraphProp + NodeIndexable,
+{
+    path.windows(2)
+        .map(|pair| {
+            let (a, b) = (graph.to_index(pair[0]), graph.to_index(pair[1]));
+            if graph.is_directed() || a <= b {
+                (a, b)
+            } else {
+                (b, a)
+            }
+        })
+        .collect()
+}
+
+/// The Jaccard similarity of two edge sets, 0 if both are empty.
+fn jaccard(a: &HashSet<(usize, usize)>, b: &HashSet<(usize, usize)>) -> f64 {
+    let shared = a.intersection(b).count();
+    let union = a.len() + b.len() - shared;
+    if union == 0 {
+        0.
+    } else {
+        shared as f64 / union as f64
+    }
+}
+
+/// Compute the similarity of two paths.
+///
+/// The similarity is the Jaccard index of the sets of edges of the paths,
+/// the number of edges they share divided by the number of edges in either
+/// of them. It's between 0 for paths without a common edge and 1 for paths
+/// using the same edges. Parallel edges are counted as a single edge and
+/// for an undirected graph an edge is the same in both directions.
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::petgraph::graph::NodeIndex;
+/// use retworkx_core::path_ranking::path_similarity;
+///
+/// let graph = petgraph::graph::UnGraph::<(), ()>::from_edges(&[
+///     (0, 1), (1, 2), (2, 3), (1, 3),
+/// ]);
+/// let a: Vec<NodeIndex> = [0, 1, 2, 3].iter().map(|n| NodeIndex::new(*n)).collect();
+/// let b: Vec<NodeIndex> = [0, 1, 3].iter().map(|n| NodeIndex::new(*n)).collect();
+/// assert_eq!(path_similarity(&graph, &a, &b), 0.25);
+/// ```
+pub fn path_similarity<G>(graph: G, a: &[G::NodeId], b: &[G::NodeId]) -> f64
+where
+    G: GraphRef + GraphProp + NodeIndexable,
+{
+    jaccard(&path_edges(graph, a), &path_edges(graph, b))
+}
+
+/// The costs and edge sets of a set of paths.
+#[allow(clippy::type_complexity)]
+fn path_data<G, F, E>(
+    graph: G,
+    paths: &[Vec<G::NodeId>],
+    mut edge_cost: F,
+) -> Result<(Vec<f64>, Vec<HashSet<(usize, usize)>>), E>
+where
+    G: IntoEdges + GraphProp + NodeIndexable,
+    G::NodeId: Eq,
+    F: FnMut(G::EdgeRef) -> Result<f64, E>,
+{
+    let costs = paths
+        .iter()
+        .map(|path| Ok(path_cost(graph, path, &mut edge_cost)?.unwrap_or(f64::INFINITY)))
+        .collect::<Result<Vec<f64>, E>>()?;
+    let edges = paths.iter().map(|path| path_edges(graph, path)).collect();
+    Ok((costs, edges))
+}
+
+/// Rank a set of paths by their cost, penalizing overlapping paths.
+///
+/// The paths are ranked greedily: the next path in the ranking is the one
+/// with the lowest score among the paths not ranked yet, where the score of
+/// a path is its cost, as computed by [`path_cost`], plus `overlap_penalty`
+/// times its largest [`path_similarity`] with a path ranked before it. With
+/// an `overlap_penalty` of 0 this is the order of the path costs, while a
+/// large `overlap_penalty` moves paths that are almost the same as a better
+/// path towards the end of the ranking.
+///
+/// Arguments:
+///
+/// * `graph` - The graph the paths are in
+/// * `paths` - The paths to rank, as the nodes of each path in order
+/// * `overlap_penalty` - The non-negative penalty of a path which has the
+///   same edges as a path ranked before it
+/// * `edge_cost` - A callable that will be passed an edge reference and
+///   returns the cost of the edge, or an error that is returned immediately
+///
+/// Returns the ranking as pairs of the position of a path in `paths` and its
+/// score. Ties are broken by the position in `paths`. A path with two
+/// consecutive nodes which aren't adjacent has an infinite cost.
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::petgraph::graph::NodeIndex;
+/// use retworkx_core::path_ranking::rank_paths;
+/// use retworkx_core::Result;
+///
+/// // Two routes from 0 to 4 with a shortcut on the first one
+/// let graph = petgraph::graph::UnGraph::<(), f64>::from_edges(&[
+///     (0, 1, 1.), (1, 2, 1.), (2, 4, 1.), (1, 3, 1.5), (3, 4, 1.5),
+///     (0, 5, 2.), (5, 4, 2.),
+/// ]);
+/// let paths: Vec<Vec<NodeIndex>> = vec![
+///     vec![0, 1, 2, 4], vec![0, 1, 3, 4], vec![0, 5, 4],
+/// ].into_iter().map(|path| path.into_iter().map(NodeIndex::new).collect()).collect();
+/// let res: Result<Vec<(usize, f64)>> = rank_paths(&graph, &paths, 0., |e| Ok(*e.weight()));
+/// assert_eq!(res.unwrap(), vec![(0, 3.), (1, 4.), (2, 4.)]);
+/// let res: Result<Vec<(usize, f64)>> = rank_paths(&graph, &paths, 2., |e| Ok(*e.weight()));
+/// assert_eq!(res.unwrap(), vec![(0, 3.), (2, 4.), (1, 4.4)]);
+/// ```
+pub fn rank_paths<G, F, E>(
+    graph: G,
+    paths: &[Vec<G::NodeId>],
+    overlap_penalty: f64,
+    edge_cost: F,
+) -> Result<Vec<(usize, f64)>, E>
+where
+    G: IntoEdges + GraphProp + NodeIndexable,
+    G::NodeId: Eq,
+    F: FnMut(G::EdgeRef) -> Result<f64, E>,
+{
+    let (costs, edges) = path_data(graph, paths, edge_cost)?;
+    let mut overlap = vec![0.; paths.len()];
+    let mut remaining: Vec<usize> = (0..paths.len()).collect();
+    let mut ranking = Vec::with_capacity(paths.len());
+    while !remaining.is_empty() {
+        let score = |path: usize| costs[path] + overlap_penalty * overlap[path];
+        let mut best = 0;
+        for pos in 1..remaining.len() {
+            if score(remaining[pos]) < score(remaining[best]) {
+                best = pos;
+            }
+        }
+        let path = remaining.remove(best);
+        ranking.push((path, score(path)));
+        for &other in &remaining {
+            let similarity = jaccard(&edges[path], &edges[other]);
+            if similarity > overlap[other] {
+                overlap[other] = similarity;
+            }
+        }
+    }
+    Ok(ranking)
+}
+
+/// Select a subset of diverse paths from a set of paths.
+///
+/// The paths are selected greedily, starting with the cheapest path, as
+/// computed by [`path_cost`]. The next path selected is the one whose
+/// largest [`path_similarity`] with the paths already selected is the
+/// smallest, so every new path is as different as possible from the
+/// previous ones, with ties broken by the lowest cost and then the position
+/// in `paths`.
+///
+/// Arguments:
+///
+/// * `graph` - The graph the paths are in
+/// * `paths` - The paths to select from, as the nodes of each path in order
+/// * `k` - The number of paths to select
+/// * `edge_cost` - A callable that will be passed an edge reference and
+///   returns the cost of the edge, or an error that is returned immediately
+///
+/// Returns the positions in `paths` of the selected paths in the order they
+/// were selected, all of them if there are at most `k` paths. A path with
+/// two consecutive nodes which aren't adjacent has an infinite cost.
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::petgraph::graph::NodeIndex;
+/// use retworkx_core::path_ranking::diverse_paths;
+/// use retworkx_core::Result;
+///
+/// // Two routes from 0 to 4 with a shortcut on the first one
+/// let graph = petgraph::graph::UnGraph::<(), f64>::from_edges(&[
+///     (0, 1, 1.), (1, 2, 1.), (2, 4, 1.), (1, 3, 1.5), (3, 4, 1.5),
+///     (0, 5, 2.), (5, 4, 2.),
+/// ]);
+/// let paths: Vec<Vec<NodeIndex>> = vec![
+///     vec![0, 1, 2, 4], vec![0, 1, 3, 4], vec![0, 5, 4],
+/// ].into_iter().map(|path| path.into_iter().map(NodeIndex::new).collect()).collect();
+/// let res: Result<Vec<usize>> = diverse_paths(&graph, &paths, 2, |e| Ok(*e.weight()));
+/// assert_eq!(res.unwrap(), vec![0, 2]);
+/// ```
+pub fn diverse_paths<G, F, E>(
+    graph: G,
+    paths: &[Vec<G::NodeId>],
+    k: usize,
+    edge_cost: F,
+) -> Result<Vec<usize>, E>
+where
+    G: IntoEdges + GraphProp + NodeIndexable,
+    G::NodeId: Eq,
+    F: FnMut(G::EdgeRef) -> Result<f64, E>,
+{
+    let (costs, edges) = path_data(graph, paths, edge_cost)?;
+    let mut overlap = vec![0.; paths.len()];
+    let mut remaining: Vec<usize> = (0..paths.len()).collect();
+    let mut selected = Vec::with_capacity(k.min(paths.len()));
+    while selected.len() < k && !remaining.is_empty() {
+        let mut best = 0;
+        for pos in 1..remaining.len() {
+            let (path, best_path) = (remaining[pos], remaining[best]);
+            if overlap[path] < overlap[best_path]
+                || (overlap[path] == overlap[best_path] && costs[path] < costs[best_path])
+            {
+                best = pos;
+            }
+        }
+        let path = remaining.remove(best);
+        selected.push(path);
+        for &other in &remaining {
+            let similarity = jaccard(&edges[path], &edges[other]);
+            if similarity > overlap[other] {
+                overlap[other] = similarity;
+            }
+        }
+    }
+    Ok(selected)
+}
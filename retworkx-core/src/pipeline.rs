@@ -0,0 +1,532 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+//! Module for chaining graph operations without copying the graph.
+//!
+//! A [`Pipeline`] records a sequence of steps (filtering nodes or edges,
+//! keeping the largest connected component or a k-core) on top of a graph
+//! and only executes them when the resulting [`GraphView`] is requested. The
+//! steps never build an intermediate graph: they only update a mask of the
+//! nodes and edges of the original graph that are still part of the view,
+//! and every algorithm in this crate can run on the final view directly.
+//!
+//! ```rust
+//! use retworkx_core::petgraph;
+//! use retworkx_core::petgraph::visit::EdgeRef;
+//! use retworkx_core::centrality::betweenness_centrality;
+//! use retworkx_core::pipeline::Pipeline;
+//!
+//! // A path 0 - 1 - 2 - 3 - 4 where the edge (2, 3) is light, and a
+//! // separate edge 5 - 6
+//! let g = petgraph::graph::UnGraph::<(), f64>::from_edges(&[
+//!     (0, 1, 1.0), (1, 2, 1.0), (2, 3, 0.1), (3, 4, 1.0), (5, 6, 1.0)
+//! ]);
+//! let centrality = Pipeline::new(&g)
+//!     .filter_edges(|edge| *edge.weight() > 0.5)
+//!     .largest_component()
+//!     .run(|view| betweenness_centrality(view, false, true, 50));
+//! // Only the nodes 0, 1 and 2 are left, node 1 is between the other two
+//! assert_eq!(
+//!     centrality,
+//!     vec![Some(0.0), Some(1.0), Some(0.0), None, None, None, None]
+//! );
+//! ```
+
+use std::collections::VecDeque;
+use std::hash::Hash;
+
+use petgraph::visit::{
+    Data, EdgeCount, EdgeIndexable, EdgeRef, GraphBase, GraphProp, IntoEdgeReferences, IntoEdges,
+    IntoEdgesDirected, IntoNeighbors, IntoNeighborsDirected, IntoNodeIdentifiers,
+    IntoNodeReferences, NodeCount, NodeIndexable, NodeRef, Visitable,
+};
+use petgraph::{Direction, Incoming, Outgoing};
+
+use crate::connectivity::k_core;
+
+/// The graph traits a [`Pipeline`] needs from the graph it runs on.
+pub trait PipelineGraph:
+    GraphProp
+    + NodeIndexable
+    + EdgeIndexable
+    + Visitable
+    + IntoNodeReferences
+    + IntoEdgeReferences
+    + IntoEdges
+    + IntoEdgesDirected
+{
+}
+
+impl<G> PipelineGraph for G where
+    G: GraphProp
+        + NodeIndexable
+        + EdgeIndexable
+        + Visitable
+        + IntoNodeReferences
+        + IntoEdgeReferences
+        + IntoEdges
+        + IntoEdgesDirected
+{
+}
+
+type NodePredicate<'a, G> = Box<dyn FnMut(<G as IntoNodeReferences>::NodeRef) -> bool + 'a>;
+type EdgePredicate<'a, G> = Box<dyn FnMut(<G as IntoEdgeReferences>::EdgeRef) -> bool + 'a>;
+
+enum Step<'a, G: PipelineGraph> {
+    FilterNodes(NodePredicate<'a, G>),
+    FilterEdges(EdgePredicate<'a, G>),
+    LargestComponent,
+    KCore(Option<usize>),
+}
+
+/// A lazy sequence of operations on a graph.
+///
+/// The steps are recorded by the builder methods and executed in order by
+/// [`Pipeline::view`] (or [`Pipeline::run`]), every step sees the graph left
+/// by the previous steps.
+pub struct Pipeline<'a, G: PipelineGraph> {
+    graph: G,
+    steps: Vec<Step<'a, G>>,
+}
+
+impl<'a, G> Pipeline<'a, G>
+where
+    G: PipelineGraph,
+    G::NodeId: Eq + Hash,
+{
+    /// Create a new pipeline over `graph` without any steps.
+    pub fn new(graph: G) -> Self {
+        Pipeline {
+            graph,
+            steps: Vec::new(),
+        }
+    }
+
+    /// Keep only the nodes for which `filter` returns `true`, along with
+    /// their edges.
+    pub fn filter_nodes<F>(mut self, filter: F) -> Self
+    where
+        F: FnMut(G::NodeRef) -> bool + 'a,
+    {
+        self.steps.push(Step::FilterNodes(Box::new(filter)));
+        self
+    }
+
+    /// Keep only the edges for which `filter` returns `true`.
+    pub fn filter_edges<F>(mut self, filter: F) -> Self
+    where
+        F: FnMut(G::EdgeRef) -> bool + 'a,
+    {
+        self.steps.push(Step::FilterEdges(Box::new(filter)));
+        self
+    }
+
+    /// Keep only the largest (weakly) connected component. If several
+    /// components have the same size the one with the first node is kept.
+    pub fn largest_component(mut self) -> Self {
+        self.steps.push(Step::LargestComponent);
+        self
+    }
+
+    /// Keep only the k-core, as returned by
+    /// [`k_core`](crate::connectivity::k_core). If `k` is not specified the
+    /// main core is kept.
+    ///
+    /// ```rust
+    /// use retworkx_core::petgraph;
+    /// use retworkx_core::petgraph::visit::{EdgeCount, NodeCount};
+    /// use retworkx_core::pipeline::Pipeline;
+    ///
+    /// // A triangle with a tail 2 -> 3 -> 4
+    /// let g = petgraph::graph::DiGraph::<(), ()>::from_edges(&[
+    ///     (0, 1), (1, 2), (2, 0), (2, 3), (3, 4)
+    /// ]);
+    /// let view = Pipeline::new(&g).k_core(None).view();
+    /// assert_eq!(view.node_count(), 3);
+    /// assert_eq!(view.edge_count(), 3);
+    /// ```
+    pub fn k_core(mut self, k: Option<usize>) -> Self {
+        self.steps.push(Step::KCore(k));
+        self
+    }
+
+    /// Execute the steps and return the resulting view of the graph.
+    pub fn view(self) -> GraphView<G> {
+        let mut view = GraphView::new(self.graph);
+        for step in self.steps {
+            let nodes: Vec<G::NodeId> = match step {
+                Step::FilterNodes(mut filter) => (&view)
+                    .node_references()
+                    .filter(|node| !filter(*node))
+                    .map(|node| node.id())
+                    .collect(),
+                Step::FilterEdges(mut filter) => {
+                    let edges: Vec<G::EdgeId> = (&view)
+                        .edge_references()
+                        .filter(|edge| !filter(*edge))
+                        .map(|edge| edge.id())
+                        .collect();
+                    for edge in edges {
+                        view.remove_edge(edge);
+                    }
+                    continue;
+                }
+                Step::LargestComponent => {
+                    let keep = largest_component(&view);
+                    (&view)
+                        .node_identifiers()
+                        .filter(|node| !keep[view.node_index(*node)])
+                        .collect()
+                }
+                Step::KCore(k) => {
+                    let mut keep = vec![false; view.graph.node_bound()];
+                    for node in k_core(&view, k) {
+                        keep[view.node_index(node)] = true;
+                    }
+                    (&view)
+                        .node_identifiers()
+                        .filter(|node| !keep[view.node_index(*node)])
+                        .collect()
+                }
+            };
+            for node in nodes {
+                view.remove_node(node);
+            }
+        }
+        view
+    }
+
+    /// Execute the steps and call `algorithm` on the resulting view.
+    pub fn run<F, R>(self, algorithm: F) -> R
+    where
+        F: FnOnce(&GraphView<G>) -> R,
+    {
+        algorithm(&self.view())
+    }
+}
+
+/// Return which nodes (by index) are in the largest weakly connected
+/// component of `view`.
+fn largest_component<G: PipelineGraph>(view: &GraphView<G>) -> Vec<bool> {
+    let bound = view.graph.node_bound();
+    let mut component: Vec<Option<usize>> = vec![None; bound];
+    let mut best = None;
+    let mut best_size = 0;
+    let mut count = 0;
+    for start in view.node_identifiers() {
+        if component[view.node_index(start)].is_some() {
+            continue;
+        }
+        let mut size = 0;
+        let mut queue = VecDeque::from(vec![start]);
+        component[view.node_index(start)] = Some(count);
+        while let Some(node) = queue.pop_front() {
+            size += 1;
+            for &direction in &[Outgoing, Incoming] {
+                for neighbor in view.neighbors_directed(node, direction) {
+                    let index = view.node_index(neighbor);
+                    if component[index].is_none() {
+                        component[index] = Some(count);
+                        queue.push_back(neighbor);
+                    }
+                }
+            }
+        }
+        if size > best_size {
+            best = Some(count);
+            best_size = size;
+        }
+        count += 1;
+    }
+    component
+        .into_iter()
+        .map(|c| c.is_some() && c == best)
+        .collect()
+}
+
+/// A view of a subset of the nodes and edges of a graph, as built by a
+/// [`Pipeline`].
+///
+/// It implements the petgraph visit traits, so it can be passed to any
+/// function that accepts a graph. The node and edge indices are the ones of
+/// the original graph.
+#[derive(Clone, Debug)]
+pub struct GraphView<G> {
+    graph: G,
+    nodes: Vec<bool>,
+    edges: Vec<bool>,
+    node_count: usize,
+    edge_count: usize,
+}
+
+impl<G: PipelineGraph> GraphView<G> {
+    /// Create a view of the whole graph.
+    pub fn new(graph: G) -> Self {
+        let mut nodes = vec![false; graph.node_bound()];
+        let mut node_count = 0;
+        for node in graph.node_references() {
+            nodes[NodeIndexable::to_index(&graph, node.id())] = true;
+            node_count += 1;
+        }
+        let mut edges = vec![false; graph.edge_bound()];
+        let mut edge_count = 0;
+        for edge in graph.edge_references() {
+            edges[EdgeIndexable::to_index(&graph, edge.id())] = true;
+            edge_count += 1;
+        }
+        GraphView {
+            graph,
+            nodes,
+            edges,
+            node_count,
+            edge_count,
+        }
+    }
+
+    /// Return the graph the view is built on.
+    pub fn graph(&self) -> G {
+        self.graph
+    }
+
+    fn node_index(&self, node: G::NodeId) -> usize {
+        NodeIndexable::to_index(&self.graph, node)
+    }
+
+    /// Return `true` if the node is part of the view.
+    pub fn contains_node(&self, node: G::NodeId) -> bool {
+        self.nodes
+            .get(self.node_index(node))
+            .copied()
+            .unwrap_or(false)
+    }
+
+    /// Return `true` if the edge is part of the view, both of its endpoints
+    /// must be part of the view too.
+    pub fn contains_edge(&self, edge: G::EdgeRef) -> bool {
+        self.edges[EdgeIndexable::to_index(&self.graph, edge.id())]
+            && self.contains_node(edge.source())
+            && self.contains_node(edge.target())
+    }
+
+    fn remove_node(&mut self, node: G::NodeId) {
+        for edge in self.graph.edges_directed(node, Outgoing) {
+            if self.contains_edge(edge) {
+                self.edge_count -= 1;
+            }
+        }
+        if self.graph.is_directed() {
+            for edge in self.graph.edges_directed(node, Incoming) {
+                // self loops were already counted as outgoing edges
+                if edge.source() != edge.target() && self.contains_edge(edge) {
+                    self.edge_count -= 1;
+                }
+            }
+        }
+        let index = self.node_index(node);
+        self.nodes[index] = false;
+        self.node_count -= 1;
+    }
+
+    fn remove_edge(&mut self, edge: G::EdgeId) {
+        let index = EdgeIndexable::to_index(&self.graph, edge);
+        if self.edges[index] {
+            self.edges[index] = false;
+            self.edge_count -= 1;
+        }
+    }
+}
+
+/// An iterator over the items of the underlying graph that are part of a
+/// [`GraphView`].
+pub struct ViewItems<'b, G, I: Iterator> {
+    view: &'b GraphView<G>,
+    iter: I,
+    include: fn(&GraphView<G>, &I::Item) -> bool,
+}
+
+impl<'b, G, I: Iterator> Iterator for ViewItems<'b, G, I> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        let include = self.include;
+        let view = self.view;
+        self.iter.find(|item| include(view, item))
+    }
+}
+
+/// An iterator over the neighbors of a node in a [`GraphView`].
+pub struct ViewNeighbors<'b, G: GraphBase, I> {
+    view: &'b GraphView<G>,
+    edges: I,
+    node: G::NodeId,
+}
+
+impl<'b, G, I> Iterator for ViewNeighbors<'b, G, I>
+where
+    G: PipelineGraph,
+    I: Iterator<Item = G::EdgeRef>,
+{
+    type Item = G::NodeId;
+
+    fn next(&mut self) -> Option<G::NodeId> {
+        let view = self.view;
+        let node = self.node;
+        self.edges
+            .find(|edge| view.contains_edge(*edge))
+            .map(|edge| {
+                if edge.source() == node {
+                    edge.target()
+                } else {
+                    edge.source()
+                }
+            })
+    }
+}
+
+impl<G: GraphBase> GraphBase for GraphView<G> {
+    type NodeId = G::NodeId;
+    type EdgeId = G::EdgeId;
+}
+
+impl<G: Data> Data for GraphView<G> {
+    type NodeWeight = G::NodeWeight;
+    type EdgeWeight = G::EdgeWeight;
+}
+
+impl<G: GraphProp> GraphProp for GraphView<G> {
+    type EdgeType = G::EdgeType;
+}
+
+impl<G: NodeIndexable> NodeIndexable for GraphView<G> {
+    fn node_bound(&self) -> usize {
+        self.graph.node_bound()
+    }
+    fn to_index(&self, a: G::NodeId) -> usize {
+        self.graph.to_index(a)
+    }
+    fn from_index(&self, i: usize) -> G::NodeId {
+        self.graph.from_index(i)
+    }
+}
+
+impl<G: EdgeIndexable> EdgeIndexable for GraphView<G> {
+    fn edge_bound(&self) -> usize {
+        self.graph.edge_bound()
+    }
+    fn to_index(&self, a: G::EdgeId) -> usize {
+        self.graph.to_index(a)
+    }
+    fn from_index(&self, i: usize) -> G::EdgeId {
+        self.graph.from_index(i)
+    }
+}
+
+impl<G: GraphBase> NodeCount for GraphView<G> {
+    fn node_count(&self) -> usize {
+        self.node_count
+    }
+}
+
+impl<G: GraphBase> EdgeCount for GraphView<G> {
+    fn edge_count(&self) -> usize {
+        self.edge_count
+    }
+}
+
+impl<G: Visitable> Visitable for GraphView<G> {
+    type Map = G::Map;
+    fn visit_map(&self) -> G::Map {
+        self.graph.visit_map()
+    }
+    fn reset_map(&self, map: &mut G::Map) {
+        self.graph.reset_map(map)
+    }
+}
+
+impl<'b, G: PipelineGraph> IntoNodeIdentifiers for &'b GraphView<G> {
+    type NodeIdentifiers = ViewItems<'b, G, G::NodeIdentifiers>;
+    fn node_identifiers(self) -> Self::NodeIdentifiers {
+        ViewItems {
+            view: self,
+            iter: self.graph.node_identifiers(),
+            include: |view, node| view.contains_node(*node),
+        }
+    }
+}
+
+impl<'b, G: PipelineGraph> IntoNodeReferences for &'b GraphView<G> {
+    type NodeRef = G::NodeRef;
+    type NodeReferences = ViewItems<'b, G, G::NodeReferences>;
+    fn node_references(self) -> Self::NodeReferences {
+        ViewItems {
+            view: self,
+            iter: self.graph.node_references(),
+            include: |view, node| view.contains_node(node.id()),
+        }
+    }
+}
+
+impl<'b, G: PipelineGraph> IntoEdgeReferences for &'b GraphView<G> {
+    type EdgeRef = G::EdgeRef;
+    type EdgeReferences = ViewItems<'b, G, G::EdgeReferences>;
+    fn edge_references(self) -> Self::EdgeReferences {
+        ViewItems {
+            view: self,
+            iter: self.graph.edge_references(),
+            include: |view, edge| view.contains_edge(*edge),
+        }
+    }
+}
+
+impl<'b, G: PipelineGraph> IntoEdges for &'b GraphView<G> {
+    type Edges = ViewItems<'b, G, G::Edges>;
+    fn edges(self, a: G::NodeId) -> Self::Edges {
+        ViewItems {
+            view: self,
+            iter: self.graph.edges(a),
+            include: |view, edge| view.contains_edge(*edge),
+        }
+    }
+}
+
+impl<'b, G: PipelineGraph> IntoEdgesDirected for &'b GraphView<G> {
+    type EdgesDirected = ViewItems<'b, G, G::EdgesDirected>;
+    fn edges_directed(self, a: G::NodeId, dir: Direction) -> Self::EdgesDirected {
+        ViewItems {
+            view: self,
+            iter: self.graph.edges_directed(a, dir),
+            include: |view, edge| view.contains_edge(*edge),
+        }
+    }
+}
+
+impl<'b, G: PipelineGraph> IntoNeighbors for &'b GraphView<G> {
+    type Neighbors = ViewNeighbors<'b, G, G::Edges>;
+    fn neighbors(self, a: G::NodeId) -> Self::Neighbors {
+        ViewNeighbors {
+            view: self,
+            edges: self.graph.edges(a),
+            node: a,
+        }
+    }
+}
+
+impl<'b, G: PipelineGraph> IntoNeighborsDirected for &'b GraphView<G> {
+    type NeighborsDirected = ViewNeighbors<'b, G, G::EdgesDirected>;
+    fn neighbors_directed(self, a: G::NodeId, dir: Direction) -> Self::NeighborsDirected {
+        ViewNeighbors {
+            view: self,
+            edges: self.graph.edges_directed(a, dir),
+            node: a,
+        }
+    }
+}
@@ -0,0 +1,765 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+//! Module for planarity testing.
+//!
+//! The planarity test is the left-right planarity test of de Fraysseix and
+//! Rosenstiehl, as described by Brandes [1], which finds a combinatorial
+//! embedding of a planar graph in linear time (up to sorting the adjacency
+//! lists). It runs without recursion, so the depth of the graph is only
+//! limited by memory.
+//!
+//! [1] Ulrik Brandes, "The Left-Right Planarity Test", 2009,
+//! <http://citeseerx.ist.psu.edu/viewdoc/summary?doi=10.1.1.217.9208>
+
+use std::hash::Hash;
+
+use hashbrown::{HashMap, HashSet};
+use petgraph::visit::{EdgeRef, IntoEdgeReferences, IntoNodeIdentifiers, NodeIndexable};
+
+use crate::dictmap::*;
+
+/// The kind of a Kuratowski subgraph, a subdivision of either the complete
+/// graph `K5` or the complete bipartite graph `K3,3`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KuratowskiKind {
+    K5,
+    K33,
+}
+
+/// The result of [`check_planarity`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Planarity<N: Hash + Eq> {
+    /// The graph is planar, with a combinatorial embedding given as the
+    /// neighbors of every node in clockwise order.
+    Planar(DictMap<N, Vec<N>>),
+    /// The graph isn't planar, with the edges of a Kuratowski subgraph as a
+    /// witness.
+    NonPlanar {
+        kind: KuratowskiKind,
+        edges: Vec<(N, N)>,
+    },
+}
+
+const UNVISITED: usize = usize::MAX;
+
+/// A set of return edges which all have to be on the same side.
+#[derive(Clone, Copy, Default)]
+struct Interval {
+    low: Option<usize>,
+    high: Option<usize>,
+}
+
+impl Interval {
+    fn is_empty(&self) -> bool {
+        self.low.is_none() && self.high.is_none()
+    }
+}
+
+/// Two intervals whose edges have to be on different sides. Pairs are
+/// compared by `id`, which is kept when a pair is popped and pushed back.
+#[derive(Clone, Copy, Default)]
+struct ConflictPair {
+    left: Interval,
+    right: Interval,
+    id: usize,
+}
+
+impl ConflictPair {
+    fn swap(&mut self) {
+        std::mem::swap(&mut self.left, &mut self.right);
+    }
+}
+
+/// A half-edge inserted next to a reference neighbor of its start node.
+#[derive(Clone, Copy)]
+enum Reference {
+    First,
+    Clockwise(usize),
+    CounterClockwise(usize),
+}
+
+/// The cyclic order of the neighbors of every node, as the clockwise and
+/// counterclockwise neighbor of every half-edge.
+struct Embedding {
+    neighbors: Vec<HashMap<usize, (usize, usize)>>,
+    leftmost: Vec<usize>,
+}
+
+impl Embedding {
+    fn new(num_nodes: usize) -> Self {
+        Embedding {
+            neighbors: vec![HashMap::new(); num_nodes],
+            leftmost: vec![UNVISITED; num_nodes],
+        }
+    }
+
+    /// Add the half-edge `start -> end`. The new neighbor becomes the
+    /// leftmost one if it's the first neighbor or it's inserted
+    /// counterclockwise of the leftmost neighbor.
+    fn add_half_edge(&mut self, start: usize, end: usize, reference: Reference) {
+        let neighbors = &mut self.neighbors[start];
+        match reference {
+            Reference::First => {
+                neighbors.insert(end, (end, end));
+                self.leftmost[start] = end;
+            }
+            Reference::Clockwise(cw) => {
+                let ccw = neighbors[&cw].1;
+                neighbors.insert(end, (cw, ccw));
+                neighbors.get_mut(&ccw).unwrap().0 = end;
+                neighbors.get_mut(&cw).unwrap().1 = end;
+                if cw == self.leftmost[start] {
+                    self.leftmost[start] = end;
+                }
+            }
+            Reference::CounterClockwise(ccw) => {
+                let cw = neighbors[&ccw].0;
+                neighbors.insert(end, (cw, ccw));
+                neighbors.get_mut(&cw).unwrap().1 = end;
+                neighbors.get_mut(&ccw).unwrap().0 = end;
+            }
+        }
+    }
+
+    /// Add the half-edge `start -> end` as the leftmost neighbor of `start`.
+    fn add_half_edge_first(&mut self, start: usize, end: usize) {
+        if self.neighbors[start].is_empty() {
+            self.add_half_edge(start, end, Reference::First);
+        } else {
+            let leftmost = self.leftmost[start];
+            self.add_half_edge(start, end, Reference::Clockwise(leftmost));
+        }
+    }
+
+    fn into_clockwise_order(self) -> Vec<Vec<usize>> {
+        self.neighbors
+            .iter()
+            .zip(self.leftmost.iter())
+            .map(|(neighbors, &leftmost)| {
+                let mut order = Vec::with_capacity(neighbors.len());
+                if !neighbors.is_empty() {
+                    let mut current = leftmost;
+                    loop {
+                        order.push(current);
+                        current = neighbors[&current].0;
+                        if current == leftmost {
+                            break;
+                        }
+                    }
+                }
+                order
+            })
+            .collect()
+    }
+}
+
+/// The state of the left-right planarity test of a simple graph with nodes
+/// `0..n`. The edges are oriented by the first DFS and identified by their
+/// index in `edges`.
+struct LrPlanarity {
+    adjacency: Vec<Vec<usize>>,
+    roots: Vec<usize>,
+    height: Vec<usize>,
+    parent_edge: Vec<Option<usize>>,
+    edges: Vec<(usize, usize)>,
+    edge_ids: HashSet<(usize, usize)>,
+    out_edges: Vec<Vec<usize>>,
+    lowpt: Vec<usize>,
+    lowpt2: Vec<usize>,
+    nesting_depth: Vec<isize>,
+    reference: Vec<Option<usize>>,
+    side: Vec<isize>,
+    lowpt_edge: Vec<Option<usize>>,
+    stack_bottom: Vec<Option<usize>>,
+    stack: Vec<ConflictPair>,
+    next_pair_id: usize,
+}
+
+impl LrPlanarity {
+    fn new(num_nodes: usize, edges: &[(usize, usize)]) -> Self {
+        let mut adjacency = vec![Vec::new(); num_nodes];
+        let mut seen: HashSet<(usize, usize)> = HashSet::with_capacity(edges.len());
+        for &(u, v) in edges {
+            if u != v && seen.insert((u.min(v), u.max(v))) {
+                adjacency[u].push(v);
+                adjacency[v].push(u);
+            }
+        }
+        let num_edges = seen.len();
+        LrPlanarity {
+            adjacency,
+            roots: Vec::new(),
+            height: vec![UNVISITED; num_nodes],
+            parent_edge: vec![None; num_nodes],
+            edges: Vec::with_capacity(num_edges),
+            edge_ids: HashSet::with_capacity(num_edges),
+            out_edges: vec![Vec::new(); num_nodes],
+            lowpt: Vec::with_capacity(num_edges),
+            lowpt2: Vec::with_capacity(num_edges),
+            nesting_depth: Vec::with_capacity(num_edges),
+            reference: vec![None; num_edges],
+            side: vec![1; num_edges],
+            lowpt_edge: vec![None; num_edges],
+            stack_bottom: vec![None; num_edges],
+            stack: Vec::new(),
+            next_pair_id: 0,
+        }
+    }
+
+    fn num_edges(&self) -> usize {
+        self.reference.len()
+    }
+
+    /// Run the test, returning the clockwise order of the neighbors of every
+    /// node if the graph is planar and `embed` is set, or an empty list if
+    /// it isn't set.
+    fn run(mut self, embed: bool) -> Option<Vec<Vec<usize>>> {
+        let num_nodes = self.adjacency.len();
+        if num_nodes > 2 && self.num_edges() > 3 * num_nodes - 6 {
+            return None;
+        }
+        for v in 0..num_nodes {
+            if self.height[v] == UNVISITED {
+                self.height[v] = 0;
+                self.roots.push(v);
+                self.dfs_orientation(v);
+            }
+        }
+        let mut ordered_adjacency = self.ordered_adjacency();
+        let mut index = vec![0; num_nodes];
+        let mut resume = vec![false; num_nodes];
+        for root in self.roots.clone() {
+            if !self.dfs_testing(root, &ordered_adjacency, &mut index, &mut resume) {
+                return None;
+            }
+        }
+        if !embed {
+            return Some(Vec::new());
+        }
+
+        for edge in 0..self.num_edges() {
+            self.nesting_depth[edge] *= self.sign(edge);
+        }
+        ordered_adjacency = self.ordered_adjacency();
+        let mut embedding = Embedding::new(num_nodes);
+        for (v, ordered) in ordered_adjacency.iter().enumerate() {
+            let mut previous = None;
+            for &edge in ordered {
+                let w = self.edges[edge].1;
+                let reference = match previous {
+                    Some(previous) => Reference::CounterClockwise(previous),
+                    None => Reference::First,
+                };
+                embedding.add_half_edge(v, w, reference);
+                previous = Some(w);
+            }
+        }
+        let mut index = vec![0; num_nodes];
+        let mut left_ref = vec![UNVISITED; num_nodes];
+        let mut right_ref = vec![UNVISITED; num_nodes];
+        for &root in &self.roots {
+            let mut stack = vec![root];
+            while let Some(v) = stack.pop() {
+                while index[v] < ordered_adjacency[v].len() {
+                    let edge = ordered_adjacency[v][index[v]];
+                    index[v] += 1;
+                    let w = self.edges[edge].1;
+                    if self.parent_edge[w] == Some(edge) {
+                        embedding.add_half_edge_first(w, v);
+                        left_ref[v] = w;
+                        right_ref[v] = w;
+                        stack.push(v);
+                        stack.push(w);
+                        break;
+                    } else if self.side[edge] == 1 {
+                        embedding.add_half_edge(w, v, Reference::CounterClockwise(right_ref[w]));
+                    } else {
+                        embedding.add_half_edge(w, v, Reference::Clockwise(left_ref[w]));
+                        left_ref[w] = v;
+                    }
+                }
+            }
+        }
+        Some(embedding.into_clockwise_order())
+    }
+
+    /// The outgoing edges of every node sorted by nesting depth.
+    fn ordered_adjacency(&self) -> Vec<Vec<usize>> {
+        self.out_edges
+            .iter()
+            .map(|out_edges| {
+                let mut ordered = out_edges.clone();
+                ordered.sort_by_key(|edge| self.nesting_depth[*edge]);
+                ordered
+            })
+            .collect()
+    }
+
+    /// Orient the edges by DFS and compute their lowpoints and nesting
+    /// depths.
+    fn dfs_orientation(&mut self, root: usize) {
+        let mut stack = vec![root];
+        let mut index = vec![0; self.adjacency.len()];
+        // Whether the edge at the index of a node is a tree edge whose child
+        // was visited
+        let mut resume = vec![false; self.adjacency.len()];
+        'dfs: while let Some(v) = stack.pop() {
+            let parent = self.parent_edge[v];
+            while index[v] < self.adjacency[v].len() {
+                let w = self.adjacency[v][index[v]];
+                let edge = if resume[v] {
+                    resume[v] = false;
+                    self.out_edges[v][self.out_edges[v].len() - 1]
+                } else {
+                    if self.edge_ids.contains(&(v, w)) || self.edge_ids.contains(&(w, v)) {
+                        index[v] += 1;
+                        continue;
+                    }
+                    let edge = self.edges.len();
+                    self.edges.push((v, w));
+                    self.edge_ids.insert((v, w));
+                    self.out_edges[v].push(edge);
+                    self.lowpt.push(self.height[v]);
+                    self.lowpt2.push(self.height[v]);
+                    self.nesting_depth.push(0);
+                    if self.height[w] == UNVISITED {
+                        // A tree edge
+                        self.parent_edge[w] = Some(edge);
+                        self.height[w] = self.height[v] + 1;
+                        resume[v] = true;
+                        stack.push(v);
+                        stack.push(w);
+                        continue 'dfs;
+                    }
+                    // A back edge
+                    self.lowpt[edge] = self.height[w];
+                    edge
+                };
+                self.nesting_depth[edge] = 2 * self.lowpt[edge] as isize;
+                if self.lowpt2[edge] < self.height[v] {
+                    // A chordal edge
+                    self.nesting_depth[edge] += 1;
+                }
+                if let Some(parent) = parent {
+                    if self.lowpt[edge] < self.lowpt[parent] {
+                        self.lowpt2[parent] = self.lowpt[parent].min(self.lowpt2[edge]);
+                        self.lowpt[parent] = self.lowpt[edge];
+                    } else if self.lowpt[edge] > self.lowpt[parent] {
+                        self.lowpt2[parent] = self.lowpt2[parent].min(self.lowpt[edge]);
+                    } else {
+                        self.lowpt2[parent] = self.lowpt2[parent].min(self.lowpt2[edge]);
+                    }
+                }
+                index[v] += 1;
+            }
+        }
+    }
+
+    /// Test for a left-right partition of the back edges.
+    fn dfs_testing(
+        &mut self,
+        root: usize,
+        ordered_adjacency: &[Vec<usize>],
+        index: &mut [usize],
+        resume: &mut [bool],
+    ) -> bool {
+        let mut stack = vec![root];
+        'dfs: while let Some(v) = stack.pop() {
+            let parent = self.parent_edge[v];
+            while index[v] < ordered_adjacency[v].len() {
+                let edge = ordered_adjacency[v][index[v]];
+                let w = self.edges[edge].1;
+                if resume[v] {
+                    resume[v] = false;
+                } else {
+                    self.stack_bottom[edge] = self.stack.last().map(|pair| pair.id);
+                    if self.parent_edge[w] == Some(edge) {
+                        resume[v] = true;
+                        stack.push(v);
+                        stack.push(w);
+                        continue 'dfs;
+                    }
+                    self.lowpt_edge[edge] = Some(edge);
+                    self.push_pair(ConflictPair {
+                        right: Interval {
+                            low: Some(edge),
+                            high: Some(edge),
+                        },
+                        ..ConflictPair::default()
+                    });
+                }
+                if self.lowpt[edge] < self.height[v] {
+                    // The edge has a return edge, and v isn't a root
+                    let parent = parent.unwrap();
+                    if edge == ordered_adjacency[v][0] {
+                        self.lowpt_edge[parent] = self.lowpt_edge[edge];
+                    } else if !self.add_constraints(edge, parent) {
+                        return false;
+                    }
+                }
+                index[v] += 1;
+            }
+            if let Some(parent) = parent {
+                self.remove_back_edges(parent);
+            }
+        }
+        true
+    }
+
+    fn push_pair(&mut self, mut pair: ConflictPair) {
+        pair.id = self.next_pair_id;
+        self.next_pair_id += 1;
+        self.stack.push(pair);
+    }
+
+    fn conflicting(&self, interval: &Interval, edge: usize) -> bool {
+        matches!(interval.high, Some(high) if self.lowpt[high] > self.lowpt[edge])
+    }
+
+    fn lowest(&self, pair: &ConflictPair) -> usize {
+        match (pair.left.low, pair.right.low) {
+            (Some(left), Some(right)) => self.lowpt[left].min(self.lowpt[right]),
+            (Some(low), None) | (None, Some(low)) => self.lowpt[low],
+            (None, None) => UNVISITED,
+        }
+    }
+
+    fn add_constraints(&mut self, edge: usize, parent: usize) -> bool {
+        let mut pair = ConflictPair::default();
+        // Merge the return edges of edge into the right interval
+        loop {
+            let mut other = self.stack.pop().unwrap();
+            if !other.left.is_empty() {
+                other.swap();
+            }
+            if !other.left.is_empty() {
+                return false;
+            }
+            let other_low = other.right.low.unwrap();
+            if self.lowpt[other_low] > self.lowpt[parent] {
+                // Merge the intervals
+                if pair.right.is_empty() {
+                    pair.right = other.right;
+                } else if let Some(low) = pair.right.low {
+                    self.reference[low] = other.right.high;
+                }
+                pair.right.low = other.right.low;
+            } else {
+                // Align
+                self.reference[other_low] = self.lowpt_edge[parent];
+            }
+            if self.stack.last().map(|pair| pair.id) == self.stack_bottom[edge] {
+                break;
+            }
+        }
+        // Merge the conflicting return edges of the previous siblings into
+        // the left interval
+        while let Some(top) = self.stack.last() {
+            if !self.conflicting(&top.left, edge) && !self.conflicting(&top.right, edge) {
+                break;
+            }
+            let mut other = self.stack.pop().unwrap();
+            if self.conflicting(&other.right, edge) {
+                other.swap();
+            }
+            if self.conflicting(&other.right, edge) {
+                return false;
+            }
+            // Merge the interval below lowpt(edge) into the right interval
+            if let Some(low) = pair.right.low {
+                self.reference[low] = other.right.high;
+            }
+            if other.right.low.is_some() {
+                pair.right.low = other.right.low;
+            }
+            if pair.left.is_empty() {
+                pair.left = other.left;
+            } else if let Some(low) = pair.left.low {
+                self.reference[low] = other.left.high;
+            }
+            pair.left.low = other.left.low;
+        }
+        if !pair.left.is_empty() || !pair.right.is_empty() {
+            self.push_pair(pair);
+        }
+        true
+    }
+
+    fn remove_back_edges(&mut self, edge: usize) {
+        let u = self.edges[edge].0;
+        // Drop the conflict pairs whose back edges all end at u
+        while let Some(top) = self.stack.last() {
+            if self.lowest(top) != self.height[u] {
+                break;
+            }
+            let pair = self.stack.pop().unwrap();
+            if let Some(low) = pair.left.low {
+                self.side[low] = -1;
+            }
+        }
+        if let Some(mut pair) = self.stack.pop() {
+            // Trim the left interval
+            while let Some(high) = pair.left.high {
+                if self.edges[high].1 != u {
+                    break;
+                }
+                pair.left.high = self.reference[high];
+            }
+            if pair.left.high.is_none() {
+                if let Some(low) = pair.left.low {
+                    self.reference[low] = pair.right.low;
+                    self.side[low] = -1;
+                    pair.left.low = None;
+                }
+            }
+            // Trim the right interval
+            while let Some(high) = pair.right.high {
+                if self.edges[high].1 != u {
+                    break;
+                }
+                pair.right.high = self.reference[high];
+            }
+            if pair.right.high.is_none() {
+                if let Some(low) = pair.right.low {
+                    self.reference[low] = pair.left.low;
+                    self.side[low] = -1;
+                    pair.right.low = None;
+                }
+            }
+            self.stack.push(pair);
+        }
+        // The side of edge is the side of a highest return edge
+        if self.lowpt[edge] < self.height[u] {
+            let top = self.stack.last().unwrap();
+            let (left, right) = (top.left.high, top.right.high);
+            self.reference[edge] = match (left, right) {
+                (Some(left), Some(right)) if self.lowpt[left] > self.lowpt[right] => Some(left),
+                (Some(left), None) => Some(left),
+                _ => right,
+            };
+        }
+    }
+
+    /// Resolve the side of an edge relative to its reference edge to an
+    /// absolute side.
+    fn sign(&mut self, edge: usize) -> isize {
+        let mut stack = vec![edge];
+        let mut old_reference: HashMap<usize, usize> = HashMap::new();
+        while let Some(current) = stack.pop() {
+            match self.reference[current].take() {
+                Some(reference) => {
+                    stack.push(current);
+                    stack.push(reference);
+                    old_reference.insert(current, reference);
+                }
+                None => {
+                    if let Some(reference) = old_reference.get(&current) {
+                        self.side[current] *= self.side[*reference];
+                    }
+                }
+            }
+        }
+        self.side[edge]
+    }
+}
+
+/// Return the edges of a minimal non planar subgraph of a non planar graph,
+/// which is a Kuratowski subgraph.
+///
+/// Removing an edge which leaves the graph non planar can't make an edge
+/// kept earlier removable, so each edge is only tested once. Edges are
+/// removed in chunks whose size doubles while the graph stays non planar,
+/// and halves when it becomes planar, since most edges of a large graph
+/// aren't part of the subgraph.
+fn kuratowski_edges(edges: Vec<(usize, usize)>) -> Vec<(usize, usize)> {
+    // Only the nodes of the remaining edges are tested, so the tests get
+    // faster as edges are removed
+    let is_planar = |edges: &[(usize, usize)]| {
+        let mut nodes: HashMap<usize, usize> = HashMap::with_capacity(edges.len());
+        let edges: Vec<(usize, usize)> = edges
+            .iter()
+            .map(|&(u, v)| {
+                let next = nodes.len();
+                let u = *nodes.entry(u).or_insert(next);
+                let next = nodes.len();
+                let v = *nodes.entry(v).or_insert(next);
+                (u, v)
+            })
+            .collect();
+        LrPlanarity::new(nodes.len(), &edges).run(false).is_some()
+    };
+    let mut kept: Vec<(usize, usize)> = Vec::new();
+    let mut start = 0;
+    let mut chunk = 1;
+    while start < edges.len() {
+        let end = (start + chunk).min(edges.len());
+        let trial: Vec<(usize, usize)> = kept.iter().chain(&edges[end..]).copied().collect();
+        if !is_planar(&trial) {
+            start = end;
+            chunk *= 2;
+        } else if end - start == 1 {
+            kept.push(edges[start]);
+            start = end;
+        } else {
+            chunk = (end - start) / 2;
+        }
+    }
+    kept
+}
+
+/// Map the nodes of a graph to `0..n` and return its edges between these
+/// indices, without self loops and parallel edges.
+fn simple_edges<G>(graph: G) -> (Vec<G::NodeId>, Vec<(usize, usize)>)
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable,
+{
+    let mut positions = vec![UNVISITED; graph.node_bound()];
+    let nodes: Vec<G::NodeId> = graph.node_identifiers().collect();
+    for (position, node) in nodes.iter().enumerate() {
+        positions[graph.to_index(*node)] = position;
+    }
+    let mut seen: HashSet<(usize, usize)> = HashSet::new();
+    let edges = graph
+        .edge_references()
+        .filter_map(|edge| {
+            let u = positions[graph.to_index(edge.source())];
+            let v = positions[graph.to_index(edge.target())];
+            if u != v && seen.insert((u.min(v), u.max(v))) {
+                Some((u, v))
+            } else {
+                None
+            }
+        })
+        .collect();
+    (nodes, edges)
+}
+
+/// Return whether a graph is planar.
+///
+/// A graph is planar if it can be drawn in the plane without any edges
+/// crossing. The direction of the edges is ignored.
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::planar::is_planar;
+///
+/// let k4 = petgraph::graph::UnGraph::<(), ()>::from_edges(&[
+///     (0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3),
+/// ]);
+/// assert!(is_planar(&k4));
+/// let k5 = petgraph::graph::UnGraph::<(), ()>::from_edges(&[
+///     (0, 1), (0, 2), (0, 3), (0, 4), (1, 2), (1, 3), (1, 4), (2, 3), (2, 4), (3, 4),
+/// ]);
+/// assert!(!is_planar(&k5));
+/// ```
+pub fn is_planar<G>(graph: G) -> bool
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable,
+{
+    let (nodes, edges) = simple_edges(graph);
+    LrPlanarity::new(nodes.len(), &edges).run(false).is_some()
+}
+
+/// Check whether a graph is planar, with an embedding or a Kuratowski
+/// subgraph as a certificate.
+///
+/// A graph is planar if it can be drawn in the plane without any edges
+/// crossing. The direction of the edges, self loops and parallel edges are
+/// ignored. If the graph is planar, [`Planarity::Planar`] has a
+/// combinatorial embedding of the graph: the neighbors of every node in the
+/// clockwise order of a planar drawing. Otherwise [`Planarity::NonPlanar`]
+/// has the edges of a Kuratowski subgraph, a subdivision of either `K5` or
+/// `K3,3` (with each edge given once), which by Kuratowski's theorem proves
+/// that the graph isn't planar.
+///
+/// The embedding is found in linear time (up to sorting the adjacency
+/// lists), while finding the Kuratowski subgraph can take a planarity test
+/// for each of its edges and `O(log m)` for each other edge.
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::petgraph::graph::NodeIndex;
+/// use retworkx_core::planar::{check_planarity, KuratowskiKind, Planarity};
+///
+/// // A square with a diagonal
+/// let g = petgraph::graph::UnGraph::<(), ()>::from_edges(&[
+///     (0, 1), (1, 2), (2, 3), (3, 0), (0, 2),
+/// ]);
+/// match check_planarity(&g) {
+///     Planarity::Planar(embedding) => {
+///         assert_eq!(embedding[&NodeIndex::new(0)].len(), 3);
+///         assert_eq!(embedding[&NodeIndex::new(1)].len(), 2);
+///     }
+///     Planarity::NonPlanar { .. } => unreachable!(),
+/// }
+///
+/// // K3,3 with an extra edge
+/// let g = petgraph::graph::UnGraph::<(), ()>::from_edges(&[
+///     (0, 3), (0, 4), (0, 5), (1, 3), (1, 4), (1, 5), (2, 3), (2, 4), (2, 5), (0, 1),
+/// ]);
+/// match check_planarity(&g) {
+///     Planarity::NonPlanar { kind, edges } => {
+///         assert_eq!(kind, KuratowskiKind::K33);
+///         assert_eq!(edges.len(), 9);
+///     }
+///     Planarity::Planar(_) => unreachable!(),
+/// }
+/// ```
+pub fn check_planarity<G>(graph: G) -> Planarity<G::NodeId>
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable,
+    G::NodeId: Hash + Eq,
+{
+    let (nodes, edges) = simple_edges(graph);
+    match LrPlanarity::new(nodes.len(), &edges).run(true) {
+        Some(order) => Planarity::Planar(
+            order
+                .into_iter()
+                .enumerate()
+                .map(|(node, neighbors)| {
+                    (
+                        nodes[node],
+                        neighbors
+                            .into_iter()
+                            .map(|neighbor| nodes[neighbor])
+                            .collect(),
+                    )
+                })
+                .collect(),
+        ),
+        None => {
+            let edges = kuratowski_edges(edges);
+            let mut degree = vec![0; nodes.len()];
+            for &(u, v) in &edges {
+                degree[u] += 1;
+                degree[v] += 1;
+            }
+            // The branch nodes of a subdivision of K5 have degree 4 and the
+            // ones of a subdivision of K3,3 have degree 3
+            let kind = if degree.contains(&4) {
+                KuratowskiKind::K5
+            } else {
+                KuratowskiKind::K33
+            };
+            Planarity::NonPlanar {
+                kind,
+                edges: edges
+                    .into_iter()
+                    .map(|(u, v)| (nodes[u], nodes[v]))
+                    .collect(),
+            }
+        }
+    }
+}
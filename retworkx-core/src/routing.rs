@@ -0,0 +1,339 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+//! Module for constructive heuristics for capacitated vehicle routing.
+//!
+//! The functions in this module build routes starting and ending at a
+//! depot node which together visit a set of customer nodes, where the total
+//! demand of the customers of a route doesn't exceed the capacity of a
+//! vehicle. The cost of going from a node to another is the length of the
+//! shortest path between them in the graph, so the graph doesn't need to be
+//! complete. They're classic construction heuristics, which build good but
+//! not optimal routes quickly, and whose routes can be the starting point of
+//! an improvement heuristic.
+
+use std::f64::consts::PI;
+use std::hash::Hash;
+
+use petgraph::visit::{GraphProp, IntoEdges, NodeIndexable, Visitable};
+
+use crate::dictmap::*;
+use crate::shortest_path::dijkstra;
+
+/// A route built by [`savings_routes`] or [`sweep_routes`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct Route<N> {
+    /// The nodes visited by the route in order, starting and ending at the
+    /// depot with the customers of the route in between.
+    pub nodes: Vec<N>,
+    /// The total demand of the customers of the route.
+    pub load: f64,
+    /// The total length of the shortest paths between consecutive nodes of
+    /// the route. It's infinite if one of them isn't reachable from the
+    /// previous one.
+    pub cost: f64,
+}
+
+/// The shortest path lengths between the depot and the customers, where
+/// index 0 is the depot and index `i + 1` is customer `i`.
+fn distance_matrix<G, F, E>(
+    graph: G,
+    depot: G::NodeId,
+    customers: &[(G::NodeId, f64)],
+    mut edge_cost: F,
+) -> Result<Vec<Vec<f64>>, E>
+where
+    G: IntoEdges + Visitable + NodeIndexable,
+    G::NodeId: Eq + Hash,
+    F: FnMut(G::EdgeRef) -> Result<f64, E>,
+{
+    let nodes: Vec<G::NodeId> = Some(depot)
+        .into_iter()
+        .chain(customers.iter().map(|(node, _)| *node))
+        .collect();
+    nodes
+        .iter()
+        .map(|source| {
+            let distances: DictMap<G::NodeId, f64> =
+                dijkstra(graph, *source, None, &mut edge_cost, None)?;
+            Ok(nodes
+                .iter()
+                .map(|target| *distances.get(target).unwrap_or(&f64::INFINITY))
+                .collect())
+        })
+        .collect()
+}
+
+/// The route visiting the customers at the given positions of `customers`
+/// in order.
+fn build_route<N: Copy>(
+    depot: N,
+    customers: &[(N, f64)],
+    distances: &[Vec<f64>],
+    stops: &[usize],
+) -> Route<N> {
+    let mut nodes = Vec::with_capacity(stops.len() + 2);
+    nodes.push(depot);
+    nodes.extend(stops.iter().map(|&stop| customers[stop].0));
+    nodes.push(depot);
+    let load = stops.iter().map(|&stop| customers[stop].1).sum();
+    let cost = Some(0)
+        .into_iter()
+        .chain(stops.iter().map(|&stop| stop + 1))
+        .zip(stops.iter().map(|&stop| stop + 1).chain(Some(0)))
+        .map(|(from, to)| distances[from][to])
+        .sum();
+    Route { nodes, load, cost }
+}
+
+/// Build capacitated routes with the Clarke-Wright savings heuristic.
+///
+/// Every customer starts in a route of its own, then the routes are merged
+/// in decreasing order of the savings `d(i, depot) + d(depot, j) - d(i, j)`
+/// of going directly from customer `i` to customer `j` instead of through
+/// the depot, where `d` is the length of the shortest path. Two routes are
+/// merged if `i` is the last customer of one route, `j` is the first
+/// customer of the other one and their total demand doesn't exceed
+/// `capacity` [1]. For an undirected graph the routes can be reversed, so
+/// `i` and `j` only need to be the first or last customer of their routes.
+/// Only positive and finite savings are used.
+///
+/// Arguments:
+///
+/// * `graph` - The graph to route on, its edge costs must be non-negative
+/// * `depot` - The node every route starts and ends at
+/// * `customers` - The nodes to visit with their demands. They must be
+///   distinct and different from `depot`.
+/// * `capacity` - The maximum total demand of the customers of a route. A
+///   customer whose demand exceeds it is visited by a route of its own.
+/// * `edge_cost` - A callable that will be passed an edge reference and
+///   returns the cost of the edge, or an error that is returned immediately
+///
+/// Returns the routes in the order of their first customer in `customers`.
+/// A customer which isn't reachable from the depot, or from which the depot
+/// isn't reachable, is visited by a route of its own with an infinite cost.
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::petgraph::graph::NodeIndex;
+/// use retworkx_core::routing::{savings_routes, Route};
+/// use retworkx_core::Result;
+///
+/// // A star with the depot in the middle and a path between its leaves
+/// let graph = petgraph::graph::UnGraph::<(), f64>::from_edges(&[
+///     (0, 1, 10.), (0, 2, 10.), (0, 3, 10.), (0, 4, 10.),
+///     (1, 2, 1.), (2, 3, 1.), (3, 4, 1.),
+/// ]);
+/// let customers: Vec<(NodeIndex, f64)> =
+///     (1..5).map(|node| (NodeIndex::new(node), 1.)).collect();
+/// let res: Result<Vec<Route<NodeIndex>>> =
+///     savings_routes(&graph, NodeIndex::new(0), &customers, 2., |e| Ok(*e.weight()));
+/// let routes = res.unwrap();
+/// assert_eq!(routes.len(), 2);
+/// assert_eq!(
+///     routes[0].nodes,
+///     vec![NodeIndex::new(0), NodeIndex::new(1), NodeIndex::new(2), NodeIndex::new(0)]
+/// );
+/// assert_eq!(routes[0].load, 2.);
+/// assert_eq!(routes[0].cost, 21.);
+/// ```
+///
+/// [1] Clarke, G. and Wright, J. W.
+///     "Scheduling of Vehicles from a Central Depot to a Number of Delivery Points"
+///     Operations Research 12 (4), 568–581 (1964).
+///     <https://doi.org/10.1287/opre.12.4.568>
+pub fn savings_routes<G, F, E>(
+    graph: G,
+    depot: G::NodeId,
+    customers: &[(G::NodeId, f64)],
+    capacity: f64,
+    edge_cost: F,
+) -> Result<Vec<Route<G::NodeId>>, E>
+where
+    G: IntoEdges + Visitable + NodeIndexable + GraphProp,
+    G::NodeId: Eq + Hash,
+    F: FnMut(G::EdgeRef) -> Result<f64, E>,
+{
+    let directed = graph.is_directed();
+    let distances = distance_matrix(graph, depot, customers, edge_cost)?;
+    let n = customers.len();
+    let mut savings: Vec<(f64, usize, usize)> = Vec::new();
+    for i in 0..n {
+        for j in 0..n {
+            if i == j || (!directed && j < i) {
+                continue;
+            }
+            let saving = distances[i + 1][0] + distances[0][j + 1] - distances[i + 1][j + 1];
+            if saving.is_finite() && saving > 0. {
+                savings.push((saving, i, j));
+            }
+        }
+    }
+    // The sort is stable so equal savings are used in the order of their
+    // customers
+    savings.sort_by(|a, b| b.0.partial_cmp(&a.0).unwrap());
+
+    // The routes are stored at the position of one of their customers
+    let mut routes: Vec<Vec<usize>> = (0..n).map(|i| vec![i]).collect();
+    let mut loads: Vec<f64> = customers.iter().map(|(_, demand)| *demand).collect();
+    let mut route_of: Vec<usize> = (0..n).collect();
+    for (_, i, j) in savings {
+        let (a, b) = (route_of[i], route_of[j]);
+        if a == b || loads[a] + loads[b] > capacity {
+            continue;
+        }
+        let (route_a, route_b) = (&routes[a], &routes[b]);
+        let i_last = *route_a.last().unwrap() == i;
+        let j_first = route_b[0] == j;
+        if directed {
+            if !(i_last && j_first) {
+                continue;
+            }
+        } else {
+            if !(i_last || route_a[0] == i) || !(j_first || *route_b.last().unwrap() == j) {
+                continue;
+            }
+            if !i_last {
+                routes[a].reverse();
+            }
+            if !j_first {
+                routes[b].reverse();
+            }
+        }
+        let route_b = std::mem::take(&mut routes[b]);
+        for &customer in &route_b {
+            route_of[customer] = a;
+        }
+        routes[a].extend(route_b);
+        loads[a] += loads[b];
+    }
+
+    let mut seen = vec![false; n];
+    let mut out_routes = Vec::new();
+    for &route in &route_of {
+        if !seen[route] {
+            seen[route] = true;
+            out_routes.push(build_route(depot, customers, &distances, &routes[route]));
+        }
+    }
+    Ok(out_routes)
+}
+
+/// Build capacitated routes with the sweep heuristic.
+///
+/// The customers are sorted by the polar angle of their position around
+/// the position of the depot, from `-π` to `π`, and split in that order
+/// into routes: a customer is added to the current route unless the total
+/// demand would exceed `capacity`, in which case it starts a new route [1].
+/// The customers of a route are visited in the order of their angles.
+/// Customers with the same angle stay in the order of `customers`.
+///
+/// Arguments:
+///
+/// * `graph` - The graph to route on, its edge costs must be non-negative
+/// * `depot` - The node every route starts and ends at
+/// * `customers` - The nodes to visit with their demands. They must be
+///   distinct and different from `depot`.
+/// * `capacity` - The maximum total demand of the customers of a route. A
+///   customer whose demand exceeds it is visited by a route of its own.
+/// * `position` - A callable that will be passed the depot and every
+///   customer and returns its `(x, y)` position, or an error that is
+///   returned immediately
+/// * `edge_cost` - A callable that will be passed an edge reference and
+///   returns the cost of the edge, or an error that is returned immediately
+///
+/// Returns the routes in the order of the angles of their customers. The
+/// cost of a route is infinite if it goes through a node which isn't
+/// reachable from the previous one.
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::petgraph::graph::NodeIndex;
+/// use retworkx_core::routing::{sweep_routes, Route};
+/// use retworkx_core::Result;
+///
+/// // A wheel with the depot in the middle
+/// let graph = petgraph::graph::UnGraph::<(f64, f64), f64>::from_edges(&[
+///     (0, 1, 1.), (0, 2, 1.), (0, 3, 1.), (0, 4, 1.),
+///     (1, 2, 1.), (2, 3, 1.), (3, 4, 1.), (4, 1, 1.),
+/// ]);
+/// let positions = [(0., 0.), (1., 0.), (0., 1.), (-1., 0.), (0., -1.)];
+/// let customers: Vec<(NodeIndex, f64)> =
+///     (1..5).map(|node| (NodeIndex::new(node), 1.)).collect();
+/// let res: Result<Vec<Route<NodeIndex>>> = sweep_routes(
+///     &graph,
+///     NodeIndex::new(0),
+///     &customers,
+///     2.,
+///     |node| Ok(positions[node.index()]),
+///     |e| Ok(*e.weight()),
+/// );
+/// let routes = res.unwrap();
+/// let nodes: Vec<Vec<usize>> = routes
+///     .iter()
+///     .map(|route| route.nodes.iter().map(|node| node.index()).collect())
+///     .collect();
+/// // The angle of node 3 is π, the angle of node 4 is -π/2
+/// assert_eq!(nodes, vec![vec![0, 4, 1, 0], vec![0, 2, 3, 0]]);
+/// assert_eq!(routes[0].cost, 3.);
+/// ```
+///
+/// [1] Gillett, B. E. and Miller, L. R.
+///     "A Heuristic Algorithm for the Vehicle-Dispatch Problem"
+///     Operations Research 22 (2), 340–349 (1974).
+///     <https://doi.org/10.1287/opre.22.2.340>
+pub fn sweep_routes<G, P, F, E>(
+    graph: G,
+    depot: G::NodeId,
+    customers: &[(G::NodeId, f64)],
+    capacity: f64,
+    mut position: P,
+    edge_cost: F,
+) -> Result<Vec<Route<G::NodeId>>, E>
+where
+    G: IntoEdges + Visitable + NodeIndexable,
+    G::NodeId: Eq + Hash,
+    P: FnMut(G::NodeId) -> Result<(f64, f64), E>,
+    F: FnMut(G::EdgeRef) -> Result<f64, E>,
+{
+    let (depot_x, depot_y) = position(depot)?;
+    let mut angles: Vec<(f64, usize)> = Vec::with_capacity(customers.len());
+    for (i, (node, _)) in customers.iter().enumerate() {
+        let (x, y) = position(*node)?;
+        let angle = (y - depot_y).atan2(x - depot_x);
+        // atan2 gives π and -π for the negative x axis depending on the sign
+        // of zero, use π for both
+        angles.push((if angle == -PI { PI } else { angle }, i));
+    }
+    angles.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+    let distances = distance_matrix(graph, depot, customers, edge_cost)?;
+
+    let mut out_routes = Vec::new();
+    let mut stops: Vec<usize> = Vec::new();
+    let mut load = 0.;
+    for (_, i) in angles {
+        let demand = customers[i].1;
+        if !stops.is_empty() && load + demand > capacity {
+            out_routes.push(build_route(depot, customers, &distances, &stops));
+            stops.clear();
+            load = 0.;
+        }
+        stops.push(i);
+        load += demand;
+    }
+    if !stops.is_empty() {
+        out_routes.push(build_route(depot, customers, &distances, &stops));
+    }
+    Ok(out_routes)
+}
@@ -0,0 +1,520 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+//! Module for sampling random walks of graphs, for example to train node
+//! embeddings, and for sampling the nodes or edges of representative
+//! subgraphs of large graphs.
+
+use std::collections::VecDeque;
+
+use petgraph::visit::{
+    EdgeRef, GraphProp, IntoEdgeReferences, IntoNeighborsDirected, IntoNodeIdentifiers,
+    NodeIndexable,
+};
+use petgraph::Direction::{Incoming, Outgoing};
+use rand::prelude::*;
+use rand_pcg::Pcg64;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// Map `f` over every item, in parallel if there are at least
+/// `parallel_threshold` items and the `parallel` feature is enabled.
+fn map_items<I, T, F>(items: &[I], parallel_threshold: usize, f: F) -> Vec<T>
+where
+    I: Sync,
+    T: Send,
+    F: Fn(&I) -> T + Sync + Send,
+{
+    #[cfg(feature = "parallel")]
+    if items.len() >= parallel_threshold {
+        return items.par_iter().map(f).collect();
+    }
+    #[cfg(not(feature = "parallel"))]
+    let _ = parallel_threshold;
+    items.iter().map(f).collect()
+}
+
+fn seeded_rng(seed: Option<u64>) -> Pcg64 {
+    match seed {
+        Some(seed) => Pcg64::seed_from_u64(seed),
+        None => Pcg64::from_entropy(),
+    }
+}
+
+/// An alias table, to pick an index with a probability proportional to its
+/// weight in constant time, built with Vose's method.
+#[derive(Clone, Debug)]
+struct AliasTable {
+    /// The probability of keeping every index rather than its alias.
+    prob: Vec<f64>,
+    alias: Vec<usize>,
+}
+
+impl AliasTable {
+    /// Build the table of non-empty positive `weights`.
+    fn new(weights: &[f64]) -> Self {
+        let n = weights.len();
+        let total: f64 = weights.iter().sum();
+        let mut prob: Vec<f64> = weights.iter().map(|w| w * n as f64 / total).collect();
+        let mut alias: Vec<usize> = (0..n).collect();
+        let (mut small, mut large): (Vec<usize>, Vec<usize>) = (0..n).partition(|i| prob[*i] < 1.0);
+        while !small.is_empty() && !large.is_empty() {
+            let less = small.pop().unwrap();
+            let more = *large.last().unwrap();
+            alias[less] = more;
+            prob[more] -= 1.0 - prob[less];
+            if prob[more] < 1.0 {
+                large.pop();
+                small.push(more);
+            }
+        }
+        // Only rounding errors can leave indices without an alias
+        for i in large.into_iter().chain(small) {
+            prob[i] = 1.0;
+        }
+        AliasTable { prob, alias }
+    }
+
+    fn sample<R: Rng>(&self, rng: &mut R) -> usize {
+        let i = rng.gen_range(0..self.prob.len());
+        if rng.gen::<f64>() < self.prob[i] {
+            i
+        } else {
+            self.alias[i]
+        }
+    }
+}
+
+/// The out neighbors of every node of a graph, by position, in order of
+/// position, with an alias table to pick one with a probability
+/// proportional to the total weight of the edges to it.
+struct WalkGraph {
+    neighbors: Vec<Vec<usize>>,
+    tables: Vec<Option<AliasTable>>,
+}
+
+impl WalkGraph {
+    /// Build the graph of the edges of positive weight, every edge of an
+    /// undirected graph goes both ways. Returns the nodes of the graph in
+    /// the order of their positions and the position of every node index.
+    #[allow(clippy::type_complexity)]
+    fn from_graph<G, F, E>(
+        graph: G,
+        mut weight_fn: F,
+    ) -> Result<(Vec<G::NodeId>, Vec<usize>, Self), E>
+    where
+        G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable + GraphProp,
+        F: FnMut(G::EdgeRef) -> Result<f64, E>,
+    {
+        let nodes: Vec<G::NodeId> = graph.node_identifiers().collect();
+        let mut position = vec![usize::MAX; graph.node_bound()];
+        for (i, node) in nodes.iter().enumerate() {
+            position[graph.to_index(*node)] = i;
+        }
+        let mut edges: Vec<Vec<(usize, f64)>> = vec![Vec::new(); nodes.len()];
+        for edge in graph.edge_references() {
+            let weight = weight_fn(edge)?;
+            if weight > 0.0 {
+                let source = position[graph.to_index(edge.source())];
+                let target = position[graph.to_index(edge.target())];
+                edges[source].push((target, weight));
+                if !graph.is_directed() && source != target {
+                    edges[target].push((source, weight));
+                }
+            }
+        }
+        let mut neighbors = Vec::with_capacity(nodes.len());
+        let mut tables = Vec::with_capacity(nodes.len());
+        for mut node_edges in edges {
+            node_edges.sort_by_key(|edge| edge.0);
+            let mut node_neighbors: Vec<usize> = Vec::with_capacity(node_edges.len());
+            let mut weights: Vec<f64> = Vec::with_capacity(node_edges.len());
+            for (neighbor, weight) in node_edges {
+                if node_neighbors.last() == Some(&neighbor) {
+                    *weights.last_mut().unwrap() += weight;
+                } else {
+                    node_neighbors.push(neighbor);
+                    weights.push(weight);
+                }
+            }
+            tables.push(if weights.is_empty() {
+                None
+            } else {
+                Some(AliasTable::new(&weights))
+            });
+            neighbors.push(node_neighbors);
+        }
+        Ok((nodes, position, WalkGraph { neighbors, tables }))
+    }
+
+    /// Walk `walk_length` nodes from `start`, or less if the walk reaches a
+    /// node without out neighbors.
+    ///
+    /// After the first step the next node is picked by rejection sampling:
+    /// a neighbor is proposed from the alias table of the current node and
+    /// accepted with a probability proportional to its bias, `1 / p` for
+    /// the previous node, 1 for the out neighbors of the previous node and
+    /// `1 / q` for the other nodes.
+    fn walk<R: Rng>(
+        &self,
+        start: usize,
+        walk_length: usize,
+        p: f64,
+        q: f64,
+        rng: &mut R,
+    ) -> Vec<usize> {
+        let mut walk = Vec::with_capacity(walk_length);
+        if walk_length == 0 {
+            return walk;
+        }
+        walk.push(start);
+        let unbiased = p == 1.0 && q == 1.0;
+        let max_bias = (1.0 / p).max(1.0).max(1.0 / q);
+        while walk.len() < walk_length {
+            let current = walk[walk.len() - 1];
+            let table = match &self.tables[current] {
+                Some(table) => table,
+                None => break,
+            };
+            let neighbors = &self.neighbors[current];
+            let next = if unbiased || walk.len() == 1 {
+                neighbors[table.sample(rng)]
+            } else {
+                let previous = walk[walk.len() - 2];
+                loop {
+                    let candidate = neighbors[table.sample(rng)];
+                    let bias = if candidate == previous {
+                        1.0 / p
+                    } else if self.neighbors[previous].binary_search(&candidate).is_ok() {
+                        1.0
+                    } else {
+                        1.0 / q
+                    };
+                    if rng.gen::<f64>() * max_bias < bias {
+                        break candidate;
+                    }
+                }
+            };
+            walk.push(next);
+        }
+        walk
+    }
+}
+
+/// Sample random walks of a graph, with the second order bias of node2vec.
+///
+/// Every walk starts at a node of `start_nodes` and moves to an out
+/// neighbor of the current node (any neighbor for an undirected graph) at
+/// every step, picked with a probability proportional to the total weight
+/// of the edges to it times a bias depending on the previous node of the
+/// walk [1]: `1 / p` to go back to the previous node, 1 to go to an out
+/// neighbor of the previous node and `1 / q` to go further away. A low `p`
+/// keeps the walks close to their start nodes, like a breadth first search,
+/// and a low `q` pushes them away, like a depth first search. With `p` and
+/// `q` set to 1 these are unbiased weighted random walks, as in DeepWalk.
+///
+/// Every neighbor is picked in constant time with an alias table, the bias
+/// is applied by rejection sampling so there's only one table per node.
+/// Edges with a weight that isn't positive are never followed. A walk
+/// stops early if it reaches a node without such an out edge.
+///
+/// The walks are sampled on multiple threads if there are at least
+/// `parallel_threshold` of them and the `parallel` feature is enabled. Every
+/// walk has its own random number generator, seeded from the one seeded
+/// with `seed`, so the walks only depend on `seed` and not on the number of
+/// threads.
+///
+/// Arguments:
+///
+/// * `graph` - The graph to walk
+/// * `weight_fn` - A callable that returns the weight of an edge
+/// * `start_nodes` - The nodes to start the walks from
+/// * `walk_length` - The number of nodes of every walk, including its start
+///   node
+/// * `num_walks` - The number of walks from every start node
+/// * `p` - The return parameter, it must be positive
+/// * `q` - The in-out parameter, it must be positive
+/// * `seed` - An optional seed to use for the random number generator
+/// * `parallel_threshold` - The number of walks to run in parallel from
+///
+/// Returns `num_walks` rounds of walks, every round has a walk from every
+/// start node, in the order of `start_nodes`.
+///
+/// # Panics
+///
+/// If `p` or `q` isn't positive or a start node isn't in the graph.
+///
+/// [1] A. Grover and J. Leskovec, "node2vec: Scalable Feature Learning for
+/// Networks", Proceedings of the 22nd ACM SIGKDD International Conference on
+/// Knowledge Discovery and Data Mining, 855-864, 2016.
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::sampling::random_walks;
+/// use retworkx_core::Result;
+///
+/// let g = petgraph::graph::UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 3)]);
+/// let starts = [petgraph::graph::NodeIndex::new(0)];
+/// let output: Result<_> =
+///     random_walks(&g, |_| Ok(1.0), &starts, 5, 2, 1.0, 0.5, Some(42), 300);
+/// let walks = output.unwrap();
+/// assert_eq!(walks.len(), 2);
+/// for walk in &walks {
+///     assert_eq!(walk.len(), 5);
+///     assert_eq!(walk[0].index(), 0);
+///     // Every step follows an edge of the path
+///     for step in walk.windows(2) {
+///         assert_eq!((step[0].index() as i64 - step[1].index() as i64).abs(), 1);
+///     }
+/// }
+/// ```
+#[allow(clippy::too_many_arguments)]
+pub fn random_walks<G, F, E>(
+    graph: G,
+    weight_fn: F,
+    start_nodes: &[G::NodeId],
+    walk_length: usize,
+    num_walks: usize,
+    p: f64,
+    q: f64,
+    seed: Option<u64>,
+    parallel_threshold: usize,
+) -> Result<Vec<Vec<G::NodeId>>, E>
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable + GraphProp,
+    G::NodeId: Send + Sync,
+    F: FnMut(G::EdgeRef) -> Result<f64, E>,
+{
+    assert!(p > 0.0 && q > 0.0, "p and q must be positive");
+    let (nodes, position, walk_graph) = WalkGraph::from_graph(graph, weight_fn)?;
+    let starts: Vec<usize> = start_nodes
+        .iter()
+        .map(|node| {
+            let start = position[graph.to_index(*node)];
+            assert!(start != usize::MAX, "start node not in the graph");
+            start
+        })
+        .collect();
+    let mut rng = seeded_rng(seed);
+    let mut jobs: Vec<(usize, u64)> = Vec::with_capacity(num_walks * starts.len());
+    for _ in 0..num_walks {
+        for start in &starts {
+            jobs.push((*start, rng.gen()));
+        }
+    }
+    Ok(map_items(
+        &jobs,
+        parallel_threshold,
+        |(start, walk_seed)| {
+            let mut walk_rng = Pcg64::seed_from_u64(*walk_seed);
+            walk_graph
+                .walk(*start, walk_length, p, q, &mut walk_rng)
+                .into_iter()
+                .map(|i| nodes[i])
+                .collect()
+        },
+    ))
+}
+
+/// Sample nodes of a graph uniformly at random, without replacement.
+///
+/// Every set of `num_nodes` nodes is as likely to be sampled. The nodes of
+/// the subgraph they induce have the same degree distribution on average
+/// as the nodes of the graph, but the subgraph has few of its edges as
+/// every edge is only kept if both its endpoints are sampled.
+///
+/// Arguments:
+///
+/// * `graph` - The graph to sample
+/// * `num_nodes` - The number of nodes to sample, at most the number of
+///   nodes of the graph
+/// * `seed` - An optional seed to use for the random number generator
+///
+/// Returns the sampled nodes in the order of `graph.node_identifiers()`.
+///
+/// # Panics
+///
+/// If `num_nodes` is larger than the number of nodes of the graph.
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::sampling::random_node_sample;
+///
+/// let g = petgraph::graph::UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 3), (3, 4)]);
+/// let nodes = random_node_sample(&g, 3, Some(42));
+/// assert_eq!(nodes.len(), 3);
+/// assert!(nodes.windows(2).all(|pair| pair[0] < pair[1]));
+/// ```
+pub fn random_node_sample<G>(graph: G, num_nodes: usize, seed: Option<u64>) -> Vec<G::NodeId>
+where
+    G: IntoNodeIdentifiers,
+{
+    let nodes: Vec<G::NodeId> = graph.node_identifiers().collect();
+    assert!(
+        num_nodes <= nodes.len(),
+        "num_nodes is larger than the number of nodes"
+    );
+    let mut rng = seeded_rng(seed);
+    let mut positions = rand::seq::index::sample(&mut rng, nodes.len(), num_nodes).into_vec();
+    positions.sort_unstable();
+    positions.into_iter().map(|i| nodes[i]).collect()
+}
+
+/// Sample edges of a graph uniformly at random, without replacement.
+///
+/// Every set of `num_edges` edges is as likely to be sampled. The subgraph
+/// of the sampled edges and their endpoints is biased towards the nodes of
+/// high degree, which are more likely to be an endpoint of a sampled edge.
+///
+/// Arguments:
+///
+/// * `graph` - The graph to sample
+/// * `num_edges` - The number of edges to sample, at most the number of
+///   edges of the graph
+/// * `seed` - An optional seed to use for the random number generator
+///
+/// Returns the sampled edges in the order of `graph.edge_references()`.
+///
+/// # Panics
+///
+/// If `num_edges` is larger than the number of edges of the graph.
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::sampling::random_edge_sample;
+/// use petgraph::visit::EdgeRef;
+///
+/// let g = petgraph::graph::UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 3), (3, 4)]);
+/// let edges = random_edge_sample(&g, 2, Some(42));
+/// assert_eq!(edges.len(), 2);
+/// assert!(edges[0].id() < edges[1].id());
+/// ```
+pub fn random_edge_sample<G>(graph: G, num_edges: usize, seed: Option<u64>) -> Vec<G::EdgeRef>
+where
+    G: IntoEdgeReferences,
+{
+    let edges: Vec<G::EdgeRef> = graph.edge_references().collect();
+    assert!(
+        num_edges <= edges.len(),
+        "num_edges is larger than the number of edges"
+    );
+    let mut rng = seeded_rng(seed);
+    let mut positions = rand::seq::index::sample(&mut rng, edges.len(), num_edges).into_vec();
+    positions.sort_unstable();
+    positions.into_iter().map(|i| edges[i]).collect()
+}
+
+/// Sample nodes of a graph by forest fire sampling.
+///
+/// A fire starts at a node picked uniformly at random and spreads to the
+/// neighbors of every burning node, ignoring the direction of the edges
+/// [1]: every burning node sets fire to `x` of its neighbors that aren't
+/// burnt yet, picked uniformly at random, where `x` is geometrically
+/// distributed with a mean of `burn_probability / (1 - burn_probability)`.
+/// When the fire dies out before `num_nodes` nodes are burnt a new one
+/// starts at a random node that isn't burnt yet. The subgraph induced by
+/// the burnt nodes keeps many properties of the graph, such as its degree
+/// distribution and its clustering, better than uniform sampling.
+///
+/// Arguments:
+///
+/// * `graph` - The graph to sample
+/// * `num_nodes` - The number of nodes to sample, at most the number of
+///   nodes of the graph
+/// * `burn_probability` - The forward burning probability, at least 0 and
+///   less than 1
+/// * `seed` - An optional seed to use for the random number generator
+///
+/// Returns the sampled nodes in the order they were burnt.
+///
+/// # Panics
+///
+/// If `num_nodes` is larger than the number of nodes of the graph or if
+/// `burn_probability` isn't in `[0, 1)`.
+///
+/// [1] J. Leskovec and C. Faloutsos, "Sampling from Large Graphs",
+/// Proceedings of the 12th ACM SIGKDD International Conference on Knowledge
+/// Discovery and Data Mining, 631-636, 2006.
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::sampling::forest_fire_sample;
+///
+/// let g = petgraph::graph::UnGraph::<(), ()>::from_edges(&[
+///     (0, 1), (1, 2), (2, 0), (3, 4), (4, 5), (5, 3),
+/// ]);
+/// let nodes = forest_fire_sample(&g, 4, 0.7, Some(42));
+/// assert_eq!(nodes.len(), 4);
+/// ```
+pub fn forest_fire_sample<G>(
+    graph: G,
+    num_nodes: usize,
+    burn_probability: f64,
+    seed: Option<u64>,
+) -> Vec<G::NodeId>
+where
+    G: IntoNeighborsDirected + IntoNodeIdentifiers + NodeIndexable,
+{
+    let mut nodes: Vec<G::NodeId> = graph.node_identifiers().collect();
+    assert!(
+        num_nodes <= nodes.len(),
+        "num_nodes is larger than the number of nodes"
+    );
+    assert!(
+        (0.0..1.0).contains(&burn_probability),
+        "burn_probability must be at least 0 and less than 1"
+    );
+    let mut rng = seeded_rng(seed);
+    // The fires start at the nodes in this random order, skipping the
+    // nodes that are burnt already
+    nodes.shuffle(&mut rng);
+    let mut next_start = 0;
+    let mut burnt = vec![false; graph.node_bound()];
+    let mut sample: Vec<G::NodeId> = Vec::with_capacity(num_nodes);
+    let mut burning: VecDeque<G::NodeId> = VecDeque::new();
+    while sample.len() < num_nodes {
+        let node = match burning.pop_front() {
+            Some(node) => node,
+            None => {
+                while burnt[graph.to_index(nodes[next_start])] {
+                    next_start += 1;
+                }
+                let start = nodes[next_start];
+                burnt[graph.to_index(start)] = true;
+                sample.push(start);
+                start
+            }
+        };
+        let mut count = 0;
+        while rng.gen::<f64>() < burn_probability {
+            count += 1;
+        }
+        let mut neighbors: Vec<G::NodeId> = graph
+            .neighbors_directed(node, Outgoing)
+            .chain(graph.neighbors_directed(node, Incoming))
+            .filter(|neighbor| !burnt[graph.to_index(*neighbor)])
+            .collect();
+        neighbors.sort_unstable_by_key(|neighbor| graph.to_index(*neighbor));
+        neighbors.dedup();
+        let count = count.min(neighbors.len()).min(num_nodes - sample.len());
+        for neighbor in neighbors.choose_multiple(&mut rng, count) {
+            burnt[graph.to_index(*neighbor)] = true;
+            sample.push(*neighbor);
+            burning.push_back(*neighbor);
+        }
+    }
+    sample
+}
@@ -0,0 +1,249 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use std::collections::BinaryHeap;
+
+use petgraph::visit::{EdgeRef, IntoEdges, IntoNodeIdentifiers, NodeIndexable};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+use crate::min_scored::MinScored;
+
+/// Find the length of the shortest path from `source` to every node with
+/// Dijkstra's algorithm, as a [`Vec`] indexed by node index.
+fn dijkstra_row<G, F, E>(graph: G, source: G::NodeId, edge_cost: &F) -> Result<Vec<Option<f64>>, E>
+where
+    G: IntoEdges + NodeIndexable,
+    F: Fn(G::EdgeRef) -> Result<f64, E>,
+{
+    let mut distance: Vec<Option<f64>> = vec![None; graph.node_bound()];
+    let mut visited = vec![false; graph.node_bound()];
+    let mut heap = BinaryHeap::new();
+    distance[graph.to_index(source)] = Some(0.0);
+    heap.push(MinScored(0.0, source));
+    while let Some(MinScored(score, node)) = heap.pop() {
+        let index = graph.to_index(node);
+        if visited[index] {
+            continue;
+        }
+        visited[index] = true;
+        for edge in graph.edges(node) {
+            let next = edge.target();
+            let next_index = graph.to_index(next);
+            if visited[next_index] {
+                continue;
+            }
+            let next_score = score + edge_cost(edge)?;
+            if distance[next_index].is_none() || Some(next_score) < distance[next_index] {
+                distance[next_index] = Some(next_score);
+                heap.push(MinScored(next_score, next));
+            }
+        }
+    }
+    Ok(distance)
+}
+
+/// Compute the rows of shortest path lengths from every node in `sources`,
+/// in parallel if the graph has at least `parallel_threshold` nodes and the
+/// `parallel` feature is enabled.
+#[allow(clippy::type_complexity)]
+fn dijkstra_rows<G, F, E>(
+    graph: G,
+    sources: &[G::NodeId],
+    edge_cost: &F,
+    parallel: bool,
+) -> Result<Vec<(G::NodeId, Vec<Option<f64>>)>, E>
+where
+    G: IntoEdges + NodeIndexable + Sync,
+    G::NodeId: Send + Sync,
+    F: Fn(G::EdgeRef) -> Result<f64, E> + Sync,
+    E: Send,
+{
+    let row = |source: &G::NodeId| -> Result<(G::NodeId, Vec<Option<f64>>), E> {
+        Ok((*source, dijkstra_row(graph, *source, edge_cost)?))
+    };
+    #[cfg(feature = "parallel")]
+    if parallel {
+        return sources.par_iter().map(row).collect();
+    }
+    #[cfg(not(feature = "parallel"))]
+    let _ = parallel;
+    sources.iter().map(row).collect()
+}
+
+/// Compute the shortest path lengths between all pairs of nodes, delivering
+/// them in chunks of source nodes.
+///
+/// The shortest paths are found with Dijkstra's algorithm from each node. The
+/// sources are processed `chunk_size` at a time, and each chunk of rows is
+/// passed to `callback` before the next chunk is computed, so at most
+/// `chunk_size` rows are in memory at once instead of the full `n x n`
+/// lengths. The rows of a chunk are computed in parallel if the graph has at
+/// least `parallel_threshold` nodes and the `parallel` feature is enabled;
+/// `callback` is always called on the calling thread.
+///
+/// Arguments:
+///
+/// * `graph` - The graph object to run the algorithm on
+/// * `edge_cost` - A callable that receives an edge and returns its weight,
+///   which must be non-negative. It is called from multiple threads.
+/// * `chunk_size` - The number of source nodes in each chunk, it must be
+///   larger than 0.
+/// * `parallel_threshold` - The number of nodes to compute the rows of a
+///   chunk in parallel at, if the number of nodes in `graph` is less than
+///   this value they are computed in a single thread.
+/// * `callback` - A callable that receives each chunk as a [`Vec`] of
+///   source nodes and their row, a [`Vec`] indexed by node index with the
+///   length of the shortest path from the source to each node ([`None`] if
+///   it's not reachable). The sources are in the order of
+///   `node_identifiers()`.
+///
+/// If `edge_cost` or `callback` returns an error no more chunks are
+/// computed and the error is returned.
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::petgraph::graph::NodeIndex;
+/// use retworkx_core::shortest_path::all_pairs_dijkstra_path_lengths_chunked;
+/// use retworkx_core::Result;
+///
+/// let g = petgraph::graph::DiGraph::<(), f64>::from_edges(&[
+///     (0, 1, 1.0), (1, 2, 2.0), (0, 2, 4.0)
+/// ]);
+/// let mut total = 0.0;
+/// let mut chunks = 0;
+/// let res: Result<()> = all_pairs_dijkstra_path_lengths_chunked(
+///     &g,
+///     |e| Ok(*e.weight()),
+///     2,
+///     50,
+///     |chunk: Vec<(NodeIndex, Vec<Option<f64>>)>| {
+///         chunks += 1;
+///         total += chunk.iter().flat_map(|(_, row)| row.iter().flatten()).sum::<f64>();
+///         Ok(())
+///     },
+/// );
+/// res.unwrap();
+/// assert_eq!(chunks, 2);
+/// // 0 -> 1, 0 -> 1 -> 2 and 1 -> 2
+/// assert_eq!(total, 1.0 + 3.0 + 2.0);
+/// ```
+pub fn all_pairs_dijkstra_path_lengths_chunked<G, F, E, C>(
+    graph: G,
+    edge_cost: F,
+    chunk_size: usize,
+    parallel_threshold: usize,
+    mut callback: C,
+) -> Result<(), E>
+where
+    G: IntoEdges + IntoNodeIdentifiers + NodeIndexable + Sync,
+    G::NodeId: Send + Sync,
+    F: Fn(G::EdgeRef) -> Result<f64, E> + Sync,
+    E: Send,
+    C: FnMut(Vec<(G::NodeId, Vec<Option<f64>>)>) -> Result<(), E>,
+{
+    assert!(chunk_size > 0, "chunk_size must be larger than 0");
+    let nodes: Vec<G::NodeId> = graph.node_identifiers().collect();
+    let parallel = nodes.len() >= parallel_threshold;
+    for sources in nodes.chunks(chunk_size) {
+        callback(dijkstra_rows(graph, sources, &edge_cost, parallel)?)?;
+    }
+    Ok(())
+}
+
+/// Compute the shortest path lengths between all pairs of nodes into a
+/// preallocated matrix.
+///
+/// The shortest paths are found with Dijkstra's algorithm from each node and
+/// written to `matrix` without building any other structure for all the
+/// pairs. The rows and columns of the matrix are the nodes in the order of
+/// `node_identifiers()`, so for a graph with `n` nodes `matrix` is an `n x n`
+/// matrix in row major order: the length of the path from the `i`-th node to
+/// the `j`-th node is written at `i * n + j`. Nodes which aren't reachable
+/// are at an infinite distance. The rows are computed in parallel if the
+/// graph has at least `parallel_threshold` nodes and the `parallel` feature
+/// is enabled.
+///
+/// Arguments:
+///
+/// * `graph` - The graph object to run the algorithm on
+/// * `edge_cost` - A callable that receives an edge and returns its weight,
+///   which must be non-negative. It is called from multiple threads.
+/// * `matrix` - The matrix to write the lengths to, it must have a length of
+///   `n * n`.
+/// * `parallel_threshold` - The number of nodes to compute the rows in
+///   parallel at, if the number of nodes in `graph` is less than this value
+///   they are computed in a single thread.
+///
+/// If `edge_cost` returns an error it is returned and the content of
+/// `matrix` is unspecified.
+///
+/// # Panics
+///
+/// If the length of `matrix` isn't the square of the number of nodes.
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::shortest_path::all_pairs_dijkstra_path_lengths_into;
+/// use retworkx_core::Result;
+///
+/// let g = petgraph::graph::DiGraph::<(), f64>::from_edges(&[(0, 1, 1.0), (1, 2, 2.0)]);
+/// let mut matrix = vec![0.0; 9];
+/// let res: Result<()> =
+///     all_pairs_dijkstra_path_lengths_into(&g, |e| Ok(*e.weight()), &mut matrix, 50);
+/// res.unwrap();
+/// let inf = f64::INFINITY;
+/// assert_eq!(matrix, vec![0.0, 1.0, 3.0, inf, 0.0, 2.0, inf, inf, 0.0]);
+/// ```
+pub fn all_pairs_dijkstra_path_lengths_into<G, F, E>(
+    graph: G,
+    edge_cost: F,
+    matrix: &mut [f64],
+    parallel_threshold: usize,
+) -> Result<(), E>
+where
+    G: IntoEdges + IntoNodeIdentifiers + NodeIndexable + Sync,
+    G::NodeId: Send + Sync,
+    F: Fn(G::EdgeRef) -> Result<f64, E> + Sync,
+    E: Send,
+{
+    let nodes: Vec<G::NodeId> = graph.node_identifiers().collect();
+    let n = nodes.len();
+    assert_eq!(
+        matrix.len(),
+        n * n,
+        "matrix must have a length of the number of nodes squared"
+    );
+    if n == 0 {
+        return Ok(());
+    }
+    let columns: Vec<usize> = nodes.iter().map(|node| graph.to_index(*node)).collect();
+    let fill = |(source, out): (&G::NodeId, &mut [f64])| -> Result<(), E> {
+        let row = dijkstra_row(graph, *source, &edge_cost)?;
+        for (value, column) in out.iter_mut().zip(&columns) {
+            *value = row[*column].unwrap_or(f64::INFINITY);
+        }
+        Ok(())
+    };
+    #[cfg(feature = "parallel")]
+    if n >= parallel_threshold {
+        return nodes
+            .par_iter()
+            .zip(matrix.par_chunks_mut(n))
+            .try_for_each(fill);
+    }
+    #[cfg(not(feature = "parallel"))]
+    let _ = parallel_threshold;
+    nodes.iter().zip(matrix.chunks_mut(n)).try_for_each(fill)
+}
@@ -104,7 +104,7 @@ where
     G::NodeId: Eq + Hash,
     F: FnMut(G::EdgeRef) -> Result<K, E>,
     H: FnMut(G::NodeId) -> Result<K, E>,
-    K: Measure + Copy,
+    K: Measure,
 {
     let mut visited = graph.visit_map();
     let mut visit_next = BinaryHeap::new();
@@ -120,7 +120,7 @@ where
         let result = is_goal(node)?;
         if result {
             let path = path_tracker.reconstruct_path_to(node);
-            let cost = scores[&node];
+            let cost = scores[&node].clone();
             return Ok(Some((cost, path)));
         }
 
@@ -132,7 +132,7 @@ where
 
         // This lookup can be unwrapped without fear of panic since the node was necessarily scored
         // before adding him to `visit_next`.
-        let node_score = scores[&node];
+        let node_score = scores[&node].clone();
 
         for edge in graph.edges(node) {
             let next = edge.target();
@@ -141,20 +141,20 @@ where
             }
 
             let cost = edge_cost(edge)?;
-            let mut next_score = node_score + cost;
+            let mut next_score = node_score.clone() + cost;
 
             match scores.entry(next) {
                 Occupied(ent) => {
-                    let old_score = *ent.get();
+                    let old_score = ent.get().clone();
                     if next_score < old_score {
-                        *ent.into_mut() = next_score;
+                        *ent.into_mut() = next_score.clone();
                         path_tracker.set_predecessor(next, node);
                     } else {
                         next_score = old_score;
                     }
                 }
                 Vacant(ent) => {
-                    ent.insert(next_score);
+                    ent.insert(next_score.clone());
                     path_tracker.set_predecessor(next, node);
                 }
             }
@@ -168,6 +168,111 @@ where
     Ok(None)
 }
 
+/// A* shortest path algorithm returning the edges of the path.
+///
+/// This is the same as [`astar`] but the path found is returned as the
+/// sequence of edge ids traversed from `start` to the goal instead of the
+/// sequence of nodes. This is unambiguous on multigraphs, where several
+/// parallel edges may connect the same pair of nodes: every parallel edge is
+/// considered and the cheapest one is used.
+///
+/// # Example
+/// ```
+/// use retworkx_core::petgraph::graph::{EdgeIndex, NodeIndex};
+/// use retworkx_core::petgraph::Graph;
+/// use retworkx_core::shortest_path::astar_edge_path;
+/// use retworkx_core::Result;
+///
+/// let mut g = Graph::new();
+/// let a = g.add_node(());
+/// let b = g.add_node(());
+/// let c = g.add_node(());
+/// let ab = g.add_edge(a, b, 3);
+/// let ab_cheap = g.add_edge(a, b, 1);
+/// let bc = g.add_edge(b, c, 1);
+///
+/// let res: Result<Option<(u64, Vec<EdgeIndex>)>> = astar_edge_path(
+///     &g, a, |finish| Ok(finish == c), |e| Ok(*e.weight()), |_| Ok(0)
+/// );
+/// assert_eq!(res.unwrap(), Some((2, vec![ab_cheap, bc])));
+/// ```
+///
+/// Returns the total cost + the path of subsequent `EdgeId` from start to
+/// finish, if one was found.
+pub fn astar_edge_path<G, F, H, K, IsGoal, E>(
+    graph: G,
+    start: G::NodeId,
+    mut is_goal: IsGoal,
+    mut edge_cost: F,
+    mut estimate_cost: H,
+) -> Result<AstarOutput<K, G::EdgeId>, E>
+where
+    G: IntoEdges + Visitable,
+    IsGoal: FnMut(G::NodeId) -> Result<bool, E>,
+    G::NodeId: Eq + Hash,
+    F: FnMut(G::EdgeRef) -> Result<K, E>,
+    H: FnMut(G::NodeId) -> Result<K, E>,
+    K: Measure,
+{
+    let mut visited = graph.visit_map();
+    let mut visit_next = BinaryHeap::new();
+    let mut scores = HashMap::new();
+    let mut came_from: HashMap<G::NodeId, (G::NodeId, G::EdgeId)> = HashMap::new();
+
+    let zero_score = K::default();
+    scores.insert(start, zero_score);
+    let estimate = estimate_cost(start)?;
+    visit_next.push(MinScored(estimate, start));
+
+    while let Some(MinScored(_, node)) = visit_next.pop() {
+        if is_goal(node)? {
+            let mut path = Vec::new();
+            let mut current = node;
+            while let Some(&(previous, edge)) = came_from.get(&current) {
+                path.push(edge);
+                current = previous;
+            }
+            path.reverse();
+            return Ok(Some((scores[&node].clone(), path)));
+        }
+        if !visited.visit(node) {
+            continue;
+        }
+        let node_score = scores[&node].clone();
+
+        for edge in graph.edges(node) {
+            let next = edge.target();
+            if visited.is_visited(&next) {
+                continue;
+            }
+
+            let cost = edge_cost(edge)?;
+            let mut next_score = node_score.clone() + cost;
+
+            match scores.entry(next) {
+                Occupied(ent) => {
+                    let old_score = ent.get().clone();
+                    if next_score < old_score {
+                        *ent.into_mut() = next_score.clone();
+                        came_from.insert(next, (node, edge.id()));
+                    } else {
+                        next_score = old_score;
+                    }
+                }
+                Vacant(ent) => {
+                    ent.insert(next_score.clone());
+                    came_from.insert(next, (node, edge.id()));
+                }
+            }
+
+            let estimate = estimate_cost(next)?;
+            visit_next.push(MinScored(next_score + estimate, next));
+        }
+    }
+
+    Ok(None)
+}
+
 struct PathTracker<G>
 where
     G: GraphBase,
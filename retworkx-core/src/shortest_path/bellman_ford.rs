@@ -0,0 +1,224 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use std::collections::VecDeque;
+use std::hash::Hash;
+
+use hashbrown::HashMap;
+
+use petgraph::algo::Measure;
+use petgraph::visit::{EdgeRef, IntoEdges, NodeIndexable};
+
+use crate::dictmap::*;
+use crate::distancemap::DistanceMap;
+
+use super::dijkstra::build_edge_paths;
+
+/// The result of the relaxation phase: the distance map, the predecessor
+/// node and edge of every reached node and the nodes in discovery order.
+/// `None` if a negative cycle is reachable.
+type Relaxation<S, N, Ed> = Option<(S, HashMap<N, (N, Ed)>, Vec<N>)>;
+
+fn relax<G, F, K, E, S>(
+    graph: G,
+    start: G::NodeId,
+    edge_cost: &mut F,
+) -> Result<Relaxation<S, G::NodeId, G::EdgeId>, E>
+where
+    G: IntoEdges + NodeIndexable,
+    G::NodeId: Eq + Hash,
+    F: FnMut(G::EdgeRef) -> Result<K, E>,
+    K: Measure,
+    S: DistanceMap<G::NodeId, K>,
+{
+    let bound = graph.node_bound();
+    let mut scores: S = S::build(bound);
+    let mut predecessors: HashMap<G::NodeId, (G::NodeId, G::EdgeId)> = HashMap::new();
+    // number of edges in the current best path to each node, a path with
+    // as many edges as nodes in the graph has to contain a cycle
+    let mut lengths: Vec<usize> = vec![0; bound];
+    let mut in_queue: Vec<bool> = vec![false; bound];
+    let mut discovered: Vec<G::NodeId> = vec![start];
+    let mut queue: VecDeque<G::NodeId> = VecDeque::new();
+
+    scores.put_item(start, K::default());
+    queue.push_back(start);
+    in_queue[graph.to_index(start)] = true;
+    while let Some(node) = queue.pop_front() {
+        let node_index = graph.to_index(node);
+        in_queue[node_index] = false;
+        let node_score = scores.get_item(node).unwrap().clone();
+        for edge in graph.edges(node) {
+            let next = edge.target();
+            let next_score = node_score.clone() + edge_cost(edge)?;
+            let improved = match scores.get_item(next) {
+                Some(current_score) => next_score < *current_score,
+                None => {
+                    discovered.push(next);
+                    true
+                }
+            };
+            if improved {
+                let next_index = graph.to_index(next);
+                lengths[next_index] = lengths[node_index] + 1;
+                if lengths[next_index] >= bound {
+                    return Ok(None);
+                }
+                scores.put_item(next, next_score);
+                predecessors.insert(next, (node, edge.id()));
+                if !in_queue[next_index] {
+                    in_queue[next_index] = true;
+                    queue.push_back(next);
+                }
+            }
+        }
+    }
+    Ok(Some((scores, predecessors, discovered)))
+}
+
+/// Bellman-Ford shortest path algorithm.
+///
+/// Compute the length of the shortest path from `start` to every reachable
+/// node. Unlike [`dijkstra`](crate::shortest_path::dijkstra) edge costs may be
+/// negative.
+///
+/// The graph should implement [`IntoEdges`] and [`NodeIndexable`]. The
+/// function `edge_cost` should return the cost for a particular edge, which
+/// is used to compute path costs. This implementation uses a work queue so
+/// that only the edges leaving a node whose distance changed are
+/// re-examined, `edge_cost` may be called more than once for the same edge.
+/// Every parallel edge of a multigraph is considered.
+///
+/// If `path` is not [`None`], then the algorithm will mutate the input
+/// [`DictMap`] to insert an entry where the index is the dest node index
+/// the value is a Vec of node indices of the path starting with `start` and
+/// ending at the index.
+///
+/// Returns a [`DistanceMap`] that maps `NodeId` to path cost, or [`None`] if
+/// a negative cycle is reachable from `start` (in which case `path` is left
+/// untouched).
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph::Graph;
+/// use retworkx_core::petgraph::prelude::*;
+/// use retworkx_core::dictmap::*;
+/// use retworkx_core::shortest_path::bellman_ford;
+/// use retworkx_core::Result;
+///
+/// let mut graph: Graph<(), i64, Directed> = Graph::new();
+/// let a = graph.add_node(());
+/// let b = graph.add_node(());
+/// let c = graph.add_node(());
+/// graph.extend_with_edges(&[(a, b, 4), (a, c, 2), (c, b, -1)]);
+///
+/// let mut paths = DictMap::new();
+/// let res: Result<Option<DictMap<NodeIndex, i64>>> = bellman_ford(
+///     &graph, a, |e| Ok(*e.weight()), Some(&mut paths)
+/// );
+/// let scores = res.unwrap().unwrap();
+/// assert_eq!(scores[&b], 1);
+/// assert_eq!(paths[&b], vec![a, c, b]);
+///
+/// // Adding a negative cycle means there is no shortest path anymore
+/// graph.add_edge(b, c, -1);
+/// let res: Result<Option<DictMap<NodeIndex, i64>>> = bellman_ford(
+///     &graph, a, |e| Ok(*e.weight()), None
+/// );
+/// assert!(res.unwrap().is_none());
+/// ```
+pub fn bellman_ford<G, F, K, E, S>(
+    graph: G,
+    start: G::NodeId,
+    mut edge_cost: F,
+    path: Option<&mut DictMap<G::NodeId, Vec<G::NodeId>>>,
+) -> Result<Option<S>, E>
+where
+    G: IntoEdges + NodeIndexable,
+    G::NodeId: Eq + Hash,
+    F: FnMut(G::EdgeRef) -> Result<K, E>,
+    K: Measure,
+    S: DistanceMap<G::NodeId, K>,
+{
+    let (scores, predecessors, discovered) = match relax(graph, start, &mut edge_cost)? {
+        Some(res) => res,
+        None => return Ok(None),
+    };
+    if let Some(path) = path {
+        for node in discovered {
+            let mut node_path = vec![node];
+            let mut current = node;
+            while let Some(&(previous, _)) = predecessors.get(&current) {
+                node_path.push(previous);
+                current = previous;
+            }
+            node_path.reverse();
+            path.insert(node, node_path);
+        }
+    }
+    Ok(Some(scores))
+}
+
+/// Bellman-Ford shortest path algorithm returning the edges of each path.
+///
+/// This is the same as [`bellman_ford`] but instead of recording the node
+/// sequence of each shortest path it records the ids of the edges used, which
+/// is unambiguous on multigraphs.
+///
+/// The input [`DictMap`] `edge_paths` is mutated to insert an entry for every
+/// reachable node, where the key is the node and the value is a [`Vec`] of the
+/// edge ids making up the path from `start` to that node.
+///
+/// Returns a [`DistanceMap`] that maps `NodeId` to path cost, or [`None`] if
+/// a negative cycle is reachable from `start`.
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph::Graph;
+/// use retworkx_core::petgraph::prelude::*;
+/// use retworkx_core::dictmap::*;
+/// use retworkx_core::shortest_path::bellman_ford_edge_paths;
+/// use retworkx_core::Result;
+///
+/// let mut graph: Graph<(), i64, Directed> = Graph::new();
+/// let a = graph.add_node(());
+/// let b = graph.add_node(());
+/// graph.add_edge(a, b, 3);
+/// let cheap = graph.add_edge(a, b, -2);
+///
+/// let mut edge_paths = DictMap::new();
+/// let res: Result<Option<Vec<Option<i64>>>> = bellman_ford_edge_paths(
+///     &graph, a, |e| Ok(*e.weight()), &mut edge_paths
+/// );
+/// assert_eq!(res.unwrap().unwrap()[b.index()], Some(-2));
+/// assert_eq!(edge_paths[&b], vec![cheap]);
+/// ```
+pub fn bellman_ford_edge_paths<G, F, K, E, S>(
+    graph: G,
+    start: G::NodeId,
+    mut edge_cost: F,
+    edge_paths: &mut DictMap<G::NodeId, Vec<G::EdgeId>>,
+) -> Result<Option<S>, E>
+where
+    G: IntoEdges + NodeIndexable,
+    G::NodeId: Eq + Hash,
+    F: FnMut(G::EdgeRef) -> Result<K, E>,
+    K: Measure,
+    S: DistanceMap<G::NodeId, K>,
+{
+    let (scores, predecessors, discovered) = match relax(graph, start, &mut edge_cost)? {
+        Some(res) => res,
+        None => return Ok(None),
+    };
+    build_edge_paths(&predecessors, discovered, edge_paths);
+    Ok(Some(scores))
+}
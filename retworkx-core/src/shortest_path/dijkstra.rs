@@ -20,6 +20,8 @@
 use std::collections::BinaryHeap;
 use std::hash::Hash;
 
+use hashbrown::HashMap;
+
 use petgraph::algo::Measure;
 use petgraph::visit::{EdgeRef, IntoEdges, NodeIndexable, VisitMap, Visitable};
 
@@ -44,6 +46,10 @@ use crate::min_scored::MinScored;
 /// the value is a Vec of node indices of the path starting with `start` and
 /// ending at the index.
 ///
+/// The cost type only needs to implement [`Measure`], it doesn't need to be
+/// [`Copy`]. So an arbitrary precision or rational number type can be used
+/// to compute the path costs exactly.
+///
 /// Returns a [`DistanceMap`] that maps `NodeId` to path cost.
 /// # Example
 /// ```rust
@@ -97,6 +103,25 @@ use crate::min_scored::MinScored;
 /// assert_eq!(res.unwrap(), expected_res);
 /// // z is not inside res because there is not path from b to z.
 /// ```
+///
+/// With arbitrary precision edge costs:
+/// ```rust
+/// use num_bigint::BigUint;
+/// use retworkx_core::petgraph;
+/// use retworkx_core::petgraph::graph::NodeIndex;
+/// use retworkx_core::dictmap::DictMap;
+/// use retworkx_core::shortest_path::dijkstra;
+/// use retworkx_core::Result;
+///
+/// let graph = petgraph::graph::DiGraph::<(), u32>::from_edges(&[
+///     (0, 1, 64), (1, 2, 64), (0, 2, 127)
+/// ]);
+/// let res: Result<DictMap<NodeIndex, BigUint>> = dijkstra(
+///     &graph, NodeIndex::new(0), None, |e| Ok(BigUint::from(1u8) << *e.weight()), None
+/// );
+/// // 2^64 + 2^64 == 2^65 is shorter than 2^127
+/// assert_eq!(res.unwrap()[&NodeIndex::new(2)], BigUint::from(1u8) << 65);
+/// ```
 pub fn dijkstra<G, F, K, E, S>(
     graph: G,
     start: G::NodeId,
@@ -108,14 +133,14 @@ where
     G: IntoEdges + Visitable + NodeIndexable,
     G::NodeId: Eq + Hash,
     F: FnMut(G::EdgeRef) -> Result<K, E>,
-    K: Measure + Copy,
+    K: Measure,
     S: DistanceMap<G::NodeId, K>,
 {
     let mut visited = graph.visit_map();
     let mut scores: S = S::build(graph.node_bound());
     let mut visit_next = BinaryHeap::new();
     let zero_score = K::default();
-    scores.put_item(start, zero_score);
+    scores.put_item(start, zero_score.clone());
     visit_next.push(MinScored(zero_score, start));
     if path.is_some() {
         path.as_mut().unwrap().insert(start, vec![start]);
@@ -133,11 +158,11 @@ where
                 continue;
             }
             let cost = edge_cost(edge)?;
-            let next_score = node_score + cost;
+            let next_score = node_score.clone() + cost;
             match scores.get_item(next) {
                 Some(current_score) => {
                     if next_score < *current_score {
-                        scores.put_item(next, next_score);
+                        scores.put_item(next, next_score.clone());
                         visit_next.push(MinScored(next_score, next));
                         if path.is_some() {
                             let mut node_path = path.as_mut().unwrap().get(&node).unwrap().clone();
@@ -149,7 +174,7 @@ where
                     }
                 }
                 None => {
-                    scores.put_item(next, next_score);
+                    scores.put_item(next, next_score.clone());
                     visit_next.push(MinScored(next_score, next));
                     if path.is_some() {
                         let mut node_path = path.as_mut().unwrap().get(&node).unwrap().clone();
@@ -164,3 +189,120 @@ where
 
     Ok(scores)
 }
+
+/// Dijkstra's shortest path algorithm returning the edges of each path.
+///
+/// This is the same as [`dijkstra`] but instead of recording the node
+/// sequence of each shortest path it records the ids of the edges used,
+/// which is unambiguous on multigraphs. All the parallel edges between a pair
+/// of nodes are considered, so the path always uses the cheapest one.
+///
+/// If `goal` is not [`None`], then the algorithm terminates once the `goal`
+/// node's cost is calculated.
+///
+/// The input [`DictMap`] `edge_paths` is mutated to insert an entry for every
+/// reachable node, where the key is the node and the value is a [`Vec`] of the
+/// edge ids making up the path from `start` to that node. The entry for
+/// `start` is an empty [`Vec`].
+///
+/// Returns a [`DistanceMap`] that maps `NodeId` to path cost.
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph::Graph;
+/// use retworkx_core::petgraph::prelude::*;
+/// use retworkx_core::dictmap::*;
+/// use retworkx_core::shortest_path::dijkstra_edge_paths;
+/// use retworkx_core::Result;
+///
+/// let mut graph: Graph<(), u64, Directed> = Graph::new();
+/// let a = graph.add_node(());
+/// let b = graph.add_node(());
+/// let c = graph.add_node(());
+/// let ab_slow = graph.add_edge(a, b, 5);
+/// let ab_fast = graph.add_edge(a, b, 1);
+/// let bc = graph.add_edge(b, c, 1);
+///
+/// let mut edge_paths = DictMap::new();
+/// let res: Result<DictMap<NodeIndex, u64>> = dijkstra_edge_paths(
+///     &graph, a, None, |e| Ok(*e.weight()), &mut edge_paths
+/// );
+/// assert_eq!(res.unwrap()[&c], 2);
+/// assert_eq!(edge_paths[&c], vec![ab_fast, bc]);
+/// assert!(!edge_paths[&b].contains(&ab_slow));
+/// ```
+pub fn dijkstra_edge_paths<G, F, K, E, S>(
+    graph: G,
+    start: G::NodeId,
+    goal: Option<G::NodeId>,
+    mut edge_cost: F,
+    edge_paths: &mut DictMap<G::NodeId, Vec<G::EdgeId>>,
+) -> Result<S, E>
+where
+    G: IntoEdges + Visitable + NodeIndexable,
+    G::NodeId: Eq + Hash,
+    F: FnMut(G::EdgeRef) -> Result<K, E>,
+    K: Measure,
+    S: DistanceMap<G::NodeId, K>,
+{
+    let mut visited = graph.visit_map();
+    let mut scores: S = S::build(graph.node_bound());
+    let mut visit_next = BinaryHeap::new();
+    let mut predecessors: HashMap<G::NodeId, (G::NodeId, G::EdgeId)> = HashMap::new();
+    let mut discovered: Vec<G::NodeId> = vec![start];
+    let zero_score = K::default();
+    scores.put_item(start, zero_score.clone());
+    visit_next.push(MinScored(zero_score, start));
+    while let Some(MinScored(node_score, node)) = visit_next.pop() {
+        if visited.is_visited(&node) {
+            continue;
+        }
+        if goal.as_ref() == Some(&node) {
+            break;
+        }
+        for edge in graph.edges(node) {
+            let next = edge.target();
+            if visited.is_visited(&next) {
+                continue;
+            }
+            let cost = edge_cost(edge)?;
+            let next_score = node_score.clone() + cost;
+            let improved = match scores.get_item(next) {
+                Some(current_score) => next_score < *current_score,
+                None => {
+                    discovered.push(next);
+                    true
+                }
+            };
+            if improved {
+                scores.put_item(next, next_score.clone());
+                visit_next.push(MinScored(next_score, next));
+                predecessors.insert(next, (node, edge.id()));
+            }
+        }
+        visited.visit(node);
+    }
+    build_edge_paths(&predecessors, discovered, edge_paths);
+    Ok(scores)
+}
+
+/// Build the edge path of every node in `nodes` from a map of the
+/// predecessor node and edge used to reach each node.
+pub(crate) fn build_edge_paths<N, Ed>(
+    predecessors: &HashMap<N, (N, Ed)>,
+    nodes: Vec<N>,
+    edge_paths: &mut DictMap<N, Vec<Ed>>,
+) where
+    N: Copy + Eq + Hash,
+    Ed: Copy,
+{
+    for node in nodes {
+        let mut path = Vec::new();
+        let mut current = node;
+        while let Some(&(previous, edge)) = predecessors.get(&current) {
+            path.push(edge);
+            current = previous;
+        }
+        path.reverse();
+        edge_paths.insert(node, path);
+    }
+}
@@ -0,0 +1,247 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use petgraph::visit::{IntoNeighbors, IntoNodeIdentifiers, NodeIndexable};
+
+/// The SplitMix64 finalizer, used to hash node indices into uniformly
+/// distributed 64 bit values.
+fn mix(mut x: u64) -> u64 {
+    x = x.wrapping_add(0x9e3779b97f4a7c15);
+    x = (x ^ (x >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+    x = (x ^ (x >> 27)).wrapping_mul(0x94d049bb133111eb);
+    x ^ (x >> 31)
+}
+
+/// Estimate the cardinality of the set counted by the HyperLogLog registers.
+fn estimate(registers: &[u8]) -> f64 {
+    let m = registers.len() as f64;
+    let alpha = match registers.len() {
+        16 => 0.673,
+        32 => 0.697,
+        64 => 0.709,
+        _ => 0.7213 / (1.0 + 1.079 / m),
+    };
+    let mut sum = 0.0;
+    let mut zeros = 0;
+    for register in registers {
+        sum += 2f64.powi(-(*register as i32));
+        if *register == 0 {
+            zeros += 1;
+        }
+    }
+    let raw = alpha * m * m / sum;
+    // use linear counting for small cardinalities where HyperLogLog is biased
+    if raw <= 2.5 * m && zeros > 0 {
+        m * (m / zeros as f64).ln()
+    } else {
+        raw
+    }
+}
+
+/// Estimate the neighborhood function of a graph with HyperANF.
+///
+/// The neighborhood function `N(t)` of a graph is the number of ordered
+/// pairs of nodes `(x, y)` such that `y` is reachable from `x` in at most `t`
+/// steps (including the pairs `(x, x)`). Computing it exactly requires a
+/// breadth first search from every node, which is impractical for large
+/// graphs. HyperANF [1] instead keeps a HyperLogLog counter [2] of the nodes
+/// within distance `t` of every node, so each iteration is a single pass
+/// over the edges of the graph that takes the union of the counters of the
+/// neighbors of every node. For directed graphs the edges are only followed
+/// in their direction.
+///
+/// Every counter uses `2^precision` registers of one byte and the relative
+/// standard error of the estimate of each counter is about
+/// `1.04 / sqrt(2^precision)`.
+///
+/// Arguments:
+///
+/// * `graph` - The graph to estimate the neighborhood function of
+/// * `precision` - The base 2 logarithm of the number of registers of every
+///   counter, it must be between 4 and 16.
+/// * `seed` - An optional seed for the hash function used by the counters
+/// * `max_distance` - An optional maximum value of `t` to compute, otherwise
+///   the iteration stops once none of the counters change.
+///
+/// Returns a [`Vec`] with the estimated `N(t)` for `t` from 0 up to the
+/// largest distance found (or `max_distance`).
+///
+/// # Panics
+///
+/// If `precision` is not between 4 and 16.
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::shortest_path::approximate_neighborhood_function;
+///
+/// let g = petgraph::graph::UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 3)]);
+/// let neighborhood_function = approximate_neighborhood_function(&g, 10, Some(42), None);
+/// // A path with 4 nodes has a diameter of 3
+/// assert_eq!(neighborhood_function.len(), 4);
+/// let exact = [4., 10., 14., 16.];
+/// for (estimate, exact) in neighborhood_function.iter().zip(exact) {
+///     assert!((estimate - exact).abs() < 0.5);
+/// }
+/// ```
+///
+/// [1] Boldi, Paolo, Marco Rosa, and Sebastiano Vigna. "HyperANF:
+///   Approximating the neighbourhood function of very large graphs on a
+///   budget." Proceedings of the 20th international conference on World
+///   Wide Web (2011): 625-634.
+/// [2] Flajolet, Philippe, Éric Fusy, Olivier Gandouet, and Frédéric Meunier.
+///   "Hyperloglog: the analysis of a near-optimal cardinality estimation
+///   algorithm." Discrete Mathematics and Theoretical Computer Science
+///   (2007): 137-156.
+pub fn approximate_neighborhood_function<G>(
+    graph: G,
+    precision: u8,
+    seed: Option<u64>,
+    max_distance: Option<usize>,
+) -> Vec<f64>
+where
+    G: IntoNeighbors + IntoNodeIdentifiers + NodeIndexable,
+{
+    assert!(
+        (4..=16).contains(&precision),
+        "precision must be between 4 and 16"
+    );
+    let m = 1 << precision;
+    let salt = mix(seed.unwrap_or_else(rand::random));
+    let nodes: Vec<G::NodeId> = graph.node_identifiers().collect();
+    let mut compact = vec![0; graph.node_bound()];
+    for (index, node) in nodes.iter().enumerate() {
+        compact[graph.to_index(*node)] = index;
+    }
+    let neighbors: Vec<Vec<usize>> = nodes
+        .iter()
+        .map(|node| {
+            graph
+                .neighbors(*node)
+                .map(|n| compact[graph.to_index(n)])
+                .collect()
+        })
+        .collect();
+
+    // the registers of the counter of every node are stored contiguously
+    let mut counters: Vec<u8> = vec![0; nodes.len() * m];
+    for (index, node) in nodes.iter().enumerate() {
+        let hash = mix(graph.to_index(*node) as u64 ^ salt);
+        let register = (hash >> (64 - precision)) as usize;
+        let rank = ((hash << precision).leading_zeros() + 1).min(64 - precision as u32 + 1);
+        counters[index * m + register] = rank as u8;
+    }
+    let total = |counters: &[u8]| -> f64 { counters.chunks(m).map(estimate).sum() };
+
+    let mut out = vec![total(&counters)];
+    loop {
+        if let Some(max_distance) = max_distance {
+            if out.len() > max_distance {
+                break;
+            }
+        }
+        let mut next = counters.clone();
+        let mut changed = false;
+        for (index, node_neighbors) in neighbors.iter().enumerate() {
+            let counter = &mut next[index * m..(index + 1) * m];
+            for neighbor in node_neighbors {
+                let other = &counters[neighbor * m..(neighbor + 1) * m];
+                for (register, value) in counter.iter_mut().zip(other) {
+                    if *value > *register {
+                        *register = *value;
+                        changed = true;
+                    }
+                }
+            }
+        }
+        if !changed {
+            break;
+        }
+        counters = next;
+        out.push(total(&counters));
+    }
+    out
+}
+
+/// Compute the effective diameter of a graph from its neighborhood function.
+///
+/// The effective diameter is the smallest distance `t` within which a
+/// `quantile` fraction of all the reachable pairs of nodes are, linearly
+/// interpolated between integer distances. It's less sensitive to outliers
+/// than the diameter, the usual choice for `quantile` is `0.9`.
+///
+/// Arguments:
+///
+/// * `neighborhood_function` - The (estimated) neighborhood function, for
+///   example from [`approximate_neighborhood_function`]
+/// * `quantile` - The fraction of reachable pairs to cover, between 0 and 1
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::shortest_path::effective_diameter;
+///
+/// // The neighborhood function of a path graph with 4 nodes
+/// let neighborhood_function = [4., 10., 14., 16.];
+/// assert_eq!(effective_diameter(&neighborhood_function, 1.0), 3.0);
+/// assert_eq!(effective_diameter(&neighborhood_function, 0.75), 1.5);
+/// ```
+pub fn effective_diameter(neighborhood_function: &[f64], quantile: f64) -> f64 {
+    let total = match neighborhood_function.last() {
+        Some(total) => *total,
+        None => return 0.0,
+    };
+    let target = quantile * total;
+    for (distance, count) in neighborhood_function.iter().enumerate() {
+        if *count >= target {
+            if distance == 0 {
+                return 0.0;
+            }
+            let previous = neighborhood_function[distance - 1];
+            return (distance - 1) as f64 + (target - previous) / (count - previous);
+        }
+    }
+    (neighborhood_function.len() - 1) as f64
+}
+
+/// Compute the average distance of a graph from its neighborhood function.
+///
+/// The average is taken over all the ordered pairs of distinct nodes `(x, y)`
+/// such that `y` is reachable from `x`.
+///
+/// Arguments:
+///
+/// * `neighborhood_function` - The (estimated) neighborhood function, for
+///   example from [`approximate_neighborhood_function`]
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::shortest_path::average_distance;
+///
+/// // The neighborhood function of a path graph with 4 nodes
+/// let neighborhood_function = [4., 10., 14., 16.];
+/// assert_eq!(average_distance(&neighborhood_function), 20. / 12.);
+/// ```
+pub fn average_distance(neighborhood_function: &[f64]) -> f64 {
+    if neighborhood_function.len() < 2 {
+        return 0.0;
+    }
+    let pairs = neighborhood_function[neighborhood_function.len() - 1] - neighborhood_function[0];
+    if pairs <= 0.0 {
+        return 0.0;
+    }
+    let distance_sum: f64 = neighborhood_function
+        .windows(2)
+        .enumerate()
+        .map(|(distance, window)| (distance + 1) as f64 * (window[1] - window[0]))
+        .sum();
+    distance_sum / pairs
+}
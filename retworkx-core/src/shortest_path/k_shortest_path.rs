@@ -86,7 +86,7 @@ where
     G: IntoEdges + Visitable + NodeCount + NodeIndexable + IntoNodeIdentifiers,
     G::NodeId: Eq + Hash,
     F: FnMut(G::EdgeRef) -> Result<K, E>,
-    K: Measure + Copy,
+    K: Measure,
     S: DistanceMap<G::NodeId, K>,
 {
     let mut counter: Vec<usize> = vec![0; graph.node_bound()];
@@ -105,7 +105,7 @@ where
         }
 
         if current_counter == k {
-            scores.put_item(node, node_score);
+            scores.put_item(node, node_score.clone());
         }
 
         //Already reached goal k times
@@ -114,7 +114,10 @@ where
         }
 
         for edge in graph.edges(node) {
-            visit_next.push(MinScored(node_score + edge_cost(edge)?, edge.target()));
+            visit_next.push(MinScored(
+                node_score.clone() + edge_cost(edge)?,
+                edge.target(),
+            ));
         }
     }
 
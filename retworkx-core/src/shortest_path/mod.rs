@@ -15,10 +15,18 @@
 //! This module contains functions for various algorithms that compute the
 //! shortest path of a graph.
 
+mod all_pairs;
 mod astar;
+mod bellman_ford;
 mod dijkstra;
+mod hyperanf;
 mod k_shortest_path;
 
-pub use astar::astar;
-pub use dijkstra::dijkstra;
+pub use all_pairs::{
+    all_pairs_dijkstra_path_lengths_chunked, all_pairs_dijkstra_path_lengths_into,
+};
+pub use astar::{astar, astar_edge_path};
+pub use bellman_ford::{bellman_ford, bellman_ford_edge_paths};
+pub use dijkstra::{dijkstra, dijkstra_edge_paths};
+pub use hyperanf::{approximate_neighborhood_function, average_distance, effective_diameter};
 pub use k_shortest_path::k_shortest_path;
@@ -0,0 +1,755 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+//! Module for spectral graph algorithms.
+//!
+//! The eigenvectors are computed with the locally optimal block
+//! preconditioned conjugate gradient method (LOBPCG) [1], and the Fiedler
+//! vector with the Lanczos method [2], which only multiply vectors by the
+//! sparse matrix of the graph, so they scale to graphs whose dense matrix
+//! wouldn't fit in memory.
+//!
+//! [1] Andrew V. Knyazev, "Toward the Optimal Preconditioned Eigensolver:
+//! Locally Optimal Block Preconditioned Conjugate Gradient Method", 2001,
+//! <https://doi.org/10.1137/S1064827500366124>
+//!
+//! [2] Cornelius Lanczos, "An iteration method for the solution of the
+//! eigenvalue problem of linear differential and integral operators", 1950,
+//! Journal of Research of the National Bureau of Standards, 45(4), 255-282
+
+use hashbrown::HashMap;
+
+use petgraph::visit::{EdgeRef, IntoEdgeReferences, IntoNodeIdentifiers, NodeIndexable};
+use rand::prelude::*;
+use rand_pcg::Pcg64;
+
+use crate::partition::GraphPartition;
+use crate::Convergence;
+
+/// The seed of the initial vectors, so the eigenvectors of degenerate
+/// eigenvalues are reproducible.
+const SEED: u64 = 0x5eed_1ab0;
+
+/// The number of eigenvectors computed in addition to the requested ones.
+const GUARD_VECTORS: usize = 4;
+
+/// The number of Lanczos vectors built before restarting from the current
+/// approximation of the Fiedler vector.
+const LANCZOS_VECTORS: usize = 40;
+
+/// The eigenvalues of a matrix and the matching eigenvectors.
+pub type Eigenpairs = (Vec<f64>, Vec<Vec<f64>>);
+
+/// The Laplacian matrix `D - A` of a graph with nodes `0..n` as adjacency
+/// lists, where every edge goes both ways and self loops are left out since
+/// they cancel out.
+struct Laplacian {
+    degrees: Vec<f64>,
+    neighbors: Vec<Vec<(usize, f64)>>,
+}
+
+impl Laplacian {
+    /// Build the Laplacian of a graph, returning the node indices of the
+    /// graph in the order of the rows of the matrix along with it.
+    fn from_graph<G, F, E>(graph: G, mut weight_fn: F) -> Result<(Vec<usize>, Self), E>
+    where
+        G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable,
+        F: FnMut(G::EdgeRef) -> Result<f64, E>,
+    {
+        let nodes: Vec<usize> = graph
+            .node_identifiers()
+            .map(|n| graph.to_index(n))
+            .collect();
+        let index: HashMap<usize, usize> = nodes.iter().enumerate().map(|(i, &n)| (n, i)).collect();
+        let mut laplacian = Laplacian {
+            degrees: vec![0.0; nodes.len()],
+            neighbors: vec![Vec::new(); nodes.len()],
+        };
+        for edge in graph.edge_references() {
+            let weight = weight_fn(edge)?;
+            let source = index[&graph.to_index(edge.source())];
+            let target = index[&graph.to_index(edge.target())];
+            if source != target {
+                laplacian.degrees[source] += weight;
+                laplacian.degrees[target] += weight;
+                laplacian.neighbors[source].push((target, weight));
+                laplacian.neighbors[target].push((source, weight));
+            }
+        }
+        Ok((nodes, laplacian))
+    }
+
+    /// The Gershgorin bound of the largest eigenvalue, which scales the
+    /// residuals.
+    fn norm(&self) -> f64 {
+        self.degrees
+            .iter()
+            .fold(0.0f64, |norm, degree| norm.max(2.0 * degree.abs()))
+            .max(f64::MIN_POSITIVE)
+    }
+
+    fn len(&self) -> usize {
+        self.degrees.len()
+    }
+
+    fn mul(&self, x: &[f64]) -> Vec<f64> {
+        self.neighbors
+            .iter()
+            .enumerate()
+            .map(|(i, neighbors)| {
+                self.degrees[i] * x[i]
+                    - neighbors
+                        .iter()
+                        .map(|&(j, weight)| weight * x[j])
+                        .sum::<f64>()
+            })
+            .collect()
+    }
+}
+
+fn dot(x: &[f64], y: &[f64]) -> f64 {
+    x.iter().zip(y).map(|(a, b)| a * b).sum()
+}
+
+/// Return the linear combinations of `vectors` with the coefficients in the
+/// columns `0..k` of the row-major matrix `coefficients` with `columns`
+/// columns, starting at row `offset`.
+fn combine(
+    vectors: &[Vec<f64>],
+    coefficients: &[f64],
+    columns: usize,
+    offset: usize,
+    k: usize,
+) -> Vec<Vec<f64>> {
+    (0..k)
+        .map(|col| {
+            let mut out = vec![0.0; vectors[0].len()];
+            for (row, vector) in vectors.iter().enumerate() {
+                let c = coefficients[(offset + row) * columns + col];
+                for (o, v) in out.iter_mut().zip(vector) {
+                    *o += c * v;
+                }
+            }
+            out
+        })
+        .collect()
+}
+
+/// Orthonormalize `vectors` in order with the modified Gram-Schmidt process
+/// applied twice, dropping the vectors which are (numerically) in the span
+/// of the previous ones.
+fn orthonormalize(vectors: Vec<Vec<f64>>) -> Vec<Vec<f64>> {
+    let mut basis: Vec<Vec<f64>> = Vec::with_capacity(vectors.len());
+    for mut v in vectors {
+        let norm = dot(&v, &v).sqrt();
+        if norm == 0.0 || !norm.is_finite() {
+            continue;
+        }
+        for _ in 0..2 {
+            for b in &basis {
+                let projection = dot(&v, b);
+                for (x, y) in v.iter_mut().zip(b) {
+                    *x -= projection * y;
+                }
+            }
+        }
+        let new_norm = dot(&v, &v).sqrt();
+        if new_norm > 1e-10 * norm {
+            v.iter_mut().for_each(|x| *x /= new_norm);
+            basis.push(v);
+        }
+    }
+    basis
+}
+
+/// Return the eigenvalues in ascending order and the eigenvectors as the
+/// columns of a row-major matrix of the dense symmetric `m x m` matrix `a`,
+/// computed with the cyclic Jacobi method.
+fn symmetric_eigen(mut a: Vec<f64>, m: usize) -> (Vec<f64>, Vec<f64>) {
+    let mut v = vec![0.0; m * m];
+    for i in 0..m {
+        v[i * m + i] = 1.0;
+    }
+    for _ in 0..100 {
+        let total: f64 = a.iter().map(|x| x * x).sum();
+        let diagonal: f64 = (0..m).map(|i| a[i * m + i] * a[i * m + i]).sum();
+        if total - diagonal <= f64::EPSILON * f64::EPSILON * total {
+            break;
+        }
+        for p in 0..m {
+            for q in p + 1..m {
+                let apq = a[p * m + q];
+                if apq == 0.0 {
+                    continue;
+                }
+                let theta = (a[q * m + q] - a[p * m + p]) / (2.0 * apq);
+                let t = theta.signum() / (theta.abs() + (theta * theta + 1.0).sqrt());
+                let c = 1.0 / (t * t + 1.0).sqrt();
+                let s = t * c;
+                for k in 0..m {
+                    let (akp, akq) = (a[k * m + p], a[k * m + q]);
+                    a[k * m + p] = c * akp - s * akq;
+                    a[k * m + q] = s * akp + c * akq;
+                }
+                for k in 0..m {
+                    let (apk, aqk) = (a[p * m + k], a[q * m + k]);
+                    a[p * m + k] = c * apk - s * aqk;
+                    a[q * m + k] = s * apk + c * aqk;
+                }
+                for k in 0..m {
+                    let (vkp, vkq) = (v[k * m + p], v[k * m + q]);
+                    v[k * m + p] = c * vkp - s * vkq;
+                    v[k * m + q] = s * vkp + c * vkq;
+                }
+            }
+        }
+    }
+    let mut order: Vec<usize> = (0..m).collect();
+    order.sort_by(|&i, &j| a[i * m + i].partial_cmp(&a[j * m + j]).unwrap());
+    let values = order.iter().map(|&i| a[i * m + i]).collect();
+    let mut vectors = vec![0.0; m * m];
+    for (col, &i) in order.iter().enumerate() {
+        for row in 0..m {
+            vectors[row * m + col] = v[row * m + i];
+        }
+    }
+    (values, vectors)
+}
+
+/// Flip the sign of a vector so its entry of largest absolute value is
+/// positive.
+fn normalize_sign(mut vector: Vec<f64>) -> Vec<f64> {
+    let largest = vector.iter().fold(
+        0.0f64,
+        |largest, x| if x.abs() > largest.abs() { *x } else { largest },
+    );
+    if largest < 0.0 {
+        vector.iter_mut().for_each(|x| *x = -*x);
+    }
+    vector
+}
+
+/// Index a vector of the rows of a Laplacian by node index, with 0 for the
+/// removed indices, after normalizing its sign.
+fn expand(nodes: &[usize], node_bound: usize, compact: Vec<f64>) -> Vec<f64> {
+    let mut vector = vec![0.0; node_bound];
+    for (node, x) in nodes.iter().zip(normalize_sign(compact)) {
+        vector[*node] = x;
+    }
+    vector
+}
+
+/// Find the `k` smallest eigenpairs of a Laplacian with LOBPCG, using its
+/// diagonal as the preconditioner.
+fn lobpcg(
+    laplacian: &Laplacian,
+    k: usize,
+    max_iter: usize,
+    tol: f64,
+) -> (Vec<f64>, Vec<Vec<f64>>, Convergence) {
+    let n = laplacian.len();
+    let norm = laplacian.norm();
+    let mut rng = Pcg64::seed_from_u64(SEED);
+    // Extra vectors in the block speed up the convergence of the last of
+    // the `k` eigenvectors when the next eigenvalues are close to it
+    let block = (k + GUARD_VECTORS).min(n);
+    let x: Vec<Vec<f64>> = (0..block)
+        .map(|_| (0..n).map(|_| rng.gen_range(-1.0..1.0)).collect())
+        .collect();
+    let mut x = orthonormalize(x);
+    let mut values: Vec<f64> = Vec::new();
+    let mut p: Vec<Vec<f64>> = Vec::new();
+    let mut convergence = Convergence {
+        iterations: 0,
+        error: f64::INFINITY,
+        converged: false,
+    };
+    let mut w: Vec<Vec<f64>> = Vec::new();
+    for iteration in 0..=max_iter {
+        // Rayleigh-Ritz on the span of the vectors, their preconditioned
+        // residuals and the previous search directions
+        let num_x = x.len();
+        let mut basis = x;
+        basis.append(&mut w);
+        basis.append(&mut p);
+        let basis = orthonormalize(basis);
+        let products: Vec<Vec<f64>> = basis.iter().map(|b| laplacian.mul(b)).collect();
+        let m = basis.len();
+        let mut gram = vec![0.0; m * m];
+        for i in 0..m {
+            for j in i..m {
+                let value = (dot(&basis[i], &products[j]) + dot(&basis[j], &products[i])) / 2.0;
+                gram[i * m + j] = value;
+                gram[j * m + i] = value;
+            }
+        }
+        let (ritz_values, coefficients) = symmetric_eigen(gram, m);
+        let block = block.min(m);
+        values = ritz_values[..block].to_vec();
+        x = combine(&basis, &coefficients, m, 0, block);
+        let lx = combine(&products, &coefficients, m, 0, block);
+        // The new search directions are the parts of the new vectors
+        // outside of the span of the previous ones
+        p = if m > num_x {
+            combine(&basis[num_x..], &coefficients, m, num_x, block)
+        } else {
+            Vec::new()
+        };
+
+        let residuals: Vec<Vec<f64>> = x
+            .iter()
+            .zip(&lx)
+            .zip(&values)
+            .map(|((x, lx), value)| lx.iter().zip(x).map(|(l, x)| l - value * x).collect())
+            .collect();
+        let error = residuals
+            .iter()
+            .take(k)
+            .map(|r| dot(r, r).sqrt() / norm)
+            .fold(0.0, f64::max);
+        convergence = Convergence {
+            iterations: iteration,
+            error,
+            converged: error <= tol,
+        };
+        if convergence.converged || iteration == max_iter {
+            break;
+        }
+        w = residuals
+            .into_iter()
+            .map(|r| {
+                r.iter()
+                    .zip(&laplacian.degrees)
+                    .map(|(r, d)| if *d > 0.0 { r / d } else { *r })
+                    .collect()
+            })
+            .collect();
+    }
+    values.truncate(k);
+    x.truncate(k);
+    (values, x, convergence)
+}
+
+/// Compute the eigenvectors of the `k` smallest eigenvalues of the Laplacian
+/// matrix of a graph.
+///
+/// The Laplacian matrix is `L = D - A`, where `A` is the weighted adjacency
+/// matrix of the graph and `D` the diagonal matrix of the weighted degrees of
+/// the nodes. The direction of the edges of a directed graph is ignored, so
+/// the matrix is symmetric (`A + A^T` is used as the adjacency matrix), and
+/// self loops don't change the matrix. The smallest eigenvalue is 0, with
+/// one eigenvector per connected component.
+///
+/// Arguments:
+///
+/// * `graph` - The graph object to run the algorithm on
+/// * `weight_fn` - A callable that receives an edge and returns its weight,
+///   which must be non-negative. Parallel edges have their weights added.
+/// * `k` - The number of eigenvectors to compute. If it's larger than the
+///   number of nodes, an eigenvector is computed for every node.
+/// * `max_iter` - The maximum number of iterations of LOBPCG
+/// * `tol` - The error tolerance used when checking for convergence, the
+///   iteration stops once the euclidean norm of the residual `L x - λ x` of
+///   every eigenvector is less than `tol` times an upper bound of the
+///   largest eigenvalue of `L`, twice the largest weighted degree.
+///
+/// Returns the eigenvalues in ascending order and the matching eigenvectors,
+/// indexed by node index (removed indices are set to 0) and normalized to a
+/// euclidean norm of 1 with a positive entry of largest absolute value, or
+/// [`None`] if LOBPCG didn't converge within `max_iter` iterations.
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::spectral::laplacian_eigenvectors;
+/// use retworkx_core::Result;
+///
+/// let g = petgraph::graph::UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2)]);
+/// let output: Result<_> = laplacian_eigenvectors(&g, |_| Ok(1.), 3, 100, 1e-8);
+/// let (values, vectors) = output.unwrap().unwrap();
+/// // The eigenvalues of the Laplacian of a path with 3 nodes are 0, 1 and 3
+/// for (value, expected) in values.iter().zip(&[0., 1., 3.]) {
+///     assert!((value - expected).abs() < 1e-6);
+/// }
+/// // The Fiedler vector of the path separates its ends
+/// let fiedler = &vectors[1];
+/// assert!((fiedler[0] + fiedler[2]).abs() < 1e-6);
+/// assert!(fiedler[1].abs() < 1e-6);
+/// ```
+pub fn laplacian_eigenvectors<G, F, E>(
+    graph: G,
+    weight_fn: F,
+    k: usize,
+    max_iter: usize,
+    tol: f64,
+) -> Result<Option<Eigenpairs>, E>
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable,
+    F: FnMut(G::EdgeRef) -> Result<f64, E>,
+{
+    let (eigenpairs, convergence) =
+        laplacian_eigenvectors_with_convergence(graph, weight_fn, k, max_iter, tol)?;
+    Ok(if convergence.converged {
+        Some(eigenpairs)
+    } else {
+        None
+    })
+}
+
+/// Compute the eigenvectors of the `k` smallest eigenvalues of the Laplacian
+/// matrix of a graph along with the convergence of LOBPCG.
+///
+/// This takes the same arguments as [`laplacian_eigenvectors`], but returns
+/// the eigenpairs of the last iteration along with its [`Convergence`]
+/// instead of [`None`] if LOBPCG didn't converge within `max_iter`
+/// iterations. The error of an iteration is the largest residual norm of an
+/// eigenvector relative to the bound of the largest eigenvalue.
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::spectral::laplacian_eigenvectors_with_convergence;
+/// use retworkx_core::Result;
+///
+/// let g = petgraph::graph::UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 3), (3, 0)]);
+/// let output: Result<_> = laplacian_eigenvectors_with_convergence(&g, |_| Ok(1.), 2, 100, 1e-8);
+/// let ((values, _), convergence) = output.unwrap();
+/// assert!(convergence.converged);
+/// assert!((values[1] - 2.).abs() < 1e-6);
+/// ```
+pub fn laplacian_eigenvectors_with_convergence<G, F, E>(
+    graph: G,
+    weight_fn: F,
+    k: usize,
+    max_iter: usize,
+    tol: f64,
+) -> Result<(Eigenpairs, Convergence), E>
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable,
+    F: FnMut(G::EdgeRef) -> Result<f64, E>,
+{
+    let (nodes, laplacian) = Laplacian::from_graph(graph, weight_fn)?;
+
+    if nodes.is_empty() || k == 0 {
+        let convergence = Convergence {
+            iterations: 0,
+            error: 0.0,
+            converged: true,
+        };
+        return Ok(((Vec::new(), Vec::new()), convergence));
+    }
+    let (values, compact_vectors, convergence) = lobpcg(&laplacian, k, max_iter, tol);
+    let vectors = compact_vectors
+        .into_iter()
+        .map(|compact| expand(&nodes, graph.node_bound(), compact))
+        .collect();
+    Ok(((values, vectors), convergence))
+}
+
+/// Find the second smallest eigenpair of a Laplacian of at least 2 rows with
+/// the Lanczos method, restricted to the vectors orthogonal to the constant
+/// vector, the eigenvector of the smallest eigenvalue 0.
+///
+/// The Lanczos vectors are fully reorthogonalized, and the iteration
+/// restarts from the current Ritz vector every [`LANCZOS_VECTORS`] steps.
+/// The iterations of the returned [`Convergence`] are the number of
+/// products by the Laplacian.
+fn lanczos_fiedler(
+    laplacian: &Laplacian,
+    max_iter: usize,
+    tol: f64,
+) -> (f64, Vec<f64>, Convergence) {
+    let n = laplacian.len();
+    let norm = laplacian.norm();
+    let constant = vec![1.0 / (n as f64).sqrt(); n];
+    let mut rng = Pcg64::seed_from_u64(SEED);
+    let mut x: Vec<f64> = (0..n).map(|_| rng.gen_range(-1.0..1.0)).collect();
+    let dimension = LANCZOS_VECTORS.min(n - 1);
+    let mut iterations = 0;
+    loop {
+        let projection = dot(&x, &constant);
+        x.iter_mut()
+            .zip(&constant)
+            .for_each(|(x, c)| *x -= projection * c);
+        let x_norm = dot(&x, &x).sqrt();
+        x.iter_mut().for_each(|x| *x /= x_norm);
+
+        let mut basis: Vec<Vec<f64>> = vec![x];
+        let mut alpha: Vec<f64> = Vec::with_capacity(dimension);
+        let mut beta: Vec<f64> = Vec::with_capacity(dimension);
+        let (value, ritz, error) = loop {
+            iterations += 1;
+            let v = basis.last().unwrap();
+            let mut w = laplacian.mul(v);
+            alpha.push(dot(&w, v));
+            for _ in 0..2 {
+                for b in std::iter::once(&constant).chain(&basis) {
+                    let projection = dot(&w, b);
+                    for (x, y) in w.iter_mut().zip(b) {
+                        *x -= projection * y;
+                    }
+                }
+            }
+            let w_norm = dot(&w, &w).sqrt();
+            // The Ritz pairs of the tridiagonal matrix of the Lanczos
+            // vectors, the residual of a Ritz vector is the norm of the
+            // next vector times its last coefficient
+            let m = alpha.len();
+            let mut tridiagonal = vec![0.0; m * m];
+            for i in 0..m {
+                tridiagonal[i * m + i] = alpha[i];
+                if i + 1 < m {
+                    tridiagonal[i * m + i + 1] = beta[i];
+                    tridiagonal[(i + 1) * m + i] = beta[i];
+                }
+            }
+            let (values, coefficients) = symmetric_eigen(tridiagonal, m);
+            let error = (w_norm * coefficients[(m - 1) * m]).abs() / norm;
+            let exhausted = w_norm <= f64::EPSILON * norm;
+            if error <= tol || exhausted || m == dimension || iterations >= max_iter {
+                let ritz = combine(&basis, &coefficients, m, 0, 1).pop().unwrap();
+                break (values[0], ritz, error);
+            }
+            beta.push(w_norm);
+            w.iter_mut().for_each(|x| *x /= w_norm);
+            basis.push(w);
+        };
+        if error <= tol || iterations >= max_iter {
+            let convergence = Convergence {
+                iterations,
+                error,
+                converged: error <= tol,
+            };
+            return (value, ritz, convergence);
+        }
+        x = ritz;
+    }
+}
+
+/// Compute the Fiedler vector of a graph, the eigenvector of the second
+/// smallest eigenvalue of its Laplacian matrix, along with that eigenvalue,
+/// the algebraic connectivity of the graph.
+///
+/// The Laplacian matrix is `L = D - A`, where `A` is the weighted adjacency
+/// matrix of the graph and `D` the diagonal matrix of the weighted degrees of
+/// the nodes. The direction of the edges of a directed graph is ignored, so
+/// the matrix is symmetric, and self loops don't change the matrix. The
+/// eigenpair is computed with the Lanczos method, restricted to the vectors
+/// orthogonal to the constant vector, the eigenvector of the smallest
+/// eigenvalue 0. The algebraic connectivity is positive if and only if the
+/// graph is connected, otherwise it's 0 and the Fiedler vector is not
+/// unique.
+///
+/// Arguments:
+///
+/// * `graph` - The graph object to run the algorithm on
+/// * `weight_fn` - A callable that receives an edge and returns its weight,
+///   which must be non-negative. Parallel edges have their weights added.
+/// * `max_iter` - The maximum number of Lanczos iterations, the number of
+///   products of a vector by the Laplacian
+/// * `tol` - The error tolerance used when checking for convergence, the
+///   iteration stops once the euclidean norm of the residual `L x - λ x` is
+///   less than `tol` times an upper bound of the largest eigenvalue of `L`,
+///   twice the largest weighted degree.
+///
+/// Returns the algebraic connectivity and the Fiedler vector, indexed by
+/// node index (removed indices are set to 0) and normalized to a euclidean
+/// norm of 1 with a positive entry of largest absolute value, or [`None`]
+/// if the Lanczos method didn't converge within `max_iter` iterations. For a
+/// graph with less than 2 nodes the algebraic connectivity is 0 and the
+/// vector is all zeros.
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::spectral::fiedler_vector;
+/// use retworkx_core::Result;
+///
+/// let g = petgraph::graph::UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2)]);
+/// let output: Result<_> = fiedler_vector(&g, |_| Ok(1.), 100, 1e-10);
+/// let (connectivity, fiedler) = output.unwrap().unwrap();
+/// // The algebraic connectivity of a path with 3 nodes is 1
+/// assert!((connectivity - 1.).abs() < 1e-8);
+/// // The Fiedler vector of the path separates its ends
+/// assert!((fiedler[0] + fiedler[2]).abs() < 1e-8);
+/// assert!(fiedler[1].abs() < 1e-8);
+/// ```
+pub fn fiedler_vector<G, F, E>(
+    graph: G,
+    weight_fn: F,
+    max_iter: usize,
+    tol: f64,
+) -> Result<Option<(f64, Vec<f64>)>, E>
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable,
+    F: FnMut(G::EdgeRef) -> Result<f64, E>,
+{
+    let (fiedler, convergence) = fiedler_vector_with_convergence(graph, weight_fn, max_iter, tol)?;
+    Ok(if convergence.converged {
+        Some(fiedler)
+    } else {
+        None
+    })
+}
+
+/// Compute the Fiedler vector of a graph and its algebraic connectivity
+/// along with the convergence of the Lanczos method.
+///
+/// This takes the same arguments as [`fiedler_vector`], but returns the
+/// eigenpair of the last iteration along with its [`Convergence`] instead of
+/// [`None`] if the Lanczos method didn't converge within `max_iter`
+/// iterations. The error of an iteration is the residual norm of the vector
+/// relative to the bound of the largest eigenvalue.
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::spectral::fiedler_vector_with_convergence;
+/// use retworkx_core::Result;
+///
+/// let g = petgraph::graph::UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 3), (3, 0)]);
+/// let output: Result<_> = fiedler_vector_with_convergence(&g, |_| Ok(1.), 100, 1e-10);
+/// let ((connectivity, _), convergence) = output.unwrap();
+/// assert!(convergence.converged);
+/// assert!((connectivity - 2.).abs() < 1e-8);
+/// ```
+pub fn fiedler_vector_with_convergence<G, F, E>(
+    graph: G,
+    weight_fn: F,
+    max_iter: usize,
+    tol: f64,
+) -> Result<((f64, Vec<f64>), Convergence), E>
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable,
+    F: FnMut(G::EdgeRef) -> Result<f64, E>,
+{
+    let (nodes, laplacian) = Laplacian::from_graph(graph, weight_fn)?;
+    if nodes.len() < 2 {
+        let convergence = Convergence {
+            iterations: 0,
+            error: 0.0,
+            converged: true,
+        };
+        return Ok(((0.0, vec![0.0; graph.node_bound()]), convergence));
+    }
+    let (value, compact, convergence) = lanczos_fiedler(&laplacian, max_iter, tol);
+    Ok((
+        (value, expand(&nodes, graph.node_bound(), compact)),
+        convergence,
+    ))
+}
+
+/// Compute the algebraic connectivity of a graph, the second smallest
+/// eigenvalue of its Laplacian matrix.
+///
+/// This is the eigenvalue of the Fiedler vector computed by
+/// [`fiedler_vector`], which takes the same arguments. It's positive if and
+/// only if the graph is connected, and the larger it is the harder it is to
+/// disconnect the graph by removing edges. Returns [`None`] if the Lanczos
+/// method didn't converge within `max_iter` iterations.
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::spectral::algebraic_connectivity;
+/// use retworkx_core::Result;
+///
+/// // The algebraic connectivity of a complete graph of n nodes is n
+/// let g = petgraph::graph::UnGraph::<(), ()>::from_edges(&[
+///     (0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3),
+/// ]);
+/// let output: Result<_> = algebraic_connectivity(&g, |_| Ok(1.), 100, 1e-10);
+/// assert!((output.unwrap().unwrap() - 4.).abs() < 1e-8);
+/// ```
+pub fn algebraic_connectivity<G, F, E>(
+    graph: G,
+    weight_fn: F,
+    max_iter: usize,
+    tol: f64,
+) -> Result<Option<f64>, E>
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable,
+    F: FnMut(G::EdgeRef) -> Result<f64, E>,
+{
+    let result = fiedler_vector(graph, weight_fn, max_iter, tol)?;
+    Ok(result.map(|(connectivity, _)| connectivity))
+}
+
+/// Split the nodes of a graph into two parts by the sign of their entry of
+/// the Fiedler vector.
+///
+/// The first part has the nodes with a negative entry and the second one
+/// the nodes with a non-negative entry of the Fiedler vector computed by
+/// [`fiedler_vector`], which takes the same arguments, normalized so its
+/// entry of largest absolute value is positive. This is a relaxation of the
+/// bisection minimizing the ratio cut, the parts are not necessarily of the
+/// same size. Returns [`None`] if the Lanczos method didn't converge within
+/// `max_iter` iterations.
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::spectral::spectral_bisection;
+/// use retworkx_core::Result;
+///
+/// // Two triangles joined by an edge
+/// let g = petgraph::graph::UnGraph::<(), ()>::from_edges(&[
+///     (0, 1), (1, 2), (2, 0), (3, 4), (4, 5), (5, 3), (2, 3),
+/// ]);
+/// let output: Result<_> = spectral_bisection(&g, |_| Ok(1.), 100, 1e-10);
+/// let partition = output.unwrap().unwrap();
+/// let mut parts: Vec<Vec<usize>> = partition
+///     .parts
+///     .iter()
+///     .map(|part| part.iter().map(|n| n.index()).collect())
+///     .collect();
+/// parts.sort();
+/// assert_eq!(parts, vec![vec![0, 1, 2], vec![3, 4, 5]]);
+/// assert_eq!(partition.cut_weight, 1.);
+/// ```
+pub fn spectral_bisection<G, F, E>(
+    graph: G,
+    weight_fn: F,
+    max_iter: usize,
+    tol: f64,
+) -> Result<Option<GraphPartition<G::NodeId>>, E>
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable,
+    F: FnMut(G::EdgeRef) -> Result<f64, E>,
+{
+    let (nodes, laplacian) = Laplacian::from_graph(graph, weight_fn)?;
+    let fiedler = if nodes.len() < 2 {
+        vec![0.0; nodes.len()]
+    } else {
+        let (_, compact, convergence) = lanczos_fiedler(&laplacian, max_iter, tol);
+        if !convergence.converged {
+            return Ok(None);
+        }
+        normalize_sign(compact)
+    };
+    let sides: Vec<usize> = fiedler.iter().map(|x| usize::from(*x >= 0.0)).collect();
+    let mut parts: Vec<Vec<G::NodeId>> = vec![Vec::new(), Vec::new()];
+    let mut order: Vec<usize> = (0..nodes.len()).collect();
+    order.sort_by_key(|i| nodes[*i]);
+    for i in order {
+        parts[sides[i]].push(graph.from_index(nodes[i]));
+    }
+    let cut_weight = laplacian
+        .neighbors
+        .iter()
+        .enumerate()
+        .flat_map(|(i, neighbors)| neighbors.iter().map(move |(j, weight)| (i, *j, *weight)))
+        .filter(|(i, j, _)| i < j && sides[*i] != sides[*j])
+        .map(|(_, _, weight)| weight)
+        .sum();
+    Ok(Some(GraphPartition { parts, cut_weight }))
+}
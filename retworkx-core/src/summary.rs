@@ -0,0 +1,288 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use std::collections::VecDeque;
+
+use petgraph::visit::{EdgeRef, GraphProp, IntoEdgeReferences, IntoNodeIdentifiers, NodeIndexable};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// A summary of the basic statistics of a graph, returned by
+/// [`graph_summary`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct GraphSummary {
+    /// The number of nodes
+    pub node_count: usize,
+    /// The number of edges, counting parallel edges and self loops
+    pub edge_count: usize,
+    /// The number of edges divided by the number of possible edges between
+    /// distinct nodes
+    pub density: f64,
+    /// The smallest degree of a node
+    pub min_degree: usize,
+    /// The largest degree of a node
+    pub max_degree: usize,
+    /// The average degree of a node
+    pub mean_degree: f64,
+    /// The number of (weakly) connected components
+    pub num_components: usize,
+    /// The number of nodes in the largest (weakly) connected component
+    pub largest_component_size: usize,
+    /// The average of the local clustering coefficients of all the nodes
+    pub average_clustering: f64,
+    /// The degree assortativity coefficient, or [`None`] if it's undefined
+    /// because there are no edges or all the edges join nodes of the same
+    /// degrees
+    pub degree_assortativity: Option<f64>,
+    /// A lower bound on the diameter of the largest (weakly) connected
+    /// component found with a double sweep breadth first search
+    pub diameter_estimate: usize,
+}
+
+/// Find the distance from `source` to every node it can reach with a
+/// breadth first search, and the nodes it can reach in the order they were
+/// found (so the last one is the farthest).
+fn sweep(neighbors: &[Vec<usize>], source: usize) -> (Vec<Option<usize>>, Vec<usize>) {
+    let mut distance: Vec<Option<usize>> = vec![None; neighbors.len()];
+    let mut queue: VecDeque<usize> = VecDeque::new();
+    let mut order = Vec::new();
+    distance[source] = Some(0);
+    queue.push_back(source);
+    while let Some(node) = queue.pop_front() {
+        order.push(node);
+        let next = distance[node].unwrap() + 1;
+        for neighbor in &neighbors[node] {
+            if distance[*neighbor].is_none() {
+                distance[*neighbor] = Some(next);
+                queue.push_back(*neighbor);
+            }
+        }
+    }
+    (distance, order)
+}
+
+/// The Pearson correlation coefficient of the pairs `(x, y)`.
+fn pearson(pairs: &[(f64, f64)]) -> Option<f64> {
+    let count = pairs.len() as f64;
+    let mean_x = pairs.iter().map(|(x, _)| x).sum::<f64>() / count;
+    let mean_y = pairs.iter().map(|(_, y)| y).sum::<f64>() / count;
+    let mut covariance = 0.0;
+    let mut variance_x = 0.0;
+    let mut variance_y = 0.0;
+    for (x, y) in pairs {
+        covariance += (x - mean_x) * (y - mean_y);
+        variance_x += (x - mean_x).powi(2);
+        variance_y += (y - mean_y).powi(2);
+    }
+    let out = covariance / (variance_x * variance_y).sqrt();
+    if out.is_finite() {
+        Some(out)
+    } else {
+        None
+    }
+}
+
+/// Compute a summary of the basic statistics of a graph.
+///
+/// This computes the statistics which are usually looked at first to profile
+/// a graph in a single call, sharing the work between them: the degrees and
+/// the adjacency of every node are collected in one pass over the edges and
+/// the local clustering coefficients are computed in parallel.
+///
+/// The degree of a node is the number of edges incident to it, where self
+/// loops count twice, and for directed graphs it's the sum of the in and out
+/// degrees. The components, clustering and diameter estimate ignore the
+/// direction of the edges, parallel edges and self loops. For directed
+/// graphs the degree assortativity is the correlation of the out degree of
+/// the source with the in degree of the target of the edges [1], for
+/// undirected graphs every edge is counted in both directions.
+///
+/// The diameter estimate is found with a double sweep: a breadth first
+/// search from a node of largest degree in the largest component finds a
+/// farthest node and a second breadth first search from that node finds the
+/// estimate. It's a lower bound on the diameter of the component which is
+/// exact for trees and often for real world graphs [2].
+///
+/// Arguments:
+///
+/// * `graph` - The graph object to summarize
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::summary::graph_summary;
+///
+/// let g = petgraph::graph::UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2), (2, 0), (2, 3)]);
+/// let summary = graph_summary(&g);
+/// assert_eq!(summary.node_count, 4);
+/// assert_eq!(summary.edge_count, 4);
+/// assert_eq!(summary.max_degree, 3);
+/// assert_eq!(summary.num_components, 1);
+/// assert_eq!(summary.diameter_estimate, 2);
+/// assert!((summary.average_clustering - 7.0 / 12.0).abs() < 1e-12);
+/// ```
+///
+/// [1] M. E. J. Newman, "Mixing patterns in networks." Physical Review E
+///   67(2):026126, 2003.
+/// [2] Clémence Magnien, Matthieu Latapy and Michel Habib, "Fast computation
+///   of empirical tight bounds for the diameter of massive graphs." Journal
+///   of Experimental Algorithmics 13:1.10-1.9, 2009.
+pub fn graph_summary<G>(graph: G) -> GraphSummary
+where
+    G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable + GraphProp,
+{
+    let bound = graph.node_bound();
+    let nodes: Vec<usize> = graph
+        .node_identifiers()
+        .map(|n| graph.to_index(n))
+        .collect();
+    let node_count = nodes.len();
+
+    let mut edge_count = 0;
+    let mut in_degree = vec![0; bound];
+    let mut out_degree = vec![0; bound];
+    let mut neighbors: Vec<Vec<usize>> = vec![Vec::new(); bound];
+    let mut ends: Vec<(usize, usize)> = Vec::new();
+    for edge in graph.edge_references() {
+        let source = graph.to_index(edge.source());
+        let target = graph.to_index(edge.target());
+        edge_count += 1;
+        out_degree[source] += 1;
+        in_degree[target] += 1;
+        ends.push((source, target));
+        if source != target {
+            neighbors[source].push(target);
+            neighbors[target].push(source);
+        }
+    }
+    #[cfg(feature = "parallel")]
+    let neighbor_lists = neighbors.par_iter_mut();
+    #[cfg(not(feature = "parallel"))]
+    let neighbor_lists = neighbors.iter_mut();
+    neighbor_lists.for_each(|n| {
+        n.sort_unstable();
+        n.dedup();
+    });
+    let degree: Vec<usize> = (0..bound).map(|n| in_degree[n] + out_degree[n]).collect();
+
+    let possible_edges = if node_count > 1 {
+        let pairs = node_count * (node_count - 1);
+        if graph.is_directed() {
+            pairs
+        } else {
+            pairs / 2
+        }
+    } else {
+        0
+    };
+    let density = if possible_edges > 0 {
+        edge_count as f64 / possible_edges as f64
+    } else {
+        0.0
+    };
+    let min_degree = nodes.iter().map(|n| degree[*n]).min().unwrap_or(0);
+    let max_degree = nodes.iter().map(|n| degree[*n]).max().unwrap_or(0);
+    let mean_degree = if node_count > 0 {
+        nodes.iter().map(|n| degree[*n]).sum::<usize>() as f64 / node_count as f64
+    } else {
+        0.0
+    };
+
+    let average_clustering = if node_count > 0 {
+        #[cfg(feature = "parallel")]
+        let node_iter = nodes.par_iter();
+        #[cfg(not(feature = "parallel"))]
+        let node_iter = nodes.iter();
+        let total: f64 = node_iter
+            .map(|n| {
+                let node_neighbors = &neighbors[*n];
+                let k = node_neighbors.len();
+                if k < 2 {
+                    return 0.0;
+                }
+                // every triangle is found from both of its other nodes
+                let links: usize = node_neighbors
+                    .iter()
+                    .map(|u| {
+                        neighbors[*u]
+                            .iter()
+                            .filter(|w| node_neighbors.binary_search(w).is_ok())
+                            .count()
+                    })
+                    .sum();
+                links as f64 / (k * (k - 1)) as f64
+            })
+            .sum();
+        total / node_count as f64
+    } else {
+        0.0
+    };
+
+    let pairs: Vec<(f64, f64)> = if graph.is_directed() {
+        ends.iter()
+            .map(|(s, t)| (out_degree[*s] as f64, in_degree[*t] as f64))
+            .collect()
+    } else {
+        ends.iter()
+            .flat_map(|(s, t)| {
+                vec![
+                    (degree[*s] as f64, degree[*t] as f64),
+                    (degree[*t] as f64, degree[*s] as f64),
+                ]
+            })
+            .collect()
+    };
+    let degree_assortativity = if pairs.is_empty() {
+        None
+    } else {
+        pearson(&pairs)
+    };
+
+    let mut num_components = 0;
+    let mut largest_component: Vec<usize> = Vec::new();
+    let mut seen = vec![false; bound];
+    for node in &nodes {
+        if seen[*node] {
+            continue;
+        }
+        num_components += 1;
+        let (_, component) = sweep(&neighbors, *node);
+        for n in &component {
+            seen[*n] = true;
+        }
+        if component.len() > largest_component.len() {
+            largest_component = component;
+        }
+    }
+    let diameter_estimate = match largest_component.iter().max_by_key(|n| degree[**n]) {
+        Some(start) => {
+            let (_, order) = sweep(&neighbors, *start);
+            let (distance, order) = sweep(&neighbors, *order.last().unwrap());
+            distance[*order.last().unwrap()].unwrap()
+        }
+        None => 0,
+    };
+
+    GraphSummary {
+        node_count,
+        edge_count,
+        density,
+        min_degree,
+        max_degree,
+        mean_degree,
+        num_components,
+        largest_component_size: largest_component.len(),
+        average_clustering,
+        degree_assortativity,
+        diameter_estimate,
+    }
+}
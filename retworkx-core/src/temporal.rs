@@ -0,0 +1,245 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+//! Module for time-respecting paths of temporal graphs, whose edges are
+//! contacts that can only be traversed at a given time.
+//!
+//! The edges active in a time window can be found with an
+//! [`IntervalIndex`](crate::interval_index::IntervalIndex).
+
+use std::cmp::Ordering;
+use std::hash::Hash;
+
+use hashbrown::HashMap;
+
+use petgraph::algo::Measure;
+use petgraph::visit::{EdgeRef, GraphProp, IntoEdgeReferences, NodeIndexable};
+
+use crate::dictmap::*;
+
+/// Find the earliest arrival time at every node reachable from `source` by
+/// a time-respecting path.
+///
+/// Every edge of the graph is a contact, which can be traversed once,
+/// departing at a time and arriving at a later (or the same) time, as
+/// returned by `contact_fn`, or `None` if the edge can't be traversed. A
+/// time-respecting path, also called a journey, leaves the source no earlier
+/// than `start_time` and departs from every node of the path after it
+/// arrived there: an edge arriving at a node at time `t` can be followed by
+/// an edge departing from that node at time `t` or later. The edges of an
+/// undirected graph can be traversed in both directions at the same times.
+///
+/// This is the one pass algorithm of Wu et al. [1] over the contacts sorted
+/// by departure time, it takes `O(m log m)` time for `m` edges. The contacts
+/// with the same departure time are followed in any order, so a path can
+/// chain several contacts without a duration at the same time.
+///
+/// Arguments:
+///
+/// * `graph` - The temporal graph
+/// * `source` - The node the paths start from
+/// * `start_time` - The earliest time the paths can leave `source`
+/// * `end_time` - If specified, the latest time the paths can arrive at a
+///   node, the contacts arriving after it are ignored
+/// * `contact_fn` - A callback returning the departure and arrival times of
+///   an edge, or `None` if the edge can't be traversed. The arrival time
+///   must not be before the departure time.
+/// * `path` - If specified, a [`DictMap`] that will be filled with an
+///   earliest arrival path, as the list of its nodes from `source`, for
+///   every reachable node
+///
+/// Returns a [`DictMap`] of the earliest arrival time at every node
+/// reachable from `source`, including `source` itself at `start_time`, in
+/// the order of the node indices.
+///
+/// [1] H. Wu, J. Cheng, S. Huang, Y. Ke, Y. Lu and Y. Xu, "Path Problems in
+/// Temporal Graphs", Proceedings of the VLDB Endowment, 7(9), 721-732, 2014.
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::petgraph::graph::NodeIndex;
+/// use retworkx_core::dictmap::DictMap;
+/// use retworkx_core::temporal::earliest_arrival;
+/// use retworkx_core::Result;
+///
+/// // Every edge is weighted by the time it's active
+/// let g = petgraph::graph::DiGraph::<(), f64>::from_edges(&[
+///     (0, 1, 1.0), (1, 2, 3.0), (1, 3, 0.5), (3, 2, 2.0),
+/// ]);
+/// let mut paths = DictMap::default();
+/// let res: Result<DictMap<NodeIndex, f64>> = earliest_arrival(
+///     &g,
+///     NodeIndex::new(0),
+///     0.0,
+///     None,
+///     |e| Ok(Some((*e.weight(), *e.weight()))),
+///     Some(&mut paths),
+/// );
+/// let arrivals = res.unwrap();
+/// assert_eq!(arrivals[&NodeIndex::new(2)], 3.0);
+/// assert_eq!(
+///     paths[&NodeIndex::new(2)],
+///     vec![NodeIndex::new(0), NodeIndex::new(1), NodeIndex::new(2)]
+/// );
+/// // The edge from 1 to 3 departs before the path can reach 1
+/// assert!(!arrivals.contains_key(&NodeIndex::new(3)));
+/// ```
+pub fn earliest_arrival<G, F, K, E>(
+    graph: G,
+    source: G::NodeId,
+    start_time: K,
+    end_time: Option<K>,
+    mut contact_fn: F,
+    path: Option<&mut DictMap<G::NodeId, Vec<G::NodeId>>>,
+) -> Result<DictMap<G::NodeId, K>, E>
+where
+    G: IntoEdgeReferences + NodeIndexable + GraphProp,
+    G::NodeId: Eq + Hash,
+    F: FnMut(G::EdgeRef) -> Result<Option<(K, K)>, E>,
+    K: Measure + Copy,
+{
+    // The contacts as (departure, arrival, from, to) in node indices
+    let mut contacts: Vec<(K, K, usize, usize)> = Vec::new();
+    for edge in graph.edge_references() {
+        let (departure, arrival) = match contact_fn(edge)? {
+            Some(times) => times,
+            None => continue,
+        };
+        // Also false for the times that can't be compared, such as NaN
+        let in_window = departure >= start_time
+            && arrival >= departure
+            && end_time.map_or(true, |end_time| arrival <= end_time);
+        if !in_window {
+            continue;
+        }
+        let source = graph.to_index(edge.source());
+        let target = graph.to_index(edge.target());
+        contacts.push((departure, arrival, source, target));
+        if !graph.is_directed() {
+            contacts.push((departure, arrival, target, source));
+        }
+    }
+    contacts.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(Ordering::Equal));
+
+    let mut arrivals: Vec<Option<K>> = vec![None; graph.node_bound()];
+    let mut predecessors: Vec<Option<usize>> = vec![None; graph.node_bound()];
+    arrivals[graph.to_index(source)] = Some(start_time);
+    let mut group_start = 0;
+    while group_start < contacts.len() {
+        let departure = contacts[group_start].0;
+        let mut group_end = group_start + 1;
+        while group_end < contacts.len() && contacts[group_end].0 == departure {
+            group_end += 1;
+        }
+        // Follow the contacts departing at the same time from the nodes
+        // reached by then, including the nodes they reach without delay
+        let mut departing: HashMap<usize, Vec<usize>> = HashMap::new();
+        let mut stack: Vec<usize> = Vec::new();
+        for (position, contact) in contacts[group_start..group_end].iter().enumerate() {
+            departing
+                .entry(contact.2)
+                .or_insert_with(Vec::new)
+                .push(group_start + position);
+            if matches!(arrivals[contact.2], Some(arrival) if arrival <= departure) {
+                stack.push(contact.2);
+            }
+        }
+        while let Some(node) = stack.pop() {
+            let positions = match departing.remove(&node) {
+                Some(positions) => positions,
+                None => continue,
+            };
+            for position in positions {
+                let (_, arrival, from, to) = contacts[position];
+                if matches!(arrivals[to], Some(current) if current <= arrival) {
+                    continue;
+                }
+                arrivals[to] = Some(arrival);
+                predecessors[to] = Some(from);
+                if arrival <= departure {
+                    stack.push(to);
+                }
+            }
+        }
+        group_start = group_end;
+    }
+
+    let source_index = graph.to_index(source);
+    if let Some(path) = path {
+        for (index, arrival) in arrivals.iter().enumerate() {
+            if arrival.is_none() {
+                continue;
+            }
+            let mut nodes = vec![graph.from_index(index)];
+            let mut current = index;
+            while current != source_index {
+                current = predecessors[current].unwrap();
+                nodes.push(graph.from_index(current));
+            }
+            nodes.reverse();
+            path.insert(graph.from_index(index), nodes);
+        }
+    }
+    Ok(arrivals
+        .into_iter()
+        .enumerate()
+        .filter_map(|(index, arrival)| arrival.map(|arrival| (graph.from_index(index), arrival)))
+        .collect())
+}
+
+/// Find the nodes reachable from `source` by a time-respecting path.
+///
+/// See [`earliest_arrival`] for the definition of the contacts and of the
+/// time-respecting paths, unlike in a static graph a node can be reachable
+/// from another one without the reverse being true in an undirected graph.
+///
+/// Returns the reachable nodes, without `source`, in the order of the node
+/// indices.
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::petgraph::graph::{EdgeReference, NodeIndex};
+/// use retworkx_core::temporal::temporal_reachability;
+/// use retworkx_core::Result;
+///
+/// let g = petgraph::graph::UnGraph::<(), f64>::from_edges(&[
+///     (0, 1, 1.0), (1, 2, 2.0), (2, 3, 1.5),
+/// ]);
+/// let contact = |e: EdgeReference<f64>| Ok(Some((*e.weight(), *e.weight())));
+/// let res: Result<Vec<NodeIndex>> = temporal_reachability(&g, NodeIndex::new(0), 0.0, None, contact);
+/// assert_eq!(res.unwrap(), vec![NodeIndex::new(1), NodeIndex::new(2)]);
+/// // Node 2 is reachable from node 0 but node 0 isn't reachable from node 2
+/// let res: Result<Vec<NodeIndex>> = temporal_reachability(&g, NodeIndex::new(2), 0.0, None, contact);
+/// assert_eq!(res.unwrap(), vec![NodeIndex::new(1), NodeIndex::new(3)]);
+/// ```
+pub fn temporal_reachability<G, F, K, E>(
+    graph: G,
+    source: G::NodeId,
+    start_time: K,
+    end_time: Option<K>,
+    contact_fn: F,
+) -> Result<Vec<G::NodeId>, E>
+where
+    G: IntoEdgeReferences + NodeIndexable + GraphProp,
+    G::NodeId: Eq + Hash,
+    F: FnMut(G::EdgeRef) -> Result<Option<(K, K)>, E>,
+    K: Measure + Copy,
+{
+    let arrivals = earliest_arrival(graph, source, start_time, end_time, contact_fn, None)?;
+    Ok(arrivals
+        .keys()
+        .copied()
+        .filter(|node| *node != source)
+        .collect())
+}
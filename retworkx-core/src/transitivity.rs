@@ -0,0 +1,286 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+//! Module for triangle counting and clustering coefficients.
+//!
+//! The triangles are counted with the degree ordered algorithm [1]: the
+//! nodes are ranked by degree, every node only keeps its neighbors of
+//! higher rank, and every triangle is found once from its lowest ranked
+//! node by intersecting the kept neighbors of the node and of each of its
+//! kept neighbors. That takes `O(m^1.5)` time for `m` edges, and avoids the
+//! quadratic cost of intersecting the neighbors of high degree nodes with
+//! each other. The work is split by node over multiple threads if the graph
+//! is large enough and the `parallel` feature is enabled. Self loops and
+//! parallel edges are ignored.
+//!
+//! [1] T. Schank and D. Wagner, "Finding, Counting and Listing All
+//! Triangles in Large Graphs, an Experimental Study", WEA 2005, pp. 606-609,
+//! <https://doi.org/10.1007/11427186_54>
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use petgraph::visit::{GraphProp, IntoNeighborsDirected, IntoNodeIdentifiers, NodeIndexable};
+use petgraph::{Outgoing, Undirected};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+use crate::neighbors::{intersection_len, SortedNeighbors};
+
+/// The neighbors of every node by node index, and for every node its
+/// neighbors ranked higher by `(degree, index)`, both sorted.
+struct RankedNeighbors {
+    nodes: Vec<usize>,
+    neighbors: SortedNeighbors,
+    higher: Vec<Vec<usize>>,
+}
+
+impl RankedNeighbors {
+    fn new<G>(graph: G) -> Self
+    where
+        G: IntoNeighborsDirected + IntoNodeIdentifiers + NodeIndexable,
+    {
+        let nodes: Vec<usize> = graph
+            .node_identifiers()
+            .map(|node| graph.to_index(node))
+            .collect();
+        let neighbors = SortedNeighbors::new(graph, Outgoing);
+        let rank = |node: usize| (neighbors.get(node).len(), node);
+        let higher = (0..graph.node_bound())
+            .map(|node| {
+                neighbors
+                    .get(node)
+                    .iter()
+                    .copied()
+                    .filter(|&neighbor| rank(neighbor) > rank(node))
+                    .collect()
+            })
+            .collect();
+        RankedNeighbors {
+            nodes,
+            neighbors,
+            higher,
+        }
+    }
+
+    fn degree(&self, node: usize) -> usize {
+        self.neighbors.get(node).len()
+    }
+
+    /// Call `f` with the 2 other nodes of every triangle whose lowest ranked
+    /// node is `node`.
+    fn for_each_triangle<F: FnMut(usize, usize)>(&self, node: usize, mut f: F) {
+        let higher = &self.higher[node];
+        for &second in higher {
+            let second_higher = &self.higher[second];
+            let (mut i, mut j) = (0, 0);
+            while i < higher.len() && j < second_higher.len() {
+                if higher[i] < second_higher[j] {
+                    i += 1;
+                } else if higher[i] > second_higher[j] {
+                    j += 1;
+                } else {
+                    f(second, higher[i]);
+                    i += 1;
+                    j += 1;
+                }
+            }
+        }
+    }
+
+    /// The number of triangles whose lowest ranked node is `node`.
+    fn count_triangles(&self, node: usize) -> usize {
+        self.higher[node]
+            .iter()
+            .map(|&second| intersection_len(&self.higher[node], &self.higher[second]))
+            .sum()
+    }
+
+    /// The number of triangles every node is in, by node index.
+    fn triangles(&self, parallel_threshold: usize) -> Vec<usize> {
+        let counts: Vec<AtomicUsize> = (0..self.higher.len())
+            .map(|_| AtomicUsize::new(0))
+            .collect();
+        let count_from = |&node: &usize| {
+            let mut found = 0;
+            self.for_each_triangle(node, |second, third| {
+                found += 1;
+                counts[second].fetch_add(1, Ordering::Relaxed);
+                counts[third].fetch_add(1, Ordering::Relaxed);
+            });
+            counts[node].fetch_add(found, Ordering::Relaxed);
+        };
+        #[cfg(feature = "parallel")]
+        if self.nodes.len() >= parallel_threshold {
+            self.nodes.par_iter().for_each(count_from);
+            return counts.into_iter().map(AtomicUsize::into_inner).collect();
+        }
+        #[cfg(not(feature = "parallel"))]
+        let _ = parallel_threshold;
+        self.nodes.iter().for_each(count_from);
+        counts.into_iter().map(AtomicUsize::into_inner).collect()
+    }
+}
+
+/// Count the triangles every node of an undirected graph is in.
+///
+/// Arguments:
+///
+/// * `graph` - The undirected graph to count the triangles of
+/// * `parallel_threshold` - The number of nodes to count the triangles of
+///   in parallel at, if the `parallel` feature is enabled
+///
+/// Returns the number of triangles of every node as a [`Vec`] indexed by
+/// node index, `None` for an index without a node.
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::transitivity::triangles;
+///
+/// // 2 triangles sharing an edge
+/// let graph = petgraph::graph::UnGraph::<(), ()>::from_edges(&[
+///     (0, 1), (1, 2), (2, 0), (1, 3), (2, 3),
+/// ]);
+/// assert_eq!(triangles(&graph, 100), vec![Some(1), Some(2), Some(2), Some(1)]);
+/// ```
+pub fn triangles<G>(graph: G, parallel_threshold: usize) -> Vec<Option<usize>>
+where
+    G: IntoNeighborsDirected
+        + IntoNodeIdentifiers
+        + NodeIndexable
+        + GraphProp<EdgeType = Undirected>,
+{
+    let ranked = RankedNeighbors::new(graph);
+    let counts = ranked.triangles(parallel_threshold);
+    let mut out = vec![None; graph.node_bound()];
+    for &node in &ranked.nodes {
+        out[node] = Some(counts[node]);
+    }
+    out
+}
+
+/// Compute the local clustering coefficient of every node of an undirected
+/// graph.
+///
+/// The local clustering coefficient of a node is the fraction of the pairs
+/// of its neighbors which are neighbors of each other:
+///
+/// ```text
+/// c(v) = 2 T(v) / (d(v) (d(v) - 1))
+/// ```
+///
+/// where `T(v)` is the number of triangles of `v` and `d(v)` its number of
+/// neighbors. It's 0 for a node with less than 2 neighbors.
+///
+/// Arguments:
+///
+/// * `graph` - The undirected graph to compute the coefficients of
+/// * `parallel_threshold` - The number of nodes to count the triangles of
+///   in parallel at, if the `parallel` feature is enabled
+///
+/// Returns the coefficient of every node as a [`Vec`] indexed by node
+/// index, `None` for an index without a node.
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::transitivity::local_clustering_coefficient;
+///
+/// // 2 triangles sharing an edge
+/// let graph = petgraph::graph::UnGraph::<(), ()>::from_edges(&[
+///     (0, 1), (1, 2), (2, 0), (1, 3), (2, 3),
+/// ]);
+/// let coefficients = local_clustering_coefficient(&graph, 100);
+/// assert_eq!(coefficients, vec![Some(1.), Some(2. / 3.), Some(2. / 3.), Some(1.)]);
+/// ```
+pub fn local_clustering_coefficient<G>(graph: G, parallel_threshold: usize) -> Vec<Option<f64>>
+where
+    G: IntoNeighborsDirected
+        + IntoNodeIdentifiers
+        + NodeIndexable
+        + GraphProp<EdgeType = Undirected>,
+{
+    let ranked = RankedNeighbors::new(graph);
+    let counts = ranked.triangles(parallel_threshold);
+    let mut out = vec![None; graph.node_bound()];
+    for &node in &ranked.nodes {
+        let degree = ranked.degree(node);
+        out[node] = Some(if degree < 2 {
+            0.
+        } else {
+            2. * counts[node] as f64 / (degree * (degree - 1)) as f64
+        });
+    }
+    out
+}
+
+/// Compute the transitivity of an undirected graph.
+///
+/// The transitivity is the fraction of the connected triples of nodes, a
+/// node with edges to 2 other nodes, which are closed into a triangle:
+///
+/// ```text
+/// t = 3 * number of triangles / number of connected triples
+/// ```
+///
+/// It's 0 for a graph without triangles.
+///
+/// Arguments:
+///
+/// * `graph` - The undirected graph to compute the transitivity of
+/// * `parallel_threshold` - The number of nodes to count the triangles of
+///   in parallel at, if the `parallel` feature is enabled
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::transitivity::transitivity;
+///
+/// // 2 triangles sharing an edge
+/// let graph = petgraph::graph::UnGraph::<(), ()>::from_edges(&[
+///     (0, 1), (1, 2), (2, 0), (1, 3), (2, 3),
+/// ]);
+/// assert_eq!(transitivity(&graph, 100), 0.75);
+/// ```
+pub fn transitivity<G>(graph: G, parallel_threshold: usize) -> f64
+where
+    G: IntoNeighborsDirected
+        + IntoNodeIdentifiers
+        + NodeIndexable
+        + GraphProp<EdgeType = Undirected>,
+{
+    let ranked = RankedNeighbors::new(graph);
+    let count = |&node: &usize| ranked.count_triangles(node);
+    #[cfg(feature = "parallel")]
+    let triangles: usize = if ranked.nodes.len() >= parallel_threshold {
+        ranked.nodes.par_iter().map(count).sum()
+    } else {
+        ranked.nodes.iter().map(count).sum()
+    };
+    #[cfg(not(feature = "parallel"))]
+    let triangles: usize = {
+        let _ = parallel_threshold;
+        ranked.nodes.iter().map(count).sum()
+    };
+    if triangles == 0 {
+        return 0.;
+    }
+    let triples: usize = ranked
+        .nodes
+        .iter()
+        .map(|&node| {
+            let degree = ranked.degree(node);
+            degree * degree.saturating_sub(1) / 2
+        })
+        .sum();
+    3. * triangles as f64 / triples as f64
+}
@@ -10,7 +10,7 @@
 // License for the specific language governing permissions and limitations
 // under the License.
 
-use petgraph::visit::{ControlFlow, EdgeRef, IntoEdges, VisitMap, Visitable};
+use petgraph::visit::{Control, ControlFlow, EdgeRef, IntoEdges, VisitMap, Visitable};
 use std::collections::VecDeque;
 
 use super::try_control;
@@ -245,3 +245,103 @@ where
 
     C::continuing()
 }
+
+/// A visitor of a [`breadth_first_search`] with a method for each kind of
+/// [`BfsEvent`].
+///
+/// Every method continues the search by default, so a visitor only needs to
+/// implement the methods of the events it handles. [`BfsVisitor::visit`]
+/// calls the method matching an event, so a visitor (or a trait object
+/// `&mut dyn BfsVisitor`) is used by passing `|event| visitor.visit(event)`
+/// as the `visitor` callback of [`breadth_first_search`]. Returning
+/// [`Control::Prune`] or [`Control::Break`] from a method prunes or stops the
+/// search like returning it from the callback does.
+///
+/// # Example
+///
+/// Find the distance from vertex 0 to 5, and exit the visit as soon as we
+/// reach the goal vertex.
+///
+/// ```
+/// use retworkx_core::petgraph::prelude::*;
+/// use retworkx_core::petgraph::graph::node_index as n;
+/// use retworkx_core::petgraph::visit::Control;
+///
+/// use retworkx_core::traversal::{BfsVisitor, breadth_first_search};
+///
+/// struct GoalDistance {
+///     goal: NodeIndex,
+///     distance: Vec<usize>,
+/// }
+///
+/// impl BfsVisitor<NodeIndex, &()> for GoalDistance {
+///     type Break = usize;
+///
+///     fn tree_edge(&mut self, u: NodeIndex, v: NodeIndex, _: &()) -> Control<usize> {
+///         self.distance[v.index()] = self.distance[u.index()] + 1;
+///         if v == self.goal {
+///             return Control::Break(self.distance[v.index()]);
+///         }
+///         Control::Continue
+///     }
+/// }
+///
+/// let gr: Graph<(), ()> = Graph::from_edges(&[
+///     (0, 1), (0, 2), (0, 3),
+///     (1, 3),
+///     (2, 3), (2, 4),
+///     (4, 0), (4, 5),
+/// ]);
+///
+/// let mut visitor = GoalDistance { goal: n(5), distance: vec![0; gr.node_count()] };
+/// let visitor: &mut dyn for<'a> BfsVisitor<NodeIndex, &'a (), Break = usize> = &mut visitor;
+/// let res = breadth_first_search(&gr, Some(n(0)), |event| visitor.visit(event));
+/// assert_eq!(res.break_value(), Some(3));
+/// ```
+pub trait BfsVisitor<N, E> {
+    /// The type of the value the search is stopped with.
+    type Break;
+
+    /// Called when a node is discovered.
+    fn discover_vertex(&mut self, _node: N) -> Control<Self::Break> {
+        Control::Continue
+    }
+
+    /// Called with an edge of the tree formed by the traversal.
+    fn tree_edge(&mut self, _source: N, _target: N, _weight: E) -> Control<Self::Break> {
+        Control::Continue
+    }
+
+    /// Called with an edge that doesn't belong to the tree.
+    fn non_tree_edge(&mut self, _source: N, _target: N, _weight: E) -> Control<Self::Break> {
+        Control::Continue
+    }
+
+    /// Called with a non-tree edge whose target is in the queue.
+    fn gray_target_edge(&mut self, _source: N, _target: N, _weight: E) -> Control<Self::Break> {
+        Control::Continue
+    }
+
+    /// Called with a non-tree edge whose target has been removed from the
+    /// queue.
+    fn black_target_edge(&mut self, _source: N, _target: N, _weight: E) -> Control<Self::Break> {
+        Control::Continue
+    }
+
+    /// Called when all the edges from a node have been reported.
+    fn finish_vertex(&mut self, _node: N) -> Control<Self::Break> {
+        Control::Continue
+    }
+
+    /// Call the method matching `event`.
+    fn visit(&mut self, event: BfsEvent<N, E>) -> Control<Self::Break> {
+        match event {
+            BfsEvent::Discover(node) => self.discover_vertex(node),
+            BfsEvent::TreeEdge(u, v, weight) => self.tree_edge(u, v, weight),
+            BfsEvent::NonTreeEdge(u, v, weight) => self.non_tree_edge(u, v, weight),
+            BfsEvent::GrayTargetEdge(u, v, weight) => self.gray_target_edge(u, v, weight),
+            BfsEvent::BlackTargetEdge(u, v, weight) => self.black_target_edge(u, v, weight),
+            BfsEvent::Finish(node) => self.finish_vertex(node),
+        }
+    }
+}
@@ -0,0 +1,204 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use std::hash::Hash;
+
+use hashbrown::HashSet;
+
+use petgraph::visit::{IntoNeighbors, NodeIndexable};
+
+/// Reusable state to expand the neighborhoods of nodes one hop at a time.
+///
+/// Expanding the neighborhood of a node visits the frontier of nodes at each
+/// distance from it in turn, finding each frontier from the neighbors of the
+/// previous one. The marks of the visited nodes and the frontiers are kept
+/// between expansions, and a new expansion only needs a new mark value
+/// instead of clearing them. So expanding the neighborhoods of many nodes of
+/// a large sparse graph takes time proportional to the sizes of the
+/// neighborhoods, and not to the number of nodes for each expansion.
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::petgraph::graph::NodeIndex;
+/// use retworkx_core::traversal::NeighborhoodExpansion;
+///
+/// let graph = petgraph::graph::UnGraph::<(), ()>::from_edges(&[
+///     (0, 1), (1, 2), (2, 3), (3, 4)
+/// ]);
+/// let mut expansion = NeighborhoodExpansion::new();
+/// let mut found = Vec::new();
+/// expansion.expand(&graph, NodeIndex::new(2), 2, |node, depth| {
+///     found.push((node.index(), depth));
+/// });
+/// found.sort_unstable();
+/// assert_eq!(found, vec![(0, 2), (1, 1), (2, 0), (3, 1), (4, 2)]);
+///
+/// // The same state is reused for the next node
+/// let mut count = 0;
+/// expansion.expand(&graph, NodeIndex::new(0), 1, |_, _| count += 1);
+/// assert_eq!(count, 2);
+/// ```
+#[derive(Default)]
+pub struct NeighborhoodExpansion {
+    /// the mark of the last expansion which visited each node, by node index
+    marks: Vec<usize>,
+    mark: usize,
+    frontier: Vec<usize>,
+    next_frontier: Vec<usize>,
+}
+
+impl NeighborhoodExpansion {
+    /// Create a new `NeighborhoodExpansion`, its memory is allocated by the
+    /// first expansion.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Call `visit` with every node within `max_depth` hops of `source` and
+    /// its distance from `source`.
+    ///
+    /// The nodes are visited in breadth-first order, starting with `source`
+    /// itself at distance 0. For a directed graph the edges are followed in
+    /// their direction.
+    pub fn expand<G, F>(&mut self, graph: G, source: G::NodeId, max_depth: usize, mut visit: F)
+    where
+        G: IntoNeighbors + NodeIndexable,
+        F: FnMut(G::NodeId, usize),
+    {
+        if self.marks.len() < graph.node_bound() {
+            self.marks.resize(graph.node_bound(), 0);
+        }
+        self.mark += 1;
+        let source_index = graph.to_index(source);
+        self.marks[source_index] = self.mark;
+        visit(source, 0);
+        self.frontier.clear();
+        self.frontier.push(source_index);
+        let mut depth = 0;
+        while depth < max_depth && !self.frontier.is_empty() {
+            depth += 1;
+            self.next_frontier.clear();
+            for &index in &self.frontier {
+                for next in graph.neighbors(graph.from_index(index)) {
+                    let next_index = graph.to_index(next);
+                    if self.marks[next_index] != self.mark {
+                        self.marks[next_index] = self.mark;
+                        visit(next, depth);
+                        self.next_frontier.push(next_index);
+                    }
+                }
+            }
+            std::mem::swap(&mut self.frontier, &mut self.next_frontier);
+        }
+    }
+}
+
+/// Return the nodes at a given distance from a source node.
+///
+/// The distance between two nodes is the number of edges on a shortest path
+/// between them, so this returns the nodes whose shortest path from `source`
+/// has exactly `distance` edges. A breadth-first search is used which stops
+/// at `distance` hops from `source`, so only the nodes within that distance
+/// are visited.
+///
+/// For a directed graph the edges are followed in their direction, so this
+/// returns descendants of `source`. To get the ancestors at a distance
+/// instead, pass the graph wrapped in [`petgraph::visit::Reversed`].
+///
+/// Arguments:
+///
+/// * `graph` - The graph to search.
+/// * `source` - The node to start from.
+/// * `distance` - The number of hops from `source` of the returned nodes.
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::petgraph::graph::NodeIndex;
+/// use retworkx_core::petgraph::visit::Reversed;
+/// use retworkx_core::traversal::descendants_at_distance;
+///
+/// let graph = petgraph::graph::DiGraph::<(), ()>::from_edges(&[
+///     (0, 1), (0, 2), (1, 3), (2, 3), (3, 4)
+/// ]);
+/// let nodes = descendants_at_distance(&graph, NodeIndex::new(0), 2);
+/// assert_eq!(nodes.len(), 1);
+/// assert!(nodes.contains(&NodeIndex::new(3)));
+///
+/// // The ancestors of node 3 at distance 1
+/// let nodes = descendants_at_distance(Reversed(&graph), NodeIndex::new(3), 1);
+/// let mut indices: Vec<usize> = nodes.iter().map(|n| n.index()).collect();
+/// indices.sort_unstable();
+/// assert_eq!(indices, vec![1, 2]);
+/// ```
+pub fn descendants_at_distance<G>(
+    graph: G,
+    source: G::NodeId,
+    distance: usize,
+) -> HashSet<G::NodeId>
+where
+    G: IntoNeighbors + NodeIndexable,
+    G::NodeId: Eq + Hash,
+{
+    let mut nodes = HashSet::new();
+    NeighborhoodExpansion::new().expand(graph, source, distance, |node, depth| {
+        if depth == distance {
+            nodes.insert(node);
+        }
+    });
+    nodes
+}
+
+/// Return the nodes reachable from a source node within a maximum number of
+/// hops.
+///
+/// This returns every node with a path from `source` of at most `max_depth`
+/// edges, including `source` itself. A breadth-first search is used which
+/// stops at `max_depth` hops from `source`, so only the returned nodes are
+/// visited.
+///
+/// For a directed graph the edges are followed in their direction. To
+/// search along the incoming edges instead, pass the graph wrapped in
+/// [`petgraph::visit::Reversed`].
+///
+/// Arguments:
+///
+/// * `graph` - The graph to search.
+/// * `source` - The node to start from.
+/// * `max_depth` - The maximum number of hops from `source`.
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::petgraph::graph::NodeIndex;
+/// use retworkx_core::traversal::reachable_within;
+///
+/// let graph = petgraph::graph::UnGraph::<(), ()>::from_edges(&[
+///     (0, 1), (1, 2), (2, 3), (3, 4)
+/// ]);
+/// let nodes = reachable_within(&graph, NodeIndex::new(2), 1);
+/// let mut indices: Vec<usize> = nodes.iter().map(|n| n.index()).collect();
+/// indices.sort_unstable();
+/// assert_eq!(indices, vec![1, 2, 3]);
+/// ```
+pub fn reachable_within<G>(graph: G, source: G::NodeId, max_depth: usize) -> HashSet<G::NodeId>
+where
+    G: IntoNeighbors + NodeIndexable,
+    G::NodeId: Eq + Hash,
+{
+    let mut nodes = HashSet::new();
+    NeighborhoodExpansion::new().expand(graph, source, max_depth, |node, _| {
+        nodes.insert(node);
+    });
+    nodes
+}
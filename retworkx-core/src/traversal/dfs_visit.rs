@@ -14,7 +14,7 @@
 // ``depth_first_search`` function.
 // https://github.com/petgraph/petgraph/blob/0.6.0/src/visit/dfsvisit.rs
 
-use petgraph::visit::{ControlFlow, EdgeRef, IntoEdges, Time, VisitMap, Visitable};
+use petgraph::visit::{Control, ControlFlow, EdgeRef, IntoEdges, Time, VisitMap, Visitable};
 
 use super::try_control;
 
@@ -246,3 +246,102 @@ fn time_post_inc(x: &mut Time) -> Time {
     x.0 += 1;
     v
 }
+
+/// A visitor of a [`depth_first_search`] with a method for each kind of
+/// [`DfsEvent`].
+///
+/// Every method continues the search by default, so a visitor only needs to
+/// implement the methods of the events it handles. [`DfsVisitor::visit`]
+/// calls the method matching an event, so a visitor (or a trait object
+/// `&mut dyn DfsVisitor`) is used by passing `|event| visitor.visit(event)`
+/// as the `visitor` callback of [`depth_first_search`]. Returning
+/// [`Control::Prune`] or [`Control::Break`] from a method prunes or stops the
+/// search like returning it from the callback does.
+///
+/// # Example
+///
+/// Record the finish order of the nodes, and stop the search at the first
+/// back edge.
+///
+/// ```
+/// use retworkx_core::petgraph::prelude::*;
+/// use retworkx_core::petgraph::graph::node_index as n;
+/// use retworkx_core::petgraph::visit::{Control, Time};
+///
+/// use retworkx_core::traversal::{DfsVisitor, depth_first_search};
+///
+/// #[derive(Default)]
+/// struct Finished {
+///     order: Vec<NodeIndex>,
+/// }
+///
+/// impl DfsVisitor<NodeIndex, &()> for Finished {
+///     type Break = (NodeIndex, NodeIndex);
+///
+///     fn back_edge(&mut self, u: NodeIndex, v: NodeIndex, _: &()) -> Control<Self::Break> {
+///         Control::Break((u, v))
+///     }
+///
+///     fn finish_vertex(&mut self, node: NodeIndex, _: Time) -> Control<Self::Break> {
+///         self.order.push(node);
+///         Control::Continue
+///     }
+/// }
+///
+/// let dag: Graph<(), ()> = Graph::from_edges(&[(0, 1), (1, 2), (0, 2)]);
+/// let mut visitor = Finished::default();
+/// let res = depth_first_search(&dag, Some(n(0)), |event| visitor.visit(event));
+/// assert_eq!(res.break_value(), None);
+/// assert_eq!(visitor.order, vec![n(2), n(1), n(0)]);
+///
+/// let cycle: Graph<(), ()> = Graph::from_edges(&[(0, 1), (1, 2), (2, 0)]);
+/// let mut visitor = Finished::default();
+/// let res = depth_first_search(&cycle, Some(n(0)), |event| visitor.visit(event));
+/// assert_eq!(res.break_value(), Some((n(2), n(0))));
+/// ```
+pub trait DfsVisitor<N, E> {
+    /// The type of the value the search is stopped with.
+    type Break;
+
+    /// Called when a node is discovered, with its discovery time.
+    fn discover_vertex(&mut self, _node: N, _time: Time) -> Control<Self::Break> {
+        Control::Continue
+    }
+
+    /// Called with an edge of the tree formed by the traversal.
+    fn tree_edge(&mut self, _source: N, _target: N, _weight: E) -> Control<Self::Break> {
+        Control::Continue
+    }
+
+    /// Called with an edge to an ancestor in the tree.
+    fn back_edge(&mut self, _source: N, _target: N, _weight: E) -> Control<Self::Break> {
+        Control::Continue
+    }
+
+    /// Called with a cross or forward edge.
+    fn forward_or_cross_edge(
+        &mut self,
+        _source: N,
+        _target: N,
+        _weight: E,
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+
+    /// Called when all the edges from a node have been reported, with its
+    /// finish time.
+    fn finish_vertex(&mut self, _node: N, _time: Time) -> Control<Self::Break> {
+        Control::Continue
+    }
+
+    /// Call the method matching `event`.
+    fn visit(&mut self, event: DfsEvent<N, E>) -> Control<Self::Break> {
+        match event {
+            DfsEvent::Discover(node, time) => self.discover_vertex(node, time),
+            DfsEvent::TreeEdge(u, v, weight) => self.tree_edge(u, v, weight),
+            DfsEvent::BackEdge(u, v, weight) => self.back_edge(u, v, weight),
+            DfsEvent::CrossForwardEdge(u, v, weight) => self.forward_or_cross_edge(u, v, weight),
+            DfsEvent::Finish(node, time) => self.finish_vertex(node, time),
+        }
+    }
+}
@@ -24,7 +24,7 @@ use hashbrown::hash_map::Entry::{Occupied, Vacant};
 use hashbrown::HashMap;
 
 use petgraph::algo::Measure;
-use petgraph::visit::{ControlFlow, EdgeRef, IntoEdges, VisitMap, Visitable};
+use petgraph::visit::{Control, ControlFlow, EdgeRef, IntoEdges, VisitMap, Visitable};
 
 use crate::min_scored::MinScored;
 
@@ -183,7 +183,7 @@ where
     G::NodeId: Eq + Hash,
     I: IntoIterator<Item = G::NodeId>,
     F: FnMut(G::EdgeRef) -> Result<K, E>,
-    K: Measure + Copy,
+    K: Measure,
     H: FnMut(DijkstraEvent<G::NodeId, &G::EdgeWeight, K>) -> C,
     C: ControlFlow,
 {
@@ -212,7 +212,7 @@ where
     G: IntoEdges + Visitable,
     G::NodeId: Eq + Hash,
     F: FnMut(G::EdgeRef) -> Result<K, E>,
-    K: Measure + Copy,
+    K: Measure,
     V: FnMut(DijkstraEvent<G::NodeId, &G::EdgeWeight, K>) -> C,
     C: ControlFlow,
 {
@@ -223,7 +223,7 @@ where
     let mut scores = HashMap::new();
     let mut visit_next = BinaryHeap::new();
     let zero_score = K::default();
-    scores.insert(start, zero_score);
+    scores.insert(start, zero_score.clone());
     visit_next.push(MinScored(zero_score, start));
 
     while let Some(MinScored(node_score, node)) = visit_next.pop() {
@@ -231,7 +231,10 @@ where
             continue;
         }
 
-        try_control_with_result!(visitor(DijkstraEvent::Discover(node, node_score)), continue);
+        try_control_with_result!(
+            visitor(DijkstraEvent::Discover(node, node_score.clone())),
+            continue
+        );
 
         for edge in graph.edges(node) {
             let next = edge.target();
@@ -245,7 +248,7 @@ where
             }
 
             let cost = edge_cost(edge)?;
-            let next_score = node_score + cost;
+            let next_score = node_score.clone() + cost;
             match scores.entry(next) {
                 Occupied(ent) => {
                     if next_score < *ent.get() {
@@ -253,7 +256,7 @@ where
                             visitor(DijkstraEvent::EdgeRelaxed(node, next, edge.weight())),
                             continue
                         );
-                        *ent.into_mut() = next_score;
+                        *ent.into_mut() = next_score.clone();
                         visit_next.push(MinScored(next_score, next));
                     } else {
                         try_control_with_result!(
@@ -267,7 +270,7 @@ where
                         visitor(DijkstraEvent::EdgeRelaxed(node, next, edge.weight())),
                         continue
                     );
-                    ent.insert(next_score);
+                    ent.insert(next_score.clone());
                     visit_next.push(MinScored(next_score, next));
                 }
             }
@@ -281,3 +284,127 @@ where
 
     Ok(C::continuing())
 }
+
+/// A visitor of a [`dijkstra_search`] with a method for each kind of
+/// [`DijkstraEvent`].
+///
+/// Every method continues the search by default, so a visitor only needs to
+/// implement the methods of the events it handles. [`DijkstraVisitor::visit`]
+/// calls the method matching an event, so a visitor (or a trait object
+/// `&mut dyn DijkstraVisitor`) is used by passing `|event| visitor.visit(event)`
+/// as the `visitor` callback of [`dijkstra_search`]. Returning
+/// [`Control::Prune`] or [`Control::Break`] from a method prunes or stops the
+/// search like returning it from the callback does.
+///
+/// # Example
+///
+/// Record the predecessors of every node on its shortest paths from vertex 0,
+/// forming the shortest path DAG, and exit the visit with the distance of the
+/// goal vertex as soon as it's reached.
+///
+/// ```
+/// use retworkx_core::petgraph::prelude::*;
+/// use retworkx_core::petgraph::graph::node_index as n;
+/// use retworkx_core::petgraph::visit::Control;
+///
+/// use retworkx_core::traversal::{DijkstraVisitor, dijkstra_search};
+///
+/// struct ShortestPathDag {
+///     goal: NodeIndex,
+///     distance: Vec<Option<usize>>,
+///     predecessors: Vec<Vec<NodeIndex>>,
+/// }
+///
+/// impl DijkstraVisitor<NodeIndex, &usize, usize> for ShortestPathDag {
+///     type Break = usize;
+///
+///     fn discover_vertex(&mut self, node: NodeIndex, score: usize) -> Control<usize> {
+///         if node == self.goal {
+///             return Control::Break(score);
+///         }
+///         Control::Continue
+///     }
+///
+///     fn edge_relaxed(&mut self, u: NodeIndex, v: NodeIndex, w: &usize) -> Control<usize> {
+///         self.distance[v.index()] = Some(self.distance[u.index()].unwrap() + w);
+///         self.predecessors[v.index()] = vec![u];
+///         Control::Continue
+///     }
+///
+///     fn edge_not_relaxed(&mut self, u: NodeIndex, v: NodeIndex, w: &usize) -> Control<usize> {
+///         // Keep every predecessor on a shortest path to `v`
+///         if self.distance[v.index()] == Some(self.distance[u.index()].unwrap() + w) {
+///             self.predecessors[v.index()].push(u);
+///         }
+///         Control::Continue
+///     }
+/// }
+///
+/// let gr: Graph<(), usize> = Graph::from_edges(&[
+///     (0, 1, 1), (0, 2, 1),
+///     (1, 3, 1), (2, 3, 1),
+///     (3, 4, 1), (0, 4, 5),
+/// ]);
+///
+/// let mut distance = vec![None; gr.node_count()];
+/// distance[0] = Some(0);
+/// let mut visitor = ShortestPathDag {
+///     goal: n(4),
+///     distance,
+///     predecessors: vec![Vec::new(); gr.node_count()],
+/// };
+/// let res: Result<Control<usize>, ()> = dijkstra_search(
+///     &gr,
+///     Some(n(0)),
+///     |edge| Ok(*edge.weight()),
+///     |event| visitor.visit(event),
+/// );
+/// assert_eq!(res.unwrap().break_value(), Some(3));
+///
+/// let mut preds = visitor.predecessors[3].clone();
+/// preds.sort();
+/// assert_eq!(preds, vec![n(1), n(2)]);
+/// assert_eq!(visitor.predecessors[4], vec![n(3)]);
+/// ```
+pub trait DijkstraVisitor<N, E, K> {
+    /// The type of the value the search is stopped with.
+    type Break;
+
+    /// Called when a node is popped from the queue for the first time, with
+    /// its optimal distance.
+    fn discover_vertex(&mut self, _node: N, _score: K) -> Control<Self::Break> {
+        Control::Continue
+    }
+
+    /// Called with every out-edge of a node after it's discovered.
+    fn examine_edge(&mut self, _source: N, _target: N, _weight: E) -> Control<Self::Break> {
+        Control::Continue
+    }
+
+    /// Called with an examined edge which reduces the distance of its target.
+    fn edge_relaxed(&mut self, _source: N, _target: N, _weight: E) -> Control<Self::Break> {
+        Control::Continue
+    }
+
+    /// Called with an examined edge which doesn't reduce the distance of its
+    /// undiscovered target.
+    fn edge_not_relaxed(&mut self, _source: N, _target: N, _weight: E) -> Control<Self::Break> {
+        Control::Continue
+    }
+
+    /// Called when all the edges from a node have been reported.
+    fn finish_vertex(&mut self, _node: N) -> Control<Self::Break> {
+        Control::Continue
+    }
+
+    /// Call the method matching `event`.
+    fn visit(&mut self, event: DijkstraEvent<N, E, K>) -> Control<Self::Break> {
+        match event {
+            DijkstraEvent::Discover(node, score) => self.discover_vertex(node, score),
+            DijkstraEvent::ExamineEdge(u, v, weight) => self.examine_edge(u, v, weight),
+            DijkstraEvent::EdgeRelaxed(u, v, weight) => self.edge_relaxed(u, v, weight),
+            DijkstraEvent::EdgeNotRelaxed(u, v, weight) => self.edge_not_relaxed(u, v, weight),
+            DijkstraEvent::Finish(node) => self.finish_vertex(node),
+        }
+    }
+}
@@ -13,14 +13,16 @@
 //! Module for graph traversal algorithms.
 
 mod bfs_visit;
+mod bounded_bfs;
 mod dfs_edges;
 mod dfs_visit;
 mod dijkstra_visit;
 
-pub use bfs_visit::{breadth_first_search, BfsEvent};
+pub use bfs_visit::{breadth_first_search, BfsEvent, BfsVisitor};
+pub use bounded_bfs::{descendants_at_distance, reachable_within, NeighborhoodExpansion};
 pub use dfs_edges::dfs_edges;
-pub use dfs_visit::{depth_first_search, DfsEvent};
-pub use dijkstra_visit::{dijkstra_search, DijkstraEvent};
+pub use dfs_visit::{depth_first_search, DfsEvent, DfsVisitor};
+pub use dijkstra_visit::{dijkstra_search, DijkstraEvent, DijkstraVisitor};
 
 /// Return if the expression is a break value, execute the provided statement
 /// if it is a prune value.
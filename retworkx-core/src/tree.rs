@@ -0,0 +1,88 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use std::cmp::Ordering;
+
+use petgraph::unionfind::UnionFind;
+use petgraph::visit::{EdgeRef, IntoEdgeReferences, NodeIndexable};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// Find the edges in the minimum spanning tree or forest of a graph using
+/// Kruskal's algorithm.
+///
+/// The edges are sorted by weight with a stable sort, so edges with equal
+/// weights are considered in the order they're returned by
+/// [`IntoEdgeReferences::edge_references`]. The output is therefore
+/// deterministic as long as the weights compare exactly. If the weights are
+/// floats which are only equal up to rounding the output can depend on the
+/// platform, in that case an exact weight type (like an integer, an
+/// arbitrary precision integer or a rational type) can be used instead.
+///
+/// Arguments:
+///
+/// * `graph` - The undirected graph to find the minimum spanning tree of
+/// * `weight_fn` - A callable that receives an edge and returns its weight.
+///   The weights need to be comparable, the order of incomparable weights
+///   (like a float NaN) is unspecified.
+///
+/// Returns a [`Vec`] with the edges of the minimum spanning tree (or forest
+/// if the graph isn't connected) in the order they were added to it.
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::petgraph::visit::EdgeRef;
+/// use retworkx_core::tree::minimum_spanning_edges;
+/// use retworkx_core::Result;
+///
+/// let g = petgraph::graph::UnGraph::<(), u64>::from_edges(&[
+///     (0, 1, 3), (0, 2, 1), (1, 2, 1), (2, 3, 2), (1, 3, 2)
+/// ]);
+/// let res: Result<Vec<_>> = minimum_spanning_edges(&g, |e| Ok(*e.weight()));
+/// let edges: Vec<(usize, usize)> = res
+///     .unwrap()
+///     .iter()
+///     .map(|e| (e.source().index(), e.target().index()))
+///     .collect();
+/// // Ties are broken by the order of the edges in the graph
+/// assert_eq!(edges, vec![(0, 2), (1, 2), (2, 3)]);
+/// ```
+pub fn minimum_spanning_edges<G, F, K, E>(graph: G, mut weight_fn: F) -> Result<Vec<G::EdgeRef>, E>
+where
+    G: IntoEdgeReferences + NodeIndexable,
+    G::EdgeRef: Send,
+    F: FnMut(G::EdgeRef) -> Result<K, E>,
+    K: PartialOrd + Send,
+{
+    let mut subgraphs = UnionFind::<usize>::new(graph.node_bound());
+    let mut edge_list: Vec<(K, G::EdgeRef)> = Vec::new();
+    for edge in graph.edge_references() {
+        edge_list.push((weight_fn(edge)?, edge));
+    }
+    let compare =
+        |a: &(K, G::EdgeRef), b: &(K, G::EdgeRef)| a.0.partial_cmp(&b.0).unwrap_or(Ordering::Equal);
+    #[cfg(feature = "parallel")]
+    edge_list.par_sort_by(compare);
+    #[cfg(not(feature = "parallel"))]
+    edge_list.sort_by(compare);
+
+    let mut answer = Vec::new();
+    for (_, edge) in edge_list {
+        let u = graph.to_index(edge.source());
+        let v = graph.to_index(edge.target());
+        if subgraphs.union(u, v) {
+            answer.push(edge);
+        }
+    }
+    Ok(answer)
+}
@@ -0,0 +1,344 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+//! Module for traveling salesperson heuristics.
+//!
+//! The functions in this module build a tour visiting every node of a graph
+//! once and returning to the first one. The cost of going from a node to
+//! another is the length of the shortest path between them, so a graph
+//! which isn't complete is handled as its metric closure and a tour can go
+//! through a node more than once in the graph itself. Finding the shortest
+//! tour is NP-hard, these heuristics build good but not optimal tours
+//! quickly.
+
+use std::hash::Hash;
+
+use petgraph::graph::{NodeIndex, UnGraph};
+use petgraph::visit::{GraphProp, IntoEdges, IntoNodeIdentifiers, NodeIndexable, Visitable};
+use petgraph::Undirected;
+
+use crate::dictmap::*;
+use crate::max_weight_matching::max_weight_matching;
+use crate::shortest_path::dijkstra;
+
+/// A tour built by [`greedy_tsp`] or [`christofides`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct Tour<N> {
+    /// The nodes in the order they're visited, every node of the graph
+    /// once. The tour returns from the last node to the first one.
+    pub nodes: Vec<N>,
+    /// The total length of the shortest paths between consecutive nodes of
+    /// the tour, including the path from the last node back to the first.
+    pub cost: f64,
+}
+
+/// The shortest path lengths from `source` to all of `nodes`.
+fn distances_from<G, F, E>(
+    graph: G,
+    source: G::NodeId,
+    nodes: &[G::NodeId],
+    edge_cost: &mut F,
+) -> Result<Vec<f64>, E>
+where
+    G: IntoEdges + Visitable + NodeIndexable,
+    G::NodeId: Eq + Hash,
+    F: FnMut(G::EdgeRef) -> Result<f64, E>,
+{
+    let distances: DictMap<G::NodeId, f64> = dijkstra(graph, source, None, edge_cost, None)?;
+    Ok(nodes
+        .iter()
+        .map(|target| *distances.get(target).unwrap_or(&f64::INFINITY))
+        .collect())
+}
+
+/// Build a tour with the nearest neighbor heuristic.
+///
+/// The tour starts at `source` and repeatedly goes to the closest node it
+/// hasn't visited yet, until it has visited every node. Nodes at the same
+/// distance are visited in the order of the node identifiers of the graph.
+/// The tour can be `O(log n)` times longer than the shortest tour for `n`
+/// nodes, but it's often a good starting point for an improvement
+/// heuristic.
+///
+/// Arguments:
+///
+/// * `graph` - The graph to find the tour in, its edge costs must be
+///   non-negative. It can be directed, in which case the tour follows the
+///   direction of the edges.
+/// * `source` - The node the tour starts at, if it's `None` the tour starts
+///   at the first node of the graph
+/// * `edge_cost` - A callable that will be passed an edge reference and
+///   returns the cost of the edge, or an error that is returned immediately
+///
+/// Returns `None` if a node can't be reached from the nodes visited before
+/// it, or if the first node can't be reached from the last one.
+///
+/// # Panics
+///
+/// If `source` isn't a node of `graph`.
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::tsp::greedy_tsp;
+/// use retworkx_core::Result;
+///
+/// // A square with a long diagonal
+/// let graph = petgraph::graph::UnGraph::<(), f64>::from_edges(&[
+///     (0, 1, 1.), (1, 2, 1.), (2, 3, 1.), (3, 0, 1.), (0, 2, 5.),
+/// ]);
+/// let res: Result<_> = greedy_tsp(&graph, None, |e| Ok(*e.weight()));
+/// let tour = res.unwrap().unwrap();
+/// let nodes: Vec<usize> = tour.nodes.iter().map(|node| node.index()).collect();
+/// assert_eq!(nodes, vec![0, 1, 2, 3]);
+/// assert_eq!(tour.cost, 4.);
+/// ```
+pub fn greedy_tsp<G, F, E>(
+    graph: G,
+    source: Option<G::NodeId>,
+    mut edge_cost: F,
+) -> Result<Option<Tour<G::NodeId>>, E>
+where
+    G: IntoEdges + IntoNodeIdentifiers + Visitable + NodeIndexable,
+    G::NodeId: Eq + Hash,
+    F: FnMut(G::EdgeRef) -> Result<f64, E>,
+{
+    let nodes: Vec<G::NodeId> = graph.node_identifiers().collect();
+    if nodes.is_empty() {
+        return Ok(Some(Tour {
+            nodes: Vec::new(),
+            cost: 0.,
+        }));
+    }
+    let mut current = match source {
+        Some(source) => nodes
+            .iter()
+            .position(|node| *node == source)
+            .expect("The source must be a node of the graph"),
+        None => 0,
+    };
+    let first = current;
+    let mut visited = vec![false; nodes.len()];
+    visited[current] = true;
+    let mut order = vec![current];
+    let mut cost = 0.;
+    loop {
+        let distances = distances_from(graph, nodes[current], &nodes, &mut edge_cost)?;
+        let next = (0..nodes.len()).filter(|&node| !visited[node]).fold(
+            None,
+            |best: Option<usize>, node| match best {
+                Some(best) if distances[best] <= distances[node] => Some(best),
+                _ => Some(node),
+            },
+        );
+        let next = next.unwrap_or(first);
+        if !distances[next].is_finite() {
+            return Ok(None);
+        }
+        cost += distances[next];
+        if next == first {
+            break;
+        }
+        visited[next] = true;
+        order.push(next);
+        current = next;
+    }
+    Ok(Some(Tour {
+        nodes: order.into_iter().map(|node| nodes[node]).collect(),
+        cost,
+    }))
+}
+
+/// The scale of the integer weights of the matching of [`christofides`],
+/// large enough for the rounding to be negligible while leaving room for
+/// the sums of the matching algorithm.
+const MATCHING_SCALE: f64 = (1u64 << 52) as f64;
+
+/// Build a tour with the Christofides algorithm.
+///
+/// A minimum spanning tree of the nodes is joined with a minimum weight
+/// perfect matching of the nodes with an odd degree in the tree, computed
+/// with [`max_weight_matching`], into a graph whose nodes all have an even
+/// degree. The tour visits the nodes in the order of an Eulerian circuit of
+/// that graph, skipping the nodes it already visited [1]. As the costs of
+/// the metric closure satisfy the triangle inequality the tour is at most
+/// 1.5 times longer than the shortest tour. The lengths are rounded to
+/// integers with 52 significant bits for the matching, which doesn't change
+/// the bound in practice. This takes `O(n^3)` time for `n` nodes.
+///
+/// Arguments:
+///
+/// * `graph` - The undirected graph to find the tour in, its edge costs
+///   must be non-negative
+/// * `edge_cost` - A callable that will be passed an edge reference and
+///   returns the cost of the edge, or an error that is returned immediately
+///
+/// Returns the tour starting at the first node of the graph, or `None` if
+/// the graph isn't connected.
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::tsp::christofides;
+/// use retworkx_core::Result;
+///
+/// // A path with a shortcut between its ends
+/// let graph = petgraph::graph::UnGraph::<(), f64>::from_edges(&[
+///     (0, 1, 1.), (1, 2, 1.), (2, 3, 1.), (3, 4, 1.), (4, 0, 2.),
+/// ]);
+/// let res: Result<_> = christofides(&graph, |e| Ok(*e.weight()));
+/// let tour = res.unwrap().unwrap();
+/// assert_eq!(tour.nodes.len(), 5);
+/// assert_eq!(tour.cost, 6.);
+/// ```
+///
+/// [1] Christofides, N.
+///     "Worst-case analysis of a new heuristic for the travelling salesman problem"
+///     Report 388, Graduate School of Industrial Administration, CMU (1976).
+///     Reprinted in Operations Research Forum 3 (1), 20 (2022).
+///     <https://doi.org/10.1007/s43069-021-00101-z>
+pub fn christofides<G, F, E>(graph: G, mut edge_cost: F) -> Result<Option<Tour<G::NodeId>>, E>
+where
+    G: IntoEdges
+        + IntoNodeIdentifiers
+        + Visitable
+        + NodeIndexable
+        + GraphProp<EdgeType = Undirected>,
+    G::NodeId: Eq + Hash,
+    F: FnMut(G::EdgeRef) -> Result<f64, E>,
+{
+    let nodes: Vec<G::NodeId> = graph.node_identifiers().collect();
+    let n = nodes.len();
+    let distances = nodes
+        .iter()
+        .map(|source| distances_from(graph, *source, &nodes, &mut edge_cost))
+        .collect::<Result<Vec<Vec<f64>>, E>>()?;
+    if distances
+        .first()
+        .into_iter()
+        .flatten()
+        .any(|distance| !distance.is_finite())
+    {
+        return Ok(None);
+    }
+
+    // The minimum spanning tree of the metric closure with Prim's algorithm
+    let mut tree_edges: Vec<(usize, usize)> = Vec::with_capacity(n.saturating_sub(1));
+    let mut in_tree = vec![false; n];
+    let mut best: Vec<(f64, usize)> = vec![(f64::INFINITY, 0); n];
+    if n > 0 {
+        best[0] = (0., 0);
+    }
+    for _ in 0..n {
+        let node = (0..n)
+            .filter(|&node| !in_tree[node])
+            .fold(None, |min: Option<usize>, node| match min {
+                Some(min) if best[min].0 <= best[node].0 => Some(min),
+                _ => Some(node),
+            })
+            .unwrap();
+        in_tree[node] = true;
+        if node != 0 {
+            tree_edges.push((best[node].1, node));
+        }
+        for other in 0..n {
+            if !in_tree[other] && distances[node][other] < best[other].0 {
+                best[other] = (distances[node][other], node);
+            }
+        }
+    }
+
+    // The minimum weight perfect matching of the nodes with an odd degree,
+    // as a maximum weight matching of maximum cardinality of the complete
+    // graph between them with the weights subtracted from the largest one
+    let mut degree = vec![0usize; n];
+    for &(a, b) in &tree_edges {
+        degree[a] += 1;
+        degree[b] += 1;
+    }
+    let odd: Vec<usize> = (0..n).filter(|&node| degree[node] % 2 == 1).collect();
+    let mut longest: f64 = 0.;
+    for (i, &a) in odd.iter().enumerate() {
+        for &b in &odd[i + 1..] {
+            longest = longest.max(distances[a][b]);
+        }
+    }
+    let mut matching_graph = UnGraph::<(), i128>::with_capacity(odd.len(), 0);
+    for _ in &odd {
+        matching_graph.add_node(());
+    }
+    for i in 0..odd.len() {
+        for j in i + 1..odd.len() {
+            let weight = if longest > 0. {
+                ((longest - distances[odd[i]][odd[j]]) / longest * MATCHING_SCALE).round() as i128
+            } else {
+                0
+            };
+            matching_graph.add_edge(NodeIndex::new(i), NodeIndex::new(j), weight);
+        }
+    }
+    let mut matching: Vec<(usize, usize)> =
+        max_weight_matching(&matching_graph, true, |e| Ok(*e.weight()), false)?
+            .into_iter()
+            .map(|(a, b)| (odd[a.min(b)], odd[a.max(b)]))
+            .collect();
+    matching.sort_unstable();
+
+    // An Eulerian circuit of the tree and the matching with Hierholzer's
+    // algorithm, shortcut to the first visit of every node
+    let mut adjacency: Vec<Vec<(usize, usize)>> = vec![Vec::new(); n];
+    for (edge, &(a, b)) in tree_edges.iter().chain(matching.iter()).enumerate() {
+        adjacency[a].push((b, edge));
+        adjacency[b].push((a, edge));
+    }
+    let mut used = vec![false; tree_edges.len() + matching.len()];
+    let mut next_edge = vec![0; n];
+    let mut circuit: Vec<usize> = Vec::with_capacity(used.len() + 1);
+    let mut stack: Vec<usize> = Vec::new();
+    if n > 0 {
+        stack.push(0);
+    }
+    while let Some(&node) = stack.last() {
+        while next_edge[node] < adjacency[node].len() && used[adjacency[node][next_edge[node]].1] {
+            next_edge[node] += 1;
+        }
+        match adjacency[node].get(next_edge[node]) {
+            Some(&(other, edge)) => {
+                used[edge] = true;
+                stack.push(other);
+            }
+            None => {
+                stack.pop();
+                circuit.push(node);
+            }
+        }
+    }
+    // The circuit is built backwards
+    circuit.reverse();
+    let mut visited = vec![false; n];
+    let mut order: Vec<usize> = Vec::with_capacity(n);
+    for node in circuit {
+        if !visited[node] {
+            visited[node] = true;
+            order.push(node);
+        }
+    }
+    let cost = order
+        .iter()
+        .zip(order.iter().skip(1).chain(order.first()))
+        .map(|(&a, &b)| distances[a][b])
+        .sum();
+    Ok(Some(Tour {
+        nodes: order.into_iter().map(|node| nodes[node]).collect(),
+        cost,
+    }))
+}
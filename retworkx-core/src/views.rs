@@ -0,0 +1,812 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+//! Module for views of a graph with different edge directions or a subset
+//! of its nodes and edges.
+//!
+//! The views in this module wrap a graph reference without copying it and
+//! implement the same graph traits as the graph, so every algorithm in this
+//! crate can run on them:
+//!
+//! * [`AsUndirected`] views a directed graph as an undirected graph, so
+//!   algorithms for undirected graphs, such as the biconnected components
+//!   or a minimum spanning tree, can run on a directed graph.
+//! * [`AsDirected`] views an undirected graph as a directed graph with every
+//!   edge in both directions.
+//! * [`SubgraphView`] views the subgraph of the nodes and edges which pass
+//!   a node filter and an edge filter.
+//!
+//! The edges of a directed graph can be reversed with petgraph's
+//! [`Reversed`](petgraph::visit::Reversed) view.
+//!
+//! ```rust
+//! use retworkx_core::petgraph;
+//! use retworkx_core::petgraph::graph::NodeIndex;
+//! use retworkx_core::connectivity::articulation_points;
+//! use retworkx_core::views::as_undirected;
+//!
+//! // Two cycles sharing node 2, with edges in both directions
+//! let graph = petgraph::graph::DiGraph::<(), ()>::from_edges(&[
+//!     (0, 1), (1, 2), (2, 0), (3, 2), (2, 4), (4, 3),
+//! ]);
+//! let points = articulation_points(as_undirected(&graph), None);
+//! assert_eq!(points.into_iter().collect::<Vec<_>>(), vec![NodeIndex::new(2)]);
+//! ```
+
+use std::iter::Map;
+
+use petgraph::visit::{
+    Data, EdgeCount, EdgeIndexable, EdgeRef, FilterEdge, FilterNode, GraphBase, GraphProp,
+    GraphRef, IntoEdgeReferences, IntoEdges, IntoEdgesDirected, IntoNeighbors,
+    IntoNeighborsDirected, IntoNodeIdentifiers, IntoNodeReferences, NodeCompactIndexable,
+    NodeCount, NodeIndexable, NodeRef, Visitable,
+};
+use petgraph::{Directed, Direction, Incoming, Outgoing, Undirected};
+
+/// An edge reference of a view, which is the edge reference of the
+/// underlying graph, possibly with its source and target swapped.
+#[derive(Copy, Clone, Debug)]
+pub struct ViewEdgeReference<R> {
+    edge: R,
+    reversed: bool,
+}
+
+impl<R> ViewEdgeReference<R> {
+    /// Return the edge reference of the underlying graph.
+    pub fn as_underlying(&self) -> &R {
+        &self.edge
+    }
+
+    /// Return whether the source and target of the edge are swapped
+    /// compared to the underlying graph.
+    pub fn is_reversed(&self) -> bool {
+        self.reversed
+    }
+}
+
+impl<R: EdgeRef> EdgeRef for ViewEdgeReference<R> {
+    type NodeId = R::NodeId;
+    type EdgeId = R::EdgeId;
+    type Weight = R::Weight;
+
+    fn source(&self) -> R::NodeId {
+        if self.reversed {
+            self.edge.target()
+        } else {
+            self.edge.source()
+        }
+    }
+
+    fn target(&self) -> R::NodeId {
+        if self.reversed {
+            self.edge.source()
+        } else {
+            self.edge.target()
+        }
+    }
+
+    fn weight(&self) -> &R::Weight {
+        self.edge.weight()
+    }
+
+    fn id(&self) -> R::EdgeId {
+        self.edge.id()
+    }
+}
+
+fn forward<R>(edge: R) -> ViewEdgeReference<R> {
+    ViewEdgeReference {
+        edge,
+        reversed: false,
+    }
+}
+
+fn backward<R>(edge: R) -> ViewEdgeReference<R> {
+    ViewEdgeReference {
+        edge,
+        reversed: true,
+    }
+}
+
+/// An iterator over edge references mapped to the edge references of a view.
+type ViewEdges<I, R> = Map<I, fn(R) -> ViewEdgeReference<R>>;
+
+/// An undirected view of a directed graph.
+///
+/// Every edge of the graph is an undirected edge of the view, so the
+/// neighbors of a node are both its successors and its predecessors and
+/// the edges of a node are its outgoing and incoming edges, with the source
+/// and target of an incoming edge swapped so the node is its source. Two
+/// edges in opposite directions between the same nodes are parallel edges
+/// in the view. The view has the same node and edge indices as the graph.
+///
+/// Create it with [`as_undirected`].
+#[derive(Copy, Clone, Debug)]
+pub struct AsUndirected<G>(pub G);
+
+/// View a directed graph as an undirected graph without copying it.
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::petgraph::visit::EdgeRef;
+/// use retworkx_core::tree::minimum_spanning_edges;
+/// use retworkx_core::views::as_undirected;
+/// use retworkx_core::Result;
+///
+/// let graph = petgraph::graph::DiGraph::<(), f64>::from_edges(&[
+///     (0, 1, 1.), (2, 1, 2.), (0, 2, 3.), (3, 2, 1.),
+/// ]);
+/// let res: Result<Vec<_>> = minimum_spanning_edges(as_undirected(&graph), |e| Ok(*e.weight()));
+/// let edges: Vec<(usize, usize)> = res
+///     .unwrap()
+///     .into_iter()
+///     .map(|e| (e.source().index(), e.target().index()))
+///     .collect();
+/// assert_eq!(edges, vec![(0, 1), (3, 2), (2, 1)]);
+/// ```
+pub fn as_undirected<G>(graph: G) -> AsUndirected<G>
+where
+    G: GraphProp<EdgeType = Directed>,
+{
+    AsUndirected(graph)
+}
+
+/// The edges of a node in an [`AsUndirected`] view.
+pub struct UndirectedEdges<G: IntoEdgesDirected> {
+    outgoing: G::EdgesDirected,
+    incoming: G::EdgesDirected,
+    node: G::NodeId,
+    reversed: bool,
+}
+
+impl<G: IntoEdgesDirected> Iterator for UndirectedEdges<G> {
+    type Item = ViewEdgeReference<G::EdgeRef>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let reversed = self.reversed;
+        if let Some(edge) = self.outgoing.next() {
+            return Some(ViewEdgeReference { edge, reversed });
+        }
+        // A self loop is both an outgoing and an incoming edge
+        let node = self.node;
+        self.incoming
+            .find(|edge| edge.source() != node)
+            .map(|edge| ViewEdgeReference {
+                edge,
+                reversed: !reversed,
+            })
+    }
+}
+
+impl<G: GraphBase> GraphBase for AsUndirected<G> {
+    type NodeId = G::NodeId;
+    type EdgeId = G::EdgeId;
+}
+
+impl<G: GraphRef> GraphRef for AsUndirected<G> {}
+
+impl<G: Data> Data for AsUndirected<G> {
+    type NodeWeight = G::NodeWeight;
+    type EdgeWeight = G::EdgeWeight;
+}
+
+impl<G: GraphBase> GraphProp for AsUndirected<G> {
+    type EdgeType = Undirected;
+}
+
+impl<G: NodeIndexable> NodeIndexable for AsUndirected<G> {
+    fn node_bound(&self) -> usize {
+        self.0.node_bound()
+    }
+    fn to_index(&self, a: G::NodeId) -> usize {
+        self.0.to_index(a)
+    }
+    fn from_index(&self, i: usize) -> G::NodeId {
+        self.0.from_index(i)
+    }
+}
+
+impl<G: NodeCompactIndexable> NodeCompactIndexable for AsUndirected<G> {}
+
+impl<G: EdgeIndexable> EdgeIndexable for AsUndirected<G> {
+    fn edge_bound(&self) -> usize {
+        self.0.edge_bound()
+    }
+    fn to_index(&self, a: G::EdgeId) -> usize {
+        self.0.to_index(a)
+    }
+    fn from_index(&self, i: usize) -> G::EdgeId {
+        self.0.from_index(i)
+    }
+}
+
+impl<G: NodeCount> NodeCount for AsUndirected<G> {
+    fn node_count(&self) -> usize {
+        self.0.node_count()
+    }
+}
+
+impl<G: EdgeCount> EdgeCount for AsUndirected<G> {
+    fn edge_count(&self) -> usize {
+        self.0.edge_count()
+    }
+}
+
+impl<G: Visitable> Visitable for AsUndirected<G> {
+    type Map = G::Map;
+    fn visit_map(&self) -> G::Map {
+        self.0.visit_map()
+    }
+    fn reset_map(&self, map: &mut G::Map) {
+        self.0.reset_map(map)
+    }
+}
+
+impl<G: IntoNodeIdentifiers> IntoNodeIdentifiers for AsUndirected<G> {
+    type NodeIdentifiers = G::NodeIdentifiers;
+    fn node_identifiers(self) -> G::NodeIdentifiers {
+        self.0.node_identifiers()
+    }
+}
+
+impl<G: IntoNodeReferences> IntoNodeReferences for AsUndirected<G> {
+    type NodeRef = G::NodeRef;
+    type NodeReferences = G::NodeReferences;
+    fn node_references(self) -> G::NodeReferences {
+        self.0.node_references()
+    }
+}
+
+impl<G: IntoEdgeReferences> IntoEdgeReferences for AsUndirected<G> {
+    type EdgeRef = ViewEdgeReference<G::EdgeRef>;
+    type EdgeReferences = ViewEdges<G::EdgeReferences, G::EdgeRef>;
+    fn edge_references(self) -> Self::EdgeReferences {
+        self.0.edge_references().map(forward)
+    }
+}
+
+impl<G: IntoEdgesDirected> IntoEdges for AsUndirected<G> {
+    type Edges = UndirectedEdges<G>;
+    fn edges(self, a: G::NodeId) -> UndirectedEdges<G> {
+        self.edges_directed(a, Outgoing)
+    }
+}
+
+impl<G: IntoEdgesDirected> IntoEdgesDirected for AsUndirected<G> {
+    type EdgesDirected = UndirectedEdges<G>;
+    fn edges_directed(self, a: G::NodeId, dir: Direction) -> UndirectedEdges<G> {
+        UndirectedEdges {
+            outgoing: self.0.edges_directed(a, Outgoing),
+            incoming: self.0.edges_directed(a, Incoming),
+            node: a,
+            // The incoming edges of an undirected graph have the node as
+            // their target
+            reversed: dir == Incoming,
+        }
+    }
+}
+
+impl<G: IntoEdgesDirected> IntoNeighbors for AsUndirected<G> {
+    type Neighbors = Map<UndirectedEdges<G>, fn(ViewEdgeReference<G::EdgeRef>) -> G::NodeId>;
+    fn neighbors(self, a: G::NodeId) -> Self::Neighbors {
+        self.edges(a).map(|edge| edge.target())
+    }
+}
+
+impl<G: IntoEdgesDirected> IntoNeighborsDirected for AsUndirected<G> {
+    type NeighborsDirected = Self::Neighbors;
+    fn neighbors_directed(self, a: G::NodeId, _dir: Direction) -> Self::Neighbors {
+        self.neighbors(a)
+    }
+}
+
+/// A directed view of an undirected graph.
+///
+/// Every edge of the graph is a pair of directed edges in opposite
+/// directions in the view, except for self loops. Both directed edges of an
+/// edge have the edge's index, so the view doesn't implement
+/// [`EdgeIndexable`] or [`EdgeCount`], and the edges in the direction
+/// opposite to the one stored in the graph are reversed edge references.
+/// The view has the same node indices as the graph.
+///
+/// Create it with [`as_directed`].
+#[derive(Copy, Clone, Debug)]
+pub struct AsDirected<G>(pub G);
+
+/// View an undirected graph as a directed graph with every edge in both
+/// directions, without copying it.
+///
+/// Combined with [`as_undirected`] this also gives the directed graph with
+/// the edges of a directed graph in both directions.
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::petgraph::algo::kosaraju_scc;
+/// use retworkx_core::petgraph::visit::{EdgeRef, IntoEdgesDirected};
+/// use retworkx_core::petgraph::graph::NodeIndex;
+/// use retworkx_core::petgraph::Incoming;
+/// use retworkx_core::views::{as_directed, as_undirected};
+///
+/// let graph = petgraph::graph::UnGraph::<(), ()>::from_edges(&[(0, 1), (1, 2)]);
+/// let sources: Vec<usize> = as_directed(&graph)
+///     .edges_directed(NodeIndex::new(1), Incoming)
+///     .map(|e| e.source().index())
+///     .collect();
+/// assert_eq!(sources, vec![2, 0]);
+///
+/// // The strongly connected components of a directed graph with its edges
+/// // in both directions are its weakly connected components
+/// let digraph = petgraph::graph::DiGraph::<(), ()>::from_edges(&[(0, 1), (2, 1), (3, 4)]);
+/// let components = kosaraju_scc(as_directed(as_undirected(&digraph)));
+/// assert_eq!(components.len(), 2);
+/// ```
+pub fn as_directed<G>(graph: G) -> AsDirected<G>
+where
+    G: GraphProp<EdgeType = Undirected>,
+{
+    AsDirected(graph)
+}
+
+/// The edge references of an [`AsDirected`] view, with every edge of the
+/// graph in both directions.
+pub struct BothDirections<I: Iterator> {
+    iter: I,
+    pending: Option<I::Item>,
+}
+
+impl<I> Iterator for BothDirections<I>
+where
+    I: Iterator,
+    I::Item: EdgeRef,
+    <I::Item as EdgeRef>::NodeId: PartialEq,
+{
+    type Item = ViewEdgeReference<I::Item>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(edge) = self.pending.take() {
+            return Some(backward(edge));
+        }
+        let edge = self.iter.next()?;
+        if edge.source() != edge.target() {
+            self.pending = Some(edge);
+        }
+        Some(forward(edge))
+    }
+}
+
+impl<G: GraphBase> GraphBase for AsDirected<G> {
+    type NodeId = G::NodeId;
+    type EdgeId = G::EdgeId;
+}
+
+impl<G: GraphRef> GraphRef for AsDirected<G> {}
+
+impl<G: Data> Data for AsDirected<G> {
+    type NodeWeight = G::NodeWeight;
+    type EdgeWeight = G::EdgeWeight;
+}
+
+impl<G: GraphBase> GraphProp for AsDirected<G> {
+    type EdgeType = Directed;
+}
+
+impl<G: NodeIndexable> NodeIndexable for AsDirected<G> {
+    fn node_bound(&self) -> usize {
+        self.0.node_bound()
+    }
+    fn to_index(&self, a: G::NodeId) -> usize {
+        self.0.to_index(a)
+    }
+    fn from_index(&self, i: usize) -> G::NodeId {
+        self.0.from_index(i)
+    }
+}
+
+impl<G: NodeCompactIndexable> NodeCompactIndexable for AsDirected<G> {}
+
+impl<G: NodeCount> NodeCount for AsDirected<G> {
+    fn node_count(&self) -> usize {
+        self.0.node_count()
+    }
+}
+
+impl<G: Visitable> Visitable for AsDirected<G> {
+    type Map = G::Map;
+    fn visit_map(&self) -> G::Map {
+        self.0.visit_map()
+    }
+    fn reset_map(&self, map: &mut G::Map) {
+        self.0.reset_map(map)
+    }
+}
+
+impl<G: IntoNodeIdentifiers> IntoNodeIdentifiers for AsDirected<G> {
+    type NodeIdentifiers = G::NodeIdentifiers;
+    fn node_identifiers(self) -> G::NodeIdentifiers {
+        self.0.node_identifiers()
+    }
+}
+
+impl<G: IntoNodeReferences> IntoNodeReferences for AsDirected<G> {
+    type NodeRef = G::NodeRef;
+    type NodeReferences = G::NodeReferences;
+    fn node_references(self) -> G::NodeReferences {
+        self.0.node_references()
+    }
+}
+
+impl<G: IntoEdgeReferences> IntoEdgeReferences for AsDirected<G> {
+    type EdgeRef = ViewEdgeReference<G::EdgeRef>;
+    type EdgeReferences = BothDirections<G::EdgeReferences>;
+    fn edge_references(self) -> Self::EdgeReferences {
+        BothDirections {
+            iter: self.0.edge_references(),
+            pending: None,
+        }
+    }
+}
+
+impl<G: IntoEdges> IntoEdges for AsDirected<G> {
+    type Edges = ViewEdges<G::Edges, G::EdgeRef>;
+    fn edges(self, a: G::NodeId) -> Self::Edges {
+        // The edges of a node of an undirected graph have it as source
+        self.0.edges(a).map(forward)
+    }
+}
+
+impl<G: IntoEdges> IntoEdgesDirected for AsDirected<G> {
+    type EdgesDirected = ViewEdges<G::Edges, G::EdgeRef>;
+    fn edges_directed(self, a: G::NodeId, dir: Direction) -> Self::EdgesDirected {
+        match dir {
+            Outgoing => self.0.edges(a).map(forward),
+            Incoming => self.0.edges(a).map(backward),
+        }
+    }
+}
+
+impl<G: IntoNeighbors> IntoNeighbors for AsDirected<G> {
+    type Neighbors = G::Neighbors;
+    fn neighbors(self, a: G::NodeId) -> G::Neighbors {
+        self.0.neighbors(a)
+    }
+}
+
+impl<G: IntoNeighbors> IntoNeighborsDirected for AsDirected<G> {
+    type NeighborsDirected = G::Neighbors;
+    fn neighbors_directed(self, a: G::NodeId, _dir: Direction) -> G::Neighbors {
+        self.0.neighbors(a)
+    }
+}
+
+/// A view of the subgraph of the nodes and edges of a graph which pass a
+/// node filter and an edge filter.
+///
+/// An edge is in the view if it passes the edge filter and both its
+/// endpoints pass the node filter. The filters are checked whenever the
+/// nodes or edges of the view are iterated over, so they should be cheap,
+/// such as a lookup in a [`FixedBitSet`](fixedbitset::FixedBitSet) of
+/// indices. The view has the same node and edge indices as the graph, so
+/// it doesn't implement [`NodeCompactIndexable`], and it doesn't implement
+/// [`NodeCount`] or [`EdgeCount`] since they would have to check every
+/// node or edge.
+///
+/// Create it with [`subgraph_view`].
+#[derive(Copy, Clone, Debug)]
+pub struct SubgraphView<G, FN, FE> {
+    graph: G,
+    node_filter: FN,
+    edge_filter: FE,
+}
+
+/// View the subgraph of the nodes of a graph which pass `node_filter` and
+/// the edges between them which pass `edge_filter`, without copying it.
+///
+/// The filters can be closures or references to closures, which are called
+/// with a node identifier and an edge reference respectively, and must be
+/// [`Copy`] since the view is.
+///
+/// # Example
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::petgraph::graph::NodeIndex;
+/// use retworkx_core::petgraph::visit::EdgeRef;
+/// use retworkx_core::petgraph::algo::kosaraju_scc;
+/// use retworkx_core::views::subgraph_view;
+///
+/// // A path with weights on its edges
+/// let graph = petgraph::graph::UnGraph::<(), u32>::from_edges(&[
+///     (0, 1, 1), (1, 2, 5), (2, 3, 1), (3, 4, 1),
+/// ]);
+/// // Without node 4 and the heavy edge the path splits in 2 parts
+/// let view = subgraph_view(
+///     &graph,
+///     |node: NodeIndex| node.index() != 4,
+///     |edge: petgraph::graph::EdgeReference<u32>| *edge.weight() < 5,
+/// );
+/// assert_eq!(kosaraju_scc(view).len(), 2);
+/// ```
+pub fn subgraph_view<G, FN, FE>(
+    graph: G,
+    node_filter: FN,
+    edge_filter: FE,
+) -> SubgraphView<G, FN, FE>
+where
+    G: IntoEdgeReferences,
+    FN: FilterNode<G::NodeId> + Copy,
+    FE: FilterEdge<G::EdgeRef> + Copy,
+{
+    SubgraphView {
+        graph,
+        node_filter,
+        edge_filter,
+    }
+}
+
+/// The node identifiers of a [`SubgraphView`].
+pub struct FilteredNodes<I, FN> {
+    iter: I,
+    node_filter: FN,
+}
+
+impl<I, FN> Iterator for FilteredNodes<I, FN>
+where
+    I: Iterator,
+    I::Item: Copy,
+    FN: FilterNode<I::Item>,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        let node_filter = &self.node_filter;
+        self.iter.find(|node| node_filter.include_node(*node))
+    }
+}
+
+/// The node references of a [`SubgraphView`].
+pub struct FilteredNodeReferences<I, FN> {
+    iter: I,
+    node_filter: FN,
+}
+
+impl<I, FN> Iterator for FilteredNodeReferences<I, FN>
+where
+    I: Iterator,
+    I::Item: NodeRef,
+    FN: FilterNode<<I::Item as NodeRef>::NodeId>,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        let node_filter = &self.node_filter;
+        self.iter.find(|node| node_filter.include_node(node.id()))
+    }
+}
+
+/// The edges of a [`SubgraphView`].
+pub struct FilteredEdges<I, FN, FE> {
+    iter: I,
+    node_filter: FN,
+    edge_filter: FE,
+}
+
+impl<I, FN, FE> Iterator for FilteredEdges<I, FN, FE>
+where
+    I: Iterator,
+    I::Item: EdgeRef,
+    FN: FilterNode<<I::Item as EdgeRef>::NodeId>,
+    FE: FilterEdge<I::Item>,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        let (node_filter, edge_filter) = (&self.node_filter, &self.edge_filter);
+        self.iter.find(|edge| {
+            edge_filter.include_edge(*edge)
+                && node_filter.include_node(edge.source())
+                && node_filter.include_node(edge.target())
+        })
+    }
+}
+
+/// The neighbors of a node of a [`SubgraphView`], which are the other
+/// endpoints of its edges.
+pub struct FilteredNeighbors<I, FN, FE> {
+    edges: FilteredEdges<I, FN, FE>,
+    incoming: bool,
+}
+
+impl<I, FN, FE> Iterator for FilteredNeighbors<I, FN, FE>
+where
+    I: Iterator,
+    I::Item: EdgeRef,
+    FN: FilterNode<<I::Item as EdgeRef>::NodeId>,
+    FE: FilterEdge<I::Item>,
+{
+    type Item = <I::Item as EdgeRef>::NodeId;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let incoming = self.incoming;
+        self.edges.next().map(|edge| {
+            if incoming {
+                edge.source()
+            } else {
+                edge.target()
+            }
+        })
+    }
+}
+
+impl<G, FN, FE> SubgraphView<G, FN, FE>
+where
+    FN: Copy,
+    FE: Copy,
+{
+    fn filter_edges<I>(&self, iter: I) -> FilteredEdges<I, FN, FE> {
+        FilteredEdges {
+            iter,
+            node_filter: self.node_filter,
+            edge_filter: self.edge_filter,
+        }
+    }
+}
+
+impl<G: GraphBase, FN, FE> GraphBase for SubgraphView<G, FN, FE> {
+    type NodeId = G::NodeId;
+    type EdgeId = G::EdgeId;
+}
+
+impl<G: GraphRef, FN: Copy, FE: Copy> GraphRef for SubgraphView<G, FN, FE> {}
+
+impl<G: Data, FN, FE> Data for SubgraphView<G, FN, FE> {
+    type NodeWeight = G::NodeWeight;
+    type EdgeWeight = G::EdgeWeight;
+}
+
+impl<G: GraphProp, FN, FE> GraphProp for SubgraphView<G, FN, FE> {
+    type EdgeType = G::EdgeType;
+}
+
+impl<G: NodeIndexable, FN, FE> NodeIndexable for SubgraphView<G, FN, FE> {
+    fn node_bound(&self) -> usize {
+        self.graph.node_bound()
+    }
+    fn to_index(&self, a: G::NodeId) -> usize {
+        self.graph.to_index(a)
+    }
+    fn from_index(&self, i: usize) -> G::NodeId {
+        self.graph.from_index(i)
+    }
+}
+
+impl<G: EdgeIndexable, FN, FE> EdgeIndexable for SubgraphView<G, FN, FE> {
+    fn edge_bound(&self) -> usize {
+        self.graph.edge_bound()
+    }
+    fn to_index(&self, a: G::EdgeId) -> usize {
+        self.graph.to_index(a)
+    }
+    fn from_index(&self, i: usize) -> G::EdgeId {
+        self.graph.from_index(i)
+    }
+}
+
+impl<G: Visitable, FN, FE> Visitable for SubgraphView<G, FN, FE> {
+    type Map = G::Map;
+    fn visit_map(&self) -> G::Map {
+        self.graph.visit_map()
+    }
+    fn reset_map(&self, map: &mut G::Map) {
+        self.graph.reset_map(map)
+    }
+}
+
+impl<G, FN, FE> IntoNodeIdentifiers for SubgraphView<G, FN, FE>
+where
+    G: IntoNodeIdentifiers,
+    FN: FilterNode<G::NodeId> + Copy,
+    FE: Copy,
+{
+    type NodeIdentifiers = FilteredNodes<G::NodeIdentifiers, FN>;
+    fn node_identifiers(self) -> Self::NodeIdentifiers {
+        FilteredNodes {
+            iter: self.graph.node_identifiers(),
+            node_filter: self.node_filter,
+        }
+    }
+}
+
+impl<G, FN, FE> IntoNodeReferences for SubgraphView<G, FN, FE>
+where
+    G: IntoNodeReferences,
+    FN: FilterNode<G::NodeId> + Copy,
+    FE: Copy,
+{
+    type NodeRef = G::NodeRef;
+    type NodeReferences = FilteredNodeReferences<G::NodeReferences, FN>;
+    fn node_references(self) -> Self::NodeReferences {
+        FilteredNodeReferences {
+            iter: self.graph.node_references(),
+            node_filter: self.node_filter,
+        }
+    }
+}
+
+impl<G, FN, FE> IntoEdgeReferences for SubgraphView<G, FN, FE>
+where
+    G: IntoEdgeReferences,
+    FN: FilterNode<G::NodeId> + Copy,
+    FE: FilterEdge<G::EdgeRef> + Copy,
+{
+    type EdgeRef = G::EdgeRef;
+    type EdgeReferences = FilteredEdges<G::EdgeReferences, FN, FE>;
+    fn edge_references(self) -> Self::EdgeReferences {
+        self.filter_edges(self.graph.edge_references())
+    }
+}
+
+impl<G, FN, FE> IntoEdges for SubgraphView<G, FN, FE>
+where
+    G: IntoEdges,
+    FN: FilterNode<G::NodeId> + Copy,
+    FE: FilterEdge<G::EdgeRef> + Copy,
+{
+    type Edges = FilteredEdges<G::Edges, FN, FE>;
+    fn edges(self, a: G::NodeId) -> Self::Edges {
+        self.filter_edges(self.graph.edges(a))
+    }
+}
+
+impl<G, FN, FE> IntoEdgesDirected for SubgraphView<G, FN, FE>
+where
+    G: IntoEdgesDirected,
+    FN: FilterNode<G::NodeId> + Copy,
+    FE: FilterEdge<G::EdgeRef> + Copy,
+{
+    type EdgesDirected = FilteredEdges<G::EdgesDirected, FN, FE>;
+    fn edges_directed(self, a: G::NodeId, dir: Direction) -> Self::EdgesDirected {
+        self.filter_edges(self.graph.edges_directed(a, dir))
+    }
+}
+
+impl<G, FN, FE> IntoNeighbors for SubgraphView<G, FN, FE>
+where
+    G: IntoEdges,
+    FN: FilterNode<G::NodeId> + Copy,
+    FE: FilterEdge<G::EdgeRef> + Copy,
+{
+    type Neighbors = FilteredNeighbors<G::Edges, FN, FE>;
+    fn neighbors(self, a: G::NodeId) -> Self::Neighbors {
+        // The edges of a node have it as source
+        FilteredNeighbors {
+            edges: self.edges(a),
+            incoming: false,
+        }
+    }
+}
+
+impl<G, FN, FE> IntoNeighborsDirected for SubgraphView<G, FN, FE>
+where
+    G: IntoEdgesDirected,
+    FN: FilterNode<G::NodeId> + Copy,
+    FE: FilterEdge<G::EdgeRef> + Copy,
+{
+    type NeighborsDirected = FilteredNeighbors<G::EdgesDirected, FN, FE>;
+    fn neighbors_directed(self, a: G::NodeId, dir: Direction) -> Self::NeighborsDirected {
+        // The incoming edges of a node have it as target
+        FilteredNeighbors {
+            edges: self.edges_directed(a, dir),
+            incoming: dir == Incoming,
+        }
+    }
+}
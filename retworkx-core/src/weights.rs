@@ -0,0 +1,137 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+//! Module for adapters that transform the edge weights seen by an algorithm.
+//!
+//! The functions in this crate take the weight of an edge through a cost
+//! function, a closure that is called with an edge reference. The adapters
+//! in this module wrap such a cost function into a new one which transforms
+//! its output, so an algorithm can work on transformed weights without
+//! mutating or copying the graph. For example, to find the path between the
+//! most similar nodes of a graph whose edge weights are similarities:
+//!
+//! ```rust
+//! use retworkx_core::petgraph;
+//! use retworkx_core::petgraph::graph::{EdgeReference, NodeIndex};
+//! use retworkx_core::dictmap::DictMap;
+//! use retworkx_core::shortest_path::dijkstra;
+//! use retworkx_core::weights;
+//! use retworkx_core::Result;
+//!
+//! let graph = petgraph::graph::UnGraph::<(), f64>::from_edges(&[
+//!     (0, 1, 0.5), (1, 2, 0.5), (0, 2, 0.2)
+//! ]);
+//! let res: Result<DictMap<NodeIndex, f64>> = dijkstra(
+//!     &graph,
+//!     NodeIndex::new(0),
+//!     None,
+//!     weights::invert(|e: EdgeReference<f64>| Ok(*e.weight())),
+//!     None,
+//! );
+//! // Going through node 1 costs 1 / 0.5 + 1 / 0.5 = 4 and the direct edge
+//! // costs 1 / 0.2 = 5
+//! assert_eq!(res.unwrap()[&NodeIndex::new(2)], 4.0);
+//! ```
+
+use petgraph::visit::IntoEdgeReferences;
+
+/// Wrap `cost` to return the inverse `1 / w` of each weight `w`.
+///
+/// This turns similarities into distances. An edge with a weight of `0`
+/// gets an infinite cost.
+pub fn invert<T, E, F>(mut cost: F) -> impl FnMut(T) -> Result<f64, E>
+where
+    F: FnMut(T) -> Result<f64, E>,
+{
+    move |edge| cost(edge).map(|weight| 1. / weight)
+}
+
+/// Wrap `cost` to return the negative logarithm `-ln(w)` of each weight `w`.
+///
+/// This turns probabilities into distances: the shortest path for the
+/// transformed weights is the path with the highest product of
+/// probabilities. Weights in `(0, 1]` are mapped to non-negative costs, an
+/// edge with a weight of `0` gets an infinite cost.
+///
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::petgraph::graph::{EdgeReference, NodeIndex};
+/// use retworkx_core::dictmap::DictMap;
+/// use retworkx_core::shortest_path::dijkstra;
+/// use retworkx_core::weights;
+/// use retworkx_core::Result;
+///
+/// let graph = petgraph::graph::DiGraph::<(), f64>::from_edges(&[
+///     (0, 1, 0.9), (1, 2, 0.9), (0, 2, 0.8)
+/// ]);
+/// let res: Result<DictMap<NodeIndex, f64>> = dijkstra(
+///     &graph,
+///     NodeIndex::new(0),
+///     None,
+///     weights::neg_log(|e: EdgeReference<f64>| Ok(*e.weight())),
+///     None,
+/// );
+/// // 0.9 * 0.9 = 0.81 is more likely than 0.8
+/// let cost = res.unwrap()[&NodeIndex::new(2)];
+/// assert!((cost - -(0.81_f64.ln())).abs() < 1e-12);
+/// ```
+pub fn neg_log<T, E, F>(mut cost: F) -> impl FnMut(T) -> Result<f64, E>
+where
+    F: FnMut(T) -> Result<f64, E>,
+{
+    move |edge| cost(edge).map(|weight| -weight.ln())
+}
+
+/// Wrap `cost` to linearly rescale the weights of `graph` to `[min, max]`.
+///
+/// This calls `cost` once on every edge of `graph` to find the smallest
+/// and largest weights, which are mapped to `min` and `max` respectively.
+/// If every edge has the same weight they are all mapped to `min`. Any
+/// error returned by `cost` while scanning the edges is returned.
+///
+/// ```rust
+/// use retworkx_core::petgraph;
+/// use retworkx_core::petgraph::visit::IntoEdgeReferences;
+/// use retworkx_core::weights;
+/// use retworkx_core::Result;
+///
+/// let graph = petgraph::graph::UnGraph::<(), f64>::from_edges(&[
+///     (0, 1, 10.), (1, 2, 20.), (2, 3, 30.)
+/// ]);
+/// let mut cost = weights::rescale(&graph, |e| Result::Ok(*e.weight()), 0., 1.).unwrap();
+/// let rescaled: Result<Vec<f64>> = graph.edge_references().map(|e| cost(e)).collect();
+/// assert_eq!(rescaled.unwrap(), vec![0., 0.5, 1.]);
+/// ```
+pub fn rescale<G, E, F>(
+    graph: G,
+    mut cost: F,
+    min: f64,
+    max: f64,
+) -> Result<impl FnMut(G::EdgeRef) -> Result<f64, E>, E>
+where
+    G: IntoEdgeReferences,
+    F: FnMut(G::EdgeRef) -> Result<f64, E>,
+{
+    let mut low = f64::INFINITY;
+    let mut high = f64::NEG_INFINITY;
+    for edge in graph.edge_references() {
+        let weight = cost(edge)?;
+        low = low.min(weight);
+        high = high.max(weight);
+    }
+    let scale = if high > low {
+        (max - min) / (high - low)
+    } else {
+        0.
+    };
+    Ok(move |edge| cost(edge).map(|weight| min + (weight - low) * scale))
+}
@@ -93,14 +93,15 @@ class PyDAG(PyDiGraph):
 
     With check_cycle set to true any calls to :meth:`PyDAG.add_edge` will
     ensure that no cycles are added, ensuring that the PyDAG class truly
-    represents a directed acyclic graph. Do note that this cycle checking on
-    :meth:`~PyDAG.add_edge`, :meth:`~PyDigraph.add_edges_from`,
-    :meth:`~PyDAG.add_edges_from_no_data`,
-    :meth:`~PyDAG.extend_from_edge_list`,  and
-    :meth:`~PyDAG.extend_from_weighted_edge_list` comes with a performance
-    penalty that grows as the graph does.  If you're adding a node and edge at
-    the same time, leveraging :meth:`PyDAG.add_child` or
-    :meth:`PyDAG.add_parent` will avoid this overhead.
+    represents a directed acyclic graph. The cycle checking maintains a
+    topological order of the nodes, returned by
+    :meth:`~PyDAG.topological_order`, which is updated as edges are added
+    instead of searching the graph for a cycle. Adding an edge which agrees
+    with the order takes constant time, otherwise only the nodes between the
+    endpoints of the edge in the order are searched and reordered. If you're
+    adding a node and edge at the same time, leveraging
+    :meth:`PyDAG.add_child` or :meth:`PyDAG.add_parent` skips the cycle check
+    and always takes constant time.
 
     By default a ``PyDAG`` is a multigraph (meaning there can be parallel
     edges between nodes) however this can be disabled by setting the
@@ -237,11 +238,89 @@ def _graph_unweighted_shortest_path_length(graph, parallel_threshold=300, discon
 
 
 @functools.singledispatch
-def adjacency_matrix(graph, weight_fn=None, default_weight=1.0, null_value=0.0):
+def approximate_neighborhood_function(graph, precision=10, seed=None, max_distance=None):
+    """Estimate the neighborhood function of a graph with HyperANF.
+
+    The neighborhood function :math:`N(t)` of a graph is the number of
+    ordered pairs of nodes :math:`(x, y)` such that :math:`y` is reachable
+    from :math:`x` in at most :math:`t` steps (including the pairs
+    :math:`(x, x)`). Computing it exactly requires a breadth first search
+    from every node which is impractical for large graphs. HyperANF instead
+    keeps a HyperLogLog counter of the nodes within distance :math:`t` of
+    every node, so each iteration takes a single pass over the edges and the
+    total run time is near linear in the size of the graph times its
+    diameter. The edges of a :class:`~retworkx.PyDiGraph` are only followed in
+    their direction. The algorithm is based on [HyperANF]_.
+
+    Every counter uses ``2**precision`` registers of one byte and the relative
+    standard error of the estimated size of each counter is about
+    ``1.04 / sqrt(2**precision)``.
+
+    .. jupyter-execute::
+
+        import retworkx
+
+        graph = retworkx.generators.grid_graph(30, 30)
+        neighborhood_function = retworkx.approximate_neighborhood_function(graph, seed=42)
+        print(neighborhood_function.effective_diameter())
+        print(neighborhood_function.average_distance())
+
+    :param graph: The graph to estimate the neighborhood function of. This can
+        be a :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`
+    :param int precision: The base 2 logarithm of the number of registers of
+        every counter, it must be between 4 and 16
+    :param int seed: An optional seed for the hash function used by the
+        counters
+    :param int max_distance: An optional maximum distance to compute the
+        neighborhood function up to. If not specified the iteration stops once
+        none of the counters change.
+
+    :returns: The estimated neighborhood function, which can be used to
+        estimate the effective diameter and average distance of the graph
+    :rtype: NeighborhoodFunction
+
+    .. [HyperANF] Boldi, Paolo, Marco Rosa, and Sebastiano Vigna. "HyperANF:
+        Approximating the neighbourhood function of very large graphs on a
+        budget." Proceedings of the 20th international conference on World
+        Wide Web (2011): 625-634.
+    """
+    raise TypeError("Invalid input type %s for graph" % type(graph))
+
+
+@approximate_neighborhood_function.register(PyDiGraph)
+def _digraph_approximate_neighborhood_function(graph, precision=10, seed=None, max_distance=None):
+    return digraph_approximate_neighborhood_function(
+        graph, precision=precision, seed=seed, max_distance=max_distance
+    )
+
+
+@approximate_neighborhood_function.register(PyGraph)
+def _graph_approximate_neighborhood_function(graph, precision=10, seed=None, max_distance=None):
+    return graph_approximate_neighborhood_function(
+        graph, precision=precision, seed=seed, max_distance=max_distance
+    )
+
+
+@functools.singledispatch
+def adjacency_matrix(
+    graph,
+    weight_fn=None,
+    default_weight=1.0,
+    null_value=0.0,
+    dtype=None,
+    parallel_edge="sum",
+):
     """Return the adjacency matrix for a graph object
 
     In the case where there are multiple edges between nodes the value in the
-    output matrix will be the sum of the edges' weights.
+    output matrix will be the sum of the edges' weights, unless another
+    ``parallel_edge`` value is given. For example, to get a ``float32``
+    matrix with the lowest weight of the edges between every pair of nodes
+    and ``inf`` where there is no edge::
+
+        matrix = retworkx.adjacency_matrix(
+            graph, float, null_value=np.inf, dtype=np.float32, parallel_edge="min"
+        )
 
     :param graph: The graph used to generate the adjacency matrix from. Can
         either be a :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`
@@ -265,217 +344,313 @@ def adjacency_matrix(graph, weight_fn=None, default_weight=1.0, null_value=0.0):
         value. This is the default value in the output matrix and it is used
         to indicate the absence of an edge between 2 nodes. By default this is
         ``0.0``.
+    :param dtype: The numpy data type of the output matrix, one of
+        ``numpy.float64``, ``numpy.float32``, ``numpy.complex128`` or
+        ``numpy.complex64`` (or any object ``numpy.dtype`` accepts for them).
+        With a complex data type ``weight_fn`` can return a ``complex`` and
+        ``default_weight`` and ``null_value`` can be ``complex`` too. By
+        default this is ``numpy.float64``.
+    :param str parallel_edge: How the weights of parallel edges between 2
+        nodes are combined in the output matrix, one of ``"sum"`` (the
+        default), ``"min"``, ``"max"`` or ``"last"`` for the weight of the edge
+        with the highest edge index. ``"min"`` and ``"max"`` are not supported
+        with a complex data type.
 
      :return: The adjacency matrix for the input dag as a numpy array
      :rtype: numpy.ndarray
+     :raises ValueError: If ``dtype`` or ``parallel_edge`` is not supported
     """
     raise TypeError("Invalid Input Type %s for graph" % type(graph))
 
 
 @adjacency_matrix.register(PyDiGraph)
-def _digraph_adjacency_matrix(graph, weight_fn=None, default_weight=1.0, null_value=0.0):
+def _digraph_adjacency_matrix(
+    graph,
+    weight_fn=None,
+    default_weight=1.0,
+    null_value=0.0,
+    dtype=None,
+    parallel_edge="sum",
+):
     return digraph_adjacency_matrix(
         graph,
         weight_fn=weight_fn,
         default_weight=default_weight,
         null_value=null_value,
+        dtype=dtype,
+        parallel_edge=parallel_edge,
     )
 
 
 @adjacency_matrix.register(PyGraph)
-def _graph_adjacency_matrix(graph, weight_fn=None, default_weight=1.0, null_value=0.0):
+def _graph_adjacency_matrix(
+    graph,
+    weight_fn=None,
+    default_weight=1.0,
+    null_value=0.0,
+    dtype=None,
+    parallel_edge="sum",
+):
     return graph_adjacency_matrix(
         graph,
         weight_fn=weight_fn,
         default_weight=default_weight,
         null_value=null_value,
+        dtype=dtype,
+        parallel_edge=parallel_edge,
     )
 
 
 @functools.singledispatch
-def all_simple_paths(graph, from_, to, min_depth=None, cutoff=None):
-    """Return all simple paths between 2 nodes in a PyGraph object
-
-    A simple path is a path with no repeated nodes.
-
-    :param graph: The graph to find the path in. Can either be a
-        class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`
-    :param int from_: The node index to find the paths from
-    :param int to: The node index to find the paths to
-    :param int min_depth: The minimum depth of the path to include in the
-        output list of paths. By default all paths are included regardless of
-        depth, setting to 0 will behave like the default.
-    :param int cutoff: The maximum depth of path to include in the output list
-        of paths. By default includes all paths regardless of depth, setting to
-        0 will behave like default.
+def csr_adjacency_matrix(
+    graph, weight_fn=None, default_weight=1.0, dtype=None, parallel_edge="sum"
+):
+    """Return the adjacency matrix for a graph object in CSR format
+
+    This builds the ``indptr``, ``indices`` and ``data`` arrays of the
+    compressed sparse row representation of the matrix returned by
+    :func:`~retworkx.adjacency_matrix` directly, without creating the dense
+    matrix, so it can be used for large graphs. Row ``i`` of the matrix is
+    for the ``i``-th node in ``graph.node_indices()`` and there is an entry
+    for every pair of nodes with an edge, sorted by column in every row. For
+    a :class:`~retworkx.PyGraph` the matrix is symmetric, so an edge between
+    2 different nodes has an entry in the rows of both nodes. The arrays can
+    be passed to ``scipy.sparse``::
+
+        indptr, indices, data = retworkx.csr_adjacency_matrix(graph)
+        n = len(graph)
+        matrix = scipy.sparse.csr_matrix((data, indices, indptr), shape=(n, n))
 
-    :returns: A list of lists where each inner list is a path of node indices
-    :rtype: list
+    :param graph: The graph used to generate the adjacency matrix from. Can
+        either be a :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`
+    :param callable weight_fn: A callable object (function, lambda, etc) which
+        will be passed the edge object and expected to return a ``float`` for
+        the weight of the edge. If this is not specified ``default_weight``
+        will be used for all edges.
+    :param float default_weight: If ``weight_fn`` is not used this can be
+        optionally used to specify a default weight to use for all edges.
+        Defaults to 1.0.
+    :param dtype: The numpy data type of the ``data`` array, one of
+        ``numpy.float64``, ``numpy.float32``, ``numpy.complex128`` or
+        ``numpy.complex64`` (or any object ``numpy.dtype`` accepts for them).
+        With a complex data type ``weight_fn`` can return a ``complex`` and
+        ``default_weight`` can be ``complex`` too. By default this is
+        ``numpy.float64``.
+    :param str parallel_edge: How the weights of parallel edges between 2
+        nodes are combined in a single entry, one of ``"sum"`` (the
+        default), ``"min"``, ``"max"`` or ``"last"`` for the weight of the edge
+        with the highest edge index. ``"min"`` and ``"max"`` are not supported
+        with a complex data type.
+
+    :return: A tuple of the ``indptr``, ``indices`` and ``data`` numpy arrays,
+        where ``indptr`` and ``indices`` are ``numpy.int64`` arrays
+    :rtype: tuple
+    :raises ValueError: If ``dtype`` or ``parallel_edge`` is not supported
     """
     raise TypeError("Invalid Input Type %s for graph" % type(graph))
 
 
-@all_simple_paths.register(PyDiGraph)
-def _digraph_all_simple_paths(graph, from_, to, min_depth=None, cutoff=None):
-    return digraph_all_simple_paths(graph, from_, to, min_depth=min_depth, cutoff=cutoff)
+@csr_adjacency_matrix.register(PyDiGraph)
+def _digraph_csr_adjacency_matrix(
+    graph, weight_fn=None, default_weight=1.0, dtype=None, parallel_edge="sum"
+):
+    return digraph_csr_adjacency_matrix(
+        graph,
+        weight_fn=weight_fn,
+        default_weight=default_weight,
+        dtype=dtype,
+        parallel_edge=parallel_edge,
+    )
 
 
-@all_simple_paths.register(PyGraph)
-def _graph_all_simple_paths(graph, from_, to, min_depth=None, cutoff=None):
-    return graph_all_simple_paths(graph, from_, to, min_depth=min_depth, cutoff=cutoff)
+@csr_adjacency_matrix.register(PyGraph)
+def _graph_csr_adjacency_matrix(
+    graph, weight_fn=None, default_weight=1.0, dtype=None, parallel_edge="sum"
+):
+    return graph_csr_adjacency_matrix(
+        graph,
+        weight_fn=weight_fn,
+        default_weight=default_weight,
+        dtype=dtype,
+        parallel_edge=parallel_edge,
+    )
 
 
 @functools.singledispatch
-def floyd_warshall(
+def laplacian_matrix(
     graph,
     weight_fn=None,
     default_weight=1.0,
-    parallel_threshold=300,
+    normalized=False,
+    sparse=False,
+    degree="out",
+    as_undirected=False,
 ):
-    """Find all-pairs shortest path lengths using Floyd's algorithm
-
-    Floyd's algorithm is used for finding shortest paths in dense graphs
-    or graphs with negative weights (where Dijkstra's algorithm fails).
-
-    This function is multithreaded and will launch a pool with threads equal
-    to the number of CPUs by default if the number of nodes in the graph is
-    above the value of ``parallel_threshold`` (it defaults to 300).
-    You can tune the number of threads with the ``RAYON_NUM_THREADS``
-    environment variable. For example, setting ``RAYON_NUM_THREADS=4`` would
-    limit the thread pool to 4 threads if parallelization was enabled.
-
-    :param graph: The graph to run Floyd's algorithm on. Can
-        either be a :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`
-    :param callable weight_fn: A callable object (function, lambda, etc) which
-        will be passed the edge object and expected to return a ``float``. This
-        tells retworkx/rust how to extract a numerical weight as a ``float``
-        for edge object. Some simple examples are::
-
-            floyd_warshall(graph, weight_fn= lambda x: 1)
-
-        to return a weight of 1 for all edges. Also::
-
-            floyd_warshall(graph, weight_fn=float)
-
-        to cast the edge object as a float as the weight. If this is not
-        specified a default value (either ``default_weight`` or 1) will be used
-        for all edges.
-    :param float default_weight: If ``weight_fn`` is not used this can be
-        optionally used to specify a default weight to use for all edges.
-    :param int parallel_threshold: The number of nodes to execute
-        the algorithm in parallel at. It defaults to 300, but this can
-        be tuned
-
-    :return: A read-only dictionary of path lengths. The keys are the source
-        node indices and the values are a dict of the target node and the
-        length of the shortest path to that node. For example::
-
-            {
-                0: {0: 0.0, 1: 2.0, 2: 2.0},
-                1: {1: 0.0, 2: 1.0},
-                2: {0: 1.0, 2: 0.0},
-            }
-
-    :rtype: AllPairsPathLengthMapping
+    """Return the Laplacian matrix of a graph
+
+    The Laplacian matrix is :math:`L = D - A`, where :math:`A` is the
+    weighted adjacency matrix of the graph, as returned by
+    :func:`~retworkx.adjacency_matrix`, and :math:`D` the diagonal matrix of
+    the weighted degrees of the nodes, the row sums of :math:`A`. For a
+    :class:`~retworkx.PyDiGraph` these are the weighted out degrees, or the
+    weighted in degrees, the column sums of :math:`A`, with ``degree="in"``.
+    The normalized Laplacian matrix is
+    :math:`D^{-1/2} L D^{-1/2} = I - D^{-1/2} A D^{-1/2}`, where the row and
+    column of a node with a weighted degree of 0 are 0. The weights of
+    parallel edges are added. A self loop is on the diagonal of :math:`A`
+    and is counted once in the weighted degree of its node, so it doesn't
+    change :math:`L` but it changes the normalized Laplacian matrix. Row
+    ``i`` of the matrix is for the ``i``-th node in
+    ``graph.node_indices()``. For example::
+
+        graph = retworkx.generators.path_graph(3)
+        matrix = retworkx.laplacian_matrix(graph)
+        # [[ 1. -1.  0.]
+        #  [-1.  2. -1.]
+        #  [ 0. -1.  1.]]
+
+    With ``sparse`` the matrix is returned in the compressed sparse row
+    format without creating the dense matrix, with an entry on the diagonal
+    for every node and an entry for every pair of nodes with an edge, sorted
+    by column in every row. The arrays can be passed to ``scipy.sparse``::
+
+        indptr, indices, data = retworkx.laplacian_matrix(graph, sparse=True)
+        n = len(graph)
+        matrix = scipy.sparse.csr_matrix((data, indices, indptr), shape=(n, n))
+
+    :param graph: The graph to get the Laplacian matrix of. Can either be a
+        :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`.
+    :param weight_fn: An optional callable that will be passed the edge's
+        payload object and is expected to return a ``float`` weight for that
+        edge.
+    :param float default_weight: If ``weight_fn`` isn't specified this
+        optional float value will be used for the weight of every edge.
+        Defaults to 1.0.
+    :param bool normalized: If set to ``True`` the normalized Laplacian
+        matrix is returned instead. Defaults to ``False``.
+    :param bool sparse: If set to ``True`` the matrix is returned in CSR
+        format. Defaults to ``False``.
+    :param str degree: The weighted degrees on the diagonal of the matrix of
+        a :class:`~retworkx.PyDiGraph`, either ``"out"`` (the default) or
+        ``"in"``. This is ignored for a :class:`~retworkx.PyGraph`.
+    :param bool as_undirected: If set to ``True`` every edge of a
+        :class:`~retworkx.PyDiGraph` goes both ways, so the matrix is
+        symmetric and ``degree`` is ignored. This is ignored for a
+        :class:`~retworkx.PyGraph`.
+
+    :returns: The Laplacian matrix as a numpy array or, with ``sparse``, a
+        tuple of the ``indptr``, ``indices`` and ``data`` numpy arrays, where
+        ``indptr`` and ``indices`` are ``numpy.int64`` arrays
+    :rtype: numpy.ndarray or tuple
+    :raises ValueError: If ``degree`` isn't ``"out"`` or ``"in"`` or if
+        ``normalized`` is set and a node has a negative weighted degree
     """
     raise TypeError("Invalid Input Type %s for graph" % type(graph))
 
 
-@floyd_warshall.register(PyDiGraph)
-def _digraph_floyd_warshall(
+@laplacian_matrix.register(PyDiGraph)
+def _digraph_laplacian_matrix(
     graph,
     weight_fn=None,
     default_weight=1.0,
-    parallel_threshold=300,
+    normalized=False,
+    sparse=False,
+    degree="out",
+    as_undirected=False,
 ):
-    return digraph_floyd_warshall(
+    return digraph_laplacian_matrix(
         graph,
         weight_fn=weight_fn,
         default_weight=default_weight,
-        parallel_threshold=parallel_threshold,
+        normalized=normalized,
+        sparse=sparse,
+        degree=degree,
+        as_undirected=as_undirected,
     )
 
 
-@floyd_warshall.register(PyGraph)
-def _graph_floyd_warshall(
+@laplacian_matrix.register(PyGraph)
+def _graph_laplacian_matrix(
     graph,
     weight_fn=None,
     default_weight=1.0,
-    parallel_threshold=300,
+    normalized=False,
+    sparse=False,
+    degree="out",
+    as_undirected=False,
 ):
-    return graph_floyd_warshall(
+    return graph_laplacian_matrix(
         graph,
         weight_fn=weight_fn,
         default_weight=default_weight,
-        parallel_threshold=parallel_threshold,
+        normalized=normalized,
+        sparse=sparse,
     )
 
-
 @functools.singledispatch
-def floyd_warshall_numpy(
-    graph,
-    weight_fn=None,
-    default_weight=1.0,
-    parallel_threshold=300,
+def linear_operator(
+    graph, matrix="adjacency", weight_fn=None, default_weight=1.0, parallel_threshold=300
 ):
-    """Find all-pairs shortest path lengths using Floyd's algorithm
-
-    Floyd's algorithm is used for finding shortest paths in dense graphs
-    or graphs with negative weights (where Dijkstra's algorithm fails).
-
-    This function is multithreaded and will launch a pool with threads equal
-    to the number of CPUs by default if the number of nodes in the graph is
-    above the value of ``parallel_threshold`` (it defaults to 300).
-    You can tune the number of threads with the ``RAYON_NUM_THREADS``
-    environment variable. For example, setting ``RAYON_NUM_THREADS=4`` would
-    limit the thread pool to 4 threads if parallelization was enabled.
-
-    :param graph: The graph to run Floyd's algorithm on. Can
-        either be a :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`
-    :param callable weight_fn: A callable object (function, lambda, etc) which
-        will be passed the edge object and expected to return a ``float``. This
-        tells retworkx/rust how to extract a numerical weight as a ``float``
-        for edge object. Some simple examples are::
+    """Return a matrix of a graph as a linear operator
 
-            floyd_warshall_numpy(graph, weight_fn: lambda x: 1)
-
-        to return a weight of 1 for all edges. Also::
+    The returned :class:`~retworkx.GraphOperator` multiplies vectors by the
+    adjacency, Laplacian, or random walk transition matrix of the graph
+    without building the matrix, the products are computed from the edges of
+    the graph every time. This makes it possible to use iterative solvers on
+    graphs too large to export as a matrix with :func:`~retworkx.adjacency_matrix`.
+    The rows and columns of the matrix are in the order of
+    :attr:`~retworkx.GraphOperator.node_indices`. For example, to find the
+    smallest eigenvalues of the Laplacian matrix of a graph with scipy::
 
-            floyd_warshall_numpy(graph, weight_fn: lambda x: float(x))
+        from scipy.sparse.linalg import aslinearoperator, eigsh
 
-        to cast the edge object as a float as the weight. If this is not
-        specified a default value (either ``default_weight`` or 1) will be used
-        for all edges.
-    :param float default_weight: If ``weight_fn`` is not used this can be
-        optionally used to specify a default weight to use for all edges.
-    :param int parallel_threshold: The number of nodes to execute
-        the algorithm in parallel at. It defaults to 300, but this can
-        be tuned
+        operator = retworkx.linear_operator(graph, matrix="laplacian")
+        eigenvalues = eigsh(aslinearoperator(operator), k=3, which="SA")[0]
 
-    :returns: A matrix of shortest path distances between nodes. If there is no
-        path between two nodes then the corresponding matrix entry will be
-        ``np.inf``.
-    :rtype: numpy.ndarray
+    :param graph: The graph to get the matrix of. Can either be a
+        :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`
+    :param str matrix: The matrix of the graph, either ``"adjacency"`` for
+        the weighted adjacency matrix :math:`A`, ``"laplacian"`` for the
+        Laplacian matrix :math:`L = D - A` where :math:`D` is the diagonal
+        matrix of the weighted (out) degrees, or ``"transition"`` for the
+        random walk transition matrix :math:`P = D^{-1} A`, where the row of a
+        node with a weighted (out) degree of 0 is 0.
+    :param weight_fn: An optional callable that will be passed the edge's
+        payload object and is expected to return a ``float`` weight for that
+        edge. It's called once per edge when the operator is created. The
+        weights of parallel edges are added.
+    :param float default_weight: If ``weight_fn`` isn't specified this
+        optional float value will be used for the weight of every edge
+    :param int parallel_threshold: The number of nodes from which the
+        products are computed on multiple threads. Defaults to 300.
+
+    :returns: The matrix of ``graph`` as a linear operator
+    :rtype: GraphOperator
+    :raises ValueError: If ``matrix`` isn't one of the supported matrices
     """
     raise TypeError("Invalid Input Type %s for graph" % type(graph))
 
 
-@floyd_warshall_numpy.register(PyDiGraph)
-def _digraph_floyd_warshall_numpy(
-    graph, weight_fn=None, default_weight=1.0, parallel_threshold=300
+@linear_operator.register(PyDiGraph)
+def _digraph_linear_operator(
+    graph, matrix="adjacency", weight_fn=None, default_weight=1.0, parallel_threshold=300
 ):
-    return digraph_floyd_warshall_numpy(
+    return digraph_linear_operator(
         graph,
+        matrix=matrix,
         weight_fn=weight_fn,
         default_weight=default_weight,
         parallel_threshold=parallel_threshold,
     )
 
 
-@floyd_warshall_numpy.register(PyGraph)
-def _graph_floyd_warshall_numpy(graph, weight_fn=None, default_weight=1.0, parallel_threshold=300):
-    return graph_floyd_warshall_numpy(
+@linear_operator.register(PyGraph)
+def _graph_linear_operator(
+    graph, matrix="adjacency", weight_fn=None, default_weight=1.0, parallel_threshold=300
+):
+    return graph_linear_operator(
         graph,
+        matrix=matrix,
         weight_fn=weight_fn,
         default_weight=default_weight,
         parallel_threshold=parallel_threshold,
@@ -483,1077 +658,4945 @@ def _graph_floyd_warshall_numpy(graph, weight_fn=None, default_weight=1.0, paral
 
 
 @functools.singledispatch
-def astar_shortest_path(graph, node, goal_fn, edge_cost_fn, estimate_cost_fn):
-    """Compute the A* shortest path for a graph
+def edge_interval_index(graph, interval_fn):
+    """Index the edges of a graph by the time interval during which they are
+    active
+
+    The returned :class:`~retworkx.EdgeIntervalIndex` finds the edges active
+    in a time window without scanning all the edges of the graph, and builds
+    a view of the graph in the window. Finding the :math:`k` edges active in
+    a window takes :math:`O(\\log m + k)` time for a graph with :math:`m`
+    edges, after building the index in :math:`O(m \\log m)` time. For
+    example, to count the edges of a temporal graph active in sliding
+    windows::
+
+        index = retworkx.edge_interval_index(graph, lambda edge: edge["time"])
+        counts = [len(index.edges_active_in(t, t + 10)) for t in range(0, 100, 10)]
+
+    :param graph: The graph to index. Can either be a
+        :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`
+    :param interval_fn: A callable that will be passed the edge's payload
+        object and is expected to return a tuple ``(start, end)`` of ``float``
+        times, the interval during which the edge is active. Both bounds are
+        included, so ``start`` can be equal to ``end``. It's called once per
+        edge when the index is created.
+
+    :returns: The index of the edges of ``graph``
+    :rtype: EdgeIntervalIndex
+    :raises ValueError: If an interval is NaN or starts after it ends
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(graph))
 
-    :param graph: The input graph to use. Can
-        either be a :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`
-    :param int node: The node index to compute the path from
-    :param goal_fn: A python callable that will take in 1 parameter, a node's
-        data object and will return a boolean which will be True if it is the
-        finish node.
-    :param edge_cost_fn: A python callable that will take in 1 parameter, an
-        edge's data object and will return a float that represents the cost
-        of that edge. It must be non-negative.
-    :param estimate_cost_fn: A python callable that will take in 1 parameter, a
-        node's data object and will return a float which represents the
-        estimated cost for the next node. The return must be non-negative. For
-        the algorithm to find the actual shortest path, it should be
-        admissible, meaning that it should never overestimate the actual cost
-        to get to the nearest goal node.
 
-    :returns: The computed shortest path between node and finish as a list
-        of node indices.
-    :rtype: NodeIndices
+@edge_interval_index.register(PyDiGraph)
+def _digraph_edge_interval_index(graph, interval_fn):
+    return digraph_edge_interval_index(graph, interval_fn)
+
+
+@edge_interval_index.register(PyGraph)
+def _graph_edge_interval_index(graph, interval_fn):
+    return graph_edge_interval_index(graph, interval_fn)
+
+
+@functools.singledispatch
+def edge_cut_partition(graph, partition, halo_depth=1):
+    """Split a partitioned graph into parts along the edges between them
+
+    Given a k-way partition of the nodes of the graph, this returns a
+    subgraph for every part with the nodes of the part and its halo (or
+    ghost) nodes, the nodes of other parts within ``halo_depth`` edges of a
+    node of the part, and the cut edges between nodes of different parts.
+    The halo nodes are found ignoring the direction of the edges. This is the
+    input needed to process the parts of a graph separately, for example in
+    a distributed job, where the halo nodes are the copies of the
+    neighboring nodes owned by other parts. For example::
+
+        graph = retworkx.generators.path_graph(5)
+        result = retworkx.edge_cut_partition(graph, [[0, 1], [2, 3, 4]])
+        first = result.parts[0]
+        # first.nodes == [0, 1], first.halo_nodes == [2] and
+        # first.halo_owners == [1]
+        # result.cut_edges == [(1, 1, 2, 0, 1)]
+
+    :param graph: The graph to split. Can either be a
+        :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`
+    :param list partition: A list of parts, where every part is a list of
+        node indices. Every node of the graph must be in exactly one part. The
+        part ids are the positions of the parts in this list.
+    :param int halo_depth: The maximum number of edges from a node of a part
+        to its halo nodes. A ``halo_depth`` of 0 gives no halo nodes. Defaults
+        to 1.
+
+    :returns: The parts and the cut edges of the graph
+    :rtype: EdgeCutPartition
+    :raises InvalidNode: If a node index in ``partition`` is not in the graph
+    :raises ValueError: If a node of the graph is not in exactly one part
     """
     raise TypeError("Invalid Input Type %s for graph" % type(graph))
 
 
-@astar_shortest_path.register(PyDiGraph)
-def _digraph_astar_shortest_path(graph, node, goal_fn, edge_cost_fn, estimate_cost_fn):
-    return digraph_astar_shortest_path(graph, node, goal_fn, edge_cost_fn, estimate_cost_fn)
+@edge_cut_partition.register(PyDiGraph)
+def _digraph_edge_cut_partition(graph, partition, halo_depth=1):
+    return digraph_edge_cut_partition(graph, partition, halo_depth=halo_depth)
 
 
-@astar_shortest_path.register(PyGraph)
-def _graph_astar_shortest_path(graph, node, goal_fn, edge_cost_fn, estimate_cost_fn):
-    return graph_astar_shortest_path(graph, node, goal_fn, edge_cost_fn, estimate_cost_fn)
+@edge_cut_partition.register(PyGraph)
+def _graph_edge_cut_partition(graph, partition, halo_depth=1):
+    return graph_edge_cut_partition(graph, partition, halo_depth=halo_depth)
 
 
 @functools.singledispatch
-def dijkstra_shortest_paths(
-    graph,
-    source,
-    target=None,
-    weight_fn=None,
-    default_weight=1.0,
-    as_undirected=False,
-):
-    """Find the shortest path from a node
+def aggregate_graph(graph, groups, weight_fn=None, default_weight=1.0):
+    """Aggregate the groups of nodes of a graph into the nodes of a smaller graph
+
+    This builds the quotient graph of a grouping of the nodes, for example to
+    draw a graph too large to draw node by node: every group of nodes becomes
+    a single node, and the edges between the nodes of two groups become a
+    single edge between them, following the direction of the edges for a
+    :class:`~retworkx.PyDiGraph`. The groups are given either by a partition
+    of the nodes or by a callable returning a key for every node, such as a
+    node attribute. For example::
+
+        graph = retworkx.generators.path_graph(4)
+        aggregated = retworkx.aggregate_graph(graph, [[0, 1], [2, 3]])
+        # aggregated.nodes()[0] == {"group": 0, "nodes": [0, 1], "size": 2,
+        #     "internal_edges": 1, "internal_weight": 1.0, "density": 1.0}
+        # aggregated.weighted_edge_list() == [(0, 1, {"count": 1, "weight": 1.0})]
+
+    The payload of every node of the aggregated graph is a ``dict`` with the
+    ``"group"`` key (or position in a partition), the list of ``"nodes"``,
+    the ``"size"``, the number of ``"internal_edges"`` and their
+    ``"internal_weight"``, and the ``"density"`` of the group. The payload of
+    every edge is a ``dict`` with the ``"count"`` of the edges between the two
+    groups and their total ``"weight"``.
+
+    :param graph: The graph to aggregate. Can either be a
+        :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`
+    :param groups: Either a partition of the nodes, as a list of lists of node
+        indices where every node of the graph is in exactly one list, or a
+        callable that will be passed the payload of every node and is
+        expected to return the hashable key of its group. The groups from a
+        callable are in the order of the lowest node index of each group.
+    :param weight_fn: An optional callable that will be passed the edge's
+        payload object and is expected to return a ``float`` weight for that
+        edge.
+    :param float default_weight: If ``weight_fn`` isn't specified this
+        optional float value will be used for the weight of every edge.
+        Defaults to 1.0.
+
+    :returns: The aggregated graph, of the same type as ``graph``, with a node
+        for every group in order
+    :raises InvalidNode: If a node index in a partition is not in the graph
+    :raises ValueError: If a node of the graph is not in exactly one part of a
+        partition
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(graph))
 
-    This function will generate the shortest path from a source node using
-    Dijkstra's algorithm.
 
-    :param graph: The input graph to use. Can either be a
+@aggregate_graph.register(PyDiGraph)
+def _digraph_aggregate_graph(graph, groups, weight_fn=None, default_weight=1.0):
+    return digraph_aggregate_graph(
+        graph, groups, weight_fn=weight_fn, default_weight=default_weight
+    )
+
+
+@aggregate_graph.register(PyGraph)
+def _graph_aggregate_graph(graph, groups, weight_fn=None, default_weight=1.0):
+    return graph_aggregate_graph(graph, groups, weight_fn=weight_fn, default_weight=default_weight)
+
+
+@functools.singledispatch
+def coarsen(graph, weight_fn=None, default_weight=1.0, min_nodes=100, max_levels=None, seed=None):
+    """Coarsen a graph into a hierarchy of progressively smaller graphs
+
+    Every level of the hierarchy is built by merging the pairs of nodes of a
+    heavy edge matching of the previous level, where every node is matched
+    with the neighbor with the heaviest edge between them, and adding up the
+    weights of the edges between the merged nodes. This is the coarsening
+    phase of multilevel algorithms, which lay out or partition the coarsest
+    graph and then refine the result level by level, using
+    :meth:`~retworkx.CoarseningHierarchy.prolong` to carry it to the next
+    finer level. The direction of the edges is ignored, parallel edges are
+    merged and self loops are ignored. For example::
+
+        graph = retworkx.generators.grid_graph(32, 32)
+        hierarchy = retworkx.coarsen(graph, min_nodes=16, seed=42)
+        coarsest = hierarchy.graphs[-1]
+        pos = retworkx.spring_layout(coarsest, seed=42)
+        for level in range(len(hierarchy.graphs), 0, -1):
+            # Start the layout of every level from the positions of the
+            # coarser level
+            pos = hierarchy.prolong(level, [pos[node] for node in range(len(pos))])
+            finer = hierarchy.graphs[level - 2] if level > 1 else graph
+            pos = retworkx.spring_layout(finer, pos=pos, seed=42)
+
+    Coarsening stops when a level has at most ``min_nodes`` nodes, when
+    ``max_levels`` levels were built, or when a matching merges less than 5%
+    of the nodes, for example when only isolated nodes are left or for
+    star-like graphs, where only a few nodes can be matched.
+
+    :param graph: The graph to coarsen. Can either be a
         :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`
-    :param int source: The node index to find paths from
-    :param int target: An optional target to find a path to
-    :param weight_fn: An optional weight function for an edge. It will accept
-        a single argument, the edge's weight object and will return a float
-        which will be used to represent the weight/cost of the edge
-    :param float default_weight: If ``weight_fn`` isn't specified this optional
-        float value will be used for the weight/cost of each edge.
-    :param bool as_undirected: If set to true the graph will be treated as
-        undirected for finding the shortest path. This only works with a
-        :class:`~retworkx.PyDiGraph` input for ``graph``
+    :param weight_fn: An optional callable that will be passed the edge's
+        payload object and is expected to return a ``float`` weight for that
+        edge.
+    :param float default_weight: If ``weight_fn`` isn't specified this
+        optional float value will be used for the weight of every edge.
+        Defaults to 1.0.
+    :param int min_nodes: The number of nodes at or below which coarsening
+        stops. Defaults to 100.
+    :param int max_levels: The maximum number of levels to build. If not
+        specified there is no limit.
+    :param int seed: An optional seed to use for the random number generator
+        that sets the order in which the nodes are matched
 
-    :return: Dictionary of paths. The keys are destination node indices and
-        the dict values are lists of node indices making the path.
-    :rtype: dict
+    :returns: The hierarchy of coarse graphs
+    :rtype: CoarseningHierarchy
     """
     raise TypeError("Invalid Input Type %s for graph" % type(graph))
 
 
-@dijkstra_shortest_paths.register(PyDiGraph)
-def _digraph_dijkstra_shortest_path(
-    graph,
-    source,
-    target=None,
-    weight_fn=None,
-    default_weight=1.0,
-    as_undirected=False,
+@coarsen.register(PyDiGraph)
+def _digraph_coarsen(
+    graph, weight_fn=None, default_weight=1.0, min_nodes=100, max_levels=None, seed=None
 ):
-    return digraph_dijkstra_shortest_paths(
+    return digraph_coarsen(
         graph,
-        source,
-        target=target,
         weight_fn=weight_fn,
         default_weight=default_weight,
-        as_undirected=as_undirected,
+        min_nodes=min_nodes,
+        max_levels=max_levels,
+        seed=seed,
     )
 
 
-@dijkstra_shortest_paths.register(PyGraph)
-def _graph_dijkstra_shortest_path(graph, source, target=None, weight_fn=None, default_weight=1.0):
-    return graph_dijkstra_shortest_paths(
+@coarsen.register(PyGraph)
+def _graph_coarsen(
+    graph, weight_fn=None, default_weight=1.0, min_nodes=100, max_levels=None, seed=None
+):
+    return graph_coarsen(
         graph,
-        source,
-        target=target,
         weight_fn=weight_fn,
         default_weight=default_weight,
+        min_nodes=min_nodes,
+        max_levels=max_levels,
+        seed=seed,
     )
 
 
 @functools.singledispatch
-def all_pairs_dijkstra_shortest_paths(graph, edge_cost_fn):
-    """For each node in the graph, finds the shortest paths to all others.
+def kernighan_lin_bisection(graph, weight_fn=None, default_weight=1.0, max_iter=10, seed=None):
+    """Split the nodes of a graph into two parts with a small weight of the
+    edges between them using the Kernighan-Lin algorithm
+
+    The two parts have the same number of nodes, or differ by one node for an
+    odd number of nodes. Starting from a random bisection, every pass of the
+    algorithm moves every node to the other part once, alternately from each
+    part and always the node whose move decreases the weight of the cut the
+    most, and keeps the moves up to the pair of moves that decreased it the
+    most. The passes stop when a pass doesn't decrease the weight of the cut
+    or after ``max_iter`` passes. The direction of the edges is ignored and
+    self loops are ignored. For example::
+
+        graph = retworkx.generators.barbell_graph(4, 0)
+        result = retworkx.kernighan_lin_bisection(graph, seed=42)
+        # sorted(result.parts) == [[0, 1, 2, 3], [4, 5, 6, 7]]
+        # result.cut_weight == 1.0
+
+    This is a heuristic, the bisection found is a local optimum which can
+    depend on ``seed``.
+
+    :param graph: The graph to bisect. Can either be a
+        :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`
+    :param weight_fn: An optional callable that will be passed the edge's
+        payload object and is expected to return a ``float`` weight for that
+        edge.
+    :param float default_weight: If ``weight_fn`` isn't specified this
+        optional float value will be used for the weight of every edge.
+        Defaults to 1.0.
+    :param int max_iter: The maximum number of passes. Defaults to 10.
+    :param int seed: An optional seed to use for the random number generator
+        that builds the initial bisection
 
-    This function will generate the shortest path from all nodes in the graph
-    using Dijkstra's algorithm. This function is multithreaded and will run
-    launch a thread pool with threads equal to the number of CPUs by default.
-    You can tune the number of threads with the ``RAYON_NUM_THREADS``
-    environment variable. For example, setting ``RAYON_NUM_THREADS=4`` would
-    limit the thread pool to 4 threads.
+    :returns: The two parts and the weight of the cut
+    :rtype: GraphPartitioning
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(graph))
 
-    :param graph: The input graph to use. Can either be a
+
+@kernighan_lin_bisection.register(PyDiGraph)
+def _digraph_kernighan_lin_bisection(
+    graph, weight_fn=None, default_weight=1.0, max_iter=10, seed=None
+):
+    return digraph_kernighan_lin_bisection(
+        graph,
+        weight_fn=weight_fn,
+        default_weight=default_weight,
+        max_iter=max_iter,
+        seed=seed,
+    )
+
+
+@kernighan_lin_bisection.register(PyGraph)
+def _graph_kernighan_lin_bisection(
+    graph, weight_fn=None, default_weight=1.0, max_iter=10, seed=None
+):
+    return graph_kernighan_lin_bisection(
+        graph,
+        weight_fn=weight_fn,
+        default_weight=default_weight,
+        max_iter=max_iter,
+        seed=seed,
+    )
+
+
+@functools.singledispatch
+def multilevel_partition(
+    graph, num_parts, weight_fn=None, default_weight=1.0, imbalance=0.03, seed=None
+):
+    """Split the nodes of a graph into parts of about the same size with a
+    small weight of the edges between them using a multilevel algorithm
+
+    The graph is coarsened as with :func:`~retworkx.coarsen`, the coarsest
+    graph is split by growing the parts from random nodes, and the partition
+    is projected back level by level, moving the nodes at the boundary of the
+    parts to decrease the weight of the cut at every level. The direction of
+    the edges is ignored and self loops are ignored. For example::
+
+        graph = retworkx.generators.grid_graph(16, 16)
+        result = retworkx.multilevel_partition(graph, 4, seed=42)
+        # Split the graph into the subgraphs of the parts
+        subgraphs = retworkx.edge_cut_partition(graph, result.parts)
+
+    The moves keep every part at or below
+    ``max(ceil(n / num_parts), (1 + imbalance) * n / num_parts)`` nodes for a
+    graph of ``n`` nodes, and the nodes of larger parts are moved to parts
+    with room for them, but the balance is not guaranteed. This is a
+    heuristic, the partition found can depend on ``seed``.
+
+    :param graph: The graph to partition. Can either be a
         :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`
-    :param edge_cost_fn: A callable object that acts as a weight function for
-        an edge. It will accept a single positional argument, the edge's weight
-        object and will return a float which will be used to represent the
-        weight/cost of the edge
+    :param int num_parts: The number of parts
+    :param weight_fn: An optional callable that will be passed the edge's
+        payload object and is expected to return a ``float`` weight for that
+        edge.
+    :param float default_weight: If ``weight_fn`` isn't specified this
+        optional float value will be used for the weight of every edge.
+        Defaults to 1.0.
+    :param float imbalance: The fraction of the average part size a part can
+        exceed it by. Defaults to 0.03.
+    :param int seed: An optional seed to use for the random number generator
 
-    :return: A read-only dictionary of paths. The keys are source node
-        indices and the values are a dict of target node indices and a list
-        of node indices making the path. For example::
+    :returns: The parts, some of which can be empty if ``num_parts`` is
+        larger than the number of nodes, and the weight of the cut
+    :rtype: GraphPartitioning
+    :raises ValueError: If ``num_parts`` is 0 or ``imbalance`` is negative
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(graph))
 
-            {
-                0: {1: [0, 1],  2: [0, 1, 2]},
-                1: {2: [1, 2]},
-                2: {0: [2, 0]},
-            }
 
-    :rtype: AllPairsPathMapping
+@multilevel_partition.register(PyDiGraph)
+def _digraph_multilevel_partition(
+    graph, num_parts, weight_fn=None, default_weight=1.0, imbalance=0.03, seed=None
+):
+    return digraph_multilevel_partition(
+        graph,
+        num_parts,
+        weight_fn=weight_fn,
+        default_weight=default_weight,
+        imbalance=imbalance,
+        seed=seed,
+    )
+
+
+@multilevel_partition.register(PyGraph)
+def _graph_multilevel_partition(
+    graph, num_parts, weight_fn=None, default_weight=1.0, imbalance=0.03, seed=None
+):
+    return graph_multilevel_partition(
+        graph,
+        num_parts,
+        weight_fn=weight_fn,
+        default_weight=default_weight,
+        imbalance=imbalance,
+        seed=seed,
+    )
+
+@functools.singledispatch
+def fiedler_vector(
+    graph, weight_fn=None, default_weight=1.0, max_iter=1000, tol=1e-8, explain=False
+):
+    """Compute the Fiedler vector of a graph
+
+    The Fiedler vector is the eigenvector of the second smallest eigenvalue
+    of the Laplacian matrix :math:`L = D - A` of the graph, where :math:`A`
+    is its weighted adjacency matrix and :math:`D` the diagonal matrix of the
+    weighted degrees of the nodes. Its entries order the nodes along the
+    graph, and their signs split the graph into two parts with few edges
+    between them, see :func:`~retworkx.spectral_bisection`. The direction of
+    the edges is ignored, self loops don't change the Laplacian matrix and
+    the weights of parallel edges are added. The vector is computed with the
+    Lanczos method, which only multiplies vectors by the sparse Laplacian
+    matrix. For example::
+
+        graph = retworkx.generators.path_graph(5)
+        fiedler = retworkx.fiedler_vector(graph)
+        # The entries increase along the path:
+        # [-0.6015, -0.3717, 0.0, 0.3717, 0.6015]
+
+    :param graph: The graph to compute the Fiedler vector of. Can either be a
+        :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`. It must be
+        (weakly) connected, otherwise the Fiedler vector is not unique.
+    :param weight_fn: An optional callable that will be passed the edge's
+        payload object and is expected to return a non-negative ``float``
+        weight for that edge.
+    :param float default_weight: If ``weight_fn`` isn't specified this
+        optional float value will be used for the weight of every edge.
+        Defaults to 1.0.
+    :param int max_iter: The maximum number of Lanczos iterations, the number
+        of products of a vector by the Laplacian matrix. Defaults to 1000.
+    :param float tol: The error tolerance used when checking for convergence,
+        the iteration stops once the residual norm of the Fiedler vector is
+        less than ``tol`` times twice the largest weighted degree. Defaults to
+        1e-8.
+    :param bool explain: If set to ``True`` an
+        :class:`~retworkx.AlgorithmInfo` recording how the algorithm was run
+        is returned along with the result
+
+    :returns: a read-only dict-like object whose keys are the node indices and
+        values are the entries of the Fiedler vector, normalized to a
+        euclidean norm of 1 with a positive entry of largest absolute value.
+        If ``explain`` is ``True`` a tuple of this and an
+        :class:`~retworkx.AlgorithmInfo` is returned instead.
+    :rtype: CentralityMapping
+    :raises ValueError: If the graph has less than 2 nodes or isn't connected
+    :raises FailedToConverge: If the Lanczos method doesn't converge within
+        ``max_iter`` iterations
     """
     raise TypeError("Invalid Input Type %s for graph" % type(graph))
 
 
-@all_pairs_dijkstra_shortest_paths.register(PyDiGraph)
-def _digraph_all_pairsdijkstra_shortest_path(graph, edge_cost_fn):
-    return digraph_all_pairs_dijkstra_shortest_paths(graph, edge_cost_fn)
+@fiedler_vector.register(PyDiGraph)
+def _digraph_fiedler_vector(
+    graph, weight_fn=None, default_weight=1.0, max_iter=1000, tol=1e-8, explain=False
+):
+    return digraph_fiedler_vector(
+        graph,
+        weight_fn=weight_fn,
+        default_weight=default_weight,
+        max_iter=max_iter,
+        tol=tol,
+        explain=explain,
+    )
 
 
-@all_pairs_dijkstra_shortest_paths.register(PyGraph)
-def _graph_all_pairs_dijkstra_shortest_path(graph, edge_cost_fn):
-    return graph_all_pairs_dijkstra_shortest_paths(graph, edge_cost_fn)
+@fiedler_vector.register(PyGraph)
+def _graph_fiedler_vector(
+    graph, weight_fn=None, default_weight=1.0, max_iter=1000, tol=1e-8, explain=False
+):
+    return graph_fiedler_vector(
+        graph,
+        weight_fn=weight_fn,
+        default_weight=default_weight,
+        max_iter=max_iter,
+        tol=tol,
+        explain=explain,
+    )
+
+@functools.singledispatch
+def algebraic_connectivity(
+    graph, weight_fn=None, default_weight=1.0, max_iter=1000, tol=1e-8, explain=False
+):
+    """Compute the algebraic connectivity of a graph
+
+    The algebraic connectivity is the second smallest eigenvalue of the
+    Laplacian matrix :math:`L = D - A` of the graph, where :math:`A` is its
+    weighted adjacency matrix and :math:`D` the diagonal matrix of the
+    weighted degrees of the nodes. It's positive if and only if the graph is
+    (weakly) connected, and the larger it is the harder it is to disconnect
+    the graph by removing edges. The direction of the edges is ignored, self
+    loops don't change the Laplacian matrix and the weights of parallel edges
+    are added. It's computed with the Lanczos method along with the Fiedler
+    vector, see :func:`~retworkx.fiedler_vector`, and it's 0 without any
+    computation for a graph that isn't connected. For example::
+
+        graph = retworkx.generators.complete_graph(5)
+        connectivity = retworkx.algebraic_connectivity(graph)
+        # connectivity == 5.0 (up to rounding)
+
+    :param graph: The graph to compute the algebraic connectivity of. Can
+        either be a :class:`~retworkx.PyGraph` or
+        :class:`~retworkx.PyDiGraph`
+    :param weight_fn: An optional callable that will be passed the edge's
+        payload object and is expected to return a non-negative ``float``
+        weight for that edge.
+    :param float default_weight: If ``weight_fn`` isn't specified this
+        optional float value will be used for the weight of every edge.
+        Defaults to 1.0.
+    :param int max_iter: The maximum number of Lanczos iterations, the number
+        of products of a vector by the Laplacian matrix. Defaults to 1000.
+    :param float tol: The error tolerance used when checking for convergence,
+        the iteration stops once the residual norm of the Fiedler vector is
+        less than ``tol`` times twice the largest weighted degree. Defaults to
+        1e-8.
+    :param bool explain: If set to ``True`` an
+        :class:`~retworkx.AlgorithmInfo` recording how the algorithm was run
+        is returned along with the result
+
+    :returns: The algebraic connectivity of the graph. If ``explain`` is
+        ``True`` a tuple of this and an :class:`~retworkx.AlgorithmInfo` is
+        returned instead.
+    :rtype: float
+    :raises ValueError: If the graph has less than 2 nodes
+    :raises FailedToConverge: If the Lanczos method doesn't converge within
+        ``max_iter`` iterations
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(graph))
+
 
+@algebraic_connectivity.register(PyDiGraph)
+def _digraph_algebraic_connectivity(
+    graph, weight_fn=None, default_weight=1.0, max_iter=1000, tol=1e-8, explain=False
+):
+    return digraph_algebraic_connectivity(
+        graph,
+        weight_fn=weight_fn,
+        default_weight=default_weight,
+        max_iter=max_iter,
+        tol=tol,
+        explain=explain,
+    )
+
+
+@algebraic_connectivity.register(PyGraph)
+def _graph_algebraic_connectivity(
+    graph, weight_fn=None, default_weight=1.0, max_iter=1000, tol=1e-8, explain=False
+):
+    return graph_algebraic_connectivity(
+        graph,
+        weight_fn=weight_fn,
+        default_weight=default_weight,
+        max_iter=max_iter,
+        tol=tol,
+        explain=explain,
+    )
 
 @functools.singledispatch
-def all_pairs_dijkstra_path_lengths(graph, edge_cost_fn):
-    """For each node in the graph, calculates the lengths of the shortest paths to all others.
+def spectral_bisection(
+    graph, weight_fn=None, default_weight=1.0, max_iter=1000, tol=1e-8, explain=False
+):
+    """Split the nodes of a graph into two parts by the signs of the
+    entries of its Fiedler vector
+
+    The first part has the nodes with a negative entry and the second part
+    the nodes with a non-negative entry of the Fiedler vector computed by
+    :func:`~retworkx.fiedler_vector`. This is the spectral relaxation of the
+    bisection minimizing the ratio cut, so the parts have few edges between
+    them but aren't necessarily of the same size, unlike with
+    :func:`~retworkx.kernighan_lin_bisection`. The direction of the edges is
+    ignored. For example::
+
+        graph = retworkx.generators.barbell_graph(4, 0)
+        result = retworkx.spectral_bisection(graph)
+        # result.parts == [[0, 1, 2, 3], [4, 5, 6, 7]] and
+        # result.cut_weight == 1.0
+
+    :param graph: The graph to bisect. Can either be a
+        :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`. It must be
+        (weakly) connected.
+    :param weight_fn: An optional callable that will be passed the edge's
+        payload object and is expected to return a non-negative ``float``
+        weight for that edge.
+    :param float default_weight: If ``weight_fn`` isn't specified this
+        optional float value will be used for the weight of every edge.
+        Defaults to 1.0.
+    :param int max_iter: The maximum number of Lanczos iterations, the number
+        of products of a vector by the Laplacian matrix. Defaults to 1000.
+    :param float tol: The error tolerance used when checking for convergence,
+        the iteration stops once the residual norm of the Fiedler vector is
+        less than ``tol`` times twice the largest weighted degree. Defaults to
+        1e-8.
+    :param bool explain: If set to ``True`` an
+        :class:`~retworkx.AlgorithmInfo` recording how the algorithm was run
+        is returned along with the result
+
+    :returns: The two parts and the weight of the cut. If ``explain`` is
+        ``True`` a tuple of this and an :class:`~retworkx.AlgorithmInfo` is
+        returned instead.
+    :rtype: GraphPartitioning
+    :raises ValueError: If the graph has less than 2 nodes or isn't connected
+    :raises FailedToConverge: If the Lanczos method doesn't converge within
+        ``max_iter`` iterations
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(graph))
 
-    This function will generate the shortest path lengths from all nodes in the
-    graph using Dijkstra's algorithm. This function is multithreaded and will
-    launch a thread pool with threads equal to the number of CPUs by
-    default. You can tune the number of threads with the ``RAYON_NUM_THREADS``
-    environment variable. For example, setting ``RAYON_NUM_THREADS=4`` would
-    limit the thread pool to 4 threads.
 
-    :param graph: The input graph to use. Can either be a
-        :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`
-    :param edge_cost_fn: A callable object that acts as a weight function for
-        an edge. It will accept a single positional argument, the edge's weight
-        object and will return a float which will be used to represent the
-        weight/cost of the edge
+@spectral_bisection.register(PyDiGraph)
+def _digraph_spectral_bisection(
+    graph, weight_fn=None, default_weight=1.0, max_iter=1000, tol=1e-8, explain=False
+):
+    return digraph_spectral_bisection(
+        graph,
+        weight_fn=weight_fn,
+        default_weight=default_weight,
+        max_iter=max_iter,
+        tol=tol,
+        explain=explain,
+    )
 
-    :return: A read-only dictionary of path lengths. The keys are the source
-        node indices and the values are a dict of the target node and the
-        length of the shortest path to that node. For example::
 
-            {
-                0: {1: 2.0, 2: 2.0},
-                1: {2: 1.0},
-                2: {0: 1.0},
-            }
+@spectral_bisection.register(PyGraph)
+def _graph_spectral_bisection(
+    graph, weight_fn=None, default_weight=1.0, max_iter=1000, tol=1e-8, explain=False
+):
+    return graph_spectral_bisection(
+        graph,
+        weight_fn=weight_fn,
+        default_weight=default_weight,
+        max_iter=max_iter,
+        tol=tol,
+        explain=explain,
+    )
 
-    :rtype: AllPairsPathLengthMapping
+@functools.singledispatch
+def random_walks(
+    graph,
+    start_nodes=None,
+    walk_length=80,
+    num_walks=10,
+    p=1.0,
+    q=1.0,
+    seed=None,
+    weight_fn=None,
+    default_weight=1.0,
+    parallel_threshold=300,
+):
+    """Sample random walks of a graph, with the second order bias of node2vec
+
+    Every walk starts at a node of ``start_nodes`` and moves to a neighbor of
+    the current node at every step (a successor for a
+    :class:`~retworkx.PyDiGraph`), picked with a probability proportional to
+    the total weight of the edges to it times a bias depending on the
+    previous node of the walk: :math:`1/p` to go back to the previous node, 1
+    to go to a neighbor of the previous node and :math:`1/q` to go further
+    away. A low ``p`` keeps the walks close to their start nodes, like a
+    breadth first search, and a low ``q`` pushes them away, like a depth
+    first search. With ``p`` and ``q`` set to 1 (the default) these are
+    unbiased weighted random walks, as in DeepWalk.
+
+    Every neighbor is picked in constant time with an alias table and the
+    bias is applied by rejection sampling. Edges with a weight of 0 are
+    never followed, and a walk stops early if it reaches a node without an
+    (outgoing) edge of positive weight. The walks are sampled on multiple
+    threads if there are at least ``parallel_threshold`` of them, every walk
+    has its own random number generator so the walks only depend on
+    ``seed``.
+
+    The walks are returned in a flat array of ``walk_length`` node indices
+    per walk, so it can be reshaped into a walk per row, for example to
+    train node embeddings with a skip-gram model::
+
+        walks = retworkx.random_walks(graph, walk_length=80, num_walks=10, q=0.5)
+        sentences = walks.reshape(-1, 80)
+
+    :param graph: The graph to walk. Can either be a
+        :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`.
+    :param list start_nodes: The node indices to start the walks from. If not
+        specified the walks start from every node.
+    :param int walk_length: The number of nodes of every walk, including its
+        start node. Defaults to 80.
+    :param int num_walks: The number of walks from every start node.
+        Defaults to 10.
+    :param float p: The return parameter, which must be positive. Defaults
+        to 1.0.
+    :param float q: The in-out parameter, which must be positive. Defaults
+        to 1.0.
+    :param int seed: An optional seed to use for the random number generator
+    :param weight_fn: An optional callable that will be passed the edge's
+        payload object and is expected to return a non-negative ``float``
+        weight for that edge.
+    :param float default_weight: If ``weight_fn`` isn't specified this
+        optional float value will be used for the weight of every edge.
+        Defaults to 1.0.
+    :param int parallel_threshold: The number of walks from which they are
+        sampled on multiple threads. Defaults to 300.
+
+    :returns: A 1 dimensional ``numpy.int64`` array of the node indices of
+        ``num_walks`` rounds of walks, every round has a walk from every
+        start node, in the order of ``start_nodes``. A walk that stopped early
+        is padded with ``-1`` up to ``walk_length``.
+    :rtype: numpy.ndarray
+    :raises InvalidNode: If a start node isn't in the graph
+    :raises ValueError: If ``p`` or ``q`` isn't positive or if an edge has a
+        negative or NaN weight
     """
     raise TypeError("Invalid Input Type %s for graph" % type(graph))
 
 
-@all_pairs_dijkstra_path_lengths.register(PyDiGraph)
-def _digraph_all_pairs_dijkstra_path_lengths(graph, edge_cost_fn):
-    return digraph_all_pairs_dijkstra_path_lengths(graph, edge_cost_fn)
+@random_walks.register(PyDiGraph)
+def _digraph_random_walks(
+    graph,
+    start_nodes=None,
+    walk_length=80,
+    num_walks=10,
+    p=1.0,
+    q=1.0,
+    seed=None,
+    weight_fn=None,
+    default_weight=1.0,
+    parallel_threshold=300,
+):
+    return digraph_random_walks(
+        graph,
+        start_nodes=start_nodes,
+        walk_length=walk_length,
+        num_walks=num_walks,
+        p=p,
+        q=q,
+        seed=seed,
+        weight_fn=weight_fn,
+        default_weight=default_weight,
+        parallel_threshold=parallel_threshold,
+    )
 
 
-@all_pairs_dijkstra_path_lengths.register(PyGraph)
-def _graph_all_pairs_dijkstra_path_lengths(graph, edge_cost_fn):
-    return graph_all_pairs_dijkstra_path_lengths(graph, edge_cost_fn)
+@random_walks.register(PyGraph)
+def _graph_random_walks(
+    graph,
+    start_nodes=None,
+    walk_length=80,
+    num_walks=10,
+    p=1.0,
+    q=1.0,
+    seed=None,
+    weight_fn=None,
+    default_weight=1.0,
+    parallel_threshold=300,
+):
+    return graph_random_walks(
+        graph,
+        start_nodes=start_nodes,
+        walk_length=walk_length,
+        num_walks=num_walks,
+        p=p,
+        q=q,
+        seed=seed,
+        weight_fn=weight_fn,
+        default_weight=default_weight,
+        parallel_threshold=parallel_threshold,
+    )
 
 
 @functools.singledispatch
-def dijkstra_shortest_path_lengths(graph, node, edge_cost_fn, goal=None):
-    """Compute the lengths of the shortest paths for a graph object using
-    Dijkstra's algorithm.
+def random_node_sample(graph, num_nodes, seed=None):
+    """Sample nodes of a graph uniformly at random and return the subgraph
+    they induce
 
-    :param graph: The input graph to use. Can either be a
-        :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`
-    :param int node: The node index to use as the source for finding the
-        shortest paths from
-    :param edge_cost_fn: A python callable that will take in 1 parameter, an
-        edge's data object and will return a float that represents the
-        cost/weight of that edge. It must be non-negative
-    :param int goal: An optional node index to use as the end of the path.
-        When specified the traversal will stop when the goal is reached and
-        the output dictionary will only have a single entry with the length
-        of the shortest path to the goal node.
+    Every set of ``num_nodes`` nodes is as likely to be sampled. The nodes of
+    the subgraph have the same degree distribution on average as the nodes
+    of the graph, but the subgraph has few of its edges as every edge is only
+    kept if both its endpoints are sampled.
 
-    :returns: A dictionary of the shortest paths from the provided node where
-        the key is the node index of the end of the path and the value is the
-        cost/sum of the weights of path
-    :rtype: dict
+    :param graph: The graph to sample. Can either be a
+        :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`.
+    :param int num_nodes: The number of nodes to sample
+    :param int seed: An optional seed to use for the random number generator
+
+    :returns: A tuple of the subgraph as a new graph of the same type as
+        ``graph``, with the node and edge payloads of ``graph``, and a
+        mapping of the node indices in ``graph`` to the node indices in the
+        subgraph.
+    :rtype: tuple
+    :raises ValueError: If ``num_nodes`` is larger than the number of nodes
+        of the graph
     """
     raise TypeError("Invalid Input Type %s for graph" % type(graph))
 
 
-@dijkstra_shortest_path_lengths.register(PyDiGraph)
-def _digraph_dijkstra_shortest_path_lengths(graph, node, edge_cost_fn, goal=None):
-    return digraph_dijkstra_shortest_path_lengths(graph, node, edge_cost_fn, goal=goal)
+@random_node_sample.register(PyDiGraph)
+def _digraph_random_node_sample(graph, num_nodes, seed=None):
+    return digraph_random_node_sample(graph, num_nodes, seed=seed)
 
 
-@dijkstra_shortest_path_lengths.register(PyGraph)
-def _graph_dijkstra_shortest_path_lengths(graph, node, edge_cost_fn, goal=None):
-    return graph_dijkstra_shortest_path_lengths(graph, node, edge_cost_fn, goal=goal)
+@random_node_sample.register(PyGraph)
+def _graph_random_node_sample(graph, num_nodes, seed=None):
+    return graph_random_node_sample(graph, num_nodes, seed=seed)
 
 
 @functools.singledispatch
-def k_shortest_path_lengths(graph, start, k, edge_cost, goal=None):
-    """Compute the length of the kth shortest path
+def random_edge_sample(graph, num_edges, seed=None):
+    """Sample edges of a graph uniformly at random and return the subgraph of
+    the sampled edges and their endpoints
 
-    Computes the lengths of the kth shortest path from ``start`` to every
-    reachable node.
+    Every set of ``num_edges`` edges is as likely to be sampled. The subgraph
+    is biased towards the nodes of high degree, which are more likely to be
+    an endpoint of a sampled edge.
 
-    Computes in :math:`O(k * (|E| + |V|*log(|V|)))` time (average).
+    :param graph: The graph to sample. Can either be a
+        :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`.
+    :param int num_edges: The number of edges to sample
+    :param int seed: An optional seed to use for the random number generator
 
-    :param graph: The graph to find the shortest paths in. Can either be a
-        :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`
-    :param int start: The node index to find the shortest paths from
-    :param int k: The kth shortest path to find the lengths of
-    :param edge_cost: A python callable that will receive an edge payload and
-        return a float for the cost of that eedge
-    :param int goal: An optional goal node index, if specified the output
-        dictionary
+    :returns: A tuple of the subgraph as a new graph of the same type as
+        ``graph``, with the node and edge payloads of ``graph``, and a
+        mapping of the node indices in ``graph`` to the node indices in the
+        subgraph.
+    :rtype: tuple
+    :raises ValueError: If ``num_edges`` is larger than the number of edges
+        of the graph
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(graph))
 
-    :returns: A dict of lengths where the key is the destination node index and
-        the value is the length of the path.
-    :rtype: dict
+
+@random_edge_sample.register(PyDiGraph)
+def _digraph_random_edge_sample(graph, num_edges, seed=None):
+    return digraph_random_edge_sample(graph, num_edges, seed=seed)
+
+
+@random_edge_sample.register(PyGraph)
+def _graph_random_edge_sample(graph, num_edges, seed=None):
+    return graph_random_edge_sample(graph, num_edges, seed=seed)
+
+
+@functools.singledispatch
+def forest_fire_sample(graph, num_nodes, burn_probability=0.7, seed=None):
+    """Sample nodes of a graph by forest fire sampling and return the
+    subgraph they induce
+
+    A fire starts at a node picked uniformly at random and spreads to the
+    neighbors of every burning node, ignoring the direction of the edges of a
+    :class:`~retworkx.PyDiGraph` [1]_: every burning node sets fire to
+    :math:`x` of its neighbors that aren't burnt yet, picked uniformly at
+    random, where :math:`x` is geometrically distributed with a mean of
+    :math:`p / (1 - p)` for a ``burn_probability`` of :math:`p`. When the
+    fire dies out before ``num_nodes`` nodes are burnt a new one starts at a
+    random node that isn't burnt yet. The subgraph induced by the burnt nodes
+    keeps many properties of the graph, such as its degree distribution and
+    its clustering, better than uniform sampling.
+
+    :param graph: The graph to sample. Can either be a
+        :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`.
+    :param int num_nodes: The number of nodes to sample
+    :param float burn_probability: The forward burning probability, at least
+        0 and less than 1. Defaults to 0.7.
+    :param int seed: An optional seed to use for the random number generator
+
+    :returns: A tuple of the subgraph as a new graph of the same type as
+        ``graph``, with the node and edge payloads of ``graph``, and a
+        mapping of the node indices in ``graph`` to the node indices in the
+        subgraph.
+    :rtype: tuple
+    :raises ValueError: If ``num_nodes`` is larger than the number of nodes
+        of the graph or if ``burn_probability`` isn't in :math:`[0, 1)`
+
+    .. [1] J. Leskovec and C. Faloutsos, "Sampling from Large Graphs",
+        Proceedings of the 12th ACM SIGKDD International Conference on
+        Knowledge Discovery and Data Mining, 631-636, 2006.
     """
     raise TypeError("Invalid Input Type %s for graph" % type(graph))
 
 
-@k_shortest_path_lengths.register(PyDiGraph)
-def _digraph_k_shortest_path_lengths(graph, start, k, edge_cost, goal=None):
-    return digraph_k_shortest_path_lengths(graph, start, k, edge_cost, goal=goal)
+@forest_fire_sample.register(PyDiGraph)
+def _digraph_forest_fire_sample(graph, num_nodes, burn_probability=0.7, seed=None):
+    return digraph_forest_fire_sample(
+        graph, num_nodes, burn_probability=burn_probability, seed=seed
+    )
 
 
-@k_shortest_path_lengths.register(PyGraph)
-def _graph_k_shortest_path_lengths(graph, start, k, edge_cost, goal=None):
-    return graph_k_shortest_path_lengths(graph, start, k, edge_cost, goal=goal)
+@forest_fire_sample.register(PyGraph)
+def _graph_forest_fire_sample(graph, num_nodes, burn_probability=0.7, seed=None):
+    return graph_forest_fire_sample(graph, num_nodes, burn_probability=burn_probability, seed=seed)
 
 
 @functools.singledispatch
-def dfs_edges(graph, source=None):
-    """Get an edge list of the tree edges from a depth-first traversal
+def temporal_shortest_paths(
+    graph,
+    source,
+    target=None,
+    time_fn=None,
+    time_attr=None,
+    start_time=None,
+    end_time=None,
+    duration_fn=None,
+    duration_attr=None,
+    default_duration=0.0,
+):
+    """Find the earliest arrival paths from a node of a graph with timestamped
+    edges
+
+    Every edge is a contact at a given time, such as an interaction in a
+    temporal network, which can be traversed from its time to its time plus
+    its duration, in either direction for a :class:`~retworkx.PyGraph`. A
+    time-respecting path leaves ``source`` no earlier than ``start_time`` and
+    only follows an edge whose time is not before the arrival at its first
+    node, so its edges have non decreasing times. This finds a path arriving
+    first at every node reachable from ``source`` with a single pass over the
+    edges sorted by time [1]_, in :math:`O(m \\log m)` time for :math:`m`
+    edges.
+
+    The times are read with ``time_fn`` or from the ``time_attr`` edge
+    attribute, one of them must be specified. The edges without a time
+    (``None`` or NaN) are never traversed. The edges active in a time window
+    can also be selected beforehand with :func:`~retworkx.edge_interval_index`.
+    For example:
 
-    The pseudo-code for the DFS algorithm is listed below. The output
-    contains the tree edges found by the procedure.
+    .. jupyter-execute::
 
-    ::
+        import retworkx
 
-        DFS(G, v)
-          let S be a stack
-          label v as discovered
-          PUSH(S, (v, iterator of G.neighbors(v)))
-          while (S != Ø)
-              let (v, iterator) := LAST(S)
-              if hasNext(iterator) then
-                  w := next(iterator)
-                  if w is not labeled as discovered then
-                      label w as discovered                   # (v, w) is a tree edge
-                      PUSH(S, (w, iterator of G.neighbors(w)))
-              else
-                  POP(S)
-          end while
-
-    .. note::
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(range(4))
+        graph.add_edges_from([(0, 1, 1.0), (1, 2, 3.0), (2, 3, 2.0), (0, 2, 5.0)])
+        print(retworkx.temporal_shortest_paths(graph, 0, time_fn=float))
 
-        If the input is an undirected graph with a single connected component,
-        the output of this function is a spanning tree.
-
-    :param graph: The graph to get the DFS edge list from. Can either be a
-        :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`
-    :param int source: An optional node index to use as the starting node
-        for the depth-first search. The edge list will only return edges in
-        the components reachable from this index. If this is not specified
-        then a source will be chosen arbitrarly and repeated until all
-        components of the graph are searched.
-
-    :returns: A list of edges as a tuple of the form ``(source, target)`` in
-        depth-first order
-    :rtype: EdgeList
+    :param graph: The temporal graph. Can either be a
+        :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`.
+    :param int source: The node index to find paths from
+    :param int target: An optional target node index, if specified only the
+        path to it is returned
+    :param time_fn: An optional callable that will be passed the edge's
+        payload object and is expected to return the ``float`` time of that
+        edge, or ``None`` if it has none.
+    :param str time_attr: An optional name of an edge attribute with the time
+        of the edges, read from the edge attribute column with this name if
+        the graph has one, otherwise from this key of the edges with a dict
+        payload.
+    :param float start_time: The earliest time the paths can leave
+        ``source``. If not specified there is no limit.
+    :param float end_time: The latest time the paths can arrive at a node. If
+        not specified there is no limit.
+    :param duration_fn: An optional callable that will be passed the edge's
+        payload object and is expected to return the non-negative ``float``
+        time it takes to traverse that edge.
+    :param str duration_attr: An optional name of an edge attribute with the
+        duration of the edges, read like ``time_attr``
+    :param float default_duration: The duration of the edges if neither
+        ``duration_fn`` nor ``duration_attr`` is specified, or of the edges
+        without a ``duration_attr`` value. Defaults to 0.0.
+
+    :returns: A mapping of every node reachable from ``source``, except
+        ``source``, to the list of node indices of an earliest arrival path
+        to it
+    :rtype: PathMapping
+    :raises InvalidNode: If ``source`` isn't in the graph
+    :raises ValueError: If neither or both of ``time_fn`` and ``time_attr``
+        are specified, if both ``duration_fn`` and ``duration_attr`` are
+        specified or if a duration is negative or NaN
+
+    .. [1] H. Wu, J. Cheng, S. Huang, Y. Ke, Y. Lu and Y. Xu, "Path Problems
+        in Temporal Graphs", Proceedings of the VLDB Endowment, 7(9),
+        721-732, 2014.
     """
     raise TypeError("Invalid Input Type %s for graph" % type(graph))
 
 
-@dfs_edges.register(PyDiGraph)
-def _digraph_dfs_edges(graph, source=None):
-    return digraph_dfs_edges(graph, source=source)
+@temporal_shortest_paths.register(PyDiGraph)
+def _digraph_temporal_shortest_paths(
+    graph,
+    source,
+    target=None,
+    time_fn=None,
+    time_attr=None,
+    start_time=None,
+    end_time=None,
+    duration_fn=None,
+    duration_attr=None,
+    default_duration=0.0,
+):
+    return digraph_temporal_shortest_paths(
+        graph,
+        source,
+        target=target,
+        time_fn=time_fn,
+        time_attr=time_attr,
+        start_time=start_time,
+        end_time=end_time,
+        duration_fn=duration_fn,
+        duration_attr=duration_attr,
+        default_duration=default_duration,
+    )
 
 
-@dfs_edges.register(PyGraph)
-def _graph_dfs_edges(graph, source=None):
-    return graph_dfs_edges(graph, source=source)
+@temporal_shortest_paths.register(PyGraph)
+def _graph_temporal_shortest_paths(
+    graph,
+    source,
+    target=None,
+    time_fn=None,
+    time_attr=None,
+    start_time=None,
+    end_time=None,
+    duration_fn=None,
+    duration_attr=None,
+    default_duration=0.0,
+):
+    return graph_temporal_shortest_paths(
+        graph,
+        source,
+        target=target,
+        time_fn=time_fn,
+        time_attr=time_attr,
+        start_time=start_time,
+        end_time=end_time,
+        duration_fn=duration_fn,
+        duration_attr=duration_attr,
+        default_duration=default_duration,
+    )
 
 
 @functools.singledispatch
-def is_isomorphic(
-    first,
-    second,
-    node_matcher=None,
-    edge_matcher=None,
-    id_order=True,
-    call_limit=None,
+def temporal_earliest_arrival(
+    graph,
+    source,
+    time_fn=None,
+    time_attr=None,
+    start_time=None,
+    end_time=None,
+    duration_fn=None,
+    duration_attr=None,
+    default_duration=0.0,
 ):
-    """Determine if 2 graphs are isomorphic
+    """Find the earliest arrival time at every node reachable from a node of a
+    graph with timestamped edges
 
-    This checks if 2 graphs are isomorphic both structurally and also
-    comparing the node and edge data using the provided matcher functions.
-    The matcher functions take in 2 data objects and will compare them. A
-    simple example that checks if they're just equal would be::
+    See :func:`~retworkx.temporal_shortest_paths` for the time-respecting
+    paths and the times of the edges.
 
-            graph_a = retworkx.PyGraph()
-            graph_b = retworkx.PyGraph()
-            retworkx.is_isomorphic(graph_a, graph_b,
-                                lambda x, y: x == y)
+    :param graph: The temporal graph. Can either be a
+        :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`.
+    :param int source: The node index to start from
+    :param time_fn: An optional callable that will be passed the edge's
+        payload object and is expected to return the ``float`` time of that
+        edge, or ``None`` if it has none.
+    :param str time_attr: An optional name of an edge attribute with the time
+        of the edges, read from the edge attribute column with this name if
+        the graph has one, otherwise from this key of the edges with a dict
+        payload.
+    :param float start_time: The earliest time the paths can leave
+        ``source``. If not specified there is no limit.
+    :param float end_time: The latest time the paths can arrive at a node. If
+        not specified there is no limit.
+    :param duration_fn: An optional callable that will be passed the edge's
+        payload object and is expected to return the non-negative ``float``
+        time it takes to traverse that edge.
+    :param str duration_attr: An optional name of an edge attribute with the
+        duration of the edges, read like ``time_attr``
+    :param float default_duration: The duration of the edges if neither
+        ``duration_fn`` nor ``duration_attr`` is specified, or of the edges
+        without a ``duration_attr`` value. Defaults to 0.0.
+
+    :returns: A mapping of every node reachable from ``source``, except
+        ``source``, to the earliest time a time-respecting path arrives there
+    :rtype: PathLengthMapping
+    :raises InvalidNode: If ``source`` isn't in the graph
+    :raises ValueError: If neither or both of ``time_fn`` and ``time_attr``
+        are specified, if both ``duration_fn`` and ``duration_attr`` are
+        specified or if a duration is negative or NaN
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(graph))
 
-    .. note::
 
-        For better performance on large graphs, consider setting
-        `id_order=False`.
+@temporal_earliest_arrival.register(PyDiGraph)
+def _digraph_temporal_earliest_arrival(
+    graph,
+    source,
+    time_fn=None,
+    time_attr=None,
+    start_time=None,
+    end_time=None,
+    duration_fn=None,
+    duration_attr=None,
+    default_duration=0.0,
+):
+    return digraph_temporal_earliest_arrival(
+        graph,
+        source,
+        time_fn=time_fn,
+        time_attr=time_attr,
+        start_time=start_time,
+        end_time=end_time,
+        duration_fn=duration_fn,
+        duration_attr=duration_attr,
+        default_duration=default_duration,
+    )
 
-    :param first: The first graph to compare. Can either be a
-        :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`.
-    :param second: The second graph to compare. Can either be a
-        :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`.
-        It should be the same type as the first graph.
-    :param callable node_matcher: A python callable object that takes 2
-        positional one for each node data object. If the return of this
-        function evaluates to True then the nodes passed to it are viewed
-        as matching.
-    :param callable edge_matcher: A python callable object that takes 2
-        positional one for each edge data object. If the return of this
-        function evaluates to True then the edges passed to it are viewed
-        as matching.
-    :param bool id_order: If set to ``False`` this function will use a
-        heuristic matching order based on [VF2]_ paper. Otherwise it will
-        default to matching the nodes in order specified by their ids.
-    :param int call_limit: An optional bound on the number of states that VF2
-        algorithm visits while searching for a solution. If it exceeds this limit,
-        the algorithm will stop and return ``False``.
 
-    :returns: ``True`` if the 2 graphs are isomorphic, ``False`` if they are
-        not.
-    :rtype: bool
+@temporal_earliest_arrival.register(PyGraph)
+def _graph_temporal_earliest_arrival(
+    graph,
+    source,
+    time_fn=None,
+    time_attr=None,
+    start_time=None,
+    end_time=None,
+    duration_fn=None,
+    duration_attr=None,
+    default_duration=0.0,
+):
+    return graph_temporal_earliest_arrival(
+        graph,
+        source,
+        time_fn=time_fn,
+        time_attr=time_attr,
+        start_time=start_time,
+        end_time=end_time,
+        duration_fn=duration_fn,
+        duration_attr=duration_attr,
+        default_duration=default_duration,
+    )
 
-    .. [VF2] VF2++  An Improved Subgraph Isomorphism Algorithm
-        by Alpár Jüttner and Péter Madarasi
+
+@functools.singledispatch
+def temporal_reachability(
+    graph,
+    source,
+    time_fn=None,
+    time_attr=None,
+    start_time=None,
+    end_time=None,
+    duration_fn=None,
+    duration_attr=None,
+    default_duration=0.0,
+):
+    """Find the nodes reachable from a node of a graph with timestamped edges
+    by a time-respecting path
+
+    See :func:`~retworkx.temporal_shortest_paths` for the time-respecting
+    paths and the times of the edges. Unlike in a static graph, a node can be
+    unreachable even if there is a path to it, when the times of the edges of
+    every path to it decrease, and a node can be reachable from another one
+    without the reverse being true in a :class:`~retworkx.PyGraph`.
+
+    :param graph: The temporal graph. Can either be a
+        :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`.
+    :param int source: The node index to start from
+    :param time_fn: An optional callable that will be passed the edge's
+        payload object and is expected to return the ``float`` time of that
+        edge, or ``None`` if it has none.
+    :param str time_attr: An optional name of an edge attribute with the time
+        of the edges, read from the edge attribute column with this name if
+        the graph has one, otherwise from this key of the edges with a dict
+        payload.
+    :param float start_time: The earliest time the paths can leave
+        ``source``. If not specified there is no limit.
+    :param float end_time: The latest time the paths can arrive at a node. If
+        not specified there is no limit.
+    :param duration_fn: An optional callable that will be passed the edge's
+        payload object and is expected to return the non-negative ``float``
+        time it takes to traverse that edge.
+    :param str duration_attr: An optional name of an edge attribute with the
+        duration of the edges, read like ``time_attr``
+    :param float default_duration: The duration of the edges if neither
+        ``duration_fn`` nor ``duration_attr`` is specified, or of the edges
+        without a ``duration_attr`` value. Defaults to 0.0.
+
+    :returns: The indices of the nodes reachable from ``source``, except
+        ``source``, in increasing order
+    :rtype: NodeIndices
+    :raises InvalidNode: If ``source`` isn't in the graph
+    :raises ValueError: If neither or both of ``time_fn`` and ``time_attr``
+        are specified, if both ``duration_fn`` and ``duration_attr`` are
+        specified or if a duration is negative or NaN
     """
-    raise TypeError("Invalid Input Type %s for graph" % type(first))
+    raise TypeError("Invalid Input Type %s for graph" % type(graph))
 
 
-@is_isomorphic.register(PyDiGraph)
-def _digraph_is_isomorphic(
-    first,
-    second,
-    node_matcher=None,
-    edge_matcher=None,
-    id_order=True,
-    call_limit=None,
+@temporal_reachability.register(PyDiGraph)
+def _digraph_temporal_reachability(
+    graph,
+    source,
+    time_fn=None,
+    time_attr=None,
+    start_time=None,
+    end_time=None,
+    duration_fn=None,
+    duration_attr=None,
+    default_duration=0.0,
 ):
-    return digraph_is_isomorphic(first, second, node_matcher, edge_matcher, id_order, call_limit)
+    return digraph_temporal_reachability(
+        graph,
+        source,
+        time_fn=time_fn,
+        time_attr=time_attr,
+        start_time=start_time,
+        end_time=end_time,
+        duration_fn=duration_fn,
+        duration_attr=duration_attr,
+        default_duration=default_duration,
+    )
 
 
-@is_isomorphic.register(PyGraph)
-def _graph_is_isomorphic(
-    first,
-    second,
-    node_matcher=None,
-    edge_matcher=None,
-    id_order=True,
-    call_limit=None,
+@temporal_reachability.register(PyGraph)
+def _graph_temporal_reachability(
+    graph,
+    source,
+    time_fn=None,
+    time_attr=None,
+    start_time=None,
+    end_time=None,
+    duration_fn=None,
+    duration_attr=None,
+    default_duration=0.0,
 ):
-    return graph_is_isomorphic(first, second, node_matcher, edge_matcher, id_order, call_limit)
+    return graph_temporal_reachability(
+        graph,
+        source,
+        time_fn=time_fn,
+        time_attr=time_attr,
+        start_time=start_time,
+        end_time=end_time,
+        duration_fn=duration_fn,
+        duration_attr=duration_attr,
+        default_duration=default_duration,
+    )
 
 
 @functools.singledispatch
-def is_isomorphic_node_match(first, second, matcher, id_order=True):
-    """Determine if 2 graphs are isomorphic
+def all_simple_paths(graph, from_, to, min_depth=None, cutoff=None):
+    """Return all simple paths between 2 nodes in a PyGraph object
 
-    This checks if 2 graphs are isomorphic both structurally and also
-    comparing the node data using the provided matcher function. The matcher
-    function takes in 2 node data objects and will compare them. A simple
-    example that checks if they're just equal would be::
+    A simple path is a path with no repeated nodes.
 
-        graph_a = retworkx.PyDAG()
-        graph_b = retworkx.PyDAG()
-        retworkx.is_isomorphic_node_match(graph_a, graph_b,
-                                        lambda x, y: x == y)
+    :param graph: The graph to find the path in. Can either be a
+        class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`
+    :param int from_: The node index to find the paths from
+    :param int to: The node index to find the paths to
+    :param int min_depth: The minimum depth of the path to include in the
+        output list of paths. By default all paths are included regardless of
+        depth, setting to 0 will behave like the default.
+    :param int cutoff: The maximum depth of path to include in the output list
+        of paths. By default includes all paths regardless of depth, setting to
+        0 will behave like default.
 
-    .. note::
+    :returns: A list of lists where each inner list is a path of node indices
+    :rtype: list
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(graph))
 
-        For better performance on large graphs, consider setting
-        `id_order=False`.
 
-    :param first: The first graph to compare. Can either be a
-        :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`.
-    :param second: The second graph to compare. Can either be a
-        :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`.
-        It should be the same type as the first graph.
-    :param callable matcher: A python callable object that takes 2 positional
-        one for each node data object. If the return of this
-        function evaluates to True then the nodes passed to it are vieded
-        as matching.
-    :param bool id_order: If set to ``False`` this function will use a
-        heuristic matching order based on [VF2]_ paper. Otherwise it will
-        default to matching the nodes in order specified by their ids.
+@all_simple_paths.register(PyDiGraph)
+def _digraph_all_simple_paths(graph, from_, to, min_depth=None, cutoff=None):
+    return digraph_all_simple_paths(graph, from_, to, min_depth=min_depth, cutoff=cutoff)
 
-    :returns: ``True`` if the 2 graphs are isomorphic ``False`` if they are
-        not.
-    :rtype: bool
+
+@all_simple_paths.register(PyGraph)
+def _graph_all_simple_paths(graph, from_, to, min_depth=None, cutoff=None):
+    return graph_all_simple_paths(graph, from_, to, min_depth=min_depth, cutoff=cutoff)
+
+
+@functools.singledispatch
+def hamiltonian_path(graph, source=None, target=None, timeout=None, call_limit=None):
+    """Search for a Hamiltonian path in a graph
+
+    A Hamiltonian path visits every node of the graph exactly once, following
+    the direction of the edges for a :class:`~retworkx.PyDiGraph`. Deciding if
+    a graph has one is NP-complete, this function uses a backtracking search
+    which extends a path one node at a time, trying the nodes with the fewest
+    neighbors left first, and prunes the paths that can't be completed: every
+    node left must still have a neighbor to enter and leave it by, and be
+    reachable from the end of the path through the nodes left. The search
+    takes exponential time in the worst case, so it can be bounded with
+    ``timeout`` and ``call_limit``. For example::
+
+        graph = retworkx.generators.grid_graph(3, 3)
+        path = retworkx.hamiltonian_path(graph, source=0)
+        # list(path) == [0, 1, 2, 5, 8, 7, 4, 3, 6]
+        retworkx.hamiltonian_path(graph, source=1)
+        # None
+
+    :param graph: The graph to search in. Can either be a
+        :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`
+    :param int source: An optional node index the path has to start at
+    :param int target: An optional node index the path has to end at
+    :param float timeout: An optional limit on the duration of the search in
+        seconds
+    :param int call_limit: An optional limit on the number of times the
+        search extends a path with a node
+
+    :returns: The node indices of a Hamiltonian path in order, or ``None`` if
+        the graph has no Hamiltonian path
+    :rtype: NodeIndices
+    :raises InvalidNode: If ``source`` or ``target`` is not in the graph
+    :raises SearchBudgetExhausted: If the search reached ``timeout`` or
+        ``call_limit`` before finding a path or proving that there is none
     """
-    raise TypeError("Invalid Input Type %s for graph" % type(first))
+    raise TypeError("Invalid Input Type %s for graph" % type(graph))
 
 
-@is_isomorphic_node_match.register(PyDiGraph)
-def _digraph_is_isomorphic_node_match(first, second, matcher, id_order=True):
-    return digraph_is_isomorphic(first, second, matcher, id_order=id_order)
+@hamiltonian_path.register(PyDiGraph)
+def _digraph_hamiltonian_path(graph, source=None, target=None, timeout=None, call_limit=None):
+    return digraph_hamiltonian_path(
+        graph, source=source, target=target, timeout=timeout, call_limit=call_limit
+    )
 
 
-@is_isomorphic_node_match.register(PyGraph)
-def _graph_is_isomorphic_node_match(first, second, matcher, id_order=True):
-    return graph_is_isomorphic(first, second, matcher, id_order=id_order)
+@hamiltonian_path.register(PyGraph)
+def _graph_hamiltonian_path(graph, source=None, target=None, timeout=None, call_limit=None):
+    return graph_hamiltonian_path(
+        graph, source=source, target=target, timeout=timeout, call_limit=call_limit
+    )
 
 
 @functools.singledispatch
-def is_subgraph_isomorphic(
-    first,
-    second,
-    node_matcher=None,
-    edge_matcher=None,
-    id_order=False,
-    induced=True,
-    call_limit=None,
+def floyd_warshall(
+    graph,
+    weight_fn=None,
+    default_weight=1.0,
+    parallel_threshold=300,
 ):
-    """Determine if 2 graphs are subgraph isomorphic
-
-    This checks if 2 graphs are subgraph isomorphic both structurally and also
-    comparing the node and edge data using the provided matcher functions.
-    The matcher functions take in 2 data objects and will compare them.
-    Since there is an ambiguity in the term 'subgraph', do note that we check
-    for an node-induced subgraph if argument `induced` is set to `True`. If it is
-    set to `False`, we check for a non induced subgraph, meaning the second graph
-    can have fewer edges than the subgraph of the first. By default it's `True`. A
-    simple example that checks if they're just equal would be::
+    """Find all-pairs shortest path lengths using Floyd's algorithm
 
-            graph_a = retworkx.PyGraph()
-            graph_b = retworkx.PyGraph()
-            retworkx.is_subgraph_isomorphic(graph_a, graph_b,
-                                            lambda x, y: x == y)
+    Floyd's algorithm is used for finding shortest paths in dense graphs
+    or graphs with negative weights (where Dijkstra's algorithm fails).
+
+    This function is multithreaded and will launch a pool with threads equal
+    to the number of CPUs by default if the number of nodes in the graph is
+    above the value of ``parallel_threshold`` (it defaults to 300).
+    You can tune the number of threads with the ``RAYON_NUM_THREADS``
+    environment variable. For example, setting ``RAYON_NUM_THREADS=4`` would
+    limit the thread pool to 4 threads if parallelization was enabled.
+
+    :param graph: The graph to run Floyd's algorithm on. Can
+        either be a :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`
+    :param callable weight_fn: A callable object (function, lambda, etc) which
+        will be passed the edge object and expected to return a ``float``. This
+        tells retworkx/rust how to extract a numerical weight as a ``float``
+        for edge object. Some simple examples are::
+
+            floyd_warshall(graph, weight_fn= lambda x: 1)
+
+        to return a weight of 1 for all edges. Also::
+
+            floyd_warshall(graph, weight_fn=float)
+
+        to cast the edge object as a float as the weight. If this is not
+        specified a default value (either ``default_weight`` or 1) will be used
+        for all edges.
+    :param float default_weight: If ``weight_fn`` is not used this can be
+        optionally used to specify a default weight to use for all edges.
+    :param int parallel_threshold: The number of nodes to execute
+        the algorithm in parallel at. It defaults to 300, but this can
+        be tuned
+
+    :return: A read-only dictionary of path lengths. The keys are the source
+        node indices and the values are a dict of the target node and the
+        length of the shortest path to that node. For example::
+
+            {
+                0: {0: 0.0, 1: 2.0, 2: 2.0},
+                1: {1: 0.0, 2: 1.0},
+                2: {0: 1.0, 2: 0.0},
+            }
+
+    :rtype: AllPairsPathLengthMapping
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(graph))
+
+
+@floyd_warshall.register(PyDiGraph)
+def _digraph_floyd_warshall(
+    graph,
+    weight_fn=None,
+    default_weight=1.0,
+    parallel_threshold=300,
+):
+    return digraph_floyd_warshall(
+        graph,
+        weight_fn=weight_fn,
+        default_weight=default_weight,
+        parallel_threshold=parallel_threshold,
+    )
+
+
+@floyd_warshall.register(PyGraph)
+def _graph_floyd_warshall(
+    graph,
+    weight_fn=None,
+    default_weight=1.0,
+    parallel_threshold=300,
+):
+    return graph_floyd_warshall(
+        graph,
+        weight_fn=weight_fn,
+        default_weight=default_weight,
+        parallel_threshold=parallel_threshold,
+    )
+
+
+@functools.singledispatch
+def floyd_warshall_numpy(
+    graph,
+    weight_fn=None,
+    default_weight=1.0,
+    parallel_threshold=300,
+):
+    """Find all-pairs shortest path lengths using Floyd's algorithm
+
+    Floyd's algorithm is used for finding shortest paths in dense graphs
+    or graphs with negative weights (where Dijkstra's algorithm fails).
+
+    This function is multithreaded and will launch a pool with threads equal
+    to the number of CPUs by default if the number of nodes in the graph is
+    above the value of ``parallel_threshold`` (it defaults to 300).
+    You can tune the number of threads with the ``RAYON_NUM_THREADS``
+    environment variable. For example, setting ``RAYON_NUM_THREADS=4`` would
+    limit the thread pool to 4 threads if parallelization was enabled.
+
+    :param graph: The graph to run Floyd's algorithm on. Can
+        either be a :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`
+    :param callable weight_fn: A callable object (function, lambda, etc) which
+        will be passed the edge object and expected to return a ``float``. This
+        tells retworkx/rust how to extract a numerical weight as a ``float``
+        for edge object. Some simple examples are::
+
+            floyd_warshall_numpy(graph, weight_fn: lambda x: 1)
+
+        to return a weight of 1 for all edges. Also::
+
+            floyd_warshall_numpy(graph, weight_fn: lambda x: float(x))
+
+        to cast the edge object as a float as the weight. If this is not
+        specified a default value (either ``default_weight`` or 1) will be used
+        for all edges.
+    :param float default_weight: If ``weight_fn`` is not used this can be
+        optionally used to specify a default weight to use for all edges.
+    :param int parallel_threshold: The number of nodes to execute
+        the algorithm in parallel at. It defaults to 300, but this can
+        be tuned
+
+    :returns: A matrix of shortest path distances between nodes. If there is no
+        path between two nodes then the corresponding matrix entry will be
+        ``np.inf``.
+    :rtype: numpy.ndarray
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(graph))
+
+
+@floyd_warshall_numpy.register(PyDiGraph)
+def _digraph_floyd_warshall_numpy(
+    graph, weight_fn=None, default_weight=1.0, parallel_threshold=300
+):
+    return digraph_floyd_warshall_numpy(
+        graph,
+        weight_fn=weight_fn,
+        default_weight=default_weight,
+        parallel_threshold=parallel_threshold,
+    )
+
+
+@floyd_warshall_numpy.register(PyGraph)
+def _graph_floyd_warshall_numpy(graph, weight_fn=None, default_weight=1.0, parallel_threshold=300):
+    return graph_floyd_warshall_numpy(
+        graph,
+        weight_fn=weight_fn,
+        default_weight=default_weight,
+        parallel_threshold=parallel_threshold,
+    )
+
+
+@functools.singledispatch
+def astar_shortest_path(graph, node, goal_fn, edge_cost_fn, estimate_cost_fn):
+    """Compute the A* shortest path for a graph
+
+    :param graph: The input graph to use. Can
+        either be a :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`
+    :param int node: The node index to compute the path from
+    :param goal_fn: A python callable that will take in 1 parameter, a node's
+        data object and will return a boolean which will be True if it is the
+        finish node.
+    :param edge_cost_fn: A python callable that will take in 1 parameter, an
+        edge's data object and will return a float that represents the cost
+        of that edge. It must be non-negative.
+    :param estimate_cost_fn: A python callable that will take in 1 parameter, a
+        node's data object and will return a float which represents the
+        estimated cost for the next node. The return must be non-negative. For
+        the algorithm to find the actual shortest path, it should be
+        admissible, meaning that it should never overestimate the actual cost
+        to get to the nearest goal node.
+
+    :returns: The computed shortest path between node and finish as a list
+        of node indices.
+    :rtype: NodeIndices
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(graph))
+
+
+@astar_shortest_path.register(PyDiGraph)
+def _digraph_astar_shortest_path(graph, node, goal_fn, edge_cost_fn, estimate_cost_fn):
+    return digraph_astar_shortest_path(graph, node, goal_fn, edge_cost_fn, estimate_cost_fn)
+
+
+@astar_shortest_path.register(PyGraph)
+def _graph_astar_shortest_path(graph, node, goal_fn, edge_cost_fn, estimate_cost_fn):
+    return graph_astar_shortest_path(graph, node, goal_fn, edge_cost_fn, estimate_cost_fn)
+
+
+@functools.singledispatch
+def dijkstra_shortest_paths(
+    graph,
+    source,
+    target=None,
+    weight_fn=None,
+    default_weight=1.0,
+    as_undirected=False,
+    weight_attr=None,
+):
+    """Find the shortest path from a node
+
+    This function will generate the shortest path from a source node using
+    Dijkstra's algorithm.
+
+    :param graph: The input graph to use. Can either be a
+        :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`
+    :param int source: The node index to find paths from
+    :param int target: An optional target to find a path to
+    :param weight_fn: An optional weight function for an edge. It will accept
+        a single argument, the edge's weight object and will return a float
+        which will be used to represent the weight/cost of the edge
+    :param float default_weight: If ``weight_fn`` isn't specified this optional
+        float value will be used for the weight/cost of each edge.
+    :param bool as_undirected: If set to true the graph will be treated as
+        undirected for finding the shortest path. This only works with a
+        :class:`~retworkx.PyDiGraph` input for ``graph``
+    :param str weight_attr: An optional name of an edge attribute with the
+        weight/cost of the edges, as an alternative to ``weight_fn`` which
+        doesn't call into Python for every edge. The weights are read from the
+        edge attribute column with this name if the graph has one, see
+        :meth:`~retworkx.PyGraph.set_edge_attribute`, otherwise from this key
+        of the edges with a dict payload. Edges without a weight use
+        ``default_weight``.
+
+    :return: Dictionary of paths. The keys are destination node indices and
+        the dict values are lists of node indices making the path.
+    :rtype: dict
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(graph))
+
+
+@dijkstra_shortest_paths.register(PyDiGraph)
+def _digraph_dijkstra_shortest_path(
+    graph,
+    source,
+    target=None,
+    weight_fn=None,
+    default_weight=1.0,
+    as_undirected=False,
+    weight_attr=None,
+):
+    return digraph_dijkstra_shortest_paths(
+        graph,
+        source,
+        target=target,
+        weight_fn=weight_fn,
+        default_weight=default_weight,
+        as_undirected=as_undirected,
+        weight_attr=weight_attr,
+    )
+
+
+@dijkstra_shortest_paths.register(PyGraph)
+def _graph_dijkstra_shortest_path(
+    graph, source, target=None, weight_fn=None, default_weight=1.0, weight_attr=None
+):
+    return graph_dijkstra_shortest_paths(
+        graph,
+        source,
+        target=target,
+        weight_fn=weight_fn,
+        default_weight=default_weight,
+        weight_attr=weight_attr,
+    )
+
+
+@functools.singledispatch
+def dijkstra_shortest_path_edges(
+    graph, source, target=None, weight_fn=None, default_weight=1.0, weight_attr=None
+):
+    """Find the edges of the shortest path from a node
+
+    This function will generate the shortest path from a source node using
+    Dijkstra's algorithm. Unlike :func:`~retworkx.dijkstra_shortest_paths`
+    the paths are returned as the indices of the edges traversed. On a
+    multigraph, where several parallel edges can connect the same pair of
+    nodes, this identifies exactly which (cheapest) edge is used at each step.
+
+    :param graph: The input graph to use. Can either be a
+        :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`
+    :param int source: The node index to find paths from
+    :param int target: An optional target to find a path to
+    :param weight_fn: An optional weight function for an edge. It will accept
+        a single argument, the edge's weight object and will return a float
+        which will be used to represent the weight/cost of the edge
+    :param float default_weight: If ``weight_fn`` isn't specified this optional
+        float value will be used for the weight/cost of each edge.
+    :param str weight_attr: An optional name of an edge attribute with the
+        weight/cost of the edges, as an alternative to ``weight_fn`` which
+        doesn't call into Python for every edge. The weights are read from the
+        edge attribute column with this name if the graph has one, see
+        :meth:`~retworkx.PyGraph.set_edge_attribute`, otherwise from this key
+        of the edges with a dict payload. Edges without a weight use
+        ``default_weight``.
+
+    :return: Dictionary of paths. The keys are destination node indices and
+        the dict values are lists of edge indices making the path.
+    :rtype: PathMapping
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(graph))
+
+
+@dijkstra_shortest_path_edges.register(PyDiGraph)
+def _digraph_dijkstra_shortest_path_edges(
+    graph, source, target=None, weight_fn=None, default_weight=1.0, weight_attr=None
+):
+    return digraph_dijkstra_shortest_path_edges(
+        graph,
+        source,
+        target=target,
+        weight_fn=weight_fn,
+        default_weight=default_weight,
+        weight_attr=weight_attr,
+    )
+
+
+@dijkstra_shortest_path_edges.register(PyGraph)
+def _graph_dijkstra_shortest_path_edges(
+    graph, source, target=None, weight_fn=None, default_weight=1.0, weight_attr=None
+):
+    return graph_dijkstra_shortest_path_edges(
+        graph,
+        source,
+        target=target,
+        weight_fn=weight_fn,
+        default_weight=default_weight,
+        weight_attr=weight_attr,
+    )
+
+
+@functools.singledispatch
+def all_pairs_dijkstra_shortest_paths(graph, edge_cost_fn):
+    """For each node in the graph, finds the shortest paths to all others.
+
+    This function will generate the shortest path from all nodes in the graph
+    using Dijkstra's algorithm. This function is multithreaded and will run
+    launch a thread pool with threads equal to the number of CPUs by default.
+    You can tune the number of threads with the ``RAYON_NUM_THREADS``
+    environment variable. For example, setting ``RAYON_NUM_THREADS=4`` would
+    limit the thread pool to 4 threads.
+
+    :param graph: The input graph to use. Can either be a
+        :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`
+    :param edge_cost_fn: A callable object that acts as a weight function for
+        an edge. It will accept a single positional argument, the edge's weight
+        object and will return a float which will be used to represent the
+        weight/cost of the edge
+
+    :return: A read-only dictionary of paths. The keys are source node
+        indices and the values are a dict of target node indices and a list
+        of node indices making the path. For example::
+
+            {
+                0: {1: [0, 1],  2: [0, 1, 2]},
+                1: {2: [1, 2]},
+                2: {0: [2, 0]},
+            }
+
+    :rtype: AllPairsPathMapping
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(graph))
+
+
+@all_pairs_dijkstra_shortest_paths.register(PyDiGraph)
+def _digraph_all_pairsdijkstra_shortest_path(graph, edge_cost_fn):
+    return digraph_all_pairs_dijkstra_shortest_paths(graph, edge_cost_fn)
+
+
+@all_pairs_dijkstra_shortest_paths.register(PyGraph)
+def _graph_all_pairs_dijkstra_shortest_path(graph, edge_cost_fn):
+    return graph_all_pairs_dijkstra_shortest_paths(graph, edge_cost_fn)
+
+
+@functools.singledispatch
+def all_pairs_dijkstra_path_lengths(graph, edge_cost_fn):
+    """For each node in the graph, calculates the lengths of the shortest paths to all others.
+
+    This function will generate the shortest path lengths from all nodes in the
+    graph using Dijkstra's algorithm. This function is multithreaded and will
+    launch a thread pool with threads equal to the number of CPUs by
+    default. You can tune the number of threads with the ``RAYON_NUM_THREADS``
+    environment variable. For example, setting ``RAYON_NUM_THREADS=4`` would
+    limit the thread pool to 4 threads.
+
+    :param graph: The input graph to use. Can either be a
+        :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`
+    :param edge_cost_fn: A callable object that acts as a weight function for
+        an edge. It will accept a single positional argument, the edge's weight
+        object and will return a float which will be used to represent the
+        weight/cost of the edge
+
+    :return: A read-only dictionary of path lengths. The keys are the source
+        node indices and the values are a dict of the target node and the
+        length of the shortest path to that node. For example::
+
+            {
+                0: {1: 2.0, 2: 2.0},
+                1: {2: 1.0},
+                2: {0: 1.0},
+            }
+
+    :rtype: AllPairsPathLengthMapping
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(graph))
+
+
+@all_pairs_dijkstra_path_lengths.register(PyDiGraph)
+def _digraph_all_pairs_dijkstra_path_lengths(graph, edge_cost_fn):
+    return digraph_all_pairs_dijkstra_path_lengths(graph, edge_cost_fn)
+
+
+@all_pairs_dijkstra_path_lengths.register(PyGraph)
+def _graph_all_pairs_dijkstra_path_lengths(graph, edge_cost_fn):
+    return graph_all_pairs_dijkstra_path_lengths(graph, edge_cost_fn)
+
+
+@functools.singledispatch
+def all_pairs_dijkstra_path_lengths_chunked(graph, edge_cost_fn, callback, chunk_size=1000):
+    """For each node in the graph, calculates the lengths of the shortest paths
+    to all others and passes them to a callback in chunks.
+
+    This function computes the same path lengths as
+    :func:`~retworkx.all_pairs_dijkstra_path_lengths` but instead of building
+    the lengths for every source node in a single mapping, the source nodes
+    are processed ``chunk_size`` at a time and ``callback`` is called with the
+    lengths for each chunk before the next chunk is computed. This bounds the
+    memory used to ``chunk_size`` rows at once. For example, to write the
+    lengths out as they are computed::
+
+        import retworkx
+
+        graph = retworkx.generators.directed_path_graph(5)
+
+        def write_chunk(chunk):
+            for source, lengths in chunk.items():
+                print(source, dict(lengths))
+
+        retworkx.all_pairs_dijkstra_path_lengths_chunked(
+            graph, lambda _: 1.0, write_chunk, chunk_size=2
+        )
+
+    The rows of each chunk are computed in parallel and will launch a thread
+    pool with threads equal to the number of CPUs by default. You can tune the
+    number of threads with the ``RAYON_NUM_THREADS`` environment variable.
+
+    :param graph: The input graph to use. Can either be a
+        :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`
+    :param edge_cost_fn: A callable object that acts as a weight function for
+        an edge. It will accept a single positional argument, the edge's weight
+        object and will return a float which will be used to represent the
+        weight/cost of the edge
+    :param callback: A callable object that will be called once per chunk
+        with a single positional argument, an
+        :class:`~retworkx.AllPairsPathLengthMapping` of the source node
+        indices in the chunk to the lengths of the shortest paths from that
+        node. Source nodes are delivered in node index order. If it raises an
+        exception no more chunks are computed and the exception is raised.
+    :param int chunk_size: The number of source nodes in each chunk, it must
+        be larger than 0. Defaults to 1000.
+
+    :raises ValueError: when an edge weight with NaN or negative value
+        is provided or ``chunk_size`` is 0.
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(graph))
+
+
+@all_pairs_dijkstra_path_lengths_chunked.register(PyDiGraph)
+def _digraph_all_pairs_dijkstra_path_lengths_chunked(
+    graph, edge_cost_fn, callback, chunk_size=1000
+):
+    return digraph_all_pairs_dijkstra_path_lengths_chunked(
+        graph, edge_cost_fn, callback, chunk_size=chunk_size
+    )
+
+
+@all_pairs_dijkstra_path_lengths_chunked.register(PyGraph)
+def _graph_all_pairs_dijkstra_path_lengths_chunked(
+    graph, edge_cost_fn, callback, chunk_size=1000
+):
+    return graph_all_pairs_dijkstra_path_lengths_chunked(
+        graph, edge_cost_fn, callback, chunk_size=chunk_size
+    )
+
+
+@functools.singledispatch
+def dijkstra_shortest_path_lengths(graph, node, edge_cost_fn, goal=None):
+    """Compute the lengths of the shortest paths for a graph object using
+    Dijkstra's algorithm.
+
+    :param graph: The input graph to use. Can either be a
+        :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`
+    :param int node: The node index to use as the source for finding the
+        shortest paths from
+    :param edge_cost_fn: A python callable that will take in 1 parameter, an
+        edge's data object and will return a float that represents the
+        cost/weight of that edge. It must be non-negative
+    :param int goal: An optional node index to use as the end of the path.
+        When specified the traversal will stop when the goal is reached and
+        the output dictionary will only have a single entry with the length
+        of the shortest path to the goal node.
+
+    :returns: A dictionary of the shortest paths from the provided node where
+        the key is the node index of the end of the path and the value is the
+        cost/sum of the weights of path
+    :rtype: dict
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(graph))
+
+
+@dijkstra_shortest_path_lengths.register(PyDiGraph)
+def _digraph_dijkstra_shortest_path_lengths(graph, node, edge_cost_fn, goal=None):
+    return digraph_dijkstra_shortest_path_lengths(graph, node, edge_cost_fn, goal=goal)
+
+
+@dijkstra_shortest_path_lengths.register(PyGraph)
+def _graph_dijkstra_shortest_path_lengths(graph, node, edge_cost_fn, goal=None):
+    return graph_dijkstra_shortest_path_lengths(graph, node, edge_cost_fn, goal=goal)
+
+
+@functools.singledispatch
+def k_shortest_path_lengths(graph, start, k, edge_cost, goal=None):
+    """Compute the length of the kth shortest path
+
+    Computes the lengths of the kth shortest path from ``start`` to every
+    reachable node.
+
+    Computes in :math:`O(k * (|E| + |V|*log(|V|)))` time (average).
+
+    :param graph: The graph to find the shortest paths in. Can either be a
+        :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`
+    :param int start: The node index to find the shortest paths from
+    :param int k: The kth shortest path to find the lengths of
+    :param edge_cost: A python callable that will receive an edge payload and
+        return a float for the cost of that eedge
+    :param int goal: An optional goal node index, if specified the output
+        dictionary
+
+    :returns: A dict of lengths where the key is the destination node index and
+        the value is the length of the path.
+    :rtype: dict
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(graph))
+
+
+@k_shortest_path_lengths.register(PyDiGraph)
+def _digraph_k_shortest_path_lengths(graph, start, k, edge_cost, goal=None):
+    return digraph_k_shortest_path_lengths(graph, start, k, edge_cost, goal=goal)
+
+
+@k_shortest_path_lengths.register(PyGraph)
+def _graph_k_shortest_path_lengths(graph, start, k, edge_cost, goal=None):
+    return graph_k_shortest_path_lengths(graph, start, k, edge_cost, goal=goal)
+
+
+@functools.singledispatch
+def rank_paths(
+    graph,
+    paths,
+    weight_fn=None,
+    default_weight=1.0,
+    overlap_penalty=0.0,
+    node_filter=None,
+    max_weight=None,
+):
+    """Rank a set of paths by their total weight, penalizing paths which overlap
+
+    This can be used to post-process a set of paths, such as the output of
+    :func:`~retworkx.all_simple_paths` or a list of the paths in a
+    :class:`~retworkx.PathMapping`. The paths are ranked greedily: the next
+    path in the ranking is the one with the lowest score among the paths not
+    ranked yet, where the score of a path is its total weight plus
+    ``overlap_penalty`` times its largest similarity with a path ranked
+    before it. The similarity of two paths is the number of edges they share
+    divided by the number of edges in either of them, so with an
+    ``overlap_penalty`` of 0 this is the order of the path weights, while a
+    large ``overlap_penalty`` moves paths that are almost the same as a
+    better path towards the end of the ranking.
+
+    :param graph: The graph the paths are in. Can either be a
+        :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`
+    :param list paths: The paths to rank, as a list of the node indices of
+        each path in order
+    :param weight_fn: An optional callable that will be passed the edge's
+        payload object and is expected to return a non-negative ``float``
+        weight for that edge. When there are parallel edges between two
+        consecutive nodes of a path the lightest one is used.
+    :param float default_weight: If ``weight_fn`` isn't specified this
+        optional float value will be used for the weight of every edge.
+        Defaults to 1.0.
+    :param float overlap_penalty: The non-negative penalty of a path which
+        uses the same edges as a path ranked before it. Defaults to 0.0.
+    :param node_filter: An optional callable that will be passed the payload
+        of a node and returns ``True`` if paths can go through the node. The
+        paths with a node it returns ``False`` for are left out of the
+        ranking.
+    :param float max_weight: An optional maximum total weight of a path,
+        the heavier paths are left out of the ranking
+
+    :returns: A list of ``(position, score)`` tuples for the ranked paths,
+        where ``position`` is the position of the path in ``paths``. Ties
+        are broken by the position.
+    :rtype: list
+    :raises InvalidNode: If a node of a path is not in the graph
+    :raises ValueError: If there is no edge from a node of a path to the next
+        one or ``overlap_penalty`` is negative
+
+    .. jupyter-execute::
+
+      import retworkx
+
+      graph = retworkx.generators.grid_graph(3, 3)
+      paths = retworkx.all_simple_paths(graph, 0, 8, cutoff=5)
+      ranking = retworkx.rank_paths(graph, paths, overlap_penalty=2)
+      print([(list(paths[position]), score) for position, score in ranking[:3]])
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(graph))
+
+
+@rank_paths.register(PyDiGraph)
+def _digraph_rank_paths(
+    graph,
+    paths,
+    weight_fn=None,
+    default_weight=1.0,
+    overlap_penalty=0.0,
+    node_filter=None,
+    max_weight=None,
+):
+    return digraph_rank_paths(
+        graph,
+        paths,
+        weight_fn=weight_fn,
+        default_weight=default_weight,
+        overlap_penalty=overlap_penalty,
+        node_filter=node_filter,
+        max_weight=max_weight,
+    )
+
+
+@rank_paths.register(PyGraph)
+def _graph_rank_paths(
+    graph,
+    paths,
+    weight_fn=None,
+    default_weight=1.0,
+    overlap_penalty=0.0,
+    node_filter=None,
+    max_weight=None,
+):
+    return graph_rank_paths(
+        graph,
+        paths,
+        weight_fn=weight_fn,
+        default_weight=default_weight,
+        overlap_penalty=overlap_penalty,
+        node_filter=node_filter,
+        max_weight=max_weight,
+    )
+
+
+@functools.singledispatch
+def diverse_paths(
+    graph, paths, k, weight_fn=None, default_weight=1.0, node_filter=None, max_weight=None
+):
+    """Select a subset of diverse paths from a set of paths
+
+    The paths are selected greedily, starting with the lightest path. The
+    next path selected is the one whose largest similarity with the paths
+    already selected is the smallest, so every new path is as different as
+    possible from the previous ones, with ties broken by the lowest total
+    weight and then the position in ``paths``. The similarity of two paths
+    is the number of edges they share divided by the number of edges in
+    either of them.
+
+    :param graph: The graph the paths are in. Can either be a
+        :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`
+    :param list paths: The paths to select from, as a list of the node
+        indices of each path in order
+    :param int k: The number of paths to select
+    :param weight_fn: An optional callable that will be passed the edge's
+        payload object and is expected to return a non-negative ``float``
+        weight for that edge. When there are parallel edges between two
+        consecutive nodes of a path the lightest one is used.
+    :param float default_weight: If ``weight_fn`` isn't specified this
+        optional float value will be used for the weight of every edge.
+        Defaults to 1.0.
+    :param node_filter: An optional callable that will be passed the payload
+        of a node and returns ``True`` if paths can go through the node. The
+        paths with a node it returns ``False`` for can't be selected.
+    :param float max_weight: An optional maximum total weight of a path,
+        the heavier paths can't be selected
+
+    :returns: The positions in ``paths`` of the selected paths in the order
+        they were selected, fewer than ``k`` if there aren't enough paths
+    :rtype: list
+    :raises InvalidNode: If a node of a path is not in the graph
+    :raises ValueError: If there is no edge from a node of a path to the next
+        one
+
+    .. jupyter-execute::
+
+      import retworkx
+
+      graph = retworkx.generators.grid_graph(3, 3)
+      paths = retworkx.all_simple_paths(graph, 0, 8, cutoff=5)
+      print([list(paths[position]) for position in retworkx.diverse_paths(graph, paths, 2)])
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(graph))
+
+
+@diverse_paths.register(PyDiGraph)
+def _digraph_diverse_paths(
+    graph, paths, k, weight_fn=None, default_weight=1.0, node_filter=None, max_weight=None
+):
+    return digraph_diverse_paths(
+        graph,
+        paths,
+        k,
+        weight_fn=weight_fn,
+        default_weight=default_weight,
+        node_filter=node_filter,
+        max_weight=max_weight,
+    )
+
+
+@diverse_paths.register(PyGraph)
+def _graph_diverse_paths(
+    graph, paths, k, weight_fn=None, default_weight=1.0, node_filter=None, max_weight=None
+):
+    return graph_diverse_paths(
+        graph,
+        paths,
+        k,
+        weight_fn=weight_fn,
+        default_weight=default_weight,
+        node_filter=node_filter,
+        max_weight=max_weight,
+    )
+
+
+@functools.singledispatch
+def savings_routes(graph, depot, demands, capacity, weight_fn=None, default_weight=1.0):
+    """Build capacitated vehicle routes with the Clarke-Wright savings heuristic
+
+    The routes start and end at the ``depot`` node and together visit every
+    node with a demand, its customers, once, without the total demand of the
+    customers of a route exceeding ``capacity``. The cost of going from a node
+    to another is the length of the shortest path between them, following the
+    direction of the edges for a :class:`~retworkx.PyDiGraph`.
+
+    Every customer starts in a route of its own, then the routes are merged
+    in decreasing order of the savings ``d(i, depot) + d(depot, j) - d(i, j)``
+    of going directly from customer ``i`` to customer ``j`` instead of through
+    the depot, as long as ``i`` and ``j`` are at an end of their routes (the
+    end and the start respectively for a :class:`~retworkx.PyDiGraph`) and the
+    merged route doesn't exceed the capacity [1]_. This is a
+    construction heuristic: the routes are good but not optimal. For
+    example::
+
+        graph = retworkx.generators.grid_graph(3, 3)
+        routes = retworkx.savings_routes(graph, 4, {0: 1, 2: 1, 6: 1, 8: 1}, 2)
+        # [list(route.nodes) for route in routes] == [[4, 0, 2, 4], [4, 6, 8, 4]]
+        # routes[0].cost == 6.0
+
+    :param graph: The graph to route on. Can either be a
+        :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`
+    :param int depot: The node index every route starts and ends at
+    :param dict demands: A dictionary of the node indices of the customers to
+        their demands. The routes are returned in the order of their first
+        customer in this dictionary.
+    :param float capacity: The maximum total demand of the customers of a
+        route
+    :param weight_fn: An optional callable that will be passed the edge's
+        payload object and is expected to return a non-negative ``float``
+        cost for that edge.
+    :param float default_weight: If ``weight_fn`` isn't specified this
+        optional float value will be used for the cost of every edge.
+        Defaults to 1.0.
+
+    :returns: The routes, the cost of a route is ``inf`` if a customer can't
+        be reached from the depot or the depot from a customer
+    :rtype: list[VehicleRoute]
+    :raises InvalidNode: If the depot or a customer is not in the graph
+    :raises ValueError: If the depot has a demand, or the demand of a
+        customer is negative or exceeds the capacity
+
+    .. [1] G. Clarke and J. W. Wright, "Scheduling of Vehicles from a Central
+        Depot to a Number of Delivery Points", Operations Research 12 (4),
+        1964, pp. 568-581, https://doi.org/10.1287/opre.12.4.568
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(graph))
+
+
+@savings_routes.register(PyDiGraph)
+def _digraph_savings_routes(graph, depot, demands, capacity, weight_fn=None, default_weight=1.0):
+    return digraph_savings_routes(
+        graph, depot, demands, capacity, weight_fn=weight_fn, default_weight=default_weight
+    )
+
+
+@savings_routes.register(PyGraph)
+def _graph_savings_routes(graph, depot, demands, capacity, weight_fn=None, default_weight=1.0):
+    return graph_savings_routes(
+        graph, depot, demands, capacity, weight_fn=weight_fn, default_weight=default_weight
+    )
+
+
+@functools.singledispatch
+def sweep_routes(graph, depot, demands, capacity, pos, weight_fn=None, default_weight=1.0):
+    """Build capacitated vehicle routes with the sweep heuristic
+
+    The routes start and end at the ``depot`` node and together visit every
+    node with a demand, its customers, once, without the total demand of the
+    customers of a route exceeding ``capacity``. The cost of going from a node
+    to another is the length of the shortest path between them, following the
+    direction of the edges for a :class:`~retworkx.PyDiGraph`.
+
+    The customers are sorted by the polar angle of their position around the
+    position of the depot, from :math:`-\\pi` to :math:`\\pi`, and split in
+    that order into routes, a new route starting when the next customer
+    doesn't fit in the current one [1]_. The customers of a route
+    are visited in the order of their angles. This is a construction
+    heuristic: the routes are good but not optimal. For example::
+
+        graph = retworkx.generators.grid_graph(3, 3)
+        pos = {node: [node % 3, -(node // 3)] for node in graph.node_indices()}
+        routes = retworkx.sweep_routes(graph, 4, {0: 1, 2: 1, 6: 1, 8: 1}, 2, pos)
+        # [list(route.nodes) for route in routes] == [[4, 6, 8, 4], [4, 2, 0, 4]]
+
+    :param graph: The graph to route on. Can either be a
+        :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`
+    :param int depot: The node index every route starts and ends at
+    :param dict demands: A dictionary of the node indices of the customers to
+        their demands. Customers with the same angle are visited in the order
+        of this dictionary.
+    :param float capacity: The maximum total demand of the customers of a
+        route
+    :param pos: A mapping of the node indices of the depot and the customers
+        to their ``[x, y]`` positions, such as a ``dict`` or the output of a
+        layout function like :func:`~retworkx.spring_layout`
+    :param weight_fn: An optional callable that will be passed the edge's
+        payload object and is expected to return a non-negative ``float``
+        cost for that edge.
+    :param float default_weight: If ``weight_fn`` isn't specified this
+        optional float value will be used for the cost of every edge.
+        Defaults to 1.0.
+
+    :returns: The routes in the order of the angles of their customers, the
+        cost of a route is ``inf`` if a customer can't be reached from the
+        previous node of the route
+    :rtype: list[VehicleRoute]
+    :raises InvalidNode: If the depot or a customer is not in the graph
+    :raises ValueError: If the depot has a demand, or the demand of a
+        customer is negative or exceeds the capacity
+
+    .. [1] B. E. Gillett and L. R. Miller, "A Heuristic Algorithm for the
+        Vehicle-Dispatch Problem", Operations Research 22 (2), 1974,
+        pp. 340-349, https://doi.org/10.1287/opre.22.2.340
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(graph))
+
+
+@sweep_routes.register(PyDiGraph)
+def _digraph_sweep_routes(graph, depot, demands, capacity, pos, weight_fn=None, default_weight=1.0):
+    return digraph_sweep_routes(
+        graph, depot, demands, capacity, pos, weight_fn=weight_fn, default_weight=default_weight
+    )
+
+
+@sweep_routes.register(PyGraph)
+def _graph_sweep_routes(graph, depot, demands, capacity, pos, weight_fn=None, default_weight=1.0):
+    return graph_sweep_routes(
+        graph, depot, demands, capacity, pos, weight_fn=weight_fn, default_weight=default_weight
+    )
+
+
+@functools.singledispatch
+def dfs_edges(graph, source=None):
+    """Get an edge list of the tree edges from a depth-first traversal
+
+    The pseudo-code for the DFS algorithm is listed below. The output
+    contains the tree edges found by the procedure.
+
+    ::
+
+        DFS(G, v)
+          let S be a stack
+          label v as discovered
+          PUSH(S, (v, iterator of G.neighbors(v)))
+          while (S != Ø)
+              let (v, iterator) := LAST(S)
+              if hasNext(iterator) then
+                  w := next(iterator)
+                  if w is not labeled as discovered then
+                      label w as discovered                   # (v, w) is a tree edge
+                      PUSH(S, (w, iterator of G.neighbors(w)))
+              else
+                  POP(S)
+          end while
+
+    .. note::
+
+        If the input is an undirected graph with a single connected component,
+        the output of this function is a spanning tree.
+
+    :param graph: The graph to get the DFS edge list from. Can either be a
+        :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`
+    :param int source: An optional node index to use as the starting node
+        for the depth-first search. The edge list will only return edges in
+        the components reachable from this index. If this is not specified
+        then a source will be chosen arbitrarly and repeated until all
+        components of the graph are searched.
+
+    :returns: A list of edges as a tuple of the form ``(source, target)`` in
+        depth-first order
+    :rtype: EdgeList
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(graph))
+
+
+@dfs_edges.register(PyDiGraph)
+def _digraph_dfs_edges(graph, source=None):
+    return digraph_dfs_edges(graph, source=source)
+
+
+@dfs_edges.register(PyGraph)
+def _graph_dfs_edges(graph, source=None):
+    return graph_dfs_edges(graph, source=source)
+
+
+@functools.singledispatch
+def dfs_traversal(graph, source=None):
+    """Get a resumable depth-first traversal of a graph
+
+    The traversal is an iterator over the node indices of the graph in
+    depth-first order. For a :class:`~retworkx.PyDiGraph` the edges are
+    followed in their direction. Unlike the functions which traverse the
+    whole graph at once, such as :func:`~retworkx.dfs_edges`, the nodes are
+    found one at a time as the traversal is iterated over, so it can be
+    paused and resumed, and its state can be saved with
+    :meth:`~retworkx.DFSTraversal.copy`. For example, to explore 2 graphs in
+    an interleaved way:
+
+    .. jupyter-execute::
+
+        import retworkx
+
+        first = retworkx.dfs_traversal(retworkx.generators.path_graph(3))
+        second = retworkx.dfs_traversal(retworkx.generators.star_graph(3))
+        for pair in zip(first, second):
+            print(pair)
+
+    :param graph: The graph to traverse. Can either be a
+        :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`
+    :param int source: An optional node index to start the traversal from.
+        The traversal will only return the nodes reachable from this index.
+        If this is not specified then the traversal starts from the lowest
+        node index, and is restarted from the lowest undiscovered node index
+        until all nodes of the graph are returned.
+
+    :returns: The traversal, an iterator of node indices
+    :rtype: DFSTraversal
+    :raises InvalidNode: If ``source`` is not in the graph
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(graph))
+
+
+@dfs_traversal.register(PyDiGraph)
+def _digraph_dfs_traversal(graph, source=None):
+    return digraph_dfs_traversal(graph, source=source)
+
+
+@dfs_traversal.register(PyGraph)
+def _graph_dfs_traversal(graph, source=None):
+    return graph_dfs_traversal(graph, source=source)
+
+
+@functools.singledispatch
+def bfs_traversal(graph, source=None):
+    """Get a resumable breadth-first traversal of a graph
+
+    The traversal is an iterator over the node indices of the graph in
+    breadth-first order. For a :class:`~retworkx.PyDiGraph` the edges are
+    followed in their direction. Unlike the functions which traverse the
+    whole graph at once, such as :func:`~retworkx.bfs_search`, the nodes are
+    found one at a time as the traversal is iterated over, so it can be
+    paused and resumed, and its state can be saved with
+    :meth:`~retworkx.BFSTraversal.copy`.
+
+    :param graph: The graph to traverse. Can either be a
+        :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`
+    :param int source: An optional node index to start the traversal from.
+        The traversal will only return the nodes reachable from this index.
+        If this is not specified then the traversal starts from the lowest
+        node index, and is restarted from the lowest undiscovered node index
+        until all nodes of the graph are returned.
+
+    :returns: The traversal, an iterator of node indices
+    :rtype: BFSTraversal
+    :raises InvalidNode: If ``source`` is not in the graph
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(graph))
+
+
+@bfs_traversal.register(PyDiGraph)
+def _digraph_bfs_traversal(graph, source=None):
+    return digraph_bfs_traversal(graph, source=source)
+
+
+@bfs_traversal.register(PyGraph)
+def _graph_bfs_traversal(graph, source=None):
+    return graph_bfs_traversal(graph, source=source)
+
+
+@functools.singledispatch
+def descendants_at_distance(graph, node, distance):
+    """Return the nodes at a given distance from a node.
+
+    The distance between two nodes is the number of edges on a shortest path
+    between them. A breadth-first search is used which stops at ``distance``
+    hops from ``node``, so only the nodes within that distance are visited.
+    For a :class:`~retworkx.PyDiGraph` edges are followed in their direction,
+    so the returned nodes are descendants of ``node``. Use
+    :func:`~retworkx.ancestors_at_distance` to follow them in reverse.
+
+    :param graph: The graph to search. Can either be a
+        :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`
+    :param int node: The index of the node to start from
+    :param int distance: The number of hops from ``node`` of the returned
+        nodes
+
+    :returns: A set of node indices of the nodes at exactly ``distance`` hops
+        from ``node``. For a ``distance`` of 0 this is only ``node``.
+    :rtype: set
+
+    :raises InvalidNode: If ``node`` is not in the graph
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(graph))
+
+
+@descendants_at_distance.register(PyDiGraph)
+def _digraph_descendants_at_distance(graph, node, distance):
+    return digraph_descendants_at_distance(graph, node, distance)
+
+
+@descendants_at_distance.register(PyGraph)
+def _graph_descendants_at_distance(graph, node, distance):
+    return graph_descendants_at_distance(graph, node, distance)
+
+
+@functools.singledispatch
+def reachable_within(graph, node, max_depth):
+    """Return the nodes reachable from a node within a maximum number of hops.
+
+    This returns every node with a path from ``node`` of at most
+    ``max_depth`` edges, including ``node`` itself. A breadth-first search is
+    used which stops at ``max_depth`` hops from ``node``, so only the
+    returned nodes are visited. For a :class:`~retworkx.PyDiGraph` edges are
+    followed in their direction.
+
+    :param graph: The graph to search. Can either be a
+        :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`
+    :param int node: The index of the node to start from
+    :param int max_depth: The maximum number of hops from ``node``
+
+    :returns: A set of node indices of the nodes within ``max_depth`` hops
+        of ``node``
+    :rtype: set
+
+    :raises InvalidNode: If ``node`` is not in the graph
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(graph))
+
+
+@reachable_within.register(PyDiGraph)
+def _digraph_reachable_within(graph, node, max_depth):
+    return digraph_reachable_within(graph, node, max_depth)
+
+
+@reachable_within.register(PyGraph)
+def _graph_reachable_within(graph, node, max_depth):
+    return graph_reachable_within(graph, node, max_depth)
+
+
+@functools.singledispatch
+def is_isomorphic(
+    first,
+    second,
+    node_matcher=None,
+    edge_matcher=None,
+    id_order=True,
+    call_limit=None,
+):
+    """Determine if 2 graphs are isomorphic
+
+    This checks if 2 graphs are isomorphic both structurally and also
+    comparing the node and edge data using the provided matcher functions.
+    The matcher functions take in 2 data objects and will compare them. A
+    simple example that checks if they're just equal would be::
+
+            graph_a = retworkx.PyGraph()
+            graph_b = retworkx.PyGraph()
+            retworkx.is_isomorphic(graph_a, graph_b,
+                                lambda x, y: x == y)
+
+    .. note::
+
+        For better performance on large graphs, consider setting
+        `id_order=False`.
+
+    :param first: The first graph to compare. Can either be a
+        :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`.
+    :param second: The second graph to compare. Can either be a
+        :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`.
+        It should be the same type as the first graph.
+    :param callable node_matcher: A python callable object that takes 2
+        positional one for each node data object. If the return of this
+        function evaluates to True then the nodes passed to it are viewed
+        as matching.
+    :param callable edge_matcher: A python callable object that takes 2
+        positional one for each edge data object. If the return of this
+        function evaluates to True then the edges passed to it are viewed
+        as matching.
+    :param bool id_order: If set to ``False`` this function will use a
+        heuristic matching order based on [VF2]_ paper. Otherwise it will
+        default to matching the nodes in order specified by their ids.
+    :param int call_limit: An optional bound on the number of states that VF2
+        algorithm visits while searching for a solution. If it exceeds this limit,
+        the algorithm will stop and return ``False``.
+
+    :returns: ``True`` if the 2 graphs are isomorphic, ``False`` if they are
+        not.
+    :rtype: bool
+
+    .. [VF2] VF2++  An Improved Subgraph Isomorphism Algorithm
+        by Alpár Jüttner and Péter Madarasi
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(first))
+
+
+@is_isomorphic.register(PyDiGraph)
+def _digraph_is_isomorphic(
+    first,
+    second,
+    node_matcher=None,
+    edge_matcher=None,
+    id_order=True,
+    call_limit=None,
+):
+    return digraph_is_isomorphic(first, second, node_matcher, edge_matcher, id_order, call_limit)
+
+
+@is_isomorphic.register(PyGraph)
+def _graph_is_isomorphic(
+    first,
+    second,
+    node_matcher=None,
+    edge_matcher=None,
+    id_order=True,
+    call_limit=None,
+):
+    return graph_is_isomorphic(first, second, node_matcher, edge_matcher, id_order, call_limit)
+
+
+@functools.singledispatch
+def is_isomorphic_node_match(first, second, matcher, id_order=True):
+    """Determine if 2 graphs are isomorphic
+
+    This checks if 2 graphs are isomorphic both structurally and also
+    comparing the node data using the provided matcher function. The matcher
+    function takes in 2 node data objects and will compare them. A simple
+    example that checks if they're just equal would be::
+
+        graph_a = retworkx.PyDAG()
+        graph_b = retworkx.PyDAG()
+        retworkx.is_isomorphic_node_match(graph_a, graph_b,
+                                        lambda x, y: x == y)
+
+    .. note::
+
+        For better performance on large graphs, consider setting
+        `id_order=False`.
+
+    :param first: The first graph to compare. Can either be a
+        :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`.
+    :param second: The second graph to compare. Can either be a
+        :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`.
+        It should be the same type as the first graph.
+    :param callable matcher: A python callable object that takes 2 positional
+        one for each node data object. If the return of this
+        function evaluates to True then the nodes passed to it are vieded
+        as matching.
+    :param bool id_order: If set to ``False`` this function will use a
+        heuristic matching order based on [VF2]_ paper. Otherwise it will
+        default to matching the nodes in order specified by their ids.
+
+    :returns: ``True`` if the 2 graphs are isomorphic ``False`` if they are
+        not.
+    :rtype: bool
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(first))
+
+
+@is_isomorphic_node_match.register(PyDiGraph)
+def _digraph_is_isomorphic_node_match(first, second, matcher, id_order=True):
+    return digraph_is_isomorphic(first, second, matcher, id_order=id_order)
+
+
+@is_isomorphic_node_match.register(PyGraph)
+def _graph_is_isomorphic_node_match(first, second, matcher, id_order=True):
+    return graph_is_isomorphic(first, second, matcher, id_order=id_order)
+
+
+@functools.singledispatch
+def is_subgraph_isomorphic(
+    first,
+    second,
+    node_matcher=None,
+    edge_matcher=None,
+    id_order=False,
+    induced=True,
+    call_limit=None,
+):
+    """Determine if 2 graphs are subgraph isomorphic
+
+    This checks if 2 graphs are subgraph isomorphic both structurally and also
+    comparing the node and edge data using the provided matcher functions.
+    The matcher functions take in 2 data objects and will compare them.
+    Since there is an ambiguity in the term 'subgraph', do note that we check
+    for an node-induced subgraph if argument `induced` is set to `True`. If it is
+    set to `False`, we check for a non induced subgraph, meaning the second graph
+    can have fewer edges than the subgraph of the first. By default it's `True`. A
+    simple example that checks if they're just equal would be::
+
+            graph_a = retworkx.PyGraph()
+            graph_b = retworkx.PyGraph()
+            retworkx.is_subgraph_isomorphic(graph_a, graph_b,
+                                            lambda x, y: x == y)
 
 
     :param first: The first graph to compare. Can either be a
         :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`.
-    :param second: The second graph to compare. Can either be a
+    :param second: The second graph to compare. Can either be a
+        :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`.
+        It should be the same type as the first graph.
+    :param callable node_matcher: A python callable object that takes 2
+        positional one for each node data object. If the return of this
+        function evaluates to True then the nodes passed to it are viewed
+        as matching.
+    :param callable edge_matcher: A python callable object that takes 2
+        positional one for each edge data object. If the return of this
+        function evaluates to True then the edges passed to it are viewed
+        as matching.
+    :param bool id_order: If set to ``True`` this function will match the nodes
+        in order specified by their ids. Otherwise it will default to a heuristic
+        matching order based on [VF2]_ paper.
+    :param bool induced: If set to ``True`` this function will check the existence
+        of a node-induced subgraph of first isomorphic to second graph.
+        Default: ``True``.
+    :param int call_limit: An optional bound on the number of states that VF2
+        algorithm visits while searching for a solution. If it exceeds this limit,
+        the algorithm will stop and return ``False``.
+
+    :returns: ``True`` if there is a subgraph of `first` isomorphic to `second`
+        , ``False`` if there is not.
+    :rtype: bool
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(first))
+
+
+@is_subgraph_isomorphic.register(PyDiGraph)
+def _digraph_is_subgraph_isomorphic(
+    first,
+    second,
+    node_matcher=None,
+    edge_matcher=None,
+    id_order=False,
+    induced=True,
+    call_limit=None,
+):
+    return digraph_is_subgraph_isomorphic(
+        first, second, node_matcher, edge_matcher, id_order, induced, call_limit
+    )
+
+
+@is_subgraph_isomorphic.register(PyGraph)
+def _graph_is_subgraph_isomorphic(
+    first,
+    second,
+    node_matcher=None,
+    edge_matcher=None,
+    id_order=False,
+    induced=True,
+    call_limit=None,
+):
+    return graph_is_subgraph_isomorphic(
+        first, second, node_matcher, edge_matcher, id_order, induced, call_limit
+    )
+
+
+@functools.singledispatch
+def transitivity(graph):
+    """Compute the transitivity of a graph.
+
+    This function is multithreaded and will run
+    launch a thread pool with threads equal to the number of CPUs by default.
+    You can tune the number of threads with the ``RAYON_NUM_THREADS``
+    environment variable. For example, setting ``RAYON_NUM_THREADS=4`` would
+    limit the thread pool to 4 threads.
+
+    .. note::
+
+        The function implicitly assumes that there are no parallel edges
+        or self loops. It may produce incorrect/unexpected results if the
+        input graph has self loops or parallel edges.
+
+    :param graph: The graph to be used. Can either be a
+        :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`.
+
+    :returns: Transitivity of the graph.
+    :rtype: float
+        raise TypeError("Invalid Input Type %s for graph" % type(graph))
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(graph))
+
+
+@transitivity.register(PyDiGraph)
+def _digraph_transitivity(graph):
+    return digraph_transitivity(graph)
+
+
+@transitivity.register(PyGraph)
+def _graph_transitivity(graph):
+    return graph_transitivity(graph)
+
+
+@functools.singledispatch
+def common_neighbors(graph, node_a, node_b):
+    """Return the common neighbors of two nodes
+
+    For a :class:`~retworkx.PyDiGraph` the neighbors of a node are its
+    successors.
+
+    :param graph: The graph to find the common neighbors in. Can either be a
+        :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`
+    :param int node_a: The index of the first node
+    :param int node_b: The index of the second node
+
+    :returns: The indices of the nodes which are neighbors of both
+        ``node_a`` and ``node_b``, other than ``node_a`` and ``node_b``, in
+        increasing order
+    :rtype: NodeIndices
+    :raises InvalidNode: If ``node_a`` or ``node_b`` is not in the graph
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(graph))
+
+
+@common_neighbors.register(PyDiGraph)
+def _digraph_common_neighbors(graph, node_a, node_b):
+    return digraph_common_neighbors(graph, node_a, node_b)
+
+
+@common_neighbors.register(PyGraph)
+def _graph_common_neighbors(graph, node_a, node_b):
+    return graph_common_neighbors(graph, node_a, node_b)
+
+
+@functools.singledispatch
+def jaccard_similarity(graph, node_pairs, parallel_threshold=300):
+    """Compute the Jaccard similarity of the neighbors of pairs of nodes
+
+    The Jaccard similarity of two nodes is the number of their common
+    neighbors divided by the number of nodes which are a neighbor of either,
+    or 0 if neither has a neighbor. Self loops are ignored, and for a
+    :class:`~retworkx.PyDiGraph` the neighbors of a node are its successors.
+    The neighbors of every node are sorted once, and the similarities are
+    computed by merging the sorted neighbors of the nodes of each pair.
+
+    The similarities are computed on multiple threads if there are at least
+    ``parallel_threshold`` pairs, the env var ``RAYON_NUM_THREADS`` can be
+    used to adjust how many threads are used.
+
+    :param graph: The graph to compute the similarities in. Can either be a
+        :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`
+    :param list node_pairs: The pairs of node indices to compute the
+        similarity of, as a list of tuples
+    :param int parallel_threshold: The number of pairs to run in parallel
+        from. Defaults to 300.
+
+    :returns: The Jaccard similarity of every pair, in the order of
+        ``node_pairs``
+    :rtype: list
+    :raises InvalidNode: If a node of a pair is not in the graph
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(graph))
+
+
+@jaccard_similarity.register(PyDiGraph)
+def _digraph_jaccard_similarity(graph, node_pairs, parallel_threshold=300):
+    return digraph_jaccard_similarity(graph, node_pairs, parallel_threshold=parallel_threshold)
+
+
+@jaccard_similarity.register(PyGraph)
+def _graph_jaccard_similarity(graph, node_pairs, parallel_threshold=300):
+    return graph_jaccard_similarity(graph, node_pairs, parallel_threshold=parallel_threshold)
+
+
+@functools.singledispatch
+def core_number(graph):
+    """Return the core number for each node in the graph.
+
+    A k-core is a maximal subgraph that contains nodes of degree k or more.
+
+    .. note::
+
+        The function implicitly assumes that there are no parallel edges
+        or self loops. It may produce incorrect/unexpected results if the
+        input graph has self loops or parallel edges.
+
+    :param graph: The graph to get core numbers. Can either be a
+        :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`
+
+    :returns: A dictionary keyed by node index to the core number
+    :rtype: dict
+        raise TypeError("Invalid Input Type %s for graph" % type(graph))
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(graph))
+
+
+@core_number.register(PyDiGraph)
+def _digraph_core_number(graph):
+    return digraph_core_number(graph)
+
+
+@core_number.register(PyGraph)
+def _graph_core_number(graph):
+    return graph_core_number(graph)
+
+
+@functools.singledispatch
+def k_core(graph, k=None):
+    """Return the k-core of a graph.
+
+    A k-core is a maximal subgraph that contains nodes of degree k or more.
+    It's made up of the nodes with a core number (as returned by
+    :func:`~retworkx.core_number`) of at least ``k``. For directed graphs,
+    the degree is calculated as in_degree + out_degree.
+
+    .. note::
+
+        The function implicitly assumes that there are no parallel edges
+        or self loops. It may produce incorrect/unexpected results if the
+        input graph has self loops or parallel edges.
+
+    :param graph: The graph to find the k-core of. Can either be a
+        :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`
+    :param int k: The order of the core. If not specified the largest core
+        number of any node in the graph is used, which returns the main core.
+
+    :returns: The k-core of the graph as a subgraph, the node and edge
+        weights are shared with ``graph``.
+    :rtype: PyGraph or PyDiGraph
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(graph))
+
+
+@k_core.register(PyDiGraph)
+def _digraph_k_core(graph, k=None):
+    return digraph_k_core(graph, k=k)
+
+
+@k_core.register(PyGraph)
+def _graph_k_core(graph, k=None):
+    return graph_k_core(graph, k=k)
+
+
+@functools.singledispatch
+def edge_connectivity(graph, source=None, target=None):
+    r"""Compute the edge connectivity of a graph.
+
+    The edge connectivity of two nodes ``source`` and ``target`` is the
+    minimum number of edges that need to be removed to disconnect ``target``
+    from ``source``, which is equal to the maximum number of edge disjoint
+    paths from ``source`` to ``target``. The edge connectivity of a graph is
+    the minimum edge connectivity of all the pairs of nodes. For directed
+    graphs the paths follow the direction of the edges. It's computed with
+    maximum flows where every edge has a capacity of 1 [Esfahanian]_.
+
+    Self loops are ignored and parallel edges are counted separately.
+
+    :param graph: The graph to compute the edge connectivity of. Can either
+        be a :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`
+    :param int source: An optional source node index, if specified (with
+        ``target``) the local edge connectivity of ``source`` and ``target``
+        is returned instead of the edge connectivity of the graph.
+    :param int target: An optional target node index
+
+    :returns: The edge connectivity, 0 if the graph isn't (strongly)
+        connected or has fewer than 2 nodes.
+    :rtype: int
+
+    :raises InvalidNode: If ``source`` or ``target`` is not in the graph
+    :raises ValueError: If only one of ``source`` and ``target`` is
+        specified, or they are the same node
+
+    .. [Esfahanian] Abdol-Hossein Esfahanian, "Connectivity Algorithms".
+       http://www.cse.msu.edu/~cse835/Papers/Graph_connectivity_revised.pdf
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(graph))
+
+
+@edge_connectivity.register(PyDiGraph)
+def _digraph_edge_connectivity(graph, source=None, target=None):
+    return digraph_edge_connectivity(graph, source=source, target=target)
+
+
+@edge_connectivity.register(PyGraph)
+def _graph_edge_connectivity(graph, source=None, target=None):
+    return graph_edge_connectivity(graph, source=source, target=target)
+
+
+@functools.singledispatch
+def node_connectivity(graph, source=None, target=None):
+    r"""Compute the node connectivity of a graph.
+
+    The node connectivity of two non-adjacent nodes ``source`` and
+    ``target`` is the minimum number of other nodes that need to be removed
+    to disconnect ``target`` from ``source``, which is equal to the maximum
+    number of internally node disjoint paths from ``source`` to ``target``.
+    If there is an edge from ``source`` to ``target`` it counts as one more
+    path. The node connectivity of a graph is the minimum node connectivity
+    of all the pairs of nodes. For directed graphs the paths follow the
+    direction of the edges. It's computed with maximum flows using Even's
+    algorithm for the global node connectivity [Even]_.
+
+    :param graph: The graph to compute the node connectivity of. Can either
+        be a :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`
+    :param int source: An optional source node index, if specified (with
+        ``target``) the local node connectivity of ``source`` and ``target``
+        is returned instead of the node connectivity of the graph.
+    :param int target: An optional target node index
+
+    :returns: The node connectivity, 0 if the graph isn't (strongly)
+        connected or has fewer than 2 nodes and :math:`n - 1` for a complete
+        graph.
+    :rtype: int
+
+    :raises InvalidNode: If ``source`` or ``target`` is not in the graph
+    :raises ValueError: If only one of ``source`` and ``target`` is
+        specified, or they are the same node
+
+    .. [Even] Shimon Even, "An Algorithm for Determining Whether the
+       Connectivity of a Graph is at Least k". SIAM Journal on Computing
+       4 (3): 393-396, 1975.
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(graph))
+
+
+@node_connectivity.register(PyDiGraph)
+def _digraph_node_connectivity(graph, source=None, target=None):
+    return digraph_node_connectivity(graph, source=source, target=target)
+
+
+@node_connectivity.register(PyGraph)
+def _graph_node_connectivity(graph, source=None, target=None):
+    return graph_node_connectivity(graph, source=source, target=target)
+
+
+@functools.singledispatch
+def minimum_edge_cut(graph, source=None, target=None):
+    r"""Find a minimum set of edges that disconnects a graph.
+
+    If ``source`` and ``target`` are specified the edges returned disconnect
+    ``target`` from ``source``, otherwise the graph is no longer (strongly)
+    connected without them. The number of edges in the cut is the edge
+    connectivity as returned by :func:`~retworkx.edge_connectivity`.
+
+    :param graph: The graph to find a minimum edge cut of. Can either be a
+        :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`
+    :param int source: An optional source node index to separate from
+        ``target``
+    :param int target: An optional target node index
+
+    :returns: The edges in the cut as ``(source, target)`` tuples, empty if
+        the graph isn't (strongly) connected or has fewer than 2 nodes.
+    :rtype: EdgeList
+
+    :raises InvalidNode: If ``source`` or ``target`` is not in the graph
+    :raises ValueError: If only one of ``source`` and ``target`` is
+        specified, or they are the same node
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(graph))
+
+
+@minimum_edge_cut.register(PyDiGraph)
+def _digraph_minimum_edge_cut(graph, source=None, target=None):
+    return digraph_minimum_edge_cut(graph, source=source, target=target)
+
+
+@minimum_edge_cut.register(PyGraph)
+def _graph_minimum_edge_cut(graph, source=None, target=None):
+    return graph_minimum_edge_cut(graph, source=source, target=target)
+
+
+@functools.singledispatch
+def minimum_node_cut(graph, source=None, target=None):
+    r"""Find a minimum set of nodes that disconnects a graph.
+
+    If ``source`` and ``target`` are specified the nodes returned disconnect
+    ``target`` from ``source``, otherwise the remaining nodes are no longer
+    (strongly) connected without them. The number of nodes in the cut is the
+    node connectivity as returned by :func:`~retworkx.node_connectivity`. A
+    complete graph has no node cut, in that case the neighbors of a node
+    with the minimum degree are returned.
+
+    :param graph: The graph to find a minimum node cut of. Can either be a
+        :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`
+    :param int source: An optional source node index to separate from
+        ``target``
+    :param int target: An optional target node index
+
+    :returns: The node indices in the cut, empty if the graph isn't
+        (strongly) connected or has fewer than 2 nodes.
+    :rtype: NodeIndices
+
+    :raises InvalidNode: If ``source`` or ``target`` is not in the graph
+    :raises ValueError: If only one of ``source`` and ``target`` is
+        specified, they are the same node or there is an edge from
+        ``source`` to ``target``
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(graph))
+
+
+@minimum_node_cut.register(PyDiGraph)
+def _digraph_minimum_node_cut(graph, source=None, target=None):
+    return digraph_minimum_node_cut(graph, source=source, target=target)
+
+
+@minimum_node_cut.register(PyGraph)
+def _graph_minimum_node_cut(graph, source=None, target=None):
+    return graph_minimum_node_cut(graph, source=source, target=target)
+
+
+@functools.singledispatch
+def complement(graph):
+    """Compute the complement of a graph.
+
+    :param graph: The graph to be used, can be either a
+        :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`.
+
+    :returns: The complement of the graph.
+    :rtype: :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`
+
+    .. note::
+        Parallel edges and self-loops are never created,
+        even if the ``multigraph`` is set to ``True``
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(graph))
+
+
+@complement.register(PyDiGraph)
+def _digraph_complement(graph):
+    return digraph_complement(graph)
+
+
+@complement.register(PyGraph)
+def _graph_complement(graph):
+    return graph_complement(graph)
+
+
+@functools.singledispatch
+def power(graph, k):
+    """Compute the ``k``-th power of a graph.
+
+    The ``k``-th power of a graph has the same nodes, and an edge between
+    every pair of distinct nodes with a path of at most ``k`` edges between
+    them in the graph. For a :class:`~retworkx.PyDiGraph` the paths follow the
+    edge directions, so there is an edge from ``u`` to ``v`` if ``v`` can be
+    reached from ``u`` in at most ``k`` hops. The neighborhood of each node is
+    expanded one hop at a time with a breadth-first search, so this is
+    efficient for large sparse graphs and never computes a dense matrix
+    power. For example:
+
+    .. jupyter-execute::
+
+        import retworkx
+
+        graph = retworkx.generators.path_graph(5)
+        square = retworkx.power(graph, 2)
+        print(sorted(square.edge_list()))
+
+    :param graph: The graph to be used, can be either a
         :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`.
-        It should be the same type as the first graph.
-    :param callable node_matcher: A python callable object that takes 2
-        positional one for each node data object. If the return of this
-        function evaluates to True then the nodes passed to it are viewed
-        as matching.
-    :param callable edge_matcher: A python callable object that takes 2
-        positional one for each edge data object. If the return of this
-        function evaluates to True then the edges passed to it are viewed
-        as matching.
-    :param bool id_order: If set to ``True`` this function will match the nodes
-        in order specified by their ids. Otherwise it will default to a heuristic
-        matching order based on [VF2]_ paper.
-    :param bool induced: If set to ``True`` this function will check the existence
-        of a node-induced subgraph of first isomorphic to second graph.
-        Default: ``True``.
-    :param int call_limit: An optional bound on the number of states that VF2
-        algorithm visits while searching for a solution. If it exceeds this limit,
-        the algorithm will stop and return ``False``.
+    :param int k: The power, the maximum number of hops between the
+        endpoints of an edge. It must be at least 1.
+
+    :returns: The ``k``-th power of the graph. It has the same node indices
+        and node data as ``graph``, and the data of every edge is ``None``.
+    :rtype: :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`
+
+    :raises ValueError: If ``k`` is 0
+
+    .. note::
+        Parallel edges and self-loops are never created,
+        even if the ``multigraph`` is set to ``True``
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(graph))
+
+
+@power.register(PyDiGraph)
+def _digraph_power(graph, k):
+    return digraph_power(graph, k)
+
+
+@power.register(PyGraph)
+def _graph_power(graph, k):
+    return graph_power(graph, k)
+
+
+@functools.singledispatch
+def random_layout(graph, center=None, seed=None):
+    """Generate a random layout
+
+    :param PyGraph graph: The graph to generate the layout for
+    :param tuple center: An optional center position. This is a 2 tuple of two
+        ``float`` values for the center position
+    :param int seed: An optional seed to set for the random number generator.
+
+    :returns: The random layout of the graph.
+    :rtype: Pos2DMapping
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(graph))
+
+
+@random_layout.register(PyDiGraph)
+def _digraph_random_layout(graph, center=None, seed=None):
+    return digraph_random_layout(graph, center=center, seed=seed)
+
+
+@random_layout.register(PyGraph)
+def _graph_random_layout(graph, center=None, seed=None):
+    return graph_random_layout(graph, center=center, seed=seed)
+
+
+@functools.singledispatch
+def spring_layout(
+    graph,
+    pos=None,
+    fixed=None,
+    k=None,
+    repulsive_exponent=2,
+    adaptive_cooling=True,
+    num_iter=50,
+    tol=1e-6,
+    weight_fn=None,
+    default_weight=1,
+    scale=1,
+    center=None,
+    seed=None,
+    explain=False,
+):
+    """
+    Position nodes using Fruchterman-Reingold force-directed algorithm.
+
+    The algorithm simulates a force-directed representation of the network
+    treating edges as springs holding nodes close, while treating nodes
+    as repelling objects, sometimes called an anti-gravity force.
+    Simulation continues until the positions are close to an equilibrium.
+
+    :param graph: Graph to be used. Can either be a
+        :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`.
+    :param dict pos:
+        Initial node positions as a dictionary with node ids as keys and values
+        as a coordinate list. If ``None``, then use random initial positions.
+        (``default=None``)
+    :param set fixed: Nodes to keep fixed at initial position.
+        Error raised if fixed specified and ``pos`` is not. (``default=None``)
+    :param float  k:
+        Optimal distance between nodes. If ``None`` the distance is set to
+        :math:`\\frac{1}{\\sqrt{n}}` where :math:`n` is the number of nodes.
+        Increase this value to move nodes farther apart. (``default=None``)
+    :param int repulsive_exponent:
+        Repulsive force exponent. (``default=2``)
+    :param bool adaptive_cooling:
+        Use an adaptive cooling scheme. If set to ``False``,
+        a linear cooling scheme is used. (``default=True``)
+    :param int num_iter:
+        Maximum number of iterations. (``default=50``)
+    :param float tol:
+        Threshold for relative error in node position changes.
+        The iteration stops if the error is below this threshold.
+        (``default = 1e-6``)
+    :param weight_fn: An optional weight function for an edge. It will accept
+        a single argument, the edge's weight object and will return a float
+        which will be used to represent the weight of the edge.
+    :param float (default=1) default_weight: If ``weight_fn`` isn't specified
+        this optional float value will be used for the weight/cost of each edge
+    :param float|None scale: Scale factor for positions.
+        Not used unless fixed is None. If scale is ``None``, no re-scaling is
+        performed. (``default=1.0``)
+    :param list center: Coordinate pair around which to center
+        the layout. Not used unless fixed is ``None``. (``default=None``)
+    :param int seed: An optional seed to use for the random number generator.
+        If not set a seed is drawn from the operating system, it's recorded
+        when ``explain`` is set.
+    :param bool explain: If set to ``True`` an
+        :class:`~retworkx.AlgorithmInfo` recording how the layout was run is
+        returned along with the positions (``default=False``)
+
+    :returns: A dictionary of positions keyed by node id.
+        If ``explain`` is ``True`` a tuple of this and an
+        :class:`~retworkx.AlgorithmInfo` is returned instead.
+    :rtype: dict
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(graph))
+
+
+@spring_layout.register(PyDiGraph)
+def _digraph_spring_layout(
+    graph,
+    pos=None,
+    fixed=None,
+    k=None,
+    repulsive_exponent=2,
+    adaptive_cooling=True,
+    num_iter=50,
+    tol=1e-6,
+    weight_fn=None,
+    default_weight=1,
+    scale=1,
+    center=None,
+    seed=None,
+    explain=False,
+):
+    return digraph_spring_layout(
+        graph,
+        pos,
+        fixed,
+        k,
+        repulsive_exponent,
+        adaptive_cooling,
+        num_iter,
+        tol,
+        weight_fn,
+        default_weight,
+        scale,
+        center,
+        seed,
+        explain,
+    )
+
+
+@spring_layout.register(PyGraph)
+def _graph_spring_layout(
+    graph,
+    pos=None,
+    fixed=None,
+    k=None,
+    repulsive_exponent=2,
+    adaptive_cooling=True,
+    num_iter=50,
+    tol=1e-6,
+    weight_fn=None,
+    default_weight=1,
+    scale=1,
+    center=None,
+    seed=None,
+    explain=False,
+):
+    return graph_spring_layout(
+        graph,
+        pos,
+        fixed,
+        k,
+        repulsive_exponent,
+        adaptive_cooling,
+        num_iter,
+        tol,
+        weight_fn,
+        default_weight,
+        scale,
+        center,
+        seed,
+        explain,
+    )
+
+
+def networkx_converter(graph, keep_attributes: bool = False):
+    """Convert a networkx graph object into a retworkx graph object.
+
+    .. note::
+
+        networkx is **not** a dependency of retworkx and this function
+        is provided as a convenience method for users of both networkx and
+        retworkx. This function will not work unless you install networkx
+        independently.
+
+    :param networkx.Graph graph: The networkx graph to convert.
+    :param bool keep_attributes: If ``True``, add networkx node attributes to
+        the data payload in the nodes of the output retworkx graph. When set to
+        ``True``, the node data payloads in the output retworkx graph object
+        will be dictionaries with the node attributes from the input networkx
+        graph where the ``"__networkx_node__"`` key contains the node from the
+        input networkx graph.
+
+    :returns: A retworkx graph, either a :class:`~retworkx.PyDiGraph` or a
+        :class:`~retworkx.PyGraph` based on whether the input graph is directed
+        or not.
+    :rtype: :class:`~retworkx.PyDiGraph` or :class:`~retworkx.PyGraph`
+    """
+    if graph.is_directed():
+        new_graph = PyDiGraph(multigraph=graph.is_multigraph())
+    else:
+        new_graph = PyGraph(multigraph=graph.is_multigraph())
+    nodes = list(graph.nodes)
+    node_indices = dict(zip(nodes, new_graph.add_nodes_from(nodes)))
+    new_graph.add_edges_from(
+        [(node_indices[x[0]], node_indices[x[1]], x[2]) for x in graph.edges(data=True)]
+    )
+
+    if keep_attributes:
+        for node, node_index in node_indices.items():
+            attributes = graph.nodes[node]
+            attributes["__networkx_node__"] = node
+            new_graph[node_index] = attributes
+
+    return new_graph
+
+
+@functools.singledispatch
+def bipartite_layout(
+    graph,
+    first_nodes,
+    horizontal=False,
+    scale=1,
+    center=None,
+    aspect_ratio=4 / 3,
+):
+    """Generate a bipartite layout of the graph
+
+    :param graph: The graph to generate the layout for. Can either be a
+        :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`
+    :param set first_nodes: The set of node indices on the left (or top if
+        horitontal is true)
+    :param bool horizontal: An optional bool specifying the orientation of the
+        layout
+    :param float scale: An optional scaling factor to scale positions
+    :param tuple center: An optional center position. This is a 2 tuple of two
+        ``float`` values for the center position
+    :param float aspect_ratio: An optional number for the ratio of the width to
+        the height of the layout.
+
+    :returns: The bipartite layout of the graph.
+    :rtype: Pos2DMapping
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(graph))
+
+
+@bipartite_layout.register(PyDiGraph)
+def _digraph_bipartite_layout(
+    graph,
+    first_nodes,
+    horizontal=False,
+    scale=1,
+    center=None,
+    aspect_ratio=4 / 3,
+):
+    return digraph_bipartite_layout(
+        graph,
+        first_nodes,
+        horizontal=horizontal,
+        scale=scale,
+        center=center,
+        aspect_ratio=aspect_ratio,
+    )
+
+
+@bipartite_layout.register(PyGraph)
+def _graph_bipartite_layout(
+    graph,
+    first_nodes,
+    horizontal=False,
+    scale=1,
+    center=None,
+    aspect_ratio=4 / 3,
+):
+    return graph_bipartite_layout(
+        graph,
+        first_nodes,
+        horizontal=horizontal,
+        scale=scale,
+        center=center,
+        aspect_ratio=aspect_ratio,
+    )
+
+
+@functools.singledispatch
+def circular_layout(graph, scale=1, center=None):
+    """Generate a circular layout of the graph
+
+    :param graph: The graph to generate the layout for. Can either be a
+        :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`
+    :param float scale: An optional scaling factor to scale positions
+    :param tuple center: An optional center position. This is a 2 tuple of two
+        ``float`` values for the center position
+
+    :returns: The circular layout of the graph.
+    :rtype: Pos2DMapping
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(graph))
+
+
+@circular_layout.register(PyDiGraph)
+def _digraph_circular_layout(graph, scale=1, center=None):
+    return digraph_circular_layout(graph, scale=scale, center=center)
+
+
+@circular_layout.register(PyGraph)
+def _graph_circular_layout(graph, scale=1, center=None):
+    return graph_circular_layout(graph, scale=scale, center=center)
+
+
+@functools.singledispatch
+def shell_layout(graph, nlist=None, rotate=None, scale=1, center=None):
+    """
+    Generate a shell layout of the graph
+
+    :param graph: The graph to generate the layout for. Can either be a
+        :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`
+    :param list nlist: The list of lists of indices which represents each shell
+    :param float rotate: Angle (in radians) by which to rotate the starting
+        position of each shell relative to the starting position of the
+        previous shell
+    :param float scale: An optional scaling factor to scale positions
+    :param tuple center: An optional center position. This is a 2 tuple of two
+        ``float`` values for the center position
+
+    :returns: The shell layout of the graph.
+    :rtype: Pos2DMapping
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(graph))
+
+
+@shell_layout.register(PyDiGraph)
+def _digraph_shell_layout(graph, nlist=None, rotate=None, scale=1, center=None):
+    return digraph_shell_layout(graph, nlist=nlist, rotate=rotate, scale=scale, center=center)
+
+
+@shell_layout.register(PyGraph)
+def _graph_shell_layout(graph, nlist=None, rotate=None, scale=1, center=None):
+    return graph_shell_layout(graph, nlist=nlist, rotate=rotate, scale=scale, center=center)
+
+
+@functools.singledispatch
+def planar_layout(graph, scale=1, center=None):
+    """Generate a planar layout of the graph
+
+    The nodes are positioned on a grid so that the edges, drawn as straight
+    lines, don't cross. The direction of the edges of a
+    :class:`~retworkx.PyDiGraph` is ignored. The drawing is computed from a
+    planar embedding of the graph (see :func:`~retworkx.check_planarity`)
+    with the shift method of Chrobak and Payne in linear time.
+
+    :param graph: The graph to generate the layout for. Can either be a
+        :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`
+    :param float scale: An optional scaling factor to scale positions
+    :param tuple center: An optional center position. This is a 2 tuple of two
+        ``float`` values for the center position
+
+    :returns: The planar layout of the graph.
+    :rtype: Pos2DMapping
+
+    :raises ValueError: If the graph isn't planar
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(graph))
+
+
+@planar_layout.register(PyDiGraph)
+def _digraph_planar_layout(graph, scale=1, center=None):
+    return digraph_planar_layout(graph, scale=scale, center=center)
+
+
+@planar_layout.register(PyGraph)
+def _graph_planar_layout(graph, scale=1, center=None):
+    return graph_planar_layout(graph, scale=scale, center=center)
+
+
+@functools.singledispatch
+def spectral_layout(
+    graph,
+    weight_fn=None,
+    default_weight=1.0,
+    scale=1,
+    center=None,
+    max_iter=1000,
+    tol=1e-6,
+    explain=False,
+):
+    """Generate a spectral layout of the graph
+
+    The coordinates of the nodes are the entries of the eigenvectors of the
+    second and third smallest eigenvalues of the Laplacian matrix of the
+    graph, which places nodes with heavy edges between them close together.
+    The eigenvectors are computed with the LOBPCG method, which only
+    multiplies vectors by the sparse Laplacian matrix, so it scales to large
+    graphs. The direction of the edges of a :class:`~retworkx.PyDiGraph` is
+    ignored.
+
+    :param graph: The graph to generate the layout for. Can either be a
+        :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`
+    :param weight_fn: An optional weight function for an edge. It will accept
+        a single argument, the edge's weight object and will return a float
+        which will be used to represent the weight of the edge. The weights
+        must be non-negative.
+    :param float default_weight: If ``weight_fn`` isn't specified this
+        optional float value will be used for the weight of each edge
+    :param float scale: An optional scaling factor to scale positions
+    :param tuple center: An optional center position. This is a 2 tuple of two
+        ``float`` values for the center position
+    :param int max_iter: The maximum number of iterations of LOBPCG
+    :param float tol: The error tolerance used when checking for convergence,
+        the iteration stops once the residual norm of every eigenvector is
+        less than ``tol`` times twice the largest weighted degree.
+    :param bool explain: If set to ``True`` an
+        :class:`~retworkx.AlgorithmInfo` recording how the layout was run is
+        returned along with the positions
+
+    :returns: The spectral layout of the graph. If ``explain`` is ``True`` a
+        tuple of this and an :class:`~retworkx.AlgorithmInfo` is returned
+        instead.
+    :rtype: Pos2DMapping
+
+    :raises FailedToConverge: If LOBPCG doesn't converge within ``max_iter``
+        iterations
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(graph))
+
+
+@spectral_layout.register(PyDiGraph)
+def _digraph_spectral_layout(
+    graph,
+    weight_fn=None,
+    default_weight=1.0,
+    scale=1,
+    center=None,
+    max_iter=1000,
+    tol=1e-6,
+    explain=False,
+):
+    return digraph_spectral_layout(
+        graph,
+        weight_fn=weight_fn,
+        default_weight=default_weight,
+        scale=scale,
+        center=center,
+        max_iter=max_iter,
+        tol=tol,
+        explain=explain,
+    )
+
+
+@spectral_layout.register(PyGraph)
+def _graph_spectral_layout(
+    graph,
+    weight_fn=None,
+    default_weight=1.0,
+    scale=1,
+    center=None,
+    max_iter=1000,
+    tol=1e-6,
+    explain=False,
+):
+    return graph_spectral_layout(
+        graph,
+        weight_fn=weight_fn,
+        default_weight=default_weight,
+        scale=scale,
+        center=center,
+        max_iter=max_iter,
+        tol=tol,
+        explain=explain,
+    )
+
+
+@functools.singledispatch
+def kamada_kawai_layout(
+    graph,
+    pos=None,
+    weight_fn=None,
+    default_weight=1.0,
+    landmarks=None,
+    max_iter=1000,
+    tol=1e-4,
+    scale=1,
+    center=None,
+    explain=False,
+):
+    """Generate a Kamada-Kawai layout of the graph
+
+    The Kamada-Kawai algorithm [1]_ places the nodes so that the euclidean
+    distance between every pair of nodes matches the length of the shortest
+    path between them, by minimizing the energy of a spring between every
+    pair of nodes whose length is the shortest path length and whose
+    stiffness is the inverse of its squared length. The energy is minimized
+    by moving every node in turn with a Newton-Raphson step on its position,
+    or a stress majorization step when the Newton-Raphson step doesn't
+    decrease the energy. The direction of the edges of a
+    :class:`~retworkx.PyDiGraph` is ignored.
+
+    Computing the shortest paths between all pairs of nodes takes
+    :math:`O(n^2)` memory and every iteration takes :math:`O(n^2)` time, for
+    large graphs set ``landmarks`` to attach every node only to its
+    neighbors and to a number of landmark nodes spread over the graph, which
+    takes :math:`O(n \\cdot landmarks)` time and memory instead.
+
+    :param graph: The graph to generate the layout for. Can either be a
+        :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`
+    :param dict pos: Initial positions of the nodes as a dictionary with node
+        indices as keys and coordinate pairs as values. Nodes without an
+        initial position start on a circle, or at the pivot MDS layout of the
+        landmarks if ``landmarks`` is set.
+    :param weight_fn: An optional weight function for an edge. It will accept
+        a single argument, the edge's weight object and will return a float
+        which will be used as the length of the edge. The lengths must be
+        positive.
+    :param float default_weight: If ``weight_fn`` isn't specified this
+        optional float value will be used as the length of each edge
+    :param int landmarks: If set, the number of landmark nodes which every
+        node is attached to, in addition to its neighbors, instead of every
+        other node. The landmarks are chosen to be far from each other.
+    :param int max_iter: The maximum number of iterations, in every
+        iteration each node is moved once.
+    :param float tol: The error tolerance used when checking for convergence,
+        the iteration stops once the norm of the gradient of the energy with
+        respect to the position of every node, divided by the number of
+        springs attached to it, is less than ``tol``.
+    :param float scale: An optional scaling factor to scale positions. If
+        ``None`` the positions are in the unit of the edge lengths.
+    :param tuple center: An optional center position. This is a 2 tuple of two
+        ``float`` values for the center position
+    :param bool explain: If set to ``True`` an
+        :class:`~retworkx.AlgorithmInfo` recording how the layout was run is
+        returned along with the positions
+
+    :returns: The Kamada-Kawai layout of the graph. If ``explain`` is
+        ``True`` a tuple of this and an :class:`~retworkx.AlgorithmInfo` is
+        returned instead.
+    :rtype: Pos2DMapping
+
+    :raises ValueError: If an edge length isn't positive
+
+    .. [1] T. Kamada and S. Kawai, "An algorithm for drawing general
+        undirected graphs", Information Processing Letters 31, 1989,
+        pp. 7-15, https://doi.org/10.1016/0020-0190(89)90102-6
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(graph))
+
+
+@kamada_kawai_layout.register(PyDiGraph)
+def _digraph_kamada_kawai_layout(
+    graph,
+    pos=None,
+    weight_fn=None,
+    default_weight=1.0,
+    landmarks=None,
+    max_iter=1000,
+    tol=1e-4,
+    scale=1,
+    center=None,
+    explain=False,
+):
+    return digraph_kamada_kawai_layout(
+        graph,
+        pos=pos,
+        weight_fn=weight_fn,
+        default_weight=default_weight,
+        landmarks=landmarks,
+        max_iter=max_iter,
+        tol=tol,
+        scale=scale,
+        center=center,
+        explain=explain,
+    )
+
+
+@kamada_kawai_layout.register(PyGraph)
+def _graph_kamada_kawai_layout(
+    graph,
+    pos=None,
+    weight_fn=None,
+    default_weight=1.0,
+    landmarks=None,
+    max_iter=1000,
+    tol=1e-4,
+    scale=1,
+    center=None,
+    explain=False,
+):
+    return graph_kamada_kawai_layout(
+        graph,
+        pos=pos,
+        weight_fn=weight_fn,
+        default_weight=default_weight,
+        landmarks=landmarks,
+        max_iter=max_iter,
+        tol=tol,
+        scale=scale,
+        center=center,
+        explain=explain,
+    )
 
-    :returns: ``True`` if there is a subgraph of `first` isomorphic to `second`
-        , ``False`` if there is not.
-    :rtype: bool
+
+@functools.singledispatch
+def edge_bundling(
+    graph,
+    pos,
+    stiffness=0.1,
+    compatibility_threshold=0.6,
+    cycles=6,
+    iterations=90,
+    step_size=0.1,
+):
+    """Bundle the edges of a layout with force-directed edge bundling
+
+    Force-directed edge bundling [1]_ draws edges as polylines whose control
+    points attract the control points of compatible edges, which are edges
+    of similar length and direction close to each other, so they are drawn
+    together in bundles. This makes the layouts of dense graphs easier to
+    read. Every edge is a chain of springs between its control points which
+    keeps it smooth. The bundling runs in ``cycles`` cycles, after every
+    cycle the number of control points of every edge is doubled, the step
+    size is halved and the number of iterations is reduced by a third.
+
+    The layout is scaled so the larger side of its bounding box is 1000
+    during the bundling, so the result doesn't depend on the scale of the
+    layout. Computing the compatible edges takes :math:`O(m^2)` time for
+    :math:`m` edges. Self loops and edges between nodes at the same position
+    aren't bundled.
+
+    :param graph: The graph the layout is of. Can either be a
+        :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`
+    :param pos: The positions of the nodes, either a
+        :class:`~retworkx.Pos2DMapping` returned by a layout function or a
+        dictionary mapping node indices to ``[x, y]`` coordinates
+    :param float stiffness: The stiffness of the springs between the control
+        points of an edge, higher values bundle less
+    :param float compatibility_threshold: The minimum compatibility, between
+        0 and 1, of two edges which attract each other
+    :param int cycles: The number of cycles
+    :param int iterations: The number of iterations of the first cycle
+    :param float step_size: The step size of the first cycle, relative to
+        the layout scaled to a size of 1000
+
+    :returns: The polyline of every edge as a mapping of edge indices to
+        lists of points from the position of the source of the edge to the
+        position of its target
+    :rtype: EdgePolylineMapping
+
+    :raises ValueError: If ``pos`` has no position for an endpoint of an
+        edge
+
+    .. [1] D. Holten and J. J. van Wijk, "Force-directed edge bundling for
+        graph visualization", Computer Graphics Forum 28(3), 2009,
+        pp. 983-990, https://doi.org/10.1111/j.1467-8659.2009.01450.x
     """
-    raise TypeError("Invalid Input Type %s for graph" % type(first))
+    raise TypeError("Invalid Input Type %s for graph" % type(graph))
 
 
-@is_subgraph_isomorphic.register(PyDiGraph)
-def _digraph_is_subgraph_isomorphic(
-    first,
-    second,
-    node_matcher=None,
-    edge_matcher=None,
-    id_order=False,
-    induced=True,
-    call_limit=None,
+@edge_bundling.register(PyDiGraph)
+def _digraph_edge_bundling(
+    graph,
+    pos,
+    stiffness=0.1,
+    compatibility_threshold=0.6,
+    cycles=6,
+    iterations=90,
+    step_size=0.1,
 ):
-    return digraph_is_subgraph_isomorphic(
-        first, second, node_matcher, edge_matcher, id_order, induced, call_limit
+    return digraph_edge_bundling(
+        graph,
+        pos,
+        stiffness=stiffness,
+        compatibility_threshold=compatibility_threshold,
+        cycles=cycles,
+        iterations=iterations,
+        step_size=step_size,
+    )
+
+
+@edge_bundling.register(PyGraph)
+def _graph_edge_bundling(
+    graph,
+    pos,
+    stiffness=0.1,
+    compatibility_threshold=0.6,
+    cycles=6,
+    iterations=90,
+    step_size=0.1,
+):
+    return graph_edge_bundling(
+        graph,
+        pos,
+        stiffness=stiffness,
+        compatibility_threshold=compatibility_threshold,
+        cycles=cycles,
+        iterations=iterations,
+        step_size=step_size,
+    )
+
+
+@functools.singledispatch
+def spiral_layout(graph, scale=1, center=None, resolution=0.35, equidistant=False):
+    """
+    Generate a spiral layout of the graph
+
+    :param graph: The graph to generate the layout for. Can either be a
+        :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`
+    :param float scale: An optional scaling factor to scale positions
+    :param tuple center: An optional center position. This is a 2 tuple of two
+        ``float`` values for the center position
+    :param float resolution: The compactness of the spiral layout returned.
+        Lower values result in more compressed spiral layouts.
+    :param bool equidistant: If true, nodes will be plotted equidistant from
+        each other.
+
+    :returns: The spiral layout of the graph.
+    :rtype: Pos2DMapping
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(graph))
+
+
+@spiral_layout.register(PyDiGraph)
+def _digraph_spiral_layout(graph, scale=1, center=None, resolution=0.35, equidistant=False):
+    return digraph_spiral_layout(
+        graph,
+        scale=scale,
+        center=center,
+        resolution=resolution,
+        equidistant=equidistant,
+    )
+
+
+@spiral_layout.register(PyGraph)
+def _graph_spiral_layout(graph, scale=1, center=None, resolution=0.35, equidistant=False):
+    return graph_spiral_layout(
+        graph,
+        scale=scale,
+        center=center,
+        resolution=resolution,
+        equidistant=equidistant,
+    )
+
+
+@functools.singledispatch
+def num_shortest_paths_unweighted(graph, source):
+    """Get the number of unweighted shortest paths from a source node
+
+    :param PyDiGraph graph: The graph to find the number of shortest paths on
+    :param int source: The source node to find the shortest paths from
+
+    :returns: A mapping of target node indices to the number of shortest paths
+        from ``source`` to that node. If there is no path from ``source`` to
+        a node in the graph that node will not be preset in the output mapping.
+    :rtype: NodesCountMapping
+    """
+    raise TypeError("Invalid input type %s for graph" % type(graph))
+
+
+@num_shortest_paths_unweighted.register(PyDiGraph)
+def _digraph_num_shortest_paths_unweighted(graph, source):
+    return digraph_num_shortest_paths_unweighted(graph, source)
+
+
+@num_shortest_paths_unweighted.register(PyGraph)
+def _graph_num_shortest_paths_unweighted(graph, source):
+    return graph_num_shortest_paths_unweighted(graph, source)
+
+
+@functools.singledispatch
+def betweenness_centrality(graph, normalized=True, endpoints=False, parallel_threshold=50):
+    r"""Returns the betweenness centrality of each node in the graph.
+
+    Betweenness centrality of a node :math:`v` is the sum of the
+    fraction of all-pairs shortest paths that pass through :math`v`
+
+    .. math::
+
+       c_B(v) =\sum_{s,t \in V} \frac{\sigma(s, t|v)}{\sigma(s, t)}
+
+    where :math:`V` is the set of nodes, :math:`\sigma(s, t)` is the number of
+    shortest :math`(s, t)` paths, and :math:`\sigma(s, t|v)` is the number of
+    those paths  passing through some  node :math:`v` other than :math:`s, t`.
+    If :math:`s = t`, :math:`\sigma(s, t) = 1`, and if :math:`v \in {s, t}`,
+    :math:`\sigma(s, t|v) = 0`
+
+    The algorithm used in this function is based on:
+
+    Ulrik Brandes, A Faster Algorithm for Betweenness Centrality.
+    Journal of Mathematical Sociology 25(2):163-177, 2001.
+
+    This function is multithreaded and will run in parallel if the number
+    of nodes in the graph is above the value of ``parallel_threshold`` (it
+    defaults to 50). If the function will be running in parallel the env var
+    ``RAYON_NUM_THREADS`` can be used to adjust how many threads will be used.
+
+    :param PyDiGraph graph: The input graph
+    :param bool normalized: Whether to normalize the betweenness scores by
+        the number of distinct paths between all pairs of nodes.
+    :param bool endpoints: Whether to include the endpoints of paths in
+        path lengths used to compute the betweenness.
+    :param int parallel_threshold: The number of nodes to calculate the
+        the betweenness centrality in parallel at if the number of nodes in
+        the graph is less than this value it will run in a single thread. The
+        default value is 50
+
+    :returns: A dictionary mapping each node index to its betweenness centrality.
+    :rtype: dict
+    """
+    raise TypeError("Invalid input type %s for graph" % type(graph))
+
+
+@betweenness_centrality.register(PyDiGraph)
+def _digraph_betweenness_centrality(graph, normalized=True, endpoints=False, parallel_threshold=50):
+    return digraph_betweenness_centrality(
+        graph,
+        normalized=normalized,
+        endpoints=endpoints,
+        parallel_threshold=parallel_threshold,
+    )
+
+
+@betweenness_centrality.register(PyGraph)
+def _graph_betweenness_centrality(graph, normalized=True, endpoints=False, parallel_threshold=50):
+    return graph_betweenness_centrality(
+        graph,
+        normalized=normalized,
+        endpoints=endpoints,
+        parallel_threshold=parallel_threshold,
+    )
+
+
+@functools.singledispatch
+def betweenness_centrality_subset(
+    graph, sources, targets, normalized=False, weight_fn=None, parallel_threshold=50
+):
+    r"""Compute the betweenness centrality of all nodes in a graph counting
+    only the shortest paths between a set of sources and a set of targets.
+
+    The subset betweenness centrality of a node :math:`v` is
+
+    .. math::
+
+       c_B(v) =\sum_{s \in S, t \in T} \frac{\sigma(s, t|v)}{\sigma(s, t)}
+
+    where :math:`S` is the set of sources, :math:`T` is the set of targets,
+    :math:`\sigma(s, t)` is the number of shortest :math:`(s, t)` paths, and
+    :math:`\sigma(s, t|v)` is the number of those paths passing through some
+    node :math:`v` other than :math:`s, t` [Brandes2008]_. It's computed with
+    the same algorithm as :func:`~retworkx.betweenness_centrality`.
+
+    This function is multithreaded and will run in parallel if the number
+    of sources is above the value of ``parallel_threshold`` (it defaults to
+    50). If the function will be running in parallel the env var
+    ``RAYON_NUM_THREADS`` can be used to adjust how many threads will be used.
+
+    :param graph: The input graph. This can be a :class:`~retworkx.PyGraph`
+        or a :class:`~retworkx.PyDiGraph`.
+    :param list sources: The node indices the counted shortest paths start at
+    :param list targets: The node indices the counted shortest paths end at
+    :param bool normalized: Whether to normalize the betweenness scores by the
+        number of distinct paths between all pairs of nodes.
+    :param weight_fn: An optional input callable that will be passed the edge's
+        payload object and is expected to return a non-negative ``float``
+        weight for that edge. If this is specified the shortest paths are
+        found with Dijkstra's algorithm using the weights, otherwise they're
+        the paths with the fewest edges.
+    :param int parallel_threshold: The number of sources to calculate the
+        the betweenness centrality in parallel at if the number of sources is
+        less than this value it will run in a single thread. The default value
+        is 50
+
+    :returns: a read-only dict-like object whose keys are the node indices and
+        values are the betweenness score for each node.
+    :rtype: CentralityMapping
+    :raises IndexError: If a source or a target isn't a node in ``graph``
+
+    .. [Brandes2008] Ulrik Brandes, "On Variants of Shortest-Path Betweenness
+        Centrality and their Generic Computation." Social Networks
+        30(2):136-145, 2008.
+    """
+    raise TypeError("Invalid input type %s for graph" % type(graph))
+
+
+@betweenness_centrality_subset.register(PyDiGraph)
+def _digraph_betweenness_centrality_subset(
+    graph, sources, targets, normalized=False, weight_fn=None, parallel_threshold=50
+):
+    return digraph_betweenness_centrality_subset(
+        graph,
+        sources,
+        targets,
+        normalized=normalized,
+        weight_fn=weight_fn,
+        parallel_threshold=parallel_threshold,
+    )
+
+
+@betweenness_centrality_subset.register(PyGraph)
+def _graph_betweenness_centrality_subset(
+    graph, sources, targets, normalized=False, weight_fn=None, parallel_threshold=50
+):
+    return graph_betweenness_centrality_subset(
+        graph,
+        sources,
+        targets,
+        normalized=normalized,
+        weight_fn=weight_fn,
+        parallel_threshold=parallel_threshold,
+    )
+
+
+@functools.singledispatch
+def eigenvector_centrality(
+    graph,
+    weight_fn=None,
+    default_weight=1.0,
+    max_iter=100,
+    tol=1e-6,
+    explain=False,
+    weight_attr=None,
+):
+    r"""Compute the eigenvector centrality of all nodes in a graph.
+
+    The eigenvector centrality of a node :math:`v` is proportional to the sum
+    of the centralities of the nodes linking to it, weighted by the edge
+    weights:
+
+    .. math::
+
+        x_v = \frac{1}{\lambda} \sum_{u} A_{uv} x_u
+
+    where :math:`A` is the weighted adjacency matrix of the graph and
+    :math:`\lambda` its largest eigenvalue [Newman]_. For a directed graph
+    the centrality of a node is based on its incoming edges. The centralities
+    are computed with power iteration on :math:`A + I` so the iteration also
+    converges on bipartite graphs.
+
+    :param graph: The graph to compute the eigenvector centrality of. This
+        can be a :class:`~retworkx.PyGraph` or a :class:`~retworkx.PyDiGraph`.
+    :param weight_fn: An optional input callable that will be passed the edge's
+        payload object and is expected to return a non-negative ``float``
+        weight for that edge. If this is not specified ``default_weight`` will
+        be used as the weight for every edge in ``graph``. The weights of
+        parallel edges are added.
+    :param float default_weight: If ``weight_fn`` is not set the default weight
+        value to use for the weight of all edges
+    :param int max_iter: The maximum number of iterations of the power method
+    :param float tol: The error tolerance used when checking for convergence,
+        the iteration stops once the change of the centralities sums to less
+        than the number of nodes times ``tol``.
+    :param bool explain: If set to ``True`` an
+        :class:`~retworkx.AlgorithmInfo` recording how the algorithm was run
+        is returned along with the result
+    :param str weight_attr: An optional name of an edge attribute with the
+        weight of the edges, as an alternative to ``weight_fn`` which
+        doesn't call into Python for every edge. The weights are read from the
+        edge attribute column with this name if the graph has one, see
+        :meth:`~retworkx.PyGraph.set_edge_attribute`, otherwise from this key
+        of the edges with a dict payload. Edges without a weight use
+        ``default_weight``.
+
+    :returns: a read-only dict-like object whose keys are the node indices and
+        values are the centrality of each node, normalized to a euclidean norm
+        of 1.
+        If ``explain`` is ``True`` a tuple of this and an
+        :class:`~retworkx.AlgorithmInfo` is returned instead.
+    :rtype: CentralityMapping
+    :raises FailedToConverge: If the power iteration doesn't converge within
+        ``max_iter`` iterations
+
+    .. [Newman] M. E. J. Newman, "Networks: An Introduction." Oxford
+        University Press, 2010, pp. 169.
+    """
+    raise TypeError("Invalid input type %s for graph" % type(graph))
+
+
+@eigenvector_centrality.register(PyDiGraph)
+def _digraph_eigenvector_centrality(
+    graph,
+    weight_fn=None,
+    default_weight=1.0,
+    max_iter=100,
+    tol=1e-6,
+    explain=False,
+    weight_attr=None,
+):
+    return digraph_eigenvector_centrality(
+        graph,
+        weight_fn=weight_fn,
+        default_weight=default_weight,
+        max_iter=max_iter,
+        tol=tol,
+        explain=explain,
+        weight_attr=weight_attr,
+    )
+
+
+@eigenvector_centrality.register(PyGraph)
+def _graph_eigenvector_centrality(
+    graph,
+    weight_fn=None,
+    default_weight=1.0,
+    max_iter=100,
+    tol=1e-6,
+    explain=False,
+    weight_attr=None,
+):
+    return graph_eigenvector_centrality(
+        graph,
+        weight_fn=weight_fn,
+        default_weight=default_weight,
+        max_iter=max_iter,
+        tol=tol,
+        explain=explain,
+        weight_attr=weight_attr,
+    )
+
+
+@functools.singledispatch
+def katz_centrality(
+    graph,
+    alpha=0.1,
+    beta=1.0,
+    weight_fn=None,
+    default_weight=1.0,
+    max_iter=1000,
+    tol=1e-6,
+    normalized=True,
+    explain=False,
+    weight_attr=None,
+):
+    r"""Compute the Katz centrality of all nodes in a graph.
+
+    The Katz centrality of a node counts the walks ending at the node, with
+    the walks of length :math:`k` attenuated by a factor of
+    :math:`\alpha^k`, plus a base centrality :math:`\beta` of every
+    node [Katz]_:
+
+    .. math::
+
+        x_v = \alpha \sum_{u} A_{uv} x_u + \beta_v
+
+    where :math:`A` is the weighted adjacency matrix of the graph. The
+    centralities are computed with power iteration, which only converges if
+    ``alpha`` is less than the inverse of the largest eigenvalue of
+    :math:`A`. For a directed graph the centrality of a node is based on its
+    incoming edges.
+
+    :param graph: The graph to compute the Katz centrality of. This can be a
+        :class:`~retworkx.PyGraph` or a :class:`~retworkx.PyDiGraph`.
+    :param float alpha: The attenuation factor
+    :param beta: The base centrality of every node, either a ``float`` used
+        for all nodes or a dictionary of node indices to the base centrality of
+        that node which must contain every node in ``graph``.
+    :param weight_fn: An optional input callable that will be passed the edge's
+        payload object and is expected to return a ``float`` weight for that
+        edge. If this is not specified ``default_weight`` will be used as the
+        weight for every edge in ``graph``. The weights of parallel edges are
+        added.
+    :param float default_weight: If ``weight_fn`` is not set the default weight
+        value to use for the weight of all edges
+    :param int max_iter: The maximum number of iterations of the power method
+    :param float tol: The error tolerance used when checking for convergence,
+        the iteration stops once the change of the centralities sums to less
+        than the number of nodes times ``tol``.
+    :param bool normalized: If set to ``True`` the centralities are normalized
+        to a euclidean norm of 1.
+    :param bool explain: If set to ``True`` an
+        :class:`~retworkx.AlgorithmInfo` recording how the algorithm was run
+        is returned along with the result
+    :param str weight_attr: An optional name of an edge attribute with the
+        weight of the edges, as an alternative to ``weight_fn`` which
+        doesn't call into Python for every edge. The weights are read from the
+        edge attribute column with this name if the graph has one, see
+        :meth:`~retworkx.PyGraph.set_edge_attribute`, otherwise from this key
+        of the edges with a dict payload. Edges without a weight use
+        ``default_weight``.
+
+    :returns: a read-only dict-like object whose keys are the node indices and
+        values are the centrality of each node.
+        If ``explain`` is ``True`` a tuple of this and an
+        :class:`~retworkx.AlgorithmInfo` is returned instead.
+    :rtype: CentralityMapping
+    :raises FailedToConverge: If the power iteration doesn't converge within
+        ``max_iter`` iterations
+    :raises ValueError: If ``beta`` is a dictionary missing a node of ``graph``
+        or if both ``weight_fn`` and ``weight_attr`` are specified
+
+    .. [Katz] Leo Katz, "A New Status Index Derived from Sociometric Index."
+        Psychometrika 18(1):39-43, 1953.
+    """
+    raise TypeError("Invalid input type %s for graph" % type(graph))
+
+
+@katz_centrality.register(PyDiGraph)
+def _digraph_katz_centrality(
+    graph,
+    alpha=0.1,
+    beta=1.0,
+    weight_fn=None,
+    default_weight=1.0,
+    max_iter=1000,
+    tol=1e-6,
+    normalized=True,
+    explain=False,
+    weight_attr=None,
+):
+    return digraph_katz_centrality(
+        graph,
+        alpha=alpha,
+        beta=beta,
+        weight_fn=weight_fn,
+        default_weight=default_weight,
+        max_iter=max_iter,
+        tol=tol,
+        normalized=normalized,
+        explain=explain,
+        weight_attr=weight_attr,
     )
 
 
-@is_subgraph_isomorphic.register(PyGraph)
-def _graph_is_subgraph_isomorphic(
-    first,
-    second,
-    node_matcher=None,
-    edge_matcher=None,
-    id_order=False,
-    induced=True,
-    call_limit=None,
+@katz_centrality.register(PyGraph)
+def _graph_katz_centrality(
+    graph,
+    alpha=0.1,
+    beta=1.0,
+    weight_fn=None,
+    default_weight=1.0,
+    max_iter=1000,
+    tol=1e-6,
+    normalized=True,
+    explain=False,
+    weight_attr=None,
 ):
-    return graph_is_subgraph_isomorphic(
-        first, second, node_matcher, edge_matcher, id_order, induced, call_limit
+    return graph_katz_centrality(
+        graph,
+        alpha=alpha,
+        beta=beta,
+        weight_fn=weight_fn,
+        default_weight=default_weight,
+        max_iter=max_iter,
+        tol=tol,
+        normalized=normalized,
+        explain=explain,
+        weight_attr=weight_attr,
     )
 
 
 @functools.singledispatch
-def transitivity(graph):
-    """Compute the transitivity of a graph.
+def closeness_centrality(graph, wf_improved=True, weight_fn=None, parallel_threshold=50):
+    r"""Compute the closeness centrality of all nodes in a graph.
 
-    This function is multithreaded and will run
-    launch a thread pool with threads equal to the number of CPUs by default.
-    You can tune the number of threads with the ``RAYON_NUM_THREADS``
-    environment variable. For example, setting ``RAYON_NUM_THREADS=4`` would
-    limit the thread pool to 4 threads.
+    The closeness centrality of a node :math:`u` is the reciprocal of the
+    average shortest path distance to :math:`u` over all the nodes which can
+    reach it:
 
-    .. note::
+    .. math::
 
-        The function implicitly assumes that there are no parallel edges
-        or self loops. It may produce incorrect/unexpected results if the
-        input graph has self loops or parallel edges.
+        C(u) = \frac{n - 1}{\sum_{v=1}^{n-1} d(v, u)}
 
-    :param graph: The graph to be used. Can either be a
-        :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`.
+    where :math:`d(v, u)` is the shortest path distance from :math:`v` to
+    :math:`u` and :math:`n` is the number of nodes which can reach :math:`u`
+    [Freeman]_. For a directed graph the incoming distances are used. If the
+    graph isn't connected the Wasserman and Faust improved formula [WF]_
+    scales the centrality by :math:`(n - 1) / (N - 1)`, where :math:`N` is the
+    number of nodes in the graph, so nodes in smaller components don't get a
+    higher centrality.
 
-    :returns: Transitivity of the graph.
-    :rtype: float
-        raise TypeError("Invalid Input Type %s for graph" % type(graph))
+    This function is multithreaded and will run in parallel if the number
+    of nodes in the graph is above the value of ``parallel_threshold`` (it
+    defaults to 50). If the function will be running in parallel the env var
+    ``RAYON_NUM_THREADS`` can be used to adjust how many threads will be used.
+
+    :param graph: The graph to compute the closeness centrality of. This can
+        be a :class:`~retworkx.PyGraph` or a :class:`~retworkx.PyDiGraph`.
+    :param bool wf_improved: Whether to use the Wasserman and Faust improved
+        formula
+    :param weight_fn: An optional input callable that will be passed the edge's
+        payload object and is expected to return a non-negative ``float``
+        weight for that edge. If this is specified the distances are the
+        weighted shortest path lengths found with Dijkstra's algorithm,
+        otherwise they're the number of edges on the shortest paths.
+    :param int parallel_threshold: The number of nodes to calculate the
+        the closeness centrality in parallel at if the number of nodes in
+        the graph is less than this value it will run in a single thread. The
+        default value is 50
+
+    :returns: a read-only dict-like object whose keys are the node indices and
+        values are the closeness centrality of each node.
+    :rtype: CentralityMapping
+
+    .. [Freeman] Linton C. Freeman, "Centrality in networks: I. Conceptual
+        clarification." Social Networks 1(3):215-239, 1979.
+    .. [WF] Stanley Wasserman and Katherine Faust, "Social Network Analysis:
+        Methods and Applications." Cambridge University Press, 1994, pp. 201.
     """
-    raise TypeError("Invalid Input Type %s for graph" % type(graph))
+    raise TypeError("Invalid input type %s for graph" % type(graph))
 
 
-@transitivity.register(PyDiGraph)
-def _digraph_transitivity(graph):
-    return digraph_transitivity(graph)
+@closeness_centrality.register(PyDiGraph)
+def _digraph_closeness_centrality(graph, wf_improved=True, weight_fn=None, parallel_threshold=50):
+    return digraph_closeness_centrality(
+        graph,
+        wf_improved=wf_improved,
+        weight_fn=weight_fn,
+        parallel_threshold=parallel_threshold,
+    )
 
 
-@transitivity.register(PyGraph)
-def _graph_transitivity(graph):
-    return graph_transitivity(graph)
+@closeness_centrality.register(PyGraph)
+def _graph_closeness_centrality(graph, wf_improved=True, weight_fn=None, parallel_threshold=50):
+    return graph_closeness_centrality(
+        graph,
+        wf_improved=wf_improved,
+        weight_fn=weight_fn,
+        parallel_threshold=parallel_threshold,
+    )
 
 
 @functools.singledispatch
-def core_number(graph):
-    """Return the core number for each node in the graph.
+def harmonic_centrality(graph, weight_fn=None, parallel_threshold=50):
+    r"""Compute the harmonic centrality of all nodes in a graph.
 
-    A k-core is a maximal subgraph that contains nodes of degree k or more.
+    The harmonic centrality of a node :math:`u` is the sum of the reciprocals
+    of the shortest path distances from all the other nodes to :math:`u`:
 
-    .. note::
+    .. math::
 
-        The function implicitly assumes that there are no parallel edges
-        or self loops. It may produce incorrect/unexpected results if the
-        input graph has self loops or parallel edges.
+        C(u) = \sum_{v \neq u} \frac{1}{d(v, u)}
 
-    :param graph: The graph to get core numbers. Can either be a
-        :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`
+    where :math:`d(v, u)` is the shortest path distance from :math:`v` to
+    :math:`u` [BV]_. Nodes which can't reach :math:`u` contribute 0, so it's
+    well defined for graphs which aren't connected. For a directed graph the
+    incoming distances are used.
 
-    :returns: A dictionary keyed by node index to the core number
-    :rtype: dict
-        raise TypeError("Invalid Input Type %s for graph" % type(graph))
-    """
-    raise TypeError("Invalid Input Type %s for graph" % type(graph))
+    This function is multithreaded and will run in parallel if the number
+    of nodes in the graph is above the value of ``parallel_threshold`` (it
+    defaults to 50). If the function will be running in parallel the env var
+    ``RAYON_NUM_THREADS`` can be used to adjust how many threads will be used.
 
+    :param graph: The graph to compute the harmonic centrality of. This can
+        be a :class:`~retworkx.PyGraph` or a :class:`~retworkx.PyDiGraph`.
+    :param weight_fn: An optional input callable that will be passed the edge's
+        payload object and is expected to return a non-negative ``float``
+        weight for that edge. If this is specified the distances are the
+        weighted shortest path lengths found with Dijkstra's algorithm,
+        otherwise they're the number of edges on the shortest paths.
+    :param int parallel_threshold: The number of nodes to calculate the
+        the harmonic centrality in parallel at if the number of nodes in
+        the graph is less than this value it will run in a single thread. The
+        default value is 50
 
-@core_number.register(PyDiGraph)
-def _digraph_core_number(graph):
-    return digraph_core_number(graph)
+    :returns: a read-only dict-like object whose keys are the node indices and
+        values are the harmonic centrality of each node.
+    :rtype: CentralityMapping
 
+    .. [BV] Paolo Boldi and Sebastiano Vigna, "Axioms for centrality."
+        Internet Mathematics 10(3-4):222-262, 2014.
+    """
+    raise TypeError("Invalid input type %s for graph" % type(graph))
 
-@core_number.register(PyGraph)
-def _graph_core_number(graph):
-    return graph_core_number(graph)
 
+@harmonic_centrality.register(PyDiGraph)
+def _digraph_harmonic_centrality(graph, weight_fn=None, parallel_threshold=50):
+    return digraph_harmonic_centrality(
+        graph, weight_fn=weight_fn, parallel_threshold=parallel_threshold
+    )
 
-@functools.singledispatch
-def complement(graph):
-    """Compute the complement of a graph.
 
-    :param graph: The graph to be used, can be either a
-        :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`.
+@harmonic_centrality.register(PyGraph)
+def _graph_harmonic_centrality(graph, weight_fn=None, parallel_threshold=50):
+    return graph_harmonic_centrality(
+        graph, weight_fn=weight_fn, parallel_threshold=parallel_threshold
+    )
 
-    :returns: The complement of the graph.
-    :rtype: :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`
 
-    .. note::
-        Parallel edges and self-loops are never created,
-        even if the ``multigraph`` is set to ``True``
+@functools.singledispatch
+def closeness_vitality(graph, weight_fn=None):
+    r"""Compute the closeness vitality of all nodes in a graph.
+
+    The closeness vitality of a node is the decrease of the Wiener index of
+    the graph, the sum of the shortest path distances between all pairs of
+    nodes, when the node is removed [BE]_. For a :class:`~retworkx.PyGraph`
+    every pair of nodes is counted once and for a
+    :class:`~retworkx.PyDiGraph` every ordered pair is counted. If the graph
+    isn't (strongly) connected its Wiener index is infinite and the closeness
+    vitality of every node is ``nan``, otherwise the closeness vitality of a
+    node whose removal disconnects the graph is ``-inf``.
+
+    Removing a node only changes the distances from a source node if the
+    removed node is the only predecessor of another node on the shortest
+    paths from that source, so the shortest paths are only computed again
+    for those sources.
+
+    :param graph: The graph to compute the closeness vitality of. This can
+        be a :class:`~retworkx.PyGraph` or a :class:`~retworkx.PyDiGraph`.
+    :param weight_fn: An optional input callable that will be passed the edge's
+        payload object and is expected to return a non-negative ``float``
+        weight for that edge. If this is specified the distances are the
+        weighted shortest path lengths found with Dijkstra's algorithm,
+        otherwise they're the number of edges on the shortest paths.
+
+    :returns: a read-only dict-like object whose keys are the node indices and
+        values are the closeness vitality of each node.
+    :rtype: CentralityMapping
+
+    .. [BE] Ulrik Brandes and Thomas Erlebach (Eds.), "Network Analysis:
+        Methodological Foundations." Springer, 2005, pp. 36-37.
     """
-    raise TypeError("Invalid Input Type %s for graph" % type(graph))
+    raise TypeError("Invalid input type %s for graph" % type(graph))
 
 
-@complement.register(PyDiGraph)
-def _digraph_complement(graph):
-    return digraph_complement(graph)
+@closeness_vitality.register(PyDiGraph)
+def _digraph_closeness_vitality(graph, weight_fn=None):
+    return digraph_closeness_vitality(graph, weight_fn=weight_fn)
 
 
-@complement.register(PyGraph)
-def _graph_complement(graph):
-    return graph_complement(graph)
+@closeness_vitality.register(PyGraph)
+def _graph_closeness_vitality(graph, weight_fn=None):
+    return graph_closeness_vitality(graph, weight_fn=weight_fn)
 
 
 @functools.singledispatch
-def random_layout(graph, center=None, seed=None):
-    """Generate a random layout
-
-    :param PyGraph graph: The graph to generate the layout for
-    :param tuple center: An optional center position. This is a 2 tuple of two
-        ``float`` values for the center position
-    :param int seed: An optional seed to set for the random number generator.
-
-    :returns: The random layout of the graph.
-    :rtype: Pos2DMapping
+def top_k_degree_centrality(graph, k):
+    """Find the ``k`` nodes with the highest degree centrality in a graph.
+
+    The degree centrality of a node is its degree divided by :math:`N - 1`,
+    where :math:`N` is the number of nodes in the graph. For a
+    :class:`~retworkx.PyDiGraph` the degree is the sum of the in degree and
+    the out degree. Only the ``k`` best nodes are kept while the degrees are
+    counted, so no array of the centrality of every node is built.
+
+    :param graph: The graph to find the most central nodes of. This can be a
+        :class:`~retworkx.PyGraph` or a :class:`~retworkx.PyDiGraph`.
+    :param int k: The number of nodes to return
+
+    :returns: A list of ``(node, centrality)`` tuples for the ``k`` nodes
+        (or every node if there are fewer) with the highest centrality,
+        sorted by decreasing centrality. Ties are broken by node index, the
+        lower index first.
+    :rtype: list
     """
-    raise TypeError("Invalid Input Type %s for graph" % type(graph))
+    raise TypeError("Invalid input type %s for graph" % type(graph))
 
 
-@random_layout.register(PyDiGraph)
-def _digraph_random_layout(graph, center=None, seed=None):
-    return digraph_random_layout(graph, center=center, seed=seed)
+@top_k_degree_centrality.register(PyDiGraph)
+def _digraph_top_k_degree_centrality(graph, k):
+    return digraph_top_k_degree_centrality(graph, k)
 
 
-@random_layout.register(PyGraph)
-def _graph_random_layout(graph, center=None, seed=None):
-    return graph_random_layout(graph, center=center, seed=seed)
+@top_k_degree_centrality.register(PyGraph)
+def _graph_top_k_degree_centrality(graph, k):
+    return graph_top_k_degree_centrality(graph, k)
 
 
 @functools.singledispatch
-def spring_layout(
-    graph,
-    pos=None,
-    fixed=None,
-    k=None,
-    repulsive_exponent=2,
-    adaptive_cooling=True,
-    num_iter=50,
-    tol=1e-6,
-    weight_fn=None,
-    default_weight=1,
-    scale=1,
-    center=None,
-    seed=None,
-):
-    """
-    Position nodes using Fruchterman-Reingold force-directed algorithm.
-
-    The algorithm simulates a force-directed representation of the network
-    treating edges as springs holding nodes close, while treating nodes
-    as repelling objects, sometimes called an anti-gravity force.
-    Simulation continues until the positions are close to an equilibrium.
+def top_k_closeness_centrality(graph, k, wf_improved=True):
+    """Find the ``k`` nodes with the highest closeness centrality in a graph.
 
-    :param graph: Graph to be used. Can either be a
-        :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`.
-    :param dict pos:
-        Initial node positions as a dictionary with node ids as keys and values
-        as a coordinate list. If ``None``, then use random initial positions.
-        (``default=None``)
-    :param set fixed: Nodes to keep fixed at initial position.
-        Error raised if fixed specified and ``pos`` is not. (``default=None``)
-    :param float  k:
-        Optimal distance between nodes. If ``None`` the distance is set to
-        :math:`\\frac{1}{\\sqrt{n}}` where :math:`n` is the number of nodes.
-        Increase this value to move nodes farther apart. (``default=None``)
-    :param int repulsive_exponent:
-        Repulsive force exponent. (``default=2``)
-    :param bool adaptive_cooling:
-        Use an adaptive cooling scheme. If set to ``False``,
-        a linear cooling scheme is used. (``default=True``)
-    :param int num_iter:
-        Maximum number of iterations. (``default=50``)
-    :param float tol:
-        Threshold for relative error in node position changes.
-        The iteration stops if the error is below this threshold.
-        (``default = 1e-6``)
-    :param weight_fn: An optional weight function for an edge. It will accept
-        a single argument, the edge's weight object and will return a float
-        which will be used to represent the weight of the edge.
-    :param float (default=1) default_weight: If ``weight_fn`` isn't specified
-        this optional float value will be used for the weight/cost of each edge
-    :param float|None scale: Scale factor for positions.
-        Not used unless fixed is None. If scale is ``None``, no re-scaling is
-        performed. (``default=1.0``)
-    :param list center: Coordinate pair around which to center
-        the layout. Not used unless fixed is ``None``. (``default=None``)
-    :param int seed: An optional seed to use for the random number generator
+    This computes the same centrality as :func:`~retworkx.closeness_centrality`
+    without edge weights, but the breadth first search from every node stops
+    as soon as the node can't be one of the ``k`` best nodes [BBCMM]_. On
+    large graphs with a small diameter most searches stop after a few levels,
+    which is much faster than computing the closeness of every node.
 
-    :returns: A dictionary of positions keyed by node id.
-    :rtype: dict
-    """
-    raise TypeError("Invalid Input Type %s for graph" % type(graph))
+    .. jupyter-execute::
 
+        import retworkx
 
-@spring_layout.register(PyDiGraph)
-def _digraph_spring_layout(
-    graph,
-    pos=None,
-    fixed=None,
-    k=None,
-    repulsive_exponent=2,
-    adaptive_cooling=True,
-    num_iter=50,
-    tol=1e-6,
-    weight_fn=None,
-    default_weight=1,
-    scale=1,
-    center=None,
-    seed=None,
-):
-    return digraph_spring_layout(
-        graph,
-        pos,
-        fixed,
-        k,
-        repulsive_exponent,
-        adaptive_cooling,
-        num_iter,
-        tol,
-        weight_fn,
-        default_weight,
-        scale,
-        center,
-        seed,
-    )
+        graph = retworkx.generators.grid_graph(30, 30)
+        print(retworkx.top_k_closeness_centrality(graph, 4))
 
+    :param graph: The graph to find the most central nodes of. This can be a
+        :class:`~retworkx.PyGraph` or a :class:`~retworkx.PyDiGraph`.
+    :param int k: The number of nodes to return
+    :param bool wf_improved: Whether to use the Wasserman and Faust improved
+        formula
 
-@spring_layout.register(PyGraph)
-def _graph_spring_layout(
-    graph,
-    pos=None,
-    fixed=None,
-    k=None,
-    repulsive_exponent=2,
-    adaptive_cooling=True,
-    num_iter=50,
-    tol=1e-6,
-    weight_fn=None,
-    default_weight=1,
-    scale=1,
-    center=None,
-    seed=None,
-):
-    return graph_spring_layout(
-        graph,
-        pos,
-        fixed,
-        k,
-        repulsive_exponent,
-        adaptive_cooling,
-        num_iter,
-        tol,
-        weight_fn,
-        default_weight,
-        scale,
-        center,
-        seed,
-    )
+    :returns: A list of ``(node, centrality)`` tuples for the ``k`` nodes
+        (or every node if there are fewer) with the highest centrality,
+        sorted by decreasing centrality. Nodes tied with the ``k``-th node
+        may be returned in any order of node index.
+    :rtype: list
 
+    .. [BBCMM] Elisabetta Bergamini, Michele Borassi, Pierluigi Crescenzi,
+        Andrea Marino and Henning Meyerhenke, "Computing top-k closeness
+        centrality faster in unweighted graphs." ACM Transactions on
+        Knowledge Discovery from Data 13(5):1-40, 2019.
+    """
+    raise TypeError("Invalid input type %s for graph" % type(graph))
 
-def networkx_converter(graph, keep_attributes: bool = False):
-    """Convert a networkx graph object into a retworkx graph object.
 
-    .. note::
+@top_k_closeness_centrality.register(PyDiGraph)
+def _digraph_top_k_closeness_centrality(graph, k, wf_improved=True):
+    return digraph_top_k_closeness_centrality(graph, k, wf_improved=wf_improved)
 
-        networkx is **not** a dependency of retworkx and this function
-        is provided as a convenience method for users of both networkx and
-        retworkx. This function will not work unless you install networkx
-        independently.
 
-    :param networkx.Graph graph: The networkx graph to convert.
-    :param bool keep_attributes: If ``True``, add networkx node attributes to
-        the data payload in the nodes of the output retworkx graph. When set to
-        ``True``, the node data payloads in the output retworkx graph object
-        will be dictionaries with the node attributes from the input networkx
-        graph where the ``"__networkx_node__"`` key contains the node from the
-        input networkx graph.
+@top_k_closeness_centrality.register(PyGraph)
+def _graph_top_k_closeness_centrality(graph, k, wf_improved=True):
+    return graph_top_k_closeness_centrality(graph, k, wf_improved=wf_improved)
 
-    :returns: A retworkx graph, either a :class:`~retworkx.PyDiGraph` or a
-        :class:`~retworkx.PyGraph` based on whether the input graph is directed
-        or not.
-    :rtype: :class:`~retworkx.PyDiGraph` or :class:`~retworkx.PyGraph`
+
+@functools.singledispatch
+def top_k_betweenness_centrality(graph, k, normalized=True, epsilon=0.01, delta=0.1, seed=None):
+    r"""Find the ``k`` nodes with the highest approximate betweenness
+    centrality in a graph.
+
+    The betweenness centrality of every node is estimated from a sample of
+    shortest paths [RK]_, with a number of samples that grows only
+    logarithmically with the number of nodes :math:`N`:
+
+    .. math::
+
+        \left\lceil \frac{\ln(2N / \delta)}{2 \epsilon^2} \right\rceil
+
+    With probability at least ``1 - delta`` the estimated betweenness of
+    every node divided by the number of ordered pairs of nodes,
+    :math:`N (N - 1)`, is within ``epsilon`` of its exact value. The
+    estimates are scaled like the output of
+    :func:`~retworkx.betweenness_centrality` with ``endpoints=False``.
+
+    :param graph: The graph to find the most central nodes of. This can be a
+        :class:`~retworkx.PyGraph` or a :class:`~retworkx.PyDiGraph`.
+    :param int k: The number of nodes to return
+    :param bool normalized: Whether to normalize the betweenness scores by
+        the number of distinct paths between all pairs of nodes. Defaults to
+        ``True``.
+    :param float epsilon: The maximum error of the estimates, as a fraction
+        of the number of ordered pairs of nodes. Defaults to 0.01.
+    :param float delta: The probability that an estimate has a larger error.
+        Defaults to 0.1.
+    :param int seed: An optional seed to use for the random number generator
+
+    :returns: A list of ``(node, centrality)`` tuples for the ``k`` nodes
+        (or every node if there are fewer) with the highest estimated
+        centrality, sorted by decreasing centrality. Ties are broken by node
+        index, the lower index first.
+    :rtype: list
+    :raises ValueError: If ``epsilon`` or ``delta`` isn't between 0 and 1
+
+    .. [RK] Matteo Riondato and Evgenios M. Kornaropoulos, "Fast
+        approximation of betweenness centrality through sampling." Data
+        Mining and Knowledge Discovery 30(2):438-475, 2016.
     """
-    if graph.is_directed():
-        new_graph = PyDiGraph(multigraph=graph.is_multigraph())
-    else:
-        new_graph = PyGraph(multigraph=graph.is_multigraph())
-    nodes = list(graph.nodes)
-    node_indices = dict(zip(nodes, new_graph.add_nodes_from(nodes)))
-    new_graph.add_edges_from(
-        [(node_indices[x[0]], node_indices[x[1]], x[2]) for x in graph.edges(data=True)]
+    raise TypeError("Invalid input type %s for graph" % type(graph))
+
+
+@top_k_betweenness_centrality.register(PyDiGraph)
+def _digraph_top_k_betweenness_centrality(
+    graph, k, normalized=True, epsilon=0.01, delta=0.1, seed=None
+):
+    return digraph_top_k_betweenness_centrality(
+        graph, k, normalized=normalized, epsilon=epsilon, delta=delta, seed=seed
     )
 
-    if keep_attributes:
-        for node, node_index in node_indices.items():
-            attributes = graph.nodes[node]
-            attributes["__networkx_node__"] = node
-            new_graph[node_index] = attributes
 
-    return new_graph
+@top_k_betweenness_centrality.register(PyGraph)
+def _graph_top_k_betweenness_centrality(
+    graph, k, normalized=True, epsilon=0.01, delta=0.1, seed=None
+):
+    return graph_top_k_betweenness_centrality(
+        graph, k, normalized=normalized, epsilon=epsilon, delta=delta, seed=seed
+    )
 
 
 @functools.singledispatch
-def bipartite_layout(
+def pagerank(
     graph,
-    first_nodes,
-    horizontal=False,
-    scale=1,
-    center=None,
-    aspect_ratio=4 / 3,
+    alpha=0.85,
+    weight_fn=None,
+    default_weight=1.0,
+    personalization=None,
+    nstart=None,
+    dangling=None,
+    max_iter=100,
+    tol=1e-6,
+    explain=False,
 ):
-    """Generate a bipartite layout of the graph
+    """Compute the PageRank of the nodes in a graph.
+
+    PageRank computes a ranking of the nodes in the graph based on the
+    structure of the incoming links. It models a random walk that follows an
+    outgoing edge, chosen with a probability proportional to the edge
+    weights, with probability ``alpha`` and otherwise teleports to a node
+    drawn from the ``personalization`` distribution. Edges of a
+    :class:`~retworkx.PyGraph` can be followed in both directions. Dangling
+    nodes, the nodes without outgoing edges (or whose outgoing edges all have
+    a weight of 0), teleport to a node drawn from the ``dangling``
+    distribution. The ranks are computed with power iteration, the algorithm
+    is based on [LM]_.
 
-    :param graph: The graph to generate the layout for. Can either be a
-        :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`
-    :param set first_nodes: The set of node indices on the left (or top if
-        horitontal is true)
-    :param bool horizontal: An optional bool specifying the orientation of the
-        layout
-    :param float scale: An optional scaling factor to scale positions
-    :param tuple center: An optional center position. This is a 2 tuple of two
-        ``float`` values for the center position
-    :param float aspect_ratio: An optional number for the ratio of the width to
-        the height of the layout.
+    .. jupyter-execute::
 
-    :returns: The bipartite layout of the graph.
-    :rtype: Pos2DMapping
+        import retworkx
+
+        graph = retworkx.PyDiGraph()
+        graph.extend_from_edge_list([(0, 1), (1, 2), (2, 0), (3, 2)])
+        ranks = retworkx.pagerank(graph)
+        print({node: round(rank, 4) for node, rank in ranks.items()})
+
+    :param graph: The graph to compute the PageRank of. This can be a
+        :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`
+    :param float alpha: The damping factor, it must be between 0 and 1
+    :param weight_fn: An optional input callable that will be passed the edge's
+        payload object and is expected to return a non-negative ``float``
+        weight for that edge. If this is not specified ``default_weight`` will
+        be used as the weight for every edge in ``graph``. The weights of
+        parallel edges are added.
+    :param float default_weight: If ``weight_fn`` is not set the default weight
+        value to use for the weight of all edges
+    :param dict personalization: An optional dictionary of node indices to the
+        (unnormalized) probability of teleporting to that node. Nodes not in the
+        dictionary have a probability of 0. If not specified a uniform
+        distribution is used.
+    :param dict nstart: An optional dictionary of node indices to the starting
+        value of the iteration for that node. If not specified a uniform
+        distribution is used.
+    :param dict dangling: An optional dictionary of node indices to the
+        (unnormalized) probability of jumping to that node from a dangling
+        node. If not specified the ``personalization`` distribution is used.
+    :param int max_iter: The maximum number of iterations of the power method
+    :param float tol: The error tolerance used when checking for convergence,
+        the iteration stops once the change of the ranks sums to less than the
+        number of nodes times ``tol``.
+    :param bool explain: If set to ``True`` an
+        :class:`~retworkx.AlgorithmInfo` recording how the algorithm was run
+        is returned along with the result
+
+    :returns: a read-only dict-like object whose keys are the node indices and
+        values are the PageRank of each node.
+        If ``explain`` is ``True`` a tuple of this and an
+        :class:`~retworkx.AlgorithmInfo` is returned instead.
+    :rtype: CentralityMapping
+    :raises FailedToConverge: If the power iteration doesn't converge within
+        ``max_iter`` iterations
+
+    .. [LM] A. Langville and C. Meyer, "A survey of eigenvector methods of web
+        information retrieval." http://citeseer.ist.psu.edu/713792.html
     """
-    raise TypeError("Invalid Input Type %s for graph" % type(graph))
+    raise TypeError("Invalid input type %s for graph" % type(graph))
 
 
-@bipartite_layout.register(PyDiGraph)
-def _digraph_bipartite_layout(
+@pagerank.register(PyDiGraph)
+def _digraph_pagerank(
     graph,
-    first_nodes,
-    horizontal=False,
-    scale=1,
-    center=None,
-    aspect_ratio=4 / 3,
+    alpha=0.85,
+    weight_fn=None,
+    default_weight=1.0,
+    personalization=None,
+    nstart=None,
+    dangling=None,
+    max_iter=100,
+    tol=1e-6,
+    explain=False,
 ):
-    return digraph_bipartite_layout(
+    return digraph_pagerank(
         graph,
-        first_nodes,
-        horizontal=horizontal,
-        scale=scale,
-        center=center,
-        aspect_ratio=aspect_ratio,
+        alpha=alpha,
+        weight_fn=weight_fn,
+        default_weight=default_weight,
+        personalization=personalization,
+        nstart=nstart,
+        dangling=dangling,
+        max_iter=max_iter,
+        tol=tol,
+        explain=explain,
     )
 
 
-@bipartite_layout.register(PyGraph)
-def _graph_bipartite_layout(
+@pagerank.register(PyGraph)
+def _graph_pagerank(
     graph,
-    first_nodes,
-    horizontal=False,
-    scale=1,
-    center=None,
-    aspect_ratio=4 / 3,
+    alpha=0.85,
+    weight_fn=None,
+    default_weight=1.0,
+    personalization=None,
+    nstart=None,
+    dangling=None,
+    max_iter=100,
+    tol=1e-6,
+    explain=False,
 ):
-    return graph_bipartite_layout(
+    return graph_pagerank(
         graph,
-        first_nodes,
-        horizontal=horizontal,
-        scale=scale,
-        center=center,
-        aspect_ratio=aspect_ratio,
+        alpha=alpha,
+        weight_fn=weight_fn,
+        default_weight=default_weight,
+        personalization=personalization,
+        nstart=nstart,
+        dangling=dangling,
+        max_iter=max_iter,
+        tol=tol,
+        explain=explain,
     )
 
 
 @functools.singledispatch
-def circular_layout(graph, scale=1, center=None):
-    """Generate a circular layout of the graph
+def hits(
+    graph,
+    weight_fn=None,
+    default_weight=1.0,
+    nstart=None,
+    max_iter=100,
+    tol=1e-8,
+    normalized=True,
+    explain=False,
+):
+    """Compute the HITS hubs and authorities scores of the nodes in a graph.
 
-    :param graph: The graph to generate the layout for. Can either be a
-        :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`
-    :param float scale: An optional scaling factor to scale positions
-    :param tuple center: An optional center position. This is a 2 tuple of two
-        ``float`` values for the center position
+    The HITS algorithm computes two scores for every node: the authority
+    score estimates the value of the content of a node based on its incoming
+    links and the hub score estimates the value of its outgoing links. A good
+    hub links to many good authorities and a good authority is linked from
+    many good hubs. For a :class:`~retworkx.PyGraph` every edge is both an
+    incoming and an outgoing link. The scores are computed with power
+    iteration, the algorithm is based on [Kleinberg]_.
 
-    :returns: The circular layout of the graph.
-    :rtype: Pos2DMapping
+    .. jupyter-execute::
+
+        import retworkx
+
+        graph = retworkx.PyDiGraph()
+        graph.extend_from_edge_list([(0, 2), (1, 2), (1, 3), (3, 2)])
+        hubs, authorities = retworkx.hits(graph)
+        print({node: round(score, 4) for node, score in hubs.items()})
+        print({node: round(score, 4) for node, score in authorities.items()})
+
+    :param graph: The graph to compute the scores of. This can be a
+        :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`
+    :param weight_fn: An optional input callable that will be passed the edge's
+        payload object and is expected to return a non-negative ``float``
+        weight for that edge. If this is not specified ``default_weight`` will
+        be used as the weight for every edge in ``graph``. The weights of
+        parallel edges are added.
+    :param float default_weight: If ``weight_fn`` is not set the default weight
+        value to use for the weight of all edges
+    :param dict nstart: An optional dictionary of node indices to the starting
+        hub score of that node. If not specified a uniform distribution is
+        used.
+    :param int max_iter: The maximum number of iterations of the power method
+    :param float tol: The error tolerance used when checking for convergence,
+        the iteration stops once the change of the hub scores sums to less than
+        ``tol``.
+    :param bool normalized: If ``True`` the scores are normalized to sum to 1,
+        otherwise they're normalized so that the largest score is 1.
+    :param bool explain: If set to ``True`` an
+        :class:`~retworkx.AlgorithmInfo` recording how the algorithm was run
+        is returned along with the result
+
+    :returns: A tuple of two read-only dict-like objects whose keys are the
+        node indices and values are the hub and the authority scores of each
+        node respectively.
+        If ``explain`` is ``True`` a tuple of this and an
+        :class:`~retworkx.AlgorithmInfo` is returned instead.
+    :rtype: tuple
+    :raises FailedToConverge: If the power iteration doesn't converge within
+        ``max_iter`` iterations
+
+    .. [Kleinberg] Jon Kleinberg, "Authoritative sources in a hyperlinked
+        environment." Journal of the ACM 46 (5): 604-32, 1999.
+        doi:10.1145/324133.324140.
     """
-    raise TypeError("Invalid Input Type %s for graph" % type(graph))
+    raise TypeError("Invalid input type %s for graph" % type(graph))
 
 
-@circular_layout.register(PyDiGraph)
-def _digraph_circular_layout(graph, scale=1, center=None):
-    return digraph_circular_layout(graph, scale=scale, center=center)
+@hits.register(PyDiGraph)
+def _digraph_hits(
+    graph,
+    weight_fn=None,
+    default_weight=1.0,
+    nstart=None,
+    max_iter=100,
+    tol=1e-8,
+    normalized=True,
+    explain=False,
+):
+    return digraph_hits(
+        graph,
+        weight_fn=weight_fn,
+        default_weight=default_weight,
+        nstart=nstart,
+        max_iter=max_iter,
+        tol=tol,
+        normalized=normalized,
+        explain=explain,
+    )
 
 
-@circular_layout.register(PyGraph)
-def _graph_circular_layout(graph, scale=1, center=None):
-    return graph_circular_layout(graph, scale=scale, center=center)
+@hits.register(PyGraph)
+def _graph_hits(
+    graph,
+    weight_fn=None,
+    default_weight=1.0,
+    nstart=None,
+    max_iter=100,
+    tol=1e-8,
+    normalized=True,
+    explain=False,
+):
+    return graph_hits(
+        graph,
+        weight_fn=weight_fn,
+        default_weight=default_weight,
+        nstart=nstart,
+        max_iter=max_iter,
+        tol=tol,
+        normalized=normalized,
+        explain=explain,
+    )
 
 
 @functools.singledispatch
-def shell_layout(graph, nlist=None, rotate=None, scale=1, center=None):
-    """
-    Generate a shell layout of the graph
+def forman_curvature(graph, weight_fn=None, default_weight=1.0):
+    r"""Compute the Forman-Ricci curvature of every edge in a graph.
 
-    :param graph: The graph to generate the layout for. Can either be a
-        :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`
-    :param list nlist: The list of lists of indices which represents each shell
-    :param float rotate: Angle (in radians) by which to rotate the starting
-        position of each shell relative to the starting position of the
-        previous shell
-    :param float scale: An optional scaling factor to scale positions
-    :param tuple center: An optional center position. This is a 2 tuple of two
-        ``float`` values for the center position
+    The Forman-Ricci curvature is a combinatorial notion of curvature that
+    only depends on the edges adjacent to an edge, which makes it cheap to
+    compute. With every node having a weight of 1 the curvature of an edge
+    :math:`e = (u, v)` with weight :math:`w_e` is
 
-    :returns: The shell layout of the graph.
-    :rtype: Pos2DMapping
-    """
-    raise TypeError("Invalid Input Type %s for graph" % type(graph))
+    .. math::
 
+        F(e) = 2 - \sum_{e_u \sim u, e_u \neq e} \sqrt{\frac{w_e}{w_{e_u}}}
+            - \sum_{e_v \sim v, e_v \neq e} \sqrt{\frac{w_e}{w_{e_v}}}
 
-@shell_layout.register(PyDiGraph)
-def _digraph_shell_layout(graph, nlist=None, rotate=None, scale=1, center=None):
-    return digraph_shell_layout(graph, nlist=nlist, rotate=rotate, scale=scale, center=center)
+    so for an unweighted graph it is :math:`4 - deg(u) - deg(v)`. For a
+    :class:`~retworkx.PyDiGraph` only the incoming edges of :math:`u` and the
+    outgoing edges of :math:`v` are considered. Negatively curved edges act as
+    bridges between parts of the graph while positively curved edges are
+    within tightly connected parts of the graph. The algorithm is based on
+    [Forman]_.
 
+    :param graph: The graph to compute the curvature of. This can be a
+        :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`
+    :param weight_fn: An optional input callable that will be passed the edge's
+        payload object and is expected to return a positive ``float`` weight
+        for that edge. If this is not specified ``default_weight`` will be used
+        as the weight for every edge in ``graph``.
+    :param float default_weight: If ``weight_fn`` is not set the default weight
+        value to use for the weight of all edges
+
+    :returns: a read-only dict-like object whose keys are the edge indices and
+        values are the curvature of each edge. Self loops are not included.
+    :rtype: EdgeCurvatureMapping
+
+    .. [Forman] Sreejith, R. P., Karthikeyan Mohanraj, Jürgen Jost, Emil
+        Saucan, and Areejit Samal. "Forman curvature for complex networks."
+        Journal of Statistical Mechanics: Theory and Experiment 2016, no. 6
+        (2016): 063206.
+    """
+    raise TypeError("Invalid input type %s for graph" % type(graph))
 
-@shell_layout.register(PyGraph)
-def _graph_shell_layout(graph, nlist=None, rotate=None, scale=1, center=None):
-    return graph_shell_layout(graph, nlist=nlist, rotate=rotate, scale=scale, center=center)
 
+@forman_curvature.register(PyDiGraph)
+def _digraph_forman_curvature(graph, weight_fn=None, default_weight=1.0):
+    return digraph_forman_curvature(graph, weight_fn=weight_fn, default_weight=default_weight)
 
-@functools.singledispatch
-def spiral_layout(graph, scale=1, center=None, resolution=0.35, equidistant=False):
-    """
-    Generate a spiral layout of the graph
 
-    :param graph: The graph to generate the layout for. Can either be a
-        :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`
-    :param float scale: An optional scaling factor to scale positions
-    :param tuple center: An optional center position. This is a 2 tuple of two
-        ``float`` values for the center position
-    :param float resolution: The compactness of the spiral layout returned.
-        Lower values result in more compressed spiral layouts.
-    :param bool equidistant: If true, nodes will be plotted equidistant from
-        each other.
+@forman_curvature.register(PyGraph)
+def _graph_forman_curvature(graph, weight_fn=None, default_weight=1.0):
+    return graph_forman_curvature(graph, weight_fn=weight_fn, default_weight=default_weight)
 
-    :returns: The spiral layout of the graph.
-    :rtype: Pos2DMapping
-    """
-    raise TypeError("Invalid Input Type %s for graph" % type(graph))
 
+@functools.singledispatch
+def ollivier_ricci_curvature(
+    graph, weight_fn=None, default_weight=1.0, alpha=0.5, reg=0.01, max_iter=1000, tol=1e-9
+):
+    r"""Compute the Ollivier-Ricci curvature of every edge in a graph.
 
-@spiral_layout.register(PyDiGraph)
-def _digraph_spiral_layout(graph, scale=1, center=None, resolution=0.35, equidistant=False):
-    return digraph_spiral_layout(
-        graph,
-        scale=scale,
-        center=center,
-        resolution=resolution,
-        equidistant=equidistant,
-    )
+    The Ollivier-Ricci curvature of an edge :math:`(u, v)` compares the
+    distance between :math:`u` and :math:`v` with the distance between their
+    neighborhoods:
 
+    .. math::
 
-@spiral_layout.register(PyGraph)
-def _graph_spiral_layout(graph, scale=1, center=None, resolution=0.35, equidistant=False):
-    return graph_spiral_layout(
-        graph,
-        scale=scale,
-        center=center,
-        resolution=resolution,
-        equidistant=equidistant,
-    )
+        \kappa(u, v) = 1 - \frac{W(m_u, m_v)}{d(u, v)}
 
+    where :math:`d` is the shortest path distance, :math:`W` is the
+    Wasserstein (earth mover's) distance and :math:`m_x` is the probability
+    distribution that puts a mass of ``alpha`` on :math:`x` and spreads the
+    remaining mass uniformly over the neighbors of :math:`x`. The direction of
+    the edges of a :class:`~retworkx.PyDiGraph` is ignored. The Wasserstein
+    distance is approximated with the Sinkhorn algorithm for entropy
+    regularized optimal transport, which converges to the exact distance as
+    ``reg`` goes to 0. The algorithm is based on [LLY]_ and [Sinkhorn]_.
 
-@functools.singledispatch
-def num_shortest_paths_unweighted(graph, source):
-    """Get the number of unweighted shortest paths from a source node
+    .. jupyter-execute::
 
-    :param PyDiGraph graph: The graph to find the number of shortest paths on
-    :param int source: The source node to find the shortest paths from
+        import retworkx
 
-    :returns: A mapping of target node indices to the number of shortest paths
-        from ``source`` to that node. If there is no path from ``source`` to
-        a node in the graph that node will not be preset in the output mapping.
-    :rtype: NodesCountMapping
+        # Two triangles joined by a bridge
+        graph = retworkx.PyGraph()
+        graph.extend_from_edge_list([(0, 1), (1, 2), (2, 0), (2, 3), (3, 4), (4, 5), (5, 3)])
+        curvatures = retworkx.ollivier_ricci_curvature(graph)
+        print({edge: round(curvature, 3) for edge, curvature in curvatures.items()})
+
+    :param graph: The graph to compute the curvature of. This can be a
+        :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`
+    :param weight_fn: An optional input callable that will be passed the edge's
+        payload object and is expected to return a non-negative ``float``
+        length for that edge. If this is not specified ``default_weight`` will
+        be used as the length of every edge in ``graph``.
+    :param float default_weight: If ``weight_fn`` is not set the default weight
+        value to use for the length of all edges
+    :param float alpha: The probability mass of the distributions kept on the
+        node itself, it must be between 0 and 1
+    :param float reg: The entropic regularization of the Sinkhorn algorithm,
+        smaller values give a better approximation but require more iterations
+    :param int max_iter: The maximum number of Sinkhorn iterations for every
+        edge
+    :param float tol: The Sinkhorn iterations for an edge stop once the
+        marginals of the transport plan differ from the distributions by less
+        than ``tol``
+
+    :returns: a read-only dict-like object whose keys are the edge indices and
+        values are the curvature of each edge. Self loops and edges with a
+        length of 0 are not included.
+    :rtype: EdgeCurvatureMapping
+
+    .. [LLY] Lin, Yong, Linyuan Lu, and Shing-Tung Yau. "Ricci curvature of
+        graphs." Tohoku Mathematical Journal 63, no. 4 (2011): 605-627.
+    .. [Sinkhorn] Cuturi, Marco. "Sinkhorn distances: Lightspeed computation
+        of optimal transport." Advances in Neural Information Processing
+        Systems 26 (2013).
     """
     raise TypeError("Invalid input type %s for graph" % type(graph))
 
 
-@num_shortest_paths_unweighted.register(PyDiGraph)
-def _digraph_num_shortest_paths_unweighted(graph, source):
-    return digraph_num_shortest_paths_unweighted(graph, source)
+@ollivier_ricci_curvature.register(PyDiGraph)
+def _digraph_ollivier_ricci_curvature(
+    graph, weight_fn=None, default_weight=1.0, alpha=0.5, reg=0.01, max_iter=1000, tol=1e-9
+):
+    return digraph_ollivier_ricci_curvature(
+        graph,
+        weight_fn=weight_fn,
+        default_weight=default_weight,
+        alpha=alpha,
+        reg=reg,
+        max_iter=max_iter,
+        tol=tol,
+    )
+
+
+@ollivier_ricci_curvature.register(PyGraph)
+def _graph_ollivier_ricci_curvature(
+    graph, weight_fn=None, default_weight=1.0, alpha=0.5, reg=0.01, max_iter=1000, tol=1e-9
+):
+    return graph_ollivier_ricci_curvature(
+        graph,
+        weight_fn=weight_fn,
+        default_weight=default_weight,
+        alpha=alpha,
+        reg=reg,
+        max_iter=max_iter,
+        tol=tol,
+    )
 
 
-@num_shortest_paths_unweighted.register(PyGraph)
-def _graph_num_shortest_paths_unweighted(graph, source):
-    return graph_num_shortest_paths_unweighted(graph, source)
+@functools.singledispatch
+def canonical_certificate(graph):
+    """Compute a canonical certificate of a graph
 
+    The certificate is a tuple of integers encoding the graph relabeled in a
+    canonical order, found with an individualization-refinement search in the
+    style of nauty. Two graphs are isomorphic if and only if their
+    certificates are equal, and since the certificate is hashable it can be
+    used to deduplicate a large collection of graphs without running
+    pairwise isomorphism checks::
 
-@functools.singledispatch
-def betweenness_centrality(graph, normalized=True, endpoints=False, parallel_threshold=50):
-    r"""Returns the betweenness centrality of each node in the graph.
+        unique = {retworkx.canonical_certificate(g): g for g in graphs}
 
-    Betweenness centrality of a node :math:`v` is the sum of the
-    fraction of all-pairs shortest paths that pass through :math`v`
+    Only the structure of the graph is considered, node and edge weights are
+    ignored. Parallel edges and self loops are taken into account. The
+    certificate of a :class:`~retworkx.PyDiGraph` is never equal to the
+    certificate of a :class:`~retworkx.PyGraph`.
 
-    .. math::
+    :param graph: The graph to compute the certificate of. Can either be a
+        :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`
 
-       c_B(v) =\sum_{s,t \in V} \frac{\sigma(s, t|v)}{\sigma(s, t)}
+    :returns: The canonical certificate of the graph
+    :rtype: tuple
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(graph))
 
-    where :math:`V` is the set of nodes, :math:`\sigma(s, t)` is the number of
-    shortest :math`(s, t)` paths, and :math:`\sigma(s, t|v)` is the number of
-    those paths  passing through some  node :math:`v` other than :math:`s, t`.
-    If :math:`s = t`, :math:`\sigma(s, t) = 1`, and if :math:`v \in {s, t}`,
-    :math:`\sigma(s, t|v) = 0`
 
-    The algorithm used in this function is based on:
+@canonical_certificate.register(PyDiGraph)
+def _digraph_canonical_certificate(graph):
+    return digraph_canonical_certificate(graph)
 
-    Ulrik Brandes, A Faster Algorithm for Betweenness Centrality.
-    Journal of Mathematical Sociology 25(2):163-177, 2001.
 
-    This function is multithreaded and will run in parallel if the number
-    of nodes in the graph is above the value of ``parallel_threshold`` (it
-    defaults to 50). If the function will be running in parallel the env var
-    ``RAYON_NUM_THREADS`` can be used to adjust how many threads will be used.
+@canonical_certificate.register(PyGraph)
+def _graph_canonical_certificate(graph):
+    return graph_canonical_certificate(graph)
 
-    :param PyDiGraph graph: The input graph
-    :param bool normalized: Whether to normalize the betweenness scores by
-        the number of distinct paths between all pairs of nodes.
-    :param bool endpoints: Whether to include the endpoints of paths in
-        path lengths used to compute the betweenness.
-    :param int parallel_threshold: The number of nodes to calculate the
-        the betweenness centrality in parallel at if the number of nodes in
-        the graph is less than this value it will run in a single thread. The
-        default value is 50
 
-    :returns: A dictionary mapping each node index to its betweenness centrality.
-    :rtype: dict
+@functools.singledispatch
+def weisfeiler_lehman_graph_hash(graph, node_attr_fn=None, edge_attr_fn=None, iterations=3):
+    """Compute the Weisfeiler-Lehman graph hash of a graph
+
+    The hash is built by iteratively relabeling every node with a hash of its
+    own label and the sorted labels of its neighbors, then hashing the counts
+    of the node labels found at each iteration [WL]_. Isomorphic graphs always
+    have the same hash while non-isomorphic graphs get different hashes with
+    high probability, so it can be used as a fast pre-filter key before
+    running an expensive isomorphism check. The returned digest is stable
+    across platforms and releases, but it is not a cryptographic hash.
+
+    :param graph: The graph to hash. Can either be a
+        :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`
+    :param callable node_attr_fn: An optional callable that will be passed the
+        weight/data payload of a node and returns its initial label, the
+        ``str()`` of the returned value is used. If not specified the degree
+        of the node is used.
+    :param callable edge_attr_fn: An optional callable that will be passed the
+        weight/data payload of an edge and returns its label, the ``str()`` of
+        the returned value is used. If not specified edges are not labeled.
+    :param int iterations: The number of relabeling iterations to run, higher
+        values take a larger neighborhood of every node into account.
+        Must be at least 1. Default: ``3``.
+
+    :returns: A hex digest of the graph
+    :rtype: str
+    :raises ValueError: If ``iterations`` is 0
+
+    .. [WL] Shervashidze, Nino, Pascal Schweitzer, Erik Jan Van Leeuwen,
+       Kurt Mehlhorn, and Karsten M. Borgwardt.
+       "Weisfeiler Lehman graph kernels."
+       Journal of Machine Learning Research. 12 (2011): 2539-2561.
     """
-    raise TypeError("Invalid input type %s for graph" % type(graph))
+    raise TypeError("Invalid Input Type %s for graph" % type(graph))
 
 
-@betweenness_centrality.register(PyDiGraph)
-def _digraph_betweenness_centrality(graph, normalized=True, endpoints=False, parallel_threshold=50):
-    return digraph_betweenness_centrality(
-        graph,
-        normalized=normalized,
-        endpoints=endpoints,
-        parallel_threshold=parallel_threshold,
+@weisfeiler_lehman_graph_hash.register(PyDiGraph)
+def _digraph_weisfeiler_lehman_graph_hash(
+    graph, node_attr_fn=None, edge_attr_fn=None, iterations=3
+):
+    return digraph_weisfeiler_lehman_graph_hash(
+        graph, node_attr_fn=node_attr_fn, edge_attr_fn=edge_attr_fn, iterations=iterations
     )
 
 
-@betweenness_centrality.register(PyGraph)
-def _graph_betweenness_centrality(graph, normalized=True, endpoints=False, parallel_threshold=50):
-    return graph_betweenness_centrality(
-        graph,
-        normalized=normalized,
-        endpoints=endpoints,
-        parallel_threshold=parallel_threshold,
+@weisfeiler_lehman_graph_hash.register(PyGraph)
+def _graph_weisfeiler_lehman_graph_hash(graph, node_attr_fn=None, edge_attr_fn=None, iterations=3):
+    return graph_weisfeiler_lehman_graph_hash(
+        graph, node_attr_fn=node_attr_fn, edge_attr_fn=edge_attr_fn, iterations=iterations
     )
 
 
@@ -1722,6 +5765,249 @@ def _graph_union(
     return graph_union(first, second, merge_nodes=merge_nodes, merge_edges=merge_edges)
 
 
+@functools.singledispatch
+def graph_diff(first, second, node_key_fn=None):
+    """Compute the differences between two graph snapshots
+
+    Nodes are matched between the two graphs by the key returned by
+    ``node_key_fn`` rather than by their node index, as the index spaces of
+    two snapshots of an evolving graph rarely align. An edge of ``first``
+    matches an edge of ``second`` if their endpoints match, with parallel
+    edges being matched one to one. Matching nodes or edges whose weight/data
+    payloads are not equal are reported as changed. For example:
+
+    .. jupyter-execute::
+
+        import retworkx
+
+        first = retworkx.PyGraph()
+        first.add_nodes_from([{"id": "a"}, {"id": "b"}, {"id": "c"}])
+        first.add_edges_from([(0, 1, 1), (1, 2, 1)])
+        second = retworkx.PyGraph()
+        second.add_nodes_from([{"id": "c"}, {"id": "b", "color": "red"}, {"id": "d"}])
+        second.add_edges_from([(1, 0, 5), (2, 0, 1)])
+
+        diff = retworkx.graph_diff(first, second, node_key_fn=lambda node: node["id"])
+        print(diff.removed_nodes, diff.added_nodes, diff.changed_nodes)
+        print(diff.removed_edges, diff.added_edges, diff.changed_edges)
+
+    :param first: The earlier graph snapshot
+    :param second: The later graph snapshot, it must be of the same type as
+        ``first``
+    :param callable node_key_fn: An optional callable that will be passed the
+        weight/data payload of a node and returns a hashable key identifying
+        the node across snapshots. If not specified the weight/data payload
+        itself is used as the key.
+
+    :returns: The differences between ``first`` and ``second``
+    :rtype: GraphDiff
+    :raises ValueError: If two nodes of the same graph have equal keys
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(first))
+
+
+@graph_diff.register(PyDiGraph)
+def _digraph_graph_diff(first, second, node_key_fn=None):
+    return digraph_graph_diff(first, second, node_key_fn=node_key_fn)
+
+
+@graph_diff.register(PyGraph)
+def _graph_graph_diff(first, second, node_key_fn=None):
+    return graph_graph_diff(first, second, node_key_fn=node_key_fn)
+
+
+@functools.singledispatch
+def graph_summary(graph):
+    """Compute a summary of the basic statistics of a graph
+
+    This computes the statistics which are usually looked at first to profile
+    a graph in a single call, sharing the work between them: the number of
+    nodes and edges, the density, the degree statistics, the (weakly)
+    connected components, the average clustering coefficient, the degree
+    assortativity and an estimate of the diameter. For example:
+
+    .. jupyter-execute::
+
+        import retworkx
+
+        graph = retworkx.generators.grid_graph(4, 5)
+        summary = retworkx.graph_summary(graph)
+        print(summary.node_count, summary.edge_count, summary.density)
+        print(summary.min_degree, summary.max_degree, summary.mean_degree)
+        print(summary.diameter_estimate)
+
+    The degree of a node counts self loops twice, and for a
+    :class:`~retworkx.PyDiGraph` it's the sum of its in and out degrees. The
+    components, clustering and diameter estimate ignore the direction of the
+    edges, parallel edges and self loops. The diameter estimate is found with
+    a double sweep breadth first search [MLH]_, it's a lower bound on the
+    diameter of the largest component which is exact for trees.
+
+    :param graph: The graph to summarize. This can be a
+        :class:`~retworkx.PyGraph` or a :class:`~retworkx.PyDiGraph`.
+
+    :returns: The statistics of ``graph``
+    :rtype: GraphSummary
+
+    .. [MLH] Clémence Magnien, Matthieu Latapy and Michel Habib, "Fast
+        computation of empirical tight bounds for the diameter of massive
+        graphs." Journal of Experimental Algorithmics 13:1.10-1.9, 2009.
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(graph))
+
+
+@graph_summary.register(PyDiGraph)
+def _digraph_graph_summary(graph):
+    return digraph_graph_summary(graph)
+
+
+@graph_summary.register(PyGraph)
+def _graph_graph_summary(graph):
+    return graph_graph_summary(graph)
+
+
+@functools.singledispatch
+def rewrite(
+    graph,
+    pattern,
+    replacement,
+    interface,
+    node_matcher=None,
+    edge_matcher=None,
+    induced=False,
+    max_applications=None,
+):
+    """Rewrite a graph in place with a double-pushout rule
+
+    A rule is made of a ``pattern`` graph (the left hand side), a
+    ``replacement`` graph (the right hand side) and an ``interface`` mapping
+    the pattern nodes that are preserved by the rule to replacement nodes.
+    Every application of the rule finds a match of ``pattern`` in ``graph``
+    with the same subgraph matching as :func:`~retworkx.is_subgraph_isomorphic`
+    and then:
+
+    1. removes the edges matched by the pattern edges and the nodes matched
+       by the pattern nodes outside of the interface,
+    2. adds a node for every replacement node outside of the interface, with
+       the weight/data payload of the replacement node,
+    3. adds an edge for every replacement edge, with the weight/data payload
+       of the replacement edge.
+
+    Preserved nodes keep their weight/data payload and any edges not matched
+    by the pattern. An edge that should be preserved has to be present in both
+    ``pattern`` and ``replacement``. A match is only applied if no edge would
+    be left dangling, that is if every edge of a removed node is matched by a
+    pattern edge, otherwise the next match is tried. For example, to cancel
+    pairs of adjacent ``"x"`` gates in a chain:
+
+    .. jupyter-execute::
+
+        import retworkx
+
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(["in", "x", "x", "h", "x", "x", "out"])
+        graph.add_edges_from_no_data([(i, i + 1) for i in range(6)])
+
+        pattern = retworkx.PyDiGraph()
+        pattern.add_nodes_from([None, "x", "x", None])
+        pattern.add_edges_from_no_data([(0, 1), (1, 2), (2, 3)])
+        replacement = retworkx.PyDiGraph()
+        replacement.add_nodes_from([None, None])
+        replacement.add_edge(0, 1, None)
+
+        applications = retworkx.rewrite(
+            graph,
+            pattern,
+            replacement,
+            {0: 0, 3: 1},
+            node_matcher=lambda node, pattern_node: pattern_node in (None, node),
+        )
+        print(len(applications))
+        print([graph[node] for node in retworkx.topological_sort(graph)])
+
+    The graph is matched again after every application, until no applicable
+    match is left or ``max_applications`` rules were applied. If the
+    replacement can create new matches of the pattern the rewriting might not
+    terminate unless ``max_applications`` is set.
+
+    :param graph: The graph to rewrite in place. This can be a
+        :class:`~retworkx.PyGraph` or a :class:`~retworkx.PyDiGraph`.
+    :param pattern: The left hand side of the rule, it must be of the same
+        type as ``graph``
+    :param replacement: The right hand side of the rule, it must be of the
+        same type as ``graph``
+    :param dict interface: A mapping of the node indices of the preserved
+        nodes in ``pattern`` to their node indices in ``replacement``
+    :param callable node_matcher: An optional callable that will be passed the
+        weight/data payloads of a node in ``graph`` and a node in ``pattern``
+        and returns ``True`` if they can be matched. If not specified the
+        payloads are not compared.
+    :param callable edge_matcher: An optional callable that will be passed the
+        weight/data payloads of an edge in ``graph`` and an edge in
+        ``pattern`` and returns ``True`` if they can be matched. If not
+        specified the payloads are not compared.
+    :param bool induced: If set to ``True`` a match must not have edges
+        between the matched nodes that are not in ``pattern``. Defaults to
+        ``False``.
+    :param int max_applications: The maximum number of times to apply the
+        rule. If not specified the rule is applied until there is no
+        applicable match.
+
+    :returns: A list with a mapping of the node indices of ``replacement`` to
+        the node indices in ``graph`` for every application of the rule
+    :rtype: list
+    :raises ValueError: If ``pattern`` is empty or ``interface`` is not an
+        injective mapping of nodes of ``pattern`` to nodes of ``replacement``
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(graph))
+
+
+@rewrite.register(PyDiGraph)
+def _digraph_rewrite(
+    graph,
+    pattern,
+    replacement,
+    interface,
+    node_matcher=None,
+    edge_matcher=None,
+    induced=False,
+    max_applications=None,
+):
+    return digraph_rewrite(
+        graph,
+        pattern,
+        replacement,
+        interface,
+        node_matcher=node_matcher,
+        edge_matcher=edge_matcher,
+        induced=induced,
+        max_applications=max_applications,
+    )
+
+
+@rewrite.register(PyGraph)
+def _graph_rewrite(
+    graph,
+    pattern,
+    replacement,
+    interface,
+    node_matcher=None,
+    edge_matcher=None,
+    induced=False,
+    max_applications=None,
+):
+    return graph_rewrite(
+        graph,
+        pattern,
+        replacement,
+        interface,
+        node_matcher=node_matcher,
+        edge_matcher=edge_matcher,
+        induced=induced,
+        max_applications=max_applications,
+    )
+
+
 @functools.singledispatch
 def tensor_product(
     first,
@@ -1876,8 +6162,9 @@ def bfs_search(graph, source, visitor):
 
         Graph can **not** be mutated while traversing.
 
-    :param graph: The graph to be used. This can be a :class:`~retworkx.PyGraph`
-        or a :class:`~retworkx.PyDiGraph`
+    :param graph: The graph to be used. This can be a :class:`~retworkx.PyGraph`,
+        a :class:`~retworkx.PyDiGraph`, or a view of one, a
+        :class:`~retworkx.GraphView` or a :class:`~retworkx.DiGraphView`
     :param List[int] source: An optional list of node indices to use as the starting
         nodes for the breadth-first search. If this is not specified then a source
         will be chosen arbitrarly and repeated until all components of the
@@ -1889,11 +6176,13 @@ def bfs_search(graph, source, visitor):
 
 
 @bfs_search.register(PyDiGraph)
+@bfs_search.register(DiGraphView)
 def _digraph_bfs_search(graph, source, visitor):
     return digraph_bfs_search(graph, source, visitor)
 
 
 @bfs_search.register(PyGraph)
+@bfs_search.register(GraphView)
 def _graph_bfs_search(graph, source, visitor):
     return graph_bfs_search(graph, source, visitor)
 
@@ -1957,7 +6246,9 @@ def dfs_search(graph, source, visitor):
 
         Graph can *not* be mutated while traversing.
 
-    :param PyGraph graph: The graph to be used.
+    :param graph: The graph to be used. This can be a :class:`~retworkx.PyGraph`,
+        a :class:`~retworkx.PyDiGraph`, or a view of one, a
+        :class:`~retworkx.GraphView` or a :class:`~retworkx.DiGraphView`.
     :param List[int] source: An optional list of node indices to use as the starting
         nodes for the depth-first search. If this is not specified then a source
         will be chosen arbitrarly and repeated until all components of the
@@ -1969,11 +6260,13 @@ def dfs_search(graph, source, visitor):
 
 
 @dfs_search.register(PyDiGraph)
+@dfs_search.register(DiGraphView)
 def _digraph_dfs_search(graph, source, visitor):
     return digraph_dfs_search(graph, source, visitor)
 
 
 @dfs_search.register(PyGraph)
+@dfs_search.register(GraphView)
 def _graph_dfs_search(graph, source, visitor):
     return graph_dfs_search(graph, source, visitor)
 
@@ -2019,8 +6312,9 @@ def dijkstra_search(graph, source, weight_fn, visitor):
 
         Graph can **not** be mutated while traversing.
 
-    :param graph: The graph to be used. This can be a :class:`~retworkx.PyGraph`
-        or a :class:`~retworkx.PyDiGraph`.
+    :param graph: The graph to be used. This can be a :class:`~retworkx.PyGraph`,
+        a :class:`~retworkx.PyDiGraph`, or a view of one, a
+        :class:`~retworkx.GraphView` or a :class:`~retworkx.DiGraphView`.
     :param List[int] source: An optional list of node indices to use as the starting nodes
         for the dijkstra search. If this is not specified then a source
         will be chosen arbitrarly and repeated until all components of the
@@ -2036,10 +6330,435 @@ def dijkstra_search(graph, source, weight_fn, visitor):
 
 
 @dijkstra_search.register(PyDiGraph)
+@dijkstra_search.register(DiGraphView)
 def _digraph_dijkstra_search(graph, source, weight_fn, visitor):
     return digraph_dijkstra_search(graph, source, weight_fn, visitor)
 
 
 @dijkstra_search.register(PyGraph)
+@dijkstra_search.register(GraphView)
 def _graph_dijkstra_search(graph, source, weight_fn, visitor):
     return graph_dijkstra_search(graph, source, weight_fn, visitor)
+
+
+@functools.singledispatch
+def reconstruction_precision_at_k(graph, embeddings, k, similarity="dot", parallel_threshold=300):
+    """Compute the precision at ``k`` of the reconstruction of a graph from
+    node embeddings
+
+    All the pairs of different nodes are ranked by decreasing similarity of
+    their embeddings, and the precision at ``k`` is the fraction of the ``k``
+    best ranked pairs which are edges of the graph. For a
+    :class:`~retworkx.PyDiGraph` the ordered pairs :math:`(u, v)` are ranked
+    and checked against the edges from :math:`u` to :math:`v`. The pairs with
+    equal similarities are ranked by their node indices. If there are less
+    than ``k`` pairs the precision is over all of them.
+
+    The best pairs of each node are found on multiple threads if the graph
+    has at least ``parallel_threshold`` nodes, the env var
+    ``RAYON_NUM_THREADS`` can be used to adjust how many threads are used.
+
+    :param graph: The graph to reconstruct. Can either be a
+        :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`
+    :param numpy.ndarray embeddings: A 2D array of ``float`` with the
+        embedding of every node, the row of a node is its node index
+    :param int k: The number of best ranked pairs to check
+    :param str similarity: The similarity of two embeddings, either ``"dot"``
+        for their dot product or ``"cosine"`` for their cosine similarity.
+        Defaults to ``"dot"``.
+    :param int parallel_threshold: The number of nodes to run in parallel
+        from. Defaults to 300.
+
+    :returns: The precision at ``k``, 0 if ``k`` is 0 or there's no pair
+    :rtype: float
+    :raises ValueError: If ``embeddings`` has fewer rows than the node
+        indices of the graph, or if ``similarity`` is invalid
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(graph))
+
+
+@reconstruction_precision_at_k.register(PyDiGraph)
+def _digraph_reconstruction_precision_at_k(
+    graph, embeddings, k, similarity="dot", parallel_threshold=300
+):
+    return digraph_reconstruction_precision_at_k(
+        graph, embeddings, k, similarity=similarity, parallel_threshold=parallel_threshold
+    )
+
+
+@reconstruction_precision_at_k.register(PyGraph)
+def _graph_reconstruction_precision_at_k(
+    graph, embeddings, k, similarity="dot", parallel_threshold=300
+):
+    return graph_reconstruction_precision_at_k(
+        graph, embeddings, k, similarity=similarity, parallel_threshold=parallel_threshold
+    )
+
+
+@functools.singledispatch
+def reconstruction_mean_average_precision(
+    graph, embeddings, similarity="dot", parallel_threshold=300
+):
+    """Compute the mean average precision of the reconstruction of a graph
+    from node embeddings
+
+    For every node :math:`u` with at least one neighbor, the other nodes are
+    ranked by decreasing similarity of their embedding to the embedding of
+    :math:`u`, and the average precision of :math:`u` is the mean of the
+    precisions at the ranks of its neighbors. The neighbors of a node of a
+    :class:`~retworkx.PyDiGraph` are its successors. The mean average
+    precision is the mean of the average precisions of these nodes. The
+    nodes with equal similarities are ranked by their node indices.
+
+    The nodes are ranked on multiple threads if the graph has at least
+    ``parallel_threshold`` nodes, the env var ``RAYON_NUM_THREADS`` can be
+    used to adjust how many threads are used.
+
+    :param graph: The graph to reconstruct. Can either be a
+        :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`
+    :param numpy.ndarray embeddings: A 2D array of ``float`` with the
+        embedding of every node, the row of a node is its node index
+    :param str similarity: The similarity of two embeddings, either ``"dot"``
+        for their dot product or ``"cosine"`` for their cosine similarity.
+        Defaults to ``"dot"``.
+    :param int parallel_threshold: The number of nodes to run in parallel
+        from. Defaults to 300.
+
+    :returns: The mean average precision, 0 if no node has a neighbor
+    :rtype: float
+    :raises ValueError: If ``embeddings`` has fewer rows than the node
+        indices of the graph, or if ``similarity`` is invalid
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(graph))
+
+
+@reconstruction_mean_average_precision.register(PyDiGraph)
+def _digraph_reconstruction_mean_average_precision(
+    graph, embeddings, similarity="dot", parallel_threshold=300
+):
+    return digraph_reconstruction_mean_average_precision(
+        graph, embeddings, similarity=similarity, parallel_threshold=parallel_threshold
+    )
+
+
+@reconstruction_mean_average_precision.register(PyGraph)
+def _graph_reconstruction_mean_average_precision(
+    graph, embeddings, similarity="dot", parallel_threshold=300
+):
+    return graph_reconstruction_mean_average_precision(
+        graph, embeddings, similarity=similarity, parallel_threshold=parallel_threshold
+    )
+
+
+@functools.singledispatch
+def link_prediction_roc_auc(
+    graph,
+    embeddings,
+    test_edges,
+    num_negative=None,
+    similarity="dot",
+    seed=None,
+    parallel_threshold=300,
+):
+    """Compute the ROC-AUC of the prediction of held out edges of a graph
+    from node embeddings
+
+    The ``test_edges`` are the held out edges, usually removed from the graph
+    before computing the embeddings. Their similarities are compared to the
+    similarities of ``num_negative`` negative pairs of nodes, drawn uniformly
+    with replacement among the pairs of different nodes which are neither an
+    edge of the graph nor a test edge. The ROC-AUC is the probability that a
+    random test edge has a higher similarity than a random negative pair, a
+    tie counts as half. It is 1 for a perfect ranking and about 0.5 for a
+    random one.
+
+    The similarities are computed on multiple threads if there are at least
+    ``parallel_threshold`` test edges and negative pairs, the env var
+    ``RAYON_NUM_THREADS`` can be used to adjust how many threads are used.
+
+    :param graph: The graph the test edges are held out of. Can either be a
+        :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`
+    :param numpy.ndarray embeddings: A 2D array of ``float`` with the
+        embedding of every node, the row of a node is its node index
+    :param list test_edges: The held out edges, as tuples of node indices
+    :param int num_negative: The number of negative pairs to draw. Defaults
+        to the number of test edges.
+    :param str similarity: The similarity of two embeddings, either ``"dot"``
+        for their dot product or ``"cosine"`` for their cosine similarity.
+        Defaults to ``"dot"``.
+    :param int seed: An optional seed to use for the random number generator
+    :param int parallel_threshold: The number of similarities to compute in
+        parallel from. Defaults to 300.
+
+    :returns: The ROC-AUC
+    :rtype: float
+    :raises InvalidNode: If a node of a test edge is not in the graph
+    :raises ValueError: If ``embeddings`` has fewer rows than the node
+        indices of the graph, if ``similarity`` is invalid, if there's no test
+        edge or no negative pair to draw, or if ``num_negative`` is 0
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(graph))
+
+
+@link_prediction_roc_auc.register(PyDiGraph)
+def _digraph_link_prediction_roc_auc(
+    graph,
+    embeddings,
+    test_edges,
+    num_negative=None,
+    similarity="dot",
+    seed=None,
+    parallel_threshold=300,
+):
+    return digraph_link_prediction_roc_auc(
+        graph,
+        embeddings,
+        test_edges,
+        num_negative=num_negative,
+        similarity=similarity,
+        seed=seed,
+        parallel_threshold=parallel_threshold,
+    )
+
+
+@link_prediction_roc_auc.register(PyGraph)
+def _graph_link_prediction_roc_auc(
+    graph,
+    embeddings,
+    test_edges,
+    num_negative=None,
+    similarity="dot",
+    seed=None,
+    parallel_threshold=300,
+):
+    return graph_link_prediction_roc_auc(
+        graph,
+        embeddings,
+        test_edges,
+        num_negative=num_negative,
+        similarity=similarity,
+        seed=seed,
+        parallel_threshold=parallel_threshold,
+    )
+
+
+@functools.singledispatch
+def node_link_json(graph, path=None, graph_attrs=None, node_attrs=None, edge_attrs=None):
+    """Write a graph as node-link JSON
+
+    The node-link format is the JSON format of d3 force layouts and of
+    :func:`networkx.node_link_data`, the output can be loaded with
+    :func:`networkx.node_link_graph` or read back with
+    :func:`~retworkx.read_node_link_json`. For example::
+
+        graph = retworkx.generators.path_graph(2)
+        retworkx.node_link_json(graph, graph_attrs={"name": "path"})
+        # {"schema_version": 1, "directed": false, "multigraph": true,
+        #  "graph": {"name": "path"}, "nodes": [{"id": 0}, {"id": 1}],
+        #  "links": [{"source": 0, "target": 1, "key": 0}]}
+
+    Every node is written as an object with its node index as ``"id"``
+    and its attributes, and every edge as an object with the ``"source"``
+    and ``"target"`` node ids, its edge index as ``"key"`` if the graph is a
+    multigraph, and its attributes. The ``"schema_version"`` is the version
+    of the format written by retworkx, it's ignored by networkx and d3.
+
+    :param graph: The graph to write. Can either be a
+        :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`
+    :param path: An optional path to write the JSON to, if specified there is
+        no return from the function
+    :param dict graph_attrs: An optional dict of graph attributes
+    :param node_attrs: An optional callable that will be passed the payload
+        of every node and is expected to return a dict of its attributes. By
+        default the payload is used if it's a dict and no attributes are
+        written for ``None``.
+    :param edge_attrs: An optional callable that will be passed the payload
+        of every edge and is expected to return a dict of its attributes. By
+        default the payload is used if it's a dict and no attributes are
+        written for ``None``.
+
+    :returns: The JSON string if ``path`` is not specified
+    :rtype: str
+
+    :raises TypeError: If an attribute dict has a key which isn't a string, a
+        value without a JSON representation, or a payload isn't a dict
+    :raises ValueError: If an attribute is named ``id``, ``source``,
+        ``target`` or ``key`` or is a float that isn't finite
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(graph))
+
+
+@node_link_json.register(PyDiGraph)
+def _digraph_node_link_json(graph, path=None, graph_attrs=None, node_attrs=None, edge_attrs=None):
+    return digraph_node_link_json(
+        graph, path=path, graph_attrs=graph_attrs, node_attrs=node_attrs, edge_attrs=edge_attrs
+    )
+
+
+@node_link_json.register(PyGraph)
+def _graph_node_link_json(graph, path=None, graph_attrs=None, node_attrs=None, edge_attrs=None):
+    return graph_node_link_json(
+        graph, path=path, graph_attrs=graph_attrs, node_attrs=node_attrs, edge_attrs=edge_attrs
+    )
+
+
+@functools.singledispatch
+def write_pajek(graph, path=None, label_fn=None, weight_fn=None):
+    """Write a graph in the Pajek ``.net`` format
+
+    The vertices are numbered from 1 in node index order, so the output can
+    be read back with :func:`~retworkx.read_pajek` with the same nodes, in
+    the same order, if no nodes have been removed. A
+    :class:`~retworkx.PyGraph` is written as an ``*Edges`` section and a
+    :class:`~retworkx.PyDiGraph` as an ``*Arcs`` section. If the graph has
+    ``"x"`` and ``"y"`` node attributes, and optionally ``"z"`` (see
+    :meth:`.PyGraph.set_node_attribute`), they're written as the vertex
+    coordinates. For example::
+
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(["a", "b"])
+        graph.add_edge(0, 1, 2.5)
+        retworkx.write_pajek(graph)
+        # '*Vertices 2\\n1 "a"\\n2 "b"\\n*Edges\\n1 2 2.5\\n'
+
+    :param graph: The graph to write. Can either be a
+        :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`
+    :param path: An optional path to write the network to, if specified there
+        is no return from the function
+    :param label_fn: An optional callable that will be passed the payload of
+        every node and is expected to return its label as a string. By
+        default the label is ``str(payload)`` and nodes with a ``None``
+        payload have no label.
+    :param weight_fn: An optional callable that will be passed the payload of
+        every edge and is expected to return its weight as a float. By
+        default the payload is the weight and edges with a ``None`` payload
+        have no weight.
+
+    :returns: The Pajek network as a string if ``path`` is not specified
+    :rtype: str
+
+    :raises TypeError: If an edge payload isn't a number and no ``weight_fn``
+        is specified
+    :raises ValueError: If a label contains a double quote or a newline
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(graph))
+
+
+@write_pajek.register(PyDiGraph)
+def _digraph_write_pajek(graph, path=None, label_fn=None, weight_fn=None):
+    return digraph_write_pajek(graph, path=path, label_fn=label_fn, weight_fn=weight_fn)
+
+
+@write_pajek.register(PyGraph)
+def _graph_write_pajek(graph, path=None, label_fn=None, weight_fn=None):
+    return graph_write_pajek(graph, path=path, label_fn=label_fn, weight_fn=weight_fn)
+
+
+@functools.singledispatch
+def to_graph6_bytes(graph, header=True):
+    """Encode a graph in the graph6 or digraph6 format
+
+    A :class:`~retworkx.PyGraph` is encoded in the graph6 format and a
+    :class:`~retworkx.PyDiGraph` in the digraph6 format, the formats of
+    nauty and its tools like ``geng``. The nodes are numbered from 0 in node
+    index order, so the data decodes with
+    :func:`~retworkx.from_graph6_bytes` to the same graph if no nodes have
+    been removed. Both formats store the adjacency matrix, so parallel edges
+    are stored once, and self loops are dropped in the graph6 format. Use
+    :func:`~retworkx.to_sparse6_bytes` to keep them for a
+    :class:`~retworkx.PyGraph`. For example::
+
+        graph = retworkx.generators.path_graph(3)
+        retworkx.to_graph6_bytes(graph, header=False)
+        # b'Bg\\n'
+
+    :param graph: The graph to encode. Can either be a
+        :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`
+    :param bool header: If set to ``True`` (the default) the data starts
+        with the ``>>graph6<<`` or ``>>digraph6<<`` header.
+
+    :returns: The encoded graph, ending with a newline
+    :rtype: bytes
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(graph))
+
+
+@to_graph6_bytes.register(PyDiGraph)
+def _digraph_to_graph6_bytes(graph, header=True):
+    return digraph_to_graph6_bytes(graph, header=header)
+
+
+@to_graph6_bytes.register(PyGraph)
+def _graph_to_graph6_bytes(graph, header=True):
+    return graph_to_graph6_bytes(graph, header=header)
+
+
+@functools.singledispatch
+def is_planar(graph):
+    """Return whether a graph is planar
+
+    A graph is planar if it can be drawn in the plane without any edges
+    crossing, the direction of the edges of a :class:`~retworkx.PyDiGraph`
+    is ignored. This uses the left-right planarity test, which runs in linear
+    time (up to sorting the adjacency lists). Use
+    :func:`~retworkx.check_planarity` to also get an embedding or a proof
+    that the graph isn't planar.
+
+    :param graph: The graph to check. Can either be a
+        :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`
+
+    :returns: ``True`` if the graph is planar, ``False`` otherwise
+    :rtype: bool
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(graph))
+
+
+@is_planar.register(PyDiGraph)
+def _digraph_is_planar(graph):
+    return digraph_is_planar(graph)
+
+
+@is_planar.register(PyGraph)
+def _graph_is_planar(graph):
+    return graph_is_planar(graph)
+
+
+@functools.singledispatch
+def check_planarity(graph):
+    """Check whether a graph is planar, with a certificate
+
+    If the graph is planar, the result has a combinatorial embedding of the
+    graph: the neighbors of every node in the clockwise order of a planar
+    drawing. If it isn't planar, the result has the edges of a Kuratowski
+    subgraph, a subdivision of either :math:`K_5` or :math:`K_{3,3}`, which
+    proves that the graph isn't planar. The direction of the edges of a
+    :class:`~retworkx.PyDiGraph`, self loops and parallel edges are ignored.
+    For example::
+
+        graph = retworkx.generators.generalized_petersen_graph(5, 2)
+        result = retworkx.check_planarity(graph)
+        if not result:
+            print(result.kuratowski_type, result.kuratowski_edges)
+
+    Finding the embedding takes linear time (up to sorting the adjacency
+    lists), finding the Kuratowski subgraph takes a planarity test for each
+    of its edges and a logarithmic number of tests for each other edge.
+
+    :param graph: The graph to check. Can either be a
+        :class:`~retworkx.PyGraph` or :class:`~retworkx.PyDiGraph`
+
+    :returns: The result of the check, with either the embedding or the
+        Kuratowski subgraph
+    :rtype: PlanarityResult
+    """
+    raise TypeError("Invalid Input Type %s for graph" % type(graph))
+
+
+@check_planarity.register(PyDiGraph)
+def _digraph_check_planarity(graph):
+    return digraph_check_planarity(graph)
+
+
+@check_planarity.register(PyGraph)
+def _graph_check_planarity(graph):
+    return graph_check_planarity(graph)
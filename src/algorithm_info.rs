@@ -0,0 +1,163 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use pyo3::Python;
+
+use retworkx_core::Convergence;
+
+/// A record of how an algorithm was run
+///
+/// This class is returned along with the result of an algorithm function
+/// when it's called with ``explain=True``, for example by
+/// :func:`~retworkx.pagerank` or :func:`~retworkx.spring_layout`, so the
+/// exact run can be recorded for reproducibility. Every default parameter is
+/// resolved to the value that was used, and the seed of the random number
+/// generator is recorded even if it wasn't set.
+///
+/// .. jupyter-execute::
+///
+///     import retworkx
+///
+///     graph = retworkx.generators.directed_cycle_graph(5)
+///     ranks, info = retworkx.pagerank(graph, explain=True)
+///     print(info.to_dict())
+///
+/// :ivar str algorithm: The name of the algorithm
+/// :ivar str variant: The variant of the algorithm that was run
+/// :ivar dict parameters: The parameters the algorithm was run with
+/// :ivar iterations: The number of iterations run, or ``None`` if the
+///     algorithm isn't iterative
+/// :ivar tolerance: The error tolerance used to check for convergence, or
+///     ``None`` if the algorithm isn't iterative
+/// :ivar error: The error of the last iteration, the value compared with
+///     ``tolerance`` to check for convergence, or ``None`` if the algorithm
+///     isn't iterative
+/// :ivar converged: Whether the algorithm converged within the maximum number
+///     of iterations, or ``None`` if the algorithm isn't iterative
+/// :ivar seed: The seed of the random number generator, or ``None`` if the
+///     algorithm isn't random
+/// :ivar list fallbacks: The descriptions of any fallbacks taken, where the
+///     algorithm didn't do what the parameters asked for
+#[pyclass(module = "retworkx")]
+pub struct AlgorithmInfo {
+    #[pyo3(get)]
+    pub algorithm: String,
+    #[pyo3(get)]
+    pub variant: String,
+    pub parameters: Vec<(&'static str, PyObject)>,
+    #[pyo3(get)]
+    pub iterations: Option<usize>,
+    #[pyo3(get)]
+    pub tolerance: Option<f64>,
+    #[pyo3(get)]
+    pub error: Option<f64>,
+    #[pyo3(get)]
+    pub converged: Option<bool>,
+    #[pyo3(get)]
+    pub seed: Option<u64>,
+    #[pyo3(get)]
+    pub fallbacks: Vec<String>,
+}
+
+impl AlgorithmInfo {
+    pub fn new(algorithm: &str, variant: &str) -> Self {
+        AlgorithmInfo {
+            algorithm: algorithm.to_string(),
+            variant: variant.to_string(),
+            parameters: Vec::new(),
+            iterations: None,
+            tolerance: None,
+            error: None,
+            converged: None,
+            seed: None,
+            fallbacks: Vec::new(),
+        }
+    }
+
+    pub fn parameter<T: ToPyObject>(mut self, py: Python, name: &'static str, value: T) -> Self {
+        self.parameters.push((name, value.to_object(py)));
+        self
+    }
+
+    pub fn convergence(mut self, convergence: Convergence, tolerance: f64) -> Self {
+        self.iterations = Some(convergence.iterations);
+        self.tolerance = Some(tolerance);
+        self.error = Some(convergence.error);
+        self.converged = Some(convergence.converged);
+        self
+    }
+
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    pub fn fallback(mut self, description: &str) -> Self {
+        self.fallbacks.push(description.to_string());
+        self
+    }
+}
+
+/// Return ``result``, or a tuple of ``result`` and ``info`` if ``explain``
+/// is set.
+pub fn explained<T: IntoPy<PyObject>>(
+    py: Python,
+    result: T,
+    info: AlgorithmInfo,
+    explain: bool,
+) -> PyResult<PyObject> {
+    if explain {
+        Ok((result.into_py(py), Py::new(py, info)?).into_py(py))
+    } else {
+        Ok(result.into_py(py))
+    }
+}
+
+#[pymethods]
+impl AlgorithmInfo {
+    #[getter]
+    fn parameters(&self, py: Python) -> PyResult<PyObject> {
+        let out_dict = PyDict::new(py);
+        for (name, value) in &self.parameters {
+            out_dict.set_item(name, value)?;
+        }
+        Ok(out_dict.into())
+    }
+
+    /// Return the record as a dictionary
+    ///
+    /// :returns: A new dictionary with every attribute of the record
+    /// :rtype: dict
+    #[pyo3(text_signature = "(self)")]
+    fn to_dict(&self, py: Python) -> PyResult<PyObject> {
+        let out_dict = PyDict::new(py);
+        out_dict.set_item("algorithm", &self.algorithm)?;
+        out_dict.set_item("variant", &self.variant)?;
+        out_dict.set_item("parameters", self.parameters(py)?)?;
+        out_dict.set_item("iterations", self.iterations)?;
+        out_dict.set_item("tolerance", self.tolerance)?;
+        out_dict.set_item("error", self.error)?;
+        out_dict.set_item("converged", self.converged)?;
+        out_dict.set_item("seed", self.seed)?;
+        out_dict.set_item("fallbacks", self.fallbacks.clone())?;
+        Ok(out_dict.into())
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "AlgorithmInfo(algorithm={:?}, variant={:?})",
+            self.algorithm, self.variant
+        )
+    }
+}
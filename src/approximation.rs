@@ -0,0 +1,80 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use crate::graph;
+
+use hashbrown::HashSet;
+use retworkx_core::approximation;
+
+use pyo3::prelude::*;
+
+/// Find a dominating set of a PyGraph with the greedy heuristic
+///
+/// A dominating set is a set of nodes such that every node of the graph is
+/// in the set or is a neighbor of a node of the set. The heuristic
+/// repeatedly adds the node which dominates the most nodes that aren't
+/// dominated yet, until every node is dominated [Johnson1974]_. Nodes
+/// dominating as many nodes are picked in a random order. The set is at
+/// most :math:`\ln(\Delta + 1) + 1` times larger than the smallest
+/// dominating set, where :math:`\Delta` is the largest degree of the graph.
+/// This function takes :math:`O((n + m) \log n)` time for ``n`` nodes and
+/// ``m`` edges. Self loops are ignored.
+///
+/// :param PyGraph graph: The graph to find the dominating set of
+/// :param int seed: An optional seed to use for the random number generator
+///
+/// :returns: The node indices of the dominating set
+/// :rtype: set
+///
+/// .. [Johnson1974] Johnson, D. S.
+///    "Approximation algorithms for combinatorial problems"
+///    Journal of Computer and System Sciences 9 (3), 256–278 (1974).
+///    https://doi.org/10.1016/S0022-0000(74)80044-9
+#[pyfunction]
+#[pyo3(text_signature = "(graph, /, seed=None)")]
+pub fn greedy_dominating_set(graph: &graph::PyGraph, seed: Option<u64>) -> HashSet<usize> {
+    approximation::greedy_dominating_set(&graph.graph, seed)
+        .into_iter()
+        .map(|node| node.index())
+        .collect()
+}
+
+/// Find a maximal independent set of a PyGraph with Luby's randomized
+/// algorithm
+///
+/// An independent set is a set of nodes without edges between them, it's
+/// maximal if no other node can be added to it. Every round of Luby's
+/// algorithm draws a random number for every node left, adds the nodes
+/// whose number is lower than the numbers of all their neighbors left to
+/// the set, and removes them and their neighbors from the nodes left
+/// [Luby1986]_. It takes :math:`O(\log n)` rounds of :math:`O(n + m)` time
+/// on average for ``n`` nodes and ``m`` edges. The set is maximal but not
+/// necessarily of maximum size. Self loops are ignored.
+///
+/// :param PyGraph graph: The graph to find the independent set of
+/// :param int seed: An optional seed to use for the random number generator
+///
+/// :returns: The node indices of the independent set
+/// :rtype: set
+///
+/// .. [Luby1986] Luby, M.
+///    "A Simple Parallel Algorithm for the Maximal Independent Set Problem"
+///    SIAM Journal on Computing 15 (4), 1036–1053 (1986).
+///    https://doi.org/10.1137/0215074
+#[pyfunction]
+#[pyo3(text_signature = "(graph, /, seed=None)")]
+pub fn maximal_independent_set(graph: &graph::PyGraph, seed: Option<u64>) -> HashSet<usize> {
+    approximation::maximal_independent_set(&graph.graph, seed)
+        .into_iter()
+        .map(|node| node.index())
+        .collect()
+}
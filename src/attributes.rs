@@ -0,0 +1,466 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use indexmap::IndexMap;
+
+use pyo3::exceptions::{PyIndexError, PyKeyError, PyTypeError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use pyo3::Python;
+
+use numpy::{IntoPyArray, PyReadonlyArray1};
+
+use petgraph::graph::{EdgeIndex, NodeIndex};
+use petgraph::prelude::*;
+use petgraph::EdgeType;
+
+use crate::{InvalidNode, StablePyGraph};
+
+/// The attribute columns of a graph, typed arrays of values indexed by node
+/// or edge index and keyed by attribute name.
+///
+/// Columns are stored next to the weight/data payloads, so algorithms can
+/// read the attributes of every node or edge without calling back into
+/// Python. The graph classes call [`AttributeColumns::remove_node`] and
+/// [`AttributeColumns::remove_edge`] every time they remove a node or an
+/// edge, so a node or an edge reusing its index starts with the missing
+/// value of every column.
+#[derive(Clone, Default)]
+pub struct AttributeColumns {
+    pub nodes: Columns,
+    pub edges: Columns,
+}
+
+impl AttributeColumns {
+    /// Reset the values of a node and of its edges, this has to be called
+    /// before the node is removed from ``graph``.
+    pub fn remove_node<Ty: EdgeType>(&mut self, graph: &StablePyGraph<Ty>, node: NodeIndex) {
+        self.nodes.reset(node.index());
+        if !self.edges.is_empty() {
+            for direction in [Outgoing, Incoming].iter() {
+                for edge in graph.edges_directed(node, *direction) {
+                    self.edges.reset(edge.id().index());
+                }
+            }
+        }
+    }
+
+    /// Reset the values of an edge.
+    pub fn remove_edge(&mut self, edge: EdgeIndex) {
+        self.edges.reset(edge.index());
+    }
+
+    /// Reset the values of every node and edge missing from ``graph``, for
+    /// the graphs built by removing nodes or edges of a copy.
+    pub fn prune<Ty: EdgeType>(&mut self, graph: &StablePyGraph<Ty>) {
+        self.nodes
+            .retain(|index| graph.node_weight(NodeIndex::new(index)).is_some());
+        self.edges
+            .retain(|index| graph.edge_weight(EdgeIndex::new(index)).is_some());
+    }
+
+    /// Add the columns to the pickled state of a graph, with the values of
+    /// ``nodes`` and ``edges`` in the order of the state.
+    pub fn get_state(
+        &self,
+        py: Python,
+        state: &PyDict,
+        nodes: &[usize],
+        edges: &[usize],
+    ) -> PyResult<()> {
+        state.set_item("node_attributes", self.nodes.to_dict(py, nodes)?)?;
+        state.set_item("edge_attributes", self.edges.to_dict(py, edges)?)?;
+        Ok(())
+    }
+
+    /// Set the columns from the pickled state of a graph, with the values
+    /// of ``nodes`` and ``edges`` in the order of the state.
+    pub fn set_state(&mut self, state: &PyDict, nodes: &[usize], edges: &[usize]) -> PyResult<()> {
+        // Pickles from before attribute columns were added don't have any
+        if let Some(columns) = state.get_item("node_attributes") {
+            self.nodes.set_dict(columns.downcast()?, nodes)?;
+        }
+        if let Some(columns) = state.get_item("edge_attributes") {
+            self.edges.set_dict(columns.downcast()?, edges)?;
+        }
+        Ok(())
+    }
+
+    /// Swap the values of 2 edges.
+    pub fn swap_edges(&mut self, a: EdgeIndex, b: EdgeIndex) {
+        self.edges.swap(a.index(), b.index());
+    }
+
+    /// Move the values of ``nodes`` and ``edges`` to the indices of their
+    /// positions in the slices, for graphs compacted to remove the holes
+    /// left by removed nodes and edges.
+    pub fn compact(&mut self, nodes: &[usize], edges: &[usize]) {
+        self.nodes.compact(nodes);
+        self.edges.compact(edges);
+    }
+}
+
+/// A column of attribute values, a missing value is ``NaN``, ``0`` or
+/// ``False`` depending on the type of the column.
+#[derive(Clone)]
+pub enum Column {
+    Float64(Vec<f64>),
+    Int64(Vec<i64>),
+    Bool(Vec<bool>),
+}
+
+impl Column {
+    pub fn dtype(&self) -> &'static str {
+        match self {
+            Column::Float64(_) => "float64",
+            Column::Int64(_) => "int64",
+            Column::Bool(_) => "bool",
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        match self {
+            Column::Float64(values) => values.len(),
+            Column::Int64(values) => values.len(),
+            Column::Bool(values) => values.len(),
+        }
+    }
+
+    /// Return the value at an index as a float.
+    pub fn get_f64(&self, index: usize) -> f64 {
+        match self {
+            Column::Float64(values) => values.get(index).copied().unwrap_or(f64::NAN),
+            Column::Int64(values) => values.get(index).copied().unwrap_or(0) as f64,
+            Column::Bool(values) => {
+                if values.get(index).copied().unwrap_or(false) {
+                    1.
+                } else {
+                    0.
+                }
+            }
+        }
+    }
+
+    fn reset(&mut self, index: usize) {
+        match self {
+            Column::Float64(values) => reset(values, index, f64::NAN),
+            Column::Int64(values) => reset(values, index, 0),
+            Column::Bool(values) => reset(values, index, false),
+        }
+    }
+
+    fn swap(&mut self, a: usize, b: usize) {
+        match self {
+            Column::Float64(values) => swap(values, a, b, f64::NAN),
+            Column::Int64(values) => swap(values, a, b, 0),
+            Column::Bool(values) => swap(values, a, b, false),
+        }
+    }
+
+    fn compact(&mut self, indices: &[usize]) {
+        match self {
+            Column::Float64(values) => *values = take(values, indices, f64::NAN),
+            Column::Int64(values) => *values = take(values, indices, 0),
+            Column::Bool(values) => *values = take(values, indices, false),
+        }
+    }
+
+    fn take(&self, py: Python, indices: &[usize]) -> PyObject {
+        match self {
+            Column::Float64(values) => take(values, indices, f64::NAN).into_pyarray(py).into(),
+            Column::Int64(values) => take(values, indices, 0).into_pyarray(py).into(),
+            Column::Bool(values) => take(values, indices, false).into_pyarray(py).into(),
+        }
+    }
+
+    fn to_list(&self, py: Python, indices: &[usize]) -> PyObject {
+        match self {
+            Column::Float64(values) => take(values, indices, f64::NAN).into_py(py),
+            Column::Int64(values) => take(values, indices, 0).into_py(py),
+            Column::Bool(values) => take(values, indices, false).into_py(py),
+        }
+    }
+}
+
+fn reset<T: Copy>(values: &mut [T], index: usize, missing: T) {
+    if let Some(value) = values.get_mut(index) {
+        *value = missing;
+    }
+}
+
+fn swap<T: Copy>(values: &mut Vec<T>, a: usize, b: usize, missing: T) {
+    let len = a.max(b) + 1;
+    if values.len() < len {
+        values.resize(len, missing);
+    }
+    values.swap(a, b);
+}
+
+fn take<T: Copy>(values: &[T], indices: &[usize], missing: T) -> Vec<T> {
+    indices
+        .iter()
+        .map(|index| values.get(*index).copied().unwrap_or(missing))
+        .collect()
+}
+
+fn put<T: Copy>(values: &mut Vec<T>, indices: &[usize], new: &[T], missing: T) {
+    if let Some(len) = indices.iter().max().map(|index| index + 1) {
+        if values.len() < len {
+            values.resize(len, missing);
+        }
+    }
+    for (index, value) in indices.iter().zip(new) {
+        values[*index] = *value;
+    }
+}
+
+/// An input array of attribute values with a ``float64``, ``int64`` or
+/// ``bool`` data type
+#[derive(FromPyObject)]
+enum ValueArray<'p> {
+    Float64(PyReadonlyArray1<'p, f64>),
+    Int64(PyReadonlyArray1<'p, i64>),
+    Bool(PyReadonlyArray1<'p, bool>),
+}
+
+impl<'p> ValueArray<'p> {
+    fn to_column(&self) -> Column {
+        match self {
+            ValueArray::Float64(array) => Column::Float64(array.as_array().to_vec()),
+            ValueArray::Int64(array) => Column::Int64(array.as_array().to_vec()),
+            ValueArray::Bool(array) => Column::Bool(array.as_array().to_vec()),
+        }
+    }
+}
+
+/// The attribute columns of the nodes or of the edges of a graph
+#[derive(Clone, Default)]
+pub struct Columns {
+    columns: IndexMap<String, Column>,
+}
+
+impl Columns {
+    pub fn is_empty(&self) -> bool {
+        self.columns.is_empty()
+    }
+
+    /// Return the column of an attribute.
+    pub fn get(&self, name: &str) -> Option<&Column> {
+        self.columns.get(name)
+    }
+
+    /// Return the names of the attributes, in the order they were added.
+    pub fn names(&self) -> Vec<String> {
+        self.columns.keys().cloned().collect()
+    }
+
+    /// Set the values of an attribute at ``indices``. If ``replace`` is set
+    /// the column is replaced by a column with the type of ``values``,
+    /// otherwise the values must have the type of the existing column.
+    pub fn set(
+        &mut self,
+        name: String,
+        values: &PyAny,
+        indices: &[usize],
+        replace: bool,
+    ) -> PyResult<()> {
+        let values: ValueArray = values.extract().map_err(|_| {
+            PyTypeError::new_err("values must be a 1D numpy array of float64, int64 or bool")
+        })?;
+        let values = values.to_column();
+        if values.len() != indices.len() {
+            return Err(PyValueError::new_err(format!(
+                "Expected {} values, got {}",
+                indices.len(),
+                values.len()
+            )));
+        }
+        let column = match self.columns.get_mut(&name) {
+            Some(column) if !replace => {
+                if column.dtype() != values.dtype() {
+                    return Err(PyTypeError::new_err(format!(
+                        "Attribute {} has dtype {}, got {}",
+                        name,
+                        column.dtype(),
+                        values.dtype()
+                    )));
+                }
+                column
+            }
+            _ => {
+                let empty = match &values {
+                    Column::Float64(_) => Column::Float64(Vec::new()),
+                    Column::Int64(_) => Column::Int64(Vec::new()),
+                    Column::Bool(_) => Column::Bool(Vec::new()),
+                };
+                self.columns.insert(name.clone(), empty);
+                self.columns.get_mut(&name).unwrap()
+            }
+        };
+        match (column, &values) {
+            (Column::Float64(column), Column::Float64(values)) => {
+                put(column, indices, values, f64::NAN)
+            }
+            (Column::Int64(column), Column::Int64(values)) => put(column, indices, values, 0),
+            (Column::Bool(column), Column::Bool(values)) => put(column, indices, values, false),
+            _ => unreachable!(),
+        }
+        Ok(())
+    }
+
+    /// Insert a column, indexed by node or edge index, replacing any column
+    /// of the attribute.
+    pub fn insert(&mut self, name: String, column: Column) {
+        self.columns.insert(name, column);
+    }
+
+    /// Return a numpy array with the values of an attribute at ``indices``,
+    /// raises a ``KeyError`` if there is no such attribute.
+    pub fn take(&self, py: Python, name: &str, indices: &[usize]) -> PyResult<PyObject> {
+        match self.columns.get(name) {
+            Some(column) => Ok(column.take(py, indices)),
+            None => Err(PyKeyError::new_err(name.to_string())),
+        }
+    }
+
+    /// Remove an attribute, raises a ``KeyError`` if there is no such
+    /// attribute.
+    pub fn remove(&mut self, name: &str) -> PyResult<()> {
+        match self.columns.shift_remove(name) {
+            Some(_) => Ok(()),
+            None => Err(PyKeyError::new_err(name.to_string())),
+        }
+    }
+
+    /// Return a dict mapping every attribute name to its type and a list of
+    /// its values at ``indices``, for pickling.
+    fn to_dict(&self, py: Python, indices: &[usize]) -> PyResult<PyObject> {
+        let out_dict = PyDict::new(py);
+        for (name, column) in self.columns.iter() {
+            out_dict.set_item(name, (column.dtype(), column.to_list(py, indices)))?;
+        }
+        Ok(out_dict.into())
+    }
+
+    /// Set the columns from a dict returned by [`Columns::to_dict`], with
+    /// the values at ``indices``.
+    fn set_dict(&mut self, dict: &PyDict, indices: &[usize]) -> PyResult<()> {
+        for (name, raw_column) in dict.iter() {
+            let (dtype, raw_values): (&str, &PyAny) = raw_column.extract()?;
+            let column = match dtype {
+                "float64" => {
+                    let mut values = Vec::new();
+                    put(
+                        &mut values,
+                        indices,
+                        &raw_values.extract::<Vec<f64>>()?,
+                        f64::NAN,
+                    );
+                    Column::Float64(values)
+                }
+                "int64" => {
+                    let mut values = Vec::new();
+                    put(&mut values, indices, &raw_values.extract::<Vec<i64>>()?, 0);
+                    Column::Int64(values)
+                }
+                "bool" => {
+                    let mut values = Vec::new();
+                    put(
+                        &mut values,
+                        indices,
+                        &raw_values.extract::<Vec<bool>>()?,
+                        false,
+                    );
+                    Column::Bool(values)
+                }
+                _ => {
+                    return Err(PyValueError::new_err(format!(
+                        "Invalid attribute dtype {}",
+                        dtype
+                    )))
+                }
+            };
+            self.columns.insert(name.extract()?, column);
+        }
+        Ok(())
+    }
+
+    fn reset(&mut self, index: usize) {
+        for column in self.columns.values_mut() {
+            column.reset(index);
+        }
+    }
+
+    fn swap(&mut self, a: usize, b: usize) {
+        for column in self.columns.values_mut() {
+            column.swap(a, b);
+        }
+    }
+
+    fn compact(&mut self, indices: &[usize]) {
+        for column in self.columns.values_mut() {
+            column.compact(indices);
+        }
+    }
+
+    fn retain<F: Fn(usize) -> bool>(&mut self, keep: F) {
+        for column in self.columns.values_mut() {
+            for index in (0..column.len()).filter(|index| !keep(*index)) {
+                column.reset(index);
+            }
+        }
+    }
+}
+
+/// Return the indices of ``nodes``, or of every node of ``graph`` if not
+/// specified, raises ``InvalidNode`` for a node missing from ``graph``.
+pub fn node_positions<Ty: EdgeType>(
+    graph: &StablePyGraph<Ty>,
+    nodes: Option<Vec<usize>>,
+) -> PyResult<Vec<usize>> {
+    match nodes {
+        Some(nodes) => {
+            for node in nodes.iter() {
+                if !graph.contains_node(NodeIndex::new(*node)) {
+                    return Err(InvalidNode::new_err(format!(
+                        "Node index {} is not in the graph",
+                        node
+                    )));
+                }
+            }
+            Ok(nodes)
+        }
+        None => Ok(graph.node_indices().map(|node| node.index()).collect()),
+    }
+}
+
+/// Return the indices of ``edges``, or of every edge of ``graph`` if not
+/// specified, raises an ``IndexError`` for an edge missing from ``graph``.
+pub fn edge_positions<Ty: EdgeType>(
+    graph: &StablePyGraph<Ty>,
+    edges: Option<Vec<usize>>,
+) -> PyResult<Vec<usize>> {
+    match edges {
+        Some(edges) => {
+            for edge in edges.iter() {
+                if graph.edge_weight(EdgeIndex::new(*edge)).is_none() {
+                    return Err(PyIndexError::new_err(format!(
+                        "Provided edge index {} is not present in the graph",
+                        edge
+                    )));
+                }
+            }
+            Ok(edges)
+        }
+        None => Ok(graph.edge_indices().map(|edge| edge.index()).collect()),
+    }
+}
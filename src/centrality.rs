@@ -10,14 +10,23 @@
 // License for the specific language governing permissions and limitations
 // under the License.
 
+use crate::algorithm_info::{explained, AlgorithmInfo};
+use crate::attributes::AttributeColumns;
 use crate::iterators::CentralityMapping;
+use crate::{digraph, graph, weight_callable, CostFn, FailedToConverge, StablePyGraph};
 
-use crate::digraph;
-use crate::graph;
+use hashbrown::HashMap;
 
+use petgraph::graph::NodeIndex;
+use petgraph::visit::NodeIndexable;
+use petgraph::EdgeType;
+
+use pyo3::exceptions::{PyIndexError, PyValueError};
 use pyo3::prelude::*;
+use pyo3::Python;
 
 use retworkx_core::centrality;
+use retworkx_core::Convergence;
 
 /// Compute the betweenness centrality of all nodes in a PyGraph.
 ///
@@ -132,3 +141,1321 @@ pub fn digraph_betweenness_centrality(
             .collect(),
     }
 }
+
+/// Convert the output of an iterative centrality algorithm into a
+/// ``CentralityMapping`` and complete its ``AlgorithmInfo``, or raise
+/// ``FailedToConverge``.
+fn centrality_mapping<Ty: EdgeType>(
+    graph: &StablePyGraph<Ty>,
+    (centralities, convergence): (Vec<f64>, Convergence),
+    info: AlgorithmInfo,
+    name: &str,
+    max_iter: usize,
+    tol: f64,
+) -> PyResult<(CentralityMapping, AlgorithmInfo)> {
+    if !convergence.converged {
+        return Err(FailedToConverge::new_err(format!(
+            "{} centrality failed to converge in {} iterations",
+            name, max_iter
+        )));
+    }
+    let centralities = CentralityMapping {
+        centralities: graph
+            .node_indices()
+            .map(|n| (n.index(), centralities[n.index()]))
+            .collect(),
+    };
+    Ok((centralities, info.convergence(convergence, tol)))
+}
+
+#[allow(clippy::too_many_arguments)]
+fn eigenvector_centrality<Ty: EdgeType>(
+    py: Python,
+    graph: &StablePyGraph<Ty>,
+    attributes: &AttributeColumns,
+    weight_fn: Option<PyObject>,
+    weight_attr: Option<&str>,
+    default_weight: f64,
+    max_iter: usize,
+    tol: f64,
+) -> PyResult<(CentralityMapping, AlgorithmInfo)> {
+    let info = AlgorithmInfo::new(
+        "eigenvector_centrality",
+        "power iteration on the adjacency matrix shifted by the identity",
+    )
+    .parameter(py, "default_weight", default_weight)
+    .parameter(py, "weight_attr", weight_attr)
+    .parameter(py, "max_iter", max_iter)
+    .parameter(py, "tol", tol);
+    let cost_fn = CostFn::from_weight_spec(
+        py,
+        graph,
+        attributes,
+        weight_fn,
+        weight_attr,
+        default_weight,
+    )?;
+    let centralities = centrality::eigenvector_centrality_with_convergence(
+        graph,
+        |e| cost_fn.call_edge(py, e),
+        max_iter,
+        tol,
+    )?;
+    centrality_mapping(graph, centralities, info, "Eigenvector", max_iter, tol)
+}
+
+#[allow(clippy::too_many_arguments)]
+fn katz_centrality<Ty: EdgeType>(
+    py: Python,
+    graph: &StablePyGraph<Ty>,
+    attributes: &AttributeColumns,
+    alpha: f64,
+    beta: Option<PyObject>,
+    weight_fn: Option<PyObject>,
+    weight_attr: Option<&str>,
+    default_weight: f64,
+    max_iter: usize,
+    tol: f64,
+    normalized: bool,
+) -> PyResult<(CentralityMapping, AlgorithmInfo)> {
+    let info = AlgorithmInfo::new("katz_centrality", "power iteration")
+        .parameter(py, "alpha", alpha)
+        .parameter(py, "beta", beta.clone().unwrap_or_else(|| 1.0.into_py(py)))
+        .parameter(py, "default_weight", default_weight)
+        .parameter(py, "weight_attr", weight_attr)
+        .parameter(py, "max_iter", max_iter)
+        .parameter(py, "tol", tol)
+        .parameter(py, "normalized", normalized);
+    let cost_fn = CostFn::from_weight_spec(
+        py,
+        graph,
+        attributes,
+        weight_fn,
+        weight_attr,
+        default_weight,
+    )?;
+    let mut beta_scalar = 1.0;
+    let mut beta_map: Option<Vec<f64>> = None;
+    if let Some(beta) = beta {
+        match beta.extract::<f64>(py) {
+            Ok(beta) => beta_scalar = beta,
+            Err(_) => {
+                let values: HashMap<usize, f64> = beta.extract(py)?;
+                let mut out = vec![0.0; graph.node_bound()];
+                for node in graph.node_indices() {
+                    out[node.index()] = *values.get(&node.index()).ok_or_else(|| {
+                        PyValueError::new_err(format!(
+                            "beta has no value for node {}",
+                            node.index()
+                        ))
+                    })?;
+                }
+                beta_map = Some(out);
+            }
+        }
+    }
+    let centralities = centrality::katz_centrality_with_convergence(
+        graph,
+        |e| cost_fn.call_edge(py, e),
+        alpha,
+        beta_map.as_deref(),
+        beta_scalar,
+        max_iter,
+        tol,
+        normalized,
+    )?;
+    centrality_mapping(graph, centralities, info, "Katz", max_iter, tol)
+}
+
+/// Compute the eigenvector centrality of all nodes in a PyGraph.
+///
+/// The eigenvector centrality of a node :math:`v` is proportional to the sum
+/// of the centralities of the nodes linking to it, weighted by the edge
+/// weights:
+///
+/// .. math::
+///
+///     x_v = \frac{1}{\lambda} \sum_{u} A_{uv} x_u
+///
+/// where :math:`A` is the weighted adjacency matrix of the graph and
+/// :math:`\lambda` its largest eigenvalue [1]_. The centralities are
+/// computed with power iteration on :math:`A + I` so the iteration also
+/// converges on bipartite graphs.
+///
+/// :param PyGraph graph: The graph to compute the eigenvector centrality of
+/// :param weight_fn: An optional input callable that will be passed the edge's
+///     payload object and is expected to return a non-negative ``float``
+///     weight for that edge. If this is not specified ``default_weight`` will
+///     be used as the weight for every edge in ``graph``. The weights of
+///     parallel edges are added.
+/// :param float default_weight: If ``weight_fn`` is not set the default weight
+///     value to use for the weight of all edges
+/// :param str weight_attr: An optional name of an edge attribute with the
+///     weight of the edges, as an alternative to ``weight_fn`` which doesn't
+///     call into Python for every edge. The weights are read from the edge
+///     attribute column with this name if the graph has one, see
+///     :meth:`~retworkx.PyGraph.set_edge_attribute`, otherwise from this key
+///     of the edges with a dict payload. Edges without a weight use
+///     ``default_weight``.
+/// :param int max_iter: The maximum number of iterations of the power method
+/// :param float tol: The error tolerance used when checking for convergence,
+///     the iteration stops once the change of the centralities sums to less
+///     than the number of nodes times ``tol``.
+/// :param bool explain: If set to ``True`` an
+///     :class:`~retworkx.AlgorithmInfo` recording how the algorithm was run
+///     is returned along with the result
+///
+/// :returns: a read-only dict-like object whose keys are the node indices and
+///     values are the centrality of each node, normalized to a euclidean norm
+///     of 1.
+///     If ``explain`` is ``True`` a tuple of this and an
+///     :class:`~retworkx.AlgorithmInfo` is returned instead.
+/// :rtype: CentralityMapping
+/// :raises FailedToConverge: If the power iteration doesn't converge within
+///     ``max_iter`` iterations
+/// :raises ValueError: If both ``weight_fn`` and ``weight_attr`` are
+///     specified
+///
+/// .. [1] M. E. J. Newman, "Networks: An Introduction." Oxford University
+///     Press, 2010, pp. 169.
+#[pyfunction(
+    default_weight = "1.0",
+    max_iter = "100",
+    tol = "1e-6",
+    explain = "false"
+)]
+#[pyo3(
+    text_signature = "(graph, /, weight_fn=None, default_weight=1.0, max_iter=100, tol=1e-6, explain=False, weight_attr=None)"
+)]
+#[allow(clippy::too_many_arguments)]
+pub fn graph_eigenvector_centrality(
+    py: Python,
+    graph: &graph::PyGraph,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+    max_iter: usize,
+    tol: f64,
+    explain: bool,
+    weight_attr: Option<&str>,
+) -> PyResult<PyObject> {
+    let (centralities, info) = eigenvector_centrality(
+        py,
+        &graph.graph,
+        &graph.attributes,
+        weight_fn,
+        weight_attr,
+        default_weight,
+        max_iter,
+        tol,
+    )?;
+    explained(py, centralities, info, explain)
+}
+
+/// Compute the eigenvector centrality of all nodes in a PyDiGraph.
+///
+/// The eigenvector centrality of a node :math:`v` is proportional to the sum
+/// of the centralities of the nodes linking to it, weighted by the edge
+/// weights:
+///
+/// .. math::
+///
+///     x_v = \frac{1}{\lambda} \sum_{u} A_{uv} x_u
+///
+/// where :math:`A` is the weighted adjacency matrix of the graph and
+/// :math:`\lambda` its largest eigenvalue [1]_. For a directed graph the
+/// centrality of a node is based on its incoming edges. The centralities are
+/// computed with power iteration on :math:`A + I` so the iteration also
+/// converges on bipartite graphs.
+///
+/// :param PyDiGraph graph: The graph to compute the eigenvector centrality of
+/// :param weight_fn: An optional input callable that will be passed the edge's
+///     payload object and is expected to return a non-negative ``float``
+///     weight for that edge. If this is not specified ``default_weight`` will
+///     be used as the weight for every edge in ``graph``. The weights of
+///     parallel edges are added.
+/// :param float default_weight: If ``weight_fn`` is not set the default weight
+///     value to use for the weight of all edges
+/// :param str weight_attr: An optional name of an edge attribute with the
+///     weight of the edges, as an alternative to ``weight_fn`` which doesn't
+///     call into Python for every edge. The weights are read from the edge
+///     attribute column with this name if the graph has one, see
+///     :meth:`~retworkx.PyDiGraph.set_edge_attribute`, otherwise from this key
+///     of the edges with a dict payload. Edges without a weight use
+///     ``default_weight``.
+/// :param int max_iter: The maximum number of iterations of the power method
+/// :param float tol: The error tolerance used when checking for convergence,
+///     the iteration stops once the change of the centralities sums to less
+///     than the number of nodes times ``tol``.
+/// :param bool explain: If set to ``True`` an
+///     :class:`~retworkx.AlgorithmInfo` recording how the algorithm was run
+///     is returned along with the result
+///
+/// :returns: a read-only dict-like object whose keys are the node indices and
+///     values are the centrality of each node, normalized to a euclidean norm
+///     of 1.
+///     If ``explain`` is ``True`` a tuple of this and an
+///     :class:`~retworkx.AlgorithmInfo` is returned instead.
+/// :rtype: CentralityMapping
+/// :raises FailedToConverge: If the power iteration doesn't converge within
+///     ``max_iter`` iterations
+/// :raises ValueError: If both ``weight_fn`` and ``weight_attr`` are
+///     specified
+///
+/// .. [1] M. E. J. Newman, "Networks: An Introduction." Oxford University
+///     Press, 2010, pp. 169.
+#[pyfunction(
+    default_weight = "1.0",
+    max_iter = "100",
+    tol = "1e-6",
+    explain = "false"
+)]
+#[pyo3(
+    text_signature = "(graph, /, weight_fn=None, default_weight=1.0, max_iter=100, tol=1e-6, explain=False, weight_attr=None)"
+)]
+#[allow(clippy::too_many_arguments)]
+pub fn digraph_eigenvector_centrality(
+    py: Python,
+    graph: &digraph::PyDiGraph,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+    max_iter: usize,
+    tol: f64,
+    explain: bool,
+    weight_attr: Option<&str>,
+) -> PyResult<PyObject> {
+    let (centralities, info) = eigenvector_centrality(
+        py,
+        &graph.graph,
+        &graph.attributes,
+        weight_fn,
+        weight_attr,
+        default_weight,
+        max_iter,
+        tol,
+    )?;
+    explained(py, centralities, info, explain)
+}
+
+/// Compute the Katz centrality of all nodes in a PyGraph.
+///
+/// The Katz centrality of a node counts the walks ending at the node, with
+/// the walks of length :math:`k` attenuated by a factor of
+/// :math:`\alpha^k`, plus a base centrality :math:`\beta` of every
+/// node [1]_:
+///
+/// .. math::
+///
+///     x_v = \alpha \sum_{u} A_{uv} x_u + \beta_v
+///
+/// where :math:`A` is the weighted adjacency matrix of the graph. The
+/// centralities are computed with power iteration, which only converges if
+/// ``alpha`` is less than the inverse of the largest eigenvalue of
+/// :math:`A`.
+///
+/// :param PyGraph graph: The graph to compute the Katz centrality of
+/// :param float alpha: The attenuation factor
+/// :param beta: The base centrality of every node, either a ``float`` used
+///     for all nodes or a dictionary of node indices to the base centrality of
+///     that node which must contain every node in ``graph``. If not specified
+///     a base centrality of ``1.0`` is used.
+/// :param weight_fn: An optional input callable that will be passed the edge's
+///     payload object and is expected to return a ``float`` weight for that
+///     edge. If this is not specified ``default_weight`` will be used as the
+///     weight for every edge in ``graph``. The weights of parallel edges are
+///     added.
+/// :param float default_weight: If ``weight_fn`` is not set the default weight
+///     value to use for the weight of all edges
+/// :param str weight_attr: An optional name of an edge attribute with the
+///     weight of the edges, as an alternative to ``weight_fn`` which doesn't
+///     call into Python for every edge. The weights are read from the edge
+///     attribute column with this name if the graph has one, see
+///     :meth:`~retworkx.PyGraph.set_edge_attribute`, otherwise from this key
+///     of the edges with a dict payload. Edges without a weight use
+///     ``default_weight``.
+/// :param int max_iter: The maximum number of iterations of the power method
+/// :param float tol: The error tolerance used when checking for convergence,
+///     the iteration stops once the change of the centralities sums to less
+///     than the number of nodes times ``tol``.
+/// :param bool normalized: If set to ``True`` the centralities are normalized
+///     to a euclidean norm of 1.
+/// :param bool explain: If set to ``True`` an
+///     :class:`~retworkx.AlgorithmInfo` recording how the algorithm was run
+///     is returned along with the result
+///
+/// :returns: a read-only dict-like object whose keys are the node indices and
+///     values are the centrality of each node.
+///     If ``explain`` is ``True`` a tuple of this and an
+///     :class:`~retworkx.AlgorithmInfo` is returned instead.
+/// :rtype: CentralityMapping
+/// :raises FailedToConverge: If the power iteration doesn't converge within
+///     ``max_iter`` iterations
+/// :raises ValueError: If ``beta`` is a dictionary missing a node of ``graph``
+///     or if both ``weight_fn`` and ``weight_attr`` are specified
+///
+/// .. [1] Leo Katz, "A New Status Index Derived from Sociometric Index."
+///     Psychometrika 18(1):39-43, 1953.
+#[pyfunction(
+    alpha = "0.1",
+    default_weight = "1.0",
+    max_iter = "1000",
+    tol = "1e-6",
+    normalized = "true",
+    explain = "false"
+)]
+#[pyo3(
+    text_signature = "(graph, /, alpha=0.1, beta=1.0, weight_fn=None, default_weight=1.0, max_iter=1000, tol=1e-6, normalized=True, explain=False, weight_attr=None)"
+)]
+#[allow(clippy::too_many_arguments)]
+pub fn graph_katz_centrality(
+    py: Python,
+    graph: &graph::PyGraph,
+    alpha: f64,
+    beta: Option<PyObject>,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+    max_iter: usize,
+    tol: f64,
+    normalized: bool,
+    explain: bool,
+    weight_attr: Option<&str>,
+) -> PyResult<PyObject> {
+    let (centralities, info) = katz_centrality(
+        py,
+        &graph.graph,
+        &graph.attributes,
+        alpha,
+        beta,
+        weight_fn,
+        weight_attr,
+        default_weight,
+        max_iter,
+        tol,
+        normalized,
+    )?;
+    explained(py, centralities, info, explain)
+}
+
+/// Compute the Katz centrality of all nodes in a PyDiGraph.
+///
+/// The Katz centrality of a node counts the walks ending at the node, with
+/// the walks of length :math:`k` attenuated by a factor of
+/// :math:`\alpha^k`, plus a base centrality :math:`\beta` of every
+/// node [1]_:
+///
+/// .. math::
+///
+///     x_v = \alpha \sum_{u} A_{uv} x_u + \beta_v
+///
+/// where :math:`A` is the weighted adjacency matrix of the graph. The
+/// centralities are computed with power iteration, which only converges if
+/// ``alpha`` is less than the inverse of the largest eigenvalue of
+/// :math:`A`. For a directed graph the centrality of a node is based on its
+/// incoming edges.
+///
+/// :param PyDiGraph graph: The graph to compute the Katz centrality of
+/// :param float alpha: The attenuation factor
+/// :param beta: The base centrality of every node, either a ``float`` used
+///     for all nodes or a dictionary of node indices to the base centrality of
+///     that node which must contain every node in ``graph``. If not specified
+///     a base centrality of ``1.0`` is used.
+/// :param weight_fn: An optional input callable that will be passed the edge's
+///     payload object and is expected to return a ``float`` weight for that
+///     edge. If this is not specified ``default_weight`` will be used as the
+///     weight for every edge in ``graph``. The weights of parallel edges are
+///     added.
+/// :param float default_weight: If ``weight_fn`` is not set the default weight
+///     value to use for the weight of all edges
+/// :param str weight_attr: An optional name of an edge attribute with the
+///     weight of the edges, as an alternative to ``weight_fn`` which doesn't
+///     call into Python for every edge. The weights are read from the edge
+///     attribute column with this name if the graph has one, see
+///     :meth:`~retworkx.PyDiGraph.set_edge_attribute`, otherwise from this key
+///     of the edges with a dict payload. Edges without a weight use
+///     ``default_weight``.
+/// :param int max_iter: The maximum number of iterations of the power method
+/// :param float tol: The error tolerance used when checking for convergence,
+///     the iteration stops once the change of the centralities sums to less
+///     than the number of nodes times ``tol``.
+/// :param bool normalized: If set to ``True`` the centralities are normalized
+///     to a euclidean norm of 1.
+/// :param bool explain: If set to ``True`` an
+///     :class:`~retworkx.AlgorithmInfo` recording how the algorithm was run
+///     is returned along with the result
+///
+/// :returns: a read-only dict-like object whose keys are the node indices and
+///     values are the centrality of each node.
+///     If ``explain`` is ``True`` a tuple of this and an
+///     :class:`~retworkx.AlgorithmInfo` is returned instead.
+/// :rtype: CentralityMapping
+/// :raises FailedToConverge: If the power iteration doesn't converge within
+///     ``max_iter`` iterations
+/// :raises ValueError: If ``beta`` is a dictionary missing a node of ``graph``
+///     or if both ``weight_fn`` and ``weight_attr`` are specified
+///
+/// .. [1] Leo Katz, "A New Status Index Derived from Sociometric Index."
+///     Psychometrika 18(1):39-43, 1953.
+#[pyfunction(
+    alpha = "0.1",
+    default_weight = "1.0",
+    max_iter = "1000",
+    tol = "1e-6",
+    normalized = "true",
+    explain = "false"
+)]
+#[pyo3(
+    text_signature = "(graph, /, alpha=0.1, beta=1.0, weight_fn=None, default_weight=1.0, max_iter=1000, tol=1e-6, normalized=True, explain=False, weight_attr=None)"
+)]
+#[allow(clippy::too_many_arguments)]
+pub fn digraph_katz_centrality(
+    py: Python,
+    graph: &digraph::PyDiGraph,
+    alpha: f64,
+    beta: Option<PyObject>,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+    max_iter: usize,
+    tol: f64,
+    normalized: bool,
+    explain: bool,
+    weight_attr: Option<&str>,
+) -> PyResult<PyObject> {
+    let (centralities, info) = katz_centrality(
+        py,
+        &graph.graph,
+        &graph.attributes,
+        alpha,
+        beta,
+        weight_fn,
+        weight_attr,
+        default_weight,
+        max_iter,
+        tol,
+        normalized,
+    )?;
+    explained(py, centralities, info, explain)
+}
+
+/// Convert a centrality indexed by node index, with ``None`` for the removed
+/// indices, into a ``CentralityMapping``.
+fn node_centrality_mapping(centralities: Vec<Option<f64>>) -> CentralityMapping {
+    CentralityMapping {
+        centralities: centralities
+            .into_iter()
+            .enumerate()
+            .filter_map(|(i, v)| v.map(|x| (i, x)))
+            .collect(),
+    }
+}
+
+fn closeness_centrality<Ty: EdgeType>(
+    py: Python,
+    graph: &StablePyGraph<Ty>,
+    wf_improved: bool,
+    weight_fn: Option<PyObject>,
+    parallel_threshold: usize,
+) -> PyResult<CentralityMapping> {
+    let centralities = match weight_fn {
+        Some(weight_fn) => {
+            let cost_fn = CostFn::from(weight_fn);
+            centrality::weighted_closeness_centrality(
+                graph,
+                |e| cost_fn.call(py, e.weight()),
+                wf_improved,
+                parallel_threshold,
+            )?
+        }
+        None => centrality::closeness_centrality(graph, wf_improved, parallel_threshold),
+    };
+    Ok(node_centrality_mapping(centralities))
+}
+
+fn harmonic_centrality<Ty: EdgeType>(
+    py: Python,
+    graph: &StablePyGraph<Ty>,
+    weight_fn: Option<PyObject>,
+    parallel_threshold: usize,
+) -> PyResult<CentralityMapping> {
+    let centralities = match weight_fn {
+        Some(weight_fn) => {
+            let cost_fn = CostFn::from(weight_fn);
+            centrality::weighted_harmonic_centrality(
+                graph,
+                |e| cost_fn.call(py, e.weight()),
+                parallel_threshold,
+            )?
+        }
+        None => centrality::harmonic_centrality(graph, parallel_threshold),
+    };
+    Ok(node_centrality_mapping(centralities))
+}
+
+/// Compute the closeness centrality of all nodes in a PyGraph.
+///
+/// The closeness centrality of a node :math:`u` is the reciprocal of the
+/// average shortest path distance to :math:`u` over all the nodes which can
+/// reach it:
+///
+/// .. math::
+///
+///     C(u) = \frac{n - 1}{\sum_{v=1}^{n-1} d(v, u)}
+///
+/// where :math:`d(v, u)` is the shortest path distance from :math:`v` to
+/// :math:`u` and :math:`n` is the number of nodes which can reach :math:`u`
+/// [1]_. If the graph isn't connected the Wasserman and Faust
+/// improved formula [2]_ scales the centrality by :math:`(n - 1) / (N - 1)`,
+/// where :math:`N` is the number of nodes in the graph, so nodes in smaller
+/// components don't get a higher centrality.
+///
+/// This function is multithreaded and will run in parallel if the number
+/// of nodes in the graph is above the value of ``parallel_threshold`` (it
+/// defaults to 50). If the function will be running in parallel the env var
+/// ``RAYON_NUM_THREADS`` can be used to adjust how many threads will be used.
+///
+/// :param PyGraph graph: The input graph
+/// :param bool wf_improved: Whether to use the Wasserman and Faust improved
+///     formula
+/// :param weight_fn: An optional input callable that will be passed the edge's
+///     payload object and is expected to return a non-negative ``float``
+///     weight for that edge. If this is specified the distances are the
+///     weighted shortest path lengths found with Dijkstra's algorithm,
+///     otherwise they're the number of edges on the shortest paths.
+/// :param int parallel_threshold: The number of nodes to calculate the
+///     the closeness centrality in parallel at if the number of nodes in
+///     the graph is less than this value it will run in a single thread. The
+///     default value is 50
+///
+/// :returns: a read-only dict-like object whose keys are the node indices and
+///     values are the closeness centrality of each node.
+/// :rtype: CentralityMapping
+///
+/// .. [1] Linton C. Freeman, "Centrality in networks: I. Conceptual
+///     clarification." Social Networks 1(3):215-239, 1979.
+/// .. [2] Stanley Wasserman and Katherine Faust, "Social Network Analysis:
+///     Methods and Applications." Cambridge University Press, 1994, pp. 201.
+#[pyfunction(wf_improved = "true", parallel_threshold = "50")]
+#[pyo3(text_signature = "(graph, /, wf_improved=True, weight_fn=None, parallel_threshold=50)")]
+pub fn graph_closeness_centrality(
+    py: Python,
+    graph: &graph::PyGraph,
+    wf_improved: bool,
+    weight_fn: Option<PyObject>,
+    parallel_threshold: usize,
+) -> PyResult<CentralityMapping> {
+    closeness_centrality(py, &graph.graph, wf_improved, weight_fn, parallel_threshold)
+}
+
+/// Compute the closeness centrality of all nodes in a PyDiGraph.
+///
+/// The closeness centrality of a node :math:`u` is the reciprocal of the
+/// average shortest path distance to :math:`u` over all the nodes which can
+/// reach it:
+///
+/// .. math::
+///
+///     C(u) = \frac{n - 1}{\sum_{v=1}^{n-1} d(v, u)}
+///
+/// where :math:`d(v, u)` is the shortest path distance from :math:`v` to
+/// :math:`u` and :math:`n` is the number of nodes which can reach :math:`u`
+/// [1]_. For a directed graph the incoming distances are used. If the graph
+/// isn't connected the Wasserman and Faust improved formula [2]_ scales the
+/// centrality by :math:`(n - 1) / (N - 1)`, where :math:`N` is the number of
+/// nodes in the graph, so nodes in smaller components don't get a higher
+/// centrality.
+///
+/// This function is multithreaded and will run in parallel if the number
+/// of nodes in the graph is above the value of ``parallel_threshold`` (it
+/// defaults to 50). If the function will be running in parallel the env var
+/// ``RAYON_NUM_THREADS`` can be used to adjust how many threads will be used.
+///
+/// :param PyDiGraph graph: The input graph
+/// :param bool wf_improved: Whether to use the Wasserman and Faust improved
+///     formula
+/// :param weight_fn: An optional input callable that will be passed the edge's
+///     payload object and is expected to return a non-negative ``float``
+///     weight for that edge. If this is specified the distances are the
+///     weighted shortest path lengths found with Dijkstra's algorithm,
+///     otherwise they're the number of edges on the shortest paths.
+/// :param int parallel_threshold: The number of nodes to calculate the
+///     the closeness centrality in parallel at if the number of nodes in
+///     the graph is less than this value it will run in a single thread. The
+///     default value is 50
+///
+/// :returns: a read-only dict-like object whose keys are the node indices and
+///     values are the closeness centrality of each node.
+/// :rtype: CentralityMapping
+///
+/// .. [1] Linton C. Freeman, "Centrality in networks: I. Conceptual
+///     clarification." Social Networks 1(3):215-239, 1979.
+/// .. [2] Stanley Wasserman and Katherine Faust, "Social Network Analysis:
+///     Methods and Applications." Cambridge University Press, 1994, pp. 201.
+#[pyfunction(wf_improved = "true", parallel_threshold = "50")]
+#[pyo3(text_signature = "(graph, /, wf_improved=True, weight_fn=None, parallel_threshold=50)")]
+pub fn digraph_closeness_centrality(
+    py: Python,
+    graph: &digraph::PyDiGraph,
+    wf_improved: bool,
+    weight_fn: Option<PyObject>,
+    parallel_threshold: usize,
+) -> PyResult<CentralityMapping> {
+    closeness_centrality(py, &graph.graph, wf_improved, weight_fn, parallel_threshold)
+}
+
+/// Compute the harmonic centrality of all nodes in a PyGraph.
+///
+/// The harmonic centrality of a node :math:`u` is the sum of the reciprocals
+/// of the shortest path distances from all the other nodes to :math:`u`:
+///
+/// .. math::
+///
+///     C(u) = \sum_{v \neq u} \frac{1}{d(v, u)}
+///
+/// where :math:`d(v, u)` is the shortest path distance from :math:`v` to
+/// :math:`u` [1]_. Nodes which can't reach :math:`u` contribute 0, so it's
+/// well defined for graphs which aren't connected.
+///
+/// This function is multithreaded and will run in parallel if the number
+/// of nodes in the graph is above the value of ``parallel_threshold`` (it
+/// defaults to 50). If the function will be running in parallel the env var
+/// ``RAYON_NUM_THREADS`` can be used to adjust how many threads will be used.
+///
+/// :param PyGraph graph: The input graph
+/// :param weight_fn: An optional input callable that will be passed the edge's
+///     payload object and is expected to return a non-negative ``float``
+///     weight for that edge. If this is specified the distances are the
+///     weighted shortest path lengths found with Dijkstra's algorithm,
+///     otherwise they're the number of edges on the shortest paths.
+/// :param int parallel_threshold: The number of nodes to calculate the
+///     the harmonic centrality in parallel at if the number of nodes in
+///     the graph is less than this value it will run in a single thread. The
+///     default value is 50
+///
+/// :returns: a read-only dict-like object whose keys are the node indices and
+///     values are the harmonic centrality of each node.
+/// :rtype: CentralityMapping
+///
+/// .. [1] Paolo Boldi and Sebastiano Vigna, "Axioms for centrality."
+///     Internet Mathematics 10(3-4):222-262, 2014.
+#[pyfunction(parallel_threshold = "50")]
+#[pyo3(text_signature = "(graph, /, weight_fn=None, parallel_threshold=50)")]
+pub fn graph_harmonic_centrality(
+    py: Python,
+    graph: &graph::PyGraph,
+    weight_fn: Option<PyObject>,
+    parallel_threshold: usize,
+) -> PyResult<CentralityMapping> {
+    harmonic_centrality(py, &graph.graph, weight_fn, parallel_threshold)
+}
+
+/// Compute the harmonic centrality of all nodes in a PyDiGraph.
+///
+/// The harmonic centrality of a node :math:`u` is the sum of the reciprocals
+/// of the shortest path distances from all the other nodes to :math:`u`:
+///
+/// .. math::
+///
+///     C(u) = \sum_{v \neq u} \frac{1}{d(v, u)}
+///
+/// where :math:`d(v, u)` is the shortest path distance from :math:`v` to
+/// :math:`u` [1]_. Nodes which can't reach :math:`u` contribute 0, so it's
+/// well defined for graphs which aren't connected. For a directed graph
+/// the incoming distances are used.
+///
+/// This function is multithreaded and will run in parallel if the number
+/// of nodes in the graph is above the value of ``parallel_threshold`` (it
+/// defaults to 50). If the function will be running in parallel the env var
+/// ``RAYON_NUM_THREADS`` can be used to adjust how many threads will be used.
+///
+/// :param PyDiGraph graph: The input graph
+/// :param weight_fn: An optional input callable that will be passed the edge's
+///     payload object and is expected to return a non-negative ``float``
+///     weight for that edge. If this is specified the distances are the
+///     weighted shortest path lengths found with Dijkstra's algorithm,
+///     otherwise they're the number of edges on the shortest paths.
+/// :param int parallel_threshold: The number of nodes to calculate the
+///     the harmonic centrality in parallel at if the number of nodes in
+///     the graph is less than this value it will run in a single thread. The
+///     default value is 50
+///
+/// :returns: a read-only dict-like object whose keys are the node indices and
+///     values are the harmonic centrality of each node.
+/// :rtype: CentralityMapping
+///
+/// .. [1] Paolo Boldi and Sebastiano Vigna, "Axioms for centrality."
+///     Internet Mathematics 10(3-4):222-262, 2014.
+#[pyfunction(parallel_threshold = "50")]
+#[pyo3(text_signature = "(graph, /, weight_fn=None, parallel_threshold=50)")]
+pub fn digraph_harmonic_centrality(
+    py: Python,
+    graph: &digraph::PyDiGraph,
+    weight_fn: Option<PyObject>,
+    parallel_threshold: usize,
+) -> PyResult<CentralityMapping> {
+    harmonic_centrality(py, &graph.graph, weight_fn, parallel_threshold)
+}
+
+fn closeness_vitality<Ty: EdgeType>(
+    py: Python,
+    graph: &StablePyGraph<Ty>,
+    weight_fn: Option<PyObject>,
+) -> PyResult<CentralityMapping> {
+    let vitalities = match weight_fn {
+        Some(weight_fn) => {
+            let cost_fn = CostFn::from(weight_fn);
+            centrality::weighted_closeness_vitality(graph, |e| cost_fn.call(py, e.weight()))?
+        }
+        None => centrality::closeness_vitality(graph),
+    };
+    Ok(node_centrality_mapping(vitalities))
+}
+
+/// Compute the closeness vitality of all nodes in a PyGraph.
+///
+/// The closeness vitality of a node is the decrease of the Wiener index of
+/// the graph, the sum of the shortest path distances between all pairs of
+/// nodes, when the node is removed [1]_. Every pair of nodes is counted once.
+/// If the graph isn't connected its Wiener index is infinite and the
+/// closeness vitality of every node is ``nan``, otherwise the closeness
+/// vitality of a node whose removal disconnects the graph is ``-inf``.
+///
+/// Removing a node only changes the distances from a source node if the
+/// removed node is the only predecessor of another node on the shortest
+/// paths from that source, so the shortest paths are only computed again
+/// for those sources.
+///
+/// :param PyGraph graph: The input graph
+/// :param weight_fn: An optional input callable that will be passed the edge's
+///     payload object and is expected to return a non-negative ``float``
+///     weight for that edge. If this is specified the distances are the
+///     weighted shortest path lengths found with Dijkstra's algorithm,
+///     otherwise they're the number of edges on the shortest paths.
+///
+/// :returns: a read-only dict-like object whose keys are the node indices and
+///     values are the closeness vitality of each node.
+/// :rtype: CentralityMapping
+///
+/// .. [1] Ulrik Brandes and Thomas Erlebach (Eds.), "Network Analysis:
+///     Methodological Foundations." Springer, 2005, pp. 36-37.
+#[pyfunction]
+#[pyo3(text_signature = "(graph, /, weight_fn=None)")]
+pub fn graph_closeness_vitality(
+    py: Python,
+    graph: &graph::PyGraph,
+    weight_fn: Option<PyObject>,
+) -> PyResult<CentralityMapping> {
+    closeness_vitality(py, &graph.graph, weight_fn)
+}
+
+/// Compute the closeness vitality of all nodes in a PyDiGraph.
+///
+/// The closeness vitality of a node is the decrease of the Wiener index of
+/// the graph, the sum of the shortest path distances between all ordered
+/// pairs of nodes, when the node is removed [1]_. If the graph isn't strongly
+/// connected its Wiener index is infinite and the closeness vitality of every
+/// node is ``nan``, otherwise the closeness vitality of a node whose removal
+/// leaves the graph not strongly connected is ``-inf``.
+///
+/// Removing a node only changes the distances from a source node if the
+/// removed node is the only predecessor of another node on the shortest
+/// paths from that source, so the shortest paths are only computed again
+/// for those sources.
+///
+/// :param PyDiGraph graph: The input graph
+/// :param weight_fn: An optional input callable that will be passed the edge's
+///     payload object and is expected to return a non-negative ``float``
+///     weight for that edge. If this is specified the distances are the
+///     weighted shortest path lengths found with Dijkstra's algorithm,
+///     otherwise they're the number of edges on the shortest paths.
+///
+/// :returns: a read-only dict-like object whose keys are the node indices and
+///     values are the closeness vitality of each node.
+/// :rtype: CentralityMapping
+///
+/// .. [1] Ulrik Brandes and Thomas Erlebach (Eds.), "Network Analysis:
+///     Methodological Foundations." Springer, 2005, pp. 36-37.
+#[pyfunction]
+#[pyo3(text_signature = "(graph, /, weight_fn=None)")]
+pub fn digraph_closeness_vitality(
+    py: Python,
+    graph: &digraph::PyDiGraph,
+    weight_fn: Option<PyObject>,
+) -> PyResult<CentralityMapping> {
+    closeness_vitality(py, &graph.graph, weight_fn)
+}
+
+/// Compute the flow vitality of all nodes in a PyGraph.
+///
+/// The flow vitality of a node is the decrease of the sum of the maximum
+/// flows between all pairs of other nodes when the node is removed [1]_.
+///
+/// The maximum flows between all pairs of nodes are found from a flow
+/// equivalent tree built with Gusfield's algorithm [2]_, which only needs
+/// :math:`n - 1` maximum flow computations for a graph with :math:`n` nodes.
+///
+/// :param PyGraph graph: The input graph
+/// :param weight_fn: An optional input callable that will be passed the edge's
+///     payload object and is expected to return a non-negative ``int``
+///     capacity for that edge. If not specified every edge has a capacity of
+///     1, so the maximum flows are the local edge connectivities.
+///
+/// :returns: a read-only dict-like object whose keys are the node indices and
+///     values are the flow vitality of each node.
+/// :rtype: CentralityMapping
+///
+/// .. [1] Ulrik Brandes and Thomas Erlebach (Eds.), "Network Analysis:
+///     Methodological Foundations." Springer, 2005, pp. 37-38.
+/// .. [2] Dan Gusfield, "Very simple methods for all pairs network flow
+///     analysis." SIAM Journal on Computing 19(1):143-155, 1990.
+#[pyfunction]
+#[pyo3(text_signature = "(graph, /, weight_fn=None)")]
+pub fn flow_vitality(
+    py: Python,
+    graph: &graph::PyGraph,
+    weight_fn: Option<PyObject>,
+) -> PyResult<CentralityMapping> {
+    let vitalities = centrality::flow_vitality(&graph.graph, |e| {
+        weight_callable(py, &weight_fn, e.weight(), 1)
+    })?;
+    Ok(node_centrality_mapping(
+        vitalities
+            .into_iter()
+            .map(|v| v.map(|v| v as f64))
+            .collect(),
+    ))
+}
+
+fn betweenness_centrality_subset<Ty: EdgeType + Sync>(
+    py: Python,
+    graph: &StablePyGraph<Ty>,
+    sources: Vec<usize>,
+    targets: Vec<usize>,
+    normalized: bool,
+    weight_fn: Option<PyObject>,
+    parallel_threshold: usize,
+) -> PyResult<CentralityMapping> {
+    let to_nodes = |indices: Vec<usize>| -> PyResult<Vec<NodeIndex>> {
+        indices
+            .into_iter()
+            .map(|index| {
+                let node = NodeIndex::new(index);
+                if graph.contains_node(node) {
+                    Ok(node)
+                } else {
+                    Err(PyIndexError::new_err(format!(
+                        "No node found for index {}",
+                        index
+                    )))
+                }
+            })
+            .collect()
+    };
+    let sources = to_nodes(sources)?;
+    let targets = to_nodes(targets)?;
+    let betweenness = match weight_fn {
+        Some(weight_fn) => {
+            let cost_fn = CostFn::from(weight_fn);
+            centrality::weighted_betweenness_centrality_subset(
+                graph,
+                &sources,
+                &targets,
+                |e| cost_fn.call(py, e.weight()),
+                normalized,
+                parallel_threshold,
+            )?
+        }
+        None => centrality::betweenness_centrality_subset(
+            graph,
+            &sources,
+            &targets,
+            normalized,
+            parallel_threshold,
+        ),
+    };
+    Ok(node_centrality_mapping(betweenness))
+}
+
+/// Compute the betweenness centrality of all nodes in a PyGraph counting only
+/// the shortest paths between a set of sources and a set of targets.
+///
+/// The subset betweenness centrality of a node :math:`v` is
+///
+/// .. math::
+///
+///    c_B(v) =\sum_{s \in S, t \in T} \frac{\sigma(s, t|v)}{\sigma(s, t)}
+///
+/// where :math:`S` is the set of sources, :math:`T` is the set of targets,
+/// :math:`\sigma(s, t)` is the number of shortest :math:`(s, t)` paths, and
+/// :math:`\sigma(s, t|v)` is the number of those paths passing through some
+/// node :math:`v` other than :math:`s, t` [1]_. It's computed with the same
+/// algorithm as :func:`~retworkx.graph_betweenness_centrality`.
+///
+/// This function is multithreaded and will run in parallel if the number
+/// of sources is above the value of ``parallel_threshold`` (it defaults to
+/// 50). If the function will be running in parallel the env var
+/// ``RAYON_NUM_THREADS`` can be used to adjust how many threads will be used.
+///
+/// :param PyGraph graph: The input graph
+/// :param list sources: The node indices the counted shortest paths start at
+/// :param list targets: The node indices the counted shortest paths end at
+/// :param bool normalized: Whether to normalize the betweenness scores by the
+///     number of distinct paths between all pairs of nodes.
+/// :param weight_fn: An optional input callable that will be passed the edge's
+///     payload object and is expected to return a non-negative ``float``
+///     weight for that edge. If this is specified the shortest paths are
+///     found with Dijkstra's algorithm using the weights, otherwise they're
+///     the paths with the fewest edges.
+/// :param int parallel_threshold: The number of sources to calculate the
+///     the betweenness centrality in parallel at if the number of sources is
+///     less than this value it will run in a single thread. The default value
+///     is 50
+///
+/// :returns: a read-only dict-like object whose keys are the node indices and
+///     values are the betweenness score for each node.
+/// :rtype: CentralityMapping
+/// :raises IndexError: If a source or a target isn't a node in ``graph``
+///
+/// .. [1] Ulrik Brandes, "On Variants of Shortest-Path Betweenness
+///     Centrality and their Generic Computation." Social Networks
+///     30(2):136-145, 2008.
+#[pyfunction(normalized = "false", parallel_threshold = "50")]
+#[pyo3(
+    text_signature = "(graph, sources, targets, /, normalized=False, weight_fn=None, parallel_threshold=50)"
+)]
+pub fn graph_betweenness_centrality_subset(
+    py: Python,
+    graph: &graph::PyGraph,
+    sources: Vec<usize>,
+    targets: Vec<usize>,
+    normalized: bool,
+    weight_fn: Option<PyObject>,
+    parallel_threshold: usize,
+) -> PyResult<CentralityMapping> {
+    betweenness_centrality_subset(
+        py,
+        &graph.graph,
+        sources,
+        targets,
+        normalized,
+        weight_fn,
+        parallel_threshold,
+    )
+}
+
+/// Compute the betweenness centrality of all nodes in a PyDiGraph counting only
+/// the shortest paths between a set of sources and a set of targets.
+///
+/// The subset betweenness centrality of a node :math:`v` is
+///
+/// .. math::
+///
+///    c_B(v) =\sum_{s \in S, t \in T} \frac{\sigma(s, t|v)}{\sigma(s, t)}
+///
+/// where :math:`S` is the set of sources, :math:`T` is the set of targets,
+/// :math:`\sigma(s, t)` is the number of shortest :math:`(s, t)` paths, and
+/// :math:`\sigma(s, t|v)` is the number of those paths passing through some
+/// node :math:`v` other than :math:`s, t` [1]_. It's computed with the same
+/// algorithm as :func:`~retworkx.digraph_betweenness_centrality`.
+///
+/// This function is multithreaded and will run in parallel if the number
+/// of sources is above the value of ``parallel_threshold`` (it defaults to
+/// 50). If the function will be running in parallel the env var
+/// ``RAYON_NUM_THREADS`` can be used to adjust how many threads will be used.
+///
+/// :param PyDiGraph graph: The input graph
+/// :param list sources: The node indices the counted shortest paths start at
+/// :param list targets: The node indices the counted shortest paths end at
+/// :param bool normalized: Whether to normalize the betweenness scores by the
+///     number of distinct paths between all pairs of nodes.
+/// :param weight_fn: An optional input callable that will be passed the edge's
+///     payload object and is expected to return a non-negative ``float``
+///     weight for that edge. If this is specified the shortest paths are
+///     found with Dijkstra's algorithm using the weights, otherwise they're
+///     the paths with the fewest edges.
+/// :param int parallel_threshold: The number of sources to calculate the
+///     the betweenness centrality in parallel at if the number of sources is
+///     less than this value it will run in a single thread. The default value
+///     is 50
+///
+/// :returns: a read-only dict-like object whose keys are the node indices and
+///     values are the betweenness score for each node.
+/// :rtype: CentralityMapping
+/// :raises IndexError: If a source or a target isn't a node in ``graph``
+///
+/// .. [1] Ulrik Brandes, "On Variants of Shortest-Path Betweenness
+///     Centrality and their Generic Computation." Social Networks
+///     30(2):136-145, 2008.
+#[pyfunction(normalized = "false", parallel_threshold = "50")]
+#[pyo3(
+    text_signature = "(graph, sources, targets, /, normalized=False, weight_fn=None, parallel_threshold=50)"
+)]
+pub fn digraph_betweenness_centrality_subset(
+    py: Python,
+    graph: &digraph::PyDiGraph,
+    sources: Vec<usize>,
+    targets: Vec<usize>,
+    normalized: bool,
+    weight_fn: Option<PyObject>,
+    parallel_threshold: usize,
+) -> PyResult<CentralityMapping> {
+    betweenness_centrality_subset(
+        py,
+        &graph.graph,
+        sources,
+        targets,
+        normalized,
+        weight_fn,
+        parallel_threshold,
+    )
+}
+
+fn top_k_scores<G: NodeIndexable>(graph: G, top_k: Vec<(G::NodeId, f64)>) -> Vec<(usize, f64)> {
+    top_k
+        .into_iter()
+        .map(|(node, score)| (graph.to_index(node), score))
+        .collect()
+}
+
+/// Find the ``k`` nodes with the highest degree centrality in a PyGraph.
+///
+/// The degree centrality of a node is its degree divided by :math:`N - 1`,
+/// where :math:`N` is the number of nodes in the graph. A self loop counts
+/// twice in the degree of its node. Only the ``k`` best nodes are kept while
+/// the degrees are counted, so no array of the centrality of every node is
+/// built.
+///
+/// :param PyGraph graph: The input graph
+/// :param int k: The number of nodes to return
+///
+/// :returns: A list of ``(node, centrality)`` tuples for the ``k`` nodes
+///     (or every node if there are fewer) with the highest centrality,
+///     sorted by decreasing centrality. Ties are broken by node index, the
+///     lower index first.
+/// :rtype: list
+#[pyfunction]
+#[pyo3(text_signature = "(graph, k, /)")]
+pub fn graph_top_k_degree_centrality(graph: &graph::PyGraph, k: usize) -> Vec<(usize, f64)> {
+    top_k_scores(
+        &graph.graph,
+        centrality::top_k_degree_centrality(&graph.graph, k),
+    )
+}
+
+/// Find the ``k`` nodes with the highest degree centrality in a PyDiGraph.
+///
+/// The degree centrality of a node is the sum of its in degree and its out
+/// degree divided by :math:`N - 1`, where :math:`N` is the number of nodes
+/// in the graph. Only the ``k`` best nodes are kept while the degrees are
+/// counted, so no array of the centrality of every node is built.
+///
+/// :param PyDiGraph graph: The input graph
+/// :param int k: The number of nodes to return
+///
+/// :returns: A list of ``(node, centrality)`` tuples for the ``k`` nodes
+///     (or every node if there are fewer) with the highest centrality,
+///     sorted by decreasing centrality. Ties are broken by node index, the
+///     lower index first.
+/// :rtype: list
+#[pyfunction]
+#[pyo3(text_signature = "(graph, k, /)")]
+pub fn digraph_top_k_degree_centrality(graph: &digraph::PyDiGraph, k: usize) -> Vec<(usize, f64)> {
+    top_k_scores(
+        &graph.graph,
+        centrality::top_k_degree_centrality(&graph.graph, k),
+    )
+}
+
+/// Find the ``k`` nodes with the highest closeness centrality in a PyGraph.
+///
+/// This computes the same centrality as
+/// :func:`~retworkx.graph_closeness_centrality` without edge weights, but
+/// the breadth first search from every node stops as soon as the node can't
+/// be one of the ``k`` best nodes [1]_. The search computes an upper bound
+/// of the closeness of the node after every level, assuming all the nodes
+/// which weren't reached yet but may be reached are on the next level, and
+/// stops if it's lower than the closeness of the ``k`` best nodes found so
+/// far. On large graphs with a small diameter most searches stop after a few
+/// levels, which is much faster than computing the closeness of every node.
+///
+/// :param PyGraph graph: The input graph
+/// :param int k: The number of nodes to return
+/// :param bool wf_improved: Whether to use the Wasserman and Faust improved
+///     formula
+///
+/// :returns: A list of ``(node, centrality)`` tuples for the ``k`` nodes
+///     (or every node if there are fewer) with the highest centrality,
+///     sorted by decreasing centrality. Nodes tied with the ``k``-th node
+///     may be returned in any order of node index.
+/// :rtype: list
+///
+/// .. [1] Elisabetta Bergamini, Michele Borassi, Pierluigi Crescenzi, Andrea
+///     Marino and Henning Meyerhenke, "Computing top-k closeness centrality
+///     faster in unweighted graphs." ACM Transactions on Knowledge Discovery
+///     from Data 13(5):1-40, 2019.
+#[pyfunction(wf_improved = "true")]
+#[pyo3(text_signature = "(graph, k, /, wf_improved=True)")]
+pub fn graph_top_k_closeness_centrality(
+    graph: &graph::PyGraph,
+    k: usize,
+    wf_improved: bool,
+) -> Vec<(usize, f64)> {
+    top_k_scores(
+        &graph.graph,
+        centrality::top_k_closeness_centrality(&graph.graph, k, wf_improved),
+    )
+}
+
+/// Find the ``k`` nodes with the highest closeness centrality in a PyDiGraph.
+///
+/// This computes the same centrality as
+/// :func:`~retworkx.digraph_closeness_centrality` without edge weights,
+/// with the incoming distances, but the breadth first search from every node
+/// stops as soon as the node can't be one of the ``k`` best nodes [1]_. The
+/// search computes an upper bound of the closeness of the node after every
+/// level, assuming all the nodes which weren't reached yet but may be
+/// reached are on the next level, and stops if it's lower than the closeness
+/// of the ``k`` best nodes found so far. On large graphs with a small
+/// diameter most searches stop after a few levels, which is much faster than
+/// computing the closeness of every node.
+///
+/// :param PyDiGraph graph: The input graph
+/// :param int k: The number of nodes to return
+/// :param bool wf_improved: Whether to use the Wasserman and Faust improved
+///     formula
+///
+/// :returns: A list of ``(node, centrality)`` tuples for the ``k`` nodes
+///     (or every node if there are fewer) with the highest centrality,
+///     sorted by decreasing centrality. Nodes tied with the ``k``-th node
+///     may be returned in any order of node index.
+/// :rtype: list
+///
+/// .. [1] Elisabetta Bergamini, Michele Borassi, Pierluigi Crescenzi, Andrea
+///     Marino and Henning Meyerhenke, "Computing top-k closeness centrality
+///     faster in unweighted graphs." ACM Transactions on Knowledge Discovery
+///     from Data 13(5):1-40, 2019.
+#[pyfunction(wf_improved = "true")]
+#[pyo3(text_signature = "(graph, k, /, wf_improved=True)")]
+pub fn digraph_top_k_closeness_centrality(
+    graph: &digraph::PyDiGraph,
+    k: usize,
+    wf_improved: bool,
+) -> Vec<(usize, f64)> {
+    top_k_scores(
+        &graph.graph,
+        centrality::top_k_closeness_centrality(&graph.graph, k, wf_improved),
+    )
+}
+
+/// Find the ``k`` nodes with the highest approximate betweenness centrality
+/// in a PyGraph.
+///
+/// The betweenness centrality of every node is estimated from a sample of
+/// shortest paths [1]_. Each sample picks a pair of distinct nodes
+/// :math:`s` and :math:`t` and a shortest path between them uniformly at
+/// random, and counts the nodes on the path other than :math:`s` and
+/// :math:`t`. The breadth first search from :math:`s` stops at the level of
+/// :math:`t`, and the number of samples grows only logarithmically with the
+/// number of nodes :math:`N`:
+///
+/// .. math::
+///
+///     \left\lceil \frac{\ln(2N / \delta)}{2 \epsilon^2} \right\rceil
+///
+/// With probability at least ``1 - delta`` the estimated betweenness of
+/// every node divided by the number of ordered pairs of nodes,
+/// :math:`N (N - 1)`, is within ``epsilon`` of its exact value. The
+/// estimates are scaled like the output of
+/// :func:`~retworkx.graph_betweenness_centrality` with ``endpoints=False``.
+///
+/// :param PyGraph graph: The input graph
+/// :param int k: The number of nodes to return
+/// :param bool normalized: Whether to normalize the betweenness scores by
+///     the number of distinct paths between all pairs of nodes. Defaults to
+///     ``True``.
+/// :param float epsilon: The maximum error of the estimates, as a fraction
+///     of the number of ordered pairs of nodes. Defaults to 0.01.
+/// :param float delta: The probability that an estimate has a larger error.
+///     Defaults to 0.1.
+/// :param int seed: An optional seed to use for the random number generator
+///
+/// :returns: A list of ``(node, centrality)`` tuples for the ``k`` nodes
+///     (or every node if there are fewer) with the highest estimated
+///     centrality, sorted by decreasing centrality. Ties are broken by node
+///     index, the lower index first.
+/// :rtype: list
+/// :raises ValueError: If ``epsilon`` or ``delta`` isn't between 0 and 1
+///
+/// .. [1] Matteo Riondato and Evgenios M. Kornaropoulos, "Fast approximation
+///     of betweenness centrality through sampling." Data Mining and
+///     Knowledge Discovery 30(2):438-475, 2016.
+#[pyfunction(normalized = "true", epsilon = "0.01", delta = "0.1")]
+#[pyo3(text_signature = "(graph, k, /, normalized=True, epsilon=0.01, delta=0.1, seed=None)")]
+pub fn graph_top_k_betweenness_centrality(
+    graph: &graph::PyGraph,
+    k: usize,
+    normalized: bool,
+    epsilon: f64,
+    delta: f64,
+    seed: Option<u64>,
+) -> PyResult<Vec<(usize, f64)>> {
+    let top_k =
+        centrality::top_k_betweenness_centrality(&graph.graph, k, normalized, epsilon, delta, seed)
+            .map_err(|err| PyValueError::new_err(err.to_string()))?;
+    Ok(top_k_scores(&graph.graph, top_k))
+}
+
+/// Find the ``k`` nodes with the highest approximate betweenness centrality
+/// in a PyDiGraph.
+///
+/// The betweenness centrality of every node is estimated from a sample of
+/// shortest paths [1]_. Each sample picks a pair of distinct nodes
+/// :math:`s` and :math:`t` and a shortest path from :math:`s` to :math:`t`
+/// uniformly at random, and counts the nodes on the path other than
+/// :math:`s` and :math:`t`. The breadth first search from :math:`s` stops at
+/// the level of :math:`t`, and the number of samples grows only
+/// logarithmically with the number of nodes :math:`N`:
+///
+/// .. math::
+///
+///     \left\lceil \frac{\ln(2N / \delta)}{2 \epsilon^2} \right\rceil
+///
+/// With probability at least ``1 - delta`` the estimated betweenness of
+/// every node divided by the number of ordered pairs of nodes,
+/// :math:`N (N - 1)`, is within ``epsilon`` of its exact value. The
+/// estimates are scaled like the output of
+/// :func:`~retworkx.digraph_betweenness_centrality` with ``endpoints=False``.
+///
+/// :param PyDiGraph graph: The input graph
+/// :param int k: The number of nodes to return
+/// :param bool normalized: Whether to normalize the betweenness scores by
+///     the number of distinct paths between all pairs of nodes. Defaults to
+///     ``True``.
+/// :param float epsilon: The maximum error of the estimates, as a fraction
+///     of the number of ordered pairs of nodes. Defaults to 0.01.
+/// :param float delta: The probability that an estimate has a larger error.
+///     Defaults to 0.1.
+/// :param int seed: An optional seed to use for the random number generator
+///
+/// :returns: A list of ``(node, centrality)`` tuples for the ``k`` nodes
+///     (or every node if there are fewer) with the highest estimated
+///     centrality, sorted by decreasing centrality. Ties are broken by node
+///     index, the lower index first.
+/// :rtype: list
+/// :raises ValueError: If ``epsilon`` or ``delta`` isn't between 0 and 1
+///
+/// .. [1] Matteo Riondato and Evgenios M. Kornaropoulos, "Fast approximation
+///     of betweenness centrality through sampling." Data Mining and
+///     Knowledge Discovery 30(2):438-475, 2016.
+#[pyfunction(normalized = "true", epsilon = "0.01", delta = "0.1")]
+#[pyo3(text_signature = "(graph, k, /, normalized=True, epsilon=0.01, delta=0.1, seed=None)")]
+pub fn digraph_top_k_betweenness_centrality(
+    graph: &digraph::PyDiGraph,
+    k: usize,
+    normalized: bool,
+    epsilon: f64,
+    delta: f64,
+    seed: Option<u64>,
+) -> PyResult<Vec<(usize, f64)>> {
+    let top_k =
+        centrality::top_k_betweenness_centrality(&graph.graph, k, normalized, epsilon, delta, seed)
+            .map_err(|err| PyValueError::new_err(err.to_string()))?;
+    Ok(top_k_scores(&graph.graph, top_k))
+}
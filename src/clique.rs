@@ -0,0 +1,106 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use crate::graph;
+use crate::iterators::NodeIndices;
+
+use retworkx_core::clique;
+
+use petgraph::graph::NodeIndex;
+
+use pyo3::prelude::*;
+use pyo3::pyclass::IterNextOutput;
+
+/// A lazy iterator over the maximal cliques of a graph, returned by
+/// :func:`~retworkx.find_cliques`.
+///
+/// Each iteration returns a :class:`~retworkx.NodeIndices` object with the
+/// node indices of the next maximal clique. The next clique is only found
+/// once the previous one is returned, so the cliques are never all held in
+/// memory at once.
+///
+/// The iterator works on a copy of the graph made when
+/// :func:`~retworkx.find_cliques` is called, so modifying the graph while
+/// iterating doesn't change the cliques returned.
+#[pyclass(module = "retworkx")]
+pub struct MaximalCliqueIterator {
+    cliques: clique::MaximalCliques<NodeIndex>,
+}
+
+#[pymethods]
+impl MaximalCliqueIterator {
+    fn __iter__(slf: PyRef<Self>) -> Py<MaximalCliqueIterator> {
+        slf.into()
+    }
+
+    fn __next__(mut slf: PyRefMut<Self>) -> IterNextOutput<NodeIndices, &'static str> {
+        match slf.cliques.next() {
+            Some(clique) => IterNextOutput::Yield(NodeIndices {
+                nodes: clique.iter().map(|node| node.index()).collect(),
+            }),
+            None => IterNextOutput::Return("Ended"),
+        }
+    }
+}
+
+/// Find the maximal cliques of a PyGraph
+///
+/// A clique is a set of nodes with an edge between every pair of them, it's
+/// maximal if no other node can be added to it. The cliques are found
+/// lazily with the Bron–Kerbosch algorithm with pivoting [Tomita2006]_,
+/// starting from the nodes in a degeneracy ordering [Eppstein2010]_, which
+/// is fast on sparse graphs. Self loops and parallel edges are ignored, an
+/// isolated node is a clique by itself.
+///
+/// :param PyGraph graph: The graph to find the cliques of
+///
+/// :returns: An iterator over the maximal cliques, each of them as the node
+///     indices of the clique. Every maximal clique is returned once.
+/// :rtype: MaximalCliqueIterator
+///
+/// .. [Tomita2006] E. Tomita, A. Tanaka and H. Takahashi, "The worst-case
+///    time complexity for generating all maximal cliques and computational
+///    experiments", Theoretical Computer Science 363 (1), 2006, pp. 28-42.
+///    https://doi.org/10.1016/j.tcs.2006.06.015
+/// .. [Eppstein2010] D. Eppstein, M. Löffler and D. Strash, "Listing All
+///    Maximal Cliques in Sparse Graphs in Near-Optimal Time", ISAAC 2010,
+///    pp. 403-414. https://doi.org/10.1007/978-3-642-17517-6_36
+#[pyfunction]
+#[pyo3(text_signature = "(graph, /)")]
+pub fn find_cliques(graph: &graph::PyGraph) -> MaximalCliqueIterator {
+    MaximalCliqueIterator {
+        cliques: clique::find_cliques(&graph.graph),
+    }
+}
+
+/// Find a maximum clique of a PyGraph, a clique with the most nodes
+///
+/// This enumerates the maximal cliques like :func:`~retworkx.find_cliques`
+/// and returns the first of the largest ones. Finding a maximum clique is
+/// NP-hard, this takes exponential time in the worst case but is fast on
+/// sparse graphs.
+///
+/// :param PyGraph graph: The graph to find the clique of
+///
+/// :returns: The node indices of a maximum clique, empty if the graph has
+///     no nodes
+/// :rtype: NodeIndices
+#[pyfunction]
+#[pyo3(text_signature = "(graph, /)")]
+pub fn max_clique(graph: &graph::PyGraph) -> NodeIndices {
+    NodeIndices {
+        nodes: clique::max_clique(&graph.graph)
+            .iter()
+            .map(|node| node.index())
+            .collect(),
+    }
+}
@@ -0,0 +1,330 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use crate::attributes::AttributeColumns;
+use crate::iterators::NodeMap;
+use crate::node_keys::NodeKeys;
+use crate::{digraph, graph, weight_callable, StablePyGraph};
+
+use retworkx_core::coarsening;
+use retworkx_core::dictmap::*;
+
+use petgraph::prelude::*;
+use petgraph::EdgeType;
+
+use pyo3::exceptions::{PyIndexError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::Python;
+
+/// A hierarchy of progressively coarser graphs built by
+/// :func:`~retworkx.coarsen`
+///
+/// The levels of the hierarchy are numbered from 0, the input graph, to
+/// ``len(graphs)``, the coarsest graph.
+///
+/// :ivar list graphs: The :class:`~retworkx.PyGraph` of every level after
+///     the input graph, from the finest to the coarsest. The data payload of
+///     every node is the number of nodes of the input graph merged into it
+///     and the data payload of every edge is the total weight of the edges
+///     of the input graph it's made of, as a ``float``.
+/// :ivar list node_maps: The prolongation map of every level after the input
+///     graph, in the order of ``graphs``, as a :class:`~retworkx.NodeMap`
+///     from the node indices of the previous level to the node index of
+///     the node they're merged into.
+#[pyclass(module = "retworkx")]
+pub struct CoarseningHierarchy {
+    #[pyo3(get)]
+    pub graphs: Vec<Py<graph::PyGraph>>,
+    #[pyo3(get)]
+    pub node_maps: Vec<NodeMap>,
+    /// The node indices of the input graph, in order
+    nodes: Vec<usize>,
+    /// The prolongation maps by position of node in its level
+    maps: Vec<Vec<usize>>,
+}
+
+impl CoarseningHierarchy {
+    fn check_level(&self, level: usize, min: usize) -> PyResult<()> {
+        if level < min || level > self.maps.len() {
+            return Err(PyIndexError::new_err(format!(
+                "Level {} is not between {} and {}",
+                level,
+                min,
+                self.maps.len()
+            )));
+        }
+        Ok(())
+    }
+}
+
+#[pymethods]
+impl CoarseningHierarchy {
+    /// The number of levels, including the input graph
+    fn __len__(&self) -> usize {
+        self.maps.len() + 1
+    }
+
+    /// Get the node of a level every node of the input graph is merged into
+    ///
+    /// :param int level: The level, from 0 for the input graph to
+    ///     ``len(graphs)`` for the coarsest graph
+    ///
+    /// :returns: A mapping of the node indices of the input graph to the
+    ///     node indices in the graph of ``level``
+    /// :rtype: NodeMap
+    /// :raises IndexError: If ``level`` is not a level of the hierarchy
+    #[pyo3(text_signature = "(self, level, /)")]
+    fn level_node_map(&self, level: usize) -> PyResult<NodeMap> {
+        self.check_level(level, 0)?;
+        // The positions of the nodes of a coarse graph are its node indices,
+        // unlike the ones of the input graph which can have removed nodes
+        if level == 0 {
+            return Ok(NodeMap {
+                node_map: self.nodes.iter().map(|node| (*node, *node)).collect(),
+            });
+        }
+        let mut map: Vec<usize> = (0..self.nodes.len()).collect();
+        for level_map in &self.maps[..level] {
+            for node in map.iter_mut() {
+                *node = level_map[*node];
+            }
+        }
+        Ok(NodeMap {
+            node_map: self.nodes.iter().copied().zip(map).collect(),
+        })
+    }
+
+    /// Prolong values of the nodes of a level to the nodes of the previous
+    /// level
+    ///
+    /// Every node of the previous level gets the value of the node it's
+    /// merged into, for example to use the positions of the nodes of a
+    /// coarse graph as the initial positions of the nodes of the next finer
+    /// graph.
+    ///
+    /// :param int level: The level of the values, from 1 to ``len(graphs)``
+    /// :param list values: The value of every node of ``level``, in the order
+    ///     of their node indices
+    ///
+    /// :returns: A dictionary of the values keyed by the node indices of the
+    ///     previous level, the input graph for level 1
+    /// :rtype: dict
+    /// :raises IndexError: If ``level`` is not a level after the input graph
+    /// :raises ValueError: If ``values`` doesn't have a value for every node
+    ///     of ``level``
+    #[pyo3(text_signature = "(self, level, values, /)")]
+    fn prolong(
+        &self,
+        py: Python,
+        level: usize,
+        values: Vec<PyObject>,
+    ) -> PyResult<DictMap<usize, PyObject>> {
+        self.check_level(level, 1)?;
+        let count = self.graphs[level - 1].borrow(py).graph.node_count();
+        if values.len() != count {
+            return Err(PyValueError::new_err(format!(
+                "Expected {} values, got {}",
+                count,
+                values.len()
+            )));
+        }
+        let map = &self.maps[level - 1];
+        Ok(map
+            .iter()
+            .enumerate()
+            .map(|(node, coarse)| {
+                let node = if level == 1 { self.nodes[node] } else { node };
+                (node, values[*coarse].clone_ref(py))
+            })
+            .collect())
+    }
+}
+
+fn coarsen<Ty: EdgeType>(
+    py: Python,
+    graph: &StablePyGraph<Ty>,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+    min_nodes: usize,
+    max_levels: Option<usize>,
+    seed: Option<u64>,
+) -> PyResult<CoarseningHierarchy> {
+    let hierarchy = coarsening::coarsen(
+        graph,
+        |edge| weight_callable(py, &weight_fn, edge.weight(), default_weight),
+        min_nodes,
+        max_levels,
+        seed,
+    )?;
+    let nodes: Vec<usize> = hierarchy.nodes.iter().map(|node| node.index()).collect();
+    let graphs = hierarchy
+        .levels
+        .iter()
+        .skip(1)
+        .map(|level| {
+            let mut out_graph = StablePyGraph::<Undirected>::with_capacity(level.node_count(), 0);
+            for size in &level.sizes {
+                out_graph.add_node((*size).into_py(py));
+            }
+            for (source, target, weight) in level.edges() {
+                out_graph.add_edge(
+                    NodeIndex::new(source),
+                    NodeIndex::new(target),
+                    weight.into_py(py),
+                );
+            }
+            Py::new(
+                py,
+                graph::PyGraph {
+                    graph: out_graph,
+                    node_removed: false,
+                    multigraph: false,
+                    node_keys: NodeKeys::default(),
+                    attributes: AttributeColumns::default(),
+                    generation: 0,
+                },
+            )
+        })
+        .collect::<PyResult<Vec<Py<graph::PyGraph>>>>()?;
+    let node_maps = hierarchy
+        .maps
+        .iter()
+        .enumerate()
+        .map(|(level, map)| NodeMap {
+            node_map: map
+                .iter()
+                .enumerate()
+                .map(|(node, coarse)| (if level == 0 { nodes[node] } else { node }, *coarse))
+                .collect(),
+        })
+        .collect();
+    Ok(CoarseningHierarchy {
+        graphs,
+        node_maps,
+        nodes,
+        maps: hierarchy.maps,
+    })
+}
+
+/// Coarsen a PyGraph into a hierarchy of progressively smaller graphs
+///
+/// Every level of the hierarchy is built by merging the pairs of nodes of a
+/// heavy edge matching of the previous level, where every node is matched
+/// with the neighbor with the heaviest edge between them, and adding up the
+/// weights of the edges between the merged nodes. This is the coarsening
+/// phase of multilevel algorithms, which lay out or partition the coarsest
+/// graph and then refine the result level by level, using
+/// :meth:`~retworkx.CoarseningHierarchy.prolong` to carry it to the next
+/// finer level. Parallel edges are merged and self loops are ignored.
+///
+/// Coarsening stops when a level has at most ``min_nodes`` nodes, when
+/// ``max_levels`` levels were built, or when a matching merges less than 5%
+/// of the nodes, for example when only isolated nodes are left or for
+/// star-like graphs, where only a few nodes can be matched.
+///
+/// :param PyGraph graph: The graph to coarsen
+/// :param weight_fn: An optional callable that will be passed the edge's
+///     payload object and is expected to return a ``float`` weight for that
+///     edge.
+/// :param float default_weight: If ``weight_fn`` isn't specified this
+///     optional float value will be used for the weight of every edge.
+///     Defaults to 1.0.
+/// :param int min_nodes: The number of nodes at or below which coarsening
+///     stops. Defaults to 100.
+/// :param int max_levels: The maximum number of levels to build. If not
+///     specified there is no limit.
+/// :param int seed: An optional seed to use for the random number generator
+///     that sets the order in which the nodes are matched
+///
+/// :returns: The hierarchy of coarse graphs
+/// :rtype: CoarseningHierarchy
+#[pyfunction(default_weight = "1.0", min_nodes = "100")]
+#[pyo3(
+    text_signature = "(graph, /, weight_fn=None, default_weight=1.0, min_nodes=100, max_levels=None, seed=None)"
+)]
+pub fn graph_coarsen(
+    py: Python,
+    graph: &graph::PyGraph,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+    min_nodes: usize,
+    max_levels: Option<usize>,
+    seed: Option<u64>,
+) -> PyResult<CoarseningHierarchy> {
+    coarsen(
+        py,
+        &graph.graph,
+        weight_fn,
+        default_weight,
+        min_nodes,
+        max_levels,
+        seed,
+    )
+}
+
+/// Coarsen a PyDiGraph into a hierarchy of progressively smaller graphs
+///
+/// Every level of the hierarchy is built by merging the pairs of nodes of a
+/// heavy edge matching of the previous level, where every node is matched
+/// with the neighbor with the heaviest edge between them, and adding up the
+/// weights of the edges between the merged nodes. This is the coarsening
+/// phase of multilevel algorithms, which lay out or partition the coarsest
+/// graph and then refine the result level by level, using
+/// :meth:`~retworkx.CoarseningHierarchy.prolong` to carry it to the next
+/// finer level. The direction of the edges is ignored, so the coarse graphs
+/// are :class:`~retworkx.PyGraph` objects, parallel edges (including edges
+/// in opposite directions) are merged and self loops are ignored.
+///
+/// Coarsening stops when a level has at most ``min_nodes`` nodes, when
+/// ``max_levels`` levels were built, or when a matching merges less than 5%
+/// of the nodes, for example when only isolated nodes are left or for
+/// star-like graphs, where only a few nodes can be matched.
+///
+/// :param PyDiGraph graph: The graph to coarsen
+/// :param weight_fn: An optional callable that will be passed the edge's
+///     payload object and is expected to return a ``float`` weight for that
+///     edge.
+/// :param float default_weight: If ``weight_fn`` isn't specified this
+///     optional float value will be used for the weight of every edge.
+///     Defaults to 1.0.
+/// :param int min_nodes: The number of nodes at or below which coarsening
+///     stops. Defaults to 100.
+/// :param int max_levels: The maximum number of levels to build. If not
+///     specified there is no limit.
+/// :param int seed: An optional seed to use for the random number generator
+///     that sets the order in which the nodes are matched
+///
+/// :returns: The hierarchy of coarse graphs
+/// :rtype: CoarseningHierarchy
+#[pyfunction(default_weight = "1.0", min_nodes = "100")]
+#[pyo3(
+    text_signature = "(graph, /, weight_fn=None, default_weight=1.0, min_nodes=100, max_levels=None, seed=None)"
+)]
+pub fn digraph_coarsen(
+    py: Python,
+    graph: &digraph::PyDiGraph,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+    min_nodes: usize,
+    max_levels: Option<usize>,
+    seed: Option<u64>,
+) -> PyResult<CoarseningHierarchy> {
+    coarsen(
+        py,
+        &graph.graph,
+        weight_fn,
+        default_weight,
+        min_nodes,
+        max_levels,
+        seed,
+    )
+}
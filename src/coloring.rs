@@ -10,7 +10,7 @@
 // License for the specific language governing permissions and limitations
 // under the License.
 
-use crate::graph;
+use crate::{deterministic_order, graph};
 use retworkx_core::dictmap::*;
 
 use hashbrown::{HashMap, HashSet};
@@ -62,6 +62,8 @@ pub fn graph_greedy_color(py: Python, graph: &graph::PyGraph) -> PyResult<PyObje
         }
         colors.insert(u_index.index(), count);
     }
+    let mut colors: Vec<(usize, usize)> = colors.into_iter().collect();
+    deterministic_order(&mut colors, |(index, _)| *index);
     let out_dict = PyDict::new(py);
     for (index, color) in colors {
         out_dict.set_item(index, color)?;
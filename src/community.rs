@@ -0,0 +1,223 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use std::convert::TryFrom;
+
+use crate::{graph, CostFn, InvalidNode};
+
+use hashbrown::HashSet;
+
+use petgraph::graph::NodeIndex;
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::Python;
+
+use retworkx_core::community;
+
+fn check_nodes(
+    graph: &graph::PyGraph,
+    pairs: &[(usize, usize)],
+) -> PyResult<Vec<(NodeIndex, NodeIndex)>> {
+    pairs
+        .iter()
+        .map(|(a, b)| {
+            for node in &[*a, *b] {
+                if !graph.graph.contains_node(NodeIndex::new(*node)) {
+                    return Err(InvalidNode::new_err(format!(
+                        "Node index {} is not in the graph",
+                        node
+                    )));
+                }
+            }
+            Ok((NodeIndex::new(*a), NodeIndex::new(*b)))
+        })
+        .collect()
+}
+
+/// Find the communities of a PyGraph with the Louvain method
+///
+/// The Louvain method [1]_ greedily maximizes the
+/// :func:`~retworkx.modularity` of the partition. Starting with every node
+/// in its own community, it repeatedly moves every node, in a random order,
+/// to the neighboring community which increases the modularity the most,
+/// until a pass over the nodes increases the modularity by ``threshold`` or
+/// less. Then every community becomes a node of a new graph, and the process
+/// is repeated on it until the modularity increases by ``threshold`` or
+/// less.
+///
+/// Hard constraints on the communities can be given as must-link pairs of
+/// nodes, which must be in the same community, and cannot-link pairs of
+/// nodes, which must be in different communities. They're enforced during
+/// the whole search [2]_ rather than by fixing the partition afterwards,
+/// which would lower its modularity: the nodes of every must-link pair are
+/// merged before the first pass, and a node is never moved to a community
+/// with a node it has a cannot-link pair with. For example:
+///
+/// .. jupyter-execute::
+///
+///     import retworkx
+///
+///     graph = retworkx.generators.barbell_graph(4, 0)
+///     print(retworkx.louvain_communities(graph, seed=42))
+///     print(retworkx.louvain_communities(graph, seed=42, cannot_link=[(0, 1)]))
+///
+/// :param PyGraph graph: The graph to find the communities of
+/// :param weight_fn: An optional input callable that will be passed the edge's
+///     payload object and is expected to return a non-negative ``float``
+///     weight for that edge. If this is not specified ``default_weight`` will
+///     be used as the weight for every edge.
+/// :param float default_weight: If ``weight_fn`` is not set the default
+///     weight value to use for the weight of all edges
+/// :param float resolution: The resolution of the modularity, a value below
+///     1 favors larger communities and a value above 1 favors smaller
+///     communities. Defaults to 1.
+/// :param float threshold: The minimum increase of the modularity to keep
+///     going. Defaults to ``1e-7``.
+/// :param int seed: An optional seed to use for the random number generator
+/// :param list must_link: An optional list of pairs of node indices which
+///     must be in the same community
+/// :param list cannot_link: An optional list of pairs of node indices which
+///     must be in different communities
+///
+/// :returns: A list of the communities, as sets of node indices, in order of
+///     their lowest node index
+/// :rtype: list
+/// :raises InvalidNode: If a node index in a constraint is not in the graph
+/// :raises ValueError: If the constraints can't be satisfied, because the
+///     nodes of a cannot-link pair are connected by must-link pairs, or if a
+///     weight is negative
+///
+/// .. [1] V. D. Blondel, J.-L. Guillaume, R. Lambiotte and E. Lefebvre,
+///     "Fast unfolding of communities in large networks", Journal of
+///     Statistical Mechanics: Theory and Experiment, 2008(10):P10008, 2008.
+/// .. [2] S. Basu, I. Davidson and K. Wagstaff, "Constrained Clustering:
+///     Advances in Algorithms, Theory, and Applications", Chapman &
+///     Hall/CRC, 2008.
+#[pyfunction(default_weight = "1.0", resolution = "1.0", threshold = "1e-7")]
+#[pyo3(
+    text_signature = "(graph, /, weight_fn=None, default_weight=1.0, resolution=1.0, threshold=1e-7, seed=None, must_link=None, cannot_link=None)"
+)]
+#[allow(clippy::too_many_arguments)]
+pub fn louvain_communities(
+    py: Python,
+    graph: &graph::PyGraph,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+    resolution: f64,
+    threshold: f64,
+    seed: Option<u64>,
+    must_link: Option<Vec<(usize, usize)>>,
+    cannot_link: Option<Vec<(usize, usize)>>,
+) -> PyResult<Vec<HashSet<usize>>> {
+    let must_link = check_nodes(graph, &must_link.unwrap_or_default())?;
+    let cannot_link = check_nodes(graph, &cannot_link.unwrap_or_default())?;
+    let cost_fn = CostFn::try_from((weight_fn, default_weight))?;
+    let communities = community::louvain_communities(
+        &graph.graph,
+        |e| cost_fn.call(py, e.weight()),
+        resolution,
+        threshold,
+        &must_link,
+        &cannot_link,
+        seed,
+    )?;
+    match communities {
+        Some(communities) => Ok(communities
+            .into_iter()
+            .map(|nodes| nodes.into_iter().map(|n| n.index()).collect())
+            .collect()),
+        None => Err(PyValueError::new_err(
+            "A cannot-link pair of nodes is connected by must-link pairs",
+        )),
+    }
+}
+
+/// Compute the modularity of a partition of the nodes of a PyGraph
+///
+/// The modularity of a partition into communities :math:`C` is [1]_:
+///
+/// .. math::
+///
+///     Q = \sum_{c \in C} \left( \frac{L_c}{m} - \gamma
+///         \left( \frac{d_c}{2m} \right)^2 \right)
+///
+/// where :math:`m` is the total weight of the edges, :math:`L_c` is the
+/// total weight of the edges between the nodes of :math:`c`, :math:`d_c` is
+/// the sum of the weighted degrees of the nodes of :math:`c` and
+/// :math:`\gamma` is the resolution. A self loop counts twice in the degree
+/// of its node. The nodes which aren't in any community are each in a
+/// community of their own. The modularity of a graph without edges is 0.
+///
+/// :param PyGraph graph: The graph the partition is of
+/// :param list communities: The communities, as iterables of node indices. A
+///     node must not be in more than one community.
+/// :param weight_fn: An optional input callable that will be passed the edge's
+///     payload object and is expected to return a non-negative ``float``
+///     weight for that edge. If this is not specified ``default_weight`` will
+///     be used as the weight for every edge.
+/// :param float default_weight: If ``weight_fn`` is not set the default
+///     weight value to use for the weight of all edges
+/// :param float resolution: The resolution :math:`\gamma`. Defaults to 1.
+///
+/// :returns: The modularity of the partition
+/// :rtype: float
+/// :raises InvalidNode: If a node index in ``communities`` is not in the
+///     graph
+/// :raises ValueError: If a node is in more than one community or if a
+///     weight is negative
+///
+/// .. [1] M. E. J. Newman, "Modularity and community structure in
+///     networks", Proceedings of the National Academy of Sciences,
+///     103(23):8577-8582, 2006.
+#[pyfunction(default_weight = "1.0", resolution = "1.0")]
+#[pyo3(
+    text_signature = "(graph, communities, /, weight_fn=None, default_weight=1.0, resolution=1.0)"
+)]
+pub fn modularity(
+    py: Python,
+    graph: &graph::PyGraph,
+    communities: Vec<&PyAny>,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+    resolution: f64,
+) -> PyResult<f64> {
+    let mut seen: HashSet<usize> = HashSet::new();
+    let mut node_communities: Vec<Vec<NodeIndex>> = Vec::with_capacity(communities.len());
+    for nodes in communities {
+        let mut community_nodes = Vec::new();
+        for node in nodes.iter()? {
+            let node: usize = node?.extract()?;
+            if !graph.graph.contains_node(NodeIndex::new(node)) {
+                return Err(InvalidNode::new_err(format!(
+                    "Node index {} is not in the graph",
+                    node
+                )));
+            }
+            if !seen.insert(node) {
+                return Err(PyValueError::new_err(format!(
+                    "Node {} is in more than one community",
+                    node
+                )));
+            }
+            community_nodes.push(NodeIndex::new(node));
+        }
+        node_communities.push(community_nodes);
+    }
+    let cost_fn = CostFn::try_from((weight_fn, default_weight))?;
+    community::modularity(
+        &graph.graph,
+        &node_communities,
+        |e| cost_fn.call(py, e.weight()),
+        resolution,
+    )
+}
@@ -0,0 +1,118 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+use retworkx_core::connectivity;
+
+/// A subspace of the cycle space of an undirected graph, spanned by a set of
+/// cycles.
+///
+/// The cycle space of an undirected graph is the vector space over GF(2) of
+/// the sets of edges in which every node has an even degree, the sums of
+/// cycles of the graph. The sum of two sets of edges is their symmetric
+/// difference, see :func:`~retworkx.cycle_sum`. Cycles and other sets of
+/// edges are given as lists of edge indices, and an index given twice
+/// cancels out.
+///
+/// Only the cycles which are independent of the ones already in the
+/// ``CycleSpace`` are kept, so for example a
+/// ``CycleSpace`` created from the output of
+/// :func:`~retworkx.minimum_cycle_basis` spans the whole cycle space of the
+/// graph and can be used to test if a set of edges is a sum of cycles, and
+/// which basis cycles it's the sum of:
+///
+/// .. jupyter-execute::
+///
+///     import retworkx
+///
+///     graph = retworkx.PyGraph()
+///     graph.extend_from_edge_list([(0, 1), (1, 2), (2, 0), (1, 3), (3, 2)])
+///     space = retworkx.CycleSpace(retworkx.minimum_cycle_basis(graph))
+///     print(space.cycles())
+///     print(space.decompose([0, 2, 3, 4]))
+///
+/// :param list cycles: An optional list of the cycles, each a list of edge
+///     indices, spanning the space. If not specified the space is empty.
+#[pyclass(module = "retworkx")]
+#[pyo3(text_signature = "(cycles=None, /)")]
+pub struct CycleSpace {
+    space: connectivity::CycleSpace,
+}
+
+#[pymethods]
+impl CycleSpace {
+    #[new]
+    fn new(cycles: Option<Vec<Vec<usize>>>) -> Self {
+        let mut space = connectivity::CycleSpace::new();
+        for cycle in cycles.unwrap_or_default() {
+            space.insert(cycle);
+        }
+        CycleSpace { space }
+    }
+
+    /// Insert a cycle into the space.
+    ///
+    /// :param list cycle: The edge indices of the cycle. Any set of edges
+    ///     can be inserted, they don't need to form a cycle.
+    ///
+    /// :returns: ``True`` if the cycle was inserted, ``False`` if it's
+    ///     already a sum of the cycles in the space, in which case the space
+    ///     isn't changed.
+    /// :rtype: bool
+    #[pyo3(text_signature = "(self, cycle, /)")]
+    fn insert(&mut self, cycle: Vec<usize>) -> bool {
+        self.space.insert(cycle)
+    }
+
+    /// Return the cycles spanning the space.
+    ///
+    /// :returns: The independent cycles inserted into the space, in the
+    ///     order they were inserted, each as a sorted list of edge indices
+    /// :rtype: list
+    #[pyo3(text_signature = "(self)")]
+    fn cycles(&self) -> Vec<Vec<usize>> {
+        self.space.cycles().to_vec()
+    }
+
+    /// Decompose a set of edges into a sum of the cycles spanning the space.
+    ///
+    /// :param list edges: The edge indices of the set of edges
+    ///
+    /// :returns: The sorted positions in :meth:`~CycleSpace.cycles` of the
+    ///     cycles which sum to ``edges``
+    /// :rtype: list
+    ///
+    /// :raises ValueError: If ``edges`` is not a sum of the cycles in the
+    ///     space
+    #[pyo3(text_signature = "(self, edges, /)")]
+    fn decompose(&self, edges: Vec<usize>) -> PyResult<Vec<usize>> {
+        self.space.decompose(edges).ok_or_else(|| {
+            PyValueError::new_err("The edges are not a sum of the cycles in the space")
+        })
+    }
+
+    /// The dimension of the space, the number of independent cycles in it.
+    #[getter]
+    fn dimension(&self) -> usize {
+        self.space.dimension()
+    }
+
+    fn __len__(&self) -> usize {
+        self.space.dimension()
+    }
+
+    fn __contains__(&self, edges: Vec<usize>) -> bool {
+        self.space.contains(edges)
+    }
+}
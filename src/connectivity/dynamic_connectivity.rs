@@ -0,0 +1,248 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use std::collections::HashSet;
+
+use crate::{digraph, graph, InvalidNode, NoEdgeBetweenNodes, StablePyGraph};
+
+use petgraph::visit::{EdgeRef, IntoEdgeReferences};
+use petgraph::EdgeType;
+
+use pyo3::exceptions::{PyTypeError, PyValueError};
+use pyo3::prelude::*;
+
+use retworkx_core::connectivity;
+
+/// The connected components of a graph whose nodes and edges are inserted
+/// and removed over time.
+///
+/// This tracks the component of every node as the graph changes, so it
+/// doesn't need to be recomputed with :func:`~retworkx.connected_components`
+/// after every change. Every edge is undirected, parallel edges and self
+/// loops are allowed. The nodes are identified by the indices they're
+/// inserted with, so it can be kept in sync with a
+/// :class:`~retworkx.PyGraph` by applying the same changes to both:
+///
+/// .. jupyter-execute::
+///
+///     import retworkx
+///
+///     graph = retworkx.generators.path_graph(4)
+///     connectivity = retworkx.DynamicConnectivity(graph)
+///     graph.remove_edge(1, 2)
+///     print(connectivity.remove_edge(1, 2))
+///     print(connectivity.components())
+///     node = graph.add_node(None)
+///     connectivity.add_node(node)
+///     graph.add_edge(node, 3, None)
+///     print(connectivity.add_edge(node, 3))
+///     print(connectivity.component(node))
+///
+/// A spanning forest of the graph is kept along with the component of every
+/// node. Inserting an edge between two components merges the smaller one
+/// into the larger one, which takes :math:`O(\log n)` amortized time per
+/// node over a sequence of insertions, like a union-find. Removing an edge
+/// outside of the forest takes constant time, and removing an edge of the
+/// forest searches the smaller of the two trees it splits for a replacement
+/// edge, in time proportional to its size. Checking if two nodes are
+/// connected takes constant time.
+///
+/// :param graph: An optional :class:`~retworkx.PyGraph` or
+///     :class:`~retworkx.PyDiGraph` to copy the nodes and edges of, the
+///     direction of the edges of a :class:`~retworkx.PyDiGraph` is ignored.
+///     If not specified there are no nodes.
+#[pyclass(module = "retworkx")]
+#[pyo3(text_signature = "(graph=None, /)")]
+pub struct DynamicConnectivity {
+    connectivity: connectivity::DynamicConnectivity,
+}
+
+fn from_graph<Ty: EdgeType>(graph: &StablePyGraph<Ty>) -> connectivity::DynamicConnectivity {
+    let mut connectivity = connectivity::DynamicConnectivity::new();
+    for node in graph.node_indices() {
+        connectivity.add_node(node.index());
+    }
+    for edge in graph.edge_references() {
+        connectivity.add_edge(edge.source().index(), edge.target().index());
+    }
+    connectivity
+}
+
+impl DynamicConnectivity {
+    fn check_node(&self, node: usize) -> PyResult<()> {
+        if !self.connectivity.contains_node(node) {
+            return Err(InvalidNode::new_err(format!(
+                "Node index {} is not in the graph",
+                node
+            )));
+        }
+        Ok(())
+    }
+}
+
+#[pymethods]
+impl DynamicConnectivity {
+    #[new]
+    fn new(graph: Option<&PyAny>) -> PyResult<Self> {
+        let connectivity = match graph {
+            None => connectivity::DynamicConnectivity::new(),
+            Some(graph) => {
+                if let Ok(graph) = graph.extract::<PyRef<graph::PyGraph>>() {
+                    from_graph(&graph.graph)
+                } else if let Ok(graph) = graph.extract::<PyRef<digraph::PyDiGraph>>() {
+                    from_graph(&graph.graph)
+                } else {
+                    return Err(PyTypeError::new_err(format!(
+                        "Invalid Input Type {} for graph",
+                        graph.get_type()
+                    )));
+                }
+            }
+        };
+        Ok(DynamicConnectivity { connectivity })
+    }
+
+    /// Insert a node, in a new component.
+    ///
+    /// :param int node: The index of the node
+    ///
+    /// :raises ValueError: If ``node`` is already a node
+    #[pyo3(text_signature = "(self, node, /)")]
+    fn add_node(&mut self, node: usize) -> PyResult<()> {
+        if !self.connectivity.add_node(node) {
+            return Err(PyValueError::new_err(format!(
+                "Node index {} is already in the graph",
+                node
+            )));
+        }
+        Ok(())
+    }
+
+    /// Remove a node and all its edges.
+    ///
+    /// :param int node: The index of the node
+    ///
+    /// :raises InvalidNode: If ``node`` isn't a node
+    #[pyo3(text_signature = "(self, node, /)")]
+    fn remove_node(&mut self, node: usize) -> PyResult<()> {
+        self.check_node(node)?;
+        self.connectivity.remove_node(node);
+        Ok(())
+    }
+
+    /// Insert an edge between two nodes.
+    ///
+    /// :param int node_a: The index of a node
+    /// :param int node_b: The index of the other node
+    ///
+    /// :returns: ``True`` if the edge connected two components, which were
+    ///     merged
+    /// :rtype: bool
+    /// :raises InvalidNode: If ``node_a`` or ``node_b`` isn't a node
+    #[pyo3(text_signature = "(self, node_a, node_b, /)")]
+    fn add_edge(&mut self, node_a: usize, node_b: usize) -> PyResult<bool> {
+        self.check_node(node_a)?;
+        self.check_node(node_b)?;
+        Ok(self.connectivity.add_edge(node_a, node_b))
+    }
+
+    /// Remove an edge between two nodes.
+    ///
+    /// If there are parallel edges between the nodes only one is removed.
+    ///
+    /// :param int node_a: The index of a node
+    /// :param int node_b: The index of the other node
+    ///
+    /// :returns: ``True`` if removing the edge split its component in two
+    /// :rtype: bool
+    /// :raises NoEdgeBetweenNodes: If there is no edge between ``node_a``
+    ///     and ``node_b``
+    #[pyo3(text_signature = "(self, node_a, node_b, /)")]
+    fn remove_edge(&mut self, node_a: usize, node_b: usize) -> PyResult<bool> {
+        self.connectivity
+            .remove_edge(node_a, node_b)
+            .ok_or_else(|| NoEdgeBetweenNodes::new_err("No edge found between nodes"))
+    }
+
+    /// Return if there is an edge between two nodes.
+    ///
+    /// :param int node_a: The index of a node
+    /// :param int node_b: The index of the other node
+    ///
+    /// :rtype: bool
+    #[pyo3(text_signature = "(self, node_a, node_b, /)")]
+    fn has_edge(&self, node_a: usize, node_b: usize) -> bool {
+        self.connectivity.edge_multiplicity(node_a, node_b) > 0
+    }
+
+    /// Return if two nodes are in the same component.
+    ///
+    /// :param int node_a: The index of a node
+    /// :param int node_b: The index of the other node
+    ///
+    /// :rtype: bool
+    /// :raises InvalidNode: If ``node_a`` or ``node_b`` isn't a node
+    #[pyo3(text_signature = "(self, node_a, node_b, /)")]
+    fn connected(&self, node_a: usize, node_b: usize) -> PyResult<bool> {
+        self.check_node(node_a)?;
+        self.check_node(node_b)?;
+        Ok(self.connectivity.connected(node_a, node_b))
+    }
+
+    /// Return the nodes of the component of a node.
+    ///
+    /// :param int node: The index of the node
+    ///
+    /// :returns: The indices of the nodes of the component, including
+    ///     ``node``
+    /// :rtype: set
+    /// :raises InvalidNode: If ``node`` isn't a node
+    #[pyo3(text_signature = "(self, node, /)")]
+    fn component(&self, node: usize) -> PyResult<HashSet<usize>> {
+        self.check_node(node)?;
+        Ok(self.connectivity.component(node).collect())
+    }
+
+    /// Return the components.
+    ///
+    /// :returns: A list of the components, each a set of node indices, in
+    ///     arbitrary order
+    /// :rtype: list
+    #[pyo3(text_signature = "(self)")]
+    fn components(&self) -> Vec<HashSet<usize>> {
+        self.connectivity
+            .components()
+            .into_iter()
+            .map(|component| component.into_iter().collect())
+            .collect()
+    }
+
+    /// The number of components.
+    #[getter]
+    fn num_components(&self) -> usize {
+        self.connectivity.num_components()
+    }
+
+    /// The number of edges, including the parallel edges and self loops.
+    #[getter]
+    fn num_edges(&self) -> usize {
+        self.connectivity.edge_count()
+    }
+
+    fn __len__(&self) -> usize {
+        self.connectivity.node_count()
+    }
+
+    fn __contains__(&self, node: usize) -> bool {
+        self.connectivity.contains_node(node)
+    }
+}
@@ -14,24 +14,42 @@
 
 mod conn_components;
 mod core_number;
+mod cycle_space;
+mod dynamic_connectivity;
 
-use super::{digraph, get_edge_iter_with_weights, graph, weight_callable, InvalidNode, NullGraph};
+use std::convert::TryFrom;
+
+use super::{
+    deterministic_order, digraph, get_edge_iter_with_weights, graph, weight_callable, CostFn,
+    InvalidNode, NullGraph, StablePyGraph,
+};
 
 use hashbrown::{HashMap, HashSet};
 
+use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
 use pyo3::Python;
 
 use petgraph::algo;
 use petgraph::graph::NodeIndex;
+use petgraph::prelude::*;
 use petgraph::unionfind::UnionFind;
 use petgraph::visit::{EdgeRef, IntoEdgeReferences, NodeCount, NodeIndexable, Visitable};
+use petgraph::EdgeType;
 
 use ndarray::prelude::*;
+use num_complex::{Complex32, Complex64};
+use num_traits::Zero;
 use numpy::IntoPyArray;
 
-use crate::iterators::{BiconnectedComponents, Chains, EdgeList};
+use crate::attributes::AttributeColumns;
+use crate::iterators::{BiconnectedComponents, Chains, EdgeList, NodeIndices};
+use crate::node_keys::NodeKeys;
 use retworkx_core::connectivity;
+use retworkx_core::operators::power_edges;
+
+pub use cycle_space::CycleSpace;
+pub use dynamic_connectivity::DynamicConnectivity;
 
 /// Return a list of cycles which form a basis for cycles of a given PyGraph
 ///
@@ -125,6 +143,121 @@ pub fn cycle_basis(graph: &graph::PyGraph, root: Option<usize>) -> Vec<Vec<usize
     cycles
 }
 
+/// Find a minimum weight cycle basis of a :class:`~retworkx.PyGraph`.
+///
+/// A cycle basis is a minimal set of cycles such that every cycle of the
+/// graph is a sum of cycles of the basis, where the sum of cycles is the
+/// exclusive or of their edges. A minimum weight cycle basis is one with
+/// the smallest total weight of its cycles. Unlike
+/// :func:`~retworkx.cycle_basis` the cycles are returned as edges, so
+/// parallel edges and self loops are supported.
+///
+/// This uses Horton's algorithm [1]_, with a shortest path tree from every
+/// node of the graph, so it needs memory quadratic in the number of nodes.
+///
+/// :param PyGraph graph: The graph to find the cycle basis of
+/// :param weight_fn: An optional callable that will be passed the edge's
+///     payload object and is expected to return a non-negative ``float``
+///     weight for that edge. If this is not specified ``default_weight`` is
+///     used for every edge.
+/// :param float default_weight: If ``weight_fn`` is not set the default
+///     weight value to use for every edge. It must be non-negative.
+///
+/// :returns: A list of the cycles of the basis in non-decreasing order of
+///     weight. Each cycle is a list of the edge indices of the cycle in the
+///     order of a walk around it.
+/// :rtype: list
+///
+/// :raises ValueError: If an edge weight is negative or NaN
+///
+/// .. [1] Horton, J. D. A polynomial-time algorithm to find the shortest
+///    cycle basis of a graph. SIAM Journal on Computing 16, 2 (1987),
+///    358-366.
+#[pyfunction(default_weight = "1.0")]
+#[pyo3(text_signature = "(graph, /, weight_fn=None, default_weight=1.0)")]
+pub fn minimum_cycle_basis(
+    py: Python,
+    graph: &graph::PyGraph,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+) -> PyResult<Vec<Vec<usize>>> {
+    let cost_fn = CostFn::try_from((weight_fn, default_weight))?;
+    connectivity::minimum_cycle_basis(&graph.graph, |e| cost_fn.call(py, e.weight()))
+}
+
+/// Find a cycle cover of a :class:`~retworkx.PyGraph` from its minimum weight
+/// cycle basis.
+///
+/// A cycle cover is a set of cycles such that every edge which is on a cycle
+/// of the graph, every edge which isn't a bridge, is on one of them. This
+/// takes the cycles of the :func:`~retworkx.minimum_cycle_basis` from the
+/// lightest one, keeping the ones with an edge which isn't covered yet, and
+/// then drops the heaviest kept cycles whose edges are all covered by the
+/// other kept cycles. The result isn't always a minimum weight cycle cover,
+/// finding one is NP-hard.
+///
+/// :param PyGraph graph: The graph to find the cycle cover of
+/// :param weight_fn: An optional callable that will be passed the edge's
+///     payload object and is expected to return a non-negative ``float``
+///     weight for that edge. If this is not specified ``default_weight`` is
+///     used for every edge.
+/// :param float default_weight: If ``weight_fn`` is not set the default
+///     weight value to use for every edge. It must be non-negative.
+///
+/// :returns: A list of the cycles of the cover in non-decreasing order of
+///     weight. Each cycle is a list of the edge indices of the cycle in the
+///     order of a walk around it.
+/// :rtype: list
+///
+/// :raises ValueError: If an edge weight is negative or NaN
+#[pyfunction(default_weight = "1.0")]
+#[pyo3(text_signature = "(graph, /, weight_fn=None, default_weight=1.0)")]
+pub fn minimum_cycle_cover(
+    py: Python,
+    graph: &graph::PyGraph,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+) -> PyResult<Vec<Vec<usize>>> {
+    let cost_fn = CostFn::try_from((weight_fn, default_weight))?;
+    connectivity::minimum_cycle_cover(&graph.graph, |e| cost_fn.call(py, e.weight()))
+}
+
+/// Return the sum of two cycles, or other sets of edges, in the cycle space of
+/// a graph.
+///
+/// The sum is the symmetric difference of the edges, the edges which are in
+/// exactly one of the sets.
+///
+/// :param list first: The edge indices of the first set of edges
+/// :param list second: The edge indices of the second set of edges
+///
+/// :returns: The sorted edge indices of the sum
+/// :rtype: list
+#[pyfunction]
+#[pyo3(text_signature = "(first, second, /)")]
+pub fn cycle_sum(first: Vec<usize>, second: Vec<usize>) -> Vec<usize> {
+    connectivity::cycle_sum(first, second)
+}
+
+/// Return if a set of edges of a :class:`~retworkx.PyGraph` is in its cycle
+/// space.
+///
+/// A set of edges is in the cycle space of the graph, it's a sum of cycles of
+/// the graph, if every node has an even number of edges from the set. A self
+/// loop counts twice.
+///
+/// :param PyGraph graph: The graph the edges are from
+/// :param list edges: The edge indices of the set of edges. Indices which
+///     aren't edges of ``graph`` are ignored.
+///
+/// :returns: ``True`` if ``edges`` is in the cycle space of ``graph``
+/// :rtype: bool
+#[pyfunction]
+#[pyo3(text_signature = "(graph, edges, /)")]
+pub fn is_in_cycle_space(graph: &graph::PyGraph, edges: Vec<usize>) -> bool {
+    connectivity::is_in_cycle_space(&graph.graph, edges)
+}
+
 /// Compute the strongly connected components for a directed graph
 ///
 /// This function is implemented using Kosaraju's algorithm
@@ -143,6 +276,95 @@ pub fn strongly_connected_components(graph: &digraph::PyDiGraph) -> Vec<Vec<usiz
         .collect()
 }
 
+/// Compute the condensation of a directed graph
+///
+/// The condensation of a directed graph contracts every strongly connected
+/// component into a single node. There is an edge between two nodes of the
+/// condensation if there is at least one edge between the corresponding
+/// components in ``graph``, so the cross-component edges are deduplicated.
+/// The condensation of a graph is always a directed acyclic graph.
+///
+/// The node at index ``i`` of the condensation is the ``i``-th component and
+/// its weight/data payload is the list of the node indices of its members in
+/// ``graph``. The edges of the condensation have ``None`` as their
+/// weight/data payload.
+///
+/// :param PyDiGraph graph: The directed graph to condense
+/// :param list sccs: An optional list of the strongly connected components
+///     of ``graph``, as lists of node indices, if they were already computed
+///     with :func:`~retworkx.strongly_connected_components`. If not specified
+///     they will be computed. Every node of ``graph`` must be in exactly one
+///     component, if the components aren't strongly connected the result
+///     won't be acyclic.
+///
+/// :returns: The condensation of ``graph``
+/// :rtype: PyDiGraph
+/// :raises InvalidNode: If a node index in ``sccs`` isn't in ``graph``
+/// :raises ValueError: If ``sccs`` doesn't contain every node of ``graph``
+///     exactly once
+#[pyfunction]
+#[pyo3(text_signature = "(graph, /, sccs=None)")]
+pub fn condensation(
+    py: Python,
+    graph: &digraph::PyDiGraph,
+    sccs: Option<Vec<Vec<usize>>>,
+) -> PyResult<digraph::PyDiGraph> {
+    let sccs = match sccs {
+        Some(sccs) => sccs,
+        None => strongly_connected_components(graph),
+    };
+    let mut component: Vec<Option<usize>> = vec![None; graph.graph.node_bound()];
+    for (index, members) in sccs.iter().enumerate() {
+        for node in members {
+            if !graph.graph.contains_node(NodeIndex::new(*node)) {
+                return Err(InvalidNode::new_err(format!(
+                    "Node index {} is not in the graph",
+                    node
+                )));
+            }
+            if component[*node].replace(index).is_some() {
+                return Err(PyValueError::new_err(format!(
+                    "Node index {} is in more than one component",
+                    node
+                )));
+            }
+        }
+    }
+    if let Some(node) = graph
+        .graph
+        .node_indices()
+        .find(|n| component[n.index()].is_none())
+    {
+        return Err(PyValueError::new_err(format!(
+            "Node index {} is not in any component",
+            node.index()
+        )));
+    }
+
+    let mut out_graph = StablePyGraph::<Directed>::with_capacity(sccs.len(), 0);
+    for members in &sccs {
+        out_graph.add_node(members.to_object(py));
+    }
+    let mut seen: HashSet<(usize, usize)> = HashSet::new();
+    for edge in graph.graph.edge_references() {
+        let source = component[edge.source().index()].unwrap();
+        let target = component[edge.target().index()].unwrap();
+        if source != target && seen.insert((source, target)) {
+            out_graph.add_edge(NodeIndex::new(source), NodeIndex::new(target), py.None());
+        }
+    }
+    Ok(digraph::PyDiGraph {
+        graph: out_graph,
+        node_removed: false,
+        check_cycle: false,
+        cycle_state: None,
+        multigraph: true,
+        node_keys: NodeKeys::default(),
+        attributes: AttributeColumns::default(),
+        generation: 0,
+    })
+}
+
 /// Return the first cycle encountered during DFS of a given PyDiGraph,
 /// empty list is returned if no cycle is found
 ///
@@ -345,10 +567,147 @@ pub fn is_weakly_connected(graph: &digraph::PyDiGraph) -> PyResult<bool> {
     Ok(weakly_connected_components(graph)[0].len() == graph.graph.node_count())
 }
 
+/// How the weights of parallel edges are combined in an adjacency matrix
+#[derive(Clone, Copy)]
+enum ParallelEdge {
+    Sum,
+    Min,
+    Max,
+    Last,
+}
+
+impl ParallelEdge {
+    fn from_name(name: &str) -> PyResult<Self> {
+        match name {
+            "sum" => Ok(ParallelEdge::Sum),
+            "min" => Ok(ParallelEdge::Min),
+            "max" => Ok(ParallelEdge::Max),
+            "last" => Ok(ParallelEdge::Last),
+            _ => Err(PyValueError::new_err(format!(
+                "Invalid parallel_edge value '{}', it must be one of 'sum', 'min', 'max' or 'last'",
+                name
+            ))),
+        }
+    }
+}
+
+/// The name of a numpy data type, ``float64`` if it's not given
+fn dtype_name(py: Python, dtype: Option<&PyAny>) -> PyResult<String> {
+    match dtype {
+        Some(dtype) => py
+            .import("numpy")?
+            .getattr("dtype")?
+            .call1((dtype,))?
+            .getattr("name")?
+            .extract(),
+        None => Ok("float64".to_string()),
+    }
+}
+
+fn fill_adjacency_matrix<Ty, T, F>(
+    py: Python,
+    graph: &StablePyGraph<Ty>,
+    weight_fn: &Option<PyObject>,
+    default_weight: T,
+    null_value: T,
+    combine: F,
+) -> PyResult<Array2<T>>
+where
+    Ty: EdgeType,
+    T: Copy + for<'p> FromPyObject<'p>,
+    F: Fn(T, T) -> T,
+{
+    let n = graph.node_count();
+    let mut matrix = Array2::<T>::from_elem((n, n), null_value);
+    let mut has_edge = Array2::<bool>::from_elem((n, n), false);
+    for (i, j, weight) in get_edge_iter_with_weights(graph) {
+        let edge_weight = weight_callable(py, weight_fn, &weight, default_weight)?;
+        let value = if has_edge[[i, j]] {
+            combine(matrix[[i, j]], edge_weight)
+        } else {
+            edge_weight
+        };
+        matrix[[i, j]] = value;
+        has_edge[[i, j]] = true;
+        if !Ty::is_directed() {
+            matrix[[j, i]] = value;
+            has_edge[[j, i]] = true;
+        }
+    }
+    Ok(matrix)
+}
+
+fn adjacency_matrix<Ty: EdgeType>(
+    py: Python,
+    graph: &StablePyGraph<Ty>,
+    weight_fn: Option<PyObject>,
+    default_weight: Complex64,
+    null_value: Complex64,
+    dtype: Option<&PyAny>,
+    parallel_edge: &str,
+) -> PyResult<PyObject> {
+    let parallel_edge = ParallelEdge::from_name(parallel_edge)?;
+    let dtype_name = dtype_name(py, dtype)?;
+    match dtype_name.as_str() {
+        "complex128" | "complex64" => {
+            let combine = match parallel_edge {
+                ParallelEdge::Sum => |a: Complex64, b: Complex64| a + b,
+                ParallelEdge::Last => |_: Complex64, b: Complex64| b,
+                ParallelEdge::Min | ParallelEdge::Max => {
+                    return Err(PyValueError::new_err(
+                        "The 'min' and 'max' parallel_edge values require a real dtype",
+                    ))
+                }
+            };
+            let matrix =
+                fill_adjacency_matrix(py, graph, &weight_fn, default_weight, null_value, combine)?;
+            if dtype_name == "complex64" {
+                Ok(matrix
+                    .mapv(|x| Complex32::new(x.re as f32, x.im as f32))
+                    .into_pyarray(py)
+                    .into())
+            } else {
+                Ok(matrix.into_pyarray(py).into())
+            }
+        }
+        "float64" | "float32" => {
+            if default_weight.im != 0.0 || null_value.im != 0.0 {
+                return Err(PyValueError::new_err(
+                    "default_weight and null_value must be real with a real dtype",
+                ));
+            }
+            let combine = match parallel_edge {
+                ParallelEdge::Sum => |a: f64, b: f64| a + b,
+                ParallelEdge::Min => |a: f64, b: f64| a.min(b),
+                ParallelEdge::Max => |a: f64, b: f64| a.max(b),
+                ParallelEdge::Last => |_: f64, b: f64| b,
+            };
+            let matrix = fill_adjacency_matrix(
+                py,
+                graph,
+                &weight_fn,
+                default_weight.re,
+                null_value.re,
+                combine,
+            )?;
+            if dtype_name == "float32" {
+                Ok(matrix.mapv(|x| x as f32).into_pyarray(py).into())
+            } else {
+                Ok(matrix.into_pyarray(py).into())
+            }
+        }
+        _ => Err(PyValueError::new_err(format!(
+            "Unsupported dtype {}, it must be float64, float32, complex128 or complex64",
+            dtype_name
+        ))),
+    }
+}
+
 /// Return the adjacency matrix for a PyDiGraph object
 ///
 /// In the case where there are multiple edges between nodes the value in the
-/// output matrix will be the sum of the edges' weights.
+/// output matrix will be the sum of the edges' weights, unless another
+/// ``parallel_edge`` value is given.
 ///
 /// :param PyDiGraph graph: The DiGraph used to generate the adjacency matrix
 ///     from
@@ -372,35 +731,54 @@ pub fn is_weakly_connected(graph: &digraph::PyDiGraph) -> PyResult<bool> {
 ///     value. This is the default value in the output matrix and it is used
 ///     to indicate the absence of an edge between 2 nodes. By default this is
 ///     ``0.0``.
+/// :param dtype: The numpy data type of the output matrix, one of
+///     ``numpy.float64``, ``numpy.float32``, ``numpy.complex128`` or
+///     ``numpy.complex64`` (or any object ``numpy.dtype`` accepts for them).
+///     With a complex data type ``weight_fn`` can return a ``complex`` and
+///     ``default_weight`` and ``null_value`` can be ``complex`` too. By
+///     default this is ``numpy.float64``.
+/// :param str parallel_edge: How the weights of parallel edges between 2
+///     nodes are combined in the output matrix, one of ``"sum"`` (the
+///     default), ``"min"``, ``"max"`` or ``"last"`` for the weight of the edge
+///     with the highest edge index. ``"min"`` and ``"max"`` are not supported
+///     with a complex data type.
 ///
 ///  :return: The adjacency matrix for the input directed graph as a numpy array
 ///  :rtype: numpy.ndarray
-#[pyfunction(default_weight = "1.0", null_value = "0.0")]
-#[pyo3(text_signature = "(graph, /, weight_fn=None, default_weight=1.0, null_value=0.0)")]
+/// :raises ValueError: If ``dtype`` or ``parallel_edge`` is not supported
+#[pyfunction(
+    default_weight = "Complex64::new(1.0, 0.0)",
+    null_value = "Complex64::zero()",
+    parallel_edge = "\"sum\""
+)]
+#[pyo3(
+    text_signature = "(graph, /, weight_fn=None, default_weight=1.0, null_value=0.0, dtype=None, parallel_edge=\"sum\")"
+)]
 pub fn digraph_adjacency_matrix(
     py: Python,
     graph: &digraph::PyDiGraph,
     weight_fn: Option<PyObject>,
-    default_weight: f64,
-    null_value: f64,
+    default_weight: Complex64,
+    null_value: Complex64,
+    dtype: Option<&PyAny>,
+    parallel_edge: &str,
 ) -> PyResult<PyObject> {
-    let n = graph.node_count();
-    let mut matrix = Array2::<f64>::from_elem((n, n), null_value);
-    for (i, j, weight) in get_edge_iter_with_weights(&graph.graph) {
-        let edge_weight = weight_callable(py, &weight_fn, &weight, default_weight)?;
-        if matrix[[i, j]] == null_value || (null_value.is_nan() && matrix[[i, j]].is_nan()) {
-            matrix[[i, j]] = edge_weight;
-        } else {
-            matrix[[i, j]] += edge_weight;
-        }
-    }
-    Ok(matrix.into_pyarray(py).into())
+    adjacency_matrix(
+        py,
+        &graph.graph,
+        weight_fn,
+        default_weight,
+        null_value,
+        dtype,
+        parallel_edge,
+    )
 }
 
 /// Return the adjacency matrix for a PyGraph class
 ///
 /// In the case where there are multiple edges between nodes the value in the
-/// output matrix will be the sum of the edges' weights.
+/// output matrix will be the sum of the edges' weights, unless another
+/// ``parallel_edge`` value is given.
 ///
 /// :param PyGraph graph: The graph used to generate the adjacency matrix from
 /// :param weight_fn: A callable object (function, lambda, etc) which
@@ -423,31 +801,314 @@ pub fn digraph_adjacency_matrix(
 ///     value. This is the default value in the output matrix and it is used
 ///     to indicate the absence of an edge between 2 nodes. By default this is
 ///     ``0.0``.
+/// :param dtype: The numpy data type of the output matrix, one of
+///     ``numpy.float64``, ``numpy.float32``, ``numpy.complex128`` or
+///     ``numpy.complex64`` (or any object ``numpy.dtype`` accepts for them).
+///     With a complex data type ``weight_fn`` can return a ``complex`` and
+///     ``default_weight`` and ``null_value`` can be ``complex`` too. By
+///     default this is ``numpy.float64``.
+/// :param str parallel_edge: How the weights of parallel edges between 2
+///     nodes are combined in the output matrix, one of ``"sum"`` (the
+///     default), ``"min"``, ``"max"`` or ``"last"`` for the weight of the edge
+///     with the highest edge index. ``"min"`` and ``"max"`` are not supported
+///     with a complex data type.
 ///
 /// :return: The adjacency matrix for the input graph as a numpy array
 /// :rtype: numpy.ndarray
-#[pyfunction(default_weight = "1.0", null_value = "0.0")]
-#[pyo3(text_signature = "(graph, /, weight_fn=None, default_weight=1.0, null_value=0.0)")]
+/// :raises ValueError: If ``dtype`` or ``parallel_edge`` is not supported
+#[pyfunction(
+    default_weight = "Complex64::new(1.0, 0.0)",
+    null_value = "Complex64::zero()",
+    parallel_edge = "\"sum\""
+)]
+#[pyo3(
+    text_signature = "(graph, /, weight_fn=None, default_weight=1.0, null_value=0.0, dtype=None, parallel_edge=\"sum\")"
+)]
 pub fn graph_adjacency_matrix(
     py: Python,
     graph: &graph::PyGraph,
     weight_fn: Option<PyObject>,
-    default_weight: f64,
-    null_value: f64,
+    default_weight: Complex64,
+    null_value: Complex64,
+    dtype: Option<&PyAny>,
+    parallel_edge: &str,
 ) -> PyResult<PyObject> {
+    adjacency_matrix(
+        py,
+        &graph.graph,
+        weight_fn,
+        default_weight,
+        null_value,
+        dtype,
+        parallel_edge,
+    )
+}
+
+/// The ``indptr``, ``indices`` and ``data`` arrays of the adjacency matrix
+/// in CSR format, with the entries of parallel edges combined in order of
+/// their edge indices.
+#[allow(clippy::type_complexity)]
+fn fill_csr_adjacency_matrix<Ty, T, F>(
+    py: Python,
+    graph: &StablePyGraph<Ty>,
+    weight_fn: &Option<PyObject>,
+    default_weight: T,
+    combine: F,
+) -> PyResult<(Vec<i64>, Vec<i64>, Vec<T>)>
+where
+    Ty: EdgeType,
+    T: Copy + for<'p> FromPyObject<'p>,
+    F: Fn(T, T) -> T,
+{
     let n = graph.node_count();
-    let mut matrix = Array2::<f64>::from_elem((n, n), null_value);
-    for (i, j, weight) in get_edge_iter_with_weights(&graph.graph) {
-        let edge_weight = weight_callable(py, &weight_fn, &weight, default_weight)?;
-        if matrix[[i, j]] == null_value || (null_value.is_nan() && matrix[[i, j]].is_nan()) {
-            matrix[[i, j]] = edge_weight;
-            matrix[[j, i]] = edge_weight;
-        } else {
-            matrix[[i, j]] += edge_weight;
-            matrix[[j, i]] += edge_weight;
+    let mut edges = Vec::with_capacity(graph.edge_count());
+    for (i, j, weight) in get_edge_iter_with_weights(graph) {
+        edges.push((
+            i,
+            j,
+            weight_callable(py, weight_fn, &weight, default_weight)?,
+        ));
+    }
+    // Bucket the entries by row, keeping them in edge index order
+    let mut row_start = vec![0; n + 1];
+    for &(i, j, _) in &edges {
+        row_start[i + 1] += 1;
+        if !Ty::is_directed() && i != j {
+            row_start[j + 1] += 1;
+        }
+    }
+    for row in 0..n {
+        row_start[row + 1] += row_start[row];
+    }
+    let mut entries = vec![(0, default_weight); row_start[n]];
+    let mut next = row_start.clone();
+    for (i, j, weight) in edges {
+        entries[next[i]] = (j, weight);
+        next[i] += 1;
+        if !Ty::is_directed() && i != j {
+            entries[next[j]] = (i, weight);
+            next[j] += 1;
+        }
+    }
+    let mut indptr = Vec::with_capacity(n + 1);
+    let mut indices: Vec<i64> = Vec::with_capacity(entries.len());
+    let mut data: Vec<T> = Vec::with_capacity(entries.len());
+    indptr.push(0);
+    for row in 0..n {
+        let row_entries = &mut entries[row_start[row]..row_start[row + 1]];
+        // A stable sort so parallel edges stay in edge index order
+        row_entries.sort_by_key(|entry| entry.0);
+        let mut last_col = None;
+        for &(col, weight) in row_entries.iter() {
+            if last_col == Some(col) {
+                let last = data.len() - 1;
+                data[last] = combine(data[last], weight);
+            } else {
+                indices.push(col as i64);
+                data.push(weight);
+                last_col = Some(col);
+            }
         }
+        indptr.push(indices.len() as i64);
     }
-    Ok(matrix.into_pyarray(py).into())
+    Ok((indptr, indices, data))
+}
+
+fn csr_adjacency_matrix<Ty: EdgeType>(
+    py: Python,
+    graph: &StablePyGraph<Ty>,
+    weight_fn: Option<PyObject>,
+    default_weight: Complex64,
+    dtype: Option<&PyAny>,
+    parallel_edge: &str,
+) -> PyResult<(PyObject, PyObject, PyObject)> {
+    let parallel_edge = ParallelEdge::from_name(parallel_edge)?;
+    let dtype_name = dtype_name(py, dtype)?;
+    match dtype_name.as_str() {
+        "complex128" | "complex64" => {
+            let combine = match parallel_edge {
+                ParallelEdge::Sum => |a: Complex64, b: Complex64| a + b,
+                ParallelEdge::Last => |_: Complex64, b: Complex64| b,
+                ParallelEdge::Min | ParallelEdge::Max => {
+                    return Err(PyValueError::new_err(
+                        "The 'min' and 'max' parallel_edge values require a real dtype",
+                    ))
+                }
+            };
+            let (indptr, indices, data) =
+                fill_csr_adjacency_matrix(py, graph, &weight_fn, default_weight, combine)?;
+            let data: PyObject = if dtype_name == "complex64" {
+                data.into_iter()
+                    .map(|x| Complex32::new(x.re as f32, x.im as f32))
+                    .collect::<Vec<Complex32>>()
+                    .into_pyarray(py)
+                    .into()
+            } else {
+                data.into_pyarray(py).into()
+            };
+            Ok((
+                indptr.into_pyarray(py).into(),
+                indices.into_pyarray(py).into(),
+                data,
+            ))
+        }
+        "float64" | "float32" => {
+            if default_weight.im != 0.0 {
+                return Err(PyValueError::new_err(
+                    "default_weight must be real with a real dtype",
+                ));
+            }
+            let combine = match parallel_edge {
+                ParallelEdge::Sum => |a: f64, b: f64| a + b,
+                ParallelEdge::Min => |a: f64, b: f64| a.min(b),
+                ParallelEdge::Max => |a: f64, b: f64| a.max(b),
+                ParallelEdge::Last => |_: f64, b: f64| b,
+            };
+            let (indptr, indices, data) =
+                fill_csr_adjacency_matrix(py, graph, &weight_fn, default_weight.re, combine)?;
+            let data: PyObject = if dtype_name == "float32" {
+                data.into_iter()
+                    .map(|x| x as f32)
+                    .collect::<Vec<f32>>()
+                    .into_pyarray(py)
+                    .into()
+            } else {
+                data.into_pyarray(py).into()
+            };
+            Ok((
+                indptr.into_pyarray(py).into(),
+                indices.into_pyarray(py).into(),
+                data,
+            ))
+        }
+        _ => Err(PyValueError::new_err(format!(
+            "Unsupported dtype {}, it must be float64, float32, complex128 or complex64",
+            dtype_name
+        ))),
+    }
+}
+
+/// Return the adjacency matrix for a PyDiGraph object in CSR format
+///
+/// This builds the ``indptr``, ``indices`` and ``data`` arrays of the
+/// compressed sparse row representation of the matrix returned by
+/// :func:`~retworkx.digraph_adjacency_matrix` directly, without creating
+/// the dense matrix, so it can be used for large graphs. Row ``i`` of the
+/// matrix is for the ``i``-th node in :meth:`~retworkx.PyDiGraph.node_indices`
+/// and there is an entry for every pair of nodes with an edge, sorted by
+/// column in every row. The arrays can be passed to ``scipy.sparse``::
+///
+///     indptr, indices, data = retworkx.digraph_csr_adjacency_matrix(graph)
+///     n = len(graph)
+///     matrix = scipy.sparse.csr_matrix((data, indices, indptr), shape=(n, n))
+///
+/// :param PyDiGraph graph: The DiGraph used to generate the adjacency matrix
+///     from
+/// :param callable weight_fn: A callable object (function, lambda, etc) which
+///     will be passed the edge object and expected to return a ``float`` for
+///     the weight of the edge. If this is not specified ``default_weight``
+///     will be used for all edges.
+/// :param float default_weight: If ``weight_fn`` is not used this can be
+///     optionally used to specify a default weight to use for all edges.
+///     Defaults to 1.0.
+/// :param dtype: The numpy data type of the ``data`` array, one of
+///     ``numpy.float64``, ``numpy.float32``, ``numpy.complex128`` or
+///     ``numpy.complex64`` (or any object ``numpy.dtype`` accepts for them).
+///     With a complex data type ``weight_fn`` can return a ``complex`` and
+///     ``default_weight`` can be ``complex`` too. By default this is
+///     ``numpy.float64``.
+/// :param str parallel_edge: How the weights of parallel edges between 2
+///     nodes are combined in a single entry, one of ``"sum"`` (the
+///     default), ``"min"``, ``"max"`` or ``"last"`` for the weight of the edge
+///     with the highest edge index. ``"min"`` and ``"max"`` are not supported
+///     with a complex data type.
+///
+/// :return: A tuple of the ``indptr``, ``indices`` and ``data`` numpy arrays,
+///     where ``indptr`` and ``indices`` are ``numpy.int64`` arrays
+/// :rtype: tuple
+/// :raises ValueError: If ``dtype`` or ``parallel_edge`` is not supported
+#[pyfunction(default_weight = "Complex64::new(1.0, 0.0)", parallel_edge = "\"sum\"")]
+#[pyo3(
+    text_signature = "(graph, /, weight_fn=None, default_weight=1.0, dtype=None, parallel_edge=\"sum\")"
+)]
+pub fn digraph_csr_adjacency_matrix(
+    py: Python,
+    graph: &digraph::PyDiGraph,
+    weight_fn: Option<PyObject>,
+    default_weight: Complex64,
+    dtype: Option<&PyAny>,
+    parallel_edge: &str,
+) -> PyResult<(PyObject, PyObject, PyObject)> {
+    csr_adjacency_matrix(
+        py,
+        &graph.graph,
+        weight_fn,
+        default_weight,
+        dtype,
+        parallel_edge,
+    )
+}
+
+/// Return the adjacency matrix for a PyGraph object in CSR format
+///
+/// This builds the ``indptr``, ``indices`` and ``data`` arrays of the
+/// compressed sparse row representation of the matrix returned by
+/// :func:`~retworkx.graph_adjacency_matrix` directly, without creating the
+/// dense matrix, so it can be used for large graphs. Row ``i`` of the
+/// matrix is for the ``i``-th node in :meth:`~retworkx.PyGraph.node_indices`
+/// and there is an entry for every pair of nodes with an edge, sorted by
+/// column in every row. As the matrix is symmetric an edge between 2
+/// different nodes has an entry in the rows of both nodes. The arrays can
+/// be passed to ``scipy.sparse``::
+///
+///     indptr, indices, data = retworkx.graph_csr_adjacency_matrix(graph)
+///     n = len(graph)
+///     matrix = scipy.sparse.csr_matrix((data, indices, indptr), shape=(n, n))
+///
+/// :param PyGraph graph: The graph used to generate the adjacency matrix from
+/// :param callable weight_fn: A callable object (function, lambda, etc) which
+///     will be passed the edge object and expected to return a ``float`` for
+///     the weight of the edge. If this is not specified ``default_weight``
+///     will be used for all edges.
+/// :param float default_weight: If ``weight_fn`` is not used this can be
+///     optionally used to specify a default weight to use for all edges.
+///     Defaults to 1.0.
+/// :param dtype: The numpy data type of the ``data`` array, one of
+///     ``numpy.float64``, ``numpy.float32``, ``numpy.complex128`` or
+///     ``numpy.complex64`` (or any object ``numpy.dtype`` accepts for them).
+///     With a complex data type ``weight_fn`` can return a ``complex`` and
+///     ``default_weight`` can be ``complex`` too. By default this is
+///     ``numpy.float64``.
+/// :param str parallel_edge: How the weights of parallel edges between 2
+///     nodes are combined in a single entry, one of ``"sum"`` (the
+///     default), ``"min"``, ``"max"`` or ``"last"`` for the weight of the edge
+///     with the highest edge index. ``"min"`` and ``"max"`` are not supported
+///     with a complex data type.
+///
+/// :return: A tuple of the ``indptr``, ``indices`` and ``data`` numpy arrays,
+///     where ``indptr`` and ``indices`` are ``numpy.int64`` arrays
+/// :rtype: tuple
+/// :raises ValueError: If ``dtype`` or ``parallel_edge`` is not supported
+#[pyfunction(default_weight = "Complex64::new(1.0, 0.0)", parallel_edge = "\"sum\"")]
+#[pyo3(
+    text_signature = "(graph, /, weight_fn=None, default_weight=1.0, dtype=None, parallel_edge=\"sum\")"
+)]
+pub fn graph_csr_adjacency_matrix(
+    py: Python,
+    graph: &graph::PyGraph,
+    weight_fn: Option<PyObject>,
+    default_weight: Complex64,
+    dtype: Option<&PyAny>,
+    parallel_edge: &str,
+) -> PyResult<(PyObject, PyObject, PyObject)> {
+    csr_adjacency_matrix(
+        py,
+        &graph.graph,
+        weight_fn,
+        default_weight,
+        dtype,
+        parallel_edge,
+    )
 }
 
 /// Compute the complement of an undirected graph.
@@ -467,6 +1128,7 @@ pub fn graph_adjacency_matrix(
 pub fn graph_complement(py: Python, graph: &graph::PyGraph) -> PyResult<graph::PyGraph> {
     let mut complement_graph = graph.clone(); // keep same node indices
     complement_graph.graph.clear_edges();
+    complement_graph.attributes.prune(&complement_graph.graph);
 
     for node_a in graph.graph.node_indices() {
         let old_neighbors: HashSet<NodeIndex> = graph.graph.neighbors(node_a).collect();
@@ -501,6 +1163,7 @@ pub fn graph_complement(py: Python, graph: &graph::PyGraph) -> PyResult<graph::P
 pub fn digraph_complement(py: Python, graph: &digraph::PyDiGraph) -> PyResult<digraph::PyDiGraph> {
     let mut complement_graph = graph.clone(); // keep same node indices
     complement_graph.graph.clear_edges();
+    complement_graph.attributes.prune(&complement_graph.graph);
 
     for node_a in graph.graph.node_indices() {
         let old_neighbors: HashSet<NodeIndex> = graph
@@ -517,6 +1180,87 @@ pub fn digraph_complement(py: Python, graph: &digraph::PyDiGraph) -> PyResult<di
     Ok(complement_graph)
 }
 
+/// Compute the ``k``-th power of an undirected graph.
+///
+/// The ``k``-th power of a graph has the same nodes, and an edge between
+/// every pair of distinct nodes with a path of at most ``k`` edges between
+/// them in the graph. The neighborhood of each node is expanded one hop at
+/// a time with a breadth-first search, so this is efficient for large sparse
+/// graphs and never computes a dense matrix power.
+///
+/// :param PyGraph graph: The graph to be used.
+/// :param int k: The power, the maximum number of hops between the
+///     endpoints of an edge. It must be at least 1.
+///
+/// :returns: The ``k``-th power of the graph. It has the same node indices
+///     and node data as ``graph``, and the data of every edge is ``None``.
+/// :rtype: PyGraph
+///
+/// :raises ValueError: If ``k`` is 0
+///
+/// .. note::
+///
+///     Parallel edges and self-loops are never created,
+///     even if the :attr:`~retworkx.PyGraph.multigraph`
+///     attribute is set to ``True``
+#[pyfunction]
+#[pyo3(text_signature = "(graph, k, /)")]
+pub fn graph_power(py: Python, graph: &graph::PyGraph, k: usize) -> PyResult<graph::PyGraph> {
+    if k == 0 {
+        return Err(PyValueError::new_err("k must be at least 1"));
+    }
+    let mut power_graph = graph.clone(); // keep same node indices
+    power_graph.graph.clear_edges();
+    power_graph.attributes.prune(&power_graph.graph);
+    for (source, target) in power_edges(&graph.graph, k) {
+        power_graph.graph.add_edge(source, target, py.None());
+    }
+    Ok(power_graph)
+}
+
+/// Compute the ``k``-th power of a directed graph.
+///
+/// The ``k``-th power of a directed graph has the same nodes, and an edge
+/// from ``u`` to ``v`` for every pair of distinct nodes such that ``v`` can
+/// be reached from ``u`` in at most ``k`` hops along the edge directions.
+/// The neighborhood of each node is expanded one hop at a time with a
+/// breadth-first search, so this is efficient for large sparse graphs and
+/// never computes a dense matrix power.
+///
+/// :param PyDiGraph graph: The graph to be used.
+/// :param int k: The power, the maximum number of hops between the
+///     endpoints of an edge. It must be at least 1.
+///
+/// :returns: The ``k``-th power of the graph. It has the same node indices
+///     and node data as ``graph``, and the data of every edge is ``None``.
+/// :rtype: PyDiGraph
+///
+/// :raises ValueError: If ``k`` is 0
+///
+/// .. note::
+///
+///     Parallel edges and self-loops are never created,
+///     even if the :attr:`~retworkx.PyDiGraph.multigraph`
+///     attribute is set to ``True``
+#[pyfunction]
+#[pyo3(text_signature = "(graph, k, /)")]
+pub fn digraph_power(
+    py: Python,
+    graph: &digraph::PyDiGraph,
+    k: usize,
+) -> PyResult<digraph::PyDiGraph> {
+    if k == 0 {
+        return Err(PyValueError::new_err("k must be at least 1"));
+    }
+    let mut power_graph = graph.clone(); // keep same node indices
+    power_graph.graph.clear_edges();
+    power_graph.attributes.prune(&power_graph.graph);
+    for (source, target) in power_edges(&graph.graph, k) {
+        power_graph.graph.add_edge(source, target, py.None());
+    }
+    Ok(power_graph)
+}
+
 /// Return all simple paths between 2 nodes in a PyGraph object
 ///
 /// A simple path is a path with no repeated nodes.
@@ -666,6 +1410,69 @@ pub fn digraph_core_number(py: Python, graph: &digraph::PyDiGraph) -> PyResult<P
     core_number::core_number(py, &graph.graph)
 }
 
+/// Return the k-core of a graph.
+///
+/// A k-core is a maximal subgraph that contains nodes of degree k or more.
+/// It's made up of the nodes with a core number (as returned by
+/// :func:`~retworkx.graph_core_number`) of at least ``k``.
+///
+/// .. note::
+///
+///     The function implicitly assumes that there are no parallel edges
+///     or self loops. It may produce incorrect/unexpected results if the
+///     input graph has self loops or parallel edges.
+///
+/// :param PyGraph graph: The graph to find the k-core of
+/// :param int k: The order of the core. If not specified the largest core
+///     number of any node in the graph is used, which returns the main core.
+///
+/// :returns: The k-core of the graph as a subgraph, the node and edge
+///     weights are shared with ``graph``.
+/// :rtype: PyGraph
+#[pyfunction]
+#[pyo3(text_signature = "(graph, /, k=None)")]
+pub fn graph_k_core(py: Python, graph: &graph::PyGraph, k: Option<usize>) -> graph::PyGraph {
+    let nodes = connectivity::k_core(&graph.graph, k)
+        .into_iter()
+        .map(|n| n.index())
+        .collect();
+    graph.subgraph(py, nodes)
+}
+
+/// Return the k-core of a directed graph.
+///
+/// A k-core is a maximal subgraph that contains nodes of degree k or more.
+/// It's made up of the nodes with a core number (as returned by
+/// :func:`~retworkx.digraph_core_number`) of at least ``k``. For directed
+/// graphs, the degree is calculated as in_degree + out_degree.
+///
+/// .. note::
+///
+///     The function implicitly assumes that there are no parallel edges
+///     or self loops. It may produce incorrect/unexpected results if the
+///     input graph has self loops or parallel edges.
+///
+/// :param PyDiGraph graph: The directed graph to find the k-core of
+/// :param int k: The order of the core. If not specified the largest core
+///     number of any node in the graph is used, which returns the main core.
+///
+/// :returns: The k-core of the graph as a subgraph, the node and edge
+///     weights are shared with ``graph``.
+/// :rtype: PyDiGraph
+#[pyfunction]
+#[pyo3(text_signature = "(graph, /, k=None)")]
+pub fn digraph_k_core(
+    py: Python,
+    graph: &digraph::PyDiGraph,
+    k: Option<usize>,
+) -> digraph::PyDiGraph {
+    let nodes = connectivity::k_core(&graph.graph, k)
+        .into_iter()
+        .map(|n| n.index())
+        .collect();
+    graph.subgraph(py, nodes)
+}
+
 /// Return the articulation points of an undirected graph.
 ///
 /// An articulation point or cut vertex is any node whose removal (along with
@@ -717,15 +1524,45 @@ pub fn articulation_points(graph: &graph::PyGraph) -> HashSet<usize> {
 pub fn biconnected_components(graph: &graph::PyGraph) -> BiconnectedComponents {
     let mut bicomp = HashMap::new();
     connectivity::articulation_points(&graph.graph, Some(&mut bicomp));
+    let mut bicomp: Vec<((usize, usize), usize)> = bicomp
+        .into_iter()
+        .map(|((v, w), comp)| ((v.index(), w.index()), comp))
+        .collect();
+    deterministic_order(&mut bicomp, |(edge, _)| *edge);
 
     BiconnectedComponents {
-        bicon_comp: bicomp
-            .into_iter()
-            .map(|((v, w), comp)| ((v.index(), w.index()), comp))
-            .collect(),
+        bicon_comp: bicomp.into_iter().collect(),
     }
 }
 
+/// Return the bridges of an undirected graph.
+///
+/// A bridge or cut edge is any edge whose removal increases the number of
+/// connected components of a graph. Every bridge forms a biconnected
+/// component on its own, so the endpoints of a bridge are articulation points
+/// unless they have degree 1.
+///
+/// .. note::
+///
+///     The function implicitly assumes that there are no parallel edges
+///     or self loops. It may produce incorrect/unexpected results if the
+///     input graph has self loops or parallel edges.
+///
+/// :param PyGraph: The undirected graph to be used.
+///
+/// :returns: A set with the bridges of the graph as tuples of node indices
+///     ``(u, v)``, where ``u`` is the endpoint visited first by the
+///     depth-first search.
+/// :rtype: set
+#[pyfunction]
+#[pyo3(text_signature = "(graph, /)")]
+pub fn bridges(graph: &graph::PyGraph) -> HashSet<(usize, usize)> {
+    connectivity::bridges(&graph.graph)
+        .into_iter()
+        .map(|(v, w)| (v.index(), w.index()))
+        .collect()
+}
+
 /// Returns the chain decomposition of a graph.
 ///
 /// The *chain decomposition* of a graph with respect to a depth-first
@@ -772,3 +1609,338 @@ pub fn chain_decomposition(graph: graph::PyGraph, source: Option<usize>) -> Chai
             .collect(),
     }
 }
+
+fn source_target_pair<Ty: EdgeType>(
+    graph: &StablePyGraph<Ty>,
+    source: Option<usize>,
+    target: Option<usize>,
+) -> PyResult<Option<(NodeIndex, NodeIndex)>> {
+    match (source, target) {
+        (None, None) => Ok(None),
+        (Some(source), Some(target)) => {
+            for &node in &[source, target] {
+                if !graph.contains_node(NodeIndex::new(node)) {
+                    return Err(InvalidNode::new_err(format!(
+                        "Node index {} is not in the graph",
+                        node
+                    )));
+                }
+            }
+            if source == target {
+                return Err(PyValueError::new_err(
+                    "source and target must be different nodes",
+                ));
+            }
+            Ok(Some((NodeIndex::new(source), NodeIndex::new(target))))
+        }
+        _ => Err(PyValueError::new_err(
+            "Both source and target must be specified",
+        )),
+    }
+}
+
+fn edge_cut_list<Ty: EdgeType>(
+    graph: &StablePyGraph<Ty>,
+    source: Option<usize>,
+    target: Option<usize>,
+) -> PyResult<EdgeList> {
+    let source_target = source_target_pair(graph, source, target)?;
+    Ok(EdgeList {
+        edges: connectivity::minimum_edge_cut(graph, source_target)
+            .into_iter()
+            .map(|edge| (edge.source().index(), edge.target().index()))
+            .collect(),
+    })
+}
+
+fn node_cut_list<Ty: EdgeType>(
+    graph: &StablePyGraph<Ty>,
+    source: Option<usize>,
+    target: Option<usize>,
+) -> PyResult<NodeIndices> {
+    let source_target = source_target_pair(graph, source, target)?;
+    match connectivity::minimum_node_cut(graph, source_target) {
+        Some(cut) => Ok(NodeIndices {
+            nodes: cut.into_iter().map(|node| node.index()).collect(),
+        }),
+        None => Err(PyValueError::new_err(
+            "source and target are adjacent, no set of nodes separates them",
+        )),
+    }
+}
+
+/// Compute the edge connectivity of an undirected graph.
+///
+/// The edge connectivity of two nodes ``source`` and ``target`` is the
+/// minimum number of edges that need to be removed to disconnect them, which
+/// is equal to the maximum number of edge disjoint paths between them. The
+/// edge connectivity of a graph is the minimum edge connectivity of all the
+/// pairs of nodes. It's computed with maximum flows where every edge has a
+/// capacity of 1, with :math:`n - 1` flow computations for the global edge
+/// connectivity [1]_.
+///
+/// Self loops are ignored and parallel edges are counted separately.
+///
+/// :param PyGraph graph: The graph to compute the edge connectivity of
+/// :param int source: An optional source node index, if specified (with
+///     ``target``) the local edge connectivity of ``source`` and ``target``
+///     is returned instead of the edge connectivity of the graph.
+/// :param int target: An optional target node index
+///
+/// :returns: The edge connectivity, 0 if the graph isn't connected or has
+///     fewer than 2 nodes.
+/// :rtype: int
+///
+/// :raises InvalidNode: If ``source`` or ``target`` is not in the graph
+/// :raises ValueError: If only one of ``source`` and ``target`` is
+///     specified, or they are the same node
+///
+/// .. [1] Abdol-Hossein Esfahanian, "Connectivity Algorithms".
+///    http://www.cse.msu.edu/~cse835/Papers/Graph_connectivity_revised.pdf
+#[pyfunction]
+#[pyo3(text_signature = "(graph, /, source=None, target=None)")]
+pub fn graph_edge_connectivity(
+    graph: &graph::PyGraph,
+    source: Option<usize>,
+    target: Option<usize>,
+) -> PyResult<usize> {
+    let source_target = source_target_pair(&graph.graph, source, target)?;
+    Ok(connectivity::edge_connectivity(&graph.graph, source_target))
+}
+
+/// Compute the edge connectivity of a directed graph.
+///
+/// The edge connectivity of two nodes ``source`` and ``target`` is the
+/// minimum number of edges that need to be removed to disconnect ``target``
+/// from ``source``, which is equal to the maximum number of edge disjoint
+/// directed paths from ``source`` to ``target``. The edge connectivity of a
+/// graph is the minimum edge connectivity of all the pairs of nodes. It's
+/// computed with maximum flows where every edge has a capacity of 1, with
+/// :math:`n` flow computations for the global edge connectivity [1]_.
+///
+/// Self loops are ignored and parallel edges are counted separately.
+///
+/// :param PyDiGraph graph: The graph to compute the edge connectivity of
+/// :param int source: An optional source node index, if specified (with
+///     ``target``) the local edge connectivity of ``source`` and ``target``
+///     is returned instead of the edge connectivity of the graph.
+/// :param int target: An optional target node index
+///
+/// :returns: The edge connectivity, 0 if the graph isn't strongly connected
+///     or has fewer than 2 nodes.
+/// :rtype: int
+///
+/// :raises InvalidNode: If ``source`` or ``target`` is not in the graph
+/// :raises ValueError: If only one of ``source`` and ``target`` is
+///     specified, or they are the same node
+///
+/// .. [1] Abdol-Hossein Esfahanian, "Connectivity Algorithms".
+///    http://www.cse.msu.edu/~cse835/Papers/Graph_connectivity_revised.pdf
+#[pyfunction]
+#[pyo3(text_signature = "(graph, /, source=None, target=None)")]
+pub fn digraph_edge_connectivity(
+    graph: &digraph::PyDiGraph,
+    source: Option<usize>,
+    target: Option<usize>,
+) -> PyResult<usize> {
+    let source_target = source_target_pair(&graph.graph, source, target)?;
+    Ok(connectivity::edge_connectivity(&graph.graph, source_target))
+}
+
+/// Find a minimum set of edges that disconnects an undirected graph.
+///
+/// If ``source`` and ``target`` are specified the edges returned disconnect
+/// them, otherwise they disconnect some pair of nodes in the graph. The
+/// number of edges in the cut is the edge connectivity as returned by
+/// :func:`~retworkx.graph_edge_connectivity`.
+///
+/// :param PyGraph graph: The graph to find a minimum edge cut of
+/// :param int source: An optional source node index to separate from
+///     ``target``
+/// :param int target: An optional target node index
+///
+/// :returns: The edges in the cut as ``(source, target)`` tuples, empty if
+///     the graph isn't connected or has fewer than 2 nodes.
+/// :rtype: EdgeList
+///
+/// :raises InvalidNode: If ``source`` or ``target`` is not in the graph
+/// :raises ValueError: If only one of ``source`` and ``target`` is
+///     specified, or they are the same node
+#[pyfunction]
+#[pyo3(text_signature = "(graph, /, source=None, target=None)")]
+pub fn graph_minimum_edge_cut(
+    graph: &graph::PyGraph,
+    source: Option<usize>,
+    target: Option<usize>,
+) -> PyResult<EdgeList> {
+    edge_cut_list(&graph.graph, source, target)
+}
+
+/// Find a minimum set of edges that disconnects a directed graph.
+///
+/// If ``source`` and ``target`` are specified the edges returned disconnect
+/// ``target`` from ``source``, otherwise the graph is no longer strongly
+/// connected without them. The number of edges in the cut is the edge
+/// connectivity as returned by :func:`~retworkx.digraph_edge_connectivity`.
+///
+/// :param PyDiGraph graph: The graph to find a minimum edge cut of
+/// :param int source: An optional source node index to separate from
+///     ``target``
+/// :param int target: An optional target node index
+///
+/// :returns: The edges in the cut as ``(source, target)`` tuples, empty if
+///     the graph isn't strongly connected or has fewer than 2 nodes.
+/// :rtype: EdgeList
+///
+/// :raises InvalidNode: If ``source`` or ``target`` is not in the graph
+/// :raises ValueError: If only one of ``source`` and ``target`` is
+///     specified, or they are the same node
+#[pyfunction]
+#[pyo3(text_signature = "(graph, /, source=None, target=None)")]
+pub fn digraph_minimum_edge_cut(
+    graph: &digraph::PyDiGraph,
+    source: Option<usize>,
+    target: Option<usize>,
+) -> PyResult<EdgeList> {
+    edge_cut_list(&graph.graph, source, target)
+}
+
+/// Compute the node connectivity of an undirected graph.
+///
+/// The node connectivity of two non-adjacent nodes ``source`` and
+/// ``target`` is the minimum number of other nodes that need to be removed
+/// to disconnect them, which is equal to the maximum number of internally
+/// node disjoint paths between them. For adjacent nodes the edges between
+/// them count as one more path. The node connectivity of a graph is the
+/// minimum node connectivity of all the pairs of nodes. It's computed with
+/// maximum flows using Even's algorithm for the global node connectivity
+/// [1]_.
+///
+/// :param PyGraph graph: The graph to compute the node connectivity of
+/// :param int source: An optional source node index, if specified (with
+///     ``target``) the local node connectivity of ``source`` and ``target``
+///     is returned instead of the node connectivity of the graph.
+/// :param int target: An optional target node index
+///
+/// :returns: The node connectivity, 0 if the graph isn't connected or has
+///     fewer than 2 nodes and :math:`n - 1` for a complete graph.
+/// :rtype: int
+///
+/// :raises InvalidNode: If ``source`` or ``target`` is not in the graph
+/// :raises ValueError: If only one of ``source`` and ``target`` is
+///     specified, or they are the same node
+///
+/// .. [1] Abdol-Hossein Esfahanian, "Connectivity Algorithms".
+///    http://www.cse.msu.edu/~cse835/Papers/Graph_connectivity_revised.pdf
+#[pyfunction]
+#[pyo3(text_signature = "(graph, /, source=None, target=None)")]
+pub fn graph_node_connectivity(
+    graph: &graph::PyGraph,
+    source: Option<usize>,
+    target: Option<usize>,
+) -> PyResult<usize> {
+    let source_target = source_target_pair(&graph.graph, source, target)?;
+    Ok(connectivity::node_connectivity(&graph.graph, source_target))
+}
+
+/// Compute the node connectivity of a directed graph.
+///
+/// The node connectivity of two non-adjacent nodes ``source`` and
+/// ``target`` is the minimum number of other nodes that need to be removed
+/// to disconnect ``target`` from ``source``, which is equal to the maximum
+/// number of internally node disjoint directed paths from ``source`` to
+/// ``target``. If there is an edge from ``source`` to ``target`` it counts
+/// as one more path. The node connectivity of a graph is the minimum node
+/// connectivity of all the pairs of nodes. It's computed with maximum flows
+/// using Even's algorithm for the global node connectivity [1]_.
+///
+/// :param PyDiGraph graph: The graph to compute the node connectivity of
+/// :param int source: An optional source node index, if specified (with
+///     ``target``) the local node connectivity of ``source`` and ``target``
+///     is returned instead of the node connectivity of the graph.
+/// :param int target: An optional target node index
+///
+/// :returns: The node connectivity, 0 if the graph isn't strongly connected
+///     or has fewer than 2 nodes and :math:`n - 1` for a complete graph.
+/// :rtype: int
+///
+/// :raises InvalidNode: If ``source`` or ``target`` is not in the graph
+/// :raises ValueError: If only one of ``source`` and ``target`` is
+///     specified, or they are the same node
+///
+/// .. [1] Abdol-Hossein Esfahanian, "Connectivity Algorithms".
+///    http://www.cse.msu.edu/~cse835/Papers/Graph_connectivity_revised.pdf
+#[pyfunction]
+#[pyo3(text_signature = "(graph, /, source=None, target=None)")]
+pub fn digraph_node_connectivity(
+    graph: &digraph::PyDiGraph,
+    source: Option<usize>,
+    target: Option<usize>,
+) -> PyResult<usize> {
+    let source_target = source_target_pair(&graph.graph, source, target)?;
+    Ok(connectivity::node_connectivity(&graph.graph, source_target))
+}
+
+/// Find a minimum set of nodes that disconnects an undirected graph.
+///
+/// If ``source`` and ``target`` are specified the nodes returned disconnect
+/// them, otherwise they disconnect some pair of the remaining nodes. The
+/// number of nodes in the cut is the node connectivity as returned by
+/// :func:`~retworkx.graph_node_connectivity`. A complete graph has no node
+/// cut, in that case the neighbors of a node with the minimum degree are
+/// returned.
+///
+/// :param PyGraph graph: The graph to find a minimum node cut of
+/// :param int source: An optional source node index to separate from
+///     ``target``
+/// :param int target: An optional target node index
+///
+/// :returns: The node indices in the cut, empty if the graph isn't
+///     connected or has fewer than 2 nodes.
+/// :rtype: NodeIndices
+///
+/// :raises InvalidNode: If ``source`` or ``target`` is not in the graph
+/// :raises ValueError: If only one of ``source`` and ``target`` is
+///     specified, they are the same node or they are adjacent
+#[pyfunction]
+#[pyo3(text_signature = "(graph, /, source=None, target=None)")]
+pub fn graph_minimum_node_cut(
+    graph: &graph::PyGraph,
+    source: Option<usize>,
+    target: Option<usize>,
+) -> PyResult<NodeIndices> {
+    node_cut_list(&graph.graph, source, target)
+}
+
+/// Find a minimum set of nodes that disconnects a directed graph.
+///
+/// If ``source`` and ``target`` are specified the nodes returned disconnect
+/// ``target`` from ``source``, otherwise the remaining nodes are no longer
+/// strongly connected without them. The number of nodes in the cut is the
+/// node connectivity as returned by
+/// :func:`~retworkx.digraph_node_connectivity`. A complete graph has no node
+/// cut, in that case the neighbors of a node with the minimum degree are
+/// returned.
+///
+/// :param PyDiGraph graph: The graph to find a minimum node cut of
+/// :param int source: An optional source node index to separate from
+///     ``target``
+/// :param int target: An optional target node index
+///
+/// :returns: The node indices in the cut, empty if the graph isn't
+///     strongly connected or has fewer than 2 nodes.
+/// :rtype: NodeIndices
+///
+/// :raises InvalidNode: If ``source`` or ``target`` is not in the graph
+/// :raises ValueError: If only one of ``source`` and ``target`` is
+///     specified, they are the same node or there is an edge from
+///     ``source`` to ``target``
+#[pyfunction]
+#[pyo3(text_signature = "(graph, /, source=None, target=None)")]
+pub fn digraph_minimum_node_cut(
+    graph: &digraph::PyDiGraph,
+    source: Option<usize>,
+    target: Option<usize>,
+) -> PyResult<NodeIndices> {
+    node_cut_list(&graph.graph, source, target)
+}
@@ -0,0 +1,234 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use crate::attributes::AttributeColumns;
+use crate::node_keys::NodeKeys;
+use crate::StablePyGraph;
+
+use hashbrown::HashSet;
+use indexmap::{IndexMap, IndexSet};
+
+use petgraph::stable_graph::{EdgeIndex, NodeIndex};
+use petgraph::visit::EdgeRef;
+use petgraph::{Direction, EdgeType};
+
+use pyo3::exceptions::{PyIndexError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::types::PyList;
+use pyo3::Python;
+
+/// How the edges between merged nodes, which become self loops of the
+/// merged node, and the parallel edges created by a merge are handled
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum MergeEdgePolicy {
+    Keep,
+    Drop,
+    Aggregate,
+}
+
+impl MergeEdgePolicy {
+    pub fn from_name(name: &str, weight_combo_fn: &Option<PyObject>) -> PyResult<Self> {
+        let policy = match name {
+            "keep" => MergeEdgePolicy::Keep,
+            "drop" => MergeEdgePolicy::Drop,
+            "aggregate" => MergeEdgePolicy::Aggregate,
+            _ => {
+                return Err(PyValueError::new_err(format!(
+                    "Invalid merge_edge_policy value '{}', it must be one of 'keep', 'drop' or 'aggregate'",
+                    name
+                )))
+            }
+        };
+        if policy == MergeEdgePolicy::Aggregate && weight_combo_fn.is_none() {
+            return Err(PyValueError::new_err(
+                "weight_combo_fn is required with merge_edge_policy='aggregate'",
+            ));
+        }
+        Ok(policy)
+    }
+}
+
+/// The endpoints of an edge of the merged node, all the edges between
+/// merged nodes end up as self loops of the merged node.
+#[derive(Clone, Copy, Hash, PartialEq, Eq)]
+enum EdgeGroup {
+    SelfLoop,
+    Other(NodeIndex, NodeIndex),
+}
+
+/// Check the nodes to merge and collect them in order without duplicates.
+pub fn merge_set<Ty: EdgeType>(
+    graph: &StablePyGraph<Ty>,
+    nodes: Vec<usize>,
+) -> PyResult<IndexSet<NodeIndex, ahash::RandomState>> {
+    if nodes.is_empty() {
+        return Err(PyValueError::new_err("At least one node must be merged"));
+    }
+    let mut merged: IndexSet<NodeIndex, ahash::RandomState> =
+        IndexSet::with_capacity_and_hasher(nodes.len(), ahash::RandomState::default());
+    for node in nodes {
+        let index = NodeIndex::new(node);
+        if !graph.contains_node(index) {
+            return Err(PyIndexError::new_err(format!(
+                "No node found for index {}",
+                node
+            )));
+        }
+        merged.insert(index);
+    }
+    Ok(merged)
+}
+
+/// Whether merging ``nodes`` would leave edges between them, which become
+/// self loops of the merged node.
+pub fn has_internal_edges<Ty: EdgeType>(
+    graph: &StablePyGraph<Ty>,
+    nodes: &IndexSet<NodeIndex, ahash::RandomState>,
+) -> bool {
+    nodes.iter().any(|node| {
+        graph
+            .edges_directed(*node, Direction::Outgoing)
+            .any(|edge| nodes.contains(&edge.source()) && nodes.contains(&edge.target()))
+    })
+}
+
+/// The payloads of ``nodes`` as a list, in order.
+pub fn payload_list<Ty: EdgeType>(
+    py: Python,
+    graph: &StablePyGraph<Ty>,
+    nodes: &IndexSet<NodeIndex, ahash::RandomState>,
+) -> PyObject {
+    PyList::new(py, nodes.iter().map(|node| graph[*node].clone_ref(py))).into()
+}
+
+/// Merge ``nodes`` into the first of them, which is kept with its index,
+/// key and attributes and gets ``payload`` as its weight/data payload.
+///
+/// The edges of the other nodes are moved to the merged node and the other
+/// nodes are removed. The edges between merged nodes and the edges that end
+/// up parallel, i.e. between the merged node and the same neighbor (in the
+/// same direction for a directed graph), are handled according to
+/// ``policy``. The merged node's own edges keep their index whenever they
+/// are kept, moved edges are added as new edges.
+#[allow(clippy::too_many_arguments)]
+pub fn merge_nodes<Ty: EdgeType>(
+    py: Python,
+    graph: &mut StablePyGraph<Ty>,
+    node_keys: &mut NodeKeys,
+    attributes: &mut AttributeColumns,
+    nodes: &IndexSet<NodeIndex, ahash::RandomState>,
+    payload: PyObject,
+    policy: MergeEdgePolicy,
+    weight_combo_fn: &Option<PyObject>,
+    multigraph: bool,
+) -> PyResult<NodeIndex> {
+    let merged = nodes[0];
+    let map = |node: NodeIndex| -> NodeIndex {
+        if nodes.contains(&node) {
+            merged
+        } else {
+            node
+        }
+    };
+
+    // Group the edges of the merged nodes, the merged node's own edges
+    // first so they are preferred when parallel edges are dropped.
+    let mut seen: HashSet<EdgeIndex> = HashSet::new();
+    let mut groups: IndexMap<EdgeGroup, Vec<EdgeIndex>, ahash::RandomState> =
+        IndexMap::with_hasher(ahash::RandomState::default());
+    for node in nodes {
+        let mut directions = vec![Direction::Outgoing];
+        if graph.is_directed() {
+            directions.push(Direction::Incoming);
+        }
+        for direction in directions {
+            for edge in graph.edges_directed(*node, direction) {
+                if !seen.insert(edge.id()) {
+                    continue;
+                }
+                let source = map(edge.source());
+                let target = map(edge.target());
+                let group = if source == target {
+                    EdgeGroup::SelfLoop
+                } else if !graph.is_directed() && target == merged {
+                    EdgeGroup::Other(target, source)
+                } else {
+                    EdgeGroup::Other(source, target)
+                };
+                groups.entry(group).or_insert_with(Vec::new).push(edge.id());
+            }
+        }
+    }
+
+    // Only edges that already connect the merged node to the rest of the
+    // graph, or are already self loops of it, can stay in place.
+    let in_place = |graph: &StablePyGraph<Ty>, edge: EdgeIndex| -> bool {
+        let (source, target) = graph.edge_endpoints(edge).unwrap();
+        map(source) == source && map(target) == target
+    };
+    let mut remove: Vec<EdgeIndex> = Vec::new();
+    let mut add: Vec<(NodeIndex, NodeIndex, PyObject)> = Vec::new();
+    let mut update: Vec<(EdgeIndex, PyObject)> = Vec::new();
+    for (group, edges) in groups {
+        let (source, target) = match group {
+            EdgeGroup::SelfLoop => (merged, merged),
+            EdgeGroup::Other(source, target) => (source, target),
+        };
+        if policy == MergeEdgePolicy::Drop && group == EdgeGroup::SelfLoop {
+            remove.extend(edges);
+            continue;
+        }
+        // Parallel edges can only be kept in a multigraph
+        if policy == MergeEdgePolicy::Keep && multigraph {
+            for edge in edges {
+                if !in_place(graph, edge) {
+                    add.push((source, target, graph[edge].clone_ref(py)));
+                    remove.push(edge);
+                }
+            }
+            continue;
+        }
+        let first = edges[0];
+        let mut weight = graph[first].clone_ref(py);
+        if policy == MergeEdgePolicy::Aggregate {
+            let combo_fn = weight_combo_fn.as_ref().unwrap();
+            for edge in edges.iter().skip(1) {
+                weight = combo_fn.call1(py, (weight, &graph[*edge]))?;
+            }
+        }
+        if in_place(graph, first) {
+            update.push((first, weight));
+        } else {
+            add.push((source, target, weight));
+            remove.push(first);
+        }
+        remove.extend(edges.into_iter().skip(1));
+    }
+
+    for edge in remove {
+        graph.remove_edge(edge);
+        attributes.remove_edge(edge);
+    }
+    for (edge, weight) in update {
+        graph[edge] = weight;
+    }
+    for node in nodes.iter().skip(1) {
+        attributes.remove_node(graph, *node);
+        graph.remove_node(*node);
+        node_keys.remove(*node);
+    }
+    graph[merged] = payload;
+    for (source, target, weight) in add {
+        graph.add_edge(source, target, weight);
+    }
+    Ok(merged)
+}
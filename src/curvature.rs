@@ -0,0 +1,313 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use std::convert::TryFrom;
+
+use crate::iterators::EdgeCurvatureMapping;
+use crate::{digraph, graph, CostFn, StablePyGraph};
+
+use petgraph::EdgeType;
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::Python;
+
+use retworkx_core::curvature;
+
+fn curvature_mapping(curvatures: Vec<Option<f64>>) -> EdgeCurvatureMapping {
+    EdgeCurvatureMapping {
+        curvatures: curvatures
+            .into_iter()
+            .enumerate()
+            .filter_map(|(i, v)| v.map(|x| (i, x)))
+            .collect(),
+    }
+}
+
+fn forman_curvature<Ty: EdgeType>(
+    py: Python,
+    graph: &StablePyGraph<Ty>,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+) -> PyResult<EdgeCurvatureMapping> {
+    let cost_fn = CostFn::try_from((weight_fn, default_weight))?;
+    let curvatures = curvature::forman_curvature(graph, |e| cost_fn.call(py, e.weight()))?;
+    Ok(curvature_mapping(curvatures))
+}
+
+#[allow(clippy::too_many_arguments)]
+fn ollivier_ricci_curvature<Ty: EdgeType>(
+    py: Python,
+    graph: &StablePyGraph<Ty>,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+    alpha: f64,
+    reg: f64,
+    max_iter: usize,
+    tol: f64,
+) -> PyResult<EdgeCurvatureMapping> {
+    if !(0.0..=1.0).contains(&alpha) {
+        return Err(PyValueError::new_err("alpha must be between 0 and 1"));
+    }
+    if reg.is_nan() || reg <= 0.0 {
+        return Err(PyValueError::new_err("reg must be a positive number"));
+    }
+    let cost_fn = CostFn::try_from((weight_fn, default_weight))?;
+    let curvatures = curvature::ollivier_ricci_curvature(
+        graph,
+        |e| cost_fn.call(py, e.weight()),
+        alpha,
+        reg,
+        max_iter,
+        tol,
+    )?;
+    Ok(curvature_mapping(curvatures))
+}
+
+/// Compute the Forman-Ricci curvature of every edge in a PyGraph.
+///
+/// The Forman-Ricci curvature is a combinatorial notion of curvature that
+/// only depends on the edges adjacent to an edge, which makes it cheap to
+/// compute. With every node having a weight of 1 the curvature of an edge
+/// :math:`e = (u, v)` with weight :math:`w_e` is
+///
+/// .. math::
+///
+///     F(e) = 2 - \sum_{e_u \sim u, e_u \neq e} \sqrt{\frac{w_e}{w_{e_u}}}
+///         - \sum_{e_v \sim v, e_v \neq e} \sqrt{\frac{w_e}{w_{e_v}}}
+///
+/// so for an unweighted graph it is :math:`4 - deg(u) - deg(v)`. The
+/// algorithm is based on [1]_.
+///
+/// :param PyGraph graph: The graph to compute the curvature of
+/// :param weight_fn: An optional input callable that will be passed the edge's
+///     payload object and is expected to return a positive ``float`` weight
+///     for that edge. If this is not specified ``default_weight`` will be used
+///     as the weight for every edge in ``graph``.
+/// :param float default_weight: If ``weight_fn`` is not set the default weight
+///     value to use for the weight of all edges
+///
+/// :returns: a read-only dict-like object whose keys are the edge indices and
+///     values are the curvature of each edge. Self loops are not included.
+/// :rtype: EdgeCurvatureMapping
+///
+/// .. [1] Sreejith, R. P., Karthikeyan Mohanraj, Jürgen Jost, Emil Saucan,
+///     and Areejit Samal. "Forman curvature for complex networks." Journal of
+///     Statistical Mechanics: Theory and Experiment 2016, no. 6 (2016): 063206.
+#[pyfunction(default_weight = "1.0")]
+#[pyo3(text_signature = "(graph, /, weight_fn=None, default_weight=1.0)")]
+pub fn graph_forman_curvature(
+    py: Python,
+    graph: &graph::PyGraph,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+) -> PyResult<EdgeCurvatureMapping> {
+    forman_curvature(py, &graph.graph, weight_fn, default_weight)
+}
+
+/// Compute the Forman-Ricci curvature of every edge in a PyDiGraph.
+///
+/// The Forman-Ricci curvature is a combinatorial notion of curvature that
+/// only depends on the edges adjacent to an edge, which makes it cheap to
+/// compute. With every node having a weight of 1 the curvature of an edge
+/// :math:`e = (u, v)` with weight :math:`w_e` is
+///
+/// .. math::
+///
+///     F(e) = 2 - \sum_{e_u \to u} \sqrt{\frac{w_e}{w_{e_u}}}
+///         - \sum_{v \to e_v} \sqrt{\frac{w_e}{w_{e_v}}}
+///
+/// where the sums are over the other incoming edges of :math:`u` and the
+/// other outgoing edges of :math:`v`, so for an unweighted graph it is
+/// :math:`2 - deg^{in}(u) - deg^{out}(v)`. The algorithm is based on [1]_.
+///
+/// :param PyDiGraph graph: The graph to compute the curvature of
+/// :param weight_fn: An optional input callable that will be passed the edge's
+///     payload object and is expected to return a positive ``float`` weight
+///     for that edge. If this is not specified ``default_weight`` will be used
+///     as the weight for every edge in ``graph``.
+/// :param float default_weight: If ``weight_fn`` is not set the default weight
+///     value to use for the weight of all edges
+///
+/// :returns: a read-only dict-like object whose keys are the edge indices and
+///     values are the curvature of each edge. Self loops are not included.
+/// :rtype: EdgeCurvatureMapping
+///
+/// .. [1] Sreejith, R. P., Jürgen Jost, Emil Saucan, and Areejit Samal.
+///     "Forman curvature for directed networks." arXiv:1605.04662 (2016).
+#[pyfunction(default_weight = "1.0")]
+#[pyo3(text_signature = "(graph, /, weight_fn=None, default_weight=1.0)")]
+pub fn digraph_forman_curvature(
+    py: Python,
+    graph: &digraph::PyDiGraph,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+) -> PyResult<EdgeCurvatureMapping> {
+    forman_curvature(py, &graph.graph, weight_fn, default_weight)
+}
+
+/// Compute the Ollivier-Ricci curvature of every edge in a PyGraph.
+///
+/// The Ollivier-Ricci curvature of an edge :math:`(u, v)` compares the
+/// distance between :math:`u` and :math:`v` with the distance between their
+/// neighborhoods:
+///
+/// .. math::
+///
+///     \kappa(u, v) = 1 - \frac{W(m_u, m_v)}{d(u, v)}
+///
+/// where :math:`d` is the shortest path distance, :math:`W` is the
+/// Wasserstein (earth mover's) distance and :math:`m_x` is the probability
+/// distribution that puts a mass of ``alpha`` on :math:`x` and spreads the
+/// remaining mass uniformly over the neighbors of :math:`x`. The Wasserstein
+/// distance is approximated with the Sinkhorn algorithm for entropy
+/// regularized optimal transport, which converges to the exact distance as
+/// ``reg`` goes to 0. The algorithm is based on [1]_ and [2]_.
+///
+/// :param PyGraph graph: The graph to compute the curvature of
+/// :param weight_fn: An optional input callable that will be passed the edge's
+///     payload object and is expected to return a non-negative ``float``
+///     length for that edge. If this is not specified ``default_weight`` will
+///     be used as the length of every edge in ``graph``.
+/// :param float default_weight: If ``weight_fn`` is not set the default weight
+///     value to use for the length of all edges
+/// :param float alpha: The probability mass of the distributions kept on the
+///     node itself, it must be between 0 and 1
+/// :param float reg: The entropic regularization of the Sinkhorn algorithm,
+///     smaller values give a better approximation but require more iterations
+/// :param int max_iter: The maximum number of Sinkhorn iterations for every
+///     edge
+/// :param float tol: The Sinkhorn iterations for an edge stop once the
+///     marginals of the transport plan differ from the distributions by less
+///     than ``tol``
+///
+/// :returns: a read-only dict-like object whose keys are the edge indices and
+///     values are the curvature of each edge. Self loops and edges with a
+///     length of 0 are not included.
+/// :rtype: EdgeCurvatureMapping
+///
+/// .. [1] Lin, Yong, Linyuan Lu, and Shing-Tung Yau. "Ricci curvature of
+///     graphs." Tohoku Mathematical Journal 63, no. 4 (2011): 605-627.
+/// .. [2] Cuturi, Marco. "Sinkhorn distances: Lightspeed computation of
+///     optimal transport." Advances in Neural Information Processing Systems
+///     26 (2013).
+#[pyfunction(
+    default_weight = "1.0",
+    alpha = "0.5",
+    reg = "0.01",
+    max_iter = "1000",
+    tol = "1e-9"
+)]
+#[pyo3(
+    text_signature = "(graph, /, weight_fn=None, default_weight=1.0, alpha=0.5, reg=0.01, max_iter=1000, tol=1e-9)"
+)]
+#[allow(clippy::too_many_arguments)]
+pub fn graph_ollivier_ricci_curvature(
+    py: Python,
+    graph: &graph::PyGraph,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+    alpha: f64,
+    reg: f64,
+    max_iter: usize,
+    tol: f64,
+) -> PyResult<EdgeCurvatureMapping> {
+    ollivier_ricci_curvature(
+        py,
+        &graph.graph,
+        weight_fn,
+        default_weight,
+        alpha,
+        reg,
+        max_iter,
+        tol,
+    )
+}
+
+/// Compute the Ollivier-Ricci curvature of every edge in a PyDiGraph.
+///
+/// The Ollivier-Ricci curvature of an edge :math:`(u, v)` compares the
+/// distance between :math:`u` and :math:`v` with the distance between their
+/// neighborhoods:
+///
+/// .. math::
+///
+///     \kappa(u, v) = 1 - \frac{W(m_u, m_v)}{d(u, v)}
+///
+/// where :math:`d` is the shortest path distance, :math:`W` is the
+/// Wasserstein (earth mover's) distance and :math:`m_x` is the probability
+/// distribution that puts a mass of ``alpha`` on :math:`x` and spreads the
+/// remaining mass uniformly over the neighbors of :math:`x`. The direction of
+/// the edges is ignored both for the neighbors and the distances. The
+/// Wasserstein distance is approximated with the Sinkhorn algorithm for
+/// entropy regularized optimal transport, which converges to the exact
+/// distance as ``reg`` goes to 0. The algorithm is based on [1]_ and [2]_.
+///
+/// :param PyDiGraph graph: The graph to compute the curvature of
+/// :param weight_fn: An optional input callable that will be passed the edge's
+///     payload object and is expected to return a non-negative ``float``
+///     length for that edge. If this is not specified ``default_weight`` will
+///     be used as the length of every edge in ``graph``.
+/// :param float default_weight: If ``weight_fn`` is not set the default weight
+///     value to use for the length of all edges
+/// :param float alpha: The probability mass of the distributions kept on the
+///     node itself, it must be between 0 and 1
+/// :param float reg: The entropic regularization of the Sinkhorn algorithm,
+///     smaller values give a better approximation but require more iterations
+/// :param int max_iter: The maximum number of Sinkhorn iterations for every
+///     edge
+/// :param float tol: The Sinkhorn iterations for an edge stop once the
+///     marginals of the transport plan differ from the distributions by less
+///     than ``tol``
+///
+/// :returns: a read-only dict-like object whose keys are the edge indices and
+///     values are the curvature of each edge. Self loops and edges with a
+///     length of 0 are not included.
+/// :rtype: EdgeCurvatureMapping
+///
+/// .. [1] Lin, Yong, Linyuan Lu, and Shing-Tung Yau. "Ricci curvature of
+///     graphs." Tohoku Mathematical Journal 63, no. 4 (2011): 605-627.
+/// .. [2] Cuturi, Marco. "Sinkhorn distances: Lightspeed computation of
+///     optimal transport." Advances in Neural Information Processing Systems
+///     26 (2013).
+#[pyfunction(
+    default_weight = "1.0",
+    alpha = "0.5",
+    reg = "0.01",
+    max_iter = "1000",
+    tol = "1e-9"
+)]
+#[pyo3(
+    text_signature = "(graph, /, weight_fn=None, default_weight=1.0, alpha=0.5, reg=0.01, max_iter=1000, tol=1e-9)"
+)]
+#[allow(clippy::too_many_arguments)]
+pub fn digraph_ollivier_ricci_curvature(
+    py: Python,
+    graph: &digraph::PyDiGraph,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+    alpha: f64,
+    reg: f64,
+    max_iter: usize,
+    tol: f64,
+) -> PyResult<EdgeCurvatureMapping> {
+    ollivier_ricci_curvature(
+        py,
+        &graph.graph,
+        weight_fn,
+        default_weight,
+        alpha,
+        reg,
+        max_iter,
+        tol,
+    )
+}
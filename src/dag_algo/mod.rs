@@ -16,18 +16,24 @@ use hashbrown::{HashMap, HashSet};
 use std::cmp::Ordering;
 use std::collections::BinaryHeap;
 
-use super::iterators::NodeIndices;
-use crate::{digraph, DAGHasCycle, InvalidNode};
+use super::iterators::{NodeIndices, NodeMap};
+use crate::attributes::AttributeColumns;
+use crate::node_keys::NodeKeys;
+use crate::toposort::{TopologicalGenerations, TopologicalTraversal};
+use crate::{digraph, DAGHasCycle, InvalidNode, StablePyGraph};
+
+use retworkx_core::dag_algo;
+use retworkx_core::dictmap::*;
 
 use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
-use pyo3::types::PyList;
+use pyo3::types::{IntoPyDict, PyList};
 use pyo3::Python;
 
 use petgraph::algo;
 use petgraph::graph::NodeIndex;
 use petgraph::prelude::*;
-use petgraph::visit::NodeCount;
+use petgraph::visit::{NodeCount, NodeIndexable};
 
 /// Find the longest path in a DAG
 ///
@@ -294,6 +300,78 @@ pub fn layers(py: Python, dag: &digraph::PyDiGraph, first_layer: Vec<usize>) ->
     Ok(PyList::new(py, output).into())
 }
 
+/// Return a lazy iterator over the topological generations of a DAG
+///
+/// The first generation is the set of nodes without any predecessors, and
+/// each following generation is the set of nodes whose predecessors are all
+/// in earlier generations. So no node in a generation has a path to another
+/// node in it. Unlike :func:`~retworkx.layers` the generations are computed
+/// one at a time as the iterator is advanced, which enables processing a
+/// large DAG level by level without building all the levels up front. For
+/// example:
+///
+/// .. jupyter-execute::
+///
+///   import retworkx
+///
+///   graph = retworkx.PyDiGraph()
+///   graph.extend_from_edge_list([(0, 1), (0, 2), (1, 3), (2, 3), (4, 3)])
+///   for generation in retworkx.topological_generations(graph):
+///       print(sorted(generation))
+///
+/// :param PyDiGraph dag: The DAG to get the generations of
+///
+/// :returns: An iterator of the generations, each generation is a
+///     :class:`~retworkx.NodeIndices` object of the node indices in it
+/// :rtype: TopologicalGenerations
+///
+/// :raises DAGHasCycle: when iterating, after the last generation is
+///     returned if the graph has a cycle. The nodes on a cycle, and any node
+///     reachable from one, are never returned.
+#[pyfunction]
+#[pyo3(text_signature = "(dag, /)")]
+pub fn topological_generations(py: Python, dag: Py<digraph::PyDiGraph>) -> TopologicalGenerations {
+    TopologicalGenerations::new(py, dag)
+}
+
+/// Get a resumable topological traversal of a DAG
+///
+/// The traversal is an iterator over the node indices of the DAG in
+/// topological order, a node is only returned once all of its predecessors
+/// were returned. Unlike :func:`~retworkx.topological_sort`, which sorts the
+/// whole graph at once, the nodes are found one at a time as the traversal
+/// is iterated over, so it can be paused and resumed, and its state can be
+/// saved with :meth:`~retworkx.TopologicalTraversal.copy`. For example, to
+/// process a DAG in time slices of 2 nodes:
+///
+/// .. jupyter-execute::
+///
+///   import itertools
+///
+///   import retworkx
+///
+///   graph = retworkx.PyDiGraph()
+///   graph.extend_from_edge_list([(0, 1), (0, 2), (1, 3), (2, 3), (4, 3)])
+///   traversal = retworkx.topological_traversal(graph)
+///   print(list(itertools.islice(traversal, 2)))
+///   saved = traversal.copy()
+///   print(list(traversal))
+///   print(list(saved))
+///
+/// :param PyDiGraph dag: The DAG to traverse
+///
+/// :returns: The traversal, an iterator of node indices
+/// :rtype: TopologicalTraversal
+///
+/// :raises DAGHasCycle: when iterating, after the last node is returned if
+///     the graph has a cycle. The nodes on a cycle, and any node reachable
+///     from one, are never returned.
+#[pyfunction]
+#[pyo3(text_signature = "(dag, /)")]
+pub fn topological_traversal(py: Python, dag: Py<digraph::PyDiGraph>) -> TopologicalTraversal {
+    TopologicalTraversal::new(py, dag)
+}
+
 /// Get the lexicographical topological sorted nodes from the provided DAG
 ///
 /// This function returns a list of nodes data in a graph lexicographically
@@ -410,6 +488,157 @@ pub fn topological_sort(graph: &digraph::PyDiGraph) -> PyResult<NodeIndices> {
     })
 }
 
+/// Return the topological sort of node indices, breaking ties with a key
+///
+/// This function differs from :func:`~retworkx.topological_sort` because
+/// whenever several nodes could come next in the order, the one with the
+/// smallest key is chosen, and nodes with the same key are chosen in the
+/// order of their indices. So the order only depends on the keys and the
+/// node indices and is reproducible. The keys are compared like the keys
+/// of :func:`sorted`.
+///
+/// :param PyDiGraph dag: The DAG to get the topological sort of
+/// :param callable key: A python function or other callable that gets
+///     passed a single argument, the node data from the graph, and returns
+///     the key used to break ties. It is called once for each node.
+/// :param bool reverse: If ``True`` the nodes are sorted as if every edge of
+///     the graph was reversed, so every node comes after all of its
+///     successors. Defaults to ``False``.
+///
+/// :returns: A list of node indices topologically sorted.
+/// :rtype: NodeIndices
+///
+/// :raises DAGHasCycle: if a cycle is encountered while sorting the graph
+#[pyfunction(reverse = "false")]
+#[pyo3(text_signature = "(dag, key, /, reverse=False)")]
+pub fn topological_sort_by(
+    py: Python,
+    dag: &digraph::PyDiGraph,
+    key: PyObject,
+    reverse: bool,
+) -> PyResult<NodeIndices> {
+    // Rank the nodes by their keys with Python's sort, which is stable so
+    // nodes with equal keys stay in index order
+    let nodes: Vec<NodeIndex> = dag.graph.node_indices().collect();
+    let keys = nodes
+        .iter()
+        .map(|node| key.call1(py, (&dag.graph[*node],)))
+        .collect::<PyResult<Vec<PyObject>>>()?;
+    let keys = PyList::new(py, keys);
+    let builtins = py.import("builtins")?;
+    let ranked: Vec<usize> = builtins
+        .getattr("sorted")?
+        .call(
+            (builtins.getattr("range")?.call1((nodes.len(),))?,),
+            Some([("key", keys.getattr("__getitem__")?)].into_py_dict(py)),
+        )?
+        .extract()?;
+    let mut rank: Vec<usize> = vec![0; dag.graph.node_bound()];
+    for (position, i) in ranked.into_iter().enumerate() {
+        rank[nodes[i].index()] = position;
+    }
+    let order = dag_algo::topological_sort_by(
+        &dag.graph,
+        |node| Ok::<usize, PyErr>(rank[node.index()]),
+        reverse,
+    )?;
+    match order {
+        Some(order) => Ok(NodeIndices {
+            nodes: order.iter().map(|node| node.index()).collect(),
+        }),
+        None => Err(DAGHasCycle::new_err("Sort encountered a cycle")),
+    }
+}
+
+/// Return the transitive reduction of a DAG
+///
+/// The transitive reduction of a DAG is the graph with the fewest edges that
+/// has the same reachability as the input: there is a path from ``u`` to
+/// ``v`` in the transitive reduction if and only if there is one in the input
+/// graph. This is done by removing every edge ``(u, v)`` for which there is
+/// another path from ``u`` to ``v``. For a DAG the transitive reduction is
+/// unique. Parallel edges are merged into a single edge with the payload of
+/// the first one.
+///
+/// This function runs a depth first search from each node, so it runs in
+/// :math:`O(|V| \cdot |E|)` time and only needs :math:`O(|V|)` additional
+/// memory, even for dense DAGs.
+///
+/// :param PyDiGraph graph: The DAG to compute the transitive reduction of
+///
+/// :returns: A tuple of the transitive reduction as a new :class:`~.PyDiGraph`
+///     with the node and edge payloads of the input graph, and a mapping of
+///     the node indices in ``graph`` to the node indices in the new graph.
+/// :rtype: Tuple[PyDiGraph, NodeMap]
+///
+/// :raises DAGHasCycle: if the graph has a cycle
+#[pyfunction]
+#[pyo3(text_signature = "(graph, /)")]
+pub fn transitive_reduction(
+    py: Python,
+    graph: &digraph::PyDiGraph,
+) -> PyResult<(digraph::PyDiGraph, NodeMap)> {
+    if algo::toposort(&graph.graph, None).is_err() {
+        return Err(DAGHasCycle::new_err("Sort encountered a cycle"));
+    }
+    let mut out_graph = StablePyGraph::<Directed>::with_capacity(graph.graph.node_count(), 0);
+    let mut node_map: DictMap<usize, usize> = DictMap::with_capacity(graph.graph.node_count());
+    for node in graph.graph.node_indices() {
+        let new_node = out_graph.add_node(graph.graph[node].clone_ref(py));
+        node_map.insert(node.index(), new_node.index());
+    }
+    // reached[v] == u.index() marks that v is reachable from u by a path of
+    // at least two edges, the marks are reused between the searches
+    let mut reached: Vec<usize> = vec![usize::MAX; graph.graph.node_bound()];
+    let mut stack: Vec<NodeIndex> = Vec::new();
+    for node in graph.graph.node_indices() {
+        let mark = node.index();
+        for child in graph.graph.neighbors_directed(node, Outgoing) {
+            for grandchild in graph.graph.neighbors_directed(child, Outgoing) {
+                if reached[grandchild.index()] != mark {
+                    reached[grandchild.index()] = mark;
+                    stack.push(grandchild);
+                }
+            }
+        }
+        while let Some(current) = stack.pop() {
+            for succ in graph.graph.neighbors_directed(current, Outgoing) {
+                if reached[succ.index()] != mark {
+                    reached[succ.index()] = mark;
+                    stack.push(succ);
+                }
+            }
+        }
+        let mut edges: Vec<_> = graph.graph.edges_directed(node, Outgoing).collect();
+        edges.sort_unstable_by_key(|edge| edge.id());
+        for edge in edges {
+            let target = edge.target();
+            if reached[target.index()] != mark {
+                // Marking the target skips any parallel edges
+                reached[target.index()] = mark;
+                out_graph.add_edge(
+                    NodeIndex::new(node_map[&node.index()]),
+                    NodeIndex::new(node_map[&target.index()]),
+                    edge.weight().clone_ref(py),
+                );
+            }
+        }
+    }
+    Ok((
+        digraph::PyDiGraph {
+            graph: out_graph,
+            node_removed: false,
+            cycle_state: None,
+            check_cycle: graph.check_cycle,
+            multigraph: graph.multigraph,
+            node_keys: NodeKeys::default(),
+            attributes: AttributeColumns::default(),
+            generation: 0,
+        },
+        NodeMap { node_map },
+    ))
+}
+
 /// Collect runs that match a filter function
 ///
 /// A run is a path of nodes where there is only a single successor and all
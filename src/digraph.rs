@@ -16,12 +16,14 @@ use std::collections::BTreeMap;
 
 use std::fs::File;
 use std::io::prelude::*;
-use std::io::{BufReader, BufWriter};
+use std::io::BufWriter;
+use std::path::PathBuf;
 use std::str;
 
 use hashbrown::{HashMap, HashSet};
 use indexmap::IndexSet;
 
+use retworkx_core::dag_algo::IncrementalTopologicalOrder;
 use retworkx_core::dictmap::*;
 
 use pyo3::exceptions::PyIndexError;
@@ -32,11 +34,10 @@ use pyo3::PyTraverseError;
 use pyo3::Python;
 
 use ndarray::prelude::*;
-use num_complex::Complex64;
+use num_complex::{Complex32, Complex64};
 use num_traits::Zero;
 use numpy::PyReadonlyArray2;
 
-use petgraph::algo;
 use petgraph::graph::{EdgeIndex, NodeIndex};
 use petgraph::prelude::*;
 
@@ -45,13 +46,20 @@ use petgraph::visit::{
     Visitable,
 };
 
+use super::attributes::{self, AttributeColumns};
+use super::contraction::{self, MergeEdgePolicy};
 use super::dot_utils::build_dot;
+use super::edge_list;
 use super::iterators::{
     EdgeIndexMap, EdgeIndices, EdgeList, NodeIndices, NodeMap, WeightedEdgeList,
 };
+use super::node_keys::NodeKeys;
+use super::snapshot;
+use super::views::{view_filters, DiGraphView, GraphView, ViewBase};
 use super::{
-    find_node_by_weight, merge_duplicates, weight_callable, DAGHasCycle, DAGWouldCycle, IsNan,
-    NoEdgeBetweenNodes, NoSuitableNeighbors, NodesRemoved, StablePyGraph,
+    find_node_by_weight, graph_from_edge_arrays, merge_duplicates, weight_callable, ComplexMatrix,
+    DAGHasCycle, DAGWouldCycle, IndexArray, IsNan, NoEdgeBetweenNodes, NoSuitableNeighbors,
+    NodesRemoved, RealMatrix, StablePyGraph, WeightArray,
 };
 
 use super::dag_algo::is_directed_acyclic_graph;
@@ -122,14 +130,40 @@ use super::dag_algo::is_directed_acyclic_graph;
 ///
 /// With check_cycle set to true any calls to :meth:`PyDiGraph.add_edge` will
 /// ensure that no cycles are added, ensuring that the PyDiGraph class truly
-/// represents a directed acyclic graph. Do note that this cycle checking on
-/// :meth:`~PyDiGraph.add_edge`, :meth:`~PyDiGraph.add_edges_from`,
-/// :meth:`~PyDiGraph.add_edges_from_no_data`,
-/// :meth:`~PyDiGraph.extend_from_edge_list`,  and
-/// :meth:`~PyDiGraph.extend_from_weighted_edge_list` comes with a performance
-/// penalty that grows as the graph does. If you're adding a node and edge at
-/// the same time leveraging :meth:`PyDiGraph.add_child` or
-/// :meth:`PyDiGraph.add_parent` will avoid this overhead.
+/// represents a directed acyclic graph. The cycle checking maintains a
+/// topological order of the nodes, returned by
+/// :meth:`~PyDiGraph.topological_order`, which is updated as edges are added
+/// instead of searching the graph for a cycle. Adding an edge which agrees
+/// with the order takes constant time, otherwise only the nodes between the
+/// endpoints of the edge in the order are searched and reordered. The order is
+/// built by sorting the graph when the first edge is added after cycle
+/// checking is enabled, and again after a method which doesn't add edges one
+/// at a time, such as :meth:`~PyDiGraph.contract_nodes` or
+/// :meth:`~PyDiGraph.merge_node_set`. A self loop is a cycle and can't be added.
+/// If you're adding a node and edge at the same time, leveraging
+/// :meth:`PyDiGraph.add_child` or :meth:`PyDiGraph.add_parent` skips the cycle
+/// check and always takes constant time.
+///
+/// A node can also be given a key, any hashable Python object such as an
+/// external id, with :meth:`~PyDiGraph.set_node_key`. Unlike node indices a
+/// key is never reused by another node: it's removed along with its node.
+/// :meth:`~PyDiGraph.find_node_by_key` and
+/// :meth:`~PyDiGraph.node_indices_by_keys` translate keys to the node indices
+/// taken by every other method and function. A new graph with the same node
+/// indices, such as the one returned by :meth:`~PyDiGraph.copy` or by unpickling,
+/// keeps the node keys, other new graphs don't have any:
+///
+/// .. jupyter-execute::
+///
+///     import retworkx
+///
+///     graph = retworkx.PyDiGraph()
+///     for name in ["a", "b", "c"]:
+///         graph.set_node_key(graph.add_node(name), name)
+///     graph.remove_node(graph.find_node_by_key("b"))
+///     graph.set_node_key(graph.add_node("d"), "d")
+///     print(graph.node_keys())
+///     print(graph.node_indices_by_keys(["d", "a"]))
 ///
 /// By default a ``PyDiGraph`` is a multigraph (meaning there can be parallel
 /// edges between nodes) however this can be disabled by setting the
@@ -155,10 +189,17 @@ use super::dag_algo::is_directed_acyclic_graph;
 #[derive(Clone)]
 pub struct PyDiGraph {
     pub graph: StablePyGraph<Directed>,
-    pub cycle_state: algo::DfsSpace<NodeIndex, <StablePyGraph<Directed> as Visitable>::Map>,
+    /// The topological order maintained while ``check_cycle`` is set, built
+    /// on the first edge insertion after it's reset to ``None``
+    pub cycle_state: Option<IncrementalTopologicalOrder>,
     pub check_cycle: bool,
     pub node_removed: bool,
     pub multigraph: bool,
+    pub node_keys: NodeKeys,
+    pub attributes: AttributeColumns,
+    /// Incremented by every method which modifies the graph, so an object
+    /// holding a reference to it can tell if it changed
+    pub generation: usize,
 }
 
 impl GraphBase for PyDiGraph {
@@ -206,19 +247,27 @@ impl PyDiGraph {
     ) -> PyResult<usize> {
         // Only check for cycles if instance attribute is set to true
         if self.check_cycle {
-            // Only check for a cycle (by running has_path_connecting) if
-            // the new edge could potentially add a cycle
-            let cycle_check_required = is_cycle_check_required(self, p_index, c_index);
-            let state = Some(&mut self.cycle_state);
-            if cycle_check_required
-                && algo::has_path_connecting(&self.graph, c_index, p_index, state)
-            {
+            self.ensure_topological_order()?;
+            let order = self.cycle_state.as_mut().unwrap();
+            if !order.add_edge(&self.graph, p_index, c_index) {
                 return Err(DAGWouldCycle::new_err("Adding an edge would cycle"));
             }
         }
         Ok(self.add_edge_no_cycle_check(p_index, c_index, edge))
     }
 
+    /// Sort the graph to build the maintained topological order if it was
+    /// reset.
+    fn ensure_topological_order(&mut self) -> PyResult<()> {
+        if self.cycle_state.is_none() {
+            match IncrementalTopologicalOrder::from_graph(&self.graph) {
+                Some(order) => self.cycle_state = Some(order),
+                None => return Err(DAGHasCycle::new_err("PyDiGraph object has a cycle")),
+            }
+        }
+        Ok(())
+    }
+
     fn insert_between(
         &mut self,
         py: Python,
@@ -253,9 +302,72 @@ impl PyDiGraph {
                 self._add_edge(index, node_between_index, weight.clone_ref(py))?;
             }
             self.graph.remove_edge(edge_index);
+            self.attributes.remove_edge(edge_index);
         }
         Ok(())
     }
+    /// Whether contracting ``nodes`` into a single node keeps the graph
+    /// acyclic, ignoring the edges between ``nodes``.
+    fn can_contract(&self, nodes: &IndexSet<NodeIndex, ahash::RandomState>) -> bool {
+        // Start with successors of `nodes` that aren't in `nodes` itself.
+        let visit_next: Vec<NodeIndex> = nodes
+            .iter()
+            .flat_map(|n| self.graph.edges(*n))
+            .filter_map(|edge| {
+                let target_node = edge.target();
+                if !nodes.contains(&target_node) {
+                    Some(target_node)
+                } else {
+                    None
+                }
+            })
+            .collect();
+
+        // Now, if we can reach any of `nodes`, there exists a path from `nodes`
+        // back to `nodes` of length > 1, meaning contraction is disallowed.
+        let mut dfs = Dfs::from_parts(visit_next, self.graph.visit_map());
+        while let Some(node) = dfs.next(&self.graph) {
+            if nodes.contains(&node) {
+                // we found a path back to `nodes`
+                return false;
+            }
+        }
+        true
+    }
+    fn merge(
+        &mut self,
+        py: Python,
+        nodes: &IndexSet<NodeIndex, ahash::RandomState>,
+        payload: PyObject,
+        policy: MergeEdgePolicy,
+        weight_combo_fn: &Option<PyObject>,
+    ) -> PyResult<usize> {
+        if self.check_cycle
+            && ((policy != MergeEdgePolicy::Drop
+                && contraction::has_internal_edges(&self.graph, nodes))
+                || !self.can_contract(nodes))
+        {
+            return Err(DAGWouldCycle::new_err(
+                "Merging nodes would create cycle(s)",
+            ));
+        }
+        let merged = contraction::merge_nodes(
+            py,
+            &mut self.graph,
+            &mut self.node_keys,
+            &mut self.attributes,
+            nodes,
+            payload,
+            policy,
+            weight_combo_fn,
+            self.multigraph,
+        )?;
+        if nodes.len() > 1 {
+            self.node_removed = true;
+        }
+        self.cycle_state = None;
+        Ok(merged.index())
+    }
 }
 
 #[pymethods]
@@ -265,10 +377,13 @@ impl PyDiGraph {
     fn new(check_cycle: bool, multigraph: bool) -> Self {
         PyDiGraph {
             graph: StablePyGraph::<Directed>::new(),
-            cycle_state: algo::DfsSpace::default(),
+            cycle_state: None,
             check_cycle,
             node_removed: false,
             multigraph,
+            node_keys: NodeKeys::default(),
+            attributes: AttributeColumns::default(),
+            generation: 0,
         }
     }
 
@@ -279,23 +394,40 @@ impl PyDiGraph {
         out_dict.set_item("nodes", node_dict)?;
         out_dict.set_item("nodes_removed", self.node_removed)?;
         out_dict.set_item("multigraph", self.multigraph)?;
+        let keys_dict = PyDict::new(py);
+        for node_index in self.graph.node_indices() {
+            if let Some(key) = self.node_keys.get(node_index) {
+                keys_dict.set_item(node_index.index(), key)?;
+            }
+        }
+        out_dict.set_item("node_keys", keys_dict)?;
         let dir = petgraph::Direction::Incoming;
+        let mut node_indices: Vec<usize> = Vec::with_capacity(self.graph.node_count());
+        let mut edge_indices: Vec<usize> = Vec::with_capacity(self.graph.edge_count());
         for node_index in self.graph.node_indices() {
             let node_data = self.graph.node_weight(node_index).unwrap();
             node_dict.set_item(node_index.index(), node_data)?;
+            node_indices.push(node_index.index());
             for edge in self.graph.edges_directed(node_index, dir) {
                 let edge_w = edge.weight();
                 let triplet = (edge.source().index(), edge.target().index(), edge_w).to_object(py);
                 out_list.push(triplet);
+                edge_indices.push(edge.id().index());
             }
         }
         let py_out_list: PyObject = PyList::new(py, out_list).into();
         out_dict.set_item("edges", py_out_list)?;
+        self.attributes
+            .get_state(py, out_dict, &node_indices, &edge_indices)?;
         Ok(out_dict.into())
     }
 
     fn __setstate__(&mut self, py: Python, state: PyObject) -> PyResult<()> {
+        self.generation += 1;
         self.graph = StablePyGraph::<Directed>::new();
+        self.cycle_state = None;
+        self.node_keys = NodeKeys::default();
+        self.attributes = AttributeColumns::default();
         let dict_state = state.cast_as::<PyDict>(py)?;
 
         let nodes_dict = dict_state.get_item("nodes").unwrap().downcast::<PyDict>()?;
@@ -316,7 +448,7 @@ impl PyDiGraph {
             node_indices.push(tmp_index.extract()?);
         }
         if node_indices.is_empty() {
-            return Ok(());
+            return self.attributes.set_state(dict_state, &[], &[]);
         }
         let max_index: usize = *node_indices.iter().max().unwrap();
         if max_index + 1 != node_indices.len() {
@@ -339,6 +471,7 @@ impl PyDiGraph {
         for tmp_node in tmp_nodes {
             self.graph.remove_node(tmp_node);
         }
+        let mut edge_indices: Vec<usize> = Vec::with_capacity(edges_list.len());
         for raw_edge in edges_list.iter() {
             let edge = raw_edge.downcast::<PyTuple>()?;
             let raw_p_index = edge.get_item(0)?.downcast::<PyLong>()?;
@@ -346,13 +479,23 @@ impl PyDiGraph {
             let raw_c_index = edge.get_item(1)?.downcast::<PyLong>()?;
             let c_index: usize = raw_c_index.extract()?;
             let edge_data = edge.get_item(2)?;
-            self.graph.add_edge(
+            let edge_index = self.graph.add_edge(
                 NodeIndex::new(p_index),
                 NodeIndex::new(c_index),
                 edge_data.into(),
             );
+            edge_indices.push(edge_index.index());
         }
-        Ok(())
+        // Pickles from before node keys were added don't have any
+        if let Some(keys_dict) = dict_state.get_item("node_keys") {
+            for (node, key) in keys_dict.downcast::<PyDict>()?.iter() {
+                self.node_keys
+                    .insert(py, NodeIndex::new(node.extract()?), key.into())?;
+            }
+        }
+        let node_indices: Vec<usize> = self.graph.node_indices().map(|n| n.index()).collect();
+        self.attributes
+            .set_state(dict_state, &node_indices, &edge_indices)
     }
 
     /// Whether cycle checking is enabled for the DiGraph/DAG.
@@ -366,13 +509,54 @@ impl PyDiGraph {
 
     #[setter]
     fn set_check_cycle(&mut self, value: bool) -> PyResult<()> {
-        if !self.check_cycle && value && !is_directed_acyclic_graph(self) {
-            return Err(DAGHasCycle::new_err("PyDiGraph object has a cycle"));
+        if !self.check_cycle && value {
+            if !is_directed_acyclic_graph(self) {
+                return Err(DAGHasCycle::new_err("PyDiGraph object has a cycle"));
+            }
+            // The order doesn't include the edges added since it was unset
+            self.cycle_state = None;
         }
         self.check_cycle = value;
         Ok(())
     }
 
+    /// Return a topological order of the nodes.
+    ///
+    /// If :attr:`~PyDiGraph.check_cycle` is set this is the order maintained
+    /// as edges are added, which is only updated where an added edge doesn't
+    /// agree with it, so it stays the same from one call to the next as much
+    /// as possible. Otherwise the graph is sorted on every call.
+    ///
+    /// .. jupyter-execute::
+    ///
+    ///     import retworkx
+    ///
+    ///     dag = retworkx.PyDAG(check_cycle=True)
+    ///     dag.add_nodes_from(range(4))
+    ///     dag.add_edges_from_no_data([(0, 1), (2, 3)])
+    ///     print(dag.topological_order())
+    ///     dag.add_edge(3, 0, None)
+    ///     print(dag.topological_order())
+    ///
+    /// :returns: The indices of the nodes in topological order
+    /// :rtype: NodeIndices
+    /// :raises DAGHasCycle: If the graph has a cycle
+    #[pyo3(text_signature = "(self)")]
+    pub fn topological_order(&mut self) -> PyResult<NodeIndices> {
+        let nodes = if self.check_cycle {
+            self.ensure_topological_order()?;
+            self.cycle_state.as_ref().unwrap().sort(&self.graph)
+        } else {
+            match IncrementalTopologicalOrder::from_graph(&self.graph) {
+                Some(order) => order.sort(&self.graph),
+                None => return Err(DAGHasCycle::new_err("PyDiGraph object has a cycle")),
+            }
+        };
+        Ok(NodeIndices {
+            nodes: nodes.iter().map(|node| node.index()).collect(),
+        })
+    }
+
     /// Whether the graph is a multigraph (allows multiple edges between
     /// nodes) or not
     ///
@@ -704,6 +888,7 @@ impl PyDiGraph {
     /// :raises NoEdgeBetweenNodes: When there is no edge between nodes
     #[pyo3(text_signature = "(self, source, target, edge /)")]
     pub fn update_edge(&mut self, source: usize, target: usize, edge: PyObject) -> PyResult<()> {
+        self.generation += 1;
         let index_a = NodeIndex::new(source);
         let index_b = NodeIndex::new(target);
         let edge_index = match self.graph.find_edge(index_a, index_b) {
@@ -724,6 +909,7 @@ impl PyDiGraph {
     ///     index
     #[pyo3(text_signature = "(self, edge_index, edge, /)")]
     pub fn update_edge_by_index(&mut self, edge_index: usize, edge: PyObject) -> PyResult<()> {
+        self.generation += 1;
         match self.graph.edge_weight_mut(EdgeIndex::new(edge_index)) {
             Some(data) => *data = edge,
             None => return Err(PyIndexError::new_err("No edge found for index")),
@@ -849,8 +1035,11 @@ impl PyDiGraph {
     ///     no effect.
     #[pyo3(text_signature = "(self, node, /)")]
     pub fn remove_node(&mut self, node: usize) -> PyResult<()> {
+        self.generation += 1;
         let index = NodeIndex::new(node);
+        self.attributes.remove_node(&self.graph, index);
         self.graph.remove_node(index);
+        self.node_keys.remove(index);
         self.node_removed = true;
         Ok(())
     }
@@ -885,6 +1074,7 @@ impl PyDiGraph {
         use_outgoing: bool,
         condition: Option<PyObject>,
     ) -> PyResult<()> {
+        self.generation += 1;
         let index = NodeIndex::new(node);
         let mut edge_list: Vec<(NodeIndex, NodeIndex, PyObject)> = Vec::new();
 
@@ -922,7 +1112,9 @@ impl PyDiGraph {
         for (source, target, weight) in edge_list {
             self._add_edge(source, target, weight)?;
         }
+        self.attributes.remove_node(&self.graph, index);
         self.graph.remove_node(index);
+        self.node_keys.remove(index);
         self.node_removed = true;
         Ok(())
     }
@@ -945,6 +1137,7 @@ impl PyDiGraph {
     /// :raises: When the new edge will create a cycle
     #[pyo3(text_signature = "(self, parent, child, edge, /)")]
     pub fn add_edge(&mut self, parent: usize, child: usize, edge: PyObject) -> PyResult<usize> {
+        self.generation += 1;
         let p_index = NodeIndex::new(parent);
         let c_index = NodeIndex::new(child);
         let out_index = self._add_edge(p_index, c_index, edge)?;
@@ -965,6 +1158,7 @@ impl PyDiGraph {
         &mut self,
         obj_list: Vec<(usize, usize, PyObject)>,
     ) -> PyResult<Vec<usize>> {
+        self.generation += 1;
         let mut out_list: Vec<usize> = Vec::with_capacity(obj_list.len());
         for obj in obj_list {
             let p_index = NodeIndex::new(obj.0);
@@ -991,6 +1185,7 @@ impl PyDiGraph {
         py: Python,
         obj_list: Vec<(usize, usize)>,
     ) -> PyResult<Vec<usize>> {
+        self.generation += 1;
         let mut out_list: Vec<usize> = Vec::with_capacity(obj_list.len());
         for obj in obj_list {
             let p_index = NodeIndex::new(obj.0);
@@ -1016,6 +1211,7 @@ impl PyDiGraph {
         py: Python,
         edge_list: Vec<(usize, usize)>,
     ) -> PyResult<()> {
+        self.generation += 1;
         for (source, target) in edge_list {
             let max_index = cmp::max(source, target);
             while max_index >= self.node_count() {
@@ -1041,6 +1237,7 @@ impl PyDiGraph {
         py: Python,
         edge_list: Vec<(usize, usize, PyObject)>,
     ) -> PyResult<()> {
+        self.generation += 1;
         for (source, target, weight) in edge_list {
             let max_index = cmp::max(source, target);
             while max_index >= self.node_count() {
@@ -1070,6 +1267,7 @@ impl PyDiGraph {
         node: usize,
         ref_nodes: Vec<usize>,
     ) -> PyResult<()> {
+        self.generation += 1;
         for ref_node in ref_nodes {
             self.insert_between(py, node, ref_node, false)?;
         }
@@ -1095,6 +1293,7 @@ impl PyDiGraph {
         node: usize,
         ref_nodes: Vec<usize>,
     ) -> PyResult<()> {
+        self.generation += 1;
         for ref_node in ref_nodes {
             self.insert_between(py, node, ref_node, true)?;
         }
@@ -1120,6 +1319,7 @@ impl PyDiGraph {
         node: usize,
         ref_node: usize,
     ) -> PyResult<()> {
+        self.generation += 1;
         self.insert_between(py, node, ref_node, false)?;
         Ok(())
     }
@@ -1143,6 +1343,7 @@ impl PyDiGraph {
         node: usize,
         ref_node: usize,
     ) -> PyResult<()> {
+        self.generation += 1;
         self.insert_between(py, node, ref_node, true)?;
         Ok(())
     }
@@ -1159,6 +1360,7 @@ impl PyDiGraph {
     ///     specified
     #[pyo3(text_signature = "(self, parent, child, /)")]
     pub fn remove_edge(&mut self, parent: usize, child: usize) -> PyResult<()> {
+        self.generation += 1;
         let p_index = NodeIndex::new(parent);
         let c_index = NodeIndex::new(child);
         let edge_index = match self.graph.find_edge(p_index, c_index) {
@@ -1166,6 +1368,7 @@ impl PyDiGraph {
             None => return Err(NoEdgeBetweenNodes::new_err("No edge found between nodes")),
         };
         self.graph.remove_edge(edge_index);
+        self.attributes.remove_edge(edge_index);
         Ok(())
     }
 
@@ -1174,8 +1377,10 @@ impl PyDiGraph {
     /// :param int edge: The index of the edge to remove
     #[pyo3(text_signature = "(self, edge, /)")]
     pub fn remove_edge_from_index(&mut self, edge: usize) -> PyResult<()> {
+        self.generation += 1;
         let edge_index = EdgeIndex::new(edge);
         self.graph.remove_edge(edge_index);
+        self.attributes.remove_edge(edge_index);
         Ok(())
     }
 
@@ -1191,6 +1396,7 @@ impl PyDiGraph {
     ///     pair of nodes.
     #[pyo3(text_signature = "(self, index_list, /)")]
     pub fn remove_edges_from(&mut self, index_list: Vec<(usize, usize)>) -> PyResult<()> {
+        self.generation += 1;
         for (p_index, c_index) in index_list
             .iter()
             .map(|(x, y)| (NodeIndex::new(*x), NodeIndex::new(*y)))
@@ -1200,6 +1406,7 @@ impl PyDiGraph {
                 None => return Err(NoEdgeBetweenNodes::new_err("No edge found between nodes")),
             };
             self.graph.remove_edge(edge_index);
+            self.attributes.remove_edge(edge_index);
         }
         Ok(())
     }
@@ -1212,6 +1419,7 @@ impl PyDiGraph {
     /// :rtype: int
     #[pyo3(text_signature = "(self, obj, /)")]
     pub fn add_node(&mut self, obj: PyObject) -> PyResult<usize> {
+        self.generation += 1;
         let index = self.graph.add_node(obj);
         Ok(index.index())
     }
@@ -1232,6 +1440,255 @@ impl PyDiGraph {
         find_node_by_weight(py, &self.graph, &obj).map(|node| node.map(|x| x.index()))
     }
 
+    /// Set the key of a node.
+    ///
+    /// A key is a hashable Python object, such as an external id, identifying
+    /// a node. Every node has at most one key and a key belongs to at most
+    /// one node. The key is removed along with the node, so it never refers
+    /// to a later node which reuses the node index.
+    ///
+    /// :param int node: The index of the node
+    /// :param key: The new key of the node, replacing its previous key
+    ///
+    /// :raises IndexError: If ``node`` is not in the graph
+    /// :raises ValueError: If ``key`` is the key of another node
+    /// :raises TypeError: If ``key`` is not hashable
+    #[pyo3(text_signature = "(self, node, key, /)")]
+    pub fn set_node_key(&mut self, py: Python, node: usize, key: PyObject) -> PyResult<()> {
+        self.generation += 1;
+        let index = NodeIndex::new(node);
+        if !self.graph.contains_node(index) {
+            return Err(PyIndexError::new_err("No node found for index"));
+        }
+        self.node_keys.insert(py, index, key)
+    }
+
+    /// Get the key of a node.
+    ///
+    /// :param int node: The index of the node
+    ///
+    /// :returns: The key of the node set with
+    ///     :meth:`~PyDiGraph.set_node_key`, or ``None`` if it has no key
+    #[pyo3(text_signature = "(self, node, /)")]
+    pub fn node_key(&self, py: Python, node: usize) -> Option<PyObject> {
+        self.node_keys
+            .get(NodeIndex::new(node))
+            .map(|key| key.clone_ref(py))
+    }
+
+    /// Remove the key of a node.
+    ///
+    /// :param int node: The index of the node
+    ///
+    /// :returns: The removed key, or ``None`` if the node has no key
+    #[pyo3(text_signature = "(self, node, /)")]
+    pub fn remove_node_key(&mut self, node: usize) -> Option<PyObject> {
+        self.generation += 1;
+        self.node_keys.remove(NodeIndex::new(node))
+    }
+
+    /// Find the node with a key
+    ///
+    /// This is a constant time lookup in the mapping of keys to node indices
+    /// kept by the graph.
+    ///
+    /// :param key: The key to look for
+    ///
+    /// :returns: The index of the node with the key, or ``None`` if no node
+    ///     has the key
+    /// :rtype: int
+    ///
+    /// :raises TypeError: If ``key`` is not hashable
+    #[pyo3(text_signature = "(self, key, /)")]
+    pub fn find_node_by_key(&self, py: Python, key: &PyAny) -> PyResult<Option<usize>> {
+        Ok(self.node_keys.find(py, key)?.map(|node| node.index()))
+    }
+
+    /// Get the node indices of the nodes with the given keys
+    ///
+    /// Use this to pass nodes identified by their keys to any method or
+    /// function taking node indices.
+    ///
+    /// :param keys: An iterable of keys
+    ///
+    /// :returns: The node indices of the nodes with the keys, in the order
+    ///     of ``keys``
+    /// :rtype: NodeIndices
+    ///
+    /// :raises KeyError: If no node has one of the keys
+    #[pyo3(text_signature = "(self, keys, /)")]
+    pub fn node_indices_by_keys(&self, py: Python, keys: &PyAny) -> PyResult<NodeIndices> {
+        Ok(NodeIndices {
+            nodes: self.node_keys.find_all(py, keys)?,
+        })
+    }
+
+    /// Get the keys of the nodes
+    ///
+    /// :returns: A new dictionary mapping every key to the index of its node
+    /// :rtype: dict
+    #[pyo3(text_signature = "(self)")]
+    pub fn node_keys(&self, py: Python) -> PyResult<PyObject> {
+        self.node_keys.to_dict(py)
+    }
+
+    /// Set the values of a node attribute column
+    ///
+    /// Attribute columns store typed values for the nodes of the graph next
+    /// to their weight/data payloads, keyed by attribute name. They're set
+    /// and read in bulk with numpy arrays, which avoids creating a Python
+    /// object for every value. A node without a value of an attribute, for
+    /// example a node added after the attribute was set, reads as ``nan``,
+    /// ``0`` or ``False`` depending on the type of the attribute. The values
+    /// of a node are cleared when it's removed.
+    ///
+    /// :param str name: The name of the attribute
+    /// :param numpy.ndarray values: A 1D array of ``float64``, ``int64`` or
+    ///     ``bool`` values, one for every node of ``nodes``
+    /// :param list nodes: An optional list of the node indices to set the
+    ///     values of. If not specified ``values`` must have a value for every
+    ///     node of the graph, in the order of
+    ///     :meth:`~PyDiGraph.node_indices`, and replaces any existing values
+    ///     of the attribute.
+    ///
+    /// :raises TypeError: If ``values`` isn't an array of a supported type, or
+    ///     if ``nodes`` is specified and the type of ``values`` isn't the type
+    ///     of the existing attribute
+    /// :raises ValueError: If there isn't one value for every node
+    /// :raises InvalidNode: If a node of ``nodes`` isn't in the graph
+    #[pyo3(text_signature = "(self, name, values, /, nodes=None)")]
+    pub fn set_node_attribute(
+        &mut self,
+        name: String,
+        values: &PyAny,
+        nodes: Option<Vec<usize>>,
+    ) -> PyResult<()> {
+        self.generation += 1;
+        let replace = nodes.is_none();
+        let nodes = attributes::node_positions(&self.graph, nodes)?;
+        self.attributes.nodes.set(name, values, &nodes, replace)
+    }
+
+    /// Get the values of a node attribute column
+    ///
+    /// :param str name: The name of the attribute
+    /// :param list nodes: An optional list of the node indices to get the
+    ///     values of. If not specified the values of every node are returned,
+    ///     in the order of :meth:`~PyDiGraph.node_indices`.
+    ///
+    /// :returns: A new 1D array with the values of the nodes
+    /// :rtype: numpy.ndarray
+    ///
+    /// :raises KeyError: If the graph has no node attribute ``name``
+    /// :raises InvalidNode: If a node of ``nodes`` isn't in the graph
+    #[pyo3(text_signature = "(self, name, /, nodes=None)")]
+    pub fn get_node_attribute(
+        &self,
+        py: Python,
+        name: &str,
+        nodes: Option<Vec<usize>>,
+    ) -> PyResult<PyObject> {
+        let nodes = attributes::node_positions(&self.graph, nodes)?;
+        self.attributes.nodes.take(py, name, &nodes)
+    }
+
+    /// Get the names of the node attribute columns
+    ///
+    /// :returns: The names of the node attributes, in the order they were
+    ///     first set
+    /// :rtype: list
+    #[pyo3(text_signature = "(self)")]
+    pub fn node_attributes(&self) -> Vec<String> {
+        self.attributes.nodes.names()
+    }
+
+    /// Remove a node attribute column
+    ///
+    /// :param str name: The name of the attribute
+    ///
+    /// :raises KeyError: If the graph has no node attribute ``name``
+    #[pyo3(text_signature = "(self, name, /)")]
+    pub fn remove_node_attribute(&mut self, name: &str) -> PyResult<()> {
+        self.generation += 1;
+        self.attributes.nodes.remove(name)
+    }
+
+    /// Set the values of an edge attribute column
+    ///
+    /// This works like :meth:`~PyDiGraph.set_node_attribute` for the edges of
+    /// the graph, the values of an edge are cleared when it's removed.
+    ///
+    /// :param str name: The name of the attribute
+    /// :param numpy.ndarray values: A 1D array of ``float64``, ``int64`` or
+    ///     ``bool`` values, one for every edge of ``edges``
+    /// :param list edges: An optional list of the edge indices to set the
+    ///     values of. If not specified ``values`` must have a value for every
+    ///     edge of the graph, in the order of
+    ///     :meth:`~PyDiGraph.edge_indices`, and replaces any existing values
+    ///     of the attribute.
+    ///
+    /// :raises TypeError: If ``values`` isn't an array of a supported type, or
+    ///     if ``edges`` is specified and the type of ``values`` isn't the type
+    ///     of the existing attribute
+    /// :raises ValueError: If there isn't one value for every edge
+    /// :raises IndexError: If an edge of ``edges`` isn't in the graph
+    #[pyo3(text_signature = "(self, name, values, /, edges=None)")]
+    pub fn set_edge_attribute(
+        &mut self,
+        name: String,
+        values: &PyAny,
+        edges: Option<Vec<usize>>,
+    ) -> PyResult<()> {
+        self.generation += 1;
+        let replace = edges.is_none();
+        let edges = attributes::edge_positions(&self.graph, edges)?;
+        self.attributes.edges.set(name, values, &edges, replace)
+    }
+
+    /// Get the values of an edge attribute column
+    ///
+    /// :param str name: The name of the attribute
+    /// :param list edges: An optional list of the edge indices to get the
+    ///     values of. If not specified the values of every edge are returned,
+    ///     in the order of :meth:`~PyDiGraph.edge_indices`.
+    ///
+    /// :returns: A new 1D array with the values of the edges
+    /// :rtype: numpy.ndarray
+    ///
+    /// :raises KeyError: If the graph has no edge attribute ``name``
+    /// :raises IndexError: If an edge of ``edges`` isn't in the graph
+    #[pyo3(text_signature = "(self, name, /, edges=None)")]
+    pub fn get_edge_attribute(
+        &self,
+        py: Python,
+        name: &str,
+        edges: Option<Vec<usize>>,
+    ) -> PyResult<PyObject> {
+        let edges = attributes::edge_positions(&self.graph, edges)?;
+        self.attributes.edges.take(py, name, &edges)
+    }
+
+    /// Get the names of the edge attribute columns
+    ///
+    /// :returns: The names of the edge attributes, in the order they were
+    ///     first set
+    /// :rtype: list
+    #[pyo3(text_signature = "(self)")]
+    pub fn edge_attributes(&self) -> Vec<String> {
+        self.attributes.edges.names()
+    }
+
+    /// Remove an edge attribute column
+    ///
+    /// :param str name: The name of the attribute
+    ///
+    /// :raises KeyError: If the graph has no edge attribute ``name``
+    #[pyo3(text_signature = "(self, name, /)")]
+    pub fn remove_edge_attribute(&mut self, name: &str) -> PyResult<()> {
+        self.generation += 1;
+        self.attributes.edges.remove(name)
+    }
+
     /// Merge two nodes in the graph.
     ///
     /// If the nodes have equal weight objects then all the edges into and out of `u` will be added
@@ -1242,6 +1699,7 @@ impl PyDiGraph {
     /// :param int v: The target node that is going to be the new node
     #[pyo3(text_signature = "(self, u, v /)")]
     pub fn merge_nodes(&mut self, py: Python, u: usize, v: usize) -> PyResult<()> {
+        self.generation += 1;
         let source_node = NodeIndex::new(u);
         let target_node = NodeIndex::new(v);
 
@@ -1297,8 +1755,14 @@ impl PyDiGraph {
     /// :rtype: int
     #[pyo3(text_signature = "(self, parent, obj, edge, /)")]
     pub fn add_child(&mut self, parent: usize, obj: PyObject, edge: PyObject) -> PyResult<usize> {
+        self.generation += 1;
         let index = NodeIndex::new(parent);
         let child_node = self.graph.add_node(obj);
+        // A new node can't close a cycle, and putting it after its parent in
+        // the maintained order takes constant time
+        if let Some(order) = self.cycle_state.as_mut() {
+            order.add_edge(&self.graph, index, child_node);
+        }
         self.graph.add_edge(index, child_node, edge);
         Ok(child_node.index())
     }
@@ -1316,8 +1780,12 @@ impl PyDiGraph {
     /// :rtype: int
     #[pyo3(text_signature = "(self, child, obj, edge, /)")]
     pub fn add_parent(&mut self, child: usize, obj: PyObject, edge: PyObject) -> PyResult<usize> {
+        self.generation += 1;
         let index = NodeIndex::new(child);
         let parent_node = self.graph.add_node(obj);
+        if let Some(order) = self.cycle_state.as_mut() {
+            order.insert_first(parent_node.index());
+        }
         self.graph.add_edge(parent_node, index, edge);
         Ok(parent_node.index())
     }
@@ -1608,6 +2076,7 @@ impl PyDiGraph {
     /// :rtype: NodeIndices
     #[pyo3(text_signature = "(self, obj_list, /)")]
     pub fn add_nodes_from(&mut self, obj_list: Vec<PyObject>) -> NodeIndices {
+        self.generation += 1;
         let out_list: Vec<usize> = obj_list
             .into_iter()
             .map(|obj| self.graph.add_node(obj).index())
@@ -1624,12 +2093,87 @@ impl PyDiGraph {
     ///     the graph.
     #[pyo3(text_signature = "(self, index_list, /)")]
     pub fn remove_nodes_from(&mut self, index_list: Vec<usize>) -> PyResult<()> {
+        self.generation += 1;
         for node in index_list.iter().map(|x| NodeIndex::new(*x)) {
+            self.attributes.remove_node(&self.graph, node);
             self.graph.remove_node(node);
+            self.node_keys.remove(node);
         }
+        self.node_removed = true;
         Ok(())
     }
 
+    /// Remove every node from the graph whose data payload doesn't pass a
+    /// filter.
+    ///
+    /// This calls ``predicate`` on every node and then removes all the
+    /// rejected nodes, and their edges, in a single pass. If ``predicate``
+    /// raises an exception the graph is left unchanged.
+    ///
+    /// :param predicate: A callable which takes the data payload/weight of a
+    ///     node and returns ``True`` for the nodes to keep and ``False`` for
+    ///     the nodes to remove.
+    ///
+    /// :returns: The indices of the removed nodes
+    /// :rtype: NodeIndices
+    #[pyo3(text_signature = "(self, predicate, /)")]
+    pub fn filter_nodes(&mut self, py: Python, predicate: PyObject) -> PyResult<NodeIndices> {
+        self.generation += 1;
+        let mut removed = Vec::new();
+        for node in self.graph.node_indices() {
+            if !predicate
+                .call1(py, (&self.graph[node],))?
+                .extract::<bool>(py)?
+            {
+                removed.push(node);
+            }
+        }
+        for node in removed.iter() {
+            self.attributes.remove_node(&self.graph, *node);
+            self.graph.remove_node(*node);
+            self.node_keys.remove(*node);
+        }
+        if !removed.is_empty() {
+            self.node_removed = true;
+        }
+        Ok(NodeIndices {
+            nodes: removed.into_iter().map(|node| node.index()).collect(),
+        })
+    }
+
+    /// Compact the node and edge indices of the graph.
+    ///
+    /// Removing nodes and edges leaves holes in the indices of a graph, which
+    /// are only filled by nodes and edges added later. This renumbers the
+    /// nodes from ``0`` to ``num_nodes() - 1`` and the edges from ``0`` to
+    /// ``num_edges() - 1``, keeping their relative order, along with their
+    /// keys and attributes. Any index of a node or an edge held outside of
+    /// the graph, including by a view of it, refers to a different node or
+    /// edge after this call.
+    ///
+    /// :returns: A mapping of the old index of every node to its new index
+    /// :rtype: NodeMap
+    #[pyo3(text_signature = "(self)")]
+    pub fn compact(&mut self, py: Python) -> PyResult<NodeMap> {
+        self.generation += 1;
+        let nodes: Vec<usize> = self.graph.node_indices().map(|node| node.index()).collect();
+        let edges: Vec<usize> = self.graph.edge_indices().map(|edge| edge.index()).collect();
+        let graph = std::mem::take(&mut self.graph);
+        // Converting to a Graph drops the holes and keeps the relative order
+        self.graph = StableGraph::from(Graph::from(graph));
+        self.cycle_state = None;
+        let node_map: DictMap<usize, usize> = nodes
+            .iter()
+            .enumerate()
+            .map(|(new, old)| (*old, new))
+            .collect();
+        self.node_keys
+            .reindex(py, |node| NodeIndex::new(node_map[&node.index()]))?;
+        self.attributes.compact(&nodes, &edges);
+        self.node_removed = false;
+        Ok(NodeMap { node_map })
+    }
+
     /// Get the degree of a node for inbound edges.
     ///
     /// :param int node: The index of the node to find the inbound degree of
@@ -1777,7 +2321,10 @@ impl PyDiGraph {
     ///
     /// The expected format for the edge list file is a line seperated list
     /// of deliminated node ids. If there are more than 3 elements on
-    /// a line the 3rd on will be treated as a string weight for the edge
+    /// a line the 3rd on will be treated as a string weight for the edge,
+    /// unless ``weight_column`` is set. Large files are parsed in parallel,
+    /// the environment variable ``RAYON_NUM_THREADS`` can be used to adjust
+    /// how many threads will be used.
     ///
     /// :param str path: The path of the file to open
     /// :param str comment: Optional character to use as a comment by default
@@ -1787,6 +2334,11 @@ impl PyDiGraph {
     /// :param bool labels: If set to ``True`` the first two separated fields
     ///     will be treated as string labels uniquely identifying a node
     ///     instead of node indices.
+    /// :param int weight_column: Optional index of the field, counting
+    ///     from 0 for the source node, to parse as a ``float`` weight for
+    ///     the edge. Other fields after the node ids are ignored. A
+    ///     ``ValueError`` is raised if a line doesn't have a numeric value
+    ///     in this field.
     ///
     /// For example:
     ///
@@ -1810,88 +2362,34 @@ impl PyDiGraph {
     ///
     #[staticmethod]
     #[args(labels = "false")]
-    #[pyo3(text_signature = "(path, /, comment=None, deliminator=None, labels=False)")]
+    #[pyo3(
+        text_signature = "(path, /, comment=None, deliminator=None, labels=False, weight_column=None)"
+    )]
     pub fn read_edge_list(
         py: Python,
         path: &str,
         comment: Option<String>,
         deliminator: Option<String>,
         labels: bool,
+        weight_column: Option<usize>,
     ) -> PyResult<PyDiGraph> {
-        let file = File::open(path)?;
-        let buf_reader = BufReader::new(file);
-        let mut out_graph = StablePyGraph::<Directed>::new();
-        let mut label_map: HashMap<String, usize> = HashMap::new();
-        for line_raw in buf_reader.lines() {
-            let line = line_raw?;
-            let skip = match &comment {
-                Some(comm) => line.trim().starts_with(comm),
-                None => line.trim().is_empty(),
-            };
-            if skip {
-                continue;
-            }
-            let line_no_comments = match &comment {
-                Some(comm) => line
-                    .find(comm)
-                    .map(|idx| &line[..idx])
-                    .unwrap_or(&line)
-                    .trim()
-                    .to_string(),
-                None => line,
-            };
-            let pieces: Vec<&str> = match &deliminator {
-                Some(del) => line_no_comments.split(del).collect(),
-                None => line_no_comments.split_whitespace().collect(),
-            };
-            let src: usize;
-            let target: usize;
-            if labels {
-                let src_str = pieces[0];
-                let target_str = pieces[1];
-                src = match label_map.get(src_str) {
-                    Some(index) => *index,
-                    None => {
-                        let index = out_graph.add_node(src_str.to_object(py)).index();
-                        label_map.insert(src_str.to_string(), index);
-                        index
-                    }
-                };
-                target = match label_map.get(target_str) {
-                    Some(index) => *index,
-                    None => {
-                        let index = out_graph.add_node(target_str.to_object(py)).index();
-                        label_map.insert(target_str.to_string(), index);
-                        index
-                    }
-                };
-            } else {
-                src = pieces[0].parse::<usize>()?;
-                target = pieces[1].parse::<usize>()?;
-                let max_index = cmp::max(src, target);
-                // Add nodes to graph
-                while max_index >= out_graph.node_count() {
-                    out_graph.add_node(py.None());
-                }
-            }
-            // Add edges to graph
-            let weight = if pieces.len() > 2 {
-                let weight_str = match &deliminator {
-                    Some(del) => pieces[2..].join(del),
-                    None => pieces[2..].join(&' '.to_string()),
-                };
-                PyString::new(py, &weight_str).into()
-            } else {
-                py.None()
-            };
-            out_graph.add_edge(NodeIndex::new(src), NodeIndex::new(target), weight);
-        }
+        let format = edge_list::EdgeListFormat {
+            comment: comment.as_deref(),
+            deliminator: deliminator.as_deref(),
+            labels,
+            weight_column,
+            first_index: 0,
+        };
+        let out_graph = edge_list::read_edge_list::<Directed>(py, path, &format)?;
         Ok(PyDiGraph {
             graph: out_graph,
-            cycle_state: algo::DfsSpace::default(),
+            cycle_state: None,
             check_cycle: false,
             node_removed: false,
             multigraph: true,
+            node_keys: NodeKeys::default(),
+            attributes: AttributeColumns::default(),
+            generation: 0,
         })
     }
 
@@ -1956,6 +2454,95 @@ impl PyDiGraph {
         Ok(())
     }
 
+    /// Write a binary snapshot of the graph to a file
+    ///
+    /// A snapshot is a compact binary format which is much faster to load
+    /// than a pickle of a large graph. The structure of the graph is stored
+    /// natively and the payloads of all the nodes and of all the edges are
+    /// stored as 2 lists serialized with ``serializer``, calling it twice
+    /// rather than once per payload. The node indices, node keys and
+    /// attribute columns are kept. Like pickling, the edge indices are
+    /// renumbered in order when the snapshot is read.
+    ///
+    /// To reject corrupt files, a snapshot can't be read if it has more than
+    /// 16 removed node indices per node (and more than 65536 in total), use
+    /// :meth:`~PyDiGraph.compact` first on a graph with that many removed nodes.
+    ///
+    /// :param path: The path of the file to write the snapshot to
+    /// :param serializer: An optional object with ``dumps`` and ``loads``
+    ///     functions, like the :mod:`pickle` module which is used by default,
+    ///     to serialize lists of payloads to ``bytes``. The same serializer
+    ///     must be passed to :meth:`~retworkx.PyDiGraph.read_snapshot`.
+    ///
+    /// For example:
+    ///
+    /// .. jupyter-execute::
+    ///
+    ///   import os
+    ///   import tempfile
+    ///
+    ///   import retworkx
+    ///
+    ///   graph = retworkx.generators.directed_path_graph(5)
+    ///   path = os.path.join(tempfile.gettempdir(), "snapshot.rx")
+    ///   graph.write_snapshot(path)
+    ///   new_graph = retworkx.PyDiGraph.read_snapshot(path)
+    ///   print(new_graph.edge_list())
+    #[pyo3(text_signature = "(self, path, /, serializer=None)")]
+    pub fn write_snapshot(
+        &self,
+        py: Python,
+        path: PathBuf,
+        serializer: Option<PyObject>,
+    ) -> PyResult<()> {
+        snapshot::write_snapshot(
+            py,
+            &self.graph,
+            self.multigraph,
+            self.node_removed,
+            self.check_cycle,
+            &self.node_keys,
+            &self.attributes,
+            path,
+            serializer,
+        )
+    }
+
+    /// Read a graph from a binary snapshot file
+    ///
+    /// This reads a snapshot written by :meth:`~retworkx.PyDiGraph.write_snapshot`.
+    ///
+    /// :param path: The path of the snapshot file
+    /// :param serializer: An optional object with a ``loads`` function used
+    ///     to deserialize the payloads, this must match the serializer used
+    ///     to write the snapshot. By default :mod:`pickle` is used.
+    ///
+    /// :returns: The graph stored in the snapshot
+    /// :rtype: PyDiGraph
+    ///
+    /// :raises ValueError: If the file isn't a valid snapshot or it's a
+    ///     snapshot of an undirected graph
+    /// :raises OSError: If the file can't be read
+    #[staticmethod]
+    #[pyo3(text_signature = "(path, /, serializer=None)")]
+    pub fn read_snapshot(
+        py: Python,
+        path: PathBuf,
+        serializer: Option<PyObject>,
+    ) -> PyResult<PyDiGraph> {
+        let snapshot = snapshot::read_snapshot::<Directed>(py, path, serializer)?;
+        Ok(PyDiGraph {
+            graph: snapshot.graph,
+            cycle_state: None,
+            check_cycle: snapshot.check_cycle,
+            node_removed: snapshot.node_removed,
+            multigraph: snapshot.multigraph,
+            node_keys: snapshot.node_keys,
+            attributes: snapshot.attributes,
+            generation: 0,
+        })
+    }
+
     /// Create a new :class:`~retworkx.PyDiGraph` object from an adjacency matrix
     /// with matrix elements of type ``float``
     ///
@@ -1967,15 +2554,16 @@ impl PyDiGraph {
     /// This differs from the
     /// :meth:`~retworkx.PyDiGraph.from_complex_adjacency_matrix` in that the
     /// type of the elements of input matrix must be a ``float`` (specifically
-    /// a ``numpy.float64``) and the output graph edge weights will be ``float``
-    /// too. While in :meth:`~retworkx.PyDiGraph.from_complex_adjacency_matrix`
+    /// a ``numpy.float64`` or ``numpy.float32``) and the output graph edge
+    /// weights will be ``float`` too. While in :meth:`~retworkx.PyDiGraph.from_complex_adjacency_matrix`
     /// the matrix elements are of type ``complex`` (specifically
-    /// ``numpy.complex128``) and the edge weights in the output graph will be
-    /// ``complex`` too.
+    /// ``numpy.complex128`` or ``numpy.complex64``) and the edge weights in
+    /// the output graph will be ``complex`` too.
     ///
     /// :param ndarray matrix: The input numpy array adjacency matrix to create
     ///     a new :class:`~retworkx.PyDiGraph` object from. It must be a 2
-    ///     dimensional array and be a ``float``/``np.float64`` data type.
+    ///     dimensional array and be a ``float``/``np.float64`` or
+    ///     ``np.float32`` data type.
     /// :param float null_value: An optional float that will treated as a null
     ///     value. If any element in the input matrix is this value it will be
     ///     treated as not an edge. By default this is ``0.0``
@@ -1987,10 +2575,13 @@ impl PyDiGraph {
     #[pyo3(text_signature = "(matrix, /, null_value=0.0)")]
     pub fn from_adjacency_matrix<'p>(
         py: Python<'p>,
-        matrix: PyReadonlyArray2<'p, f64>,
+        matrix: RealMatrix<'p>,
         null_value: f64,
     ) -> PyDiGraph {
-        _from_adjacency_matrix(py, matrix, null_value)
+        match matrix {
+            RealMatrix::Float64(matrix) => _from_adjacency_matrix(py, matrix, null_value),
+            RealMatrix::Float32(matrix) => _from_adjacency_matrix(py, matrix, null_value as f32),
+        }
     }
 
     /// Create a new :class:`~retworkx.PyDiGraph` object from an adjacency matrix
@@ -2004,15 +2595,17 @@ impl PyDiGraph {
     /// This differs from the
     /// :meth:`~retworkx.PyDiGraph.from_adjacency_matrix` in that the type of
     /// the elements of the input matrix in this method must be a ``complex``
-    /// (specifically a ``numpy.complex128``) and the output graph edge weights
-    /// will be ``complex`` too. While in
+    /// (specifically a ``numpy.complex128`` or ``numpy.complex64``) and the
+    /// output graph edge weights will be ``complex`` too. While in
     /// :meth:`~retworkx.PyDiGraph.from_adjacency_matrix` the matrix elements
-    /// are of type ``float`` (specifically ``numpy.float64``) and the edge
-    /// weights in the output graph will be ``float`` too.
+    /// are of type ``float`` (specifically ``numpy.float64`` or
+    /// ``numpy.float32``) and the edge weights in the output graph will be
+    /// ``float`` too.
     ///
     /// :param ndarray matrix: The input numpy array adjacency matrix to create
     ///     a new :class:`~retworkx.PyDiGraph` object from. It must be a 2
-    ///     dimensional array and be a ``complex``/``np.complex128`` data type.
+    ///     dimensional array and be a ``complex``/``np.complex128`` or
+    ///     ``np.complex64`` data type.
     /// :param complex null_value: An optional complex that will treated as a
     ///     null value. If any element in the input matrix is this value it
     ///     will be treated as not an edge. By default this is ``0.0+0.0j``
@@ -2024,10 +2617,80 @@ impl PyDiGraph {
     #[pyo3(text_signature = "(matrix, /, null_value=0.0+0.0j)")]
     pub fn from_complex_adjacency_matrix<'p>(
         py: Python<'p>,
-        matrix: PyReadonlyArray2<'p, Complex64>,
+        matrix: ComplexMatrix<'p>,
         null_value: Complex64,
     ) -> PyDiGraph {
-        _from_adjacency_matrix(py, matrix, null_value)
+        match matrix {
+            ComplexMatrix::Complex128(matrix) => _from_adjacency_matrix(py, matrix, null_value),
+            ComplexMatrix::Complex64(matrix) => _from_adjacency_matrix(
+                py,
+                matrix,
+                Complex32::new(null_value.re as f32, null_value.im as f32),
+            ),
+        }
+    }
+
+    /// Create a new :class:`~retworkx.PyDiGraph` object from arrays of edges
+    ///
+    /// This is a fast way to build a large graph: the ``sources``,
+    /// ``targets`` and ``weights`` numpy arrays are read directly, without
+    /// creating a Python tuple for every edge like
+    /// :meth:`~retworkx.PyDiGraph.add_edges_from` needs. There is a node for
+    /// every index up to the largest node index in ``sources`` and
+    /// ``targets``, or up to ``num_nodes - 1``, and the payload of every node
+    /// is its index. Edge ``i`` goes from node ``sources[i]`` to node
+    /// ``targets[i]`` and has the payload ``weights[i]``, or ``None`` without
+    /// ``weights``. The edge indices are the positions in the arrays.
+    /// The graph is a multigraph, so repeated pairs of nodes are parallel
+    /// edges.
+    ///
+    /// For example::
+    ///
+    ///     import numpy as np
+    ///     import retworkx
+    ///
+    ///     sources = np.array([0, 1, 2])
+    ///     targets = np.array([1, 2, 0])
+    ///     graph = retworkx.PyDiGraph.from_edge_arrays(
+    ///         sources, targets, weights=np.array([0.5, 1.0, 2.0])
+    ///     )
+    ///
+    /// :param ndarray sources: A 1 dimensional array of the node indices of
+    ///     the sources of the edges, with an ``int64``, ``int32``, ``uint64``
+    ///     or ``uint32`` data type
+    /// :param ndarray targets: A 1 dimensional array of the node indices of
+    ///     the targets of the edges, with an ``int64``, ``int32``, ``uint64``
+    ///     or ``uint32`` data type
+    /// :param ndarray weights: An optional 1 dimensional array of the edge
+    ///     weights, with a ``float64``, ``float32``, ``int64`` or ``int32``
+    ///     data type
+    /// :param int num_nodes: An optional number of nodes for the graph, which
+    ///     must be larger than every node index in the arrays. This can be
+    ///     used to add nodes without edges at the end of the graph.
+    ///
+    /// :returns: A new graph object with the edges from the arrays
+    /// :rtype: PyDiGraph
+    /// :raises ValueError: If the arrays don't have the same length, a node
+    ///     index is negative or ``num_nodes`` is too small
+    #[staticmethod]
+    #[pyo3(text_signature = "(sources, targets, /, weights=None, num_nodes=None)")]
+    pub fn from_edge_arrays<'p>(
+        py: Python<'p>,
+        sources: IndexArray<'p>,
+        targets: IndexArray<'p>,
+        weights: Option<WeightArray<'p>>,
+        num_nodes: Option<usize>,
+    ) -> PyResult<PyDiGraph> {
+        Ok(PyDiGraph {
+            graph: graph_from_edge_arrays(py, sources, targets, weights, num_nodes)?,
+            cycle_state: None,
+            check_cycle: false,
+            node_removed: false,
+            multigraph: true,
+            node_keys: NodeKeys::default(),
+            attributes: AttributeColumns::default(),
+            generation: 0,
+        })
     }
 
     /// Add another PyDiGraph object into this PyDiGraph
@@ -2100,6 +2763,7 @@ impl PyDiGraph {
         node_map_func: Option<PyObject>,
         edge_map_func: Option<PyObject>,
     ) -> PyResult<PyObject> {
+        self.generation += 1;
         let mut new_node_map: DictMap<NodeIndex, NodeIndex> =
             DictMap::with_capacity(other.node_count());
 
@@ -2179,6 +2843,7 @@ impl PyDiGraph {
         node_filter: Option<PyObject>,
         edge_weight_map: Option<PyObject>,
     ) -> PyResult<NodeMap> {
+        self.generation += 1;
         let weight_map_fn = |obj: &PyObject, weight_fn: &Option<PyObject>| -> PyResult<PyObject> {
             match weight_fn {
                 Some(weight_fn) => weight_fn.call1(py, (obj,)),
@@ -2218,7 +2883,9 @@ impl PyDiGraph {
         // If no nodes are copied bail here since there is nothing left
         // to do.
         if out_map.is_empty() {
+            self.attributes.remove_node(&self.graph, node_index);
             self.graph.remove_node(node_index);
+            self.node_keys.remove(node_index);
             // Return a new empty map to clear allocation from out_map
             return Ok(NodeMap {
                 node_map: DictMap::new(),
@@ -2279,7 +2946,9 @@ impl PyDiGraph {
             self._add_edge(source_out, target, weight)?;
         }
         // Remove node
+        self.attributes.remove_node(&self.graph, node_index);
         self.graph.remove_node(node_index);
+        self.node_keys.remove(node_index);
         Ok(NodeMap { node_map: out_map })
     }
 
@@ -2319,37 +2988,11 @@ impl PyDiGraph {
         check_cycle: Option<bool>,
         weight_combo_fn: Option<PyObject>,
     ) -> PyResult<usize> {
-        let can_contract = |nodes: &IndexSet<NodeIndex, ahash::RandomState>| {
-            // Start with successors of `nodes` that aren't in `nodes` itself.
-            let visit_next: Vec<NodeIndex> = nodes
-                .iter()
-                .flat_map(|n| self.graph.edges(*n))
-                .filter_map(|edge| {
-                    let target_node = edge.target();
-                    if !nodes.contains(&target_node) {
-                        Some(target_node)
-                    } else {
-                        None
-                    }
-                })
-                .collect();
-
-            // Now, if we can reach any of `nodes`, there exists a path from `nodes`
-            // back to `nodes` of length > 1, meaning contraction is disallowed.
-            let mut dfs = Dfs::from_parts(visit_next, self.graph.visit_map());
-            while let Some(node) = dfs.next(&self.graph) {
-                if nodes.contains(&node) {
-                    // we found a path back to `nodes`
-                    return false;
-                }
-            }
-            true
-        };
-
+        self.generation += 1;
         let mut indices_to_remove: IndexSet<NodeIndex, ahash::RandomState> =
             nodes.into_iter().map(NodeIndex::new).collect();
 
-        if check_cycle.unwrap_or(self.check_cycle) && !can_contract(&indices_to_remove) {
+        if check_cycle.unwrap_or(self.check_cycle) && !self.can_contract(&indices_to_remove) {
             return Err(DAGWouldCycle::new_err("Contraction would create cycle(s)"));
         }
 
@@ -2388,7 +3031,9 @@ impl PyDiGraph {
 
         // Remove nodes that will be replaced.
         for index in indices_to_remove {
+            self.attributes.remove_node(&self.graph, index);
             self.graph.remove_node(index);
+            self.node_keys.remove(index);
         }
 
         // If `weight_combo_fn` was specified, merge edges according
@@ -2408,10 +3053,135 @@ impl PyDiGraph {
         for (target, weight) in outgoing_edges {
             self.add_edge_no_cycle_check(node_index, target, weight);
         }
+        self.cycle_state = None;
 
         Ok(node_index.index())
     }
 
+    /// Merge a set of nodes into the first of them.
+    ///
+    /// Unlike :meth:`~PyDiGraph.contract_nodes`, which replaces the nodes
+    /// with a new node, the first node of ``nodes`` is kept along with its
+    /// index, key and attributes, and the other nodes are removed after
+    /// their edges are moved to it. This makes it suitable for coarsening a
+    /// graph, where every node represents a group of nodes of the previous
+    /// level.
+    ///
+    /// The edges between the merged nodes become self loops of the merged
+    /// node and edges from (or to) several of the merged nodes to (or from)
+    /// the same neighbor become parallel edges. Both are handled according
+    /// to ``merge_edge_policy``:
+    ///
+    /// * ``"keep"``: all the edges are kept. If this
+    ///   :class:`~retworkx.PyDiGraph` is not a multigraph, parallel edges
+    ///   (including multiple self loops) are handled like with ``"drop"``.
+    /// * ``"drop"``: the self loops are removed and only the first of the
+    ///   parallel edges is kept, preferring the existing edges of the first
+    ///   node.
+    /// * ``"aggregate"``: the self loops are replaced with a single self loop
+    ///   and the parallel edges with a single edge, whose weight/data
+    ///   payload is built by calling ``weight_combo_fn`` on the payloads of
+    ///   the edges, in order.
+    ///
+    /// Edges of the first node that are kept keep their index, the other
+    /// edges kept are added as new edges.
+    ///
+    /// :param list nodes: The indices of the nodes to merge, the first one is
+    ///     kept and the others are merged into it.
+    /// :param merge_payload_fn: A python callable that will be passed a list
+    ///     of the data payloads/weights of ``nodes``, in order, and returns
+    ///     the data payload/weight of the merged node.
+    /// :param str merge_edge_policy: How self loops and parallel edges
+    ///     created by the merge are handled, one of ``"keep"``, ``"drop"``
+    ///     or ``"aggregate"``. Defaults to ``"drop"``.
+    /// :param weight_combo_fn: A python callable that will be passed the data
+    ///     payloads/weights of 2 edges and returns the combined data
+    ///     payload/weight. Required when ``merge_edge_policy`` is
+    ///     ``"aggregate"``.
+    ///
+    /// :returns: The index of the merged node
+    /// :rtype: int
+    /// :raises IndexError: If a node in ``nodes`` is not in the graph
+    /// :raises ValueError: If ``nodes`` is empty or ``merge_edge_policy`` is
+    ///     not valid
+    /// :raises DAGWouldCycle: If ``check_cycle`` is enabled and the merge
+    ///     would introduce cycle(s), including self loops.
+    #[args(merge_edge_policy = "\"drop\"")]
+    #[pyo3(
+        text_signature = "(self, nodes, merge_payload_fn, /, merge_edge_policy=\"drop\", weight_combo_fn=None)"
+    )]
+    pub fn merge_node_set(
+        &mut self,
+        py: Python,
+        nodes: Vec<usize>,
+        merge_payload_fn: PyObject,
+        merge_edge_policy: &str,
+        weight_combo_fn: Option<PyObject>,
+    ) -> PyResult<usize> {
+        self.generation += 1;
+        let policy = MergeEdgePolicy::from_name(merge_edge_policy, &weight_combo_fn)?;
+        let nodes = contraction::merge_set(&self.graph, nodes)?;
+        let payload =
+            merge_payload_fn.call1(py, (contraction::payload_list(py, &self.graph, &nodes),))?;
+        self.merge(py, &nodes, payload, policy, &weight_combo_fn)
+    }
+
+    /// Contract an edge, merging its target node into its source node.
+    ///
+    /// This is equivalent to calling :meth:`~PyDiGraph.merge_node_set` with the
+    /// source and target node of the edge. The contracted edge becomes a self
+    /// loop of the source node, which is handled according to
+    /// ``merge_edge_policy`` like any other edge between the merged nodes.
+    ///
+    /// :param int edge: The index of the edge to contract
+    /// :param merge_payload_fn: An optional python callable that will be
+    ///     passed a list with the data payloads/weights of the source and
+    ///     target nodes and returns the data payload/weight of the merged
+    ///     node. If not specified the merged node keeps the data
+    ///     payload/weight of the source node.
+    /// :param str merge_edge_policy: How self loops and parallel edges
+    ///     created by the contraction are handled, one of ``"keep"``,
+    ///     ``"drop"`` or ``"aggregate"``. Defaults to ``"drop"``, which
+    ///     removes the contracted edge.
+    /// :param weight_combo_fn: A python callable that will be passed the data
+    ///     payloads/weights of 2 edges and returns the combined data
+    ///     payload/weight. Required when ``merge_edge_policy`` is
+    ///     ``"aggregate"``.
+    ///
+    /// :returns: The index of the merged node
+    /// :rtype: int
+    /// :raises IndexError: If there is no edge with index ``edge``
+    /// :raises ValueError: If ``merge_edge_policy`` is not valid
+    /// :raises DAGWouldCycle: If ``check_cycle`` is enabled and the
+    ///     contraction would introduce cycle(s), including self loops.
+    #[args(merge_edge_policy = "\"drop\"")]
+    #[pyo3(
+        text_signature = "(self, edge, /, merge_payload_fn=None, merge_edge_policy=\"drop\", weight_combo_fn=None)"
+    )]
+    pub fn contract_edge(
+        &mut self,
+        py: Python,
+        edge: usize,
+        merge_payload_fn: Option<PyObject>,
+        merge_edge_policy: &str,
+        weight_combo_fn: Option<PyObject>,
+    ) -> PyResult<usize> {
+        self.generation += 1;
+        let policy = MergeEdgePolicy::from_name(merge_edge_policy, &weight_combo_fn)?;
+        let (source, target) = match self.graph.edge_endpoints(EdgeIndex::new(edge)) {
+            Some(endpoints) => endpoints,
+            None => return Err(PyIndexError::new_err("No edge found for index")),
+        };
+        let nodes = contraction::merge_set(&self.graph, vec![source.index(), target.index()])?;
+        let payload = match merge_payload_fn {
+            Some(merge_payload_fn) => {
+                merge_payload_fn.call1(py, (contraction::payload_list(py, &self.graph, &nodes),))?
+            }
+            None => self.graph[source].clone_ref(py),
+        };
+        self.merge(py, &nodes, payload, policy, &weight_combo_fn)
+    }
+
     /// Return a new PyDiGraph object for a subgraph of this graph
     ///
     /// :param list nodes: A list of node indices to generate the subgraph
@@ -2444,9 +3214,12 @@ impl PyDiGraph {
         PyDiGraph {
             graph: out_graph,
             node_removed: false,
-            cycle_state: algo::DfsSpace::default(),
+            cycle_state: None,
             check_cycle: self.check_cycle,
             multigraph: self.multigraph,
+            node_keys: NodeKeys::default(),
+            attributes: AttributeColumns::default(),
+            generation: 0,
         }
     }
 
@@ -2495,6 +3268,7 @@ impl PyDiGraph {
             .filter(|node| !nodes.contains(node))
         {
             out_graph.graph.remove_node(node);
+            out_graph.node_keys.remove(node);
             out_graph.node_removed = true;
         }
         for edge in self
@@ -2504,9 +3278,118 @@ impl PyDiGraph {
         {
             out_graph.graph.remove_edge(edge.id());
         }
+        out_graph.attributes.prune(&out_graph.graph);
         out_graph
     }
 
+    /// Return a view of a subgraph of the graph without copying it.
+    ///
+    /// The view has the nodes of the graph which are in ``nodes``, if it's
+    /// specified, and whose data payload passes ``node_filter``, and the
+    /// edges between them whose data payload passes ``edge_filter``, with
+    /// the same indices as in the graph. Unlike :meth:`~PyDiGraph.subgraph`
+    /// it doesn't copy the graph, and the functions which accept a view,
+    /// such as :func:`~retworkx.bfs_search`, run on it like on a graph
+    /// with only those nodes and edges.
+    ///
+    /// The filters are evaluated when the view is created, so nodes and
+    /// edges added to the graph afterwards are only in the view if no
+    /// filter is specified.
+    ///
+    /// :param list nodes: An optional list of the indices of the nodes to
+    ///     keep in the view
+    /// :param node_filter: An optional callable which takes the data
+    ///     payload of a node and returns ``True`` if the node is in the view
+    /// :param edge_filter: An optional callable which takes the data
+    ///     payload of an edge and returns ``True`` if the edge is in the view
+    ///
+    /// :returns: A view of the subgraph
+    /// :rtype: DiGraphView
+    ///
+    /// .. jupyter-execute::
+    ///
+    ///     import retworkx
+    ///
+    ///     graph = retworkx.generators.directed_path_graph(5)
+    ///     view = graph.subgraph_view(nodes=[0, 1, 3, 4])
+    ///     print(view.edge_list())
+    #[pyo3(text_signature = "(self, /, nodes=None, node_filter=None, edge_filter=None)")]
+    pub fn subgraph_view(
+        slf: PyRef<Self>,
+        py: Python,
+        nodes: Option<Vec<usize>>,
+        node_filter: Option<PyObject>,
+        edge_filter: Option<PyObject>,
+    ) -> PyResult<DiGraphView> {
+        let (nodes, edges) = view_filters(
+            py,
+            &slf.graph,
+            (None, None),
+            nodes,
+            node_filter,
+            edge_filter,
+        )?;
+        Ok(DiGraphView {
+            graph: slf.into(),
+            reversed: false,
+            nodes,
+            edges,
+        })
+    }
+
+    /// Return a view of the graph with the direction of every edge
+    /// reversed, without copying it.
+    ///
+    /// The view has the nodes and edges of the graph with the same indices.
+    /// The functions which accept a view, such as
+    /// :func:`~retworkx.bfs_search`, run on it like on the reversed graph.
+    ///
+    /// :returns: A reversed view of the graph
+    /// :rtype: DiGraphView
+    ///
+    /// .. jupyter-execute::
+    ///
+    ///     import retworkx
+    ///
+    ///     graph = retworkx.generators.directed_path_graph(3)
+    ///     print(graph.reverse_view().edge_list())
+    #[pyo3(text_signature = "(self)")]
+    pub fn reverse_view(slf: PyRef<Self>) -> DiGraphView {
+        DiGraphView {
+            graph: slf.into(),
+            reversed: true,
+            nodes: None,
+            edges: None,
+        }
+    }
+
+    /// Return an undirected view of the graph without copying it.
+    ///
+    /// Every edge of the graph is an undirected edge of the view, with the
+    /// same indices, so two edges in opposite directions between the same
+    /// nodes are parallel edges of the view. Unlike
+    /// :meth:`~PyDiGraph.to_undirected` it doesn't copy the graph, and the
+    /// functions which accept an undirected view, such as
+    /// :func:`~retworkx.bfs_search`, run on it like on an undirected graph.
+    ///
+    /// :returns: An undirected view of the graph
+    /// :rtype: GraphView
+    ///
+    /// .. jupyter-execute::
+    ///
+    ///     import retworkx
+    ///
+    ///     graph = retworkx.generators.directed_path_graph(3)
+    ///     print(graph.undirected_view().neighbors(1))
+    #[pyo3(text_signature = "(self)")]
+    pub fn undirected_view(slf: PyRef<Self>) -> GraphView {
+        GraphView {
+            base: ViewBase::DiGraph(slf.into()),
+            nodes: None,
+            edges: None,
+        }
+    }
+
     /// Check if the graph is symmetric
     ///
     /// :returns: True if the graph is symmetric
@@ -2626,6 +3509,9 @@ impl PyDiGraph {
             graph: new_graph,
             node_removed: false,
             multigraph,
+            node_keys: NodeKeys::default(),
+            attributes: AttributeColumns::default(),
+            generation: 0,
         })
     }
 
@@ -2651,6 +3537,7 @@ impl PyDiGraph {
     }
 
     fn __setitem__(&mut self, idx: usize, value: PyObject) -> PyResult<()> {
+        self.generation += 1;
         let data = match self.graph.node_weight_mut(NodeIndex::new(idx as usize)) {
             Some(node_data) => node_data,
             None => return Err(PyIndexError::new_err("No node found for index")),
@@ -2660,8 +3547,14 @@ impl PyDiGraph {
     }
 
     fn __delitem__(&mut self, idx: usize) -> PyResult<()> {
-        match self.graph.remove_node(NodeIndex::new(idx as usize)) {
-            Some(_) => Ok(()),
+        self.generation += 1;
+        let index = NodeIndex::new(idx);
+        self.attributes.remove_node(&self.graph, index);
+        match self.graph.remove_node(index) {
+            Some(_) => {
+                self.node_keys.remove(index);
+                Ok(())
+            }
             None => Err(PyIndexError::new_err("No node found for index")),
         }
     }
@@ -2688,6 +3581,7 @@ impl PyDiGraph {
         {
             visit.call(edge)?;
         }
+        self.node_keys.traverse(&visit)?;
         Ok(())
     }
 
@@ -2698,21 +3592,15 @@ impl PyDiGraph {
     // ]1] https://docs.python.org/3/c-api/typeobj.html#c.PyTypeObject.tp_clear
     // [2] https://pyo3.rs/v0.12.4/class/protocols.html#garbage-collector-integration
     fn __clear__(&mut self) {
+        self.generation += 1;
         self.graph = StablePyGraph::<Directed>::new();
+        self.cycle_state = None;
         self.node_removed = false;
+        self.node_keys = NodeKeys::default();
+        self.attributes = AttributeColumns::default();
     }
 }
 
-fn is_cycle_check_required(dag: &PyDiGraph, a: NodeIndex, b: NodeIndex) -> bool {
-    let mut parents_a = dag
-        .graph
-        .neighbors_directed(a, petgraph::Direction::Incoming);
-    let mut children_b = dag
-        .graph
-        .neighbors_directed(b, petgraph::Direction::Outgoing);
-    parents_a.next().is_some() && children_b.next().is_some() && dag.graph.find_edge(a, b).is_none()
-}
-
 fn weight_transform_callable(
     py: Python,
     map_fn: &Option<PyObject>,
@@ -2767,9 +3655,12 @@ where
 
     PyDiGraph {
         graph: out_graph,
-        cycle_state: algo::DfsSpace::default(),
+        cycle_state: None,
         check_cycle: false,
         node_removed: false,
         multigraph: true,
+        node_keys: NodeKeys::default(),
+        attributes: AttributeColumns::default(),
+        generation: 0,
     }
 }
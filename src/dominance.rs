@@ -0,0 +1,139 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use hashbrown::HashSet;
+
+use crate::iterators::NodeMap;
+use crate::{digraph, InvalidNode};
+
+use petgraph::graph::NodeIndex;
+
+use pyo3::prelude::*;
+
+use retworkx_core::dictmap::*;
+use retworkx_core::dominance;
+
+fn entry_index(graph: &digraph::PyDiGraph, entry: usize) -> PyResult<NodeIndex> {
+    let index = NodeIndex::new(entry);
+    if !graph.graph.contains_node(index) {
+        return Err(InvalidNode::new_err(format!(
+            "Node index {} is not in the graph",
+            entry
+        )));
+    }
+    Ok(index)
+}
+
+/// Find the immediate dominator of every node reachable from an entry node.
+///
+/// A node ``d`` dominates a node ``n`` if every path from ``entry`` to ``n``
+/// goes through ``d``, and strictly dominates it if ``d`` is also different
+/// from ``n``. The immediate dominator of a node is the one of its strict
+/// dominators which is dominated by all the others, so the immediate
+/// dominators are the parents of the nodes in the dominator tree rooted at
+/// ``entry``. For a control flow graph, the dominators of a basic block are
+/// the blocks which are always executed before it.
+///
+/// This uses the iterative algorithm of Cooper, Harvey and Kennedy
+/// [Cooper2001]_, which visits the nodes in reverse postorder until the
+/// dominators don't change, taking :math:`O(|V| + |E|)` time per pass.
+///
+/// .. jupyter-execute::
+///
+///     import retworkx
+///
+///     # An if/else from 0 with a loop on 3
+///     graph = retworkx.PyDiGraph()
+///     graph.add_nodes_from(range(6))
+///     graph.add_edges_from_no_data(
+///         [(0, 1), (0, 2), (1, 3), (2, 3), (3, 4), (4, 3), (4, 5)]
+///     )
+///     print(retworkx.immediate_dominators(graph, 0))
+///
+/// :param PyDiGraph graph: The graph to find the dominators in
+/// :param int entry: The index of the node every path starts from, such as
+///     the entry block of a control flow graph
+///
+/// :returns: A mapping of the index of every node reachable from ``entry`` to
+///     the index of its immediate dominator, in reverse postorder from
+///     ``entry``. ``entry`` is mapped to itself and the nodes which aren't
+///     reachable from ``entry`` aren't included.
+/// :rtype: NodeMap
+/// :raises InvalidNode: If ``entry`` is not a node of the graph
+///
+/// .. [Cooper2001] K. D. Cooper, T. J. Harvey and K. Kennedy, "A Simple, Fast
+///     Dominance Algorithm", Software Practice and Experience, 4, 1-10, 2001.
+#[pyfunction]
+#[pyo3(text_signature = "(graph, entry, /)")]
+pub fn immediate_dominators(graph: &digraph::PyDiGraph, entry: usize) -> PyResult<NodeMap> {
+    let entry = entry_index(graph, entry)?;
+    Ok(NodeMap {
+        node_map: dominance::immediate_dominators(&graph.graph, entry)
+            .into_iter()
+            .map(|(node, idom)| (node.index(), idom.index()))
+            .collect(),
+    })
+}
+
+/// Find the dominance frontier of every node reachable from an entry node.
+///
+/// The dominance frontier of a node ``d`` is the set of the nodes ``n`` such
+/// that ``d`` dominates a predecessor of ``n`` but doesn't strictly dominate
+/// ``n``, see :func:`~retworkx.immediate_dominators` for the definition of
+/// dominance. For a control flow graph, the frontier of a basic block is
+/// where the paths through it join other paths, which is where static
+/// single assignment form places the phi functions of the variables assigned
+/// in the block.
+///
+/// The frontiers are found from the immediate dominators by walking up the
+/// dominator tree from the source of every edge to a node until the
+/// immediate dominator of the node [Cooper2001]_.
+///
+/// .. jupyter-execute::
+///
+///     import retworkx
+///
+///     # An if/else from 0 with a loop on 3
+///     graph = retworkx.PyDiGraph()
+///     graph.add_nodes_from(range(6))
+///     graph.add_edges_from_no_data(
+///         [(0, 1), (0, 2), (1, 3), (2, 3), (3, 4), (4, 3), (4, 5)]
+///     )
+///     print(retworkx.dominance_frontiers(graph, 0))
+///
+/// :param PyDiGraph graph: The graph to find the dominance frontiers in
+/// :param int entry: The index of the node every path starts from, such as
+///     the entry block of a control flow graph
+///
+/// :returns: A dictionary mapping the index of every node reachable from
+///     ``entry`` to the set of the indices of the nodes in its dominance
+///     frontier, in reverse postorder from ``entry``. The nodes which aren't
+///     reachable from ``entry`` and the edges from them are ignored.
+/// :rtype: dict
+/// :raises InvalidNode: If ``entry`` is not a node of the graph
+#[pyfunction]
+#[pyo3(text_signature = "(graph, entry, /)")]
+pub fn dominance_frontiers(
+    graph: &digraph::PyDiGraph,
+    entry: usize,
+) -> PyResult<DictMap<usize, HashSet<usize>>> {
+    let entry = entry_index(graph, entry)?;
+    Ok(dominance::dominance_frontiers(&graph.graph, entry)
+        .into_iter()
+        .map(|(node, frontier)| {
+            (
+                node.index(),
+                frontier.into_iter().map(|node| node.index()).collect(),
+            )
+        })
+        .collect())
+}
@@ -11,13 +11,27 @@
 // under the License.
 
 use std::collections::BTreeMap;
+use std::fs;
 use std::io::prelude::*;
+use std::path::PathBuf;
 
+use hashbrown::HashMap;
+use indexmap::{IndexMap, IndexSet};
+
+use petgraph::prelude::*;
 use petgraph::visit::{
     Data, EdgeRef, GraphBase, GraphProp, IntoEdgeReferences, IntoNodeReferences, NodeIndexable,
     NodeRef,
 };
+use petgraph::EdgeType;
+use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyString};
+use pyo3::Python;
+
+use crate::attributes::AttributeColumns;
+use crate::node_keys::NodeKeys;
+use crate::{digraph, graph, StablePyGraph};
 
 static TYPE: [&str; 2] = ["graph", "digraph"];
 static EDGE: [&str; 2] = ["--", "->"];
@@ -96,3 +110,628 @@ fn attr_map_to_string<'a>(
         .join(", ");
     Ok(format!("[{}]", attr_string))
 }
+
+type Attrs = IndexMap<String, String>;
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    /// An identifier, numeral, quoted string or HTML string, the flag is
+    /// set if it's unquoted and so may be a keyword
+    Id(String, bool),
+    LBrace,
+    RBrace,
+    LBracket,
+    RBracket,
+    Equals,
+    Semicolon,
+    Comma,
+    Colon,
+    Plus,
+    EdgeOp(bool),
+    Eof,
+}
+
+fn dot_error(line: usize, msg: &str) -> PyErr {
+    PyValueError::new_err(format!("Invalid DOT on line {}: {}", line, msg))
+}
+
+fn is_id_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '_' || !c.is_ascii()
+}
+
+/// Split DOT source into tokens, each with the line it starts on.
+fn tokenize(source: &str) -> PyResult<Vec<(Token, usize)>> {
+    let chars: Vec<char> = source.chars().collect();
+    let mut tokens = Vec::new();
+    let mut line = 1;
+    let mut line_start = true;
+    let mut pos = 0;
+    while pos < chars.len() {
+        let c = chars[pos];
+        if c == '\n' {
+            line += 1;
+            line_start = true;
+            pos += 1;
+            continue;
+        }
+        if c.is_whitespace() {
+            pos += 1;
+            continue;
+        }
+        // Lines starting with '#' are C preprocessor output
+        if c == '#' && line_start {
+            while pos < chars.len() && chars[pos] != '\n' {
+                pos += 1;
+            }
+            continue;
+        }
+        line_start = false;
+        if c == '/' && chars.get(pos + 1) == Some(&'/') {
+            while pos < chars.len() && chars[pos] != '\n' {
+                pos += 1;
+            }
+            continue;
+        }
+        if c == '/' && chars.get(pos + 1) == Some(&'*') {
+            let start_line = line;
+            pos += 2;
+            loop {
+                match chars.get(pos) {
+                    None => return Err(dot_error(start_line, "unterminated comment")),
+                    Some('*') if chars.get(pos + 1) == Some(&'/') => {
+                        pos += 2;
+                        break;
+                    }
+                    Some(c) => {
+                        if *c == '\n' {
+                            line += 1;
+                        }
+                        pos += 1;
+                    }
+                }
+            }
+            continue;
+        }
+        let start_line = line;
+        let token = match c {
+            '{' => Token::LBrace,
+            '}' => Token::RBrace,
+            '[' => Token::LBracket,
+            ']' => Token::RBracket,
+            '=' => Token::Equals,
+            ';' => Token::Semicolon,
+            ',' => Token::Comma,
+            ':' => Token::Colon,
+            '+' => Token::Plus,
+            '-' if chars.get(pos + 1) == Some(&'-') => {
+                pos += 1;
+                Token::EdgeOp(false)
+            }
+            '-' if chars.get(pos + 1) == Some(&'>') => {
+                pos += 1;
+                Token::EdgeOp(true)
+            }
+            '"' => {
+                let mut value = String::new();
+                pos += 1;
+                loop {
+                    match chars.get(pos) {
+                        None => return Err(dot_error(start_line, "unterminated string")),
+                        Some('"') => break,
+                        Some('\\') if chars.get(pos + 1) == Some(&'"') => {
+                            value.push('"');
+                            pos += 1;
+                        }
+                        // A backslash newline continues the string
+                        Some('\\') if chars.get(pos + 1) == Some(&'\n') => {
+                            line += 1;
+                            pos += 1;
+                        }
+                        Some(c) => {
+                            if *c == '\n' {
+                                line += 1;
+                            }
+                            value.push(*c);
+                        }
+                    }
+                    pos += 1;
+                }
+                Token::Id(value, false)
+            }
+            '<' => {
+                let mut value = String::new();
+                let mut depth = 0;
+                loop {
+                    match chars.get(pos) {
+                        None => return Err(dot_error(start_line, "unterminated HTML string")),
+                        Some(c) => {
+                            match c {
+                                '<' => depth += 1,
+                                '>' => depth -= 1,
+                                '\n' => line += 1,
+                                _ => (),
+                            }
+                            value.push(*c);
+                        }
+                    }
+                    if depth == 0 {
+                        break;
+                    }
+                    pos += 1;
+                }
+                Token::Id(value, false)
+            }
+            c if c == '-' || c == '.' || c.is_ascii_digit() => {
+                let start = pos;
+                if c == '-' {
+                    pos += 1;
+                }
+                let mut digits = 0;
+                let mut seen_dot = false;
+                while let Some(c) = chars.get(pos) {
+                    if c.is_ascii_digit() {
+                        digits += 1;
+                    } else if *c == '.' && !seen_dot {
+                        seen_dot = true;
+                    } else {
+                        break;
+                    }
+                    pos += 1;
+                }
+                let numeral: String = chars[start..pos].iter().collect();
+                if digits == 0 {
+                    return Err(dot_error(line, &format!("invalid numeral '{}'", numeral)));
+                }
+                pos -= 1;
+                Token::Id(numeral, false)
+            }
+            c if is_id_char(c) => {
+                let start = pos;
+                while matches!(chars.get(pos + 1), Some(c) if is_id_char(*c)) {
+                    pos += 1;
+                }
+                Token::Id(chars[start..=pos].iter().collect(), true)
+            }
+            c => return Err(dot_error(line, &format!("unexpected character '{}'", c))),
+        };
+        tokens.push((token, start_line));
+        pos += 1;
+    }
+    tokens.push((Token::Eof, line));
+    Ok(tokens)
+}
+
+/// The default attributes and the nodes of the graph or a subgraph body.
+#[derive(Default)]
+struct Scope {
+    node_defaults: Attrs,
+    edge_defaults: Attrs,
+    nodes: IndexSet<usize>,
+}
+
+/// A node, with an optional port, or the nodes of a subgraph at an end of
+/// an edge statement.
+enum Endpoint {
+    Node(usize, Option<String>),
+    Subgraph(IndexSet<usize>),
+}
+
+impl Endpoint {
+    fn nodes(&self) -> (Vec<usize>, Option<&String>) {
+        match self {
+            Endpoint::Node(node, port) => (vec![*node], port.as_ref()),
+            Endpoint::Subgraph(nodes) => (nodes.iter().copied().collect(), None),
+        }
+    }
+}
+
+/// The graph described by DOT source, with subgraphs flattened.
+#[derive(Default)]
+struct DotGraph {
+    directed: bool,
+    strict: bool,
+    names: Vec<String>,
+    nodes: Vec<Attrs>,
+    edges: Vec<(usize, usize, Attrs)>,
+}
+
+/// A recursive descent parser for the DOT grammar described at
+/// https://graphviz.org/doc/info/lang.html
+struct DotParser {
+    tokens: Vec<(Token, usize)>,
+    pos: usize,
+    graph: DotGraph,
+    node_map: HashMap<String, usize>,
+    edge_map: HashMap<(usize, usize), usize>,
+    scopes: Vec<Scope>,
+}
+
+impl DotParser {
+    fn new(source: &str) -> PyResult<Self> {
+        Ok(DotParser {
+            tokens: tokenize(source)?,
+            pos: 0,
+            graph: DotGraph::default(),
+            node_map: HashMap::new(),
+            edge_map: HashMap::new(),
+            scopes: Vec::new(),
+        })
+    }
+
+    fn peek(&self) -> &Token {
+        &self.tokens[self.pos].0
+    }
+
+    fn accept(&mut self, token: &Token) -> bool {
+        if self.peek() == token {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, token: Token, what: &str) -> PyResult<()> {
+        if self.accept(&token) {
+            Ok(())
+        } else {
+            Err(self.unexpected(what))
+        }
+    }
+
+    fn unexpected(&self, what: &str) -> PyErr {
+        let found = match self.peek() {
+            Token::Id(id, _) => format!("'{}'", id),
+            Token::LBrace => "'{'".to_string(),
+            Token::RBrace => "'}'".to_string(),
+            Token::LBracket => "'['".to_string(),
+            Token::RBracket => "']'".to_string(),
+            Token::Equals => "'='".to_string(),
+            Token::Semicolon => "';'".to_string(),
+            Token::Comma => "','".to_string(),
+            Token::Colon => "':'".to_string(),
+            Token::Plus => "'+'".to_string(),
+            Token::EdgeOp(true) => "'->'".to_string(),
+            Token::EdgeOp(false) => "'--'".to_string(),
+            Token::Eof => "end of input".to_string(),
+        };
+        dot_error(
+            self.tokens[self.pos].1,
+            &format!("expected {}, found {}", what, found),
+        )
+    }
+
+    fn is_keyword(&self, keyword: &str) -> bool {
+        match self.peek() {
+            Token::Id(id, true) => id.eq_ignore_ascii_case(keyword),
+            _ => false,
+        }
+    }
+
+    fn is_subgraph(&self) -> bool {
+        self.is_keyword("subgraph") || self.peek() == &Token::LBrace
+    }
+
+    /// Parse an ID, joining quoted strings concatenated with '+'.
+    fn id(&mut self, what: &str) -> PyResult<String> {
+        let (mut id, plain) = match self.peek() {
+            Token::Id(id, plain) => (id.clone(), *plain),
+            _ => return Err(self.unexpected(what)),
+        };
+        self.pos += 1;
+        if !plain {
+            while self.accept(&Token::Plus) {
+                match self.peek() {
+                    Token::Id(more, false) => id.push_str(more),
+                    _ => return Err(self.unexpected("a quoted string")),
+                }
+                self.pos += 1;
+            }
+        }
+        Ok(id)
+    }
+
+    fn parse(mut self) -> PyResult<DotGraph> {
+        if self.is_keyword("strict") {
+            self.graph.strict = true;
+            self.pos += 1;
+        }
+        if self.is_keyword("graph") {
+            self.graph.directed = false;
+        } else if self.is_keyword("digraph") {
+            self.graph.directed = true;
+        } else {
+            return Err(self.unexpected("'graph' or 'digraph'"));
+        }
+        self.pos += 1;
+        if let Token::Id(..) = self.peek() {
+            self.id("a graph name")?;
+        }
+        self.expect(Token::LBrace, "'{'")?;
+        self.scopes.push(Scope::default());
+        self.stmt_list()?;
+        self.expect(Token::RBrace, "'}'")?;
+        if self.peek() != &Token::Eof {
+            return Err(self.unexpected("end of input"));
+        }
+        Ok(self.graph)
+    }
+
+    fn stmt_list(&mut self) -> PyResult<()> {
+        while !matches!(self.peek(), Token::RBrace | Token::Eof) {
+            self.stmt()?;
+            self.accept(&Token::Semicolon);
+        }
+        Ok(())
+    }
+
+    fn stmt(&mut self) -> PyResult<()> {
+        if self.is_keyword("graph") || self.is_keyword("node") || self.is_keyword("edge") {
+            let kind = self.id("a statement")?.to_ascii_lowercase();
+            if self.peek() != &Token::LBracket {
+                return Err(self.unexpected("'['"));
+            }
+            let attrs = self.attr_list()?;
+            let scope = self.scopes.last_mut().unwrap();
+            // Graph attributes are dropped, there's nowhere to keep them
+            match kind.as_str() {
+                "node" => scope.node_defaults.extend(attrs),
+                "edge" => scope.edge_defaults.extend(attrs),
+                _ => (),
+            }
+            return Ok(());
+        }
+        let first = if self.is_subgraph() {
+            Endpoint::Subgraph(self.subgraph()?)
+        } else {
+            let name = self.id("a statement")?;
+            if self.accept(&Token::Equals) {
+                // A graph attribute
+                self.id("an attribute value")?;
+                return Ok(());
+            }
+            let port = self.port()?;
+            Endpoint::Node(self.node(&name), port)
+        };
+        if let Token::EdgeOp(_) = self.peek() {
+            return self.edge_stmt(first);
+        }
+        if let Endpoint::Node(node, _) = first {
+            let attrs = self.attr_list()?;
+            self.graph.nodes[node].extend(attrs);
+        }
+        Ok(())
+    }
+
+    fn edge_stmt(&mut self, first: Endpoint) -> PyResult<()> {
+        let mut endpoints = vec![first];
+        while let Token::EdgeOp(directed) = *self.peek() {
+            if directed != self.graph.directed {
+                let op = if self.graph.directed { "'->'" } else { "'--'" };
+                return Err(self.unexpected(op));
+            }
+            self.pos += 1;
+            let endpoint = if self.is_subgraph() {
+                Endpoint::Subgraph(self.subgraph()?)
+            } else {
+                let name = self.id("a node or subgraph")?;
+                let port = self.port()?;
+                Endpoint::Node(self.node(&name), port)
+            };
+            endpoints.push(endpoint);
+        }
+        let attrs = self.attr_list()?;
+        let edge_defaults = self.scopes.last().unwrap().edge_defaults.clone();
+        for pair in endpoints.windows(2) {
+            let (sources, tail_port) = pair[0].nodes();
+            let (targets, head_port) = pair[1].nodes();
+            for source in &sources {
+                for target in &targets {
+                    let mut edge_attrs = edge_defaults.clone();
+                    if let Some(port) = tail_port {
+                        edge_attrs.insert("tailport".to_string(), port.clone());
+                    }
+                    if let Some(port) = head_port {
+                        edge_attrs.insert("headport".to_string(), port.clone());
+                    }
+                    edge_attrs.extend(attrs.iter().map(|(k, v)| (k.clone(), v.clone())));
+                    self.edge(*source, *target, edge_attrs);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Parse a subgraph, returning the nodes in it.
+    fn subgraph(&mut self) -> PyResult<IndexSet<usize>> {
+        if self.is_keyword("subgraph") {
+            self.pos += 1;
+            if let Token::Id(..) = self.peek() {
+                self.id("a subgraph name")?;
+            }
+        }
+        self.expect(Token::LBrace, "'{'")?;
+        let parent = self.scopes.last().unwrap();
+        let scope = Scope {
+            node_defaults: parent.node_defaults.clone(),
+            edge_defaults: parent.edge_defaults.clone(),
+            nodes: IndexSet::new(),
+        };
+        self.scopes.push(scope);
+        self.stmt_list()?;
+        self.expect(Token::RBrace, "'}'")?;
+        let nodes = self.scopes.pop().unwrap().nodes;
+        let parent = self.scopes.last_mut().unwrap();
+        parent.nodes.extend(nodes.iter().copied());
+        Ok(nodes)
+    }
+
+    fn port(&mut self) -> PyResult<Option<String>> {
+        if !self.accept(&Token::Colon) {
+            return Ok(None);
+        }
+        let mut port = self.id("a port")?;
+        if self.accept(&Token::Colon) {
+            port.push(':');
+            port.push_str(&self.id("a compass point")?);
+        }
+        Ok(Some(port))
+    }
+
+    fn attr_list(&mut self) -> PyResult<Attrs> {
+        let mut attrs = Attrs::new();
+        while self.accept(&Token::LBracket) {
+            while !self.accept(&Token::RBracket) {
+                let key = self.id("an attribute name or ']'")?;
+                self.expect(Token::Equals, "'='")?;
+                let value = self.id("an attribute value")?;
+                attrs.insert(key, value);
+                if !self.accept(&Token::Comma) {
+                    self.accept(&Token::Semicolon);
+                }
+            }
+        }
+        Ok(attrs)
+    }
+
+    /// Return the node with a name, adding it with the node defaults of the
+    /// current scope if it's new.
+    fn node(&mut self, name: &str) -> usize {
+        let node = match self.node_map.get(name) {
+            Some(node) => *node,
+            None => {
+                let node = self.graph.nodes.len();
+                let defaults = self.scopes.last().unwrap().node_defaults.clone();
+                self.graph.nodes.push(defaults);
+                self.graph.names.push(name.to_string());
+                self.node_map.insert(name.to_string(), node);
+                node
+            }
+        };
+        self.scopes.last_mut().unwrap().nodes.insert(node);
+        node
+    }
+
+    fn edge(&mut self, source: usize, target: usize, attrs: Attrs) {
+        if !self.graph.strict {
+            self.graph.edges.push((source, target, attrs));
+            return;
+        }
+        let key = if self.graph.directed || source <= target {
+            (source, target)
+        } else {
+            (target, source)
+        };
+        match self.edge_map.get(&key) {
+            Some(edge) => self.graph.edges[*edge].2.extend(attrs),
+            None => {
+                self.edge_map.insert(key, self.graph.edges.len());
+                self.graph.edges.push((source, target, attrs));
+            }
+        }
+    }
+}
+
+fn attrs_to_dict(py: Python, attrs: Attrs) -> PyResult<PyObject> {
+    let dict = PyDict::new(py);
+    for (key, value) in attrs {
+        dict.set_item(key, value)?;
+    }
+    Ok(dict.into())
+}
+
+fn dot_to_graph<Ty: EdgeType>(
+    py: Python,
+    dot: DotGraph,
+) -> PyResult<(StablePyGraph<Ty>, NodeKeys)> {
+    let mut out_graph = StablePyGraph::<Ty>::with_capacity(dot.nodes.len(), dot.edges.len());
+    let mut node_keys = NodeKeys::default();
+    for (name, attrs) in dot.names.into_iter().zip(dot.nodes) {
+        let node = out_graph.add_node(attrs_to_dict(py, attrs)?);
+        node_keys.insert(py, node, name.into_py(py))?;
+    }
+    for (source, target, attrs) in dot.edges {
+        out_graph.add_edge(
+            NodeIndex::new(source),
+            NodeIndex::new(target),
+            attrs_to_dict(py, attrs)?,
+        );
+    }
+    Ok((out_graph, node_keys))
+}
+
+/// Read a graph from the DOT language used by Graphviz
+///
+/// This reads the output of :meth:`.PyGraph.to_dot` and
+/// :meth:`.PyDiGraph.to_dot` as well as hand written DOT. A ``graph``
+/// returns a :class:`~retworkx.PyGraph` and a ``digraph`` returns a
+/// :class:`~retworkx.PyDiGraph`. The payload of each node and edge is a
+/// dictionary of its attributes, with string keys and values, starting from
+/// the ``node [...]`` or ``edge [...]`` defaults in effect where it's
+/// created. The DOT id of each node is set as its key (see
+/// :meth:`.PyGraph.set_node_key`) so it can be looked up with
+/// :meth:`~.PyGraph.find_node_by_key`.
+///
+/// Subgraphs and clusters are flattened, an edge to or from a subgraph
+/// connects to every node in it. Parallel edges are kept, except in a
+/// ``strict`` graph where they're merged and the returned graph has
+/// ``multigraph=False``. Ports are stored in the ``tailport`` and
+/// ``headport`` edge attributes. Graph attributes are ignored.
+///
+/// :param path_or_str: Either a string of DOT source or the path of a DOT
+///     file. A string containing ``{`` is treated as DOT source.
+///
+/// :returns: The graph described by the DOT source
+/// :rtype: PyGraph or PyDiGraph
+///
+/// :raises ValueError: If the DOT source is not valid
+/// :raises OSError: If the file can't be read
+///
+/// .. jupyter-execute::
+///
+///   import retworkx
+///
+///   graph = retworkx.read_dot("""
+///   digraph {
+///       node [shape=box];
+///       a -> b [label="x"];
+///       a -> {b c};
+///   }
+///   """)
+///   print(graph.nodes())
+///   print(graph.weighted_edge_list())
+#[pyfunction]
+#[pyo3(text_signature = "(path_or_str, /)")]
+pub fn read_dot(py: Python, path_or_str: &PyAny) -> PyResult<PyObject> {
+    let source = match path_or_str.downcast::<PyString>() {
+        Ok(string) if string.to_str()?.contains('{') => string.to_str()?.to_string(),
+        _ => fs::read_to_string(path_or_str.extract::<PathBuf>()?)?,
+    };
+    let dot = DotParser::new(&source)?.parse()?;
+    let multigraph = !dot.strict;
+    if dot.directed {
+        let (out_graph, node_keys) = dot_to_graph::<Directed>(py, dot)?;
+        Ok(digraph::PyDiGraph {
+            graph: out_graph,
+            cycle_state: None,
+            check_cycle: false,
+            node_removed: false,
+            multigraph,
+            node_keys,
+            attributes: AttributeColumns::default(),
+            generation: 0,
+        }
+        .into_py(py))
+    } else {
+        let (out_graph, node_keys) = dot_to_graph::<Undirected>(py, dot)?;
+        Ok(graph::PyGraph {
+            graph: out_graph,
+            node_removed: false,
+            multigraph,
+            node_keys,
+            attributes: AttributeColumns::default(),
+            generation: 0,
+        }
+        .into_py(py))
+    }
+}
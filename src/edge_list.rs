@@ -0,0 +1,390 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use std::fs;
+use std::path::PathBuf;
+
+use hashbrown::HashMap;
+
+use petgraph::prelude::*;
+use petgraph::EdgeType;
+use rayon::prelude::*;
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::PyString;
+use pyo3::Python;
+
+use crate::attributes::AttributeColumns;
+use crate::node_keys::NodeKeys;
+use crate::{digraph, graph, StablePyGraph};
+
+/// The minimum number of bytes parsed by a thread, smaller inputs are
+/// parsed on a single thread
+const MIN_CHUNK_SIZE: usize = 1 << 20;
+
+/// The options of an edge list, shared by ``read_edge_list`` and
+/// ``read_matrix_market``
+pub struct EdgeListFormat<'a> {
+    pub comment: Option<&'a str>,
+    pub deliminator: Option<&'a str>,
+    pub labels: bool,
+    pub weight_column: Option<usize>,
+    /// The index of the first node in the file, 1 for Matrix Market
+    pub first_index: usize,
+}
+
+enum Endpoint<'a> {
+    Index(usize),
+    Label(&'a str),
+}
+
+enum Weight {
+    None,
+    Text(String),
+    Float(f64),
+}
+
+struct Entry<'a> {
+    source: Endpoint<'a>,
+    target: Endpoint<'a>,
+    weight: Weight,
+}
+
+fn parse_endpoint<'a>(
+    piece: &'a str,
+    format: &EdgeListFormat,
+    line: &str,
+) -> Result<Endpoint<'a>, String> {
+    if format.labels {
+        return Ok(Endpoint::Label(piece));
+    }
+    piece
+        .trim()
+        .parse::<usize>()
+        .ok()
+        .and_then(|index| index.checked_sub(format.first_index))
+        .map(Endpoint::Index)
+        .ok_or_else(|| format!("Invalid node index '{}' in line '{}'", piece, line))
+}
+
+fn parse_line<'a>(line: &'a str, format: &EdgeListFormat) -> Result<Option<Entry<'a>>, String> {
+    let trimmed = line.trim();
+    let content = match format.comment {
+        Some(comment) => {
+            if trimmed.starts_with(comment) {
+                return Ok(None);
+            }
+            trimmed
+                .find(comment)
+                .map(|idx| &trimmed[..idx])
+                .unwrap_or(trimmed)
+                .trim()
+        }
+        None => trimmed,
+    };
+    if content.is_empty() {
+        return Ok(None);
+    }
+    let pieces: Vec<&str> = match format.deliminator {
+        Some(del) => content.split(del).collect(),
+        None => content.split_whitespace().collect(),
+    };
+    if pieces.len() < 2 {
+        return Err(format!("Expected 2 node ids in line '{}'", line));
+    }
+    let weight = match format.weight_column {
+        Some(column) => {
+            let piece = pieces
+                .get(column)
+                .ok_or_else(|| format!("Missing weight column {} in line '{}'", column, line))?;
+            let value = piece
+                .trim()
+                .parse::<f64>()
+                .map_err(|_| format!("Invalid weight '{}' in line '{}'", piece, line))?;
+            Weight::Float(value)
+        }
+        None if pieces.len() > 2 => {
+            Weight::Text(pieces[2..].join(format.deliminator.unwrap_or(" ")))
+        }
+        None => Weight::None,
+    };
+    Ok(Some(Entry {
+        source: parse_endpoint(pieces[0], format, line)?,
+        target: parse_endpoint(pieces[1], format, line)?,
+        weight,
+    }))
+}
+
+/// Split the source in chunks of whole lines, one or more for each thread
+fn split_chunks(source: &str) -> Vec<&str> {
+    let chunk_size = MIN_CHUNK_SIZE.max(source.len() / (rayon::current_num_threads() * 4));
+    let bytes = source.as_bytes();
+    let mut chunks = Vec::new();
+    let mut start = 0;
+    while start < bytes.len() {
+        let mut end = (start + chunk_size).min(bytes.len());
+        end = match bytes[end..].iter().position(|byte| *byte == b'\n') {
+            Some(offset) => end + offset + 1,
+            None => bytes.len(),
+        };
+        chunks.push(&source[start..end]);
+        start = end;
+    }
+    chunks
+}
+
+/// Parse the lines of an edge list, the chunks of a large source are parsed
+/// in parallel without holding the GIL
+fn parse_edge_list<'a>(
+    py: Python,
+    source: &'a str,
+    format: &EdgeListFormat,
+) -> PyResult<Vec<Vec<Entry<'a>>>> {
+    let chunks = split_chunks(source);
+    py.allow_threads(|| {
+        chunks
+            .par_iter()
+            .map(|chunk| {
+                chunk
+                    .lines()
+                    .filter_map(|line| parse_line(line, format).transpose())
+                    .collect::<Result<Vec<Entry>, String>>()
+            })
+            .collect::<Result<Vec<Vec<Entry>>, String>>()
+    })
+    .map_err(PyValueError::new_err)
+}
+
+/// Return the node of an endpoint, adding it to the graph if it's missing
+fn add_endpoint<'a, Ty: EdgeType>(
+    py: Python,
+    out_graph: &mut StablePyGraph<Ty>,
+    label_map: &mut HashMap<&'a str, NodeIndex>,
+    endpoint: Endpoint<'a>,
+) -> NodeIndex {
+    match endpoint {
+        Endpoint::Index(index) => {
+            while index >= out_graph.node_count() {
+                out_graph.add_node(py.None());
+            }
+            NodeIndex::new(index)
+        }
+        Endpoint::Label(label) => *label_map
+            .entry(label)
+            .or_insert_with(|| out_graph.add_node(label.to_object(py))),
+    }
+}
+
+fn build_graph<Ty: EdgeType>(
+    py: Python,
+    entries: Vec<Vec<Entry>>,
+    node_count: usize,
+) -> StablePyGraph<Ty> {
+    let edge_count = entries.iter().map(|chunk| chunk.len()).sum();
+    let mut out_graph = StablePyGraph::<Ty>::with_capacity(node_count, edge_count);
+    for _ in 0..node_count {
+        out_graph.add_node(py.None());
+    }
+    let mut label_map: HashMap<&str, NodeIndex> = HashMap::new();
+    for entry in entries.into_iter().flatten() {
+        let source = add_endpoint(py, &mut out_graph, &mut label_map, entry.source);
+        let target = add_endpoint(py, &mut out_graph, &mut label_map, entry.target);
+        let weight = match entry.weight {
+            Weight::None => py.None(),
+            Weight::Text(text) => PyString::new(py, &text).into(),
+            Weight::Float(value) => value.into_py(py),
+        };
+        out_graph.add_edge(source, target, weight);
+    }
+    out_graph
+}
+
+/// Read an edge list file into a graph for ``PyGraph.read_edge_list`` and
+/// ``PyDiGraph.read_edge_list``
+pub fn read_edge_list<Ty: EdgeType>(
+    py: Python,
+    path: &str,
+    format: &EdgeListFormat,
+) -> PyResult<StablePyGraph<Ty>> {
+    let source = fs::read_to_string(path)?;
+    let entries = parse_edge_list(py, &source, format)?;
+    Ok(build_graph(py, entries, 0))
+}
+
+/// Return the next line of ``rest`` and advance it past the line
+fn next_line<'a>(rest: &mut &'a str) -> Option<&'a str> {
+    if rest.is_empty() {
+        return None;
+    }
+    let end = rest.find('\n').map_or(rest.len(), |idx| idx + 1);
+    let line = &rest[..end];
+    *rest = &rest[end..];
+    Some(line)
+}
+
+/// Read a graph from a Matrix Market file
+///
+/// Only the ``coordinate`` format of square matrices is supported, where
+/// every entry ``i j`` of the matrix is an edge from node ``i - 1`` to node
+/// ``j - 1``. A ``symmetric`` matrix returns a :class:`~retworkx.PyGraph`
+/// and a ``general`` matrix returns a :class:`~retworkx.PyDiGraph`, with a
+/// node for every row of the matrix. The payload of every node is ``None``
+/// and the payload of every edge is its value as a ``float``, or ``None``
+/// for a ``pattern`` matrix. Large files are parsed in parallel, the
+/// environment variable ``RAYON_NUM_THREADS`` can be used to adjust how
+/// many threads will be used.
+///
+/// :param path_or_str: Either a string of Matrix Market data or the path
+///     of a ``.mtx`` file. A string with a newline is treated as Matrix
+///     Market data.
+///
+/// :returns: The graph of the matrix
+/// :rtype: PyGraph or PyDiGraph
+///
+/// :raises ValueError: If the input is not a valid Matrix Market file or
+///     its matrix isn't supported
+/// :raises OSError: If the file can't be read
+///
+/// .. jupyter-execute::
+///
+///   import retworkx
+///
+///   graph = retworkx.read_matrix_market("""%%MatrixMarket matrix coordinate real general
+///   % A comment
+///   3 3 3
+///   1 2 0.5
+///   2 3 1.5
+///   3 1 2.0
+///   """)
+///   print(graph.weighted_edge_list())
+#[pyfunction]
+#[pyo3(text_signature = "(path_or_str, /)")]
+pub fn read_matrix_market(py: Python, path_or_str: &PyAny) -> PyResult<PyObject> {
+    let source = match path_or_str.downcast::<PyString>() {
+        Ok(string) if string.to_str()?.contains('\n') => string.to_str()?.to_string(),
+        _ => fs::read_to_string(path_or_str.extract::<PathBuf>()?)?,
+    };
+    let mut rest = source.as_str();
+    let header: Vec<String> = next_line(&mut rest)
+        .unwrap_or("")
+        .split_whitespace()
+        .map(|token| token.to_ascii_lowercase())
+        .collect();
+    if header.len() != 5 || header[0] != "%%matrixmarket" || header[1] != "matrix" {
+        return Err(PyValueError::new_err(
+            "Expected a '%%MatrixMarket matrix' header",
+        ));
+    }
+    if header[2] != "coordinate" {
+        return Err(PyValueError::new_err(format!(
+            "Unsupported Matrix Market format '{}', only 'coordinate' is supported",
+            header[2]
+        )));
+    }
+    let weight_column = match header[3].as_str() {
+        "real" | "integer" | "double" => Some(2),
+        "pattern" => None,
+        field => {
+            return Err(PyValueError::new_err(format!(
+                "Unsupported Matrix Market field '{}'",
+                field
+            )))
+        }
+    };
+    let directed = match header[4].as_str() {
+        "general" => true,
+        "symmetric" => false,
+        symmetry => {
+            return Err(PyValueError::new_err(format!(
+                "Unsupported Matrix Market symmetry '{}'",
+                symmetry
+            )))
+        }
+    };
+    // The size line is the first line after the header which isn't a
+    // comment, the entries start after it
+    let mut size = None;
+    while let Some(line) = next_line(&mut rest) {
+        let line = line.trim();
+        if !line.is_empty() && !line.starts_with('%') {
+            size = Some(line);
+            break;
+        }
+    }
+    let size: Vec<usize> = size
+        .unwrap_or("")
+        .split_whitespace()
+        .map(|token| token.parse::<usize>())
+        .collect::<Result<_, _>>()
+        .map_err(|_| PyValueError::new_err("Invalid Matrix Market size line"))?;
+    if size.len() != 3 {
+        return Err(PyValueError::new_err(
+            "Expected a Matrix Market size line with rows, columns and entries",
+        ));
+    }
+    if size[0] != size[1] {
+        return Err(PyValueError::new_err(format!(
+            "Expected a square matrix, found {} rows and {} columns",
+            size[0], size[1]
+        )));
+    }
+    let format = EdgeListFormat {
+        comment: Some("%"),
+        deliminator: None,
+        labels: false,
+        weight_column,
+        first_index: 1,
+    };
+    let entries = parse_edge_list(py, rest, &format)?;
+    let entry_count: usize = entries.iter().map(|chunk| chunk.len()).sum();
+    if entry_count != size[2] {
+        return Err(PyValueError::new_err(format!(
+            "Expected {} entries, found {}",
+            size[2], entry_count
+        )));
+    }
+    let out_of_range = entries.iter().flatten().any(|entry| {
+        [&entry.source, &entry.target]
+            .iter()
+            .any(|endpoint| matches!(endpoint, Endpoint::Index(index) if *index >= size[0]))
+    });
+    if out_of_range {
+        return Err(PyValueError::new_err(format!(
+            "Entry out of range of a {0}x{0} matrix",
+            size[0]
+        )));
+    }
+    if directed {
+        Ok(digraph::PyDiGraph {
+            graph: build_graph(py, entries, size[0]),
+            cycle_state: None,
+            check_cycle: false,
+            node_removed: false,
+            multigraph: true,
+            node_keys: NodeKeys::default(),
+            attributes: AttributeColumns::default(),
+            generation: 0,
+        }
+        .into_py(py))
+    } else {
+        Ok(graph::PyGraph {
+            graph: build_graph(py, entries, size[0]),
+            node_removed: false,
+            multigraph: true,
+            node_keys: NodeKeys::default(),
+            attributes: AttributeColumns::default(),
+            generation: 0,
+        }
+        .into_py(py))
+    }
+}
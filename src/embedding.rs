@@ -0,0 +1,446 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use crate::{digraph, graph, InvalidNode, StablePyGraph};
+
+use numpy::PyReadonlyArray2;
+
+use petgraph::graph::NodeIndex;
+use petgraph::visit::NodeIndexable;
+use petgraph::EdgeType;
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+use retworkx_core::embedding;
+
+/// The similarity of the embeddings of the nodes, with one row of
+/// `embeddings` per node index.
+struct EmbeddingSimilarity {
+    values: Vec<f64>,
+    dimension: usize,
+    /// The norms of the rows, only set for the cosine similarity
+    norms: Option<Vec<f64>>,
+}
+
+impl EmbeddingSimilarity {
+    fn new<Ty: EdgeType>(
+        graph: &StablePyGraph<Ty>,
+        embeddings: PyReadonlyArray2<f64>,
+        similarity: &str,
+    ) -> PyResult<Self> {
+        let array = embeddings.as_array();
+        let shape = array.shape();
+        if shape[0] < graph.node_bound() {
+            return Err(PyValueError::new_err(format!(
+                "embeddings has {} rows, expected at least {}",
+                shape[0],
+                graph.node_bound()
+            )));
+        }
+        let mut out = EmbeddingSimilarity {
+            values: array.iter().copied().collect(),
+            dimension: shape[1],
+            norms: None,
+        };
+        match similarity {
+            "dot" => (),
+            "cosine" => {
+                out.norms = Some((0..shape[0]).map(|row| out.dot(row, row).sqrt()).collect())
+            }
+            _ => {
+                return Err(PyValueError::new_err(
+                    "similarity must be one of 'dot' or 'cosine'",
+                ))
+            }
+        }
+        Ok(out)
+    }
+
+    fn dot(&self, u: usize, v: usize) -> f64 {
+        let row_u = &self.values[u * self.dimension..(u + 1) * self.dimension];
+        let row_v = &self.values[v * self.dimension..(v + 1) * self.dimension];
+        row_u.iter().zip(row_v).map(|(a, b)| a * b).sum()
+    }
+
+    fn score(&self, u: NodeIndex, v: NodeIndex) -> f64 {
+        let dot = self.dot(u.index(), v.index());
+        match &self.norms {
+            Some(norms) => {
+                let norm = norms[u.index()] * norms[v.index()];
+                if norm > 0.0 {
+                    dot / norm
+                } else {
+                    0.0
+                }
+            }
+            None => dot,
+        }
+    }
+}
+
+fn precision_at_k<Ty: EdgeType + Sync>(
+    graph: &StablePyGraph<Ty>,
+    embeddings: PyReadonlyArray2<f64>,
+    k: usize,
+    similarity: &str,
+    parallel_threshold: usize,
+) -> PyResult<f64> {
+    let similarity = EmbeddingSimilarity::new(graph, embeddings, similarity)?;
+    Ok(embedding::reconstruction_precision_at_k(
+        graph,
+        |u, v| similarity.score(u, v),
+        k,
+        parallel_threshold,
+    ))
+}
+
+fn mean_average_precision<Ty: EdgeType + Sync>(
+    graph: &StablePyGraph<Ty>,
+    embeddings: PyReadonlyArray2<f64>,
+    similarity: &str,
+    parallel_threshold: usize,
+) -> PyResult<f64> {
+    let similarity = EmbeddingSimilarity::new(graph, embeddings, similarity)?;
+    Ok(embedding::reconstruction_mean_average_precision(
+        graph,
+        |u, v| similarity.score(u, v),
+        parallel_threshold,
+    ))
+}
+
+#[allow(clippy::too_many_arguments)]
+fn roc_auc<Ty: EdgeType + Sync>(
+    graph: &StablePyGraph<Ty>,
+    embeddings: PyReadonlyArray2<f64>,
+    test_edges: Vec<(usize, usize)>,
+    num_negative: Option<usize>,
+    similarity: &str,
+    seed: Option<u64>,
+    parallel_threshold: usize,
+) -> PyResult<f64> {
+    let mut positive_edges: Vec<(NodeIndex, NodeIndex)> = Vec::with_capacity(test_edges.len());
+    for (u, v) in test_edges {
+        for node in &[u, v] {
+            if !graph.contains_node(NodeIndex::new(*node)) {
+                return Err(InvalidNode::new_err(format!(
+                    "Node index {} is not in the graph",
+                    node
+                )));
+            }
+        }
+        positive_edges.push((NodeIndex::new(u), NodeIndex::new(v)));
+    }
+    let similarity = EmbeddingSimilarity::new(graph, embeddings, similarity)?;
+    embedding::link_prediction_roc_auc(
+        graph,
+        |u, v| similarity.score(u, v),
+        &positive_edges,
+        num_negative.unwrap_or(positive_edges.len()),
+        seed,
+        parallel_threshold,
+    )
+    .ok_or_else(|| {
+        PyValueError::new_err(
+            "No test edge, or no pair of nodes which is neither an edge nor a test edge",
+        )
+    })
+}
+
+/// Compute the precision at ``k`` of the reconstruction of a PyDiGraph from
+/// node embeddings
+///
+/// All the ordered pairs of different nodes :math:`(u, v)` are ranked by
+/// decreasing similarity of the embeddings of :math:`u` and :math:`v`, and
+/// the precision at ``k`` is the fraction of the ``k`` best ranked pairs
+/// which are edges from :math:`u` to :math:`v`. The pairs with equal
+/// similarities are ranked by their node indices. If there are less than
+/// ``k`` pairs the precision is over all of them.
+///
+/// The best pairs of each node are found on multiple threads if the graph
+/// has at least ``parallel_threshold`` nodes, the env var
+/// ``RAYON_NUM_THREADS`` can be used to adjust how many threads are used.
+///
+/// :param PyDiGraph graph: The graph to reconstruct
+/// :param numpy.ndarray embeddings: A 2D array of ``float`` with the
+///     embedding of every node, the row of a node is its node index
+/// :param int k: The number of best ranked pairs to check
+/// :param str similarity: The similarity of two embeddings, either ``"dot"``
+///     for their dot product or ``"cosine"`` for their cosine similarity.
+///     Defaults to ``"dot"``.
+/// :param int parallel_threshold: The number of nodes to run in parallel
+///     from. Defaults to 300.
+///
+/// :returns: The precision at ``k``, 0 if ``k`` is 0 or there's no pair
+/// :rtype: float
+/// :raises ValueError: If ``embeddings`` has fewer rows than the node
+///     indices of the graph, or if ``similarity`` is invalid
+#[pyfunction(similarity = "\"dot\"", parallel_threshold = "300")]
+#[pyo3(text_signature = "(graph, embeddings, k, /, similarity=\"dot\", parallel_threshold=300)")]
+pub fn digraph_reconstruction_precision_at_k(
+    graph: &digraph::PyDiGraph,
+    embeddings: PyReadonlyArray2<f64>,
+    k: usize,
+    similarity: &str,
+    parallel_threshold: usize,
+) -> PyResult<f64> {
+    precision_at_k(&graph.graph, embeddings, k, similarity, parallel_threshold)
+}
+
+/// Compute the precision at ``k`` of the reconstruction of a PyGraph from
+/// node embeddings
+///
+/// All the pairs of different nodes are ranked by decreasing similarity of
+/// their embeddings, and the precision at ``k`` is the fraction of the ``k``
+/// best ranked pairs which are edges of the graph. The pairs with equal
+/// similarities are ranked by their node indices. If there are less than
+/// ``k`` pairs the precision is over all of them.
+///
+/// The best pairs of each node are found on multiple threads if the graph
+/// has at least ``parallel_threshold`` nodes, the env var
+/// ``RAYON_NUM_THREADS`` can be used to adjust how many threads are used.
+///
+/// :param PyGraph graph: The graph to reconstruct
+/// :param numpy.ndarray embeddings: A 2D array of ``float`` with the
+///     embedding of every node, the row of a node is its node index
+/// :param int k: The number of best ranked pairs to check
+/// :param str similarity: The similarity of two embeddings, either ``"dot"``
+///     for their dot product or ``"cosine"`` for their cosine similarity.
+///     Defaults to ``"dot"``.
+/// :param int parallel_threshold: The number of nodes to run in parallel
+///     from. Defaults to 300.
+///
+/// :returns: The precision at ``k``, 0 if ``k`` is 0 or there's no pair
+/// :rtype: float
+/// :raises ValueError: If ``embeddings`` has fewer rows than the node
+///     indices of the graph, or if ``similarity`` is invalid
+#[pyfunction(similarity = "\"dot\"", parallel_threshold = "300")]
+#[pyo3(text_signature = "(graph, embeddings, k, /, similarity=\"dot\", parallel_threshold=300)")]
+pub fn graph_reconstruction_precision_at_k(
+    graph: &graph::PyGraph,
+    embeddings: PyReadonlyArray2<f64>,
+    k: usize,
+    similarity: &str,
+    parallel_threshold: usize,
+) -> PyResult<f64> {
+    precision_at_k(&graph.graph, embeddings, k, similarity, parallel_threshold)
+}
+
+/// Compute the mean average precision of the reconstruction of a PyDiGraph
+/// from node embeddings
+///
+/// For every node :math:`u` with at least one successor, the other nodes are
+/// ranked by decreasing similarity of their embedding to the embedding of
+/// :math:`u`, and the average precision of :math:`u` is the mean of the
+/// precisions at the ranks of its successors. The mean average precision is
+/// the mean of the average precisions of these nodes. The nodes with equal
+/// similarities are ranked by their node indices.
+///
+/// The nodes are ranked on multiple threads if the graph has at least
+/// ``parallel_threshold`` nodes, the env var ``RAYON_NUM_THREADS`` can be
+/// used to adjust how many threads are used.
+///
+/// :param PyDiGraph graph: The graph to reconstruct
+/// :param numpy.ndarray embeddings: A 2D array of ``float`` with the
+///     embedding of every node, the row of a node is its node index
+/// :param str similarity: The similarity of two embeddings, either ``"dot"``
+///     for their dot product or ``"cosine"`` for their cosine similarity.
+///     Defaults to ``"dot"``.
+/// :param int parallel_threshold: The number of nodes to run in parallel
+///     from. Defaults to 300.
+///
+/// :returns: The mean average precision, 0 if no node has a successor
+/// :rtype: float
+/// :raises ValueError: If ``embeddings`` has fewer rows than the node
+///     indices of the graph, or if ``similarity`` is invalid
+#[pyfunction(similarity = "\"dot\"", parallel_threshold = "300")]
+#[pyo3(text_signature = "(graph, embeddings, /, similarity=\"dot\", parallel_threshold=300)")]
+pub fn digraph_reconstruction_mean_average_precision(
+    graph: &digraph::PyDiGraph,
+    embeddings: PyReadonlyArray2<f64>,
+    similarity: &str,
+    parallel_threshold: usize,
+) -> PyResult<f64> {
+    mean_average_precision(&graph.graph, embeddings, similarity, parallel_threshold)
+}
+
+/// Compute the mean average precision of the reconstruction of a PyGraph
+/// from node embeddings
+///
+/// For every node :math:`u` with at least one neighbor, the other nodes are
+/// ranked by decreasing similarity of their embedding to the embedding of
+/// :math:`u`, and the average precision of :math:`u` is the mean of the
+/// precisions at the ranks of its neighbors. The mean average precision is
+/// the mean of the average precisions of these nodes. The nodes with equal
+/// similarities are ranked by their node indices.
+///
+/// The nodes are ranked on multiple threads if the graph has at least
+/// ``parallel_threshold`` nodes, the env var ``RAYON_NUM_THREADS`` can be
+/// used to adjust how many threads are used.
+///
+/// :param PyGraph graph: The graph to reconstruct
+/// :param numpy.ndarray embeddings: A 2D array of ``float`` with the
+///     embedding of every node, the row of a node is its node index
+/// :param str similarity: The similarity of two embeddings, either ``"dot"``
+///     for their dot product or ``"cosine"`` for their cosine similarity.
+///     Defaults to ``"dot"``.
+/// :param int parallel_threshold: The number of nodes to run in parallel
+///     from. Defaults to 300.
+///
+/// :returns: The mean average precision, 0 if no node has a neighbor
+/// :rtype: float
+/// :raises ValueError: If ``embeddings`` has fewer rows than the node
+///     indices of the graph, or if ``similarity`` is invalid
+#[pyfunction(similarity = "\"dot\"", parallel_threshold = "300")]
+#[pyo3(text_signature = "(graph, embeddings, /, similarity=\"dot\", parallel_threshold=300)")]
+pub fn graph_reconstruction_mean_average_precision(
+    graph: &graph::PyGraph,
+    embeddings: PyReadonlyArray2<f64>,
+    similarity: &str,
+    parallel_threshold: usize,
+) -> PyResult<f64> {
+    mean_average_precision(&graph.graph, embeddings, similarity, parallel_threshold)
+}
+
+/// Compute the ROC-AUC of the prediction of held out edges of a PyDiGraph
+/// from node embeddings
+///
+/// The ``test_edges`` are the held out edges, usually removed from the graph
+/// before computing the embeddings. Their similarities are compared to the
+/// similarities of ``num_negative`` negative pairs of nodes, drawn uniformly
+/// with replacement among the ordered pairs of different nodes which are
+/// neither an edge of the graph nor a test edge. The ROC-AUC is the
+/// probability that a random test edge has a higher similarity than a random
+/// negative pair, a tie counts as half. It is 1 for a perfect ranking and
+/// about 0.5 for a random one.
+///
+/// The similarities are computed on multiple threads if there are at least
+/// ``parallel_threshold`` test edges and negative pairs, the env var
+/// ``RAYON_NUM_THREADS`` can be used to adjust how many threads are used.
+///
+/// :param PyDiGraph graph: The graph the test edges are held out of
+/// :param numpy.ndarray embeddings: A 2D array of ``float`` with the
+///     embedding of every node, the row of a node is its node index
+/// :param list test_edges: The held out edges, as tuples of node indices
+///     ``(source, target)``
+/// :param int num_negative: The number of negative pairs to draw. Defaults
+///     to the number of test edges.
+/// :param str similarity: The similarity of two embeddings, either ``"dot"``
+///     for their dot product or ``"cosine"`` for their cosine similarity.
+///     Defaults to ``"dot"``.
+/// :param int seed: An optional seed to use for the random number generator
+/// :param int parallel_threshold: The number of similarities to compute in
+///     parallel from. Defaults to 300.
+///
+/// :returns: The ROC-AUC
+/// :rtype: float
+/// :raises InvalidNode: If a node of a test edge is not in the graph
+/// :raises ValueError: If ``embeddings`` has fewer rows than the node
+///     indices of the graph, if ``similarity`` is invalid, if there's no test
+///     edge or no negative pair to draw, or if ``num_negative`` is 0
+#[pyfunction(similarity = "\"dot\"", parallel_threshold = "300")]
+#[pyo3(
+    text_signature = "(graph, embeddings, test_edges, /, num_negative=None, similarity=\"dot\", seed=None, parallel_threshold=300)"
+)]
+pub fn digraph_link_prediction_roc_auc(
+    graph: &digraph::PyDiGraph,
+    embeddings: PyReadonlyArray2<f64>,
+    test_edges: Vec<(usize, usize)>,
+    num_negative: Option<usize>,
+    similarity: &str,
+    seed: Option<u64>,
+    parallel_threshold: usize,
+) -> PyResult<f64> {
+    roc_auc(
+        &graph.graph,
+        embeddings,
+        test_edges,
+        num_negative,
+        similarity,
+        seed,
+        parallel_threshold,
+    )
+}
+
+/// Compute the ROC-AUC of the prediction of held out edges of a PyGraph
+/// from node embeddings
+///
+/// The ``test_edges`` are the held out edges, usually removed from the graph
+/// before computing the embeddings. Their similarities are compared to the
+/// similarities of ``num_negative`` negative pairs of nodes, drawn uniformly
+/// with replacement among the pairs of different nodes which are neither an
+/// edge of the graph nor a test edge. The ROC-AUC is the probability that a
+/// random test edge has a higher similarity than a random negative pair, a
+/// tie counts as half. It is 1 for a perfect ranking and about 0.5 for a
+/// random one. For example:
+///
+/// .. jupyter-execute::
+///
+///     import numpy as np
+///     import retworkx
+///
+///     graph = retworkx.generators.path_graph(6)
+///     test_edges = [(2, 3)]
+///     graph.remove_edge(2, 3)
+///     # The nodes are on an arc, so consecutive nodes are the most similar
+///     angles = np.arange(6) * np.pi / 6
+///     embeddings = np.column_stack([np.cos(angles), np.sin(angles)])
+///     print(retworkx.graph_link_prediction_roc_auc(
+///         graph, embeddings, test_edges, num_negative=100, similarity="cosine", seed=42
+///     ))
+///
+/// The similarities are computed on multiple threads if there are at least
+/// ``parallel_threshold`` test edges and negative pairs, the env var
+/// ``RAYON_NUM_THREADS`` can be used to adjust how many threads are used.
+///
+/// :param PyGraph graph: The graph the test edges are held out of
+/// :param numpy.ndarray embeddings: A 2D array of ``float`` with the
+///     embedding of every node, the row of a node is its node index
+/// :param list test_edges: The held out edges, as tuples of node indices
+/// :param int num_negative: The number of negative pairs to draw. Defaults
+///     to the number of test edges.
+/// :param str similarity: The similarity of two embeddings, either ``"dot"``
+///     for their dot product or ``"cosine"`` for their cosine similarity.
+///     Defaults to ``"dot"``.
+/// :param int seed: An optional seed to use for the random number generator
+/// :param int parallel_threshold: The number of similarities to compute in
+///     parallel from. Defaults to 300.
+///
+/// :returns: The ROC-AUC
+/// :rtype: float
+/// :raises InvalidNode: If a node of a test edge is not in the graph
+/// :raises ValueError: If ``embeddings`` has fewer rows than the node
+///     indices of the graph, if ``similarity`` is invalid, if there's no test
+///     edge or no negative pair to draw, or if ``num_negative`` is 0
+#[pyfunction(similarity = "\"dot\"", parallel_threshold = "300")]
+#[pyo3(
+    text_signature = "(graph, embeddings, test_edges, /, num_negative=None, similarity=\"dot\", seed=None, parallel_threshold=300)"
+)]
+pub fn graph_link_prediction_roc_auc(
+    graph: &graph::PyGraph,
+    embeddings: PyReadonlyArray2<f64>,
+    test_edges: Vec<(usize, usize)>,
+    num_negative: Option<usize>,
+    similarity: &str,
+    seed: Option<u64>,
+    parallel_threshold: usize,
+) -> PyResult<f64> {
+    roc_auc(
+        &graph.graph,
+        embeddings,
+        test_edges,
+        num_negative,
+        similarity,
+        seed,
+        parallel_threshold,
+    )
+}
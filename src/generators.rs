@@ -13,18 +13,21 @@
 use std::collections::VecDeque;
 use std::iter;
 
-use petgraph::algo;
 use petgraph::graph::NodeIndex;
 use petgraph::prelude::*;
 use petgraph::visit::{EdgeRef, IntoEdgeReferences};
 use petgraph::Undirected;
 
-use pyo3::exceptions::{PyIndexError, PyOverflowError};
+use pyo3::exceptions::{PyIndexError, PyOverflowError, PyValueError};
 use pyo3::prelude::*;
+use pyo3::types::PyTuple;
 use pyo3::wrap_pyfunction;
 use pyo3::Python;
 
+use super::attributes::AttributeColumns;
+use super::node_keys::NodeKeys;
 use super::{digraph, graph, StablePyGraph};
+use retworkx_core::generators as core_generators;
 
 pub fn pairwise<I>(right: I) -> impl Iterator<Item = (Option<I::Item>, I::Item)>
 where
@@ -115,8 +118,11 @@ pub fn directed_cycle_graph(
         graph,
         node_removed: false,
         check_cycle: false,
-        cycle_state: algo::DfsSpace::default(),
+        cycle_state: None,
         multigraph,
+        node_keys: NodeKeys::default(),
+        attributes: AttributeColumns::default(),
+        generation: 0,
     })
 }
 
@@ -190,6 +196,9 @@ pub fn cycle_graph(
         graph,
         node_removed: false,
         multigraph,
+        node_keys: NodeKeys::default(),
+        attributes: AttributeColumns::default(),
+        generation: 0,
     })
 }
 
@@ -263,8 +272,11 @@ pub fn directed_path_graph(
         graph,
         node_removed: false,
         check_cycle: false,
-        cycle_state: algo::DfsSpace::default(),
+        cycle_state: None,
         multigraph,
+        node_keys: NodeKeys::default(),
+        attributes: AttributeColumns::default(),
+        generation: 0,
     })
 }
 
@@ -330,6 +342,9 @@ pub fn path_graph(
         graph,
         node_removed: false,
         multigraph,
+        node_keys: NodeKeys::default(),
+        attributes: AttributeColumns::default(),
+        generation: 0,
     })
 }
 
@@ -417,8 +432,11 @@ pub fn directed_star_graph(
         graph,
         node_removed: false,
         check_cycle: false,
-        cycle_state: algo::DfsSpace::default(),
+        cycle_state: None,
         multigraph,
+        node_keys: NodeKeys::default(),
+        attributes: AttributeColumns::default(),
+        generation: 0,
     })
 }
 
@@ -481,6 +499,9 @@ pub fn star_graph(
         graph,
         node_removed: false,
         multigraph,
+        node_keys: NodeKeys::default(),
+        attributes: AttributeColumns::default(),
+        generation: 0,
     })
 }
 
@@ -546,6 +567,9 @@ pub fn mesh_graph(
         graph,
         node_removed: false,
         multigraph,
+        node_keys: NodeKeys::default(),
+        attributes: AttributeColumns::default(),
+        generation: 0,
     })
 }
 
@@ -611,8 +635,11 @@ pub fn directed_mesh_graph(
         graph,
         node_removed: false,
         check_cycle: false,
-        cycle_state: algo::DfsSpace::default(),
+        cycle_state: None,
         multigraph,
+        node_keys: NodeKeys::default(),
+        attributes: AttributeColumns::default(),
+        generation: 0,
     })
 }
 
@@ -657,7 +684,6 @@ pub fn grid_graph(
     weights: Option<Vec<PyObject>>,
     multigraph: bool,
 ) -> PyResult<graph::PyGraph> {
-    let mut graph = StablePyGraph::<Undirected>::default();
     if weights.is_none() && (rows.is_none() || cols.is_none()) {
         return Err(PyIndexError::new_err(
             "dimensions and weights list not specified",
@@ -666,54 +692,28 @@ pub fn grid_graph(
 
     let mut rowlen = rows.unwrap_or(0);
     let mut collen = cols.unwrap_or(0);
-    let mut num_nodes = rowlen * collen;
-
-    let nodes: Vec<NodeIndex> = match weights {
-        Some(weights) => {
-            let mut node_list: Vec<NodeIndex> = Vec::new();
-            if num_nodes < weights.len() && rowlen == 0 {
-                collen = weights.len();
-                rowlen = 1;
-                num_nodes = collen;
-            }
-
-            let mut node_cnt = num_nodes;
-
-            for weight in weights {
-                if node_cnt == 0 {
-                    break;
-                }
-                let index = graph.add_node(weight);
-                node_list.push(index);
-                node_cnt -= 1;
-            }
-            for _i in 0..node_cnt {
-                let index = graph.add_node(py.None());
-                node_list.push(index);
-            }
-            node_list
-        }
-        None => (0..num_nodes).map(|_| graph.add_node(py.None())).collect(),
-    };
-
-    for i in 0..rowlen {
-        for j in 0..collen {
-            if i + 1 < rowlen {
-                graph.add_edge(
-                    nodes[i * collen + j],
-                    nodes[(i + 1) * collen + j],
-                    py.None(),
-                );
-            }
-            if j + 1 < collen {
-                graph.add_edge(nodes[i * collen + j], nodes[i * collen + j + 1], py.None());
-            }
-        }
-    }
+    let weights = weights.unwrap_or_default();
+    if rowlen * collen < weights.len() && rowlen == 0 {
+        collen = weights.len();
+        rowlen = 1;
+    }
+    let graph: StablePyGraph<Undirected> = core_generators::grid_graph(
+        rowlen,
+        collen,
+        |row, col| match weights.get(row * collen + col) {
+            Some(weight) => weight.clone_ref(py),
+            None => py.None(),
+        },
+        || py.None(),
+        false,
+    );
     Ok(graph::PyGraph {
         graph,
         node_removed: false,
         multigraph,
+        node_keys: NodeKeys::default(),
+        attributes: AttributeColumns::default(),
+        generation: 0,
     })
 }
 
@@ -764,7 +764,6 @@ pub fn directed_grid_graph(
     bidirectional: bool,
     multigraph: bool,
 ) -> PyResult<digraph::PyDiGraph> {
-    let mut graph = StablePyGraph::<Directed>::default();
     if weights.is_none() && (rows.is_none() || cols.is_none()) {
         return Err(PyIndexError::new_err(
             "dimensions and weights list not specified",
@@ -773,67 +772,30 @@ pub fn directed_grid_graph(
 
     let mut rowlen = rows.unwrap_or(0);
     let mut collen = cols.unwrap_or(0);
-    let mut num_nodes = rowlen * collen;
-
-    let nodes: Vec<NodeIndex> = match weights {
-        Some(weights) => {
-            let mut node_list: Vec<NodeIndex> = Vec::new();
-            if num_nodes < weights.len() && rowlen == 0 {
-                collen = weights.len();
-                rowlen = 1;
-                num_nodes = collen;
-            }
-
-            let mut node_cnt = num_nodes;
-
-            for weight in weights {
-                if node_cnt == 0 {
-                    break;
-                }
-                let index = graph.add_node(weight);
-                node_list.push(index);
-                node_cnt -= 1;
-            }
-            for _i in 0..node_cnt {
-                let index = graph.add_node(py.None());
-                node_list.push(index);
-            }
-            node_list
-        }
-        None => (0..num_nodes).map(|_| graph.add_node(py.None())).collect(),
-    };
-
-    for i in 0..rowlen {
-        for j in 0..collen {
-            if i + 1 < rowlen {
-                graph.add_edge(
-                    nodes[i * collen + j],
-                    nodes[(i + 1) * collen + j],
-                    py.None(),
-                );
-                if bidirectional {
-                    graph.add_edge(
-                        nodes[(i + 1) * collen + j],
-                        nodes[i * collen + j],
-                        py.None(),
-                    );
-                }
-            }
-
-            if j + 1 < collen {
-                graph.add_edge(nodes[i * collen + j], nodes[i * collen + j + 1], py.None());
-                if bidirectional {
-                    graph.add_edge(nodes[i * collen + j + 1], nodes[i * collen + j], py.None());
-                }
-            }
-        }
-    }
+    let weights = weights.unwrap_or_default();
+    if rowlen * collen < weights.len() && rowlen == 0 {
+        collen = weights.len();
+        rowlen = 1;
+    }
+    let graph: StablePyGraph<Directed> = core_generators::grid_graph(
+        rowlen,
+        collen,
+        |row, col| match weights.get(row * collen + col) {
+            Some(weight) => weight.clone_ref(py),
+            None => py.None(),
+        },
+        || py.None(),
+        bidirectional,
+    );
     Ok(digraph::PyDiGraph {
         graph,
         node_removed: false,
         check_cycle: false,
-        cycle_state: algo::DfsSpace::default(),
+        cycle_state: None,
         multigraph,
+        node_keys: NodeKeys::default(),
+        attributes: AttributeColumns::default(),
+        generation: 0,
     })
 }
 
@@ -885,48 +847,32 @@ pub fn binomial_tree_graph(
         )));
     }
     let num_nodes = usize::pow(2, order);
-    let num_edges = usize::pow(2, order) - 1;
-    let mut graph = StablePyGraph::<Undirected>::with_capacity(num_nodes, num_edges);
-    for i in 0..num_nodes {
-        match weights {
-            Some(ref weights) => {
-                if weights.len() > num_nodes {
-                    return Err(PyIndexError::new_err("weights should be <= 2**order"));
-                }
-                if i < weights.len() {
-                    graph.add_node(weights[i].clone_ref(py))
-                } else {
-                    graph.add_node(py.None())
-                }
+    let weights = weights.unwrap_or_default();
+    if weights.len() > num_nodes {
+        return Err(PyIndexError::new_err("weights should be <= 2**order"));
+    }
+    let mut index = 0;
+    let graph: StablePyGraph<Undirected> = core_generators::binomial_tree_graph(
+        order,
+        |_| {
+            index += 1;
+            match weights.get(index - 1) {
+                Some(weight) => weight.clone_ref(py),
+                None => py.None(),
             }
-            None => graph.add_node(py.None()),
-        };
-    }
-
-    let mut n = 1;
-    let zero_index = NodeIndex::new(0);
-
-    for _ in 0..order {
-        let edges: Vec<(NodeIndex, NodeIndex)> = graph
-            .edge_references()
-            .map(|e| (e.source(), e.target()))
-            .collect();
-        for (source, target) in edges {
-            let source_index = NodeIndex::new(source.index() + n);
-            let target_index = NodeIndex::new(target.index() + n);
-
-            graph.add_edge(source_index, target_index, py.None());
-        }
-
-        graph.add_edge(zero_index, NodeIndex::new(n), py.None());
-
-        n *= 2;
-    }
+        },
+        || py.None(),
+        false,
+    )
+    .map_err(|err| PyOverflowError::new_err(err.to_string()))?;
 
     Ok(graph::PyGraph {
         graph,
         node_removed: false,
         multigraph,
+        node_keys: NodeKeys::default(),
+        attributes: AttributeColumns::default(),
+        generation: 0,
     })
 }
 
@@ -1005,6 +951,9 @@ pub fn full_rary_tree(
         graph,
         node_removed: false,
         multigraph,
+        node_keys: NodeKeys::default(),
+        attributes: AttributeColumns::default(),
+        generation: 0,
     })
 }
 
@@ -1054,65 +1003,34 @@ pub fn directed_binomial_tree_graph(
         )));
     }
     let num_nodes = usize::pow(2, order);
-    let num_edges = usize::pow(2, order) - 1;
-    let mut graph = StablePyGraph::<Directed>::with_capacity(num_nodes, num_edges);
-
-    for i in 0..num_nodes {
-        match weights {
-            Some(ref weights) => {
-                if weights.len() > num_nodes {
-                    return Err(PyIndexError::new_err("weights should be <= 2**order"));
-                }
-                if i < weights.len() {
-                    graph.add_node(weights[i].clone_ref(py))
-                } else {
-                    graph.add_node(py.None())
-                }
+    let weights = weights.unwrap_or_default();
+    if weights.len() > num_nodes {
+        return Err(PyIndexError::new_err("weights should be <= 2**order"));
+    }
+    let mut index = 0;
+    let graph: StablePyGraph<Directed> = core_generators::binomial_tree_graph(
+        order,
+        |_| {
+            index += 1;
+            match weights.get(index - 1) {
+                Some(weight) => weight.clone_ref(py),
+                None => py.None(),
             }
-            None => graph.add_node(py.None()),
-        };
-    }
-
-    let mut n = 1;
-    let zero_index = NodeIndex::new(0);
-
-    for _ in 0..order {
-        let edges: Vec<(NodeIndex, NodeIndex)> = graph
-            .edge_references()
-            .map(|e| (e.source(), e.target()))
-            .collect();
-
-        for (source, target) in edges {
-            let source_index = NodeIndex::new(source.index() + n);
-            let target_index = NodeIndex::new(target.index() + n);
-
-            if graph.find_edge(source_index, target_index).is_none() {
-                graph.add_edge(source_index, target_index, py.None());
-            }
-
-            if bidirectional && graph.find_edge(target_index, source_index).is_none() {
-                graph.add_edge(target_index, source_index, py.None());
-            }
-        }
-        let n_index = NodeIndex::new(n);
-
-        if graph.find_edge(zero_index, n_index).is_none() {
-            graph.add_edge(zero_index, n_index, py.None());
-        }
-
-        if bidirectional && graph.find_edge(n_index, zero_index).is_none() {
-            graph.add_edge(n_index, zero_index, py.None());
-        }
-
-        n *= 2;
-    }
+        },
+        || py.None(),
+        bidirectional,
+    )
+    .map_err(|err| PyOverflowError::new_err(err.to_string()))?;
 
     Ok(digraph::PyDiGraph {
         graph,
         node_removed: false,
         check_cycle: false,
-        cycle_state: algo::DfsSpace::default(),
+        cycle_state: None,
         multigraph,
+        node_keys: NodeKeys::default(),
+        attributes: AttributeColumns::default(),
+        generation: 0,
     })
 }
 
@@ -1194,6 +1112,9 @@ pub fn heavy_square_graph(py: Python, d: usize, multigraph: bool) -> PyResult<gr
             graph,
             node_removed: false,
             multigraph,
+            node_keys: NodeKeys::default(),
+            attributes: AttributeColumns::default(),
+            generation: 0,
         });
     }
 
@@ -1257,6 +1178,9 @@ pub fn heavy_square_graph(py: Python, d: usize, multigraph: bool) -> PyResult<gr
         graph,
         node_removed: false,
         multigraph,
+        node_keys: NodeKeys::default(),
+        attributes: AttributeColumns::default(),
+        generation: 0,
     })
 }
 
@@ -1340,8 +1264,11 @@ pub fn directed_heavy_square_graph(
             graph,
             node_removed: false,
             check_cycle: false,
-            cycle_state: algo::DfsSpace::default(),
+            cycle_state: None,
             multigraph,
+            node_keys: NodeKeys::default(),
+            attributes: AttributeColumns::default(),
+            generation: 0,
         });
     }
 
@@ -1433,8 +1360,11 @@ pub fn directed_heavy_square_graph(
         graph,
         node_removed: false,
         check_cycle: false,
-        cycle_state: algo::DfsSpace::default(),
+        cycle_state: None,
         multigraph,
+        node_keys: NodeKeys::default(),
+        attributes: AttributeColumns::default(),
+        generation: 0,
     })
 }
 
@@ -1524,6 +1454,9 @@ pub fn heavy_hex_graph(py: Python, d: usize, multigraph: bool) -> PyResult<graph
             graph,
             node_removed: false,
             multigraph,
+            node_keys: NodeKeys::default(),
+            attributes: AttributeColumns::default(),
+            generation: 0,
         });
     }
 
@@ -1595,6 +1528,9 @@ pub fn heavy_hex_graph(py: Python, d: usize, multigraph: bool) -> PyResult<graph
         graph,
         node_removed: false,
         multigraph,
+        node_keys: NodeKeys::default(),
+        attributes: AttributeColumns::default(),
+        generation: 0,
     })
 }
 
@@ -1689,8 +1625,11 @@ pub fn directed_heavy_hex_graph(
             graph,
             node_removed: false,
             check_cycle: false,
-            cycle_state: algo::DfsSpace::default(),
+            cycle_state: None,
             multigraph,
+            node_keys: NodeKeys::default(),
+            attributes: AttributeColumns::default(),
+            generation: 0,
         });
     }
 
@@ -1798,8 +1737,11 @@ pub fn directed_heavy_hex_graph(
         graph,
         node_removed: false,
         check_cycle: false,
-        cycle_state: algo::DfsSpace::default(),
+        cycle_state: None,
         multigraph,
+        node_keys: NodeKeys::default(),
+        attributes: AttributeColumns::default(),
+        generation: 0,
     })
 }
 
@@ -1841,6 +1783,9 @@ pub fn hexagonal_lattice_graph(
             graph,
             node_removed: false,
             multigraph,
+            node_keys: NodeKeys::default(),
+            attributes: AttributeColumns::default(),
+            generation: 0,
         };
     }
 
@@ -1906,6 +1851,9 @@ pub fn hexagonal_lattice_graph(
         graph,
         node_removed: false,
         multigraph,
+        node_keys: NodeKeys::default(),
+        attributes: AttributeColumns::default(),
+        generation: 0,
     }
 }
 
@@ -1951,8 +1899,11 @@ pub fn directed_hexagonal_lattice_graph(
             graph,
             node_removed: false,
             check_cycle: false,
-            cycle_state: algo::DfsSpace::default(),
+            cycle_state: None,
             multigraph,
+            node_keys: NodeKeys::default(),
+            attributes: AttributeColumns::default(),
+            generation: 0,
         };
     }
 
@@ -2048,8 +1999,11 @@ pub fn directed_hexagonal_lattice_graph(
         graph,
         node_removed: false,
         check_cycle: false,
-        cycle_state: algo::DfsSpace::default(),
+        cycle_state: None,
         multigraph,
+        node_keys: NodeKeys::default(),
+        attributes: AttributeColumns::default(),
+        generation: 0,
     }
 }
 
@@ -2190,38 +2144,287 @@ pub fn generalized_petersen_graph(
     k: usize,
     multigraph: bool,
 ) -> PyResult<graph::PyGraph> {
-    if n < 3 {
-        return Err(PyIndexError::new_err("n must be at least 3"));
-    }
-
-    if k == 0 || 2 * k >= n {
-        return Err(PyIndexError::new_err(
-            "k is invalid: it must be positive and less than n/2",
-        ));
-    }
-
-    let mut graph = StablePyGraph::<Undirected>::with_capacity(2 * n, 3 * n);
-
-    let star_nodes: Vec<NodeIndex> = (0..n).map(|_| graph.add_node(py.None())).collect();
+    let graph: StablePyGraph<Undirected> =
+        core_generators::generalized_petersen_graph(n, k, |_| py.None(), || py.None())
+            .map_err(|err| PyIndexError::new_err(err.to_string()))?;
+    Ok(graph::PyGraph {
+        graph,
+        node_removed: false,
+        multigraph,
+        node_keys: NodeKeys::default(),
+        attributes: AttributeColumns::default(),
+        generation: 0,
+    })
+}
 
-    let polygon_nodes: Vec<NodeIndex> = (0..n).map(|_| graph.add_node(py.None())).collect();
+/// Generate an undirected circulant graph
+///
+/// The circulant graph :math:`C_n(S)` has :math:`n` nodes and node :math:`i`
+/// is connected to the nodes :math:`(i + s) \bmod n` and
+/// :math:`(i - s) \bmod n` for every offset :math:`s` of :math:`S`. An
+/// offset of :math:`n / 2` connects the opposite nodes with a single edge.
+/// With the offsets ``[1]`` the circulant graph is a cycle graph.
+///
+/// :param int n: The number of nodes
+/// :param list offsets: The offsets of the nodes connected to each node, they
+///     must be distinct and between 1 and :math:`n / 2`
+/// :param bool multigraph: When set to False the output
+///     :class:`~retworkx.PyGraph` object will not be not be a multigraph and
+///     won't  allow parallel edges to be added. Instead
+///     calls which would create a parallel edge will update the existing edge.
+///
+/// :returns: The generated circulant graph
+/// :rtype: PyGraph
+/// :raises ValueError: If an offset is out of range or repeated
+///
+/// .. jupyter-execute::
+///
+///   import retworkx.generators
+///   from retworkx.visualization import mpl_draw
+///
+///   graph = retworkx.generators.circulant_graph(10, [1, 3])
+///   mpl_draw(graph, pos=retworkx.circular_layout(graph))
+#[pyfunction(multigraph = true)]
+#[pyo3(text_signature = "(n, offsets, /, multigraph=True)")]
+pub fn circulant_graph(
+    py: Python,
+    n: usize,
+    offsets: Vec<usize>,
+    multigraph: bool,
+) -> PyResult<graph::PyGraph> {
+    let graph: StablePyGraph<Undirected> =
+        core_generators::circulant_graph(n, &offsets, |_| py.None(), || py.None(), false)
+            .map_err(|err| PyValueError::new_err(err.to_string()))?;
+    Ok(graph::PyGraph {
+        graph,
+        node_removed: false,
+        multigraph,
+        node_keys: NodeKeys::default(),
+        attributes: AttributeColumns::default(),
+        generation: 0,
+    })
+}
 
-    for i in 0..n {
-        graph.add_edge(star_nodes[i], star_nodes[(i + k) % n], py.None());
-    }
+/// Generate a directed circulant graph
+///
+/// The circulant graph :math:`C_n(S)` has :math:`n` nodes and an edge from
+/// node :math:`i` to node :math:`(i + s) \bmod n` for every offset :math:`s`
+/// of :math:`S`. With the offsets ``[1]`` the circulant graph is a directed
+/// cycle graph.
+///
+/// :param int n: The number of nodes
+/// :param list offsets: The offsets of the nodes connected to each node, they
+///     must be distinct and between 1 and :math:`n / 2`
+/// :param bool bidirectional: Adds edges in both directions between two nodes
+///     if set to ``True``. Default value is ``False``
+/// :param bool multigraph: When set to False the output
+///     :class:`~retworkx.PyDiGraph` object will not be not be a multigraph and
+///     won't allow parallel edges to be added. Instead
+///     calls which would create a parallel edge will update the existing edge.
+///
+/// :returns: The generated circulant graph
+/// :rtype: PyDiGraph
+/// :raises ValueError: If an offset is out of range or repeated
+///
+/// .. jupyter-execute::
+///
+///   import retworkx.generators
+///   from retworkx.visualization import mpl_draw
+///
+///   graph = retworkx.generators.directed_circulant_graph(10, [1, 3])
+///   mpl_draw(graph, pos=retworkx.circular_layout(graph))
+#[pyfunction(bidirectional = false, multigraph = true)]
+#[pyo3(text_signature = "(n, offsets, /, bidirectional=False, multigraph=True)")]
+pub fn directed_circulant_graph(
+    py: Python,
+    n: usize,
+    offsets: Vec<usize>,
+    bidirectional: bool,
+    multigraph: bool,
+) -> PyResult<digraph::PyDiGraph> {
+    let graph: StablePyGraph<Directed> =
+        core_generators::circulant_graph(n, &offsets, |_| py.None(), || py.None(), bidirectional)
+            .map_err(|err| PyValueError::new_err(err.to_string()))?;
+    Ok(digraph::PyDiGraph {
+        graph,
+        node_removed: false,
+        check_cycle: false,
+        cycle_state: None,
+        multigraph,
+        node_keys: NodeKeys::default(),
+        attributes: AttributeColumns::default(),
+        generation: 0,
+    })
+}
 
-    for i in 0..n {
-        graph.add_edge(polygon_nodes[i], polygon_nodes[(i + 1) % n], py.None());
-    }
+/// Generate an undirected hypercube graph
+///
+/// The nodes of the :math:`n`-dimensional hypercube graph are the
+/// :math:`2^n` points of :math:`\{0, 1\}^n`, and two nodes are connected if
+/// their coordinates differ in exactly one dimension. The weight of a node
+/// is the tuple of its coordinates, and its index is the binary number
+/// formed by its coordinates, so the nodes are in the lexicographic order of
+/// their coordinates.
+///
+/// :param int dim: The number of dimensions of the hypercube
+/// :param bool multigraph: When set to False the output
+///     :class:`~retworkx.PyGraph` object will not be not be a multigraph and
+///     won't  allow parallel edges to be added. Instead
+///     calls which would create a parallel edge will update the existing edge.
+///
+/// :returns: The generated hypercube graph
+/// :rtype: PyGraph
+/// :raises ValueError: If the graph would have too many edges
+///
+/// .. jupyter-execute::
+///
+///   import retworkx.generators
+///   from retworkx.visualization import mpl_draw
+///
+///   graph = retworkx.generators.hypercube_graph(4)
+///   mpl_draw(graph)
+#[pyfunction(multigraph = true)]
+#[pyo3(text_signature = "(dim, /, multigraph=True)")]
+pub fn hypercube_graph(py: Python, dim: usize, multigraph: bool) -> PyResult<graph::PyGraph> {
+    let graph: StablePyGraph<Undirected> = core_generators::hypercube_graph(
+        dim,
+        |coordinates| PyTuple::new(py, coordinates).into(),
+        || py.None(),
+        false,
+    )
+    .map_err(|err| PyValueError::new_err(err.to_string()))?;
+    Ok(graph::PyGraph {
+        graph,
+        node_removed: false,
+        multigraph,
+        node_keys: NodeKeys::default(),
+        attributes: AttributeColumns::default(),
+        generation: 0,
+    })
+}
 
-    for i in 0..n {
-        graph.add_edge(polygon_nodes[i], star_nodes[i], py.None());
-    }
+/// Generate a directed hypercube graph
+///
+/// The nodes of the :math:`n`-dimensional hypercube graph are the
+/// :math:`2^n` points of :math:`\{0, 1\}^n`, and two nodes are connected if
+/// their coordinates differ in exactly one dimension. The weight of a node
+/// is the tuple of its coordinates, and its index is the binary number
+/// formed by its coordinates, so the nodes are in the lexicographic order of
+/// their coordinates. The edges go from the lower node index to the higher
+/// one.
+///
+/// :param int dim: The number of dimensions of the hypercube
+/// :param bool bidirectional: Adds edges in both directions between two nodes
+///     if set to ``True``. Default value is ``False``
+/// :param bool multigraph: When set to False the output
+///     :class:`~retworkx.PyDiGraph` object will not be not be a multigraph and
+///     won't allow parallel edges to be added. Instead
+///     calls which would create a parallel edge will update the existing edge.
+///
+/// :returns: The generated hypercube graph
+/// :rtype: PyDiGraph
+/// :raises ValueError: If the graph would have too many edges
+///
+/// .. jupyter-execute::
+///
+///   import retworkx.generators
+///   from retworkx.visualization import mpl_draw
+///
+///   graph = retworkx.generators.directed_hypercube_graph(3)
+///   mpl_draw(graph, with_labels=True, labels=str)
+#[pyfunction(bidirectional = false, multigraph = true)]
+#[pyo3(text_signature = "(dim, /, bidirectional=False, multigraph=True)")]
+pub fn directed_hypercube_graph(
+    py: Python,
+    dim: usize,
+    bidirectional: bool,
+    multigraph: bool,
+) -> PyResult<digraph::PyDiGraph> {
+    let graph: StablePyGraph<Directed> = core_generators::hypercube_graph(
+        dim,
+        |coordinates| PyTuple::new(py, coordinates).into(),
+        || py.None(),
+        bidirectional,
+    )
+    .map_err(|err| PyValueError::new_err(err.to_string()))?;
+    Ok(digraph::PyDiGraph {
+        graph,
+        node_removed: false,
+        check_cycle: false,
+        cycle_state: None,
+        multigraph,
+        node_keys: NodeKeys::default(),
+        attributes: AttributeColumns::default(),
+        generation: 0,
+    })
+}
 
-    Ok(graph::PyGraph {
+/// Generate a de Bruijn graph
+///
+/// The nodes of the de Bruijn graph :math:`B(k, n)` are the :math:`k^n`
+/// words of length :math:`n` over an alphabet of :math:`k` symbols. There is
+/// an edge from every word :math:`s_1 s_2 \dots s_n` to each of the
+/// :math:`k` words :math:`s_2 \dots s_n s` obtained by shifting it one
+/// symbol to the left and appending a symbol :math:`s`, so every node has
+/// :math:`k` outgoing and :math:`k` incoming edges. The words made of a
+/// single repeated symbol have a self loop.
+///
+/// The weight of a node is the tuple of the symbols of its word, and its
+/// index is the number formed by its word in base :math:`k`, where the
+/// digit of a symbol is its position in ``symbols``, so the nodes are in the
+/// lexicographic order of their words.
+///
+/// :param symbols: The alphabet, either a sequence of symbols, such as a
+///     ``list`` or a ``str`` whose characters are the symbols, or an ``int``
+///     number of symbols, which are then the integers from 0
+/// :param int length: The length :math:`n` of the words
+/// :param bool multigraph: When set to False the output
+///     :class:`~retworkx.PyDiGraph` object will not be not be a multigraph and
+///     won't allow parallel edges to be added. Instead
+///     calls which would create a parallel edge will update the existing edge.
+///
+/// :returns: The generated de Bruijn graph
+/// :rtype: PyDiGraph
+/// :raises ValueError: If the graph would have too many edges
+///
+/// .. jupyter-execute::
+///
+///   import retworkx.generators
+///   from retworkx.visualization import mpl_draw
+///
+///   graph = retworkx.generators.de_bruijn_graph("01", 3)
+///   mpl_draw(graph, with_labels=True, labels="".join)
+#[pyfunction(multigraph = true)]
+#[pyo3(text_signature = "(symbols, length, /, multigraph=True)")]
+pub fn de_bruijn_graph(
+    py: Python,
+    symbols: &PyAny,
+    length: usize,
+    multigraph: bool,
+) -> PyResult<digraph::PyDiGraph> {
+    let symbols: Vec<PyObject> = match symbols.extract::<usize>() {
+        Ok(num_symbols) => (0..num_symbols).map(|symbol| symbol.into_py(py)).collect(),
+        Err(_) => symbols
+            .iter()?
+            .map(|symbol| symbol.map(|symbol| symbol.into()))
+            .collect::<PyResult<_>>()?,
+    };
+    let graph: StablePyGraph<Directed> = core_generators::de_bruijn_graph(
+        symbols.len(),
+        length,
+        |word| PyTuple::new(py, word.iter().map(|symbol| &symbols[*symbol])).into(),
+        || py.None(),
+    )
+    .map_err(|err| PyValueError::new_err(err.to_string()))?;
+    Ok(digraph::PyDiGraph {
         graph,
         node_removed: false,
+        check_cycle: false,
+        cycle_state: None,
         multigraph,
+        node_keys: NodeKeys::default(),
+        attributes: AttributeColumns::default(),
+        generation: 0,
     })
 }
 
@@ -2321,6 +2524,290 @@ pub fn barbell_graph(
         graph: left_mesh,
         node_removed: false,
         multigraph,
+        node_keys: NodeKeys::default(),
+        attributes: AttributeColumns::default(),
+        generation: 0,
+    })
+}
+
+/// Generate an undirected complete multipartite graph
+///
+/// The nodes are split into subsets of the sizes in ``subset_sizes``, the
+/// first ``subset_sizes[0]`` nodes are in the first subset, the next
+/// ``subset_sizes[1]`` nodes in the second one and so on. Every node is
+/// connected to every node of the other subsets, and to no node of its own
+/// subset.
+///
+/// :param list subset_sizes: The number of nodes in each subset
+/// :param bool multigraph: When set to False the output
+///     :class:`~retworkx.PyGraph` object will not be not be a multigraph and
+///     won't  allow parallel edges to be added. Instead
+///     calls which would create a parallel edge will update the existing edge.
+///
+/// :returns: A tuple of the generated graph and a list of the index of the
+///     subset of every node, in the order of the node indices
+/// :rtype: tuple
+///
+/// .. jupyter-execute::
+///
+///   import retworkx.generators
+///   from retworkx.visualization import mpl_draw
+///
+///   graph, subsets = retworkx.generators.complete_multipartite_graph([2, 3, 4])
+///   mpl_draw(graph, node_color=subsets)
+///
+#[pyfunction(multigraph = true)]
+#[pyo3(text_signature = "(subset_sizes, /, multigraph=True)")]
+pub fn complete_multipartite_graph(
+    py: Python,
+    subset_sizes: Vec<usize>,
+    multigraph: bool,
+) -> (graph::PyGraph, Vec<usize>) {
+    let (graph, subsets): (StablePyGraph<Undirected>, Vec<usize>) =
+        core_generators::complete_multipartite_graph(
+            &subset_sizes,
+            |_| py.None(),
+            || py.None(),
+            false,
+        );
+    (
+        graph::PyGraph {
+            graph,
+            node_removed: false,
+            multigraph,
+            node_keys: NodeKeys::default(),
+            attributes: AttributeColumns::default(),
+            generation: 0,
+        },
+        subsets,
+    )
+}
+
+/// Generate a directed complete multipartite graph
+///
+/// The nodes are split into subsets of the sizes in ``subset_sizes``, the
+/// first ``subset_sizes[0]`` nodes are in the first subset, the next
+/// ``subset_sizes[1]`` nodes in the second one and so on. Every node is
+/// connected to every node of the other subsets, and to no node of its own
+/// subset. The edges go from the node of the earlier subset to the node of
+/// the later one.
+///
+/// :param list subset_sizes: The number of nodes in each subset
+/// :param bool bidirectional: Adds edges in both directions between two nodes
+///     if set to ``True``. Default value is ``False``
+/// :param bool multigraph: When set to False the output
+///     :class:`~retworkx.PyDiGraph` object will not be not be a multigraph and
+///     won't allow parallel edges to be added. Instead
+///     calls which would create a parallel edge will update the existing edge.
+///
+/// :returns: A tuple of the generated graph and a list of the index of the
+///     subset of every node, in the order of the node indices
+/// :rtype: tuple
+///
+/// .. jupyter-execute::
+///
+///   import retworkx.generators
+///   from retworkx.visualization import mpl_draw
+///
+///   graph, subsets = retworkx.generators.directed_complete_multipartite_graph([2, 3])
+///   mpl_draw(graph, node_color=subsets)
+///
+#[pyfunction(bidirectional = "false", multigraph = "true")]
+#[pyo3(text_signature = "(subset_sizes, /, bidirectional=False, multigraph=True)")]
+pub fn directed_complete_multipartite_graph(
+    py: Python,
+    subset_sizes: Vec<usize>,
+    bidirectional: bool,
+    multigraph: bool,
+) -> (digraph::PyDiGraph, Vec<usize>) {
+    let (graph, subsets): (StablePyGraph<Directed>, Vec<usize>) =
+        core_generators::complete_multipartite_graph(
+            &subset_sizes,
+            |_| py.None(),
+            || py.None(),
+            bidirectional,
+        );
+    (
+        digraph::PyDiGraph {
+            graph,
+            node_removed: false,
+            check_cycle: false,
+            cycle_state: None,
+            multigraph,
+            node_keys: NodeKeys::default(),
+            attributes: AttributeColumns::default(),
+            generation: 0,
+        },
+        subsets,
+    )
+}
+
+/// Generate an undirected Turán graph
+///
+/// The Turán graph :math:`T(n, r)` is the complete multipartite graph with
+/// :math:`n` nodes split into :math:`r` subsets whose sizes differ by at most
+/// 1, the :math:`n \bmod r` larger subsets come last. It's the graph with
+/// :math:`n` nodes and the most edges that doesn't contain a complete
+/// subgraph of :math:`r + 1` nodes [1]_.
+///
+/// :param int n: The number of nodes
+/// :param int r: The number of subsets, it must be between 1 and ``n``
+/// :param bool multigraph: When set to False the output
+///     :class:`~retworkx.PyGraph` object will not be not be a multigraph and
+///     won't  allow parallel edges to be added. Instead
+///     calls which would create a parallel edge will update the existing edge.
+///
+/// :returns: A tuple of the generated graph and a list of the index of the
+///     subset of every node, in the order of the node indices
+/// :rtype: tuple
+/// :raises ValueError: If ``r`` is out of range
+///
+/// .. jupyter-execute::
+///
+///   import retworkx.generators
+///   from retworkx.visualization import mpl_draw
+///
+///   graph, subsets = retworkx.generators.turan_graph(10, 3)
+///   mpl_draw(graph, node_color=subsets)
+///
+/// .. [1] P. Turán, "On an extremal problem in graph theory", Matematikai és
+///    Fizikai Lapok, 48, pp. 436–452, 1941.
+#[pyfunction(multigraph = true)]
+#[pyo3(text_signature = "(n, r, /, multigraph=True)")]
+pub fn turan_graph(
+    py: Python,
+    n: usize,
+    r: usize,
+    multigraph: bool,
+) -> PyResult<(graph::PyGraph, Vec<usize>)> {
+    let (graph, subsets): (StablePyGraph<Undirected>, Vec<usize>) =
+        core_generators::turan_graph(n, r, |_| py.None(), || py.None(), false)
+            .map_err(|err| PyValueError::new_err(err.to_string()))?;
+    Ok((
+        graph::PyGraph {
+            graph,
+            node_removed: false,
+            multigraph,
+            node_keys: NodeKeys::default(),
+            attributes: AttributeColumns::default(),
+            generation: 0,
+        },
+        subsets,
+    ))
+}
+
+/// Generate a directed Turán graph
+///
+/// The Turán graph :math:`T(n, r)` is the complete multipartite graph with
+/// :math:`n` nodes split into :math:`r` subsets whose sizes differ by at most
+/// 1, the :math:`n \bmod r` larger subsets come last. The edges go from the
+/// node of the earlier subset to the node of the later one.
+///
+/// :param int n: The number of nodes
+/// :param int r: The number of subsets, it must be between 1 and ``n``
+/// :param bool bidirectional: Adds edges in both directions between two nodes
+///     if set to ``True``. Default value is ``False``
+/// :param bool multigraph: When set to False the output
+///     :class:`~retworkx.PyDiGraph` object will not be not be a multigraph and
+///     won't allow parallel edges to be added. Instead
+///     calls which would create a parallel edge will update the existing edge.
+///
+/// :returns: A tuple of the generated graph and a list of the index of the
+///     subset of every node, in the order of the node indices
+/// :rtype: tuple
+/// :raises ValueError: If ``r`` is out of range
+///
+/// .. jupyter-execute::
+///
+///   import retworkx.generators
+///   from retworkx.visualization import mpl_draw
+///
+///   graph, subsets = retworkx.generators.directed_turan_graph(7, 3)
+///   mpl_draw(graph, node_color=subsets)
+///
+#[pyfunction(bidirectional = "false", multigraph = "true")]
+#[pyo3(text_signature = "(n, r, /, bidirectional=False, multigraph=True)")]
+pub fn directed_turan_graph(
+    py: Python,
+    n: usize,
+    r: usize,
+    bidirectional: bool,
+    multigraph: bool,
+) -> PyResult<(digraph::PyDiGraph, Vec<usize>)> {
+    let (graph, subsets): (StablePyGraph<Directed>, Vec<usize>) =
+        core_generators::turan_graph(n, r, |_| py.None(), || py.None(), bidirectional)
+            .map_err(|err| PyValueError::new_err(err.to_string()))?;
+    Ok((
+        digraph::PyDiGraph {
+            graph,
+            node_removed: false,
+            check_cycle: false,
+            cycle_state: None,
+            multigraph,
+            node_keys: NodeKeys::default(),
+            attributes: AttributeColumns::default(),
+            generation: 0,
+        },
+        subsets,
+    ))
+}
+
+/// Generate an undirected graph from a SMILES string
+///
+/// Every atom of the molecule is a node, with the text of the atom as its
+/// weight, and every bond is an edge, with the symbol of the bond as its
+/// weight. The subset of SMILES [1]_ supported is:
+///
+/// * Atoms of the organic subset ``B``, ``C``, ``N``, ``O``, ``P``, ``S``,
+///   ``F``, ``Cl``, ``Br`` and ``I``, their aromatic forms ``b``, ``c``,
+///   ``n``, ``o``, ``p`` and ``s``, the wildcard ``*``, and any atom in
+///   brackets such as ``[NH4+]``. The weight of a bracket atom is the text
+///   in the brackets, which isn't interpreted further.
+/// * The bonds ``-``, ``=``, ``#``, ``$`` and ``:``, and ``/`` and ``\``
+///   which are read as single bonds (``-``). A bond which isn't written is
+///   aromatic (``:``) between two aromatic atoms and single otherwise.
+/// * Branches in parentheses, ring bonds written as a digit or ``%``
+///   followed by two digits, and ``.`` to separate disconnected parts.
+///
+/// Hydrogen atoms which aren't written aren't added to the graph.
+///
+/// :param str smiles: The SMILES string to parse
+/// :param bool multigraph: When set to False the output
+///     :class:`~retworkx.PyGraph` object will not be not be a multigraph and
+///     won't allow parallel edges to be added. Instead
+///     calls which would create a parallel edge will update the existing edge.
+///
+/// :returns: The generated graph
+/// :rtype: PyGraph
+/// :raises ValueError: If the string isn't valid, the message gives the
+///     position of the error
+///
+/// .. jupyter-execute::
+///
+///   import retworkx.generators
+///   from retworkx.visualization import mpl_draw
+///
+///   # Aspirin
+///   graph = retworkx.generators.smiles_graph("CC(=O)Oc1ccccc1C(=O)O")
+///   mpl_draw(graph, with_labels=True, labels=str, edge_labels=str)
+///
+/// .. [1] OpenSMILES specification, http://opensmiles.org/opensmiles.html
+#[pyfunction(multigraph = true)]
+#[pyo3(text_signature = "(smiles, /, multigraph=True)")]
+pub fn smiles_graph(py: Python, smiles: &str, multigraph: bool) -> PyResult<graph::PyGraph> {
+    let graph: StablePyGraph<Undirected> = core_generators::smiles_graph(
+        smiles,
+        |atom| atom.into_py(py),
+        |bond| bond.symbol().to_string().into_py(py),
+    )
+    .map_err(|err| PyValueError::new_err(err.to_string()))?;
+    Ok(graph::PyGraph {
+        graph,
+        node_removed: false,
+        multigraph,
+        node_keys: NodeKeys::default(),
+        attributes: AttributeColumns::default(),
+        generation: 0,
     })
 }
 
@@ -2347,6 +2834,16 @@ pub fn generators(_py: Python, m: &PyModule) -> PyResult<()> {
     m.add_wrapped(wrap_pyfunction!(lollipop_graph))?;
     m.add_wrapped(wrap_pyfunction!(full_rary_tree))?;
     m.add_wrapped(wrap_pyfunction!(generalized_petersen_graph))?;
+    m.add_wrapped(wrap_pyfunction!(circulant_graph))?;
+    m.add_wrapped(wrap_pyfunction!(directed_circulant_graph))?;
+    m.add_wrapped(wrap_pyfunction!(hypercube_graph))?;
+    m.add_wrapped(wrap_pyfunction!(directed_hypercube_graph))?;
+    m.add_wrapped(wrap_pyfunction!(de_bruijn_graph))?;
     m.add_wrapped(wrap_pyfunction!(barbell_graph))?;
+    m.add_wrapped(wrap_pyfunction!(complete_multipartite_graph))?;
+    m.add_wrapped(wrap_pyfunction!(directed_complete_multipartite_graph))?;
+    m.add_wrapped(wrap_pyfunction!(turan_graph))?;
+    m.add_wrapped(wrap_pyfunction!(directed_turan_graph))?;
+    m.add_wrapped(wrap_pyfunction!(smiles_graph))?;
     Ok(())
 }
@@ -14,7 +14,8 @@ use std::cmp;
 use std::collections::BTreeMap;
 use std::fs::File;
 use std::io::prelude::*;
-use std::io::{BufReader, BufWriter};
+use std::io::BufWriter;
+use std::path::PathBuf;
 use std::str;
 
 use hashbrown::{HashMap, HashSet};
@@ -29,18 +30,25 @@ use pyo3::PyTraverseError;
 use pyo3::Python;
 
 use ndarray::prelude::*;
-use num_complex::Complex64;
+use num_complex::{Complex32, Complex64};
 use num_traits::Zero;
 use numpy::PyReadonlyArray2;
 
+use super::attributes::{self, AttributeColumns};
+use super::contraction::{self, MergeEdgePolicy};
 use super::dot_utils::build_dot;
-use super::iterators::{EdgeIndexMap, EdgeIndices, EdgeList, NodeIndices, WeightedEdgeList};
+use super::edge_list;
+use super::iterators::{
+    EdgeIndexMap, EdgeIndices, EdgeList, NodeIndices, NodeMap, WeightedEdgeList,
+};
+use super::node_keys::NodeKeys;
+use super::snapshot;
+use super::views::{view_filters, GraphView, ViewBase};
 use super::{
-    find_node_by_weight, merge_duplicates, weight_callable, IsNan, NoEdgeBetweenNodes,
-    NodesRemoved, StablePyGraph,
+    find_node_by_weight, graph_from_edge_arrays, merge_duplicates, weight_callable, ComplexMatrix,
+    IndexArray, IsNan, NoEdgeBetweenNodes, NodesRemoved, RealMatrix, StablePyGraph, WeightArray,
 };
 
-use petgraph::algo;
 use petgraph::graph::{EdgeIndex, NodeIndex};
 use petgraph::prelude::*;
 use petgraph::visit::{
@@ -96,6 +104,27 @@ use petgraph::visit::{
 ///     print("Node Index: %s" % node_index)
 ///     print(graph[node_index])
 ///
+/// A node can also be given a key, any hashable Python object such as an
+/// external id, with :meth:`~PyGraph.set_node_key`. Unlike node indices a
+/// key is never reused by another node: it's removed along with its node.
+/// :meth:`~PyGraph.find_node_by_key` and
+/// :meth:`~PyGraph.node_indices_by_keys` translate keys to the node indices
+/// taken by every other method and function. A new graph with the same node
+/// indices, such as the one returned by :meth:`~PyGraph.copy` or by unpickling,
+/// keeps the node keys, other new graphs don't have any:
+///
+/// .. jupyter-execute::
+///
+///     import retworkx
+///
+///     graph = retworkx.PyGraph()
+///     for name in ["a", "b", "c"]:
+///         graph.set_node_key(graph.add_node(name), name)
+///     graph.remove_node(graph.find_node_by_key("b"))
+///     graph.set_node_key(graph.add_node("d"), "d")
+///     print(graph.node_keys())
+///     print(graph.node_indices_by_keys(["d", "a"]))
+///
 /// By default a ``PyGraph`` is a multigraph (meaning there can be parallel
 /// edges between nodes) however this can be disabled by setting the
 /// ``multigraph`` kwarg to ``False`` when calling the ``PyGraph``
@@ -120,6 +149,11 @@ pub struct PyGraph {
     pub graph: StablePyGraph<Undirected>,
     pub node_removed: bool,
     pub multigraph: bool,
+    pub node_keys: NodeKeys,
+    pub attributes: AttributeColumns,
+    /// Incremented by every method which modifies the graph, so an object
+    /// holding a reference to it can tell if it changed
+    pub generation: usize,
 }
 
 impl GraphBase for PyGraph {
@@ -139,6 +173,34 @@ impl NodeCount for PyGraph {
     }
 }
 
+// Rust side only PyGraph methods
+impl PyGraph {
+    fn merge(
+        &mut self,
+        py: Python,
+        nodes: &IndexSet<NodeIndex, ahash::RandomState>,
+        payload: PyObject,
+        policy: MergeEdgePolicy,
+        weight_combo_fn: &Option<PyObject>,
+    ) -> PyResult<usize> {
+        let merged = contraction::merge_nodes(
+            py,
+            &mut self.graph,
+            &mut self.node_keys,
+            &mut self.attributes,
+            nodes,
+            payload,
+            policy,
+            weight_combo_fn,
+            self.multigraph,
+        )?;
+        if nodes.len() > 1 {
+            self.node_removed = true;
+        }
+        Ok(merged.index())
+    }
+}
+
 #[pymethods]
 impl PyGraph {
     #[new]
@@ -148,6 +210,9 @@ impl PyGraph {
             graph: StablePyGraph::<Undirected>::default(),
             node_removed: false,
             multigraph,
+            node_keys: NodeKeys::default(),
+            attributes: AttributeColumns::default(),
+            generation: 0,
         }
     }
 
@@ -158,6 +223,17 @@ impl PyGraph {
         out_dict.set_item("nodes", node_dict)?;
         out_dict.set_item("nodes_removed", self.node_removed)?;
         out_dict.set_item("multigraph", self.multigraph)?;
+        let keys_dict = PyDict::new(py);
+        for node_index in self.graph.node_indices() {
+            if let Some(key) = self.node_keys.get(node_index) {
+                keys_dict.set_item(node_index.index(), key)?;
+            }
+        }
+        out_dict.set_item("node_keys", keys_dict)?;
+        let node_indices: Vec<usize> = self.graph.node_indices().map(|n| n.index()).collect();
+        let edge_indices: Vec<usize> = self.graph.edge_indices().map(|e| e.index()).collect();
+        self.attributes
+            .get_state(py, out_dict, &node_indices, &edge_indices)?;
         for node_index in self.graph.node_indices() {
             let node_data = self.graph.node_weight(node_index).unwrap();
             node_dict.set_item(node_index.index(), node_data)?;
@@ -175,7 +251,10 @@ impl PyGraph {
     }
 
     fn __setstate__(&mut self, py: Python, state: PyObject) -> PyResult<()> {
+        self.generation += 1;
         self.graph = StablePyGraph::<Undirected>::default();
+        self.node_keys = NodeKeys::default();
+        self.attributes = AttributeColumns::default();
         let dict_state = state.cast_as::<PyDict>(py)?;
         let nodes_dict = dict_state.get_item("nodes").unwrap().downcast::<PyDict>()?;
         let edges_list = dict_state.get_item("edges").unwrap().downcast::<PyList>()?;
@@ -196,7 +275,7 @@ impl PyGraph {
             node_indices.push(tmp_index.extract()?);
         }
         if node_indices.is_empty() {
-            return Ok(());
+            return self.attributes.set_state(dict_state, &[], &[]);
         }
         let max_index: usize = *node_indices.iter().max().unwrap();
         let mut tmp_nodes: Vec<NodeIndex> = Vec::new();
@@ -216,6 +295,7 @@ impl PyGraph {
         for tmp_node in tmp_nodes {
             self.graph.remove_node(tmp_node);
         }
+        let mut edge_indices: Vec<usize> = Vec::with_capacity(edges_list.len());
         for raw_edge in edges_list.iter() {
             let edge = raw_edge.downcast::<PyTuple>()?;
             let raw_p_index = edge.get_item(0)?.downcast::<PyLong>()?;
@@ -226,9 +306,19 @@ impl PyGraph {
             let c_index = NodeIndex::new(child);
             let edge_data = edge.get_item(2)?;
 
-            self.graph.add_edge(p_index, c_index, edge_data.into());
+            let edge_index = self.graph.add_edge(p_index, c_index, edge_data.into());
+            edge_indices.push(edge_index.index());
         }
-        Ok(())
+        // Pickles from before node keys were added don't have any
+        if let Some(keys_dict) = dict_state.get_item("node_keys") {
+            for (node, key) in keys_dict.downcast::<PyDict>()?.iter() {
+                self.node_keys
+                    .insert(py, NodeIndex::new(node.extract()?), key.into())?;
+            }
+        }
+        let node_indices: Vec<usize> = self.graph.node_indices().map(|n| n.index()).collect();
+        self.attributes
+            .set_state(dict_state, &node_indices, &edge_indices)
     }
 
     /// Whether the graph is a multigraph (allows multiple edges between
@@ -535,6 +625,7 @@ impl PyGraph {
     /// :raises NoEdgeBetweenNodes: When there is no edge between nodes
     #[pyo3(text_signature = "(self, source, target, edge /)")]
     pub fn update_edge(&mut self, source: usize, target: usize, edge: PyObject) -> PyResult<()> {
+        self.generation += 1;
         let index_a = NodeIndex::new(source);
         let index_b = NodeIndex::new(target);
         let edge_index = match self.graph.find_edge(index_a, index_b) {
@@ -555,6 +646,7 @@ impl PyGraph {
     ///     index
     #[pyo3(text_signature = "(self, edge_index, edge, /)")]
     pub fn update_edge_by_index(&mut self, edge_index: usize, edge: PyObject) -> PyResult<()> {
+        self.generation += 1;
         match self.graph.edge_weight_mut(EdgeIndex::new(edge_index)) {
             Some(data) => *data = edge,
             None => return Err(PyIndexError::new_err("No edge found for index")),
@@ -681,8 +773,11 @@ impl PyGraph {
     ///     have no effect.
     #[pyo3(text_signature = "(self, node, /)")]
     pub fn remove_node(&mut self, node: usize) -> PyResult<()> {
+        self.generation += 1;
         let index = NodeIndex::new(node);
+        self.attributes.remove_node(&self.graph, index);
         self.graph.remove_node(index);
+        self.node_keys.remove(index);
         self.node_removed = true;
         Ok(())
     }
@@ -703,6 +798,7 @@ impl PyGraph {
     /// :rtype: int
     #[pyo3(text_signature = "(self, node_a, node_b, edge, /)")]
     pub fn add_edge(&mut self, node_a: usize, node_b: usize, edge: PyObject) -> PyResult<usize> {
+        self.generation += 1;
         let p_index = NodeIndex::new(node_a);
         let c_index = NodeIndex::new(node_b);
         if !self.multigraph {
@@ -737,6 +833,7 @@ impl PyGraph {
         &mut self,
         obj_list: Vec<(usize, usize, PyObject)>,
     ) -> PyResult<Vec<usize>> {
+        self.generation += 1;
         let mut out_list: Vec<usize> = Vec::with_capacity(obj_list.len());
         for obj in obj_list {
             let p_index = NodeIndex::new(obj.0);
@@ -776,6 +873,7 @@ impl PyGraph {
         py: Python,
         obj_list: Vec<(usize, usize)>,
     ) -> PyResult<Vec<usize>> {
+        self.generation += 1;
         let mut out_list: Vec<usize> = Vec::with_capacity(obj_list.len());
         for obj in obj_list {
             let p_index = NodeIndex::new(obj.0);
@@ -810,6 +908,7 @@ impl PyGraph {
     ///     weight of ``None``) to that index.
     #[pyo3(text_signature = "(self, edge_list, /)")]
     pub fn extend_from_edge_list(&mut self, py: Python, edge_list: Vec<(usize, usize)>) {
+        self.generation += 1;
         for (source, target) in edge_list {
             let max_index = cmp::max(source, target);
             while max_index >= self.node_count() {
@@ -850,6 +949,7 @@ impl PyGraph {
         py: Python,
         edge_list: Vec<(usize, usize, PyObject)>,
     ) {
+        self.generation += 1;
         for (source, target, weight) in edge_list {
             let max_index = cmp::max(source, target);
             while max_index >= self.node_count() {
@@ -881,6 +981,7 @@ impl PyGraph {
     ///     specified
     #[pyo3(text_signature = "(self, node_a, node_b, /)")]
     pub fn remove_edge(&mut self, node_a: usize, node_b: usize) -> PyResult<()> {
+        self.generation += 1;
         let p_index = NodeIndex::new(node_a);
         let c_index = NodeIndex::new(node_b);
         let edge_index = match self.graph.find_edge(p_index, c_index) {
@@ -888,6 +989,7 @@ impl PyGraph {
             None => return Err(NoEdgeBetweenNodes::new_err("No edge found between nodes")),
         };
         self.graph.remove_edge(edge_index);
+        self.attributes.remove_edge(edge_index);
         Ok(())
     }
 
@@ -896,8 +998,10 @@ impl PyGraph {
     /// :param int edge: The index of the edge to remove
     #[pyo3(text_signature = "(self, edge, /)")]
     pub fn remove_edge_from_index(&mut self, edge: usize) -> PyResult<()> {
+        self.generation += 1;
         let edge_index = EdgeIndex::new(edge);
         self.graph.remove_edge(edge_index);
+        self.attributes.remove_edge(edge_index);
         Ok(())
     }
 
@@ -913,6 +1017,7 @@ impl PyGraph {
     ///     pair of nodes.
     #[pyo3(text_signature = "(self, index_list, /)")]
     pub fn remove_edges_from(&mut self, index_list: Vec<(usize, usize)>) -> PyResult<()> {
+        self.generation += 1;
         for (p_index, c_index) in index_list
             .iter()
             .map(|(x, y)| (NodeIndex::new(*x), NodeIndex::new(*y)))
@@ -922,6 +1027,7 @@ impl PyGraph {
                 None => return Err(NoEdgeBetweenNodes::new_err("No edge found between nodes")),
             };
             self.graph.remove_edge(edge_index);
+            self.attributes.remove_edge(edge_index);
         }
         Ok(())
     }
@@ -934,6 +1040,7 @@ impl PyGraph {
     /// :rtype: int
     #[pyo3(text_signature = "(self, obj, /)")]
     pub fn add_node(&mut self, obj: PyObject) -> PyResult<usize> {
+        self.generation += 1;
         let index = self.graph.add_node(obj);
         Ok(index.index())
     }
@@ -946,6 +1053,7 @@ impl PyGraph {
     /// :rtype: NodeIndices
     #[pyo3(text_signature = "(self, obj_list, /)")]
     pub fn add_nodes_from(&mut self, obj_list: Vec<PyObject>) -> NodeIndices {
+        self.generation += 1;
         let out_list: Vec<usize> = obj_list
             .into_iter()
             .map(|obj| self.graph.add_node(obj).index())
@@ -962,12 +1070,86 @@ impl PyGraph {
     ///     the graph
     #[pyo3(text_signature = "(self, index_list, /)")]
     pub fn remove_nodes_from(&mut self, index_list: Vec<usize>) -> PyResult<()> {
+        self.generation += 1;
         for node in index_list.iter().map(|x| NodeIndex::new(*x)) {
+            self.attributes.remove_node(&self.graph, node);
             self.graph.remove_node(node);
+            self.node_keys.remove(node);
         }
+        self.node_removed = true;
         Ok(())
     }
 
+    /// Remove every node from the graph whose data payload doesn't pass a
+    /// filter.
+    ///
+    /// This calls ``predicate`` on every node and then removes all the
+    /// rejected nodes, and their edges, in a single pass. If ``predicate``
+    /// raises an exception the graph is left unchanged.
+    ///
+    /// :param predicate: A callable which takes the data payload/weight of a
+    ///     node and returns ``True`` for the nodes to keep and ``False`` for
+    ///     the nodes to remove.
+    ///
+    /// :returns: The indices of the removed nodes
+    /// :rtype: NodeIndices
+    #[pyo3(text_signature = "(self, predicate, /)")]
+    pub fn filter_nodes(&mut self, py: Python, predicate: PyObject) -> PyResult<NodeIndices> {
+        self.generation += 1;
+        let mut removed = Vec::new();
+        for node in self.graph.node_indices() {
+            if !predicate
+                .call1(py, (&self.graph[node],))?
+                .extract::<bool>(py)?
+            {
+                removed.push(node);
+            }
+        }
+        for node in removed.iter() {
+            self.attributes.remove_node(&self.graph, *node);
+            self.graph.remove_node(*node);
+            self.node_keys.remove(*node);
+        }
+        if !removed.is_empty() {
+            self.node_removed = true;
+        }
+        Ok(NodeIndices {
+            nodes: removed.into_iter().map(|node| node.index()).collect(),
+        })
+    }
+
+    /// Compact the node and edge indices of the graph.
+    ///
+    /// Removing nodes and edges leaves holes in the indices of a graph, which
+    /// are only filled by nodes and edges added later. This renumbers the
+    /// nodes from ``0`` to ``num_nodes() - 1`` and the edges from ``0`` to
+    /// ``num_edges() - 1``, keeping their relative order, along with their
+    /// keys and attributes. Any index of a node or an edge held outside of
+    /// the graph, including by a view of it, refers to a different node or
+    /// edge after this call.
+    ///
+    /// :returns: A mapping of the old index of every node to its new index
+    /// :rtype: NodeMap
+    #[pyo3(text_signature = "(self)")]
+    pub fn compact(&mut self, py: Python) -> PyResult<NodeMap> {
+        self.generation += 1;
+        let nodes: Vec<usize> = self.graph.node_indices().map(|node| node.index()).collect();
+        let edges: Vec<usize> = self.graph.edge_indices().map(|edge| edge.index()).collect();
+        let graph = std::mem::take(&mut self.graph);
+        // Converting to a Graph drops the holes and keeps the relative order
+        self.graph = StableGraph::from(Graph::from(graph));
+        let node_map: DictMap<usize, usize> = nodes
+            .iter()
+            .enumerate()
+            .map(|(new, old)| (*old, new))
+            .collect();
+        self.node_keys
+            .reindex(py, |node| NodeIndex::new(node_map[&node.index()]))?;
+        self.attributes.compact(&nodes, &edges);
+        self.node_removed = false;
+        Ok(NodeMap { node_map })
+    }
+
     /// Find node within this graph given a specific weight
     ///
     /// This algorithm has a worst case of O(n) since it searches the node
@@ -984,6 +1166,255 @@ impl PyGraph {
         find_node_by_weight(py, &self.graph, &obj).map(|node| node.map(|x| x.index()))
     }
 
+    /// Set the key of a node.
+    ///
+    /// A key is a hashable Python object, such as an external id, identifying
+    /// a node. Every node has at most one key and a key belongs to at most
+    /// one node. The key is removed along with the node, so it never refers
+    /// to a later node which reuses the node index.
+    ///
+    /// :param int node: The index of the node
+    /// :param key: The new key of the node, replacing its previous key
+    ///
+    /// :raises IndexError: If ``node`` is not in the graph
+    /// :raises ValueError: If ``key`` is the key of another node
+    /// :raises TypeError: If ``key`` is not hashable
+    #[pyo3(text_signature = "(self, node, key, /)")]
+    pub fn set_node_key(&mut self, py: Python, node: usize, key: PyObject) -> PyResult<()> {
+        self.generation += 1;
+        let index = NodeIndex::new(node);
+        if !self.graph.contains_node(index) {
+            return Err(PyIndexError::new_err("No node found for index"));
+        }
+        self.node_keys.insert(py, index, key)
+    }
+
+    /// Get the key of a node.
+    ///
+    /// :param int node: The index of the node
+    ///
+    /// :returns: The key of the node set with
+    ///     :meth:`~PyGraph.set_node_key`, or ``None`` if it has no key
+    #[pyo3(text_signature = "(self, node, /)")]
+    pub fn node_key(&self, py: Python, node: usize) -> Option<PyObject> {
+        self.node_keys
+            .get(NodeIndex::new(node))
+            .map(|key| key.clone_ref(py))
+    }
+
+    /// Remove the key of a node.
+    ///
+    /// :param int node: The index of the node
+    ///
+    /// :returns: The removed key, or ``None`` if the node has no key
+    #[pyo3(text_signature = "(self, node, /)")]
+    pub fn remove_node_key(&mut self, node: usize) -> Option<PyObject> {
+        self.generation += 1;
+        self.node_keys.remove(NodeIndex::new(node))
+    }
+
+    /// Find the node with a key
+    ///
+    /// This is a constant time lookup in the mapping of keys to node indices
+    /// kept by the graph.
+    ///
+    /// :param key: The key to look for
+    ///
+    /// :returns: The index of the node with the key, or ``None`` if no node
+    ///     has the key
+    /// :rtype: int
+    ///
+    /// :raises TypeError: If ``key`` is not hashable
+    #[pyo3(text_signature = "(self, key, /)")]
+    pub fn find_node_by_key(&self, py: Python, key: &PyAny) -> PyResult<Option<usize>> {
+        Ok(self.node_keys.find(py, key)?.map(|node| node.index()))
+    }
+
+    /// Get the node indices of the nodes with the given keys
+    ///
+    /// Use this to pass nodes identified by their keys to any method or
+    /// function taking node indices.
+    ///
+    /// :param keys: An iterable of keys
+    ///
+    /// :returns: The node indices of the nodes with the keys, in the order
+    ///     of ``keys``
+    /// :rtype: NodeIndices
+    ///
+    /// :raises KeyError: If no node has one of the keys
+    #[pyo3(text_signature = "(self, keys, /)")]
+    pub fn node_indices_by_keys(&self, py: Python, keys: &PyAny) -> PyResult<NodeIndices> {
+        Ok(NodeIndices {
+            nodes: self.node_keys.find_all(py, keys)?,
+        })
+    }
+
+    /// Get the keys of the nodes
+    ///
+    /// :returns: A new dictionary mapping every key to the index of its node
+    /// :rtype: dict
+    #[pyo3(text_signature = "(self)")]
+    pub fn node_keys(&self, py: Python) -> PyResult<PyObject> {
+        self.node_keys.to_dict(py)
+    }
+
+    /// Set the values of a node attribute column
+    ///
+    /// Attribute columns store typed values for the nodes of the graph next
+    /// to their weight/data payloads, keyed by attribute name. They're set
+    /// and read in bulk with numpy arrays, which avoids creating a Python
+    /// object for every value. A node without a value of an attribute, for
+    /// example a node added after the attribute was set, reads as ``nan``,
+    /// ``0`` or ``False`` depending on the type of the attribute. The values
+    /// of a node are cleared when it's removed.
+    ///
+    /// :param str name: The name of the attribute
+    /// :param numpy.ndarray values: A 1D array of ``float64``, ``int64`` or
+    ///     ``bool`` values, one for every node of ``nodes``
+    /// :param list nodes: An optional list of the node indices to set the
+    ///     values of. If not specified ``values`` must have a value for every
+    ///     node of the graph, in the order of
+    ///     :meth:`~PyGraph.node_indices`, and replaces any existing values
+    ///     of the attribute.
+    ///
+    /// :raises TypeError: If ``values`` isn't an array of a supported type, or
+    ///     if ``nodes`` is specified and the type of ``values`` isn't the type
+    ///     of the existing attribute
+    /// :raises ValueError: If there isn't one value for every node
+    /// :raises InvalidNode: If a node of ``nodes`` isn't in the graph
+    #[pyo3(text_signature = "(self, name, values, /, nodes=None)")]
+    pub fn set_node_attribute(
+        &mut self,
+        name: String,
+        values: &PyAny,
+        nodes: Option<Vec<usize>>,
+    ) -> PyResult<()> {
+        self.generation += 1;
+        let replace = nodes.is_none();
+        let nodes = attributes::node_positions(&self.graph, nodes)?;
+        self.attributes.nodes.set(name, values, &nodes, replace)
+    }
+
+    /// Get the values of a node attribute column
+    ///
+    /// :param str name: The name of the attribute
+    /// :param list nodes: An optional list of the node indices to get the
+    ///     values of. If not specified the values of every node are returned,
+    ///     in the order of :meth:`~PyGraph.node_indices`.
+    ///
+    /// :returns: A new 1D array with the values of the nodes
+    /// :rtype: numpy.ndarray
+    ///
+    /// :raises KeyError: If the graph has no node attribute ``name``
+    /// :raises InvalidNode: If a node of ``nodes`` isn't in the graph
+    #[pyo3(text_signature = "(self, name, /, nodes=None)")]
+    pub fn get_node_attribute(
+        &self,
+        py: Python,
+        name: &str,
+        nodes: Option<Vec<usize>>,
+    ) -> PyResult<PyObject> {
+        let nodes = attributes::node_positions(&self.graph, nodes)?;
+        self.attributes.nodes.take(py, name, &nodes)
+    }
+
+    /// Get the names of the node attribute columns
+    ///
+    /// :returns: The names of the node attributes, in the order they were
+    ///     first set
+    /// :rtype: list
+    #[pyo3(text_signature = "(self)")]
+    pub fn node_attributes(&self) -> Vec<String> {
+        self.attributes.nodes.names()
+    }
+
+    /// Remove a node attribute column
+    ///
+    /// :param str name: The name of the attribute
+    ///
+    /// :raises KeyError: If the graph has no node attribute ``name``
+    #[pyo3(text_signature = "(self, name, /)")]
+    pub fn remove_node_attribute(&mut self, name: &str) -> PyResult<()> {
+        self.generation += 1;
+        self.attributes.nodes.remove(name)
+    }
+
+    /// Set the values of an edge attribute column
+    ///
+    /// This works like :meth:`~PyGraph.set_node_attribute` for the edges of
+    /// the graph, the values of an edge are cleared when it's removed.
+    ///
+    /// :param str name: The name of the attribute
+    /// :param numpy.ndarray values: A 1D array of ``float64``, ``int64`` or
+    ///     ``bool`` values, one for every edge of ``edges``
+    /// :param list edges: An optional list of the edge indices to set the
+    ///     values of. If not specified ``values`` must have a value for every
+    ///     edge of the graph, in the order of
+    ///     :meth:`~PyGraph.edge_indices`, and replaces any existing values
+    ///     of the attribute.
+    ///
+    /// :raises TypeError: If ``values`` isn't an array of a supported type, or
+    ///     if ``edges`` is specified and the type of ``values`` isn't the type
+    ///     of the existing attribute
+    /// :raises ValueError: If there isn't one value for every edge
+    /// :raises IndexError: If an edge of ``edges`` isn't in the graph
+    #[pyo3(text_signature = "(self, name, values, /, edges=None)")]
+    pub fn set_edge_attribute(
+        &mut self,
+        name: String,
+        values: &PyAny,
+        edges: Option<Vec<usize>>,
+    ) -> PyResult<()> {
+        self.generation += 1;
+        let replace = edges.is_none();
+        let edges = attributes::edge_positions(&self.graph, edges)?;
+        self.attributes.edges.set(name, values, &edges, replace)
+    }
+
+    /// Get the values of an edge attribute column
+    ///
+    /// :param str name: The name of the attribute
+    /// :param list edges: An optional list of the edge indices to get the
+    ///     values of. If not specified the values of every edge are returned,
+    ///     in the order of :meth:`~PyGraph.edge_indices`.
+    ///
+    /// :returns: A new 1D array with the values of the edges
+    /// :rtype: numpy.ndarray
+    ///
+    /// :raises KeyError: If the graph has no edge attribute ``name``
+    /// :raises IndexError: If an edge of ``edges`` isn't in the graph
+    #[pyo3(text_signature = "(self, name, /, edges=None)")]
+    pub fn get_edge_attribute(
+        &self,
+        py: Python,
+        name: &str,
+        edges: Option<Vec<usize>>,
+    ) -> PyResult<PyObject> {
+        let edges = attributes::edge_positions(&self.graph, edges)?;
+        self.attributes.edges.take(py, name, &edges)
+    }
+
+    /// Get the names of the edge attribute columns
+    ///
+    /// :returns: The names of the edge attributes, in the order they were
+    ///     first set
+    /// :rtype: list
+    #[pyo3(text_signature = "(self)")]
+    pub fn edge_attributes(&self) -> Vec<String> {
+        self.attributes.edges.names()
+    }
+
+    /// Remove an edge attribute column
+    ///
+    /// :param str name: The name of the attribute
+    ///
+    /// :raises KeyError: If the graph has no edge attribute ``name``
+    #[pyo3(text_signature = "(self, name, /)")]
+    pub fn remove_edge_attribute(&mut self, name: &str) -> PyResult<()> {
+        self.generation += 1;
+        self.attributes.edges.remove(name)
+    }
+
     /// Get the index and data for the neighbors of a node.
     ///
     /// This will return a dictionary where the keys are the node indices of
@@ -1083,9 +1514,12 @@ impl PyGraph {
         crate::digraph::PyDiGraph {
             graph: new_graph,
             node_removed: false,
-            cycle_state: algo::DfsSpace::default(),
+            cycle_state: None,
             check_cycle: false,
             multigraph: self.multigraph,
+            node_keys: NodeKeys::default(),
+            attributes: AttributeColumns::default(),
+            generation: 0,
         }
     }
 
@@ -1173,7 +1607,10 @@ impl PyGraph {
     ///
     /// The expected format for the edge list file is a line seperated list
     /// of deliminated node ids. If there are more than 3 elements on
-    /// a line the 3rd on will be treated as a string weight for the edge
+    /// a line the 3rd on will be treated as a string weight for the edge,
+    /// unless ``weight_column`` is set. Large files are parsed in parallel,
+    /// the environment variable ``RAYON_NUM_THREADS`` can be used to adjust
+    /// how many threads will be used.
     ///
     /// :param str path: The path of the file to open
     /// :param str comment: Optional character to use as a comment by default
@@ -1183,6 +1620,11 @@ impl PyGraph {
     /// :param bool labels: If set to ``True`` the first two separated fields
     ///     will be treated as string labels uniquely identifying a node
     ///     instead of node indices.
+    /// :param int weight_column: Optional index of the field, counting
+    ///     from 0 for the source node, to parse as a ``float`` weight for
+    ///     the edge. Other fields after the node ids are ignored. A
+    ///     ``ValueError`` is raised if a line doesn't have a numeric value
+    ///     in this field.
     ///
     /// For example:
     ///
@@ -1206,86 +1648,32 @@ impl PyGraph {
     ///
     #[staticmethod]
     #[args(labels = "false")]
-    #[pyo3(text_signature = "(path, /, comment=None, deliminator=None, labels=False)")]
+    #[pyo3(
+        text_signature = "(path, /, comment=None, deliminator=None, labels=False, weight_column=None)"
+    )]
     pub fn read_edge_list(
         py: Python,
         path: &str,
         comment: Option<String>,
         deliminator: Option<String>,
         labels: bool,
+        weight_column: Option<usize>,
     ) -> PyResult<PyGraph> {
-        let file = File::open(path)?;
-        let buf_reader = BufReader::new(file);
-        let mut out_graph = StablePyGraph::<Undirected>::default();
-        let mut label_map: HashMap<String, usize> = HashMap::new();
-        for line_raw in buf_reader.lines() {
-            let line = line_raw?;
-            let skip = match &comment {
-                Some(comm) => line.trim().starts_with(comm),
-                None => line.trim().is_empty(),
-            };
-            if skip {
-                continue;
-            }
-            let line_no_comments = match &comment {
-                Some(comm) => line
-                    .find(comm)
-                    .map(|idx| &line[..idx])
-                    .unwrap_or(&line)
-                    .trim()
-                    .to_string(),
-                None => line,
-            };
-            let pieces: Vec<&str> = match &deliminator {
-                Some(del) => line_no_comments.split(del).collect(),
-                None => line_no_comments.split_whitespace().collect(),
-            };
-            let src: usize;
-            let target: usize;
-            if labels {
-                let src_str = pieces[0];
-                let target_str = pieces[1];
-                src = match label_map.get(src_str) {
-                    Some(index) => *index,
-                    None => {
-                        let index = out_graph.add_node(src_str.to_object(py)).index();
-                        label_map.insert(src_str.to_string(), index);
-                        index
-                    }
-                };
-                target = match label_map.get(target_str) {
-                    Some(index) => *index,
-                    None => {
-                        let index = out_graph.add_node(target_str.to_object(py)).index();
-                        label_map.insert(target_str.to_string(), index);
-                        index
-                    }
-                };
-            } else {
-                src = pieces[0].parse::<usize>()?;
-                target = pieces[1].parse::<usize>()?;
-                let max_index = cmp::max(src, target);
-                // Add nodes to graph
-                while max_index >= out_graph.node_count() {
-                    out_graph.add_node(py.None());
-                }
-            }
-            // Add edges tp graph
-            let weight = if pieces.len() > 2 {
-                let weight_str = match &deliminator {
-                    Some(del) => pieces[2..].join(del),
-                    None => pieces[2..].join(&' '.to_string()),
-                };
-                PyString::new(py, &weight_str).into()
-            } else {
-                py.None()
-            };
-            out_graph.add_edge(NodeIndex::new(src), NodeIndex::new(target), weight);
-        }
+        let format = edge_list::EdgeListFormat {
+            comment: comment.as_deref(),
+            deliminator: deliminator.as_deref(),
+            labels,
+            weight_column,
+            first_index: 0,
+        };
+        let out_graph = edge_list::read_edge_list::<Undirected>(py, path, &format)?;
         Ok(PyGraph {
             graph: out_graph,
             node_removed: false,
             multigraph: true,
+            node_keys: NodeKeys::default(),
+            attributes: AttributeColumns::default(),
+            generation: 0,
         })
     }
 
@@ -1350,6 +1738,93 @@ impl PyGraph {
         Ok(())
     }
 
+    /// Write a binary snapshot of the graph to a file
+    ///
+    /// A snapshot is a compact binary format which is much faster to load
+    /// than a pickle of a large graph. The structure of the graph is stored
+    /// natively and the payloads of all the nodes and of all the edges are
+    /// stored as 2 lists serialized with ``serializer``, calling it twice
+    /// rather than once per payload. The node indices, node keys and
+    /// attribute columns are kept. Like pickling, the edge indices are
+    /// renumbered in order when the snapshot is read.
+    ///
+    /// To reject corrupt files, a snapshot can't be read if it has more than
+    /// 16 removed node indices per node (and more than 65536 in total), use
+    /// :meth:`~PyGraph.compact` first on a graph with that many removed nodes.
+    ///
+    /// :param path: The path of the file to write the snapshot to
+    /// :param serializer: An optional object with ``dumps`` and ``loads``
+    ///     functions, like the :mod:`pickle` module which is used by default,
+    ///     to serialize lists of payloads to ``bytes``. The same serializer
+    ///     must be passed to :meth:`~retworkx.PyGraph.read_snapshot`.
+    ///
+    /// For example:
+    ///
+    /// .. jupyter-execute::
+    ///
+    ///   import os
+    ///   import tempfile
+    ///
+    ///   import retworkx
+    ///
+    ///   graph = retworkx.generators.path_graph(5)
+    ///   path = os.path.join(tempfile.gettempdir(), "snapshot.rx")
+    ///   graph.write_snapshot(path)
+    ///   new_graph = retworkx.PyGraph.read_snapshot(path)
+    ///   print(new_graph.edge_list())
+    #[pyo3(text_signature = "(self, path, /, serializer=None)")]
+    pub fn write_snapshot(
+        &self,
+        py: Python,
+        path: PathBuf,
+        serializer: Option<PyObject>,
+    ) -> PyResult<()> {
+        snapshot::write_snapshot(
+            py,
+            &self.graph,
+            self.multigraph,
+            self.node_removed,
+            false,
+            &self.node_keys,
+            &self.attributes,
+            path,
+            serializer,
+        )
+    }
+
+    /// Read a graph from a binary snapshot file
+    ///
+    /// This reads a snapshot written by :meth:`~retworkx.PyGraph.write_snapshot`.
+    ///
+    /// :param path: The path of the snapshot file
+    /// :param serializer: An optional object with a ``loads`` function used
+    ///     to deserialize the payloads, this must match the serializer used
+    ///     to write the snapshot. By default :mod:`pickle` is used.
+    ///
+    /// :returns: The graph stored in the snapshot
+    /// :rtype: PyGraph
+    ///
+    /// :raises ValueError: If the file isn't a valid snapshot or it's a
+    ///     snapshot of a directed graph
+    /// :raises OSError: If the file can't be read
+    #[staticmethod]
+    #[pyo3(text_signature = "(path, /, serializer=None)")]
+    pub fn read_snapshot(
+        py: Python,
+        path: PathBuf,
+        serializer: Option<PyObject>,
+    ) -> PyResult<PyGraph> {
+        let snapshot = snapshot::read_snapshot::<Undirected>(py, path, serializer)?;
+        Ok(PyGraph {
+            graph: snapshot.graph,
+            node_removed: snapshot.node_removed,
+            multigraph: snapshot.multigraph,
+            node_keys: snapshot.node_keys,
+            attributes: snapshot.attributes,
+            generation: 0,
+        })
+    }
+
     /// Create a new :class:`~retworkx.PyGraph` object from an adjacency matrix
     /// with matrix elements of type ``float``
     ///
@@ -1361,15 +1836,16 @@ impl PyGraph {
     /// This differs from the
     /// :meth:`~retworkx.PyGraph.from_complex_adjacency_matrix` in that the
     /// type of the elements of input matrix must be a ``float`` (specifically
-    /// a ``numpy.float64``) and the output graph edge weights will be ``float``
-    /// too. While in :meth:`~retworkx.PyGraph.from_complex_adjacency_matrix`
+    /// a ``numpy.float64`` or ``numpy.float32``) and the output graph edge
+    /// weights will be ``float`` too. While in :meth:`~retworkx.PyGraph.from_complex_adjacency_matrix`
     /// the matrix elements are of type ``complex`` (specifically
-    /// ``numpy.complex128``) and the edge weights in the output graph will be
-    /// ``complex`` too.
+    /// ``numpy.complex128`` or ``numpy.complex64``) and the edge weights in
+    /// the output graph will be ``complex`` too.
     ///
     /// :param ndarray matrix: The input numpy array adjacency matrix to create
     ///     a new :class:`~retworkx.PyGraph` object from. It must be a 2
-    ///     dimensional array and be a ``float``/``np.float64`` data type.
+    ///     dimensional array and be a ``float``/``np.float64`` or
+    ///     ``np.float32`` data type.
     /// :param float null_value: An optional float that will treated as a null
     ///     value. If any element in the input matrix is this value it will be
     ///     treated as not an edge. By default this is ``0.0``.
@@ -1381,10 +1857,13 @@ impl PyGraph {
     #[pyo3(text_signature = "(matrix, /, null_value=0.0)")]
     pub fn from_adjacency_matrix<'p>(
         py: Python<'p>,
-        matrix: PyReadonlyArray2<'p, f64>,
+        matrix: RealMatrix<'p>,
         null_value: f64,
     ) -> PyGraph {
-        _from_adjacency_matrix(py, matrix, null_value)
+        match matrix {
+            RealMatrix::Float64(matrix) => _from_adjacency_matrix(py, matrix, null_value),
+            RealMatrix::Float32(matrix) => _from_adjacency_matrix(py, matrix, null_value as f32),
+        }
     }
 
     /// Create a new :class:`~retworkx.PyGraph` object from an adjacency matrix
@@ -1398,15 +1877,17 @@ impl PyGraph {
     /// This differs from the
     /// :meth:`~retworkx.PyGraph.from_adjacency_matrix` in that the type of
     /// the elements of the input matrix in this method must be a ``complex``
-    /// (specifically a ``numpy.complex128``) and the output graph edge weights
-    /// will be ``complex`` too. While in
+    /// (specifically a ``numpy.complex128`` or ``numpy.complex64``) and the
+    /// output graph edge weights will be ``complex`` too. While in
     /// :meth:`~retworkx.PyGraph.from_adjacency_matrix` the matrix elements
-    /// are of type ``float`` (specifically ``numpy.float64``) and the edge
-    /// weights in the output graph will be ``float`` too.
+    /// are of type ``float`` (specifically ``numpy.float64`` or
+    /// ``numpy.float32``) and the edge weights in the output graph will be
+    /// ``float`` too.
     ///
     /// :param ndarray matrix: The input numpy array adjacency matrix to create
     ///     a new :class:`~retworkx.PyGraph` object from. It must be a 2
-    ///     dimensional array and be a ``complex``/``np.complex128`` data type.
+    ///     dimensional array and be a ``complex``/``np.complex128`` or
+    ///     ``np.complex64`` data type.
     /// :param float null_value: An optional complex that will treated as a null
     ///     value. If any element in the input matrix is this value it will be
     ///     treated as not an edge. By default this is ``0.0+0.0j``
@@ -1418,10 +1899,78 @@ impl PyGraph {
     #[pyo3(text_signature = "(matrix, /, null_value=0.0+0.0j)")]
     pub fn from_complex_adjacency_matrix<'p>(
         py: Python<'p>,
-        matrix: PyReadonlyArray2<'p, Complex64>,
+        matrix: ComplexMatrix<'p>,
         null_value: Complex64,
     ) -> PyGraph {
-        _from_adjacency_matrix(py, matrix, null_value)
+        match matrix {
+            ComplexMatrix::Complex128(matrix) => _from_adjacency_matrix(py, matrix, null_value),
+            ComplexMatrix::Complex64(matrix) => _from_adjacency_matrix(
+                py,
+                matrix,
+                Complex32::new(null_value.re as f32, null_value.im as f32),
+            ),
+        }
+    }
+
+    /// Create a new :class:`~retworkx.PyGraph` object from arrays of edges
+    ///
+    /// This is a fast way to build a large graph: the ``sources``,
+    /// ``targets`` and ``weights`` numpy arrays are read directly, without
+    /// creating a Python tuple for every edge like
+    /// :meth:`~retworkx.PyGraph.add_edges_from` needs. There is a node for
+    /// every index up to the largest node index in ``sources`` and
+    /// ``targets``, or up to ``num_nodes - 1``, and the payload of every node
+    /// is its index. Edge ``i`` goes from node ``sources[i]`` to node
+    /// ``targets[i]`` and has the payload ``weights[i]``, or ``None`` without
+    /// ``weights``. The edge indices are the positions in the arrays.
+    /// The graph is a multigraph, so repeated pairs of nodes are parallel
+    /// edges.
+    ///
+    /// For example::
+    ///
+    ///     import numpy as np
+    ///     import retworkx
+    ///
+    ///     sources = np.array([0, 1, 2])
+    ///     targets = np.array([1, 2, 0])
+    ///     graph = retworkx.PyGraph.from_edge_arrays(
+    ///         sources, targets, weights=np.array([0.5, 1.0, 2.0])
+    ///     )
+    ///
+    /// :param ndarray sources: A 1 dimensional array of the node indices of
+    ///     the sources of the edges, with an ``int64``, ``int32``, ``uint64``
+    ///     or ``uint32`` data type
+    /// :param ndarray targets: A 1 dimensional array of the node indices of
+    ///     the targets of the edges, with an ``int64``, ``int32``, ``uint64``
+    ///     or ``uint32`` data type
+    /// :param ndarray weights: An optional 1 dimensional array of the edge
+    ///     weights, with a ``float64``, ``float32``, ``int64`` or ``int32``
+    ///     data type
+    /// :param int num_nodes: An optional number of nodes for the graph, which
+    ///     must be larger than every node index in the arrays. This can be
+    ///     used to add nodes without edges at the end of the graph.
+    ///
+    /// :returns: A new graph object with the edges from the arrays
+    /// :rtype: PyGraph
+    /// :raises ValueError: If the arrays don't have the same length, a node
+    ///     index is negative or ``num_nodes`` is too small
+    #[staticmethod]
+    #[pyo3(text_signature = "(sources, targets, /, weights=None, num_nodes=None)")]
+    pub fn from_edge_arrays<'p>(
+        py: Python<'p>,
+        sources: IndexArray<'p>,
+        targets: IndexArray<'p>,
+        weights: Option<WeightArray<'p>>,
+        num_nodes: Option<usize>,
+    ) -> PyResult<PyGraph> {
+        Ok(PyGraph {
+            graph: graph_from_edge_arrays(py, sources, targets, weights, num_nodes)?,
+            node_removed: false,
+            multigraph: true,
+            node_keys: NodeKeys::default(),
+            attributes: AttributeColumns::default(),
+            generation: 0,
+        })
     }
 
     /// Add another PyGraph object into this PyGraph
@@ -1500,6 +2049,7 @@ impl PyGraph {
         node_map_func: Option<PyObject>,
         edge_map_func: Option<PyObject>,
     ) -> PyResult<PyObject> {
+        self.generation += 1;
         let mut new_node_map: DictMap<NodeIndex, NodeIndex> =
             DictMap::with_capacity(other.node_count());
 
@@ -1567,6 +2117,7 @@ impl PyGraph {
         obj: PyObject,
         weight_combo_fn: Option<PyObject>,
     ) -> PyResult<usize> {
+        self.generation += 1;
         let mut indices_to_remove: IndexSet<NodeIndex, ahash::RandomState> =
             nodes.into_iter().map(NodeIndex::new).collect();
 
@@ -1595,7 +2146,9 @@ impl PyGraph {
 
         // Remove nodes that will be replaced.
         for index in indices_to_remove {
+            self.attributes.remove_node(&self.graph, index);
             self.graph.remove_node(index);
+            self.node_keys.remove(index);
         }
 
         // If `weight_combo_fn` was specified, merge edges according
@@ -1612,6 +2165,128 @@ impl PyGraph {
         Ok(node_index.index())
     }
 
+    /// Merge a set of nodes into the first of them.
+    ///
+    /// Unlike :meth:`~PyGraph.contract_nodes`, which replaces the nodes
+    /// with a new node, the first node of ``nodes`` is kept along with its
+    /// index, key and attributes, and the other nodes are removed after
+    /// their edges are moved to it. This makes it suitable for coarsening a
+    /// graph, where every node represents a group of nodes of the previous
+    /// level.
+    ///
+    /// The edges between the merged nodes become self loops of the merged
+    /// node and edges from several of the merged nodes to the same neighbor
+    /// become parallel edges. Both are handled according
+    /// to ``merge_edge_policy``:
+    ///
+    /// * ``"keep"``: all the edges are kept. If this
+    ///   :class:`~retworkx.PyGraph` is not a multigraph, parallel edges
+    ///   (including multiple self loops) are handled like with ``"drop"``.
+    /// * ``"drop"``: the self loops are removed and only the first of the
+    ///   parallel edges is kept, preferring the existing edges of the first
+    ///   node.
+    /// * ``"aggregate"``: the self loops are replaced with a single self loop
+    ///   and the parallel edges with a single edge, whose weight/data
+    ///   payload is built by calling ``weight_combo_fn`` on the payloads of
+    ///   the edges, in order.
+    ///
+    /// Edges of the first node that are kept keep their index, the other
+    /// edges kept are added as new edges.
+    ///
+    /// :param list nodes: The indices of the nodes to merge, the first one is
+    ///     kept and the others are merged into it.
+    /// :param merge_payload_fn: A python callable that will be passed a list
+    ///     of the data payloads/weights of ``nodes``, in order, and returns
+    ///     the data payload/weight of the merged node.
+    /// :param str merge_edge_policy: How self loops and parallel edges
+    ///     created by the merge are handled, one of ``"keep"``, ``"drop"``
+    ///     or ``"aggregate"``. Defaults to ``"drop"``.
+    /// :param weight_combo_fn: A python callable that will be passed the data
+    ///     payloads/weights of 2 edges and returns the combined data
+    ///     payload/weight. Required when ``merge_edge_policy`` is
+    ///     ``"aggregate"``.
+    ///
+    /// :returns: The index of the merged node
+    /// :rtype: int
+    /// :raises IndexError: If a node in ``nodes`` is not in the graph
+    /// :raises ValueError: If ``nodes`` is empty or ``merge_edge_policy`` is
+    ///     not valid
+    #[args(merge_edge_policy = "\"drop\"")]
+    #[pyo3(
+        text_signature = "(self, nodes, merge_payload_fn, /, merge_edge_policy=\"drop\", weight_combo_fn=None)"
+    )]
+    pub fn merge_node_set(
+        &mut self,
+        py: Python,
+        nodes: Vec<usize>,
+        merge_payload_fn: PyObject,
+        merge_edge_policy: &str,
+        weight_combo_fn: Option<PyObject>,
+    ) -> PyResult<usize> {
+        self.generation += 1;
+        let policy = MergeEdgePolicy::from_name(merge_edge_policy, &weight_combo_fn)?;
+        let nodes = contraction::merge_set(&self.graph, nodes)?;
+        let payload =
+            merge_payload_fn.call1(py, (contraction::payload_list(py, &self.graph, &nodes),))?;
+        self.merge(py, &nodes, payload, policy, &weight_combo_fn)
+    }
+
+    /// Contract an edge, merging its target node into its source node.
+    ///
+    /// The source and target of the edge are the endpoints in the order
+    /// returned by :meth:`~PyGraph.get_edge_endpoints_by_index`. This is
+    /// equivalent to calling :meth:`~PyGraph.merge_node_set` with the source
+    /// and target node of the edge. The contracted edge becomes a self
+    /// loop of the source node, which is handled according to
+    /// ``merge_edge_policy`` like any other edge between the merged nodes.
+    ///
+    /// :param int edge: The index of the edge to contract
+    /// :param merge_payload_fn: An optional python callable that will be
+    ///     passed a list with the data payloads/weights of the source and
+    ///     target nodes and returns the data payload/weight of the merged
+    ///     node. If not specified the merged node keeps the data
+    ///     payload/weight of the source node.
+    /// :param str merge_edge_policy: How self loops and parallel edges
+    ///     created by the contraction are handled, one of ``"keep"``,
+    ///     ``"drop"`` or ``"aggregate"``. Defaults to ``"drop"``, which
+    ///     removes the contracted edge.
+    /// :param weight_combo_fn: A python callable that will be passed the data
+    ///     payloads/weights of 2 edges and returns the combined data
+    ///     payload/weight. Required when ``merge_edge_policy`` is
+    ///     ``"aggregate"``.
+    ///
+    /// :returns: The index of the merged node
+    /// :rtype: int
+    /// :raises IndexError: If there is no edge with index ``edge``
+    /// :raises ValueError: If ``merge_edge_policy`` is not valid
+    #[args(merge_edge_policy = "\"drop\"")]
+    #[pyo3(
+        text_signature = "(self, edge, /, merge_payload_fn=None, merge_edge_policy=\"drop\", weight_combo_fn=None)"
+    )]
+    pub fn contract_edge(
+        &mut self,
+        py: Python,
+        edge: usize,
+        merge_payload_fn: Option<PyObject>,
+        merge_edge_policy: &str,
+        weight_combo_fn: Option<PyObject>,
+    ) -> PyResult<usize> {
+        self.generation += 1;
+        let policy = MergeEdgePolicy::from_name(merge_edge_policy, &weight_combo_fn)?;
+        let (source, target) = match self.graph.edge_endpoints(EdgeIndex::new(edge)) {
+            Some(endpoints) => endpoints,
+            None => return Err(PyIndexError::new_err("No edge found for index")),
+        };
+        let nodes = contraction::merge_set(&self.graph, vec![source.index(), target.index()])?;
+        let payload = match merge_payload_fn {
+            Some(merge_payload_fn) => {
+                merge_payload_fn.call1(py, (contraction::payload_list(py, &self.graph, &nodes),))?
+            }
+            None => self.graph[source].clone_ref(py),
+        };
+        self.merge(py, &nodes, payload, policy, &weight_combo_fn)
+    }
+
     /// Return a new PyGraph object for a subgraph of this graph
     ///
     /// :param list nodes: A list of node indices to generate the subgraph
@@ -1645,6 +2320,9 @@ impl PyGraph {
             graph: out_graph,
             node_removed: false,
             multigraph: self.multigraph,
+            node_keys: NodeKeys::default(),
+            attributes: AttributeColumns::default(),
+            generation: 0,
         }
     }
 
@@ -1693,6 +2371,7 @@ impl PyGraph {
             .filter(|node| !nodes.contains(node))
         {
             out_graph.graph.remove_node(node);
+            out_graph.node_keys.remove(node);
             out_graph.node_removed = true;
         }
         for edge in self.graph.edge_references().filter(|edge| {
@@ -1701,9 +2380,64 @@ impl PyGraph {
         }) {
             out_graph.graph.remove_edge(edge.id());
         }
+        out_graph.attributes.prune(&out_graph.graph);
         out_graph
     }
 
+    /// Return a view of a subgraph of the graph without copying it.
+    ///
+    /// The view has the nodes of the graph which are in ``nodes``, if it's
+    /// specified, and whose data payload passes ``node_filter``, and the
+    /// edges between them whose data payload passes ``edge_filter``, with
+    /// the same indices as in the graph. Unlike :meth:`~PyGraph.subgraph`
+    /// it doesn't copy the graph, and the functions which accept a view,
+    /// such as :func:`~retworkx.bfs_search`, run on it like on a graph
+    /// with only those nodes and edges.
+    ///
+    /// The filters are evaluated when the view is created, so nodes and
+    /// edges added to the graph afterwards are only in the view if no
+    /// filter is specified.
+    ///
+    /// :param list nodes: An optional list of the indices of the nodes to
+    ///     keep in the view
+    /// :param node_filter: An optional callable which takes the data
+    ///     payload of a node and returns ``True`` if the node is in the view
+    /// :param edge_filter: An optional callable which takes the data
+    ///     payload of an edge and returns ``True`` if the edge is in the view
+    ///
+    /// :returns: A view of the subgraph
+    /// :rtype: GraphView
+    ///
+    /// .. jupyter-execute::
+    ///
+    ///     import retworkx
+    ///
+    ///     graph = retworkx.generators.path_graph(5)
+    ///     view = graph.subgraph_view(nodes=[0, 1, 3, 4])
+    ///     print(view.edge_list())
+    #[pyo3(text_signature = "(self, /, nodes=None, node_filter=None, edge_filter=None)")]
+    pub fn subgraph_view(
+        slf: PyRef<Self>,
+        py: Python,
+        nodes: Option<Vec<usize>>,
+        node_filter: Option<PyObject>,
+        edge_filter: Option<PyObject>,
+    ) -> PyResult<GraphView> {
+        let (nodes, edges) = view_filters(
+            py,
+            &slf.graph,
+            (None, None),
+            nodes,
+            node_filter,
+            edge_filter,
+        )?;
+        Ok(GraphView {
+            base: ViewBase::Graph(slf.into()),
+            nodes,
+            edges,
+        })
+    }
+
     /// Return a shallow copy of the graph
     ///
     /// All node and edge weight/data payloads in the copy will have a
@@ -1726,6 +2460,7 @@ impl PyGraph {
     }
 
     fn __setitem__(&mut self, idx: usize, value: PyObject) -> PyResult<()> {
+        self.generation += 1;
         let data = match self.graph.node_weight_mut(NodeIndex::new(idx)) {
             Some(node_data) => node_data,
             None => return Err(PyIndexError::new_err("No node found for index")),
@@ -1735,8 +2470,14 @@ impl PyGraph {
     }
 
     fn __delitem__(&mut self, idx: usize) -> PyResult<()> {
-        match self.graph.remove_node(NodeIndex::new(idx as usize)) {
-            Some(_) => Ok(()),
+        self.generation += 1;
+        let index = NodeIndex::new(idx);
+        self.attributes.remove_node(&self.graph, index);
+        match self.graph.remove_node(index) {
+            Some(_) => {
+                self.node_keys.remove(index);
+                Ok(())
+            }
             None => Err(PyIndexError::new_err("No node found for index")),
         }
     }
@@ -1763,6 +2504,7 @@ impl PyGraph {
         {
             visit.call(edge)?;
         }
+        self.node_keys.traverse(&visit)?;
         Ok(())
     }
 
@@ -1773,8 +2515,11 @@ impl PyGraph {
     // ]1] https://docs.python.org/3/c-api/typeobj.html#c.PyTypeObject.tp_clear
     // [2] https://pyo3.rs/v0.12.4/class/protocols.html#garbage-collector-integration
     fn __clear__(&mut self) {
+        self.generation += 1;
         self.graph = StablePyGraph::<Undirected>::default();
         self.node_removed = false;
+        self.node_keys = NodeKeys::default();
+        self.attributes = AttributeColumns::default();
     }
 }
 
@@ -1836,5 +2581,8 @@ where
         graph: out_graph,
         node_removed: false,
         multigraph: true,
+        node_keys: NodeKeys::default(),
+        attributes: AttributeColumns::default(),
+        generation: 0,
     }
 }
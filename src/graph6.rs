@@ -0,0 +1,246 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::path::PathBuf;
+
+use petgraph::prelude::*;
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::pyclass::IterNextOutput;
+use pyo3::types::PyBytes;
+use pyo3::Python;
+
+use retworkx_core::graph6::{self as core_graph6, Graph6Format};
+
+use crate::attributes::AttributeColumns;
+use crate::node_keys::NodeKeys;
+use crate::{digraph, graph, StablePyGraph};
+
+fn graph6_to_graph(py: Python, data: &[u8]) -> PyResult<PyObject> {
+    let to_err =
+        |err: retworkx_core::err::InvalidInputError| PyValueError::new_err(err.to_string());
+    if Graph6Format::detect(data) == Graph6Format::Digraph6 {
+        let out_graph: StablePyGraph<Directed> =
+            core_graph6::from_graph6(data, || py.None(), || py.None()).map_err(to_err)?;
+        Ok(digraph::PyDiGraph {
+            graph: out_graph,
+            cycle_state: None,
+            check_cycle: false,
+            node_removed: false,
+            multigraph: true,
+            node_keys: NodeKeys::default(),
+            attributes: AttributeColumns::default(),
+            generation: 0,
+        }
+        .into_py(py))
+    } else {
+        let out_graph: StablePyGraph<Undirected> =
+            core_graph6::from_graph6(data, || py.None(), || py.None()).map_err(to_err)?;
+        Ok(graph::PyGraph {
+            graph: out_graph,
+            node_removed: false,
+            multigraph: true,
+            node_keys: NodeKeys::default(),
+            attributes: AttributeColumns::default(),
+            generation: 0,
+        }
+        .into_py(py))
+    }
+}
+
+fn encoded_bytes(py: Python, encoded: String, format: Graph6Format, header: bool) -> PyObject {
+    let mut out = String::new();
+    if header {
+        out.push_str(format.header());
+    }
+    out.push_str(&encoded);
+    out.push('\n');
+    PyBytes::new(py, out.as_bytes()).into()
+}
+
+/// Create a graph from graph6, sparse6 or digraph6 data
+///
+/// These are the formats of nauty [1]_ and its tools like ``geng``. The
+/// format is found from the first character of the data, ``:`` for
+/// sparse6, ``&`` for digraph6 and any other for graph6. An optional header
+/// like ``>>graph6<<`` and whitespace around the data, like the newline
+/// ending a line of a file, are ignored. Digraph6 data returns a
+/// :class:`~retworkx.PyDiGraph` and graph6 or sparse6 data returns a
+/// :class:`~retworkx.PyGraph`. The payloads of all nodes and edges are
+/// ``None``.
+///
+/// :param bytes data: The encoded graph
+///
+/// :returns: The decoded graph
+/// :rtype: PyGraph or PyDiGraph
+///
+/// :raises ValueError: If the data isn't valid
+///
+/// .. jupyter-execute::
+///
+///   import retworkx
+///
+///   graph = retworkx.from_graph6_bytes(b"Dhc")
+///   print(graph.edge_list())
+///
+/// .. [1] https://users.cecs.anu.edu.au/~bdm/data/formats.txt
+#[pyfunction]
+#[pyo3(text_signature = "(data, /)")]
+pub fn from_graph6_bytes(py: Python, data: &[u8]) -> PyResult<PyObject> {
+    graph6_to_graph(py, data)
+}
+
+/// Encode a :class:`~retworkx.PyGraph` in the graph6 format
+///
+/// The nodes are numbered from 0 in node index order, so the data decodes
+/// to the same graph if no nodes have been removed. The graph6 format only
+/// stores simple graphs, so self loops are dropped and parallel edges are
+/// stored once, use :func:`~retworkx.to_sparse6_bytes` to keep them.
+///
+/// :param PyGraph graph: The graph to encode
+/// :param bool header: If set to ``True`` (the default) the data starts
+///     with the ``>>graph6<<`` header.
+///
+/// :returns: The encoded graph, ending with a newline
+/// :rtype: bytes
+#[pyfunction(header = "true")]
+#[pyo3(text_signature = "(graph, /, header=True)")]
+pub fn graph_to_graph6_bytes(py: Python, graph: &graph::PyGraph, header: bool) -> PyObject {
+    encoded_bytes(
+        py,
+        core_graph6::to_graph6(&graph.graph),
+        Graph6Format::Graph6,
+        header,
+    )
+}
+
+/// Encode a :class:`~retworkx.PyDiGraph` in the digraph6 format
+///
+/// The nodes are numbered from 0 in node index order, so the data decodes
+/// to the same graph if no nodes have been removed. The digraph6 format
+/// stores the adjacency matrix, so parallel edges are stored once.
+///
+/// :param PyDiGraph graph: The graph to encode
+/// :param bool header: If set to ``True`` (the default) the data starts
+///     with the ``>>digraph6<<`` header.
+///
+/// :returns: The encoded graph, ending with a newline
+/// :rtype: bytes
+#[pyfunction(header = "true")]
+#[pyo3(text_signature = "(graph, /, header=True)")]
+pub fn digraph_to_graph6_bytes(py: Python, graph: &digraph::PyDiGraph, header: bool) -> PyObject {
+    encoded_bytes(
+        py,
+        core_graph6::to_digraph6(&graph.graph),
+        Graph6Format::Digraph6,
+        header,
+    )
+}
+
+/// Encode a :class:`~retworkx.PyGraph` in the sparse6 format
+///
+/// The nodes are numbered from 0 in node index order, so the data decodes
+/// to the same graph if no nodes have been removed. Unlike graph6, the
+/// sparse6 format stores the list of edges, so it keeps self loops and
+/// parallel edges and is more compact for sparse graphs.
+///
+/// :param PyGraph graph: The graph to encode
+/// :param bool header: If set to ``True`` (the default) the data starts
+///     with the ``>>sparse6<<`` header.
+///
+/// :returns: The encoded graph, ending with a newline
+/// :rtype: bytes
+///
+/// .. jupyter-execute::
+///
+///   import retworkx
+///
+///   graph = retworkx.generators.cycle_graph(5)
+///   data = retworkx.to_sparse6_bytes(graph, header=False)
+///   print(data)
+///   print(retworkx.from_graph6_bytes(data).edge_list())
+#[pyfunction(header = "true")]
+#[pyo3(text_signature = "(graph, /, header=True)")]
+pub fn to_sparse6_bytes(py: Python, graph: &graph::PyGraph, header: bool) -> PyObject {
+    encoded_bytes(
+        py,
+        core_graph6::to_sparse6(&graph.graph),
+        Graph6Format::Sparse6,
+        header,
+    )
+}
+
+/// Read a file with a graph6, sparse6 or digraph6 graph on each line
+///
+/// The file is read lazily, one line for each graph, so large outputs of
+/// tools like ``geng`` can be processed without holding all the graphs in
+/// memory. Each line is decoded as with :func:`~retworkx.from_graph6_bytes`
+/// and empty lines are skipped.
+///
+/// :param str path: The path of the file to read
+///
+/// :returns: An iterator over the graphs of the file
+/// :rtype: Graph6FileIterator
+///
+/// :raises OSError: If the file can't be opened
+#[pyfunction]
+#[pyo3(text_signature = "(path, /)")]
+pub fn read_graph6_file(path: PathBuf) -> PyResult<Graph6FileIterator> {
+    Ok(Graph6FileIterator {
+        reader: BufReader::new(File::open(path)?),
+        line_no: 0,
+    })
+}
+
+/// A lazy iterator over the graphs of a graph6 file, returned by
+/// :func:`~retworkx.read_graph6_file`.
+///
+/// Each iteration reads the next line of the file and returns its graph, a
+/// :class:`~retworkx.PyGraph` for graph6 or sparse6 data or a
+/// :class:`~retworkx.PyDiGraph` for digraph6 data. A ``ValueError`` with
+/// the line number is raised for an invalid line.
+#[pyclass(module = "retworkx")]
+pub struct Graph6FileIterator {
+    reader: BufReader<File>,
+    line_no: usize,
+}
+
+#[pymethods]
+impl Graph6FileIterator {
+    fn __iter__(slf: PyRef<Self>) -> Py<Graph6FileIterator> {
+        slf.into()
+    }
+
+    fn __next__(
+        mut slf: PyRefMut<Self>,
+        py: Python,
+    ) -> PyResult<IterNextOutput<PyObject, &'static str>> {
+        let mut line = Vec::new();
+        loop {
+            line.clear();
+            if slf.reader.read_until(b'\n', &mut line)? == 0 {
+                return Ok(IterNextOutput::Return("Ended"));
+            }
+            slf.line_no += 1;
+            if line.iter().any(|byte| !byte.is_ascii_whitespace()) {
+                break;
+            }
+        }
+        let line_no = slf.line_no;
+        graph6_to_graph(py, &line)
+            .map(IterNextOutput::Yield)
+            .map_err(|err| PyValueError::new_err(format!("Line {}: {}", line_no, err.value(py))))
+    }
+}
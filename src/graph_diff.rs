@@ -0,0 +1,257 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use crate::{digraph, graph, StablePyGraph};
+
+use hashbrown::HashSet;
+
+use petgraph::stable_graph::{EdgeIndex, NodeIndex};
+use petgraph::visit::{EdgeRef, IntoEdgeReferences};
+use petgraph::EdgeType;
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use pyo3::Python;
+
+use retworkx_core::dictmap::*;
+
+/// The differences between two graph snapshots
+///
+/// This class is returned by :func:`~retworkx.graph_diff` and all of its
+/// attributes refer to node and edge indices in the graph they were found
+/// in: ``removed_*`` attributes to indices in the first graph and
+/// ``added_*`` attributes to indices in the second graph.
+///
+/// :ivar list added_nodes: The indices of the nodes in the second graph
+///     without a matching node in the first graph.
+/// :ivar list removed_nodes: The indices of the nodes in the first graph
+///     without a matching node in the second graph.
+/// :ivar dict node_map: A mapping of the index of every node in the first
+///     graph to the index of its matching node in the second graph.
+/// :ivar dict changed_nodes: The subset of ``node_map`` for which the
+///     weight/data payloads of the matching nodes are not equal.
+/// :ivar list added_edges: The indices of the edges in the second graph
+///     without a matching edge in the first graph.
+/// :ivar list removed_edges: The indices of the edges in the first graph
+///     without a matching edge in the second graph.
+/// :ivar dict changed_edges: A mapping of edge indices in the first graph
+///     to the index of the matching edge in the second graph for the edges
+///     whose weight/data payloads are not equal.
+#[pyclass(module = "retworkx")]
+pub struct GraphDiff {
+    #[pyo3(get)]
+    pub added_nodes: Vec<usize>,
+    #[pyo3(get)]
+    pub removed_nodes: Vec<usize>,
+    #[pyo3(get)]
+    pub node_map: DictMap<usize, usize>,
+    #[pyo3(get)]
+    pub changed_nodes: DictMap<usize, usize>,
+    #[pyo3(get)]
+    pub added_edges: Vec<usize>,
+    #[pyo3(get)]
+    pub removed_edges: Vec<usize>,
+    #[pyo3(get)]
+    pub changed_edges: DictMap<usize, usize>,
+}
+
+#[pymethods]
+impl GraphDiff {
+    /// Return ``True`` if there are no differences between the graphs.
+    fn is_empty(&self) -> bool {
+        self.added_nodes.is_empty()
+            && self.removed_nodes.is_empty()
+            && self.changed_nodes.is_empty()
+            && self.added_edges.is_empty()
+            && self.removed_edges.is_empty()
+            && self.changed_edges.is_empty()
+    }
+}
+
+/// Build a dict of the node keys of a graph to node indices, raising an
+/// error if two nodes share a key.
+fn node_keys<'p, Ty: EdgeType>(
+    py: Python<'p>,
+    graph: &StablePyGraph<Ty>,
+    node_key_fn: &Option<PyObject>,
+) -> PyResult<&'p PyDict> {
+    let keys = PyDict::new(py);
+    for node in graph.node_indices() {
+        let weight = &graph[node];
+        let key = match node_key_fn {
+            Some(node_key_fn) => node_key_fn.call1(py, (weight,))?,
+            None => weight.clone_ref(py),
+        };
+        if keys.contains(&key)? {
+            return Err(PyValueError::new_err(format!(
+                "Multiple nodes in the same graph have the key {}",
+                key.as_ref(py).repr()?
+            )));
+        }
+        keys.set_item(key, node.index())?;
+    }
+    Ok(keys)
+}
+
+fn graph_diff<Ty: EdgeType>(
+    py: Python,
+    first: &StablePyGraph<Ty>,
+    second: &StablePyGraph<Ty>,
+    node_key_fn: Option<PyObject>,
+) -> PyResult<GraphDiff> {
+    let weights_equal = |a: &PyObject, b: &PyObject| -> PyResult<bool> {
+        a.as_ref(py)
+            .rich_compare(b, pyo3::basic::CompareOp::Eq)?
+            .is_true()
+    };
+
+    let first_keys = node_keys(py, first, &node_key_fn)?;
+    let second_keys = node_keys(py, second, &node_key_fn)?;
+
+    let mut node_map: DictMap<usize, usize> = DictMap::with_capacity(first.node_count());
+    let mut changed_nodes: DictMap<usize, usize> = DictMap::new();
+    let mut removed_nodes: Vec<usize> = Vec::new();
+    for (key, index) in first_keys.iter() {
+        let index: usize = index.extract()?;
+        match second_keys.get_item(key) {
+            Some(other) => {
+                let other: usize = other.extract()?;
+                node_map.insert(index, other);
+                if !weights_equal(
+                    &first[NodeIndex::new(index)],
+                    &second[NodeIndex::new(other)],
+                )? {
+                    changed_nodes.insert(index, other);
+                }
+            }
+            None => removed_nodes.push(index),
+        }
+    }
+    let mut added_nodes: Vec<usize> = Vec::new();
+    for (key, index) in second_keys.iter() {
+        if !first_keys.contains(key)? {
+            added_nodes.push(index.extract()?);
+        }
+    }
+
+    let mut matched: HashSet<EdgeIndex> = HashSet::new();
+    let mut changed_edges: DictMap<usize, usize> = DictMap::new();
+    let mut removed_edges: Vec<usize> = Vec::new();
+    for edge in first.edge_references() {
+        let endpoints = (
+            node_map.get(&edge.source().index()),
+            node_map.get(&edge.target().index()),
+        );
+        let candidates: Vec<_> = match endpoints {
+            (Some(source), Some(target)) => second
+                .edges(NodeIndex::new(*source))
+                .filter(|other| other.target().index() == *target && !matched.contains(&other.id()))
+                .collect(),
+            _ => Vec::new(),
+        };
+        // Prefer a parallel edge with an equal weight so that reordered
+        // parallel edges are not reported as changed
+        let mut found = None;
+        for other in &candidates {
+            if weights_equal(edge.weight(), other.weight())? {
+                found = Some(other.id());
+                break;
+            }
+        }
+        match (found, candidates.first()) {
+            (Some(other), _) => {
+                matched.insert(other);
+            }
+            (None, Some(other)) => {
+                matched.insert(other.id());
+                changed_edges.insert(edge.id().index(), other.id().index());
+            }
+            (None, None) => removed_edges.push(edge.id().index()),
+        }
+    }
+    let added_edges: Vec<usize> = second
+        .edge_indices()
+        .filter(|edge| !matched.contains(edge))
+        .map(|edge| edge.index())
+        .collect();
+
+    Ok(GraphDiff {
+        added_nodes,
+        removed_nodes,
+        node_map,
+        changed_nodes,
+        added_edges,
+        removed_edges,
+        changed_edges,
+    })
+}
+
+/// Compute the differences between two PyGraph snapshots
+///
+/// Nodes are matched between the two graphs by the key returned by
+/// ``node_key_fn`` rather than by their node index, as the index spaces of
+/// two snapshots of an evolving graph rarely align. An edge of ``first``
+/// matches an edge of ``second`` if their endpoints match, with parallel
+/// edges being matched one to one. Matching nodes or edges whose weight/data
+/// payloads are not equal are reported as changed.
+///
+/// :param PyGraph first: The earlier graph snapshot
+/// :param PyGraph second: The later graph snapshot
+/// :param callable node_key_fn: An optional callable that will be passed the
+///     weight/data payload of a node and returns a hashable key identifying
+///     the node across snapshots. If not specified the weight/data payload
+///     itself is used as the key.
+///
+/// :returns: The differences between ``first`` and ``second``
+/// :rtype: GraphDiff
+/// :raises ValueError: If two nodes of the same graph have equal keys
+#[pyfunction]
+#[pyo3(text_signature = "(first, second, /, node_key_fn=None)")]
+pub fn graph_graph_diff(
+    py: Python,
+    first: &graph::PyGraph,
+    second: &graph::PyGraph,
+    node_key_fn: Option<PyObject>,
+) -> PyResult<GraphDiff> {
+    graph_diff(py, &first.graph, &second.graph, node_key_fn)
+}
+
+/// Compute the differences between two PyDiGraph snapshots
+///
+/// Nodes are matched between the two graphs by the key returned by
+/// ``node_key_fn`` rather than by their node index, as the index spaces of
+/// two snapshots of an evolving graph rarely align. An edge of ``first``
+/// matches an edge of ``second`` if their endpoints match, with parallel
+/// edges being matched one to one. Matching nodes or edges whose weight/data
+/// payloads are not equal are reported as changed.
+///
+/// :param PyDiGraph first: The earlier graph snapshot
+/// :param PyDiGraph second: The later graph snapshot
+/// :param callable node_key_fn: An optional callable that will be passed the
+///     weight/data payload of a node and returns a hashable key identifying
+///     the node across snapshots. If not specified the weight/data payload
+///     itself is used as the key.
+///
+/// :returns: The differences between ``first`` and ``second``
+/// :rtype: GraphDiff
+/// :raises ValueError: If two nodes of the same graph have equal keys
+#[pyfunction]
+#[pyo3(text_signature = "(first, second, /, node_key_fn=None)")]
+pub fn digraph_graph_diff(
+    py: Python,
+    first: &digraph::PyDiGraph,
+    second: &digraph::PyDiGraph,
+    node_key_fn: Option<PyObject>,
+) -> PyResult<GraphDiff> {
+    graph_diff(py, &first.graph, &second.graph, node_key_fn)
+}
@@ -0,0 +1,130 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use crate::{digraph, graph};
+
+use pyo3::prelude::*;
+
+use retworkx_core::summary;
+
+/// A summary of the basic statistics of a graph
+///
+/// This class is returned by :func:`~retworkx.graph_summary`. The components,
+/// clustering and diameter estimate ignore the direction of the edges,
+/// parallel edges and self loops.
+///
+/// :ivar int node_count: The number of nodes
+/// :ivar int edge_count: The number of edges, counting parallel edges and
+///     self loops
+/// :ivar float density: The number of edges divided by the number of
+///     possible edges between distinct nodes
+/// :ivar int min_degree: The smallest degree of a node
+/// :ivar int max_degree: The largest degree of a node
+/// :ivar float mean_degree: The average degree of a node
+/// :ivar int num_components: The number of (weakly) connected components
+/// :ivar int largest_component_size: The number of nodes in the largest
+///     (weakly) connected component
+/// :ivar float average_clustering: The average of the local clustering
+///     coefficients of all the nodes
+/// :ivar degree_assortativity: The degree assortativity coefficient, or
+///     ``None`` if it's undefined because there are no edges or all the edges
+///     join nodes of the same degrees
+/// :ivar int diameter_estimate: A lower bound on the diameter of the largest
+///     (weakly) connected component
+#[pyclass(module = "retworkx")]
+pub struct GraphSummary {
+    #[pyo3(get)]
+    pub node_count: usize,
+    #[pyo3(get)]
+    pub edge_count: usize,
+    #[pyo3(get)]
+    pub density: f64,
+    #[pyo3(get)]
+    pub min_degree: usize,
+    #[pyo3(get)]
+    pub max_degree: usize,
+    #[pyo3(get)]
+    pub mean_degree: f64,
+    #[pyo3(get)]
+    pub num_components: usize,
+    #[pyo3(get)]
+    pub largest_component_size: usize,
+    #[pyo3(get)]
+    pub average_clustering: f64,
+    #[pyo3(get)]
+    pub degree_assortativity: Option<f64>,
+    #[pyo3(get)]
+    pub diameter_estimate: usize,
+}
+
+impl From<summary::GraphSummary> for GraphSummary {
+    fn from(summary: summary::GraphSummary) -> Self {
+        GraphSummary {
+            node_count: summary.node_count,
+            edge_count: summary.edge_count,
+            density: summary.density,
+            min_degree: summary.min_degree,
+            max_degree: summary.max_degree,
+            mean_degree: summary.mean_degree,
+            num_components: summary.num_components,
+            largest_component_size: summary.largest_component_size,
+            average_clustering: summary.average_clustering,
+            degree_assortativity: summary.degree_assortativity,
+            diameter_estimate: summary.diameter_estimate,
+        }
+    }
+}
+
+/// Compute a summary of the basic statistics of a PyGraph
+///
+/// This computes the statistics which are usually looked at first to profile
+/// a graph in a single call, sharing the work between them. The degree of a
+/// node counts self loops twice. The degree assortativity counts every edge
+/// in both directions. The diameter estimate is found with a double sweep
+/// breadth first search [1]_, it's exact for trees.
+///
+/// :param PyGraph graph: The graph to summarize
+///
+/// :returns: The statistics of ``graph``
+/// :rtype: GraphSummary
+///
+/// .. [1] Clémence Magnien, Matthieu Latapy and Michel Habib, "Fast
+///     computation of empirical tight bounds for the diameter of massive
+///     graphs." Journal of Experimental Algorithmics 13:1.10-1.9, 2009.
+#[pyfunction]
+#[pyo3(text_signature = "(graph, /)")]
+pub fn graph_graph_summary(graph: &graph::PyGraph) -> GraphSummary {
+    summary::graph_summary(&graph.graph).into()
+}
+
+/// Compute a summary of the basic statistics of a PyDiGraph
+///
+/// This computes the statistics which are usually looked at first to profile
+/// a graph in a single call, sharing the work between them. The degree of a
+/// node is the sum of its in and out degrees. The degree assortativity is the
+/// correlation of the out degree of the source with the in degree of the
+/// target of the edges. The diameter estimate is found with a double sweep
+/// breadth first search [1]_ ignoring the direction of the edges.
+///
+/// :param PyDiGraph graph: The graph to summarize
+///
+/// :returns: The statistics of ``graph``
+/// :rtype: GraphSummary
+///
+/// .. [1] Clémence Magnien, Matthieu Latapy and Michel Habib, "Fast
+///     computation of empirical tight bounds for the diameter of massive
+///     graphs." Journal of Experimental Algorithmics 13:1.10-1.9, 2009.
+#[pyfunction]
+#[pyo3(text_signature = "(graph, /)")]
+pub fn digraph_graph_summary(graph: &digraph::PyDiGraph) -> GraphSummary {
+    summary::graph_summary(&graph.graph).into()
+}
@@ -0,0 +1,137 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use std::time::Duration;
+
+use crate::iterators::NodeIndices;
+use crate::{digraph, graph, InvalidNode, SearchBudgetExhausted, StablePyGraph};
+
+use retworkx_core::hamiltonian;
+
+use petgraph::prelude::*;
+use petgraph::EdgeType;
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+fn hamiltonian_path<Ty: EdgeType>(
+    graph: &StablePyGraph<Ty>,
+    source: Option<usize>,
+    target: Option<usize>,
+    timeout: Option<f64>,
+    call_limit: Option<usize>,
+) -> PyResult<Option<NodeIndices>> {
+    for node in source.iter().chain(target.iter()) {
+        if !graph.contains_node(NodeIndex::new(*node)) {
+            return Err(InvalidNode::new_err(format!(
+                "Node index {} is not in the graph",
+                node
+            )));
+        }
+    }
+    let timeout = match timeout {
+        Some(timeout) if timeout.is_nan() || timeout < 0. || timeout.is_infinite() => {
+            return Err(PyValueError::new_err(
+                "timeout must be a finite non-negative number of seconds",
+            ));
+        }
+        Some(timeout) => Some(Duration::from_secs_f64(timeout)),
+        None => None,
+    };
+    match hamiltonian::hamiltonian_path(
+        graph,
+        source.map(NodeIndex::new),
+        target.map(NodeIndex::new),
+        call_limit,
+        timeout,
+    ) {
+        Ok(path) => Ok(path.map(|path| NodeIndices {
+            nodes: path.iter().map(|node| node.index()).collect(),
+        })),
+        Err(err) => Err(SearchBudgetExhausted::new_err(err.to_string())),
+    }
+}
+
+/// Search for a Hamiltonian path in a PyGraph
+///
+/// A Hamiltonian path visits every node of the graph exactly once. Deciding
+/// if a graph has one is NP-complete, this function uses a backtracking
+/// search which extends a path one node at a time, trying the nodes with
+/// the fewest neighbors left first, and prunes the paths that can't be
+/// completed: every node left must still have a neighbor to enter and leave
+/// it by, and be reachable from the end of the path through the nodes
+/// left. The search takes exponential time in the worst case, so it can be
+/// bounded with ``timeout`` and ``call_limit``.
+///
+/// :param PyGraph graph: The graph to search in
+/// :param int source: An optional node index the path has to start at
+/// :param int target: An optional node index the path has to end at
+/// :param float timeout: An optional limit on the duration of the search in
+///     seconds
+/// :param int call_limit: An optional limit on the number of times the
+///     search extends a path with a node
+///
+/// :returns: The node indices of a Hamiltonian path in order, or ``None``
+///     if the graph has no Hamiltonian path
+/// :rtype: NodeIndices
+/// :raises InvalidNode: If ``source`` or ``target`` is not in the graph
+/// :raises SearchBudgetExhausted: If the search reached ``timeout`` or
+///     ``call_limit`` before finding a path or proving that there is none
+#[pyfunction]
+#[pyo3(text_signature = "(graph, /, source=None, target=None, timeout=None, call_limit=None)")]
+pub fn graph_hamiltonian_path(
+    graph: &graph::PyGraph,
+    source: Option<usize>,
+    target: Option<usize>,
+    timeout: Option<f64>,
+    call_limit: Option<usize>,
+) -> PyResult<Option<NodeIndices>> {
+    hamiltonian_path(&graph.graph, source, target, timeout, call_limit)
+}
+
+/// Search for a Hamiltonian path in a PyDiGraph
+///
+/// A Hamiltonian path visits every node of the graph exactly once following
+/// the direction of the edges. Deciding if a graph has one is NP-complete,
+/// this function uses a backtracking search which extends a path one node
+/// at a time, trying the nodes with the fewest successors left first, and
+/// prunes the paths that can't be completed: every node left must still
+/// have a predecessor and a successor to enter and leave it by, and be
+/// reachable from the end of the path through the nodes left. The search
+/// takes exponential time in the worst case, so it can be bounded with
+/// ``timeout`` and ``call_limit``.
+///
+/// :param PyDiGraph graph: The graph to search in
+/// :param int source: An optional node index the path has to start at
+/// :param int target: An optional node index the path has to end at
+/// :param float timeout: An optional limit on the duration of the search in
+///     seconds
+/// :param int call_limit: An optional limit on the number of times the
+///     search extends a path with a node
+///
+/// :returns: The node indices of a Hamiltonian path in order, or ``None``
+///     if the graph has no Hamiltonian path
+/// :rtype: NodeIndices
+/// :raises InvalidNode: If ``source`` or ``target`` is not in the graph
+/// :raises SearchBudgetExhausted: If the search reached ``timeout`` or
+///     ``call_limit`` before finding a path or proving that there is none
+#[pyfunction]
+#[pyo3(text_signature = "(graph, /, source=None, target=None, timeout=None, call_limit=None)")]
+pub fn digraph_hamiltonian_path(
+    graph: &digraph::PyDiGraph,
+    source: Option<usize>,
+    target: Option<usize>,
+    timeout: Option<f64>,
+    call_limit: Option<usize>,
+) -> PyResult<Option<NodeIndices>> {
+    hamiltonian_path(&graph.graph, source, target, timeout, call_limit)
+}
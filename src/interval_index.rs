@@ -0,0 +1,314 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use crate::iterators::EdgeIndices;
+use crate::views::{DiGraphView, GraphView, ViewBase};
+use crate::{digraph, graph, StablePyGraph};
+
+use fixedbitset::FixedBitSet;
+
+use petgraph::visit::{EdgeIndexable, EdgeRef, IntoEdgeReferences};
+use petgraph::EdgeType;
+
+use pyo3::exceptions::PyValueError;
+use pyo3::gc::PyVisit;
+use pyo3::prelude::*;
+use pyo3::PyTraverseError;
+use pyo3::Python;
+
+use retworkx_core::interval_index::IntervalIndex;
+
+enum IndexedGraph {
+    Graph(Py<graph::PyGraph>),
+    DiGraph(Py<digraph::PyDiGraph>),
+}
+
+/// An index of the edges of a graph by the time interval during which they
+/// are active.
+///
+/// This class finds the edges active in a time window without scanning all
+/// the edges of the graph, with an interval tree built once from the
+/// interval of every edge. It's returned by
+/// :func:`~retworkx.edge_interval_index`. An edge active from ``start`` to
+/// ``end`` is active in a window from ``window_start`` to ``window_end`` if
+/// ``start <= window_end`` and ``end >= window_start``, all the bounds are
+/// included. For example, to slide a window over a temporal graph:
+///
+/// .. code-block:: python
+///
+///     import retworkx
+///
+///     graph = retworkx.PyGraph()
+///     graph.add_nodes_from(range(4))
+///     graph.add_edges_from([(0, 1, (0, 3)), (1, 2, (2, 6)), (2, 3, (5, 9))])
+///     index = retworkx.edge_interval_index(graph, lambda interval: interval)
+///     for start in range(0, 10, 2):
+///         view = index.window_view(start, start + 1)
+///         print(start, view.edge_list())
+///
+/// The index keeps a reference to the graph and the interval of every edge.
+/// The graph must not be modified while the index is used: the queries
+/// raise a ``ValueError`` if a method modifying the graph was called since
+/// the index was created. A payload modified in place, such as a list
+/// payload which is appended to, isn't detected.
+#[pyclass(module = "retworkx")]
+pub struct EdgeIntervalIndex {
+    /// The indexed graph, ``None`` once it's cleared by the garbage collector
+    graph: Option<IndexedGraph>,
+    index: IntervalIndex<usize, f64>,
+    /// The generation of the graph when the index was created
+    generation: usize,
+}
+
+impl EdgeIntervalIndex {
+    fn new<Ty: EdgeType>(
+        py: Python,
+        graph: IndexedGraph,
+        inner_graph: &StablePyGraph<Ty>,
+        generation: usize,
+        interval_fn: PyObject,
+    ) -> PyResult<Self> {
+        let mut intervals = Vec::with_capacity(inner_graph.edge_count());
+        for edge in inner_graph.edge_references() {
+            let (start, end): (f64, f64) = interval_fn.call1(py, (edge.weight(),))?.extract(py)?;
+            if start.is_nan() || end.is_nan() {
+                return Err(PyValueError::new_err(format!(
+                    "The interval of edge {} is NaN",
+                    edge.id().index()
+                )));
+            }
+            if start > end {
+                return Err(PyValueError::new_err(format!(
+                    "The interval of edge {} starts after it ends",
+                    edge.id().index()
+                )));
+            }
+            intervals.push((edge.id().index(), start, end));
+        }
+        Ok(EdgeIntervalIndex {
+            graph: Some(graph),
+            index: IntervalIndex::new(intervals),
+            generation,
+        })
+    }
+
+    /// The indexed graph, after checking that it wasn't modified.
+    fn checked_graph(&self, py: Python) -> PyResult<&IndexedGraph> {
+        let graph = match &self.graph {
+            Some(graph) => graph,
+            None => return Err(PyValueError::new_err("The graph of the index was cleared")),
+        };
+        let generation = match graph {
+            IndexedGraph::Graph(inner) => inner.borrow(py).generation,
+            IndexedGraph::DiGraph(inner) => inner.borrow(py).generation,
+        };
+        if generation != self.generation {
+            return Err(PyValueError::new_err(
+                "The graph was modified after the index was created",
+            ));
+        }
+        Ok(graph)
+    }
+
+    /// The sorted indices of the edges active in the window, after checking
+    /// that the graph wasn't modified.
+    fn active_edges(&self, py: Python, start: f64, end: f64) -> PyResult<Vec<usize>> {
+        self.checked_graph(py)?;
+        let mut edges: Vec<usize> = self
+            .index
+            .overlapping(start, end)
+            .map(|(edge, _, _)| *edge)
+            .collect();
+        edges.sort_unstable();
+        Ok(edges)
+    }
+
+    /// The set of the edges active in the window, out of the `bound` edge
+    /// indices of the graph.
+    fn active_edge_set(
+        &self,
+        py: Python,
+        start: f64,
+        end: f64,
+        bound: usize,
+    ) -> PyResult<FixedBitSet> {
+        let mut active = FixedBitSet::with_capacity(bound);
+        active.extend(self.active_edges(py, start, end)?);
+        Ok(active)
+    }
+}
+
+#[pymethods]
+impl EdgeIntervalIndex {
+    /// Return the edges active in a time window
+    ///
+    /// An edge is active in the window if its interval overlaps the window,
+    /// the bounds of the intervals and of the window are included.
+    ///
+    /// :param float start: The start of the window
+    /// :param float end: The end of the window, if it's before ``start`` the
+    ///     window is empty and no edge is active in it
+    ///
+    /// :returns: The indices of the edges active in the window, in increasing
+    ///     order
+    /// :rtype: EdgeIndices
+    /// :raises ValueError: If the graph was modified
+    #[pyo3(text_signature = "(self, start, end, /)")]
+    fn edges_active_in(&self, py: Python, start: f64, end: f64) -> PyResult<EdgeIndices> {
+        Ok(EdgeIndices {
+            edges: self.active_edges(py, start, end)?,
+        })
+    }
+
+    /// Return the edges active at a time
+    ///
+    /// :param float time: The time, the bounds of the intervals are included
+    ///
+    /// :returns: The indices of the edges active at ``time``, in increasing
+    ///     order
+    /// :rtype: EdgeIndices
+    /// :raises ValueError: If the graph was modified
+    #[pyo3(text_signature = "(self, time, /)")]
+    fn edges_active_at(&self, py: Python, time: f64) -> PyResult<EdgeIndices> {
+        Ok(EdgeIndices {
+            edges: self.active_edges(py, time, time)?,
+        })
+    }
+
+    /// Return a view of the graph in a time window
+    ///
+    /// The view has all the nodes of the graph but only the edges active in
+    /// the window, with the same indices, and it's built without copying
+    /// the graph. Use :meth:`.GraphView.to_graph` or
+    /// :meth:`.DiGraphView.to_digraph` to copy it into a new graph.
+    ///
+    /// :param float start: The start of the window
+    /// :param float end: The end of the window
+    ///
+    /// :returns: The view, a :class:`~retworkx.GraphView` of a
+    ///     :class:`~retworkx.PyGraph` or a :class:`~retworkx.DiGraphView` of a
+    ///     :class:`~retworkx.PyDiGraph`
+    /// :rtype: GraphView or DiGraphView
+    /// :raises ValueError: If the graph was modified
+    #[pyo3(text_signature = "(self, start, end, /)")]
+    fn window_view(&self, py: Python, start: f64, end: f64) -> PyResult<PyObject> {
+        Ok(match self.checked_graph(py)? {
+            IndexedGraph::Graph(graph) => {
+                let bound = graph.borrow(py).graph.edge_bound();
+                GraphView {
+                    base: ViewBase::Graph(graph.clone_ref(py)),
+                    nodes: None,
+                    edges: Some(self.active_edge_set(py, start, end, bound)?),
+                }
+                .into_py(py)
+            }
+            IndexedGraph::DiGraph(graph) => {
+                let bound = graph.borrow(py).graph.edge_bound();
+                DiGraphView {
+                    graph: graph.clone_ref(py),
+                    reversed: false,
+                    nodes: None,
+                    edges: Some(self.active_edge_set(py, start, end, bound)?),
+                }
+                .into_py(py)
+            }
+        })
+    }
+
+    fn __len__(&self) -> usize {
+        self.index.len()
+    }
+
+    fn __traverse__(&self, visit: PyVisit) -> Result<(), PyTraverseError> {
+        match &self.graph {
+            Some(IndexedGraph::Graph(graph)) => visit.call(graph),
+            Some(IndexedGraph::DiGraph(graph)) => visit.call(graph),
+            None => Ok(()),
+        }
+    }
+
+    fn __clear__(&mut self) {
+        self.graph = None;
+    }
+}
+
+/// Index the edges of a PyGraph by the time interval during which they are
+/// active
+///
+/// The returned :class:`~retworkx.EdgeIntervalIndex` finds the edges active
+/// in a time window without scanning all the edges of the graph, and builds
+/// a view of the graph in the window. Finding the :math:`k` edges active in
+/// a window takes :math:`O(\log m + k)` time for a graph with :math:`m` edges,
+/// after building the index in :math:`O(m \log m)` time.
+///
+/// :param PyGraph graph: The graph to index
+/// :param interval_fn: A callable that will be passed the edge's payload
+///     object and is expected to return a tuple ``(start, end)`` of ``float``
+///     times, the interval during which the edge is active. Both bounds are
+///     included, so ``start`` can be equal to ``end``. It's called once per
+///     edge when the index is created.
+///
+/// :returns: The index of the edges of ``graph``
+/// :rtype: EdgeIntervalIndex
+/// :raises ValueError: If an interval is NaN or starts after it ends
+#[pyfunction]
+#[pyo3(text_signature = "(graph, interval_fn, /)")]
+pub fn graph_edge_interval_index(
+    py: Python,
+    graph: Py<graph::PyGraph>,
+    interval_fn: PyObject,
+) -> PyResult<EdgeIntervalIndex> {
+    let inner_graph = graph.borrow(py);
+    EdgeIntervalIndex::new(
+        py,
+        IndexedGraph::Graph(graph.clone_ref(py)),
+        &inner_graph.graph,
+        inner_graph.generation,
+        interval_fn,
+    )
+}
+
+/// Index the edges of a PyDiGraph by the time interval during which they are
+/// active
+///
+/// The returned :class:`~retworkx.EdgeIntervalIndex` finds the edges active
+/// in a time window without scanning all the edges of the graph, and builds
+/// a view of the graph in the window. Finding the :math:`k` edges active in
+/// a window takes :math:`O(\log m + k)` time for a graph with :math:`m` edges,
+/// after building the index in :math:`O(m \log m)` time.
+///
+/// :param PyDiGraph graph: The graph to index
+/// :param interval_fn: A callable that will be passed the edge's payload
+///     object and is expected to return a tuple ``(start, end)`` of ``float``
+///     times, the interval during which the edge is active. Both bounds are
+///     included, so ``start`` can be equal to ``end``. It's called once per
+///     edge when the index is created.
+///
+/// :returns: The index of the edges of ``graph``
+/// :rtype: EdgeIntervalIndex
+/// :raises ValueError: If an interval is NaN or starts after it ends
+#[pyfunction]
+#[pyo3(text_signature = "(graph, interval_fn, /)")]
+pub fn digraph_edge_interval_index(
+    py: Python,
+    graph: Py<digraph::PyDiGraph>,
+    interval_fn: PyObject,
+) -> PyResult<EdgeIntervalIndex> {
+    let inner_graph = graph.borrow(py);
+    EdgeIntervalIndex::new(
+        py,
+        IndexedGraph::DiGraph(graph.clone_ref(py)),
+        &inner_graph.graph,
+        inner_graph.generation,
+        interval_fn,
+    )
+}
@@ -0,0 +1,210 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::PyTuple;
+use pyo3::Python;
+
+use retworkx_core::isomorphism::Tolerance;
+
+/// A matcher comparing numeric payloads within a tolerance
+///
+/// An instance of this class can be passed as the ``node_matcher`` or
+/// ``edge_matcher`` of the isomorphism functions, such as
+/// :func:`~retworkx.is_isomorphic` or :func:`~retworkx.vf2_mapping`. It's
+/// called with 2 payloads and returns ``True`` if their numeric values
+/// ``a`` and ``b`` are close, with the same comparison as
+/// :func:`math.isclose`::
+///
+///     abs(a - b) <= max(rel_tol * max(abs(a), abs(b)), abs_tol)
+///
+/// This makes it possible to match graphs with floating point weights that
+/// were computed differently, where an exact comparison would spuriously
+/// fail. For example:
+///
+/// .. jupyter-execute::
+///
+///     import retworkx
+///
+///     first = retworkx.PyGraph()
+///     first.extend_from_weighted_edge_list([(0, 1, 0.1 + 0.2)])
+///     second = retworkx.PyGraph()
+///     second.extend_from_weighted_edge_list([(0, 1, 0.3)])
+///     matcher = retworkx.ToleranceMatcher(abs_tol=1e-9)
+///     print(retworkx.is_isomorphic(first, second, edge_matcher=matcher))
+///
+/// :param float rel_tol: The tolerance relative to the larger of the
+///     absolute values of the 2 numbers. Defaults to 0.
+/// :param float abs_tol: The absolute tolerance. Defaults to 0. With both
+///     tolerances set to 0 the numbers are compared exactly.
+/// :param key: An optional way to get the number to compare from a payload.
+///     If it's a callable it's called with the payload and must return a
+///     ``float``, otherwise it's used as an index, for example
+///     ``key="weight"`` compares ``payload["weight"]``. If it's not
+///     specified the payloads must be numbers.
+///
+/// :raises ValueError: If a tolerance is negative
+#[pyclass(module = "retworkx")]
+#[pyo3(text_signature = "(rel_tol=0.0, abs_tol=0.0, key=None)")]
+pub struct ToleranceMatcher {
+    tolerance: Tolerance,
+    key: Option<PyObject>,
+}
+
+impl ToleranceMatcher {
+    fn value(&self, py: Python, payload: &PyAny) -> PyResult<f64> {
+        match &self.key {
+            Some(key) => {
+                let key = key.as_ref(py);
+                if key.is_callable() {
+                    key.call1((payload,))?.extract()
+                } else {
+                    payload.get_item(key)?.extract()
+                }
+            }
+            None => payload.extract(),
+        }
+    }
+}
+
+#[pymethods]
+impl ToleranceMatcher {
+    #[new]
+    #[args(rel_tol = "0.0", abs_tol = "0.0", key = "None")]
+    fn new(rel_tol: f64, abs_tol: f64, key: Option<PyObject>) -> PyResult<Self> {
+        if rel_tol < 0.0 || abs_tol < 0.0 {
+            return Err(PyValueError::new_err("tolerances must be non-negative"));
+        }
+        Ok(ToleranceMatcher {
+            tolerance: Tolerance::new(rel_tol, abs_tol),
+            key,
+        })
+    }
+
+    /// The relative tolerance
+    #[getter]
+    fn rel_tol(&self) -> f64 {
+        self.tolerance.rel_tol
+    }
+
+    /// The absolute tolerance
+    #[getter]
+    fn abs_tol(&self) -> f64 {
+        self.tolerance.abs_tol
+    }
+
+    /// The key used to get the number to compare from a payload
+    #[getter]
+    fn key(&self, py: Python) -> PyObject {
+        self.key.clone().unwrap_or_else(|| py.None())
+    }
+
+    fn __call__(&self, py: Python, first: &PyAny, second: &PyAny) -> PyResult<bool> {
+        Ok(self
+            .tolerance
+            .is_close(self.value(py, first)?, self.value(py, second)?))
+    }
+}
+
+/// Call the matchers in order with the 2 payloads and return whether
+/// ``all_match`` payloads matched, stopping at the first matcher that decides
+/// the result.
+fn combine(py: Python, matchers: &[PyObject], args: &PyTuple, all_match: bool) -> PyResult<bool> {
+    for matcher in matchers {
+        if matcher.call1(py, args)?.is_true(py)? != all_match {
+            return Ok(!all_match);
+        }
+    }
+    Ok(all_match)
+}
+
+/// A matcher that matches when all of its matchers match
+///
+/// An instance of this class can be passed as the ``node_matcher`` or
+/// ``edge_matcher`` of the isomorphism functions. It's called with 2
+/// payloads and calls its matchers in order with them, it returns ``False``
+/// as soon as one of them returns ``False``. For example, to match the
+/// ``"weight"`` of edges within a tolerance and their ``"kind"`` exactly::
+///
+///     matcher = retworkx.AllOfMatcher(
+///         retworkx.ToleranceMatcher(rel_tol=1e-6, key="weight"),
+///         lambda a, b: a["kind"] == b["kind"],
+///     )
+///
+/// :param matchers: The matchers to combine, any callables taking 2 payloads,
+///     such as :class:`~retworkx.ToleranceMatcher`. Without any matcher
+///     every pair of payloads matches.
+#[pyclass(module = "retworkx")]
+#[pyo3(text_signature = "(*matchers)")]
+pub struct AllOfMatcher {
+    #[pyo3(get)]
+    matchers: Vec<PyObject>,
+}
+
+#[pymethods]
+impl AllOfMatcher {
+    #[new]
+    #[args(matchers = "*")]
+    fn new(matchers: &PyTuple) -> Self {
+        AllOfMatcher {
+            matchers: matchers.iter().map(|m| m.into()).collect(),
+        }
+    }
+
+    fn __call__(&self, py: Python, first: PyObject, second: PyObject) -> PyResult<bool> {
+        combine(py, &self.matchers, PyTuple::new(py, &[first, second]), true)
+    }
+}
+
+/// A matcher that matches when any of its matchers matches
+///
+/// An instance of this class can be passed as the ``node_matcher`` or
+/// ``edge_matcher`` of the isomorphism functions. It's called with 2
+/// payloads and calls its matchers in order with them, it returns ``True``
+/// as soon as one of them returns ``True``. For example, to match payloads
+/// that are either both ``None`` or numbers within a tolerance::
+///
+///     matcher = retworkx.AnyOfMatcher(
+///         lambda a, b: a is None and b is None,
+///         retworkx.ToleranceMatcher(abs_tol=1e-9),
+///     )
+///
+/// :param matchers: The matchers to combine, any callables taking 2 payloads,
+///     such as :class:`~retworkx.ToleranceMatcher`. Without any matcher no
+///     pair of payloads matches.
+#[pyclass(module = "retworkx")]
+#[pyo3(text_signature = "(*matchers)")]
+pub struct AnyOfMatcher {
+    #[pyo3(get)]
+    matchers: Vec<PyObject>,
+}
+
+#[pymethods]
+impl AnyOfMatcher {
+    #[new]
+    #[args(matchers = "*")]
+    fn new(matchers: &PyTuple) -> Self {
+        AnyOfMatcher {
+            matchers: matchers.iter().map(|m| m.into()).collect(),
+        }
+    }
+
+    fn __call__(&self, py: Python, first: PyObject, second: PyObject) -> PyResult<bool> {
+        combine(
+            py,
+            &self.matchers,
+            PyTuple::new(py, &[first, second]),
+            false,
+        )
+    }
+}
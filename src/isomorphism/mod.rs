@@ -12,15 +12,25 @@
 
 #![allow(clippy::too_many_arguments)]
 
+mod matchers;
 mod vf2;
 
-use crate::{digraph, graph};
+pub use matchers::{AllOfMatcher, AnyOfMatcher, ToleranceMatcher};
+
+use crate::{digraph, graph, StablePyGraph};
 
 use std::cmp::Ordering;
 
+use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
+use pyo3::types::PyTuple;
 use pyo3::Python;
 
+use petgraph::EdgeType;
+
+use retworkx_core::err::CallbackError;
+use retworkx_core::isomorphism::{canonical_certificate, weisfeiler_lehman_graph_hash};
+
 /// Determine if 2 directed graphs are isomorphic
 ///
 /// This checks if 2 graphs are isomorphic both structurally and also
@@ -443,3 +453,158 @@ pub fn graph_vf2_mapping(
         call_limit,
     )
 }
+
+/// Compute a canonical certificate of a directed graph
+///
+/// The certificate is a tuple of integers encoding the graph relabeled in a
+/// canonical order, found with an individualization-refinement search in the
+/// style of nauty. Two graphs are isomorphic if and only if their
+/// certificates are equal, and since the certificate is hashable it can be
+/// used to deduplicate a large collection of graphs without running
+/// pairwise isomorphism checks::
+///
+///     unique = {retworkx.digraph_canonical_certificate(g): g for g in graphs}
+///
+/// Only the structure of the graph is considered, node and edge weights are
+/// ignored. Parallel edges and self loops are taken into account.
+///
+/// :param PyDiGraph graph: The graph to compute the certificate of
+///
+/// :returns: The canonical certificate of the graph
+/// :rtype: tuple
+#[pyfunction]
+#[pyo3(text_signature = "(graph, /)")]
+pub fn digraph_canonical_certificate(py: Python, graph: &digraph::PyDiGraph) -> PyObject {
+    PyTuple::new(py, canonical_certificate(&graph.graph)).into()
+}
+
+/// Compute a canonical certificate of a undirected graph
+///
+/// The certificate is a tuple of integers encoding the graph relabeled in a
+/// canonical order, found with an individualization-refinement search in the
+/// style of nauty. Two graphs are isomorphic if and only if their
+/// certificates are equal, and since the certificate is hashable it can be
+/// used to deduplicate a large collection of graphs without running
+/// pairwise isomorphism checks::
+///
+///     unique = {retworkx.graph_canonical_certificate(g): g for g in graphs}
+///
+/// Only the structure of the graph is considered, node and edge weights are
+/// ignored. Parallel edges and self loops are taken into account.
+///
+/// :param PyGraph graph: The graph to compute the certificate of
+///
+/// :returns: The canonical certificate of the graph
+/// :rtype: tuple
+#[pyfunction]
+#[pyo3(text_signature = "(graph, /)")]
+pub fn graph_canonical_certificate(py: Python, graph: &graph::PyGraph) -> PyObject {
+    PyTuple::new(py, canonical_certificate(&graph.graph)).into()
+}
+
+fn wl_graph_hash<Ty: EdgeType>(
+    py: Python,
+    graph: &StablePyGraph<Ty>,
+    node_attr_fn: Option<PyObject>,
+    edge_attr_fn: Option<PyObject>,
+    iterations: usize,
+) -> PyResult<String> {
+    let label = |attr_fn: &Option<PyObject>, weight: &PyObject| -> PyResult<String> {
+        match attr_fn {
+            Some(attr_fn) => Ok(attr_fn.call1(py, (weight,))?.as_ref(py).str()?.to_string()),
+            None => Ok(String::new()),
+        }
+    };
+    weisfeiler_lehman_graph_hash(
+        graph,
+        |node| match node_attr_fn {
+            Some(_) => label(&node_attr_fn, &graph[node]),
+            None if graph.is_directed() => Ok(graph
+                .edges_directed(node, petgraph::Direction::Outgoing)
+                .chain(graph.edges_directed(node, petgraph::Direction::Incoming))
+                .count()
+                .to_string()),
+            None => Ok(graph.edges(node).count().to_string()),
+        },
+        |edge| label(&edge_attr_fn, edge.weight()),
+        iterations,
+    )
+    .map_err(|err| match err {
+        CallbackError::InvalidInput(err) => PyValueError::new_err(err.to_string()),
+        CallbackError::Callback(err) => err,
+    })
+}
+
+/// Compute the Weisfeiler-Lehman graph hash of a directed graph
+///
+/// The hash is built by iteratively relabeling every node with a hash of its
+/// own label and the sorted labels of its neighbors, then hashing the counts
+/// of the node labels found at each iteration [WL]_. Isomorphic graphs always
+/// have the same hash while non-isomorphic graphs get different hashes with
+/// high probability, so it can be used as a fast pre-filter key before
+/// running an expensive isomorphism check. The returned digest is stable
+/// across platforms and releases, but it is not a cryptographic hash.
+///
+/// :param PyDiGraph graph: The graph to hash
+/// :param callable node_attr_fn: An optional callable that will be passed the
+///     weight/data payload of a node and returns its initial label, the
+///     ``str()`` of the returned value is used. If not specified the degree
+///     of the node is used.
+/// :param callable edge_attr_fn: An optional callable that will be passed the
+///     weight/data payload of an edge and returns its label, the ``str()`` of
+///     the returned value is used. If not specified edges are not labeled.
+/// :param int iterations: The number of relabeling iterations to run, higher
+///     values take a larger neighborhood of every node into account.
+///     Must be at least 1. Default: ``3``.
+///
+/// :returns: A hex digest of the graph
+/// :rtype: str
+/// :raises ValueError: If ``iterations`` is 0
+#[pyfunction(iterations = "3")]
+#[pyo3(text_signature = "(graph, /, node_attr_fn=None, edge_attr_fn=None, iterations=3)")]
+pub fn digraph_weisfeiler_lehman_graph_hash(
+    py: Python,
+    graph: &digraph::PyDiGraph,
+    node_attr_fn: Option<PyObject>,
+    edge_attr_fn: Option<PyObject>,
+    iterations: usize,
+) -> PyResult<String> {
+    wl_graph_hash(py, &graph.graph, node_attr_fn, edge_attr_fn, iterations)
+}
+
+/// Compute the Weisfeiler-Lehman graph hash of a undirected graph
+///
+/// The hash is built by iteratively relabeling every node with a hash of its
+/// own label and the sorted labels of its neighbors, then hashing the counts
+/// of the node labels found at each iteration [WL]_. Isomorphic graphs always
+/// have the same hash while non-isomorphic graphs get different hashes with
+/// high probability, so it can be used as a fast pre-filter key before
+/// running an expensive isomorphism check. The returned digest is stable
+/// across platforms and releases, but it is not a cryptographic hash.
+///
+/// :param PyGraph graph: The graph to hash
+/// :param callable node_attr_fn: An optional callable that will be passed the
+///     weight/data payload of a node and returns its initial label, the
+///     ``str()`` of the returned value is used. If not specified the degree
+///     of the node is used.
+/// :param callable edge_attr_fn: An optional callable that will be passed the
+///     weight/data payload of an edge and returns its label, the ``str()`` of
+///     the returned value is used. If not specified edges are not labeled.
+/// :param int iterations: The number of relabeling iterations to run, higher
+///     values take a larger neighborhood of every node into account.
+///     Must be at least 1. Default: ``3``.
+///
+/// :returns: A hex digest of the graph
+/// :rtype: str
+/// :raises ValueError: If ``iterations`` is 0
+#[pyfunction(iterations = "3")]
+#[pyo3(text_signature = "(graph, /, node_attr_fn=None, edge_attr_fn=None, iterations=3)")]
+pub fn graph_weisfeiler_lehman_graph_hash(
+    py: Python,
+    graph: &graph::PyGraph,
+    node_attr_fn: Option<PyObject>,
+    edge_attr_fn: Option<PyObject>,
+    iterations: usize,
+) -> PyResult<String> {
+    wl_graph_hash(py, &graph.graph, node_attr_fn, edge_attr_fn, iterations)
+}
@@ -1198,6 +1198,50 @@ custom_hash_map_iter_impl!(
 );
 impl PyGCProtocol for CentralityMapping {}
 
+custom_hash_map_iter_impl!(
+    EdgeCurvatureMapping,
+    EdgeCurvatureMappingKeys,
+    EdgeCurvatureMappingValues,
+    EdgeCurvatureMappingItems,
+    curvatures,
+    curvatures_keys,
+    curvatures_values,
+    curvatures_items,
+    usize,
+    f64,
+    "A custom class for the return of curvatures of edges
+
+    This class is a container class for the results of functions that
+    return a mapping of integer edge indices to the float curvature of that
+    edge. It implements the Python mapping protocol so you can treat the
+    return as a read-only mapping/dict.
+    "
+);
+impl PyGCProtocol for EdgeCurvatureMapping {}
+
+custom_hash_map_iter_impl!(
+    EdgePolylineMapping,
+    EdgePolylineMappingKeys,
+    EdgePolylineMappingValues,
+    EdgePolylineMappingItems,
+    polylines,
+    polylines_keys,
+    polylines_values,
+    polylines_items,
+    usize,
+    Vec<[f64; 2]>,
+    "A custom class for the return of the polylines of edges
+
+    This class is a container class for the results of functions that
+    return a mapping of integer edge indices to the polyline the edge is
+    drawn as, a list of ``[x, y]`` points from the position of the source
+    of the edge to the position of its target. It implements the Python
+    mapping protocol so you can treat the return as a read-only
+    mapping/dict.
+    "
+);
+impl PyGCProtocol for EdgePolylineMapping {}
+
 custom_hash_map_iter_impl!(
     NodesCountMapping,
     NodesCountMappingKeys,
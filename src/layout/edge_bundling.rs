@@ -0,0 +1,281 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use hashbrown::HashMap;
+
+use petgraph::visit::{EdgeRef, IntoEdgeReferences};
+use petgraph::EdgeType;
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+use super::spring::Point;
+use crate::iterators::{EdgePolylineMapping, Pos2DMapping};
+use crate::StablePyGraph;
+
+/// The length of the larger side of the bounding box of the layout during
+/// the bundling, so the forces and the step size don't depend on the scale
+/// of the layout.
+const CANVAS_SIZE: f64 = 1000.0;
+
+/// The number of control points of every edge is multiplied by this after
+/// every cycle.
+const SUBDIVISION_RATE: usize = 2;
+
+/// The step size is multiplied by this after every cycle.
+const STEP_SIZE_RATE: f64 = 0.5;
+
+/// The number of iterations is multiplied by this after every cycle.
+const ITERATIONS_RATE: f64 = 2.0 / 3.0;
+
+/// Extract the positions of the nodes from either a :class:`Pos2DMapping` or
+/// a dictionary.
+pub fn extract_positions(pos: &PyAny) -> PyResult<HashMap<usize, Point>> {
+    match pos.extract::<PyRef<Pos2DMapping>>() {
+        Ok(mapping) => Ok(mapping.pos_map.iter().map(|(k, v)| (*k, *v)).collect()),
+        Err(_) => pos.extract(),
+    }
+}
+
+fn sub(a: Point, b: Point) -> Point {
+    [a[0] - b[0], a[1] - b[1]]
+}
+
+fn norm(a: Point) -> f64 {
+    a[0].hypot(a[1])
+}
+
+fn midpoint(a: Point, b: Point) -> Point {
+    [(a[0] + b[0]) / 2.0, (a[1] + b[1]) / 2.0]
+}
+
+/// An edge being bundled, with its control points between its endpoints.
+struct Edge {
+    source: Point,
+    target: Point,
+    points: Vec<Point>,
+}
+
+impl Edge {
+    fn vector(&self) -> Point {
+        sub(self.target, self.source)
+    }
+
+    fn point(&self, i: usize) -> Point {
+        if i == 0 {
+            self.source
+        } else if i > self.points.len() {
+            self.target
+        } else {
+            self.points[i - 1]
+        }
+    }
+
+    /// Replace the control points by `count` points evenly spaced along the
+    /// current polyline of the edge.
+    fn subdivide(&mut self, count: usize) {
+        let polyline: Vec<Point> = (0..self.points.len() + 2).map(|i| self.point(i)).collect();
+        let length: f64 = polyline.windows(2).map(|w| norm(sub(w[1], w[0]))).sum();
+        let spacing = length / (count + 1) as f64;
+        let mut points = Vec::with_capacity(count);
+        let mut walked = 0.0;
+        let mut next = spacing;
+        for w in polyline.windows(2) {
+            let segment = norm(sub(w[1], w[0]));
+            while points.len() < count && next <= walked + segment {
+                let t = if segment > 0.0 {
+                    (next - walked) / segment
+                } else {
+                    0.0
+                };
+                points.push([
+                    w[0][0] + t * (w[1][0] - w[0][0]),
+                    w[0][1] + t * (w[1][1] - w[0][1]),
+                ]);
+                next += spacing;
+            }
+            walked += segment;
+        }
+        // Rounding can leave the last points past the end
+        while points.len() < count {
+            points.push(self.target);
+        }
+        self.points = points;
+    }
+}
+
+/// How well the edge `q` projected on the line of the edge `p` overlaps it.
+fn visibility(p: &Edge, q: &Edge) -> f64 {
+    let vector = p.vector();
+    let length_sq = vector[0] * vector[0] + vector[1] * vector[1];
+    let project = |point: Point| {
+        let offset = sub(point, p.source);
+        let t = (offset[0] * vector[0] + offset[1] * vector[1]) / length_sq;
+        [p.source[0] + t * vector[0], p.source[1] + t * vector[1]]
+    };
+    let start = project(q.source);
+    let end = project(q.target);
+    let span = norm(sub(end, start));
+    if span == 0.0 {
+        return 0.0;
+    }
+    let offset = norm(sub(midpoint(p.source, p.target), midpoint(start, end)));
+    (1.0 - 2.0 * offset / span).max(0.0)
+}
+
+/// The product of the angle, scale, position and visibility compatibilities
+/// of two edges.
+fn compatibility(p: &Edge, q: &Edge) -> f64 {
+    let (p_vector, q_vector) = (p.vector(), q.vector());
+    let (p_length, q_length) = (norm(p_vector), norm(q_vector));
+    let angle =
+        (p_vector[0] * q_vector[0] + p_vector[1] * q_vector[1]).abs() / (p_length * q_length);
+    let average = (p_length + q_length) / 2.0;
+    let scale = 2.0 / (average / p_length.min(q_length) + p_length.max(q_length) / average);
+    let distance = norm(sub(
+        midpoint(p.source, p.target),
+        midpoint(q.source, q.target),
+    ));
+    let position = average / (average + distance);
+    angle * scale * position * visibility(p, q).min(visibility(q, p))
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn edge_bundling<Ty: EdgeType>(
+    graph: &StablePyGraph<Ty>,
+    pos: HashMap<usize, Point>,
+    stiffness: f64,
+    compatibility_threshold: f64,
+    cycles: usize,
+    iterations: usize,
+    step_size: f64,
+) -> PyResult<EdgePolylineMapping> {
+    let position = |node: usize| -> PyResult<Point> {
+        pos.get(&node)
+            .copied()
+            .ok_or_else(|| PyValueError::new_err(format!("No position given for node {}", node)))
+    };
+    let mut ids: Vec<usize> = Vec::with_capacity(graph.edge_count());
+    let mut endpoints: Vec<(Point, Point)> = Vec::with_capacity(graph.edge_count());
+    let mut edges: Vec<Edge> = Vec::with_capacity(graph.edge_count());
+    for edge in graph.edge_references() {
+        ids.push(edge.id().index());
+        endpoints.push((
+            position(edge.source().index())?,
+            position(edge.target().index())?,
+        ));
+        edges.push(Edge {
+            source: position(edge.source().index())?,
+            target: position(edge.target().index())?,
+            points: Vec::new(),
+        });
+    }
+
+    // Move the layout into a square canvas of a fixed size
+    let (mut min, mut max) = ([f64::INFINITY; 2], [f64::NEG_INFINITY; 2]);
+    let points = endpoints
+        .iter()
+        .flat_map(|(source, target)| std::iter::once(*source).chain(std::iter::once(*target)));
+    for point in points {
+        for axis in 0..2 {
+            min[axis] = min[axis].min(point[axis]);
+            max[axis] = max[axis].max(point[axis]);
+        }
+    }
+    let side = (max[0] - min[0]).max(max[1] - min[1]);
+    let factor = if side > 0.0 { CANVAS_SIZE / side } else { 1.0 };
+    let to_canvas = |point: Point| [(point[0] - min[0]) * factor, (point[1] - min[1]) * factor];
+    let from_canvas = |point: Point| [point[0] / factor + min[0], point[1] / factor + min[1]];
+    for edge in edges.iter_mut() {
+        edge.source = to_canvas(edge.source);
+        edge.target = to_canvas(edge.target);
+    }
+
+    // The compatible pairs of edges, and whether they point in opposite
+    // directions so their control points are matched in reverse order.
+    // Self loops and edges of length 0 aren't bundled.
+    let bundled: Vec<usize> = (0..edges.len())
+        .filter(|&e| norm(edges[e].vector()) > 0.0)
+        .collect();
+    let mut compatible: Vec<Vec<(usize, bool)>> = vec![Vec::new(); edges.len()];
+    for (i, &p) in bundled.iter().enumerate() {
+        for &q in &bundled[i + 1..] {
+            if compatibility(&edges[p], &edges[q]) >= compatibility_threshold {
+                let (p_vector, q_vector) = (edges[p].vector(), edges[q].vector());
+                let reversed = p_vector[0] * q_vector[0] + p_vector[1] * q_vector[1] < 0.0;
+                compatible[p].push((q, reversed));
+                compatible[q].push((p, reversed));
+            }
+        }
+    }
+    let lengths: Vec<f64> = edges.iter().map(|edge| norm(edge.vector())).collect();
+
+    let mut count = 1;
+    let mut step = step_size;
+    let mut cycle_iterations = iterations as f64;
+    for _ in 0..cycles {
+        for &e in &bundled {
+            edges[e].subdivide(count);
+        }
+        for _ in 0..cycle_iterations.round() as usize {
+            let moved: Vec<Vec<Point>> = bundled
+                .iter()
+                .map(|&e| {
+                    let edge = &edges[e];
+                    let spring = stiffness / (lengths[e] * (count + 1) as f64);
+                    (1..=count)
+                        .map(|i| {
+                            let point = edge.point(i);
+                            let prev = sub(edge.point(i - 1), point);
+                            let next = sub(edge.point(i + 1), point);
+                            let mut force =
+                                [spring * (prev[0] + next[0]), spring * (prev[1] + next[1])];
+                            // Every compatible edge pulls the point towards its
+                            // matching control point with a unit force
+                            for &(q, reversed) in &compatible[e] {
+                                let j = if reversed { count + 1 - i } else { i };
+                                let pull = sub(edges[q].point(j), point);
+                                let distance = norm(pull);
+                                if distance > 1e-6 {
+                                    force[0] += pull[0] / distance;
+                                    force[1] += pull[1] / distance;
+                                }
+                            }
+                            [point[0] + step * force[0], point[1] + step * force[1]]
+                        })
+                        .collect()
+                })
+                .collect();
+            for (&e, points) in bundled.iter().zip(moved) {
+                edges[e].points = points;
+            }
+        }
+        count *= SUBDIVISION_RATE;
+        step *= STEP_SIZE_RATE;
+        cycle_iterations *= ITERATIONS_RATE;
+    }
+
+    Ok(EdgePolylineMapping {
+        polylines: ids
+            .into_iter()
+            .zip(endpoints)
+            .zip(edges)
+            .map(|((id, (source, target)), edge)| {
+                let mut polyline = Vec::with_capacity(edge.points.len() + 2);
+                polyline.push(source);
+                polyline.extend(edge.points.into_iter().map(from_canvas));
+                polyline.push(target);
+                (id, polyline)
+            })
+            .collect(),
+    })
+}
@@ -0,0 +1,469 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use std::convert::Infallible;
+
+use hashbrown::HashMap;
+
+use petgraph::graph::{NodeIndex, UnGraph};
+use petgraph::visit::{EdgeRef, IntoEdgeReferences};
+use petgraph::EdgeType;
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+use retworkx_core::dictmap::DictMap;
+use retworkx_core::shortest_path::{all_pairs_dijkstra_path_lengths_into, dijkstra};
+use retworkx_core::Convergence;
+
+use super::spring::{recenter, rescale, Point};
+use crate::algorithm_info::AlgorithmInfo;
+use crate::iterators::Pos2DMapping;
+use crate::{weight_callable, StablePyGraph};
+
+/// The number of power iterations used to compute the pivot MDS layout.
+const PIVOT_MDS_ITERATIONS: usize = 100;
+
+/// The target distances of the springs attached to every node, either to
+/// all other nodes as a dense `n x n` matrix or only to some of them.
+enum Springs {
+    AllPairs(Vec<f64>),
+    Sparse(Vec<Vec<(usize, f64)>>),
+}
+
+/// The first and second derivatives of the energy with respect to the
+/// position of a node.
+#[derive(Default)]
+struct Derivatives {
+    dx: f64,
+    dy: f64,
+    dxx: f64,
+    dxy: f64,
+    dyy: f64,
+    stiffness: f64,
+    energy: f64,
+    count: usize,
+}
+
+/// Compute the derivatives of the energy of the springs attached to `node`,
+/// where a spring of length `l` has a stiffness of `1 / l^2`.
+fn derivatives<I>(node: usize, pos: &[Point], springs: I) -> Derivatives
+where
+    I: Iterator<Item = (usize, f64)>,
+{
+    let [x, y] = pos[node];
+    let mut out = Derivatives::default();
+    for (other, length) in springs {
+        let delta_x = x - pos[other][0];
+        let delta_y = y - pos[other][1];
+        let dist = (delta_x * delta_x + delta_y * delta_y).sqrt();
+        out.count += 1;
+        let k = 1.0 / (length * length);
+        out.stiffness += k;
+        out.energy += k * (dist - length) * (dist - length);
+        if dist == 0.0 {
+            continue;
+        }
+        let dist_cubed = dist * dist * dist;
+        out.dx += k * (delta_x - length * delta_x / dist);
+        out.dy += k * (delta_y - length * delta_y / dist);
+        out.dxx += k * (1.0 - length * delta_y * delta_y / dist_cubed);
+        out.dxy += k * length * delta_x * delta_y / dist_cubed;
+        out.dyy += k * (1.0 - length * delta_x * delta_x / dist_cubed);
+    }
+    out
+}
+
+/// Compute the energy of the springs attached to a node at `point`.
+fn energy<I>(point: Point, pos: &[Point], springs: I) -> f64
+where
+    I: Iterator<Item = (usize, f64)>,
+{
+    springs
+        .map(|(other, length)| {
+            let dist = (point[0] - pos[other][0]).hypot(point[1] - pos[other][1]);
+            (dist - length) * (dist - length) / (length * length)
+        })
+        .sum()
+}
+
+impl Springs {
+    /// Iterate over the other nodes and the lengths of the springs attached
+    /// to `node`, out of `n` nodes.
+    fn springs<'a>(&'a self, node: usize, n: usize) -> Box<dyn Iterator<Item = (usize, f64)> + 'a> {
+        match self {
+            Springs::AllPairs(matrix) => Box::new(
+                matrix[node * n..(node + 1) * n]
+                    .iter()
+                    .enumerate()
+                    .filter(move |(other, _)| *other != node)
+                    .map(|(other, length)| (other, *length)),
+            ),
+            Springs::Sparse(lists) => Box::new(lists[node].iter().copied()),
+        }
+    }
+}
+
+/// Move every node in turn with a Newton-Raphson step on its position until
+/// the largest gradient of the energy with respect to the position of a node,
+/// divided by its number of springs, is less than `tol`.
+fn minimize(springs: &Springs, pos: &mut [Point], max_iter: usize, tol: f64) -> Convergence {
+    let mut convergence = Convergence {
+        iterations: 0,
+        error: f64::INFINITY,
+        converged: false,
+    };
+    for iteration in 1..=max_iter {
+        let mut error: f64 = 0.0;
+        for node in 0..pos.len() {
+            let d = derivatives(node, pos, springs.springs(node, pos.len()));
+            if d.count == 0 {
+                continue;
+            }
+            error = error.max((d.dx * d.dx + d.dy * d.dy).sqrt() / d.count as f64);
+            let [x, y] = pos[node];
+            let det = d.dxx * d.dyy - d.dxy * d.dxy;
+            if d.dxx > 0.0 && det > 0.0 {
+                let newton = [
+                    x + (d.dxy * d.dy - d.dyy * d.dx) / det,
+                    y + (d.dxy * d.dx - d.dxx * d.dy) / det,
+                ];
+                if energy(newton, pos, springs.springs(node, pos.len())) < d.energy {
+                    pos[node] = newton;
+                    continue;
+                }
+            }
+            // Far from the minimum the Newton-Raphson step can overshoot or
+            // go uphill, fall back to the gradient step scaled by the
+            // stiffness of the springs which is the stress majorization step
+            // and never increases the energy
+            pos[node] = [x - d.dx / d.stiffness, y - d.dy / d.stiffness];
+        }
+        convergence = Convergence {
+            iterations: iteration,
+            error,
+            converged: error < tol,
+        };
+        if convergence.converged {
+            break;
+        }
+    }
+    convergence
+}
+
+/// Replace the infinite distances between disconnected nodes by the largest
+/// finite distance, so the components are placed next to each other.
+fn fill_unreachable(rows: &mut [&mut [f64]]) {
+    let largest = rows
+        .iter()
+        .flat_map(|row| row.iter())
+        .filter(|d| d.is_finite())
+        .fold(0.0f64, |largest, d| largest.max(*d));
+    let largest = if largest > 0.0 { largest } else { 1.0 };
+    for d in rows.iter_mut().flat_map(|row| row.iter_mut()) {
+        if !d.is_finite() {
+            *d = largest;
+        }
+    }
+}
+
+/// Choose `count` landmarks which are as far as possible from each other:
+/// the first node and then repeatedly the node furthest from the chosen ones.
+/// Returns the landmarks and the rows of their distances to every node.
+fn landmark_distances(lengths: &UnGraph<(), f64>, count: usize) -> Vec<(usize, Vec<f64>)> {
+    let n = lengths.node_count();
+    let mut rows: Vec<(usize, Vec<f64>)> = Vec::with_capacity(count);
+    let mut nearest = vec![f64::INFINITY; n];
+    let mut next = 0;
+    while rows.len() < count.min(n) {
+        let distances: DictMap<NodeIndex, f64> = dijkstra(
+            lengths,
+            NodeIndex::new(next),
+            None,
+            |e| Ok::<f64, Infallible>(*e.weight()),
+            None,
+        )
+        .unwrap();
+        let mut row = vec![f64::INFINITY; n];
+        for (node, length) in distances {
+            row[node.index()] = length;
+        }
+        for (nearest, d) in nearest.iter_mut().zip(&row) {
+            *nearest = nearest.min(*d);
+        }
+        rows.push((next, row));
+        // Unreachable nodes are the furthest, so every component gets a
+        // landmark first
+        next = (0..n).fold(0, |best, node| {
+            if nearest[node] > nearest[best] {
+                node
+            } else {
+                best
+            }
+        });
+        if nearest[next] == 0.0 {
+            break;
+        }
+    }
+    rows
+}
+
+/// Compute a layout from the distances between the landmarks and every node
+/// with pivot MDS [1]: the classical multidimensional scaling of the double
+/// centered squared distances, restricted to the landmarks. Returns `None`
+/// if there are less than 3 landmarks.
+///
+/// [1] U. Brandes and C. Pich, "Eigensolver methods for progressive
+/// multidimensional scaling of large data", Graph Drawing 2006, pp. 42-53
+fn pivot_mds(rows: &[(usize, Vec<f64>)]) -> Option<Vec<Point>> {
+    let k = rows.len();
+    if k < 3 {
+        return None;
+    }
+    let n = rows[0].1.len();
+    let mut centered: Vec<Vec<f64>> = rows
+        .iter()
+        .map(|(_, row)| row.iter().map(|d| d * d).collect())
+        .collect();
+    let row_means: Vec<f64> = centered
+        .iter()
+        .map(|row| row.iter().sum::<f64>() / n as f64)
+        .collect();
+    let col_means: Vec<f64> = (0..n)
+        .map(|node| centered.iter().map(|row| row[node]).sum::<f64>() / k as f64)
+        .collect();
+    let mean = row_means.iter().sum::<f64>() / k as f64;
+    for (row, row_mean) in centered.iter_mut().zip(&row_means) {
+        for (d, col_mean) in row.iter_mut().zip(&col_means) {
+            *d = -0.5 * (*d - row_mean - col_mean + mean);
+        }
+    }
+    // The two dominant eigenvectors of the small k x k matrix C C^T by power
+    // iteration, deflating the first from the second
+    let product: Vec<Vec<f64>> = centered
+        .iter()
+        .map(|a| {
+            centered
+                .iter()
+                .map(|b| a.iter().zip(b).map(|(x, y)| x * y).sum())
+                .collect()
+        })
+        .collect();
+    let mut axes: Vec<Vec<f64>> = Vec::with_capacity(2);
+    for axis in 0..2 {
+        let mut v: Vec<f64> = (0..k)
+            .map(|i| if (i + axis) % 2 == 0 { 1.0 } else { 0.5 })
+            .collect();
+        for _ in 0..PIVOT_MDS_ITERATIONS {
+            let mut next: Vec<f64> = product
+                .iter()
+                .map(|row| row.iter().zip(&v).map(|(x, y)| x * y).sum())
+                .collect();
+            for prev in &axes {
+                let overlap: f64 = next.iter().zip(prev).map(|(x, y)| x * y).sum();
+                for (x, y) in next.iter_mut().zip(prev) {
+                    *x -= overlap * y;
+                }
+            }
+            let norm = next.iter().map(|x| x * x).sum::<f64>().sqrt();
+            if norm == 0.0 {
+                return None;
+            }
+            next.iter_mut().for_each(|x| *x /= norm);
+            v = next;
+        }
+        axes.push(v);
+    }
+    let mut pos: Vec<Point> = (0..n)
+        .map(|node| {
+            let coord = |axis: &Vec<f64>| -> f64 {
+                centered
+                    .iter()
+                    .zip(axis)
+                    .map(|(row, weight)| row[node] * weight)
+                    .sum()
+            };
+            [coord(&axes[0]), coord(&axes[1])]
+        })
+        .collect();
+    // Scale the layout so the first two landmarks are at their distance
+    let (first, second) = (rows[0].0, rows[1].0);
+    let dist = (pos[first][0] - pos[second][0]).hypot(pos[first][1] - pos[second][1]);
+    if dist == 0.0 {
+        return None;
+    }
+    let factor = rows[0].1[second] / dist;
+    for point in pos.iter_mut() {
+        point[0] *= factor;
+        point[1] *= factor;
+    }
+    Some(pos)
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn kamada_kawai_layout<Ty: EdgeType>(
+    py: Python,
+    graph: &StablePyGraph<Ty>,
+    pos: Option<HashMap<usize, Point>>,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+    landmarks: Option<usize>,
+    max_iter: usize,
+    tol: f64,
+    scale: Option<f64>,
+    center: Option<Point>,
+) -> PyResult<(Pos2DMapping, AlgorithmInfo)> {
+    let info = AlgorithmInfo::new(
+        "kamada_kawai_layout",
+        if landmarks.is_some() {
+            "Newton-Raphson on the stress of neighbors and landmarks"
+        } else {
+            "Newton-Raphson on the stress of all pairs"
+        },
+    )
+    .parameter(py, "default_weight", default_weight)
+    .parameter(py, "landmarks", landmarks)
+    .parameter(py, "max_iter", max_iter)
+    .parameter(py, "tol", tol)
+    .parameter(py, "scale", scale)
+    .parameter(py, "center", center.map(|center| center.to_vec()));
+
+    // The undirected graph of the edge lengths with the nodes numbered in
+    // node index order
+    let n = graph.node_count();
+    let index: HashMap<usize, usize> = graph
+        .node_indices()
+        .enumerate()
+        .map(|(i, node)| (node.index(), i))
+        .collect();
+    let mut lengths: UnGraph<(), f64> = UnGraph::with_capacity(n, graph.edge_count());
+    for _ in 0..n {
+        lengths.add_node(());
+    }
+    for edge in graph.edge_references() {
+        let weight = weight_callable(py, &weight_fn, edge.weight(), default_weight)?;
+        if weight <= 0.0 || !weight.is_finite() {
+            return Err(PyValueError::new_err(
+                "Kamada-Kawai layout requires positive finite edge weights",
+            ));
+        }
+        let source = index[&edge.source().index()];
+        let target = index[&edge.target().index()];
+        if source != target {
+            lengths.add_edge(NodeIndex::new(source), NodeIndex::new(target), weight);
+        }
+    }
+
+    let (springs, start) = match landmarks {
+        None => {
+            let mut matrix = vec![0.0; n * n];
+            all_pairs_dijkstra_path_lengths_into(
+                &lengths,
+                |e| Ok::<f64, Infallible>(*e.weight()),
+                &mut matrix,
+                300,
+            )
+            .unwrap();
+            fill_unreachable(&mut [&mut matrix]);
+            (Springs::AllPairs(matrix), None)
+        }
+        Some(count) => {
+            // Every node is attached to its neighbors and to the landmarks
+            let mut lists: Vec<HashMap<usize, f64>> = vec![HashMap::new(); n];
+            for edge in lengths.edge_references() {
+                let source = edge.source().index();
+                let target = edge.target().index();
+                let weight = *edge.weight();
+                for &(u, v) in &[(source, target), (target, source)] {
+                    let length = lists[u].entry(v).or_insert(weight);
+                    *length = length.min(weight);
+                }
+            }
+            let mut rows = landmark_distances(&lengths, count);
+            fill_unreachable(
+                &mut rows
+                    .iter_mut()
+                    .map(|(_, row)| row.as_mut_slice())
+                    .collect::<Vec<_>>(),
+            );
+            let start = pivot_mds(&rows);
+            for (landmark, row) in rows {
+                for (node, length) in row.into_iter().enumerate() {
+                    if node != landmark {
+                        lists[node].insert(landmark, length);
+                        lists[landmark].insert(node, length);
+                    }
+                }
+            }
+            let springs = Springs::Sparse(
+                lists
+                    .into_iter()
+                    .map(|list| {
+                        let mut list: Vec<(usize, f64)> = list.into_iter().collect();
+                        list.sort_unstable_by_key(|(other, _)| *other);
+                        list
+                    })
+                    .collect(),
+            );
+            (springs, start)
+        }
+    };
+
+    // Unless initial positions are given start from the pivot MDS of the
+    // landmarks, as the springs to the landmarks alone get stuck in folded
+    // layouts, or from a circle whose diameter is the largest target distance
+    let largest = match &springs {
+        Springs::AllPairs(matrix) => matrix.iter().fold(0.0f64, |a, b| a.max(*b)),
+        Springs::Sparse(lists) => lists.iter().flatten().fold(0.0f64, |a, (_, b)| a.max(*b)),
+    };
+    let radius = if largest > 0.0 { largest / 2.0 } else { 1.0 };
+    let initial = pos.unwrap_or_default();
+    let mut positions: Vec<Point> = graph
+        .node_indices()
+        .enumerate()
+        .map(|(i, node)| match (initial.get(&node.index()), &start) {
+            (Some(point), _) => *point,
+            (None, Some(start)) => start[i],
+            (None, None) => {
+                let angle = 2.0 * std::f64::consts::PI * i as f64 / n as f64;
+                [radius * angle.cos(), radius * angle.sin()]
+            }
+        })
+        .collect();
+
+    let convergence = minimize(&springs, &mut positions, max_iter, tol);
+
+    match scale {
+        Some(scale) => rescale(&mut positions, scale, (0..n).collect()),
+        // The energy doesn't depend on the translation of the layout, keep
+        // it around the origin
+        None if n > 0 => {
+            let mean_x = positions.iter().map(|p| p[0]).sum::<f64>() / n as f64;
+            let mean_y = positions.iter().map(|p| p[1]).sum::<f64>() / n as f64;
+            recenter(&mut positions, [-mean_x, -mean_y]);
+        }
+        None => (),
+    }
+
+    if let Some(center) = center {
+        recenter(&mut positions, center);
+    }
+
+    let pos = Pos2DMapping {
+        pos_map: graph
+            .node_indices()
+            .map(|n| n.index())
+            .zip(positions)
+            .collect(),
+    };
+    Ok((pos, info.convergence(convergence, tol)))
+}
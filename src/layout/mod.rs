@@ -12,20 +12,27 @@
 
 mod bipartite;
 mod circular;
+mod edge_bundling;
+mod kamada_kawai;
+mod planar;
 mod random;
 mod shell;
+mod spectral;
 mod spiral;
 mod spring;
+mod sugiyama;
 
 use crate::{digraph, graph};
 use spring::Point;
+pub use sugiyama::SugiyamaLayout;
 
 use hashbrown::{HashMap, HashSet};
 
 use pyo3::prelude::*;
 use pyo3::Python;
 
-use crate::iterators::Pos2DMapping;
+use crate::algorithm_info::explained;
+use crate::iterators::{EdgePolylineMapping, Pos2DMapping};
 
 /// Position nodes using Fruchterman-Reingold force-directed algorithm.
 ///
@@ -65,15 +72,22 @@ use crate::iterators::Pos2DMapping;
 ///     performed. (``default=1.0``)
 /// :param list center: Coordinate pair around which to center
 ///     the layout. Not used unless fixed is ``None``. (``default=None``)
-/// :param int seed: An optional seed to use for the random number generator
+/// :param int seed: An optional seed to use for the random number generator.
+///     If not set a seed is drawn from the operating system, it's recorded
+///     when ``explain`` is set.
+/// :param bool explain: If set to ``True`` an
+///     :class:`~retworkx.AlgorithmInfo` recording how the layout was run is
+///     returned along with the positions (``default=False``)
 ///
-/// :returns: A dictionary of positions keyed by node id.
+/// :returns: A dictionary of positions keyed by node id. If ``explain`` is
+///     ``True`` a tuple of this and an :class:`~retworkx.AlgorithmInfo` is
+///     returned instead.
 /// :rtype: dict
 #[pyfunction]
 #[pyo3(
     text_signature = "(graph, pos=None, fixed=None, k=None, repulsive_exponent=2, adaptive_cooling=True,
                      num_iter=50, tol=1e-6, weight_fn=None, default_weight=1, scale=1,
-                     center=None, seed=None, /)"
+                     center=None, seed=None, explain=False, /)"
 )]
 #[allow(clippy::too_many_arguments)]
 pub fn graph_spring_layout(
@@ -91,8 +105,9 @@ pub fn graph_spring_layout(
     scale: Option<f64>,
     center: Option<Point>,
     seed: Option<u64>,
-) -> PyResult<Pos2DMapping> {
-    spring::spring_layout(
+    explain: Option<bool>,
+) -> PyResult<PyObject> {
+    let (pos, info) = spring::spring_layout(
         py,
         &graph.graph,
         pos,
@@ -107,7 +122,8 @@ pub fn graph_spring_layout(
         scale,
         center,
         seed,
-    )
+    )?;
+    explained(py, pos, info, explain.unwrap_or(false))
 }
 
 /// Position nodes using Fruchterman-Reingold force-directed algorithm.
@@ -148,15 +164,22 @@ pub fn graph_spring_layout(
 ///     performed. (``default=1.0``)
 /// :param list center: Coordinate pair around which to center
 ///     the layout. Not used unless fixed is ``None``. (``default=None``)
-/// :param int seed: An optional seed to use for the random number generator
+/// :param int seed: An optional seed to use for the random number generator.
+///     If not set a seed is drawn from the operating system, it's recorded
+///     when ``explain`` is set.
+/// :param bool explain: If set to ``True`` an
+///     :class:`~retworkx.AlgorithmInfo` recording how the layout was run is
+///     returned along with the positions (``default=False``)
 ///
-/// :returns: A dictionary of positions keyed by node id.
+/// :returns: A dictionary of positions keyed by node id. If ``explain`` is
+///     ``True`` a tuple of this and an :class:`~retworkx.AlgorithmInfo` is
+///     returned instead.
 /// :rtype: dict
 #[pyfunction]
 #[pyo3(
     text_signature = "(graph, pos=None, fixed=None, k=None, repulsive_exponent=2, adaptive_cooling=True,
                      num_iter=50, tol=1e-6, weight_fn=None, default_weight=1, scale=1,
-                     center=None, seed=None, /)"
+                     center=None, seed=None, explain=False, /)"
 )]
 #[allow(clippy::too_many_arguments)]
 pub fn digraph_spring_layout(
@@ -174,8 +197,9 @@ pub fn digraph_spring_layout(
     scale: Option<f64>,
     center: Option<Point>,
     seed: Option<u64>,
-) -> PyResult<Pos2DMapping> {
-    spring::spring_layout(
+    explain: Option<bool>,
+) -> PyResult<PyObject> {
+    let (pos, info) = spring::spring_layout(
         py,
         &graph.graph,
         pos,
@@ -190,7 +214,8 @@ pub fn digraph_spring_layout(
         scale,
         center,
         seed,
-    )
+    )?;
+    explained(py, pos, info, explain.unwrap_or(false))
 }
 
 /// Generate a random layout
@@ -441,3 +466,593 @@ pub fn digraph_spiral_layout(
 ) -> Pos2DMapping {
     spiral::spiral_layout(&graph.graph, scale, center, resolution, equidistant)
 }
+
+/// Generate a planar layout of the graph
+///
+/// The nodes are positioned on a grid so that the edges, drawn as straight
+/// lines, don't cross. The drawing is computed from a planar embedding of the
+/// graph (see :func:`~retworkx.graph_check_planarity`) with the shift method
+/// of Chrobak and Payne [1]_ in linear time.
+///
+/// :param PyGraph graph: The graph to generate the layout for
+/// :param float scale: An optional scaling factor to scale positions
+/// :param tuple center: An optional center position. This is a 2 tuple of two
+///     ``float`` values for the center position
+///
+/// :returns: The planar layout of the graph.
+/// :rtype: Pos2DMapping
+///
+/// :raises ValueError: If the graph isn't planar
+///
+/// .. [1] M. Chrobak and T.H. Payne, "A Linear-time Algorithm for Drawing
+///     a Planar Graph on a Grid", 1989,
+///     http://citeseerx.ist.psu.edu/viewdoc/summary?doi=10.1.1.51.6677
+#[pyfunction]
+#[pyo3(text_signature = "(graph, /, scale=1, center=None)")]
+pub fn graph_planar_layout(
+    graph: &graph::PyGraph,
+    scale: Option<f64>,
+    center: Option<Point>,
+) -> PyResult<Pos2DMapping> {
+    planar::planar_layout(&graph.graph, scale, center)
+}
+
+/// Generate a planar layout of the graph
+///
+/// The nodes are positioned on a grid so that the edges, drawn as straight
+/// lines, don't cross. The direction of the edges is ignored. The drawing is
+/// computed from a planar embedding of the graph (see
+/// :func:`~retworkx.digraph_check_planarity`) with the shift method of
+/// Chrobak and Payne [1]_ in linear time.
+///
+/// :param PyDiGraph graph: The graph to generate the layout for
+/// :param float scale: An optional scaling factor to scale positions
+/// :param tuple center: An optional center position. This is a 2 tuple of two
+///     ``float`` values for the center position
+///
+/// :returns: The planar layout of the graph.
+/// :rtype: Pos2DMapping
+///
+/// :raises ValueError: If the graph isn't planar
+///
+/// .. [1] M. Chrobak and T.H. Payne, "A Linear-time Algorithm for Drawing
+///     a Planar Graph on a Grid", 1989,
+///     http://citeseerx.ist.psu.edu/viewdoc/summary?doi=10.1.1.51.6677
+#[pyfunction]
+#[pyo3(text_signature = "(graph, /, scale=1, center=None)")]
+pub fn digraph_planar_layout(
+    graph: &digraph::PyDiGraph,
+    scale: Option<f64>,
+    center: Option<Point>,
+) -> PyResult<Pos2DMapping> {
+    planar::planar_layout(&graph.graph, scale, center)
+}
+
+/// Generate a spectral layout of the graph
+///
+/// The coordinates of the nodes are the entries of the eigenvectors of the
+/// second and third smallest eigenvalues of the Laplacian matrix of the
+/// graph, which places nodes with heavy edges between them close together.
+/// The eigenvectors are computed with the LOBPCG method, which only
+/// multiplies vectors by the sparse Laplacian matrix, so it scales to large
+/// graphs. The smallest eigenvalue is 0 for every connected component, so
+/// the coordinates of a graph with several components mostly separate the
+/// components.
+///
+/// :param PyGraph graph: The graph to generate the layout for
+/// :param weight_fn: An optional weight function for an edge. It will accept
+///     a single argument, the edge's weight object and will return a float
+///     which will be used to represent the weight of the edge. The weights
+///     must be non-negative.
+/// :param float default_weight: If ``weight_fn`` isn't specified this
+///     optional float value will be used for the weight of each edge
+/// :param float scale: An optional scaling factor to scale positions
+/// :param tuple center: An optional center position. This is a 2 tuple of two
+///     ``float`` values for the center position
+/// :param int max_iter: The maximum number of iterations of LOBPCG
+/// :param float tol: The error tolerance used when checking for convergence,
+///     the iteration stops once the residual norm of every eigenvector is
+///     less than ``tol`` times twice the largest weighted degree.
+/// :param bool explain: If set to ``True`` an
+///     :class:`~retworkx.AlgorithmInfo` recording how the layout was run is
+///     returned along with the positions
+///
+/// :returns: The spectral layout of the graph. If ``explain`` is ``True`` a
+///     tuple of this and an :class:`~retworkx.AlgorithmInfo` is returned
+///     instead.
+/// :rtype: Pos2DMapping
+///
+/// :raises FailedToConverge: If LOBPCG doesn't converge within ``max_iter``
+///     iterations
+#[pyfunction(
+    default_weight = "1.0",
+    max_iter = "1000",
+    tol = "1e-6",
+    explain = "false"
+)]
+#[pyo3(
+    text_signature = "(graph, /, weight_fn=None, default_weight=1.0, scale=1, center=None,
+                     max_iter=1000, tol=1e-6, explain=False)"
+)]
+#[allow(clippy::too_many_arguments)]
+pub fn graph_spectral_layout(
+    py: Python,
+    graph: &graph::PyGraph,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+    scale: Option<f64>,
+    center: Option<Point>,
+    max_iter: usize,
+    tol: f64,
+    explain: bool,
+) -> PyResult<PyObject> {
+    let (pos, info) = spectral::spectral_layout(
+        py,
+        &graph.graph,
+        weight_fn,
+        default_weight,
+        scale,
+        center,
+        max_iter,
+        tol,
+    )?;
+    explained(py, pos, info, explain)
+}
+
+/// Generate a spectral layout of the graph
+///
+/// The coordinates of the nodes are the entries of the eigenvectors of the
+/// second and third smallest eigenvalues of the Laplacian matrix of the
+/// graph, which places nodes with heavy edges between them close together.
+/// The eigenvectors are computed with the LOBPCG method, which only
+/// multiplies vectors by the sparse Laplacian matrix, so it scales to large
+/// graphs. The smallest eigenvalue is 0 for every connected component, so
+/// the coordinates of a graph with several components mostly separate the
+/// components. The direction of the edges is
+/// ignored.
+///
+/// :param PyDiGraph graph: The graph to generate the layout for
+/// :param weight_fn: An optional weight function for an edge. It will accept
+///     a single argument, the edge's weight object and will return a float
+///     which will be used to represent the weight of the edge. The weights
+///     must be non-negative.
+/// :param float default_weight: If ``weight_fn`` isn't specified this
+///     optional float value will be used for the weight of each edge
+/// :param float scale: An optional scaling factor to scale positions
+/// :param tuple center: An optional center position. This is a 2 tuple of two
+///     ``float`` values for the center position
+/// :param int max_iter: The maximum number of iterations of LOBPCG
+/// :param float tol: The error tolerance used when checking for convergence,
+///     the iteration stops once the residual norm of every eigenvector is
+///     less than ``tol`` times twice the largest weighted degree.
+/// :param bool explain: If set to ``True`` an
+///     :class:`~retworkx.AlgorithmInfo` recording how the layout was run is
+///     returned along with the positions
+///
+/// :returns: The spectral layout of the graph. If ``explain`` is ``True`` a
+///     tuple of this and an :class:`~retworkx.AlgorithmInfo` is returned
+///     instead.
+/// :rtype: Pos2DMapping
+///
+/// :raises FailedToConverge: If LOBPCG doesn't converge within ``max_iter``
+///     iterations
+#[pyfunction(
+    default_weight = "1.0",
+    max_iter = "1000",
+    tol = "1e-6",
+    explain = "false"
+)]
+#[pyo3(
+    text_signature = "(graph, /, weight_fn=None, default_weight=1.0, scale=1, center=None,
+                     max_iter=1000, tol=1e-6, explain=False)"
+)]
+#[allow(clippy::too_many_arguments)]
+pub fn digraph_spectral_layout(
+    py: Python,
+    graph: &digraph::PyDiGraph,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+    scale: Option<f64>,
+    center: Option<Point>,
+    max_iter: usize,
+    tol: f64,
+    explain: bool,
+) -> PyResult<PyObject> {
+    let (pos, info) = spectral::spectral_layout(
+        py,
+        &graph.graph,
+        weight_fn,
+        default_weight,
+        scale,
+        center,
+        max_iter,
+        tol,
+    )?;
+    explained(py, pos, info, explain)
+}
+
+/// Generate a Kamada-Kawai layout of the graph
+///
+/// The Kamada-Kawai algorithm [1]_ places the nodes so that the euclidean
+/// distance between every pair of nodes matches the length of the shortest
+/// path between them, by minimizing the energy of a spring between every
+/// pair of nodes whose length is the shortest path length and whose
+/// stiffness is the inverse of its squared length. The energy is minimized
+/// by moving every node in turn with a Newton-Raphson step on its position,
+/// or a stress majorization step when the Newton-Raphson step doesn't
+/// decrease the energy.
+///
+/// Computing the shortest paths between all pairs of nodes takes
+/// :math:`O(n^2)` memory and every iteration takes :math:`O(n^2)` time, for
+/// large graphs set ``landmarks`` to attach every node only to its
+/// neighbors and to a number of landmark nodes spread over the graph, which
+/// takes :math:`O(n \cdot landmarks)` time and memory instead.
+///
+/// :param PyGraph graph: The graph to generate the layout for
+/// :param dict pos: Initial positions of the nodes as a dictionary with node
+///     indices as keys and coordinate pairs as values. Nodes without an
+///     initial position start on a circle, or at the pivot MDS layout of the
+///     landmarks if ``landmarks`` is set.
+/// :param weight_fn: An optional weight function for an edge. It will accept
+///     a single argument, the edge's weight object and will return a float
+///     which will be used as the length of the edge. The lengths must be
+///     positive.
+/// :param float default_weight: If ``weight_fn`` isn't specified this
+///     optional float value will be used as the length of each edge
+/// :param int landmarks: If set, the number of landmark nodes which every
+///     node is attached to, in addition to its neighbors, instead of every
+///     other node. The landmarks are chosen to be far from each other.
+/// :param int max_iter: The maximum number of iterations, in every
+///     iteration each node is moved once.
+/// :param float tol: The error tolerance used when checking for convergence,
+///     the iteration stops once the norm of the gradient of the energy with
+///     respect to the position of every node, divided by the number of
+///     springs attached to it, is less than ``tol``.
+/// :param float scale: An optional scaling factor to scale positions. If
+///     ``None`` the positions are in the unit of the edge lengths.
+/// :param tuple center: An optional center position. This is a 2 tuple of two
+///     ``float`` values for the center position
+/// :param bool explain: If set to ``True`` an
+///     :class:`~retworkx.AlgorithmInfo` recording how the layout was run is
+///     returned along with the positions
+///
+/// :returns: The Kamada-Kawai layout of the graph. If ``explain`` is ``True``
+///     a tuple of this and an :class:`~retworkx.AlgorithmInfo` is returned
+///     instead.
+/// :rtype: Pos2DMapping
+///
+/// :raises ValueError: If an edge length isn't positive
+///
+/// .. [1] T. Kamada and S. Kawai, "An algorithm for drawing general
+///     undirected graphs", Information Processing Letters 31, 1989, pp. 7-15,
+///     https://doi.org/10.1016/0020-0190(89)90102-6
+#[pyfunction(
+    default_weight = "1.0",
+    max_iter = "1000",
+    tol = "1e-4",
+    explain = "false"
+)]
+#[pyo3(
+    text_signature = "(graph, /, pos=None, weight_fn=None, default_weight=1.0, landmarks=None,
+                     max_iter=1000, tol=1e-4, scale=1, center=None, explain=False)"
+)]
+#[allow(clippy::too_many_arguments)]
+pub fn graph_kamada_kawai_layout(
+    py: Python,
+    graph: &graph::PyGraph,
+    pos: Option<HashMap<usize, Point>>,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+    landmarks: Option<usize>,
+    max_iter: usize,
+    tol: f64,
+    scale: Option<f64>,
+    center: Option<Point>,
+    explain: bool,
+) -> PyResult<PyObject> {
+    let (pos, info) = kamada_kawai::kamada_kawai_layout(
+        py,
+        &graph.graph,
+        pos,
+        weight_fn,
+        default_weight,
+        landmarks,
+        max_iter,
+        tol,
+        scale,
+        center,
+    )?;
+    explained(py, pos, info, explain)
+}
+
+/// Generate a Kamada-Kawai layout of the graph
+///
+/// The Kamada-Kawai algorithm [1]_ places the nodes so that the euclidean
+/// distance between every pair of nodes matches the length of the shortest
+/// path between them, by minimizing the energy of a spring between every
+/// pair of nodes whose length is the shortest path length and whose
+/// stiffness is the inverse of its squared length. The energy is minimized
+/// by moving every node in turn with a Newton-Raphson step on its position,
+/// or a stress majorization step when the Newton-Raphson step doesn't
+/// decrease the energy. The direction of the edges is ignored.
+///
+/// Computing the shortest paths between all pairs of nodes takes
+/// :math:`O(n^2)` memory and every iteration takes :math:`O(n^2)` time, for
+/// large graphs set ``landmarks`` to attach every node only to its
+/// neighbors and to a number of landmark nodes spread over the graph, which
+/// takes :math:`O(n \cdot landmarks)` time and memory instead.
+///
+/// :param PyDiGraph graph: The graph to generate the layout for
+/// :param dict pos: Initial positions of the nodes as a dictionary with node
+///     indices as keys and coordinate pairs as values. Nodes without an
+///     initial position start on a circle, or at the pivot MDS layout of the
+///     landmarks if ``landmarks`` is set.
+/// :param weight_fn: An optional weight function for an edge. It will accept
+///     a single argument, the edge's weight object and will return a float
+///     which will be used as the length of the edge. The lengths must be
+///     positive.
+/// :param float default_weight: If ``weight_fn`` isn't specified this
+///     optional float value will be used as the length of each edge
+/// :param int landmarks: If set, the number of landmark nodes which every
+///     node is attached to, in addition to its neighbors, instead of every
+///     other node. The landmarks are chosen to be far from each other.
+/// :param int max_iter: The maximum number of iterations, in every
+///     iteration each node is moved once.
+/// :param float tol: The error tolerance used when checking for convergence,
+///     the iteration stops once the norm of the gradient of the energy with
+///     respect to the position of every node, divided by the number of
+///     springs attached to it, is less than ``tol``.
+/// :param float scale: An optional scaling factor to scale positions. If
+///     ``None`` the positions are in the unit of the edge lengths.
+/// :param tuple center: An optional center position. This is a 2 tuple of two
+///     ``float`` values for the center position
+/// :param bool explain: If set to ``True`` an
+///     :class:`~retworkx.AlgorithmInfo` recording how the layout was run is
+///     returned along with the positions
+///
+/// :returns: The Kamada-Kawai layout of the graph. If ``explain`` is ``True``
+///     a tuple of this and an :class:`~retworkx.AlgorithmInfo` is returned
+///     instead.
+/// :rtype: Pos2DMapping
+///
+/// :raises ValueError: If an edge length isn't positive
+///
+/// .. [1] T. Kamada and S. Kawai, "An algorithm for drawing general
+///     undirected graphs", Information Processing Letters 31, 1989, pp. 7-15,
+///     https://doi.org/10.1016/0020-0190(89)90102-6
+#[pyfunction(
+    default_weight = "1.0",
+    max_iter = "1000",
+    tol = "1e-4",
+    explain = "false"
+)]
+#[pyo3(
+    text_signature = "(graph, /, pos=None, weight_fn=None, default_weight=1.0, landmarks=None,
+                     max_iter=1000, tol=1e-4, scale=1, center=None, explain=False)"
+)]
+#[allow(clippy::too_many_arguments)]
+pub fn digraph_kamada_kawai_layout(
+    py: Python,
+    graph: &digraph::PyDiGraph,
+    pos: Option<HashMap<usize, Point>>,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+    landmarks: Option<usize>,
+    max_iter: usize,
+    tol: f64,
+    scale: Option<f64>,
+    center: Option<Point>,
+    explain: bool,
+) -> PyResult<PyObject> {
+    let (pos, info) = kamada_kawai::kamada_kawai_layout(
+        py,
+        &graph.graph,
+        pos,
+        weight_fn,
+        default_weight,
+        landmarks,
+        max_iter,
+        tol,
+        scale,
+        center,
+    )?;
+    explained(py, pos, info, explain)
+}
+
+/// Generate a layered (Sugiyama) layout of a DAG
+///
+/// The nodes are drawn in horizontal layers with every edge pointing down,
+/// following the framework of Sugiyama et al. [1]_ in three steps:
+///
+/// 1. Layer assignment: every node is placed one layer below its lowest
+///    predecessor, which is a longest path layering, then every source is
+///    moved down to the layer just above its highest successor.
+/// 2. Crossing minimization: edges spanning more than one layer are split
+///    by a dummy node in every layer they cross, and the order of the nodes
+///    in every layer is improved by alternating downward and upward sweeps
+///    of the median heuristic [2]_, each followed by swaps of adjacent
+///    nodes which reduce the crossings. The order with the fewest crossings
+///    is kept.
+/// 3. Coordinate assignment: every node is placed as close as possible to
+///    the mean of its neighbors in the adjacent layer while keeping the
+///    nodes of a layer in order and at least 1 apart, which also
+///    straightens the chains of dummy nodes of long edges.
+///
+/// :param PyDiGraph dag: The DAG to generate the layout for
+/// :param int max_iter: The maximum number of median sweeps used to
+///     minimize the crossings
+/// :param float scale: An optional scaling factor to scale positions. If
+///     ``None`` the layers are 1 apart, the sources are in the top layer and
+///     the x coordinates start at 0.
+/// :param tuple center: An optional center position. This is a 2 tuple of two
+///     ``float`` values for the center position
+///
+/// :returns: The positions of the nodes along with their layers, the order
+///     of the layers and the bends of the long edges
+/// :rtype: SugiyamaLayout
+///
+/// :raises DAGHasCycle: If the graph has a cycle
+///
+/// .. [1] K. Sugiyama, S. Tagawa and M. Toda, "Methods for visual
+///     understanding of hierarchical system structures", IEEE Transactions
+///     on Systems, Man, and Cybernetics 11(2), 1981, pp. 109-125,
+///     https://doi.org/10.1109/TSMC.1981.4308636
+/// .. [2] E. R. Gansner, E. Koutsofios, S. C. North and K.-P. Vo, "A
+///     technique for drawing directed graphs", IEEE Transactions on Software
+///     Engineering 19(3), 1993, pp. 214-230, https://doi.org/10.1109/32.221135
+#[pyfunction(max_iter = "24")]
+#[pyo3(text_signature = "(dag, /, max_iter=24, scale=None, center=None)")]
+pub fn sugiyama_layout(
+    dag: &digraph::PyDiGraph,
+    max_iter: usize,
+    scale: Option<f64>,
+    center: Option<Point>,
+) -> PyResult<SugiyamaLayout> {
+    sugiyama::sugiyama_layout(&dag.graph, max_iter, scale, center)
+}
+
+/// Bundle the edges of a layout of a PyGraph with force-directed edge bundling
+///
+/// Force-directed edge bundling [1]_ draws edges as polylines whose control
+/// points attract the control points of compatible edges, which are edges
+/// of similar length and direction close to each other, so they are drawn
+/// together in bundles. This makes the layouts of dense graphs easier to
+/// read. Every edge is a chain of springs between its control points which
+/// keeps it smooth. The bundling runs in ``cycles`` cycles, after every
+/// cycle the number of control points of every edge is doubled, the step
+/// size is halved and the number of iterations is reduced by a third.
+///
+/// The layout is scaled so the larger side of its bounding box is 1000
+/// during the bundling, so the result doesn't depend on the scale of the
+/// layout. Computing the compatible edges takes :math:`O(m^2)` time for
+/// :math:`m` edges. Self loops and edges between nodes at the same position
+/// aren't bundled.
+///
+/// :param PyGraph graph: The graph the layout is of
+/// :param pos: The positions of the nodes, either a
+///     :class:`~retworkx.Pos2DMapping` returned by a layout function or a
+///     dictionary mapping node indices to ``[x, y]`` coordinates
+/// :param float stiffness: The stiffness of the springs between the control
+///     points of an edge, higher values bundle less
+/// :param float compatibility_threshold: The minimum compatibility, between
+///     0 and 1, of two edges which attract each other
+/// :param int cycles: The number of cycles
+/// :param int iterations: The number of iterations of the first cycle
+/// :param float step_size: The step size of the first cycle, relative to the
+///     layout scaled to a size of 1000
+///
+/// :returns: The polyline of every edge as a mapping of edge indices to
+///     lists of points from the position of the source of the edge to the
+///     position of its target
+/// :rtype: EdgePolylineMapping
+///
+/// :raises ValueError: If ``pos`` has no position for an endpoint of an
+///     edge
+///
+/// .. [1] D. Holten and J. J. van Wijk, "Force-directed edge bundling for
+///     graph visualization", Computer Graphics Forum 28(3), 2009,
+///     pp. 983-990, https://doi.org/10.1111/j.1467-8659.2009.01450.x
+#[pyfunction(
+    stiffness = "0.1",
+    compatibility_threshold = "0.6",
+    cycles = "6",
+    iterations = "90",
+    step_size = "0.1"
+)]
+#[pyo3(
+    text_signature = "(graph, pos, /, stiffness=0.1, compatibility_threshold=0.6, cycles=6,
+                     iterations=90, step_size=0.1)"
+)]
+#[allow(clippy::too_many_arguments)]
+pub fn graph_edge_bundling(
+    graph: &graph::PyGraph,
+    pos: &PyAny,
+    stiffness: f64,
+    compatibility_threshold: f64,
+    cycles: usize,
+    iterations: usize,
+    step_size: f64,
+) -> PyResult<EdgePolylineMapping> {
+    edge_bundling::edge_bundling(
+        &graph.graph,
+        edge_bundling::extract_positions(pos)?,
+        stiffness,
+        compatibility_threshold,
+        cycles,
+        iterations,
+        step_size,
+    )
+}
+
+/// Bundle the edges of a layout of a PyDiGraph with force-directed edge bundling
+///
+/// Force-directed edge bundling [1]_ draws edges as polylines whose control
+/// points attract the control points of compatible edges, which are edges
+/// of similar length and direction close to each other, so they are drawn
+/// together in bundles. This makes the layouts of dense graphs easier to
+/// read. Every edge is a chain of springs between its control points which
+/// keeps it smooth. The bundling runs in ``cycles`` cycles, after every
+/// cycle the number of control points of every edge is doubled, the step
+/// size is halved and the number of iterations is reduced by a third.
+///
+/// The layout is scaled so the larger side of its bounding box is 1000
+/// during the bundling, so the result doesn't depend on the scale of the
+/// layout. Computing the compatible edges takes :math:`O(m^2)` time for
+/// :math:`m` edges. Self loops and edges between nodes at the same position
+/// aren't bundled.
+///
+/// :param PyDiGraph graph: The graph the layout is of
+/// :param pos: The positions of the nodes, either a
+///     :class:`~retworkx.Pos2DMapping` returned by a layout function or a
+///     dictionary mapping node indices to ``[x, y]`` coordinates
+/// :param float stiffness: The stiffness of the springs between the control
+///     points of an edge, higher values bundle less
+/// :param float compatibility_threshold: The minimum compatibility, between
+///     0 and 1, of two edges which attract each other
+/// :param int cycles: The number of cycles
+/// :param int iterations: The number of iterations of the first cycle
+/// :param float step_size: The step size of the first cycle, relative to the
+///     layout scaled to a size of 1000
+///
+/// :returns: The polyline of every edge as a mapping of edge indices to
+///     lists of points from the position of the source of the edge to the
+///     position of its target
+/// :rtype: EdgePolylineMapping
+///
+/// :raises ValueError: If ``pos`` has no position for an endpoint of an
+///     edge
+///
+/// .. [1] D. Holten and J. J. van Wijk, "Force-directed edge bundling for
+///     graph visualization", Computer Graphics Forum 28(3), 2009,
+///     pp. 983-990, https://doi.org/10.1111/j.1467-8659.2009.01450.x
+#[pyfunction(
+    stiffness = "0.1",
+    compatibility_threshold = "0.6",
+    cycles = "6",
+    iterations = "90",
+    step_size = "0.1"
+)]
+#[pyo3(
+    text_signature = "(graph, pos, /, stiffness=0.1, compatibility_threshold=0.6, cycles=6,
+                     iterations=90, step_size=0.1)"
+)]
+#[allow(clippy::too_many_arguments)]
+pub fn digraph_edge_bundling(
+    graph: &digraph::PyDiGraph,
+    pos: &PyAny,
+    stiffness: f64,
+    compatibility_threshold: f64,
+    cycles: usize,
+    iterations: usize,
+    step_size: f64,
+) -> PyResult<EdgePolylineMapping> {
+    edge_bundling::edge_bundling(
+        &graph.graph,
+        edge_bundling::extract_positions(pos)?,
+        stiffness,
+        compatibility_threshold,
+        cycles,
+        iterations,
+        step_size,
+    )
+}
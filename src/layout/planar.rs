@@ -0,0 +1,488 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use std::collections::VecDeque;
+
+use hashbrown::{HashMap, HashSet};
+
+use petgraph::EdgeType;
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+use retworkx_core::planar::{check_planarity, Planarity};
+
+use super::spring::{recenter, rescale, Point};
+use crate::iterators::Pos2DMapping;
+use crate::StablePyGraph;
+
+/// A combinatorial embedding of a graph with nodes `0..n`, storing the
+/// clockwise and counterclockwise neighbor of every half-edge around its
+/// start node, which allows adding edges while keeping the embedding planar.
+struct Embedding {
+    cw: HashMap<(usize, usize), usize>,
+    ccw: HashMap<(usize, usize), usize>,
+    first_nbr: Vec<Option<usize>>,
+}
+
+impl Embedding {
+    fn new(neighbors: &[Vec<usize>]) -> Self {
+        let mut embedding = Embedding {
+            cw: HashMap::new(),
+            ccw: HashMap::new(),
+            first_nbr: neighbors.iter().map(|nbrs| nbrs.first().copied()).collect(),
+        };
+        for (v, nbrs) in neighbors.iter().enumerate() {
+            for (i, &w) in nbrs.iter().enumerate() {
+                let next = nbrs[(i + 1) % nbrs.len()];
+                embedding.cw.insert((v, w), next);
+                embedding.ccw.insert((v, next), w);
+            }
+        }
+        embedding
+    }
+
+    fn has_edge(&self, v: usize, w: usize) -> bool {
+        self.cw.contains_key(&(v, w))
+    }
+
+    /// Return the neighbor of `v` after `w` in clockwise order, or `None`
+    /// after the last neighbor, which allows iterating over the neighbors
+    /// while edges are added.
+    fn next_cw_neighbor(&self, v: usize, w: usize) -> Option<usize> {
+        let next = self.cw[&(v, w)];
+        if Some(next) == self.first_nbr[v] {
+            None
+        } else {
+            Some(next)
+        }
+    }
+
+    /// Add the half-edge `(start, end)` clockwise after `reference`.
+    fn add_half_edge_cw(&mut self, start: usize, end: usize, reference: Option<usize>) {
+        match reference {
+            None => {
+                self.cw.insert((start, end), end);
+                self.ccw.insert((start, end), end);
+                self.first_nbr[start] = Some(end);
+            }
+            Some(reference) => {
+                let cw_reference = self.cw[&(start, reference)];
+                self.cw.insert((start, reference), end);
+                self.cw.insert((start, end), cw_reference);
+                self.ccw.insert((start, cw_reference), end);
+                self.ccw.insert((start, end), reference);
+            }
+        }
+    }
+
+    /// Add the half-edge `(start, end)` counterclockwise before `reference`.
+    fn add_half_edge_ccw(&mut self, start: usize, end: usize, reference: Option<usize>) {
+        match reference {
+            None => self.add_half_edge_cw(start, end, None),
+            Some(reference) => {
+                let ccw_reference = self.ccw[&(start, reference)];
+                self.add_half_edge_cw(start, end, Some(ccw_reference));
+                if Some(reference) == self.first_nbr[start] {
+                    self.first_nbr[start] = Some(end);
+                }
+            }
+        }
+    }
+
+    /// Add the edge `(v, w)` as the first neighbor of both nodes, which
+    /// connects two components without making the embedding non planar.
+    fn connect_components(&mut self, v: usize, w: usize) {
+        self.add_half_edge_ccw(v, w, self.first_nbr[v]);
+        self.add_half_edge_ccw(w, v, self.first_nbr[w]);
+    }
+
+    /// Return the half-edge after `(v, w)` on the face to its right.
+    fn next_face_half_edge(&self, v: usize, w: usize) -> (usize, usize) {
+        (w, self.ccw[&(w, v)])
+    }
+
+    /// Walk around the face to the right of the half-edge `(start, out)`,
+    /// returning its nodes, and add an edge whenever a node is visited twice
+    /// so the face is bounded by a cycle. Returns an empty list if the face
+    /// was already visited.
+    fn make_biconnected(
+        &mut self,
+        start: usize,
+        out: usize,
+        visited: &mut HashSet<(usize, usize)>,
+    ) -> Vec<usize> {
+        if !visited.insert((start, out)) {
+            return Vec::new();
+        }
+        let mut face = vec![start];
+        let mut face_set: HashSet<usize> = HashSet::new();
+        face_set.insert(start);
+        let (mut v1, mut v2) = (start, out);
+        let (_, mut v3) = self.next_face_half_edge(v1, v2);
+        while v2 != start || v3 != out {
+            if face_set.contains(&v2) {
+                // Replace the path v1, v2, v3 by an edge from v1 to v3
+                self.add_half_edge_cw(v1, v3, Some(v2));
+                self.add_half_edge_ccw(v3, v1, Some(v2));
+                visited.insert((v2, v3));
+                visited.insert((v3, v1));
+                v2 = v1;
+            } else {
+                face_set.insert(v2);
+                face.push(v2);
+            }
+            v1 = v2;
+            let next = self.next_face_half_edge(v2, v3);
+            v2 = next.0;
+            v3 = next.1;
+            visited.insert((v1, v2));
+        }
+        face
+    }
+
+    /// Triangulate the face to the right of the half-edge `(v1, v2)`.
+    fn triangulate_face(&mut self, mut v1: usize, mut v2: usize) {
+        let (_, mut v3) = self.next_face_half_edge(v1, v2);
+        let (_, mut v4) = self.next_face_half_edge(v2, v3);
+        if v1 == v2 || v1 == v3 {
+            return;
+        }
+        while v1 != v4 {
+            if self.has_edge(v1, v3) {
+                v1 = v2;
+                v2 = v3;
+                v3 = v4;
+            } else {
+                self.add_half_edge_cw(v1, v3, Some(v2));
+                self.add_half_edge_ccw(v3, v1, Some(v2));
+                v2 = v3;
+                v3 = v4;
+            }
+            v4 = self.next_face_half_edge(v2, v3).1;
+        }
+    }
+}
+
+/// Connect the components of the embedding and triangulate all of its faces
+/// except the largest one, which is returned as the outer face.
+fn triangulate_embedding(embedding: &mut Embedding, neighbors: &[Vec<usize>]) -> Vec<usize> {
+    let num_nodes = neighbors.len();
+    let mut seen = vec![false; num_nodes];
+    let mut components: Vec<usize> = Vec::new();
+    for root in 0..num_nodes {
+        if seen[root] {
+            continue;
+        }
+        seen[root] = true;
+        components.push(root);
+        let mut queue = VecDeque::from(vec![root]);
+        while let Some(v) = queue.pop_front() {
+            for &w in &neighbors[v] {
+                if !seen[w] {
+                    seen[w] = true;
+                    queue.push_back(w);
+                }
+            }
+        }
+    }
+    for pair in components.windows(2) {
+        embedding.connect_components(pair[0], pair[1]);
+    }
+
+    let mut outer_face = 0;
+    let mut faces: Vec<Vec<usize>> = Vec::new();
+    let mut visited: HashSet<(usize, usize)> = HashSet::new();
+    for v in 0..num_nodes {
+        let mut w = embedding.first_nbr[v];
+        while let Some(nbr) = w {
+            let face = embedding.make_biconnected(v, nbr, &mut visited);
+            if !face.is_empty() {
+                if face.len() > faces.get(outer_face).map_or(0, |face| face.len()) {
+                    outer_face = faces.len();
+                }
+                faces.push(face);
+            }
+            w = embedding.next_cw_neighbor(v, nbr);
+        }
+    }
+    for (index, face) in faces.iter().enumerate() {
+        if index != outer_face {
+            embedding.triangulate_face(face[0], face[1]);
+        }
+    }
+    faces.swap_remove(outer_face)
+}
+
+/// Return a canonical ordering of a triangulated embedding with the given
+/// outer face, with the nodes on the contour of the drawing of the previous
+/// nodes which are adjacent to each node, from left to right.
+fn canonical_ordering(embedding: &Embedding, outer_face: &[usize]) -> Vec<(usize, Vec<usize>)> {
+    let num_nodes = embedding.first_nbr.len();
+    let v1 = outer_face[0];
+    let v2 = outer_face[1];
+    let mut chords = vec![0usize; num_nodes];
+    let mut marked = vec![false; num_nodes];
+    let mut ready = vec![false; num_nodes];
+    // Nodes can be pushed more than once, only those still ready when
+    // popped are picked
+    let mut ready_stack: Vec<usize> = Vec::new();
+    for &v in outer_face {
+        ready[v] = true;
+    }
+
+    let mut outer_ccw_nbr: Vec<Option<usize>> = vec![None; num_nodes];
+    let mut outer_cw_nbr: Vec<Option<usize>> = vec![None; num_nodes];
+    let mut prev = v2;
+    for &v in &outer_face[2..] {
+        outer_ccw_nbr[prev] = Some(v);
+        prev = v;
+    }
+    outer_ccw_nbr[prev] = Some(v1);
+    let mut prev = v1;
+    for &v in outer_face[1..].iter().rev() {
+        outer_cw_nbr[prev] = Some(v);
+        prev = v;
+    }
+
+    let is_outer_face_nbr =
+        |outer_ccw_nbr: &[Option<usize>], outer_cw_nbr: &[Option<usize>], x: usize, y: usize| {
+            outer_ccw_nbr[x] == Some(y) || outer_cw_nbr[x] == Some(y)
+        };
+    let is_on_outer_face = |outer_ccw_nbr: &[Option<usize>], marked: &[bool], x: usize| {
+        !marked[x] && (outer_ccw_nbr[x].is_some() || x == v1)
+    };
+    let neighbors_cw_order = |v: usize| {
+        let mut nbrs = Vec::new();
+        let mut w = embedding.first_nbr[v];
+        while let Some(nbr) = w {
+            nbrs.push(nbr);
+            w = embedding.next_cw_neighbor(v, nbr);
+        }
+        nbrs
+    };
+
+    for &v in outer_face {
+        for nbr in neighbors_cw_order(v) {
+            if is_on_outer_face(&outer_ccw_nbr, &marked, nbr)
+                && !is_outer_face_nbr(&outer_ccw_nbr, &outer_cw_nbr, v, nbr)
+            {
+                chords[v] += 1;
+                ready[v] = false;
+            }
+        }
+    }
+    ready[v1] = false;
+    ready[v2] = false;
+    ready_stack.extend(outer_face.iter().rev().filter(|&&v| ready[v]).copied());
+
+    let mut ordering: Vec<(usize, Vec<usize>)> = Vec::with_capacity(num_nodes);
+    ordering.push((v1, Vec::new()));
+    ordering.push((v2, Vec::new()));
+    while ordering.len() < num_nodes {
+        let v = loop {
+            let v = ready_stack.pop().unwrap();
+            if ready[v] {
+                break v;
+            }
+        };
+        ready[v] = false;
+        marked[v] = true;
+
+        // Find the two neighbors of v on the outer face
+        let mut wp = None;
+        let mut wq = None;
+        for nbr in neighbors_cw_order(v) {
+            if marked[nbr] || !is_on_outer_face(&outer_ccw_nbr, &marked, nbr) {
+                continue;
+            }
+            if nbr == v1 {
+                wp = Some(v1);
+            } else if nbr == v2 {
+                wq = Some(v2);
+            } else if outer_cw_nbr[nbr] == Some(v) {
+                wp = Some(nbr);
+            } else {
+                wq = Some(nbr);
+            }
+            if wp.is_some() && wq.is_some() {
+                break;
+            }
+        }
+        let wp = wp.unwrap();
+        let wq = wq.unwrap();
+
+        // The neighbors of v from wp to wq are now on the outer face
+        let mut wp_wq = vec![wp];
+        let mut nbr = wp;
+        while nbr != wq {
+            let next = embedding.ccw[&(v, nbr)];
+            wp_wq.push(next);
+            outer_cw_nbr[nbr] = Some(next);
+            outer_ccw_nbr[next] = Some(nbr);
+            nbr = next;
+        }
+
+        if wp_wq.len() == 2 {
+            // The chord between wp and wq is now an outer face edge
+            for &w in &[wp, wq] {
+                chords[w] -= 1;
+                if chords[w] == 0 {
+                    ready[w] = true;
+                    ready_stack.push(w);
+                }
+            }
+        } else {
+            let new_face_nodes: HashSet<usize> =
+                wp_wq[1..wp_wq.len() - 1].iter().copied().collect();
+            for &w in &wp_wq[1..wp_wq.len() - 1] {
+                ready[w] = true;
+                ready_stack.push(w);
+                for nbr in neighbors_cw_order(w) {
+                    if is_on_outer_face(&outer_ccw_nbr, &marked, nbr)
+                        && !is_outer_face_nbr(&outer_ccw_nbr, &outer_cw_nbr, w, nbr)
+                    {
+                        chords[w] += 1;
+                        ready[w] = false;
+                        if !new_face_nodes.contains(&nbr) {
+                            chords[nbr] += 1;
+                            ready[nbr] = false;
+                        }
+                    }
+                }
+            }
+        }
+        ordering.push((v, wp_wq));
+    }
+    // The nodes were picked by removing them from the outer face
+    ordering[2..].reverse();
+    ordering
+}
+
+/// Compute integer coordinates of a straight-line planar drawing of a graph
+/// with at least 4 nodes given the clockwise order of the neighbors of every
+/// node, with the shift method of Chrobak and Payne.
+fn embedding_to_pos(neighbors: &[Vec<usize>]) -> Vec<[i64; 2]> {
+    let num_nodes = neighbors.len();
+    let mut embedding = Embedding::new(neighbors);
+    let outer_face = triangulate_embedding(&mut embedding, neighbors);
+    let ordering = canonical_ordering(&embedding, &outer_face);
+
+    // The x coordinate of every node is stored relative to its parent in a
+    // binary tree, so moving a node moves all the nodes below it
+    let mut left_child: Vec<Option<usize>> = vec![None; num_nodes];
+    let mut right_child: Vec<Option<usize>> = vec![None; num_nodes];
+    let mut delta_x = vec![0i64; num_nodes];
+    let mut y = vec![0i64; num_nodes];
+
+    let v1 = ordering[0].0;
+    let v2 = ordering[1].0;
+    let v3 = ordering[2].0;
+    delta_x[v2] = 1;
+    delta_x[v3] = 1;
+    y[v3] = 1;
+    right_child[v1] = Some(v3);
+    right_child[v3] = Some(v2);
+
+    for (vk, contour) in &ordering[3..] {
+        let vk = *vk;
+        let wp = contour[0];
+        let wp1 = contour[1];
+        let wq = contour[contour.len() - 1];
+        let wq1 = contour[contour.len() - 2];
+        let adds_mult_tri = contour.len() > 2;
+
+        // Stretch the gaps
+        delta_x[wp1] += 1;
+        delta_x[wq] += 1;
+        let delta_x_wp_wq: i64 = contour[1..].iter().map(|&w| delta_x[w]).sum();
+
+        // Adjust the offsets
+        delta_x[vk] = (-y[wp] + delta_x_wp_wq + y[wq]).div_euclid(2);
+        y[vk] = (y[wp] + delta_x_wp_wq + y[wq]).div_euclid(2);
+        delta_x[wq] = delta_x_wp_wq - delta_x[vk];
+        if adds_mult_tri {
+            delta_x[wp1] -= delta_x[vk];
+        }
+
+        // Install vk
+        right_child[wp] = Some(vk);
+        right_child[vk] = Some(wq);
+        if adds_mult_tri {
+            left_child[vk] = Some(wp1);
+            right_child[wq1] = None;
+        } else {
+            left_child[vk] = None;
+        }
+    }
+
+    let mut pos = vec![[0, 0]; num_nodes];
+    pos[v1] = [0, y[v1]];
+    let mut stack = vec![v1];
+    while let Some(parent) = stack.pop() {
+        for child in left_child[parent].iter().chain(right_child[parent].iter()) {
+            pos[*child] = [pos[parent][0] + delta_x[*child], y[*child]];
+            stack.push(*child);
+        }
+    }
+    pos
+}
+
+pub fn planar_layout<Ty: EdgeType>(
+    graph: &StablePyGraph<Ty>,
+    scale: Option<f64>,
+    center: Option<Point>,
+) -> PyResult<Pos2DMapping> {
+    let embedding = match check_planarity(graph) {
+        Planarity::Planar(embedding) => embedding,
+        Planarity::NonPlanar { .. } => {
+            return Err(PyValueError::new_err("The graph isn't planar"));
+        }
+    };
+    let node_num = graph.node_count();
+    let index: HashMap<usize, usize> = graph
+        .node_indices()
+        .enumerate()
+        .map(|(i, node)| (node.index(), i))
+        .collect();
+    let neighbors: Vec<Vec<usize>> = graph
+        .node_indices()
+        .map(|node| {
+            embedding[&node]
+                .iter()
+                .map(|nbr| index[&nbr.index()])
+                .collect()
+        })
+        .collect();
+
+    let mut pos: Vec<Point> = if node_num < 4 {
+        // Any three points which aren't on a line can be used
+        [[0.0, 0.0], [2.0, 0.0], [1.0, 1.0]][..node_num].to_vec()
+    } else {
+        embedding_to_pos(&neighbors)
+            .into_iter()
+            .map(|[x, y]| [x as f64, y as f64])
+            .collect()
+    };
+
+    if let Some(scale) = scale {
+        rescale(&mut pos, scale, (0..node_num).collect());
+    }
+
+    if let Some(center) = center {
+        recenter(&mut pos, center);
+    }
+
+    Ok(Pos2DMapping {
+        pos_map: graph.node_indices().map(|n| n.index()).zip(pos).collect(),
+    })
+}
@@ -0,0 +1,74 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use petgraph::EdgeType;
+
+use pyo3::prelude::*;
+
+use retworkx_core::spectral::laplacian_eigenvectors_with_convergence;
+
+use super::spring::{recenter, rescale, Point};
+use crate::algorithm_info::AlgorithmInfo;
+use crate::iterators::Pos2DMapping;
+use crate::{weight_callable, FailedToConverge, StablePyGraph};
+
+#[allow(clippy::too_many_arguments)]
+pub fn spectral_layout<Ty: EdgeType>(
+    py: Python,
+    graph: &StablePyGraph<Ty>,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+    scale: Option<f64>,
+    center: Option<Point>,
+    max_iter: usize,
+    tol: f64,
+) -> PyResult<(Pos2DMapping, AlgorithmInfo)> {
+    let info = AlgorithmInfo::new("spectral_layout", "LOBPCG on the Laplacian matrix")
+        .parameter(py, "default_weight", default_weight)
+        .parameter(py, "scale", scale)
+        .parameter(py, "center", center.map(|center| center.to_vec()))
+        .parameter(py, "max_iter", max_iter)
+        .parameter(py, "tol", tol);
+    // The first eigenvector is constant on every connected component, the
+    // next two are the coordinates
+    let ((_, vectors), convergence) = laplacian_eigenvectors_with_convergence(
+        graph,
+        |e| weight_callable(py, &weight_fn, e.weight(), default_weight),
+        3,
+        max_iter,
+        tol,
+    )?;
+    if !convergence.converged {
+        return Err(FailedToConverge::new_err(format!(
+            "spectral layout failed to converge in {} iterations",
+            max_iter
+        )));
+    }
+    let coordinate = |axis: usize, node: usize| vectors.get(axis).map_or(0.0, |v| v[node]);
+    let mut pos: Vec<Point> = graph
+        .node_indices()
+        .map(|n| [coordinate(1, n.index()), coordinate(2, n.index())])
+        .collect();
+
+    if let Some(scale) = scale {
+        rescale(&mut pos, scale, (0..graph.node_count()).collect());
+    }
+
+    if let Some(center) = center {
+        recenter(&mut pos, center);
+    }
+
+    let pos = Pos2DMapping {
+        pos_map: graph.node_indices().map(|n| n.index()).zip(pos).collect(),
+    };
+    Ok((pos, info.convergence(convergence, tol)))
+}
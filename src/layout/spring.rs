@@ -10,6 +10,7 @@
 // License for the specific language governing permissions and limitations
 // under the License.
 
+use crate::algorithm_info::AlgorithmInfo;
 use crate::iterators::Pos2DMapping;
 use crate::weight_callable;
 
@@ -29,6 +30,8 @@ use rand::distributions::{Distribution, Uniform};
 use rand::prelude::*;
 use rand_pcg::Pcg64;
 
+use retworkx_core::Convergence;
+
 use crate::StablePyGraph;
 
 type Nt = f64;
@@ -225,7 +228,7 @@ pub fn evolve<Ty, Fa, Fr, C>(
     weights: HashMap<(usize, usize), f64>,
     scale: Option<Nt>,
     center: Option<Point>,
-) -> Vec<Point>
+) -> (Vec<Point>, Convergence)
 where
     Ty: EdgeType,
     Fa: Force,
@@ -233,10 +236,15 @@ where
     C: CoolingScheme,
 {
     let mut step = cs.update_step(std::f64::INFINITY);
+    let mut convergence = Convergence {
+        iterations: 0,
+        error: f64::INFINITY,
+        converged: false,
+    };
 
     for _ in 0..num_iter {
         let mut energy = 0.0;
-        let mut converged = true;
+        let mut error: f64 = 0.0;
 
         for v in graph.node_indices() {
             let v = v.index();
@@ -268,13 +276,14 @@ where
             pos[v][0] += dx;
             pos[v][1] += dy;
 
-            if dx * dx + dy * dy > tol {
-                converged = false;
-            }
+            error = error.max(dx * dx + dy * dy);
         }
 
         step = cs.update_step(energy);
-        if converged {
+        convergence.iterations += 1;
+        convergence.error = error;
+        if error <= tol {
+            convergence.converged = true;
             break;
         }
     }
@@ -293,7 +302,7 @@ where
         }
     }
 
-    pos
+    (pos, convergence)
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -312,7 +321,7 @@ pub fn spring_layout<Ty>(
     scale: Option<f64>,
     center: Option<Point>,
     seed: Option<u64>,
-) -> PyResult<Pos2DMapping>
+) -> PyResult<(Pos2DMapping, AlgorithmInfo)>
 where
     Ty: EdgeType,
 {
@@ -320,10 +329,9 @@ where
         return Err(PyValueError::new_err("`fixed` specified but `pos` not."));
     }
 
-    let mut rng: Pcg64 = match seed {
-        Some(seed) => Pcg64::seed_from_u64(seed),
-        None => Pcg64::from_entropy(),
-    };
+    // Draw a seed when none is set so it can be reported back
+    let seed = seed.unwrap_or_else(|| Pcg64::from_entropy().gen());
+    let mut rng = Pcg64::seed_from_u64(seed);
 
     let dist = Uniform::new(0.0, 1.0);
 
@@ -343,6 +351,28 @@ where
     let num_iter = num_iter.unwrap_or(50);
     let tol = tol.unwrap_or(1e-6);
     let step = 0.1;
+    let adaptive_cooling = adaptive_cooling.unwrap_or(true);
+
+    let mut info = AlgorithmInfo::new(
+        "spring_layout",
+        if adaptive_cooling {
+            "Fruchterman-Reingold with adaptive cooling"
+        } else {
+            "Fruchterman-Reingold with linear cooling"
+        },
+    )
+    .parameter(py, "k", k)
+    .parameter(py, "repulsive_exponent", repulsive_exponent.unwrap_or(2))
+    .parameter(py, "adaptive_cooling", adaptive_cooling)
+    .parameter(py, "num_iter", num_iter)
+    .parameter(py, "tol", tol)
+    .parameter(py, "default_weight", default_weight)
+    .parameter(py, "scale", scale)
+    .parameter(py, "center", center.map(|center| center.to_vec()))
+    .seed(seed);
+    if !fixed.is_empty() && (scale.is_some() || center.is_some()) {
+        info = info.fallback("scale and center were not applied since nodes are fixed");
+    }
 
     let mut weights: HashMap<(usize, usize), f64> = HashMap::with_capacity(2 * graph.edge_count());
     for e in graph.edge_references() {
@@ -354,22 +384,19 @@ where
         weights.insert((target, source), w);
     }
 
-    let pos = match adaptive_cooling {
-        Some(false) => {
-            let cs = LinearCoolingScheme::new(step, num_iter);
-            evolve(
-                graph, vpos, fixed, f_a, f_r, cs, num_iter, tol, weights, scale, center,
-            )
-        }
-        _ => {
-            let cs = AdaptiveCoolingScheme::new(step);
-            evolve(
-                graph, vpos, fixed, f_a, f_r, cs, num_iter, tol, weights, scale, center,
-            )
-        }
+    let (pos, convergence) = if adaptive_cooling {
+        let cs = AdaptiveCoolingScheme::new(step);
+        evolve(
+            graph, vpos, fixed, f_a, f_r, cs, num_iter, tol, weights, scale, center,
+        )
+    } else {
+        let cs = LinearCoolingScheme::new(step, num_iter);
+        evolve(
+            graph, vpos, fixed, f_a, f_r, cs, num_iter, tol, weights, scale, center,
+        )
     };
 
-    Ok(Pos2DMapping {
+    let pos = Pos2DMapping {
         pos_map: graph
             .node_indices()
             .map(|n| {
@@ -377,5 +404,6 @@ where
                 (n, pos[n])
             })
             .collect(),
-    })
+    };
+    Ok((pos, info.convergence(convergence, tol)))
 }
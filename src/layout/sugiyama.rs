@@ -0,0 +1,456 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use petgraph::algo;
+use petgraph::visit::{EdgeRef, IntoEdgeReferences};
+use petgraph::Directed;
+
+use pyo3::prelude::*;
+
+use hashbrown::HashMap;
+use retworkx_core::dictmap::*;
+
+use super::spring::{recenter, rescale, Point};
+use crate::iterators::Pos2DMapping;
+use crate::{DAGHasCycle, StablePyGraph};
+
+/// The number of sweeps of the coordinate assignment.
+const COORDINATE_SWEEPS: usize = 8;
+
+/// The result of a layered drawing of a DAG
+///
+/// This class is returned by :func:`~retworkx.sugiyama_layout`.
+///
+/// :ivar Pos2DMapping pos: The position of every node
+/// :ivar dict node_layer: The layer of every node as a dictionary mapping
+///     node indices to layer numbers, the sources are in layer ``0``
+/// :ivar list layers: The node indices in every layer, in left to right
+///     order
+/// :ivar dict bends: The points where the edges spanning more than one layer
+///     cross the layers in between, as a dictionary mapping edge indices to
+///     lists of positions ordered from the source to the target of the edge
+/// :ivar int crossings: The number of edge crossings in the drawing
+#[pyclass(module = "retworkx")]
+pub struct SugiyamaLayout {
+    #[pyo3(get)]
+    pub pos: Pos2DMapping,
+    #[pyo3(get)]
+    pub node_layer: DictMap<usize, usize>,
+    #[pyo3(get)]
+    pub layers: Vec<Vec<usize>>,
+    #[pyo3(get)]
+    pub bends: DictMap<usize, Vec<Point>>,
+    #[pyo3(get)]
+    pub crossings: usize,
+}
+
+/// The layered graph: the nodes of the DAG followed by a dummy node for every
+/// layer crossed by an edge spanning more than one layer, so every edge only
+/// joins adjacent layers.
+struct Layered {
+    layer: Vec<usize>,
+    up: Vec<Vec<usize>>,
+    down: Vec<Vec<usize>>,
+}
+
+impl Layered {
+    fn add_node(&mut self, layer: usize) -> usize {
+        self.layer.push(layer);
+        self.up.push(Vec::new());
+        self.down.push(Vec::new());
+        self.layer.len() - 1
+    }
+
+    fn add_edge(&mut self, source: usize, target: usize) {
+        self.down[source].push(target);
+        self.up[target].push(source);
+    }
+
+    /// The neighbors of `node` in the layer above it if `downward`, else in
+    /// the layer below it.
+    fn neighbors(&self, node: usize, downward: bool) -> &[usize] {
+        if downward {
+            &self.up[node]
+        } else {
+            &self.down[node]
+        }
+    }
+}
+
+/// Count the crossings between the edges from `upper` to `lower` by counting
+/// the inversions of the positions of their targets with a Fenwick tree.
+fn count_crossings(
+    layered: &Layered,
+    upper: &[usize],
+    position: &[usize],
+    lower_len: usize,
+) -> usize {
+    let mut targets: Vec<(usize, usize)> = upper
+        .iter()
+        .flat_map(|&node| {
+            layered.down[node]
+                .iter()
+                .map(move |&target| (position[node], position[target]))
+        })
+        .collect();
+    targets.sort_unstable();
+    let mut tree = vec![0usize; lower_len + 1];
+    let mut crossings = 0;
+    for (seen, (_, target)) in targets.into_iter().enumerate() {
+        // The number of earlier edges whose target is at or left of target
+        let mut not_crossing = 0;
+        let mut i = target + 1;
+        while i > 0 {
+            not_crossing += tree[i];
+            i -= i & i.wrapping_neg();
+        }
+        crossings += seen - not_crossing;
+        let mut i = target + 1;
+        while i <= lower_len {
+            tree[i] += 1;
+            i += i & i.wrapping_neg();
+        }
+    }
+    crossings
+}
+
+fn total_crossings(layered: &Layered, order: &[Vec<usize>], position: &[usize]) -> usize {
+    order
+        .windows(2)
+        .map(|pair| count_crossings(layered, &pair[0], position, pair[1].len()))
+        .sum()
+}
+
+/// The weighted median of the positions of the neighbors of a node, or
+/// `None` if it has no neighbors.
+fn median(mut positions: Vec<usize>) -> Option<f64> {
+    positions.sort_unstable();
+    let len = positions.len();
+    let mid = len / 2;
+    if len == 0 {
+        None
+    } else if len % 2 == 1 {
+        Some(positions[mid] as f64)
+    } else if len == 2 {
+        Some((positions[0] + positions[1]) as f64 / 2.0)
+    } else {
+        let left = (positions[mid - 1] - positions[0]) as f64;
+        let right = (positions[len - 1] - positions[mid]) as f64;
+        if left + right == 0.0 {
+            Some((positions[mid - 1] + positions[mid]) as f64 / 2.0)
+        } else {
+            Some(
+                (positions[mid - 1] as f64 * right + positions[mid] as f64 * left) / (left + right),
+            )
+        }
+    }
+}
+
+/// Sort every layer by the median of the positions of the neighbors of its
+/// nodes in the previous layer of the sweep, nodes without neighbors there
+/// keep their position.
+fn median_sweep(
+    layered: &Layered,
+    order: &mut [Vec<usize>],
+    position: &mut [usize],
+    downward: bool,
+) {
+    let layers: Vec<usize> = if downward {
+        (1..order.len()).collect()
+    } else {
+        (0..order.len().saturating_sub(1)).rev().collect()
+    };
+    for layer in layers {
+        let mut keyed: Vec<(f64, usize)> = order[layer]
+            .iter()
+            .map(|&node| {
+                let neighbors = layered.neighbors(node, downward);
+                let key = median(neighbors.iter().map(|&other| position[other]).collect());
+                (key.unwrap_or(position[node] as f64), node)
+            })
+            .collect();
+        keyed.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+        order[layer] = keyed.into_iter().map(|(_, node)| node).collect();
+        for (i, &node) in order[layer].iter().enumerate() {
+            position[node] = i;
+        }
+    }
+}
+
+/// The number of crossings between the edges of `left` and `right` to their
+/// neighbors in one of the adjacent layers if `left` is placed left of
+/// `right`.
+fn pair_crossings(left: &[usize], right: &[usize], position: &[usize]) -> usize {
+    left.iter()
+        .map(|&a| right.iter().filter(|&&b| position[b] < position[a]).count())
+        .sum()
+}
+
+/// Swap adjacent nodes of every layer while it reduces the crossings.
+fn transpose(layered: &Layered, order: &mut [Vec<usize>], position: &mut [usize]) {
+    let mut improved = true;
+    while improved {
+        improved = false;
+        for layer in order.iter_mut() {
+            for i in 1..layer.len() {
+                let (v, w) = (layer[i - 1], layer[i]);
+                let crossings = |a: usize, b: usize| {
+                    pair_crossings(&layered.up[a], &layered.up[b], position)
+                        + pair_crossings(&layered.down[a], &layered.down[b], position)
+                };
+                if crossings(w, v) < crossings(v, w) {
+                    layer.swap(i - 1, i);
+                    position[v] = i;
+                    position[w] = i - 1;
+                    improved = true;
+                }
+            }
+        }
+    }
+}
+
+/// Minimize the sum of the squared distances of `x` to `desired` subject to
+/// the nodes being at least 1 apart and in order, with the pool adjacent
+/// violators algorithm on `desired[i] - i` which has to be non-decreasing.
+fn place_layer(desired: &[f64]) -> Vec<f64> {
+    // Blocks of (sum, count) of merged values
+    let mut blocks: Vec<(f64, usize)> = Vec::with_capacity(desired.len());
+    for (i, value) in desired.iter().enumerate() {
+        blocks.push((value - i as f64, 1));
+        while blocks.len() > 1 {
+            let (sum, count) = blocks[blocks.len() - 1];
+            let (prev_sum, prev_count) = blocks[blocks.len() - 2];
+            if prev_sum / (prev_count as f64) <= sum / (count as f64) {
+                break;
+            }
+            blocks.pop();
+            let last = blocks.len() - 1;
+            blocks[last] = (prev_sum + sum, prev_count + count);
+        }
+    }
+    blocks
+        .into_iter()
+        .flat_map(|(sum, count)| (0..count).map(move |_| sum / count as f64))
+        .enumerate()
+        .map(|(i, value)| value + i as f64)
+        .collect()
+}
+
+/// Assign the x coordinates with sweeps placing every node as close as
+/// possible to the mean of its neighbors in the previous layer of the sweep.
+fn assign_coordinates(layered: &Layered, order: &[Vec<usize>]) -> Vec<f64> {
+    let mut x = vec![0.0; layered.layer.len()];
+    for layer in order {
+        for (i, &node) in layer.iter().enumerate() {
+            x[node] = i as f64;
+        }
+    }
+    for sweep in 0..COORDINATE_SWEEPS {
+        let downward = sweep % 2 == 0;
+        let layers: Vec<&Vec<usize>> = if downward {
+            order.iter().skip(1).collect()
+        } else {
+            order.iter().rev().skip(1).collect()
+        };
+        for layer in layers {
+            let desired: Vec<f64> = layer
+                .iter()
+                .map(|&node| {
+                    let neighbors = layered.neighbors(node, downward);
+                    if neighbors.is_empty() {
+                        x[node]
+                    } else {
+                        neighbors.iter().map(|&other| x[other]).sum::<f64>()
+                            / neighbors.len() as f64
+                    }
+                })
+                .collect();
+            for (&node, value) in layer.iter().zip(place_layer(&desired)) {
+                x[node] = value;
+            }
+        }
+    }
+    x
+}
+
+pub fn sugiyama_layout(
+    graph: &StablePyGraph<Directed>,
+    max_iter: usize,
+    scale: Option<f64>,
+    center: Option<Point>,
+) -> PyResult<SugiyamaLayout> {
+    let sorted = match algo::toposort(graph, None) {
+        Ok(nodes) => nodes,
+        Err(_err) => return Err(DAGHasCycle::new_err("Sort encountered a cycle")),
+    };
+    let n = graph.node_count();
+    let index: HashMap<usize, usize> = graph
+        .node_indices()
+        .enumerate()
+        .map(|(i, node)| (node.index(), i))
+        .collect();
+
+    // Layer assignment: every node is one layer below its lowest predecessor,
+    // then the sources are moved down to just above their highest successor
+    let mut layer = vec![0; n];
+    for node in &sorted {
+        let depth = graph
+            .neighbors_directed(*node, petgraph::Incoming)
+            .map(|pred| layer[index[&pred.index()]] + 1)
+            .max()
+            .unwrap_or(0);
+        layer[index[&node.index()]] = depth;
+    }
+    for node in &sorted {
+        let is_source = graph
+            .neighbors_directed(*node, petgraph::Incoming)
+            .next()
+            .is_none();
+        if let Some(depth) = graph
+            .neighbors_directed(*node, petgraph::Outgoing)
+            .map(|succ| layer[index[&succ.index()]])
+            .min()
+        {
+            if is_source {
+                layer[index[&node.index()]] = depth - 1;
+            }
+        }
+    }
+
+    let mut layered = Layered {
+        layer: layer.clone(),
+        up: vec![Vec::new(); n],
+        down: vec![Vec::new(); n],
+    };
+    let mut chains: Vec<(usize, Vec<usize>)> = Vec::new();
+    for edge in graph.edge_references() {
+        let source = index[&edge.source().index()];
+        let target = index[&edge.target().index()];
+        let mut prev = source;
+        let mut chain = Vec::new();
+        for dummy_layer in layer[source] + 1..layer[target] {
+            let dummy = layered.add_node(dummy_layer);
+            layered.add_edge(prev, dummy);
+            chain.push(dummy);
+            prev = dummy;
+        }
+        layered.add_edge(prev, target);
+        if !chain.is_empty() {
+            chains.push((edge.id().index(), chain));
+        }
+    }
+
+    // The initial order of every layer is the order of a depth first search
+    // from the sources, which draws trees without crossings
+    let layer_count = layer.iter().max().map_or(0, |max| max + 1);
+    let total = layered.layer.len();
+    let mut order: Vec<Vec<usize>> = vec![Vec::new(); layer_count];
+    let mut visited = vec![false; total];
+    for root in 0..n {
+        if visited[root] || !layered.up[root].is_empty() {
+            continue;
+        }
+        let mut stack = vec![root];
+        while let Some(node) = stack.pop() {
+            if visited[node] {
+                continue;
+            }
+            visited[node] = true;
+            order[layered.layer[node]].push(node);
+            stack.extend(
+                layered.down[node]
+                    .iter()
+                    .rev()
+                    .filter(|&&next| !visited[next]),
+            );
+        }
+    }
+    let mut position = vec![0; total];
+    for nodes in &order {
+        for (i, &node) in nodes.iter().enumerate() {
+            position[node] = i;
+        }
+    }
+
+    // Crossing minimization: alternate downward and upward median sweeps,
+    // each followed by swaps of adjacent nodes, and keep the best order
+    transpose(&layered, &mut order, &mut position);
+    let mut crossings = total_crossings(&layered, &order, &position);
+    let mut best = order.clone();
+    for iteration in 0..max_iter {
+        if crossings == 0 {
+            break;
+        }
+        median_sweep(&layered, &mut order, &mut position, iteration % 2 == 0);
+        transpose(&layered, &mut order, &mut position);
+        let current = total_crossings(&layered, &order, &position);
+        if current < crossings {
+            crossings = current;
+            best = order.clone();
+        }
+    }
+    let order = best;
+
+    // The sources are at the top and the layers are 1 apart
+    let x = assign_coordinates(&layered, &order);
+    let min_x = x.iter().cloned().fold(f64::INFINITY, f64::min);
+    let mut positions: Vec<Point> = (0..total)
+        .map(|node| {
+            [
+                x[node] - min_x,
+                (layer_count - 1 - layered.layer[node]) as f64,
+            ]
+        })
+        .collect();
+    if let Some(scale) = scale {
+        rescale(&mut positions, scale, (0..total).collect());
+    }
+    if let Some(center) = center {
+        recenter(&mut positions, center);
+    }
+
+    let nodes: Vec<usize> = graph.node_indices().map(|node| node.index()).collect();
+    Ok(SugiyamaLayout {
+        pos: Pos2DMapping {
+            pos_map: nodes
+                .iter()
+                .enumerate()
+                .map(|(i, &node)| (node, positions[i]))
+                .collect(),
+        },
+        node_layer: nodes
+            .iter()
+            .enumerate()
+            .map(|(i, &node)| (node, layer[i]))
+            .collect(),
+        layers: order
+            .iter()
+            .map(|layer| {
+                layer
+                    .iter()
+                    .filter(|&&node| node < n)
+                    .map(|&node| nodes[node])
+                    .collect()
+            })
+            .collect(),
+        bends: chains
+            .into_iter()
+            .map(|(edge, chain)| {
+                (
+                    edge,
+                    chain.into_iter().map(|node| positions[node]).collect(),
+                )
+            })
+            .collect(),
+        crossings,
+    })
+}
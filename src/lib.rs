@@ -10,55 +10,120 @@
 // License for the specific language governing permissions and limitations
 // under the License.
 
+mod algorithm_info;
+mod approximation;
+mod attributes;
 mod cartesian_product;
 mod centrality;
+mod clique;
+mod coarsening;
 mod coloring;
+mod community;
 mod connectivity;
+mod contraction;
+mod curvature;
 mod dag_algo;
 mod digraph;
+mod dominance;
 mod dot_utils;
+mod edge_list;
+mod embedding;
 mod generators;
 mod graph;
+mod graph6;
+mod graph_diff;
+mod graph_summary;
+mod hamiltonian;
+mod interval_index;
 mod isomorphism;
 mod iterators;
 mod layout;
+mod linear_operator;
+mod link_analysis;
 mod matching;
+mod motifs;
+mod neighbors;
+mod node_keys;
+mod node_link;
+mod pajek;
+mod partition;
+mod path_ranking;
+mod planar;
 mod random_graph;
+mod rewrite;
+mod routing;
+mod sampling;
 mod shortest_path;
+mod snapshot;
+mod spectral;
 mod steiner_tree;
+mod temporal;
 mod tensor_product;
 mod toposort;
 mod transitivity;
 mod traversal;
 mod tree;
+mod tsp;
 mod union;
+mod views;
 
+use approximation::*;
 use cartesian_product::*;
 use centrality::*;
+use clique::*;
+use coarsening::*;
 use coloring::*;
+use community::*;
 use connectivity::*;
+use curvature::*;
 use dag_algo::*;
+use dominance::*;
+use dot_utils::*;
+use edge_list::*;
+use embedding::*;
+use graph6::*;
+use graph_diff::*;
+use graph_summary::*;
+use hamiltonian::*;
+use interval_index::*;
 use isomorphism::*;
 use layout::*;
+use linear_operator::*;
+use link_analysis::*;
 use matching::*;
+use motifs::*;
+use neighbors::*;
+use node_link::*;
+use pajek::*;
+use partition::*;
+use path_ranking::*;
+use planar::*;
 use random_graph::*;
+use rewrite::*;
+use routing::*;
+use sampling::*;
 use shortest_path::*;
+use spectral::*;
 use steiner_tree::*;
+use temporal::*;
 use tensor_product::*;
 use transitivity::*;
 use traversal::*;
 use tree::*;
+use tsp::*;
 use union::*;
 
 use hashbrown::HashMap;
 use indexmap::map::Entry::{Occupied, Vacant};
-use num_complex::Complex64;
+use num_complex::{Complex32, Complex64};
+use numpy::{PyReadonlyArray1, PyReadonlyArray2};
 
 use pyo3::create_exception;
 use pyo3::exceptions::PyException;
 use pyo3::exceptions::PyValueError;
 use pyo3::import_exception;
 use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyString};
 use pyo3::wrap_pyfunction;
 use pyo3::wrap_pymodule;
 use pyo3::Python;
@@ -66,7 +131,8 @@ use pyo3::Python;
 use petgraph::graph::NodeIndex;
 use petgraph::prelude::*;
 use petgraph::visit::{
-    Data, GraphBase, GraphProp, IntoEdgeReferences, IntoNodeIdentifiers, NodeCount, NodeIndexable,
+    Data, EdgeIndexable, GraphBase, GraphProp, IntoEdgeReferences, IntoNodeIdentifiers, NodeCount,
+    NodeIndexable,
 };
 use petgraph::EdgeType;
 
@@ -75,6 +141,8 @@ use std::hash::Hash;
 
 use retworkx_core::dictmap::*;
 
+use crate::attributes::AttributeColumns;
+
 trait IsNan {
     fn is_nan(&self) -> bool;
 }
@@ -88,6 +156,14 @@ impl IsNan for f64 {
     }
 }
 
+impl IsNan for f32 {
+    #[inline]
+    #[allow(clippy::eq_op)]
+    fn is_nan(&self) -> bool {
+        self != self
+    }
+}
+
 /// https://docs.rs/num-complex/0.4.0/src/num_complex/lib.rs.html#572-574
 impl IsNan for Complex64 {
     #[inline]
@@ -95,6 +171,185 @@ impl IsNan for Complex64 {
         self.re.is_nan() || self.im.is_nan()
     }
 }
+
+impl IsNan for Complex32 {
+    #[inline]
+    fn is_nan(&self) -> bool {
+        self.re.is_nan() || self.im.is_nan()
+    }
+}
+
+/// An input adjacency matrix with ``float64`` or ``float32`` elements
+#[derive(FromPyObject)]
+pub enum RealMatrix<'p> {
+    Float64(PyReadonlyArray2<'p, f64>),
+    Float32(PyReadonlyArray2<'p, f32>),
+}
+
+/// An input adjacency matrix with ``complex128`` or ``complex64`` elements
+#[derive(FromPyObject)]
+pub enum ComplexMatrix<'p> {
+    Complex128(PyReadonlyArray2<'p, Complex64>),
+    Complex64(PyReadonlyArray2<'p, Complex32>),
+}
+
+/// An input array of node indices with an integer data type
+#[derive(FromPyObject)]
+pub enum IndexArray<'p> {
+    Int64(PyReadonlyArray1<'p, i64>),
+    Int32(PyReadonlyArray1<'p, i32>),
+    UInt64(PyReadonlyArray1<'p, u64>),
+    UInt32(PyReadonlyArray1<'p, u32>),
+}
+
+impl<'p> IndexArray<'p> {
+    fn len(&self) -> usize {
+        match self {
+            IndexArray::Int64(array) => array.len(),
+            IndexArray::Int32(array) => array.len(),
+            IndexArray::UInt64(array) => array.len(),
+            IndexArray::UInt32(array) => array.len(),
+        }
+    }
+
+    /// The node indices in the array, which must fit in the index type of
+    /// the graph
+    fn to_indices(&self) -> PyResult<Vec<u32>> {
+        fn convert<T>(array: &PyReadonlyArray1<T>) -> PyResult<Vec<u32>>
+        where
+            T: Copy + numpy::Element + std::fmt::Display,
+            u32: TryFrom<T>,
+        {
+            array
+                .as_array()
+                .iter()
+                .map(|&index| {
+                    u32::try_from(index)
+                        .ok()
+                        .filter(|&index| index != u32::MAX)
+                        .ok_or_else(|| {
+                            PyValueError::new_err(format!("Invalid node index {}", index))
+                        })
+                })
+                .collect()
+        }
+        match self {
+            IndexArray::Int64(array) => convert(array),
+            IndexArray::Int32(array) => convert(array),
+            IndexArray::UInt64(array) => convert(array),
+            IndexArray::UInt32(array) => convert(array),
+        }
+    }
+}
+
+/// An input array of edge weights with a ``float64``, ``float32``, ``int64``
+/// or ``int32`` data type
+#[derive(FromPyObject)]
+pub enum WeightArray<'p> {
+    Float64(PyReadonlyArray1<'p, f64>),
+    Float32(PyReadonlyArray1<'p, f32>),
+    Int64(PyReadonlyArray1<'p, i64>),
+    Int32(PyReadonlyArray1<'p, i32>),
+}
+
+impl<'p> WeightArray<'p> {
+    fn len(&self) -> usize {
+        match self {
+            WeightArray::Float64(array) => array.len(),
+            WeightArray::Float32(array) => array.len(),
+            WeightArray::Int64(array) => array.len(),
+            WeightArray::Int32(array) => array.len(),
+        }
+    }
+}
+
+/// Build a graph from arrays of the sources, targets and weights of its
+/// edges, with a node for every index up to the largest one in the arrays
+/// or ``num_nodes``, whose payload is its index
+fn graph_from_edge_arrays<Ty: EdgeType>(
+    py: Python,
+    sources: IndexArray,
+    targets: IndexArray,
+    weights: Option<WeightArray>,
+    num_nodes: Option<usize>,
+) -> PyResult<StablePyGraph<Ty>> {
+    let num_edges = sources.len();
+    if targets.len() != num_edges || matches!(&weights, Some(weights) if weights.len() != num_edges)
+    {
+        return Err(PyValueError::new_err(
+            "sources, targets and weights must have the same length",
+        ));
+    }
+    let sources = sources.to_indices()?;
+    let targets = targets.to_indices()?;
+    let max_index = sources.iter().chain(targets.iter()).max();
+    let min_num_nodes = max_index.map_or(0, |&index| index as usize + 1);
+    let num_nodes = match num_nodes {
+        Some(num_nodes) if num_nodes < min_num_nodes => {
+            return Err(PyValueError::new_err(format!(
+                "num_nodes is {} but the arrays have the node index {}",
+                num_nodes,
+                min_num_nodes - 1
+            )))
+        }
+        Some(num_nodes) if num_nodes >= u32::MAX as usize => {
+            return Err(PyValueError::new_err(format!(
+                "num_nodes is {} but a graph can have at most {} nodes",
+                num_nodes,
+                u32::MAX - 1
+            )))
+        }
+        Some(num_nodes) => num_nodes,
+        None => min_num_nodes,
+    };
+    let mut graph = StablePyGraph::<Ty>::with_capacity(num_nodes, num_edges);
+    for node in 0..num_nodes {
+        graph.add_node(node.to_object(py));
+    }
+    fn add_edges<Ty: EdgeType, F: FnMut(usize) -> PyObject>(
+        graph: &mut StablePyGraph<Ty>,
+        sources: &[u32],
+        targets: &[u32],
+        mut weight: F,
+    ) {
+        for (edge, (&source, &target)) in sources.iter().zip(targets.iter()).enumerate() {
+            graph.add_edge(
+                NodeIndex::new(source as usize),
+                NodeIndex::new(target as usize),
+                weight(edge),
+            );
+        }
+    }
+    match weights {
+        None => add_edges(&mut graph, &sources, &targets, |_| py.None()),
+        Some(WeightArray::Float64(weights)) => {
+            let weights = weights.as_array();
+            add_edges(&mut graph, &sources, &targets, |edge| {
+                weights[edge].to_object(py)
+            })
+        }
+        Some(WeightArray::Float32(weights)) => {
+            let weights = weights.as_array();
+            add_edges(&mut graph, &sources, &targets, |edge| {
+                weights[edge].to_object(py)
+            })
+        }
+        Some(WeightArray::Int64(weights)) => {
+            let weights = weights.as_array();
+            add_edges(&mut graph, &sources, &targets, |edge| {
+                weights[edge].to_object(py)
+            })
+        }
+        Some(WeightArray::Int32(weights)) => {
+            let weights = weights.as_array();
+            add_edges(&mut graph, &sources, &targets, |edge| {
+                weights[edge].to_object(py)
+            })
+        }
+    }
+    Ok(graph)
+}
+
 pub type StablePyGraph<Ty> = StableGraph<PyObject, PyObject, Ty>;
 
 pub trait NodesRemoved {
@@ -170,6 +425,52 @@ where
     }
 }
 
+/// Extract the weight of every edge of ``graph`` named by ``weight_attr``,
+/// indexed by edge index. The weights are read from the edge attribute column
+/// ``weight_attr`` if the graph has one, otherwise from the ``weight_attr``
+/// key of the edges with a dict payload. An edge without a value gets
+/// ``default_weight``. Returns ``None`` if ``weight_attr`` isn't specified.
+fn edge_weights<Ty: EdgeType>(
+    py: Python,
+    graph: &StablePyGraph<Ty>,
+    attributes: &AttributeColumns,
+    weight_fn: &Option<PyObject>,
+    weight_attr: Option<&str>,
+    default_weight: f64,
+) -> PyResult<Option<Vec<f64>>> {
+    let weight_attr = match weight_attr {
+        Some(weight_attr) => weight_attr,
+        None => return Ok(None),
+    };
+    if weight_fn.is_some() {
+        return Err(PyValueError::new_err(
+            "weight_fn and weight_attr can't both be specified",
+        ));
+    }
+    let mut weights = vec![default_weight; graph.edge_bound()];
+    match attributes.edges.get(weight_attr) {
+        Some(column) => {
+            for edge in graph.edge_indices() {
+                let weight = column.get_f64(edge.index());
+                if !weight.is_nan() {
+                    weights[edge.index()] = weight;
+                }
+            }
+        }
+        None => {
+            let key = PyString::new(py, weight_attr);
+            for edge in graph.edge_references() {
+                if let Ok(payload) = edge.weight().as_ref(py).downcast::<PyDict>() {
+                    if let Some(weight) = payload.get_item(key) {
+                        weights[edge.id().index()] = weight.extract()?;
+                    }
+                }
+            }
+        }
+    }
+    Ok(Some(weights))
+}
+
 #[inline]
 fn is_valid_weight(val: f64) -> PyResult<f64> {
     if val.is_sign_negative() {
@@ -186,6 +487,9 @@ fn is_valid_weight(val: f64) -> PyResult<f64> {
 pub enum CostFn {
     Default(f64),
     PyFunction(PyObject),
+    /// The cost of every edge indexed by edge index, see
+    /// [`CostFn::from_weight_spec`]
+    EdgeCosts(Vec<f64>),
 }
 
 impl From<PyObject> for CostFn {
@@ -216,6 +520,35 @@ impl TryFrom<(Option<PyObject>, f64)> for CostFn {
 }
 
 impl CostFn {
+    /// Build the cost function of the edges of ``graph`` from ``weight_fn``
+    /// or from ``weight_attr``, whose costs are extracted once with
+    /// [`edge_weights`] and have to be read with [`CostFn::call_edge`].
+    fn from_weight_spec<Ty: EdgeType>(
+        py: Python,
+        graph: &StablePyGraph<Ty>,
+        attributes: &AttributeColumns,
+        weight_fn: Option<PyObject>,
+        weight_attr: Option<&str>,
+        default_weight: f64,
+    ) -> PyResult<Self> {
+        match edge_weights(
+            py,
+            graph,
+            attributes,
+            &weight_fn,
+            weight_attr,
+            default_weight,
+        )? {
+            Some(weights) => {
+                for weight in weights.iter() {
+                    is_valid_weight(*weight)?;
+                }
+                Ok(CostFn::EdgeCosts(weights))
+            }
+            None => CostFn::try_from((weight_fn, default_weight)),
+        }
+    }
+
     fn call(&self, py: Python, arg: &PyObject) -> PyResult<f64> {
         match self {
             CostFn::Default(val) => Ok(*val),
@@ -224,6 +557,17 @@ impl CostFn {
                 let val: f64 = raw.extract(py)?;
                 is_valid_weight(val)
             }
+            CostFn::EdgeCosts(_) => unreachable!("edge costs are read with call_edge"),
+        }
+    }
+
+    fn call_edge<E>(&self, py: Python, edge: E) -> PyResult<f64>
+    where
+        E: EdgeRef<Weight = PyObject, EdgeId = EdgeIndex>,
+    {
+        match self {
+            CostFn::EdgeCosts(costs) => Ok(costs[edge.id().index()]),
+            _ => self.call(py, edge.weight()),
         }
     }
 }
@@ -248,6 +592,19 @@ fn find_node_by_weight<Ty: EdgeType>(
     Ok(index)
 }
 
+/// Sort the items of a result built from a hash table by ``key``, so the
+/// result is in the same order on every run, unless the
+/// ``deterministic-order`` feature is disabled.
+fn deterministic_order<T, K, F>(items: &mut [T], key: F)
+where
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    if cfg!(feature = "deterministic-order") {
+        items.sort_unstable_by_key(key);
+    }
+}
+
 fn merge_duplicates<K, V, F, E>(xs: Vec<(K, V)>, mut merge_fn: F) -> Result<Vec<(K, V)>, E>
 where
     K: Hash + Eq,
@@ -281,6 +638,10 @@ create_exception!(retworkx, NoSuitableNeighbors, PyException);
 create_exception!(retworkx, NullGraph, PyException);
 // No path was found between the specified nodes.
 create_exception!(retworkx, NoPathFound, PyException);
+// An iterative algorithm failed to converge within its iteration limit.
+create_exception!(retworkx, FailedToConverge, PyException);
+// A search reached its time or call limit before it could finish.
+create_exception!(retworkx, SearchBudgetExhausted, PyException);
 // Prune part of the search tree while traversing a graph.
 import_exception!(retworkx.visit, PruneSearch);
 // Stop graph traversal.
@@ -296,6 +657,11 @@ fn retworkx(py: Python<'_>, m: &PyModule) -> PyResult<()> {
     m.add("NoSuitableNeighbors", py.get_type::<NoSuitableNeighbors>())?;
     m.add("NoPathFound", py.get_type::<NoPathFound>())?;
     m.add("NullGraph", py.get_type::<NullGraph>())?;
+    m.add("FailedToConverge", py.get_type::<FailedToConverge>())?;
+    m.add(
+        "SearchBudgetExhausted",
+        py.get_type::<SearchBudgetExhausted>(),
+    )?;
     m.add_wrapped(wrap_pyfunction!(bfs_successors))?;
     m.add_wrapped(wrap_pyfunction!(graph_bfs_search))?;
     m.add_wrapped(wrap_pyfunction!(digraph_bfs_search))?;
@@ -317,15 +683,59 @@ fn retworkx(py: Python<'_>, m: &PyModule) -> PyResult<()> {
     m.add_wrapped(wrap_pyfunction!(graph_is_isomorphic))?;
     m.add_wrapped(wrap_pyfunction!(digraph_is_subgraph_isomorphic))?;
     m.add_wrapped(wrap_pyfunction!(graph_is_subgraph_isomorphic))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_canonical_certificate))?;
+    m.add_wrapped(wrap_pyfunction!(graph_canonical_certificate))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_weisfeiler_lehman_graph_hash))?;
+    m.add_wrapped(wrap_pyfunction!(graph_weisfeiler_lehman_graph_hash))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_pagerank))?;
+    m.add_wrapped(wrap_pyfunction!(graph_pagerank))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_hits))?;
+    m.add_wrapped(wrap_pyfunction!(graph_hits))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_approximate_neighborhood_function))?;
+    m.add_wrapped(wrap_pyfunction!(graph_approximate_neighborhood_function))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_forman_curvature))?;
+    m.add_wrapped(wrap_pyfunction!(graph_forman_curvature))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_ollivier_ricci_curvature))?;
+    m.add_wrapped(wrap_pyfunction!(graph_ollivier_ricci_curvature))?;
     m.add_wrapped(wrap_pyfunction!(digraph_vf2_mapping))?;
     m.add_wrapped(wrap_pyfunction!(graph_vf2_mapping))?;
     m.add_wrapped(wrap_pyfunction!(digraph_union))?;
     m.add_wrapped(wrap_pyfunction!(graph_union))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_graph_diff))?;
+    m.add_wrapped(wrap_pyfunction!(graph_graph_diff))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_graph_summary))?;
+    m.add_wrapped(wrap_pyfunction!(graph_graph_summary))?;
+    m.add_wrapped(wrap_pyfunction!(read_dot))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_node_link_json))?;
+    m.add_wrapped(wrap_pyfunction!(graph_node_link_json))?;
+    m.add_wrapped(wrap_pyfunction!(read_node_link_json))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_write_pajek))?;
+    m.add_wrapped(wrap_pyfunction!(graph_write_pajek))?;
+    m.add_wrapped(wrap_pyfunction!(read_pajek))?;
+    m.add_wrapped(wrap_pyfunction!(read_matrix_market))?;
+    m.add_wrapped(wrap_pyfunction!(from_graph6_bytes))?;
+    m.add_wrapped(wrap_pyfunction!(graph_to_graph6_bytes))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_to_graph6_bytes))?;
+    m.add_wrapped(wrap_pyfunction!(to_sparse6_bytes))?;
+    m.add_wrapped(wrap_pyfunction!(read_graph6_file))?;
+    m.add_wrapped(wrap_pyfunction!(graph_is_planar))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_is_planar))?;
+    m.add_wrapped(wrap_pyfunction!(graph_check_planarity))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_check_planarity))?;
     m.add_wrapped(wrap_pyfunction!(digraph_cartesian_product))?;
     m.add_wrapped(wrap_pyfunction!(graph_cartesian_product))?;
     m.add_wrapped(wrap_pyfunction!(topological_sort))?;
+    m.add_wrapped(wrap_pyfunction!(topological_sort_by))?;
+    m.add_wrapped(wrap_pyfunction!(transitive_reduction))?;
     m.add_wrapped(wrap_pyfunction!(descendants))?;
+    m.add_wrapped(wrap_pyfunction!(ancestors_at_distance))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_descendants_at_distance))?;
+    m.add_wrapped(wrap_pyfunction!(graph_descendants_at_distance))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_reachable_within))?;
+    m.add_wrapped(wrap_pyfunction!(graph_reachable_within))?;
     m.add_wrapped(wrap_pyfunction!(ancestors))?;
+    m.add_wrapped(wrap_pyfunction!(immediate_dominators))?;
+    m.add_wrapped(wrap_pyfunction!(dominance_frontiers))?;
     m.add_wrapped(wrap_pyfunction!(lexicographical_topological_sort))?;
     m.add_wrapped(wrap_pyfunction!(graph_floyd_warshall))?;
     m.add_wrapped(wrap_pyfunction!(digraph_floyd_warshall))?;
@@ -334,25 +744,60 @@ fn retworkx(py: Python<'_>, m: &PyModule) -> PyResult<()> {
     m.add_wrapped(wrap_pyfunction!(collect_runs))?;
     m.add_wrapped(wrap_pyfunction!(collect_bicolor_runs))?;
     m.add_wrapped(wrap_pyfunction!(layers))?;
+    m.add_wrapped(wrap_pyfunction!(topological_generations))?;
+    m.add_wrapped(wrap_pyfunction!(topological_traversal))?;
     m.add_wrapped(wrap_pyfunction!(graph_distance_matrix))?;
     m.add_wrapped(wrap_pyfunction!(digraph_distance_matrix))?;
     m.add_wrapped(wrap_pyfunction!(digraph_adjacency_matrix))?;
     m.add_wrapped(wrap_pyfunction!(graph_adjacency_matrix))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_csr_adjacency_matrix))?;
+    m.add_wrapped(wrap_pyfunction!(graph_csr_adjacency_matrix))?;
     m.add_wrapped(wrap_pyfunction!(graph_all_simple_paths))?;
     m.add_wrapped(wrap_pyfunction!(digraph_all_simple_paths))?;
+    m.add_wrapped(wrap_pyfunction!(graph_hamiltonian_path))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_hamiltonian_path))?;
     m.add_wrapped(wrap_pyfunction!(graph_dijkstra_shortest_paths))?;
     m.add_wrapped(wrap_pyfunction!(digraph_dijkstra_shortest_paths))?;
+    m.add_wrapped(wrap_pyfunction!(graph_dijkstra_shortest_path_edges))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_dijkstra_shortest_path_edges))?;
     m.add_wrapped(wrap_pyfunction!(graph_dijkstra_shortest_path_lengths))?;
     m.add_wrapped(wrap_pyfunction!(digraph_dijkstra_shortest_path_lengths))?;
     m.add_wrapped(wrap_pyfunction!(digraph_all_pairs_dijkstra_path_lengths))?;
+    m.add_wrapped(wrap_pyfunction!(
+        digraph_all_pairs_dijkstra_path_lengths_chunked
+    ))?;
     m.add_wrapped(wrap_pyfunction!(digraph_all_pairs_dijkstra_shortest_paths))?;
     m.add_wrapped(wrap_pyfunction!(graph_all_pairs_dijkstra_path_lengths))?;
+    m.add_wrapped(wrap_pyfunction!(
+        graph_all_pairs_dijkstra_path_lengths_chunked
+    ))?;
     m.add_wrapped(wrap_pyfunction!(graph_all_pairs_dijkstra_shortest_paths))?;
     m.add_wrapped(wrap_pyfunction!(graph_betweenness_centrality))?;
     m.add_wrapped(wrap_pyfunction!(digraph_betweenness_centrality))?;
+    m.add_wrapped(wrap_pyfunction!(graph_betweenness_centrality_subset))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_betweenness_centrality_subset))?;
+    m.add_wrapped(wrap_pyfunction!(graph_top_k_degree_centrality))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_top_k_degree_centrality))?;
+    m.add_wrapped(wrap_pyfunction!(graph_top_k_closeness_centrality))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_top_k_closeness_centrality))?;
+    m.add_wrapped(wrap_pyfunction!(graph_top_k_betweenness_centrality))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_top_k_betweenness_centrality))?;
+    m.add_wrapped(wrap_pyfunction!(graph_eigenvector_centrality))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_eigenvector_centrality))?;
+    m.add_wrapped(wrap_pyfunction!(graph_katz_centrality))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_katz_centrality))?;
+    m.add_wrapped(wrap_pyfunction!(graph_closeness_centrality))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_closeness_centrality))?;
+    m.add_wrapped(wrap_pyfunction!(graph_harmonic_centrality))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_harmonic_centrality))?;
+    m.add_wrapped(wrap_pyfunction!(graph_closeness_vitality))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_closeness_vitality))?;
+    m.add_wrapped(wrap_pyfunction!(flow_vitality))?;
     m.add_wrapped(wrap_pyfunction!(graph_astar_shortest_path))?;
     m.add_wrapped(wrap_pyfunction!(digraph_astar_shortest_path))?;
     m.add_wrapped(wrap_pyfunction!(graph_greedy_color))?;
+    m.add_wrapped(wrap_pyfunction!(louvain_communities))?;
+    m.add_wrapped(wrap_pyfunction!(modularity))?;
     m.add_wrapped(wrap_pyfunction!(graph_tensor_product))?;
     m.add_wrapped(wrap_pyfunction!(digraph_tensor_product))?;
     m.add_wrapped(wrap_pyfunction!(directed_gnp_random_graph))?;
@@ -360,24 +805,123 @@ fn retworkx(py: Python<'_>, m: &PyModule) -> PyResult<()> {
     m.add_wrapped(wrap_pyfunction!(directed_gnm_random_graph))?;
     m.add_wrapped(wrap_pyfunction!(undirected_gnm_random_graph))?;
     m.add_wrapped(wrap_pyfunction!(random_geometric_graph))?;
+    m.add_wrapped(wrap_pyfunction!(barabasi_albert_graph))?;
+    m.add_wrapped(wrap_pyfunction!(directed_barabasi_albert_graph))?;
+    m.add_wrapped(wrap_pyfunction!(powerlaw_cluster_graph))?;
+    m.add_wrapped(wrap_pyfunction!(watts_strogatz_graph))?;
+    m.add_wrapped(wrap_pyfunction!(connected_watts_strogatz_graph))?;
+    m.add_wrapped(wrap_pyfunction!(stochastic_block_model))?;
+    m.add_wrapped(wrap_pyfunction!(random_bipartite_graph))?;
+    m.add_wrapped(wrap_pyfunction!(configuration_model))?;
+    m.add_wrapped(wrap_pyfunction!(is_graphical))?;
+    m.add_wrapped(wrap_pyfunction!(double_edge_swap))?;
+    m.add_wrapped(wrap_pyfunction!(connected_double_edge_swap))?;
+    m.add_wrapped(wrap_pyfunction!(graph_linear_operator))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_linear_operator))?;
+    m.add_wrapped(wrap_pyfunction!(graph_edge_interval_index))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_edge_interval_index))?;
+    m.add_wrapped(wrap_pyfunction!(graph_edge_cut_partition))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_edge_cut_partition))?;
+    m.add_wrapped(wrap_pyfunction!(graph_aggregate_graph))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_aggregate_graph))?;
+    m.add_wrapped(wrap_pyfunction!(graph_coarsen))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_coarsen))?;
+    m.add_wrapped(wrap_pyfunction!(graph_kernighan_lin_bisection))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_kernighan_lin_bisection))?;
+    m.add_wrapped(wrap_pyfunction!(graph_multilevel_partition))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_multilevel_partition))?;
+    m.add_wrapped(wrap_pyfunction!(graph_fiedler_vector))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_fiedler_vector))?;
+    m.add_wrapped(wrap_pyfunction!(graph_algebraic_connectivity))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_algebraic_connectivity))?;
+    m.add_wrapped(wrap_pyfunction!(graph_spectral_bisection))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_spectral_bisection))?;
+    m.add_wrapped(wrap_pyfunction!(graph_laplacian_matrix))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_laplacian_matrix))?;
+    m.add_wrapped(wrap_pyfunction!(graph_random_walks))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_random_walks))?;
+    m.add_wrapped(wrap_pyfunction!(graph_random_node_sample))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_random_node_sample))?;
+    m.add_wrapped(wrap_pyfunction!(graph_random_edge_sample))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_random_edge_sample))?;
+    m.add_wrapped(wrap_pyfunction!(graph_forest_fire_sample))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_forest_fire_sample))?;
+    m.add_wrapped(wrap_pyfunction!(graph_temporal_shortest_paths))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_temporal_shortest_paths))?;
+    m.add_wrapped(wrap_pyfunction!(graph_temporal_earliest_arrival))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_temporal_earliest_arrival))?;
+    m.add_wrapped(wrap_pyfunction!(graph_temporal_reachability))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_temporal_reachability))?;
     m.add_wrapped(wrap_pyfunction!(cycle_basis))?;
+    m.add_wrapped(wrap_pyfunction!(minimum_cycle_basis))?;
+    m.add_wrapped(wrap_pyfunction!(minimum_cycle_cover))?;
+    m.add_wrapped(wrap_pyfunction!(cycle_sum))?;
+    m.add_wrapped(wrap_pyfunction!(is_in_cycle_space))?;
     m.add_wrapped(wrap_pyfunction!(strongly_connected_components))?;
+    m.add_wrapped(wrap_pyfunction!(condensation))?;
     m.add_wrapped(wrap_pyfunction!(digraph_dfs_edges))?;
     m.add_wrapped(wrap_pyfunction!(graph_dfs_edges))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_reconstruction_precision_at_k))?;
+    m.add_wrapped(wrap_pyfunction!(graph_reconstruction_precision_at_k))?;
+    m.add_wrapped(wrap_pyfunction!(
+        digraph_reconstruction_mean_average_precision
+    ))?;
+    m.add_wrapped(wrap_pyfunction!(
+        graph_reconstruction_mean_average_precision
+    ))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_link_prediction_roc_auc))?;
+    m.add_wrapped(wrap_pyfunction!(graph_link_prediction_roc_auc))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_dfs_traversal))?;
+    m.add_wrapped(wrap_pyfunction!(graph_dfs_traversal))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_bfs_traversal))?;
+    m.add_wrapped(wrap_pyfunction!(graph_bfs_traversal))?;
     m.add_wrapped(wrap_pyfunction!(digraph_find_cycle))?;
     m.add_wrapped(wrap_pyfunction!(digraph_k_shortest_path_lengths))?;
     m.add_wrapped(wrap_pyfunction!(graph_k_shortest_path_lengths))?;
+    m.add_wrapped(wrap_pyfunction!(graph_rank_paths))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_rank_paths))?;
+    m.add_wrapped(wrap_pyfunction!(graph_diverse_paths))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_diverse_paths))?;
+    m.add_wrapped(wrap_pyfunction!(graph_savings_routes))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_savings_routes))?;
+    m.add_wrapped(wrap_pyfunction!(graph_sweep_routes))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_sweep_routes))?;
     m.add_wrapped(wrap_pyfunction!(is_matching))?;
     m.add_wrapped(wrap_pyfunction!(is_maximal_matching))?;
+    m.add_wrapped(wrap_pyfunction!(greedy_dominating_set))?;
+    m.add_wrapped(wrap_pyfunction!(maximal_independent_set))?;
+    m.add_wrapped(wrap_pyfunction!(find_cliques))?;
+    m.add_wrapped(wrap_pyfunction!(max_clique))?;
     m.add_wrapped(wrap_pyfunction!(max_weight_matching))?;
     m.add_wrapped(wrap_pyfunction!(minimum_spanning_edges))?;
     m.add_wrapped(wrap_pyfunction!(minimum_spanning_tree))?;
     m.add_wrapped(wrap_pyfunction!(graph_transitivity))?;
+    m.add_wrapped(wrap_pyfunction!(triangles))?;
+    m.add_wrapped(wrap_pyfunction!(local_clustering_coefficient))?;
+    m.add_wrapped(wrap_pyfunction!(graphlet_census))?;
+    m.add_wrapped(wrap_pyfunction!(graphlet_orbits))?;
+    m.add_wrapped(wrap_pyfunction!(triad_census))?;
     m.add_wrapped(wrap_pyfunction!(digraph_transitivity))?;
+    m.add_wrapped(wrap_pyfunction!(graph_common_neighbors))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_common_neighbors))?;
+    m.add_wrapped(wrap_pyfunction!(graph_jaccard_similarity))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_jaccard_similarity))?;
     m.add_wrapped(wrap_pyfunction!(graph_core_number))?;
     m.add_wrapped(wrap_pyfunction!(digraph_core_number))?;
+    m.add_wrapped(wrap_pyfunction!(graph_k_core))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_k_core))?;
+    m.add_wrapped(wrap_pyfunction!(graph_edge_connectivity))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_edge_connectivity))?;
+    m.add_wrapped(wrap_pyfunction!(graph_node_connectivity))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_node_connectivity))?;
+    m.add_wrapped(wrap_pyfunction!(graph_minimum_edge_cut))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_minimum_edge_cut))?;
+    m.add_wrapped(wrap_pyfunction!(graph_minimum_node_cut))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_minimum_node_cut))?;
     m.add_wrapped(wrap_pyfunction!(graph_complement))?;
     m.add_wrapped(wrap_pyfunction!(digraph_complement))?;
+    m.add_wrapped(wrap_pyfunction!(graph_power))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_power))?;
     m.add_wrapped(wrap_pyfunction!(graph_random_layout))?;
     m.add_wrapped(wrap_pyfunction!(digraph_random_layout))?;
     m.add_wrapped(wrap_pyfunction!(graph_bipartite_layout))?;
@@ -390,6 +934,15 @@ fn retworkx(py: Python<'_>, m: &PyModule) -> PyResult<()> {
     m.add_wrapped(wrap_pyfunction!(digraph_spiral_layout))?;
     m.add_wrapped(wrap_pyfunction!(graph_spring_layout))?;
     m.add_wrapped(wrap_pyfunction!(digraph_spring_layout))?;
+    m.add_wrapped(wrap_pyfunction!(graph_planar_layout))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_planar_layout))?;
+    m.add_wrapped(wrap_pyfunction!(graph_spectral_layout))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_spectral_layout))?;
+    m.add_wrapped(wrap_pyfunction!(graph_kamada_kawai_layout))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_kamada_kawai_layout))?;
+    m.add_wrapped(wrap_pyfunction!(sugiyama_layout))?;
+    m.add_wrapped(wrap_pyfunction!(graph_edge_bundling))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_edge_bundling))?;
     m.add_wrapped(wrap_pyfunction!(digraph_num_shortest_paths_unweighted))?;
     m.add_wrapped(wrap_pyfunction!(graph_num_shortest_paths_unweighted))?;
     m.add_wrapped(wrap_pyfunction!(
@@ -400,14 +953,46 @@ fn retworkx(py: Python<'_>, m: &PyModule) -> PyResult<()> {
     ))?;
     m.add_wrapped(wrap_pyfunction!(metric_closure))?;
     m.add_wrapped(wrap_pyfunction!(steiner_tree::steiner_tree))?;
+    m.add_wrapped(wrap_pyfunction!(greedy_tsp))?;
+    m.add_wrapped(wrap_pyfunction!(christofides))?;
     m.add_wrapped(wrap_pyfunction!(digraph_dfs_search))?;
     m.add_wrapped(wrap_pyfunction!(graph_dfs_search))?;
     m.add_wrapped(wrap_pyfunction!(articulation_points))?;
     m.add_wrapped(wrap_pyfunction!(biconnected_components))?;
+    m.add_wrapped(wrap_pyfunction!(bridges))?;
     m.add_wrapped(wrap_pyfunction!(chain_decomposition))?;
+    m.add_wrapped(wrap_pyfunction!(graph_rewrite))?;
+    m.add_wrapped(wrap_pyfunction!(digraph_rewrite))?;
     m.add_class::<digraph::PyDiGraph>()?;
     m.add_class::<graph::PyGraph>()?;
     m.add_class::<toposort::TopologicalSorter>()?;
+    m.add_class::<toposort::TopologicalGenerations>()?;
+    m.add_class::<clique::MaximalCliqueIterator>()?;
+    m.add_class::<graph6::Graph6FileIterator>()?;
+    m.add_class::<toposort::TopologicalTraversal>()?;
+    m.add_class::<DFSTraversal>()?;
+    m.add_class::<BFSTraversal>()?;
+    m.add_class::<graph_diff::GraphDiff>()?;
+    m.add_class::<planar::PlanarityResult>()?;
+    m.add_class::<SugiyamaLayout>()?;
+    m.add_class::<graph_summary::GraphSummary>()?;
+    m.add_class::<algorithm_info::AlgorithmInfo>()?;
+    m.add_class::<linear_operator::GraphOperator>()?;
+    m.add_class::<interval_index::EdgeIntervalIndex>()?;
+    m.add_class::<partition::GraphPart>()?;
+    m.add_class::<partition::EdgeCutPartition>()?;
+    m.add_class::<coarsening::CoarseningHierarchy>()?;
+    m.add_class::<partition::GraphPartitioning>()?;
+    m.add_class::<routing::VehicleRoute>()?;
+    m.add_class::<tsp::SalespersonTour>()?;
+    m.add_class::<views::GraphView>()?;
+    m.add_class::<views::DiGraphView>()?;
+    m.add_class::<ToleranceMatcher>()?;
+    m.add_class::<AllOfMatcher>()?;
+    m.add_class::<AnyOfMatcher>()?;
+    m.add_class::<CycleSpace>()?;
+    m.add_class::<DynamicConnectivity>()?;
+    m.add_class::<shortest_path::neighborhood_function::NeighborhoodFunction>()?;
     m.add_class::<iterators::BFSSuccessors>()?;
     m.add_class::<iterators::Chains>()?;
     m.add_class::<iterators::NodeIndices>()?;
@@ -418,6 +1003,8 @@ fn retworkx(py: Python<'_>, m: &PyModule) -> PyResult<()> {
     m.add_class::<iterators::PathMapping>()?;
     m.add_class::<iterators::PathLengthMapping>()?;
     m.add_class::<iterators::CentralityMapping>()?;
+    m.add_class::<iterators::EdgeCurvatureMapping>()?;
+    m.add_class::<iterators::EdgePolylineMapping>()?;
     m.add_class::<iterators::Pos2DMapping>()?;
     m.add_class::<iterators::AllPairsPathLengthMapping>()?;
     m.add_class::<iterators::AllPairsPathMapping>()?;
@@ -0,0 +1,365 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use std::convert::Infallible;
+
+use crate::iterators::NodeIndices;
+use crate::{digraph, graph, weight_callable, StablePyGraph};
+
+use numpy::{IntoPyArray, PyArrayDescr, PyReadonlyArray1};
+
+use petgraph::stable_graph::EdgeReference;
+use petgraph::visit::{EdgeIndexable, EdgeRef, IntoEdgeReferences, NodeIndexable};
+use petgraph::EdgeType;
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::Python;
+
+use retworkx_core::linear_operator::{self, GraphMatrix};
+
+enum OperatorGraph {
+    Graph(Py<graph::PyGraph>),
+    DiGraph(Py<digraph::PyDiGraph>),
+}
+
+/// A matrix of a graph as a linear operator.
+///
+/// This class multiplies vectors by the adjacency, Laplacian, or random walk
+/// transition matrix of a graph without building the matrix, the products
+/// are computed from the edges of the graph every time. It's returned by
+/// :func:`~retworkx.linear_operator`. The rows and columns of the matrix are
+/// in the order of the node indices of the graph, given by
+/// :attr:`node_indices`. The operator has a ``shape`` and the ``matvec`` and
+/// ``rmatvec`` methods, so it can be passed to
+/// ``scipy.sparse.linalg.aslinearoperator`` and used by the iterative solvers
+/// and eigensolvers of scipy, or to your own solvers:
+///
+/// .. code-block:: python
+///
+///     import retworkx
+///     from scipy.sparse.linalg import aslinearoperator, eigsh
+///
+///     graph = retworkx.generators.grid_graph(100, 100)
+///     operator = retworkx.linear_operator(graph, matrix="laplacian")
+///     eigenvalues = eigsh(aslinearoperator(operator), k=3, which="SA")[0]
+///
+/// The operator keeps a reference to the graph and, if a ``weight_fn`` was
+/// given, one ``float`` weight per edge. The graph must not be modified
+/// while the operator is used: the products raise a ``ValueError`` if the
+/// number of nodes or edges of the graph changed since the operator was
+/// created.
+#[pyclass(module = "retworkx")]
+pub struct GraphOperator {
+    graph: OperatorGraph,
+    matrix: GraphMatrix,
+    weights: Option<Vec<f64>>,
+    default_weight: f64,
+    nodes: Vec<usize>,
+    node_bound: usize,
+    edge_count: usize,
+    edge_bound: usize,
+    parallel_threshold: usize,
+}
+
+impl GraphOperator {
+    fn new<Ty: EdgeType>(
+        py: Python,
+        graph: OperatorGraph,
+        inner_graph: &StablePyGraph<Ty>,
+        matrix: &str,
+        weight_fn: Option<PyObject>,
+        default_weight: f64,
+        parallel_threshold: usize,
+    ) -> PyResult<Self> {
+        let matrix = match matrix {
+            "adjacency" => GraphMatrix::Adjacency,
+            "laplacian" => GraphMatrix::Laplacian,
+            "transition" => GraphMatrix::Transition,
+            _ => {
+                return Err(PyValueError::new_err(
+                    "matrix must be one of 'adjacency', 'laplacian', or 'transition'",
+                ))
+            }
+        };
+        let weights = match weight_fn {
+            Some(_) => {
+                let mut weights = vec![0.0; inner_graph.edge_bound()];
+                for edge in inner_graph.edge_references() {
+                    weights[edge.id().index()] =
+                        weight_callable(py, &weight_fn, edge.weight(), default_weight)?;
+                }
+                Some(weights)
+            }
+            None => None,
+        };
+        Ok(GraphOperator {
+            graph,
+            matrix,
+            weights,
+            default_weight,
+            nodes: inner_graph.node_indices().map(|n| n.index()).collect(),
+            node_bound: inner_graph.node_bound(),
+            edge_count: inner_graph.edge_count(),
+            edge_bound: inner_graph.edge_bound(),
+            parallel_threshold,
+        })
+    }
+
+    fn product<Ty: EdgeType + Sync>(
+        &self,
+        graph: &StablePyGraph<Ty>,
+        x: PyReadonlyArray1<f64>,
+        transpose: bool,
+    ) -> PyResult<Vec<f64>> {
+        if graph.node_count() != self.nodes.len()
+            || graph.node_bound() != self.node_bound
+            || graph.edge_count() != self.edge_count
+            || graph.edge_bound() != self.edge_bound
+        {
+            return Err(PyValueError::new_err(
+                "The graph was modified after the operator was created",
+            ));
+        }
+        let x = x.as_array();
+        if x.len() != self.nodes.len() {
+            return Err(PyValueError::new_err(format!(
+                "x has length {}, expected {}",
+                x.len(),
+                self.nodes.len()
+            )));
+        }
+        let mut full_x = vec![0.0; self.node_bound];
+        for (node, value) in self.nodes.iter().zip(x.iter()) {
+            full_x[*node] = *value;
+        }
+        let weight_fn = |edge: EdgeReference<PyObject>| -> Result<f64, Infallible> {
+            Ok(match &self.weights {
+                Some(weights) => weights[edge.id().index()],
+                None => self.default_weight,
+            })
+        };
+        let full_y = if transpose {
+            linear_operator::rmatvec(
+                graph,
+                self.matrix,
+                weight_fn,
+                &full_x,
+                self.parallel_threshold,
+            )
+        } else {
+            linear_operator::matvec(
+                graph,
+                self.matrix,
+                weight_fn,
+                &full_x,
+                self.parallel_threshold,
+            )
+        }
+        .unwrap();
+        Ok(self.nodes.iter().map(|node| full_y[*node]).collect())
+    }
+
+    fn graph_product(
+        &self,
+        py: Python,
+        x: PyReadonlyArray1<f64>,
+        transpose: bool,
+    ) -> PyResult<PyObject> {
+        let y = match &self.graph {
+            OperatorGraph::Graph(graph) => self.product(&graph.borrow(py).graph, x, transpose)?,
+            OperatorGraph::DiGraph(graph) => self.product(&graph.borrow(py).graph, x, transpose)?,
+        };
+        Ok(y.into_pyarray(py).into())
+    }
+}
+
+#[pymethods]
+impl GraphOperator {
+    /// The shape of the matrix, a tuple of the number of nodes twice
+    #[getter]
+    fn shape(&self) -> (usize, usize) {
+        (self.nodes.len(), self.nodes.len())
+    }
+
+    /// The numpy data type of the matrix, always ``float64``
+    #[getter]
+    fn dtype<'py>(&self, py: Python<'py>) -> &'py PyArrayDescr {
+        PyArrayDescr::of::<f64>(py)
+    }
+
+    /// The node indices of the rows and columns of the matrix in order
+    #[getter]
+    fn node_indices(&self) -> NodeIndices {
+        NodeIndices {
+            nodes: self.nodes.clone(),
+        }
+    }
+
+    /// Multiply a vector by the matrix
+    ///
+    /// :param numpy.ndarray x: A 1 dimensional array of ``float`` with an
+    ///     entry for every node, in the order of :attr:`node_indices`
+    ///
+    /// :returns: The product of the matrix and ``x``
+    /// :rtype: numpy.ndarray
+    /// :raises ValueError: If the length of ``x`` isn't the number of nodes
+    ///     or if the graph was modified
+    #[pyo3(text_signature = "(self, x, /)")]
+    fn matvec(&self, py: Python, x: PyReadonlyArray1<f64>) -> PyResult<PyObject> {
+        self.graph_product(py, x, false)
+    }
+
+    /// Multiply a vector by the transpose of the matrix
+    ///
+    /// For a :class:`~retworkx.PyDiGraph` the entry of a node is computed from
+    /// its incoming edges. For a :class:`~retworkx.PyGraph` the adjacency and
+    /// Laplacian matrices are symmetric and this is the same as
+    /// :meth:`matvec`, but the transition matrix isn't.
+    ///
+    /// :param numpy.ndarray x: A 1 dimensional array of ``float`` with an
+    ///     entry for every node, in the order of :attr:`node_indices`
+    ///
+    /// :returns: The product of the transpose of the matrix and ``x``
+    /// :rtype: numpy.ndarray
+    /// :raises ValueError: If the length of ``x`` isn't the number of nodes
+    ///     or if the graph was modified
+    #[pyo3(text_signature = "(self, x, /)")]
+    fn rmatvec(&self, py: Python, x: PyReadonlyArray1<f64>) -> PyResult<PyObject> {
+        self.graph_product(py, x, true)
+    }
+
+    fn __matmul__(&self, py: Python, x: PyReadonlyArray1<f64>) -> PyResult<PyObject> {
+        self.graph_product(py, x, false)
+    }
+
+    fn __len__(&self) -> usize {
+        self.nodes.len()
+    }
+}
+
+/// Return a matrix of a PyGraph as a linear operator
+///
+/// The returned :class:`~retworkx.GraphOperator` multiplies vectors by the
+/// adjacency, Laplacian, or random walk transition matrix of the graph
+/// without building the matrix, the products are computed from the edges of
+/// the graph every time. This makes it possible to use iterative solvers on
+/// graphs too large to export as a matrix. Every edge goes both ways, so the
+/// adjacency and Laplacian matrices are symmetric. The products are computed
+/// on multiple threads for graphs with at least ``parallel_threshold``
+/// nodes, the env var ``RAYON_NUM_THREADS`` can be used to adjust how many
+/// threads are used.
+///
+/// :param PyGraph graph: The graph to get the matrix of
+/// :param str matrix: The matrix of the graph, either ``"adjacency"`` for
+///     the weighted adjacency matrix :math:`A`, ``"laplacian"`` for the
+///     Laplacian matrix :math:`L = D - A` where :math:`D` is the diagonal
+///     matrix of the weighted degrees, or ``"transition"`` for the random
+///     walk transition matrix :math:`P = D^{-1} A`, where the row of a node
+///     with a weighted degree of 0 is 0.
+/// :param weight_fn: An optional callable that will be passed the edge's
+///     payload object and is expected to return a ``float`` weight for that
+///     edge. It's called once per edge when the operator is created. The
+///     weights of parallel edges are added.
+/// :param float default_weight: If ``weight_fn`` isn't specified this
+///     optional float value will be used for the weight of every edge
+/// :param int parallel_threshold: The number of nodes from which the
+///     products are computed on multiple threads. Defaults to 300.
+///
+/// :returns: The matrix of ``graph`` as a linear operator
+/// :rtype: GraphOperator
+/// :raises ValueError: If ``matrix`` isn't one of the supported matrices
+#[pyfunction(
+    matrix = "\"adjacency\"",
+    default_weight = "1.0",
+    parallel_threshold = "300"
+)]
+#[pyo3(
+    text_signature = "(graph, /, matrix=\"adjacency\", weight_fn=None, default_weight=1.0, parallel_threshold=300)"
+)]
+pub fn graph_linear_operator(
+    py: Python,
+    graph: Py<graph::PyGraph>,
+    matrix: &str,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+    parallel_threshold: usize,
+) -> PyResult<GraphOperator> {
+    let inner_graph = graph.borrow(py);
+    GraphOperator::new(
+        py,
+        OperatorGraph::Graph(graph.clone_ref(py)),
+        &inner_graph.graph,
+        matrix,
+        weight_fn,
+        default_weight,
+        parallel_threshold,
+    )
+}
+
+/// Return a matrix of a PyDiGraph as a linear operator
+///
+/// The returned :class:`~retworkx.GraphOperator` multiplies vectors by the
+/// adjacency, Laplacian, or random walk transition matrix of the graph
+/// without building the matrix, the products are computed from the edges of
+/// the graph every time. This makes it possible to use iterative solvers on
+/// graphs too large to export as a matrix. The row of a node is given by its
+/// outgoing edges. The products are computed on multiple threads for graphs
+/// with at least ``parallel_threshold`` nodes, the env var
+/// ``RAYON_NUM_THREADS`` can be used to adjust how many threads are used.
+///
+/// :param PyDiGraph graph: The graph to get the matrix of
+/// :param str matrix: The matrix of the graph, either ``"adjacency"`` for
+///     the weighted adjacency matrix :math:`A`, ``"laplacian"`` for the
+///     Laplacian matrix :math:`L = D - A` where :math:`D` is the diagonal
+///     matrix of the weighted out degrees, or ``"transition"`` for the random
+///     walk transition matrix :math:`P = D^{-1} A`, where the row of a node
+///     with a weighted out degree of 0 is 0.
+/// :param weight_fn: An optional callable that will be passed the edge's
+///     payload object and is expected to return a ``float`` weight for that
+///     edge. It's called once per edge when the operator is created. The
+///     weights of parallel edges are added.
+/// :param float default_weight: If ``weight_fn`` isn't specified this
+///     optional float value will be used for the weight of every edge
+/// :param int parallel_threshold: The number of nodes from which the
+///     products are computed on multiple threads. Defaults to 300.
+///
+/// :returns: The matrix of ``graph`` as a linear operator
+/// :rtype: GraphOperator
+/// :raises ValueError: If ``matrix`` isn't one of the supported matrices
+#[pyfunction(
+    matrix = "\"adjacency\"",
+    default_weight = "1.0",
+    parallel_threshold = "300"
+)]
+#[pyo3(
+    text_signature = "(graph, /, matrix=\"adjacency\", weight_fn=None, default_weight=1.0, parallel_threshold=300)"
+)]
+pub fn digraph_linear_operator(
+    py: Python,
+    graph: Py<digraph::PyDiGraph>,
+    matrix: &str,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+    parallel_threshold: usize,
+) -> PyResult<GraphOperator> {
+    let inner_graph = graph.borrow(py);
+    GraphOperator::new(
+        py,
+        OperatorGraph::DiGraph(graph.clone_ref(py)),
+        &inner_graph.graph,
+        matrix,
+        weight_fn,
+        default_weight,
+        parallel_threshold,
+    )
+}
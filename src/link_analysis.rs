@@ -0,0 +1,491 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use std::convert::TryFrom;
+
+use crate::algorithm_info::{explained, AlgorithmInfo};
+use crate::iterators::CentralityMapping;
+use crate::{digraph, graph, CostFn, FailedToConverge, StablePyGraph};
+
+use hashbrown::HashMap;
+
+use petgraph::graph::NodeIndex;
+use petgraph::visit::NodeIndexable;
+use petgraph::EdgeType;
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::Python;
+
+use retworkx_core::err::CallbackError;
+use retworkx_core::link_analysis;
+
+/// Convert an optional dict of node indices to values into a vector indexed
+/// by node index, nodes missing from the dict get a value of 0.
+fn node_values<Ty: EdgeType>(
+    graph: &StablePyGraph<Ty>,
+    values: Option<HashMap<usize, f64>>,
+    name: &str,
+) -> PyResult<Option<Vec<f64>>> {
+    let values = match values {
+        Some(values) => values,
+        None => return Ok(None),
+    };
+    let mut out = vec![0.0; graph.node_bound()];
+    for (node, value) in values {
+        if !graph.contains_node(NodeIndex::new(node)) {
+            continue;
+        }
+        if value.is_nan() || value < 0.0 {
+            return Err(PyValueError::new_err(format!(
+                "{} values must be non-negative numbers",
+                name
+            )));
+        }
+        out[node] = value;
+    }
+    Ok(Some(out))
+}
+
+#[allow(clippy::too_many_arguments)]
+fn pagerank<Ty: EdgeType>(
+    py: Python,
+    graph: &StablePyGraph<Ty>,
+    alpha: f64,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+    personalization: Option<HashMap<usize, f64>>,
+    nstart: Option<HashMap<usize, f64>>,
+    dangling: Option<HashMap<usize, f64>>,
+    max_iter: usize,
+    tol: f64,
+) -> PyResult<(CentralityMapping, AlgorithmInfo)> {
+    if !(0.0..=1.0).contains(&alpha) {
+        return Err(PyValueError::new_err("alpha must be between 0 and 1"));
+    }
+    let info = AlgorithmInfo::new("pagerank", "power iteration")
+        .parameter(py, "alpha", alpha)
+        .parameter(py, "default_weight", default_weight)
+        .parameter(py, "personalization", &personalization)
+        .parameter(py, "nstart", &nstart)
+        .parameter(py, "dangling", &dangling)
+        .parameter(py, "max_iter", max_iter)
+        .parameter(py, "tol", tol);
+    let cost_fn = CostFn::try_from((weight_fn, default_weight))?;
+    let personalization = node_values(graph, personalization, "personalization")?;
+    let nstart = node_values(graph, nstart, "nstart")?;
+    let dangling = node_values(graph, dangling, "dangling")?;
+    let (ranks, convergence) = link_analysis::pagerank_with_convergence(
+        graph,
+        |e| cost_fn.call(py, e.weight()),
+        alpha,
+        personalization.as_deref(),
+        nstart.as_deref(),
+        dangling.as_deref(),
+        max_iter,
+        tol,
+    )
+    .map_err(|err| match err {
+        CallbackError::InvalidInput(err) => PyValueError::new_err(err.to_string()),
+        CallbackError::Callback(err) => err,
+    })?;
+    if !convergence.converged {
+        return Err(FailedToConverge::new_err(format!(
+            "PageRank failed to converge in {} iterations",
+            max_iter
+        )));
+    }
+    let ranks = CentralityMapping {
+        centralities: graph
+            .node_indices()
+            .map(|n| (n.index(), ranks[n.index()]))
+            .collect(),
+    };
+    Ok((ranks, info.convergence(convergence, tol)))
+}
+
+/// Compute the PageRank of the nodes in a PyDiGraph.
+///
+/// PageRank computes a ranking of the nodes in the graph based on the
+/// structure of the incoming links. It models a random walk that follows an
+/// outgoing edge, chosen with a probability proportional to the edge
+/// weights, with probability ``alpha`` and otherwise teleports to a node
+/// drawn from the ``personalization`` distribution. Dangling nodes, the nodes
+/// without outgoing edges (or whose outgoing edges all have a weight of 0),
+/// teleport to a node drawn from the ``dangling`` distribution. The ranks are
+/// computed with power iteration, the algorithm is based on [1]_.
+///
+/// :param PyDiGraph graph: The graph to compute the PageRank of
+/// :param float alpha: The damping factor, it must be between 0 and 1
+/// :param weight_fn: An optional input callable that will be passed the edge's
+///     payload object and is expected to return a non-negative ``float``
+///     weight for that edge. If this is not specified ``default_weight`` will
+///     be used as the weight for every edge in ``graph``. The weights of
+///     parallel edges are added.
+/// :param float default_weight: If ``weight_fn`` is not set the default weight
+///     value to use for the weight of all edges
+/// :param dict personalization: An optional dictionary of node indices to the
+///     (unnormalized) probability of teleporting to that node. Nodes not in the
+///     dictionary have a probability of 0. If not specified a uniform
+///     distribution is used.
+/// :param dict nstart: An optional dictionary of node indices to the starting
+///     value of the iteration for that node. If not specified a uniform
+///     distribution is used.
+/// :param dict dangling: An optional dictionary of node indices to the
+///     (unnormalized) probability of jumping to that node from a dangling
+///     node. If not specified the ``personalization`` distribution is used.
+/// :param int max_iter: The maximum number of iterations of the power method
+/// :param float tol: The error tolerance used when checking for convergence,
+///     the iteration stops once the change of the ranks sums to less than the
+///     number of nodes times ``tol``.
+/// :param bool explain: If set to ``True`` an
+///     :class:`~retworkx.AlgorithmInfo` recording how the algorithm was run
+///     is returned along with the result
+///
+/// :returns: a read-only dict-like object whose keys are the node indices and
+///     values are the PageRank of each node.
+///     If ``explain`` is ``True`` a tuple of this and an
+///     :class:`~retworkx.AlgorithmInfo` is returned instead.
+/// :rtype: CentralityMapping
+/// :raises FailedToConverge: If the power iteration doesn't converge within
+///     ``max_iter`` iterations
+///
+/// .. [1] A. Langville and C. Meyer, "A survey of eigenvector methods of web
+///     information retrieval." http://citeseer.ist.psu.edu/713792.html
+#[pyfunction(
+    alpha = "0.85",
+    default_weight = "1.0",
+    max_iter = "100",
+    tol = "1e-6",
+    explain = "false"
+)]
+#[pyo3(
+    text_signature = "(graph, /, alpha=0.85, weight_fn=None, default_weight=1.0, personalization=None, nstart=None, dangling=None, max_iter=100, tol=1e-6, explain=False)"
+)]
+#[allow(clippy::too_many_arguments)]
+pub fn digraph_pagerank(
+    py: Python,
+    graph: &digraph::PyDiGraph,
+    alpha: f64,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+    personalization: Option<HashMap<usize, f64>>,
+    nstart: Option<HashMap<usize, f64>>,
+    dangling: Option<HashMap<usize, f64>>,
+    max_iter: usize,
+    tol: f64,
+    explain: bool,
+) -> PyResult<PyObject> {
+    let (ranks, info) = pagerank(
+        py,
+        &graph.graph,
+        alpha,
+        weight_fn,
+        default_weight,
+        personalization,
+        nstart,
+        dangling,
+        max_iter,
+        tol,
+    )?;
+    explained(py, ranks, info, explain)
+}
+
+/// Compute the PageRank of the nodes in a PyGraph.
+///
+/// PageRank computes a ranking of the nodes in the graph based on the
+/// structure of the incoming links. It models a random walk that follows an
+/// edge, chosen with a probability proportional to the edge weights, with
+/// probability ``alpha`` and otherwise teleports to a node drawn from the
+/// ``personalization`` distribution. Every edge of the undirected graph can be
+/// followed in both directions. Dangling nodes, the nodes without edges (or
+/// whose edges all have a weight of 0), teleport to a node drawn from the
+/// ``dangling`` distribution. The ranks are computed with power iteration,
+/// the algorithm is based on [1]_.
+///
+/// :param PyGraph graph: The graph to compute the PageRank of
+/// :param float alpha: The damping factor, it must be between 0 and 1
+/// :param weight_fn: An optional input callable that will be passed the edge's
+///     payload object and is expected to return a non-negative ``float``
+///     weight for that edge. If this is not specified ``default_weight`` will
+///     be used as the weight for every edge in ``graph``. The weights of
+///     parallel edges are added.
+/// :param float default_weight: If ``weight_fn`` is not set the default weight
+///     value to use for the weight of all edges
+/// :param dict personalization: An optional dictionary of node indices to the
+///     (unnormalized) probability of teleporting to that node. Nodes not in the
+///     dictionary have a probability of 0. If not specified a uniform
+///     distribution is used.
+/// :param dict nstart: An optional dictionary of node indices to the starting
+///     value of the iteration for that node. If not specified a uniform
+///     distribution is used.
+/// :param dict dangling: An optional dictionary of node indices to the
+///     (unnormalized) probability of jumping to that node from a dangling
+///     node. If not specified the ``personalization`` distribution is used.
+/// :param int max_iter: The maximum number of iterations of the power method
+/// :param float tol: The error tolerance used when checking for convergence,
+///     the iteration stops once the change of the ranks sums to less than the
+///     number of nodes times ``tol``.
+/// :param bool explain: If set to ``True`` an
+///     :class:`~retworkx.AlgorithmInfo` recording how the algorithm was run
+///     is returned along with the result
+///
+/// :returns: a read-only dict-like object whose keys are the node indices and
+///     values are the PageRank of each node.
+///     If ``explain`` is ``True`` a tuple of this and an
+///     :class:`~retworkx.AlgorithmInfo` is returned instead.
+/// :rtype: CentralityMapping
+/// :raises FailedToConverge: If the power iteration doesn't converge within
+///     ``max_iter`` iterations
+///
+/// .. [1] A. Langville and C. Meyer, "A survey of eigenvector methods of web
+///     information retrieval." http://citeseer.ist.psu.edu/713792.html
+#[pyfunction(
+    alpha = "0.85",
+    default_weight = "1.0",
+    max_iter = "100",
+    tol = "1e-6",
+    explain = "false"
+)]
+#[pyo3(
+    text_signature = "(graph, /, alpha=0.85, weight_fn=None, default_weight=1.0, personalization=None, nstart=None, dangling=None, max_iter=100, tol=1e-6, explain=False)"
+)]
+#[allow(clippy::too_many_arguments)]
+pub fn graph_pagerank(
+    py: Python,
+    graph: &graph::PyGraph,
+    alpha: f64,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+    personalization: Option<HashMap<usize, f64>>,
+    nstart: Option<HashMap<usize, f64>>,
+    dangling: Option<HashMap<usize, f64>>,
+    max_iter: usize,
+    tol: f64,
+    explain: bool,
+) -> PyResult<PyObject> {
+    let (ranks, info) = pagerank(
+        py,
+        &graph.graph,
+        alpha,
+        weight_fn,
+        default_weight,
+        personalization,
+        nstart,
+        dangling,
+        max_iter,
+        tol,
+    )?;
+    explained(py, ranks, info, explain)
+}
+
+#[allow(clippy::too_many_arguments)]
+fn hits<Ty: EdgeType>(
+    py: Python,
+    graph: &StablePyGraph<Ty>,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+    nstart: Option<HashMap<usize, f64>>,
+    max_iter: usize,
+    tol: f64,
+    normalized: bool,
+) -> PyResult<((CentralityMapping, CentralityMapping), AlgorithmInfo)> {
+    let info = AlgorithmInfo::new("hits", "power iteration")
+        .parameter(py, "default_weight", default_weight)
+        .parameter(py, "nstart", &nstart)
+        .parameter(py, "max_iter", max_iter)
+        .parameter(py, "tol", tol)
+        .parameter(py, "normalized", normalized);
+    let cost_fn = CostFn::try_from((weight_fn, default_weight))?;
+    let nstart = node_values(graph, nstart, "nstart")?;
+    let (hubs, authorities, convergence) = link_analysis::hits_with_convergence(
+        graph,
+        |e| cost_fn.call(py, e.weight()),
+        nstart.as_deref(),
+        max_iter,
+        tol,
+        normalized,
+    )
+    .map_err(|err| match err {
+        CallbackError::InvalidInput(err) => PyValueError::new_err(err.to_string()),
+        CallbackError::Callback(err) => err,
+    })?;
+    if !convergence.converged {
+        return Err(FailedToConverge::new_err(format!(
+            "HITS failed to converge in {} iterations",
+            max_iter
+        )));
+    }
+    let mapping = |scores: Vec<f64>| CentralityMapping {
+        centralities: graph
+            .node_indices()
+            .map(|n| (n.index(), scores[n.index()]))
+            .collect(),
+    };
+    Ok((
+        (mapping(hubs), mapping(authorities)),
+        info.convergence(convergence, tol),
+    ))
+}
+
+/// Compute the HITS hubs and authorities scores of the nodes in a PyDiGraph.
+///
+/// The HITS algorithm computes two scores for every node: the authority
+/// score estimates the value of the content of a node based on its incoming
+/// links and the hub score estimates the value of its outgoing links. A good
+/// hub links to many good authorities and a good authority is linked from
+/// many good hubs. The scores are computed with power iteration, the
+/// algorithm is based on [1]_.
+///
+/// :param PyDiGraph graph: The graph to compute the scores of
+/// :param weight_fn: An optional input callable that will be passed the edge's
+///     payload object and is expected to return a non-negative ``float``
+///     weight for that edge. If this is not specified ``default_weight`` will
+///     be used as the weight for every edge in ``graph``. The weights of
+///     parallel edges are added.
+/// :param float default_weight: If ``weight_fn`` is not set the default weight
+///     value to use for the weight of all edges
+/// :param dict nstart: An optional dictionary of node indices to the starting
+///     hub score of that node. If not specified a uniform distribution is
+///     used.
+/// :param int max_iter: The maximum number of iterations of the power method
+/// :param float tol: The error tolerance used when checking for convergence,
+///     the iteration stops once the change of the hub scores sums to less than
+///     ``tol``.
+/// :param bool normalized: If ``True`` the scores are normalized to sum to 1,
+///     otherwise they're normalized so that the largest score is 1.
+/// :param bool explain: If set to ``True`` an
+///     :class:`~retworkx.AlgorithmInfo` recording how the algorithm was run
+///     is returned along with the result
+///
+/// :returns: A tuple of two read-only dict-like objects whose keys are the
+///     node indices and values are the hub and the authority scores of each
+///     node respectively.
+///     If ``explain`` is ``True`` a tuple of this and an
+///     :class:`~retworkx.AlgorithmInfo` is returned instead.
+/// :rtype: tuple
+/// :raises FailedToConverge: If the power iteration doesn't converge within
+///     ``max_iter`` iterations
+///
+/// .. [1] Jon Kleinberg, "Authoritative sources in a hyperlinked environment."
+///     Journal of the ACM 46 (5): 604-32, 1999. doi:10.1145/324133.324140.
+#[pyfunction(
+    default_weight = "1.0",
+    max_iter = "100",
+    tol = "1e-8",
+    normalized = "true",
+    explain = "false"
+)]
+#[pyo3(
+    text_signature = "(graph, /, weight_fn=None, default_weight=1.0, nstart=None, max_iter=100, tol=1e-8, normalized=True, explain=False)"
+)]
+#[allow(clippy::too_many_arguments)]
+pub fn digraph_hits(
+    py: Python,
+    graph: &digraph::PyDiGraph,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+    nstart: Option<HashMap<usize, f64>>,
+    max_iter: usize,
+    tol: f64,
+    normalized: bool,
+    explain: bool,
+) -> PyResult<PyObject> {
+    let (scores, info) = hits(
+        py,
+        &graph.graph,
+        weight_fn,
+        default_weight,
+        nstart,
+        max_iter,
+        tol,
+        normalized,
+    )?;
+    explained(py, scores, info, explain)
+}
+
+/// Compute the HITS hubs and authorities scores of the nodes in a PyGraph.
+///
+/// The HITS algorithm computes two scores for every node: the authority
+/// score estimates the value of the content of a node based on its incoming
+/// links and the hub score estimates the value of its outgoing links. As
+/// every edge of an undirected graph is both an incoming and an outgoing link
+/// the hub and authority scores are the same, unless the power iteration
+/// stops on a different eigenvector for a bipartite graph. The scores are
+/// computed with power iteration, the algorithm is based on [1]_.
+///
+/// :param PyGraph graph: The graph to compute the scores of
+/// :param weight_fn: An optional input callable that will be passed the edge's
+///     payload object and is expected to return a non-negative ``float``
+///     weight for that edge. If this is not specified ``default_weight`` will
+///     be used as the weight for every edge in ``graph``. The weights of
+///     parallel edges are added.
+/// :param float default_weight: If ``weight_fn`` is not set the default weight
+///     value to use for the weight of all edges
+/// :param dict nstart: An optional dictionary of node indices to the starting
+///     hub score of that node. If not specified a uniform distribution is
+///     used.
+/// :param int max_iter: The maximum number of iterations of the power method
+/// :param float tol: The error tolerance used when checking for convergence,
+///     the iteration stops once the change of the hub scores sums to less than
+///     ``tol``.
+/// :param bool normalized: If ``True`` the scores are normalized to sum to 1,
+///     otherwise they're normalized so that the largest score is 1.
+/// :param bool explain: If set to ``True`` an
+///     :class:`~retworkx.AlgorithmInfo` recording how the algorithm was run
+///     is returned along with the result
+///
+/// :returns: A tuple of two read-only dict-like objects whose keys are the
+///     node indices and values are the hub and the authority scores of each
+///     node respectively.
+///     If ``explain`` is ``True`` a tuple of this and an
+///     :class:`~retworkx.AlgorithmInfo` is returned instead.
+/// :rtype: tuple
+/// :raises FailedToConverge: If the power iteration doesn't converge within
+///     ``max_iter`` iterations
+///
+/// .. [1] Jon Kleinberg, "Authoritative sources in a hyperlinked environment."
+///     Journal of the ACM 46 (5): 604-32, 1999. doi:10.1145/324133.324140.
+#[pyfunction(
+    default_weight = "1.0",
+    max_iter = "100",
+    tol = "1e-8",
+    normalized = "true",
+    explain = "false"
+)]
+#[pyo3(
+    text_signature = "(graph, /, weight_fn=None, default_weight=1.0, nstart=None, max_iter=100, tol=1e-8, normalized=True, explain=False)"
+)]
+#[allow(clippy::too_many_arguments)]
+pub fn graph_hits(
+    py: Python,
+    graph: &graph::PyGraph,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+    nstart: Option<HashMap<usize, f64>>,
+    max_iter: usize,
+    tol: f64,
+    normalized: bool,
+    explain: bool,
+) -> PyResult<PyObject> {
+    let (scores, info) = hits(
+        py,
+        &graph.graph,
+        weight_fn,
+        default_weight,
+        nstart,
+        max_iter,
+        tol,
+        normalized,
+    )?;
+    explained(py, scores, info, explain)
+}
@@ -0,0 +1,188 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use crate::{digraph, graph};
+
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+use retworkx_core::motifs;
+
+/// Count the connected induced subgraphs with 3 and 4 nodes of a graph
+/// by graphlet.
+///
+/// The graphlets are the connected graphs with 3 and 4 nodes:
+///
+/// * ``"3-path"`` - a path through 3 nodes
+/// * ``"triangle"`` - a cycle through 3 nodes
+/// * ``"4-path"`` - a path through 4 nodes
+/// * ``"3-star"`` - a node with 3 leaves
+/// * ``"4-cycle"`` - a cycle through 4 nodes
+/// * ``"tailed-triangle"`` - a triangle with a leaf on one of its nodes
+/// * ``"diamond"`` - a cycle through 4 nodes with one chord
+/// * ``"4-clique"`` - 4 nodes all connected to each other
+///
+/// The subgraphs are enumerated with the ESU algorithm [Wernicke2006]_, so
+/// the run time grows with the number of connected subgraphs, which can be
+/// large for graphs with nodes of high degree. This function is
+/// multithreaded and will launch a thread pool with threads equal to the
+/// number of CPUs by default if the number of nodes in the graph is above
+/// the value of ``parallel_threshold`` (it defaults to 300). You can tune
+/// the number of threads with the ``RAYON_NUM_THREADS`` environment
+/// variable. For example, setting ``RAYON_NUM_THREADS=4`` would limit the
+/// thread pool to 4 threads.
+///
+/// Self loops and parallel edges are ignored.
+///
+/// :param PyGraph graph: The graph to count the graphlets of
+/// :param int parallel_threshold: The number of nodes to count the
+///     graphlets in parallel at, if the graph has fewer nodes they are
+///     counted in a single thread. It defaults to 300.
+///
+/// :returns: A dictionary of the name of every graphlet to the number of
+///     induced subgraphs of the graph which are that graphlet
+/// :rtype: dict
+///
+/// .. jupyter-execute::
+///
+///     import retworkx
+///
+///     graph = retworkx.generators.grid_graph(3, 3)
+///     print(retworkx.graphlet_census(graph))
+///
+/// .. [Wernicke2006] S. Wernicke, "Efficient Detection of Network Motifs",
+///     IEEE/ACM Transactions on Computational Biology and Bioinformatics
+///     3(4), 2006, pp. 347-359, https://doi.org/10.1109/TCBB.2006.51
+#[pyfunction(parallel_threshold = "300")]
+#[pyo3(text_signature = "(graph, /, parallel_threshold=300)")]
+pub fn graphlet_census(
+    py: Python,
+    graph: &graph::PyGraph,
+    parallel_threshold: usize,
+) -> PyResult<PyObject> {
+    let census = motifs::graphlet_census(&graph.graph, parallel_threshold);
+    let out_dict = PyDict::new(py);
+    for graphlet in motifs::Graphlet::ALL.iter() {
+        out_dict.set_item(graphlet.name(), census[graphlet])?;
+    }
+    Ok(out_dict.into())
+}
+
+/// Count the orbits of the graphlets with 2 to 4 nodes every node of a
+/// graph is in.
+///
+/// The orbits of a graphlet are its sets of nodes which are symmetric to
+/// each other, and the number of times a node is in every orbit is its
+/// graphlet degree vector [Przulj2007]_. The 15 orbits of the graphlets with
+/// 2 to 4 nodes are numbered:
+///
+/// * 0 - an edge, so this is the degree of the node
+/// * 1 and 2 - the end and middle of a ``"3-path"``
+/// * 3 - a ``"triangle"``
+/// * 4 and 5 - an end and an inner node of a ``"4-path"``
+/// * 6 and 7 - a leaf and the center of a ``"3-star"``
+/// * 8 - a ``"4-cycle"``
+/// * 9, 10 and 11 - the leaf, a triangle node with 2 neighbors, and the
+///   node with 3 neighbors of a ``"tailed-triangle"``
+/// * 12 and 13 - a node with 2 and with 3 neighbors of a ``"diamond"``
+/// * 14 - a ``"4-clique"``
+///
+/// The graphlets are as in :func:`~retworkx.graphlet_census`, which
+/// describes the run time. This function is multithreaded and will launch a
+/// thread pool with threads equal to the number of CPUs by default if the
+/// number of nodes in the graph is above the value of
+/// ``parallel_threshold`` (it defaults to 300). You can tune the number of
+/// threads with the ``RAYON_NUM_THREADS`` environment variable. For
+/// example, setting ``RAYON_NUM_THREADS=4`` would limit the thread pool to
+/// 4 threads.
+///
+/// Self loops and parallel edges are ignored.
+///
+/// :param PyGraph graph: The graph to count the orbits of
+/// :param int parallel_threshold: The number of nodes to count the orbits
+///     in parallel at, if the graph has fewer nodes they are counted in a
+///     single thread. It defaults to 300.
+///
+/// :returns: A dictionary keyed by node index to a list of the number of
+///     times the node is in each of the 15 orbits
+/// :rtype: dict
+///
+/// .. jupyter-execute::
+///
+///     import retworkx
+///
+///     graph = retworkx.generators.star_graph(4)
+///     print(retworkx.graphlet_orbits(graph))
+///
+/// .. [Przulj2007] N. Pržulj, "Biological network comparison using graphlet
+///     degree distribution", Bioinformatics 23(2), 2007, pp. e177-e183,
+///     https://doi.org/10.1093/bioinformatics/btl301
+#[pyfunction(parallel_threshold = "300")]
+#[pyo3(text_signature = "(graph, /, parallel_threshold=300)")]
+pub fn graphlet_orbits(
+    py: Python,
+    graph: &graph::PyGraph,
+    parallel_threshold: usize,
+) -> PyResult<PyObject> {
+    let orbits = motifs::graphlet_orbits(&graph.graph, parallel_threshold);
+    let out_dict = PyDict::new(py);
+    for (node, counts) in orbits.into_iter().enumerate() {
+        if let Some(counts) = counts {
+            out_dict.set_item(node, counts.to_vec())?;
+        }
+    }
+    Ok(out_dict.into())
+}
+
+/// Count the subgraphs induced by the triples of nodes of a directed graph
+/// by type.
+///
+/// The 16 types of triads are named by their number of mutual, asymmetric
+/// and null pairs of nodes, and, for the ones which have several types with
+/// the same numbers, whether the edges point down (``D``), up (``U``), are
+/// cyclic (``C``), or transitive (``T``): ``"003"``, ``"012"``, ``"102"``,
+/// ``"021D"``, ``"021U"``, ``"021C"``, ``"111D"``, ``"111U"``, ``"030T"``,
+/// ``"030C"``, ``"201"``, ``"120D"``, ``"120U"``, ``"120C"``, ``"210"``
+/// and ``"300"``.
+///
+/// The census is taken with the algorithm of [BatageljMrvar2001]_ in
+/// :math:`O(m \Delta)` time for a graph with :math:`m` edges whose nodes
+/// have at most :math:`\Delta` neighbors. Self loops and parallel edges
+/// are ignored.
+///
+/// :param PyDiGraph graph: The graph to take the triad census of
+///
+/// :returns: A dictionary of the name of every triad type to the number of
+///     triples of nodes of the graph which induce that type
+/// :rtype: dict
+///
+/// .. jupyter-execute::
+///
+///     import retworkx
+///
+///     graph = retworkx.generators.directed_cycle_graph(4)
+///     print(retworkx.triad_census(graph))
+///
+/// .. [BatageljMrvar2001] V. Batagelj and A. Mrvar, "A subquadratic triad
+///     census algorithm for large sparse networks with small maximum
+///     degree", Social Networks 23(3), 2001, pp. 237-243,
+///     https://doi.org/10.1016/S0378-8733(01)00035-1
+#[pyfunction]
+#[pyo3(text_signature = "(graph, /)")]
+pub fn triad_census(py: Python, graph: &digraph::PyDiGraph) -> PyResult<PyObject> {
+    let census = motifs::triad_census(&graph.graph);
+    let out_dict = PyDict::new(py);
+    for triad in motifs::Triad::ALL.iter() {
+        out_dict.set_item(triad.name(), census[triad])?;
+    }
+    Ok(out_dict.into())
+}
@@ -0,0 +1,175 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use crate::iterators::NodeIndices;
+use crate::{digraph, graph, InvalidNode, StablePyGraph};
+
+use petgraph::graph::NodeIndex;
+use petgraph::Direction::Outgoing;
+use petgraph::EdgeType;
+
+use pyo3::prelude::*;
+use rayon::prelude::*;
+
+use retworkx_core::neighbors::{self, SortedNeighbors};
+
+fn check_node<Ty: EdgeType>(graph: &StablePyGraph<Ty>, node: usize) -> PyResult<NodeIndex> {
+    let index = NodeIndex::new(node);
+    if !graph.contains_node(index) {
+        return Err(InvalidNode::new_err(format!(
+            "Node index {} is not in the graph",
+            node
+        )));
+    }
+    Ok(index)
+}
+
+fn common_neighbors<Ty: EdgeType>(
+    graph: &StablePyGraph<Ty>,
+    node_a: usize,
+    node_b: usize,
+) -> PyResult<NodeIndices> {
+    let a = check_node(graph, node_a)?;
+    let b = check_node(graph, node_b)?;
+    Ok(NodeIndices {
+        nodes: neighbors::common_neighbors(graph, a, b)
+            .into_iter()
+            .map(|node| node.index())
+            .collect(),
+    })
+}
+
+fn jaccard_similarity<Ty: EdgeType>(
+    graph: &StablePyGraph<Ty>,
+    node_pairs: Vec<(usize, usize)>,
+    parallel_threshold: usize,
+) -> PyResult<Vec<f64>> {
+    for (a, b) in &node_pairs {
+        check_node(graph, *a)?;
+        check_node(graph, *b)?;
+    }
+    let neighbors = SortedNeighbors::new(graph, Outgoing);
+    let similarity = |(a, b): &(usize, usize)| neighbors.jaccard_similarity(*a, *b);
+    if node_pairs.len() < parallel_threshold {
+        Ok(node_pairs.iter().map(similarity).collect())
+    } else {
+        Ok(node_pairs.par_iter().map(similarity).collect())
+    }
+}
+
+/// Return the common neighbors of two nodes of a PyGraph
+///
+/// :param PyGraph graph: The graph to find the common neighbors in
+/// :param int node_a: The index of the first node
+/// :param int node_b: The index of the second node
+///
+/// :returns: The indices of the nodes which are neighbors of both
+///     ``node_a`` and ``node_b``, other than ``node_a`` and ``node_b``, in
+///     increasing order
+/// :rtype: NodeIndices
+/// :raises InvalidNode: If ``node_a`` or ``node_b`` is not in the graph
+#[pyfunction]
+#[pyo3(text_signature = "(graph, node_a, node_b, /)")]
+pub fn graph_common_neighbors(
+    graph: &graph::PyGraph,
+    node_a: usize,
+    node_b: usize,
+) -> PyResult<NodeIndices> {
+    common_neighbors(&graph.graph, node_a, node_b)
+}
+
+/// Return the common successors of two nodes of a PyDiGraph
+///
+/// :param PyDiGraph graph: The graph to find the common successors in
+/// :param int node_a: The index of the first node
+/// :param int node_b: The index of the second node
+///
+/// :returns: The indices of the nodes which are successors of both
+///     ``node_a`` and ``node_b``, other than ``node_a`` and ``node_b``, in
+///     increasing order
+/// :rtype: NodeIndices
+/// :raises InvalidNode: If ``node_a`` or ``node_b`` is not in the graph
+#[pyfunction]
+#[pyo3(text_signature = "(graph, node_a, node_b, /)")]
+pub fn digraph_common_neighbors(
+    graph: &digraph::PyDiGraph,
+    node_a: usize,
+    node_b: usize,
+) -> PyResult<NodeIndices> {
+    common_neighbors(&graph.graph, node_a, node_b)
+}
+
+/// Compute the Jaccard similarity of the neighbors of pairs of nodes of a
+/// PyGraph
+///
+/// The Jaccard similarity of two nodes is the number of their common
+/// neighbors divided by the number of nodes which are a neighbor of either,
+/// or 0 if neither has a neighbor. Self loops are ignored. The neighbors of
+/// every node are sorted once, and the similarities are computed by merging
+/// the sorted neighbors of the nodes of each pair.
+///
+/// The similarities are computed on multiple threads if there are at least
+/// ``parallel_threshold`` pairs, the env var ``RAYON_NUM_THREADS`` can be
+/// used to adjust how many threads are used.
+///
+/// :param PyGraph graph: The graph to compute the similarities in
+/// :param list node_pairs: The pairs of node indices to compute the
+///     similarity of, as a list of tuples
+/// :param int parallel_threshold: The number of pairs to run in parallel
+///     from. Defaults to 300.
+///
+/// :returns: The Jaccard similarity of every pair, in the order of
+///     ``node_pairs``
+/// :rtype: list
+/// :raises InvalidNode: If a node of a pair is not in the graph
+#[pyfunction(parallel_threshold = "300")]
+#[pyo3(text_signature = "(graph, node_pairs, /, parallel_threshold=300)")]
+pub fn graph_jaccard_similarity(
+    graph: &graph::PyGraph,
+    node_pairs: Vec<(usize, usize)>,
+    parallel_threshold: usize,
+) -> PyResult<Vec<f64>> {
+    jaccard_similarity(&graph.graph, node_pairs, parallel_threshold)
+}
+
+/// Compute the Jaccard similarity of the successors of pairs of nodes of a
+/// PyDiGraph
+///
+/// The Jaccard similarity of two nodes is the number of their common
+/// successors divided by the number of nodes which are a successor of
+/// either, or 0 if neither has a successor. Self loops are ignored. The
+/// successors of every node are sorted once, and the similarities are
+/// computed by merging the sorted successors of the nodes of each pair.
+///
+/// The similarities are computed on multiple threads if there are at least
+/// ``parallel_threshold`` pairs, the env var ``RAYON_NUM_THREADS`` can be
+/// used to adjust how many threads are used.
+///
+/// :param PyDiGraph graph: The graph to compute the similarities in
+/// :param list node_pairs: The pairs of node indices to compute the
+///     similarity of, as a list of tuples
+/// :param int parallel_threshold: The number of pairs to run in parallel
+///     from. Defaults to 300.
+///
+/// :returns: The Jaccard similarity of every pair, in the order of
+///     ``node_pairs``
+/// :rtype: list
+/// :raises InvalidNode: If a node of a pair is not in the graph
+#[pyfunction(parallel_threshold = "300")]
+#[pyo3(text_signature = "(graph, node_pairs, /, parallel_threshold=300)")]
+pub fn digraph_jaccard_similarity(
+    graph: &digraph::PyDiGraph,
+    node_pairs: Vec<(usize, usize)>,
+    parallel_threshold: usize,
+) -> PyResult<Vec<f64>> {
+    jaccard_similarity(&graph.graph, node_pairs, parallel_threshold)
+}
@@ -0,0 +1,159 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use hashbrown::HashMap;
+
+use pyo3::exceptions::{PyKeyError, PyValueError};
+use pyo3::gc::PyVisit;
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use pyo3::PyTraverseError;
+use pyo3::Python;
+
+use petgraph::graph::NodeIndex;
+
+/// The keys of the nodes of a graph, a bidirectional mapping between
+/// user-provided hashable Python objects and node indices.
+///
+/// A node has at most one key and a key belongs to at most one node. The
+/// graph classes call [`NodeKeys::remove`] every time they remove a node, so
+/// a key never points at a node index reused by a later node.
+#[derive(Default)]
+pub struct NodeKeys {
+    /// key -> node index, created with the first key
+    indices: Option<Py<PyDict>>,
+    /// node index -> key
+    keys: HashMap<NodeIndex, PyObject>,
+}
+
+impl Clone for NodeKeys {
+    fn clone(&self) -> Self {
+        Python::with_gil(|py| NodeKeys {
+            indices: self
+                .indices
+                .as_ref()
+                .map(|indices| indices.as_ref(py).copy().unwrap().into()),
+            keys: self
+                .keys
+                .iter()
+                .map(|(node, key)| (*node, key.clone_ref(py)))
+                .collect(),
+        })
+    }
+}
+
+impl NodeKeys {
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    /// Return the key of a node.
+    pub fn get(&self, node: NodeIndex) -> Option<&PyObject> {
+        self.keys.get(&node)
+    }
+
+    /// Return the node with a key, raises a ``TypeError`` if the key isn't
+    /// hashable.
+    pub fn find(&self, py: Python, key: &PyAny) -> PyResult<Option<NodeIndex>> {
+        key.hash()?;
+        match &self.indices {
+            Some(indices) => match indices.as_ref(py).get_item(key) {
+                Some(index) => Ok(Some(NodeIndex::new(index.extract()?))),
+                None => Ok(None),
+            },
+            None => Ok(None),
+        }
+    }
+
+    /// Return the nodes with the keys, raises a ``KeyError`` for a key
+    /// without a node.
+    pub fn find_all(&self, py: Python, keys: &PyAny) -> PyResult<Vec<usize>> {
+        let mut out = Vec::new();
+        for key in keys.iter()? {
+            let key = key?;
+            match self.find(py, key)? {
+                Some(node) => out.push(node.index()),
+                None => return Err(PyKeyError::new_err(key.to_object(py))),
+            }
+        }
+        Ok(out)
+    }
+
+    /// Set the key of a node, replacing its previous key. Raises a
+    /// ``ValueError`` if the key belongs to another node.
+    pub fn insert(&mut self, py: Python, node: NodeIndex, key: PyObject) -> PyResult<()> {
+        if let Some(other) = self.find(py, key.as_ref(py))? {
+            if other != node {
+                return Err(PyValueError::new_err(format!(
+                    "The key is already used by node {}",
+                    other.index()
+                )));
+            }
+        }
+        self.remove(node);
+        let indices = self
+            .indices
+            .get_or_insert_with(|| PyDict::new(py).into())
+            .as_ref(py);
+        indices.set_item(key.as_ref(py), node.index())?;
+        self.keys.insert(node, key);
+        Ok(())
+    }
+
+    /// Remove the key of a node, returning it.
+    pub fn remove(&mut self, node: NodeIndex) -> Option<PyObject> {
+        let key = self.keys.remove(&node)?;
+        if let Some(indices) = &self.indices {
+            // The key was hashable when it was inserted
+            Python::with_gil(|py| indices.as_ref(py).del_item(key.as_ref(py)).unwrap());
+        }
+        Some(key)
+    }
+
+    /// Move every key to the node ``node_map`` maps its node to, for graphs
+    /// compacted to remove the holes left by removed nodes.
+    pub fn reindex<F: Fn(NodeIndex) -> NodeIndex>(
+        &mut self,
+        py: Python,
+        node_map: F,
+    ) -> PyResult<()> {
+        let keys = std::mem::take(&mut self.keys);
+        let indices = PyDict::new(py);
+        for (node, key) in keys {
+            let node = node_map(node);
+            indices.set_item(key.as_ref(py), node.index())?;
+            self.keys.insert(node, key);
+        }
+        if self.indices.is_some() {
+            self.indices = Some(indices.into());
+        }
+        Ok(())
+    }
+
+    /// Return a dict mapping every key to its node.
+    pub fn to_dict(&self, py: Python) -> PyResult<PyObject> {
+        match &self.indices {
+            Some(indices) => Ok(indices.as_ref(py).copy()?.into()),
+            None => Ok(PyDict::new(py).into()),
+        }
+    }
+
+    pub fn traverse(&self, visit: &PyVisit) -> Result<(), PyTraverseError> {
+        if let Some(indices) = &self.indices {
+            visit.call(indices)?;
+        }
+        for key in self.keys.values() {
+            visit.call(key)?;
+        }
+        Ok(())
+    }
+}
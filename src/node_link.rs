@@ -0,0 +1,810 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+//! Reading and writing the node-link JSON format of d3 and networkx.
+
+use std::fs::{self, File};
+use std::io::prelude::*;
+use std::io::BufWriter;
+use std::path::PathBuf;
+use std::str;
+
+use hashbrown::HashMap;
+
+use petgraph::prelude::*;
+use petgraph::visit::IntoEdgeReferences;
+use petgraph::EdgeType;
+
+use pyo3::exceptions::{PyTypeError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::types::{PyBool, PyDict, PyFloat, PyList, PyLong, PyString, PyTuple};
+use pyo3::Python;
+
+use crate::attributes::AttributeColumns;
+use crate::node_keys::NodeKeys;
+use crate::{digraph, graph, StablePyGraph};
+
+/// The version of the node-link format written, newer versions are rejected
+/// when reading.
+const SCHEMA_VERSION: u64 = 1;
+
+fn write_string<W: Write>(out: &mut W, value: &str) -> PyResult<()> {
+    out.write_all(b"\"")?;
+    let mut start = 0;
+    for (pos, c) in value.char_indices() {
+        let escape = match c {
+            '"' => "\\\"".to_string(),
+            '\\' => "\\\\".to_string(),
+            '\n' => "\\n".to_string(),
+            '\r' => "\\r".to_string(),
+            '\t' => "\\t".to_string(),
+            '\u{8}' => "\\b".to_string(),
+            '\u{c}' => "\\f".to_string(),
+            c if (c as u32) < 0x20 => format!("\\u{:04x}", c as u32),
+            _ => continue,
+        };
+        out.write_all(&value.as_bytes()[start..pos])?;
+        out.write_all(escape.as_bytes())?;
+        start = pos + c.len_utf8();
+    }
+    out.write_all(&value.as_bytes()[start..])?;
+    out.write_all(b"\"")?;
+    Ok(())
+}
+
+/// Write a Python object as JSON, raising a ``TypeError`` for objects
+/// without a JSON representation like :func:`json.dumps`.
+fn write_value<W: Write>(out: &mut W, obj: &PyAny) -> PyResult<()> {
+    if obj.is_none() {
+        out.write_all(b"null")?;
+    } else if let Ok(value) = obj.downcast::<PyBool>() {
+        out.write_all(if value.is_true() { b"true" } else { b"false" })?;
+    } else if obj.is_instance_of::<PyLong>()? {
+        out.write_all(obj.str()?.to_str()?.as_bytes())?;
+    } else if let Ok(value) = obj.downcast::<PyFloat>() {
+        let value = value.value();
+        if !value.is_finite() {
+            return Err(PyValueError::new_err(format!(
+                "Out of range float value {} is not JSON compliant",
+                value
+            )));
+        }
+        write!(out, "{:?}", value)?;
+    } else if let Ok(value) = obj.downcast::<PyString>() {
+        write_string(out, value.to_str()?)?;
+    } else if let Ok(dict) = obj.downcast::<PyDict>() {
+        write_object(out, dict, &[])?;
+    } else if obj.is_instance_of::<PyList>()? || obj.is_instance_of::<PyTuple>()? {
+        out.write_all(b"[")?;
+        for (i, item) in obj.iter()?.enumerate() {
+            if i > 0 {
+                out.write_all(b", ")?;
+            }
+            write_value(out, item?)?;
+        }
+        out.write_all(b"]")?;
+    } else {
+        return Err(PyTypeError::new_err(format!(
+            "Object of type {} is not JSON serializable",
+            obj.get_type().name()?
+        )));
+    }
+    Ok(())
+}
+
+/// Write a dict as a JSON object after the already written ``fields``.
+fn write_object<W: Write>(out: &mut W, dict: &PyDict, fields: &[(&str, String)]) -> PyResult<()> {
+    out.write_all(b"{")?;
+    for (i, (key, value)) in fields.iter().enumerate() {
+        if i > 0 {
+            out.write_all(b", ")?;
+        }
+        write_string(out, key)?;
+        write!(out, ": {}", value)?;
+    }
+    for (i, (key, value)) in dict.iter().enumerate() {
+        let key = match key.downcast::<PyString>() {
+            Ok(key) => key.to_str()?,
+            Err(_) => {
+                return Err(PyTypeError::new_err(format!(
+                    "Keys must be str, not {}",
+                    key.get_type().name()?
+                )))
+            }
+        };
+        if fields.iter().any(|(field, _)| *field == key) {
+            return Err(PyValueError::new_err(format!(
+                "The attribute name \"{}\" is reserved",
+                key
+            )));
+        }
+        if i > 0 || !fields.is_empty() {
+            out.write_all(b", ")?;
+        }
+        write_string(out, key)?;
+        out.write_all(b": ")?;
+        write_value(out, value)?;
+    }
+    out.write_all(b"}")?;
+    Ok(())
+}
+
+/// Return the attributes of a node or edge, either from the ``attrs``
+/// callable or the payload itself if it's a dict.
+fn payload_attrs<'p>(
+    py: Python<'p>,
+    attrs: &Option<PyObject>,
+    payload: &'p PyObject,
+) -> PyResult<&'p PyDict> {
+    let payload = payload.as_ref(py);
+    let res = match attrs {
+        Some(attrs) => attrs.call1(py, (payload,))?.into_ref(py),
+        None if payload.is_none() => return Ok(PyDict::new(py)),
+        None => payload,
+    };
+    res.downcast::<PyDict>().map_err(|_| {
+        PyTypeError::new_err(format!(
+            "Expected a dict of attributes, got {}, use node_attrs or edge_attrs to convert it",
+            res.get_type().name().unwrap_or("?")
+        ))
+    })
+}
+
+fn write_node_link<Ty: EdgeType, W: Write>(
+    py: Python,
+    graph: &StablePyGraph<Ty>,
+    multigraph: bool,
+    out: &mut W,
+    graph_attrs: Option<&PyDict>,
+    node_attrs: &Option<PyObject>,
+    edge_attrs: &Option<PyObject>,
+) -> PyResult<()> {
+    write!(
+        out,
+        "{{\"schema_version\": {}, \"directed\": {}, \"multigraph\": {}, \"graph\": ",
+        SCHEMA_VERSION,
+        Ty::is_directed(),
+        multigraph
+    )?;
+    write_object(out, graph_attrs.unwrap_or_else(|| PyDict::new(py)), &[])?;
+    out.write_all(b", \"nodes\": [")?;
+    for (i, node) in graph.node_indices().enumerate() {
+        if i > 0 {
+            out.write_all(b", ")?;
+        }
+        let attrs = payload_attrs(py, node_attrs, &graph[node])?;
+        write_object(out, attrs, &[("id", node.index().to_string())])?;
+    }
+    out.write_all(b"], \"links\": [")?;
+    for (i, edge) in graph.edge_references().enumerate() {
+        if i > 0 {
+            out.write_all(b", ")?;
+        }
+        let attrs = payload_attrs(py, edge_attrs, edge.weight())?;
+        let mut fields = vec![
+            ("source", edge.source().index().to_string()),
+            ("target", edge.target().index().to_string()),
+        ];
+        if multigraph {
+            fields.push(("key", edge.id().index().to_string()));
+        }
+        write_object(out, attrs, &fields)?;
+    }
+    out.write_all(b"]}")?;
+    Ok(())
+}
+
+fn node_link_json<Ty: EdgeType>(
+    py: Python,
+    graph: &StablePyGraph<Ty>,
+    multigraph: bool,
+    path: Option<PathBuf>,
+    graph_attrs: Option<&PyDict>,
+    node_attrs: Option<PyObject>,
+    edge_attrs: Option<PyObject>,
+) -> PyResult<Option<String>> {
+    match path {
+        Some(path) => {
+            let mut file = BufWriter::new(File::create(path)?);
+            write_node_link(
+                py,
+                graph,
+                multigraph,
+                &mut file,
+                graph_attrs,
+                &node_attrs,
+                &edge_attrs,
+            )?;
+            file.flush()?;
+            Ok(None)
+        }
+        None => {
+            let mut out = Vec::<u8>::new();
+            write_node_link(
+                py,
+                graph,
+                multigraph,
+                &mut out,
+                graph_attrs,
+                &node_attrs,
+                &edge_attrs,
+            )?;
+            Ok(Some(str::from_utf8(&out)?.to_string()))
+        }
+    }
+}
+
+/// Write a :class:`~retworkx.PyDiGraph` as node-link JSON
+///
+/// The output is compatible with :func:`networkx.node_link_graph` and d3, see
+/// :func:`~retworkx.node_link_json` for details on the format.
+///
+/// :param PyDiGraph graph: The graph to write
+/// :param path: An optional path to write the JSON to, if specified there is
+///     no return from the function
+/// :param dict graph_attrs: An optional dict of graph attributes
+/// :param node_attrs: An optional callable that will be passed the payload
+///     of every node and is expected to return a dict of its attributes. By
+///     default the payload is used if it's a dict and no attributes are
+///     written for ``None``.
+/// :param edge_attrs: An optional callable that will be passed the payload
+///     of every edge and is expected to return a dict of its attributes. By
+///     default the payload is used if it's a dict and no attributes are
+///     written for ``None``.
+///
+/// :returns: The JSON string if ``path`` is not specified
+/// :rtype: str
+///
+/// :raises TypeError: If an attribute dict has a key which isn't a string, a
+///     value without a JSON representation, or a payload isn't a dict
+/// :raises ValueError: If an attribute is named ``id``, ``source``,
+///     ``target`` or ``key`` or is a float that isn't finite
+#[pyfunction]
+#[pyo3(
+    text_signature = "(graph, /, path=None, graph_attrs=None, node_attrs=None, edge_attrs=None)"
+)]
+pub fn digraph_node_link_json(
+    py: Python,
+    graph: &digraph::PyDiGraph,
+    path: Option<PathBuf>,
+    graph_attrs: Option<&PyDict>,
+    node_attrs: Option<PyObject>,
+    edge_attrs: Option<PyObject>,
+) -> PyResult<Option<String>> {
+    node_link_json(
+        py,
+        &graph.graph,
+        graph.multigraph,
+        path,
+        graph_attrs,
+        node_attrs,
+        edge_attrs,
+    )
+}
+
+/// Write a :class:`~retworkx.PyGraph` as node-link JSON
+///
+/// The output is compatible with :func:`networkx.node_link_graph` and d3, see
+/// :func:`~retworkx.node_link_json` for details on the format.
+///
+/// :param PyGraph graph: The graph to write
+/// :param path: An optional path to write the JSON to, if specified there is
+///     no return from the function
+/// :param dict graph_attrs: An optional dict of graph attributes
+/// :param node_attrs: An optional callable that will be passed the payload
+///     of every node and is expected to return a dict of its attributes. By
+///     default the payload is used if it's a dict and no attributes are
+///     written for ``None``.
+/// :param edge_attrs: An optional callable that will be passed the payload
+///     of every edge and is expected to return a dict of its attributes. By
+///     default the payload is used if it's a dict and no attributes are
+///     written for ``None``.
+///
+/// :returns: The JSON string if ``path`` is not specified
+/// :rtype: str
+///
+/// :raises TypeError: If an attribute dict has a key which isn't a string, a
+///     value without a JSON representation, or a payload isn't a dict
+/// :raises ValueError: If an attribute is named ``id``, ``source``,
+///     ``target`` or ``key`` or is a float that isn't finite
+#[pyfunction]
+#[pyo3(
+    text_signature = "(graph, /, path=None, graph_attrs=None, node_attrs=None, edge_attrs=None)"
+)]
+pub fn graph_node_link_json(
+    py: Python,
+    graph: &graph::PyGraph,
+    path: Option<PathBuf>,
+    graph_attrs: Option<&PyDict>,
+    node_attrs: Option<PyObject>,
+    edge_attrs: Option<PyObject>,
+) -> PyResult<Option<String>> {
+    node_link_json(
+        py,
+        &graph.graph,
+        graph.multigraph,
+        path,
+        graph_attrs,
+        node_attrs,
+        edge_attrs,
+    )
+}
+
+/// A parsed JSON value.
+enum Value {
+    Null,
+    Bool(bool),
+    /// An integer, kept as text since it may not fit in 64 bits
+    Int(String),
+    Float(f64),
+    Str(String),
+    Array(Vec<Value>),
+    Object(Vec<(String, Value)>),
+}
+
+impl Value {
+    fn to_object(&self, py: Python) -> PyResult<PyObject> {
+        Ok(match self {
+            Value::Null => py.None(),
+            Value::Bool(value) => value.into_py(py),
+            Value::Int(value) => match value.parse::<i64>() {
+                Ok(value) => value.into_py(py),
+                Err(_) => py
+                    .get_type::<PyLong>()
+                    .call1((value.as_str(),))?
+                    .into_py(py),
+            },
+            Value::Float(value) => value.into_py(py),
+            Value::Str(value) => value.into_py(py),
+            Value::Array(values) => {
+                let items = values
+                    .iter()
+                    .map(|value| value.to_object(py))
+                    .collect::<PyResult<Vec<PyObject>>>()?;
+                PyList::new(py, items).into()
+            }
+            Value::Object(fields) => fields_to_dict(py, fields, &[])?.into(),
+        })
+    }
+
+    /// The text identifying a value as a node id.
+    fn id_key(&self) -> String {
+        match self {
+            Value::Null => "null".to_string(),
+            Value::Bool(value) => value.to_string(),
+            Value::Int(value) => value.clone(),
+            Value::Float(value) => format!("{:?}", value),
+            Value::Str(value) => format!("{:?}", value),
+            Value::Array(values) => format!(
+                "[{}]",
+                values
+                    .iter()
+                    .map(|value| value.id_key())
+                    .collect::<Vec<String>>()
+                    .join(",")
+            ),
+            Value::Object(_) => "{}".to_string(),
+        }
+    }
+}
+
+/// Return a dict of the fields of an object, without the ``skip`` fields.
+fn fields_to_dict<'p>(
+    py: Python<'p>,
+    fields: &[(String, Value)],
+    skip: &[&str],
+) -> PyResult<&'p PyDict> {
+    let dict = PyDict::new(py);
+    for (key, value) in fields {
+        if !skip.contains(&key.as_str()) {
+            dict.set_item(key, value.to_object(py)?)?;
+        }
+    }
+    Ok(dict)
+}
+
+fn get_field<'a>(fields: &'a [(String, Value)], name: &str) -> Option<&'a Value> {
+    fields
+        .iter()
+        .rev()
+        .find(|(key, _)| key == name)
+        .map(|(_, value)| value)
+}
+
+/// A recursive descent parser of JSON, as described in RFC 8259.
+struct JsonParser<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> JsonParser<'a> {
+    fn error(&self, msg: &str) -> PyErr {
+        let consumed = &self.data[..self.pos.min(self.data.len())];
+        let line = consumed.iter().filter(|c| **c == b'\n').count() + 1;
+        let column = consumed.iter().rev().take_while(|c| **c != b'\n').count() + 1;
+        PyValueError::new_err(format!(
+            "Invalid JSON at line {} column {}: {}",
+            line, column, msg
+        ))
+    }
+
+    fn skip_whitespace(&mut self) {
+        while matches!(
+            self.data.get(self.pos),
+            Some(b' ') | Some(b'\t') | Some(b'\n') | Some(b'\r')
+        ) {
+            self.pos += 1;
+        }
+    }
+
+    fn expect(&mut self, c: u8) -> PyResult<()> {
+        self.skip_whitespace();
+        if self.data.get(self.pos) == Some(&c) {
+            self.pos += 1;
+            Ok(())
+        } else {
+            Err(self.error(&format!("expected '{}'", c as char)))
+        }
+    }
+
+    fn literal(&mut self, literal: &str, value: Value) -> PyResult<Value> {
+        if self.data[self.pos..].starts_with(literal.as_bytes()) {
+            self.pos += literal.len();
+            Ok(value)
+        } else {
+            Err(self.error("expected a value"))
+        }
+    }
+
+    fn value(&mut self) -> PyResult<Value> {
+        self.skip_whitespace();
+        match self.data.get(self.pos) {
+            None => Err(self.error("unexpected end of input")),
+            Some(b'n') => self.literal("null", Value::Null),
+            Some(b't') => self.literal("true", Value::Bool(true)),
+            Some(b'f') => self.literal("false", Value::Bool(false)),
+            Some(b'"') => Ok(Value::Str(self.string()?)),
+            Some(b'[') => {
+                self.pos += 1;
+                let mut values = Vec::new();
+                self.skip_whitespace();
+                if self.data.get(self.pos) == Some(&b']') {
+                    self.pos += 1;
+                    return Ok(Value::Array(values));
+                }
+                loop {
+                    values.push(self.value()?);
+                    self.skip_whitespace();
+                    match self.data.get(self.pos) {
+                        Some(b',') => self.pos += 1,
+                        Some(b']') => {
+                            self.pos += 1;
+                            return Ok(Value::Array(values));
+                        }
+                        _ => return Err(self.error("expected ',' or ']'")),
+                    }
+                }
+            }
+            Some(b'{') => {
+                self.pos += 1;
+                let mut fields = Vec::new();
+                self.skip_whitespace();
+                if self.data.get(self.pos) == Some(&b'}') {
+                    self.pos += 1;
+                    return Ok(Value::Object(fields));
+                }
+                loop {
+                    self.skip_whitespace();
+                    if self.data.get(self.pos) != Some(&b'"') {
+                        return Err(self.error("expected a string key"));
+                    }
+                    let key = self.string()?;
+                    self.expect(b':')?;
+                    fields.push((key, self.value()?));
+                    self.skip_whitespace();
+                    match self.data.get(self.pos) {
+                        Some(b',') => self.pos += 1,
+                        Some(b'}') => {
+                            self.pos += 1;
+                            return Ok(Value::Object(fields));
+                        }
+                        _ => return Err(self.error("expected ',' or '}'")),
+                    }
+                }
+            }
+            Some(b'-') | Some(b'0'..=b'9') => self.number(),
+            Some(_) => Err(self.error("expected a value")),
+        }
+    }
+
+    fn digits(&mut self) -> usize {
+        let start = self.pos;
+        while matches!(self.data.get(self.pos), Some(b'0'..=b'9')) {
+            self.pos += 1;
+        }
+        self.pos - start
+    }
+
+    fn number(&mut self) -> PyResult<Value> {
+        let start = self.pos;
+        if self.data[self.pos] == b'-' {
+            self.pos += 1;
+        }
+        let int_start = self.pos;
+        let int_digits = self.digits();
+        if int_digits == 0 || (int_digits > 1 && self.data[int_start] == b'0') {
+            return Err(self.error("invalid number"));
+        }
+        let mut float = false;
+        if self.data.get(self.pos) == Some(&b'.') {
+            self.pos += 1;
+            float = true;
+            if self.digits() == 0 {
+                return Err(self.error("invalid number"));
+            }
+        }
+        if matches!(self.data.get(self.pos), Some(b'e') | Some(b'E')) {
+            self.pos += 1;
+            float = true;
+            if matches!(self.data.get(self.pos), Some(b'+') | Some(b'-')) {
+                self.pos += 1;
+            }
+            if self.digits() == 0 {
+                return Err(self.error("invalid number"));
+            }
+        }
+        // The number is ASCII so this can't fail
+        let text = str::from_utf8(&self.data[start..self.pos]).unwrap();
+        if float {
+            Ok(Value::Float(text.parse::<f64>().unwrap()))
+        } else {
+            Ok(Value::Int(text.to_string()))
+        }
+    }
+
+    fn hex4(&mut self) -> PyResult<u32> {
+        let hex = self
+            .data
+            .get(self.pos..self.pos + 4)
+            .and_then(|hex| str::from_utf8(hex).ok())
+            .and_then(|hex| u32::from_str_radix(hex, 16).ok())
+            .ok_or_else(|| self.error("invalid unicode escape"))?;
+        self.pos += 4;
+        Ok(hex)
+    }
+
+    fn string(&mut self) -> PyResult<String> {
+        // Skip the opening quote
+        self.pos += 1;
+        let mut out = Vec::new();
+        loop {
+            match self.data.get(self.pos) {
+                None => return Err(self.error("unterminated string")),
+                Some(b'"') => {
+                    self.pos += 1;
+                    break;
+                }
+                Some(b'\\') => {
+                    self.pos += 1;
+                    let escaped = match self.data.get(self.pos) {
+                        Some(b'"') => '"',
+                        Some(b'\\') => '\\',
+                        Some(b'/') => '/',
+                        Some(b'b') => '\u{8}',
+                        Some(b'f') => '\u{c}',
+                        Some(b'n') => '\n',
+                        Some(b'r') => '\r',
+                        Some(b't') => '\t',
+                        Some(b'u') => {
+                            self.pos += 1;
+                            let mut code = self.hex4()?;
+                            if (0xD800..0xDC00).contains(&code)
+                                && self.data[self.pos..].starts_with(b"\\u")
+                            {
+                                self.pos += 2;
+                                let low = self.hex4()?;
+                                if !(0xDC00..0xE000).contains(&low) {
+                                    return Err(self.error("invalid unicode escape"));
+                                }
+                                code = 0x10000 + ((code - 0xD800) << 10) + (low - 0xDC00);
+                            }
+                            let c = std::char::from_u32(code)
+                                .ok_or_else(|| self.error("invalid unicode escape"))?;
+                            let mut buf = [0; 4];
+                            out.extend_from_slice(c.encode_utf8(&mut buf).as_bytes());
+                            continue;
+                        }
+                        _ => return Err(self.error("invalid escape")),
+                    };
+                    let mut buf = [0; 4];
+                    out.extend_from_slice(escaped.encode_utf8(&mut buf).as_bytes());
+                    self.pos += 1;
+                }
+                Some(c) if *c < 0x20 => return Err(self.error("control character in string")),
+                Some(c) => {
+                    out.push(*c);
+                    self.pos += 1;
+                }
+            }
+        }
+        // The input was valid UTF-8 and escapes are encoded as UTF-8
+        Ok(String::from_utf8(out).unwrap())
+    }
+
+    fn parse(mut self) -> PyResult<Value> {
+        let value = self.value()?;
+        self.skip_whitespace();
+        if self.pos != self.data.len() {
+            return Err(self.error("extra data"));
+        }
+        Ok(value)
+    }
+}
+
+fn field_bool(fields: &[(String, Value)], name: &str, default: bool) -> PyResult<bool> {
+    match get_field(fields, name) {
+        None => Ok(default),
+        Some(Value::Bool(value)) => Ok(*value),
+        Some(_) => Err(PyValueError::new_err(format!(
+            "\"{}\" must be a boolean",
+            name
+        ))),
+    }
+}
+
+fn field_array<'a>(fields: &'a [(String, Value)], names: &[&str]) -> PyResult<&'a [Value]> {
+    for name in names {
+        match get_field(fields, name) {
+            None => continue,
+            Some(Value::Array(values)) => return Ok(values),
+            Some(_) => {
+                return Err(PyValueError::new_err(format!(
+                    "\"{}\" must be an array",
+                    name
+                )))
+            }
+        }
+    }
+    Ok(&[])
+}
+
+fn value_fields<'a>(value: &'a Value, what: &str) -> PyResult<&'a [(String, Value)]> {
+    match value {
+        Value::Object(fields) => Ok(fields),
+        _ => Err(PyValueError::new_err(format!("{} must be an object", what))),
+    }
+}
+
+fn build_graph<Ty: EdgeType>(
+    py: Python,
+    nodes: &[Value],
+    links: &[Value],
+) -> PyResult<(StablePyGraph<Ty>, NodeKeys)> {
+    let mut out_graph = StablePyGraph::<Ty>::with_capacity(nodes.len(), links.len());
+    let mut node_keys = NodeKeys::default();
+    let mut node_map: HashMap<String, NodeIndex> = HashMap::with_capacity(nodes.len());
+    for node in nodes {
+        let fields = value_fields(node, "A node")?;
+        let id = get_field(fields, "id")
+            .ok_or_else(|| PyValueError::new_err("A node is missing its \"id\""))?;
+        let index = out_graph.add_node(fields_to_dict(py, fields, &["id"])?.into());
+        if node_map.insert(id.id_key(), index).is_some() {
+            return Err(PyValueError::new_err(format!(
+                "Duplicate node id {}",
+                id.id_key()
+            )));
+        }
+        node_keys.insert(py, index, id.to_object(py)?)?;
+    }
+    let endpoint = |fields: &[(String, Value)], name: &str| -> PyResult<NodeIndex> {
+        let id = get_field(fields, name)
+            .ok_or_else(|| PyValueError::new_err(format!("A link is missing its \"{}\"", name)))?;
+        node_map
+            .get(&id.id_key())
+            .copied()
+            .ok_or_else(|| PyValueError::new_err(format!("Unknown node id {}", id.id_key())))
+    };
+    for link in links {
+        let fields = value_fields(link, "A link")?;
+        let source = endpoint(fields, "source")?;
+        let target = endpoint(fields, "target")?;
+        let weight = fields_to_dict(py, fields, &["source", "target", "key"])?;
+        out_graph.add_edge(source, target, weight.into());
+    }
+    Ok((out_graph, node_keys))
+}
+
+/// Read a graph from node-link JSON
+///
+/// This reads the format written by :func:`~retworkx.node_link_json` as well
+/// as :func:`networkx.node_link_data` and d3. A ``"directed"`` graph returns
+/// a :class:`~retworkx.PyDiGraph` and any other graph returns a
+/// :class:`~retworkx.PyGraph`. The payload of every node and edge is a dict
+/// of its attributes, without the ``"id"`` of a node or the ``"source"``,
+/// ``"target"`` and ``"key"`` of a link. Nodes are added in the order they
+/// are listed and the ``"id"`` of every node is set as its key (see
+/// :meth:`.PyGraph.set_node_key`), so node ids map to the new node indices
+/// with :meth:`~.PyGraph.node_indices_by_keys`. Links may be listed under
+/// either ``"links"`` or ``"edges"``.
+///
+/// :param path_or_str: Either a string of JSON or the path of a JSON file. A
+///     string starting with ``{`` is treated as JSON.
+///
+/// :returns: A tuple of the graph and a dict of its ``"graph"`` attributes
+/// :rtype: tuple
+///
+/// :raises ValueError: If the input is not valid JSON, not in the node-link
+///     format, or has a newer ``"schema_version"`` than supported
+/// :raises OSError: If the file can't be read
+#[pyfunction]
+#[pyo3(text_signature = "(path_or_str, /)")]
+pub fn read_node_link_json(py: Python, path_or_str: &PyAny) -> PyResult<(PyObject, PyObject)> {
+    let source = match path_or_str.downcast::<PyString>() {
+        Ok(string) if string.to_str()?.trim_start().starts_with('{') => {
+            string.to_str()?.to_string()
+        }
+        _ => fs::read_to_string(path_or_str.extract::<PathBuf>()?)?,
+    };
+    let data = JsonParser {
+        data: source.as_bytes(),
+        pos: 0,
+    }
+    .parse()?;
+    let fields = value_fields(&data, "The node-link data")?;
+    if let Some(version) = get_field(fields, "schema_version") {
+        let supported = match version {
+            Value::Int(version) => matches!(version.parse::<u64>(), Ok(v) if v <= SCHEMA_VERSION),
+            _ => false,
+        };
+        if !supported {
+            return Err(PyValueError::new_err(format!(
+                "Unsupported node-link schema version {}, the latest supported is {}",
+                version.id_key(),
+                SCHEMA_VERSION
+            )));
+        }
+    }
+    let directed = field_bool(fields, "directed", false)?;
+    let multigraph = field_bool(fields, "multigraph", true)?;
+    let graph_attrs = match get_field(fields, "graph") {
+        None => PyDict::new(py),
+        Some(value) => fields_to_dict(py, value_fields(value, "\"graph\"")?, &[])?,
+    };
+    let nodes = field_array(fields, &["nodes"])?;
+    let links = field_array(fields, &["links", "edges"])?;
+    let out_graph = if directed {
+        let (out_graph, node_keys) = build_graph::<Directed>(py, nodes, links)?;
+        digraph::PyDiGraph {
+            graph: out_graph,
+            cycle_state: None,
+            check_cycle: false,
+            node_removed: false,
+            multigraph,
+            node_keys,
+            attributes: AttributeColumns::default(),
+            generation: 0,
+        }
+        .into_py(py)
+    } else {
+        let (out_graph, node_keys) = build_graph::<Undirected>(py, nodes, links)?;
+        graph::PyGraph {
+            graph: out_graph,
+            node_removed: false,
+            multigraph,
+            node_keys,
+            attributes: AttributeColumns::default(),
+            generation: 0,
+        }
+        .into_py(py)
+    };
+    Ok((out_graph, graph_attrs.into()))
+}
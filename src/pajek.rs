@@ -0,0 +1,544 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use std::fs::{self, File};
+use std::io::prelude::*;
+use std::io::BufWriter;
+use std::path::PathBuf;
+use std::str;
+
+use petgraph::prelude::*;
+use petgraph::visit::{IntoEdgeReferences, NodeIndexable};
+use petgraph::EdgeType;
+
+use pyo3::exceptions::{PyTypeError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::types::PyString;
+use pyo3::Python;
+
+use crate::attributes::{AttributeColumns, Column};
+use crate::node_keys::NodeKeys;
+use crate::{digraph, graph, StablePyGraph};
+
+const COORDINATES: [&str; 3] = ["x", "y", "z"];
+
+fn pajek_error(line: usize, msg: &str) -> PyErr {
+    PyValueError::new_err(format!("Invalid Pajek on line {}: {}", line, msg))
+}
+
+/// Split a line into whitespace separated tokens, a double quoted token may
+/// contain whitespace. The flag of a token is set if it was quoted.
+fn split_line(line: &str, line_no: usize) -> PyResult<Vec<(String, bool)>> {
+    let mut tokens = Vec::new();
+    let mut chars = line.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c.is_whitespace() {
+            continue;
+        }
+        if c == '"' {
+            let mut token = String::new();
+            loop {
+                match chars.next() {
+                    Some('"') => break,
+                    Some(c) => token.push(c),
+                    None => return Err(pajek_error(line_no, "unterminated label")),
+                }
+            }
+            tokens.push((token, true));
+        } else {
+            let mut token = c.to_string();
+            while let Some(c) = chars.peek() {
+                if c.is_whitespace() {
+                    break;
+                }
+                token.push(*c);
+                chars.next();
+            }
+            tokens.push((token, false));
+        }
+    }
+    Ok(tokens)
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Section {
+    None,
+    Vertices,
+    Arcs,
+    Edges,
+    ArcsList,
+    EdgesList,
+    Matrix,
+}
+
+/// The vertices and edges of a Pajek network, the vertices numbered from 0.
+struct PajekNetwork {
+    labels: Vec<String>,
+    coordinates: Vec<[f64; 3]>,
+    dimensions: usize,
+    /// The source, target, weight and whether each edge is an arc
+    edges: Vec<(usize, usize, Option<f64>, bool)>,
+}
+
+fn parse_vertex(network: &PajekNetwork, token: &str, line_no: usize) -> PyResult<usize> {
+    match token.parse::<usize>() {
+        Ok(vertex) if vertex >= 1 && vertex <= network.labels.len() => Ok(vertex - 1),
+        _ => Err(pajek_error(
+            line_no,
+            &format!(
+                "'{}' is not a vertex number between 1 and {}",
+                token,
+                network.labels.len()
+            ),
+        )),
+    }
+}
+
+fn check_matrix_rows(
+    network: &PajekNetwork,
+    section: Section,
+    matrix_row: usize,
+    line_no: usize,
+) -> PyResult<()> {
+    if section == Section::Matrix && matrix_row != network.labels.len() {
+        return Err(pajek_error(
+            line_no,
+            &format!(
+                "expected {} matrix rows, found {}",
+                network.labels.len(),
+                matrix_row
+            ),
+        ));
+    }
+    Ok(())
+}
+
+fn parse_pajek(source: &str) -> PyResult<PajekNetwork> {
+    let mut network = PajekNetwork {
+        labels: Vec::new(),
+        coordinates: Vec::new(),
+        dimensions: 0,
+        edges: Vec::new(),
+    };
+    let mut section = Section::None;
+    let mut matrix_row = 0;
+    for (line_no, line) in source.lines().enumerate() {
+        let line_no = line_no + 1;
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('%') {
+            continue;
+        }
+        if let Some(header) = line.strip_prefix('*') {
+            check_matrix_rows(&network, section, matrix_row, line_no)?;
+            let keyword = header
+                .split_whitespace()
+                .next()
+                .unwrap_or("")
+                .to_ascii_lowercase();
+            section = match keyword.as_str() {
+                "network" => Section::None,
+                "vertices" => {
+                    if !network.labels.is_empty() {
+                        return Err(pajek_error(line_no, "duplicate *Vertices section"));
+                    }
+                    let count = header
+                        .split_whitespace()
+                        .nth(1)
+                        .and_then(|count| count.parse::<usize>().ok())
+                        .ok_or_else(|| pajek_error(line_no, "expected a number of vertices"))?;
+                    network.labels = (1..=count).map(|vertex| vertex.to_string()).collect();
+                    network.coordinates = vec![[f64::NAN; 3]; count];
+                    Section::Vertices
+                }
+                "arcs" => Section::Arcs,
+                "edges" => Section::Edges,
+                "arcslist" => Section::ArcsList,
+                "edgeslist" => Section::EdgesList,
+                "matrix" => {
+                    matrix_row = 0;
+                    Section::Matrix
+                }
+                _ => {
+                    return Err(pajek_error(
+                        line_no,
+                        &format!("unknown section '*{}'", keyword),
+                    ))
+                }
+            };
+            if section != Section::None && section != Section::Vertices && network.labels.is_empty()
+            {
+                return Err(pajek_error(line_no, "expected a *Vertices section first"));
+            }
+            continue;
+        }
+        let tokens = split_line(line, line_no)?;
+        match section {
+            Section::None => {
+                return Err(pajek_error(line_no, "expected a section"));
+            }
+            Section::Vertices => {
+                let vertex = parse_vertex(&network, &tokens[0].0, line_no)?;
+                if let Some((label, _)) = tokens.get(1) {
+                    network.labels[vertex] = label.clone();
+                }
+                // Any attributes after the coordinates, like colors, are
+                // ignored
+                for (dim, (token, quoted)) in tokens.iter().skip(2).take(3).enumerate() {
+                    match token.parse::<f64>() {
+                        Ok(value) if !quoted => {
+                            network.coordinates[vertex][dim] = value;
+                            network.dimensions = network.dimensions.max(dim + 1);
+                        }
+                        _ => break,
+                    }
+                }
+            }
+            Section::Arcs | Section::Edges => {
+                if tokens.len() < 2 {
+                    return Err(pajek_error(line_no, "expected 2 vertex numbers"));
+                }
+                let source = parse_vertex(&network, &tokens[0].0, line_no)?;
+                let target = parse_vertex(&network, &tokens[1].0, line_no)?;
+                let weight = match tokens.get(2) {
+                    Some((token, false)) => token.parse::<f64>().ok(),
+                    _ => None,
+                };
+                network
+                    .edges
+                    .push((source, target, weight, section == Section::Arcs));
+            }
+            Section::ArcsList | Section::EdgesList => {
+                let source = parse_vertex(&network, &tokens[0].0, line_no)?;
+                for (token, _) in &tokens[1..] {
+                    let target = parse_vertex(&network, token, line_no)?;
+                    network
+                        .edges
+                        .push((source, target, None, section == Section::ArcsList));
+                }
+            }
+            Section::Matrix => {
+                if matrix_row >= network.labels.len() || tokens.len() != network.labels.len() {
+                    return Err(pajek_error(
+                        line_no,
+                        &format!(
+                            "expected {} rows of {} values",
+                            network.labels.len(),
+                            network.labels.len()
+                        ),
+                    ));
+                }
+                for (target, (token, _)) in tokens.iter().enumerate() {
+                    let weight = token.parse::<f64>().map_err(|_| {
+                        pajek_error(line_no, &format!("'{}' is not a number", token))
+                    })?;
+                    if weight != 0.0 {
+                        network.edges.push((matrix_row, target, Some(weight), true));
+                    }
+                }
+                matrix_row += 1;
+            }
+        }
+    }
+    check_matrix_rows(&network, section, matrix_row, source.lines().count())?;
+    if network.labels.is_empty() && !source.to_ascii_lowercase().contains("*vertices") {
+        return Err(pajek_error(1, "expected a *Vertices section"));
+    }
+    Ok(network)
+}
+
+fn network_to_graph<Ty: EdgeType>(
+    py: Python,
+    network: PajekNetwork,
+) -> (StablePyGraph<Ty>, AttributeColumns) {
+    let mut out_graph =
+        StablePyGraph::<Ty>::with_capacity(network.labels.len(), network.edges.len());
+    for label in network.labels {
+        out_graph.add_node(label.into_py(py));
+    }
+    for (source, target, weight, arc) in network.edges {
+        let (source, target) = (NodeIndex::new(source), NodeIndex::new(target));
+        out_graph.add_edge(source, target, weight.into_py(py));
+        // An edge of a directed network is a pair of opposite arcs
+        if Ty::is_directed() && !arc && source != target {
+            out_graph.add_edge(target, source, weight.into_py(py));
+        }
+    }
+    let mut attributes = AttributeColumns::default();
+    for (dim, name) in COORDINATES.iter().enumerate().take(network.dimensions) {
+        let values = network.coordinates.iter().map(|point| point[dim]).collect();
+        attributes
+            .nodes
+            .insert(name.to_string(), Column::Float64(values));
+    }
+    (out_graph, attributes)
+}
+
+/// Read a graph from the Pajek ``.net`` format
+///
+/// A network with an ``*Arcs``, ``*Arcslist`` or ``*Matrix`` section
+/// returns a :class:`~retworkx.PyDiGraph`, where every edge of an
+/// ``*Edges`` or ``*Edgeslist`` section is a pair of opposite edges, and
+/// any other network returns a :class:`~retworkx.PyGraph`. Node ``i`` is
+/// the vertex numbered ``i + 1`` and its payload is the vertex label, or
+/// its number as a string if it has no label. The payload of every edge is
+/// its weight as a ``float``, or ``None`` if it has none. Vertex
+/// coordinates are set as the ``"x"``, ``"y"`` and ``"z"`` node attributes
+/// (see :meth:`.PyGraph.get_node_attribute`), which are ``NaN`` for
+/// vertices without coordinates. Other vertex and edge attributes, such as
+/// colors, are ignored.
+///
+/// :param path_or_str: Either a string of Pajek data or the path of a
+///     ``.net`` file. A string with a newline is treated as Pajek data.
+///
+/// :returns: The graph of the network
+/// :rtype: PyGraph or PyDiGraph
+///
+/// :raises ValueError: If the input is not a valid Pajek network
+/// :raises OSError: If the file can't be read
+///
+/// .. jupyter-execute::
+///
+///   import retworkx
+///
+///   graph = retworkx.read_pajek("""*Vertices 3
+///   1 "a" 0.0 0.5
+///   2 "b" 1.0 0.5
+///   3 "c" 0.5 1.0
+///   *Edges
+///   1 2 2.5
+///   2 3
+///   """)
+///   print(graph.nodes())
+///   print(graph.weighted_edge_list())
+#[pyfunction]
+#[pyo3(text_signature = "(path_or_str, /)")]
+pub fn read_pajek(py: Python, path_or_str: &PyAny) -> PyResult<PyObject> {
+    let source = match path_or_str.downcast::<PyString>() {
+        Ok(string) if string.to_str()?.contains('\n') => string.to_str()?.to_string(),
+        _ => fs::read_to_string(path_or_str.extract::<PathBuf>()?)?,
+    };
+    let network = parse_pajek(&source)?;
+    let directed = network.edges.iter().any(|edge| edge.3);
+    if directed {
+        let (out_graph, attributes) = network_to_graph::<Directed>(py, network);
+        Ok(digraph::PyDiGraph {
+            graph: out_graph,
+            cycle_state: None,
+            check_cycle: false,
+            node_removed: false,
+            multigraph: true,
+            node_keys: NodeKeys::default(),
+            attributes,
+            generation: 0,
+        }
+        .into_py(py))
+    } else {
+        let (out_graph, attributes) = network_to_graph::<Undirected>(py, network);
+        Ok(graph::PyGraph {
+            graph: out_graph,
+            node_removed: false,
+            multigraph: true,
+            node_keys: NodeKeys::default(),
+            attributes,
+            generation: 0,
+        }
+        .into_py(py))
+    }
+}
+
+fn write_pajek<Ty: EdgeType, W: Write>(
+    py: Python,
+    graph: &StablePyGraph<Ty>,
+    attributes: &AttributeColumns,
+    out: &mut W,
+    label_fn: &Option<PyObject>,
+    weight_fn: &Option<PyObject>,
+) -> PyResult<()> {
+    // Vertices are numbered from 1 in node index order
+    let mut vertices = vec![0; graph.node_bound()];
+    writeln!(out, "*Vertices {}", graph.node_count())?;
+    // Coordinates are written if there are "x" and "y" node attributes
+    let mut columns: Vec<&Column> = Vec::new();
+    for name in COORDINATES.iter() {
+        match attributes.nodes.get(name) {
+            Some(column) => columns.push(column),
+            None => break,
+        }
+    }
+    if columns.len() < 2 {
+        columns.clear();
+    }
+    for (i, node) in graph.node_indices().enumerate() {
+        vertices[node.index()] = i + 1;
+        let payload = &graph[node];
+        let label: Option<String> = match label_fn {
+            Some(label_fn) => Some(label_fn.call1(py, (payload,))?.extract(py)?),
+            None if payload.is_none(py) => None,
+            None => Some(payload.as_ref(py).str()?.to_str()?.to_string()),
+        };
+        let coordinates: Vec<f64> = columns
+            .iter()
+            .map(|column| column.get_f64(node.index()))
+            .take_while(|value| !value.is_nan())
+            .collect();
+        write!(out, "{}", i + 1)?;
+        if label.is_some() || coordinates.len() >= 2 {
+            let label = label.unwrap_or_else(|| (i + 1).to_string());
+            if label.contains('"') || label.contains('\n') {
+                return Err(PyValueError::new_err(format!(
+                    "The label {:?} contains a double quote or newline",
+                    label
+                )));
+            }
+            write!(out, " \"{}\"", label)?;
+            if coordinates.len() >= 2 {
+                for value in coordinates {
+                    write!(out, " {}", value)?;
+                }
+            }
+        }
+        writeln!(out)?;
+    }
+    writeln!(
+        out,
+        "{}",
+        if Ty::is_directed() { "*Arcs" } else { "*Edges" }
+    )?;
+    for edge in graph.edge_references() {
+        let payload = edge.weight();
+        let weight: Option<f64> = match weight_fn {
+            Some(weight_fn) => Some(weight_fn.call1(py, (payload,))?.extract(py)?),
+            None if payload.is_none(py) => None,
+            None => Some(payload.extract(py).map_err(|_| {
+                PyTypeError::new_err(
+                    "Edge payloads must be numbers or None, use weight_fn to convert them",
+                )
+            })?),
+        };
+        write!(
+            out,
+            "{} {}",
+            vertices[edge.source().index()],
+            vertices[edge.target().index()]
+        )?;
+        if let Some(weight) = weight {
+            write!(out, " {}", weight)?;
+        }
+        writeln!(out)?;
+    }
+    Ok(())
+}
+
+fn pajek_string<Ty: EdgeType>(
+    py: Python,
+    graph: &StablePyGraph<Ty>,
+    attributes: &AttributeColumns,
+    path: Option<PathBuf>,
+    label_fn: Option<PyObject>,
+    weight_fn: Option<PyObject>,
+) -> PyResult<Option<String>> {
+    match path {
+        Some(path) => {
+            let mut file = BufWriter::new(File::create(path)?);
+            write_pajek(py, graph, attributes, &mut file, &label_fn, &weight_fn)?;
+            file.flush()?;
+            Ok(None)
+        }
+        None => {
+            let mut out = Vec::<u8>::new();
+            write_pajek(py, graph, attributes, &mut out, &label_fn, &weight_fn)?;
+            Ok(Some(str::from_utf8(&out)?.to_string()))
+        }
+    }
+}
+
+/// Write a :class:`~retworkx.PyDiGraph` in the Pajek ``.net`` format
+///
+/// See :func:`~retworkx.write_pajek` for details on the output.
+///
+/// :param PyDiGraph graph: The graph to write
+/// :param path: An optional path to write the network to, if specified there
+///     is no return from the function
+/// :param label_fn: An optional callable that will be passed the payload of
+///     every node and is expected to return its label as a string. By
+///     default the label is ``str(payload)`` and nodes with a ``None``
+///     payload have no label.
+/// :param weight_fn: An optional callable that will be passed the payload of
+///     every edge and is expected to return its weight as a float. By
+///     default the payload is the weight and edges with a ``None`` payload
+///     have no weight.
+///
+/// :returns: The Pajek network as a string if ``path`` is not specified
+/// :rtype: str
+///
+/// :raises TypeError: If an edge payload isn't a number and no ``weight_fn``
+///     is specified
+/// :raises ValueError: If a label contains a double quote or a newline
+#[pyfunction]
+#[pyo3(text_signature = "(graph, /, path=None, label_fn=None, weight_fn=None)")]
+pub fn digraph_write_pajek(
+    py: Python,
+    graph: &digraph::PyDiGraph,
+    path: Option<PathBuf>,
+    label_fn: Option<PyObject>,
+    weight_fn: Option<PyObject>,
+) -> PyResult<Option<String>> {
+    pajek_string(
+        py,
+        &graph.graph,
+        &graph.attributes,
+        path,
+        label_fn,
+        weight_fn,
+    )
+}
+
+/// Write a :class:`~retworkx.PyGraph` in the Pajek ``.net`` format
+///
+/// See :func:`~retworkx.write_pajek` for details on the output.
+///
+/// :param PyGraph graph: The graph to write
+/// :param path: An optional path to write the network to, if specified there
+///     is no return from the function
+/// :param label_fn: An optional callable that will be passed the payload of
+///     every node and is expected to return its label as a string. By
+///     default the label is ``str(payload)`` and nodes with a ``None``
+///     payload have no label.
+/// :param weight_fn: An optional callable that will be passed the payload of
+///     every edge and is expected to return its weight as a float. By
+///     default the payload is the weight and edges with a ``None`` payload
+///     have no weight.
+///
+/// :returns: The Pajek network as a string if ``path`` is not specified
+/// :rtype: str
+///
+/// :raises TypeError: If an edge payload isn't a number and no ``weight_fn``
+///     is specified
+/// :raises ValueError: If a label contains a double quote or a newline
+#[pyfunction]
+#[pyo3(text_signature = "(graph, /, path=None, label_fn=None, weight_fn=None)")]
+pub fn graph_write_pajek(
+    py: Python,
+    graph: &graph::PyGraph,
+    path: Option<PathBuf>,
+    label_fn: Option<PyObject>,
+    weight_fn: Option<PyObject>,
+) -> PyResult<Option<String>> {
+    pajek_string(
+        py,
+        &graph.graph,
+        &graph.attributes,
+        path,
+        label_fn,
+        weight_fn,
+    )
+}
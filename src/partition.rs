@@ -0,0 +1,821 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use crate::attributes::AttributeColumns;
+use crate::iterators::{EdgeIndices, NodeIndices, NodeMap};
+use crate::node_keys::NodeKeys;
+use crate::{digraph, graph, weight_callable, InvalidNode, StablePyGraph};
+
+use retworkx_core::dictmap::*;
+use retworkx_core::err::CallbackError;
+use retworkx_core::partition;
+
+use petgraph::prelude::*;
+use petgraph::visit::NodeIndexable;
+use petgraph::EdgeType;
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use pyo3::Python;
+
+/// A part of a graph split by :func:`~retworkx.edge_cut_partition`
+///
+/// :ivar int part: The id of the part, its position in the input partition
+/// :ivar subgraph: A new graph of the same type as the input graph with the
+///     nodes of the part followed by its halo nodes, and every edge of the
+///     input graph between them. The node and edge payloads are shared with
+///     the input graph.
+/// :ivar NodeIndices nodes: The node indices in the input graph of the nodes
+///     of the part, in order. They're the first nodes of ``subgraph``.
+/// :ivar NodeIndices halo_nodes: The node indices in the input graph of the
+///     halo (or ghost) nodes of the part, the nodes of other parts within
+///     ``halo_depth`` edges of a node of the part, in order of distance.
+///     They follow the nodes of the part in ``subgraph``.
+/// :ivar list halo_owners: The part id of every halo node, in the order of
+///     ``halo_nodes``
+/// :ivar NodeMap node_map: A mapping of the node indices in the input graph
+///     to the node indices in ``subgraph``
+/// :ivar EdgeIndices cut_edges: The edge indices in the input graph of the
+///     edges between a node of the part and a node of another part, in order
+#[pyclass(module = "retworkx")]
+pub struct GraphPart {
+    #[pyo3(get)]
+    pub part: usize,
+    #[pyo3(get)]
+    pub subgraph: PyObject,
+    #[pyo3(get)]
+    pub nodes: NodeIndices,
+    #[pyo3(get)]
+    pub halo_nodes: NodeIndices,
+    #[pyo3(get)]
+    pub halo_owners: Vec<usize>,
+    #[pyo3(get)]
+    pub node_map: NodeMap,
+    #[pyo3(get)]
+    pub cut_edges: EdgeIndices,
+}
+
+/// A graph split into parts by :func:`~retworkx.edge_cut_partition`
+///
+/// :ivar list parts: The :class:`~retworkx.GraphPart` of every part, in the
+///     order of the input partition
+/// :ivar list cut_edges: The edges between nodes of different parts in the
+///     order of their edge indices, as tuples of the form
+///     ``(edge_index, source, target, source_part, target_part)``
+#[pyclass(module = "retworkx")]
+pub struct EdgeCutPartition {
+    #[pyo3(get)]
+    pub parts: Vec<Py<GraphPart>>,
+    #[pyo3(get)]
+    pub cut_edges: Vec<(usize, usize, usize, usize, usize)>,
+}
+
+/// The subgraph of the nodes of a part followed by its halo nodes
+fn part_subgraph<Ty: EdgeType>(
+    py: Python,
+    graph: &StablePyGraph<Ty>,
+    nodes: &[NodeIndex],
+) -> (StablePyGraph<Ty>, DictMap<usize, usize>) {
+    let mut out_graph = StablePyGraph::<Ty>::default();
+    let mut node_map: DictMap<usize, usize> = DictMap::with_capacity(nodes.len());
+    for node in nodes {
+        let new_node = out_graph.add_node(graph[*node].clone_ref(py));
+        node_map.insert(node.index(), new_node.index());
+    }
+    for node in nodes {
+        let new_source = node_map[&node.index()];
+        for edge in graph.edges_directed(*node, Outgoing) {
+            let other = if edge.source() == *node {
+                edge.target()
+            } else {
+                edge.source()
+            };
+            // Every undirected edge is seen from both of its endpoints
+            match node_map.get(&other.index()) {
+                Some(new_target) if Ty::is_directed() || new_source <= *new_target => {
+                    out_graph.add_edge(
+                        NodeIndex::new(new_source),
+                        NodeIndex::new(*new_target),
+                        edge.weight().clone_ref(py),
+                    );
+                }
+                _ => (),
+            }
+        }
+    }
+    (out_graph, node_map)
+}
+
+/// The part id of every node index of a partition of the graph, after
+/// checking that every node of the graph is in exactly one part.
+fn node_parts<Ty: EdgeType>(
+    graph: &StablePyGraph<Ty>,
+    partition: &[Vec<usize>],
+) -> PyResult<Vec<usize>> {
+    let mut node_parts = vec![usize::MAX; graph.node_bound()];
+    for (part, nodes) in partition.iter().enumerate() {
+        for node in nodes {
+            if !graph.contains_node(NodeIndex::new(*node)) {
+                return Err(InvalidNode::new_err(format!(
+                    "Node index {} is not in the graph",
+                    node
+                )));
+            }
+            if node_parts[*node] != usize::MAX {
+                return Err(PyValueError::new_err(format!(
+                    "Node {} is in more than one part",
+                    node
+                )));
+            }
+            node_parts[*node] = part;
+        }
+    }
+    if let Some(node) = graph
+        .node_indices()
+        .find(|node| node_parts[node.index()] == usize::MAX)
+    {
+        return Err(PyValueError::new_err(format!(
+            "Node {} is not in any part",
+            node.index()
+        )));
+    }
+    Ok(node_parts)
+}
+
+fn edge_cut_partition<Ty: EdgeType, F>(
+    py: Python,
+    graph: &StablePyGraph<Ty>,
+    partition: Vec<Vec<usize>>,
+    halo_depth: usize,
+    new_graph: F,
+) -> PyResult<EdgeCutPartition>
+where
+    F: Fn(StablePyGraph<Ty>) -> PyObject,
+{
+    let node_parts = node_parts(graph, &partition)?;
+    let result = partition::edge_cut_partition(
+        graph,
+        partition.len(),
+        |node| node_parts[node.index()],
+        halo_depth,
+    )
+    .map_err(|err| PyValueError::new_err(err.to_string()))?;
+    let parts = result
+        .parts
+        .into_iter()
+        .enumerate()
+        .map(|(part_id, part)| {
+            let nodes: Vec<NodeIndex> = part
+                .nodes
+                .iter()
+                .chain(part.halo_nodes.iter())
+                .copied()
+                .collect();
+            let (subgraph, node_map) = part_subgraph(py, graph, &nodes);
+            Py::new(
+                py,
+                GraphPart {
+                    part: part_id,
+                    subgraph: new_graph(subgraph),
+                    nodes: NodeIndices {
+                        nodes: part.nodes.iter().map(|n| n.index()).collect(),
+                    },
+                    halo_owners: part
+                        .halo_nodes
+                        .iter()
+                        .map(|n| node_parts[n.index()])
+                        .collect(),
+                    halo_nodes: NodeIndices {
+                        nodes: part.halo_nodes.iter().map(|n| n.index()).collect(),
+                    },
+                    node_map: NodeMap { node_map },
+                    cut_edges: EdgeIndices {
+                        edges: part.cut_edges.iter().map(|e| e.index()).collect(),
+                    },
+                },
+            )
+        })
+        .collect::<PyResult<Vec<Py<GraphPart>>>>()?;
+    let cut_edges = result
+        .cut_edges
+        .iter()
+        .map(|edge| {
+            let (source, target) = graph.edge_endpoints(*edge).unwrap();
+            (
+                edge.index(),
+                source.index(),
+                target.index(),
+                node_parts[source.index()],
+                node_parts[target.index()],
+            )
+        })
+        .collect();
+    Ok(EdgeCutPartition { parts, cut_edges })
+}
+
+/// Split a partitioned PyGraph into parts along the edges between them
+///
+/// Given a k-way partition of the nodes of the graph, this returns a
+/// subgraph for every part with the nodes of the part and its halo (or
+/// ghost) nodes, the nodes of other parts within ``halo_depth`` edges of a
+/// node of the part, and the cut edges between nodes of different parts.
+/// This is the input needed to process the parts of a graph separately,
+/// for example in a distributed job, where the halo nodes are the copies of
+/// the neighboring nodes owned by other parts.
+///
+/// :param PyGraph graph: The graph to split
+/// :param list partition: A list of parts, where every part is a list of
+///     node indices. Every node of the graph must be in exactly one part. The
+///     part ids are the positions of the parts in this list.
+/// :param int halo_depth: The maximum number of edges from a node of a part
+///     to its halo nodes. A ``halo_depth`` of 0 gives no halo nodes. Defaults
+///     to 1.
+///
+/// :returns: The parts and the cut edges of the graph
+/// :rtype: EdgeCutPartition
+/// :raises InvalidNode: If a node index in ``partition`` is not in the graph
+/// :raises ValueError: If a node of the graph is not in exactly one part
+#[pyfunction(halo_depth = "1")]
+#[pyo3(text_signature = "(graph, partition, /, halo_depth=1)")]
+pub fn graph_edge_cut_partition(
+    py: Python,
+    graph: &graph::PyGraph,
+    partition: Vec<Vec<usize>>,
+    halo_depth: usize,
+) -> PyResult<EdgeCutPartition> {
+    edge_cut_partition(py, &graph.graph, partition, halo_depth, |subgraph| {
+        graph::PyGraph {
+            graph: subgraph,
+            node_removed: false,
+            multigraph: graph.multigraph,
+            node_keys: NodeKeys::default(),
+            attributes: AttributeColumns::default(),
+            generation: 0,
+        }
+        .into_py(py)
+    })
+}
+
+/// Split a partitioned PyDiGraph into parts along the edges between them
+///
+/// Given a k-way partition of the nodes of the graph, this returns a
+/// subgraph for every part with the nodes of the part and its halo (or
+/// ghost) nodes, the nodes of other parts within ``halo_depth`` edges of a
+/// node of the part, and the cut edges between nodes of different parts.
+/// The halo nodes are found ignoring the direction of the edges. This is the
+/// input needed to process the parts of a graph separately, for example in
+/// a distributed job, where the halo nodes are the copies of the
+/// neighboring nodes owned by other parts.
+///
+/// :param PyDiGraph graph: The graph to split
+/// :param list partition: A list of parts, where every part is a list of
+///     node indices. Every node of the graph must be in exactly one part. The
+///     part ids are the positions of the parts in this list.
+/// :param int halo_depth: The maximum number of edges from a node of a part
+///     to its halo nodes. A ``halo_depth`` of 0 gives no halo nodes. Defaults
+///     to 1.
+///
+/// :returns: The parts and the cut edges of the graph
+/// :rtype: EdgeCutPartition
+/// :raises InvalidNode: If a node index in ``partition`` is not in the graph
+/// :raises ValueError: If a node of the graph is not in exactly one part
+#[pyfunction(halo_depth = "1")]
+#[pyo3(text_signature = "(graph, partition, /, halo_depth=1)")]
+pub fn digraph_edge_cut_partition(
+    py: Python,
+    graph: &digraph::PyDiGraph,
+    partition: Vec<Vec<usize>>,
+    halo_depth: usize,
+) -> PyResult<EdgeCutPartition> {
+    edge_cut_partition(py, &graph.graph, partition, halo_depth, |subgraph| {
+        digraph::PyDiGraph {
+            graph: subgraph,
+            cycle_state: None,
+            check_cycle: graph.check_cycle,
+            node_removed: false,
+            multigraph: graph.multigraph,
+            node_keys: NodeKeys::default(),
+            attributes: AttributeColumns::default(),
+            generation: 0,
+        }
+        .into_py(py)
+    })
+}
+
+/// Aggregate the groups of nodes of a graph into the nodes of a new graph
+/// of the same edge type, with a summary of every group and of the edges
+/// between every pair of groups as their payloads.
+fn aggregate_graph<Ty: EdgeType>(
+    py: Python,
+    graph: &StablePyGraph<Ty>,
+    groups: &PyAny,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+) -> PyResult<StablePyGraph<Ty>> {
+    let (node_groups, keys): (Vec<usize>, Vec<PyObject>) = if groups.is_callable() {
+        let group_ids = PyDict::new(py);
+        let mut node_groups = vec![usize::MAX; graph.node_bound()];
+        let mut keys: Vec<PyObject> = Vec::new();
+        for node in graph.node_indices() {
+            let key = groups.call1((&graph[node],))?;
+            let group = match group_ids.get_item(key) {
+                Some(group) => group.extract()?,
+                None => {
+                    group_ids.set_item(key, keys.len())?;
+                    keys.push(key.into());
+                    keys.len() - 1
+                }
+            };
+            node_groups[node.index()] = group;
+        }
+        (node_groups, keys)
+    } else {
+        let partition: Vec<Vec<usize>> = groups.extract()?;
+        let keys = (0..partition.len()).map(|part| part.into_py(py)).collect();
+        (node_parts(graph, &partition)?, keys)
+    };
+
+    let aggregated = partition::aggregate_graph(
+        graph,
+        keys.len(),
+        |node| node_groups[node.index()],
+        |edge| weight_callable(py, &weight_fn, edge.weight(), default_weight),
+    )?;
+    let mut out_graph =
+        StablePyGraph::<Ty>::with_capacity(aggregated.groups.len(), aggregated.edges.len());
+    for (group, key) in aggregated.groups.iter().zip(keys) {
+        let payload = PyDict::new(py);
+        payload.set_item("group", key)?;
+        payload.set_item(
+            "nodes",
+            group
+                .nodes
+                .iter()
+                .map(|n| n.index())
+                .collect::<Vec<usize>>(),
+        )?;
+        payload.set_item("size", group.nodes.len())?;
+        payload.set_item("internal_edges", group.internal_edges)?;
+        payload.set_item("internal_weight", group.internal_weight)?;
+        payload.set_item("density", group.density)?;
+        out_graph.add_node(payload.into());
+    }
+    for edge in aggregated.edges {
+        let payload = PyDict::new(py);
+        payload.set_item("count", edge.count)?;
+        payload.set_item("weight", edge.weight)?;
+        out_graph.add_edge(
+            NodeIndex::new(edge.source),
+            NodeIndex::new(edge.target),
+            payload.into(),
+        );
+    }
+    Ok(out_graph)
+}
+
+/// Aggregate the groups of nodes of a PyGraph into the nodes of a smaller
+/// PyGraph
+///
+/// This builds the quotient graph of a grouping of the nodes, for example
+/// to draw a graph too large to draw node by node: every group of nodes
+/// becomes a single node, and the edges between the nodes of two groups
+/// become a single edge between them. The groups are given either by a
+/// partition of the nodes or by a callable returning a key for every node,
+/// such as a node attribute.
+///
+/// The payload of every node of the aggregated graph is a ``dict`` with the
+/// summary of its group:
+///
+/// * ``"group"``: The key of the group, or its position in ``groups`` for a
+///   partition
+/// * ``"nodes"``: The list of the node indices of the group, in order
+/// * ``"size"``: The number of nodes of the group
+/// * ``"internal_edges"``: The number of edges between two nodes of the
+///   group, including self loops
+/// * ``"internal_weight"``: The sum of the weights of these edges
+/// * ``"density"``: The number of internal edges divided by the number of
+///   pairs of distinct nodes of the group, or 0 for a group of less than 2
+///   nodes. It can exceed 1 if the graph has parallel edges or self loops.
+///
+/// The payload of every edge of the aggregated graph is a ``dict`` with the
+/// ``"count"`` of the edges between the two groups and their total
+/// ``"weight"``. The edges are added in increasing order of the groups of
+/// their endpoints, and there's no edge between groups without an edge
+/// between them.
+///
+/// :param PyGraph graph: The graph to aggregate
+/// :param groups: Either a partition of the nodes, as a list of lists of node
+///     indices where every node of the graph is in exactly one list, or a
+///     callable that will be passed the payload of every node and is
+///     expected to return the hashable key of its group. The groups from a
+///     callable are in the order of the lowest node index of each group.
+/// :param weight_fn: An optional callable that will be passed the edge's
+///     payload object and is expected to return a ``float`` weight for that
+///     edge.
+/// :param float default_weight: If ``weight_fn`` isn't specified this
+///     optional float value will be used for the weight of every edge.
+///     Defaults to 1.0.
+///
+/// :returns: The aggregated graph, with a node for every group in order
+/// :rtype: PyGraph
+/// :raises InvalidNode: If a node index in a partition is not in the graph
+/// :raises ValueError: If a node of the graph is not in exactly one part of
+///     a partition
+///
+/// .. jupyter-execute::
+///
+///   import retworkx
+///   from retworkx.visualization import mpl_draw
+///
+///   # Group the nodes of the grid in 3x3 blocks
+///   blocks = [(node // 18, node % 6 // 3) for node in range(36)]
+///   graph = retworkx.generators.grid_graph(6, 6, weights=blocks)
+///   aggregated = retworkx.graph_aggregate_graph(graph, lambda block: block)
+///   mpl_draw(
+///       aggregated,
+///       node_size=[100 * group["size"] for group in aggregated.nodes()],
+///       width=[edge["count"] for edge in aggregated.edges()],
+///       with_labels=True,
+///       labels=lambda group: str(group["group"]),
+///   )
+#[pyfunction(default_weight = "1.0")]
+#[pyo3(text_signature = "(graph, groups, /, weight_fn=None, default_weight=1.0)")]
+pub fn graph_aggregate_graph(
+    py: Python,
+    graph: &graph::PyGraph,
+    groups: &PyAny,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+) -> PyResult<graph::PyGraph> {
+    Ok(graph::PyGraph {
+        graph: aggregate_graph(py, &graph.graph, groups, weight_fn, default_weight)?,
+        node_removed: false,
+        multigraph: graph.multigraph,
+        node_keys: NodeKeys::default(),
+        attributes: AttributeColumns::default(),
+        generation: 0,
+    })
+}
+
+/// Aggregate the groups of nodes of a PyDiGraph into the nodes of a smaller
+/// PyDiGraph
+///
+/// This builds the quotient graph of a grouping of the nodes, for example
+/// to draw a graph too large to draw node by node: every group of nodes
+/// becomes a single node, and the edges from the nodes of a group to the
+/// nodes of another group become a single edge between them. The groups are
+/// given either by a partition of the nodes or by a callable returning a key
+/// for every node, such as a node attribute.
+///
+/// The payload of every node of the aggregated graph is a ``dict`` with the
+/// summary of its group:
+///
+/// * ``"group"``: The key of the group, or its position in ``groups`` for a
+///   partition
+/// * ``"nodes"``: The list of the node indices of the group, in order
+/// * ``"size"``: The number of nodes of the group
+/// * ``"internal_edges"``: The number of edges between two nodes of the
+///   group, including self loops
+/// * ``"internal_weight"``: The sum of the weights of these edges
+/// * ``"density"``: The number of internal edges divided by the number of
+///   ordered pairs of distinct nodes of the group, or 0 for a group of less
+///   than 2 nodes. It can exceed 1 if the graph has parallel edges or self
+///   loops.
+///
+/// The payload of every edge of the aggregated graph is a ``dict`` with the
+/// ``"count"`` of the edges from the nodes of its source group to the nodes
+/// of its target group and their total ``"weight"``. The edges are added in
+/// increasing order of their source and target groups, and there's no edge
+/// between groups without an edge between them.
+///
+/// :param PyDiGraph graph: The graph to aggregate
+/// :param groups: Either a partition of the nodes, as a list of lists of node
+///     indices where every node of the graph is in exactly one list, or a
+///     callable that will be passed the payload of every node and is
+///     expected to return the hashable key of its group. The groups from a
+///     callable are in the order of the lowest node index of each group.
+/// :param weight_fn: An optional callable that will be passed the edge's
+///     payload object and is expected to return a ``float`` weight for that
+///     edge.
+/// :param float default_weight: If ``weight_fn`` isn't specified this
+///     optional float value will be used for the weight of every edge.
+///     Defaults to 1.0.
+///
+/// :returns: The aggregated graph, with a node for every group in order
+/// :rtype: PyDiGraph
+/// :raises InvalidNode: If a node index in a partition is not in the graph
+/// :raises ValueError: If a node of the graph is not in exactly one part of
+///     a partition
+#[pyfunction(default_weight = "1.0")]
+#[pyo3(text_signature = "(graph, groups, /, weight_fn=None, default_weight=1.0)")]
+pub fn digraph_aggregate_graph(
+    py: Python,
+    graph: &digraph::PyDiGraph,
+    groups: &PyAny,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+) -> PyResult<digraph::PyDiGraph> {
+    Ok(digraph::PyDiGraph {
+        graph: aggregate_graph(py, &graph.graph, groups, weight_fn, default_weight)?,
+        cycle_state: None,
+        check_cycle: false,
+        node_removed: false,
+        multigraph: graph.multigraph,
+        node_keys: NodeKeys::default(),
+        attributes: AttributeColumns::default(),
+        generation: 0,
+    })
+}
+
+/// A partition of the nodes of a graph found by
+/// :func:`~retworkx.kernighan_lin_bisection` or
+/// :func:`~retworkx.multilevel_partition`
+///
+/// :ivar list parts: The node indices of every part, as a list of lists of
+///     node indices in order. It can be passed as the partition of
+///     :func:`~retworkx.edge_cut_partition` or
+///     :func:`~retworkx.aggregate_graph`.
+/// :ivar float cut_weight: The total weight of the edges between nodes of
+///     different parts
+#[pyclass(module = "retworkx")]
+pub struct GraphPartitioning {
+    #[pyo3(get)]
+    pub parts: Vec<Vec<usize>>,
+    #[pyo3(get)]
+    pub cut_weight: f64,
+}
+
+#[pymethods]
+impl GraphPartitioning {
+    /// The part of every node, as a ``dict`` of node indices to part ids in
+    /// the order of the node indices
+    #[getter]
+    fn assignment(&self) -> DictMap<usize, usize> {
+        let mut assignment: Vec<(usize, usize)> = self
+            .parts
+            .iter()
+            .enumerate()
+            .flat_map(|(part, nodes)| nodes.iter().map(move |node| (*node, part)))
+            .collect();
+        assignment.sort_unstable();
+        assignment.into_iter().collect()
+    }
+
+    fn __len__(&self) -> usize {
+        self.parts.len()
+    }
+}
+
+impl From<partition::GraphPartition<NodeIndex>> for GraphPartitioning {
+    fn from(result: partition::GraphPartition<NodeIndex>) -> Self {
+        GraphPartitioning {
+            parts: result
+                .parts
+                .iter()
+                .map(|part| part.iter().map(|n| n.index()).collect())
+                .collect(),
+            cut_weight: result.cut_weight,
+        }
+    }
+}
+
+fn kernighan_lin_bisection<Ty: EdgeType>(
+    py: Python,
+    graph: &StablePyGraph<Ty>,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+    max_iter: usize,
+    seed: Option<u64>,
+) -> PyResult<GraphPartitioning> {
+    let result = partition::kernighan_lin_bisection(
+        graph,
+        |edge| weight_callable(py, &weight_fn, edge.weight(), default_weight),
+        max_iter,
+        seed,
+    )?;
+    Ok(result.into())
+}
+
+/// Split the nodes of a PyGraph into two parts with a small weight of the
+/// edges between them using the Kernighan-Lin algorithm
+///
+/// The two parts have the same number of nodes, or differ by one node for
+/// an odd number of nodes. Starting from a random bisection, every pass of
+/// the algorithm moves every node to the other part once, alternately from
+/// each part and always the node whose move decreases the weight of the cut
+/// the most, and keeps the moves up to the pair of moves that decreased it
+/// the most. The passes stop when a pass doesn't decrease the weight of the
+/// cut or after ``max_iter`` passes. Self loops are ignored.
+///
+/// This is a heuristic, the bisection found is a local optimum which can
+/// depend on ``seed``.
+///
+/// :param PyGraph graph: The graph to bisect
+/// :param weight_fn: An optional callable that will be passed the edge's
+///     payload object and is expected to return a ``float`` weight for that
+///     edge.
+/// :param float default_weight: If ``weight_fn`` isn't specified this
+///     optional float value will be used for the weight of every edge.
+///     Defaults to 1.0.
+/// :param int max_iter: The maximum number of passes. Defaults to 10.
+/// :param int seed: An optional seed to use for the random number generator
+///     that builds the initial bisection
+///
+/// :returns: The two parts and the weight of the cut
+/// :rtype: GraphPartitioning
+#[pyfunction(default_weight = "1.0", max_iter = "10")]
+#[pyo3(text_signature = "(graph, /, weight_fn=None, default_weight=1.0, max_iter=10, seed=None)")]
+pub fn graph_kernighan_lin_bisection(
+    py: Python,
+    graph: &graph::PyGraph,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+    max_iter: usize,
+    seed: Option<u64>,
+) -> PyResult<GraphPartitioning> {
+    kernighan_lin_bisection(py, &graph.graph, weight_fn, default_weight, max_iter, seed)
+}
+
+/// Split the nodes of a PyDiGraph into two parts with a small weight of the
+/// edges between them using the Kernighan-Lin algorithm
+///
+/// The two parts have the same number of nodes, or differ by one node for
+/// an odd number of nodes. Starting from a random bisection, every pass of
+/// the algorithm moves every node to the other part once, alternately from
+/// each part and always the node whose move decreases the weight of the cut
+/// the most, and keeps the moves up to the pair of moves that decreased it
+/// the most. The passes stop when a pass doesn't decrease the weight of the
+/// cut or after ``max_iter`` passes. The direction of the edges is ignored
+/// and self loops are ignored.
+///
+/// This is a heuristic, the bisection found is a local optimum which can
+/// depend on ``seed``.
+///
+/// :param PyDiGraph graph: The graph to bisect
+/// :param weight_fn: An optional callable that will be passed the edge's
+///     payload object and is expected to return a ``float`` weight for that
+///     edge.
+/// :param float default_weight: If ``weight_fn`` isn't specified this
+///     optional float value will be used for the weight of every edge.
+///     Defaults to 1.0.
+/// :param int max_iter: The maximum number of passes. Defaults to 10.
+/// :param int seed: An optional seed to use for the random number generator
+///     that builds the initial bisection
+///
+/// :returns: The two parts and the weight of the cut
+/// :rtype: GraphPartitioning
+#[pyfunction(default_weight = "1.0", max_iter = "10")]
+#[pyo3(text_signature = "(graph, /, weight_fn=None, default_weight=1.0, max_iter=10, seed=None)")]
+pub fn digraph_kernighan_lin_bisection(
+    py: Python,
+    graph: &digraph::PyDiGraph,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+    max_iter: usize,
+    seed: Option<u64>,
+) -> PyResult<GraphPartitioning> {
+    kernighan_lin_bisection(py, &graph.graph, weight_fn, default_weight, max_iter, seed)
+}
+
+fn multilevel_partition<Ty: EdgeType>(
+    py: Python,
+    graph: &StablePyGraph<Ty>,
+    num_parts: usize,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+    imbalance: f64,
+    seed: Option<u64>,
+) -> PyResult<GraphPartitioning> {
+    let result = partition::multilevel_partition(
+        graph,
+        |edge| weight_callable(py, &weight_fn, edge.weight(), default_weight),
+        num_parts,
+        imbalance,
+        seed,
+    )
+    .map_err(|err| match err {
+        CallbackError::InvalidInput(err) => PyValueError::new_err(err.to_string()),
+        CallbackError::Callback(err) => err,
+    })?;
+    Ok(result.into())
+}
+
+/// Split the nodes of a PyGraph into parts of about the same size with a
+/// small weight of the edges between them using a multilevel algorithm
+///
+/// The graph is coarsened as with :func:`~retworkx.graph_coarsen`, the
+/// coarsest graph is split by growing the parts from random nodes, and the
+/// partition is projected back level by level, moving the nodes at the
+/// boundary of the parts to decrease the weight of the cut at every level.
+/// Self loops are ignored.
+///
+/// The moves keep every part at or below
+/// ``max(ceil(n / num_parts), (1 + imbalance) * n / num_parts)`` nodes for
+/// a graph of ``n`` nodes, and the nodes of larger parts are moved to parts
+/// with room for them, but the balance is not guaranteed. This is a
+/// heuristic, the partition found can depend on ``seed``.
+///
+/// :param PyGraph graph: The graph to partition
+/// :param int num_parts: The number of parts
+/// :param weight_fn: An optional callable that will be passed the edge's
+///     payload object and is expected to return a ``float`` weight for that
+///     edge.
+/// :param float default_weight: If ``weight_fn`` isn't specified this
+///     optional float value will be used for the weight of every edge.
+///     Defaults to 1.0.
+/// :param float imbalance: The fraction of the average part size a part can
+///     exceed it by. Defaults to 0.03.
+/// :param int seed: An optional seed to use for the random number generator
+///
+/// :returns: The parts, some of which can be empty if ``num_parts`` is
+///     larger than the number of nodes, and the weight of the cut
+/// :rtype: GraphPartitioning
+/// :raises ValueError: If ``num_parts`` is 0 or ``imbalance`` is negative
+#[pyfunction(default_weight = "1.0", imbalance = "0.03")]
+#[pyo3(
+    text_signature = "(graph, num_parts, /, weight_fn=None, default_weight=1.0, imbalance=0.03, seed=None)"
+)]
+pub fn graph_multilevel_partition(
+    py: Python,
+    graph: &graph::PyGraph,
+    num_parts: usize,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+    imbalance: f64,
+    seed: Option<u64>,
+) -> PyResult<GraphPartitioning> {
+    multilevel_partition(
+        py,
+        &graph.graph,
+        num_parts,
+        weight_fn,
+        default_weight,
+        imbalance,
+        seed,
+    )
+}
+
+/// Split the nodes of a PyDiGraph into parts of about the same size with a
+/// small weight of the edges between them using a multilevel algorithm
+///
+/// The graph is coarsened as with :func:`~retworkx.digraph_coarsen`, the
+/// coarsest graph is split by growing the parts from random nodes, and the
+/// partition is projected back level by level, moving the nodes at the
+/// boundary of the parts to decrease the weight of the cut at every level.
+/// The direction of the edges is ignored and self loops are ignored.
+///
+/// The moves keep every part at or below
+/// ``max(ceil(n / num_parts), (1 + imbalance) * n / num_parts)`` nodes for
+/// a graph of ``n`` nodes, and the nodes of larger parts are moved to parts
+/// with room for them, but the balance is not guaranteed. This is a
+/// heuristic, the partition found can depend on ``seed``.
+///
+/// :param PyDiGraph graph: The graph to partition
+/// :param int num_parts: The number of parts
+/// :param weight_fn: An optional callable that will be passed the edge's
+///     payload object and is expected to return a ``float`` weight for that
+///     edge.
+/// :param float default_weight: If ``weight_fn`` isn't specified this
+///     optional float value will be used for the weight of every edge.
+///     Defaults to 1.0.
+/// :param float imbalance: The fraction of the average part size a part can
+///     exceed it by. Defaults to 0.03.
+/// :param int seed: An optional seed to use for the random number generator
+///
+/// :returns: The parts, some of which can be empty if ``num_parts`` is
+///     larger than the number of nodes, and the weight of the cut
+/// :rtype: GraphPartitioning
+/// :raises ValueError: If ``num_parts`` is 0 or ``imbalance`` is negative
+#[pyfunction(default_weight = "1.0", imbalance = "0.03")]
+#[pyo3(
+    text_signature = "(graph, num_parts, /, weight_fn=None, default_weight=1.0, imbalance=0.03, seed=None)"
+)]
+pub fn digraph_multilevel_partition(
+    py: Python,
+    graph: &digraph::PyDiGraph,
+    num_parts: usize,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+    imbalance: f64,
+    seed: Option<u64>,
+) -> PyResult<GraphPartitioning> {
+    multilevel_partition(
+        py,
+        &graph.graph,
+        num_parts,
+        weight_fn,
+        default_weight,
+        imbalance,
+        seed,
+    )
+}
@@ -0,0 +1,390 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use std::convert::TryFrom;
+
+use crate::{digraph, graph, CostFn, InvalidNode, StablePyGraph};
+
+use retworkx_core::path_ranking;
+
+use petgraph::prelude::*;
+use petgraph::EdgeType;
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::Python;
+
+/// The paths which pass the filters with their positions in ``paths``,
+/// after checking that they're paths in the graph.
+fn candidate_paths<Ty: EdgeType>(
+    py: Python,
+    graph: &StablePyGraph<Ty>,
+    paths: Vec<Vec<usize>>,
+    cost_fn: &CostFn,
+    node_filter: Option<PyObject>,
+    max_weight: Option<f64>,
+) -> PyResult<(Vec<usize>, Vec<Vec<NodeIndex>>)> {
+    let mut positions = Vec::new();
+    let mut candidates = Vec::new();
+    'paths: for (position, path) in paths.into_iter().enumerate() {
+        let path: Vec<NodeIndex> = path.into_iter().map(NodeIndex::new).collect();
+        for node in &path {
+            if !graph.contains_node(*node) {
+                return Err(InvalidNode::new_err(format!(
+                    "Node index {} is not in the graph",
+                    node.index()
+                )));
+            }
+        }
+        let cost = path_ranking::path_cost(graph, &path, |edge| cost_fn.call(py, edge.weight()))?
+            .ok_or_else(|| {
+            PyValueError::new_err(format!("Path {} is not a path in the graph", position))
+        })?;
+        if let Some(max_weight) = max_weight {
+            if cost > max_weight {
+                continue;
+            }
+        }
+        if let Some(node_filter) = &node_filter {
+            for node in &path {
+                let keep: bool = node_filter.call1(py, (&graph[*node],))?.extract(py)?;
+                if !keep {
+                    continue 'paths;
+                }
+            }
+        }
+        positions.push(position);
+        candidates.push(path);
+    }
+    Ok((positions, candidates))
+}
+
+#[allow(clippy::too_many_arguments)]
+fn rank_paths<Ty: EdgeType>(
+    py: Python,
+    graph: &StablePyGraph<Ty>,
+    paths: Vec<Vec<usize>>,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+    overlap_penalty: f64,
+    node_filter: Option<PyObject>,
+    max_weight: Option<f64>,
+) -> PyResult<Vec<(usize, f64)>> {
+    if overlap_penalty.is_nan() || overlap_penalty < 0. {
+        return Err(PyValueError::new_err(
+            "overlap_penalty must be a non-negative number",
+        ));
+    }
+    let cost_fn = CostFn::try_from((weight_fn, default_weight))?;
+    let (positions, candidates) =
+        candidate_paths(py, graph, paths, &cost_fn, node_filter, max_weight)?;
+    let ranking = path_ranking::rank_paths(graph, &candidates, overlap_penalty, |edge| {
+        cost_fn.call(py, edge.weight())
+    })?;
+    Ok(ranking
+        .into_iter()
+        .map(|(path, score)| (positions[path], score))
+        .collect())
+}
+
+#[allow(clippy::too_many_arguments)]
+fn diverse_paths<Ty: EdgeType>(
+    py: Python,
+    graph: &StablePyGraph<Ty>,
+    paths: Vec<Vec<usize>>,
+    k: usize,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+    node_filter: Option<PyObject>,
+    max_weight: Option<f64>,
+) -> PyResult<Vec<usize>> {
+    let cost_fn = CostFn::try_from((weight_fn, default_weight))?;
+    let (positions, candidates) =
+        candidate_paths(py, graph, paths, &cost_fn, node_filter, max_weight)?;
+    let selected = path_ranking::diverse_paths(graph, &candidates, k, |edge| {
+        cost_fn.call(py, edge.weight())
+    })?;
+    Ok(selected.into_iter().map(|path| positions[path]).collect())
+}
+
+/// Rank a set of paths in a PyGraph by their total weight, penalizing paths
+/// which overlap
+///
+/// This can be used to post-process a set of paths, such as the output of
+/// :func:`~retworkx.all_simple_paths` or a list of the paths in a
+/// :class:`~retworkx.PathMapping`. The paths are ranked greedily: the next
+/// path in the ranking is the one with the lowest score among the paths not
+/// ranked yet, where the score of a path is its total weight plus
+/// ``overlap_penalty`` times its largest similarity with a path ranked
+/// before it. The similarity of two paths is the number of edges they share
+/// divided by the number of edges in either of them, so with an
+/// ``overlap_penalty`` of 0 this is the order of the path weights, while a
+/// large ``overlap_penalty`` moves paths that are almost the same as a
+/// better path towards the end of the ranking.
+///
+/// :param PyGraph graph: The graph the paths are in
+/// :param list paths: The paths to rank, as a list of the node indices of
+///     each path in order
+/// :param weight_fn: An optional callable that will be passed the edge's
+///     payload object and is expected to return a non-negative ``float``
+///     weight for that edge. When there are parallel edges between two
+///     consecutive nodes of a path the lightest one is used.
+/// :param float default_weight: If ``weight_fn`` isn't specified this
+///     optional float value will be used for the weight of every edge.
+///     Defaults to 1.0.
+/// :param float overlap_penalty: The non-negative penalty of a path which
+///     uses the same edges as a path ranked before it. Defaults to 0.0.
+/// :param node_filter: An optional callable that will be passed the payload
+///     of a node and returns ``True`` if paths can go through the node. The
+///     paths with a node it returns ``False`` for are left out of the
+///     ranking.
+/// :param float max_weight: An optional maximum total weight of a path,
+///     the heavier paths are left out of the ranking
+///
+/// :returns: A list of ``(position, score)`` tuples for the ranked paths,
+///     where ``position`` is the position of the path in ``paths``. Ties
+///     are broken by the position.
+/// :rtype: list
+/// :raises InvalidNode: If a node of a path is not in the graph
+/// :raises ValueError: If there is no edge between two consecutive nodes of
+///     a path or ``overlap_penalty`` is negative
+///
+/// .. jupyter-execute::
+///
+///   import retworkx
+///
+///   graph = retworkx.generators.grid_graph(3, 3)
+///   paths = retworkx.all_simple_paths(graph, 0, 8, cutoff=5)
+///   ranking = retworkx.graph_rank_paths(graph, paths, overlap_penalty=2)
+///   print([(list(paths[position]), score) for position, score in ranking[:3]])
+#[pyfunction(default_weight = "1.0", overlap_penalty = "0.0")]
+#[pyo3(
+    text_signature = "(graph, paths, /, weight_fn=None, default_weight=1.0, overlap_penalty=0.0, node_filter=None, max_weight=None)"
+)]
+#[allow(clippy::too_many_arguments)]
+pub fn graph_rank_paths(
+    py: Python,
+    graph: &graph::PyGraph,
+    paths: Vec<Vec<usize>>,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+    overlap_penalty: f64,
+    node_filter: Option<PyObject>,
+    max_weight: Option<f64>,
+) -> PyResult<Vec<(usize, f64)>> {
+    rank_paths(
+        py,
+        &graph.graph,
+        paths,
+        weight_fn,
+        default_weight,
+        overlap_penalty,
+        node_filter,
+        max_weight,
+    )
+}
+
+/// Rank a set of paths in a PyDiGraph by their total weight, penalizing
+/// paths which overlap
+///
+/// This can be used to post-process a set of paths, such as the output of
+/// :func:`~retworkx.all_simple_paths` or a list of the paths in a
+/// :class:`~retworkx.PathMapping`. The paths are ranked greedily: the next
+/// path in the ranking is the one with the lowest score among the paths not
+/// ranked yet, where the score of a path is its total weight plus
+/// ``overlap_penalty`` times its largest similarity with a path ranked
+/// before it. The similarity of two paths is the number of edges they share
+/// divided by the number of edges in either of them, so with an
+/// ``overlap_penalty`` of 0 this is the order of the path weights, while a
+/// large ``overlap_penalty`` moves paths that are almost the same as a
+/// better path towards the end of the ranking.
+///
+/// :param PyDiGraph graph: The graph the paths are in
+/// :param list paths: The paths to rank, as a list of the node indices of
+///     each path in order following the direction of the edges
+/// :param weight_fn: An optional callable that will be passed the edge's
+///     payload object and is expected to return a non-negative ``float``
+///     weight for that edge. When there are parallel edges between two
+///     consecutive nodes of a path the lightest one is used.
+/// :param float default_weight: If ``weight_fn`` isn't specified this
+///     optional float value will be used for the weight of every edge.
+///     Defaults to 1.0.
+/// :param float overlap_penalty: The non-negative penalty of a path which
+///     uses the same edges as a path ranked before it. Defaults to 0.0.
+/// :param node_filter: An optional callable that will be passed the payload
+///     of a node and returns ``True`` if paths can go through the node. The
+///     paths with a node it returns ``False`` for are left out of the
+///     ranking.
+/// :param float max_weight: An optional maximum total weight of a path,
+///     the heavier paths are left out of the ranking
+///
+/// :returns: A list of ``(position, score)`` tuples for the ranked paths,
+///     where ``position`` is the position of the path in ``paths``. Ties
+///     are broken by the position.
+/// :rtype: list
+/// :raises InvalidNode: If a node of a path is not in the graph
+/// :raises ValueError: If there is no edge from a node of a path to the next
+///     one or ``overlap_penalty`` is negative
+#[pyfunction(default_weight = "1.0", overlap_penalty = "0.0")]
+#[pyo3(
+    text_signature = "(graph, paths, /, weight_fn=None, default_weight=1.0, overlap_penalty=0.0, node_filter=None, max_weight=None)"
+)]
+#[allow(clippy::too_many_arguments)]
+pub fn digraph_rank_paths(
+    py: Python,
+    graph: &digraph::PyDiGraph,
+    paths: Vec<Vec<usize>>,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+    overlap_penalty: f64,
+    node_filter: Option<PyObject>,
+    max_weight: Option<f64>,
+) -> PyResult<Vec<(usize, f64)>> {
+    rank_paths(
+        py,
+        &graph.graph,
+        paths,
+        weight_fn,
+        default_weight,
+        overlap_penalty,
+        node_filter,
+        max_weight,
+    )
+}
+
+/// Select a subset of diverse paths from a set of paths in a PyGraph
+///
+/// The paths are selected greedily, starting with the lightest path. The
+/// next path selected is the one whose largest similarity with the paths
+/// already selected is the smallest, so every new path is as different as
+/// possible from the previous ones, with ties broken by the lowest total
+/// weight and then the position in ``paths``. The similarity of two paths
+/// is the number of edges they share divided by the number of edges in
+/// either of them.
+///
+/// :param PyGraph graph: The graph the paths are in
+/// :param list paths: The paths to select from, as a list of the node
+///     indices of each path in order
+/// :param int k: The number of paths to select
+/// :param weight_fn: An optional callable that will be passed the edge's
+///     payload object and is expected to return a non-negative ``float``
+///     weight for that edge. When there are parallel edges between two
+///     consecutive nodes of a path the lightest one is used.
+/// :param float default_weight: If ``weight_fn`` isn't specified this
+///     optional float value will be used for the weight of every edge.
+///     Defaults to 1.0.
+/// :param node_filter: An optional callable that will be passed the payload
+///     of a node and returns ``True`` if paths can go through the node. The
+///     paths with a node it returns ``False`` for can't be selected.
+/// :param float max_weight: An optional maximum total weight of a path,
+///     the heavier paths can't be selected
+///
+/// :returns: The positions in ``paths`` of the selected paths in the order
+///     they were selected, fewer than ``k`` if there aren't enough paths
+/// :rtype: list
+/// :raises InvalidNode: If a node of a path is not in the graph
+/// :raises ValueError: If there is no edge between two consecutive nodes of
+///     a path
+///
+/// .. jupyter-execute::
+///
+///   import retworkx
+///
+///   graph = retworkx.generators.grid_graph(3, 3)
+///   paths = retworkx.all_simple_paths(graph, 0, 8, cutoff=5)
+///   print([list(paths[position]) for position in retworkx.graph_diverse_paths(graph, paths, 2)])
+#[pyfunction(default_weight = "1.0")]
+#[pyo3(
+    text_signature = "(graph, paths, k, /, weight_fn=None, default_weight=1.0, node_filter=None, max_weight=None)"
+)]
+#[allow(clippy::too_many_arguments)]
+pub fn graph_diverse_paths(
+    py: Python,
+    graph: &graph::PyGraph,
+    paths: Vec<Vec<usize>>,
+    k: usize,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+    node_filter: Option<PyObject>,
+    max_weight: Option<f64>,
+) -> PyResult<Vec<usize>> {
+    diverse_paths(
+        py,
+        &graph.graph,
+        paths,
+        k,
+        weight_fn,
+        default_weight,
+        node_filter,
+        max_weight,
+    )
+}
+
+/// Select a subset of diverse paths from a set of paths in a PyDiGraph
+///
+/// The paths are selected greedily, starting with the lightest path. The
+/// next path selected is the one whose largest similarity with the paths
+/// already selected is the smallest, so every new path is as different as
+/// possible from the previous ones, with ties broken by the lowest total
+/// weight and then the position in ``paths``. The similarity of two paths
+/// is the number of edges they share divided by the number of edges in
+/// either of them.
+///
+/// :param PyDiGraph graph: The graph the paths are in
+/// :param list paths: The paths to select from, as a list of the node
+///     indices of each path in order following the direction of the edges
+/// :param int k: The number of paths to select
+/// :param weight_fn: An optional callable that will be passed the edge's
+///     payload object and is expected to return a non-negative ``float``
+///     weight for that edge. When there are parallel edges between two
+///     consecutive nodes of a path the lightest one is used.
+/// :param float default_weight: If ``weight_fn`` isn't specified this
+///     optional float value will be used for the weight of every edge.
+///     Defaults to 1.0.
+/// :param node_filter: An optional callable that will be passed the payload
+///     of a node and returns ``True`` if paths can go through the node. The
+///     paths with a node it returns ``False`` for can't be selected.
+/// :param float max_weight: An optional maximum total weight of a path,
+///     the heavier paths can't be selected
+///
+/// :returns: The positions in ``paths`` of the selected paths in the order
+///     they were selected, fewer than ``k`` if there aren't enough paths
+/// :rtype: list
+/// :raises InvalidNode: If a node of a path is not in the graph
+/// :raises ValueError: If there is no edge from a node of a path to the next
+///     one
+#[pyfunction(default_weight = "1.0")]
+#[pyo3(
+    text_signature = "(graph, paths, k, /, weight_fn=None, default_weight=1.0, node_filter=None, max_weight=None)"
+)]
+#[allow(clippy::too_many_arguments)]
+pub fn digraph_diverse_paths(
+    py: Python,
+    graph: &digraph::PyDiGraph,
+    paths: Vec<Vec<usize>>,
+    k: usize,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+    node_filter: Option<PyObject>,
+    max_weight: Option<f64>,
+) -> PyResult<Vec<usize>> {
+    diverse_paths(
+        py,
+        &graph.graph,
+        paths,
+        k,
+        weight_fn,
+        default_weight,
+        node_filter,
+        max_weight,
+    )
+}
@@ -0,0 +1,206 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use petgraph::EdgeType;
+
+use pyo3::prelude::*;
+
+use retworkx_core::dictmap::*;
+use retworkx_core::planar::{self, KuratowskiKind, Planarity};
+
+use crate::{digraph, graph, StablePyGraph};
+
+/// The result of a planarity check
+///
+/// This class is returned by :func:`~retworkx.check_planarity`. Its truth
+/// value is whether the graph is planar, so it can be used directly in a
+/// condition.
+///
+/// :ivar bool is_planar: Whether the graph is planar
+/// :ivar dict embedding: If the graph is planar, a combinatorial embedding
+///     of it as a dictionary mapping the index of every node to the indices
+///     of its neighbors in the clockwise order of a planar drawing, otherwise
+///     ``None``
+/// :ivar list kuratowski_edges: If the graph isn't planar, the edges of a
+///     Kuratowski subgraph as a list of ``(source, target)`` tuples of node
+///     indices, otherwise ``None``
+/// :ivar str kuratowski_type: If the graph isn't planar, the type of the
+///     Kuratowski subgraph, ``"K5"`` for a subdivision of the complete graph
+///     on 5 nodes or ``"K3,3"`` for a subdivision of the complete bipartite
+///     graph on 3 and 3 nodes, otherwise ``None``
+#[pyclass(module = "retworkx")]
+pub struct PlanarityResult {
+    #[pyo3(get)]
+    pub is_planar: bool,
+    #[pyo3(get)]
+    pub embedding: Option<DictMap<usize, Vec<usize>>>,
+    #[pyo3(get)]
+    pub kuratowski_edges: Option<Vec<(usize, usize)>>,
+    #[pyo3(get)]
+    pub kuratowski_type: Option<String>,
+}
+
+#[pymethods]
+impl PlanarityResult {
+    fn __bool__(&self) -> bool {
+        self.is_planar
+    }
+}
+
+fn check_planarity<Ty: EdgeType>(graph: &StablePyGraph<Ty>) -> PlanarityResult {
+    match planar::check_planarity(graph) {
+        Planarity::Planar(embedding) => PlanarityResult {
+            is_planar: true,
+            embedding: Some(
+                embedding
+                    .into_iter()
+                    .map(|(node, neighbors)| {
+                        (
+                            node.index(),
+                            neighbors.into_iter().map(|node| node.index()).collect(),
+                        )
+                    })
+                    .collect(),
+            ),
+            kuratowski_edges: None,
+            kuratowski_type: None,
+        },
+        Planarity::NonPlanar { kind, edges } => PlanarityResult {
+            is_planar: false,
+            embedding: None,
+            kuratowski_edges: Some(
+                edges
+                    .into_iter()
+                    .map(|(source, target)| (source.index(), target.index()))
+                    .collect(),
+            ),
+            kuratowski_type: Some(
+                match kind {
+                    KuratowskiKind::K5 => "K5",
+                    KuratowskiKind::K33 => "K3,3",
+                }
+                .to_string(),
+            ),
+        },
+    }
+}
+
+/// Return whether a :class:`~retworkx.PyGraph` is planar
+///
+/// A graph is planar if it can be drawn in the plane without any edges
+/// crossing. This uses the left-right planarity test [1]_, which runs in
+/// linear time (up to sorting the adjacency lists). Use
+/// :func:`~retworkx.graph_check_planarity` to also get an embedding or a
+/// proof that the graph isn't planar.
+///
+/// :param PyGraph graph: The graph to check
+///
+/// :returns: ``True`` if the graph is planar, ``False`` otherwise
+/// :rtype: bool
+///
+/// .. [1] Ulrik Brandes, "The Left-Right Planarity Test", 2009,
+///     http://citeseerx.ist.psu.edu/viewdoc/summary?doi=10.1.1.217.9208
+#[pyfunction]
+#[pyo3(text_signature = "(graph, /)")]
+pub fn graph_is_planar(graph: &graph::PyGraph) -> bool {
+    planar::is_planar(&graph.graph)
+}
+
+/// Return whether a :class:`~retworkx.PyDiGraph` is planar
+///
+/// A graph is planar if it can be drawn in the plane without any edges
+/// crossing, the direction of the edges is ignored. This uses the
+/// left-right planarity test [1]_, which runs in linear time (up to sorting
+/// the adjacency lists). Use :func:`~retworkx.digraph_check_planarity` to
+/// also get an embedding or a proof that the graph isn't planar.
+///
+/// :param PyDiGraph graph: The graph to check
+///
+/// :returns: ``True`` if the graph is planar, ``False`` otherwise
+/// :rtype: bool
+///
+/// .. [1] Ulrik Brandes, "The Left-Right Planarity Test", 2009,
+///     http://citeseerx.ist.psu.edu/viewdoc/summary?doi=10.1.1.217.9208
+#[pyfunction]
+#[pyo3(text_signature = "(graph, /)")]
+pub fn digraph_is_planar(graph: &digraph::PyDiGraph) -> bool {
+    planar::is_planar(&graph.graph)
+}
+
+/// Check whether a :class:`~retworkx.PyGraph` is planar, with a certificate
+///
+/// If the graph is planar, the result has a combinatorial embedding of the
+/// graph: the neighbors of every node in the clockwise order of a planar
+/// drawing, which can be used to draw the graph without crossings. If it
+/// isn't planar, the result has the edges of a Kuratowski subgraph, a
+/// subdivision of either :math:`K_5` or :math:`K_{3,3}`, which by
+/// Kuratowski's theorem proves that the graph isn't planar. Self loops and
+/// parallel edges are ignored, so they're not part of the embedding.
+///
+/// The embedding is found in linear time (up to sorting the adjacency
+/// lists) with the left-right planarity test [1]_. Finding the Kuratowski
+/// subgraph takes a planarity test for each of its edges and a logarithmic
+/// number of tests for each other edge, so it can be much slower.
+///
+/// :param PyGraph graph: The graph to check
+///
+/// :returns: The result of the check, with either the embedding or the
+///     Kuratowski subgraph
+/// :rtype: PlanarityResult
+///
+/// .. jupyter-execute::
+///
+///   import retworkx
+///
+///   graph = retworkx.generators.generalized_petersen_graph(5, 2)
+///   result = retworkx.graph_check_planarity(graph)
+///   print(result.is_planar, result.kuratowski_type)
+///   print(result.kuratowski_edges)
+///
+/// .. [1] Ulrik Brandes, "The Left-Right Planarity Test", 2009,
+///     http://citeseerx.ist.psu.edu/viewdoc/summary?doi=10.1.1.217.9208
+#[pyfunction]
+#[pyo3(text_signature = "(graph, /)")]
+pub fn graph_check_planarity(graph: &graph::PyGraph) -> PlanarityResult {
+    check_planarity(&graph.graph)
+}
+
+/// Check whether a :class:`~retworkx.PyDiGraph` is planar, with a
+/// certificate
+///
+/// The direction of the edges is ignored. If the graph is planar, the
+/// result has a combinatorial embedding of the graph: the neighbors of
+/// every node in the clockwise order of a planar drawing, which can be used
+/// to draw the graph without crossings. If it isn't planar, the result has
+/// the edges of a Kuratowski subgraph, a subdivision of either :math:`K_5`
+/// or :math:`K_{3,3}`, which by Kuratowski's theorem proves that the graph
+/// isn't planar. Self loops and parallel edges are ignored, so they're not
+/// part of the embedding.
+///
+/// The embedding is found in linear time (up to sorting the adjacency
+/// lists) with the left-right planarity test [1]_. Finding the Kuratowski
+/// subgraph takes a planarity test for each of its edges and a logarithmic
+/// number of tests for each other edge, so it can be much slower.
+///
+/// :param PyDiGraph graph: The graph to check
+///
+/// :returns: The result of the check, with either the embedding or the
+///     Kuratowski subgraph
+/// :rtype: PlanarityResult
+///
+/// .. [1] Ulrik Brandes, "The Left-Right Planarity Test", 2009,
+///     http://citeseerx.ist.psu.edu/viewdoc/summary?doi=10.1.1.217.9208
+#[pyfunction]
+#[pyo3(text_signature = "(graph, /)")]
+pub fn digraph_check_planarity(graph: &digraph::PyDiGraph) -> PlanarityResult {
+    check_planarity(&graph.graph)
+}
@@ -12,21 +12,63 @@
 
 #![allow(clippy::float_cmp)]
 
+use crate::attributes::AttributeColumns;
+use crate::node_keys::NodeKeys;
 use crate::{digraph, graph, StablePyGraph};
 
+use hashbrown::HashSet;
+
 use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
-use pyo3::types::PyDict;
+use pyo3::types::{PyDict, PyLong};
 use pyo3::Python;
 
-use petgraph::algo;
 use petgraph::graph::NodeIndex;
 use petgraph::prelude::*;
+use petgraph::EdgeType;
 
 use rand::distributions::{Distribution, Uniform};
 use rand::prelude::*;
 use rand_pcg::Pcg64;
 
+use retworkx_core::generators as core_generators;
+use retworkx_core::generators::InvalidInputError;
+
+/// Wrap an optional Python edge filter callable for the generators in
+/// retworkx-core. The first exception raised by the callable is stored in
+/// ``error``, after which every edge is accepted so the generator finishes as
+/// fast as possible since its output will be discarded.
+fn edge_filter_fn<'a>(
+    py: Python<'a>,
+    edge_filter: &'a Option<PyObject>,
+    error: &'a mut Option<PyErr>,
+) -> impl FnMut(usize, usize) -> bool + 'a {
+    move |u, v| match edge_filter {
+        Some(edge_filter) if error.is_none() => {
+            match edge_filter
+                .call1(py, (u, v))
+                .and_then(|res| res.as_ref(py).is_true())
+            {
+                Ok(accept) => accept,
+                Err(err) => {
+                    *error = Some(err);
+                    true
+                }
+            }
+        }
+        _ => true,
+    }
+}
+
+/// Replace the node weights of a generated graph by the node indices, which
+/// is what the generators have always used as node payloads.
+fn index_node_weights<Ty: EdgeType>(py: Python, graph: &mut StablePyGraph<Ty>) {
+    let nodes: Vec<NodeIndex> = graph.node_indices().collect();
+    for node in nodes {
+        graph[node] = node.index().to_object(py);
+    }
+}
+
 /// Return a :math:`G_{np}` directed random graph, also known as an
 /// Erdős-Rényi graph or a binomial graph.
 ///
@@ -48,6 +90,11 @@ use rand_pcg::Pcg64;
 /// :param int num_nodes: The number of nodes to create in the graph
 /// :param float probability: The probability of creating an edge between two nodes
 /// :param int seed: An optional seed to use for the random number generator
+/// :param callable edge_filter: An optional callable which is called with the
+///     source and target node indices of every edge drawn, before it is
+///     created. The edge is only added to the graph if it returns ``True``,
+///     which makes it possible to enforce constraints on the generated edges
+///     without materializing the rejected ones.
 ///
 /// :return: A PyDiGraph object
 /// :rtype: PyDiGraph
@@ -57,80 +104,48 @@ use rand_pcg::Pcg64;
 ///    Phys. Rev. E, 71, 036113, 2005.
 /// .. [2] https://github.com/networkx/networkx/blob/networkx-2.4/networkx/generators/random_graphs.py#L49-L120
 #[pyfunction]
-#[pyo3(text_signature = "(num_nodes, probability, seed=None, /)")]
+#[pyo3(text_signature = "(num_nodes, probability, /, seed=None, edge_filter=None)")]
 pub fn directed_gnp_random_graph(
     py: Python,
     num_nodes: isize,
     probability: f64,
     seed: Option<u64>,
+    edge_filter: Option<PyObject>,
 ) -> PyResult<digraph::PyDiGraph> {
     if num_nodes <= 0 {
         return Err(PyValueError::new_err("num_nodes must be > 0"));
     }
-    let mut rng: Pcg64 = match seed {
-        Some(seed) => Pcg64::seed_from_u64(seed),
-        None => Pcg64::from_entropy(),
-    };
-    let mut inner_graph = StablePyGraph::<Directed>::new();
-    for x in 0..num_nodes {
-        inner_graph.add_node(x.to_object(py));
-    }
     if !(0.0..=1.0).contains(&probability) {
         return Err(PyValueError::new_err(
             "Probability out of range, must be 0 <= p <= 1",
         ));
     }
-    if probability > 0.0 {
-        if (probability - 1.0).abs() < std::f64::EPSILON {
-            for u in 0..num_nodes {
-                for v in 0..num_nodes {
-                    if u != v {
-                        // exclude self-loops
-                        let u_index = NodeIndex::new(u as usize);
-                        let v_index = NodeIndex::new(v as usize);
-                        inner_graph.add_edge(u_index, v_index, py.None());
-                    }
-                }
-            }
-        } else {
-            let mut v: isize = 0;
-            let mut w: isize = -1;
-            let lp: f64 = (1.0 - probability).ln();
-
-            let between = Uniform::new(0.0, 1.0);
-            while v < num_nodes {
-                let random: f64 = between.sample(&mut rng);
-                let lr: f64 = (1.0 - random).ln();
-                let ratio: isize = (lr / lp) as isize;
-                w = w + 1 + ratio;
-                // avoid self loops
-                if v == w {
-                    w += 1;
-                }
-                while v < num_nodes && num_nodes <= w {
-                    w -= v;
-                    v += 1;
-                    // avoid self loops
-                    if v == w {
-                        w -= v;
-                        v += 1;
-                    }
-                }
-                if v < num_nodes {
-                    let v_index = NodeIndex::new(v as usize);
-                    let w_index = NodeIndex::new(w as usize);
-                    inner_graph.add_edge(v_index, w_index, py.None());
-                }
-            }
-        }
+    let mut filter_error: Option<PyErr> = None;
+    let mut inner_graph: StablePyGraph<Directed> = match core_generators::gnp_random_graph(
+        num_nodes as usize,
+        probability,
+        seed,
+        || py.None(),
+        || py.None(),
+        edge_filter_fn(py, &edge_filter, &mut filter_error),
+    ) {
+        Ok(graph) => graph,
+        Err(err) => return Err(PyValueError::new_err(err.to_string())),
+    };
+    if let Some(err) = filter_error {
+        return Err(err);
     }
+    index_node_weights(py, &mut inner_graph);
 
     let graph = digraph::PyDiGraph {
         graph: inner_graph,
-        cycle_state: algo::DfsSpace::default(),
+        cycle_state: None,
         check_cycle: false,
         node_removed: false,
         multigraph: true,
+        node_keys: NodeKeys::default(),
+        attributes: AttributeColumns::default(),
+        generation: 0,
     };
     Ok(graph)
 }
@@ -156,6 +171,11 @@ pub fn directed_gnp_random_graph(
 /// :param int num_nodes: The number of nodes to create in the graph
 /// :param float probability: The probability of creating an edge between two nodes
 /// :param int seed: An optional seed to use for the random number generator
+/// :param callable edge_filter: An optional callable which is called with the
+///     source and target node indices of every edge drawn, before it is
+///     created. The edge is only added to the graph if it returns ``True``,
+///     which makes it possible to enforce constraints on the generated edges
+///     without materializing the rejected ones.
 ///
 /// :return: A PyGraph object
 /// :rtype: PyGraph
@@ -165,66 +185,46 @@ pub fn directed_gnp_random_graph(
 ///    Phys. Rev. E, 71, 036113, 2005.
 /// .. [2] https://github.com/networkx/networkx/blob/networkx-2.4/networkx/generators/random_graphs.py#L49-L120
 #[pyfunction]
-#[pyo3(text_signature = "(num_nodes, probability, seed=None, /)")]
+#[pyo3(text_signature = "(num_nodes, probability, /, seed=None, edge_filter=None)")]
 pub fn undirected_gnp_random_graph(
     py: Python,
     num_nodes: isize,
     probability: f64,
     seed: Option<u64>,
+    edge_filter: Option<PyObject>,
 ) -> PyResult<graph::PyGraph> {
     if num_nodes <= 0 {
         return Err(PyValueError::new_err("num_nodes must be > 0"));
     }
-    let mut rng: Pcg64 = match seed {
-        Some(seed) => Pcg64::seed_from_u64(seed),
-        None => Pcg64::from_entropy(),
-    };
-    let mut inner_graph = StablePyGraph::<Undirected>::default();
-    for x in 0..num_nodes {
-        inner_graph.add_node(x.to_object(py));
-    }
     if !(0.0..=1.0).contains(&probability) {
         return Err(PyValueError::new_err(
             "Probability out of range, must be 0 <= p <= 1",
         ));
     }
-    if probability > 0.0 {
-        if (probability - 1.0).abs() < std::f64::EPSILON {
-            for u in 0..num_nodes {
-                for v in u + 1..num_nodes {
-                    let u_index = NodeIndex::new(u as usize);
-                    let v_index = NodeIndex::new(v as usize);
-                    inner_graph.add_edge(u_index, v_index, py.None());
-                }
-            }
-        } else {
-            let mut v: isize = 1;
-            let mut w: isize = -1;
-            let lp: f64 = (1.0 - probability).ln();
-
-            let between = Uniform::new(0.0, 1.0);
-            while v < num_nodes {
-                let random: f64 = between.sample(&mut rng);
-                let lr = (1.0 - random).ln();
-                let ratio: isize = (lr / lp) as isize;
-                w = w + 1 + ratio;
-                while w >= v && v < num_nodes {
-                    w -= v;
-                    v += 1;
-                }
-                if v < num_nodes {
-                    let v_index = NodeIndex::new(v as usize);
-                    let w_index = NodeIndex::new(w as usize);
-                    inner_graph.add_edge(v_index, w_index, py.None());
-                }
-            }
-        }
+    let mut filter_error: Option<PyErr> = None;
+    let mut inner_graph: StablePyGraph<Undirected> = match core_generators::gnp_random_graph(
+        num_nodes as usize,
+        probability,
+        seed,
+        || py.None(),
+        || py.None(),
+        edge_filter_fn(py, &edge_filter, &mut filter_error),
+    ) {
+        Ok(graph) => graph,
+        Err(err) => return Err(PyValueError::new_err(err.to_string())),
+    };
+    if let Some(err) = filter_error {
+        return Err(err);
     }
+    index_node_weights(py, &mut inner_graph);
 
     let graph = graph::PyGraph {
         graph: inner_graph,
         node_removed: false,
         multigraph: true,
+        node_keys: NodeKeys::default(),
+        attributes: AttributeColumns::default(),
+        generation: 0,
     };
     Ok(graph)
 }
@@ -246,17 +246,23 @@ pub fn undirected_gnp_random_graph(
 /// :param int num_nodes: The number of nodes to create in the graph
 /// :param int num_edges: The number of edges to create in the graph
 /// :param int seed: An optional seed to use for the random number generator
+/// :param callable edge_filter: An optional callable which is called with the
+///     source and target node indices of every edge drawn, before it is
+///     created. The edge is only added to the graph if it returns ``True``,
+///     otherwise another edge is drawn. If fewer than ``num_edges`` edges are
+///     accepted by ``edge_filter`` the graph will contain all of them.
 ///
 /// :return: A PyDiGraph object
 /// :rtype: PyDiGraph
 ///
 #[pyfunction]
-#[pyo3(text_signature = "(num_nodes, num_edges, /, seed=None)")]
+#[pyo3(text_signature = "(num_nodes, num_edges, /, seed=None, edge_filter=None)")]
 pub fn directed_gnm_random_graph(
     py: Python,
     num_nodes: isize,
     num_edges: isize,
     seed: Option<u64>,
+    edge_filter: Option<PyObject>,
 ) -> PyResult<digraph::PyDiGraph> {
     if num_nodes <= 0 {
         return Err(PyValueError::new_err("num_nodes must be > 0"));
@@ -264,48 +270,32 @@ pub fn directed_gnm_random_graph(
     if num_edges < 0 {
         return Err(PyValueError::new_err("num_edges must be >= 0"));
     }
-    let mut rng: Pcg64 = match seed {
-        Some(seed) => Pcg64::seed_from_u64(seed),
-        None => Pcg64::from_entropy(),
+    let mut filter_error: Option<PyErr> = None;
+    let mut inner_graph: StablePyGraph<Directed> = match core_generators::gnm_random_graph(
+        num_nodes as usize,
+        num_edges as usize,
+        seed,
+        || py.None(),
+        || py.None(),
+        edge_filter_fn(py, &edge_filter, &mut filter_error),
+    ) {
+        Ok(graph) => graph,
+        Err(err) => return Err(PyValueError::new_err(err.to_string())),
     };
-    let mut inner_graph = StablePyGraph::<Directed>::new();
-    for x in 0..num_nodes {
-        inner_graph.add_node(x.to_object(py));
-    }
-    // if number of edges to be created is >= max,
-    // avoid randomly missed trials and directly add edges between every node
-    if num_edges >= num_nodes * (num_nodes - 1) {
-        for u in 0..num_nodes {
-            for v in 0..num_nodes {
-                // avoid self-loops
-                if u != v {
-                    let u_index = NodeIndex::new(u as usize);
-                    let v_index = NodeIndex::new(v as usize);
-                    inner_graph.add_edge(u_index, v_index, py.None());
-                }
-            }
-        }
-    } else {
-        let mut created_edges: isize = 0;
-        let between = Uniform::new(0, num_nodes);
-        while created_edges < num_edges {
-            let u = between.sample(&mut rng);
-            let v = between.sample(&mut rng);
-            let u_index = NodeIndex::new(u as usize);
-            let v_index = NodeIndex::new(v as usize);
-            // avoid self-loops and multi-graphs
-            if u != v && inner_graph.find_edge(u_index, v_index).is_none() {
-                inner_graph.add_edge(u_index, v_index, py.None());
-                created_edges += 1;
-            }
-        }
+    if let Some(err) = filter_error {
+        return Err(err);
     }
+    index_node_weights(py, &mut inner_graph);
+
     let graph = digraph::PyDiGraph {
         graph: inner_graph,
-        cycle_state: algo::DfsSpace::default(),
+        cycle_state: None,
         check_cycle: false,
         node_removed: false,
         multigraph: true,
+        node_keys: NodeKeys::default(),
+        attributes: AttributeColumns::default(),
+        generation: 0,
     };
     Ok(graph)
 }
@@ -327,17 +317,23 @@ pub fn directed_gnm_random_graph(
 /// :param int num_nodes: The number of nodes to create in the graph
 /// :param int num_edges: The number of edges to create in the graph
 /// :param int seed: An optional seed to use for the random number generator
+/// :param callable edge_filter: An optional callable which is called with the
+///     source and target node indices of every edge drawn, before it is
+///     created. The edge is only added to the graph if it returns ``True``,
+///     otherwise another edge is drawn. If fewer than ``num_edges`` edges are
+///     accepted by ``edge_filter`` the graph will contain all of them.
 ///
 /// :return: A PyGraph object
 /// :rtype: PyGraph
 
 #[pyfunction]
-#[pyo3(text_signature = "(num_nodes, num_edges, /, seed=None)")]
+#[pyo3(text_signature = "(num_nodes, num_edges, /, seed=None, edge_filter=None)")]
 pub fn undirected_gnm_random_graph(
     py: Python,
     num_nodes: isize,
     num_edges: isize,
     seed: Option<u64>,
+    edge_filter: Option<PyObject>,
 ) -> PyResult<graph::PyGraph> {
     if num_nodes <= 0 {
         return Err(PyValueError::new_err("num_nodes must be > 0"));
@@ -345,43 +341,30 @@ pub fn undirected_gnm_random_graph(
     if num_edges < 0 {
         return Err(PyValueError::new_err("num_edges must be >= 0"));
     }
-    let mut rng: Pcg64 = match seed {
-        Some(seed) => Pcg64::seed_from_u64(seed),
-        None => Pcg64::from_entropy(),
+    let mut filter_error: Option<PyErr> = None;
+    let mut inner_graph: StablePyGraph<Undirected> = match core_generators::gnm_random_graph(
+        num_nodes as usize,
+        num_edges as usize,
+        seed,
+        || py.None(),
+        || py.None(),
+        edge_filter_fn(py, &edge_filter, &mut filter_error),
+    ) {
+        Ok(graph) => graph,
+        Err(err) => return Err(PyValueError::new_err(err.to_string())),
     };
-    let mut inner_graph = StablePyGraph::<Undirected>::default();
-    for x in 0..num_nodes {
-        inner_graph.add_node(x.to_object(py));
-    }
-    // if number of edges to be created is >= max,
-    // avoid randomly missed trials and directly add edges between every node
-    if num_edges >= num_nodes * (num_nodes - 1) / 2 {
-        for u in 0..num_nodes {
-            for v in u + 1..num_nodes {
-                let u_index = NodeIndex::new(u as usize);
-                let v_index = NodeIndex::new(v as usize);
-                inner_graph.add_edge(u_index, v_index, py.None());
-            }
-        }
-    } else {
-        let mut created_edges: isize = 0;
-        let between = Uniform::new(0, num_nodes);
-        while created_edges < num_edges {
-            let u = between.sample(&mut rng);
-            let v = between.sample(&mut rng);
-            let u_index = NodeIndex::new(u as usize);
-            let v_index = NodeIndex::new(v as usize);
-            // avoid self-loops and multi-graphs
-            if u != v && inner_graph.find_edge(u_index, v_index).is_none() {
-                inner_graph.add_edge(u_index, v_index, py.None());
-                created_edges += 1;
-            }
-        }
+    if let Some(err) = filter_error {
+        return Err(err);
     }
+    index_node_weights(py, &mut inner_graph);
+
     let graph = graph::PyGraph {
         graph: inner_graph,
         node_removed: false,
         multigraph: true,
+        node_keys: NodeKeys::default(),
+        attributes: AttributeColumns::default(),
+        generation: 0,
     };
     Ok(graph)
 }
@@ -397,8 +380,17 @@ fn pnorm(x: f64, p: f64) -> f64 {
     }
 }
 
-fn distance(x: &[f64], y: &[f64], p: f64) -> f64 {
-    let it = x.iter().zip(y.iter()).map(|(xi, yi)| pnorm(xi - yi, p));
+fn distance(x: &[f64], y: &[f64], p: f64, torus: bool) -> f64 {
+    let it = x.iter().zip(y.iter()).map(|(xi, yi)| {
+        if torus {
+            // The unit cube wraps around, so the distance along each
+            // dimension is the shorter way around it
+            let d = (xi - yi).abs() % 1.0;
+            pnorm(d.min(1.0 - d), p)
+        } else {
+            pnorm(xi - yi, p)
+        }
+    });
 
     if p == std::f64::INFINITY {
         it.fold(-1.0, |max, x| if x > max { x } else { max })
@@ -418,6 +410,11 @@ fn distance(x: &[f64], y: &[f64], p: f64) -> f64 {
 /// ``pos`` keyword argument or, if ``pos`` was not provided, as
 /// generated by this function.
 ///
+/// With ``torus`` set the unit cube wraps around on itself, every dimension
+/// is periodic, so nodes near opposite faces of the cube can be joined. This
+/// removes the boundary effects of the cube, where nodes near a face have
+/// fewer neighbors, for example when simulating a wireless network.
+///
 /// :param int num_nodes: The number of nodes to create in the graph
 /// :param float radius: Distance threshold value
 /// :param int dim: Dimension of node positions. Default: 2
@@ -427,11 +424,24 @@ fn distance(x: &[f64], y: &[f64], p: f64) -> f64 {
 ///     If this argument is not specified, the :math:`L^2` metric
 ///     (the Euclidean distance metric), p = 2 is used.
 /// :param int seed: An optional seed to use for the random number generator
+/// :param callable edge_filter: An optional callable which is called with the
+///     node indices of every pair of nodes within ``radius`` of each other.
+///     The edge between them is only added to the graph if it returns
+///     ``True``, which makes it possible to enforce constraints on the
+///     generated edges without materializing the rejected ones.
+/// :param bool torus: If set to ``True`` the distance between two nodes is
+///     measured on the torus formed by wrapping the unit cube around on
+///     itself, the positions are taken modulo 1.
+/// :param bool with_positions: If set to ``False`` the nodes have a payload
+///     of ``None`` instead of a dictionary with their position.
 ///
 /// :return: A PyGraph object
 /// :rtype: PyGraph
-#[pyfunction(dim = "2", p = "2.0")]
-#[pyo3(text_signature = "(num_nodes, radius, /, dim=2, pos=None, p=2.0, seed=None)")]
+#[allow(clippy::too_many_arguments)]
+#[pyfunction(dim = "2", p = "2.0", torus = "false", with_positions = "true")]
+#[pyo3(
+    text_signature = "(num_nodes, radius, /, dim=2, pos=None, p=2.0, seed=None, edge_filter=None, torus=False, with_positions=True)"
+)]
 pub fn random_geometric_graph(
     py: Python,
     num_nodes: usize,
@@ -440,6 +450,9 @@ pub fn random_geometric_graph(
     pos: Option<Vec<Vec<f64>>>,
     p: f64,
     seed: Option<u64>,
+    edge_filter: Option<PyObject>,
+    torus: bool,
+    with_positions: bool,
 ) -> PyResult<graph::PyGraph> {
     if num_nodes == 0 {
         return Err(PyValueError::new_err("num_nodes must be > 0"));
@@ -467,24 +480,904 @@ pub fn random_geometric_graph(
     }
 
     for pval in pos.iter() {
-        let pos_dict = PyDict::new(py);
-        pos_dict.set_item("pos", pval.to_object(py))?;
-
-        inner_graph.add_node(pos_dict.into());
+        if with_positions {
+            let pos_dict = PyDict::new(py);
+            pos_dict.set_item("pos", pval.to_object(py))?;
+            inner_graph.add_node(pos_dict.into());
+        } else {
+            inner_graph.add_node(py.None());
+        }
     }
 
-    for u in 0..(num_nodes - 1) {
-        for v in (u + 1)..num_nodes {
-            if distance(&pos[u], &pos[v], p) < radius_p {
-                inner_graph.add_edge(NodeIndex::new(u), NodeIndex::new(v), py.None());
+    let mut filter_error: Option<PyErr> = None;
+    {
+        let mut accept_edge = edge_filter_fn(py, &edge_filter, &mut filter_error);
+        for u in 0..(num_nodes - 1) {
+            for v in (u + 1)..num_nodes {
+                if distance(&pos[u], &pos[v], p, torus) < radius_p && accept_edge(u, v) {
+                    inner_graph.add_edge(NodeIndex::new(u), NodeIndex::new(v), py.None());
+                }
             }
         }
     }
+    if let Some(err) = filter_error {
+        return Err(err);
+    }
 
     let graph = graph::PyGraph {
         graph: inner_graph,
         node_removed: false,
         multigraph: true,
+        node_keys: NodeKeys::default(),
+        attributes: AttributeColumns::default(),
+        generation: 0,
+    };
+    Ok(graph)
+}
+
+/// Run a preferential attachment generator on a copy of ``initial_graph``,
+/// the nodes it adds get their node index as payload like with the other
+/// generators while the nodes of ``initial_graph`` keep theirs.
+fn grow_graph<Ty, F>(
+    py: Python,
+    initial_graph: Option<&StablePyGraph<Ty>>,
+    generate: F,
+) -> PyResult<StablePyGraph<Ty>>
+where
+    Ty: EdgeType,
+    F: FnOnce(Option<StablePyGraph<Ty>>) -> Result<StablePyGraph<Ty>, InvalidInputError>,
+{
+    let mut graph = match generate(initial_graph.cloned()) {
+        Ok(graph) => graph,
+        Err(err) => return Err(PyValueError::new_err(err.to_string())),
     };
+    let new_nodes: Vec<NodeIndex> = graph
+        .node_indices()
+        .filter(|node| match initial_graph {
+            Some(initial) => !initial.contains_node(*node),
+            None => true,
+        })
+        .collect();
+    for node in new_nodes {
+        graph[node] = node.index().to_object(py);
+    }
     Ok(graph)
 }
+
+/// Return a random graph generated with the Barabási–Albert preferential
+/// attachment model.
+///
+/// Starting from ``initial_graph``, or from a star graph with :math:`m + 1`
+/// nodes if it's not given, nodes are added one at a time until the graph
+/// has :math:`n` nodes. Every new node gets an edge to each of :math:`m`
+/// distinct existing nodes, drawn with a probability proportional to their
+/// degree, so the degree distribution of the graph follows a power law [1]_.
+/// A node is drawn in constant time from a list of the ends of every edge,
+/// where a node is repeated once per incident edge.
+///
+/// :param int n: The number of nodes of the generated graph
+/// :param int m: The number of edges to attach from every new node
+/// :param int seed: An optional seed to use for the random number generator
+/// :param PyGraph initial_graph: An optional graph to grow, it must have at
+///     most ``n`` nodes and at least ``m`` nodes with an edge. It's copied
+///     and its nodes, edges, and their payloads are kept as is. The payload of
+///     every new node is its index.
+///
+/// :return: A PyGraph object
+/// :rtype: PyGraph
+/// :raises ValueError: If ``m`` is 0, if ``initial_graph`` isn't given and
+///     ``m`` isn't less than ``n``, or if ``initial_graph`` has more than
+///     ``n`` nodes or fewer than ``m`` nodes with an edge
+///
+/// .. [1] A. L. Barabási and R. Albert, "Emergence of scaling in random
+///    networks", Science 286, pp 509-512, 1999.
+#[pyfunction]
+#[pyo3(text_signature = "(n, m, /, seed=None, initial_graph=None)")]
+pub fn barabasi_albert_graph(
+    py: Python,
+    n: usize,
+    m: usize,
+    seed: Option<u64>,
+    initial_graph: Option<&graph::PyGraph>,
+) -> PyResult<graph::PyGraph> {
+    let inner_graph = grow_graph(py, initial_graph.map(|g| &g.graph), |initial| {
+        core_generators::barabasi_albert_graph(n, m, seed, initial, || py.None(), || py.None())
+    })?;
+    let (node_removed, multigraph, node_keys, attributes) = match initial_graph {
+        Some(g) => (
+            g.node_removed,
+            g.multigraph,
+            g.node_keys.clone(),
+            g.attributes.clone(),
+        ),
+        None => (
+            false,
+            true,
+            NodeKeys::default(),
+            AttributeColumns::default(),
+        ),
+    };
+    Ok(graph::PyGraph {
+        graph: inner_graph,
+        node_removed,
+        multigraph,
+        node_keys,
+        attributes,
+        generation: 0,
+    })
+}
+
+/// Return a directed random graph generated with the Barabási–Albert
+/// preferential attachment model.
+///
+/// Starting from ``initial_graph``, or from a star graph with :math:`m + 1`
+/// nodes if it's not given, nodes are added one at a time until the graph
+/// has :math:`n` nodes. Every new node gets an edge to each of :math:`m`
+/// distinct existing nodes, drawn with a probability proportional to their
+/// degree, so the degree distribution of the graph follows a power law [1]_.
+/// The edges go from the new node to the existing nodes and the degree of a
+/// node counts both its incoming and outgoing edges. A node is drawn in
+/// constant time from a list of the ends of every edge, where a node is
+/// repeated once per incident edge.
+///
+/// :param int n: The number of nodes of the generated graph
+/// :param int m: The number of edges to attach from every new node
+/// :param int seed: An optional seed to use for the random number generator
+/// :param PyDiGraph initial_graph: An optional graph to grow, it must have at
+///     most ``n`` nodes and at least ``m`` nodes with an edge. It's copied
+///     and its nodes, edges, and their payloads are kept as is. The payload of
+///     every new node is its index.
+///
+/// :return: A PyDiGraph object
+/// :rtype: PyDiGraph
+/// :raises ValueError: If ``m`` is 0, if ``initial_graph`` isn't given and
+///     ``m`` isn't less than ``n``, or if ``initial_graph`` has more than
+///     ``n`` nodes or fewer than ``m`` nodes with an edge
+///
+/// .. [1] A. L. Barabási and R. Albert, "Emergence of scaling in random
+///    networks", Science 286, pp 509-512, 1999.
+#[pyfunction]
+#[pyo3(text_signature = "(n, m, /, seed=None, initial_graph=None)")]
+pub fn directed_barabasi_albert_graph(
+    py: Python,
+    n: usize,
+    m: usize,
+    seed: Option<u64>,
+    initial_graph: Option<&digraph::PyDiGraph>,
+) -> PyResult<digraph::PyDiGraph> {
+    let inner_graph = grow_graph(py, initial_graph.map(|g| &g.graph), |initial| {
+        core_generators::barabasi_albert_graph(n, m, seed, initial, || py.None(), || py.None())
+    })?;
+    let (check_cycle, node_removed, multigraph, node_keys, attributes) = match initial_graph {
+        Some(g) => (
+            g.check_cycle,
+            g.node_removed,
+            g.multigraph,
+            g.node_keys.clone(),
+            g.attributes.clone(),
+        ),
+        None => (
+            false,
+            false,
+            true,
+            NodeKeys::default(),
+            AttributeColumns::default(),
+        ),
+    };
+    Ok(digraph::PyDiGraph {
+        graph: inner_graph,
+        cycle_state: None,
+        check_cycle,
+        node_removed,
+        multigraph,
+        node_keys,
+        attributes,
+        generation: 0,
+    })
+}
+
+/// Return a random graph generated with the Holme–Kim model, an extension
+/// of the Barabási–Albert preferential attachment model with triad
+/// formation.
+///
+/// Nodes are added one at a time until the graph has :math:`n` nodes, each
+/// one with :math:`m` edges. Like :func:`~retworkx.barabasi_albert_graph`
+/// the first edge of a new node goes to an existing node drawn with a
+/// probability proportional to its degree. Every following edge is, with
+/// probability :math:`p`, a triad formation step, which goes to a random
+/// neighbor of the node the previous preferential attachment edge went to
+/// and so closes a triangle, and otherwise another preferential attachment
+/// edge [1]_. The triad formation steps raise the clustering of the graph
+/// while keeping the power law degree distribution.
+///
+/// :param int n: The number of nodes of the generated graph
+/// :param int m: The number of edges to attach from every new node
+/// :param float p: The probability of a triad formation step after an edge,
+///     it must be between 0 and 1
+/// :param int seed: An optional seed to use for the random number generator
+/// :param PyGraph initial_graph: An optional graph to grow, it must have at
+///     most ``n`` nodes and at least ``m`` nodes with an edge. It's copied
+///     and its nodes, edges, and their payloads are kept as is. The payload of
+///     every new node is its index. A star graph with :math:`m + 1` nodes is
+///     used if it's not given.
+///
+/// :return: A PyGraph object
+/// :rtype: PyGraph
+/// :raises ValueError: For the same arguments as
+///     :func:`~retworkx.barabasi_albert_graph` or if ``p`` is out of range
+///
+/// .. [1] P. Holme and B. J. Kim, "Growing scale-free networks with tunable
+///    clustering", Phys. Rev. E, 65, 026107, 2002.
+#[pyfunction]
+#[pyo3(text_signature = "(n, m, p, /, seed=None, initial_graph=None)")]
+pub fn powerlaw_cluster_graph(
+    py: Python,
+    n: usize,
+    m: usize,
+    p: f64,
+    seed: Option<u64>,
+    initial_graph: Option<&graph::PyGraph>,
+) -> PyResult<graph::PyGraph> {
+    let inner_graph = grow_graph(py, initial_graph.map(|g| &g.graph), |initial| {
+        core_generators::powerlaw_cluster_graph(n, m, p, seed, initial, || py.None(), || py.None())
+    })?;
+    let (node_removed, multigraph, node_keys, attributes) = match initial_graph {
+        Some(g) => (
+            g.node_removed,
+            g.multigraph,
+            g.node_keys.clone(),
+            g.attributes.clone(),
+        ),
+        None => (
+            false,
+            true,
+            NodeKeys::default(),
+            AttributeColumns::default(),
+        ),
+    };
+    Ok(graph::PyGraph {
+        graph: inner_graph,
+        node_removed,
+        multigraph,
+        node_keys,
+        attributes,
+        generation: 0,
+    })
+}
+
+/// Return a random graph generated with the Watts–Strogatz small-world model.
+///
+/// The graph starts as a ring lattice of :math:`n` nodes, where every node is
+/// connected to its :math:`\lfloor k / 2 \rfloor` nearest neighbors on each
+/// side. Then every edge :math:`(u, v)` of the lattice is, with probability
+/// :math:`p`, rewired to :math:`(u, w)` where :math:`w` is a node drawn
+/// uniformly among the nodes :math:`u` isn't connected to [1]_. A small
+/// :math:`p` keeps the high clustering of the lattice while the rewired
+/// edges act as shortcuts that make the average shortest path length small.
+/// The graph always has :math:`n \lfloor k / 2 \rfloor` edges and no self
+/// loops or parallel edges. If :math:`k = n` a complete graph is returned.
+/// The graph may be disconnected, use
+/// :func:`~retworkx.connected_watts_strogatz_graph` to get a connected graph.
+///
+/// :param int n: The number of nodes of the generated graph
+/// :param int k: The number of nearest neighbors every node is connected to
+///     in the ring lattice
+/// :param float p: The probability of rewiring every edge, it must be between
+///     0 and 1
+/// :param int seed: An optional seed to use for the random number generator
+///
+/// :return: A PyGraph object
+/// :rtype: PyGraph
+/// :raises ValueError: If ``n`` is 0, if ``k`` is larger than ``n`` or if
+///     ``p`` is out of range
+///
+/// .. [1] D. J. Watts and S. H. Strogatz, "Collective dynamics of
+///    'small-world' networks", Nature, 393, pp. 440–442, 1998.
+#[pyfunction]
+#[pyo3(text_signature = "(n, k, p, /, seed=None)")]
+pub fn watts_strogatz_graph(
+    py: Python,
+    n: usize,
+    k: usize,
+    p: f64,
+    seed: Option<u64>,
+) -> PyResult<graph::PyGraph> {
+    let mut inner_graph: StablePyGraph<Undirected> =
+        match core_generators::watts_strogatz_graph(n, k, p, seed, || py.None(), || py.None()) {
+            Ok(graph) => graph,
+            Err(err) => return Err(PyValueError::new_err(err.to_string())),
+        };
+    index_node_weights(py, &mut inner_graph);
+    Ok(graph::PyGraph {
+        graph: inner_graph,
+        node_removed: false,
+        multigraph: true,
+        node_keys: NodeKeys::default(),
+        attributes: AttributeColumns::default(),
+        generation: 0,
+    })
+}
+
+/// Return a connected random graph generated with the Watts–Strogatz
+/// small-world model.
+///
+/// This draws graphs like :func:`~retworkx.watts_strogatz_graph` until one
+/// of them is connected, for up to ``max_attempts`` attempts. A small
+/// :math:`k` and a large :math:`p` make a disconnected graph more likely,
+/// with :math:`k < 2` the graph is only connected if it has a single node.
+///
+/// :param int n: The number of nodes of the generated graph
+/// :param int k: The number of nearest neighbors every node is connected to
+///     in the ring lattice
+/// :param float p: The probability of rewiring every edge, it must be between
+///     0 and 1
+/// :param int seed: An optional seed to use for the random number generator
+/// :param int max_attempts: The maximum number of graphs drawn. Defaults to
+///     100.
+///
+/// :return: A PyGraph object
+/// :rtype: PyGraph
+/// :raises ValueError: For the same arguments as
+///     :func:`~retworkx.watts_strogatz_graph` or if none of the graphs drawn
+///     was connected
+#[pyfunction(max_attempts = "100")]
+#[pyo3(text_signature = "(n, k, p, /, seed=None, max_attempts=100)")]
+pub fn connected_watts_strogatz_graph(
+    py: Python,
+    n: usize,
+    k: usize,
+    p: f64,
+    seed: Option<u64>,
+    max_attempts: usize,
+) -> PyResult<graph::PyGraph> {
+    let mut inner_graph: StablePyGraph<Undirected> =
+        match core_generators::connected_watts_strogatz_graph(
+            n,
+            k,
+            p,
+            max_attempts,
+            seed,
+            || py.None(),
+            || py.None(),
+        ) {
+            Ok(graph) => graph,
+            Err(err) => return Err(PyValueError::new_err(err.to_string())),
+        };
+    index_node_weights(py, &mut inner_graph);
+    Ok(graph::PyGraph {
+        graph: inner_graph,
+        node_removed: false,
+        multigraph: true,
+        node_keys: NodeKeys::default(),
+        attributes: AttributeColumns::default(),
+        generation: 0,
+    })
+}
+
+/// Return a random graph generated with the stochastic block model.
+///
+/// The nodes are split into consecutive blocks, with ``sizes[i]`` nodes in
+/// block ``i``: the first ``sizes[0]`` nodes are in block 0, the next
+/// ``sizes[1]`` nodes in block 1 and so on. Every edge between a node of
+/// block ``i`` and a node of block ``j`` is created independently with
+/// probability ``probability_matrix[i][j]``, so a matrix with larger
+/// probabilities on its diagonal plants a community structure in the graph.
+/// The graph has no self loops or parallel edges. The edges of every pair of
+/// blocks are drawn by skipping over the rejected edges with a geometric
+/// distribution [1]_, so the run time is :math:`O(n + m + b^2)` for
+/// :math:`b` blocks, where :math:`m` is the expected number of edges.
+///
+/// :param list sizes: The number of nodes of every block
+/// :param probability_matrix: The probability of creating an edge between
+///     every pair of blocks, a square matrix (as a list of lists or a 2D
+///     numpy array) with a row and a column per block. It must be symmetric
+///     if ``directed`` is ``False``, otherwise ``probability_matrix[i][j]``
+///     is the probability of an edge from a node of block ``i`` to a node of
+///     block ``j``.
+/// :param int seed: An optional seed to use for the random number generator
+/// :param bool directed: If set to ``True`` a :class:`~retworkx.PyDiGraph` is
+///     returned instead of a :class:`~retworkx.PyGraph`. Defaults to
+///     ``False``.
+///
+/// :return: The generated graph, the payload of every node is its index
+/// :rtype: PyGraph or PyDiGraph
+/// :raises ValueError: If ``probability_matrix`` isn't a square matrix with
+///     a row per block, if a probability is out of range, or if ``directed``
+///     is ``False`` and ``probability_matrix`` isn't symmetric
+///
+/// .. [1] Vladimir Batagelj and Ulrik Brandes,
+///    "Efficient generation of large random networks",
+///    Phys. Rev. E, 71, 036113, 2005.
+#[pyfunction(directed = "false")]
+#[pyo3(text_signature = "(sizes, probability_matrix, /, seed=None, directed=False)")]
+pub fn stochastic_block_model(
+    py: Python,
+    sizes: Vec<usize>,
+    probability_matrix: Vec<Vec<f64>>,
+    seed: Option<u64>,
+    directed: bool,
+) -> PyResult<PyObject> {
+    fn generate<Ty: EdgeType>(
+        py: Python,
+        sizes: &[usize],
+        probability_matrix: &[Vec<f64>],
+        seed: Option<u64>,
+    ) -> PyResult<StablePyGraph<Ty>> {
+        let mut graph = match core_generators::stochastic_block_model(
+            sizes,
+            probability_matrix,
+            seed,
+            || py.None(),
+            || py.None(),
+        ) {
+            Ok(graph) => graph,
+            Err(err) => return Err(PyValueError::new_err(err.to_string())),
+        };
+        index_node_weights(py, &mut graph);
+        Ok(graph)
+    }
+
+    if directed {
+        Ok(digraph::PyDiGraph {
+            graph: generate(py, &sizes, &probability_matrix, seed)?,
+            cycle_state: None,
+            check_cycle: false,
+            node_removed: false,
+            multigraph: true,
+            node_keys: NodeKeys::default(),
+            attributes: AttributeColumns::default(),
+            generation: 0,
+        }
+        .into_py(py))
+    } else {
+        Ok(graph::PyGraph {
+            graph: generate(py, &sizes, &probability_matrix, seed)?,
+            node_removed: false,
+            multigraph: true,
+            node_keys: NodeKeys::default(),
+            attributes: AttributeColumns::default(),
+            generation: 0,
+        }
+        .into_py(py))
+    }
+}
+
+/// Return a random bipartite graph and its bipartition.
+///
+/// The graph has ``n_left + n_right`` nodes, the first ``n_left`` nodes are
+/// the left set and the next ``n_right`` nodes are the right set. The edges
+/// are drawn between a node of the left set and a node of the right set,
+/// with either of these models depending on the type of ``p_or_m``:
+///
+/// * if it's a ``float`` every possible edge is created independently with
+///   probability ``p_or_m``, the bipartite version of the :math:`G(n, p)`
+///   model. The rejected edges are skipped with a geometric distribution
+///   [1]_, so the run time is :math:`O(n + m)`, where :math:`m` is the
+///   expected number of edges.
+/// * if it's an ``int`` the graph is drawn uniformly out of all the bipartite
+///   graphs with ``p_or_m`` edges, the bipartite version of the
+///   :math:`G(n, m)` model. If ``p_or_m`` is larger than
+///   ``n_left * n_right`` a complete bipartite graph is returned.
+///
+/// The bipartition is returned with the graph, so the graph can be used
+/// directly as the input of the bipartite algorithms. For example:
+///
+/// .. jupyter-execute::
+///
+///     import retworkx
+///
+///     graph, left, right = retworkx.random_bipartite_graph(4, 5, 0.5, seed=42)
+///     print(left, right)
+///     print(graph.edge_list())
+///
+/// :param int n_left: The number of nodes in the left set
+/// :param int n_right: The number of nodes in the right set
+/// :param p_or_m: Either the probability of creating an edge between a node
+///     of the left set and a node of the right set as a ``float`` between 0
+///     and 1, or the number of edges as an ``int``. Note that ``1`` is a
+///     single edge while ``1.0`` is a complete bipartite graph.
+/// :param int seed: An optional seed to use for the random number generator
+/// :param bool directed: If set to ``True`` a :class:`~retworkx.PyDiGraph` is
+///     returned instead of a :class:`~retworkx.PyGraph`, with every edge
+///     going from the left set to the right set. Defaults to ``False``.
+///
+/// :return: A tuple ``(graph, left, right)`` of the generated graph, where
+///     the payload of every node is its index, and the sets of the node
+///     indices of the left and the right set
+/// :rtype: tuple
+/// :raises ValueError: If ``p_or_m`` is a probability out of range
+///
+/// .. [1] Vladimir Batagelj and Ulrik Brandes,
+///    "Efficient generation of large random networks",
+///    Phys. Rev. E, 71, 036113, 2005.
+#[pyfunction(directed = "false")]
+#[pyo3(text_signature = "(n_left, n_right, p_or_m, /, seed=None, directed=False)")]
+pub fn random_bipartite_graph(
+    py: Python,
+    n_left: usize,
+    n_right: usize,
+    p_or_m: &PyAny,
+    seed: Option<u64>,
+    directed: bool,
+) -> PyResult<(PyObject, HashSet<usize>, HashSet<usize>)> {
+    fn generate<Ty: EdgeType>(
+        py: Python,
+        n_left: usize,
+        n_right: usize,
+        p_or_m: &PyAny,
+        seed: Option<u64>,
+    ) -> PyResult<StablePyGraph<Ty>> {
+        let result = if p_or_m.is_instance_of::<PyLong>()? {
+            core_generators::random_bipartite_gnm_graph(
+                n_left,
+                n_right,
+                p_or_m.extract()?,
+                seed,
+                || py.None(),
+                || py.None(),
+            )
+        } else {
+            core_generators::random_bipartite_graph(
+                n_left,
+                n_right,
+                p_or_m.extract()?,
+                seed,
+                || py.None(),
+                || py.None(),
+            )
+        };
+        let mut graph = match result {
+            Ok(graph) => graph,
+            Err(err) => return Err(PyValueError::new_err(err.to_string())),
+        };
+        index_node_weights(py, &mut graph);
+        Ok(graph)
+    }
+
+    let graph = if directed {
+        digraph::PyDiGraph {
+            graph: generate(py, n_left, n_right, p_or_m, seed)?,
+            cycle_state: None,
+            check_cycle: false,
+            node_removed: false,
+            multigraph: true,
+            node_keys: NodeKeys::default(),
+            attributes: AttributeColumns::default(),
+            generation: 0,
+        }
+        .into_py(py)
+    } else {
+        graph::PyGraph {
+            graph: generate(py, n_left, n_right, p_or_m, seed)?,
+            node_removed: false,
+            multigraph: true,
+            node_keys: NodeKeys::default(),
+            attributes: AttributeColumns::default(),
+            generation: 0,
+        }
+        .into_py(py)
+    };
+    Ok((
+        graph,
+        (0..n_left).collect(),
+        (n_left..n_left + n_right).collect(),
+    ))
+}
+
+/// Return whether a sequence of degrees is the degree sequence of a simple
+/// graph
+///
+/// A simple graph has no self loops or parallel edges. The sequence is
+/// checked with the Erdős–Gallai theorem [1]_ in :math:`O(n \log n)` time.
+///
+/// :param list degree_sequence: The degree of every node, in any order
+///
+/// :returns: ``True`` if a simple graph with the given degrees exists
+/// :rtype: bool
+///
+/// .. [1] P. Erdős and T. Gallai, "Graphs with prescribed degrees of
+///    vertices", Mat. Lapok, 11, pp. 264–274, 1960.
+#[pyfunction]
+#[pyo3(text_signature = "(degree_sequence, /)")]
+pub fn is_graphical(degree_sequence: Vec<usize>) -> bool {
+    core_generators::is_graphical(&degree_sequence)
+}
+
+/// Return a random graph with a given degree sequence generated with the
+/// configuration model.
+///
+/// Every node ``i`` gets ``degree_sequence[i]`` stubs, or half edges, and
+/// the stubs are matched uniformly at random into edges [1]_. The result is
+/// a multigraph with exactly the given degrees, where a self loop counts
+/// twice in the degree of its node, so it can be used as a null model for
+/// degree preserving randomization tests. For example, to compare the number
+/// of triangles of a graph to random graphs with the same degrees:
+///
+/// .. jupyter-execute::
+///
+///     import retworkx
+///
+///     graph = retworkx.generators.grid_graph(5, 5)
+///     degrees = [graph.degree(node) for node in graph.node_indexes()]
+///     random_graph = retworkx.configuration_model(degrees, seed=42)
+///     print(graph.num_edges(), random_graph.num_edges())
+///
+/// If ``simple`` is ``True`` the self loops are removed and the parallel
+/// edges are collapsed into a single edge, so the degrees of the returned
+/// graph may be lower than the given degrees.
+///
+/// :param list degree_sequence: The degree of every node of the generated
+///     graph. It must be graphical, see :func:`~retworkx.is_graphical`.
+/// :param int seed: An optional seed to use for the random number generator
+/// :param bool simple: Whether to remove the self loops and collapse the
+///     parallel edges of the generated multigraph. Defaults to ``False``.
+///
+/// :return: The generated graph, the payload of every node is its index.
+///     Its ``multigraph`` attribute is ``False`` if ``simple`` is ``True``.
+/// :rtype: PyGraph
+/// :raises ValueError: If ``degree_sequence`` isn't graphical
+///
+/// .. [1] M. E. J. Newman, "The structure and function of complex
+///    networks", SIAM Review, 45, pp. 167–256, 2003.
+#[pyfunction(simple = "false")]
+#[pyo3(text_signature = "(degree_sequence, /, seed=None, simple=False)")]
+pub fn configuration_model(
+    py: Python,
+    degree_sequence: Vec<usize>,
+    seed: Option<u64>,
+    simple: bool,
+) -> PyResult<graph::PyGraph> {
+    let mut inner_graph: StablePyGraph<Undirected> = match core_generators::configuration_model(
+        &degree_sequence,
+        simple,
+        seed,
+        || py.None(),
+        || py.None(),
+    ) {
+        Ok(graph) => graph,
+        Err(err) => return Err(PyValueError::new_err(err.to_string())),
+    };
+    index_node_weights(py, &mut inner_graph);
+    Ok(graph::PyGraph {
+        graph: inner_graph,
+        node_removed: false,
+        multigraph: !simple,
+        node_keys: NodeKeys::default(),
+        attributes: AttributeColumns::default(),
+        generation: 0,
+    })
+}
+
+/// A swap of 2 edges done by [`try_edge_swap`], which can be undone.
+struct EdgeSwap {
+    positions: (usize, usize),
+    old: [(NodeIndex, NodeIndex); 2],
+}
+
+/// Try to swap a random pair of edges ``(u, v)`` and ``(x, y)`` of ``graph``
+/// for ``(u, x)`` and ``(v, y)``, which keeps the degree of every node. The
+/// swap is rejected if it would create a self loop or a parallel edge. The
+/// payload of every removed edge is moved to the new edge at the same
+/// position in ``edges``, the indices of the edges of ``graph``.
+fn try_edge_swap(
+    graph: &mut StablePyGraph<Undirected>,
+    attributes: &mut AttributeColumns,
+    edges: &mut [EdgeIndex],
+    rng: &mut Pcg64,
+) -> Option<EdgeSwap> {
+    let i = rng.gen_range(0..edges.len());
+    let mut j = rng.gen_range(0..edges.len() - 1);
+    if j >= i {
+        j += 1;
+    }
+    let (u, v) = graph.edge_endpoints(edges[i]).unwrap();
+    let (mut x, mut y) = graph.edge_endpoints(edges[j]).unwrap();
+    if rng.gen::<bool>() {
+        std::mem::swap(&mut x, &mut y);
+    }
+    if u == x || v == y || graph.contains_edge(u, x) || graph.contains_edge(v, y) {
+        return None;
+    }
+    replace_edge_pair(graph, attributes, edges, (i, j), [(u, x), (v, y)]);
+    Some(EdgeSwap {
+        positions: (i, j),
+        old: [(u, v), (x, y)],
+    })
+}
+
+fn undo_edge_swap(
+    graph: &mut StablePyGraph<Undirected>,
+    attributes: &mut AttributeColumns,
+    edges: &mut [EdgeIndex],
+    swap: EdgeSwap,
+) {
+    replace_edge_pair(graph, attributes, edges, swap.positions, swap.old);
+}
+
+/// Replace the edges at positions ``i`` and ``j`` of ``edges`` with edges
+/// between the pairs of ``ends``, moving the payload and the attribute values
+/// of every removed edge to the new edge at the same position.
+fn replace_edge_pair(
+    graph: &mut StablePyGraph<Undirected>,
+    attributes: &mut AttributeColumns,
+    edges: &mut [EdgeIndex],
+    (i, j): (usize, usize),
+    ends: [(NodeIndex, NodeIndex); 2],
+) {
+    let old = (edges[i], edges[j]);
+    let first = graph.remove_edge(old.0).unwrap();
+    let second = graph.remove_edge(old.1).unwrap();
+    edges[i] = graph.add_edge(ends[0].0, ends[0].1, first);
+    edges[j] = graph.add_edge(ends[1].0, ends[1].1, second);
+    // The new edges take the indices of the removed edges, maybe swapped
+    if edges[i] != old.0 {
+        attributes.swap_edges(old.0, old.1);
+    }
+}
+
+fn is_connected_graph(graph: &StablePyGraph<Undirected>) -> bool {
+    match graph.node_indices().next() {
+        Some(start) => {
+            let mut bfs = Bfs::new(graph, start);
+            let mut count = 0;
+            while bfs.next(graph).is_some() {
+                count += 1;
+            }
+            count == graph.node_count()
+        }
+        None => true,
+    }
+}
+
+/// Randomize a PyGraph in place while keeping the degree of every node
+///
+/// Every swap picks 2 random edges :math:`(u, v)` and :math:`(x, y)` and
+/// replaces them with :math:`(u, x)` and :math:`(v, y)`, so the degree of
+/// every node stays the same [1]_. A swap which would create a self loop or
+/// a parallel edge is rejected, so a graph without self loops or parallel
+/// edges stays simple. Swapping enough edges gives a random graph with the
+/// same degrees as ``graph``, which can be used as a null model of an
+/// empirical graph. For example:
+///
+/// .. jupyter-execute::
+///
+///     import retworkx
+///
+///     graph = retworkx.generators.grid_graph(4, 4)
+///     degrees = [graph.degree(node) for node in graph.node_indexes()]
+///     swaps = retworkx.double_edge_swap(graph, 50, max_tries=1000, seed=42)
+///     print(swaps)
+///     print(degrees == [graph.degree(node) for node in graph.node_indexes()])
+///
+/// The payload and the attribute values of a removed edge are moved to the
+/// new edge which replaces it, the edge indices of the swapped edges may
+/// change.
+///
+/// :param PyGraph graph: The graph to randomize in place
+/// :param int nswap: The number of swaps to do. Defaults to 1.
+/// :param int max_tries: The maximum number of swaps to try, including the
+///     rejected swaps. Defaults to 100.
+/// :param int seed: An optional seed to use for the random number generator
+///
+/// :returns: The number of swaps done, which is less than ``nswap`` if
+///     ``max_tries`` swaps were tried first or if the graph has less than 2
+///     edges
+/// :rtype: int
+///
+/// .. [1] Sergei Maslov and Kim Sneppen, "Specificity and stability in
+///    topology of protein networks", Science, 296(5569), pp. 910–913, 2002.
+#[pyfunction(nswap = "1", max_tries = "100")]
+#[pyo3(text_signature = "(graph, /, nswap=1, max_tries=100, seed=None)")]
+pub fn double_edge_swap(
+    graph: &mut graph::PyGraph,
+    nswap: usize,
+    max_tries: usize,
+    seed: Option<u64>,
+) -> usize {
+    graph.generation += 1;
+    let mut edges: Vec<EdgeIndex> = graph.graph.edge_indices().collect();
+    if edges.len() < 2 {
+        return 0;
+    }
+    let mut rng: Pcg64 = match seed {
+        Some(seed) => Pcg64::seed_from_u64(seed),
+        None => Pcg64::from_entropy(),
+    };
+    let mut swaps = 0;
+    let mut tries = 0;
+    while swaps < nswap && tries < max_tries {
+        tries += 1;
+        if try_edge_swap(
+            &mut graph.graph,
+            &mut graph.attributes,
+            &mut edges,
+            &mut rng,
+        )
+        .is_some()
+        {
+            swaps += 1;
+        }
+    }
+    swaps
+}
+
+/// Randomize a connected PyGraph in place while keeping the degree of every
+/// node and the graph connected
+///
+/// This does the same swaps as :func:`~retworkx.double_edge_swap`, and
+/// undoes the swaps which disconnect the graph. Since checking the
+/// connectivity after every swap is slow, the swaps are done in windows and
+/// the connectivity is checked after every window [1]_. If the graph is
+/// still connected the window grows by 1 swap, otherwise the swaps of the
+/// window are undone and the window is halved.
+///
+/// The payload and the attribute values of a removed edge are moved to the
+/// new edge which replaces it, the edge indices of the swapped edges may
+/// change.
+///
+/// :param PyGraph graph: The graph to randomize in place, it must be
+///     connected
+/// :param int nswap: The number of swaps to do. Defaults to 1.
+/// :param int max_tries: The maximum number of swaps to try, including the
+///     rejected and the undone swaps. Defaults to 100.
+/// :param int seed: An optional seed to use for the random number generator
+///
+/// :returns: The number of swaps done, which is less than ``nswap`` if
+///     ``max_tries`` swaps were tried first or if the graph has less than 2
+///     edges
+/// :rtype: int
+/// :raises ValueError: If the graph isn't connected
+///
+/// .. [1] Christos Gkantsidis, Milena Mihail and Ellen Zegura, "The Markov
+///    chain simulation method for generating connected power law random
+///    graphs", Proceedings of the Fifth Workshop on Algorithm Engineering
+///    and Experiments (ALENEX), 2003.
+#[pyfunction(nswap = "1", max_tries = "100")]
+#[pyo3(text_signature = "(graph, /, nswap=1, max_tries=100, seed=None)")]
+pub fn connected_double_edge_swap(
+    graph: &mut graph::PyGraph,
+    nswap: usize,
+    max_tries: usize,
+    seed: Option<u64>,
+) -> PyResult<usize> {
+    if !is_connected_graph(&graph.graph) {
+        return Err(PyValueError::new_err("graph must be connected"));
+    }
+    graph.generation += 1;
+    let mut edges: Vec<EdgeIndex> = graph.graph.edge_indices().collect();
+    if edges.len() < 2 {
+        return Ok(0);
+    }
+    let mut rng: Pcg64 = match seed {
+        Some(seed) => Pcg64::seed_from_u64(seed),
+        None => Pcg64::from_entropy(),
+    };
+    let mut swaps = 0;
+    let mut tries = 0;
+    let mut window = 1;
+    let mut done: Vec<EdgeSwap> = Vec::new();
+    while swaps < nswap && tries < max_tries {
+        while done.len() < window && swaps + done.len() < nswap && tries < max_tries {
+            tries += 1;
+            if let Some(swap) = try_edge_swap(
+                &mut graph.graph,
+                &mut graph.attributes,
+                &mut edges,
+                &mut rng,
+            ) {
+                done.push(swap);
+            }
+        }
+        if is_connected_graph(&graph.graph) {
+            swaps += done.len();
+            done.clear();
+            window += 1;
+        } else {
+            while let Some(swap) = done.pop() {
+                undo_edge_swap(&mut graph.graph, &mut graph.attributes, &mut edges, swap);
+            }
+            window -= window / 2;
+        }
+    }
+    Ok(swaps)
+}
@@ -0,0 +1,455 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use crate::attributes::AttributeColumns;
+use crate::iterators::NodeMap;
+use crate::node_keys::NodeKeys;
+use crate::{digraph, graph, StablePyGraph};
+
+use hashbrown::{HashMap, HashSet};
+
+use petgraph::stable_graph::{EdgeIndex, NodeIndex};
+use petgraph::visit::{EdgeRef, IntoEdgeReferences};
+use petgraph::{Direction, EdgeType};
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::Python;
+
+use retworkx_core::dictmap::*;
+use retworkx_core::isomorphism::subgraph_isomorphism_mappings;
+
+/// An occurrence of the pattern of a rule in the graph being rewritten
+/// that satisfies the gluing conditions.
+struct Redex {
+    /// The host node matched by every pattern node index
+    node_map: DictMap<usize, NodeIndex>,
+    /// The host edges matched by the pattern edges
+    edges: Vec<EdgeIndex>,
+    /// The host nodes matched by the pattern nodes outside of the interface
+    nodes: Vec<NodeIndex>,
+}
+
+type Rewrite = (DictMap<usize, usize>, Vec<(NodeIndex, NodeIndex, PyObject)>);
+
+fn matches(
+    py: Python,
+    matcher: &Option<PyObject>,
+    host: &PyObject,
+    pattern: &PyObject,
+) -> PyResult<bool> {
+    match matcher {
+        Some(matcher) => matcher.call1(py, (host, pattern))?.is_true(py),
+        None => Ok(true),
+    }
+}
+
+fn check_rule<Ty: EdgeType>(
+    pattern: &StablePyGraph<Ty>,
+    replacement: &StablePyGraph<Ty>,
+    interface: &HashMap<usize, usize>,
+) -> PyResult<()> {
+    if pattern.node_count() == 0 {
+        return Err(PyValueError::new_err(
+            "The pattern must have at least one node",
+        ));
+    }
+    let mut preserved: HashSet<usize> = HashSet::with_capacity(interface.len());
+    for (left, right) in interface {
+        if !pattern.contains_node(NodeIndex::new(*left)) {
+            return Err(PyValueError::new_err(format!(
+                "Interface node {} is not in the pattern",
+                left
+            )));
+        }
+        if !replacement.contains_node(NodeIndex::new(*right)) {
+            return Err(PyValueError::new_err(format!(
+                "Interface node {} is not in the replacement",
+                right
+            )));
+        }
+        if !preserved.insert(*right) {
+            return Err(PyValueError::new_err(format!(
+                "Replacement node {} is mapped to by multiple pattern nodes",
+                right
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// Find the first match of the pattern in the graph for which every pattern
+/// edge can be assigned a distinct host edge and deleting the nodes outside
+/// of the interface doesn't leave dangling edges.
+fn find_redex<Ty: EdgeType>(
+    py: Python,
+    graph: &StablePyGraph<Ty>,
+    pattern: &StablePyGraph<Ty>,
+    interface: &HashMap<usize, usize>,
+    node_matcher: &Option<PyObject>,
+    edge_matcher: &Option<PyObject>,
+    induced: bool,
+) -> PyResult<Option<Redex>> {
+    let mappings = subgraph_isomorphism_mappings(
+        graph,
+        pattern,
+        |host: &PyObject, pattern: &PyObject| matches(py, node_matcher, host, pattern),
+        |host: &PyObject, pattern: &PyObject| matches(py, edge_matcher, host, pattern),
+        induced,
+    );
+    'mappings: for mapping in mappings {
+        let node_map: DictMap<usize, NodeIndex> = mapping?
+            .into_iter()
+            .map(|(host, pattern)| (pattern.index(), host))
+            .collect();
+
+        // Assign the pattern edges to host edges one to one, which is only
+        // ambiguous in the presence of parallel edges.
+        let mut edges: Vec<EdgeIndex> = Vec::with_capacity(pattern.edge_count());
+        let mut used: HashSet<EdgeIndex> = HashSet::with_capacity(pattern.edge_count());
+        for edge in pattern.edge_references() {
+            let source = node_map[&edge.source().index()];
+            let target = node_map[&edge.target().index()];
+            let mut found = None;
+            for candidate in graph.edges(source) {
+                if candidate.target() == target
+                    && !used.contains(&candidate.id())
+                    && matches(py, edge_matcher, candidate.weight(), edge.weight())?
+                {
+                    found = Some(candidate.id());
+                    break;
+                }
+            }
+            match found {
+                Some(index) => {
+                    used.insert(index);
+                    edges.push(index);
+                }
+                None => continue 'mappings,
+            }
+        }
+
+        let nodes: Vec<NodeIndex> = pattern
+            .node_indices()
+            .filter(|node| !interface.contains_key(&node.index()))
+            .map(|node| node_map[&node.index()])
+            .collect();
+        // The dangling condition: every host edge of a deleted node must
+        // be deleted with it.
+        for node in &nodes {
+            let dangling = graph
+                .edges_directed(*node, Direction::Outgoing)
+                .chain(graph.edges_directed(*node, Direction::Incoming))
+                .any(|edge| !used.contains(&edge.id()));
+            if dangling {
+                continue 'mappings;
+            }
+        }
+        return Ok(Some(Redex {
+            node_map,
+            edges,
+            nodes,
+        }));
+    }
+    Ok(None)
+}
+
+/// Find a redex and rewrite it, deleting the matched edges and the matched
+/// nodes outside of the interface and adding the replacement nodes outside
+/// of the interface. The replacement edges are returned instead of being
+/// added so the caller can add them with the graph's own edge semantics.
+#[allow(clippy::too_many_arguments)]
+fn rewrite_step<Ty: EdgeType>(
+    py: Python,
+    graph: &mut StablePyGraph<Ty>,
+    node_keys: &mut NodeKeys,
+    attributes: &mut AttributeColumns,
+    pattern: &StablePyGraph<Ty>,
+    replacement: &StablePyGraph<Ty>,
+    interface: &HashMap<usize, usize>,
+    node_matcher: &Option<PyObject>,
+    edge_matcher: &Option<PyObject>,
+    induced: bool,
+) -> PyResult<Option<Rewrite>> {
+    let redex = match find_redex(
+        py,
+        graph,
+        pattern,
+        interface,
+        node_matcher,
+        edge_matcher,
+        induced,
+    )? {
+        Some(redex) => redex,
+        None => return Ok(None),
+    };
+    let preserved: HashMap<usize, NodeIndex> = interface
+        .iter()
+        .map(|(left, right)| (*right, redex.node_map[left]))
+        .collect();
+    for edge in redex.edges {
+        graph.remove_edge(edge);
+        attributes.remove_edge(edge);
+    }
+    for node in redex.nodes {
+        attributes.remove_node(graph, node);
+        graph.remove_node(node);
+        node_keys.remove(node);
+    }
+
+    let mut node_map: DictMap<usize, usize> = DictMap::with_capacity(replacement.node_count());
+    for node in replacement.node_indices() {
+        let host = match preserved.get(&node.index()) {
+            Some(host) => *host,
+            None => graph.add_node(replacement[node].clone_ref(py)),
+        };
+        node_map.insert(node.index(), host.index());
+    }
+    let edges = replacement
+        .edge_references()
+        .map(|edge| {
+            (
+                NodeIndex::new(node_map[&edge.source().index()]),
+                NodeIndex::new(node_map[&edge.target().index()]),
+                edge.weight().clone_ref(py),
+            )
+        })
+        .collect();
+    Ok(Some((node_map, edges)))
+}
+
+/// Rewrite a :class:`~retworkx.PyGraph` with a double-pushout rule
+///
+/// A rule is made of a ``pattern`` graph (the left hand side), a
+/// ``replacement`` graph (the right hand side) and an ``interface`` mapping
+/// the pattern nodes that are preserved by the rule to replacement nodes.
+/// Every application of the rule finds a match of ``pattern`` in ``graph``
+/// with the same subgraph matching as
+/// :func:`~retworkx.is_subgraph_isomorphic` and then:
+///
+/// 1. removes the edges matched by the pattern edges and the nodes matched
+///    by the pattern nodes outside of the interface,
+/// 2. adds a node for every replacement node outside of the interface, with
+///    the weight/data payload of the replacement node,
+/// 3. adds an edge for every replacement edge, with the weight/data payload of
+///    the replacement edge.
+///
+/// Preserved nodes keep their weight/data payload and any edges not matched
+/// by the pattern. An edge that should be preserved has to be present in both
+/// ``pattern`` and ``replacement``. A match is only applied if no edge would
+/// be left dangling, that is if every edge of a removed node is matched by a
+/// pattern edge, otherwise the next match is tried.
+///
+/// The graph is matched again after every application, until no applicable
+/// match is left or ``max_applications`` rules were applied. If the
+/// replacement can create new matches of the pattern the rewriting might not
+/// terminate unless ``max_applications`` is set.
+///
+/// :param PyGraph graph: The graph to rewrite in place
+/// :param PyGraph pattern: The left hand side of the rule
+/// :param PyGraph replacement: The right hand side of the rule
+/// :param dict interface: A mapping of the node indices of the preserved
+///     nodes in ``pattern`` to their node indices in ``replacement``
+/// :param callable node_matcher: An optional callable that will be passed the
+///     weight/data payloads of a node in ``graph`` and a node in ``pattern``
+///     and returns ``True`` if they can be matched. If not specified the
+///     payloads are not compared.
+/// :param callable edge_matcher: An optional callable that will be passed the
+///     weight/data payloads of an edge in ``graph`` and an edge in
+///     ``pattern`` and returns ``True`` if they can be matched. If not
+///     specified the payloads are not compared.
+/// :param bool induced: If set to ``True`` a match must not have edges
+///     between the matched nodes that are not in ``pattern``. Defaults to
+///     ``False``.
+/// :param int max_applications: The maximum number of times to apply the
+///     rule. If not specified the rule is applied until there is no
+///     applicable match.
+///
+/// :returns: A list with a mapping of the node indices of ``replacement`` to
+///     the node indices in ``graph`` for every application of the rule
+/// :rtype: list
+/// :raises ValueError: If ``pattern`` is empty or ``interface`` is not an
+///     injective mapping of nodes of ``pattern`` to nodes of ``replacement``
+#[pyfunction(induced = "false")]
+#[pyo3(
+    text_signature = "(graph, pattern, replacement, interface, /, node_matcher=None,
+                    edge_matcher=None, induced=False, max_applications=None)"
+)]
+#[allow(clippy::too_many_arguments)]
+pub fn graph_rewrite(
+    py: Python,
+    graph: &mut graph::PyGraph,
+    pattern: &graph::PyGraph,
+    replacement: &graph::PyGraph,
+    interface: HashMap<usize, usize>,
+    node_matcher: Option<PyObject>,
+    edge_matcher: Option<PyObject>,
+    induced: bool,
+    max_applications: Option<usize>,
+) -> PyResult<Vec<NodeMap>> {
+    check_rule(&pattern.graph, &replacement.graph, &interface)?;
+    graph.generation += 1;
+    let removes_nodes = pattern.graph.node_count() > interface.len();
+    let mut out: Vec<NodeMap> = Vec::new();
+    while max_applications != Some(out.len()) {
+        let (node_map, edges) = match rewrite_step(
+            py,
+            &mut graph.graph,
+            &mut graph.node_keys,
+            &mut graph.attributes,
+            &pattern.graph,
+            &replacement.graph,
+            &interface,
+            &node_matcher,
+            &edge_matcher,
+            induced,
+        )? {
+            Some(rewrite) => rewrite,
+            None => break,
+        };
+        graph.node_removed |= removes_nodes;
+        for (source, target, weight) in edges {
+            graph.add_edge(source.index(), target.index(), weight)?;
+        }
+        out.push(NodeMap { node_map });
+    }
+    Ok(out)
+}
+
+/// Rewrite a :class:`~retworkx.PyDiGraph` with a double-pushout rule
+///
+/// A rule is made of a ``pattern`` graph (the left hand side), a
+/// ``replacement`` graph (the right hand side) and an ``interface`` mapping
+/// the pattern nodes that are preserved by the rule to replacement nodes.
+/// Every application of the rule finds a match of ``pattern`` in ``graph``
+/// with the same subgraph matching as
+/// :func:`~retworkx.is_subgraph_isomorphic` and then:
+///
+/// 1. removes the edges matched by the pattern edges and the nodes matched
+///    by the pattern nodes outside of the interface,
+/// 2. adds a node for every replacement node outside of the interface, with
+///    the weight/data payload of the replacement node,
+/// 3. adds an edge for every replacement edge, with the weight/data payload of
+///    the replacement edge.
+///
+/// Preserved nodes keep their weight/data payload and any edges not matched
+/// by the pattern. An edge that should be preserved has to be present in both
+/// ``pattern`` and ``replacement``. A match is only applied if no edge would
+/// be left dangling, that is if every edge of a removed node is matched by a
+/// pattern edge, otherwise the next match is tried.
+///
+/// The graph is matched again after every application, until no applicable
+/// match is left or ``max_applications`` rules were applied. If the
+/// replacement can create new matches of the pattern the rewriting might not
+/// terminate unless ``max_applications`` is set.
+///
+/// If :attr:`~retworkx.PyDiGraph.check_cycle` is ``True`` and an application
+/// would introduce a cycle, that application is undone and
+/// :class:`~retworkx.DAGWouldCycle` is raised. The earlier applications are
+/// kept.
+///
+/// :param PyDiGraph graph: The graph to rewrite in place
+/// :param PyDiGraph pattern: The left hand side of the rule
+/// :param PyDiGraph replacement: The right hand side of the rule
+/// :param dict interface: A mapping of the node indices of the preserved
+///     nodes in ``pattern`` to their node indices in ``replacement``
+/// :param callable node_matcher: An optional callable that will be passed the
+///     weight/data payloads of a node in ``graph`` and a node in ``pattern``
+///     and returns ``True`` if they can be matched. If not specified the
+///     payloads are not compared.
+/// :param callable edge_matcher: An optional callable that will be passed the
+///     weight/data payloads of an edge in ``graph`` and an edge in
+///     ``pattern`` and returns ``True`` if they can be matched. If not
+///     specified the payloads are not compared.
+/// :param bool induced: If set to ``True`` a match must not have edges
+///     between the matched nodes that are not in ``pattern``. Defaults to
+///     ``False``.
+/// :param int max_applications: The maximum number of times to apply the
+///     rule. If not specified the rule is applied until there is no
+///     applicable match.
+///
+/// :returns: A list with a mapping of the node indices of ``replacement`` to
+///     the node indices in ``graph`` for every application of the rule
+/// :rtype: list
+/// :raises ValueError: If ``pattern`` is empty or ``interface`` is not an
+///     injective mapping of nodes of ``pattern`` to nodes of ``replacement``
+/// :raises DAGWouldCycle: If an application would introduce a cycle in a
+///     graph with ``check_cycle`` set
+#[pyfunction(induced = "false")]
+#[pyo3(
+    text_signature = "(graph, pattern, replacement, interface, /, node_matcher=None,
+                    edge_matcher=None, induced=False, max_applications=None)"
+)]
+#[allow(clippy::too_many_arguments)]
+pub fn digraph_rewrite(
+    py: Python,
+    graph: &mut digraph::PyDiGraph,
+    pattern: &digraph::PyDiGraph,
+    replacement: &digraph::PyDiGraph,
+    interface: HashMap<usize, usize>,
+    node_matcher: Option<PyObject>,
+    edge_matcher: Option<PyObject>,
+    induced: bool,
+    max_applications: Option<usize>,
+) -> PyResult<Vec<NodeMap>> {
+    check_rule(&pattern.graph, &replacement.graph, &interface)?;
+    graph.generation += 1;
+    let removes_nodes = pattern.graph.node_count() > interface.len();
+    let mut out: Vec<NodeMap> = Vec::new();
+    while max_applications != Some(out.len()) {
+        // Keep a copy of the graph to undo the application if it would
+        // introduce a cycle.
+        let backup = if graph.check_cycle {
+            Some((
+                graph.graph.clone(),
+                graph.node_removed,
+                graph.node_keys.clone(),
+                graph.attributes.clone(),
+            ))
+        } else {
+            None
+        };
+        let (node_map, edges) = match rewrite_step(
+            py,
+            &mut graph.graph,
+            &mut graph.node_keys,
+            &mut graph.attributes,
+            &pattern.graph,
+            &replacement.graph,
+            &interface,
+            &node_matcher,
+            &edge_matcher,
+            induced,
+        )? {
+            Some(rewrite) => rewrite,
+            None => break,
+        };
+        graph.node_removed |= removes_nodes;
+        let added: PyResult<Vec<usize>> = edges
+            .into_iter()
+            .map(|(source, target, weight)| graph.add_edge(source.index(), target.index(), weight))
+            .collect();
+        if let Err(err) = added {
+            if let Some((backup, node_removed, node_keys, attributes)) = backup {
+                graph.graph = backup;
+                graph.node_removed = node_removed;
+                graph.node_keys = node_keys;
+                graph.attributes = attributes;
+                // The restored edges may not agree with the updated order
+                graph.cycle_state = None;
+            }
+            return Err(err);
+        }
+        out.push(NodeMap { node_map });
+    }
+    Ok(out)
+}
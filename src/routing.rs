@@ -0,0 +1,411 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use std::convert::TryFrom;
+
+use crate::iterators::NodeIndices;
+use crate::{digraph, graph, CostFn, InvalidNode, StablePyGraph};
+
+use retworkx_core::dictmap::*;
+use retworkx_core::routing;
+
+use petgraph::prelude::*;
+use petgraph::EdgeType;
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::Python;
+
+/// A route built by :func:`~retworkx.savings_routes` or
+/// :func:`~retworkx.sweep_routes`
+///
+/// :ivar NodeIndices nodes: The node indices visited by the route in order,
+///     starting and ending at the depot with the customers of the route in
+///     between
+/// :ivar float load: The total demand of the customers of the route
+/// :ivar float cost: The total length of the shortest paths between
+///     consecutive nodes of the route. It's ``inf`` if one of them isn't
+///     reachable from the previous one.
+#[pyclass(module = "retworkx")]
+pub struct VehicleRoute {
+    #[pyo3(get)]
+    pub nodes: NodeIndices,
+    #[pyo3(get)]
+    pub load: f64,
+    #[pyo3(get)]
+    pub cost: f64,
+}
+
+impl From<routing::Route<NodeIndex>> for VehicleRoute {
+    fn from(route: routing::Route<NodeIndex>) -> Self {
+        VehicleRoute {
+            nodes: NodeIndices {
+                nodes: route.nodes.iter().map(|node| node.index()).collect(),
+            },
+            load: route.load,
+            cost: route.cost,
+        }
+    }
+}
+
+/// The customers and their demands, after checking them against the graph,
+/// the depot and the capacity.
+fn customers<Ty: EdgeType>(
+    graph: &StablePyGraph<Ty>,
+    depot: usize,
+    demands: DictMap<usize, f64>,
+    capacity: f64,
+) -> PyResult<Vec<(NodeIndex, f64)>> {
+    for node in Some(depot).iter().chain(demands.keys()) {
+        if !graph.contains_node(NodeIndex::new(*node)) {
+            return Err(InvalidNode::new_err(format!(
+                "Node index {} is not in the graph",
+                node
+            )));
+        }
+    }
+    if demands.contains_key(&depot) {
+        return Err(PyValueError::new_err("The depot can't have a demand"));
+    }
+    demands
+        .into_iter()
+        .map(|(node, demand)| {
+            if demand.is_nan() || demand < 0. {
+                return Err(PyValueError::new_err(format!(
+                    "The demand of node {} is negative or NaN",
+                    node
+                )));
+            }
+            if demand > capacity {
+                return Err(PyValueError::new_err(format!(
+                    "The demand of node {} exceeds the capacity",
+                    node
+                )));
+            }
+            Ok((NodeIndex::new(node), demand))
+        })
+        .collect()
+}
+
+fn savings_routes<Ty: EdgeType>(
+    py: Python,
+    graph: &StablePyGraph<Ty>,
+    depot: usize,
+    demands: DictMap<usize, f64>,
+    capacity: f64,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+) -> PyResult<Vec<VehicleRoute>> {
+    let customers = customers(graph, depot, demands, capacity)?;
+    let cost_fn = CostFn::try_from((weight_fn, default_weight))?;
+    let routes =
+        routing::savings_routes(graph, NodeIndex::new(depot), &customers, capacity, |edge| {
+            cost_fn.call(py, edge.weight())
+        })?;
+    Ok(routes.into_iter().map(VehicleRoute::from).collect())
+}
+
+#[allow(clippy::too_many_arguments)]
+fn sweep_routes<Ty: EdgeType>(
+    py: Python,
+    graph: &StablePyGraph<Ty>,
+    depot: usize,
+    demands: DictMap<usize, f64>,
+    capacity: f64,
+    pos: &PyAny,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+) -> PyResult<Vec<VehicleRoute>> {
+    let customers = customers(graph, depot, demands, capacity)?;
+    let cost_fn = CostFn::try_from((weight_fn, default_weight))?;
+    let routes = routing::sweep_routes(
+        graph,
+        NodeIndex::new(depot),
+        &customers,
+        capacity,
+        |node| {
+            let position: Vec<f64> = pos.get_item(node.index())?.extract()?;
+            match position.as_slice() {
+                [x, y] => Ok((*x, *y)),
+                _ => Err(PyValueError::new_err(format!(
+                    "The position of node {} must have 2 coordinates",
+                    node.index()
+                ))),
+            }
+        },
+        |edge| cost_fn.call(py, edge.weight()),
+    )?;
+    Ok(routes.into_iter().map(VehicleRoute::from).collect())
+}
+
+/// Build capacitated vehicle routes on a PyGraph with the Clarke-Wright
+/// savings heuristic
+///
+/// The routes start and end at the ``depot`` node and together visit every
+/// node with a demand, its customers, once, without the total demand of
+/// the customers of a route exceeding ``capacity``. The cost of going from
+/// a node to another is the length of the shortest path between them.
+///
+/// Every customer starts in a route of its own, then the routes are merged
+/// in decreasing order of the savings ``d(i, depot) + d(depot, j) - d(i, j)``
+/// of going directly from customer ``i`` to customer ``j`` instead of
+/// through the depot, as long as ``i`` and ``j`` are at an end of their
+/// routes and the merged route doesn't exceed the capacity [1]_.
+/// This is a construction heuristic: the routes are good but not optimal.
+///
+/// :param PyGraph graph: The graph to route on
+/// :param int depot: The node index every route starts and ends at
+/// :param dict demands: A dictionary of the node indices of the customers to
+///     their demands. The routes are returned in the order of their first
+///     customer in this dictionary.
+/// :param float capacity: The maximum total demand of the customers of a
+///     route
+/// :param weight_fn: An optional callable that will be passed the edge's
+///     payload object and is expected to return a non-negative ``float``
+///     cost for that edge.
+/// :param float default_weight: If ``weight_fn`` isn't specified this
+///     optional float value will be used for the cost of every edge.
+///     Defaults to 1.0.
+///
+/// :returns: The routes, the cost of a route is ``inf`` if a customer can't
+///     be reached from the depot
+/// :rtype: list[VehicleRoute]
+/// :raises InvalidNode: If the depot or a customer is not in the graph
+/// :raises ValueError: If the depot has a demand, or the demand of a
+///     customer is negative or exceeds the capacity
+///
+/// .. [1] G. Clarke and J. W. Wright, "Scheduling of Vehicles from a Central
+///     Depot to a Number of Delivery Points", Operations Research 12 (4),
+///     1964, pp. 568-581, https://doi.org/10.1287/opre.12.4.568
+#[pyfunction(default_weight = "1.0")]
+#[pyo3(text_signature = "(graph, depot, demands, capacity, /, weight_fn=None, default_weight=1.0)")]
+pub fn graph_savings_routes(
+    py: Python,
+    graph: &graph::PyGraph,
+    depot: usize,
+    demands: DictMap<usize, f64>,
+    capacity: f64,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+) -> PyResult<Vec<VehicleRoute>> {
+    savings_routes(
+        py,
+        &graph.graph,
+        depot,
+        demands,
+        capacity,
+        weight_fn,
+        default_weight,
+    )
+}
+
+/// Build capacitated vehicle routes on a PyDiGraph with the Clarke-Wright
+/// savings heuristic
+///
+/// The routes start and end at the ``depot`` node and together visit every
+/// node with a demand, its customers, once, without the total demand of
+/// the customers of a route exceeding ``capacity``. The cost of going from
+/// a node to another is the length of the shortest path between them
+/// following the direction of the edges.
+///
+/// Every customer starts in a route of its own, then the routes are merged
+/// in decreasing order of the savings ``d(i, depot) + d(depot, j) - d(i, j)``
+/// of going directly from customer ``i`` to customer ``j`` instead of
+/// through the depot, as long as ``i`` is the last customer of its route,
+/// ``j`` is the first customer of its route and the merged route doesn't
+/// exceed the capacity [1]_. This is a construction heuristic: the
+/// routes are good but not optimal.
+///
+/// :param PyDiGraph graph: The graph to route on
+/// :param int depot: The node index every route starts and ends at
+/// :param dict demands: A dictionary of the node indices of the customers to
+///     their demands. The routes are returned in the order of their first
+///     customer in this dictionary.
+/// :param float capacity: The maximum total demand of the customers of a
+///     route
+/// :param weight_fn: An optional callable that will be passed the edge's
+///     payload object and is expected to return a non-negative ``float``
+///     cost for that edge.
+/// :param float default_weight: If ``weight_fn`` isn't specified this
+///     optional float value will be used for the cost of every edge.
+///     Defaults to 1.0.
+///
+/// :returns: The routes, the cost of a route is ``inf`` if a customer can't
+///     be reached from the depot or the depot from a customer
+/// :rtype: list[VehicleRoute]
+/// :raises InvalidNode: If the depot or a customer is not in the graph
+/// :raises ValueError: If the depot has a demand, or the demand of a
+///     customer is negative or exceeds the capacity
+///
+/// .. [1] G. Clarke and J. W. Wright, "Scheduling of Vehicles from a Central
+///     Depot to a Number of Delivery Points", Operations Research 12 (4),
+///     1964, pp. 568-581, https://doi.org/10.1287/opre.12.4.568
+#[pyfunction(default_weight = "1.0")]
+#[pyo3(text_signature = "(graph, depot, demands, capacity, /, weight_fn=None, default_weight=1.0)")]
+pub fn digraph_savings_routes(
+    py: Python,
+    graph: &digraph::PyDiGraph,
+    depot: usize,
+    demands: DictMap<usize, f64>,
+    capacity: f64,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+) -> PyResult<Vec<VehicleRoute>> {
+    savings_routes(
+        py,
+        &graph.graph,
+        depot,
+        demands,
+        capacity,
+        weight_fn,
+        default_weight,
+    )
+}
+
+/// Build capacitated vehicle routes on a PyGraph with the sweep heuristic
+///
+/// The routes start and end at the ``depot`` node and together visit every
+/// node with a demand, its customers, once, without the total demand of
+/// the customers of a route exceeding ``capacity``. The cost of going from
+/// a node to another is the length of the shortest path between them.
+///
+/// The customers are sorted by the polar angle of their position around the
+/// position of the depot, from :math:`-\pi` to :math:`\pi`, and split in
+/// that order into routes, a new route starting when the next customer
+/// doesn't fit in the current one [1]_. The customers of a route
+/// are visited in the order of their angles. This is a construction
+/// heuristic: the routes are good but not optimal.
+///
+/// :param PyGraph graph: The graph to route on
+/// :param int depot: The node index every route starts and ends at
+/// :param dict demands: A dictionary of the node indices of the customers to
+///     their demands. Customers with the same angle are visited in the order
+///     of this dictionary.
+/// :param float capacity: The maximum total demand of the customers of a
+///     route
+/// :param pos: A mapping of the node indices of the depot and the customers
+///     to their ``[x, y]`` positions, such as a ``dict`` or the output of a
+///     layout function like :func:`~retworkx.spring_layout`
+/// :param weight_fn: An optional callable that will be passed the edge's
+///     payload object and is expected to return a non-negative ``float``
+///     cost for that edge.
+/// :param float default_weight: If ``weight_fn`` isn't specified this
+///     optional float value will be used for the cost of every edge.
+///     Defaults to 1.0.
+///
+/// :returns: The routes in the order of the angles of their customers, the
+///     cost of a route is ``inf`` if a customer can't be reached from the
+///     previous node of the route
+/// :rtype: list[VehicleRoute]
+/// :raises InvalidNode: If the depot or a customer is not in the graph
+/// :raises ValueError: If the depot has a demand, or the demand of a
+///     customer is negative or exceeds the capacity
+///
+/// .. [1] B. E. Gillett and L. R. Miller, "A Heuristic Algorithm for the
+///     Vehicle-Dispatch Problem", Operations Research 22 (2), 1974,
+///     pp. 340-349, https://doi.org/10.1287/opre.22.2.340
+#[pyfunction(default_weight = "1.0")]
+#[pyo3(
+    text_signature = "(graph, depot, demands, capacity, pos, /, weight_fn=None, default_weight=1.0)"
+)]
+#[allow(clippy::too_many_arguments)]
+pub fn graph_sweep_routes(
+    py: Python,
+    graph: &graph::PyGraph,
+    depot: usize,
+    demands: DictMap<usize, f64>,
+    capacity: f64,
+    pos: &PyAny,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+) -> PyResult<Vec<VehicleRoute>> {
+    sweep_routes(
+        py,
+        &graph.graph,
+        depot,
+        demands,
+        capacity,
+        pos,
+        weight_fn,
+        default_weight,
+    )
+}
+
+/// Build capacitated vehicle routes on a PyDiGraph with the sweep heuristic
+///
+/// The routes start and end at the ``depot`` node and together visit every
+/// node with a demand, its customers, once, without the total demand of
+/// the customers of a route exceeding ``capacity``. The cost of going from
+/// a node to another is the length of the shortest path between them
+/// following the direction of the edges.
+///
+/// The customers are sorted by the polar angle of their position around the
+/// position of the depot, from :math:`-\pi` to :math:`\pi`, and split in
+/// that order into routes, a new route starting when the next customer
+/// doesn't fit in the current one [1]_. The customers of a route
+/// are visited in the order of their angles. This is a construction
+/// heuristic: the routes are good but not optimal.
+///
+/// :param PyDiGraph graph: The graph to route on
+/// :param int depot: The node index every route starts and ends at
+/// :param dict demands: A dictionary of the node indices of the customers to
+///     their demands. Customers with the same angle are visited in the order
+///     of this dictionary.
+/// :param float capacity: The maximum total demand of the customers of a
+///     route
+/// :param pos: A mapping of the node indices of the depot and the customers
+///     to their ``[x, y]`` positions, such as a ``dict`` or the output of a
+///     layout function like :func:`~retworkx.spring_layout`
+/// :param weight_fn: An optional callable that will be passed the edge's
+///     payload object and is expected to return a non-negative ``float``
+///     cost for that edge.
+/// :param float default_weight: If ``weight_fn`` isn't specified this
+///     optional float value will be used for the cost of every edge.
+///     Defaults to 1.0.
+///
+/// :returns: The routes in the order of the angles of their customers, the
+///     cost of a route is ``inf`` if a customer can't be reached from the
+///     previous node of the route
+/// :rtype: list[VehicleRoute]
+/// :raises InvalidNode: If the depot or a customer is not in the graph
+/// :raises ValueError: If the depot has a demand, or the demand of a
+///     customer is negative or exceeds the capacity
+///
+/// .. [1] B. E. Gillett and L. R. Miller, "A Heuristic Algorithm for the
+///     Vehicle-Dispatch Problem", Operations Research 22 (2), 1974,
+///     pp. 340-349, https://doi.org/10.1287/opre.22.2.340
+#[pyfunction(default_weight = "1.0")]
+#[pyo3(
+    text_signature = "(graph, depot, demands, capacity, pos, /, weight_fn=None, default_weight=1.0)"
+)]
+#[allow(clippy::too_many_arguments)]
+pub fn digraph_sweep_routes(
+    py: Python,
+    graph: &digraph::PyDiGraph,
+    depot: usize,
+    demands: DictMap<usize, f64>,
+    capacity: f64,
+    pos: &PyAny,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+) -> PyResult<Vec<VehicleRoute>> {
+    sweep_routes(
+        py,
+        &graph.graph,
+        depot,
+        demands,
+        capacity,
+        pos,
+        weight_fn,
+        default_weight,
+    )
+}
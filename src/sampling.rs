@@ -0,0 +1,629 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use std::iter;
+
+use crate::attributes::AttributeColumns;
+use crate::iterators::NodeMap;
+use crate::node_keys::NodeKeys;
+use crate::{digraph, graph, is_valid_weight, weight_callable, InvalidNode, StablePyGraph};
+
+use numpy::IntoPyArray;
+
+use petgraph::prelude::*;
+use petgraph::visit::{IntoEdgeReferences, NodeIndexable};
+use petgraph::EdgeType;
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::Python;
+
+use retworkx_core::dictmap::*;
+use retworkx_core::sampling;
+
+/// The subgraph of ``nodes`` and ``edges`` of a graph, which must be sorted
+/// by index, with their payloads, and the map of the node indices of the
+/// graph to the node indices of the subgraph.
+fn sampled_subgraph<Ty: EdgeType>(
+    py: Python,
+    graph: &StablePyGraph<Ty>,
+    nodes: &[NodeIndex],
+    edges: &[EdgeIndex],
+) -> (StablePyGraph<Ty>, NodeMap) {
+    let mut out_graph = StablePyGraph::<Ty>::with_capacity(nodes.len(), edges.len());
+    let mut node_map: DictMap<usize, usize> = DictMap::with_capacity(nodes.len());
+    for node in nodes {
+        let new_node = out_graph.add_node(graph[*node].clone_ref(py));
+        node_map.insert(node.index(), new_node.index());
+    }
+    for edge in edges {
+        let (source, target) = graph.edge_endpoints(*edge).unwrap();
+        out_graph.add_edge(
+            NodeIndex::new(node_map[&source.index()]),
+            NodeIndex::new(node_map[&target.index()]),
+            graph[*edge].clone_ref(py),
+        );
+    }
+    (out_graph, NodeMap { node_map })
+}
+
+/// The subgraph induced by ``nodes``, see :func:`sampled_subgraph`.
+fn induced_subgraph<Ty: EdgeType>(
+    py: Python,
+    graph: &StablePyGraph<Ty>,
+    mut nodes: Vec<NodeIndex>,
+) -> (StablePyGraph<Ty>, NodeMap) {
+    nodes.sort_unstable();
+    let mut sampled = vec![false; graph.node_bound()];
+    for node in &nodes {
+        sampled[node.index()] = true;
+    }
+    let edges: Vec<EdgeIndex> = graph
+        .edge_references()
+        .filter(|edge| sampled[edge.source().index()] && sampled[edge.target().index()])
+        .map(|edge| edge.id())
+        .collect();
+    sampled_subgraph(py, graph, &nodes, &edges)
+}
+
+fn check_num_nodes<Ty: EdgeType>(graph: &StablePyGraph<Ty>, num_nodes: usize) -> PyResult<()> {
+    if num_nodes > graph.node_count() {
+        return Err(PyValueError::new_err(format!(
+            "num_nodes is {}, but the graph only has {} nodes",
+            num_nodes,
+            graph.node_count()
+        )));
+    }
+    Ok(())
+}
+
+fn random_node_sample<Ty: EdgeType>(
+    py: Python,
+    graph: &StablePyGraph<Ty>,
+    num_nodes: usize,
+    seed: Option<u64>,
+) -> PyResult<(StablePyGraph<Ty>, NodeMap)> {
+    check_num_nodes(graph, num_nodes)?;
+    let nodes = sampling::random_node_sample(graph, num_nodes, seed);
+    Ok(induced_subgraph(py, graph, nodes))
+}
+
+fn random_edge_sample<Ty: EdgeType>(
+    py: Python,
+    graph: &StablePyGraph<Ty>,
+    num_edges: usize,
+    seed: Option<u64>,
+) -> PyResult<(StablePyGraph<Ty>, NodeMap)> {
+    if num_edges > graph.edge_count() {
+        return Err(PyValueError::new_err(format!(
+            "num_edges is {}, but the graph only has {} edges",
+            num_edges,
+            graph.edge_count()
+        )));
+    }
+    let edges: Vec<EdgeIndex> = sampling::random_edge_sample(graph, num_edges, seed)
+        .into_iter()
+        .map(|edge| edge.id())
+        .collect();
+    let mut nodes: Vec<NodeIndex> = edges
+        .iter()
+        .flat_map(|edge| {
+            let (source, target) = graph.edge_endpoints(*edge).unwrap();
+            vec![source, target]
+        })
+        .collect();
+    nodes.sort_unstable();
+    nodes.dedup();
+    Ok(sampled_subgraph(py, graph, &nodes, &edges))
+}
+
+fn forest_fire_sample<Ty: EdgeType>(
+    py: Python,
+    graph: &StablePyGraph<Ty>,
+    num_nodes: usize,
+    burn_probability: f64,
+    seed: Option<u64>,
+) -> PyResult<(StablePyGraph<Ty>, NodeMap)> {
+    check_num_nodes(graph, num_nodes)?;
+    if !(0.0..1.0).contains(&burn_probability) {
+        return Err(PyValueError::new_err(
+            "burn_probability must be at least 0 and less than 1",
+        ));
+    }
+    let nodes = sampling::forest_fire_sample(graph, num_nodes, burn_probability, seed);
+    Ok(induced_subgraph(py, graph, nodes))
+}
+
+fn new_graph(graph: &graph::PyGraph, sample: StablePyGraph<Undirected>) -> graph::PyGraph {
+    graph::PyGraph {
+        graph: sample,
+        node_removed: false,
+        multigraph: graph.multigraph,
+        node_keys: NodeKeys::default(),
+        attributes: AttributeColumns::default(),
+        generation: 0,
+    }
+}
+
+fn new_digraph(graph: &digraph::PyDiGraph, sample: StablePyGraph<Directed>) -> digraph::PyDiGraph {
+    digraph::PyDiGraph {
+        graph: sample,
+        node_removed: false,
+        cycle_state: None,
+        check_cycle: graph.check_cycle,
+        multigraph: graph.multigraph,
+        node_keys: NodeKeys::default(),
+        attributes: AttributeColumns::default(),
+        generation: 0,
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn random_walks<Ty: EdgeType>(
+    py: Python,
+    graph: &StablePyGraph<Ty>,
+    start_nodes: Option<Vec<usize>>,
+    walk_length: usize,
+    num_walks: usize,
+    p: f64,
+    q: f64,
+    seed: Option<u64>,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+    parallel_threshold: usize,
+) -> PyResult<PyObject> {
+    if p.is_nan() || q.is_nan() || p <= 0.0 || q <= 0.0 {
+        return Err(PyValueError::new_err("p and q must be positive"));
+    }
+    let start_nodes: Vec<NodeIndex> = match start_nodes {
+        Some(start_nodes) => start_nodes
+            .into_iter()
+            .map(|node| {
+                let index = NodeIndex::new(node);
+                if graph.contains_node(index) {
+                    Ok(index)
+                } else {
+                    Err(InvalidNode::new_err(format!(
+                        "Node index {} is not in the graph",
+                        node
+                    )))
+                }
+            })
+            .collect::<PyResult<Vec<NodeIndex>>>()?,
+        None => graph.node_indices().collect(),
+    };
+    let walks = sampling::random_walks(
+        graph,
+        |edge| {
+            let weight = weight_callable(py, &weight_fn, edge.weight(), default_weight)?;
+            is_valid_weight(weight)
+        },
+        &start_nodes,
+        walk_length,
+        num_walks,
+        p,
+        q,
+        seed,
+        parallel_threshold,
+    )?;
+    let mut out: Vec<i64> = Vec::with_capacity(walks.len() * walk_length);
+    for walk in walks {
+        let padding = walk_length - walk.len();
+        out.extend(walk.into_iter().map(|node| node.index() as i64));
+        out.extend(iter::repeat(-1).take(padding));
+    }
+    Ok(out.into_pyarray(py).into())
+}
+
+/// Sample random walks of a PyGraph, with the second order bias of node2vec
+///
+/// Every walk starts at a node of ``start_nodes`` and moves to a neighbor of
+/// the current node at every step, picked with a probability proportional
+/// to the total weight of the edges to it times a bias depending on the
+/// previous node of the walk [1]_: :math:`1/p` to go back to the previous
+/// node, 1 to go to a neighbor of the previous node and :math:`1/q` to go
+/// further away. A low ``p`` keeps the walks close to their start nodes,
+/// like a breadth first search, and a low ``q`` pushes them away, like a
+/// depth first search. With ``p`` and ``q`` set to 1 (the default) these
+/// are unbiased weighted random walks, as in DeepWalk.
+///
+/// Every neighbor is picked in constant time with an alias table and the
+/// bias is applied by rejection sampling. Edges with a weight of 0 are
+/// never followed, and a walk stops early if it reaches a node without an
+/// edge of positive weight. The walks are sampled on multiple threads if there are at
+/// least ``parallel_threshold`` of them, every walk has its own random
+/// number generator so the walks only depend on ``seed``.
+///
+/// The walks are returned in a flat array of ``walk_length`` node indices
+/// per walk, so it can be reshaped into a walk per row, for example to
+/// train node embeddings with a skip-gram model::
+///
+///     walks = retworkx.graph_random_walks(graph, walk_length=80, num_walks=10)
+///     sentences = walks.reshape(-1, 80)
+///
+/// :param PyGraph graph: The graph to walk
+/// :param list start_nodes: The node indices to start the walks from. If not
+///     specified the walks start from every node.
+/// :param int walk_length: The number of nodes of every walk, including its
+///     start node. Defaults to 80.
+/// :param int num_walks: The number of walks from every start node.
+///     Defaults to 10.
+/// :param float p: The return parameter, which must be positive. Defaults
+///     to 1.0.
+/// :param float q: The in-out parameter, which must be positive. Defaults
+///     to 1.0.
+/// :param int seed: An optional seed to use for the random number generator
+/// :param weight_fn: An optional callable that will be passed the edge's
+///     payload object and is expected to return a non-negative ``float``
+///     weight for that edge.
+/// :param float default_weight: If ``weight_fn`` isn't specified this
+///     optional float value will be used for the weight of every edge.
+///     Defaults to 1.0.
+/// :param int parallel_threshold: The number of walks from which they are
+///     sampled on multiple threads. Defaults to 300.
+///
+/// :returns: A 1 dimensional ``numpy.int64`` array of the node indices of
+///     ``num_walks`` rounds of walks, every round has a walk from every
+///     start node, in the order of ``start_nodes``. A walk that stopped early
+///     is padded with ``-1`` up to ``walk_length``.
+/// :rtype: numpy.ndarray
+/// :raises InvalidNode: If a start node isn't in the graph
+/// :raises ValueError: If ``p`` or ``q`` isn't positive or if an edge has a
+///     negative or NaN weight
+///
+/// .. [1] A. Grover and J. Leskovec, "node2vec: Scalable Feature Learning
+///     for Networks", Proceedings of the 22nd ACM SIGKDD International
+///     Conference on Knowledge Discovery and Data Mining, 855-864, 2016.
+#[pyfunction(
+    walk_length = "80",
+    num_walks = "10",
+    p = "1.0",
+    q = "1.0",
+    default_weight = "1.0",
+    parallel_threshold = "300"
+)]
+#[pyo3(
+    text_signature = "(graph, /, start_nodes=None, walk_length=80, num_walks=10, p=1.0, q=1.0, seed=None, weight_fn=None, default_weight=1.0, parallel_threshold=300)"
+)]
+#[allow(clippy::too_many_arguments)]
+pub fn graph_random_walks(
+    py: Python,
+    graph: &graph::PyGraph,
+    start_nodes: Option<Vec<usize>>,
+    walk_length: usize,
+    num_walks: usize,
+    p: f64,
+    q: f64,
+    seed: Option<u64>,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+    parallel_threshold: usize,
+) -> PyResult<PyObject> {
+    random_walks(
+        py,
+        &graph.graph,
+        start_nodes,
+        walk_length,
+        num_walks,
+        p,
+        q,
+        seed,
+        weight_fn,
+        default_weight,
+        parallel_threshold,
+    )
+}
+
+/// Sample random walks of a PyDiGraph, with the second order bias of
+/// node2vec
+///
+/// Every walk starts at a node of ``start_nodes`` and follows an outgoing
+/// edge of the current node at every step, to a successor picked with a
+/// probability proportional to the total weight of the edges to it times a
+/// bias depending on the previous node of the walk [1]_: :math:`1/p` to go
+/// back to the previous node, 1 to go to a successor of the previous node
+/// and :math:`1/q` to go further away. A low ``p`` keeps the walks close to
+/// their start nodes, like a breadth first search, and a low ``q`` pushes
+/// them away, like a depth first search. With ``p`` and ``q`` set to 1 (the
+/// default) these are unbiased weighted random walks, as in DeepWalk.
+///
+/// Every successor is picked in constant time with an alias table and the
+/// bias is applied by rejection sampling. Edges with a weight of 0 are
+/// never followed, and a walk stops early if it reaches a node without an
+/// outgoing edge of positive weight. The walks are sampled on multiple threads if there
+/// are at least ``parallel_threshold`` of them, every walk has its own
+/// random number generator so the walks only depend on ``seed``.
+///
+/// The walks are returned in a flat array of ``walk_length`` node indices
+/// per walk, so it can be reshaped into a walk per row, for example to
+/// train node embeddings with a skip-gram model::
+///
+///     walks = retworkx.digraph_random_walks(graph, walk_length=80, num_walks=10)
+///     sentences = walks.reshape(-1, 80)
+///
+/// :param PyDiGraph graph: The graph to walk
+/// :param list start_nodes: The node indices to start the walks from. If not
+///     specified the walks start from every node.
+/// :param int walk_length: The number of nodes of every walk, including its
+///     start node. Defaults to 80.
+/// :param int num_walks: The number of walks from every start node.
+///     Defaults to 10.
+/// :param float p: The return parameter, which must be positive. Defaults
+///     to 1.0.
+/// :param float q: The in-out parameter, which must be positive. Defaults
+///     to 1.0.
+/// :param int seed: An optional seed to use for the random number generator
+/// :param weight_fn: An optional callable that will be passed the edge's
+///     payload object and is expected to return a non-negative ``float``
+///     weight for that edge.
+/// :param float default_weight: If ``weight_fn`` isn't specified this
+///     optional float value will be used for the weight of every edge.
+///     Defaults to 1.0.
+/// :param int parallel_threshold: The number of walks from which they are
+///     sampled on multiple threads. Defaults to 300.
+///
+/// :returns: A 1 dimensional ``numpy.int64`` array of the node indices of
+///     ``num_walks`` rounds of walks, every round has a walk from every
+///     start node, in the order of ``start_nodes``. A walk that stopped early
+///     is padded with ``-1`` up to ``walk_length``.
+/// :rtype: numpy.ndarray
+/// :raises InvalidNode: If a start node isn't in the graph
+/// :raises ValueError: If ``p`` or ``q`` isn't positive or if an edge has a
+///     negative or NaN weight
+///
+/// .. [1] A. Grover and J. Leskovec, "node2vec: Scalable Feature Learning
+///     for Networks", Proceedings of the 22nd ACM SIGKDD International
+///     Conference on Knowledge Discovery and Data Mining, 855-864, 2016.
+#[pyfunction(
+    walk_length = "80",
+    num_walks = "10",
+    p = "1.0",
+    q = "1.0",
+    default_weight = "1.0",
+    parallel_threshold = "300"
+)]
+#[pyo3(
+    text_signature = "(graph, /, start_nodes=None, walk_length=80, num_walks=10, p=1.0, q=1.0, seed=None, weight_fn=None, default_weight=1.0, parallel_threshold=300)"
+)]
+#[allow(clippy::too_many_arguments)]
+pub fn digraph_random_walks(
+    py: Python,
+    graph: &digraph::PyDiGraph,
+    start_nodes: Option<Vec<usize>>,
+    walk_length: usize,
+    num_walks: usize,
+    p: f64,
+    q: f64,
+    seed: Option<u64>,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+    parallel_threshold: usize,
+) -> PyResult<PyObject> {
+    random_walks(
+        py,
+        &graph.graph,
+        start_nodes,
+        walk_length,
+        num_walks,
+        p,
+        q,
+        seed,
+        weight_fn,
+        default_weight,
+        parallel_threshold,
+    )
+}
+
+/// Sample nodes of a PyGraph uniformly at random and return the subgraph they
+/// induce
+///
+/// Every set of ``num_nodes`` nodes is as likely to be sampled. The nodes of
+/// the subgraph have the same degree distribution on average as the nodes
+/// of the graph, but the subgraph has few of its edges as every edge is
+/// only kept if both its endpoints are sampled.
+///
+/// :param PyGraph graph: The graph to sample
+/// :param int num_nodes: The number of nodes to sample
+/// :param int seed: An optional seed to use for the random number generator
+///
+/// :returns: A tuple of the subgraph as a new :class:`~retworkx.PyGraph`
+///     with the node and edge payloads of the input graph, and a mapping of
+///     the node indices in ``graph`` to the node indices in the subgraph.
+/// :rtype: Tuple[PyGraph, NodeMap]
+/// :raises ValueError: If ``num_nodes`` is larger than the number of nodes
+///     of the graph
+#[pyfunction]
+#[pyo3(text_signature = "(graph, num_nodes, /, seed=None)")]
+pub fn graph_random_node_sample(
+    py: Python,
+    graph: &graph::PyGraph,
+    num_nodes: usize,
+    seed: Option<u64>,
+) -> PyResult<(graph::PyGraph, NodeMap)> {
+    let (sample, node_map) = random_node_sample(py, &graph.graph, num_nodes, seed)?;
+    Ok((new_graph(graph, sample), node_map))
+}
+
+/// Sample edges of a PyGraph uniformly at random and return the subgraph of
+/// the sampled edges and their endpoints
+///
+/// Every set of ``num_edges`` edges is as likely to be sampled. The
+/// subgraph is biased towards the nodes of high degree, which are more
+/// likely to be an endpoint of a sampled edge.
+///
+/// :param PyGraph graph: The graph to sample
+/// :param int num_edges: The number of edges to sample
+/// :param int seed: An optional seed to use for the random number generator
+///
+/// :returns: A tuple of the subgraph as a new :class:`~retworkx.PyGraph`
+///     with the node and edge payloads of the input graph, and a mapping of
+///     the node indices in ``graph`` to the node indices in the subgraph.
+/// :rtype: Tuple[PyGraph, NodeMap]
+/// :raises ValueError: If ``num_edges`` is larger than the number of edges
+///     of the graph
+#[pyfunction]
+#[pyo3(text_signature = "(graph, num_edges, /, seed=None)")]
+pub fn graph_random_edge_sample(
+    py: Python,
+    graph: &graph::PyGraph,
+    num_edges: usize,
+    seed: Option<u64>,
+) -> PyResult<(graph::PyGraph, NodeMap)> {
+    let (sample, node_map) = random_edge_sample(py, &graph.graph, num_edges, seed)?;
+    Ok((new_graph(graph, sample), node_map))
+}
+
+/// Sample nodes of a PyGraph by forest fire sampling and return the
+/// subgraph they induce
+///
+/// A fire starts at a node picked uniformly at random and spreads to the
+/// neighbors of every burning node [1]_: every burning node sets fire to
+/// :math:`x` of its neighbors that aren't burnt yet, picked uniformly at random, where :math:`x` is geometrically
+/// distributed with a mean of :math:`p / (1 - p)` for a
+/// ``burn_probability`` of :math:`p`. When the fire dies out before
+/// ``num_nodes`` nodes are burnt a new one starts at a random node that
+/// isn't burnt yet. The subgraph induced by the burnt nodes keeps many
+/// properties of the graph, such as its degree distribution and its
+/// clustering, better than uniform sampling.
+///
+/// :param PyGraph graph: The graph to sample
+/// :param int num_nodes: The number of nodes to sample
+/// :param float burn_probability: The forward burning probability, at least
+///     0 and less than 1. Defaults to 0.7.
+/// :param int seed: An optional seed to use for the random number generator
+///
+/// :returns: A tuple of the subgraph as a new :class:`~retworkx.PyGraph`
+///     with the node and edge payloads of the input graph, and a mapping of
+///     the node indices in ``graph`` to the node indices in the subgraph.
+/// :rtype: Tuple[PyGraph, NodeMap]
+/// :raises ValueError: If ``num_nodes`` is larger than the number of nodes
+///     of the graph or if ``burn_probability`` isn't in :math:`[0, 1)`
+///
+/// .. [1] J. Leskovec and C. Faloutsos, "Sampling from Large Graphs",
+///     Proceedings of the 12th ACM SIGKDD International Conference on
+///     Knowledge Discovery and Data Mining, 631-636, 2006.
+#[pyfunction(burn_probability = "0.7")]
+#[pyo3(text_signature = "(graph, num_nodes, /, burn_probability=0.7, seed=None)")]
+pub fn graph_forest_fire_sample(
+    py: Python,
+    graph: &graph::PyGraph,
+    num_nodes: usize,
+    burn_probability: f64,
+    seed: Option<u64>,
+) -> PyResult<(graph::PyGraph, NodeMap)> {
+    let (sample, node_map) =
+        forest_fire_sample(py, &graph.graph, num_nodes, burn_probability, seed)?;
+    Ok((new_graph(graph, sample), node_map))
+}
+
+/// Sample nodes of a PyDiGraph uniformly at random and return the subgraph they
+/// induce
+///
+/// Every set of ``num_nodes`` nodes is as likely to be sampled. The nodes of
+/// the subgraph have the same degree distribution on average as the nodes
+/// of the graph, but the subgraph has few of its edges as every edge is
+/// only kept if both its endpoints are sampled.
+///
+/// :param PyDiGraph graph: The graph to sample
+/// :param int num_nodes: The number of nodes to sample
+/// :param int seed: An optional seed to use for the random number generator
+///
+/// :returns: A tuple of the subgraph as a new :class:`~retworkx.PyDiGraph`
+///     with the node and edge payloads of the input graph, and a mapping of
+///     the node indices in ``graph`` to the node indices in the subgraph.
+/// :rtype: Tuple[PyDiGraph, NodeMap]
+/// :raises ValueError: If ``num_nodes`` is larger than the number of nodes
+///     of the graph
+#[pyfunction]
+#[pyo3(text_signature = "(graph, num_nodes, /, seed=None)")]
+pub fn digraph_random_node_sample(
+    py: Python,
+    graph: &digraph::PyDiGraph,
+    num_nodes: usize,
+    seed: Option<u64>,
+) -> PyResult<(digraph::PyDiGraph, NodeMap)> {
+    let (sample, node_map) = random_node_sample(py, &graph.graph, num_nodes, seed)?;
+    Ok((new_digraph(graph, sample), node_map))
+}
+
+/// Sample edges of a PyDiGraph uniformly at random and return the subgraph of
+/// the sampled edges and their endpoints
+///
+/// Every set of ``num_edges`` edges is as likely to be sampled. The
+/// subgraph is biased towards the nodes of high degree, which are more
+/// likely to be an endpoint of a sampled edge.
+///
+/// :param PyDiGraph graph: The graph to sample
+/// :param int num_edges: The number of edges to sample
+/// :param int seed: An optional seed to use for the random number generator
+///
+/// :returns: A tuple of the subgraph as a new :class:`~retworkx.PyDiGraph`
+///     with the node and edge payloads of the input graph, and a mapping of
+///     the node indices in ``graph`` to the node indices in the subgraph.
+/// :rtype: Tuple[PyDiGraph, NodeMap]
+/// :raises ValueError: If ``num_edges`` is larger than the number of edges
+///     of the graph
+#[pyfunction]
+#[pyo3(text_signature = "(graph, num_edges, /, seed=None)")]
+pub fn digraph_random_edge_sample(
+    py: Python,
+    graph: &digraph::PyDiGraph,
+    num_edges: usize,
+    seed: Option<u64>,
+) -> PyResult<(digraph::PyDiGraph, NodeMap)> {
+    let (sample, node_map) = random_edge_sample(py, &graph.graph, num_edges, seed)?;
+    Ok((new_digraph(graph, sample), node_map))
+}
+
+/// Sample nodes of a PyDiGraph by forest fire sampling and return the
+/// subgraph they induce
+///
+/// A fire starts at a node picked uniformly at random and spreads to the
+/// neighbors of every burning node, ignoring the direction of the edges
+/// [1]_: every burning node sets fire to :math:`x` of its neighbors that
+/// aren't burnt yet, picked uniformly at random, where :math:`x` is geometrically
+/// distributed with a mean of :math:`p / (1 - p)` for a
+/// ``burn_probability`` of :math:`p`. When the fire dies out before
+/// ``num_nodes`` nodes are burnt a new one starts at a random node that
+/// isn't burnt yet. The subgraph induced by the burnt nodes keeps many
+/// properties of the graph, such as its degree distribution and its
+/// clustering, better than uniform sampling.
+///
+/// :param PyDiGraph graph: The graph to sample
+/// :param int num_nodes: The number of nodes to sample
+/// :param float burn_probability: The forward burning probability, at least
+///     0 and less than 1. Defaults to 0.7.
+/// :param int seed: An optional seed to use for the random number generator
+///
+/// :returns: A tuple of the subgraph as a new :class:`~retworkx.PyDiGraph`
+///     with the node and edge payloads of the input graph, and a mapping of
+///     the node indices in ``graph`` to the node indices in the subgraph.
+/// :rtype: Tuple[PyDiGraph, NodeMap]
+/// :raises ValueError: If ``num_nodes`` is larger than the number of nodes
+///     of the graph or if ``burn_probability`` isn't in :math:`[0, 1)`
+///
+/// .. [1] J. Leskovec and C. Faloutsos, "Sampling from Large Graphs",
+///     Proceedings of the 12th ACM SIGKDD International Conference on
+///     Knowledge Discovery and Data Mining, 631-636, 2006.
+#[pyfunction(burn_probability = "0.7")]
+#[pyo3(text_signature = "(graph, num_nodes, /, burn_probability=0.7, seed=None)")]
+pub fn digraph_forest_fire_sample(
+    py: Python,
+    graph: &digraph::PyDiGraph,
+    num_nodes: usize,
+    burn_probability: f64,
+    seed: Option<u64>,
+) -> PyResult<(digraph::PyDiGraph, NodeMap)> {
+    let (sample, node_map) =
+        forest_fire_sample(py, &graph.graph, num_nodes, burn_probability, seed)?;
+    Ok((new_digraph(graph, sample), node_map))
+}
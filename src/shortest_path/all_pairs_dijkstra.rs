@@ -13,16 +13,20 @@
 use hashbrown::HashMap;
 
 use retworkx_core::dictmap::*;
-use retworkx_core::shortest_path::dijkstra;
+use retworkx_core::shortest_path::{
+    all_pairs_dijkstra_path_lengths_chunked as all_pairs_dijkstra_path_lengths_chunked_core,
+    dijkstra,
+};
 
 use std::sync::RwLock;
 
-use pyo3::exceptions::PyIndexError;
+use pyo3::exceptions::{PyIndexError, PyValueError};
 use pyo3::prelude::*;
 use pyo3::Python;
 
 use petgraph::graph::NodeIndex;
 use petgraph::prelude::*;
+use petgraph::stable_graph::EdgeReference;
 use petgraph::visit::EdgeIndexable;
 use petgraph::EdgeType;
 
@@ -184,3 +188,53 @@ pub fn all_pairs_dijkstra_shortest_paths<Ty: EdgeType + Sync>(
     };
     Ok(out_map)
 }
+
+pub fn all_pairs_dijkstra_path_lengths_chunked<Ty: EdgeType + Sync>(
+    py: Python,
+    graph: &StablePyGraph<Ty>,
+    edge_cost_fn: PyObject,
+    callback: PyObject,
+    chunk_size: usize,
+) -> PyResult<()> {
+    if chunk_size == 0 {
+        return Err(PyValueError::new_err("chunk_size must be larger than 0"));
+    }
+    let edge_cost_callable = CostFn::from(edge_cost_fn);
+    let mut edge_weights: Vec<Option<f64>> = Vec::with_capacity(graph.edge_bound());
+    for index in 0..=graph.edge_bound() {
+        let raw_weight = graph.edge_weight(EdgeIndex::new(index));
+        match raw_weight {
+            Some(weight) => edge_weights.push(Some(edge_cost_callable.call(py, weight)?)),
+            None => edge_weights.push(None),
+        };
+    }
+    let edge_cost = |e: EdgeReference<PyObject>| -> PyResult<f64> {
+        match edge_weights[e.id().index()] {
+            Some(weight) => Ok(weight),
+            None => Err(PyIndexError::new_err("No edge found for index")),
+        }
+    };
+    all_pairs_dijkstra_path_lengths_chunked_core(graph, edge_cost, chunk_size, 0, |chunk| {
+        let path_lengths = chunk
+            .into_iter()
+            .map(|(source, row)| {
+                let out_map = PathLengthMapping {
+                    path_lengths: row
+                        .into_iter()
+                        .enumerate()
+                        .filter_map(|(index, opt_cost)| {
+                            if index != source.index() {
+                                opt_cost.map(|cost| (index, cost))
+                            } else {
+                                None
+                            }
+                        })
+                        .collect(),
+                };
+                (source.index(), out_map)
+            })
+            .collect();
+        callback.call1(py, (AllPairsPathLengthMapping { path_lengths },))?;
+        Ok(())
+    })
+}
@@ -13,7 +13,7 @@
 use hashbrown::HashMap;
 use retworkx_core::dictmap::*;
 
-use crate::{get_edge_iter_with_weights, weight_callable};
+use crate::{deterministic_order, get_edge_iter_with_weights, weight_callable};
 
 use pyo3::prelude::*;
 use pyo3::Python;
@@ -131,8 +131,10 @@ pub fn floyd_warshall<Ty: EdgeType>(
     let out_map: DictMap<usize, PathLengthMapping> = graph
         .node_indices()
         .map(|i| {
+            let mut row: Vec<(usize, f64)> = mat[i.index()].iter().map(|(k, v)| (*k, *v)).collect();
+            deterministic_order(&mut row, |(k, _)| *k);
             let out_map = PathLengthMapping {
-                path_lengths: mat[i.index()].iter().map(|(k, v)| (*k, *v)).collect(),
+                path_lengths: row.into_iter().collect(),
             };
             (i.index(), out_map)
         })
@@ -14,22 +14,22 @@ pub mod all_pairs_dijkstra;
 mod average_length;
 mod distance_matrix;
 mod floyd_warshall;
+pub mod neighborhood_function;
 mod num_shortest_path;
 
-use std::convert::TryFrom;
-
 use crate::{digraph, graph, CostFn, NoPathFound};
 
 use pyo3::prelude::*;
 use pyo3::Python;
 
-use petgraph::graph::NodeIndex;
+use petgraph::graph::{EdgeIndex, NodeIndex};
 use petgraph::visit::NodeCount;
 
 use numpy::IntoPyArray;
 
 use retworkx_core::dictmap::*;
-use retworkx_core::shortest_path::{astar, dijkstra, k_shortest_path};
+use retworkx_core::shortest_path::{astar, dijkstra, dijkstra_edge_paths, k_shortest_path};
+use retworkx_core::views::as_undirected as as_undirected_view;
 
 use crate::iterators::{
     AllPairsPathLengthMapping, AllPairsPathMapping, NodeIndices, NodesCountMapping,
@@ -51,14 +51,24 @@ use crate::iterators::{
 ///     float value will be used for the weight/cost of each edge.
 /// :param bool as_undirected: If set to true the graph will be treated as
 ///     undirected for finding the shortest path.
+/// :param str weight_attr: An optional name of an edge attribute with the
+///     weight/cost of the edges, as an alternative to ``weight_fn`` which
+///     doesn't call into Python for every edge. The weights are read from the
+///     edge attribute column with this name if the graph has one, see
+///     :meth:`~retworkx.PyGraph.set_edge_attribute`, otherwise from this key
+///     of the edges with a dict payload. Edges without a weight use
+///     ``default_weight``.
 ///
 /// :return: Dictionary of paths. The keys are destination node indices and
 ///     the dict values are lists of node indices making the path.
 /// :rtype: dict
 /// :raises ValueError: when an edge weight with NaN or negative value
-///     is provided.
+///     is provided, or if both ``weight_fn`` and ``weight_attr`` are
+///     specified.
 #[pyfunction(default_weight = "1.0", as_undirected = "false")]
-#[pyo3(text_signature = "(graph, source, /, target=None weight_fn=None, default_weight=1.0)")]
+#[pyo3(
+    text_signature = "(graph, source, /, target=None weight_fn=None, default_weight=1.0, weight_attr=None)"
+)]
 pub fn graph_dijkstra_shortest_paths(
     py: Python,
     graph: &graph::PyGraph,
@@ -66,18 +76,26 @@ pub fn graph_dijkstra_shortest_paths(
     target: Option<usize>,
     weight_fn: Option<PyObject>,
     default_weight: f64,
+    weight_attr: Option<&str>,
 ) -> PyResult<PathMapping> {
     let start = NodeIndex::new(source);
     let goal_index: Option<NodeIndex> = target.map(NodeIndex::new);
     let mut paths: DictMap<NodeIndex, Vec<NodeIndex>> = DictMap::with_capacity(graph.node_count());
 
-    let cost_fn = CostFn::try_from((weight_fn, default_weight))?;
+    let cost_fn = CostFn::from_weight_spec(
+        py,
+        &graph.graph,
+        &graph.attributes,
+        weight_fn,
+        weight_attr,
+        default_weight,
+    )?;
 
     (dijkstra(
         &graph.graph,
         start,
         goal_index,
-        |e| cost_fn.call(py, e.weight()),
+        |e| cost_fn.call_edge(py, e),
         Some(&mut paths),
     ) as PyResult<Vec<Option<f64>>>)?;
 
@@ -114,16 +132,25 @@ pub fn graph_dijkstra_shortest_paths(
 ///     float value will be used for the weight/cost of each edge.
 /// :param bool as_undirected: If set to true the graph will be treated as
 ///     undirected for finding the shortest path.
+/// :param str weight_attr: An optional name of an edge attribute with the
+///     weight/cost of the edges, as an alternative to ``weight_fn`` which
+///     doesn't call into Python for every edge. The weights are read from the
+///     edge attribute column with this name if the graph has one, see
+///     :meth:`~retworkx.PyDiGraph.set_edge_attribute`, otherwise from this key
+///     of the edges with a dict payload. Edges without a weight use
+///     ``default_weight``.
 ///
 /// :return: Dictionary of paths. The keys are destination node indices and
 ///     the dict values are lists of node indices making the path.
 /// :rtype: dict
 /// :raises ValueError: when an edge weight with NaN or negative value
-///     is provided.
+///     is provided, or if both ``weight_fn`` and ``weight_attr`` are
+///     specified.
 #[pyfunction(default_weight = "1.0", as_undirected = "false")]
 #[pyo3(
-    text_signature = "(graph, source, /, target=None weight_fn=None, default_weight=1.0, as_undirected=False)"
+    text_signature = "(graph, source, /, target=None weight_fn=None, default_weight=1.0, as_undirected=False, weight_attr=None)"
 )]
+#[allow(clippy::too_many_arguments)]
 pub fn digraph_dijkstra_shortest_paths(
     py: Python,
     graph: &digraph::PyDiGraph,
@@ -132,21 +159,26 @@ pub fn digraph_dijkstra_shortest_paths(
     weight_fn: Option<PyObject>,
     default_weight: f64,
     as_undirected: bool,
+    weight_attr: Option<&str>,
 ) -> PyResult<PathMapping> {
     let start = NodeIndex::new(source);
     let goal_index: Option<NodeIndex> = target.map(NodeIndex::new);
     let mut paths: DictMap<NodeIndex, Vec<NodeIndex>> = DictMap::with_capacity(graph.node_count());
-    let cost_fn = CostFn::try_from((weight_fn, default_weight))?;
+    let cost_fn = CostFn::from_weight_spec(
+        py,
+        &graph.graph,
+        &graph.attributes,
+        weight_fn,
+        weight_attr,
+        default_weight,
+    )?;
 
     if as_undirected {
         (dijkstra(
-            // TODO: Use petgraph undirected adapter after
-            // https://github.com/petgraph/petgraph/pull/318 is available in
-            // a petgraph release.
-            &graph.to_undirected(py, true, None)?.graph,
+            as_undirected_view(&graph.graph),
             start,
             goal_index,
-            |e| cost_fn.call(py, e.weight()),
+            |e| cost_fn.call_edge(py, e),
             Some(&mut paths),
         ) as PyResult<Vec<Option<f64>>>)?;
     } else {
@@ -154,7 +186,7 @@ pub fn digraph_dijkstra_shortest_paths(
             &graph.graph,
             start,
             goal_index,
-            |e| cost_fn.call(py, e.weight()),
+            |e| cost_fn.call_edge(py, e),
             Some(&mut paths),
         ) as PyResult<Vec<Option<f64>>>)?;
     }
@@ -173,6 +205,160 @@ pub fn digraph_dijkstra_shortest_paths(
     })
 }
 
+/// Find the edges of the shortest path from a node
+///
+/// This function will generate the shortest path from a source node using
+/// Dijkstra's algorithm. Unlike :func:`~retworkx.graph_dijkstra_shortest_paths`
+/// the paths are returned as the indices of the edges traversed. On a
+/// multigraph, where several parallel edges can connect the same pair of
+/// nodes, this identifies exactly which (cheapest) edge is used at each step.
+///
+/// :param PyGraph graph:
+/// :param int source: The node index to find paths from
+/// :param int target: An optional target to find a path to
+/// :param weight_fn: An optional weight function for an edge. It will accept
+///     a single argument, the edge's weight object and will return a float which
+///     will be used to represent the weight/cost of the edge
+/// :param float default_weight: If ``weight_fn`` isn't specified this optional
+///     float value will be used for the weight/cost of each edge.
+/// :param str weight_attr: An optional name of an edge attribute with the
+///     weight/cost of the edges, as an alternative to ``weight_fn`` which
+///     doesn't call into Python for every edge. The weights are read from the
+///     edge attribute column with this name if the graph has one, see
+///     :meth:`~retworkx.PyGraph.set_edge_attribute`, otherwise from this key
+///     of the edges with a dict payload. Edges without a weight use
+///     ``default_weight``.
+///
+/// :return: Dictionary of paths. The keys are destination node indices and
+///     the dict values are lists of edge indices making the path.
+/// :rtype: PathMapping
+/// :raises ValueError: when an edge weight with NaN or negative value
+///     is provided, or if both ``weight_fn`` and ``weight_attr`` are
+///     specified.
+#[pyfunction(default_weight = "1.0")]
+#[pyo3(
+    text_signature = "(graph, source, /, target=None, weight_fn=None, default_weight=1.0, weight_attr=None)"
+)]
+pub fn graph_dijkstra_shortest_path_edges(
+    py: Python,
+    graph: &graph::PyGraph,
+    source: usize,
+    target: Option<usize>,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+    weight_attr: Option<&str>,
+) -> PyResult<PathMapping> {
+    let start = NodeIndex::new(source);
+    let goal_index: Option<NodeIndex> = target.map(NodeIndex::new);
+    let mut edge_paths: DictMap<NodeIndex, Vec<EdgeIndex>> =
+        DictMap::with_capacity(graph.node_count());
+    let cost_fn = CostFn::from_weight_spec(
+        py,
+        &graph.graph,
+        &graph.attributes,
+        weight_fn,
+        weight_attr,
+        default_weight,
+    )?;
+
+    (dijkstra_edge_paths(
+        &graph.graph,
+        start,
+        goal_index,
+        |e| cost_fn.call_edge(py, e),
+        &mut edge_paths,
+    ) as PyResult<Vec<Option<f64>>>)?;
+
+    Ok(edge_path_mapping(edge_paths, source, target))
+}
+
+/// Find the edges of the shortest path from a node
+///
+/// This function will generate the shortest path from a source node using
+/// Dijkstra's algorithm. Unlike :func:`~retworkx.digraph_dijkstra_shortest_paths`
+/// the paths are returned as the indices of the edges traversed. On a
+/// multigraph, where several parallel edges can connect the same pair of
+/// nodes, this identifies exactly which (cheapest) edge is used at each step.
+///
+/// :param PyDiGraph graph:
+/// :param int source: The node index to find paths from
+/// :param int target: An optional target to find a path to
+/// :param weight_fn: An optional weight function for an edge. It will accept
+///     a single argument, the edge's weight object and will return a float which
+///     will be used to represent the weight/cost of the edge
+/// :param float default_weight: If ``weight_fn`` isn't specified this optional
+///     float value will be used for the weight/cost of each edge.
+/// :param str weight_attr: An optional name of an edge attribute with the
+///     weight/cost of the edges, as an alternative to ``weight_fn`` which
+///     doesn't call into Python for every edge. The weights are read from the
+///     edge attribute column with this name if the graph has one, see
+///     :meth:`~retworkx.PyDiGraph.set_edge_attribute`, otherwise from this key
+///     of the edges with a dict payload. Edges without a weight use
+///     ``default_weight``.
+///
+/// :return: Dictionary of paths. The keys are destination node indices and
+///     the dict values are lists of edge indices making the path.
+/// :rtype: PathMapping
+/// :raises ValueError: when an edge weight with NaN or negative value
+///     is provided, or if both ``weight_fn`` and ``weight_attr`` are
+///     specified.
+#[pyfunction(default_weight = "1.0")]
+#[pyo3(
+    text_signature = "(graph, source, /, target=None, weight_fn=None, default_weight=1.0, weight_attr=None)"
+)]
+pub fn digraph_dijkstra_shortest_path_edges(
+    py: Python,
+    graph: &digraph::PyDiGraph,
+    source: usize,
+    target: Option<usize>,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+    weight_attr: Option<&str>,
+) -> PyResult<PathMapping> {
+    let start = NodeIndex::new(source);
+    let goal_index: Option<NodeIndex> = target.map(NodeIndex::new);
+    let mut edge_paths: DictMap<NodeIndex, Vec<EdgeIndex>> =
+        DictMap::with_capacity(graph.node_count());
+    let cost_fn = CostFn::from_weight_spec(
+        py,
+        &graph.graph,
+        &graph.attributes,
+        weight_fn,
+        weight_attr,
+        default_weight,
+    )?;
+
+    (dijkstra_edge_paths(
+        &graph.graph,
+        start,
+        goal_index,
+        |e| cost_fn.call_edge(py, e),
+        &mut edge_paths,
+    ) as PyResult<Vec<Option<f64>>>)?;
+
+    Ok(edge_path_mapping(edge_paths, source, target))
+}
+
+fn edge_path_mapping(
+    edge_paths: DictMap<NodeIndex, Vec<EdgeIndex>>,
+    source: usize,
+    target: Option<usize>,
+) -> PathMapping {
+    PathMapping {
+        paths: edge_paths
+            .into_iter()
+            .filter_map(|(k, v)| {
+                let k_int = k.index();
+                if k_int == source || target.is_some() && target.unwrap() != k_int {
+                    None
+                } else {
+                    Some((k_int, v.iter().map(|x| x.index()).collect::<Vec<usize>>()))
+                }
+            })
+            .collect(),
+    }
+}
+
 /// Compute the lengths of the shortest paths for a PyGraph object using
 /// Dijkstra's algorithm
 ///
@@ -351,6 +537,55 @@ pub fn digraph_all_pairs_dijkstra_path_lengths(
     all_pairs_dijkstra::all_pairs_dijkstra_path_lengths(py, &graph.graph, edge_cost_fn)
 }
 
+/// For each node in the graph, calculates the lengths of the shortest paths
+/// to all others in a :class:`~retworkx.PyDiGraph` object and passes them to a
+/// callback in chunks
+///
+/// This function computes the same path lengths as
+/// :func:`~retworkx.digraph_all_pairs_dijkstra_path_lengths` but instead of
+/// building the lengths for every source node in a single mapping, the source
+/// nodes are processed ``chunk_size`` at a time and ``callback`` is called
+/// with the lengths for each chunk before the next chunk is computed. This
+/// bounds the memory used to ``chunk_size`` rows at once, which enables
+/// processing graphs where the full all-pairs output would not fit in memory.
+/// The rows of each chunk are computed in parallel and will launch a thread
+/// pool with threads equal to the number of CPUs by default. You can tune the
+/// number of threads with the ``RAYON_NUM_THREADS`` environment variable.
+///
+/// :param graph: The input :class:`~retworkx.PyDiGraph` to use
+/// :param edge_cost_fn: A callable object that acts as a weight function for
+///     an edge. It will accept a single positional argument, the edge's weight
+///     object and will return a float which will be used to represent the
+///     weight/cost of the edge
+/// :param callback: A callable object that will be called once per chunk
+///     with a single positional argument, an
+///     :class:`~retworkx.AllPairsPathLengthMapping` of the source node
+///     indices in the chunk to the lengths of the shortest paths from that
+///     node. Source nodes are delivered in node index order. If it raises an
+///     exception no more chunks are computed and the exception is raised.
+/// :param int chunk_size: The number of source nodes in each chunk, it must
+///     be larger than 0. Defaults to 1000.
+///
+/// :raises ValueError: when an edge weight with NaN or negative value
+///     is provided or ``chunk_size`` is 0.
+#[pyfunction(chunk_size = "1000")]
+#[pyo3(text_signature = "(graph, edge_cost_fn, callback, /, chunk_size=1000)")]
+pub fn digraph_all_pairs_dijkstra_path_lengths_chunked(
+    py: Python,
+    graph: &digraph::PyDiGraph,
+    edge_cost_fn: PyObject,
+    callback: PyObject,
+    chunk_size: usize,
+) -> PyResult<()> {
+    all_pairs_dijkstra::all_pairs_dijkstra_path_lengths_chunked(
+        py,
+        &graph.graph,
+        edge_cost_fn,
+        callback,
+        chunk_size,
+    )
+}
+
 /// For each node in the graph, finds the shortest paths to all others in a
 /// :class:`~retworkx.PyDiGraph` object
 ///
@@ -425,6 +660,55 @@ pub fn graph_all_pairs_dijkstra_path_lengths(
     all_pairs_dijkstra::all_pairs_dijkstra_path_lengths(py, &graph.graph, edge_cost_fn)
 }
 
+/// For each node in the graph, calculates the lengths of the shortest paths
+/// to all others in a :class:`~retworkx.PyGraph` object and passes them to a
+/// callback in chunks
+///
+/// This function computes the same path lengths as
+/// :func:`~retworkx.graph_all_pairs_dijkstra_path_lengths` but instead of
+/// building the lengths for every source node in a single mapping, the source
+/// nodes are processed ``chunk_size`` at a time and ``callback`` is called
+/// with the lengths for each chunk before the next chunk is computed. This
+/// bounds the memory used to ``chunk_size`` rows at once, which enables
+/// processing graphs where the full all-pairs output would not fit in memory.
+/// The rows of each chunk are computed in parallel and will launch a thread
+/// pool with threads equal to the number of CPUs by default. You can tune the
+/// number of threads with the ``RAYON_NUM_THREADS`` environment variable.
+///
+/// :param graph: The input :class:`~retworkx.PyGraph` to use
+/// :param edge_cost_fn: A callable object that acts as a weight function for
+///     an edge. It will accept a single positional argument, the edge's weight
+///     object and will return a float which will be used to represent the
+///     weight/cost of the edge
+/// :param callback: A callable object that will be called once per chunk
+///     with a single positional argument, an
+///     :class:`~retworkx.AllPairsPathLengthMapping` of the source node
+///     indices in the chunk to the lengths of the shortest paths from that
+///     node. Source nodes are delivered in node index order. If it raises an
+///     exception no more chunks are computed and the exception is raised.
+/// :param int chunk_size: The number of source nodes in each chunk, it must
+///     be larger than 0. Defaults to 1000.
+///
+/// :raises ValueError: when an edge weight with NaN or negative value
+///     is provided or ``chunk_size`` is 0.
+#[pyfunction(chunk_size = "1000")]
+#[pyo3(text_signature = "(graph, edge_cost_fn, callback, /, chunk_size=1000)")]
+pub fn graph_all_pairs_dijkstra_path_lengths_chunked(
+    py: Python,
+    graph: &graph::PyGraph,
+    edge_cost_fn: PyObject,
+    callback: PyObject,
+    chunk_size: usize,
+) -> PyResult<()> {
+    all_pairs_dijkstra::all_pairs_dijkstra_path_lengths_chunked(
+        py,
+        &graph.graph,
+        edge_cost_fn,
+        callback,
+        chunk_size,
+    )
+}
+
 /// For each node in the graph, finds the shortest paths to all others in a
 /// :class:`~retworkx.PyGraph` object
 ///
@@ -1192,3 +1476,90 @@ pub fn graph_unweighted_average_shortest_path_length(
 
     (sum as f64) / (conn_pairs as f64)
 }
+
+/// Estimate the neighborhood function of a :class:`~retworkx.PyDiGraph` with
+/// HyperANF.
+///
+/// The neighborhood function :math:`N(t)` of a graph is the number of
+/// ordered pairs of nodes :math:`(x, y)` such that :math:`y` is reachable
+/// from :math:`x` in at most :math:`t` steps (including the pairs
+/// :math:`(x, x)`). HyperANF keeps a HyperLogLog counter of the nodes within
+/// distance :math:`t` of every node, so each iteration takes a single pass
+/// over the edges and the total run time is near linear in the size of the
+/// graph times its diameter. The edges are only followed in their direction.
+/// The algorithm is based on [1]_.
+///
+/// Every counter uses ``2**precision`` registers of one byte and the relative
+/// standard error of the estimated size of each counter is about
+/// ``1.04 / sqrt(2**precision)``.
+///
+/// :param PyDiGraph graph: The graph to estimate the neighborhood function of
+/// :param int precision: The base 2 logarithm of the number of registers of
+///     every counter, it must be between 4 and 16
+/// :param int seed: An optional seed for the hash function used by the
+///     counters
+/// :param int max_distance: An optional maximum distance to compute the
+///     neighborhood function up to. If not specified the iteration stops once
+///     none of the counters change.
+///
+/// :returns: The estimated neighborhood function, which can be used to
+///     estimate the effective diameter and average distance of the graph
+/// :rtype: NeighborhoodFunction
+///
+/// .. [1] Boldi, Paolo, Marco Rosa, and Sebastiano Vigna. "HyperANF:
+///     Approximating the neighbourhood function of very large graphs on a
+///     budget." Proceedings of the 20th international conference on World
+///     Wide Web (2011): 625-634.
+#[pyfunction(precision = "10")]
+#[pyo3(text_signature = "(graph, /, precision=10, seed=None, max_distance=None)")]
+pub fn digraph_approximate_neighborhood_function(
+    graph: &digraph::PyDiGraph,
+    precision: u8,
+    seed: Option<u64>,
+    max_distance: Option<usize>,
+) -> PyResult<neighborhood_function::NeighborhoodFunction> {
+    neighborhood_function::neighborhood_function(&graph.graph, precision, seed, max_distance)
+}
+
+/// Estimate the neighborhood function of a :class:`~retworkx.PyGraph` with
+/// HyperANF.
+///
+/// The neighborhood function :math:`N(t)` of a graph is the number of
+/// ordered pairs of nodes :math:`(x, y)` such that :math:`y` is reachable
+/// from :math:`x` in at most :math:`t` steps (including the pairs
+/// :math:`(x, x)`). HyperANF keeps a HyperLogLog counter of the nodes within
+/// distance :math:`t` of every node, so each iteration takes a single pass
+/// over the edges and the total run time is near linear in the size of the
+/// graph times its diameter. The algorithm is based on [1]_.
+///
+/// Every counter uses ``2**precision`` registers of one byte and the relative
+/// standard error of the estimated size of each counter is about
+/// ``1.04 / sqrt(2**precision)``.
+///
+/// :param PyGraph graph: The graph to estimate the neighborhood function of
+/// :param int precision: The base 2 logarithm of the number of registers of
+///     every counter, it must be between 4 and 16
+/// :param int seed: An optional seed for the hash function used by the
+///     counters
+/// :param int max_distance: An optional maximum distance to compute the
+///     neighborhood function up to. If not specified the iteration stops once
+///     none of the counters change.
+///
+/// :returns: The estimated neighborhood function, which can be used to
+///     estimate the effective diameter and average distance of the graph
+/// :rtype: NeighborhoodFunction
+///
+/// .. [1] Boldi, Paolo, Marco Rosa, and Sebastiano Vigna. "HyperANF:
+///     Approximating the neighbourhood function of very large graphs on a
+///     budget." Proceedings of the 20th international conference on World
+///     Wide Web (2011): 625-634.
+#[pyfunction(precision = "10")]
+#[pyo3(text_signature = "(graph, /, precision=10, seed=None, max_distance=None)")]
+pub fn graph_approximate_neighborhood_function(
+    graph: &graph::PyGraph,
+    precision: u8,
+    seed: Option<u64>,
+    max_distance: Option<usize>,
+) -> PyResult<neighborhood_function::NeighborhoodFunction> {
+    neighborhood_function::neighborhood_function(&graph.graph, precision, seed, max_distance)
+}
@@ -0,0 +1,91 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use crate::StablePyGraph;
+
+use petgraph::EdgeType;
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+use retworkx_core::shortest_path;
+
+/// An estimated neighborhood function of a graph
+///
+/// This class is returned by :func:`~retworkx.approximate_neighborhood_function`
+/// and holds the estimated number of ordered pairs of nodes ``(x, y)`` such
+/// that ``y`` is reachable from ``x`` in at most ``t`` steps, for ``t`` from 0
+/// up to the largest distance found. It also provides the distance statistics
+/// that can be derived from it.
+///
+/// :ivar list values: The estimated neighborhood function, where
+///     ``values[t]`` is the estimated number of pairs of nodes within
+///     distance ``t`` of each other.
+#[pyclass(module = "retworkx")]
+pub struct NeighborhoodFunction {
+    #[pyo3(get)]
+    pub values: Vec<f64>,
+}
+
+#[pymethods]
+impl NeighborhoodFunction {
+    /// Return the estimated effective diameter of the graph.
+    ///
+    /// The effective diameter is the smallest distance within which a
+    /// ``quantile`` fraction of all the reachable pairs of nodes are,
+    /// linearly interpolated between integer distances.
+    ///
+    /// :param float quantile: The fraction of reachable pairs of nodes to
+    ///     cover, it must be between 0 and 1
+    ///
+    /// :returns: The estimated effective diameter
+    /// :rtype: float
+    #[pyo3(text_signature = "(self, /, quantile=0.9)")]
+    #[args(quantile = "0.9")]
+    fn effective_diameter(&self, quantile: f64) -> PyResult<f64> {
+        if !(0.0..=1.0).contains(&quantile) {
+            return Err(PyValueError::new_err("quantile must be between 0 and 1"));
+        }
+        Ok(shortest_path::effective_diameter(&self.values, quantile))
+    }
+
+    /// Return the estimated average distance of the graph.
+    ///
+    /// The average is taken over all the ordered pairs of distinct nodes
+    /// ``(x, y)`` such that ``y`` is reachable from ``x``.
+    ///
+    /// :returns: The estimated average distance
+    /// :rtype: float
+    #[pyo3(text_signature = "(self)")]
+    fn average_distance(&self) -> f64 {
+        shortest_path::average_distance(&self.values)
+    }
+}
+
+pub fn neighborhood_function<Ty: EdgeType>(
+    graph: &StablePyGraph<Ty>,
+    precision: u8,
+    seed: Option<u64>,
+    max_distance: Option<usize>,
+) -> PyResult<NeighborhoodFunction> {
+    if !(4..=16).contains(&precision) {
+        return Err(PyValueError::new_err("precision must be between 4 and 16"));
+    }
+    Ok(NeighborhoodFunction {
+        values: shortest_path::approximate_neighborhood_function(
+            graph,
+            precision,
+            seed,
+            max_distance,
+        ),
+    })
+}
@@ -0,0 +1,353 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use std::fs::{self, File};
+use std::io::prelude::*;
+use std::io::BufWriter;
+use std::iter;
+use std::path::PathBuf;
+
+use petgraph::prelude::*;
+use petgraph::visit::IntoEdgeReferences;
+use petgraph::EdgeType;
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::{PyBytes, PyDict, PyList};
+use pyo3::Python;
+
+use crate::attributes::AttributeColumns;
+use crate::node_keys::NodeKeys;
+use crate::StablePyGraph;
+
+// A snapshot stores the structure of a graph natively: a header, the node
+// indices and the endpoints of the edges as little endian integers. The
+// payloads are stored as 2 blobs, one for the list of node payloads and one
+// for the list of edge payloads, produced by a pluggable serializer which
+// is called once per blob rather than once per payload. A last blob holds
+// the node keys and attribute columns, if any. Edges are renumbered in
+// order like when pickling, node indices are kept.
+const MAGIC: &[u8; 6] = b"RXSNAP";
+const VERSION: u16 = 1;
+// The largest number of removed node indices below the node bound of a
+// snapshot per node, with a floor for small graphs, so a corrupt node index
+// can't make the reader allocate an unbounded number of removed nodes.
+const MAX_HOLES_PER_NODE: usize = 16;
+const MIN_MAX_HOLES: usize = 1 << 16;
+
+/// A graph read from a snapshot, with the state of the graph classes.
+pub struct Snapshot<Ty: EdgeType> {
+    pub graph: StablePyGraph<Ty>,
+    pub multigraph: bool,
+    pub node_removed: bool,
+    pub check_cycle: bool,
+    pub node_keys: NodeKeys,
+    pub attributes: AttributeColumns,
+}
+
+fn serializer(py: Python, serializer: Option<PyObject>) -> PyResult<PyObject> {
+    match serializer {
+        Some(serializer) => Ok(serializer),
+        None => Ok(py.import("pickle")?.into()),
+    }
+}
+
+/// Write a blob of serialized data, an empty blob is written for ``None``.
+fn write_blob<W: Write>(
+    py: Python,
+    out: &mut W,
+    serializer: &PyObject,
+    obj: Option<PyObject>,
+) -> PyResult<()> {
+    match obj {
+        Some(obj) => {
+            let data = serializer.call_method1(py, "dumps", (obj,))?;
+            let data: &[u8] = data.extract(py)?;
+            out.write_all(&[1])?;
+            out.write_all(&(data.len() as u64).to_le_bytes())?;
+            out.write_all(data)?;
+        }
+        None => out.write_all(&[0])?,
+    }
+    Ok(())
+}
+
+/// Return the payloads, or ``None`` if they're all ``None``.
+fn payload_list<'a, I>(py: Python, payloads: I) -> Option<PyObject>
+where
+    I: Iterator<Item = &'a PyObject>,
+{
+    let payloads: Vec<&PyObject> = payloads.collect();
+    if payloads.iter().all(|payload| payload.is_none(py)) {
+        None
+    } else {
+        Some(PyList::new(py, payloads).into())
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn write_snapshot<Ty: EdgeType>(
+    py: Python,
+    graph: &StablePyGraph<Ty>,
+    multigraph: bool,
+    node_removed: bool,
+    check_cycle: bool,
+    node_keys: &NodeKeys,
+    attributes: &AttributeColumns,
+    path: PathBuf,
+    serializer_obj: Option<PyObject>,
+) -> PyResult<()> {
+    let serializer = serializer(py, serializer_obj)?;
+    let mut out = BufWriter::new(File::create(path)?);
+    out.write_all(MAGIC)?;
+    out.write_all(&VERSION.to_le_bytes())?;
+    out.write_all(&[
+        Ty::is_directed() as u8,
+        multigraph as u8,
+        node_removed as u8,
+        check_cycle as u8,
+    ])?;
+    out.write_all(&(graph.node_count() as u64).to_le_bytes())?;
+    for node in graph.node_indices() {
+        out.write_all(&(node.index() as u32).to_le_bytes())?;
+    }
+    out.write_all(&(graph.edge_count() as u64).to_le_bytes())?;
+    for edge in graph.edge_references() {
+        out.write_all(&(edge.source().index() as u32).to_le_bytes())?;
+        out.write_all(&(edge.target().index() as u32).to_le_bytes())?;
+    }
+    let node_payloads = payload_list(py, graph.node_weights());
+    write_blob(py, &mut out, &serializer, node_payloads)?;
+    let edge_payloads = payload_list(py, graph.edge_weights());
+    write_blob(py, &mut out, &serializer, edge_payloads)?;
+    let extra =
+        if node_keys.is_empty() && attributes.nodes.is_empty() && attributes.edges.is_empty() {
+            None
+        } else {
+            let extra = PyDict::new(py);
+            let keys = PyDict::new(py);
+            for node in graph.node_indices() {
+                if let Some(key) = node_keys.get(node) {
+                    keys.set_item(node.index(), key)?;
+                }
+            }
+            extra.set_item("node_keys", keys)?;
+            let nodes: Vec<usize> = graph.node_indices().map(|n| n.index()).collect();
+            let edges: Vec<usize> = graph.edge_indices().map(|e| e.index()).collect();
+            attributes.get_state(py, extra, &nodes, &edges)?;
+            Some(extra.into())
+        };
+    write_blob(py, &mut out, &serializer, extra)?;
+    out.flush()?;
+    Ok(())
+}
+
+struct SnapshotReader<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> SnapshotReader<'a> {
+    fn bytes(&mut self, len: usize) -> PyResult<&'a [u8]> {
+        if len > self.data.len() - self.pos {
+            return Err(PyValueError::new_err("The snapshot is truncated"));
+        }
+        let bytes = &self.data[self.pos..self.pos + len];
+        self.pos += len;
+        Ok(bytes)
+    }
+
+    fn u8(&mut self) -> PyResult<u8> {
+        Ok(self.bytes(1)?[0])
+    }
+
+    fn u64(&mut self) -> PyResult<u64> {
+        let mut buf = [0; 8];
+        buf.copy_from_slice(self.bytes(8)?);
+        Ok(u64::from_le_bytes(buf))
+    }
+
+    /// Read a count of items of ``size`` bytes, checking it fits in the
+    /// rest of the snapshot before anything is allocated for them.
+    fn count(&mut self, size: usize) -> PyResult<usize> {
+        let count = self.u64()? as usize;
+        if count > (self.data.len() - self.pos) / size {
+            return Err(PyValueError::new_err("The snapshot is truncated"));
+        }
+        Ok(count)
+    }
+
+    fn blob(&mut self, py: Python, serializer: &PyObject) -> PyResult<Option<PyObject>> {
+        match self.u8()? {
+            0 => Ok(None),
+            _ => {
+                let len = self.count(1)?;
+                let data = PyBytes::new(py, self.bytes(len)?);
+                Ok(Some(serializer.call_method1(py, "loads", (data,))?))
+            }
+        }
+    }
+}
+
+fn u32_at(bytes: &[u8]) -> usize {
+    let mut buf = [0; 4];
+    buf.copy_from_slice(&bytes[..4]);
+    u32::from_le_bytes(buf) as usize
+}
+
+/// Return the ``count`` payloads of a blob, an empty blob is ``None`` for
+/// every payload.
+fn blob_payloads(
+    py: Python,
+    blob: Option<PyObject>,
+    count: usize,
+) -> PyResult<impl Iterator<Item = PyObject>> {
+    let payloads: Option<Vec<PyObject>> = match blob {
+        Some(blob) => {
+            let payloads: Vec<PyObject> = blob.extract(py)?;
+            if payloads.len() != count {
+                return Err(PyValueError::new_err(format!(
+                    "The snapshot has {} payloads for {} items",
+                    payloads.len(),
+                    count
+                )));
+            }
+            Some(payloads)
+        }
+        None => None,
+    };
+    let mut payloads = payloads.map(Vec::into_iter);
+    let none = py.None();
+    Ok(iter::repeat_with(move || match &mut payloads {
+        Some(payloads) => payloads.next().unwrap(),
+        None => none.clone(),
+    })
+    .take(count))
+}
+
+pub fn read_snapshot<Ty: EdgeType>(
+    py: Python,
+    path: PathBuf,
+    serializer_obj: Option<PyObject>,
+) -> PyResult<Snapshot<Ty>> {
+    let serializer = serializer(py, serializer_obj)?;
+    let data = fs::read(path)?;
+    let mut reader = SnapshotReader {
+        data: &data,
+        pos: 0,
+    };
+    if data.len() < MAGIC.len() || reader.bytes(MAGIC.len())? != MAGIC {
+        return Err(PyValueError::new_err("The file is not a retworkx snapshot"));
+    }
+    let mut version = [0; 2];
+    version.copy_from_slice(reader.bytes(2)?);
+    let version = u16::from_le_bytes(version);
+    if version > VERSION {
+        return Err(PyValueError::new_err(format!(
+            "Unsupported snapshot version {}, the latest supported is {}",
+            version, VERSION
+        )));
+    }
+    let directed = reader.u8()? != 0;
+    if directed != Ty::is_directed() {
+        let (kind, class) = if directed {
+            ("directed", "PyDiGraph")
+        } else {
+            ("undirected", "PyGraph")
+        };
+        return Err(PyValueError::new_err(format!(
+            "The snapshot is of an {} graph, use {}.read_snapshot",
+            kind, class
+        )));
+    }
+    let multigraph = reader.u8()? != 0;
+    let node_removed = reader.u8()? != 0;
+    let check_cycle = reader.u8()? != 0;
+    let node_count = reader.count(4)?;
+    let nodes: Vec<usize> = reader
+        .bytes(node_count * 4)?
+        .chunks_exact(4)
+        .map(u32_at)
+        .collect();
+    if nodes.windows(2).any(|pair| pair[0] >= pair[1]) {
+        return Err(PyValueError::new_err(
+            "The node indices of the snapshot are not strictly increasing",
+        ));
+    }
+    let node_bound = match nodes.last() {
+        Some(last) => last + 1,
+        None => 0,
+    };
+    let max_holes = (node_count * MAX_HOLES_PER_NODE).max(MIN_MAX_HOLES);
+    if node_bound - node_count > max_holes {
+        return Err(PyValueError::new_err(format!(
+            "The snapshot has {} removed node indices for {} nodes, the limit is {}",
+            node_bound - node_count,
+            node_count,
+            max_holes
+        )));
+    }
+    let edge_count = reader.count(8)?;
+    let edges = reader.bytes(edge_count * 8)?;
+    let node_payloads = reader.blob(py, &serializer)?;
+    let mut node_payloads = blob_payloads(py, node_payloads, node_count)?;
+    let edge_payloads = reader.blob(py, &serializer)?;
+    let edge_payloads = blob_payloads(py, edge_payloads, edge_count)?;
+    let extra = reader.blob(py, &serializer)?;
+
+    let mut graph = StablePyGraph::<Ty>::with_capacity(node_bound, edge_count);
+    let mut holes = Vec::with_capacity(node_bound - node_count);
+    let mut present = vec![false; node_bound];
+    let mut next = nodes.iter().peekable();
+    for (index, is_present) in present.iter_mut().enumerate() {
+        if next.peek() == Some(&&index) {
+            next.next();
+            graph.add_node(node_payloads.next().unwrap());
+            *is_present = true;
+        } else {
+            holes.push(graph.add_node(py.None()));
+        }
+    }
+    for hole in holes {
+        graph.remove_node(hole);
+    }
+    for (edge, payload) in edges.chunks_exact(8).zip(edge_payloads) {
+        let (source, target) = (u32_at(edge), u32_at(&edge[4..]));
+        if present.get(source) != Some(&true) || present.get(target) != Some(&true) {
+            return Err(PyValueError::new_err(
+                "An edge of the snapshot has a node not in the snapshot",
+            ));
+        }
+        graph.add_edge(NodeIndex::new(source), NodeIndex::new(target), payload);
+    }
+
+    let mut node_keys = NodeKeys::default();
+    let mut attributes = AttributeColumns::default();
+    if let Some(extra) = extra {
+        let extra: &PyDict = extra.as_ref(py).downcast()?;
+        if let Some(keys) = extra.get_item("node_keys") {
+            for (node, key) in keys.downcast::<PyDict>()?.iter() {
+                node_keys.insert(py, NodeIndex::new(node.extract()?), key.into())?;
+            }
+        }
+        let edges: Vec<usize> = (0..edge_count).collect();
+        attributes.set_state(extra, &nodes, &edges)?;
+    }
+    Ok(Snapshot {
+        graph,
+        multigraph,
+        node_removed,
+        check_cycle,
+        node_keys,
+        attributes,
+    })
+}
@@ -0,0 +1,861 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use crate::algorithm_info::{explained, AlgorithmInfo};
+use crate::iterators::CentralityMapping;
+use crate::partition::GraphPartitioning;
+use crate::{
+    digraph, get_edge_iter_with_weights, graph, weight_callable, FailedToConverge, StablePyGraph,
+};
+
+use hashbrown::HashSet;
+
+use ndarray::prelude::*;
+use numpy::IntoPyArray;
+
+use petgraph::prelude::*;
+use petgraph::visit::{EdgeIndexable, IntoEdgeReferences};
+use petgraph::EdgeType;
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::Python;
+
+use retworkx_core::spectral;
+
+/// Whether there's a path between every pair of nodes, ignoring the
+/// direction of the edges.
+fn is_connected<Ty: EdgeType>(graph: &StablePyGraph<Ty>) -> bool {
+    let start = match graph.node_indices().next() {
+        Some(start) => start,
+        None => return true,
+    };
+    let mut seen: HashSet<NodeIndex> = HashSet::with_capacity(graph.node_count());
+    seen.insert(start);
+    let mut stack = vec![start];
+    while let Some(node) = stack.pop() {
+        for neighbor in graph.neighbors_undirected(node) {
+            if seen.insert(neighbor) {
+                stack.push(neighbor);
+            }
+        }
+    }
+    seen.len() == graph.node_count()
+}
+
+/// Check that the Fiedler vector of a graph is defined, the graph must have
+/// at least 2 nodes and, unless ``allow_disconnected`` is set, be connected.
+/// Returns whether the graph is connected.
+fn check_graph<Ty: EdgeType>(
+    graph: &StablePyGraph<Ty>,
+    allow_disconnected: bool,
+) -> PyResult<bool> {
+    if graph.node_count() < 2 {
+        return Err(PyValueError::new_err(
+            "The graph must have at least 2 nodes",
+        ));
+    }
+    let connected = is_connected(graph);
+    if !connected && !allow_disconnected {
+        return Err(PyValueError::new_err("The graph is not connected"));
+    }
+    Ok(connected)
+}
+
+fn new_info(
+    py: Python,
+    name: &str,
+    default_weight: f64,
+    max_iter: usize,
+    tol: f64,
+) -> AlgorithmInfo {
+    AlgorithmInfo::new(name, "Lanczos on the Laplacian matrix")
+        .parameter(py, "default_weight", default_weight)
+        .parameter(py, "max_iter", max_iter)
+        .parameter(py, "tol", tol)
+}
+
+fn not_converged(max_iter: usize) -> PyErr {
+    FailedToConverge::new_err(format!(
+        "The Lanczos method failed to converge in {} iterations",
+        max_iter
+    ))
+}
+
+fn fiedler_vector<Ty: EdgeType>(
+    py: Python,
+    graph: &StablePyGraph<Ty>,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+    max_iter: usize,
+    tol: f64,
+) -> PyResult<(CentralityMapping, AlgorithmInfo)> {
+    check_graph(graph, false)?;
+    let info = new_info(py, "fiedler_vector", default_weight, max_iter, tol);
+    let ((_, fiedler), convergence) = spectral::fiedler_vector_with_convergence(
+        graph,
+        |e| weight_callable(py, &weight_fn, e.weight(), default_weight),
+        max_iter,
+        tol,
+    )?;
+    if !convergence.converged {
+        return Err(not_converged(max_iter));
+    }
+    let fiedler = CentralityMapping {
+        centralities: graph
+            .node_indices()
+            .map(|n| (n.index(), fiedler[n.index()]))
+            .collect(),
+    };
+    Ok((fiedler, info.convergence(convergence, tol)))
+}
+
+fn algebraic_connectivity<Ty: EdgeType>(
+    py: Python,
+    graph: &StablePyGraph<Ty>,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+    max_iter: usize,
+    tol: f64,
+) -> PyResult<(f64, AlgorithmInfo)> {
+    let info = new_info(py, "algebraic_connectivity", default_weight, max_iter, tol);
+    if !check_graph(graph, true)? {
+        return Ok((
+            0.0,
+            info.fallback("the graph is not connected, so its algebraic connectivity is 0"),
+        ));
+    }
+    let ((connectivity, _), convergence) = spectral::fiedler_vector_with_convergence(
+        graph,
+        |e| weight_callable(py, &weight_fn, e.weight(), default_weight),
+        max_iter,
+        tol,
+    )?;
+    if !convergence.converged {
+        return Err(not_converged(max_iter));
+    }
+    Ok((connectivity, info.convergence(convergence, tol)))
+}
+
+fn spectral_bisection<Ty: EdgeType>(
+    py: Python,
+    graph: &StablePyGraph<Ty>,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+    max_iter: usize,
+    tol: f64,
+) -> PyResult<(GraphPartitioning, AlgorithmInfo)> {
+    check_graph(graph, false)?;
+    let info = new_info(py, "spectral_bisection", default_weight, max_iter, tol);
+    let mut weights = vec![0.0; graph.edge_bound()];
+    let ((_, fiedler), convergence) = spectral::fiedler_vector_with_convergence(
+        graph,
+        |e| {
+            let weight = weight_callable(py, &weight_fn, e.weight(), default_weight)?;
+            weights[e.id().index()] = weight;
+            Ok::<f64, PyErr>(weight)
+        },
+        max_iter,
+        tol,
+    )?;
+    if !convergence.converged {
+        return Err(not_converged(max_iter));
+    }
+    let side = |node: NodeIndex| usize::from(fiedler[node.index()] >= 0.0);
+    let mut parts: Vec<Vec<usize>> = vec![Vec::new(), Vec::new()];
+    for node in graph.node_indices() {
+        parts[side(node)].push(node.index());
+    }
+    let cut_weight = graph
+        .edge_references()
+        .filter(|edge| side(edge.source()) != side(edge.target()))
+        .map(|edge| weights[edge.id().index()])
+        .sum();
+    let partitioning = GraphPartitioning { parts, cut_weight };
+    Ok((partitioning, info.convergence(convergence, tol)))
+}
+
+/// The rows of the (normalized) Laplacian matrix of a graph, with a row for
+/// every node in the order of the node indices and the entries of every row
+/// sorted by column, including a diagonal entry for every node.
+///
+/// The weighted degrees are the row sums of the weighted adjacency matrix,
+/// where a self loop is counted once, or its column sums if ``in_degree`` is
+/// set for a directed graph. With ``symmetric`` every edge goes both ways.
+#[allow(clippy::type_complexity)]
+fn laplacian_rows<Ty: EdgeType>(
+    py: Python,
+    graph: &StablePyGraph<Ty>,
+    weight_fn: &Option<PyObject>,
+    default_weight: f64,
+    normalized: bool,
+    in_degree: bool,
+    symmetric: bool,
+) -> PyResult<Vec<Vec<(usize, f64)>>> {
+    let n = graph.node_count();
+    let mut rows: Vec<Vec<(usize, f64)>> = vec![Vec::new(); n];
+    let mut degrees = vec![0.0; n];
+    for (i, j, weight) in get_edge_iter_with_weights(graph) {
+        let weight = weight_callable(py, weight_fn, &weight, default_weight)?;
+        rows[i].push((j, -weight));
+        if symmetric {
+            degrees[i] += weight;
+            if i != j {
+                rows[j].push((i, -weight));
+                degrees[j] += weight;
+            }
+        } else if in_degree {
+            degrees[j] += weight;
+        } else {
+            degrees[i] += weight;
+        }
+    }
+    let scales: Vec<f64> = if normalized {
+        if degrees.iter().any(|degree| *degree < 0.0) {
+            return Err(PyValueError::new_err(
+                "The normalized Laplacian matrix requires non-negative weighted degrees",
+            ));
+        }
+        degrees
+            .iter()
+            .map(|degree| {
+                if *degree == 0.0 {
+                    0.0
+                } else {
+                    1.0 / degree.sqrt()
+                }
+            })
+            .collect()
+    } else {
+        vec![1.0; n]
+    };
+    for (i, row) in rows.iter_mut().enumerate() {
+        row.push((i, degrees[i]));
+        // A stable sort so the entries of parallel edges are added in order
+        row.sort_by_key(|entry| entry.0);
+        row.dedup_by(|next, kept| {
+            if next.0 == kept.0 {
+                kept.1 += next.1;
+                true
+            } else {
+                false
+            }
+        });
+        for entry in row.iter_mut() {
+            entry.1 *= scales[i] * scales[entry.0];
+        }
+    }
+    Ok(rows)
+}
+
+/// The Laplacian matrix of a graph as a dense numpy array, or as a tuple of
+/// the ``indptr``, ``indices`` and ``data`` arrays of the CSR format if
+/// ``sparse`` is set.
+#[allow(clippy::too_many_arguments)]
+fn laplacian_matrix<Ty: EdgeType>(
+    py: Python,
+    graph: &StablePyGraph<Ty>,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+    normalized: bool,
+    sparse: bool,
+    in_degree: bool,
+    symmetric: bool,
+) -> PyResult<PyObject> {
+    let rows = laplacian_rows(
+        py,
+        graph,
+        &weight_fn,
+        default_weight,
+        normalized,
+        in_degree,
+        symmetric,
+    )?;
+    let n = rows.len();
+    if !sparse {
+        let mut matrix = Array2::<f64>::zeros((n, n));
+        for (i, row) in rows.iter().enumerate() {
+            for (j, value) in row {
+                matrix[[i, *j]] = *value;
+            }
+        }
+        return Ok(matrix.into_pyarray(py).into());
+    }
+    let mut indptr: Vec<i64> = Vec::with_capacity(n + 1);
+    let mut indices: Vec<i64> = Vec::new();
+    let mut data: Vec<f64> = Vec::new();
+    indptr.push(0);
+    for row in rows {
+        for (j, value) in row {
+            indices.push(j as i64);
+            data.push(value);
+        }
+        indptr.push(indices.len() as i64);
+    }
+    let indptr: PyObject = indptr.into_pyarray(py).into();
+    let indices: PyObject = indices.into_pyarray(py).into();
+    let data: PyObject = data.into_pyarray(py).into();
+    Ok((indptr, indices, data).into_py(py))
+}
+
+/// Compute the Fiedler vector of a PyGraph
+///
+/// The Fiedler vector is the eigenvector of the second smallest eigenvalue
+/// of the Laplacian matrix :math:`L = D - A` of the graph, where :math:`A`
+/// is its weighted adjacency matrix and :math:`D` the diagonal matrix of the
+/// weighted degrees of the nodes [1]_. Its entries order the nodes along the
+/// graph, and their signs split the graph into two parts with few edges
+/// between them, see :func:`~retworkx.graph_spectral_bisection`. Self loops
+/// don't change the Laplacian matrix and the weights of parallel edges are
+/// added.
+///
+/// The vector is computed with the Lanczos method, which only multiplies
+/// vectors by the sparse Laplacian matrix of the graph, restricted to the
+/// vectors orthogonal to the constant vector, the eigenvector of the
+/// smallest eigenvalue 0.
+///
+/// :param PyGraph graph: The graph to compute the Fiedler vector of. It must
+///     be connected, otherwise the Fiedler vector is not unique.
+/// :param weight_fn: An optional callable that will be passed the edge's
+///     payload object and is expected to return a non-negative ``float``
+///     weight for that edge.
+/// :param float default_weight: If ``weight_fn`` isn't specified this
+///     optional float value will be used for the weight of every edge.
+///     Defaults to 1.0.
+/// :param int max_iter: The maximum number of Lanczos iterations, the number
+///     of products of a vector by the Laplacian matrix. Defaults to 1000.
+/// :param float tol: The error tolerance used when checking for convergence,
+///     the iteration stops once the residual norm of the vector is less than
+///     ``tol`` times twice the largest weighted degree. Defaults to 1e-8.
+/// :param bool explain: If set to ``True`` an
+///     :class:`~retworkx.AlgorithmInfo` recording how the algorithm was run
+///     is returned along with the result
+///
+/// :returns: a read-only dict-like object whose keys are the node indices and
+///     values are the entries of the Fiedler vector, normalized to a
+///     euclidean norm of 1 with a positive entry of largest absolute value.
+///     If ``explain`` is ``True`` a tuple of this and an
+///     :class:`~retworkx.AlgorithmInfo` is returned instead.
+/// :rtype: CentralityMapping
+/// :raises ValueError: If the graph has less than 2 nodes or isn't connected
+/// :raises FailedToConverge: If the Lanczos method doesn't converge within
+///     ``max_iter`` iterations
+///
+/// .. [1] M. Fiedler, "Algebraic connectivity of graphs", Czechoslovak
+///     Mathematical Journal, 23(2), 298-305, 1973.
+#[pyfunction(
+    default_weight = "1.0",
+    max_iter = "1000",
+    tol = "1e-8",
+    explain = "false"
+)]
+#[pyo3(
+    text_signature = "(graph, /, weight_fn=None, default_weight=1.0, max_iter=1000, tol=1e-8, explain=False)"
+)]
+pub fn graph_fiedler_vector(
+    py: Python,
+    graph: &graph::PyGraph,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+    max_iter: usize,
+    tol: f64,
+    explain: bool,
+) -> PyResult<PyObject> {
+    let (fiedler, info) =
+        fiedler_vector(py, &graph.graph, weight_fn, default_weight, max_iter, tol)?;
+    explained(py, fiedler, info, explain)
+}
+
+/// Compute the Fiedler vector of a PyDiGraph
+///
+/// The Fiedler vector is the eigenvector of the second smallest eigenvalue
+/// of the Laplacian matrix :math:`L = D - A` of the graph, where :math:`A`
+/// is its weighted adjacency matrix and :math:`D` the diagonal matrix of the
+/// weighted degrees of the nodes [1]_. Its entries order the nodes along the
+/// graph, and their signs split the graph into two parts with few edges
+/// between them, see :func:`~retworkx.digraph_spectral_bisection`. The
+/// direction of the edges is ignored, so the Laplacian matrix is the one of
+/// the underlying undirected graph. Self loops don't change the Laplacian
+/// matrix and the weights of parallel edges are added.
+///
+/// The vector is computed with the Lanczos method, which only multiplies
+/// vectors by the sparse Laplacian matrix of the graph, restricted to the
+/// vectors orthogonal to the constant vector, the eigenvector of the
+/// smallest eigenvalue 0.
+///
+/// :param PyDiGraph graph: The graph to compute the Fiedler vector of. It
+///     must be weakly connected, otherwise the Fiedler vector is not unique.
+/// :param weight_fn: An optional callable that will be passed the edge's
+///     payload object and is expected to return a non-negative ``float``
+///     weight for that edge.
+/// :param float default_weight: If ``weight_fn`` isn't specified this
+///     optional float value will be used for the weight of every edge.
+///     Defaults to 1.0.
+/// :param int max_iter: The maximum number of Lanczos iterations, the number
+///     of products of a vector by the Laplacian matrix. Defaults to 1000.
+/// :param float tol: The error tolerance used when checking for convergence,
+///     the iteration stops once the residual norm of the vector is less than
+///     ``tol`` times twice the largest weighted degree. Defaults to 1e-8.
+/// :param bool explain: If set to ``True`` an
+///     :class:`~retworkx.AlgorithmInfo` recording how the algorithm was run
+///     is returned along with the result
+///
+/// :returns: a read-only dict-like object whose keys are the node indices and
+///     values are the entries of the Fiedler vector, normalized to a
+///     euclidean norm of 1 with a positive entry of largest absolute value.
+///     If ``explain`` is ``True`` a tuple of this and an
+///     :class:`~retworkx.AlgorithmInfo` is returned instead.
+/// :rtype: CentralityMapping
+/// :raises ValueError: If the graph has less than 2 nodes or isn't weakly
+///     connected
+/// :raises FailedToConverge: If the Lanczos method doesn't converge within
+///     ``max_iter`` iterations
+///
+/// .. [1] M. Fiedler, "Algebraic connectivity of graphs", Czechoslovak
+///     Mathematical Journal, 23(2), 298-305, 1973.
+#[pyfunction(
+    default_weight = "1.0",
+    max_iter = "1000",
+    tol = "1e-8",
+    explain = "false"
+)]
+#[pyo3(
+    text_signature = "(graph, /, weight_fn=None, default_weight=1.0, max_iter=1000, tol=1e-8, explain=False)"
+)]
+pub fn digraph_fiedler_vector(
+    py: Python,
+    graph: &digraph::PyDiGraph,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+    max_iter: usize,
+    tol: f64,
+    explain: bool,
+) -> PyResult<PyObject> {
+    let (fiedler, info) =
+        fiedler_vector(py, &graph.graph, weight_fn, default_weight, max_iter, tol)?;
+    explained(py, fiedler, info, explain)
+}
+
+/// Compute the algebraic connectivity of a PyGraph
+///
+/// The algebraic connectivity is the second smallest eigenvalue of the
+/// Laplacian matrix :math:`L = D - A` of the graph, where :math:`A` is its
+/// weighted adjacency matrix and :math:`D` the diagonal matrix of the
+/// weighted degrees of the nodes [1]_. It's positive if and only if the graph
+/// is connected, and the larger it is the harder it is to disconnect the
+/// graph by removing edges, so it measures the robustness of the
+/// connectivity of the graph. Self loops don't change the Laplacian matrix
+/// and the weights of parallel edges are added.
+///
+/// It's computed with the Lanczos method along with the Fiedler vector, see
+/// :func:`~retworkx.graph_fiedler_vector`. For a graph that isn't connected
+/// it's 0 and nothing is computed.
+///
+/// :param PyGraph graph: The graph to compute the algebraic connectivity of
+/// :param weight_fn: An optional callable that will be passed the edge's
+///     payload object and is expected to return a non-negative ``float``
+///     weight for that edge.
+/// :param float default_weight: If ``weight_fn`` isn't specified this
+///     optional float value will be used for the weight of every edge.
+///     Defaults to 1.0.
+/// :param int max_iter: The maximum number of Lanczos iterations, the number
+///     of products of a vector by the Laplacian matrix. Defaults to 1000.
+/// :param float tol: The error tolerance used when checking for convergence,
+///     the iteration stops once the residual norm of the Fiedler vector is
+///     less than ``tol`` times twice the largest weighted degree. Defaults to
+///     1e-8.
+/// :param bool explain: If set to ``True`` an
+///     :class:`~retworkx.AlgorithmInfo` recording how the algorithm was run
+///     is returned along with the result
+///
+/// :returns: The algebraic connectivity of the graph. If ``explain`` is
+///     ``True`` a tuple of this and an :class:`~retworkx.AlgorithmInfo` is
+///     returned instead.
+/// :rtype: float
+/// :raises ValueError: If the graph has less than 2 nodes
+/// :raises FailedToConverge: If the Lanczos method doesn't converge within
+///     ``max_iter`` iterations
+///
+/// .. [1] M. Fiedler, "Algebraic connectivity of graphs", Czechoslovak
+///     Mathematical Journal, 23(2), 298-305, 1973.
+#[pyfunction(
+    default_weight = "1.0",
+    max_iter = "1000",
+    tol = "1e-8",
+    explain = "false"
+)]
+#[pyo3(
+    text_signature = "(graph, /, weight_fn=None, default_weight=1.0, max_iter=1000, tol=1e-8, explain=False)"
+)]
+pub fn graph_algebraic_connectivity(
+    py: Python,
+    graph: &graph::PyGraph,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+    max_iter: usize,
+    tol: f64,
+    explain: bool,
+) -> PyResult<PyObject> {
+    let (connectivity, info) =
+        algebraic_connectivity(py, &graph.graph, weight_fn, default_weight, max_iter, tol)?;
+    explained(py, connectivity, info, explain)
+}
+
+/// Compute the algebraic connectivity of a PyDiGraph
+///
+/// The algebraic connectivity is the second smallest eigenvalue of the
+/// Laplacian matrix :math:`L = D - A` of the graph, where :math:`A` is its
+/// weighted adjacency matrix and :math:`D` the diagonal matrix of the
+/// weighted degrees of the nodes [1]_. It's positive if and only if the graph
+/// is weakly connected, and the larger it is the harder it is to disconnect
+/// the graph by removing edges, so it measures the robustness of the
+/// connectivity of the graph. The direction of the edges is ignored, so the
+/// Laplacian matrix is the one of the underlying undirected graph. Self
+/// loops don't change the Laplacian matrix and the weights of parallel edges
+/// are added.
+///
+/// It's computed with the Lanczos method along with the Fiedler vector, see
+/// :func:`~retworkx.digraph_fiedler_vector`. For a graph that isn't weakly
+/// connected it's 0 and nothing is computed.
+///
+/// :param PyDiGraph graph: The graph to compute the algebraic connectivity of
+/// :param weight_fn: An optional callable that will be passed the edge's
+///     payload object and is expected to return a non-negative ``float``
+///     weight for that edge.
+/// :param float default_weight: If ``weight_fn`` isn't specified this
+///     optional float value will be used for the weight of every edge.
+///     Defaults to 1.0.
+/// :param int max_iter: The maximum number of Lanczos iterations, the number
+///     of products of a vector by the Laplacian matrix. Defaults to 1000.
+/// :param float tol: The error tolerance used when checking for convergence,
+///     the iteration stops once the residual norm of the Fiedler vector is
+///     less than ``tol`` times twice the largest weighted degree. Defaults to
+///     1e-8.
+/// :param bool explain: If set to ``True`` an
+///     :class:`~retworkx.AlgorithmInfo` recording how the algorithm was run
+///     is returned along with the result
+///
+/// :returns: The algebraic connectivity of the graph. If ``explain`` is
+///     ``True`` a tuple of this and an :class:`~retworkx.AlgorithmInfo` is
+///     returned instead.
+/// :rtype: float
+/// :raises ValueError: If the graph has less than 2 nodes
+/// :raises FailedToConverge: If the Lanczos method doesn't converge within
+///     ``max_iter`` iterations
+///
+/// .. [1] M. Fiedler, "Algebraic connectivity of graphs", Czechoslovak
+///     Mathematical Journal, 23(2), 298-305, 1973.
+#[pyfunction(
+    default_weight = "1.0",
+    max_iter = "1000",
+    tol = "1e-8",
+    explain = "false"
+)]
+#[pyo3(
+    text_signature = "(graph, /, weight_fn=None, default_weight=1.0, max_iter=1000, tol=1e-8, explain=False)"
+)]
+pub fn digraph_algebraic_connectivity(
+    py: Python,
+    graph: &digraph::PyDiGraph,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+    max_iter: usize,
+    tol: f64,
+    explain: bool,
+) -> PyResult<PyObject> {
+    let (connectivity, info) =
+        algebraic_connectivity(py, &graph.graph, weight_fn, default_weight, max_iter, tol)?;
+    explained(py, connectivity, info, explain)
+}
+
+/// Split the nodes of a PyGraph into two parts by the signs of the entries
+/// of its Fiedler vector
+///
+/// The first part has the nodes with a negative entry and the second part
+/// the nodes with a non-negative entry of the Fiedler vector computed by
+/// :func:`~retworkx.graph_fiedler_vector`. This is the spectral relaxation
+/// of the bisection minimizing the ratio cut [1]_, so the parts have few
+/// edges between them but aren't necessarily of the same size, unlike with
+/// :func:`~retworkx.graph_kernighan_lin_bisection`.
+///
+/// :param PyGraph graph: The graph to bisect. It must be connected.
+/// :param weight_fn: An optional callable that will be passed the edge's
+///     payload object and is expected to return a non-negative ``float``
+///     weight for that edge.
+/// :param float default_weight: If ``weight_fn`` isn't specified this
+///     optional float value will be used for the weight of every edge.
+///     Defaults to 1.0.
+/// :param int max_iter: The maximum number of Lanczos iterations, the number
+///     of products of a vector by the Laplacian matrix. Defaults to 1000.
+/// :param float tol: The error tolerance used when checking for convergence,
+///     the iteration stops once the residual norm of the Fiedler vector is
+///     less than ``tol`` times twice the largest weighted degree. Defaults to
+///     1e-8.
+/// :param bool explain: If set to ``True`` an
+///     :class:`~retworkx.AlgorithmInfo` recording how the algorithm was run
+///     is returned along with the result
+///
+/// :returns: The two parts and the weight of the cut. If ``explain`` is
+///     ``True`` a tuple of this and an :class:`~retworkx.AlgorithmInfo` is
+///     returned instead.
+/// :rtype: GraphPartitioning
+/// :raises ValueError: If the graph has less than 2 nodes or isn't connected
+/// :raises FailedToConverge: If the Lanczos method doesn't converge within
+///     ``max_iter`` iterations
+///
+/// .. [1] L. Hagen and A. B. Kahng, "New spectral methods for ratio cut
+///     partitioning and clustering", IEEE Transactions on Computer-Aided
+///     Design of Integrated Circuits and Systems, 11(9), 1074-1085, 1992.
+#[pyfunction(
+    default_weight = "1.0",
+    max_iter = "1000",
+    tol = "1e-8",
+    explain = "false"
+)]
+#[pyo3(
+    text_signature = "(graph, /, weight_fn=None, default_weight=1.0, max_iter=1000, tol=1e-8, explain=False)"
+)]
+pub fn graph_spectral_bisection(
+    py: Python,
+    graph: &graph::PyGraph,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+    max_iter: usize,
+    tol: f64,
+    explain: bool,
+) -> PyResult<PyObject> {
+    let (partitioning, info) =
+        spectral_bisection(py, &graph.graph, weight_fn, default_weight, max_iter, tol)?;
+    explained(py, partitioning, info, explain)
+}
+
+/// Split the nodes of a PyDiGraph into two parts by the signs of the entries
+/// of its Fiedler vector
+///
+/// The first part has the nodes with a negative entry and the second part
+/// the nodes with a non-negative entry of the Fiedler vector computed by
+/// :func:`~retworkx.digraph_fiedler_vector`. This is the spectral
+/// relaxation of the bisection minimizing the ratio cut [1]_, so the parts
+/// have few edges between them but aren't necessarily of the same size,
+/// unlike with :func:`~retworkx.digraph_kernighan_lin_bisection`. The
+/// direction of the edges is ignored.
+///
+/// :param PyDiGraph graph: The graph to bisect. It must be weakly connected.
+/// :param weight_fn: An optional callable that will be passed the edge's
+///     payload object and is expected to return a non-negative ``float``
+///     weight for that edge.
+/// :param float default_weight: If ``weight_fn`` isn't specified this
+///     optional float value will be used for the weight of every edge.
+///     Defaults to 1.0.
+/// :param int max_iter: The maximum number of Lanczos iterations, the number
+///     of products of a vector by the Laplacian matrix. Defaults to 1000.
+/// :param float tol: The error tolerance used when checking for convergence,
+///     the iteration stops once the residual norm of the Fiedler vector is
+///     less than ``tol`` times twice the largest weighted degree. Defaults to
+///     1e-8.
+/// :param bool explain: If set to ``True`` an
+///     :class:`~retworkx.AlgorithmInfo` recording how the algorithm was run
+///     is returned along with the result
+///
+/// :returns: The two parts and the weight of the cut. If ``explain`` is
+///     ``True`` a tuple of this and an :class:`~retworkx.AlgorithmInfo` is
+///     returned instead.
+/// :rtype: GraphPartitioning
+/// :raises ValueError: If the graph has less than 2 nodes or isn't weakly
+///     connected
+/// :raises FailedToConverge: If the Lanczos method doesn't converge within
+///     ``max_iter`` iterations
+///
+/// .. [1] L. Hagen and A. B. Kahng, "New spectral methods for ratio cut
+///     partitioning and clustering", IEEE Transactions on Computer-Aided
+///     Design of Integrated Circuits and Systems, 11(9), 1074-1085, 1992.
+#[pyfunction(
+    default_weight = "1.0",
+    max_iter = "1000",
+    tol = "1e-8",
+    explain = "false"
+)]
+#[pyo3(
+    text_signature = "(graph, /, weight_fn=None, default_weight=1.0, max_iter=1000, tol=1e-8, explain=False)"
+)]
+pub fn digraph_spectral_bisection(
+    py: Python,
+    graph: &digraph::PyDiGraph,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+    max_iter: usize,
+    tol: f64,
+    explain: bool,
+) -> PyResult<PyObject> {
+    let (partitioning, info) =
+        spectral_bisection(py, &graph.graph, weight_fn, default_weight, max_iter, tol)?;
+    explained(py, partitioning, info, explain)
+}
+
+/// Return the Laplacian matrix of a PyGraph
+///
+/// The Laplacian matrix is :math:`L = D - A`, where :math:`A` is the
+/// weighted adjacency matrix of the graph, as returned by
+/// :func:`~retworkx.graph_adjacency_matrix`, and :math:`D` the diagonal
+/// matrix of the weighted degrees of the nodes, the row sums of :math:`A`.
+/// The normalized Laplacian matrix is
+/// :math:`D^{-1/2} L D^{-1/2} = I - D^{-1/2} A D^{-1/2}`, where the row and
+/// column of a node with a weighted degree of 0 are 0. The weights of
+/// parallel edges are added. A self loop is on the diagonal of :math:`A`
+/// and is counted once in the weighted degree of its node, so it doesn't
+/// change :math:`L` but it changes the normalized Laplacian matrix. Row
+/// ``i`` of the matrix is for the ``i``-th node in
+/// :meth:`~retworkx.PyGraph.node_indices`.
+///
+/// With ``sparse`` the matrix is returned in the compressed sparse row
+/// format without creating the dense matrix, with an entry on the diagonal
+/// for every node and an entry for every pair of nodes with an edge, sorted
+/// by column in every row. The arrays can be passed to ``scipy.sparse``::
+///
+///     indptr, indices, data = retworkx.graph_laplacian_matrix(graph, sparse=True)
+///     n = len(graph)
+///     matrix = scipy.sparse.csr_matrix((data, indices, indptr), shape=(n, n))
+///
+/// :param PyGraph graph: The graph to get the Laplacian matrix of
+/// :param weight_fn: An optional callable that will be passed the edge's
+///     payload object and is expected to return a ``float`` weight for that
+///     edge.
+/// :param float default_weight: If ``weight_fn`` isn't specified this
+///     optional float value will be used for the weight of every edge.
+///     Defaults to 1.0.
+/// :param bool normalized: If set to ``True`` the normalized Laplacian
+///     matrix is returned instead. Defaults to ``False``.
+/// :param bool sparse: If set to ``True`` the matrix is returned in CSR
+///     format. Defaults to ``False``.
+///
+/// :returns: The Laplacian matrix as a numpy array or, with ``sparse``, a
+///     tuple of the ``indptr``, ``indices`` and ``data`` numpy arrays, where
+///     ``indptr`` and ``indices`` are ``numpy.int64`` arrays
+/// :rtype: numpy.ndarray or tuple
+/// :raises ValueError: If ``normalized`` is set and a node has a negative
+///     weighted degree
+#[pyfunction(default_weight = "1.0", normalized = "false", sparse = "false")]
+#[pyo3(
+    text_signature = "(graph, /, weight_fn=None, default_weight=1.0, normalized=False, sparse=False)"
+)]
+pub fn graph_laplacian_matrix(
+    py: Python,
+    graph: &graph::PyGraph,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+    normalized: bool,
+    sparse: bool,
+) -> PyResult<PyObject> {
+    laplacian_matrix(
+        py,
+        &graph.graph,
+        weight_fn,
+        default_weight,
+        normalized,
+        sparse,
+        false,
+        true,
+    )
+}
+
+/// Return the Laplacian matrix of a PyDiGraph
+///
+/// The Laplacian matrix is :math:`L = D - A`, where :math:`A` is the
+/// weighted adjacency matrix of the graph, as returned by
+/// :func:`~retworkx.digraph_adjacency_matrix`, and :math:`D` the diagonal
+/// matrix of the weighted out degrees of the nodes, the row sums of
+/// :math:`A`, or of their weighted in degrees, the column sums of
+/// :math:`A`, with ``degree="in"``. The rows of the out degree Laplacian
+/// matrix add up to 0, and so do the columns of the in degree Laplacian
+/// matrix. The normalized Laplacian matrix is
+/// :math:`D^{-1/2} L D^{-1/2} = I - D^{-1/2} A D^{-1/2}`, where the row and
+/// column of a node with a weighted degree of 0 are 0. The weights of
+/// parallel edges are added. A self loop is on the diagonal of :math:`A`
+/// and is counted once in the weighted degree of its node, so it doesn't
+/// change :math:`L` but it changes the normalized Laplacian matrix. Row
+/// ``i`` of the matrix is for the ``i``-th node in
+/// :meth:`~retworkx.PyDiGraph.node_indices`.
+///
+/// With ``sparse`` the matrix is returned in the compressed sparse row
+/// format without creating the dense matrix, with an entry on the diagonal
+/// for every node and an entry for every pair of nodes with an edge, sorted
+/// by column in every row. The arrays can be passed to ``scipy.sparse``::
+///
+///     indptr, indices, data = retworkx.digraph_laplacian_matrix(graph, sparse=True)
+///     n = len(graph)
+///     matrix = scipy.sparse.csr_matrix((data, indices, indptr), shape=(n, n))
+///
+/// :param PyDiGraph graph: The graph to get the Laplacian matrix of
+/// :param weight_fn: An optional callable that will be passed the edge's
+///     payload object and is expected to return a ``float`` weight for that
+///     edge.
+/// :param float default_weight: If ``weight_fn`` isn't specified this
+///     optional float value will be used for the weight of every edge.
+///     Defaults to 1.0.
+/// :param bool normalized: If set to ``True`` the normalized Laplacian
+///     matrix is returned instead. Defaults to ``False``.
+/// :param bool sparse: If set to ``True`` the matrix is returned in CSR
+///     format. Defaults to ``False``.
+/// :param str degree: The weighted degrees on the diagonal of the matrix,
+///     either ``"out"`` (the default) or ``"in"``
+/// :param bool as_undirected: If set to ``True`` every edge goes both ways,
+///     as for a :class:`~retworkx.PyGraph`, so the matrix is symmetric and
+///     ``degree`` is ignored. Defaults to ``False``.
+///
+/// :returns: The Laplacian matrix as a numpy array or, with ``sparse``, a
+///     tuple of the ``indptr``, ``indices`` and ``data`` numpy arrays, where
+///     ``indptr`` and ``indices`` are ``numpy.int64`` arrays
+/// :rtype: numpy.ndarray or tuple
+/// :raises ValueError: If ``degree`` isn't ``"out"`` or ``"in"`` or if
+///     ``normalized`` is set and a node has a negative weighted degree
+#[pyfunction(
+    default_weight = "1.0",
+    normalized = "false",
+    sparse = "false",
+    degree = "\"out\"",
+    as_undirected = "false"
+)]
+#[pyo3(
+    text_signature = "(graph, /, weight_fn=None, default_weight=1.0, normalized=False, sparse=False, degree=\"out\", as_undirected=False)"
+)]
+#[allow(clippy::too_many_arguments)]
+pub fn digraph_laplacian_matrix(
+    py: Python,
+    graph: &digraph::PyDiGraph,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+    normalized: bool,
+    sparse: bool,
+    degree: &str,
+    as_undirected: bool,
+) -> PyResult<PyObject> {
+    let in_degree = match degree {
+        "out" => false,
+        "in" => true,
+        _ => {
+            return Err(PyValueError::new_err(format!(
+                "Invalid degree value '{}', it must be either 'out' or 'in'",
+                degree
+            )))
+        }
+    };
+    laplacian_matrix(
+        py,
+        &graph.graph,
+        weight_fn,
+        default_weight,
+        normalized,
+        sparse,
+        in_degree,
+        as_undirected,
+    )
+}
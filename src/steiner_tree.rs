@@ -10,26 +10,22 @@
 // License for the specific language governing permissions and limitations
 // under the License.
 
-use std::cmp::Ordering;
+use std::convert::Infallible;
 
 use hashbrown::{HashMap, HashSet};
-use rayon::prelude::*;
 
 use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
 use pyo3::Python;
 
-use petgraph::stable_graph::{EdgeIndex, EdgeReference, NodeIndex};
-use petgraph::unionfind::UnionFind;
-use petgraph::visit::{EdgeRef, IntoEdgeReferences, NodeIndexable};
+use petgraph::stable_graph::{EdgeIndex, NodeIndex};
+use petgraph::visit::{EdgeIndexable, EdgeRef, IntoEdgeReferences};
 
-use crate::generators::pairwise;
 use crate::graph;
 use crate::is_valid_weight;
 use crate::shortest_path::all_pairs_dijkstra::all_pairs_dijkstra_shortest_paths;
 
-use retworkx_core::dictmap::*;
-use retworkx_core::shortest_path::dijkstra;
+use retworkx_core::steiner_tree::steiner_tree as core_steiner_tree;
 
 struct MetricClosureEdge {
     source: usize,
@@ -61,6 +57,7 @@ pub fn metric_closure(
 ) -> PyResult<graph::PyGraph> {
     let mut out_graph = graph.clone();
     out_graph.graph.clear_edges();
+    out_graph.attributes.prune(&out_graph.graph);
     let edges = _metric_closure_edges(py, graph, weight_fn)?;
     for edge in edges {
         out_graph.graph.add_edge(
@@ -85,7 +82,7 @@ fn _metric_closure_edges(
     let mut distances = HashMap::with_capacity(graph.graph.node_count());
     let paths =
         all_pairs_dijkstra_shortest_paths(py, &graph.graph, weight_fn, Some(&mut distances))?.paths;
-    let mut nodes: HashSet<usize> = graph.graph.node_indices().map(|x| x.index()).collect();
+    let nodes: HashSet<usize> = graph.graph.node_indices().map(|x| x.index()).collect();
     let first_node = graph
         .graph
         .node_indices()
@@ -103,11 +100,11 @@ fn _metric_closure_edges(
         ));
     }
     // Iterate over node indices for a deterministic order
-    for node in graph.graph.node_indices().map(|x| x.index()) {
+    let node_list: Vec<usize> = graph.graph.node_indices().map(|x| x.index()).collect();
+    for (i, node) in node_list.iter().copied().enumerate() {
         let path_map = &paths[&node].paths;
-        nodes.remove(&node);
         let distance = &distances[&node];
-        for v in &nodes {
+        for v in &node_list[i + 1..] {
             let v_index = NodeIndex::new(*v);
             out_vec.push(MetricClosureEdge {
                 source: node,
@@ -120,106 +117,6 @@ fn _metric_closure_edges(
     Ok(out_vec)
 }
 
-/// Computes the shortest path between all pairs `(s, t)` of the given `terminal_nodes`
-/// *provided* that:
-///   - there is an edge `(u, v)` in the graph and path pass through this edge.
-///   - node `s` is the closest node to  `u` among all `terminal_nodes`
-///   - node `t` is the closest node to `v` among all `terminal_nodes`
-/// and wraps the result inside a `MetricClosureEdge`
-///
-/// For example, if all vertices are terminals, it returns the original edges of the graph.
-fn fast_metric_edges(
-    py: Python,
-    graph: &mut graph::PyGraph,
-    terminal_nodes: &[usize],
-    weight_fn: &PyObject,
-) -> PyResult<Vec<MetricClosureEdge>> {
-    // temporarily add a ``dummy`` node, connect it with
-    // all the terminal nodes and find all the shortest paths
-    // starting from ``dummy`` node.
-    let dummy = graph.graph.add_node(py.None());
-    for node in terminal_nodes {
-        graph
-            .graph
-            .add_edge(dummy, NodeIndex::new(*node), py.None());
-    }
-
-    let cost_fn = |edge: EdgeReference<'_, PyObject>| -> PyResult<f64> {
-        if edge.source() != dummy && edge.target() != dummy {
-            let weight: f64 = weight_fn.call1(py, (edge.weight(),))?.extract(py)?;
-            is_valid_weight(weight)
-        } else {
-            Ok(0.0)
-        }
-    };
-
-    let mut paths = DictMap::with_capacity(graph.graph.node_count());
-    let mut distance: DictMap<NodeIndex, f64> =
-        dijkstra(&graph.graph, dummy, None, cost_fn, Some(&mut paths))?;
-    paths.remove(&dummy);
-    distance.remove(&dummy);
-    graph.graph.remove_node(dummy);
-
-    // ``partition[u]`` holds the terminal node closest to node ``u``.
-    let mut partition: Vec<usize> = vec![std::usize::MAX; graph.graph.node_bound()];
-    for (u, path) in paths.iter() {
-        let u = u.index();
-        partition[u] = path[1].index();
-    }
-
-    let mut out_edges: Vec<MetricClosureEdge> = Vec::with_capacity(graph.graph.edge_count());
-
-    for edge in graph.graph.edge_references() {
-        let source = edge.source();
-        let target = edge.target();
-        // assert that ``source`` is reachable from a terminal node.
-        if distance.contains_key(&source) {
-            let weight = distance[&source] + cost_fn(edge)? + distance[&target];
-            let mut path: Vec<usize> = paths[&source].iter().skip(1).map(|x| x.index()).collect();
-            path.append(
-                &mut paths[&target]
-                    .iter()
-                    .skip(1)
-                    .rev()
-                    .map(|x| x.index())
-                    .collect(),
-            );
-
-            let source = source.index();
-            let target = target.index();
-
-            let mut source = partition[source];
-            let mut target = partition[target];
-
-            match source.cmp(&target) {
-                Ordering::Equal => continue,
-                Ordering::Greater => std::mem::swap(&mut source, &mut target),
-                _ => {}
-            }
-
-            out_edges.push(MetricClosureEdge {
-                source,
-                target,
-                distance: weight,
-                path,
-            });
-        }
-    }
-
-    // if parallel edges, keep the edge with minimum distance.
-    out_edges.par_sort_unstable_by(|a, b| {
-        let weight_a = (a.source, a.target, a.distance);
-        let weight_b = (b.source, b.target, b.distance);
-        weight_a.partial_cmp(&weight_b).unwrap_or(Ordering::Less)
-    });
-
-    out_edges.dedup_by(|edge_a, edge_b| {
-        edge_a.source == edge_b.source && edge_a.target == edge_b.target
-    });
-
-    Ok(out_edges)
-}
-
 /// Return an approximation to the minimum Steiner tree of a graph.
 ///
 /// The minimum tree of ``graph`` with regard to a set of ``terminal_nodes``
@@ -263,110 +160,70 @@ fn fast_metric_edges(
 #[pyo3(text_signature = "(graph, terminal_nodes, weight_fn, /)")]
 pub fn steiner_tree(
     py: Python,
-    graph: &mut graph::PyGraph,
+    graph: &graph::PyGraph,
     terminal_nodes: Vec<usize>,
     weight_fn: PyObject,
 ) -> PyResult<graph::PyGraph> {
-    let mut edge_list = fast_metric_edges(py, graph, &terminal_nodes, &weight_fn)?;
-    let mut subgraphs = UnionFind::<usize>::new(graph.graph.node_bound());
-    edge_list.par_sort_unstable_by(|a, b| {
-        let weight_a = (a.distance, a.source, a.target);
-        let weight_b = (b.distance, b.source, b.target);
-        weight_a.partial_cmp(&weight_b).unwrap_or(Ordering::Less)
-    });
-    let mut mst_edges: Vec<MetricClosureEdge> = Vec::new();
-    for float_edge_pair in edge_list {
-        let u = float_edge_pair.source;
-        let v = float_edge_pair.target;
-        if subgraphs.union(u, v) {
-            mst_edges.push(float_edge_pair);
-        }
+    let mut weights = vec![0.0; graph.graph.edge_bound()];
+    for edge in graph.graph.edge_references() {
+        let weight: f64 = weight_fn.call1(py, (edge.weight(),))?.extract(py)?;
+        weights[edge.id().index()] = is_valid_weight(weight)?;
     }
-    // assert that the terminal nodes are connected.
-    if !terminal_nodes.is_empty() && mst_edges.len() != terminal_nodes.len() - 1 {
-        return Err(PyValueError::new_err(
-            "The terminal nodes in the input graph must belong to the same connected component. \
-                  The steiner tree is not defined for a graph with unconnected terminal nodes",
-        ));
+    for node in &terminal_nodes {
+        if !graph.graph.contains_node(NodeIndex::new(*node)) {
+            return Err(PyValueError::new_err(format!(
+                "Terminal node {} is not in the graph",
+                node
+            )));
+        }
     }
-    // Generate the output graph from the MST
-    let out_edge_list: Vec<[usize; 2]> = mst_edges
+    // The search is seeded with the terminals in reverse order, which keeps
+    // the tie breaking between equally close terminals of earlier releases
+    let terminal_nodes: Vec<NodeIndex> = terminal_nodes
         .into_iter()
-        .flat_map(|edge| pairwise(edge.path))
-        .filter_map(|x| x.0.map(|a| [a, x.1]))
+        .rev()
+        .map(NodeIndex::new)
         .collect();
-    let out_edges: HashSet<(usize, usize)> = out_edge_list.iter().map(|x| (x[0], x[1])).collect();
-    let mut out_graph = graph.clone();
-    let out_nodes: HashSet<NodeIndex> = out_edge_list
+    let tree_edges = match core_steiner_tree(&graph.graph, &terminal_nodes, |edge| {
+        Ok::<f64, Infallible>(weights[edge.id().index()])
+    })
+    .unwrap()
+    {
+        Some(tree_edges) => tree_edges,
+        None => {
+            return Err(PyValueError::new_err(
+                "The terminal nodes in the input graph must belong to the same connected \
+                component. The steiner tree is not defined for a graph with unconnected \
+                terminal nodes",
+            ))
+        }
+    };
+    // Generate the output graph from the edges of the tree
+    let out_edges: HashSet<EdgeIndex> = tree_edges.into_iter().collect();
+    let out_nodes: HashSet<NodeIndex> = out_edges
         .iter()
-        .flat_map(|x| x.iter())
-        .copied()
-        .map(NodeIndex::new)
+        .flat_map(|edge| {
+            let (source, target) = graph.graph.edge_endpoints(*edge).unwrap();
+            vec![source, target]
+        })
         .collect();
+    let mut out_graph = graph.clone();
     for node in graph
         .graph
         .node_indices()
         .filter(|node| !out_nodes.contains(node))
     {
         out_graph.graph.remove_node(node);
+        out_graph.node_keys.remove(node);
         out_graph.node_removed = true;
     }
-    for edge in graph.graph.edge_references().filter(|edge| {
-        let source = edge.source().index();
-        let target = edge.target().index();
-        !out_edges.contains(&(source, target)) && !out_edges.contains(&(target, source))
-    }) {
-        out_graph.graph.remove_edge(edge.id());
+    for edge in graph
+        .graph
+        .edge_indices()
+        .filter(|edge| !out_edges.contains(edge))
+    {
+        out_graph.graph.remove_edge(edge);
     }
-    // Deduplicate potential duplicate edges
-    deduplicate_edges(py, &mut out_graph, &weight_fn)?;
+    out_graph.attributes.prune(&out_graph.graph);
     Ok(out_graph)
 }
-
-fn deduplicate_edges(
-    py: Python,
-    out_graph: &mut graph::PyGraph,
-    weight_fn: &PyObject,
-) -> PyResult<()> {
-    if out_graph.multigraph {
-        // Find all edges between nodes
-        let mut duplicate_map: HashMap<[NodeIndex; 2], Vec<(EdgeIndex, PyObject)>> = HashMap::new();
-        for edge in out_graph.graph.edge_references() {
-            if duplicate_map.contains_key(&[edge.source(), edge.target()]) {
-                duplicate_map
-                    .get_mut(&[edge.source(), edge.target()])
-                    .unwrap()
-                    .push((edge.id(), edge.weight().clone_ref(py)));
-            } else if duplicate_map.contains_key(&[edge.target(), edge.source()]) {
-                duplicate_map
-                    .get_mut(&[edge.target(), edge.source()])
-                    .unwrap()
-                    .push((edge.id(), edge.weight().clone_ref(py)));
-            } else {
-                duplicate_map.insert(
-                    [edge.source(), edge.target()],
-                    vec![(edge.id(), edge.weight().clone_ref(py))],
-                );
-            }
-        }
-        // For a node pair with > 1 edge find minimum edge and remove others
-        for edges_raw in duplicate_map.values().filter(|x| x.len() > 1) {
-            let mut edges: Vec<(EdgeIndex, f64)> = Vec::with_capacity(edges_raw.len());
-            for edge in edges_raw {
-                let res = weight_fn.call1(py, (&edge.1,))?;
-                let raw = res.to_object(py);
-                let weight = raw.extract(py)?;
-                edges.push((edge.0, weight));
-            }
-            edges.par_sort_unstable_by(|a, b| {
-                let weight_a = a.1;
-                let weight_b = b.1;
-                weight_a.partial_cmp(&weight_b).unwrap_or(Ordering::Less)
-            });
-            edges[1..].iter().for_each(|x| {
-                out_graph.graph.remove_edge(x.0);
-            });
-        }
-    }
-    Ok(())
-}
@@ -0,0 +1,689 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use crate::attributes::AttributeColumns;
+use crate::iterators::{NodeIndices, PathLengthMapping, PathMapping};
+use crate::{digraph, edge_weights, graph, weight_callable, InvalidNode, StablePyGraph};
+
+use petgraph::prelude::*;
+use petgraph::visit::{EdgeIndexable, IntoEdgeReferences};
+use petgraph::EdgeType;
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::Python;
+
+use retworkx_core::dictmap::*;
+use retworkx_core::temporal;
+
+/// The arguments of the temporal functions which set the time and the
+/// duration of the edges.
+struct ContactTimes<'a> {
+    time_fn: Option<PyObject>,
+    time_attr: Option<&'a str>,
+    duration_fn: Option<PyObject>,
+    duration_attr: Option<&'a str>,
+    default_duration: f64,
+}
+
+impl<'a> ContactTimes<'a> {
+    /// The departure and arrival times of every edge of ``graph``, indexed
+    /// by edge index, ``None`` for the edges without a time.
+    fn edge_contacts<Ty: EdgeType>(
+        &self,
+        py: Python,
+        graph: &StablePyGraph<Ty>,
+        attributes: &AttributeColumns,
+    ) -> PyResult<Vec<Option<(f64, f64)>>> {
+        let times = match (&self.time_fn, self.time_attr) {
+            (Some(_), Some(_)) => {
+                return Err(PyValueError::new_err(
+                    "time_fn and time_attr can't both be specified",
+                ))
+            }
+            (None, None) => {
+                return Err(PyValueError::new_err(
+                    "One of time_fn or time_attr must be specified",
+                ))
+            }
+            (Some(time_fn), None) => {
+                let mut times = vec![f64::NAN; graph.edge_bound()];
+                for edge in graph.edge_references() {
+                    let time: Option<f64> = time_fn.call1(py, (edge.weight(),))?.extract(py)?;
+                    if let Some(time) = time {
+                        times[edge.id().index()] = time;
+                    }
+                }
+                times
+            }
+            (None, Some(_)) => {
+                edge_weights(py, graph, attributes, &None, self.time_attr, f64::NAN)?.unwrap()
+            }
+        };
+        if self.duration_fn.is_some() && self.duration_attr.is_some() {
+            return Err(PyValueError::new_err(
+                "duration_fn and duration_attr can't both be specified",
+            ));
+        }
+        let durations = match edge_weights(
+            py,
+            graph,
+            attributes,
+            &self.duration_fn,
+            self.duration_attr,
+            self.default_duration,
+        )? {
+            Some(durations) => durations,
+            None => {
+                let mut durations = vec![self.default_duration; graph.edge_bound()];
+                for edge in graph.edge_references() {
+                    durations[edge.id().index()] = weight_callable(
+                        py,
+                        &self.duration_fn,
+                        edge.weight(),
+                        self.default_duration,
+                    )?;
+                }
+                durations
+            }
+        };
+        let mut contacts = vec![None; graph.edge_bound()];
+        for edge in graph.edge_indices() {
+            let (time, duration) = (times[edge.index()], durations[edge.index()]);
+            if duration.is_sign_negative() || duration.is_nan() {
+                return Err(PyValueError::new_err(format!(
+                    "The duration of edge {} is negative or NaN",
+                    edge.index()
+                )));
+            }
+            if !time.is_nan() {
+                contacts[edge.index()] = Some((time, time + duration));
+            }
+        }
+        Ok(contacts)
+    }
+}
+
+/// The earliest arrival time at every node reachable from ``source``,
+/// including ``source``, and the paths to them if ``paths`` is specified.
+#[allow(clippy::too_many_arguments)]
+fn earliest_arrival<Ty: EdgeType>(
+    py: Python,
+    graph: &StablePyGraph<Ty>,
+    attributes: &AttributeColumns,
+    source: usize,
+    contact_times: ContactTimes,
+    start_time: Option<f64>,
+    end_time: Option<f64>,
+    paths: Option<&mut DictMap<NodeIndex, Vec<NodeIndex>>>,
+) -> PyResult<DictMap<NodeIndex, f64>> {
+    if !graph.contains_node(NodeIndex::new(source)) {
+        return Err(InvalidNode::new_err(format!(
+            "Node index {} is not in the graph",
+            source
+        )));
+    }
+    let contacts = contact_times.edge_contacts(py, graph, attributes)?;
+    temporal::earliest_arrival(
+        graph,
+        NodeIndex::new(source),
+        start_time.unwrap_or(f64::NEG_INFINITY),
+        end_time,
+        |edge| Ok(contacts[edge.id().index()]),
+        paths,
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+fn temporal_shortest_paths<Ty: EdgeType>(
+    py: Python,
+    graph: &StablePyGraph<Ty>,
+    attributes: &AttributeColumns,
+    source: usize,
+    target: Option<usize>,
+    contact_times: ContactTimes,
+    start_time: Option<f64>,
+    end_time: Option<f64>,
+) -> PyResult<PathMapping> {
+    let mut paths: DictMap<NodeIndex, Vec<NodeIndex>> = DictMap::new();
+    earliest_arrival(
+        py,
+        graph,
+        attributes,
+        source,
+        contact_times,
+        start_time,
+        end_time,
+        Some(&mut paths),
+    )?;
+    Ok(PathMapping {
+        paths: paths
+            .into_iter()
+            .filter(|(node, _)| {
+                node.index() != source && target.map_or(true, |target| target == node.index())
+            })
+            .map(|(node, path)| {
+                (
+                    node.index(),
+                    path.into_iter().map(|node| node.index()).collect(),
+                )
+            })
+            .collect(),
+    })
+}
+
+fn temporal_earliest_arrival<Ty: EdgeType>(
+    py: Python,
+    graph: &StablePyGraph<Ty>,
+    attributes: &AttributeColumns,
+    source: usize,
+    contact_times: ContactTimes,
+    start_time: Option<f64>,
+    end_time: Option<f64>,
+) -> PyResult<PathLengthMapping> {
+    let arrivals = earliest_arrival(
+        py,
+        graph,
+        attributes,
+        source,
+        contact_times,
+        start_time,
+        end_time,
+        None,
+    )?;
+    Ok(PathLengthMapping {
+        path_lengths: arrivals
+            .into_iter()
+            .filter(|(node, _)| node.index() != source)
+            .map(|(node, arrival)| (node.index(), arrival))
+            .collect(),
+    })
+}
+
+fn temporal_reachability<Ty: EdgeType>(
+    py: Python,
+    graph: &StablePyGraph<Ty>,
+    attributes: &AttributeColumns,
+    source: usize,
+    contact_times: ContactTimes,
+    start_time: Option<f64>,
+    end_time: Option<f64>,
+) -> PyResult<NodeIndices> {
+    let arrivals = earliest_arrival(
+        py,
+        graph,
+        attributes,
+        source,
+        contact_times,
+        start_time,
+        end_time,
+        None,
+    )?;
+    Ok(NodeIndices {
+        nodes: arrivals
+            .keys()
+            .map(|node| node.index())
+            .filter(|node| *node != source)
+            .collect(),
+    })
+}
+
+/// Find the earliest arrival paths from a node of a PyGraph with timestamped
+/// edges
+///
+/// Every edge is a contact between its endpoints at a given time, such as an
+/// interaction in a temporal network, and can be traversed in either
+/// direction from its time to its time plus its duration. A time-respecting
+/// path leaves ``source`` no earlier than ``start_time`` and only follows an
+/// edge whose time is not before the arrival at its first node, so its edges
+/// have non decreasing times. This finds a path arriving first at every node
+/// reachable from ``source`` with a single pass over the edges sorted by
+/// time [1]_, in :math:`O(m \log m)` time for :math:`m` edges.
+///
+/// The times are read with ``time_fn`` or from the ``time_attr`` edge
+/// attribute, one of them must be specified. The edges without a time
+/// (``None`` or NaN) are never traversed. The edges active in a time window
+/// can also be selected beforehand with
+/// :func:`~retworkx.edge_interval_index`.
+///
+/// :param PyGraph graph: The temporal graph
+/// :param int source: The node index to find paths from
+/// :param int target: An optional target node index, if specified only the
+///     path to it is returned
+/// :param time_fn: An optional callable that will be passed the edge's
+///     payload object and is expected to return the ``float`` time of that
+///     edge, or ``None`` if it has none.
+/// :param str time_attr: An optional name of an edge attribute with the time
+///     of the edges, read from the edge attribute column with this name if
+///     the graph has one, see :meth:`~retworkx.PyGraph.set_edge_attribute`,
+///     otherwise from this key of the edges with a dict payload.
+/// :param float start_time: The earliest time the paths can leave ``source``.
+///     If not specified there is no limit.
+/// :param float end_time: The latest time the paths can arrive at a node.
+///     If not specified there is no limit.
+/// :param duration_fn: An optional callable that will be passed the edge's
+///     payload object and is expected to return the non-negative ``float``
+///     time it takes to traverse that edge.
+/// :param str duration_attr: An optional name of an edge attribute with the
+///     duration of the edges, read like ``time_attr``
+/// :param float default_duration: The duration of the edges if neither
+///     ``duration_fn`` nor ``duration_attr`` is specified, or of the edges
+///     without a ``duration_attr`` value. Defaults to 0.0.
+///
+/// :returns: A mapping of every node reachable from ``source``, except
+///     ``source``, to the list of node indices of an earliest arrival path
+///     to it
+/// :rtype: PathMapping
+/// :raises InvalidNode: If ``source`` isn't in the graph
+/// :raises ValueError: If neither or both of ``time_fn`` and ``time_attr``
+///     are specified, if both ``duration_fn`` and ``duration_attr`` are
+///     specified or if a duration is negative or NaN
+///
+/// .. [1] H. Wu, J. Cheng, S. Huang, Y. Ke, Y. Lu and Y. Xu, "Path Problems
+///     in Temporal Graphs", Proceedings of the VLDB Endowment, 7(9),
+///     721-732, 2014.
+#[pyfunction(default_duration = "0.0")]
+#[pyo3(
+    text_signature = "(graph, source, /, target=None, time_fn=None, time_attr=None, start_time=None, end_time=None, duration_fn=None, duration_attr=None, default_duration=0.0)"
+)]
+#[allow(clippy::too_many_arguments)]
+pub fn graph_temporal_shortest_paths(
+    py: Python,
+    graph: &graph::PyGraph,
+    source: usize,
+    target: Option<usize>,
+    time_fn: Option<PyObject>,
+    time_attr: Option<&str>,
+    start_time: Option<f64>,
+    end_time: Option<f64>,
+    duration_fn: Option<PyObject>,
+    duration_attr: Option<&str>,
+    default_duration: f64,
+) -> PyResult<PathMapping> {
+    temporal_shortest_paths(
+        py,
+        &graph.graph,
+        &graph.attributes,
+        source,
+        target,
+        ContactTimes {
+            time_fn,
+            time_attr,
+            duration_fn,
+            duration_attr,
+            default_duration,
+        },
+        start_time,
+        end_time,
+    )
+}
+
+/// Find the earliest arrival paths from a node of a PyDiGraph with
+/// timestamped edges
+///
+/// Every edge is a contact from its source to its target at a given time,
+/// such as a message in a temporal network, and can be traversed from its
+/// time to its time plus its duration. A time-respecting path leaves
+/// ``source`` no earlier than ``start_time`` and only follows an edge whose
+/// time is not before the arrival at its source, so its edges have non
+/// decreasing times. This finds a path arriving first at every node
+/// reachable from ``source`` with a single pass over the edges sorted by
+/// time [1]_, in :math:`O(m \log m)` time for :math:`m` edges.
+///
+/// The times are read with ``time_fn`` or from the ``time_attr`` edge
+/// attribute, one of them must be specified. The edges without a time
+/// (``None`` or NaN) are never traversed. The edges active in a time window
+/// can also be selected beforehand with
+/// :func:`~retworkx.edge_interval_index`.
+///
+/// :param PyDiGraph graph: The temporal graph
+/// :param int source: The node index to find paths from
+/// :param int target: An optional target node index, if specified only the
+///     path to it is returned
+/// :param time_fn: An optional callable that will be passed the edge's
+///     payload object and is expected to return the ``float`` time of that
+///     edge, or ``None`` if it has none.
+/// :param str time_attr: An optional name of an edge attribute with the time
+///     of the edges, read from the edge attribute column with this name if
+///     the graph has one, see :meth:`~retworkx.PyDiGraph.set_edge_attribute`,
+///     otherwise from this key of the edges with a dict payload.
+/// :param float start_time: The earliest time the paths can leave ``source``.
+///     If not specified there is no limit.
+/// :param float end_time: The latest time the paths can arrive at a node.
+///     If not specified there is no limit.
+/// :param duration_fn: An optional callable that will be passed the edge's
+///     payload object and is expected to return the non-negative ``float``
+///     time it takes to traverse that edge.
+/// :param str duration_attr: An optional name of an edge attribute with the
+///     duration of the edges, read like ``time_attr``
+/// :param float default_duration: The duration of the edges if neither
+///     ``duration_fn`` nor ``duration_attr`` is specified, or of the edges
+///     without a ``duration_attr`` value. Defaults to 0.0.
+///
+/// :returns: A mapping of every node reachable from ``source``, except
+///     ``source``, to the list of node indices of an earliest arrival path
+///     to it
+/// :rtype: PathMapping
+/// :raises InvalidNode: If ``source`` isn't in the graph
+/// :raises ValueError: If neither or both of ``time_fn`` and ``time_attr``
+///     are specified, if both ``duration_fn`` and ``duration_attr`` are
+///     specified or if a duration is negative or NaN
+///
+/// .. [1] H. Wu, J. Cheng, S. Huang, Y. Ke, Y. Lu and Y. Xu, "Path Problems
+///     in Temporal Graphs", Proceedings of the VLDB Endowment, 7(9),
+///     721-732, 2014.
+#[pyfunction(default_duration = "0.0")]
+#[pyo3(
+    text_signature = "(graph, source, /, target=None, time_fn=None, time_attr=None, start_time=None, end_time=None, duration_fn=None, duration_attr=None, default_duration=0.0)"
+)]
+#[allow(clippy::too_many_arguments)]
+pub fn digraph_temporal_shortest_paths(
+    py: Python,
+    graph: &digraph::PyDiGraph,
+    source: usize,
+    target: Option<usize>,
+    time_fn: Option<PyObject>,
+    time_attr: Option<&str>,
+    start_time: Option<f64>,
+    end_time: Option<f64>,
+    duration_fn: Option<PyObject>,
+    duration_attr: Option<&str>,
+    default_duration: f64,
+) -> PyResult<PathMapping> {
+    temporal_shortest_paths(
+        py,
+        &graph.graph,
+        &graph.attributes,
+        source,
+        target,
+        ContactTimes {
+            time_fn,
+            time_attr,
+            duration_fn,
+            duration_attr,
+            default_duration,
+        },
+        start_time,
+        end_time,
+    )
+}
+
+/// Find the earliest arrival time at every node reachable from a node of a
+/// PyGraph with timestamped edges
+///
+/// See :func:`~retworkx.graph_temporal_shortest_paths` for the time-respecting
+/// paths and the times of the edges.
+///
+/// :param PyGraph graph: The temporal graph
+/// :param int source: The node index to start from
+/// :param time_fn: An optional callable that will be passed the edge's
+///     payload object and is expected to return the ``float`` time of that
+///     edge, or ``None`` if it has none.
+/// :param str time_attr: An optional name of an edge attribute with the time
+///     of the edges
+/// :param float start_time: The earliest time the paths can leave ``source``.
+///     If not specified there is no limit.
+/// :param float end_time: The latest time the paths can arrive at a node.
+///     If not specified there is no limit.
+/// :param duration_fn: An optional callable that will be passed the edge's
+///     payload object and is expected to return the non-negative ``float``
+///     time it takes to traverse that edge.
+/// :param str duration_attr: An optional name of an edge attribute with the
+///     duration of the edges
+/// :param float default_duration: The duration of the edges if neither
+///     ``duration_fn`` nor ``duration_attr`` is specified, or of the edges
+///     without a ``duration_attr`` value. Defaults to 0.0.
+///
+/// :returns: A mapping of every node reachable from ``source``, except
+///     ``source``, to the earliest time a time-respecting path arrives there
+/// :rtype: PathLengthMapping
+/// :raises InvalidNode: If ``source`` isn't in the graph
+/// :raises ValueError: If neither or both of ``time_fn`` and ``time_attr``
+///     are specified, if both ``duration_fn`` and ``duration_attr`` are
+///     specified or if a duration is negative or NaN
+#[pyfunction(default_duration = "0.0")]
+#[pyo3(
+    text_signature = "(graph, source, /, time_fn=None, time_attr=None, start_time=None, end_time=None, duration_fn=None, duration_attr=None, default_duration=0.0)"
+)]
+#[allow(clippy::too_many_arguments)]
+pub fn graph_temporal_earliest_arrival(
+    py: Python,
+    graph: &graph::PyGraph,
+    source: usize,
+    time_fn: Option<PyObject>,
+    time_attr: Option<&str>,
+    start_time: Option<f64>,
+    end_time: Option<f64>,
+    duration_fn: Option<PyObject>,
+    duration_attr: Option<&str>,
+    default_duration: f64,
+) -> PyResult<PathLengthMapping> {
+    temporal_earliest_arrival(
+        py,
+        &graph.graph,
+        &graph.attributes,
+        source,
+        ContactTimes {
+            time_fn,
+            time_attr,
+            duration_fn,
+            duration_attr,
+            default_duration,
+        },
+        start_time,
+        end_time,
+    )
+}
+
+/// Find the earliest arrival time at every node reachable from a node of a
+/// PyDiGraph with timestamped edges
+///
+/// See :func:`~retworkx.digraph_temporal_shortest_paths` for the
+/// time-respecting paths and the times of the edges.
+///
+/// :param PyDiGraph graph: The temporal graph
+/// :param int source: The node index to start from
+/// :param time_fn: An optional callable that will be passed the edge's
+///     payload object and is expected to return the ``float`` time of that
+///     edge, or ``None`` if it has none.
+/// :param str time_attr: An optional name of an edge attribute with the time
+///     of the edges
+/// :param float start_time: The earliest time the paths can leave ``source``.
+///     If not specified there is no limit.
+/// :param float end_time: The latest time the paths can arrive at a node.
+///     If not specified there is no limit.
+/// :param duration_fn: An optional callable that will be passed the edge's
+///     payload object and is expected to return the non-negative ``float``
+///     time it takes to traverse that edge.
+/// :param str duration_attr: An optional name of an edge attribute with the
+///     duration of the edges
+/// :param float default_duration: The duration of the edges if neither
+///     ``duration_fn`` nor ``duration_attr`` is specified, or of the edges
+///     without a ``duration_attr`` value. Defaults to 0.0.
+///
+/// :returns: A mapping of every node reachable from ``source``, except
+///     ``source``, to the earliest time a time-respecting path arrives there
+/// :rtype: PathLengthMapping
+/// :raises InvalidNode: If ``source`` isn't in the graph
+/// :raises ValueError: If neither or both of ``time_fn`` and ``time_attr``
+///     are specified, if both ``duration_fn`` and ``duration_attr`` are
+///     specified or if a duration is negative or NaN
+#[pyfunction(default_duration = "0.0")]
+#[pyo3(
+    text_signature = "(graph, source, /, time_fn=None, time_attr=None, start_time=None, end_time=None, duration_fn=None, duration_attr=None, default_duration=0.0)"
+)]
+#[allow(clippy::too_many_arguments)]
+pub fn digraph_temporal_earliest_arrival(
+    py: Python,
+    graph: &digraph::PyDiGraph,
+    source: usize,
+    time_fn: Option<PyObject>,
+    time_attr: Option<&str>,
+    start_time: Option<f64>,
+    end_time: Option<f64>,
+    duration_fn: Option<PyObject>,
+    duration_attr: Option<&str>,
+    default_duration: f64,
+) -> PyResult<PathLengthMapping> {
+    temporal_earliest_arrival(
+        py,
+        &graph.graph,
+        &graph.attributes,
+        source,
+        ContactTimes {
+            time_fn,
+            time_attr,
+            duration_fn,
+            duration_attr,
+            default_duration,
+        },
+        start_time,
+        end_time,
+    )
+}
+
+/// Find the nodes reachable from a node of a PyGraph with timestamped edges
+/// by a time-respecting path
+///
+/// See :func:`~retworkx.graph_temporal_shortest_paths` for the time-respecting
+/// paths and the times of the edges. Unlike in a static graph, a node can be
+/// reachable from another one without the reverse being true.
+///
+/// :param PyGraph graph: The temporal graph
+/// :param int source: The node index to start from
+/// :param time_fn: An optional callable that will be passed the edge's
+///     payload object and is expected to return the ``float`` time of that
+///     edge, or ``None`` if it has none.
+/// :param str time_attr: An optional name of an edge attribute with the time
+///     of the edges
+/// :param float start_time: The earliest time the paths can leave ``source``.
+///     If not specified there is no limit.
+/// :param float end_time: The latest time the paths can arrive at a node.
+///     If not specified there is no limit.
+/// :param duration_fn: An optional callable that will be passed the edge's
+///     payload object and is expected to return the non-negative ``float``
+///     time it takes to traverse that edge.
+/// :param str duration_attr: An optional name of an edge attribute with the
+///     duration of the edges
+/// :param float default_duration: The duration of the edges if neither
+///     ``duration_fn`` nor ``duration_attr`` is specified, or of the edges
+///     without a ``duration_attr`` value. Defaults to 0.0.
+///
+/// :returns: The indices of the nodes reachable from ``source``, except
+///     ``source``, in increasing order
+/// :rtype: NodeIndices
+/// :raises InvalidNode: If ``source`` isn't in the graph
+/// :raises ValueError: If neither or both of ``time_fn`` and ``time_attr``
+///     are specified, if both ``duration_fn`` and ``duration_attr`` are
+///     specified or if a duration is negative or NaN
+#[pyfunction(default_duration = "0.0")]
+#[pyo3(
+    text_signature = "(graph, source, /, time_fn=None, time_attr=None, start_time=None, end_time=None, duration_fn=None, duration_attr=None, default_duration=0.0)"
+)]
+#[allow(clippy::too_many_arguments)]
+pub fn graph_temporal_reachability(
+    py: Python,
+    graph: &graph::PyGraph,
+    source: usize,
+    time_fn: Option<PyObject>,
+    time_attr: Option<&str>,
+    start_time: Option<f64>,
+    end_time: Option<f64>,
+    duration_fn: Option<PyObject>,
+    duration_attr: Option<&str>,
+    default_duration: f64,
+) -> PyResult<NodeIndices> {
+    temporal_reachability(
+        py,
+        &graph.graph,
+        &graph.attributes,
+        source,
+        ContactTimes {
+            time_fn,
+            time_attr,
+            duration_fn,
+            duration_attr,
+            default_duration,
+        },
+        start_time,
+        end_time,
+    )
+}
+
+/// Find the nodes reachable from a node of a PyDiGraph with timestamped
+/// edges by a time-respecting path
+///
+/// See :func:`~retworkx.digraph_temporal_shortest_paths` for the
+/// time-respecting paths and the times of the edges. Unlike with
+/// :func:`~retworkx.descendants`, a node can be unreachable even if there is
+/// a path to it, when the times of the edges of every path to it decrease.
+///
+/// :param PyDiGraph graph: The temporal graph
+/// :param int source: The node index to start from
+/// :param time_fn: An optional callable that will be passed the edge's
+///     payload object and is expected to return the ``float`` time of that
+///     edge, or ``None`` if it has none.
+/// :param str time_attr: An optional name of an edge attribute with the time
+///     of the edges
+/// :param float start_time: The earliest time the paths can leave ``source``.
+///     If not specified there is no limit.
+/// :param float end_time: The latest time the paths can arrive at a node.
+///     If not specified there is no limit.
+/// :param duration_fn: An optional callable that will be passed the edge's
+///     payload object and is expected to return the non-negative ``float``
+///     time it takes to traverse that edge.
+/// :param str duration_attr: An optional name of an edge attribute with the
+///     duration of the edges
+/// :param float default_duration: The duration of the edges if neither
+///     ``duration_fn`` nor ``duration_attr`` is specified, or of the edges
+///     without a ``duration_attr`` value. Defaults to 0.0.
+///
+/// :returns: The indices of the nodes reachable from ``source``, except
+///     ``source``, in increasing order
+/// :rtype: NodeIndices
+/// :raises InvalidNode: If ``source`` isn't in the graph
+/// :raises ValueError: If neither or both of ``time_fn`` and ``time_attr``
+///     are specified, if both ``duration_fn`` and ``duration_attr`` are
+///     specified or if a duration is negative or NaN
+#[pyfunction(default_duration = "0.0")]
+#[pyo3(
+    text_signature = "(graph, source, /, time_fn=None, time_attr=None, start_time=None, end_time=None, duration_fn=None, duration_attr=None, default_duration=0.0)"
+)]
+#[allow(clippy::too_many_arguments)]
+pub fn digraph_temporal_reachability(
+    py: Python,
+    graph: &digraph::PyDiGraph,
+    source: usize,
+    time_fn: Option<PyObject>,
+    time_attr: Option<&str>,
+    start_time: Option<f64>,
+    end_time: Option<f64>,
+    duration_fn: Option<PyObject>,
+    duration_attr: Option<&str>,
+    default_duration: f64,
+) -> PyResult<NodeIndices> {
+    temporal_reachability(
+        py,
+        &graph.graph,
+        &graph.attributes,
+        source,
+        ContactTimes {
+            time_fn,
+            time_attr,
+            duration_fn,
+            duration_attr,
+            default_duration,
+        },
+        start_time,
+        end_time,
+    )
+}
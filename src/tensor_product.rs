@@ -10,13 +10,15 @@
 // License for the specific language governing permissions and limitations
 // under the License.
 
+use crate::attributes::AttributeColumns;
 use crate::iterators::ProductNodeMap;
-use crate::{digraph, graph, StablePyGraph};
+use crate::node_keys::NodeKeys;
+use crate::{deterministic_order, digraph, graph, StablePyGraph};
 
 use hashbrown::HashMap;
 
 use petgraph::visit::{EdgeRef, IntoEdgeReferences};
-use petgraph::{algo, EdgeType};
+use petgraph::EdgeType;
 
 use pyo3::prelude::*;
 use pyo3::Python;
@@ -80,11 +82,13 @@ fn tensor_product<Ty: EdgeType>(
         }
     }
 
+    let mut node_map: Vec<((usize, usize), usize)> = hash_nodes
+        .into_iter()
+        .map(|((x, y), n)| ((x.index(), y.index()), n.index()))
+        .collect();
+    deterministic_order(&mut node_map, |(_, n)| *n);
     let out_node_map = ProductNodeMap {
-        node_map: hash_nodes
-            .into_iter()
-            .map(|((x, y), n)| ((x.index(), y.index()), n.index()))
-            .collect(),
+        node_map: node_map.into_iter().collect(),
     };
 
     (final_graph, out_node_map)
@@ -133,6 +137,9 @@ pub fn graph_tensor_product(
             graph: out_graph,
             multigraph: true,
             node_removed: false,
+            node_keys: NodeKeys::default(),
+            attributes: AttributeColumns::default(),
+            generation: 0,
         },
         out_node_map,
     )
@@ -179,10 +186,13 @@ pub fn digraph_tensor_product(
     (
         digraph::PyDiGraph {
             graph: out_graph,
-            cycle_state: algo::DfsSpace::default(),
+            cycle_state: None,
             check_cycle: false,
             node_removed: false,
             multigraph: true,
+            node_keys: NodeKeys::default(),
+            attributes: AttributeColumns::default(),
+            generation: 0,
         },
         out_node_map,
     )
@@ -10,11 +10,14 @@
 // License for the specific language governing permissions and limitations
 // under the License.
 
+use std::collections::VecDeque;
+
 use crate::digraph::PyDiGraph;
 
 use hashbrown::hash_map::Entry;
 use hashbrown::HashMap;
 
+use pyo3::class::iter::IterNextOutput;
 use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
 use pyo3::Python;
@@ -23,6 +26,7 @@ use petgraph::stable_graph::NodeIndex;
 use petgraph::visit::IntoNodeIdentifiers;
 
 use crate::dag_algo::is_directed_acyclic_graph;
+use crate::iterators::NodeIndices;
 use crate::DAGHasCycle;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -205,3 +209,239 @@ impl TopologicalSorter {
         Ok(())
     }
 }
+
+/// A lazy iterator over the topological generations of a DAG, returned by
+/// :func:`~retworkx.topological_generations`.
+///
+/// Each iteration returns a :class:`~retworkx.NodeIndices` object with the
+/// node indices of the next generation. The next generation is only found
+/// once the previous one is returned, so the generations are never all held
+/// in memory at once.
+///
+/// The underlying graph should not be mutated while iterating, the
+/// iterator may pick up the modifications but it can result in a
+/// logical-error.
+#[pyclass(module = "retworkx")]
+pub struct TopologicalGenerations {
+    dag: Py<PyDiGraph>,
+    generation: Vec<NodeIndex>,
+    predecessor_count: HashMap<NodeIndex, usize>,
+    num_passed_out: usize,
+    done: bool,
+}
+
+impl TopologicalGenerations {
+    pub fn new(py: Python, dag: Py<PyDiGraph>) -> Self {
+        let generation = {
+            let dag = &dag.borrow(py);
+
+            dag.graph
+                .node_identifiers()
+                .filter(|node| {
+                    dag.graph
+                        .neighbors_directed(*node, petgraph::Direction::Incoming)
+                        .next()
+                        .is_none()
+                })
+                .collect()
+        };
+
+        TopologicalGenerations {
+            dag,
+            generation,
+            predecessor_count: HashMap::new(),
+            num_passed_out: 0,
+            done: false,
+        }
+    }
+}
+
+#[pymethods]
+impl TopologicalGenerations {
+    fn __iter__(slf: PyRef<Self>) -> Py<TopologicalGenerations> {
+        slf.into()
+    }
+
+    fn __next__(
+        mut slf: PyRefMut<Self>,
+        py: Python,
+    ) -> PyResult<IterNextOutput<NodeIndices, &'static str>> {
+        if slf.done {
+            return Ok(IterNextOutput::Return("Ended"));
+        }
+        let dag = slf.dag.clone_ref(py);
+        let dag = &dag.borrow(py);
+        if slf.generation.is_empty() {
+            slf.done = true;
+            if slf.num_passed_out < dag.graph.node_count() {
+                return Err(DAGHasCycle::new_err("Sort encountered a cycle"));
+            }
+            return Ok(IterNextOutput::Return("Ended"));
+        }
+        let generation = std::mem::take(&mut slf.generation);
+        let mut next_generation = Vec::new();
+        for node in &generation {
+            for succ in dag
+                .graph
+                .neighbors_directed(*node, petgraph::Direction::Outgoing)
+            {
+                match slf.predecessor_count.entry(succ) {
+                    Entry::Occupied(mut entry) => {
+                        *entry.get_mut() -= 1;
+                        if *entry.get() == 0 {
+                            next_generation.push(succ);
+                            entry.remove_entry();
+                        }
+                    }
+                    Entry::Vacant(entry) => {
+                        let in_degree = dag
+                            .graph
+                            .neighbors_directed(succ, petgraph::Direction::Incoming)
+                            .count()
+                            - 1;
+
+                        if in_degree == 0 {
+                            next_generation.push(succ);
+                        } else {
+                            entry.insert(in_degree);
+                        }
+                    }
+                }
+            }
+        }
+        slf.generation = next_generation;
+        slf.num_passed_out += generation.len();
+        Ok(IterNextOutput::Yield(NodeIndices {
+            nodes: generation.iter().map(|node| node.index()).collect(),
+        }))
+    }
+}
+
+/// A resumable topological traversal of a DAG, returned by
+/// :func:`~retworkx.topological_traversal`.
+///
+/// Iterating over it returns the node indices of the DAG in topological
+/// order, one at a time: a node is only returned once all of its
+/// predecessors were returned. It holds the state of the traversal, the
+/// nodes ready to be returned and the number of predecessors of the other
+/// nodes not returned yet, so the traversal can be paused at any node and
+/// resumed later by iterating again. Its state can be saved with
+/// :meth:`copy`, which returns an independent traversal at the same
+/// position.
+///
+/// The underlying graph should not be mutated while iterating, the
+/// traversal may pick up the modifications but it can result in a
+/// logical-error.
+#[pyclass(module = "retworkx")]
+pub struct TopologicalTraversal {
+    dag: Py<PyDiGraph>,
+    ready_nodes: VecDeque<NodeIndex>,
+    predecessor_count: HashMap<NodeIndex, usize>,
+    num_passed_out: usize,
+    done: bool,
+}
+
+impl TopologicalTraversal {
+    pub fn new(py: Python, dag: Py<PyDiGraph>) -> Self {
+        let ready_nodes = {
+            let dag = &dag.borrow(py);
+
+            dag.graph
+                .node_identifiers()
+                .filter(|node| {
+                    dag.graph
+                        .neighbors_directed(*node, petgraph::Direction::Incoming)
+                        .next()
+                        .is_none()
+                })
+                .collect()
+        };
+
+        TopologicalTraversal {
+            dag,
+            ready_nodes,
+            predecessor_count: HashMap::new(),
+            num_passed_out: 0,
+            done: false,
+        }
+    }
+}
+
+#[pymethods]
+impl TopologicalTraversal {
+    fn __iter__(slf: PyRef<Self>) -> Py<TopologicalTraversal> {
+        slf.into()
+    }
+
+    fn __next__(
+        mut slf: PyRefMut<Self>,
+        py: Python,
+    ) -> PyResult<IterNextOutput<usize, &'static str>> {
+        if slf.done {
+            return Ok(IterNextOutput::Return("Ended"));
+        }
+        let dag = slf.dag.clone_ref(py);
+        let dag = &dag.borrow(py);
+        let node = match slf.ready_nodes.pop_front() {
+            Some(node) => node,
+            None => {
+                slf.done = true;
+                if slf.num_passed_out < dag.graph.node_count() {
+                    return Err(DAGHasCycle::new_err("Sort encountered a cycle"));
+                }
+                return Ok(IterNextOutput::Return("Ended"));
+            }
+        };
+        for succ in dag
+            .graph
+            .neighbors_directed(node, petgraph::Direction::Outgoing)
+        {
+            match slf.predecessor_count.entry(succ) {
+                Entry::Occupied(mut entry) => {
+                    *entry.get_mut() -= 1;
+                    if *entry.get() == 0 {
+                        entry.remove_entry();
+                        slf.ready_nodes.push_back(succ);
+                    }
+                }
+                Entry::Vacant(entry) => {
+                    let in_degree = dag
+                        .graph
+                        .neighbors_directed(succ, petgraph::Direction::Incoming)
+                        .count()
+                        - 1;
+
+                    if in_degree == 0 {
+                        slf.ready_nodes.push_back(succ);
+                    } else {
+                        entry.insert(in_degree);
+                    }
+                }
+            }
+        }
+        slf.num_passed_out += 1;
+        Ok(IterNextOutput::Yield(node.index()))
+    }
+
+    /// Return a copy of the traversal at its current position
+    ///
+    /// The copy shares the graph but has its own state, so the traversal and
+    /// its copy can be advanced independently. This can be used to save the
+    /// state of the traversal and resume from it later.
+    ///
+    /// :returns: A copy of the traversal
+    #[pyo3(text_signature = "(self)")]
+    fn copy(&self, py: Python) -> TopologicalTraversal {
+        TopologicalTraversal {
+            dag: self.dag.clone_ref(py),
+            ready_nodes: self.ready_nodes.clone(),
+            predecessor_count: self.predecessor_count.clone(),
+            num_passed_out: self.num_passed_out,
+            done: self.done,
+        }
+    }
+
+    fn __copy__(&self, py: Python) -> TopologicalTraversal {
+        self.copy(py)
+    }
+}
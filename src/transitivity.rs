@@ -11,36 +11,17 @@
 // under the License.
 
 use super::{digraph, graph};
-use hashbrown::HashSet;
+use crate::iterators::CentralityMapping;
 
 use pyo3::prelude::*;
+use pyo3::types::PyDict;
 
 use petgraph::graph::NodeIndex;
+use petgraph::Direction::{Incoming, Outgoing};
 use rayon::prelude::*;
 
-fn _graph_triangles(graph: &graph::PyGraph, node: usize) -> (usize, usize) {
-    let mut triangles: usize = 0;
-
-    let index = NodeIndex::new(node);
-    let mut neighbors: HashSet<NodeIndex> = graph.graph.neighbors(index).collect();
-    neighbors.remove(&index);
-
-    for nodev in &neighbors {
-        triangles += graph
-            .graph
-            .neighbors(*nodev)
-            .filter(|&x| (x != *nodev) && neighbors.contains(&x))
-            .count();
-    }
-
-    let d: usize = neighbors.len();
-    let triples: usize = match d {
-        0 => 0,
-        _ => (d * (d - 1)) / 2,
-    };
-
-    (triangles / 2, triples)
-}
+use retworkx_core::neighbors::{intersection_len, SortedNeighbors};
+use retworkx_core::transitivity;
 
 /// Compute the transitivity of an undirected graph.
 ///
@@ -58,11 +39,7 @@ fn _graph_triangles(graph: &graph::PyGraph, node: usize) -> (usize, usize) {
 /// environment variable. For example, setting ``RAYON_NUM_THREADS=4`` would
 /// limit the thread pool to 4 threads.
 ///
-/// .. note::
-///
-///     The function implicitly assumes that there are no parallel edges
-///     or self loops. It may produce incorrect/unexpected results if the
-///     input graph has self loops or parallel edges.
+/// Self loops and parallel edges are ignored.
 ///
 /// :param PyGraph graph: Graph to be used.
 ///
@@ -71,67 +48,136 @@ fn _graph_triangles(graph: &graph::PyGraph, node: usize) -> (usize, usize) {
 #[pyfunction]
 #[pyo3(text_signature = "(graph, /)")]
 pub fn graph_transitivity(graph: &graph::PyGraph) -> f64 {
-    let node_indices: Vec<NodeIndex> = graph.graph.node_indices().collect();
-    let (triangles, triples) = node_indices
-        .par_iter()
-        .map(|node| _graph_triangles(graph, node.index()))
-        .reduce(
-            || (0, 0),
-            |(sumx, sumy), (resx, resy)| (sumx + resx, sumy + resy),
-        );
+    transitivity::transitivity(&graph.graph, 0)
+}
 
-    match triangles {
-        0 => 0.0,
-        _ => triangles as f64 / triples as f64,
+/// Count the triangles every node of an undirected graph is in.
+///
+/// The triangles are counted with a degree ordered algorithm, which finds
+/// every triangle once from its node of lowest degree. This function is
+/// multithreaded and will launch a thread pool with threads equal to the
+/// number of CPUs by default if the number of nodes in the graph is above
+/// the value of ``parallel_threshold`` (it defaults to 300). You can tune
+/// the number of threads with the ``RAYON_NUM_THREADS`` environment
+/// variable. For example, setting ``RAYON_NUM_THREADS=4`` would limit the
+/// thread pool to 4 threads.
+///
+/// Self loops and parallel edges are ignored.
+///
+/// :param PyGraph graph: The graph to count the triangles of
+/// :param int parallel_threshold: The number of nodes to count the
+///     triangles in parallel at, if the graph has fewer nodes the triangles
+///     are counted in a single thread. It defaults to 300.
+///
+/// :returns: A dictionary keyed by node index to the number of triangles
+///     the node is in
+/// :rtype: dict
+///
+/// .. jupyter-execute::
+///
+///     import retworkx
+///
+///     graph = retworkx.generators.mesh_graph(4)
+///     graph.add_node(None)
+///     print(retworkx.triangles(graph))
+#[pyfunction(parallel_threshold = "300")]
+#[pyo3(text_signature = "(graph, /, parallel_threshold=300)")]
+pub fn triangles(
+    py: Python,
+    graph: &graph::PyGraph,
+    parallel_threshold: usize,
+) -> PyResult<PyObject> {
+    let out_dict = PyDict::new(py);
+    let counts = transitivity::triangles(&graph.graph, parallel_threshold);
+    for (node, count) in counts.into_iter().enumerate() {
+        if let Some(count) = count {
+            out_dict.set_item(node, count)?;
+        }
     }
+    Ok(out_dict.into())
 }
 
-fn _digraph_triangles(graph: &digraph::PyDiGraph, node: usize) -> (usize, usize) {
-    let mut triangles: usize = 0;
-
-    let index = NodeIndex::new(node);
-    let mut out_neighbors: HashSet<NodeIndex> = graph
-        .graph
-        .neighbors_directed(index, petgraph::Direction::Outgoing)
-        .collect();
-    out_neighbors.remove(&index);
-
-    let mut in_neighbors: HashSet<NodeIndex> = graph
-        .graph
-        .neighbors_directed(index, petgraph::Direction::Incoming)
-        .collect();
-    in_neighbors.remove(&index);
-
-    let neighbors = out_neighbors.iter().chain(in_neighbors.iter());
-
-    for nodev in neighbors {
-        triangles += graph
-            .graph
-            .neighbors_directed(*nodev, petgraph::Direction::Outgoing)
-            .chain(
-                graph
-                    .graph
-                    .neighbors_directed(*nodev, petgraph::Direction::Incoming),
-            )
-            .map(|x| {
-                let mut res: usize = 0;
-
-                if (x != *nodev) && out_neighbors.contains(&x) {
-                    res += 1;
-                }
-                if (x != *nodev) && in_neighbors.contains(&x) {
-                    res += 1;
-                }
-                res
-            })
-            .sum::<usize>();
+/// Compute the local clustering coefficient of every node of an undirected
+/// graph.
+///
+/// The local clustering coefficient of a node is the fraction of the pairs
+/// of its neighbors which are neighbors of each other:
+///
+/// .. math::
+///
+///     c_v = \frac{2 T(v)}{deg(v)(deg(v)-1)}
+///
+/// where :math:`T(v)` is the number of triangles the node is in. It is 0
+/// for a node with fewer than 2 neighbors.
+///
+/// This function is multithreaded and will launch a thread pool with threads
+/// equal to the number of CPUs by default if the number of nodes in the
+/// graph is above the value of ``parallel_threshold`` (it defaults to 300).
+/// You can tune the number of threads with the ``RAYON_NUM_THREADS``
+/// environment variable. For example, setting ``RAYON_NUM_THREADS=4`` would
+/// limit the thread pool to 4 threads.
+///
+/// Self loops and parallel edges are ignored.
+///
+/// :param PyGraph graph: The graph to compute the coefficients of
+/// :param int parallel_threshold: The number of nodes to count the
+///     triangles in parallel at, if the graph has fewer nodes the triangles
+///     are counted in a single thread. It defaults to 300.
+///
+/// :returns: A mapping of node index to the local clustering coefficient
+///     of the node
+/// :rtype: CentralityMapping
+///
+/// .. jupyter-execute::
+///
+///     import retworkx
+///
+///     graph = retworkx.PyGraph()
+///     graph.extend_from_edge_list([(0, 1), (1, 2), (2, 0), (1, 3), (2, 3)])
+///     print(retworkx.local_clustering_coefficient(graph))
+#[pyfunction(parallel_threshold = "300")]
+#[pyo3(text_signature = "(graph, /, parallel_threshold=300)")]
+pub fn local_clustering_coefficient(
+    graph: &graph::PyGraph,
+    parallel_threshold: usize,
+) -> CentralityMapping {
+    let coefficients = transitivity::local_clustering_coefficient(&graph.graph, parallel_threshold);
+    CentralityMapping {
+        centralities: coefficients
+            .into_iter()
+            .enumerate()
+            .filter_map(|(i, v)| v.map(|x| (i, x)))
+            .collect(),
     }
+}
 
-    let din: usize = in_neighbors.len();
-    let dout: usize = out_neighbors.len();
+fn _digraph_triangles(
+    out_neighbors: &SortedNeighbors,
+    in_neighbors: &SortedNeighbors,
+    node: usize,
+) -> (usize, usize) {
+    let node_out = out_neighbors.get(node);
+    let node_in = in_neighbors.get(node);
+
+    // Every neighbor of a neighbor closes a triangle with each of the edges
+    // between it and the node
+    let triangles: usize = node_out
+        .iter()
+        .chain(node_in.iter())
+        .map(|nodev| {
+            let (v_out, v_in) = (out_neighbors.get(*nodev), in_neighbors.get(*nodev));
+            intersection_len(v_out, node_out)
+                + intersection_len(v_out, node_in)
+                + intersection_len(v_in, node_out)
+                + intersection_len(v_in, node_in)
+        })
+        .sum();
+
+    let din: usize = node_in.len();
+    let dout: usize = node_out.len();
 
     let dtot = dout + din;
-    let dbil: usize = out_neighbors.intersection(&in_neighbors).count();
+    let dbil: usize = intersection_len(node_out, node_in);
     let triples: usize = match dtot {
         0 => 0,
         _ => dtot * (dtot - 1) - 2 * dbil,
@@ -173,9 +219,11 @@ fn _digraph_triangles(graph: &digraph::PyDiGraph, node: usize) -> (usize, usize)
 #[pyo3(text_signature = "(graph, /)")]
 pub fn digraph_transitivity(graph: &digraph::PyDiGraph) -> f64 {
     let node_indices: Vec<NodeIndex> = graph.graph.node_indices().collect();
+    let out_neighbors = SortedNeighbors::new(&graph.graph, Outgoing);
+    let in_neighbors = SortedNeighbors::new(&graph.graph, Incoming);
     let (triangles, triples) = node_indices
         .par_iter()
-        .map(|node| _digraph_triangles(graph, node.index()))
+        .map(|node| _digraph_triangles(&out_neighbors, &in_neighbors, node.index()))
         .reduce(
             || (0, 0),
             |(sumx, sumy), (resx, resy)| (sumx + resx, sumy + resy),
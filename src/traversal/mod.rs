@@ -13,16 +13,20 @@
 mod bfs_visit;
 pub mod dfs_visit;
 mod dijkstra_visit;
+mod resumable;
 
 use bfs_visit::{bfs_handler, PyBfsVisitor};
 use dfs_visit::{dfs_handler, PyDfsVisitor};
 use dijkstra_visit::{dijkstra_handler, PyDijkstraVisitor};
+use resumable::TraversalGraph;
+pub use resumable::{BFSTraversal, DFSTraversal};
 
 use retworkx_core::traversal::{
-    breadth_first_search, depth_first_search, dfs_edges, dijkstra_search,
+    breadth_first_search, depth_first_search, descendants_at_distance, dfs_edges, dijkstra_search,
+    reachable_within,
 };
 
-use super::{digraph, graph, iterators, CostFn};
+use super::{digraph, graph, iterators, CostFn, InvalidNode, StablePyGraph};
 
 use std::convert::TryFrom;
 
@@ -33,9 +37,11 @@ use pyo3::Python;
 
 use petgraph::algo;
 use petgraph::graph::NodeIndex;
-use petgraph::visit::{Bfs, NodeCount, Reversed};
+use petgraph::visit::{Bfs, EdgeRef, NodeCount, Reversed};
+use petgraph::EdgeType;
 
 use crate::iterators::EdgeList;
+use crate::views::{with_digraph_or_view, with_graph_or_view, DiGraphOrView, GraphOrView};
 
 /// Get an edge list of the tree edges from a depth-first traversal
 ///
@@ -122,6 +128,138 @@ pub fn graph_dfs_edges(graph: &graph::PyGraph, source: Option<usize>) -> EdgeLis
     }
 }
 
+/// Get a resumable depth-first traversal of a PyDiGraph
+///
+/// The traversal is an iterator over the node indices of the graph in
+/// depth-first order, following the edges in their direction. Unlike the
+/// functions which traverse the whole graph at once, such as
+/// :func:`~retworkx.digraph_dfs_edges`, the nodes are found one at a time
+/// as the traversal is iterated over, so it can be paused and resumed, and
+/// its state can be saved with :meth:`~retworkx.DFSTraversal.copy`.
+///
+/// :param PyDiGraph graph: The graph to traverse
+/// :param int source: An optional node index to start the traversal from.
+///     The traversal will only return the nodes reachable from this index.
+///     If this is not specified then the traversal starts from the lowest
+///     node index, and is restarted from the lowest undiscovered node index
+///     until all nodes of the graph are returned.
+///
+/// :returns: The traversal, an iterator of node indices
+/// :rtype: DFSTraversal
+/// :raises InvalidNode: If ``source`` is not in the graph
+#[pyfunction]
+#[pyo3(text_signature = "(graph, /, source=None)")]
+pub fn digraph_dfs_traversal(
+    py: Python,
+    graph: Py<digraph::PyDiGraph>,
+    source: Option<usize>,
+) -> PyResult<DFSTraversal> {
+    let source = match source {
+        Some(source) => Some(node_index(&graph.borrow(py).graph, source)?),
+        None => None,
+    };
+    Ok(DFSTraversal::new(TraversalGraph::DiGraph(graph), source))
+}
+
+/// Get a resumable depth-first traversal of a PyGraph
+///
+/// The traversal is an iterator over the node indices of the graph in
+/// depth-first order. Unlike the functions which traverse the whole graph at
+/// once, such as :func:`~retworkx.graph_dfs_edges`, the nodes are found one
+/// at a time as the traversal is iterated over, so it can be paused and
+/// resumed, and its state can be saved with
+/// :meth:`~retworkx.DFSTraversal.copy`.
+///
+/// :param PyGraph graph: The graph to traverse
+/// :param int source: An optional node index to start the traversal from.
+///     The traversal will only return the nodes in the component of this
+///     index. If this is not specified then the traversal starts from the
+///     lowest node index, and is restarted from the lowest undiscovered node
+///     index until all nodes of the graph are returned.
+///
+/// :returns: The traversal, an iterator of node indices
+/// :rtype: DFSTraversal
+/// :raises InvalidNode: If ``source`` is not in the graph
+#[pyfunction]
+#[pyo3(text_signature = "(graph, /, source=None)")]
+pub fn graph_dfs_traversal(
+    py: Python,
+    graph: Py<graph::PyGraph>,
+    source: Option<usize>,
+) -> PyResult<DFSTraversal> {
+    let source = match source {
+        Some(source) => Some(node_index(&graph.borrow(py).graph, source)?),
+        None => None,
+    };
+    Ok(DFSTraversal::new(TraversalGraph::Graph(graph), source))
+}
+
+/// Get a resumable breadth-first traversal of a PyDiGraph
+///
+/// The traversal is an iterator over the node indices of the graph in
+/// breadth-first order, following the edges in their direction. Unlike the
+/// functions which traverse the whole graph at once, such as
+/// :func:`~retworkx.bfs_successors`, the nodes are found one at a time as
+/// the traversal is iterated over, so it can be paused and resumed, and its
+/// state can be saved with :meth:`~retworkx.BFSTraversal.copy`.
+///
+/// :param PyDiGraph graph: The graph to traverse
+/// :param int source: An optional node index to start the traversal from.
+///     The traversal will only return the nodes reachable from this index.
+///     If this is not specified then the traversal starts from the lowest
+///     node index, and is restarted from the lowest undiscovered node index
+///     until all nodes of the graph are returned.
+///
+/// :returns: The traversal, an iterator of node indices
+/// :rtype: BFSTraversal
+/// :raises InvalidNode: If ``source`` is not in the graph
+#[pyfunction]
+#[pyo3(text_signature = "(graph, /, source=None)")]
+pub fn digraph_bfs_traversal(
+    py: Python,
+    graph: Py<digraph::PyDiGraph>,
+    source: Option<usize>,
+) -> PyResult<BFSTraversal> {
+    let source = match source {
+        Some(source) => Some(node_index(&graph.borrow(py).graph, source)?),
+        None => None,
+    };
+    Ok(BFSTraversal::new(TraversalGraph::DiGraph(graph), source))
+}
+
+/// Get a resumable breadth-first traversal of a PyGraph
+///
+/// The traversal is an iterator over the node indices of the graph in
+/// breadth-first order. Unlike the functions which traverse the whole graph
+/// at once, such as :func:`~retworkx.graph_bfs_search`, the nodes are found
+/// one at a time as the traversal is iterated over, so it can be paused and
+/// resumed, and its state can be saved with
+/// :meth:`~retworkx.BFSTraversal.copy`.
+///
+/// :param PyGraph graph: The graph to traverse
+/// :param int source: An optional node index to start the traversal from.
+///     The traversal will only return the nodes in the component of this
+///     index. If this is not specified then the traversal starts from the
+///     lowest node index, and is restarted from the lowest undiscovered node
+///     index until all nodes of the graph are returned.
+///
+/// :returns: The traversal, an iterator of node indices
+/// :rtype: BFSTraversal
+/// :raises InvalidNode: If ``source`` is not in the graph
+#[pyfunction]
+#[pyo3(text_signature = "(graph, /, source=None)")]
+pub fn graph_bfs_traversal(
+    py: Python,
+    graph: Py<graph::PyGraph>,
+    source: Option<usize>,
+) -> PyResult<BFSTraversal> {
+    let source = match source {
+        Some(source) => Some(node_index(&graph.borrow(py).graph, source)?),
+        None => None,
+    };
+    Ok(BFSTraversal::new(TraversalGraph::Graph(graph), source))
+}
+
 /// Return successors in a breadth-first-search from a source node.
 ///
 /// The return format is ``[(Parent Node, [Children Nodes])]`` in a bfs order
@@ -218,6 +356,176 @@ pub fn descendants(graph: &digraph::PyDiGraph, node: usize) -> HashSet<usize> {
     out_set
 }
 
+fn node_index<Ty: EdgeType>(graph: &StablePyGraph<Ty>, node: usize) -> PyResult<NodeIndex> {
+    let index = NodeIndex::new(node);
+    if !graph.contains_node(index) {
+        return Err(InvalidNode::new_err(
+            "The input index for 'node' is not a valid node index",
+        ));
+    }
+    Ok(index)
+}
+
+/// Return the ancestors of a node at a given distance in a graph.
+///
+/// The distance between two nodes is the number of edges on a shortest path
+/// between them, so this returns the nodes whose shortest path into ``node``
+/// has exactly ``distance`` edges. A breadth-first search along the incoming
+/// edges is used which stops at ``distance`` hops from ``node``.
+///
+/// :param PyDiGraph graph: The graph to get the ancestors from
+/// :param int node: The index of the graph node to get the ancestors for
+/// :param int distance: The number of hops into ``node`` of the returned
+///     ancestors
+///
+/// :returns: A set of node indices of the ancestors at exactly ``distance``
+///     hops from ``node``. For a ``distance`` of 0 this is only ``node``.
+/// :rtype: set
+///
+/// :raises InvalidNode: If ``node`` is not in the graph
+#[pyfunction]
+#[pyo3(text_signature = "(graph, node, distance, /)")]
+pub fn ancestors_at_distance(
+    graph: &digraph::PyDiGraph,
+    node: usize,
+    distance: usize,
+) -> PyResult<HashSet<usize>> {
+    let index = node_index(&graph.graph, node)?;
+    Ok(
+        descendants_at_distance(Reversed(&graph.graph), index, distance)
+            .iter()
+            .map(|n| n.index())
+            .collect(),
+    )
+}
+
+/// Return the nodes at a given distance from a node in a
+/// :class:`~retworkx.PyDiGraph`.
+///
+/// The distance between two nodes is the number of edges on a shortest path
+/// between them. A breadth-first search is used which stops at ``distance``
+/// hops from ``node``, so only the nodes within that distance are visited.
+/// Edges are followed in their direction, so the returned nodes are
+/// descendants of ``node``.
+///
+/// :param PyDiGraph graph: The graph to search
+/// :param int node: The index of the node to start from
+/// :param int distance: The number of hops from ``node`` of the returned
+///     nodes
+///
+/// :returns: A set of node indices of the nodes at exactly ``distance`` hops
+///     from ``node``. For a ``distance`` of 0 this is only ``node``.
+/// :rtype: set
+///
+/// :raises InvalidNode: If ``node`` is not in the graph
+#[pyfunction]
+#[pyo3(text_signature = "(graph, node, distance, /)")]
+pub fn digraph_descendants_at_distance(
+    graph: &digraph::PyDiGraph,
+    node: usize,
+    distance: usize,
+) -> PyResult<HashSet<usize>> {
+    let index = node_index(&graph.graph, node)?;
+    Ok(descendants_at_distance(&graph.graph, index, distance)
+        .iter()
+        .map(|n| n.index())
+        .collect())
+}
+
+/// Return the nodes at a given distance from a node in a
+/// :class:`~retworkx.PyGraph`.
+///
+/// The distance between two nodes is the number of edges on a shortest path
+/// between them. A breadth-first search is used which stops at ``distance``
+/// hops from ``node``, so only the nodes within that distance are visited.
+///
+/// :param PyGraph graph: The graph to search
+/// :param int node: The index of the node to start from
+/// :param int distance: The number of hops from ``node`` of the returned
+///     nodes
+///
+/// :returns: A set of node indices of the nodes at exactly ``distance`` hops
+///     from ``node``. For a ``distance`` of 0 this is only ``node``.
+/// :rtype: set
+///
+/// :raises InvalidNode: If ``node`` is not in the graph
+#[pyfunction]
+#[pyo3(text_signature = "(graph, node, distance, /)")]
+pub fn graph_descendants_at_distance(
+    graph: &graph::PyGraph,
+    node: usize,
+    distance: usize,
+) -> PyResult<HashSet<usize>> {
+    let index = node_index(&graph.graph, node)?;
+    Ok(descendants_at_distance(&graph.graph, index, distance)
+        .iter()
+        .map(|n| n.index())
+        .collect())
+}
+
+/// Return the nodes reachable from a node within a maximum number of hops
+/// in a :class:`~retworkx.PyDiGraph`.
+///
+/// This returns every node with a path from ``node`` of at most
+/// ``max_depth`` edges, including ``node`` itself. A breadth-first search is
+/// used which stops at ``max_depth`` hops from ``node``, so only the
+/// returned nodes are visited.
+/// Edges are followed in their direction.
+///
+/// :param PyDiGraph graph: The graph to search
+/// :param int node: The index of the node to start from
+/// :param int max_depth: The maximum number of hops from ``node``
+///
+/// :returns: A set of node indices of the nodes within ``max_depth`` hops
+///     of ``node``
+/// :rtype: set
+///
+/// :raises InvalidNode: If ``node`` is not in the graph
+#[pyfunction]
+#[pyo3(text_signature = "(graph, node, max_depth, /)")]
+pub fn digraph_reachable_within(
+    graph: &digraph::PyDiGraph,
+    node: usize,
+    max_depth: usize,
+) -> PyResult<HashSet<usize>> {
+    let index = node_index(&graph.graph, node)?;
+    Ok(reachable_within(&graph.graph, index, max_depth)
+        .iter()
+        .map(|n| n.index())
+        .collect())
+}
+
+/// Return the nodes reachable from a node within a maximum number of hops
+/// in a :class:`~retworkx.PyGraph`.
+///
+/// This returns every node with a path from ``node`` of at most
+/// ``max_depth`` edges, including ``node`` itself. A breadth-first search is
+/// used which stops at ``max_depth`` hops from ``node``, so only the
+/// returned nodes are visited.
+///
+/// :param PyGraph graph: The graph to search
+/// :param int node: The index of the node to start from
+/// :param int max_depth: The maximum number of hops from ``node``
+///
+/// :returns: A set of node indices of the nodes within ``max_depth`` hops
+///     of ``node``
+/// :rtype: set
+///
+/// :raises InvalidNode: If ``node`` is not in the graph
+#[pyfunction]
+#[pyo3(text_signature = "(graph, node, max_depth, /)")]
+pub fn graph_reachable_within(
+    graph: &graph::PyGraph,
+    node: usize,
+    max_depth: usize,
+) -> PyResult<HashSet<usize>> {
+    let index = node_index(&graph.graph, node)?;
+    Ok(reachable_within(&graph.graph, index, max_depth)
+        .iter()
+        .map(|n| n.index())
+        .collect())
+}
+
 /// Breadth-first traversal of a directed graph.
 ///
 /// The pseudo-code for the BFS algorithm is listed below, with the annotated
@@ -277,7 +585,8 @@ pub fn descendants(graph: &digraph::PyDiGraph, node: usize) -> HashSet<usize> {
 ///
 ///     Graph can **not** be mutated while traversing.
 ///
-/// :param PyDiGraph graph: The graph to be used.
+/// :param graph: The graph to be used, a :class:`~retworkx.PyDiGraph` or a
+///     :class:`~retworkx.DiGraphView`.
 /// :param List[int] source: An optional list of node indices to use as the starting nodes
 ///     for the breadth-first search. If this is not specified then a source
 ///     will be chosen arbitrarly and repeated until all components of the
@@ -288,18 +597,14 @@ pub fn descendants(graph: &digraph::PyDiGraph, node: usize) -> HashSet<usize> {
 #[pyo3(text_signature = "(graph, source, visitor)")]
 pub fn digraph_bfs_search(
     py: Python,
-    graph: &digraph::PyDiGraph,
+    graph: DiGraphOrView,
     source: Option<Vec<usize>>,
     visitor: PyBfsVisitor,
 ) -> PyResult<()> {
-    let starts: Vec<_> = match source {
-        Some(nx) => nx.into_iter().map(NodeIndex::new).collect(),
-        None => graph.graph.node_indices().collect(),
-    };
-
-    breadth_first_search(&graph.graph, starts, |event| {
+    let starts = graph.starts(py, source)?;
+    with_digraph_or_view!(py, graph, |g| breadth_first_search(g, starts, |event| {
         bfs_handler(py, &visitor, event)
-    })?;
+    }))?;
 
     Ok(())
 }
@@ -363,7 +668,8 @@ pub fn digraph_bfs_search(
 ///
 ///     Graph can **not** be mutated while traversing.
 ///
-/// :param PyGraph graph: The graph to be used.
+/// :param graph: The graph to be used, a :class:`~retworkx.PyGraph` or a
+///     :class:`~retworkx.GraphView`.
 /// :param List[int] source: An optional list of node indices to use as the starting nodes
 ///     for the breadth-first search. If this is not specified then a source
 ///     will be chosen arbitrarly and repeated until all components of the
@@ -374,18 +680,14 @@ pub fn digraph_bfs_search(
 #[pyo3(text_signature = "(graph, source, visitor)")]
 pub fn graph_bfs_search(
     py: Python,
-    graph: &graph::PyGraph,
+    graph: GraphOrView,
     source: Option<Vec<usize>>,
     visitor: PyBfsVisitor,
 ) -> PyResult<()> {
-    let starts: Vec<_> = match source {
-        Some(nx) => nx.into_iter().map(NodeIndex::new).collect(),
-        None => graph.graph.node_indices().collect(),
-    };
-
-    breadth_first_search(&graph.graph, starts, |event| {
+    let starts = graph.starts(py, source)?;
+    with_graph_or_view!(py, graph, |g| breadth_first_search(g, starts, |event| {
         bfs_handler(py, &visitor, event)
-    })?;
+    }))?;
 
     Ok(())
 }
@@ -447,7 +749,8 @@ pub fn graph_bfs_search(
 ///
 ///     Graph can *not* be mutated while traversing.
 ///
-/// :param PyDiGraph graph: The graph to be used.
+/// :param graph: The graph to be used, a :class:`~retworkx.PyDiGraph` or a
+///     :class:`~retworkx.DiGraphView`.
 /// :param List[int] source: An optional list of node indices to use as the starting nodes
 ///     for the depth-first search. If this is not specified then a source
 ///     will be chosen arbitrarly and repeated until all components of the
@@ -458,18 +761,14 @@ pub fn graph_bfs_search(
 #[pyo3(text_signature = "(graph, source, visitor)")]
 pub fn digraph_dfs_search(
     py: Python,
-    graph: &digraph::PyDiGraph,
+    graph: DiGraphOrView,
     source: Option<Vec<usize>>,
     visitor: PyDfsVisitor,
 ) -> PyResult<()> {
-    let starts: Vec<_> = match source {
-        Some(nx) => nx.into_iter().map(NodeIndex::new).collect(),
-        None => graph.graph.node_indices().collect(),
-    };
-
-    depth_first_search(&graph.graph, starts, |event| {
+    let starts = graph.starts(py, source)?;
+    with_digraph_or_view!(py, graph, |g| depth_first_search(g, starts, |event| {
         dfs_handler(py, &visitor, event)
-    })?;
+    }))?;
 
     Ok(())
 }
@@ -531,7 +830,8 @@ pub fn digraph_dfs_search(
 ///
 ///     Graph can *not* be mutated while traversing.
 ///
-/// :param PyGraph graph: The graph to be used.
+/// :param graph: The graph to be used, a :class:`~retworkx.PyGraph` or a
+///     :class:`~retworkx.GraphView`.
 /// :param List[int] source: An optional list of node indices to use as the starting nodes
 ///     for the depth-first search. If this is not specified then a source
 ///     will be chosen arbitrarly and repeated until all components of the
@@ -542,18 +842,14 @@ pub fn digraph_dfs_search(
 #[pyo3(text_signature = "(graph, source, visitor)")]
 pub fn graph_dfs_search(
     py: Python,
-    graph: &graph::PyGraph,
+    graph: GraphOrView,
     source: Option<Vec<usize>>,
     visitor: PyDfsVisitor,
 ) -> PyResult<()> {
-    let starts: Vec<_> = match source {
-        Some(nx) => nx.into_iter().map(NodeIndex::new).collect(),
-        None => graph.graph.node_indices().collect(),
-    };
-
-    depth_first_search(&graph.graph, starts, |event| {
+    let starts = graph.starts(py, source)?;
+    with_graph_or_view!(py, graph, |g| depth_first_search(g, starts, |event| {
         dfs_handler(py, &visitor, event)
-    })?;
+    }))?;
 
     Ok(())
 }
@@ -597,7 +893,8 @@ pub fn graph_dfs_search(
 ///
 ///     Graph can **not** be mutated while traversing.
 ///
-/// :param PyDiGraph graph: The graph to be used.
+/// :param graph: The graph to be used, a :class:`~retworkx.PyDiGraph` or a
+///     :class:`~retworkx.DiGraphView`.
 /// :param List[int] source: An optional list of node indices to use as the starting nodes
 ///     for the dijkstra search. If this is not specified then a source
 ///     will be chosen arbitrarly and repeated until all components of the
@@ -612,23 +909,19 @@ pub fn graph_dfs_search(
 #[pyo3(text_signature = "(graph, source, weight_fn, visitor)")]
 pub fn digraph_dijkstra_search(
     py: Python,
-    graph: &digraph::PyDiGraph,
+    graph: DiGraphOrView,
     source: Option<Vec<usize>>,
     weight_fn: Option<PyObject>,
     visitor: PyDijkstraVisitor,
 ) -> PyResult<()> {
-    let starts: Vec<_> = match source {
-        Some(nx) => nx.into_iter().map(NodeIndex::new).collect(),
-        None => graph.graph.node_indices().collect(),
-    };
-
+    let starts = graph.starts(py, source)?;
     let edge_cost_fn = CostFn::try_from((weight_fn, 1.0))?;
-    dijkstra_search(
-        &graph.graph,
+    with_digraph_or_view!(py, graph, |g| dijkstra_search(
+        g,
         starts,
         |e| edge_cost_fn.call(py, e.weight()),
         |event| dijkstra_handler(py, &visitor, event),
-    )??;
+    ))??;
 
     Ok(())
 }
@@ -672,7 +965,8 @@ pub fn digraph_dijkstra_search(
 ///
 ///     Graph can **not** be mutated while traversing.
 ///
-/// :param PyGraph graph: The graph to be used.
+/// :param graph: The graph to be used, a :class:`~retworkx.PyGraph` or a
+///     :class:`~retworkx.GraphView`.
 /// :param List[int] source: An optional list of node indices to use as the starting nodes
 ///     for the dijkstra search. If this is not specified then a source
 ///     will be chosen arbitrarly and repeated until all components of the
@@ -687,23 +981,19 @@ pub fn digraph_dijkstra_search(
 #[pyo3(text_signature = "(graph, source, weight_fn, visitor)")]
 pub fn graph_dijkstra_search(
     py: Python,
-    graph: &graph::PyGraph,
+    graph: GraphOrView,
     source: Option<Vec<usize>>,
     weight_fn: Option<PyObject>,
     visitor: PyDijkstraVisitor,
 ) -> PyResult<()> {
-    let starts: Vec<_> = match source {
-        Some(nx) => nx.into_iter().map(NodeIndex::new).collect(),
-        None => graph.graph.node_indices().collect(),
-    };
-
+    let starts = graph.starts(py, source)?;
     let edge_cost_fn = CostFn::try_from((weight_fn, 1.0))?;
-    dijkstra_search(
-        &graph.graph,
+    with_graph_or_view!(py, graph, |g| dijkstra_search(
+        g,
         starts,
         |e| edge_cost_fn.call(py, e.weight()),
         |event| dijkstra_handler(py, &visitor, event),
-    )??;
+    ))??;
 
     Ok(())
 }
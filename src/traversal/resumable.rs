@@ -0,0 +1,225 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use std::collections::VecDeque;
+
+use crate::{digraph, graph, StablePyGraph};
+
+use hashbrown::HashSet;
+
+use pyo3::class::iter::IterNextOutput;
+use pyo3::prelude::*;
+use pyo3::Python;
+
+use petgraph::graph::NodeIndex;
+use petgraph::visit::NodeIndexable;
+use petgraph::EdgeType;
+
+pub enum TraversalGraph {
+    Graph(Py<graph::PyGraph>),
+    DiGraph(Py<digraph::PyDiGraph>),
+}
+
+impl TraversalGraph {
+    fn clone_ref(&self, py: Python) -> Self {
+        match self {
+            TraversalGraph::Graph(graph) => TraversalGraph::Graph(graph.clone_ref(py)),
+            TraversalGraph::DiGraph(graph) => TraversalGraph::DiGraph(graph.clone_ref(py)),
+        }
+    }
+}
+
+/// The state of a traversal, which is all that's needed to resume it.
+///
+/// The nodes waiting to be visited are in `pending`, which is used as a
+/// stack by a depth-first traversal and as a queue by a breadth-first
+/// traversal. If the traversal has no source, `next_source` is the lowest
+/// node index which may start the traversal of the next component.
+#[derive(Clone)]
+struct TraversalState {
+    pending: VecDeque<NodeIndex>,
+    discovered: HashSet<NodeIndex>,
+    next_source: Option<usize>,
+}
+
+impl TraversalState {
+    fn new(source: Option<NodeIndex>, depth_first: bool) -> Self {
+        let mut state = TraversalState {
+            pending: VecDeque::new(),
+            discovered: HashSet::new(),
+            next_source: None,
+        };
+        match source {
+            Some(source) => {
+                if !depth_first {
+                    state.discovered.insert(source);
+                }
+                state.pending.push_back(source);
+            }
+            None => state.next_source = Some(0),
+        }
+        state
+    }
+
+    /// Find the lowest undiscovered node to start the traversal of the next
+    /// component from.
+    fn next_component<Ty: EdgeType>(&mut self, graph: &StablePyGraph<Ty>) -> Option<NodeIndex> {
+        let start = self.next_source?;
+        let source = (start..graph.node_bound())
+            .map(NodeIndex::new)
+            .find(|node| graph.contains_node(*node) && !self.discovered.contains(node));
+        self.next_source = Some(source.map_or(graph.node_bound(), |node| node.index() + 1));
+        source
+    }
+
+    fn next_dfs<Ty: EdgeType>(&mut self, graph: &StablePyGraph<Ty>) -> Option<NodeIndex> {
+        loop {
+            while let Some(node) = self.pending.pop_back() {
+                if graph.contains_node(node) && self.discovered.insert(node) {
+                    for succ in graph.neighbors(node) {
+                        if !self.discovered.contains(&succ) {
+                            self.pending.push_back(succ);
+                        }
+                    }
+                    return Some(node);
+                }
+            }
+            let source = self.next_component(graph)?;
+            self.pending.push_back(source);
+        }
+    }
+
+    fn next_bfs<Ty: EdgeType>(&mut self, graph: &StablePyGraph<Ty>) -> Option<NodeIndex> {
+        loop {
+            while let Some(node) = self.pending.pop_front() {
+                if graph.contains_node(node) {
+                    for succ in graph.neighbors(node) {
+                        if self.discovered.insert(succ) {
+                            self.pending.push_back(succ);
+                        }
+                    }
+                    return Some(node);
+                }
+            }
+            let source = self.next_component(graph)?;
+            self.discovered.insert(source);
+            self.pending.push_back(source);
+        }
+    }
+}
+
+macro_rules! traversal_iterator_impl {
+    ($name:ident, $next:ident, $depth_first:expr) => {
+        impl $name {
+            pub fn new(graph: TraversalGraph, source: Option<NodeIndex>) -> Self {
+                $name {
+                    graph,
+                    state: TraversalState::new(source, $depth_first),
+                }
+            }
+        }
+
+        #[pymethods]
+        impl $name {
+            fn __iter__(slf: PyRef<Self>) -> Py<$name> {
+                slf.into()
+            }
+
+            fn __next__(
+                mut slf: PyRefMut<Self>,
+                py: Python,
+            ) -> IterNextOutput<usize, &'static str> {
+                let graph = slf.graph.clone_ref(py);
+                let node = match &graph {
+                    TraversalGraph::Graph(graph) => slf.state.$next(&graph.borrow(py).graph),
+                    TraversalGraph::DiGraph(graph) => slf.state.$next(&graph.borrow(py).graph),
+                };
+                match node {
+                    Some(node) => IterNextOutput::Yield(node.index()),
+                    None => IterNextOutput::Return("Ended"),
+                }
+            }
+
+            /// Return a copy of the traversal at its current position
+            ///
+            /// The copy shares the graph but has its own state, so the
+            /// traversal and its copy can be advanced independently. This can
+            /// be used to save the state of the traversal and resume from it
+            /// later.
+            ///
+            /// :returns: A copy of the traversal
+            #[pyo3(text_signature = "(self)")]
+            fn copy(&self, py: Python) -> $name {
+                $name {
+                    graph: self.graph.clone_ref(py),
+                    state: self.state.clone(),
+                }
+            }
+
+            fn __copy__(&self, py: Python) -> $name {
+                self.copy(py)
+            }
+
+            /// The set of node indices discovered so far
+            #[getter]
+            fn discovered(&self) -> HashSet<usize> {
+                self.state
+                    .discovered
+                    .iter()
+                    .map(|node| node.index())
+                    .collect()
+            }
+        }
+    };
+}
+
+/// A resumable depth-first traversal of a graph, returned by
+/// :func:`~retworkx.dfs_traversal`.
+///
+/// Iterating over it returns the node indices of the graph in depth-first
+/// order, one at a time. It holds the state of the traversal, the stack of
+/// nodes to visit and the set of nodes already discovered, so the traversal
+/// can be paused at any node and resumed later by iterating again. Its
+/// state can be saved with :meth:`copy`, which returns an independent
+/// traversal at the same position.
+///
+/// The underlying graph should not be mutated while iterating, the
+/// traversal may pick up the modifications but it can result in a
+/// logical-error.
+#[pyclass(module = "retworkx")]
+pub struct DFSTraversal {
+    graph: TraversalGraph,
+    state: TraversalState,
+}
+
+traversal_iterator_impl!(DFSTraversal, next_dfs, true);
+
+/// A resumable breadth-first traversal of a graph, returned by
+/// :func:`~retworkx.bfs_traversal`.
+///
+/// Iterating over it returns the node indices of the graph in breadth-first
+/// order, one at a time. It holds the state of the traversal, the queue of
+/// nodes to visit and the set of nodes already discovered, so the traversal
+/// can be paused at any node and resumed later by iterating again. Its
+/// state can be saved with :meth:`copy`, which returns an independent
+/// traversal at the same position.
+///
+/// The underlying graph should not be mutated while iterating, the
+/// traversal may pick up the modifications but it can result in a
+/// logical-error.
+#[pyclass(module = "retworkx")]
+pub struct BFSTraversal {
+    graph: TraversalGraph,
+    state: TraversalState,
+}
+
+traversal_iterator_impl!(BFSTraversal, next_bfs, false);
@@ -10,20 +10,15 @@
 // License for the specific language governing permissions and limitations
 // under the License.
 
-use std::cmp::Ordering;
-
-use super::{graph, weight_callable};
+use super::{edge_weights, graph, weight_callable};
 
 use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
 use pyo3::Python;
 
 use petgraph::prelude::*;
-use petgraph::stable_graph::EdgeReference;
-use petgraph::unionfind::UnionFind;
-use petgraph::visit::{IntoEdgeReferences, NodeIndexable};
 
-use rayon::prelude::*;
+use retworkx_core::tree::minimum_spanning_edges as core_minimum_spanning_edges;
 
 use crate::iterators::WeightedEdgeList;
 
@@ -45,46 +40,56 @@ use crate::iterators::WeightedEdgeList;
 ///     to cast the edge object as a float as the weight.
 /// :param float default_weight: If ``weight_fn`` isn't specified this optional
 ///     float value will be used for the weight/cost of each edge.
+/// :param str weight_attr: An optional name of an edge attribute with the
+///     weight of the edges, as an alternative to ``weight_fn`` which doesn't
+///     call into Python for every edge. The weights are read from the edge
+///     attribute column with this name if the graph has one, see
+///     :meth:`~retworkx.PyGraph.set_edge_attribute`, otherwise from this key
+///     of the edges with a dict payload. Edges without a weight use
+///     ``default_weight``.
 ///
 /// :returns: The :math:`N - |c|` edges of the Minimum Spanning Tree (or Forest, if :math:`|c| > 1`)
 ///     where :math:`N` is the number of nodes and :math:`|c|` is the number of connected components of the graph
 /// :rtype: WeightedEdgeList
+/// :raises ValueError: If both ``weight_fn`` and ``weight_attr`` are
+///     specified
 #[pyfunction(weight_fn = "None", default_weight = "1.0")]
-#[pyo3(text_signature = "(graph, weight_fn=None, default_weight=1.0)")]
+#[pyo3(text_signature = "(graph, weight_fn=None, default_weight=1.0, weight_attr=None)")]
 pub fn minimum_spanning_edges(
     py: Python,
     graph: &graph::PyGraph,
     weight_fn: Option<PyObject>,
     default_weight: f64,
+    weight_attr: Option<&str>,
 ) -> PyResult<WeightedEdgeList> {
-    let mut subgraphs = UnionFind::<usize>::new(graph.graph.node_bound());
-
-    let mut edge_list: Vec<(f64, EdgeReference<PyObject>)> =
-        Vec::with_capacity(graph.graph.edge_count());
-    for edge in graph.graph.edge_references() {
-        let weight = weight_callable(py, &weight_fn, edge.weight(), default_weight)?;
+    let weights = edge_weights(
+        py,
+        &graph.graph,
+        &graph.attributes,
+        &weight_fn,
+        weight_attr,
+        default_weight,
+    )?;
+    let edges = core_minimum_spanning_edges(&graph.graph, |edge| -> PyResult<f64> {
+        let weight = match &weights {
+            Some(weights) => weights[edge.id().index()],
+            None => weight_callable(py, &weight_fn, edge.weight(), default_weight)?,
+        };
         if weight.is_nan() {
             return Err(PyValueError::new_err("NaN found as an edge weight"));
         }
-        edge_list.push((weight, edge));
-    }
-
-    edge_list.par_sort_unstable_by(|a, b| {
-        let weight_a = a.0;
-        let weight_b = b.0;
-        weight_a.partial_cmp(&weight_b).unwrap_or(Ordering::Less)
-    });
-
-    let mut answer: Vec<(usize, usize, PyObject)> = Vec::new();
-    for float_edge_pair in edge_list.iter() {
-        let edge = float_edge_pair.1;
-        let u = edge.source().index();
-        let v = edge.target().index();
-        if subgraphs.union(u, v) {
-            let w = edge.weight().clone_ref(py);
-            answer.push((u, v, w));
-        }
-    }
+        Ok(weight)
+    })?;
+    let answer: Vec<(usize, usize, PyObject)> = edges
+        .into_iter()
+        .map(|edge| {
+            (
+                edge.source().index(),
+                edge.target().index(),
+                edge.weight().clone_ref(py),
+            )
+        })
+        .collect();
 
     Ok(WeightedEdgeList { edges: answer })
 }
@@ -107,26 +112,37 @@ pub fn minimum_spanning_edges(
 ///     to cast the edge object as a float as the weight.
 /// :param float default_weight: If ``weight_fn`` isn't specified this optional
 ///     float value will be used for the weight/cost of each edge.
+/// :param str weight_attr: An optional name of an edge attribute with the
+///     weight of the edges, as an alternative to ``weight_fn`` which doesn't
+///     call into Python for every edge. The weights are read from the edge
+///     attribute column with this name if the graph has one, see
+///     :meth:`~retworkx.PyGraph.set_edge_attribute`, otherwise from this key
+///     of the edges with a dict payload. Edges without a weight use
+///     ``default_weight``.
 ///
 /// :returns: A Minimum Spanning Tree (or Forest, if the graph is not connected).
 ///
 /// :rtype: PyGraph
+/// :raises ValueError: If both ``weight_fn`` and ``weight_attr`` are
+///     specified
 ///
 /// .. note::
 ///
 ///     The new graph will keep the same node indices, but edge indices might differ.
 #[pyfunction(weight_fn = "None", default_weight = "1.0")]
-#[pyo3(text_signature = "(graph, weight_fn=None, default_weight=1.0)")]
+#[pyo3(text_signature = "(graph, weight_fn=None, default_weight=1.0, weight_attr=None)")]
 pub fn minimum_spanning_tree(
     py: Python,
     graph: &graph::PyGraph,
     weight_fn: Option<PyObject>,
     default_weight: f64,
+    weight_attr: Option<&str>,
 ) -> PyResult<graph::PyGraph> {
     let mut spanning_tree = (*graph).clone();
     spanning_tree.graph.clear_edges();
+    spanning_tree.attributes.prune(&spanning_tree.graph);
 
-    for edge in minimum_spanning_edges(py, graph, weight_fn, default_weight)?
+    for edge in minimum_spanning_edges(py, graph, weight_fn, default_weight, weight_attr)?
         .edges
         .iter()
     {
@@ -0,0 +1,158 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use std::convert::TryFrom;
+
+use crate::iterators::NodeIndices;
+use crate::{graph, CostFn, InvalidNode};
+
+use retworkx_core::tsp;
+
+use petgraph::prelude::*;
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::Python;
+
+/// A tour built by :func:`~retworkx.greedy_tsp` or
+/// :func:`~retworkx.christofides`
+///
+/// :ivar NodeIndices nodes: The node indices in the order they're visited,
+///     every node of the graph once. The tour returns from the last node to
+///     the first one.
+/// :ivar float cost: The total length of the shortest paths between
+///     consecutive nodes of the tour, including the path from the last node
+///     back to the first one
+#[pyclass(module = "retworkx")]
+pub struct SalespersonTour {
+    #[pyo3(get)]
+    pub nodes: NodeIndices,
+    #[pyo3(get)]
+    pub cost: f64,
+}
+
+/// The Python tour of a tour of the core crate, or an error if the graph
+/// isn't connected.
+fn tour_or_err(tour: Option<tsp::Tour<NodeIndex>>) -> PyResult<SalespersonTour> {
+    match tour {
+        Some(tour) => Ok(SalespersonTour {
+            nodes: NodeIndices {
+                nodes: tour.nodes.iter().map(|node| node.index()).collect(),
+            },
+            cost: tour.cost,
+        }),
+        None => Err(PyValueError::new_err(
+            "The graph is not connected, no tour visits every node",
+        )),
+    }
+}
+
+/// Find a traveling salesperson tour of a PyGraph with the nearest
+/// neighbor heuristic
+///
+/// The tour visits every node of the graph once and returns to the first
+/// one. The cost of going from a node to another is the length of the
+/// shortest path between them, so the graph doesn't need to be complete:
+/// the tour is found in its metric closure.
+///
+/// The tour starts at ``source`` and repeatedly goes to the closest node it
+/// hasn't visited yet. Nodes at the same distance are visited in the order
+/// of their indices. This is a construction heuristic: the tour is good but
+/// not optimal, it can be :math:`O(\log n)` times longer than the shortest
+/// tour for ``n`` nodes.
+///
+/// :param PyGraph graph: The graph to find the tour in
+/// :param int source: The node index the tour starts at. If it's not
+///     specified the tour starts at the node with the lowest index.
+/// :param weight_fn: An optional callable that will be passed the edge's
+///     payload object and is expected to return a non-negative ``float``
+///     cost for that edge.
+/// :param float default_weight: If ``weight_fn`` isn't specified this
+///     optional float value will be used for the cost of every edge.
+///     Defaults to 1.0.
+///
+/// :returns: The tour
+/// :rtype: SalespersonTour
+/// :raises InvalidNode: If ``source`` is not in the graph
+/// :raises ValueError: If the graph is not connected
+#[pyfunction(default_weight = "1.0")]
+#[pyo3(text_signature = "(graph, /, source=None, weight_fn=None, default_weight=1.0)")]
+pub fn greedy_tsp(
+    py: Python,
+    graph: &graph::PyGraph,
+    source: Option<usize>,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+) -> PyResult<SalespersonTour> {
+    let source = match source {
+        Some(source) => {
+            let source = NodeIndex::new(source);
+            if !graph.graph.contains_node(source) {
+                return Err(InvalidNode::new_err(format!(
+                    "Node index {} is not in the graph",
+                    source.index()
+                )));
+            }
+            Some(source)
+        }
+        None => None,
+    };
+    let cost_fn = CostFn::try_from((weight_fn, default_weight))?;
+    let tour = tsp::greedy_tsp(&graph.graph, source, |edge| cost_fn.call(py, edge.weight()))?;
+    tour_or_err(tour)
+}
+
+/// Find a traveling salesperson tour of a PyGraph with the Christofides
+/// algorithm
+///
+/// The tour visits every node of the graph once and returns to the first
+/// one. The cost of going from a node to another is the length of the
+/// shortest path between them, so the graph doesn't need to be complete:
+/// the tour is found in its metric closure.
+///
+/// A minimum spanning tree of the nodes is joined with a minimum weight
+/// perfect matching of the nodes with an odd degree in the tree, computed
+/// with the same algorithm as :func:`~retworkx.max_weight_matching`, and the
+/// tour visits the nodes in the order of an Eulerian circuit of the result,
+/// skipping the nodes it already visited [Christofides1976]_. The tour is
+/// at most 1.5 times longer than the shortest tour. This function takes
+/// :math:`O(n^3)` time for ``n`` nodes.
+///
+/// :param PyGraph graph: The graph to find the tour in
+/// :param weight_fn: An optional callable that will be passed the edge's
+///     payload object and is expected to return a non-negative ``float``
+///     cost for that edge.
+/// :param float default_weight: If ``weight_fn`` isn't specified this
+///     optional float value will be used for the cost of every edge.
+///     Defaults to 1.0.
+///
+/// :returns: The tour, starting at the node with the lowest index
+/// :rtype: SalespersonTour
+/// :raises ValueError: If the graph is not connected
+///
+/// .. [Christofides1976] Christofides, N.
+///    "Worst-case analysis of a new heuristic for the travelling salesman
+///    problem" Report 388, Graduate School of Industrial Administration,
+///    CMU (1976). Reprinted in Operations Research Forum 3 (1), 20 (2022).
+///    https://doi.org/10.1007/s43069-021-00101-z
+#[pyfunction(default_weight = "1.0")]
+#[pyo3(text_signature = "(graph, /, weight_fn=None, default_weight=1.0)")]
+pub fn christofides(
+    py: Python,
+    graph: &graph::PyGraph,
+    weight_fn: Option<PyObject>,
+    default_weight: f64,
+) -> PyResult<SalespersonTour> {
+    let cost_fn = CostFn::try_from((weight_fn, default_weight))?;
+    let tour = tsp::christofides(&graph.graph, |edge| cost_fn.call(py, edge.weight()))?;
+    tour_or_err(tour)
+}
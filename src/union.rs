@@ -10,11 +10,13 @@
 // License for the specific language governing permissions and limitations
 // under the License.
 
+use crate::attributes::AttributeColumns;
+use crate::node_keys::NodeKeys;
 use crate::{digraph, find_node_by_weight, graph, StablePyGraph};
 
 use petgraph::stable_graph::NodeIndex;
 use petgraph::visit::{EdgeRef, IntoEdgeReferences, NodeIndexable};
-use petgraph::{algo, EdgeType};
+use petgraph::EdgeType;
 
 use pyo3::prelude::*;
 use pyo3::Python;
@@ -139,6 +141,9 @@ pub fn graph_union(
         graph: out_graph,
         node_removed: first.node_removed,
         multigraph: true,
+        node_keys: NodeKeys::default(),
+        attributes: AttributeColumns::default(),
+        generation: 0,
     })
 }
 
@@ -185,9 +190,12 @@ pub fn digraph_union(
 
     Ok(digraph::PyDiGraph {
         graph: out_graph,
-        cycle_state: algo::DfsSpace::default(),
+        cycle_state: None,
         check_cycle: false,
         node_removed: first.node_removed,
         multigraph: true,
+        node_keys: NodeKeys::default(),
+        attributes: AttributeColumns::default(),
+        generation: 0,
     })
 }
@@ -0,0 +1,840 @@
+// Licensed under the Apache License, Version 2.0 (the "License"); you may
+// not use this file except in compliance with the License. You may obtain
+// a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+
+use crate::attributes::AttributeColumns;
+use crate::digraph::PyDiGraph;
+use crate::graph::PyGraph;
+use crate::iterators::{EdgeIndices, EdgeList, NodeIndices, WeightedEdgeList};
+use crate::node_keys::NodeKeys;
+use crate::{InvalidNode, StablePyGraph};
+
+use fixedbitset::FixedBitSet;
+use hashbrown::{HashMap, HashSet};
+
+use pyo3::exceptions::PyIndexError;
+use pyo3::prelude::*;
+
+use petgraph::graph::{EdgeIndex, NodeIndex};
+use petgraph::visit::{
+    Data, EdgeIndexable, EdgeRef, FilterEdge, FilterNode, IntoEdgeReferences, IntoEdges,
+    IntoEdgesDirected, IntoNeighborsDirected, IntoNodeIdentifiers, NodeIndexable,
+};
+use petgraph::{Directed, EdgeType, Incoming, Outgoing, Undirected};
+
+/// A filter of the nodes or edges of a view by index, which passes every
+/// node or edge if there's no set of indices.
+#[derive(Clone, Copy)]
+pub struct IndexFilter<'a>(pub Option<&'a FixedBitSet>);
+
+impl<'a> FilterNode<NodeIndex> for IndexFilter<'a> {
+    fn include_node(&self, node: NodeIndex) -> bool {
+        match self.0 {
+            Some(indices) => indices.contains(node.index()),
+            None => true,
+        }
+    }
+}
+
+impl<'a, E: EdgeRef<EdgeId = EdgeIndex>> FilterEdge<E> for IndexFilter<'a> {
+    fn include_edge(&self, edge: E) -> bool {
+        match self.0 {
+            Some(indices) => indices.contains(edge.id().index()),
+            None => true,
+        }
+    }
+}
+
+/// The graph a view is of.
+pub enum ViewBase {
+    Graph(Py<PyGraph>),
+    DiGraph(Py<PyDiGraph>),
+}
+
+impl ViewBase {
+    fn clone_ref(&self, py: Python) -> Self {
+        match self {
+            ViewBase::Graph(graph) => ViewBase::Graph(graph.clone_ref(py)),
+            ViewBase::DiGraph(graph) => ViewBase::DiGraph(graph.clone_ref(py)),
+        }
+    }
+}
+
+/// Evaluate `body` with `$g` bound to the retworkx-core view of a
+/// [`GraphView`], which is a different type for every kind of graph the
+/// view can be of.
+macro_rules! graph_view {
+    ($py:expr, $view:expr, |$g:ident| $body:expr) => {{
+        let view: &$crate::views::GraphView = $view;
+        let nodes = $crate::views::IndexFilter(view.nodes.as_ref());
+        let edges = $crate::views::IndexFilter(view.edges.as_ref());
+        match &view.base {
+            $crate::views::ViewBase::Graph(graph) => {
+                let graph = graph.borrow($py);
+                let $g = retworkx_core::views::subgraph_view(&graph.graph, nodes, edges);
+                $body
+            }
+            $crate::views::ViewBase::DiGraph(graph) => {
+                let graph = graph.borrow($py);
+                let $g = retworkx_core::views::subgraph_view(
+                    retworkx_core::views::as_undirected(&graph.graph),
+                    nodes,
+                    edges,
+                );
+                $body
+            }
+        }
+    }};
+}
+
+/// Evaluate `body` with `$g` bound to the retworkx-core view of a
+/// [`DiGraphView`].
+macro_rules! digraph_view {
+    ($py:expr, $view:expr, |$g:ident| $body:expr) => {{
+        let view: &$crate::views::DiGraphView = $view;
+        let nodes = $crate::views::IndexFilter(view.nodes.as_ref());
+        let edges = $crate::views::IndexFilter(view.edges.as_ref());
+        let graph = view.graph.borrow($py);
+        if view.reversed {
+            let $g = retworkx_core::views::subgraph_view(
+                petgraph::visit::Reversed(&graph.graph),
+                nodes,
+                edges,
+            );
+            $body
+        } else {
+            let $g = retworkx_core::views::subgraph_view(&graph.graph, nodes, edges);
+            $body
+        }
+    }};
+}
+
+/// Evaluate `body` with `$g` bound to the graph or view passed to a
+/// function for undirected graphs.
+macro_rules! with_graph_or_view {
+    ($py:expr, $input:expr, |$g:ident| $body:expr) => {
+        match $input {
+            $crate::views::GraphOrView::Graph(graph) => {
+                let $g = &graph.graph;
+                $body
+            }
+            $crate::views::GraphOrView::View(view) => {
+                $crate::views::graph_view!($py, &view, |$g| $body)
+            }
+        }
+    };
+}
+
+/// Evaluate `body` with `$g` bound to the graph or view passed to a
+/// function for directed graphs.
+macro_rules! with_digraph_or_view {
+    ($py:expr, $input:expr, |$g:ident| $body:expr) => {
+        match $input {
+            $crate::views::DiGraphOrView::DiGraph(graph) => {
+                let $g = &graph.graph;
+                $body
+            }
+            $crate::views::DiGraphOrView::View(view) => {
+                $crate::views::digraph_view!($py, &view, |$g| $body)
+            }
+        }
+    };
+}
+
+pub(crate) use {digraph_view, graph_view, with_digraph_or_view, with_graph_or_view};
+
+/// An undirected graph or a view of one, accepted by the functions for
+/// undirected graphs which can run on views.
+#[derive(FromPyObject)]
+pub enum GraphOrView<'a> {
+    Graph(PyRef<'a, PyGraph>),
+    View(PyRef<'a, GraphView>),
+}
+
+/// A directed graph or a view of one, accepted by the functions for
+/// directed graphs which can run on views.
+#[derive(FromPyObject)]
+pub enum DiGraphOrView<'a> {
+    DiGraph(PyRef<'a, PyDiGraph>),
+    View(PyRef<'a, DiGraphView>),
+}
+
+impl<'a> GraphOrView<'a> {
+    /// Return the nodes to start a traversal from, `source` if it's
+    /// specified, or else every node of the graph or view.
+    pub fn starts(&self, py: Python, source: Option<Vec<usize>>) -> PyResult<Vec<NodeIndex>> {
+        let nodes = match self {
+            GraphOrView::Graph(graph) => graph.node_indices(),
+            GraphOrView::View(view) => view.node_indices(py),
+        };
+        traversal_starts(nodes, source)
+    }
+}
+
+impl<'a> DiGraphOrView<'a> {
+    /// Return the nodes to start a traversal from, `source` if it's
+    /// specified, or else every node of the graph or view.
+    pub fn starts(&self, py: Python, source: Option<Vec<usize>>) -> PyResult<Vec<NodeIndex>> {
+        let nodes = match self {
+            DiGraphOrView::DiGraph(graph) => graph.node_indices(),
+            DiGraphOrView::View(view) => view.node_indices(py),
+        };
+        traversal_starts(nodes, source)
+    }
+}
+
+fn traversal_starts(nodes: NodeIndices, source: Option<Vec<usize>>) -> PyResult<Vec<NodeIndex>> {
+    match source {
+        Some(source) => {
+            let nodes: HashSet<usize> = nodes.nodes.into_iter().collect();
+            source
+                .into_iter()
+                .map(|node| {
+                    if nodes.contains(&node) {
+                        Ok(NodeIndex::new(node))
+                    } else {
+                        Err(InvalidNode::new_err(format!(
+                            "Node index {} is not in the graph",
+                            node
+                        )))
+                    }
+                })
+                .collect()
+        }
+        None => Ok(nodes.nodes.into_iter().map(NodeIndex::new).collect()),
+    }
+}
+
+/// Narrow down the optional set of `bound` indices in `current` to the
+/// ones in `indices` and the ones whose weight passes `filter`.
+fn narrow<'a, W>(
+    py: Python,
+    current: Option<&FixedBitSet>,
+    bound: usize,
+    weights: W,
+    indices: Option<Vec<usize>>,
+    filter: Option<PyObject>,
+) -> PyResult<Option<FixedBitSet>>
+where
+    W: Iterator<Item = (usize, &'a PyObject)>,
+{
+    if current.is_none() && indices.is_none() && filter.is_none() {
+        return Ok(None);
+    }
+    let mut out = FixedBitSet::with_capacity(bound);
+    let selected: Option<HashSet<usize>> = indices.map(|indices| indices.into_iter().collect());
+    for (index, weight) in weights {
+        if let Some(current) = current {
+            if !current.contains(index) {
+                continue;
+            }
+        }
+        if let Some(selected) = &selected {
+            if !selected.contains(&index) {
+                continue;
+            }
+        }
+        if let Some(filter) = &filter {
+            if !filter.call1(py, (weight,))?.extract::<bool>(py)? {
+                continue;
+            }
+        }
+        out.insert(index);
+    }
+    Ok(Some(out))
+}
+
+/// The sets of node and edge indices of a view of `graph` narrowed down
+/// from the `current` ones by the arguments of a ``subgraph_view()`` call.
+pub fn view_filters<Ty: EdgeType>(
+    py: Python,
+    graph: &StablePyGraph<Ty>,
+    current: (Option<&FixedBitSet>, Option<&FixedBitSet>),
+    nodes: Option<Vec<usize>>,
+    node_filter: Option<PyObject>,
+    edge_filter: Option<PyObject>,
+) -> PyResult<(Option<FixedBitSet>, Option<FixedBitSet>)> {
+    let node_weights = graph
+        .node_indices()
+        .map(|node| (node.index(), &graph[node]));
+    let edge_weights = graph
+        .edge_references()
+        .map(|edge| (edge.id().index(), edge.weight()));
+    Ok((
+        narrow(
+            py,
+            current.0,
+            graph.node_bound(),
+            node_weights,
+            nodes,
+            node_filter,
+        )?,
+        narrow(
+            py,
+            current.1,
+            graph.edge_bound(),
+            edge_weights,
+            None,
+            edge_filter,
+        )?,
+    ))
+}
+
+/// Copy the nodes and edges of a view into a new graph, with the nodes
+/// in the order of their indices in the view.
+fn copy_view<G, Ty, F>(py: Python, view: G, node_weight: F) -> StablePyGraph<Ty>
+where
+    G: IntoNodeIdentifiers<NodeId = NodeIndex> + IntoEdgeReferences,
+    G: Data<EdgeWeight = PyObject>,
+    Ty: EdgeType,
+    F: Fn(NodeIndex) -> PyObject,
+{
+    let mut out_graph = StablePyGraph::<Ty>::default();
+    let mut node_map: HashMap<NodeIndex, NodeIndex> = HashMap::new();
+    for node in view.node_identifiers() {
+        node_map.insert(node, out_graph.add_node(node_weight(node)));
+    }
+    for edge in view.edge_references() {
+        out_graph.add_edge(
+            node_map[&edge.source()],
+            node_map[&edge.target()],
+            edge.weight().clone_ref(py),
+        );
+    }
+    out_graph
+}
+
+/// A view of an undirected graph, or of a directed graph as an undirected
+/// graph, without copying it.
+///
+/// A view has a subset of the nodes and edges of the graph, with the same
+/// indices, and the functions which accept a view, such as
+/// :func:`~retworkx.bfs_search`, :func:`~retworkx.dfs_search` and
+/// :func:`~retworkx.dijkstra_search`, run on it like on a graph with only
+/// those nodes and edges. Create it with :meth:`.PyGraph.subgraph_view` or
+/// :meth:`.PyDiGraph.undirected_view`, and copy it into a
+/// :class:`~retworkx.PyGraph` with :meth:`.to_graph`.
+///
+/// The node and edge filters of the view are evaluated when it's created,
+/// so nodes and edges added to the graph afterwards are only in the view if
+/// it has no filter. The graph can't be mutated while a function runs on
+/// the view.
+#[pyclass(module = "retworkx")]
+pub struct GraphView {
+    pub base: ViewBase,
+    pub nodes: Option<FixedBitSet>,
+    pub edges: Option<FixedBitSet>,
+}
+
+impl GraphView {
+    fn node_weight(&self, py: Python, node: usize) -> Option<PyObject> {
+        if !IndexFilter(self.nodes.as_ref()).include_node(NodeIndex::new(node)) {
+            return None;
+        }
+        match &self.base {
+            ViewBase::Graph(graph) => graph
+                .borrow(py)
+                .graph
+                .node_weight(NodeIndex::new(node))
+                .map(|weight| weight.clone_ref(py)),
+            ViewBase::DiGraph(graph) => graph
+                .borrow(py)
+                .graph
+                .node_weight(NodeIndex::new(node))
+                .map(|weight| weight.clone_ref(py)),
+        }
+    }
+}
+
+#[pymethods]
+impl GraphView {
+    /// The graph this is a view of.
+    #[getter]
+    fn graph(&self, py: Python) -> PyObject {
+        match &self.base {
+            ViewBase::Graph(graph) => graph.clone_ref(py).into_py(py),
+            ViewBase::DiGraph(graph) => graph.clone_ref(py).into_py(py),
+        }
+    }
+
+    /// Return a view of a subgraph of this view.
+    ///
+    /// The arguments are as in :meth:`.PyGraph.subgraph_view`.
+    ///
+    /// :returns: A view with the nodes and edges of this view which pass the
+    ///     filters
+    /// :rtype: GraphView
+    #[pyo3(text_signature = "(self, /, nodes=None, node_filter=None, edge_filter=None)")]
+    fn subgraph_view(
+        &self,
+        py: Python,
+        nodes: Option<Vec<usize>>,
+        node_filter: Option<PyObject>,
+        edge_filter: Option<PyObject>,
+    ) -> PyResult<GraphView> {
+        let current = (self.nodes.as_ref(), self.edges.as_ref());
+        let (nodes, edges) = match &self.base {
+            ViewBase::Graph(graph) => {
+                let graph = &graph.borrow(py).graph;
+                view_filters(py, graph, current, nodes, node_filter, edge_filter)?
+            }
+            ViewBase::DiGraph(graph) => {
+                let graph = &graph.borrow(py).graph;
+                view_filters(py, graph, current, nodes, node_filter, edge_filter)?
+            }
+        };
+        Ok(GraphView {
+            base: self.base.clone_ref(py),
+            nodes,
+            edges,
+        })
+    }
+
+    /// Return the number of nodes in the view
+    #[pyo3(text_signature = "(self)")]
+    fn num_nodes(&self, py: Python) -> usize {
+        graph_view!(py, self, |g| g.node_identifiers().count())
+    }
+
+    /// Return the number of edges in the view
+    #[pyo3(text_signature = "(self)")]
+    fn num_edges(&self, py: Python) -> usize {
+        graph_view!(py, self, |g| g.edge_references().count())
+    }
+
+    /// Return a list of all node indices in the view.
+    ///
+    /// :rtype: NodeIndices
+    #[pyo3(text_signature = "(self)")]
+    pub fn node_indices(&self, py: Python) -> NodeIndices {
+        NodeIndices {
+            nodes: graph_view!(py, self, |g| g
+                .node_identifiers()
+                .map(|node| node.index())
+                .collect()),
+        }
+    }
+
+    /// Return a list of all edge indices in the view.
+    ///
+    /// :rtype: EdgeIndices
+    #[pyo3(text_signature = "(self)")]
+    fn edge_indices(&self, py: Python) -> EdgeIndices {
+        EdgeIndices {
+            edges: graph_view!(py, self, |g| g
+                .edge_references()
+                .map(|edge| edge.id().index())
+                .collect()),
+        }
+    }
+
+    /// Return a list of the edges in the view as tuples of the form
+    /// ``(source, target)``.
+    ///
+    /// :rtype: EdgeList
+    #[pyo3(text_signature = "(self)")]
+    fn edge_list(&self, py: Python) -> EdgeList {
+        EdgeList {
+            edges: graph_view!(py, self, |g| g
+                .edge_references()
+                .map(|edge| (edge.source().index(), edge.target().index()))
+                .collect()),
+        }
+    }
+
+    /// Return a list of the edges in the view as tuples of the form
+    /// ``(source, target, weight)``.
+    ///
+    /// :rtype: WeightedEdgeList
+    #[pyo3(text_signature = "(self)")]
+    fn weighted_edge_list(&self, py: Python) -> WeightedEdgeList {
+        WeightedEdgeList {
+            edges: graph_view!(py, self, |g| g
+                .edge_references()
+                .map(|edge| {
+                    (
+                        edge.source().index(),
+                        edge.target().index(),
+                        edge.weight().clone_ref(py),
+                    )
+                })
+                .collect()),
+        }
+    }
+
+    /// Return the indices of the neighbors of a node in the view.
+    ///
+    /// :param int node: The index of the node
+    ///
+    /// :rtype: NodeIndices
+    #[pyo3(text_signature = "(self, node, /)")]
+    fn neighbors(&self, py: Python, node: usize) -> NodeIndices {
+        let neighbors: HashSet<usize> = graph_view!(py, self, |g| g
+            .edges(NodeIndex::new(node))
+            .map(|edge| edge.target().index())
+            .collect());
+        NodeIndices {
+            nodes: neighbors.into_iter().collect(),
+        }
+    }
+
+    /// Return the degree of a node in the view, which counts self loops
+    /// twice.
+    ///
+    /// :param int node: The index of the node
+    ///
+    /// :rtype: int
+    #[pyo3(text_signature = "(self, node, /)")]
+    fn degree(&self, py: Python, node: usize) -> usize {
+        graph_view!(py, self, |g| g.edges(NodeIndex::new(node)).fold(
+            0,
+            |count, edge| {
+                if edge.source() == edge.target() {
+                    count + 2
+                } else {
+                    count + 1
+                }
+            }
+        ))
+    }
+
+    /// Return True if there is an edge between ``node_a`` and ``node_b`` in
+    /// the view.
+    ///
+    /// :param int node_a: The index of the first node
+    /// :param int node_b: The index of the second node
+    ///
+    /// :rtype: bool
+    #[pyo3(text_signature = "(self, node_a, node_b, /)")]
+    fn has_edge(&self, py: Python, node_a: usize, node_b: usize) -> bool {
+        graph_view!(py, self, |g| g
+            .edges(NodeIndex::new(node_a))
+            .any(|edge| edge.target().index() == node_b))
+    }
+
+    /// Copy the nodes and edges of the view into a new graph.
+    ///
+    /// The nodes are added to the new graph in the order of their indices
+    /// in the view, so they are reindexed if the view doesn't have all the
+    /// nodes of the graph. The node and edge data payloads are shared with
+    /// the graph.
+    ///
+    /// :rtype: PyGraph
+    #[pyo3(text_signature = "(self)")]
+    fn to_graph(&self, py: Python) -> PyGraph {
+        let graph: StablePyGraph<Undirected> =
+            graph_view!(py, self, |g| copy_view(py, g, |node| {
+                self.node_weight(py, node.index()).unwrap()
+            }));
+        let multigraph = match &self.base {
+            ViewBase::Graph(graph) => graph.borrow(py).multigraph,
+            ViewBase::DiGraph(graph) => graph.borrow(py).multigraph,
+        };
+        PyGraph {
+            graph,
+            node_removed: false,
+            multigraph,
+            node_keys: NodeKeys::default(),
+            attributes: AttributeColumns::default(),
+            generation: 0,
+        }
+    }
+
+    fn __len__(&self, py: Python) -> usize {
+        self.num_nodes(py)
+    }
+
+    fn __getitem__(&self, py: Python, idx: usize) -> PyResult<PyObject> {
+        self.node_weight(py, idx)
+            .ok_or_else(|| PyIndexError::new_err("No node found for index"))
+    }
+}
+
+/// A view of a directed graph, or of one with its edges reversed, without
+/// copying it.
+///
+/// A view has a subset of the nodes and edges of the graph, with the same
+/// indices, and the functions which accept a view, such as
+/// :func:`~retworkx.bfs_search`, :func:`~retworkx.dfs_search` and
+/// :func:`~retworkx.dijkstra_search`, run on it like on a graph with only
+/// those nodes and edges. Create it with :meth:`.PyDiGraph.subgraph_view`
+/// or :meth:`.PyDiGraph.reverse_view`, and copy it into a
+/// :class:`~retworkx.PyDiGraph` with :meth:`.to_digraph`.
+///
+/// The node and edge filters of the view are evaluated when it's created,
+/// so nodes and edges added to the graph afterwards are only in the view if
+/// it has no filter. The graph can't be mutated while a function runs on
+/// the view.
+#[pyclass(module = "retworkx")]
+pub struct DiGraphView {
+    pub graph: Py<PyDiGraph>,
+    pub reversed: bool,
+    pub nodes: Option<FixedBitSet>,
+    pub edges: Option<FixedBitSet>,
+}
+
+impl DiGraphView {
+    fn node_weight(&self, py: Python, node: usize) -> Option<PyObject> {
+        if !IndexFilter(self.nodes.as_ref()).include_node(NodeIndex::new(node)) {
+            return None;
+        }
+        self.graph
+            .borrow(py)
+            .graph
+            .node_weight(NodeIndex::new(node))
+            .map(|weight| weight.clone_ref(py))
+    }
+
+    fn neighbors_directed(&self, py: Python, node: usize, dir: petgraph::Direction) -> NodeIndices {
+        NodeIndices {
+            nodes: digraph_view!(py, self, |g| g
+                .neighbors_directed(NodeIndex::new(node), dir)
+                .map(|node| node.index())
+                .collect()),
+        }
+    }
+}
+
+#[pymethods]
+impl DiGraphView {
+    /// The graph this is a view of.
+    #[getter]
+    fn graph(&self, py: Python) -> Py<PyDiGraph> {
+        self.graph.clone_ref(py)
+    }
+
+    /// Whether the edges of the view are reversed compared to the graph.
+    #[getter]
+    fn reversed(&self) -> bool {
+        self.reversed
+    }
+
+    /// Return a view of a subgraph of this view.
+    ///
+    /// The arguments are as in :meth:`.PyDiGraph.subgraph_view`.
+    ///
+    /// :returns: A view with the nodes and edges of this view which pass the
+    ///     filters
+    /// :rtype: DiGraphView
+    #[pyo3(text_signature = "(self, /, nodes=None, node_filter=None, edge_filter=None)")]
+    fn subgraph_view(
+        &self,
+        py: Python,
+        nodes: Option<Vec<usize>>,
+        node_filter: Option<PyObject>,
+        edge_filter: Option<PyObject>,
+    ) -> PyResult<DiGraphView> {
+        let (nodes, edges) = view_filters(
+            py,
+            &self.graph.borrow(py).graph,
+            (self.nodes.as_ref(), self.edges.as_ref()),
+            nodes,
+            node_filter,
+            edge_filter,
+        )?;
+        Ok(DiGraphView {
+            graph: self.graph.clone_ref(py),
+            reversed: self.reversed,
+            nodes,
+            edges,
+        })
+    }
+
+    /// Return a view of this view with the direction of every edge
+    /// reversed.
+    ///
+    /// :rtype: DiGraphView
+    #[pyo3(text_signature = "(self)")]
+    fn reverse_view(&self, py: Python) -> DiGraphView {
+        DiGraphView {
+            graph: self.graph.clone_ref(py),
+            reversed: !self.reversed,
+            nodes: self.nodes.clone(),
+            edges: self.edges.clone(),
+        }
+    }
+
+    /// Return an undirected view of this view.
+    ///
+    /// :rtype: GraphView
+    #[pyo3(text_signature = "(self)")]
+    fn undirected_view(&self, py: Python) -> GraphView {
+        GraphView {
+            base: ViewBase::DiGraph(self.graph.clone_ref(py)),
+            nodes: self.nodes.clone(),
+            edges: self.edges.clone(),
+        }
+    }
+
+    /// Return the number of nodes in the view
+    #[pyo3(text_signature = "(self)")]
+    fn num_nodes(&self, py: Python) -> usize {
+        digraph_view!(py, self, |g| g.node_identifiers().count())
+    }
+
+    /// Return the number of edges in the view
+    #[pyo3(text_signature = "(self)")]
+    fn num_edges(&self, py: Python) -> usize {
+        digraph_view!(py, self, |g| g.edge_references().count())
+    }
+
+    /// Return a list of all node indices in the view.
+    ///
+    /// :rtype: NodeIndices
+    #[pyo3(text_signature = "(self)")]
+    pub fn node_indices(&self, py: Python) -> NodeIndices {
+        NodeIndices {
+            nodes: digraph_view!(py, self, |g| g
+                .node_identifiers()
+                .map(|node| node.index())
+                .collect()),
+        }
+    }
+
+    /// Return a list of all edge indices in the view.
+    ///
+    /// :rtype: EdgeIndices
+    #[pyo3(text_signature = "(self)")]
+    fn edge_indices(&self, py: Python) -> EdgeIndices {
+        EdgeIndices {
+            edges: digraph_view!(py, self, |g| g
+                .edge_references()
+                .map(|edge| edge.id().index())
+                .collect()),
+        }
+    }
+
+    /// Return a list of the edges in the view as tuples of the form
+    /// ``(source, target)``.
+    ///
+    /// :rtype: EdgeList
+    #[pyo3(text_signature = "(self)")]
+    fn edge_list(&self, py: Python) -> EdgeList {
+        EdgeList {
+            edges: digraph_view!(py, self, |g| g
+                .edge_references()
+                .map(|edge| (edge.source().index(), edge.target().index()))
+                .collect()),
+        }
+    }
+
+    /// Return a list of the edges in the view as tuples of the form
+    /// ``(source, target, weight)``.
+    ///
+    /// :rtype: WeightedEdgeList
+    #[pyo3(text_signature = "(self)")]
+    fn weighted_edge_list(&self, py: Python) -> WeightedEdgeList {
+        WeightedEdgeList {
+            edges: digraph_view!(py, self, |g| g
+                .edge_references()
+                .map(|edge| {
+                    (
+                        edge.source().index(),
+                        edge.target().index(),
+                        edge.weight().clone_ref(py),
+                    )
+                })
+                .collect()),
+        }
+    }
+
+    /// Return the indices of the successors of a node in the view.
+    ///
+    /// :param int node: The index of the node
+    ///
+    /// :rtype: NodeIndices
+    #[pyo3(text_signature = "(self, node, /)")]
+    fn successor_indices(&self, py: Python, node: usize) -> NodeIndices {
+        self.neighbors_directed(py, node, Outgoing)
+    }
+
+    /// Return the indices of the predecessors of a node in the view.
+    ///
+    /// :param int node: The index of the node
+    ///
+    /// :rtype: NodeIndices
+    #[pyo3(text_signature = "(self, node, /)")]
+    fn predecessor_indices(&self, py: Python, node: usize) -> NodeIndices {
+        self.neighbors_directed(py, node, Incoming)
+    }
+
+    /// Return the number of edges to a node in the view.
+    ///
+    /// :param int node: The index of the node
+    ///
+    /// :rtype: int
+    #[pyo3(text_signature = "(self, node, /)")]
+    fn in_degree(&self, py: Python, node: usize) -> usize {
+        digraph_view!(py, self, |g| g
+            .edges_directed(NodeIndex::new(node), Incoming)
+            .count())
+    }
+
+    /// Return the number of edges from a node in the view.
+    ///
+    /// :param int node: The index of the node
+    ///
+    /// :rtype: int
+    #[pyo3(text_signature = "(self, node, /)")]
+    fn out_degree(&self, py: Python, node: usize) -> usize {
+        digraph_view!(py, self, |g| g.edges(NodeIndex::new(node)).count())
+    }
+
+    /// Return True if there is an edge from ``node_a`` to ``node_b`` in the
+    /// view.
+    ///
+    /// :param int node_a: The index of the source node
+    /// :param int node_b: The index of the target node
+    ///
+    /// :rtype: bool
+    #[pyo3(text_signature = "(self, node_a, node_b, /)")]
+    fn has_edge(&self, py: Python, node_a: usize, node_b: usize) -> bool {
+        digraph_view!(py, self, |g| g
+            .edges(NodeIndex::new(node_a))
+            .any(|edge| edge.target().index() == node_b))
+    }
+
+    /// Copy the nodes and edges of the view into a new directed graph.
+    ///
+    /// The nodes are added to the new graph in the order of their indices
+    /// in the view, so they are reindexed if the view doesn't have all the
+    /// nodes of the graph, and the edges are in their direction in the
+    /// view. The node and edge data payloads are shared with the graph.
+    ///
+    /// :rtype: PyDiGraph
+    #[pyo3(text_signature = "(self)")]
+    fn to_digraph(&self, py: Python) -> PyDiGraph {
+        let graph: StablePyGraph<Directed> =
+            digraph_view!(py, self, |g| copy_view(py, g, |node| {
+                self.node_weight(py, node.index()).unwrap()
+            }));
+        let source = self.graph.borrow(py);
+        PyDiGraph {
+            graph,
+            cycle_state: None,
+            check_cycle: source.check_cycle,
+            node_removed: false,
+            multigraph: source.multigraph,
+            node_keys: NodeKeys::default(),
+            attributes: AttributeColumns::default(),
+            generation: 0,
+        }
+    }
+
+    fn __len__(&self, py: Python) -> usize {
+        self.num_nodes(py)
+    }
+
+    fn __getitem__(&self, py: Python, idx: usize) -> PyResult<PyObject> {
+        self.node_weight(py, idx)
+            .ok_or_else(|| PyIndexError::new_err("No node found for index"))
+    }
+}
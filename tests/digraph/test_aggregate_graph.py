@@ -0,0 +1,82 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import retworkx
+
+
+class TestDiGraphAggregateGraph(unittest.TestCase):
+    def setUp(self):
+        self.graph = retworkx.PyDiGraph()
+        self.graph.add_nodes_from(["a", "a", "b", "b"])
+        self.graph.add_edges_from(
+            [
+                (0, 1, 1.0),
+                (1, 0, 2.0),
+                (0, 2, 3.0),
+                (1, 3, 4.0),
+                (3, 0, 5.0),
+                (2, 3, 6.0),
+            ]
+        )
+
+    def test_partition(self):
+        res = retworkx.digraph_aggregate_graph(self.graph, [[0, 1], [2, 3]], weight_fn=float)
+        self.assertIsInstance(res, retworkx.PyDiGraph)
+        self.assertEqual(
+            [
+                {
+                    "group": 0,
+                    "nodes": [0, 1],
+                    "size": 2,
+                    "internal_edges": 2,
+                    "internal_weight": 3.0,
+                    "density": 1.0,
+                },
+                {
+                    "group": 1,
+                    "nodes": [2, 3],
+                    "size": 2,
+                    "internal_edges": 1,
+                    "internal_weight": 6.0,
+                    "density": 0.5,
+                },
+            ],
+            res.nodes(),
+        )
+        self.assertEqual(
+            [
+                (0, 1, {"count": 2, "weight": 7.0}),
+                (1, 0, {"count": 1, "weight": 5.0}),
+            ],
+            res.weighted_edge_list(),
+        )
+
+    def test_callable_groups(self):
+        res = retworkx.digraph_aggregate_graph(self.graph, lambda node: node)
+        self.assertEqual(["a", "b"], [group["group"] for group in res.nodes()])
+        self.assertEqual({"count": 2, "weight": 2.0}, res.get_edge_data(0, 1))
+        self.assertEqual({"count": 1, "weight": 1.0}, res.get_edge_data(1, 0))
+
+    def test_invalid_node(self):
+        with self.assertRaises(retworkx.InvalidNode):
+            retworkx.digraph_aggregate_graph(self.graph, [[0, 1], [2, 3, 4]])
+
+    def test_node_in_no_part(self):
+        with self.assertRaises(ValueError):
+            retworkx.digraph_aggregate_graph(self.graph, [[0, 1], [2]])
+
+    def test_universal(self):
+        res = retworkx.aggregate_graph(self.graph, [[0, 1], [2, 3]])
+        self.assertIsInstance(res, retworkx.PyDiGraph)
+        self.assertEqual(2, res.num_edges())
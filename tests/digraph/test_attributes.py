@@ -0,0 +1,116 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import os
+import pickle
+import tempfile
+import unittest
+
+import numpy as np
+
+import retworkx
+
+
+class TestDiGraphAttributes(unittest.TestCase):
+    def setUp(self):
+        self.graph = retworkx.generators.directed_path_graph(4)
+        self.graph.set_node_attribute("size", np.array([1.0, 2.0, 3.0, 4.0]))
+        self.graph.set_edge_attribute("capacity", np.array([5, 6, 7]))
+
+    def test_get_attribute(self):
+        self.assertEqual([1.0, 2.0, 3.0, 4.0], self.graph.get_node_attribute("size").tolist())
+        self.assertEqual([6], self.graph.get_edge_attribute("capacity", edges=[1]).tolist())
+        self.assertEqual(["size"], self.graph.node_attributes())
+        self.assertEqual(["capacity"], self.graph.edge_attributes())
+
+    def test_set_subset(self):
+        self.graph.set_edge_attribute("capacity", np.array([1]), edges=[2])
+        self.assertEqual([5, 6, 1], self.graph.get_edge_attribute("capacity").tolist())
+        with self.assertRaises(TypeError):
+            self.graph.set_edge_attribute("capacity", np.array([1.0]), edges=[2])
+
+    def test_missing_attribute(self):
+        with self.assertRaises(KeyError):
+            self.graph.get_edge_attribute("size")
+        with self.assertRaises(KeyError):
+            self.graph.remove_node_attribute("capacity")
+
+    def test_invalid_index(self):
+        with self.assertRaises(retworkx.InvalidNode):
+            self.graph.set_node_attribute("size", np.array([1.0]), nodes=[7])
+        with self.assertRaises(IndexError):
+            self.graph.set_edge_attribute("capacity", np.array([1]), edges=[7])
+
+    def test_remove_node(self):
+        self.graph.remove_node(0)
+        self.assertEqual([2.0, 3.0, 4.0], self.graph.get_node_attribute("size").tolist())
+        self.assertEqual([6, 7], self.graph.get_edge_attribute("capacity").tolist())
+        edge = self.graph.add_edge(3, 1, None)
+        self.assertEqual(0, edge)
+        self.assertEqual([0], self.graph.get_edge_attribute("capacity", [edge]).tolist())
+
+    def test_remove_node_retain_edges(self):
+        self.graph.remove_node_retain_edges(1)
+        self.assertEqual([1.0, 3.0, 4.0], self.graph.get_node_attribute("size").tolist())
+        self.assertEqual(
+            {(0, 2): 0, (2, 3): 7},
+            dict(zip(self.graph.edge_list(), self.graph.get_edge_attribute("capacity"))),
+        )
+
+    def test_contract_nodes(self):
+        node = self.graph.contract_nodes([1, 2], None)
+        self.assertEqual(1.0, self.graph.get_node_attribute("size", [0])[0])
+        self.assertTrue(np.isnan(self.graph.get_node_attribute("size", [node])[0]))
+        self.assertEqual([0, 0], self.graph.get_edge_attribute("capacity").tolist())
+
+    def test_remove_edge(self):
+        self.graph.remove_edge(2, 3)
+        self.graph.remove_edge_from_index(0)
+        self.assertEqual([6], self.graph.get_edge_attribute("capacity").tolist())
+        edge = self.graph.add_edge(3, 0, None)
+        self.assertEqual([0], self.graph.get_edge_attribute("capacity", [edge]).tolist())
+
+    def test_pickle(self):
+        self.graph.add_edge(3, 0, None)
+        self.graph.set_edge_attribute("capacity", np.array([8]), edges=[3])
+        self.graph.remove_node(1)
+        graph = pickle.loads(pickle.dumps(self.graph))
+        self.assertEqual(
+            self.graph.get_node_attribute("size").tolist(),
+            graph.get_node_attribute("size").tolist(),
+        )
+        self.assertEqual(
+            {(2, 3): 7, (3, 0): 8},
+            dict(zip(graph.edge_list(), graph.get_edge_attribute("capacity"))),
+        )
+
+    def test_snapshot(self):
+        self.graph.remove_node(3)
+        with tempfile.TemporaryDirectory() as tmpdirname:
+            path = os.path.join(tmpdirname, "graph.rx")
+            self.graph.write_snapshot(path)
+            graph = retworkx.PyDiGraph.read_snapshot(path)
+        self.assertEqual(self.graph.node_attributes(), graph.node_attributes())
+        self.assertEqual(
+            self.graph.get_node_attribute("size").tolist(),
+            graph.get_node_attribute("size").tolist(),
+        )
+        self.assertEqual(
+            dict(zip(self.graph.edge_list(), self.graph.get_edge_attribute("capacity"))),
+            dict(zip(graph.edge_list(), graph.get_edge_attribute("capacity"))),
+        )
+
+    def test_pickle_empty_graph(self):
+        graph = retworkx.PyDiGraph()
+        graph.set_node_attribute("size", np.array([], dtype=np.float64))
+        graph = pickle.loads(pickle.dumps(graph))
+        self.assertEqual(["size"], graph.node_attributes())
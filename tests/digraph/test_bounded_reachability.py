@@ -0,0 +1,64 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+
+import unittest
+
+import retworkx
+
+
+class TestBoundedReachability(unittest.TestCase):
+    def setUp(self):
+        self.graph = retworkx.PyDiGraph()
+        self.graph.extend_from_edge_list([(0, 1), (0, 2), (1, 3), (2, 3), (3, 4), (0, 4), (5, 0)])
+
+    def test_descendants_at_distance(self):
+        for distance, expected in enumerate([{0}, {1, 2, 4}, {3}, set(), set()]):
+            res = retworkx.digraph_descendants_at_distance(self.graph, 0, distance)
+            self.assertEqual(expected, res)
+
+    def test_descendants_at_distance_dispatch(self):
+        self.assertEqual({3}, retworkx.descendants_at_distance(self.graph, 0, 2))
+
+    def test_ancestors_at_distance(self):
+        for distance, expected in enumerate([{4}, {0, 3}, {1, 2, 5}, set()]):
+            res = retworkx.ancestors_at_distance(self.graph, 4, distance)
+            self.assertEqual(expected, res)
+
+    def test_reachable_within(self):
+        expected = [{0}, {0, 1, 2, 4}, {0, 1, 2, 3, 4}, {0, 1, 2, 3, 4}]
+        for max_depth, nodes in enumerate(expected):
+            res = retworkx.digraph_reachable_within(self.graph, 0, max_depth)
+            self.assertEqual(nodes, res)
+        self.assertEqual(
+            retworkx.descendants(self.graph, 0) | {0},
+            retworkx.reachable_within(self.graph, 0, 10),
+        )
+
+    def test_cycle(self):
+        graph = retworkx.generators.directed_cycle_graph(4)
+        self.assertEqual({2}, retworkx.digraph_descendants_at_distance(graph, 0, 2))
+        self.assertEqual(set(), retworkx.digraph_descendants_at_distance(graph, 0, 4))
+        self.assertEqual({0, 1, 2, 3}, retworkx.digraph_reachable_within(graph, 0, 5))
+
+    def test_with_node_removal(self):
+        self.graph.remove_node(1)
+        self.assertEqual({3}, retworkx.digraph_descendants_at_distance(self.graph, 0, 2))
+        self.assertEqual({2, 3, 4}, retworkx.digraph_reachable_within(self.graph, 2, 2))
+
+    def test_invalid_node(self):
+        with self.assertRaises(retworkx.InvalidNode):
+            retworkx.digraph_descendants_at_distance(self.graph, 10, 1)
+        with self.assertRaises(retworkx.InvalidNode):
+            retworkx.ancestors_at_distance(self.graph, 10, 1)
+        with self.assertRaises(retworkx.InvalidNode):
+            retworkx.digraph_reachable_within(self.graph, 10, 1)
@@ -10,6 +10,7 @@
 # License for the specific language governing permissions and limitations
 # under the License.
 
+import math
 import unittest
 
 import retworkx
@@ -128,3 +129,291 @@ class TestCentralityDiGraphDeletedNode(unittest.TestCase):
         )
         expected = {0: 0.0, 1: 2.0, 2: 2.0, 4: 0.0}
         self.assertEqual(expected, betweenness)
+
+
+class TestEigenvectorCentrality(unittest.TestCase):
+    def setUp(self):
+        self.graph = retworkx.PyDiGraph()
+        self.graph.add_nodes_from(list(range(5)))
+        self.graph.add_edges_from(
+            [(0, 1, 2.0), (1, 2, 1.0), (2, 0, 1.0), (2, 3, 3.0), (3, 4, 1.0), (4, 2, 0.5)]
+        )
+
+    def test_eigenvector_centrality_weighted(self):
+        centrality = retworkx.digraph_eigenvector_centrality(
+            self.graph, weight_fn=float, max_iter=1000, tol=1e-8
+        )
+        expected = {
+            0: 0.23606583385760227,
+            1: 0.31096186034081735,
+            2: 0.3584174513333984,
+            3: 0.7081975014074204,
+            4: 0.4664427865006068,
+        }
+        self.assertEqual(sorted(expected), sorted(centrality.keys()))
+        for node, value in expected.items():
+            self.assertAlmostEqual(value, centrality[node], places=5)
+
+    def test_eigenvector_centrality_incoming_edges(self):
+        # node 2 has no incoming edges so its centrality vanishes
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(list(range(3)))
+        graph.add_edges_from_no_data([(0, 1), (1, 0), (2, 0)])
+        centrality = retworkx.digraph_eigenvector_centrality(graph)
+        self.assertAlmostEqual(0.5**0.5, centrality[0], places=5)
+        self.assertAlmostEqual(0.5**0.5, centrality[1], places=5)
+        self.assertAlmostEqual(0.0, centrality[2], places=5)
+
+    def test_eigenvector_centrality_failed_to_converge(self):
+        with self.assertRaises(retworkx.FailedToConverge):
+            retworkx.digraph_eigenvector_centrality(self.graph, max_iter=1)
+
+    def test_eigenvector_centrality_universal(self):
+        centrality = retworkx.eigenvector_centrality(self.graph)
+        self.assertAlmostEqual(1.0, sum(value**2 for value in centrality.values()))
+
+
+class TestKatzCentrality(unittest.TestCase):
+    def setUp(self):
+        self.graph = retworkx.PyDiGraph()
+        self.graph.add_nodes_from(list(range(5)))
+        self.graph.add_edges_from(
+            [(0, 1, 2.0), (1, 2, 1.0), (2, 0, 1.0), (2, 3, 3.0), (3, 4, 1.0), (4, 2, 0.5)]
+        )
+
+    def test_katz_centrality_weighted(self):
+        centrality = retworkx.digraph_katz_centrality(self.graph, weight_fn=float, tol=1e-8)
+        expected = {
+            0: 0.41492764001590504,
+            1: 0.4541483585286318,
+            2: 0.437648094108863,
+            3: 0.5024572583602694,
+            4: 0.4214085562023416,
+        }
+        self.assertEqual(sorted(expected), sorted(centrality.keys()))
+        for node, value in expected.items():
+            self.assertAlmostEqual(value, centrality[node], places=6)
+
+    def test_katz_centrality_unnormalized(self):
+        centrality = retworkx.digraph_katz_centrality(
+            self.graph, alpha=0.2, normalized=False, tol=1e-8
+        )
+        expected = {
+            0: 1.30081300676608,
+            1: 1.26016260030464,
+            2: 1.50406503907328,
+            3: 1.30081300676608,
+            4: 1.26016260030464,
+        }
+        for node, value in expected.items():
+            self.assertAlmostEqual(value, centrality[node], places=6)
+
+    def test_katz_centrality_beta_scalar(self):
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(list(range(3)))
+        graph.add_edges_from_no_data([(0, 1), (1, 2)])
+        centrality = retworkx.digraph_katz_centrality(
+            graph, alpha=0.5, beta=2.0, normalized=False, tol=1e-9
+        )
+        self.assertAlmostEqual(2.0, centrality[0])
+        self.assertAlmostEqual(3.0, centrality[1])
+        self.assertAlmostEqual(3.5, centrality[2])
+
+    def test_katz_centrality_failed_to_converge(self):
+        with self.assertRaises(retworkx.FailedToConverge):
+            retworkx.digraph_katz_centrality(self.graph, alpha=1.0)
+
+
+class TestClosenessHarmonicCentrality(unittest.TestCase):
+    def setUp(self):
+        self.graph = retworkx.PyDiGraph()
+        self.graph.add_nodes_from(range(6))
+        self.graph.add_edges_from(
+            [(0, 1, 1.0), (1, 2, 2.0), (2, 0, 1.0), (0, 3, 3.0), (3, 4, 0.5), (2, 4, 4.0)]
+        )
+
+    def assertCentrality(self, expected, centrality):
+        self.assertEqual(sorted(expected), sorted(centrality.keys()))
+        for node, value in expected.items():
+            self.assertAlmostEqual(value, centrality[node])
+
+    def test_closeness_centrality(self):
+        centrality = retworkx.digraph_closeness_centrality(self.graph)
+        expected = {0: 4 / 15, 1: 4 / 15, 2: 4 / 15, 3: 0.3, 4: 8 / 15, 5: 0.0}
+        self.assertCentrality(expected, centrality)
+
+    def test_closeness_centrality_not_wf_improved(self):
+        centrality = retworkx.digraph_closeness_centrality(self.graph, wf_improved=False)
+        expected = {0: 2 / 3, 1: 2 / 3, 2: 2 / 3, 3: 0.5, 4: 2 / 3, 5: 0.0}
+        self.assertCentrality(expected, centrality)
+
+    def test_closeness_centrality_weighted(self):
+        centrality = retworkx.digraph_closeness_centrality(self.graph, weight_fn=float)
+        expected = {
+            0: 0.2,
+            1: 0.26666666666666666,
+            2: 0.16000000000000003,
+            3: 0.13846153846153847,
+            4: 0.22857142857142856,
+            5: 0.0,
+        }
+        self.assertCentrality(expected, centrality)
+
+    def test_closeness_centrality_parallel(self):
+        graph = retworkx.generators.directed_grid_graph(10, 10)
+        serial = retworkx.digraph_closeness_centrality(graph, parallel_threshold=1000)
+        parallel = retworkx.digraph_closeness_centrality(graph, parallel_threshold=1)
+        self.assertEqual(dict(serial), dict(parallel))
+
+    def test_harmonic_centrality(self):
+        centrality = retworkx.digraph_harmonic_centrality(self.graph)
+        expected = {0: 1.5, 1: 1.5, 2: 1.5, 3: 11 / 6, 4: 3.0, 5: 0.0}
+        self.assertCentrality(expected, centrality)
+
+    def test_harmonic_centrality_weighted(self):
+        centrality = retworkx.digraph_harmonic_centrality(self.graph, weight_fn=float)
+        expected = {0: 4 / 3, 1: 1.5, 2: 5 / 6, 3: 0.75, 4: 2.7023809523809526, 5: 0.0}
+        self.assertCentrality(expected, centrality)
+
+    def test_universal(self):
+        self.assertEqual(
+            dict(retworkx.digraph_closeness_centrality(self.graph, weight_fn=float)),
+            dict(retworkx.closeness_centrality(self.graph, weight_fn=float)),
+        )
+        self.assertEqual(
+            dict(retworkx.digraph_harmonic_centrality(self.graph)),
+            dict(retworkx.harmonic_centrality(self.graph)),
+        )
+
+
+class TestBetweennessCentralitySubset(unittest.TestCase):
+    def setUp(self):
+        self.graph = retworkx.PyDiGraph()
+        self.graph.add_nodes_from(range(7))
+        self.graph.add_edges_from(
+            [
+                (0, 1, 1.0),
+                (0, 4, 1.0),
+                (1, 2, 1.0),
+                (1, 4, 1.0),
+                (2, 3, 1.0),
+                (2, 6, 3.0),
+                (3, 4, 2.0),
+                (3, 5, 1.0),
+                (5, 0, 1.0),
+            ]
+        )
+
+    def assertCentrality(self, expected, centrality):
+        self.assertEqual(sorted(expected), sorted(centrality.keys()))
+        for node, value in expected.items():
+            self.assertAlmostEqual(value, centrality[node])
+
+    def test_betweenness_centrality_subset(self):
+        centrality = retworkx.digraph_betweenness_centrality_subset(self.graph, [0, 2], [5, 6])
+        expected = {0: 0.0, 1: 2.0, 2: 2.0, 3: 2.0, 4: 0.0, 5: 0.0, 6: 0.0}
+        self.assertCentrality(expected, centrality)
+
+    def test_betweenness_centrality_subset_normalized(self):
+        centrality = retworkx.digraph_betweenness_centrality_subset(
+            self.graph, [0, 2], [5, 6], normalized=True
+        )
+        expected = {0: 0.0, 1: 1 / 15, 2: 1 / 15, 3: 1 / 15, 4: 0.0, 5: 0.0, 6: 0.0}
+        self.assertCentrality(expected, centrality)
+
+    def test_betweenness_centrality_subset_weighted(self):
+        centrality = retworkx.digraph_betweenness_centrality_subset(
+            self.graph, [0, 2], [5, 6], weight_fn=float
+        )
+        expected = {0: 0.0, 1: 2.0, 2: 2.0, 3: 2.0, 4: 0.0, 5: 0.0, 6: 0.0}
+        self.assertCentrality(expected, centrality)
+
+    def test_betweenness_centrality_subset_all_nodes(self):
+        nodes = list(self.graph.node_indices())
+        subset = retworkx.digraph_betweenness_centrality_subset(self.graph, nodes, nodes)
+        full = retworkx.digraph_betweenness_centrality(self.graph, normalized=False)
+        self.assertCentrality(dict(full), subset)
+
+    def test_betweenness_centrality_subset_deleted_node(self):
+        self.graph.remove_node(4)
+        centrality = retworkx.digraph_betweenness_centrality_subset(self.graph, [0], [5])
+        self.assertEqual([0, 1, 2, 3, 5, 6], sorted(centrality.keys()))
+        self.assertEqual(1.0, centrality[3])
+
+    def test_betweenness_centrality_subset_invalid_node(self):
+        with self.assertRaises(IndexError):
+            retworkx.digraph_betweenness_centrality_subset(self.graph, [42], [0])
+
+    def test_betweenness_centrality_subset_universal(self):
+        centrality = retworkx.betweenness_centrality_subset(self.graph, [0, 2], [5, 6])
+        self.assertEqual(2.0, centrality[1])
+
+
+class TestClosenessVitality(unittest.TestCase):
+    def test_closeness_vitality(self):
+        graph = retworkx.generators.directed_cycle_graph(3)
+        graph.add_edge(0, 2, None)
+        vitality = retworkx.digraph_closeness_vitality(graph)
+        self.assertEqual({0: -math.inf, 1: 6.0, 2: -math.inf}, dict(vitality))
+
+    def test_closeness_vitality_weighted(self):
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(list(range(3)))
+        graph.add_edges_from(
+            [(0, 1, 1.0), (1, 0, 1.0), (1, 2, 1.0), (2, 1, 1.0), (0, 2, 3.0)]
+        )
+        vitality = retworkx.digraph_closeness_vitality(graph, weight_fn=float)
+        self.assertEqual({0: 6.0, 1: -math.inf, 2: 6.0}, dict(vitality))
+
+    def test_closeness_vitality_universal(self):
+        graph = retworkx.generators.directed_cycle_graph(4)
+        vitality = retworkx.closeness_vitality(graph)
+        self.assertEqual({i: -math.inf for i in range(4)}, dict(vitality))
+
+
+class TestTopKCentrality(unittest.TestCase):
+    def assertTopK(self, expected, top_k, k):
+        expected_scores = sorted(expected.values(), reverse=True)[:k]
+        self.assertEqual(len(expected_scores), len(top_k))
+        for (node, score), expected_score in zip(top_k, expected_scores):
+            self.assertAlmostEqual(expected[node], score)
+            self.assertAlmostEqual(expected_score, score)
+
+    def test_top_k_degree_centrality(self):
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(range(4))
+        graph.add_edges_from_no_data([(0, 1), (0, 2), (3, 0), (1, 2)])
+        top_k = retworkx.digraph_top_k_degree_centrality(graph, 2)
+        self.assertEqual([(0, 1.0), (1, 2 / 3)], top_k)
+        self.assertEqual(top_k, retworkx.top_k_degree_centrality(graph, 2))
+
+    def test_top_k_closeness_centrality(self):
+        graph = retworkx.directed_gnp_random_graph(60, 0.05, seed=4)
+        for wf_improved in [True, False]:
+            expected = retworkx.digraph_closeness_centrality(graph, wf_improved=wf_improved)
+            for k in [1, 5, 60]:
+                top_k = retworkx.digraph_top_k_closeness_centrality(
+                    graph, k, wf_improved=wf_improved
+                )
+                self.assertTopK(expected, top_k, k)
+
+    def test_top_k_closeness_centrality_incoming(self):
+        graph = retworkx.generators.directed_star_graph(5)
+        top_k = retworkx.top_k_closeness_centrality(graph, 2)
+        # Every leaf is reached from the center only
+        self.assertEqual([(1, 0.25), (2, 0.25)], top_k)
+
+    def test_top_k_betweenness_centrality(self):
+        graph = retworkx.generators.directed_path_graph(6)
+        expected = retworkx.digraph_betweenness_centrality(graph, normalized=False)
+        top_k = retworkx.digraph_top_k_betweenness_centrality(
+            graph, 2, normalized=False, epsilon=0.02, seed=5
+        )
+        self.assertEqual({2, 3}, {node for node, _ in top_k})
+        for node, score in top_k:
+            self.assertAlmostEqual(expected[node], score, delta=0.02 * 30)
+
+    def test_top_k_betweenness_centrality_invalid(self):
+        graph = retworkx.generators.directed_path_graph(4)
+        with self.assertRaises(ValueError):
+            retworkx.top_k_betweenness_centrality(graph, 1, epsilon=2.0)
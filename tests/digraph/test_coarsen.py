@@ -0,0 +1,32 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import retworkx
+
+
+class TestDiGraphCoarsen(unittest.TestCase):
+    def test_edges_in_both_directions(self):
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(range(4))
+        graph.add_edges_from([(0, 1, 2.0), (1, 0, 2.0), (1, 2, 3.0), (2, 3, 5.0)])
+        res = retworkx.digraph_coarsen(graph, weight_fn=float, min_nodes=1, max_levels=1)
+        self.assertIsInstance(res.graphs[0], retworkx.PyGraph)
+        self.assertEqual({0: 0, 1: 0, 2: 1, 3: 1}, dict(res.node_maps[0]))
+        self.assertEqual([(0, 1, 3.0)], res.graphs[0].weighted_edge_list())
+
+    def test_dispatch(self):
+        graph = retworkx.generators.directed_grid_graph(10, 10)
+        res = retworkx.coarsen(graph, min_nodes=20, seed=42)
+        self.assertLessEqual(len(res.graphs[-1]), 20)
+        self.assertEqual(100, sum(res.graphs[-1].nodes()))
@@ -94,3 +94,36 @@ class TestCoreNumber(unittest.TestCase):
         res = retworkx.core_number(digraph)
         self.assertIsInstance(res, dict)
         self.assertEqual(res, self.example_core)
+
+    def test_directed_removed_node(self):
+        digraph = retworkx.PyDiGraph()
+        digraph.add_nodes_from(list(range(21)))
+        digraph.add_edges_from_no_data(self.example_edges)
+        digraph.remove_node(20)
+        res = retworkx.core_number(digraph)
+        del self.example_core[20]
+        self.assertEqual(res, self.example_core)
+
+    def test_directed_k_core(self):
+        digraph = retworkx.PyDiGraph()
+        digraph.add_nodes_from(list(range(21)))
+        digraph.add_edges_from_no_data(self.example_edges)
+        for k in range(4):
+            core = retworkx.k_core(digraph, k)
+            self.assertIsInstance(core, retworkx.PyDiGraph)
+            expected = [node for node, core in self.example_core.items() if core >= k]
+            self.assertEqual(core.nodes(), expected)
+        self.assertEqual(retworkx.k_core(digraph).nodes(), list(range(8)))
+        self.assertEqual(retworkx.k_core(digraph, 4).num_nodes(), 0)
+
+    def test_directed_k_core_edges(self):
+        digraph = retworkx.PyDiGraph()
+        digraph.add_nodes_from(list(range(4)))
+        digraph.add_edges_from([(0, 1, "a"), (1, 2, "b"), (2, 0, "c"), (2, 3, "d")])
+        core = retworkx.digraph_k_core(digraph, k=2)
+        self.assertEqual(core.nodes(), [0, 1, 2])
+        self.assertEqual(sorted(core.edges()), ["a", "b", "c"])
+
+    def test_directed_k_core_empty(self):
+        digraph = retworkx.PyDiGraph()
+        self.assertEqual(retworkx.k_core(digraph).num_nodes(), 0)
@@ -0,0 +1,60 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+
+import unittest
+
+import retworkx
+
+
+class TestFormanCurvature(unittest.TestCase):
+    def test_path_graph(self):
+        graph = retworkx.generators.directed_path_graph(4)
+        res = retworkx.forman_curvature(graph)
+        self.assertEqual({0: 1.0, 1: 0.0, 2: 1.0}, dict(res.items()))
+
+    def test_cycle_graph(self):
+        graph = retworkx.generators.directed_cycle_graph(3)
+        res = retworkx.digraph_forman_curvature(graph)
+        self.assertEqual({0: 0.0, 1: 0.0, 2: 0.0}, dict(res.items()))
+
+    def test_only_incoming_and_outgoing_edges(self):
+        graph = retworkx.PyDiGraph()
+        # Edges out of the source and into the target of 0 -> 1 are ignored
+        graph.extend_from_edge_list([(0, 1), (0, 2), (3, 1)])
+        res = retworkx.forman_curvature(graph)
+        self.assertEqual({0: 2.0, 1: 2.0, 2: 2.0}, dict(res.items()))
+
+    def test_weighted(self):
+        graph = retworkx.PyDiGraph()
+        graph.extend_from_weighted_edge_list([(0, 1, 1.0), (1, 2, 4.0)])
+        res = retworkx.forman_curvature(graph, weight_fn=float)
+        self.assertEqual({0: 1.5, 1: 0.0}, dict(res.items()))
+
+
+class TestOllivierRicciCurvature(unittest.TestCase):
+    def test_direction_ignored(self):
+        graph = retworkx.generators.directed_mesh_graph(4)
+        undirected = graph.to_undirected(multigraph=False)
+        res = retworkx.digraph_ollivier_ricci_curvature(graph, alpha=0.0)
+        expected = retworkx.ollivier_ricci_curvature(undirected, alpha=0.0)
+        self.assertEqual(len(graph.edge_indices()), len(res))
+        for curvature in res.values():
+            self.assertAlmostEqual(expected[0], curvature, places=6)
+            self.assertAlmostEqual(2 / 3, curvature, places=3)
+
+    def test_path_graph(self):
+        graph = retworkx.generators.directed_path_graph(3)
+        res = retworkx.ollivier_ricci_curvature(graph)
+        self.assertEqual([0, 1], sorted(res.keys()))
+        for curvature in res.values():
+            self.assertAlmostEqual(0.5, curvature, places=3)
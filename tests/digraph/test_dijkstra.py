@@ -273,6 +273,69 @@ class TestDijkstraDiGraph(unittest.TestCase):
             retworkx.digraph_all_pairs_dijkstra_shortest_paths(graph, float),
         )
 
+    def test_dijkstra_all_pair_path_lengths_chunked(self):
+        expected = retworkx.digraph_all_pairs_dijkstra_path_lengths(self.graph, float)
+        for chunk_size in [1, 4, 6, 1000]:
+            chunks = []
+            retworkx.digraph_all_pairs_dijkstra_path_lengths_chunked(
+                self.graph, float, chunks.append, chunk_size=chunk_size
+            )
+            self.assertEqual(
+                [min(chunk_size, 6 - i) for i in range(0, 6, chunk_size)],
+                [len(chunk) for chunk in chunks],
+            )
+            lengths = {}
+            for chunk in chunks:
+                self.assertIsInstance(chunk, retworkx.AllPairsPathLengthMapping)
+                lengths.update(chunk)
+            self.assertEqual([0, 1, 2, 3, 4, 5], list(lengths))
+            self.assertEqual(expected, lengths)
+
+    def test_dijkstra_all_pair_path_lengths_chunked_with_node_removal(self):
+        self.graph.remove_node(3)
+        expected = retworkx.digraph_all_pairs_dijkstra_path_lengths(self.graph, float)
+        chunks = []
+        retworkx.digraph_all_pairs_dijkstra_path_lengths_chunked(
+            self.graph, float, chunks.append, chunk_size=2
+        )
+        self.assertEqual([[0, 1], [2, 4], [5]], [list(chunk) for chunk in chunks])
+        lengths = {}
+        for chunk in chunks:
+            lengths.update(chunk)
+        self.assertEqual(expected, lengths)
+
+    def test_dijkstra_all_pair_path_lengths_chunked_empty_graph(self):
+        graph = retworkx.PyDiGraph()
+        chunks = []
+        retworkx.digraph_all_pairs_dijkstra_path_lengths_chunked(graph, float, chunks.append)
+        self.assertEqual([], chunks)
+
+    def test_dijkstra_all_pair_path_lengths_chunked_callback_error(self):
+        chunks = []
+
+        def callback(chunk):
+            chunks.append(chunk)
+            raise KeyError("stop")
+
+        with self.assertRaises(KeyError):
+            retworkx.digraph_all_pairs_dijkstra_path_lengths_chunked(
+                self.graph, float, callback, chunk_size=2
+            )
+        self.assertEqual(1, len(chunks))
+
+    def test_dijkstra_all_pair_path_lengths_chunked_invalid_chunk_size(self):
+        with self.assertRaises(ValueError):
+            retworkx.digraph_all_pairs_dijkstra_path_lengths_chunked(
+                self.graph, float, lambda _: None, chunk_size=0
+            )
+
+    def test_dijkstra_all_pair_path_lengths_chunked_invalid_weight(self):
+        graph = retworkx.generators.directed_path_graph(2)
+        with self.assertRaises(ValueError):
+            retworkx.digraph_all_pairs_dijkstra_path_lengths_chunked(
+                graph, lambda _: -1.0, lambda _: None
+            )
+
     def dijkstra_with_invalid_weights(self):
         graph = retworkx.generators.directed_path_graph(2)
         for invalid_weight in [float("nan"), -1]:
@@ -312,3 +375,40 @@ class TestDijkstraDiGraph(unittest.TestCase):
                     retworkx.digraph_all_pairs_dijkstra_path_lengths(
                         graph, edge_cost_fn=lambda _: invalid_weight
                     )
+
+    def test_dijkstra_shortest_path_edges_multigraph(self):
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(list(range(3)))
+        graph.add_edge(0, 1, 5)
+        cheap = graph.add_edge(0, 1, 2)
+        graph.add_edge(1, 0, 0)
+        graph.add_edge(1, 2, 3)
+        last = graph.add_edge(1, 2, 1)
+        paths = retworkx.dijkstra_shortest_path_edges(graph, 0, weight_fn=float)
+        self.assertEqual({1: [cheap], 2: [cheap, last]}, dict(paths))
+        paths = retworkx.dijkstra_shortest_path_edges(graph, 2, weight_fn=float)
+        self.assertEqual({}, dict(paths))
+
+    def test_dijkstra_shortest_path_edges_invalid_weights(self):
+        graph = retworkx.generators.directed_path_graph(2)
+        for invalid_weight in [float("nan"), -1]:
+            with self.subTest(invalid_weight=invalid_weight):
+                with self.assertRaises(ValueError):
+                    retworkx.digraph_dijkstra_shortest_path_edges(
+                        graph, 0, weight_fn=lambda _: invalid_weight
+                    )
+
+    def test_dijkstra_path_weight_attr(self):
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(list(range(3)))
+        graph.add_edge(0, 1, {"weight": 4})
+        graph.add_edge(2, 1, {"weight": 1})
+        graph.add_edge(0, 2, {"weight": 1})
+        paths = retworkx.digraph_dijkstra_shortest_paths(graph, 1, weight_attr="weight")
+        self.assertEqual({}, paths)
+        paths = retworkx.digraph_dijkstra_shortest_paths(
+            graph, 1, weight_attr="weight", as_undirected=True
+        )
+        self.assertEqual({0: [1, 2, 0], 2: [1, 2]}, paths)
+        paths = retworkx.dijkstra_shortest_path_edges(graph, 0, weight_attr="weight")
+        self.assertEqual({1: [2, 1], 2: [2]}, dict(paths))
@@ -0,0 +1,132 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import random
+import unittest
+
+import retworkx
+
+
+def dominators(graph, entry):
+    # A node dominates the nodes which aren't reachable without it
+    reachable = retworkx.descendants(graph, entry) | {entry}
+    out = {node: {node} for node in reachable}
+    for node in reachable:
+        if node == entry:
+            continue
+        without = graph.copy()
+        without.remove_node(node)
+        for other in reachable - retworkx.descendants(without, entry) - {entry, node}:
+            out[other].add(node)
+        out[node].add(entry)
+    return out
+
+
+class TestImmediateDominators(unittest.TestCase):
+    def setUp(self):
+        # An if/else from 0 with a loop on 3
+        self.graph = retworkx.PyDiGraph()
+        self.graph.add_nodes_from(range(6))
+        self.graph.add_edges_from_no_data([(0, 1), (0, 2), (1, 3), (2, 3), (3, 4), (4, 3), (4, 5)])
+
+    def test_immediate_dominators(self):
+        res = retworkx.immediate_dominators(self.graph, 0)
+        self.assertEqual({0: 0, 1: 0, 2: 0, 3: 0, 4: 3, 5: 4}, dict(res))
+
+    def test_reverse_postorder(self):
+        res = retworkx.immediate_dominators(self.graph, 0)
+        order = list(res.keys())
+        self.assertEqual(0, order[0])
+        for node, idom in res.items():
+            self.assertLessEqual(order.index(idom), order.index(node))
+
+    def test_unreachable_nodes(self):
+        self.graph.add_edge(self.graph.add_node(6), 3, None)
+        res = retworkx.immediate_dominators(self.graph, 3)
+        self.assertEqual({3: 3, 4: 3, 5: 4}, dict(res))
+
+    def test_single_node(self):
+        graph = retworkx.PyDiGraph()
+        graph.add_node(None)
+        self.assertEqual({0: 0}, dict(retworkx.immediate_dominators(graph, 0)))
+
+    def test_cooper_figure_2(self):
+        # The irreducible graph of figure 2 of Cooper, Harvey and Kennedy,
+        # numbered in postorder from 5
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(range(6))
+        graph.add_edges_from_no_data([(5, 4), (5, 3), (4, 1), (3, 2), (1, 2), (2, 1)])
+        res = retworkx.immediate_dominators(graph, 5)
+        self.assertEqual({5: 5, 4: 5, 3: 5, 2: 5, 1: 5}, dict(res))
+
+    def test_invalid_node(self):
+        with self.assertRaises(retworkx.InvalidNode):
+            retworkx.immediate_dominators(self.graph, 10)
+
+    def test_random_graphs(self):
+        rng = random.Random(42)
+        for _ in range(20):
+            graph = retworkx.directed_gnp_random_graph(10, 0.2, seed=rng.randrange(1000))
+            doms = dominators(graph, 0)
+            res = retworkx.immediate_dominators(graph, 0)
+            self.assertEqual(set(doms), set(res))
+            for node, idom in res.items():
+                if node == 0:
+                    self.assertEqual(0, idom)
+                    continue
+                strict = doms[node] - {node}
+                self.assertIn(idom, strict)
+                self.assertEqual(strict, doms[idom])
+
+
+class TestDominanceFrontiers(unittest.TestCase):
+    def test_dominance_frontiers(self):
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(range(6))
+        graph.add_edges_from_no_data([(0, 1), (0, 2), (1, 3), (2, 3), (3, 4), (4, 3), (4, 5)])
+        res = retworkx.dominance_frontiers(graph, 0)
+        self.assertEqual({0: set(), 1: {3}, 2: {3}, 3: {3}, 4: {3}, 5: set()}, res)
+
+    def test_loop_to_entry(self):
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(range(3))
+        graph.add_edges_from_no_data([(0, 1), (1, 2), (2, 0)])
+        res = retworkx.dominance_frontiers(graph, 0)
+        self.assertEqual({0: {0}, 1: {0}, 2: {0}}, res)
+
+    def test_unreachable_nodes(self):
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(range(4))
+        graph.add_edges_from_no_data([(0, 1), (0, 2), (3, 2)])
+        res = retworkx.dominance_frontiers(graph, 0)
+        self.assertEqual({0: set(), 1: set(), 2: set()}, res)
+
+    def test_invalid_node(self):
+        graph = retworkx.PyDiGraph()
+        with self.assertRaises(retworkx.InvalidNode):
+            retworkx.dominance_frontiers(graph, 0)
+
+    def test_random_graphs(self):
+        rng = random.Random(42)
+        for _ in range(20):
+            graph = retworkx.directed_gnp_random_graph(10, 0.2, seed=rng.randrange(1000))
+            doms = dominators(graph, 0)
+            res = retworkx.dominance_frontiers(graph, 0)
+            self.assertEqual(set(doms), set(res))
+            for node, frontier in res.items():
+                expected = {
+                    other
+                    for other in doms
+                    if any(node in doms.get(pred, ()) for pred in graph.predecessor_indices(other))
+                    and not (node in doms[other] and node != other)
+                }
+                self.assertEqual(expected, frontier)
@@ -0,0 +1,26 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+import unittest
+
+import retworkx
+
+
+class TestDynamicConnectivity(unittest.TestCase):
+    def test_from_digraph(self):
+        graph = retworkx.PyDiGraph()
+        graph.extend_from_edge_list([(0, 1), (2, 1), (3, 4)])
+        connectivity = retworkx.DynamicConnectivity(graph)
+        # The direction of the edges is ignored
+        self.assertTrue(connectivity.connected(0, 2))
+        self.assertEqual([{0, 1, 2}, {3, 4}], sorted(connectivity.components(), key=min))
+        self.assertTrue(connectivity.remove_edge(1, 2))
+        self.assertEqual(3, connectivity.num_components)
@@ -0,0 +1,69 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import retworkx
+
+
+class TestDiGraphEdgeCutPartition(unittest.TestCase):
+    def setUp(self):
+        self.graph = retworkx.generators.directed_path_graph(6)
+        self.graph.add_edge(5, 0, None)
+        self.partition = [[0, 1, 2], [3, 4, 5]]
+
+    def test_parts(self):
+        res = retworkx.digraph_edge_cut_partition(self.graph, self.partition)
+        first, second = res.parts
+        self.assertEqual([0, 1, 2], list(first.nodes))
+        self.assertEqual([5, 3], list(first.halo_nodes))
+        self.assertEqual([1, 1], first.halo_owners)
+        self.assertEqual([3, 4, 5], list(second.nodes))
+        self.assertEqual([2, 0], list(second.halo_nodes))
+        self.assertEqual([0, 0], second.halo_owners)
+        self.assertEqual([2, 5], list(first.cut_edges))
+        self.assertEqual([2, 5], list(second.cut_edges))
+
+    def test_cut_edges(self):
+        res = retworkx.digraph_edge_cut_partition(self.graph, self.partition)
+        self.assertEqual([(2, 2, 3, 0, 1), (5, 5, 0, 1, 0)], res.cut_edges)
+
+    def test_subgraph(self):
+        res = retworkx.digraph_edge_cut_partition(self.graph, self.partition)
+        subgraph = res.parts[0].subgraph
+        self.assertIsInstance(subgraph, retworkx.PyDiGraph)
+        self.assertEqual({0: 0, 1: 1, 2: 2, 5: 3, 3: 4}, dict(res.parts[0].node_map))
+        self.assertEqual(
+            sorted([(0, 1), (1, 2), (2, 4), (3, 0)]), sorted(subgraph.edge_list())
+        )
+
+    def test_halo_depth(self):
+        res = retworkx.digraph_edge_cut_partition(self.graph, self.partition, halo_depth=3)
+        self.assertEqual([5, 3, 4], list(res.parts[0].halo_nodes))
+        self.assertEqual(6, len(res.parts[0].subgraph.edge_list()))
+
+    def test_universal_function(self):
+        res = retworkx.edge_cut_partition(self.graph, self.partition, halo_depth=0)
+        self.assertEqual([], list(res.parts[0].halo_nodes))
+        self.assertEqual([(2, 2, 3, 0, 1), (5, 5, 0, 1, 0)], res.cut_edges)
+
+    def test_node_in_two_parts(self):
+        with self.assertRaises(ValueError):
+            retworkx.digraph_edge_cut_partition(self.graph, [[0, 1, 2], [2, 3, 4, 5]])
+
+    def test_node_not_in_any_part(self):
+        with self.assertRaises(ValueError):
+            retworkx.digraph_edge_cut_partition(self.graph, [[0, 1, 2], [4, 5]])
+
+    def test_invalid_node(self):
+        with self.assertRaises(retworkx.InvalidNode):
+            retworkx.digraph_edge_cut_partition(self.graph, [[0, 1, 2, 10], [3, 4, 5]])
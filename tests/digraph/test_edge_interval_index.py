@@ -0,0 +1,86 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import gc
+import unittest
+import weakref
+
+import retworkx
+
+
+class TestDiGraphEdgeIntervalIndex(unittest.TestCase):
+    def setUp(self):
+        self.graph = retworkx.PyDiGraph()
+        self.graph.add_nodes_from(range(4))
+        self.graph.add_edges_from(
+            [
+                (0, 1, {"time": (0.0, 2.0)}),
+                (1, 0, {"time": (1.0, 5.0)}),
+                (1, 2, {"time": (3.0, 4.0)}),
+                (2, 3, {"time": (6.0, 8.0)}),
+            ]
+        )
+        self.index = retworkx.digraph_edge_interval_index(self.graph, lambda edge: edge["time"])
+
+    def test_len(self):
+        self.assertEqual(4, len(self.index))
+
+    def test_edges_active_in(self):
+        self.assertEqual([1, 2], list(self.index.edges_active_in(2.5, 3.5)))
+        self.assertEqual([3], list(self.index.edges_active_in(5.5, 10.0)))
+        self.assertEqual([], list(self.index.edges_active_in(8.5, 10.0)))
+
+    def test_edges_active_at(self):
+        self.assertEqual([0, 1], list(self.index.edges_active_at(2.0)))
+
+    def test_window_view(self):
+        view = self.index.window_view(0.0, 1.0)
+        self.assertIsInstance(view, retworkx.DiGraphView)
+        self.assertEqual(4, len(view))
+        self.assertEqual([(0, 1), (1, 0)], view.edge_list())
+        self.assertIs(self.graph.get_edge_data_by_index(1), view.weighted_edge_list()[1][2])
+
+    def test_window_view_to_digraph(self):
+        digraph = self.index.window_view(0.0, 1.0).to_digraph()
+        digraph.add_edge(2, 3, None)
+        self.assertEqual(4, self.graph.num_edges())
+        self.assertEqual([(0, 1), (1, 0), (2, 3)], digraph.edge_list())
+        self.assertEqual([0, 1, 2, 3], list(self.index.edges_active_in(0.0, 10.0)))
+
+    def test_empty_graph(self):
+        index = retworkx.digraph_edge_interval_index(retworkx.PyDiGraph(), lambda edge: edge)
+        self.assertEqual(0, len(index))
+        self.assertEqual([], list(index.edges_active_in(0.0, 1.0)))
+
+    def test_modified_graph(self):
+        self.graph.remove_edge_from_index(0)
+        with self.assertRaises(ValueError):
+            self.index.edges_active_at(1.0)
+
+    def test_reference_cycle(self):
+        class Payload:
+            pass
+
+        payload = Payload()
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from([None, payload])
+        graph.add_edge(0, 1, (0.0, 1.0))
+        # The graph references the index, which references the graph
+        graph[0] = retworkx.digraph_edge_interval_index(graph, lambda edge: edge)
+        ref = weakref.ref(payload)
+        del graph, payload
+        gc.collect()
+        self.assertIsNone(ref())
+
+    def test_universal_function(self):
+        index = retworkx.edge_interval_index(self.graph, lambda edge: edge["time"])
+        self.assertEqual([1, 2], list(index.edges_active_in(2.5, 3.5)))
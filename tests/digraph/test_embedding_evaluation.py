@@ -0,0 +1,151 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import retworkx
+import numpy as np
+
+
+class TestDiGraphEmbeddingEvaluation(unittest.TestCase):
+    def setUp(self):
+        # Node i is embedded as the sum of the unit vectors i and i + 1, so
+        # the dot product of the nodes of the path 0 -> 1 -> 2 -> 3 is 1 for
+        # neighbors in either direction and 0 otherwise
+        self.graph = retworkx.generators.directed_path_graph(4)
+        self.embeddings = np.zeros((4, 5))
+        for node in range(4):
+            self.embeddings[node, node] = 1.0
+            self.embeddings[node, node + 1] = 1.0
+
+    def test_precision_at_k(self):
+        for similarity in ["dot", "cosine"]:
+            with self.subTest(similarity=similarity):
+                # The 6 pairs of neighbors are ranked first, ties by node
+                # indices: (0, 1), (1, 0), (1, 2), (2, 1), (2, 3), (3, 2)
+                self.assertEqual(
+                    1.0,
+                    retworkx.digraph_reconstruction_precision_at_k(
+                        self.graph, self.embeddings, 1, similarity=similarity
+                    ),
+                )
+                self.assertEqual(
+                    2 / 3,
+                    retworkx.digraph_reconstruction_precision_at_k(
+                        self.graph, self.embeddings, 3, similarity=similarity
+                    ),
+                )
+                self.assertEqual(
+                    0.5,
+                    retworkx.digraph_reconstruction_precision_at_k(
+                        self.graph, self.embeddings, 6, similarity=similarity
+                    ),
+                )
+
+    def test_precision_at_k_more_than_pairs(self):
+        self.assertEqual(
+            0.25,
+            retworkx.digraph_reconstruction_precision_at_k(self.graph, self.embeddings, 100),
+        )
+
+    def test_precision_at_k_parallel(self):
+        self.assertEqual(
+            retworkx.digraph_reconstruction_precision_at_k(self.graph, self.embeddings, 5),
+            retworkx.digraph_reconstruction_precision_at_k(
+                self.graph, self.embeddings, 5, parallel_threshold=1
+            ),
+        )
+
+    def test_mean_average_precision(self):
+        # The successor of node 0 is ranked first, the successors of nodes 1
+        # and 2 are ranked after their predecessors
+        self.assertAlmostEqual(
+            2 / 3,
+            retworkx.digraph_reconstruction_mean_average_precision(self.graph, self.embeddings),
+        )
+        self.assertAlmostEqual(
+            2 / 3,
+            retworkx.digraph_reconstruction_mean_average_precision(
+                self.graph, self.embeddings, parallel_threshold=1
+            ),
+        )
+
+    def test_mean_average_precision_no_edges(self):
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(range(3))
+        self.assertEqual(
+            0.0, retworkx.digraph_reconstruction_mean_average_precision(graph, np.ones((3, 2)))
+        )
+
+    def held_out_graph(self):
+        # The edges in both directions between the neighbors of the path,
+        # except the held out edge 2 -> 3, so every negative pair has a dot
+        # product of 0
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(range(4))
+        graph.add_edges_from_no_data([(0, 1), (1, 0), (1, 2), (2, 1), (3, 2)])
+        return graph
+
+    def test_link_prediction_roc_auc(self):
+        for similarity in ["dot", "cosine"]:
+            with self.subTest(similarity=similarity):
+                self.assertEqual(
+                    1.0,
+                    retworkx.digraph_link_prediction_roc_auc(
+                        self.held_out_graph(),
+                        self.embeddings,
+                        [(2, 3)],
+                        num_negative=20,
+                        similarity=similarity,
+                        seed=42,
+                    ),
+                )
+
+    def test_link_prediction_roc_auc_ties(self):
+        res = retworkx.digraph_link_prediction_roc_auc(
+            self.held_out_graph(), np.ones((4, 2)), [(2, 3)], num_negative=10, seed=42
+        )
+        self.assertEqual(0.5, res)
+
+    def test_link_prediction_invalid_node(self):
+        with self.assertRaises(retworkx.InvalidNode):
+            retworkx.digraph_link_prediction_roc_auc(self.graph, self.embeddings, [(10, 0)])
+
+    def test_link_prediction_no_test_edges(self):
+        with self.assertRaises(ValueError):
+            retworkx.digraph_link_prediction_roc_auc(self.graph, self.embeddings, [])
+
+    def test_too_few_embeddings(self):
+        with self.assertRaises(ValueError):
+            retworkx.digraph_reconstruction_mean_average_precision(
+                self.graph, self.embeddings[:2]
+            )
+
+    def test_invalid_similarity(self):
+        with self.assertRaises(ValueError):
+            retworkx.digraph_reconstruction_precision_at_k(
+                self.graph, self.embeddings, 2, similarity="euclidean"
+            )
+
+    def test_universal_functions(self):
+        self.assertEqual(
+            0.5, retworkx.reconstruction_precision_at_k(self.graph, self.embeddings, 6)
+        )
+        self.assertAlmostEqual(
+            2 / 3, retworkx.reconstruction_mean_average_precision(self.graph, self.embeddings)
+        )
+        self.assertEqual(
+            1.0,
+            retworkx.link_prediction_roc_auc(
+                self.held_out_graph(), self.embeddings, [(2, 3)], seed=4
+            ),
+        )
@@ -0,0 +1,60 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import retworkx
+
+
+class TestGraphDiff(unittest.TestCase):
+    def test_snapshots(self):
+        first = retworkx.PyDiGraph()
+        first.add_nodes_from(["a", "b", "c"])
+        first.add_edges_from([(0, 1, 1), (1, 2, 1)])
+        second = retworkx.PyDiGraph()
+        second.add_nodes_from(["c", "b", "d"])
+        second.add_edges_from([(1, 0, 5), (2, 0, 1)])
+        diff = retworkx.graph_diff(first, second)
+        self.assertEqual([0], diff.removed_nodes)
+        self.assertEqual([2], diff.added_nodes)
+        self.assertEqual({1: 1, 2: 0}, diff.node_map)
+        self.assertEqual({}, diff.changed_nodes)
+        self.assertEqual([0], diff.removed_edges)
+        self.assertEqual([1], diff.added_edges)
+        self.assertEqual({1: 0}, diff.changed_edges)
+
+    def test_edge_direction(self):
+        first = retworkx.PyDiGraph()
+        first.add_nodes_from(["a", "b"])
+        first.add_edge(0, 1, None)
+        second = retworkx.PyDiGraph()
+        second.add_nodes_from(["a", "b"])
+        second.add_edge(1, 0, None)
+        diff = retworkx.digraph_graph_diff(first, second)
+        self.assertEqual([0], diff.removed_edges)
+        self.assertEqual([0], diff.added_edges)
+        self.assertEqual([], diff.removed_nodes)
+
+    def test_removed_node_indices(self):
+        first = retworkx.PyDiGraph()
+        first.add_nodes_from([0, 1, 2])
+        second = first.copy()
+        second.remove_node(1)
+        second.add_node(3)
+        diff = retworkx.graph_diff(first, second, node_key_fn=str)
+        self.assertEqual([1], diff.removed_nodes)
+        self.assertEqual([1], diff.added_nodes)
+        self.assertEqual({0: 0, 2: 2}, diff.node_map)
+
+    def test_invalid_type(self):
+        with self.assertRaises(TypeError):
+            retworkx.graph_diff(None, None)
@@ -0,0 +1,52 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+
+import unittest
+
+import retworkx
+
+
+class TestGraphSummary(unittest.TestCase):
+    def setUp(self):
+        self.graph = retworkx.PyDiGraph()
+        self.graph.add_nodes_from(range(6))
+        self.graph.extend_from_edge_list(
+            [(0, 1), (1, 2), (2, 0), (0, 3), (3, 4), (0, 4), (4, 5), (5, 4)]
+        )
+
+    def test_summary(self):
+        summary = retworkx.digraph_graph_summary(self.graph)
+        self.assertEqual(6, summary.node_count)
+        self.assertEqual(8, summary.edge_count)
+        self.assertAlmostEqual(4 / 15, summary.density)
+        self.assertEqual(2, summary.min_degree)
+        self.assertEqual(4, summary.max_degree)
+        self.assertAlmostEqual(8 / 3, summary.mean_degree)
+        self.assertEqual(1, summary.num_components)
+        self.assertEqual(6, summary.largest_component_size)
+        self.assertAlmostEqual(0.611111111111111, summary.average_clustering)
+        self.assertAlmostEqual(-1 / 15, summary.degree_assortativity)
+        self.assertEqual(3, summary.diameter_estimate)
+
+    def test_summary_weakly_connected_components(self):
+        graph = retworkx.PyDiGraph()
+        graph.extend_from_edge_list([(0, 1), (2, 1), (3, 4)])
+        summary = retworkx.digraph_graph_summary(graph)
+        self.assertEqual(2, summary.num_components)
+        self.assertEqual(3, summary.largest_component_size)
+        self.assertEqual(2, summary.diameter_estimate)
+
+    def test_summary_universal(self):
+        summary = retworkx.graph_summary(self.graph)
+        self.assertIsInstance(summary, retworkx.GraphSummary)
+        self.assertEqual(8, summary.edge_count)
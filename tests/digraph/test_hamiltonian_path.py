@@ -0,0 +1,85 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import retworkx
+
+
+class TestHamiltonianPath(unittest.TestCase):
+    def assertHamiltonianPath(self, graph, path):
+        path = list(path)
+        self.assertEqual(sorted(graph.node_indices()), sorted(path))
+        for source, target in zip(path, path[1:]):
+            self.assertTrue(graph.has_edge(source, target))
+
+    def test_tournament(self):
+        # Every tournament has a Hamiltonian path
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(range(8))
+        for source in range(8):
+            for target in range(source + 1, 8):
+                if (source * 3 + target * 5) % 7 < 3:
+                    graph.add_edge(source, target, None)
+                else:
+                    graph.add_edge(target, source, None)
+        path = retworkx.digraph_hamiltonian_path(graph)
+        self.assertHamiltonianPath(graph, path)
+
+    def test_direction(self):
+        graph = retworkx.generators.directed_path_graph(5)
+        self.assertEqual([0, 1, 2, 3, 4], list(retworkx.digraph_hamiltonian_path(graph)))
+        self.assertIsNone(retworkx.digraph_hamiltonian_path(graph, source=4))
+        self.assertIsNone(retworkx.digraph_hamiltonian_path(graph, target=0))
+
+    def test_source_and_target(self):
+        graph = retworkx.generators.directed_cycle_graph(5)
+        self.assertEqual(
+            [2, 3, 4, 0, 1], list(retworkx.digraph_hamiltonian_path(graph, source=2, target=1))
+        )
+        self.assertIsNone(retworkx.digraph_hamiltonian_path(graph, source=2, target=3))
+
+    def test_two_sources(self):
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(range(3))
+        graph.add_edges_from_no_data([(0, 2), (1, 2)])
+        self.assertIsNone(retworkx.digraph_hamiltonian_path(graph))
+
+    def test_two_sinks(self):
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(range(3))
+        graph.add_edges_from_no_data([(0, 1), (0, 2)])
+        self.assertIsNone(retworkx.digraph_hamiltonian_path(graph))
+
+    def test_dag(self):
+        # A DAG has a Hamiltonian path if and only if its topological order
+        # is one
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(range(4))
+        graph.add_edges_from_no_data([(0, 1), (0, 2), (1, 2), (2, 3), (1, 3)])
+        self.assertEqual([0, 1, 2, 3], list(retworkx.digraph_hamiltonian_path(graph)))
+        graph.remove_edge(1, 2)
+        self.assertIsNone(retworkx.digraph_hamiltonian_path(graph))
+
+    def test_call_limit(self):
+        graph = retworkx.generators.directed_grid_graph(7, 7, bidirectional=True)
+        with self.assertRaises(retworkx.SearchBudgetExhausted):
+            retworkx.digraph_hamiltonian_path(graph, source=1, call_limit=1000)
+
+    def test_invalid_source(self):
+        graph = retworkx.generators.directed_path_graph(3)
+        with self.assertRaises(retworkx.InvalidNode):
+            retworkx.digraph_hamiltonian_path(graph, source=3)
+
+    def test_universal(self):
+        graph = retworkx.generators.directed_path_graph(3)
+        self.assertEqual([0, 1, 2], list(retworkx.hamiltonian_path(graph)))
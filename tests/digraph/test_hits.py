@@ -0,0 +1,74 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+
+import unittest
+
+import retworkx
+
+
+class TestHits(unittest.TestCase):
+    def setUp(self):
+        self.graph = retworkx.PyDiGraph()
+        self.graph.extend_from_edge_list([(0, 1), (0, 2), (1, 2), (2, 0), (3, 2)])
+
+    def assertScoresAlmostEqual(self, expected, scores):
+        self.assertEqual(sorted(expected), sorted(scores.keys()))
+        for node, score in expected.items():
+            self.assertAlmostEqual(score, scores[node], places=5)
+
+    def test_hits(self):
+        hubs, authorities = retworkx.hits(self.graph)
+        self.assertScoresAlmostEqual({0: 0.414214, 1: 0.292893, 2: 0.0, 3: 0.292893}, hubs)
+        self.assertScoresAlmostEqual({0: 0.0, 1: 0.292893, 2: 0.707107, 3: 0.0}, authorities)
+
+    def test_hits_not_normalized(self):
+        hubs, authorities = retworkx.digraph_hits(self.graph, normalized=False)
+        self.assertScoresAlmostEqual({0: 1.0, 1: 0.707107, 2: 0.0, 3: 0.707107}, hubs)
+        self.assertScoresAlmostEqual({0: 0.0, 1: 0.414214, 2: 1.0, 3: 0.0}, authorities)
+
+    def test_hits_nstart(self):
+        hubs, authorities = retworkx.hits(self.graph, nstart={0: 1.0})
+        self.assertScoresAlmostEqual({0: 0.414214, 1: 0.292893, 2: 0.0, 3: 0.292893}, hubs)
+        self.assertScoresAlmostEqual({0: 0.0, 1: 0.292893, 2: 0.707107, 3: 0.0}, authorities)
+
+    def test_hits_weighted(self):
+        graph = retworkx.PyDiGraph()
+        graph.extend_from_weighted_edge_list([(0, 1, 3), (0, 2, 1), (1, 2, 1)])
+        hubs, authorities = retworkx.hits(graph, weight_fn=float)
+        self.assertScoresAlmostEqual({0: 0.901086, 1: 0.098914, 2: 0.0}, hubs)
+        self.assertScoresAlmostEqual({0: 0.0, 1: 0.729967, 2: 0.270033}, authorities)
+
+    def test_hits_dag(self):
+        graph = retworkx.PyDAG()
+        graph.extend_from_edge_list([(0, 2), (1, 2), (1, 3)])
+        hubs, authorities = retworkx.hits(graph, normalized=False)
+        self.assertAlmostEqual(1.0, hubs[1])
+        self.assertAlmostEqual(1.0, authorities[2])
+        self.assertEqual(0.0, hubs[2])
+        self.assertEqual(0.0, authorities[1])
+
+    def test_hits_no_edges(self):
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(range(3))
+        hubs, authorities = retworkx.hits(graph)
+        self.assertEqual({0: 0.0, 1: 0.0, 2: 0.0}, dict(hubs.items()))
+        self.assertEqual({0: 0.0, 1: 0.0, 2: 0.0}, dict(authorities.items()))
+
+    def test_hits_failed_to_converge(self):
+        with self.assertRaises(retworkx.FailedToConverge):
+            retworkx.hits(self.graph, max_iter=1)
+
+    def test_hits_empty(self):
+        hubs, authorities = retworkx.hits(retworkx.PyDiGraph())
+        self.assertEqual({}, dict(hubs.items()))
+        self.assertEqual({}, dict(authorities.items()))
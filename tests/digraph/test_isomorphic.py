@@ -348,3 +348,54 @@ class TestIsomorphic(unittest.TestCase):
             with self.subTest(id_order=id_order):
                 mapping = retworkx.digraph_vf2_mapping(g_a, g_b, id_order=id_order, subgraph=False)
                 self.assertEqual({}, next(mapping))
+
+    def test_canonical_certificate_isomorphic(self):
+        g_a = retworkx.PyDiGraph()
+        g_a.extend_from_edge_list([(0, 1), (1, 2), (2, 0), (2, 3)])
+        g_b = retworkx.PyDiGraph()
+        g_b.extend_from_edge_list([(3, 0), (1, 3), (0, 1), (1, 2)])
+        self.assertEqual(retworkx.canonical_certificate(g_a), retworkx.canonical_certificate(g_b))
+        self.assertEqual(
+            retworkx.digraph_canonical_certificate(g_a), retworkx.canonical_certificate(g_b)
+        )
+
+    def test_canonical_certificate_edge_direction(self):
+        g_a = retworkx.PyDiGraph()
+        g_a.extend_from_edge_list([(0, 1), (1, 2)])
+        g_b = retworkx.PyDiGraph()
+        g_b.extend_from_edge_list([(0, 1), (2, 1)])
+        self.assertNotEqual(
+            retworkx.canonical_certificate(g_a), retworkx.canonical_certificate(g_b)
+        )
+
+    def test_canonical_certificate_not_equal_to_undirected(self):
+        g_a = retworkx.PyDiGraph()
+        g_a.extend_from_edge_list([(0, 1)])
+        self.assertNotEqual(
+            retworkx.canonical_certificate(g_a),
+            retworkx.canonical_certificate(g_a.to_undirected()),
+        )
+
+    def test_weisfeiler_lehman_graph_hash_isomorphic(self):
+        g_a = retworkx.PyDiGraph()
+        g_a.extend_from_edge_list([(0, 1), (1, 2), (2, 0), (2, 3)])
+        g_b = retworkx.PyDiGraph()
+        g_b.extend_from_edge_list([(3, 0), (1, 3), (0, 1), (1, 2)])
+        self.assertEqual(
+            retworkx.weisfeiler_lehman_graph_hash(g_a),
+            retworkx.digraph_weisfeiler_lehman_graph_hash(g_b),
+        )
+
+    def test_weisfeiler_lehman_graph_hash_edge_direction(self):
+        g_a = retworkx.PyDiGraph()
+        g_a.extend_from_edge_list([(0, 1), (1, 2)])
+        g_b = retworkx.PyDiGraph()
+        g_b.extend_from_edge_list([(0, 1), (2, 1)])
+        self.assertNotEqual(
+            retworkx.weisfeiler_lehman_graph_hash(g_a), retworkx.weisfeiler_lehman_graph_hash(g_b)
+        )
+
+    def test_weisfeiler_lehman_graph_hash_no_iterations(self):
+        graph = retworkx.generators.directed_cycle_graph(4)
+        with self.assertRaises(ValueError):
+            retworkx.digraph_weisfeiler_lehman_graph_hash(graph, iterations=0)
@@ -0,0 +1,134 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import numpy as np
+
+import retworkx
+
+
+def to_dense(csr, size):
+    indptr, indices, data = csr
+    matrix = np.zeros((size, size))
+    for row in range(size):
+        for pos in range(indptr[row], indptr[row + 1]):
+            matrix[row, indices[pos]] += data[pos]
+    return matrix
+
+
+class TestDiGraphLaplacianMatrix(unittest.TestCase):
+    def test_out_degree(self):
+        graph = retworkx.generators.directed_path_graph(3)
+        res = retworkx.digraph_laplacian_matrix(graph)
+        self.assertIsInstance(res, np.ndarray)
+        expected = np.array([[1.0, -1.0, 0.0], [0.0, 1.0, -1.0], [0.0, 0.0, 0.0]])
+        self.assertTrue(np.array_equal(expected, res))
+
+    def test_in_degree(self):
+        graph = retworkx.generators.directed_path_graph(3)
+        res = retworkx.digraph_laplacian_matrix(graph, degree="in")
+        expected = np.array([[0.0, -1.0, 0.0], [0.0, 1.0, -1.0], [0.0, 0.0, 1.0]])
+        self.assertTrue(np.array_equal(expected, res))
+
+    def test_rows_and_columns_sum_to_zero(self):
+        graph = retworkx.generators.directed_grid_graph(4, 5)
+        graph.add_edge(19, 0, None)
+        res = retworkx.digraph_laplacian_matrix(graph)
+        self.assertTrue(np.array_equal(np.zeros(20), res.sum(axis=1)))
+        res = retworkx.digraph_laplacian_matrix(graph, degree="in")
+        self.assertTrue(np.array_equal(np.zeros(20), res.sum(axis=0)))
+
+    def test_as_undirected(self):
+        graph = retworkx.generators.directed_path_graph(3)
+        graph.add_edge(1, 0, None)
+        for degree in ["out", "in"]:
+            res = retworkx.digraph_laplacian_matrix(graph, degree=degree, as_undirected=True)
+            expected = np.array([[2.0, -2.0, 0.0], [-2.0, 3.0, -1.0], [0.0, -1.0, 1.0]])
+            self.assertTrue(np.array_equal(expected, res))
+
+    def test_weight_fn_and_parallel_edges(self):
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(range(3))
+        graph.add_edges_from([(0, 1, 1.0), (0, 1, 2.0), (1, 2, 4.0), (2, 0, 0.5)])
+        res = retworkx.digraph_laplacian_matrix(graph, weight_fn=float)
+        expected = np.array([[3.0, -3.0, 0.0], [0.0, 4.0, -4.0], [-0.5, 0.0, 0.5]])
+        self.assertTrue(np.array_equal(expected, res))
+
+    def test_self_loop(self):
+        graph = retworkx.generators.directed_path_graph(3)
+        graph.add_edge(1, 1, None)
+        for degree in ["out", "in"]:
+            without_loop = retworkx.digraph_laplacian_matrix(
+                retworkx.generators.directed_path_graph(3), degree=degree
+            )
+            res = retworkx.digraph_laplacian_matrix(graph, degree=degree)
+            self.assertTrue(np.array_equal(without_loop, res))
+
+    def test_normalized(self):
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(range(3))
+        graph.add_edges_from([(0, 1, 1.0), (0, 2, 1.0), (1, 2, 4.0)])
+        res = retworkx.digraph_laplacian_matrix(graph, weight_fn=float, normalized=True)
+        # The out degrees are 2, 4 and 0
+        expected = np.array(
+            [
+                [1.0, -1.0 / np.sqrt(8.0), 0.0],
+                [0.0, 1.0, 0.0],
+                [0.0, 0.0, 0.0],
+            ]
+        )
+        self.assertTrue(np.allclose(expected, res))
+        res = retworkx.digraph_laplacian_matrix(
+            graph, weight_fn=float, normalized=True, degree="in"
+        )
+        # The in degrees are 0, 1 and 5
+        expected = np.array(
+            [
+                [0.0, 0.0, 0.0],
+                [0.0, 1.0, -4.0 / np.sqrt(5.0)],
+                [0.0, 0.0, 1.0],
+            ]
+        )
+        self.assertTrue(np.allclose(expected, res))
+
+    def test_sparse(self):
+        graph = retworkx.generators.directed_path_graph(3)
+        indptr, indices, data = retworkx.digraph_laplacian_matrix(graph, sparse=True)
+        self.assertEqual([0, 2, 4, 5], indptr.tolist())
+        self.assertEqual([0, 1, 1, 2, 2], indices.tolist())
+        self.assertEqual([1.0, -1.0, 1.0, -1.0, 0.0], data.tolist())
+
+    def test_sparse_matches_dense(self):
+        graph = retworkx.generators.directed_grid_graph(4, 5)
+        graph.add_edge(1, 0, None)
+        graph.add_edge(0, 1, None)
+        graph.add_edge(7, 7, None)
+        graph.remove_node(3)
+        for normalized in [False, True]:
+            for degree in ["out", "in"]:
+                for as_undirected in [False, True]:
+                    kwargs = dict(normalized=normalized, degree=degree, as_undirected=as_undirected)
+                    dense = retworkx.digraph_laplacian_matrix(graph, **kwargs)
+                    csr = retworkx.digraph_laplacian_matrix(graph, sparse=True, **kwargs)
+                    self.assertTrue(np.allclose(dense, to_dense(csr, len(graph))))
+
+    def test_invalid_degree(self):
+        graph = retworkx.generators.directed_path_graph(3)
+        with self.assertRaises(ValueError):
+            retworkx.digraph_laplacian_matrix(graph, degree="total")
+
+    def test_universal(self):
+        graph = retworkx.generators.directed_path_graph(3)
+        res = retworkx.laplacian_matrix(graph, degree="in")
+        expected = np.array([[0.0, -1.0, 0.0], [0.0, 1.0, -1.0], [0.0, 0.0, 1.0]])
+        self.assertTrue(np.array_equal(expected, res))
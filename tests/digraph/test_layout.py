@@ -10,6 +10,7 @@
 # License for the specific language governing permissions and limitations
 # under the License.
 
+import math
 import unittest
 
 import retworkx
@@ -475,3 +476,203 @@ class TestSpiralLayout(LayoutTest):
             9: (-1.0, 0.2018583081028111),
         }
         self.assertLayoutEquiv(expected, res)
+
+
+class TestSpectralLayout(LayoutTest):
+    def test_spectral_layout_empty(self):
+        res = retworkx.spectral_layout(retworkx.PyDiGraph())
+        self.assertEqual({}, res)
+
+    def test_spectral_layout_direction_ignored(self):
+        graph = retworkx.generators.directed_grid_graph(3, 4)
+        res = retworkx.digraph_spectral_layout(graph, scale=1)
+        expected = retworkx.graph_spectral_layout(graph.to_undirected(), scale=1)
+        self.assertLayoutEquiv(expected, res)
+
+    def test_spectral_layout_weights(self):
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(range(4))
+        graph.add_edges_from([(0, 1, 1.0), (2, 1, 100.0), (2, 3, 1.0)])
+        res = retworkx.spectral_layout(graph, weight_fn=float)
+        self.assertLess(abs(res[1][0] - res[2][0]), abs(res[0][0] - res[1][0]) / 10)
+
+
+class TestPlanarLayout(LayoutTest):
+    def test_planar_layout_empty(self):
+        res = retworkx.planar_layout(retworkx.PyDiGraph())
+        self.assertEqual({}, res)
+
+    def test_planar_layout_k4(self):
+        graph = retworkx.generators.directed_mesh_graph(4)
+        res = retworkx.digraph_planar_layout(graph)
+        expected = {0: (0, 0), 1: (4, 0), 2: (2, 1), 3: (2, 2)}
+        self.assertLayoutEquiv(expected, res)
+
+    def test_planar_layout_scale(self):
+        graph = retworkx.generators.directed_mesh_graph(4)
+        res = retworkx.planar_layout(graph, scale=2)
+        expected = {0: (-2.0, -0.75), 1: (2.0, -0.75), 2: (0.0, 0.25), 3: (0.0, 1.25)}
+        self.assertLayoutEquiv(expected, res)
+
+    def test_planar_layout_non_planar(self):
+        with self.assertRaises(ValueError):
+            retworkx.planar_layout(retworkx.generators.directed_mesh_graph(5))
+
+
+def distance(a, b):
+    return math.hypot(a[0] - b[0], a[1] - b[1])
+
+
+class TestKamadaKawaiLayout(LayoutTest):
+    def test_kamada_kawai_layout_empty(self):
+        res = retworkx.kamada_kawai_layout(retworkx.PyDiGraph())
+        self.assertEqual({}, res)
+
+    def test_kamada_kawai_layout_direction_ignored(self):
+        graph = retworkx.generators.directed_grid_graph(3, 4)
+        res = retworkx.digraph_kamada_kawai_layout(graph, scale=1)
+        expected = retworkx.graph_kamada_kawai_layout(graph.to_undirected(), scale=1)
+        self.assertLayoutEquiv(expected, res)
+
+    def test_kamada_kawai_layout_weights(self):
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(range(3))
+        graph.add_edges_from([(1, 0, 1.0), (1, 2, 3.0)])
+        res = retworkx.kamada_kawai_layout(graph, weight_fn=float, scale=None, tol=1e-8)
+        self.assertAlmostEqual(4.0, distance(res[0], res[2]), places=5)
+
+
+class TestSugiyamaLayout(LayoutTest):
+    def assertLayered(self, dag, res):
+        self.assertEqual(set(dag.node_indices()), set(res.pos))
+        self.assertEqual(
+            sorted(dag.node_indices()), sorted(node for layer in res.layers for node in layer)
+        )
+        top = len(res.layers) - 1
+        for layer, nodes in enumerate(res.layers):
+            for node in nodes:
+                self.assertEqual(layer, res.node_layer[node])
+                self.assertAlmostEqual(top - layer, res.pos[node][1])
+            for left, right in zip(nodes, nodes[1:]):
+                self.assertGreaterEqual(res.pos[right][0] - res.pos[left][0], 1 - 1e-9)
+        for source, target in dag.edge_list():
+            self.assertLess(res.node_layer[source], res.node_layer[target])
+
+    def test_sugiyama_layout_empty(self):
+        res = retworkx.sugiyama_layout(retworkx.PyDiGraph())
+        self.assertEqual({}, res.pos)
+        self.assertEqual([], res.layers)
+        self.assertEqual(0, res.crossings)
+
+    def test_sugiyama_layout_path(self):
+        dag = retworkx.generators.directed_path_graph(4)
+        res = retworkx.sugiyama_layout(dag)
+        self.assertLayered(dag, res)
+        self.assertEqual([[0], [1], [2], [3]], res.layers)
+        expected = {0: (0, 3), 1: (0, 2), 2: (0, 1), 3: (0, 0)}
+        self.assertLayoutEquiv(expected, res.pos)
+
+    def test_sugiyama_layout_tree(self):
+        dag = retworkx.generators.directed_binomial_tree_graph(4)
+        res = retworkx.sugiyama_layout(dag)
+        self.assertLayered(dag, res)
+        self.assertEqual(0, res.crossings)
+        layers = {node: res.node_layer[node] for node in range(4)}
+        self.assertEqual({0: 0, 1: 1, 2: 1, 3: 2}, layers)
+
+    def test_sugiyama_layout_source_moved_down(self):
+        dag = retworkx.generators.directed_path_graph(4)
+        source = dag.add_node(None)
+        dag.add_edge(source, 3, None)
+        res = retworkx.sugiyama_layout(dag)
+        self.assertLayered(dag, res)
+        self.assertEqual(2, res.node_layer[source])
+        self.assertEqual({}, res.bends)
+
+    def test_sugiyama_layout_bends(self):
+        dag = retworkx.generators.directed_path_graph(4)
+        edge = dag.add_edge(0, 3, None)
+        res = retworkx.sugiyama_layout(dag)
+        self.assertLayered(dag, res)
+        self.assertEqual([edge], list(res.bends))
+        self.assertEqual([2.0, 1.0], [point[1] for point in res.bends[edge]])
+        # The long edge is routed beside the path
+        for point, node in zip(res.bends[edge], [1, 2]):
+            self.assertGreaterEqual(abs(point[0] - res.pos[node][0]), 1 - 1e-9)
+
+    def test_sugiyama_layout_crossings(self):
+        # The graph can be drawn without crossings
+        dag = retworkx.PyDiGraph()
+        dag.add_nodes_from(range(6))
+        dag.add_edges_from_no_data([(0, 3), (0, 4), (1, 2), (1, 5), (0, 5)])
+        res = retworkx.sugiyama_layout(dag)
+        self.assertLayered(dag, res)
+        self.assertEqual(0, res.crossings)
+
+    def test_sugiyama_layout_crossings_counted(self):
+        # Every order of the complete bipartite graph on 3 and 3 nodes has
+        # 9 crossings
+        dag = retworkx.PyDiGraph()
+        dag.add_nodes_from(range(6))
+        dag.add_edges_from_no_data([(i, j) for i in range(3) for j in range(3, 6)])
+        res = retworkx.sugiyama_layout(dag)
+        self.assertLayered(dag, res)
+        self.assertEqual(9, res.crossings)
+
+    def test_sugiyama_layout_fewer_crossings(self):
+        dag = retworkx.PyDiGraph()
+        dag.add_nodes_from(range(40))
+        for node in range(1, 40):
+            dag.add_edge((node * 7) % 40 % node, node, None)
+            dag.add_edge((node * 13) % 40 % node, node, None)
+        res = retworkx.sugiyama_layout(dag)
+        self.assertLayered(dag, res)
+        unswept = retworkx.sugiyama_layout(dag, max_iter=0)
+        self.assertLess(res.crossings, unswept.crossings)
+
+    def test_sugiyama_layout_scale_center(self):
+        dag = retworkx.generators.directed_path_graph(3)
+        res = retworkx.sugiyama_layout(dag, scale=2, center=(1, 1))
+        expected = {0: (1, 3), 1: (1, 1), 2: (1, -1)}
+        self.assertLayoutEquiv(expected, res.pos)
+
+    def test_sugiyama_layout_hole(self):
+        dag = retworkx.generators.directed_path_graph(4)
+        dag.remove_node(1)
+        res = retworkx.sugiyama_layout(dag)
+        self.assertLayered(dag, res)
+        self.assertEqual({0: 0, 2: 0, 3: 1}, res.node_layer)
+
+    def test_sugiyama_layout_parallel_edges(self):
+        dag = retworkx.generators.directed_path_graph(3)
+        dag.add_edge(0, 1, None)
+        res = retworkx.sugiyama_layout(dag)
+        self.assertLayered(dag, res)
+        self.assertEqual(0, res.crossings)
+
+    def test_sugiyama_layout_cycle(self):
+        dag = retworkx.generators.directed_cycle_graph(3)
+        with self.assertRaises(retworkx.DAGHasCycle):
+            retworkx.sugiyama_layout(dag)
+
+
+class TestEdgeBundling(unittest.TestCase):
+    def test_edge_bundling_empty(self):
+        self.assertEqual({}, retworkx.edge_bundling(retworkx.PyDiGraph(), {}))
+
+    def test_edge_bundling_opposite_directions(self):
+        # Edges in opposite directions are bundled like edges in the same
+        # direction
+        pos = {0: [0.0, 0.0], 1: [10.0, 0.0], 2: [0.0, 0.4], 3: [10.0, 0.4]}
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(range(4))
+        graph.add_edges_from_no_data([(0, 1), (2, 3)])
+        expected = retworkx.digraph_edge_bundling(graph, pos)
+        graph.remove_edge(2, 3)
+        edge = graph.add_edge(3, 2, None)
+        res = retworkx.digraph_edge_bundling(graph, pos)
+        self.assertEqual([pos[3], pos[2]], [res[edge][0], res[edge][-1]])
+        for point, expected_point in zip(res[edge], reversed(expected[1])):
+            self.assertAlmostEqual(expected_point[0], point[0])
+            self.assertAlmostEqual(expected_point[1], point[1])
+        self.assertLess(abs(res[0][17][1] - res[edge][17][1]), 0.4)
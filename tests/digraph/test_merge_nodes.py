@@ -0,0 +1,88 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import retworkx
+
+
+class TestMergeNodes(unittest.TestCase):
+    def setUp(self):
+        self.graph = retworkx.PyDiGraph()
+        self.graph.add_nodes_from(["a", "b", "c", "d"])
+        self.graph.add_edges_from([(0, 1, 1), (1, 2, 2), (0, 2, 3), (3, 1, 4), (3, 2, 5)])
+
+    def test_merge_nodes_drop(self):
+        res = self.graph.merge_node_set([1, 2], lambda nodes: "".join(nodes))
+        self.assertEqual(1, res)
+        self.assertEqual(["a", "bc", "d"], self.graph.nodes())
+        self.assertEqual([(0, 1, 1), (3, 1, 4)], sorted(self.graph.weighted_edge_list()))
+
+    def test_merge_nodes_keep(self):
+        self.graph.merge_node_set([1, 2], lambda nodes: nodes[0], merge_edge_policy="keep")
+        self.assertEqual(
+            [(0, 1, 1), (0, 1, 3), (1, 1, 2), (3, 1, 4), (3, 1, 5)],
+            sorted(self.graph.weighted_edge_list()),
+        )
+
+    def test_merge_nodes_aggregate(self):
+        self.graph.merge_node_set(
+            [1, 2],
+            lambda nodes: nodes[0],
+            merge_edge_policy="aggregate",
+            weight_combo_fn=lambda a, b: a + b,
+        )
+        self.assertEqual([(0, 1, 4), (1, 1, 2), (3, 1, 9)], sorted(self.graph.weighted_edge_list()))
+
+    def test_merge_nodes_keeps_direction(self):
+        self.graph.add_edge(2, 0, 6)
+        self.graph.merge_node_set([1, 2], lambda nodes: nodes[0])
+        self.assertEqual([(0, 1, 1), (1, 0, 6), (3, 1, 4)], sorted(self.graph.weighted_edge_list()))
+
+    def test_merge_nodes_check_cycle(self):
+        self.graph.check_cycle = True
+        with self.assertRaises(retworkx.DAGWouldCycle):
+            self.graph.merge_node_set([0, 2], lambda nodes: nodes[0])
+        with self.assertRaises(retworkx.DAGWouldCycle):
+            self.graph.merge_node_set([1, 2], lambda nodes: nodes[0], merge_edge_policy="keep")
+        self.assertEqual(5, len(self.graph.edges()))
+        self.graph.merge_node_set([1, 2], lambda nodes: nodes[0])
+        self.assertTrue(retworkx.is_directed_acyclic_graph(self.graph))
+
+    def test_merge_nodes_invalid(self):
+        with self.assertRaises(IndexError):
+            self.graph.merge_node_set([0, 10], lambda nodes: nodes[0])
+        with self.assertRaises(ValueError):
+            self.graph.merge_node_set([], lambda nodes: None)
+        with self.assertRaises(ValueError):
+            self.graph.merge_node_set([0, 1], lambda nodes: None, merge_edge_policy="bad")
+        self.assertEqual(4, len(self.graph))
+
+    def test_contract_edge(self):
+        res = self.graph.contract_edge(1)
+        self.assertEqual(1, res)
+        self.assertEqual(["a", "b", "d"], self.graph.nodes())
+        self.assertEqual([(0, 1, 1), (3, 1, 4)], sorted(self.graph.weighted_edge_list()))
+
+    def test_contract_edge_aggregate(self):
+        res = self.graph.contract_edge(
+            1,
+            merge_payload_fn=lambda nodes: nodes,
+            merge_edge_policy="aggregate",
+            weight_combo_fn=lambda a, b: a + b,
+        )
+        self.assertEqual(["b", "c"], self.graph[res])
+        self.assertEqual([(0, 1, 4), (1, 1, 2), (3, 1, 9)], sorted(self.graph.weighted_edge_list()))
+
+    def test_contract_edge_invalid(self):
+        with self.assertRaises(IndexError):
+            self.graph.contract_edge(10)
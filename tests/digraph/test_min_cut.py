@@ -0,0 +1,80 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+
+import unittest
+
+import retworkx
+
+
+class TestMinCut(unittest.TestCase):
+    def setUp(self):
+        # A directed cycle with a chord from 0 to 2 and an edge from 3 to 1
+        self.graph = retworkx.PyDiGraph()
+        self.graph.add_nodes_from(range(4))
+        self.graph.add_edges_from_no_data([(0, 1), (1, 2), (2, 3), (3, 0), (0, 2), (3, 1)])
+
+    def test_edge_connectivity(self):
+        self.assertEqual(retworkx.edge_connectivity(self.graph), 1)
+        self.assertEqual(retworkx.edge_connectivity(self.graph, 0, 2), 2)
+        self.assertEqual(retworkx.edge_connectivity(self.graph, 2, 0), 1)
+
+    def test_node_connectivity(self):
+        self.assertEqual(retworkx.node_connectivity(self.graph), 1)
+        self.assertEqual(retworkx.node_connectivity(self.graph, 0, 3), 1)
+        # the edge from 0 to 2 counts as one path
+        self.assertEqual(retworkx.node_connectivity(self.graph, 0, 2), 2)
+
+    def test_minimum_edge_cut(self):
+        self.assertEqual(retworkx.minimum_edge_cut(self.graph, 2, 0), [(2, 3)])
+        cut = retworkx.minimum_edge_cut(self.graph)
+        self.assertEqual(len(cut), 1)
+        graph = self.graph.copy()
+        for source, target in cut:
+            graph.remove_edge(source, target)
+        self.assertNotEqual(len(retworkx.strongly_connected_components(graph)), 1)
+
+    def test_minimum_node_cut(self):
+        self.assertEqual(list(retworkx.minimum_node_cut(self.graph, 0, 3)), [2])
+        cut = retworkx.minimum_node_cut(self.graph)
+        self.assertEqual(len(cut), 1)
+        graph = self.graph.copy()
+        graph.remove_nodes_from(cut)
+        self.assertNotEqual(len(retworkx.strongly_connected_components(graph)), 1)
+
+    def test_minimum_node_cut_adjacent(self):
+        with self.assertRaises(ValueError):
+            retworkx.minimum_node_cut(self.graph, 0, 2)
+        # there's no edge from 2 to 0
+        self.assertEqual(list(retworkx.minimum_node_cut(self.graph, 2, 0)), [3])
+
+    def test_not_strongly_connected(self):
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(range(3))
+        graph.add_edges_from_no_data([(0, 1), (1, 2)])
+        self.assertEqual(retworkx.edge_connectivity(graph), 0)
+        self.assertEqual(retworkx.node_connectivity(graph), 0)
+        self.assertEqual(len(retworkx.minimum_edge_cut(graph)), 0)
+        self.assertEqual(retworkx.edge_connectivity(graph, 0, 2), 1)
+        self.assertEqual(retworkx.edge_connectivity(graph, 2, 0), 0)
+
+    def test_complete_graph(self):
+        graph = retworkx.generators.directed_mesh_graph(4)
+        self.assertEqual(retworkx.edge_connectivity(graph), 3)
+        self.assertEqual(retworkx.node_connectivity(graph), 3)
+        self.assertEqual(len(retworkx.minimum_node_cut(graph)), 3)
+
+    def test_invalid_source_target(self):
+        with self.assertRaises(ValueError):
+            retworkx.minimum_node_cut(self.graph, target=1)
+        with self.assertRaises(retworkx.InvalidNode):
+            retworkx.node_connectivity(self.graph, 7, 0)
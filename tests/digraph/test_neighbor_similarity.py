@@ -0,0 +1,66 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import retworkx
+
+
+class TestCommonNeighbors(unittest.TestCase):
+    def setUp(self):
+        self.graph = retworkx.PyDiGraph()
+        self.graph.add_nodes_from(range(5))
+        self.graph.add_edges_from_no_data([(0, 2), (0, 3), (1, 2), (1, 3), (4, 0), (4, 1), (3, 1)])
+
+    def test_common_neighbors(self):
+        self.assertEqual([2, 3], list(retworkx.digraph_common_neighbors(self.graph, 0, 1)))
+
+    def test_common_neighbors_are_successors(self):
+        self.assertEqual([], list(retworkx.digraph_common_neighbors(self.graph, 2, 3)))
+
+    def test_common_neighbors_predecessor(self):
+        self.assertEqual([1], list(retworkx.digraph_common_neighbors(self.graph, 4, 3)))
+
+    def test_common_neighbors_same_node(self):
+        self.assertEqual([0, 1], list(retworkx.digraph_common_neighbors(self.graph, 4, 4)))
+
+    def test_common_neighbors_invalid_node(self):
+        with self.assertRaises(retworkx.InvalidNode):
+            retworkx.digraph_common_neighbors(self.graph, 10, 0)
+
+    def test_common_neighbors_universal(self):
+        self.assertEqual([2, 3], list(retworkx.common_neighbors(self.graph, 0, 1)))
+
+
+class TestJaccardSimilarity(unittest.TestCase):
+    def setUp(self):
+        self.graph = retworkx.PyDiGraph()
+        self.graph.add_nodes_from(range(5))
+        self.graph.add_edges_from_no_data([(0, 2), (0, 3), (1, 3), (1, 4), (2, 0), (3, 3)])
+
+    def test_jaccard_similarity(self):
+        res = retworkx.digraph_jaccard_similarity(self.graph, [(0, 1), (2, 3), (3, 4), (0, 0)])
+        self.assertEqual([1 / 3, 0.0, 0.0, 1.0], res)
+
+    def test_jaccard_similarity_parallel(self):
+        pairs = [(a, b) for a in range(5) for b in range(5)]
+        self.assertEqual(
+            retworkx.digraph_jaccard_similarity(self.graph, pairs),
+            retworkx.digraph_jaccard_similarity(self.graph, pairs, parallel_threshold=1),
+        )
+
+    def test_jaccard_similarity_invalid_node(self):
+        with self.assertRaises(retworkx.InvalidNode):
+            retworkx.digraph_jaccard_similarity(self.graph, [(0, 5)])
+
+    def test_jaccard_similarity_universal(self):
+        self.assertEqual([1 / 3], retworkx.jaccard_similarity(self.graph, [(0, 1)]))
@@ -0,0 +1,34 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+
+import unittest
+
+import retworkx
+
+
+class TestApproximateNeighborhoodFunction(unittest.TestCase):
+    def test_directed_path_graph(self):
+        graph = retworkx.generators.directed_path_graph(4)
+        res = retworkx.approximate_neighborhood_function(graph, seed=42)
+        self.assertEqual(4, len(res.values))
+        for estimate, exact in zip(res.values, [4, 7, 9, 10]):
+            self.assertAlmostEqual(exact, estimate, delta=0.5)
+        self.assertAlmostEqual(10 / 6, res.average_distance(), delta=0.1)
+
+    def test_directed_cycle_graph(self):
+        graph = retworkx.generators.directed_cycle_graph(100)
+        res = retworkx.digraph_approximate_neighborhood_function(graph, precision=12, seed=42)
+        self.assertEqual(100, len(res.values))
+        self.assertAlmostEqual(100 * 100, res.values[-1], delta=0.02 * 100 * 100)
+        self.assertAlmostEqual(50.0, res.average_distance(), delta=2.5)
+        self.assertAlmostEqual(89.1, res.effective_diameter(0.9), delta=4.5)
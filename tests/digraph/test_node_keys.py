@@ -0,0 +1,177 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import copy
+import pickle
+import unittest
+
+import retworkx
+
+
+class TestNodeKeys(unittest.TestCase):
+    def setUp(self):
+        self.graph = retworkx.PyDiGraph()
+        for key in ["a", "b", "c", "d"]:
+            self.graph.set_node_key(self.graph.add_node(key.upper()), key)
+        self.graph.add_edges_from_no_data([(0, 1), (1, 2), (2, 3)])
+
+    def test_set_node_key(self):
+        self.assertEqual(self.graph.node_key(2), "c")
+        self.assertEqual(self.graph.find_node_by_key("c"), 2)
+        self.assertEqual(self.graph.node_keys(), {"a": 0, "b": 1, "c": 2, "d": 3})
+
+    def test_no_key(self):
+        node = self.graph.add_node("E")
+        self.assertIsNone(self.graph.node_key(node))
+        self.assertIsNone(self.graph.find_node_by_key("e"))
+        self.assertIsNone(retworkx.PyDiGraph().find_node_by_key("e"))
+        self.assertEqual(retworkx.PyDiGraph().node_keys(), {})
+
+    def test_replace_key(self):
+        self.graph.set_node_key(0, ("a", 1))
+        self.assertEqual(self.graph.node_key(0), ("a", 1))
+        self.assertIsNone(self.graph.find_node_by_key("a"))
+        self.assertEqual(self.graph.find_node_by_key(("a", 1)), 0)
+        self.graph.set_node_key(0, ("a", 1))
+        self.assertEqual(len(self.graph.node_keys()), 4)
+
+    def test_duplicate_key(self):
+        with self.assertRaises(ValueError):
+            self.graph.set_node_key(0, "b")
+        self.assertEqual(self.graph.node_key(0), "a")
+
+    def test_unhashable_key(self):
+        with self.assertRaises(TypeError):
+            self.graph.set_node_key(0, ["a"])
+        with self.assertRaises(TypeError):
+            self.graph.find_node_by_key(["a"])
+        self.assertEqual(self.graph.node_key(0), "a")
+
+    def test_invalid_node(self):
+        with self.assertRaises(IndexError):
+            self.graph.set_node_key(42, "z")
+
+    def test_remove_node_key(self):
+        self.assertEqual(self.graph.remove_node_key(1), "b")
+        self.assertIsNone(self.graph.remove_node_key(1))
+        self.assertIsNone(self.graph.find_node_by_key("b"))
+        self.graph.set_node_key(2, "b")
+        self.assertEqual(self.graph.find_node_by_key("b"), 2)
+
+    def test_node_indices_by_keys(self):
+        res = self.graph.node_indices_by_keys(["d", "a"])
+        self.assertIsInstance(res, retworkx.NodeIndices)
+        self.assertEqual(res, [3, 0])
+        self.assertEqual(self.graph.node_indices_by_keys(k for k in "cb"), [2, 1])
+        with self.assertRaises(KeyError):
+            self.graph.node_indices_by_keys(["a", "z"])
+
+    def test_keys_with_algorithm(self):
+        source, target = self.graph.node_indices_by_keys(["a", "d"])
+        path = retworkx.dijkstra_shortest_paths(self.graph, source, target)[target]
+        self.assertEqual([self.graph.node_key(node) for node in path], ["a", "b", "c", "d"])
+
+    def test_remove_node_with_reused_index(self):
+        self.graph.remove_node(1)
+        self.assertIsNone(self.graph.find_node_by_key("b"))
+        node = self.graph.add_node("E")
+        self.assertEqual(node, 1)
+        self.assertIsNone(self.graph.node_key(node))
+        self.graph.set_node_key(node, "b")
+        self.assertEqual(self.graph.find_node_by_key("b"), 1)
+
+    def test_remove_nodes_from(self):
+        self.graph.remove_nodes_from([0, 2])
+        self.assertEqual(self.graph.node_keys(), {"b": 1, "d": 3})
+
+    def test_delitem(self):
+        del self.graph[3]
+        self.assertEqual(self.graph.node_keys(), {"a": 0, "b": 1, "c": 2})
+
+    def test_contract_nodes(self):
+        node = self.graph.contract_nodes([1, 2], "BC")
+        self.assertEqual(self.graph.node_keys(), {"a": 0, "d": 3})
+        self.assertIsNone(self.graph.node_key(node))
+
+    def test_edge_subgraph(self):
+        subgraph = self.graph.edge_subgraph([(0, 1)])
+        self.assertEqual(subgraph.node_keys(), {"a": 0, "b": 1})
+        self.assertEqual(len(self.graph.node_keys()), 4)
+
+    def test_copy(self):
+        for graph_copy in (self.graph.copy(), copy.copy(self.graph), copy.deepcopy(self.graph)):
+            graph_copy.remove_node(0)
+            graph_copy.set_node_key(1, "z")
+            self.assertEqual(graph_copy.node_keys(), {"z": 1, "c": 2, "d": 3})
+            self.assertEqual(self.graph.node_keys(), {"a": 0, "b": 1, "c": 2, "d": 3})
+
+    def test_pickle(self):
+        self.graph.remove_node(1)
+        graph = pickle.loads(pickle.dumps(self.graph))
+        self.assertEqual(graph.node_keys(), {"a": 0, "c": 2, "d": 3})
+        self.assertEqual(graph.node_key(2), "c")
+
+    def test_new_graphs_have_no_keys(self):
+        self.assertEqual(self.graph.subgraph([0, 1]).node_keys(), {})
+        self.assertEqual(self.graph.to_undirected().node_keys(), {})
+
+    def test_rewrite_removes_keys(self):
+        pattern = retworkx.PyDiGraph()
+        pattern.add_node("D")
+        replacement = retworkx.PyDiGraph()
+        replacement.add_node("E")
+        self.graph.remove_edge(2, 3)
+        retworkx.digraph_rewrite(
+            self.graph, pattern, replacement, {}, node_matcher=lambda a, b: a == b
+        )
+        self.assertEqual(self.graph.node_keys(), {"a": 0, "b": 1, "c": 2})
+        self.assertEqual(self.graph[3], "E")
+        self.assertIsNone(self.graph.node_key(3))
+
+    def test_remove_node_retain_edges(self):
+        self.graph.remove_node_retain_edges(1)
+        self.assertEqual(self.graph.node_keys(), {"a": 0, "c": 2, "d": 3})
+
+    def test_merge_nodes(self):
+        self.graph[1] = "C"
+        self.graph.merge_nodes(1, 2)
+        self.assertEqual(self.graph.node_keys(), {"a": 0, "c": 2, "d": 3})
+
+    def test_substitute_node_with_subgraph(self):
+        subgraph = retworkx.generators.directed_path_graph(2)
+        self.graph.substitute_node_with_subgraph(1, subgraph, lambda *_: 0)
+        self.assertEqual(self.graph.node_keys(), {"a": 0, "c": 2, "d": 3})
+        self.assertIsNone(self.graph.find_node_by_key("b"))
+
+    def test_substitute_node_with_empty_subgraph(self):
+        self.graph.substitute_node_with_subgraph(1, retworkx.PyDiGraph(), lambda *_: 0)
+        self.assertEqual(self.graph.node_keys(), {"a": 0, "c": 2, "d": 3})
+
+    def test_rewrite_rollback_keeps_keys(self):
+        self.graph.check_cycle = True
+        pattern = retworkx.PyDiGraph()
+        pattern.add_nodes_from(["C", "D"])
+        pattern.add_edge(0, 1, None)
+        replacement = retworkx.PyDiGraph()
+        replacement.add_nodes_from(["C", "E"])
+        replacement.add_edge(0, 1, None)
+        replacement.add_edge(1, 0, None)
+        with self.assertRaises(retworkx.DAGWouldCycle):
+            retworkx.digraph_rewrite(
+                self.graph,
+                pattern,
+                replacement,
+                {0: 0},
+                node_matcher=lambda a, b: a == b,
+                max_applications=1,
+            )
+        self.assertEqual(self.graph.node_keys(), {"a": 0, "b": 1, "c": 2, "d": 3})
@@ -261,6 +261,50 @@ class TestNodes(unittest.TestCase):
         ]
         self.assertEqual(expected, res)
 
+    def test_filter_nodes(self):
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(range(6))
+        graph.add_edges_from_no_data([(0, 1), (1, 2), (2, 3), (3, 4), (4, 5)])
+        res = graph.filter_nodes(lambda node: node % 2 == 0)
+        self.assertEqual([1, 3, 5], res)
+        self.assertEqual([0, 2, 4], graph.node_indices())
+        self.assertEqual([], graph.edge_list())
+
+    def test_filter_nodes_exception(self):
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(range(3))
+
+        def predicate(node):
+            if node == 2:
+                raise ValueError("bad node")
+            return False
+
+        with self.assertRaises(ValueError):
+            graph.filter_nodes(predicate)
+        self.assertEqual([0, 1, 2], graph.node_indices())
+
+    def test_compact(self):
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(["a", "b", "c", "d", "e"])
+        graph.add_edges_from([(0, 1, "ab"), (1, 2, "bc"), (2, 3, "cd"), (3, 4, "de")])
+        graph.set_node_key(4, "e")
+        graph.remove_nodes_from([0, 2])
+        res = graph.compact()
+        self.assertEqual({1: 0, 3: 1, 4: 2}, dict(res))
+        self.assertEqual([0, 1, 2], graph.node_indices())
+        self.assertEqual(["b", "d", "e"], graph.nodes())
+        self.assertEqual([(1, 2, "de")], graph.weighted_edge_list())
+        self.assertEqual([0], graph.edge_indices())
+        self.assertEqual(2, graph.find_node_by_key("e"))
+        self.assertEqual(3, graph.add_node("f"))
+
+    def test_compact_no_holes(self):
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(["a", "b"])
+        graph.add_edge(0, 1, None)
+        self.assertEqual({0: 0, 1: 1}, dict(graph.compact()))
+        self.assertEqual([(0, 1)], graph.edge_list())
+
     def test_get_node_data(self):
         dag = retworkx.PyDAG()
         node_a = dag.add_node("a")
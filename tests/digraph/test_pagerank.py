@@ -0,0 +1,90 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+
+import unittest
+
+import retworkx
+
+
+class TestPageRank(unittest.TestCase):
+    def setUp(self):
+        self.graph = retworkx.PyDiGraph()
+        self.graph.extend_from_edge_list([(0, 1), (0, 2), (1, 2), (2, 0), (3, 2)])
+
+    def assertRanksAlmostEqual(self, expected, ranks):
+        self.assertEqual(sorted(expected), sorted(ranks.keys()))
+        for node, rank in expected.items():
+            self.assertAlmostEqual(rank, ranks[node], places=5)
+
+    def test_pagerank(self):
+        ranks = retworkx.pagerank(self.graph)
+        expected = {0: 0.372526, 1: 0.195824, 2: 0.39415, 3: 0.0375}
+        self.assertRanksAlmostEqual(expected, ranks)
+        self.assertAlmostEqual(1.0, sum(ranks.values()))
+
+    def test_pagerank_personalization(self):
+        ranks = retworkx.digraph_pagerank(self.graph, personalization={0: 1, 3: 1})
+        expected = {0: 0.389485, 1: 0.165531, 2: 0.369984, 3: 0.075}
+        self.assertRanksAlmostEqual(expected, ranks)
+
+    def test_pagerank_weighted(self):
+        graph = retworkx.PyDiGraph()
+        graph.extend_from_weighted_edge_list([(0, 1, 3), (0, 2, 1), (1, 0, 1), (2, 0, 1)])
+        ranks = retworkx.pagerank(graph, weight_fn=float)
+        expected = {0: 0.486486, 1: 0.360136, 2: 0.153379}
+        self.assertRanksAlmostEqual(expected, ranks)
+
+    def test_pagerank_dangling(self):
+        graph = retworkx.PyDiGraph()
+        graph.extend_from_edge_list([(0, 1), (1, 2)])
+        ranks = retworkx.pagerank(graph)
+        expected = {0: 0.184417, 1: 0.341172, 2: 0.474412}
+        self.assertRanksAlmostEqual(expected, ranks)
+        ranks = retworkx.pagerank(graph, dangling={0: 1})
+        expected = {0: 1 / 3, 1: 1 / 3, 2: 1 / 3}
+        self.assertRanksAlmostEqual(expected, ranks)
+
+    def test_pagerank_zero_weight_edges_are_dangling(self):
+        graph = retworkx.PyDiGraph()
+        graph.extend_from_weighted_edge_list([(0, 1, 1), (1, 2, 1), (2, 0, 0)])
+        ranks = retworkx.pagerank(graph, weight_fn=float)
+        expected = {0: 0.184417, 1: 0.341172, 2: 0.474412}
+        self.assertRanksAlmostEqual(expected, ranks)
+
+    def test_pagerank_nstart(self):
+        ranks = retworkx.pagerank(self.graph, nstart={3: 1.0}, tol=1e-10)
+        expected = {0: 0.372526, 1: 0.195824, 2: 0.39415, 3: 0.0375}
+        self.assertRanksAlmostEqual(expected, ranks)
+
+    def test_pagerank_removed_nodes(self):
+        self.graph.remove_node(1)
+        ranks = retworkx.pagerank(self.graph)
+        self.assertEqual([0, 2, 3], sorted(ranks.keys()))
+        self.assertAlmostEqual(1.0, sum(ranks.values()))
+
+    def test_pagerank_failed_to_converge(self):
+        with self.assertRaises(retworkx.FailedToConverge):
+            retworkx.pagerank(self.graph, max_iter=2)
+
+    def test_pagerank_invalid_personalization(self):
+        with self.assertRaises(ValueError):
+            retworkx.pagerank(self.graph, personalization={0: 0})
+        with self.assertRaises(ValueError):
+            retworkx.pagerank(self.graph, personalization={0: -1, 1: 2})
+
+    def test_pagerank_invalid_alpha(self):
+        with self.assertRaises(ValueError):
+            retworkx.pagerank(self.graph, alpha=1.5)
+
+    def test_pagerank_empty(self):
+        self.assertEqual({}, retworkx.pagerank(retworkx.PyDiGraph()))
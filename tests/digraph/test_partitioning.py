@@ -0,0 +1,78 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import retworkx
+
+
+def two_cliques():
+    # Two complete digraphs of 4 nodes joined by an edge from 3 to 4
+    graph = retworkx.PyDiGraph()
+    graph.add_nodes_from(range(8))
+    for first in (0, 4):
+        for i in range(first, first + 4):
+            for j in range(i + 1, first + 4):
+                graph.add_edge(i, j, 1.0)
+    graph.add_edge(3, 4, 1.0)
+    return graph
+
+
+class TestDiGraphKernighanLinBisection(unittest.TestCase):
+    def test_two_cliques(self):
+        graph = two_cliques()
+        for seed in range(10):
+            res = retworkx.digraph_kernighan_lin_bisection(graph, seed=seed)
+            self.assertIsInstance(res, retworkx.GraphPartitioning)
+            self.assertEqual([[0, 1, 2, 3], [4, 5, 6, 7]], sorted(res.parts))
+            self.assertEqual(1.0, res.cut_weight)
+
+    def test_opposite_edges_are_added_up(self):
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(range(4))
+        graph.add_edges_from([(0, 1, 3.0), (1, 0, 3.0), (1, 2, 5.0), (2, 3, 3.0), (3, 2, 3.0)])
+        res = retworkx.digraph_kernighan_lin_bisection(graph, weight_fn=float, seed=42)
+        self.assertEqual([[0, 1], [2, 3]], sorted(res.parts))
+        self.assertEqual(5.0, res.cut_weight)
+
+    def test_empty(self):
+        res = retworkx.digraph_kernighan_lin_bisection(retworkx.PyDiGraph())
+        self.assertEqual([[], []], res.parts)
+        self.assertEqual(0.0, res.cut_weight)
+
+
+class TestDiGraphMultilevelPartition(unittest.TestCase):
+    def test_grid(self):
+        graph = retworkx.generators.directed_grid_graph(16, 16)
+        res = retworkx.digraph_multilevel_partition(graph, 4, seed=42)
+        self.assertEqual(4, len(res))
+        self.assertEqual(list(range(256)), sorted(node for part in res.parts for node in part))
+        for part in res.parts:
+            self.assertLessEqual(len(part), 65)
+        assignment = res.assignment
+        cut = sum(
+            1.0 for source, target in graph.edge_list() if assignment[source] != assignment[target]
+        )
+        self.assertEqual(cut, res.cut_weight)
+        self.assertLess(res.cut_weight, 100)
+
+    def test_two_cliques(self):
+        res = retworkx.digraph_multilevel_partition(two_cliques(), 2, seed=42)
+        self.assertEqual([[0, 1, 2, 3], [4, 5, 6, 7]], sorted(res.parts))
+        self.assertEqual(1.0, res.cut_weight)
+
+    def test_invalid_arguments(self):
+        graph = retworkx.generators.directed_path_graph(3)
+        with self.assertRaises(ValueError):
+            retworkx.digraph_multilevel_partition(graph, 0)
+        with self.assertRaises(ValueError):
+            retworkx.digraph_multilevel_partition(graph, 2, imbalance=-0.5)
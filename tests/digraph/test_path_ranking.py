@@ -0,0 +1,77 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import retworkx
+
+
+class TestDiGraphPathRanking(unittest.TestCase):
+    def setUp(self):
+        # Two routes from 0 to 4 with a detour on the first one
+        self.graph = retworkx.PyDiGraph()
+        self.graph.add_nodes_from(["a", "b", "c", "d", "e", "f"])
+        self.graph.add_edges_from(
+            [(0, 1, 1), (1, 2, 1), (2, 4, 1), (1, 3, 1.5), (3, 4, 1.5), (0, 5, 2), (5, 4, 2)]
+        )
+        self.paths = [[0, 1, 2, 4], [0, 1, 3, 4], [0, 5, 4]]
+
+    def test_rank_paths(self):
+        res = retworkx.digraph_rank_paths(self.graph, self.paths, weight_fn=float)
+        self.assertEqual([(0, 3), (1, 4), (2, 4)], res)
+
+    def test_rank_paths_overlap_penalty(self):
+        res = retworkx.digraph_rank_paths(
+            self.graph, self.paths, weight_fn=float, overlap_penalty=2
+        )
+        self.assertEqual([0, 2, 1], [position for position, _ in res])
+        self.assertAlmostEqual(4.4, res[2][1])
+
+    def test_rank_paths_opposite_edges(self):
+        self.graph.add_edges_from([(4, 2, 1), (2, 1, 1), (1, 0, 1)])
+        paths = [[0, 1, 2, 4], [4, 2, 1, 0]]
+        res = retworkx.digraph_rank_paths(self.graph, paths, overlap_penalty=1)
+        self.assertEqual([(0, 3), (1, 3)], res)
+
+    def test_rank_paths_node_filter(self):
+        res = retworkx.digraph_rank_paths(
+            self.graph, self.paths, weight_fn=float, node_filter=lambda node: node != "c"
+        )
+        self.assertEqual([(1, 4), (2, 4)], res)
+
+    def test_rank_paths_max_weight(self):
+        res = retworkx.digraph_rank_paths(self.graph, self.paths, weight_fn=float, max_weight=3.5)
+        self.assertEqual([(0, 3)], res)
+
+    def test_rank_paths_against_edges(self):
+        with self.assertRaises(ValueError):
+            retworkx.digraph_rank_paths(self.graph, [[4, 2, 1, 0]])
+
+    def test_rank_paths_invalid_node(self):
+        with self.assertRaises(retworkx.InvalidNode):
+            retworkx.digraph_rank_paths(self.graph, [[0, 1, 10]])
+
+    def test_diverse_paths(self):
+        res = retworkx.digraph_diverse_paths(self.graph, self.paths, 2, weight_fn=float)
+        self.assertEqual([0, 2], res)
+
+    def test_diverse_paths_node_filter(self):
+        res = retworkx.digraph_diverse_paths(
+            self.graph, self.paths, 2, weight_fn=float, node_filter=lambda node: node != "f"
+        )
+        self.assertEqual([0, 1], res)
+
+    def test_universal(self):
+        res = retworkx.rank_paths(self.graph, self.paths, weight_fn=float, overlap_penalty=2)
+        self.assertEqual([0, 2, 1], [position for position, _ in res])
+        res = retworkx.diverse_paths(self.graph, self.paths, 2, weight_fn=float)
+        self.assertEqual([0, 2], res)
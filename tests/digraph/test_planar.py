@@ -0,0 +1,54 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import retworkx
+
+
+class TestPlanar(unittest.TestCase):
+    def test_empty(self):
+        graph = retworkx.PyDiGraph()
+        self.assertTrue(retworkx.digraph_is_planar(graph))
+        self.assertEqual({}, retworkx.digraph_check_planarity(graph).embedding)
+
+    def test_direction_ignored(self):
+        graph = retworkx.generators.directed_mesh_graph(4)
+        self.assertTrue(retworkx.digraph_is_planar(graph))
+        result = retworkx.digraph_check_planarity(graph)
+        self.assertTrue(result)
+        for node, neighbors in result.embedding.items():
+            self.assertEqual(set(range(4)) - {node}, set(neighbors))
+
+    def test_k5(self):
+        graph = retworkx.generators.directed_mesh_graph(5)
+        self.assertFalse(retworkx.digraph_is_planar(graph))
+        result = retworkx.digraph_check_planarity(graph)
+        self.assertFalse(result)
+        self.assertIsNone(result.embedding)
+        self.assertEqual("K5", result.kuratowski_type)
+        self.assertEqual(10, len(result.kuratowski_edges))
+        for source, target in result.kuratowski_edges:
+            self.assertTrue(graph.has_edge(source, target))
+
+    def test_k33(self):
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(range(6))
+        graph.add_edges_from_no_data([(i, j) for i in range(3) for j in range(3, 6)])
+        result = retworkx.digraph_check_planarity(graph)
+        self.assertEqual("K3,3", result.kuratowski_type)
+        self.assertEqual(9, len(result.kuratowski_edges))
+
+    def test_universal(self):
+        graph = retworkx.generators.directed_grid_graph(4, 4)
+        self.assertTrue(retworkx.is_planar(graph))
+        self.assertTrue(retworkx.check_planarity(graph).is_planar)
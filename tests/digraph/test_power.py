@@ -0,0 +1,53 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+
+import unittest
+
+import retworkx
+
+
+class TestPower(unittest.TestCase):
+    def test_path_square(self):
+        graph = retworkx.generators.directed_path_graph(5)
+        power_graph = retworkx.digraph_power(graph, 2)
+        expected = [(0, 1), (0, 2), (1, 2), (1, 3), (2, 3), (2, 4), (3, 4)]
+        self.assertEqual(expected, sorted(power_graph.edge_list()))
+
+    def test_follows_edge_directions(self):
+        graph = retworkx.PyDiGraph()
+        graph.extend_from_edge_list([(0, 1), (2, 1), (1, 3)])
+        power_graph = retworkx.power(graph, 2)
+        expected = [(0, 1), (0, 3), (1, 3), (2, 1), (2, 3)]
+        self.assertEqual(expected, sorted(power_graph.edge_list()))
+
+    def test_cycle_no_self_loops(self):
+        graph = retworkx.generators.directed_cycle_graph(3)
+        power_graph = retworkx.digraph_power(graph, 3)
+        expected = [(0, 1), (0, 2), (1, 0), (1, 2), (2, 0), (2, 1)]
+        self.assertEqual(expected, sorted(power_graph.edge_list()))
+
+    def test_keeps_nodes(self):
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(["a", "b", "c", "d"])
+        graph.add_edges_from([(0, 1, "x"), (1, 3, "y")])
+        graph.remove_node(2)
+        power_graph = retworkx.digraph_power(graph, 2)
+        self.assertEqual(graph.node_indexes(), power_graph.node_indexes())
+        self.assertEqual(["a", "b", "d"], power_graph.nodes())
+        self.assertEqual([(0, 1), (0, 3), (1, 3)], sorted(power_graph.edge_list()))
+        self.assertEqual([None, None, None], power_graph.edges())
+
+    def test_invalid_k(self):
+        graph = retworkx.generators.directed_path_graph(3)
+        with self.assertRaises(ValueError):
+            retworkx.digraph_power(graph, 0)
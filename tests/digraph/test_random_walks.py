@@ -0,0 +1,98 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import numpy as np
+
+import retworkx
+
+
+class TestDiGraphRandomWalks(unittest.TestCase):
+    def test_walks_follow_edges(self):
+        graph = retworkx.generators.directed_grid_graph(4, 4, bidirectional=True)
+        walks = retworkx.digraph_random_walks(graph, walk_length=10, num_walks=3, seed=42)
+        self.assertIsInstance(walks, np.ndarray)
+        self.assertEqual(np.int64, walks.dtype)
+        self.assertEqual((16 * 3 * 10,), walks.shape)
+        walks = walks.reshape(-1, 10)
+        self.assertEqual(list(range(16)) * 3, walks[:, 0].tolist())
+        for walk in walks.tolist():
+            for source, target in zip(walk, walk[1:]):
+                self.assertTrue(graph.has_edge(source, target))
+
+    def test_follows_edge_direction(self):
+        graph = retworkx.generators.directed_cycle_graph(5)
+        walks = retworkx.digraph_random_walks(
+            graph, start_nodes=[2], walk_length=7, num_walks=1, seed=42
+        )
+        self.assertEqual([2, 3, 4, 0, 1, 2, 3], walks.tolist())
+
+    def test_dead_end_padding(self):
+        graph = retworkx.generators.directed_path_graph(3)
+        walks = retworkx.digraph_random_walks(graph, walk_length=4, num_walks=1, seed=42)
+        self.assertEqual([0, 1, 2, -1, 1, 2, -1, -1, 2, -1, -1, -1], walks.tolist())
+
+    def test_seed(self):
+        graph = retworkx.generators.directed_grid_graph(10, 10, bidirectional=True)
+        first = retworkx.digraph_random_walks(graph, walk_length=20, q=0.5, seed=1234)
+        second = retworkx.digraph_random_walks(graph, walk_length=20, q=0.5, seed=1234)
+        self.assertTrue(np.array_equal(first, second))
+
+    def test_parallel_same_walks(self):
+        graph = retworkx.generators.directed_grid_graph(10, 10, bidirectional=True)
+        for p, q in [(1.0, 1.0), (0.5, 2.0)]:
+            serial = retworkx.digraph_random_walks(
+                graph, walk_length=20, p=p, q=q, seed=42, parallel_threshold=100000
+            )
+            parallel = retworkx.digraph_random_walks(
+                graph, walk_length=20, p=p, q=q, seed=42, parallel_threshold=1
+            )
+            self.assertTrue(np.array_equal(serial, parallel))
+
+    def test_weight_fn(self):
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(range(3))
+        graph.add_edges_from([(0, 1, 1.0), (1, 0, 1.0), (0, 2, 0.0)])
+        walks = retworkx.digraph_random_walks(
+            graph, start_nodes=[0], walk_length=10, num_walks=20, weight_fn=float, seed=42
+        )
+        self.assertEqual({0, 1}, set(walks.tolist()))
+
+    def test_in_out_parameter(self):
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(range(4))
+        graph.extend_from_edge_list([(0, 1), (1, 0), (0, 2), (1, 2), (1, 3), (3, 0)])
+        # From 1 the walk goes back to 0 with a bias of 1 / p, to 2, a
+        # successor of 0, with a bias of 1 and to 3 with a bias of 1 / q,
+        # even though there's an edge from 3 to 0
+        walks = retworkx.digraph_random_walks(
+            graph, start_nodes=[0], walk_length=3, num_walks=4000, p=4.0, q=0.25, seed=42
+        ).reshape(-1, 3)
+        walks = walks[walks[:, 1] == 1]
+        self.assertAlmostEqual(0.05, np.mean(walks[:, 2] == 0), delta=0.03)
+        self.assertAlmostEqual(0.19, np.mean(walks[:, 2] == 2), delta=0.05)
+        self.assertAlmostEqual(0.76, np.mean(walks[:, 2] == 3), delta=0.05)
+
+    def test_invalid_parameters(self):
+        graph = retworkx.generators.directed_path_graph(3)
+        with self.assertRaises(ValueError):
+            retworkx.digraph_random_walks(graph, p=-1.0)
+        with self.assertRaises(ValueError):
+            retworkx.digraph_random_walks(graph, q=0.0)
+        with self.assertRaises(retworkx.InvalidNode):
+            retworkx.digraph_random_walks(graph, start_nodes=[5])
+
+    def test_universal(self):
+        graph = retworkx.generators.directed_path_graph(2)
+        walks = retworkx.random_walks(graph, walk_length=3, num_walks=1, seed=42)
+        self.assertEqual([0, 1, -1, 1, -1, -1], walks.tolist())
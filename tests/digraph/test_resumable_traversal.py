@@ -0,0 +1,134 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import copy
+import itertools
+import unittest
+
+import retworkx
+
+
+class TestDFSTraversal(unittest.TestCase):
+    def setUp(self):
+        self.graph = retworkx.PyDiGraph()
+        self.graph.extend_from_edge_list([(0, 1), (1, 2), (0, 3), (3, 4), (5, 0)])
+
+    def test_dfs_traversal(self):
+        traversal = retworkx.digraph_dfs_traversal(self.graph, 0)
+        self.assertIsInstance(traversal, retworkx.DFSTraversal)
+        self.assertIs(traversal, iter(traversal))
+        self.assertEqual([0, 1, 2, 3, 4], list(traversal))
+        self.assertEqual([], list(traversal))
+
+    def test_dfs_traversal_follows_direction(self):
+        self.assertEqual([3, 4], list(retworkx.digraph_dfs_traversal(self.graph, 3)))
+
+    def test_dfs_traversal_all_components(self):
+        self.graph.add_node(None)
+        self.assertEqual([0, 1, 2, 3, 4, 5, 6], list(retworkx.digraph_dfs_traversal(self.graph)))
+
+    def test_dfs_traversal_resume(self):
+        traversal = retworkx.digraph_dfs_traversal(self.graph, 0)
+        self.assertEqual([0, 1], list(itertools.islice(traversal, 2)))
+        self.assertEqual({0, 1}, traversal.discovered)
+        self.assertEqual([2, 3, 4], list(traversal))
+
+    def test_dfs_traversal_copy(self):
+        traversal = retworkx.digraph_dfs_traversal(self.graph, 0)
+        next(traversal)
+        saved = traversal.copy()
+        self.assertIsInstance(saved, retworkx.DFSTraversal)
+        self.assertEqual([1, 2, 3, 4], list(traversal))
+        self.assertEqual([1, 2], list(itertools.islice(saved, 2)))
+        copied = copy.copy(saved)
+        self.assertEqual([3, 4], list(saved))
+        self.assertEqual([3, 4], list(copied))
+
+    def test_dfs_traversal_invalid_source(self):
+        with self.assertRaises(retworkx.InvalidNode):
+            retworkx.digraph_dfs_traversal(self.graph, 42)
+
+    def test_dfs_traversal_universal(self):
+        self.assertEqual([0, 1, 2, 3, 4], list(retworkx.dfs_traversal(self.graph, source=0)))
+
+    def test_dfs_traversal_empty(self):
+        self.assertEqual([], list(retworkx.digraph_dfs_traversal(retworkx.PyDiGraph())))
+
+
+class TestBFSTraversal(unittest.TestCase):
+    def setUp(self):
+        self.graph = retworkx.PyDiGraph()
+        self.graph.extend_from_edge_list([(0, 1), (1, 2), (0, 3), (3, 4), (5, 0)])
+
+    def test_bfs_traversal(self):
+        traversal = retworkx.digraph_bfs_traversal(self.graph, 0)
+        self.assertIsInstance(traversal, retworkx.BFSTraversal)
+        self.assertEqual([0, 3, 1, 4, 2], list(traversal))
+        self.assertEqual([], list(traversal))
+
+    def test_bfs_traversal_all_components(self):
+        self.graph.add_node(None)
+        order = list(retworkx.digraph_bfs_traversal(self.graph))
+        self.assertEqual([0, 3, 1, 4, 2, 5, 6], order)
+
+    def test_bfs_traversal_resume_and_copy(self):
+        traversal = retworkx.digraph_bfs_traversal(self.graph, 0)
+        self.assertEqual([0, 3], list(itertools.islice(traversal, 2)))
+        self.assertEqual({0, 1, 3, 4}, traversal.discovered)
+        saved = traversal.copy()
+        self.assertEqual([1, 4, 2], list(traversal))
+        self.assertEqual([1, 4, 2], list(saved))
+
+    def test_bfs_traversal_invalid_source(self):
+        with self.assertRaises(retworkx.InvalidNode):
+            retworkx.bfs_traversal(self.graph, 42)
+
+
+class TestTopologicalTraversal(unittest.TestCase):
+    def test_topological_traversal(self):
+        graph = retworkx.PyDiGraph()
+        graph.extend_from_edge_list([(0, 1), (0, 2), (1, 3), (2, 3), (4, 3), (3, 5), (1, 5)])
+        traversal = retworkx.topological_traversal(graph)
+        self.assertIsInstance(traversal, retworkx.TopologicalTraversal)
+        order = list(traversal)
+        self.assertEqual(sorted(order), list(graph.node_indices()))
+        position = {node: index for index, node in enumerate(order)}
+        for source, target in graph.edge_list():
+            self.assertLess(position[source], position[target])
+        self.assertEqual([], list(traversal))
+
+    def test_topological_traversal_resume_and_copy(self):
+        graph = retworkx.generators.directed_path_graph(5)
+        traversal = retworkx.topological_traversal(graph)
+        self.assertEqual([0, 1], list(itertools.islice(traversal, 2)))
+        saved = copy.copy(traversal)
+        self.assertEqual([2, 3, 4], list(traversal))
+        self.assertEqual([2], list(itertools.islice(saved, 1)))
+        self.assertEqual([3, 4], list(saved.copy()))
+        self.assertEqual([3, 4], list(saved))
+
+    def test_topological_traversal_parallel_edges(self):
+        graph = retworkx.PyDiGraph()
+        graph.extend_from_edge_list([(0, 1), (0, 1), (1, 2), (0, 2)])
+        self.assertEqual([0, 1, 2], list(retworkx.topological_traversal(graph)))
+
+    def test_topological_traversal_cycle(self):
+        graph = retworkx.PyDiGraph()
+        graph.extend_from_edge_list([(0, 1), (1, 2), (2, 1)])
+        traversal = retworkx.topological_traversal(graph)
+        self.assertEqual(0, next(traversal))
+        with self.assertRaises(retworkx.DAGHasCycle):
+            next(traversal)
+        self.assertEqual([], list(traversal))
+
+    def test_topological_traversal_empty(self):
+        self.assertEqual([], list(retworkx.topological_traversal(retworkx.PyDiGraph())))
@@ -0,0 +1,244 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+
+import unittest
+
+import retworkx
+
+
+def gate_matcher(node, pattern_node):
+    return pattern_node is None or node == pattern_node
+
+
+class TestRewrite(unittest.TestCase):
+    def setUp(self):
+        # cancel a pair of adjacent "x" nodes in a chain
+        self.pattern = retworkx.PyDiGraph()
+        self.pattern.add_nodes_from([None, "x", "x", None])
+        self.pattern.add_edges_from_no_data([(0, 1), (1, 2), (2, 3)])
+        self.replacement = retworkx.PyDiGraph()
+        self.replacement.add_nodes_from([None, None])
+        self.replacement.add_edge(0, 1, "wire")
+        self.interface = {0: 0, 3: 1}
+
+    def chain(self, labels):
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(labels)
+        graph.add_edges_from_no_data([(i, i + 1) for i in range(len(labels) - 1)])
+        return graph
+
+    def labels(self, graph):
+        return [graph[node] for node in retworkx.topological_sort(graph)]
+
+    def test_rewrite_exhaustive(self):
+        graph = self.chain(["in", "x", "x", "h", "x", "x", "x", "x", "out"])
+        res = retworkx.digraph_rewrite(
+            graph,
+            self.pattern,
+            self.replacement,
+            self.interface,
+            node_matcher=gate_matcher,
+        )
+        self.assertEqual(3, len(res))
+        self.assertEqual(["in", "h", "out"], self.labels(graph))
+        self.assertEqual(2, len(graph.edges()))
+        self.assertEqual(["wire", "wire"], graph.edges())
+
+    def test_rewrite_node_map(self):
+        graph = self.chain(["in", "x", "x", "out"])
+        res = retworkx.digraph_rewrite(
+            graph,
+            self.pattern,
+            self.replacement,
+            self.interface,
+            node_matcher=gate_matcher,
+        )
+        self.assertEqual(1, len(res))
+        self.assertEqual({0: 0, 1: 3}, dict(res[0]))
+        self.assertEqual([0, 3], graph.node_indices())
+        self.assertEqual([(0, 3)], graph.edge_list())
+
+    def test_rewrite_max_applications(self):
+        graph = self.chain(["in", "x", "x", "x", "x", "x", "x", "out"])
+        res = retworkx.digraph_rewrite(
+            graph,
+            self.pattern,
+            self.replacement,
+            self.interface,
+            node_matcher=gate_matcher,
+            max_applications=2,
+        )
+        self.assertEqual(2, len(res))
+        self.assertEqual(["in", "x", "x", "out"], self.labels(graph))
+
+    def test_rewrite_max_applications_zero(self):
+        graph = self.chain(["in", "x", "x", "out"])
+        res = retworkx.digraph_rewrite(
+            graph,
+            self.pattern,
+            self.replacement,
+            self.interface,
+            node_matcher=gate_matcher,
+            max_applications=0,
+        )
+        self.assertEqual([], res)
+        self.assertEqual(4, len(graph))
+
+    def test_rewrite_no_match(self):
+        graph = self.chain(["in", "x", "h", "x", "out"])
+        res = retworkx.digraph_rewrite(
+            graph,
+            self.pattern,
+            self.replacement,
+            self.interface,
+            node_matcher=gate_matcher,
+        )
+        self.assertEqual([], res)
+        self.assertEqual(["in", "x", "h", "x", "out"], self.labels(graph))
+
+    def test_rewrite_dangling_condition(self):
+        graph = self.chain(["in", "x", "x", "out"])
+        # the first "x" also feeds another node so it can't be removed
+        graph.add_child(1, "measure", None)
+        res = retworkx.digraph_rewrite(
+            graph,
+            self.pattern,
+            self.replacement,
+            self.interface,
+            node_matcher=gate_matcher,
+        )
+        self.assertEqual([], res)
+        self.assertEqual(5, len(graph))
+
+    def test_rewrite_edge_matcher(self):
+        pattern = retworkx.PyDiGraph()
+        pattern.add_nodes_from([None, None])
+        pattern.add_edge(0, 1, "a")
+        replacement = retworkx.PyDiGraph()
+        replacement.add_nodes_from([None, None])
+        replacement.add_edge(1, 0, "b")
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(["u", "v", "w"])
+        graph.add_edges_from([(0, 1, "a"), (1, 2, "c"), (2, 0, "a")])
+        res = retworkx.digraph_rewrite(
+            graph, pattern, replacement, {0: 0, 1: 1}, edge_matcher=lambda a, b: a == b
+        )
+        self.assertEqual(2, len(res))
+        self.assertEqual(
+            [(1, 0, "b"), (0, 2, "b"), (1, 2, "c")],
+            sorted(graph.weighted_edge_list(), key=lambda edge: edge[2]),
+        )
+
+    def test_rewrite_parallel_edges(self):
+        pattern = retworkx.PyDiGraph()
+        pattern.add_nodes_from([None, None])
+        pattern.add_edges_from([(0, 1, "a"), (0, 1, "b")])
+        replacement = retworkx.PyDiGraph()
+        replacement.add_nodes_from([None, None])
+        replacement.add_edge(0, 1, "ab")
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(["u", "v"])
+        graph.add_edges_from([(0, 1, "b"), (0, 1, "c"), (0, 1, "a")])
+        res = retworkx.digraph_rewrite(
+            graph, pattern, replacement, {0: 0, 1: 1}, edge_matcher=lambda a, b: a == b
+        )
+        self.assertEqual(1, len(res))
+        self.assertEqual(["ab", "c"], sorted(graph.edges()))
+
+    def test_rewrite_adds_nodes(self):
+        # expand every "swap" node into three "cx" nodes
+        pattern = retworkx.PyDiGraph()
+        pattern.add_node("swap")
+        replacement = retworkx.PyDiGraph()
+        replacement.add_nodes_from(["cx", "cx", "cx"])
+        replacement.add_edges_from_no_data([(0, 1), (1, 2)])
+        graph = retworkx.PyDiGraph()
+        graph.add_node("swap")
+        res = retworkx.digraph_rewrite(
+            graph, pattern, replacement, {}, node_matcher=lambda a, b: a == b
+        )
+        self.assertEqual(1, len(res))
+        self.assertEqual(["cx", "cx", "cx"], self.labels(graph))
+        self.assertEqual(sorted(res[0].values()), graph.node_indices())
+
+    def test_rewrite_preserves_payload(self):
+        pattern = retworkx.PyDiGraph()
+        pattern.add_nodes_from([None, None])
+        pattern.add_edge(0, 1, None)
+        replacement = retworkx.PyDiGraph()
+        replacement.add_nodes_from(["new", "ignored"])
+        replacement.add_edges_from_no_data([(1, 0)])
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(["a", "b"])
+        graph.add_edge(0, 1, None)
+        res = retworkx.digraph_rewrite(graph, pattern, replacement, {1: 1}, max_applications=1)
+        # the index of the removed node is reused by the new node
+        self.assertEqual({0: 0, 1: 1}, dict(res[0]))
+        self.assertEqual(["b", "new"], self.labels(graph))
+
+    def test_rewrite_induced(self):
+        pattern = retworkx.PyDiGraph()
+        pattern.add_nodes_from([None, None, None])
+        pattern.add_edges_from_no_data([(0, 1), (1, 2)])
+        replacement = retworkx.PyDiGraph()
+        replacement.add_nodes_from([None, None])
+        replacement.add_edges_from_no_data([(0, 1)])
+        interface = {0: 0, 2: 1}
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(["a", "b", "c"])
+        graph.add_edges_from_no_data([(0, 1), (1, 2), (0, 2)])
+        res = retworkx.digraph_rewrite(graph, pattern, replacement, interface, induced=True)
+        self.assertEqual([], res)
+        res = retworkx.digraph_rewrite(graph, pattern, replacement, interface, induced=False)
+        self.assertEqual(1, len(res))
+        self.assertEqual([(0, 2), (0, 2)], graph.edge_list())
+
+    def test_rewrite_would_cycle(self):
+        pattern = retworkx.PyDiGraph()
+        pattern.add_nodes_from([None, None])
+        pattern.add_edge(0, 1, None)
+        replacement = retworkx.PyDiGraph()
+        replacement.add_nodes_from([None, None])
+        replacement.add_edges_from_no_data([(0, 1), (1, 0)])
+        graph = retworkx.PyDAG(check_cycle=True)
+        graph.add_nodes_from(["a", "b"])
+        graph.add_edge(0, 1, None)
+        with self.assertRaises(retworkx.DAGWouldCycle):
+            retworkx.digraph_rewrite(graph, pattern, replacement, {0: 0, 1: 1})
+        self.assertEqual([(0, 1)], graph.edge_list())
+
+    def test_rewrite_invalid_interface(self):
+        graph = self.chain(["in", "x", "x", "out"])
+        with self.assertRaises(ValueError):
+            retworkx.digraph_rewrite(graph, self.pattern, self.replacement, {0: 0, 7: 1})
+        with self.assertRaises(ValueError):
+            retworkx.digraph_rewrite(graph, self.pattern, self.replacement, {0: 0, 3: 7})
+        with self.assertRaises(ValueError):
+            retworkx.digraph_rewrite(graph, self.pattern, self.replacement, {0: 0, 3: 0})
+
+    def test_rewrite_empty_pattern(self):
+        graph = self.chain(["in", "x", "x", "out"])
+        with self.assertRaises(ValueError):
+            retworkx.digraph_rewrite(graph, retworkx.PyDiGraph(), self.replacement, {})
+
+    def test_rewrite_universal(self):
+        graph = self.chain(["in", "x", "x", "out"])
+        res = retworkx.rewrite(
+            graph,
+            self.pattern,
+            self.replacement,
+            self.interface,
+            node_matcher=gate_matcher,
+        )
+        self.assertEqual(1, len(res))
+        self.assertEqual(["in", "out"], self.labels(graph))
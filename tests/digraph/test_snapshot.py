@@ -0,0 +1,115 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import marshal
+import os
+import tempfile
+import unittest
+
+import retworkx
+
+
+class TestSnapshot(unittest.TestCase):
+    def setUp(self):
+        self.tmpdir = tempfile.TemporaryDirectory()
+        self.path = os.path.join(self.tmpdir.name, "graph.rx")
+
+    def tearDown(self):
+        self.tmpdir.cleanup()
+
+    def test_round_trip(self):
+        graph = retworkx.PyDiGraph(multigraph=False)
+        graph.add_nodes_from(["a", {"b": [1, 2]}, None])
+        graph.add_edges_from([(0, 1, 1.5), (1, 2, None), (2, 2, ("x", 3))])
+        graph.write_snapshot(self.path)
+        res = retworkx.PyDiGraph.read_snapshot(self.path)
+        self.assertIsInstance(res, retworkx.PyDiGraph)
+        self.assertFalse(res.multigraph)
+        self.assertEqual(graph.nodes(), res.nodes())
+        self.assertEqual(list(graph.weighted_edge_list()), list(res.weighted_edge_list()))
+
+    def test_no_payloads(self):
+        graph = retworkx.generators.directed_path_graph(4)
+        graph.write_snapshot(self.path)
+        res = retworkx.PyDiGraph.read_snapshot(self.path)
+        self.assertEqual([None] * 4, res.nodes())
+        self.assertEqual(list(graph.edge_list()), list(res.edge_list()))
+
+    def test_empty(self):
+        retworkx.PyDiGraph().write_snapshot(self.path)
+        res = retworkx.PyDiGraph.read_snapshot(self.path)
+        self.assertEqual(0, len(res))
+        self.assertEqual(0, res.num_edges())
+
+    def test_removed_nodes(self):
+        graph = retworkx.generators.directed_path_graph(6)
+        graph.remove_node(0)
+        graph.remove_node(3)
+        graph.remove_edge(4, 5)
+        graph.write_snapshot(self.path)
+        res = retworkx.PyDiGraph.read_snapshot(self.path)
+        self.assertEqual([1, 2, 4, 5], list(res.node_indices()))
+        self.assertEqual([(1, 2)], list(res.edge_list()))
+        self.assertEqual(res.add_node(None), 3)
+
+    def test_node_keys(self):
+        graph = retworkx.generators.directed_path_graph(3)
+        graph.set_node_key(0, "a")
+        graph.set_node_key(2, ("c", 1))
+        graph.write_snapshot(self.path)
+        res = retworkx.PyDiGraph.read_snapshot(self.path)
+        self.assertEqual({"a": 0, ("c", 1): 2}, res.node_keys())
+
+    def test_serializer(self):
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from([1, "b"])
+        graph.add_edge(0, 1, [1.0, None])
+        graph.write_snapshot(self.path, serializer=marshal)
+        res = retworkx.PyDiGraph.read_snapshot(self.path, serializer=marshal)
+        self.assertEqual([1, "b"], res.nodes())
+        self.assertEqual([(0, 1, [1.0, None])], list(res.weighted_edge_list()))
+
+    def test_wrong_graph_type(self):
+        retworkx.PyGraph().write_snapshot(self.path)
+        with self.assertRaises(ValueError):
+            retworkx.PyDiGraph.read_snapshot(self.path)
+
+    def test_invalid_file(self):
+        with open(self.path, "wb") as fd:
+            fd.write(b"not a snapshot")
+        with self.assertRaises(ValueError):
+            retworkx.PyDiGraph.read_snapshot(self.path)
+
+    def test_truncated_file(self):
+        retworkx.generators.directed_path_graph(4).write_snapshot(self.path)
+        with open(self.path, "rb") as fd:
+            data = fd.read()
+        for size in [4, 20, len(data) - 1]:
+            with self.subTest(size=size):
+                with open(self.path, "wb") as fd:
+                    fd.write(data[:size])
+                with self.assertRaises(ValueError):
+                    retworkx.PyDiGraph.read_snapshot(self.path)
+
+    def test_missing_file(self):
+        with self.assertRaises(OSError):
+            retworkx.PyDiGraph.read_snapshot(self.path)
+
+    def test_check_cycle(self):
+        graph = retworkx.PyDiGraph(check_cycle=True)
+        graph.add_nodes_from([0, 1])
+        graph.add_edge(0, 1, None)
+        graph.write_snapshot(self.path)
+        res = retworkx.PyDiGraph.read_snapshot(self.path)
+        self.assertTrue(res.check_cycle)
+        with self.assertRaises(retworkx.DAGWouldCycle):
+            res.add_edge(1, 0, None)
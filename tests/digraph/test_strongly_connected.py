@@ -65,3 +65,72 @@ class TestStronglyConnected(unittest.TestCase):
             node = G.add_node(i)
             G.add_child(node, str(i), {})
         self.assertEqual(len(retworkx.strongly_connected_components(G)), 200000)
+
+
+class TestCondensation(unittest.TestCase):
+    def setUp(self):
+        self.graph = retworkx.PyDiGraph()
+        self.graph.add_nodes_from(range(7))
+        self.graph.extend_from_edge_list(
+            [(0, 1), (1, 2), (2, 0), (2, 3), (1, 3), (3, 4), (4, 3), (4, 5), (0, 6)]
+        )
+
+    def assertCondensation(self, sccs, condensed):
+        self.assertEqual(len(sccs), len(condensed))
+        self.assertEqual(sorted(map(sorted, sccs)), sorted(map(sorted, condensed.nodes())))
+        component = {}
+        for index in condensed.node_indices():
+            for node in condensed[index]:
+                component[node] = index
+        expected_edges = {
+            (component[source], component[target])
+            for source, target in self.graph.edge_list()
+            if component[source] != component[target]
+        }
+        self.assertEqual(sorted(expected_edges), sorted(condensed.edge_list()))
+        self.assertTrue(retworkx.is_directed_acyclic_graph(condensed))
+
+    def test_condensation(self):
+        condensed = retworkx.condensation(self.graph)
+        self.assertCondensation([[0, 1, 2], [3, 4], [5], [6]], condensed)
+        self.assertEqual(3, condensed.num_edges())
+        for edge in condensed.edges():
+            self.assertIsNone(edge)
+
+    def test_condensation_node_order_matches_sccs(self):
+        sccs = retworkx.strongly_connected_components(self.graph)
+        condensed = retworkx.condensation(self.graph)
+        self.assertEqual(sccs, condensed.nodes())
+
+    def test_condensation_precomputed_sccs(self):
+        sccs = [[6], [5], [4, 3], [2, 1, 0]]
+        condensed = retworkx.condensation(self.graph, sccs=sccs)
+        self.assertEqual(sccs, condensed.nodes())
+        self.assertEqual([(3, 2), (2, 1), (3, 0)], list(condensed.edge_list()))
+
+    def test_condensation_removed_nodes(self):
+        self.graph.remove_node(2)
+        condensed = retworkx.condensation(self.graph)
+        self.assertCondensation([[0], [1], [3, 4], [5], [6]], condensed)
+
+    def test_condensation_dag(self):
+        dag = retworkx.generators.directed_path_graph(4)
+        condensed = retworkx.condensation(dag)
+        self.assertEqual(4, len(condensed))
+        self.assertEqual(3, condensed.num_edges())
+
+    def test_condensation_empty(self):
+        condensed = retworkx.condensation(retworkx.PyDiGraph())
+        self.assertEqual(0, len(condensed))
+
+    def test_condensation_sccs_missing_node(self):
+        with self.assertRaises(ValueError):
+            retworkx.condensation(self.graph, sccs=[[0, 1, 2], [3, 4], [5]])
+
+    def test_condensation_sccs_duplicate_node(self):
+        with self.assertRaises(ValueError):
+            retworkx.condensation(self.graph, sccs=[[0, 1, 2], [2, 3, 4], [5], [6]])
+
+    def test_condensation_sccs_invalid_node(self):
+        with self.assertRaises(retworkx.InvalidNode):
+            retworkx.condensation(self.graph, sccs=[[0, 1, 2], [3, 4], [5], [6], [42]])
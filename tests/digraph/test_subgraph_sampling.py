@@ -0,0 +1,97 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+import unittest
+
+import retworkx
+
+
+class TestDiGraphSubgraphSampling(unittest.TestCase):
+    def assert_induced(self, graph, subgraph, node_map):
+        for node, new_node in node_map.items():
+            self.assertEqual(graph[node], subgraph[new_node])
+        expected = sorted(
+            (node_map[source], node_map[target])
+            for source, target in graph.edge_list()
+            if source in node_map and target in node_map
+        )
+        self.assertEqual(expected, sorted(subgraph.edge_list()))
+
+    def test_random_node_sample(self):
+        graph = retworkx.generators.directed_grid_graph(5, 5)
+        subgraph, node_map = retworkx.digraph_random_node_sample(graph, 10, seed=42)
+        self.assertIsInstance(subgraph, retworkx.PyDiGraph)
+        self.assertEqual(10, len(subgraph))
+        self.assertEqual(sorted(node_map.values()), list(range(10)))
+        self.assert_induced(graph, subgraph, node_map)
+
+    def test_random_node_sample_keeps_options(self):
+        graph = retworkx.PyDiGraph(check_cycle=True, multigraph=False)
+        graph.extend_from_edge_list([(0, 1), (1, 2), (2, 3)])
+        subgraph, _ = retworkx.digraph_random_node_sample(graph, 3, seed=42)
+        self.assertTrue(subgraph.check_cycle)
+        self.assertFalse(subgraph.multigraph)
+
+    def test_random_node_sample_too_many(self):
+        graph = retworkx.generators.directed_path_graph(3)
+        with self.assertRaises(ValueError):
+            retworkx.digraph_random_node_sample(graph, 4)
+
+    def test_random_edge_sample(self):
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(range(6))
+        graph.add_edges_from([(0, 1, "a"), (2, 1, "b"), (2, 3, "c"), (5, 4, "d"), (0, 1, "e")])
+        subgraph, node_map = retworkx.digraph_random_edge_sample(graph, 3, seed=42)
+        self.assertEqual(3, subgraph.num_edges())
+        original = {new_node: node for node, new_node in node_map.items()}
+        for source, target, weight in subgraph.weighted_edge_list():
+            self.assertIn(weight, graph.get_all_edge_data(original[source], original[target]))
+        self.assertEqual(
+            set(subgraph.node_indices()),
+            {node for edge in subgraph.edge_list() for node in edge},
+        )
+
+    def test_random_edge_sample_too_many(self):
+        graph = retworkx.generators.directed_path_graph(3)
+        with self.assertRaises(ValueError):
+            retworkx.digraph_random_edge_sample(graph, 3)
+
+    def test_forest_fire_sample(self):
+        graph = retworkx.generators.directed_grid_graph(6, 6)
+        subgraph, node_map = retworkx.digraph_forest_fire_sample(graph, 12, seed=42)
+        self.assertEqual(12, len(subgraph))
+        self.assert_induced(graph, subgraph, node_map)
+        _, other_node_map = retworkx.digraph_forest_fire_sample(graph, 12, seed=42)
+        self.assertEqual(dict(node_map), dict(other_node_map))
+
+    def test_forest_fire_sample_ignores_direction(self):
+        # The edges point towards the center of the stars, a fire spreads
+        # against them too
+        graph = retworkx.generators.directed_star_graph(4, inward=True)
+        other = retworkx.generators.directed_star_graph(4, inward=True)
+        graph.compose(other, {})
+        _, node_map = retworkx.digraph_forest_fire_sample(graph, 4, burn_probability=0.99, seed=42)
+        self.assertIn(set(node_map), [{0, 1, 2, 3}, {4, 5, 6, 7}])
+
+    def test_forest_fire_sample_invalid_burn_probability(self):
+        graph = retworkx.generators.directed_path_graph(3)
+        with self.assertRaises(ValueError):
+            retworkx.digraph_forest_fire_sample(graph, 2, burn_probability=1.5)
+
+    def test_universal_functions(self):
+        graph = retworkx.generators.directed_grid_graph(4, 4)
+        for func in (
+            retworkx.random_node_sample,
+            retworkx.random_edge_sample,
+            retworkx.forest_fire_sample,
+        ):
+            subgraph, _ = func(graph, 5, seed=42)
+            self.assertIsInstance(subgraph, retworkx.PyDiGraph)
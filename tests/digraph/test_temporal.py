@@ -0,0 +1,86 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+import unittest
+
+import numpy as np
+
+import retworkx
+
+
+class TestDiGraphTemporal(unittest.TestCase):
+    def setUp(self):
+        # Every edge payload is the time of the edge
+        self.graph = retworkx.PyDiGraph()
+        self.graph.add_nodes_from(range(5))
+        self.graph.add_edges_from(
+            [(0, 1, 1.0), (1, 2, 2.0), (2, 0, 3.0), (3, 1, 0.0), (2, 4, 1.0), (0, 4, 6.0)]
+        )
+
+    def test_shortest_paths(self):
+        paths = retworkx.digraph_temporal_shortest_paths(self.graph, 0, time_fn=float)
+        # The edge from 2 to 4 departs before the arrival at 2
+        self.assertEqual({1: [0, 1], 2: [0, 1, 2], 4: [0, 4]}, paths)
+
+    def test_earliest_arrival(self):
+        arrivals = retworkx.digraph_temporal_earliest_arrival(self.graph, 0, time_fn=float)
+        self.assertEqual({1: 1.0, 2: 2.0, 4: 6.0}, arrivals)
+        arrivals = retworkx.digraph_temporal_earliest_arrival(self.graph, 1, time_fn=float)
+        self.assertEqual({0: 3.0, 2: 2.0, 4: 6.0}, arrivals)
+
+    def test_reachability(self):
+        # The edges are only traversed from their source to their target
+        self.assertEqual([], retworkx.digraph_temporal_reachability(self.graph, 4, time_fn=float))
+        self.assertEqual(
+            [0, 1, 2, 4], retworkx.digraph_temporal_reachability(self.graph, 3, time_fn=float)
+        )
+
+    def test_time_window(self):
+        reachable = retworkx.digraph_temporal_reachability(
+            self.graph, 0, time_fn=float, start_time=1.5, end_time=5.0
+        )
+        self.assertEqual([], reachable)
+        reachable = retworkx.digraph_temporal_reachability(
+            self.graph, 1, time_fn=float, start_time=1.5, end_time=5.0
+        )
+        self.assertEqual([0, 2], reachable)
+
+    def test_time_attr_column(self):
+        self.graph.set_edge_attribute("time", np.array([1.0, 2.0, 3.0, 0.0, 1.0, 6.0]))
+        arrivals = retworkx.digraph_temporal_earliest_arrival(
+            self.graph, 0, time_attr="time", default_duration=0.5
+        )
+        self.assertEqual({1: 1.5, 2: 2.5, 4: 6.5}, arrivals)
+
+    def test_duration_fn_and_duration_attr(self):
+        with self.assertRaises(ValueError):
+            retworkx.digraph_temporal_earliest_arrival(
+                self.graph, 0, time_fn=float, duration_fn=float, duration_attr="duration"
+            )
+
+    def test_invalid_source(self):
+        with self.assertRaises(retworkx.InvalidNode):
+            retworkx.digraph_temporal_shortest_paths(self.graph, 10, time_fn=float)
+
+    def test_universal_functions(self):
+        self.assertEqual(
+            {2: [0, 1, 2]},
+            retworkx.temporal_shortest_paths(self.graph, 0, target=2, time_fn=float),
+        )
+        self.assertEqual(
+            {1: 1.0, 2: 2.0, 4: 6.0},
+            retworkx.temporal_earliest_arrival(self.graph, 0, time_fn=float),
+        )
+        self.assertEqual([1, 2, 4], retworkx.temporal_reachability(self.graph, 0, time_fn=float))
+
+    def test_invalid_type(self):
+        with self.assertRaises(TypeError):
+            retworkx.temporal_reachability(None, 0, time_fn=float)
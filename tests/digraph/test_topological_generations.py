@@ -0,0 +1,85 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+
+import unittest
+
+import retworkx
+
+
+class TestTopologicalGenerations(unittest.TestCase):
+    def assertGenerations(self, expected, generations):
+        self.assertEqual(expected, [sorted(generation) for generation in generations])
+
+    def test_generations(self):
+        graph = retworkx.PyDiGraph()
+        graph.extend_from_edge_list([(0, 1), (0, 2), (1, 3), (2, 3), (4, 3), (3, 5), (1, 5)])
+        self.assertGenerations(
+            [[0, 4], [1, 2], [3], [5]], retworkx.topological_generations(graph)
+        )
+
+    def test_generations_are_node_indices(self):
+        graph = retworkx.generators.directed_path_graph(3)
+        for generation in retworkx.topological_generations(graph):
+            self.assertIsInstance(generation, retworkx.NodeIndices)
+
+    def test_lazy(self):
+        graph = retworkx.generators.directed_path_graph(4)
+        generations = retworkx.topological_generations(graph)
+        self.assertIsInstance(generations, retworkx.TopologicalGenerations)
+        self.assertIs(generations, iter(generations))
+        self.assertEqual([0], next(generations))
+        self.assertEqual([1], next(generations))
+        self.assertEqual([[2], [3]], list(generations))
+        self.assertEqual([], list(generations))
+
+    def test_parallel_edges(self):
+        graph = retworkx.PyDiGraph()
+        graph.extend_from_edge_list([(0, 1), (0, 1), (1, 2), (0, 2)])
+        self.assertGenerations([[0], [1], [2]], retworkx.topological_generations(graph))
+
+    def test_with_node_removal(self):
+        graph = retworkx.generators.directed_path_graph(5)
+        graph.remove_node(2)
+        self.assertGenerations([[0, 3], [1, 4]], retworkx.topological_generations(graph))
+
+    def test_empty_graph(self):
+        graph = retworkx.PyDiGraph()
+        self.assertEqual([], list(retworkx.topological_generations(graph)))
+
+    def test_no_edges(self):
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(range(5))
+        self.assertGenerations([[0, 1, 2, 3, 4]], retworkx.topological_generations(graph))
+
+    def test_matches_layers(self):
+        graph = retworkx.generators.directed_grid_graph(4, 5, weights=list(range(20)))
+        layers = retworkx.layers(graph, [0])
+        self.assertGenerations(
+            [sorted(layer) for layer in layers],
+            retworkx.topological_generations(graph),
+        )
+
+    def test_cycle(self):
+        graph = retworkx.PyDiGraph()
+        graph.extend_from_edge_list([(0, 1), (1, 2), (2, 1), (2, 3)])
+        generations = retworkx.topological_generations(graph)
+        self.assertEqual([0], next(generations))
+        with self.assertRaises(retworkx.DAGHasCycle):
+            next(generations)
+        self.assertEqual([], list(generations))
+
+    def test_self_loop(self):
+        graph = retworkx.PyDiGraph()
+        graph.extend_from_edge_list([(0, 0)])
+        with self.assertRaises(retworkx.DAGHasCycle):
+            list(retworkx.topological_generations(graph))
@@ -0,0 +1,182 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import pickle
+import random
+import unittest
+
+import retworkx
+
+
+class TestTopologicalOrder(unittest.TestCase):
+    def assertTopologicalOrder(self, dag, order):
+        self.assertEqual(sorted(order), sorted(dag.node_indices()))
+        position = {node: index for index, node in enumerate(order)}
+        for source, target in dag.edge_list():
+            self.assertLess(position[source], position[target])
+
+    def test_empty(self):
+        dag = retworkx.PyDAG(check_cycle=True)
+        self.assertEqual([], dag.topological_order())
+
+    def test_edges_in_order(self):
+        dag = retworkx.PyDAG(check_cycle=True)
+        dag.add_nodes_from(range(4))
+        dag.add_edges_from_no_data([(0, 1), (1, 2), (0, 3)])
+        self.assertTopologicalOrder(dag, dag.topological_order())
+
+    def test_edge_reorders(self):
+        dag = retworkx.PyDAG(check_cycle=True)
+        dag.add_nodes_from(range(4))
+        dag.add_edges_from_no_data([(0, 1), (2, 3)])
+        dag.add_edge(3, 0, None)
+        self.assertEqual([2, 3, 0, 1], dag.topological_order())
+
+    def test_order_is_kept(self):
+        dag = retworkx.PyDAG(check_cycle=True)
+        dag.add_nodes_from(range(6))
+        dag.add_edges_from_no_data([(0, 1), (2, 3), (4, 5)])
+        order = dag.topological_order()
+        self.assertEqual(order, dag.topological_order())
+        # An edge agreeing with the order doesn't change it
+        dag.add_edge(order[0], order[-1], None)
+        self.assertEqual(order, dag.topological_order())
+
+    def test_cycle(self):
+        dag = retworkx.PyDAG(check_cycle=True)
+        dag.add_nodes_from(range(4))
+        dag.add_edges_from_no_data([(0, 1), (1, 2), (2, 3)])
+        order = dag.topological_order()
+        with self.assertRaises(retworkx.DAGWouldCycle):
+            dag.add_edge(3, 1, None)
+        self.assertEqual(3, dag.num_edges())
+        self.assertEqual(order, dag.topological_order())
+
+    def test_self_loop(self):
+        dag = retworkx.PyDAG(check_cycle=True)
+        node = dag.add_node(None)
+        with self.assertRaises(retworkx.DAGWouldCycle):
+            dag.add_edge(node, node, None)
+        self.assertEqual(0, dag.num_edges())
+
+    def test_add_child_and_parent(self):
+        dag = retworkx.PyDAG(check_cycle=True)
+        node = dag.add_node(None)
+        child = dag.add_child(node, None, None)
+        parent = dag.add_parent(node, None, None)
+        dag.add_parent(parent, None, None)
+        dag.add_edge(parent, child, None)
+        self.assertTopologicalOrder(dag, dag.topological_order())
+        with self.assertRaises(retworkx.DAGWouldCycle):
+            dag.add_edge(child, parent, None)
+
+    def test_add_parent_is_first(self):
+        dag = retworkx.PyDAG(check_cycle=True)
+        dag.add_nodes_from(range(3))
+        dag.add_edges_from_no_data([(0, 1), (1, 2)])
+        parent = dag.add_parent(2, None, None)
+        child = dag.add_child(0, None, None)
+        self.assertEqual([parent, 0, 1, 2, child], dag.topological_order())
+        with self.assertRaises(retworkx.DAGWouldCycle):
+            dag.add_edge(2, parent, None)
+
+    def test_reused_index(self):
+        dag = retworkx.PyDAG(check_cycle=True)
+        dag.add_nodes_from(range(3))
+        dag.add_edges_from_no_data([(0, 1), (1, 2)])
+        dag.remove_node(0)
+        node = dag.add_node(None)
+        self.assertEqual(0, node)
+        dag.add_edge(2, node, None)
+        self.assertEqual([1, 2, 0], dag.topological_order())
+        with self.assertRaises(retworkx.DAGWouldCycle):
+            dag.add_edge(node, 1, None)
+
+    def test_enable_check_cycle(self):
+        dag = retworkx.PyDAG()
+        dag.add_nodes_from(range(3))
+        dag.add_edge(2, 1, None)
+        dag.check_cycle = True
+        dag.add_edge(1, 0, None)
+        self.assertEqual([2, 1, 0], dag.topological_order())
+        dag.check_cycle = False
+        dag.add_edge(0, 1, None)
+        with self.assertRaises(retworkx.DAGHasCycle):
+            dag.topological_order()
+
+    def test_edges_added_again_after_disabling(self):
+        dag = retworkx.PyDAG(check_cycle=True)
+        dag.add_nodes_from(range(3))
+        dag.add_edge(0, 1, None)
+        dag.check_cycle = False
+        dag.add_edge(2, 0, None)
+        dag.check_cycle = True
+        with self.assertRaises(retworkx.DAGWouldCycle):
+            dag.add_edge(1, 2, None)
+        self.assertEqual([2, 0, 1], dag.topological_order())
+
+    def test_contract_nodes(self):
+        dag = retworkx.PyDAG(check_cycle=True)
+        dag.add_nodes_from(range(5))
+        dag.add_edges_from_no_data([(0, 1), (1, 2), (0, 3), (3, 4)])
+        node = dag.contract_nodes([1, 3], None)
+        self.assertTopologicalOrder(dag, dag.topological_order())
+        dag.add_edge(4, 2, None)
+        self.assertTopologicalOrder(dag, dag.topological_order())
+        with self.assertRaises(retworkx.DAGWouldCycle):
+            dag.add_edge(2, node, None)
+
+    def test_merge_node_set(self):
+        dag = retworkx.PyDAG(check_cycle=True)
+        dag.add_nodes_from(range(4))
+        dag.add_edges_from_no_data([(0, 1), (2, 3)])
+        dag.merge_node_set([3, 0], lambda payloads: None)
+        self.assertEqual([2, 3, 1], dag.topological_order())
+        with self.assertRaises(retworkx.DAGWouldCycle):
+            dag.add_edge(1, 2, None)
+
+    def test_copy_and_pickle(self):
+        dag = retworkx.PyDAG(check_cycle=True)
+        dag.add_nodes_from(range(3))
+        dag.add_edges_from_no_data([(2, 1), (1, 0)])
+        for other in [dag.copy(), pickle.loads(pickle.dumps(dag))]:
+            other.check_cycle = True
+            self.assertEqual([2, 1, 0], other.topological_order())
+            with self.assertRaises(retworkx.DAGWouldCycle):
+                other.add_edge(0, 2, None)
+
+    def test_without_check_cycle(self):
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(range(4))
+        graph.add_edges_from_no_data([(3, 2), (2, 1), (1, 0)])
+        self.assertEqual([3, 2, 1, 0], graph.topological_order())
+        graph.add_edge(0, 3, None)
+        with self.assertRaises(retworkx.DAGHasCycle):
+            graph.topological_order()
+
+    def test_random_edges(self):
+        rng = random.Random(42)
+        dag = retworkx.PyDAG(check_cycle=True)
+        dag.add_nodes_from(range(30))
+        for _ in range(300):
+            if dag.num_edges() and rng.random() < 0.2:
+                dag.remove_edge_from_index(rng.choice(list(dag.edge_indices())))
+                continue
+            source = rng.randrange(30)
+            target = rng.randrange(30)
+            would_cycle = source == target or source in retworkx.descendants(dag, target)
+            if would_cycle:
+                with self.assertRaises(retworkx.DAGWouldCycle):
+                    dag.add_edge(source, target, None)
+            else:
+                dag.add_edge(source, target, None)
+            self.assertTopologicalOrder(dag, dag.topological_order())
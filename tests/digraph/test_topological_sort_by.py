@@ -0,0 +1,76 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+
+import unittest
+
+import retworkx
+
+
+class TestTopologicalSortBy(unittest.TestCase):
+    def setUp(self):
+        self.graph = retworkx.PyDiGraph()
+        self.graph.add_nodes_from(["e", "d", "c", "b", "a", "f"])
+        self.graph.add_edges_from_no_data([(0, 2), (1, 2), (2, 3), (2, 4), (3, 5)])
+
+    def test_sort_by_key(self):
+        order = retworkx.topological_sort_by(self.graph, lambda x: x)
+        self.assertEqual([1, 0, 2, 4, 3, 5], order)
+
+    def test_sort_by_key_reverse(self):
+        order = retworkx.topological_sort_by(self.graph, lambda x: x, reverse=True)
+        self.assertEqual([4, 5, 3, 2, 1, 0], order)
+
+    def test_ties_use_node_index(self):
+        order = retworkx.topological_sort_by(self.graph, lambda _: 0)
+        self.assertEqual([0, 1, 2, 3, 4, 5], order)
+
+    def test_non_string_keys(self):
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from([(2, "x"), (1, "y"), (1, "x")])
+        order = retworkx.topological_sort_by(graph, lambda x: x)
+        self.assertEqual([2, 1, 0], order)
+
+    def test_parallel_edges(self):
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(["b", "a", "c"])
+        graph.add_edges_from_no_data([(0, 2), (0, 2), (1, 2)])
+        order = retworkx.topological_sort_by(graph, lambda x: x)
+        self.assertEqual([1, 0, 2], order)
+
+    def test_removed_nodes(self):
+        self.graph.remove_node(1)
+        order = retworkx.topological_sort_by(self.graph, lambda x: x)
+        self.assertEqual([0, 2, 4, 3, 5], order)
+
+    def test_empty(self):
+        order = retworkx.topological_sort_by(retworkx.PyDiGraph(), lambda x: x)
+        self.assertEqual([], order)
+
+    def test_cycle(self):
+        graph = retworkx.generators.directed_cycle_graph(3)
+        graph.add_child(0, None, None)
+        with self.assertRaises(retworkx.DAGHasCycle):
+            retworkx.topological_sort_by(graph, lambda _: 0)
+
+    def test_key_error(self):
+        def key(_):
+            raise KeyError
+
+        with self.assertRaises(KeyError):
+            retworkx.topological_sort_by(self.graph, key)
+
+    def test_incomparable_keys(self):
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from([1, "a"])
+        with self.assertRaises(TypeError):
+            retworkx.topological_sort_by(graph, lambda x: x)
@@ -0,0 +1,89 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+
+import unittest
+
+import retworkx
+
+
+class TestTransitiveReduction(unittest.TestCase):
+    def test_transitive_reduction(self):
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(list(range(5)))
+        graph.add_edges_from(
+            [
+                (0, 1, "a"),
+                (0, 2, "b"),
+                (0, 3, "c"),
+                (0, 4, "d"),
+                (1, 3, "e"),
+                (2, 3, "f"),
+                (2, 4, "g"),
+                (3, 4, "h"),
+            ]
+        )
+        reduced, node_map = retworkx.transitive_reduction(graph)
+        self.assertEqual(reduced.nodes(), [0, 1, 2, 3, 4])
+        self.assertEqual(
+            reduced.weighted_edge_list(),
+            [(0, 1, "a"), (0, 2, "b"), (1, 3, "e"), (2, 3, "f"), (3, 4, "h")],
+        )
+        self.assertEqual(dict(node_map), {i: i for i in range(5)})
+        # The input graph is not modified
+        self.assertEqual(len(graph.edges()), 8)
+
+    def test_path_is_unchanged(self):
+        graph = retworkx.generators.directed_path_graph(5)
+        reduced, _ = retworkx.transitive_reduction(graph)
+        self.assertEqual(reduced.edge_list(), graph.edge_list())
+
+    def test_tournament(self):
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(list(range(6)))
+        graph.add_edges_from_no_data(
+            [(i, j) for i in range(6) for j in range(i + 1, 6)]
+        )
+        reduced, _ = retworkx.transitive_reduction(graph)
+        self.assertEqual(
+            reduced.edge_list(), [(0, 1), (1, 2), (2, 3), (3, 4), (4, 5)]
+        )
+
+    def test_parallel_edges(self):
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(list(range(3)))
+        graph.add_edges_from([(0, 1, "a"), (0, 1, "b"), (1, 2, "c")])
+        reduced, _ = retworkx.transitive_reduction(graph)
+        self.assertEqual(
+            reduced.weighted_edge_list(), [(0, 1, "a"), (1, 2, "c")]
+        )
+
+    def test_removed_nodes(self):
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(["a", "b", "c", "d", "e"])
+        graph.add_edges_from_no_data([(1, 3), (3, 4), (1, 4)])
+        graph.remove_node(0)
+        graph.remove_node(2)
+        reduced, node_map = retworkx.transitive_reduction(graph)
+        self.assertEqual(dict(node_map), {1: 0, 3: 1, 4: 2})
+        self.assertEqual(reduced.nodes(), ["b", "d", "e"])
+        self.assertEqual(reduced.edge_list(), [(0, 1), (1, 2)])
+
+    def test_empty(self):
+        reduced, node_map = retworkx.transitive_reduction(retworkx.PyDiGraph())
+        self.assertEqual(len(reduced), 0)
+        self.assertEqual(len(node_map), 0)
+
+    def test_cycle(self):
+        graph = retworkx.generators.directed_cycle_graph(3)
+        with self.assertRaises(retworkx.DAGHasCycle):
+            retworkx.transitive_reduction(graph)
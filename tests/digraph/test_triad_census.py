@@ -0,0 +1,95 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import retworkx
+
+TRIADS = [
+    "003",
+    "012",
+    "102",
+    "021D",
+    "021U",
+    "021C",
+    "111D",
+    "111U",
+    "030T",
+    "030C",
+    "201",
+    "120D",
+    "120U",
+    "120C",
+    "210",
+    "300",
+]
+
+
+class TestTriadCensus(unittest.TestCase):
+    def assertCensus(self, edges, expected):
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(range(3))
+        graph.add_edges_from_no_data(edges)
+        res = retworkx.triad_census(graph)
+        self.assertEqual(list(res.keys()), TRIADS)
+        self.assertEqual({triad: count for triad, count in res.items() if count}, {expected: 1})
+
+    def test_triad_types(self):
+        self.assertCensus([], "003")
+        self.assertCensus([(0, 1)], "012")
+        self.assertCensus([(0, 1), (1, 0)], "102")
+        self.assertCensus([(0, 1), (0, 2)], "021D")
+        self.assertCensus([(1, 0), (2, 0)], "021U")
+        self.assertCensus([(0, 1), (1, 2)], "021C")
+        self.assertCensus([(0, 1), (1, 0), (2, 1)], "111D")
+        self.assertCensus([(0, 1), (1, 0), (1, 2)], "111U")
+        self.assertCensus([(0, 1), (1, 2), (0, 2)], "030T")
+        self.assertCensus([(0, 1), (1, 2), (2, 0)], "030C")
+        self.assertCensus([(0, 1), (1, 0), (1, 2), (2, 1)], "201")
+        self.assertCensus([(0, 1), (1, 0), (2, 0), (2, 1)], "120D")
+        self.assertCensus([(0, 1), (1, 0), (0, 2), (1, 2)], "120U")
+        self.assertCensus([(0, 1), (1, 0), (0, 2), (2, 1)], "120C")
+        self.assertCensus([(0, 1), (1, 0), (1, 2), (2, 1), (0, 2)], "210")
+        self.assertCensus([(0, 1), (1, 0), (1, 2), (2, 1), (0, 2), (2, 0)], "300")
+
+    def test_directed_cycle(self):
+        graph = retworkx.generators.directed_cycle_graph(4)
+        res = retworkx.triad_census(graph)
+        self.assertEqual(res["021C"], 4)
+        self.assertEqual(sum(res.values()), 4)
+
+    def test_null_triads(self):
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(range(10))
+        graph.add_edge(0, 1, None)
+        res = retworkx.triad_census(graph)
+        self.assertEqual(res["012"], 8)
+        self.assertEqual(res["003"], 120 - 8)
+
+    def test_self_loops_and_parallel_edges(self):
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(range(3))
+        graph.add_edges_from_no_data([(0, 1), (0, 1), (1, 1)])
+        res = retworkx.triad_census(graph)
+        self.assertEqual(res["012"], 1)
+        self.assertEqual(sum(res.values()), 1)
+
+    def test_removed_node(self):
+        graph = retworkx.generators.directed_path_graph(5)
+        graph.remove_node(2)
+        res = retworkx.triad_census(graph)
+        self.assertEqual(res["012"], 4)
+        self.assertEqual(sum(res.values()), 4)
+
+    def test_empty(self):
+        res = retworkx.triad_census(retworkx.PyDiGraph())
+        self.assertEqual(sum(res.values()), 0)
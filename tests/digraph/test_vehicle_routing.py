@@ -0,0 +1,78 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import math
+import unittest
+
+import retworkx
+
+
+class TestDiGraphSavingsRoutes(unittest.TestCase):
+    def setUp(self):
+        # A star with edges in both directions between the depot in the
+        # middle and its leaves, and a directed cycle between the leaves
+        self.graph = retworkx.PyDiGraph()
+        self.graph.add_nodes_from(range(5))
+        for leaf in range(1, 5):
+            self.graph.add_edge(0, leaf, 10)
+            self.graph.add_edge(leaf, 0, 10)
+        self.graph.add_edges_from([(1, 2, 1), (2, 3, 1), (3, 4, 1), (4, 1, 1)])
+        self.demands = {1: 1, 2: 1, 3: 1, 4: 1}
+
+    def test_savings_routes(self):
+        routes = retworkx.digraph_savings_routes(self.graph, 0, self.demands, 2, weight_fn=float)
+        self.assertEqual([[0, 1, 2, 0], [0, 3, 4, 0]], [list(route.nodes) for route in routes])
+        self.assertEqual([21, 21], [route.cost for route in routes])
+
+    def test_single_route_follows_edges(self):
+        demands = {4: 1, 3: 1, 2: 1}
+        routes = retworkx.digraph_savings_routes(self.graph, 0, demands, 3, weight_fn=float)
+        self.assertEqual([[0, 2, 3, 4, 0]], [list(route.nodes) for route in routes])
+        self.assertEqual(22, routes[0].cost)
+
+    def test_unreachable_depot(self):
+        self.graph.add_node(5)
+        self.graph.add_edge(0, 5, 1)
+        routes = retworkx.digraph_savings_routes(self.graph, 0, {1: 1, 5: 1}, 2)
+        self.assertEqual([[0, 1, 0], [0, 5, 0]], [list(route.nodes) for route in routes])
+        self.assertTrue(math.isinf(routes[1].cost))
+
+    def test_universal(self):
+        routes = retworkx.savings_routes(self.graph, 0, self.demands, 2, weight_fn=float)
+        self.assertEqual(2, len(routes))
+
+
+class TestDiGraphSweepRoutes(unittest.TestCase):
+    def setUp(self):
+        self.graph = retworkx.PyDiGraph()
+        self.graph.add_nodes_from(range(5))
+        for leaf in range(1, 5):
+            self.graph.add_edge(0, leaf, None)
+            self.graph.add_edge(leaf, 0, None)
+        self.graph.add_edges_from_no_data([(1, 2), (2, 3), (3, 4), (4, 1)])
+        self.pos = {0: [0, 0], 1: [1, 0], 2: [0, 1], 3: [-1, 0], 4: [0, -1]}
+
+    def test_sweep_routes(self):
+        routes = retworkx.digraph_sweep_routes(self.graph, 0, {1: 1, 2: 1, 3: 1, 4: 1}, 2, self.pos)
+        self.assertEqual([[0, 4, 1, 0], [0, 2, 3, 0]], [list(route.nodes) for route in routes])
+        self.assertEqual([3, 3], [route.cost for route in routes])
+
+    def test_route_against_edges(self):
+        # Going from 2 to 1 needs a path through the depot
+        self.pos[1] = [-1, 1]
+        routes = retworkx.digraph_sweep_routes(self.graph, 0, {1: 1, 2: 1}, 2, self.pos)
+        self.assertEqual([[0, 2, 1, 0]], [list(route.nodes) for route in routes])
+        self.assertEqual(4, routes[0].cost)
+
+    def test_universal(self):
+        routes = retworkx.sweep_routes(self.graph, 0, {1: 1, 2: 1}, 2, self.pos)
+        self.assertEqual([[0, 1, 2, 0]], [list(route.nodes) for route in routes])
@@ -0,0 +1,102 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import retworkx
+
+
+class TreeEdgesRecorder(retworkx.visit.BFSVisitor):
+    def __init__(self):
+        self.edges = []
+
+    def tree_edge(self, edge):
+        self.edges.append((edge[0], edge[1]))
+
+
+class TestDiGraphView(unittest.TestCase):
+    def setUp(self):
+        self.graph = retworkx.PyDiGraph()
+        self.graph.add_nodes_from(["a", "b", "c", "d"])
+        self.graph.add_edges_from(
+            [(0, 1, 1.0), (1, 2, 2.0), (2, 3, 3.0), (0, 2, 1.0)]
+        )
+
+    def test_subgraph_view(self):
+        view = self.graph.subgraph_view(nodes=[0, 2, 3])
+        self.assertFalse(view.reversed)
+        self.assertEqual([0, 2, 3], view.node_indices())
+        self.assertEqual([(2, 3), (0, 2)], view.edge_list())
+        self.assertEqual([2], view.successor_indices(0))
+        self.assertEqual(1, view.in_degree(2))
+
+    def test_reverse_view(self):
+        view = self.graph.reverse_view()
+        self.assertTrue(view.reversed)
+        self.assertEqual([(1, 0), (2, 1), (3, 2), (2, 0)], view.edge_list())
+        self.assertEqual([0, 1], sorted(view.successor_indices(2)))
+        self.assertEqual([3], view.predecessor_indices(2))
+        self.assertEqual(1, view.in_degree(2))
+        self.assertEqual(2, view.out_degree(2))
+        self.assertTrue(view.has_edge(1, 0))
+        self.assertFalse(view.has_edge(0, 1))
+
+    def test_reverse_of_reverse(self):
+        view = self.graph.reverse_view().reverse_view()
+        self.assertFalse(view.reversed)
+        self.assertEqual(self.graph.edge_list(), view.edge_list())
+
+    def test_reversed_subgraph_view(self):
+        view = self.graph.reverse_view().subgraph_view(nodes=[0, 1, 2])
+        self.assertTrue(view.reversed)
+        self.assertEqual([(1, 0), (2, 1), (2, 0)], view.edge_list())
+
+    def test_to_digraph(self):
+        digraph = self.graph.reverse_view().subgraph_view(nodes=[1, 2, 3]).to_digraph()
+        self.assertEqual(["b", "c", "d"], digraph.nodes())
+        self.assertEqual([(1, 0, 2.0), (2, 1, 3.0)], digraph.weighted_edge_list())
+
+    def test_undirected_view(self):
+        view = self.graph.undirected_view()
+        self.assertEqual([0, 1, 3], sorted(view.neighbors(2)))
+        self.assertEqual(3, view.degree(2))
+        self.assertTrue(view.has_edge(3, 2))
+        graph = view.to_graph()
+        self.assertIsInstance(graph, retworkx.PyGraph)
+        self.assertEqual(self.graph.weighted_edge_list(), graph.weighted_edge_list())
+
+    def test_bfs_search_reverse_view(self):
+        vis = TreeEdgesRecorder()
+        retworkx.bfs_search(self.graph.reverse_view(), [3], vis)
+        self.assertEqual([(3, 2), (2, 0), (2, 1)], vis.edges)
+
+    def test_bfs_search_undirected_view(self):
+        vis = TreeEdgesRecorder()
+        retworkx.bfs_search(self.graph.undirected_view(), [3], vis)
+        self.assertEqual([(3, 2), (2, 0), (2, 1)], vis.edges)
+
+    def test_dijkstra_search_reverse_view(self):
+        class Distances(retworkx.visit.DijkstraVisitor):
+            def __init__(self):
+                self.dist = {}
+
+            def discover_vertex(self, node, cost):
+                self.dist[node] = cost
+
+        vis = Distances()
+        retworkx.dijkstra_search(self.graph.reverse_view(), [3], float, vis)
+        self.assertEqual({3: 0.0, 2: 3.0, 0: 4.0, 1: 5.0}, vis.dist)
+
+    def test_search_filtered_source(self):
+        view = self.graph.subgraph_view(nodes=[0, 1])
+        with self.assertRaises(retworkx.InvalidNode):
+            retworkx.bfs_search(view, [3], TreeEdgesRecorder())
@@ -0,0 +1,77 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import retworkx
+
+
+class TestCirculantGraph(unittest.TestCase):
+    def test_circulant_graph(self):
+        graph = retworkx.generators.circulant_graph(6, [1, 2])
+        self.assertEqual(len(graph), 6)
+        self.assertEqual(
+            graph.edge_list(),
+            [(0, 1), (1, 2), (2, 3), (3, 4), (4, 5), (5, 0)]
+            + [(0, 2), (1, 3), (2, 4), (3, 5), (4, 0), (5, 1)],
+        )
+
+    def test_circulant_graph_is_cycle(self):
+        graph = retworkx.generators.circulant_graph(7, [1])
+        self.assertTrue(retworkx.is_isomorphic(graph, retworkx.generators.cycle_graph(7)))
+
+    def test_circulant_graph_is_complete(self):
+        for n in range(2, 9):
+            with self.subTest(n=n):
+                graph = retworkx.generators.circulant_graph(n, list(range(1, n // 2 + 1)))
+                self.assertTrue(retworkx.is_isomorphic(graph, retworkx.generators.mesh_graph(n)))
+
+    def test_circulant_graph_opposite_offset(self):
+        graph = retworkx.generators.circulant_graph(8, [4])
+        self.assertEqual(graph.edge_list(), [(0, 4), (1, 5), (2, 6), (3, 7)])
+
+    def test_circulant_graph_no_offsets(self):
+        graph = retworkx.generators.circulant_graph(5, [])
+        self.assertEqual(len(graph), 5)
+        self.assertEqual(graph.num_edges(), 0)
+
+    def test_circulant_graph_no_nodes(self):
+        graph = retworkx.generators.circulant_graph(0, [])
+        self.assertEqual(len(graph), 0)
+
+    def test_circulant_graph_invalid_offsets(self):
+        for offsets in [[0], [4], [1, 1], [1, 3, 1]]:
+            with self.subTest(offsets=offsets):
+                with self.assertRaises(ValueError):
+                    retworkx.generators.circulant_graph(7, offsets)
+
+    def test_directed_circulant_graph(self):
+        graph = retworkx.generators.directed_circulant_graph(5, [2])
+        self.assertEqual(graph.edge_list(), [(0, 2), (1, 3), (2, 4), (3, 0), (4, 1)])
+
+    def test_directed_circulant_graph_opposite_offset(self):
+        graph = retworkx.generators.directed_circulant_graph(4, [2])
+        self.assertEqual(graph.edge_list(), [(0, 2), (1, 3), (2, 0), (3, 1)])
+        graph = retworkx.generators.directed_circulant_graph(4, [2], bidirectional=True)
+        self.assertEqual(graph.edge_list(), [(0, 2), (1, 3), (2, 0), (3, 1)])
+
+    def test_directed_circulant_graph_bidirectional(self):
+        graph = retworkx.generators.directed_circulant_graph(4, [1, 2], bidirectional=True)
+        self.assertEqual(graph.num_edges(), 12)
+        for u in range(4):
+            for v in range(4):
+                if u != v:
+                    self.assertTrue(graph.has_edge(u, v))
+
+    def test_directed_circulant_graph_invalid_offsets(self):
+        with self.assertRaises(ValueError):
+            retworkx.generators.directed_circulant_graph(6, [1, 4])
@@ -0,0 +1,64 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import retworkx
+
+
+class TestDeBruijnGraph(unittest.TestCase):
+    def test_de_bruijn_graph(self):
+        graph = retworkx.generators.de_bruijn_graph(2, 2)
+        self.assertEqual(graph.nodes(), [(0, 0), (0, 1), (1, 0), (1, 1)])
+        self.assertEqual(
+            graph.edge_list(),
+            [(0, 0), (0, 1), (1, 2), (1, 3), (2, 0), (2, 1), (3, 2), (3, 3)],
+        )
+
+    def test_de_bruijn_graph_shifts_words(self):
+        graph = retworkx.generators.de_bruijn_graph("abc", 3)
+        self.assertEqual(len(graph), 27)
+        self.assertEqual(graph.num_edges(), 81)
+        self.assertEqual(graph[5], ("a", "b", "c"))
+        for u, v in graph.edge_list():
+            self.assertEqual(graph[u][1:], graph[v][:-1])
+        for node in graph.node_indices():
+            self.assertEqual(graph.out_degree(node), 3)
+            self.assertEqual(graph.in_degree(node), 3)
+
+    def test_de_bruijn_graph_symbol_list(self):
+        symbols = [None, "x", 1.5]
+        graph = retworkx.generators.de_bruijn_graph(symbols, 1)
+        self.assertEqual(graph.nodes(), [(None,), ("x",), (1.5,)])
+        self.assertEqual(graph.num_edges(), 9)
+
+    def test_de_bruijn_graph_self_loops(self):
+        graph = retworkx.generators.de_bruijn_graph(3, 2)
+        loops = [u for u, v in graph.edge_list() if u == v]
+        self.assertEqual(loops, [0, 4, 8])
+
+    def test_de_bruijn_graph_empty_word(self):
+        graph = retworkx.generators.de_bruijn_graph(3, 0)
+        self.assertEqual(graph.nodes(), [()])
+        self.assertEqual(graph.edge_list(), [(0, 0), (0, 0), (0, 0)])
+
+    def test_de_bruijn_graph_no_symbols(self):
+        graph = retworkx.generators.de_bruijn_graph([], 2)
+        self.assertEqual(len(graph), 0)
+
+    def test_de_bruijn_graph_too_large(self):
+        with self.assertRaises(ValueError):
+            retworkx.generators.de_bruijn_graph(10, 30)
+
+    def test_de_bruijn_graph_invalid_symbols(self):
+        with self.assertRaises(TypeError):
+            retworkx.generators.de_bruijn_graph(None, 2)
@@ -0,0 +1,56 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import itertools
+import unittest
+
+import retworkx
+
+
+class TestHypercubeGraph(unittest.TestCase):
+    def test_hypercube_graph(self):
+        graph = retworkx.generators.hypercube_graph(3)
+        self.assertEqual(len(graph), 8)
+        self.assertEqual(graph.num_edges(), 12)
+        self.assertEqual(graph.nodes(), list(itertools.product([0, 1], repeat=3)))
+        for u, v in graph.edge_list():
+            diff = sum(a != b for a, b in zip(graph[u], graph[v]))
+            self.assertEqual(diff, 1)
+
+    def test_hypercube_graph_is_regular(self):
+        graph = retworkx.generators.hypercube_graph(5)
+        self.assertEqual(len(graph), 32)
+        self.assertEqual(graph.num_edges(), 80)
+        for node in graph.node_indices():
+            self.assertEqual(graph.degree(node), 5)
+
+    def test_hypercube_graph_small(self):
+        graph = retworkx.generators.hypercube_graph(0)
+        self.assertEqual(graph.nodes(), [()])
+        self.assertEqual(graph.num_edges(), 0)
+        graph = retworkx.generators.hypercube_graph(2)
+        self.assertTrue(retworkx.is_isomorphic(graph, retworkx.generators.cycle_graph(4)))
+
+    def test_hypercube_graph_too_large(self):
+        with self.assertRaises(ValueError):
+            retworkx.generators.hypercube_graph(100)
+
+    def test_directed_hypercube_graph(self):
+        graph = retworkx.generators.directed_hypercube_graph(2)
+        self.assertEqual(graph.edge_list(), [(0, 1), (0, 2), (1, 3), (2, 3)])
+        self.assertEqual(graph.nodes(), [(0, 0), (0, 1), (1, 0), (1, 1)])
+
+    def test_directed_hypercube_graph_bidirectional(self):
+        graph = retworkx.generators.directed_hypercube_graph(2, bidirectional=True)
+        self.assertEqual(
+            graph.edge_list(), [(0, 1), (1, 0), (0, 2), (2, 0), (1, 3), (3, 1), (2, 3), (3, 2)]
+        )
@@ -0,0 +1,111 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import retworkx
+
+
+class TestCompleteMultipartiteGraph(unittest.TestCase):
+    def assertMultipartite(self, graph, subsets):
+        for u in graph.node_indices():
+            for v in graph.node_indices():
+                if u != v:
+                    self.assertEqual(subsets[u] != subsets[v], graph.has_edge(u, v))
+
+    def test_complete_multipartite_graph(self):
+        graph, subsets = retworkx.generators.complete_multipartite_graph([1, 2, 3])
+        self.assertIsInstance(graph, retworkx.PyGraph)
+        self.assertEqual(6, len(graph))
+        self.assertEqual(11, graph.num_edges())
+        self.assertEqual([0, 1, 1, 2, 2, 2], subsets)
+        self.assertMultipartite(graph, subsets)
+
+    def test_complete_multipartite_graph_bipartite(self):
+        graph, subsets = retworkx.generators.complete_multipartite_graph([3, 4])
+        self.assertEqual(12, graph.num_edges())
+        self.assertMultipartite(graph, subsets)
+
+    def test_complete_multipartite_graph_singletons(self):
+        graph, subsets = retworkx.generators.complete_multipartite_graph([1] * 5)
+        mesh = retworkx.generators.mesh_graph(5)
+        self.assertTrue(retworkx.is_isomorphic(graph, mesh))
+        self.assertEqual(list(range(5)), subsets)
+
+    def test_complete_multipartite_graph_empty_subsets(self):
+        graph, subsets = retworkx.generators.complete_multipartite_graph([0, 2, 0])
+        self.assertEqual(2, len(graph))
+        self.assertEqual(0, graph.num_edges())
+        self.assertEqual([1, 1], subsets)
+        graph, subsets = retworkx.generators.complete_multipartite_graph([])
+        self.assertEqual(0, len(graph))
+        self.assertEqual([], subsets)
+
+    def test_directed_complete_multipartite_graph(self):
+        graph, subsets = retworkx.generators.directed_complete_multipartite_graph([2, 1])
+        self.assertIsInstance(graph, retworkx.PyDiGraph)
+        self.assertEqual([(0, 2), (1, 2)], sorted(graph.edge_list()))
+        self.assertEqual([0, 0, 1], subsets)
+
+    def test_directed_complete_multipartite_graph_bidirectional(self):
+        graph, _ = retworkx.generators.directed_complete_multipartite_graph(
+            [2, 1], bidirectional=True
+        )
+        self.assertEqual([(0, 2), (1, 2), (2, 0), (2, 1)], sorted(graph.edge_list()))
+
+    def test_multigraph(self):
+        graph, _ = retworkx.generators.complete_multipartite_graph([1, 1], multigraph=False)
+        self.assertFalse(graph.multigraph)
+        graph, _ = retworkx.generators.directed_complete_multipartite_graph(
+            [1, 1], multigraph=False
+        )
+        self.assertFalse(graph.multigraph)
+
+
+class TestTuranGraph(unittest.TestCase):
+    def test_turan_graph(self):
+        graph, subsets = retworkx.generators.turan_graph(7, 3)
+        self.assertIsInstance(graph, retworkx.PyGraph)
+        self.assertEqual(7, len(graph))
+        self.assertEqual(16, graph.num_edges())
+        self.assertEqual([0, 0, 1, 1, 2, 2, 2], subsets)
+
+    def test_turan_graph_edge_count(self):
+        # T(n, r) has floor((1 - 1 / r) * n ** 2 / 2) edges
+        for n in range(1, 12):
+            for r in range(1, n + 1):
+                graph, subsets = retworkx.generators.turan_graph(n, r)
+                self.assertEqual((r - 1) * n * n // (2 * r), graph.num_edges())
+                sizes = [subsets.count(subset) for subset in range(r)]
+                self.assertLessEqual(max(sizes) - min(sizes), 1)
+
+    def test_turan_graph_extremes(self):
+        graph, _ = retworkx.generators.turan_graph(5, 1)
+        self.assertEqual(0, graph.num_edges())
+        graph, _ = retworkx.generators.turan_graph(5, 5)
+        self.assertEqual(10, graph.num_edges())
+
+    def test_turan_graph_invalid(self):
+        with self.assertRaises(ValueError):
+            retworkx.generators.turan_graph(5, 0)
+        with self.assertRaises(ValueError):
+            retworkx.generators.turan_graph(5, 6)
+        with self.assertRaises(ValueError):
+            retworkx.generators.directed_turan_graph(0, 1)
+
+    def test_directed_turan_graph(self):
+        graph, subsets = retworkx.generators.directed_turan_graph(4, 2)
+        self.assertIsInstance(graph, retworkx.PyDiGraph)
+        self.assertEqual([0, 0, 1, 1], subsets)
+        self.assertEqual([(0, 2), (0, 3), (1, 2), (1, 3)], sorted(graph.edge_list()))
+        graph, _ = retworkx.generators.directed_turan_graph(4, 2, bidirectional=True)
+        self.assertEqual(8, graph.num_edges())
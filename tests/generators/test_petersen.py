@@ -54,3 +54,8 @@ class TestPetersenGraph(unittest.TestCase):
 
         with self.assertRaises(IndexError):
             retworkx.generators.generalized_petersen_graph(5, 4)
+
+    def test_petersen_graph_is_cubic(self):
+        graph = retworkx.generators.generalized_petersen_graph(8, 3)
+        for node in graph.node_indices():
+            self.assertEqual(graph.degree(node), 3)
@@ -0,0 +1,98 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+
+import unittest
+
+import retworkx
+
+
+class TestSmilesGraph(unittest.TestCase):
+    def test_chain_with_branch(self):
+        graph = retworkx.generators.smiles_graph("CC(=O)O")
+        self.assertEqual(graph.nodes(), ["C", "C", "O", "O"])
+        self.assertEqual(
+            graph.weighted_edge_list(), [(0, 1, "-"), (1, 2, "="), (1, 3, "-")]
+        )
+
+    def test_two_letter_atoms(self):
+        graph = retworkx.generators.smiles_graph("ClCBr")
+        self.assertEqual(graph.nodes(), ["Cl", "C", "Br"])
+
+    def test_bonds(self):
+        graph = retworkx.generators.smiles_graph("C=C#N$C:C/C\\C")
+        self.assertEqual(
+            [weight for _, _, weight in graph.weighted_edge_list()],
+            ["=", "#", "$", ":", "-", "-"],
+        )
+
+    def test_aromatic_ring(self):
+        graph = retworkx.generators.smiles_graph("c1ccccc1C")
+        self.assertEqual(len(graph), 7)
+        edges = graph.weighted_edge_list()
+        self.assertEqual(len(edges), 7)
+        self.assertIn((0, 5, ":"), edges)
+        self.assertIn((5, 6, "-"), edges)
+        self.assertEqual([w for _, _, w in edges].count(":"), 6)
+
+    def test_ring_bond_types(self):
+        graph = retworkx.generators.smiles_graph("C=1CCC1")
+        self.assertIn((0, 3, "="), graph.weighted_edge_list())
+        graph = retworkx.generators.smiles_graph("C1CCC=1")
+        self.assertIn((0, 3, "="), graph.weighted_edge_list())
+        graph = retworkx.generators.smiles_graph("C%12CC%12")
+        self.assertIn((0, 2, "-"), graph.weighted_edge_list())
+
+    def test_bracket_atoms(self):
+        graph = retworkx.generators.smiles_graph("[NH4+].[Cl-]")
+        self.assertEqual(graph.nodes(), ["NH4+", "Cl-"])
+        self.assertEqual(graph.edge_list(), [])
+
+    def test_bracket_aromatic(self):
+        graph = retworkx.generators.smiles_graph("c1cc[nH]c1")
+        self.assertEqual(graph.nodes()[3], "nH")
+        self.assertEqual(
+            [w for _, _, w in graph.weighted_edge_list()], [":"] * 5
+        )
+
+    def test_disconnected(self):
+        graph = retworkx.generators.smiles_graph("CC.O")
+        self.assertEqual(graph.edge_list(), [(0, 1)])
+        self.assertEqual(len(retworkx.connected_components(graph)), 2)
+
+    def test_empty(self):
+        graph = retworkx.generators.smiles_graph("")
+        self.assertEqual(len(graph), 0)
+
+    def test_invalid(self):
+        for smiles in [
+            "C(",
+            "C)",
+            "C1CC",
+            "=C",
+            "C==C",
+            "C=",
+            "[C",
+            "[]",
+            "CX",
+            "C%1",
+            "C11",
+            "C=1CC#1",
+            "(C)",
+        ]:
+            with self.subTest(smiles=smiles):
+                with self.assertRaises(ValueError):
+                    retworkx.generators.smiles_graph(smiles)
+
+    def test_invalid_position(self):
+        with self.assertRaisesRegex(ValueError, "position 2"):
+            retworkx.generators.smiles_graph("CCX")
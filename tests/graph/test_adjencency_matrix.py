@@ -190,6 +190,83 @@ class TestGraphAdjacencyMatrix(unittest.TestCase):
         expected_matrix = np.array([[0, 1, 0], [1, 0, 1], [0, 1, 0]], dtype=np.float64)
         self.assertTrue(np.array_equal(adj_matrix, expected_matrix))
 
+    def test_parallel_edge_sum_with_null_weight(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from([0, 1])
+        graph.add_edge(0, 1, 1.0)
+        graph.add_edge(0, 1, 2.0)
+        res = retworkx.graph_adjacency_matrix(graph, float, null_value=1.0)
+        self.assertTrue(np.array_equal(np.array([[1.0, 3.0], [3.0, 1.0]]), res))
+
+    def test_parallel_edge_min(self):
+        res = retworkx.graph_adjacency_matrix(self.parallel_graph(), float, parallel_edge="min")
+        expected = np.array([[0.0, 1.0, 0.0], [1.0, 0.0, 4.0], [0.0, 4.0, 0.0]])
+        self.assertTrue(np.array_equal(expected, res))
+
+    def test_parallel_edge_max(self):
+        res = retworkx.graph_adjacency_matrix(self.parallel_graph(), float, parallel_edge="max")
+        expected = np.array([[0.0, 3.0, 0.0], [3.0, 0.0, 4.0], [0.0, 4.0, 0.0]])
+        self.assertTrue(np.array_equal(expected, res))
+
+    def test_parallel_edge_last(self):
+        res = retworkx.graph_adjacency_matrix(self.parallel_graph(), float, parallel_edge="last")
+        expected = np.array([[0.0, 3.0, 0.0], [3.0, 0.0, 4.0], [0.0, 4.0, 0.0]])
+        self.assertTrue(np.array_equal(expected, res))
+
+    def test_parallel_edge_min_nan_null(self):
+        res = retworkx.adjacency_matrix(
+            self.parallel_graph(), float, null_value=np.nan, parallel_edge="min"
+        )
+        expected = np.array([[np.nan, 1.0, np.nan], [1.0, np.nan, 4.0], [np.nan, 4.0, np.nan]])
+        self.assertTrue(np.array_equal(expected, res, equal_nan=True))
+
+    def test_invalid_parallel_edge(self):
+        with self.assertRaises(ValueError):
+            retworkx.graph_adjacency_matrix(self.parallel_graph(), parallel_edge="mean")
+
+    def test_float32_dtype(self):
+        res = retworkx.graph_adjacency_matrix(self.parallel_graph(), float, dtype=np.float32)
+        self.assertEqual(np.dtype(np.float32), res.dtype)
+        expected = np.array([[0.0, 6.0, 0.0], [6.0, 0.0, 4.0], [0.0, 4.0, 0.0]], dtype=np.float32)
+        self.assertTrue(np.array_equal(expected, res))
+
+    def test_complex_dtype(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from([0, 1])
+        graph.add_edge(0, 1, 1 + 2j)
+        graph.add_edge(0, 1, 3j)
+        for dtype in [complex, np.complex64, "complex128"]:
+            with self.subTest(dtype=dtype):
+                res = retworkx.graph_adjacency_matrix(graph, lambda x: x, dtype=dtype)
+                self.assertEqual(np.dtype(dtype), res.dtype)
+                self.assertTrue(np.array_equal(np.array([[0, 1 + 5j], [1 + 5j, 0]]), res))
+
+    def test_complex_dtype_min(self):
+        with self.assertRaises(ValueError):
+            retworkx.graph_adjacency_matrix(
+                self.parallel_graph(), dtype=np.complex128, parallel_edge="min"
+            )
+
+    def test_complex_null_real_dtype(self):
+        with self.assertRaises(ValueError):
+            retworkx.graph_adjacency_matrix(self.parallel_graph(), null_value=1j)
+
+    def test_unsupported_dtype(self):
+        with self.assertRaises(ValueError):
+            retworkx.graph_adjacency_matrix(self.parallel_graph(), dtype=np.int64)
+
+    def test_from_float32_adjacency_matrix(self):
+        input_matrix = np.array([[0, 1.5, 0], [1.5, 0, 2], [0, 2, 0]], dtype=np.float32)
+        graph = retworkx.PyGraph.from_adjacency_matrix(input_matrix)
+        self.assertEqual([(0, 1, 1.5), (1, 2, 2.0)], graph.weighted_edge_list())
+
+    @staticmethod
+    def parallel_graph():
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from([0, 1, 2])
+        graph.add_edges_from([(0, 1, 2.0), (0, 1, 1.0), (0, 1, 3.0), (1, 2, 4.0)])
+        return graph
+
 
 class TestFromComplexAdjacencyMatrix(unittest.TestCase):
     def test_from_adjacency_matrix(self):
@@ -260,3 +337,126 @@ class TestFromComplexAdjacencyMatrix(unittest.TestCase):
             edge_list,
             [(0, 1, 1 + 0j), (1, 2, 1 + 0j)],
         )
+
+    def test_from_complex64_adjacency_matrix(self):
+        input_matrix = np.array([[0, 1j, 0], [1j, 0, 2], [0, 2, 0]], dtype=np.complex64)
+        graph = retworkx.PyGraph.from_complex_adjacency_matrix(input_matrix)
+        self.assertEqual([(0, 1, 1j), (1, 2, 2 + 0j)], graph.weighted_edge_list())
+
+
+class TestGraphCSRAdjacencyMatrix(unittest.TestCase):
+    @staticmethod
+    def parallel_graph():
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from([0, 1, 2])
+        graph.add_edges_from([(0, 1, 2.0), (0, 1, 1.0), (0, 1, 3.0), (1, 2, 4.0)])
+        return graph
+
+    @staticmethod
+    def to_dense(csr, size):
+        indptr, indices, data = csr
+        matrix = np.zeros((size, size), dtype=data.dtype)
+        for row in range(size):
+            for pos in range(indptr[row], indptr[row + 1]):
+                matrix[row, indices[pos]] = data[pos]
+        return matrix
+
+    def test_csr_adjacency_matrix(self):
+        indptr, indices, data = retworkx.graph_csr_adjacency_matrix(self.parallel_graph(), float)
+        self.assertTrue(np.array_equal(np.array([0, 1, 3, 4]), indptr))
+        self.assertTrue(np.array_equal(np.array([1, 0, 2, 1]), indices))
+        self.assertTrue(np.array_equal(np.array([6.0, 6.0, 4.0, 4.0]), data))
+        self.assertEqual(np.dtype(np.int64), indptr.dtype)
+        self.assertEqual(np.dtype(np.int64), indices.dtype)
+        self.assertEqual(np.dtype(np.float64), data.dtype)
+
+    def test_matches_adjacency_matrix(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(range(6))
+        graph.add_edges_from(
+            [(0, 5, 1.0), (0, 2, 2.0), (3, 0, 3.0), (4, 4, 4.0), (2, 3, 5.0), (5, 0, 6.0)]
+        )
+        graph.remove_node(1)
+        csr = retworkx.graph_csr_adjacency_matrix(graph, float)
+        expected = retworkx.graph_adjacency_matrix(graph, float)
+        self.assertTrue(np.array_equal(expected, self.to_dense(csr, len(graph))))
+        indptr, indices, _ = csr
+        for row in range(len(graph)):
+            row_indices = list(indices[indptr[row] : indptr[row + 1]])
+            self.assertEqual(sorted(row_indices), row_indices)
+
+    def test_default_weight(self):
+        csr = retworkx.graph_csr_adjacency_matrix(self.parallel_graph(), default_weight=2.0)
+        expected = retworkx.graph_adjacency_matrix(self.parallel_graph(), default_weight=2.0)
+        self.assertTrue(np.array_equal(expected, self.to_dense(csr, 3)))
+
+    def test_parallel_edge_sum(self):
+        csr = retworkx.graph_csr_adjacency_matrix(self.parallel_graph(), float)
+        expected = np.array([[0.0, 6.0, 0.0], [6.0, 0.0, 4.0], [0.0, 4.0, 0.0]])
+        self.assertTrue(np.array_equal(expected, self.to_dense(csr, 3)))
+
+    def test_parallel_edge_min(self):
+        _, _, data = retworkx.graph_csr_adjacency_matrix(
+            self.parallel_graph(), float, parallel_edge="min"
+        )
+        self.assertTrue(np.array_equal(np.array([1.0, 1.0, 4.0, 4.0]), data))
+
+    def test_parallel_edge_last(self):
+        _, _, data = retworkx.graph_csr_adjacency_matrix(
+            self.parallel_graph(), float, parallel_edge="last"
+        )
+        self.assertTrue(np.array_equal(np.array([3.0, 3.0, 4.0, 4.0]), data))
+
+    def test_zero_weight_entry(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from([0, 1])
+        graph.add_edge(0, 1, 0.0)
+        indptr, indices, data = retworkx.graph_csr_adjacency_matrix(graph, float)
+        self.assertEqual(1, indices[0])
+        self.assertEqual(0.0, data[0])
+
+    def test_empty_graph(self):
+        indptr, indices, data = retworkx.graph_csr_adjacency_matrix(retworkx.PyGraph())
+        self.assertEqual([0], list(indptr))
+        self.assertEqual(0, len(indices))
+        self.assertEqual(0, len(data))
+
+    def test_float32_dtype(self):
+        _, _, data = retworkx.graph_csr_adjacency_matrix(
+            self.parallel_graph(), float, dtype=np.float32
+        )
+        self.assertEqual(np.dtype(np.float32), data.dtype)
+
+    def test_complex_dtype(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from([0, 1])
+        graph.add_edge(0, 1, 1 + 2j)
+        graph.add_edge(0, 1, 3j)
+        for dtype in [complex, np.complex64, "complex128"]:
+            with self.subTest(dtype=dtype):
+                _, _, data = retworkx.graph_csr_adjacency_matrix(graph, lambda x: x, dtype=dtype)
+                self.assertEqual(np.dtype(dtype), data.dtype)
+                self.assertEqual(1 + 5j, data[0])
+
+    def test_complex_dtype_min(self):
+        with self.assertRaises(ValueError):
+            retworkx.graph_csr_adjacency_matrix(
+                self.parallel_graph(), dtype=np.complex128, parallel_edge="min"
+            )
+
+    def test_complex_default_weight_real_dtype(self):
+        with self.assertRaises(ValueError):
+            retworkx.graph_csr_adjacency_matrix(self.parallel_graph(), default_weight=1j)
+
+    def test_invalid_parallel_edge(self):
+        with self.assertRaises(ValueError):
+            retworkx.graph_csr_adjacency_matrix(self.parallel_graph(), parallel_edge="mean")
+
+    def test_unsupported_dtype(self):
+        with self.assertRaises(ValueError):
+            retworkx.graph_csr_adjacency_matrix(self.parallel_graph(), dtype=np.int64)
+
+    def test_universal(self):
+        csr = retworkx.csr_adjacency_matrix(self.parallel_graph(), float)
+        expected = retworkx.adjacency_matrix(self.parallel_graph(), float)
+        self.assertTrue(np.array_equal(expected, self.to_dense(csr, 3)))
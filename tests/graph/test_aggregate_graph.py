@@ -0,0 +1,123 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import retworkx
+
+
+class TestGraphAggregateGraph(unittest.TestCase):
+    def setUp(self):
+        self.graph = retworkx.PyGraph()
+        self.graph.add_nodes_from(["a", "a", "b", "a", "c", "b"])
+        self.graph.add_edges_from(
+            [
+                (0, 1, 2.0),
+                (1, 3, 3.0),
+                (0, 2, 1.0),
+                (2, 5, 4.0),
+                (5, 3, 0.5),
+                (3, 4, 2.0),
+                (4, 4, 1.0),
+            ]
+        )
+
+    def test_partition(self):
+        res = retworkx.graph_aggregate_graph(self.graph, [[0, 1, 3], [2, 5], [4]])
+        self.assertIsInstance(res, retworkx.PyGraph)
+        self.assertEqual(
+            [
+                {
+                    "group": 0,
+                    "nodes": [0, 1, 3],
+                    "size": 3,
+                    "internal_edges": 2,
+                    "internal_weight": 2.0,
+                    "density": 2 / 3,
+                },
+                {
+                    "group": 1,
+                    "nodes": [2, 5],
+                    "size": 2,
+                    "internal_edges": 1,
+                    "internal_weight": 1.0,
+                    "density": 1.0,
+                },
+                {
+                    "group": 2,
+                    "nodes": [4],
+                    "size": 1,
+                    "internal_edges": 1,
+                    "internal_weight": 1.0,
+                    "density": 0.0,
+                },
+            ],
+            res.nodes(),
+        )
+        self.assertEqual(
+            [
+                (0, 1, {"count": 2, "weight": 2.0}),
+                (0, 2, {"count": 1, "weight": 1.0}),
+            ],
+            res.weighted_edge_list(),
+        )
+
+    def test_callable_groups(self):
+        res = retworkx.graph_aggregate_graph(self.graph, lambda node: node, weight_fn=float)
+        self.assertEqual(["a", "b", "c"], [group["group"] for group in res.nodes()])
+        self.assertEqual([[0, 1, 3], [2, 5], [4]], [group["nodes"] for group in res.nodes()])
+        self.assertEqual(5.0, res.nodes()[0]["internal_weight"])
+        self.assertEqual(
+            [
+                (0, 1, {"count": 2, "weight": 1.5}),
+                (0, 2, {"count": 1, "weight": 2.0}),
+            ],
+            res.weighted_edge_list(),
+        )
+
+    def test_default_weight(self):
+        res = retworkx.graph_aggregate_graph(self.graph, lambda node: node, default_weight=0.5)
+        self.assertEqual(1.0, res.nodes()[0]["internal_weight"])
+        self.assertEqual(1.0, res.get_edge_data(0, 1)["weight"])
+
+    def test_single_group(self):
+        res = retworkx.graph_aggregate_graph(self.graph, lambda _: None)
+        self.assertEqual(1, len(res))
+        self.assertEqual(7, res[0]["internal_edges"])
+        self.assertEqual(0, res.num_edges())
+
+    def test_with_holes(self):
+        self.graph.remove_node(1)
+        res = retworkx.graph_aggregate_graph(self.graph, [[0, 3], [2, 5], [4]])
+        self.assertEqual([[0, 3], [2, 5], [4]], [group["nodes"] for group in res.nodes()])
+        self.assertEqual(0, res[0]["internal_edges"])
+
+    def test_empty_graph(self):
+        res = retworkx.graph_aggregate_graph(retworkx.PyGraph(), [])
+        self.assertEqual(0, len(res))
+
+    def test_invalid_node(self):
+        with self.assertRaises(retworkx.InvalidNode):
+            retworkx.graph_aggregate_graph(self.graph, [[0, 1, 2, 3, 4, 5, 6]])
+
+    def test_node_in_two_parts(self):
+        with self.assertRaises(ValueError):
+            retworkx.graph_aggregate_graph(self.graph, [[0, 1, 2], [2, 3, 4, 5]])
+
+    def test_node_in_no_part(self):
+        with self.assertRaises(ValueError):
+            retworkx.graph_aggregate_graph(self.graph, [[0, 1, 2], [3, 4]])
+
+    def test_universal(self):
+        res = retworkx.aggregate_graph(self.graph, lambda node: node)
+        self.assertIsInstance(res, retworkx.PyGraph)
+        self.assertEqual(3, len(res))
@@ -0,0 +1,148 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+
+import unittest
+
+import retworkx
+
+
+class TestAlgorithmInfo(unittest.TestCase):
+    def setUp(self):
+        self.graph = retworkx.generators.cycle_graph(5)
+        self.graph.add_edge(0, 2, None)
+
+    def assertConverged(self, info, tol):
+        self.assertTrue(info.converged)
+        self.assertGreater(info.iterations, 0)
+        self.assertEqual(tol, info.tolerance)
+        self.assertLessEqual(info.error, tol)
+
+    def test_pagerank_explain(self):
+        ranks, info = retworkx.graph_pagerank(self.graph, alpha=0.9, explain=True)
+        self.assertEqual(dict(retworkx.graph_pagerank(self.graph, alpha=0.9)), dict(ranks))
+        self.assertIsInstance(info, retworkx.AlgorithmInfo)
+        self.assertEqual("pagerank", info.algorithm)
+        self.assertEqual("power iteration", info.variant)
+        self.assertEqual(0.9, info.parameters["alpha"])
+        self.assertEqual(100, info.parameters["max_iter"])
+        self.assertIsNone(info.parameters["personalization"])
+        self.assertConverged(info, 1e-6)
+        self.assertIsNone(info.seed)
+        self.assertEqual([], info.fallbacks)
+
+    def test_pagerank_default_return(self):
+        self.assertIsInstance(retworkx.graph_pagerank(self.graph), retworkx.CentralityMapping)
+
+    def test_pagerank_explain_personalization(self):
+        _, info = retworkx.pagerank(self.graph, personalization={0: 1.0}, explain=True)
+        self.assertEqual({0: 1.0}, info.parameters["personalization"])
+
+    def test_hits_explain(self):
+        (hubs, authorities), info = retworkx.graph_hits(self.graph, explain=True)
+        self.assertEqual(
+            (dict(hubs), dict(authorities)),
+            tuple(dict(scores) for scores in retworkx.graph_hits(self.graph)),
+        )
+        self.assertEqual("hits", info.algorithm)
+        self.assertTrue(info.parameters["normalized"])
+        self.assertConverged(info, 1e-8)
+
+    def test_eigenvector_centrality_explain(self):
+        centralities, info = retworkx.eigenvector_centrality(self.graph, explain=True)
+        self.assertEqual(dict(retworkx.eigenvector_centrality(self.graph)), dict(centralities))
+        self.assertEqual("eigenvector_centrality", info.algorithm)
+        self.assertConverged(info, 1e-6)
+
+    def test_katz_centrality_explain(self):
+        centralities, info = retworkx.katz_centrality(self.graph, alpha=0.2, explain=True)
+        self.assertEqual(dict(retworkx.katz_centrality(self.graph, alpha=0.2)), dict(centralities))
+        self.assertEqual("katz_centrality", info.algorithm)
+        self.assertEqual(0.2, info.parameters["alpha"])
+        self.assertEqual(1.0, info.parameters["beta"])
+        self.assertConverged(info, 1e-6)
+
+    def test_explain_empty_graph(self):
+        ranks, info = retworkx.pagerank(retworkx.PyGraph(), explain=True)
+        self.assertEqual({}, dict(ranks))
+        self.assertEqual(0, info.iterations)
+        self.assertTrue(info.converged)
+
+    def test_spring_layout_explain(self):
+        pos, info = retworkx.spring_layout(self.graph, seed=42, explain=True)
+        self.assertEqual(dict(retworkx.spring_layout(self.graph, seed=42)), dict(pos))
+        self.assertEqual("spring_layout", info.algorithm)
+        self.assertEqual("Fruchterman-Reingold with adaptive cooling", info.variant)
+        self.assertEqual(42, info.seed)
+        self.assertAlmostEqual(1 / 5**0.5, info.parameters["k"])
+        self.assertEqual(50, info.parameters["num_iter"])
+        self.assertEqual(1e-6, info.tolerance)
+        self.assertLessEqual(info.iterations, 50)
+        self.assertEqual(info.converged, info.error <= 1e-6)
+
+    def test_spectral_layout_explain(self):
+        graph = retworkx.generators.grid_graph(4, 4)
+        pos, info = retworkx.spectral_layout(graph, explain=True)
+        self.assertEqual(dict(retworkx.spectral_layout(graph)), dict(pos))
+        self.assertEqual("spectral_layout", info.algorithm)
+        self.assertEqual("LOBPCG on the Laplacian matrix", info.variant)
+        self.assertEqual(1000, info.parameters["max_iter"])
+        self.assertConverged(info, 1e-6)
+
+    def test_kamada_kawai_layout_explain(self):
+        graph = retworkx.generators.grid_graph(4, 4)
+        pos, info = retworkx.kamada_kawai_layout(graph, explain=True)
+        self.assertEqual(dict(retworkx.kamada_kawai_layout(graph)), dict(pos))
+        self.assertEqual("kamada_kawai_layout", info.algorithm)
+        self.assertEqual("Newton-Raphson on the stress of all pairs", info.variant)
+        self.assertIsNone(info.parameters["landmarks"])
+        self.assertConverged(info, 1e-4)
+        _, info = retworkx.kamada_kawai_layout(graph, landmarks=4, explain=True)
+        self.assertEqual("Newton-Raphson on the stress of neighbors and landmarks", info.variant)
+        self.assertEqual(4, info.parameters["landmarks"])
+        self.assertConverged(info, 1e-4)
+
+    def test_kamada_kawai_layout_not_converged(self):
+        graph = retworkx.generators.grid_graph(4, 4)
+        _, info = retworkx.kamada_kawai_layout(graph, max_iter=1, explain=True)
+        self.assertEqual(1, info.iterations)
+        self.assertFalse(info.converged)
+
+    def test_spring_layout_unseeded_is_reproducible(self):
+        pos, info = retworkx.spring_layout(self.graph, explain=True)
+        self.assertIsNotNone(info.seed)
+        self.assertEqual(dict(pos), dict(retworkx.spring_layout(self.graph, seed=info.seed)))
+
+    def test_spring_layout_linear_cooling_not_converged(self):
+        _, info = retworkx.spring_layout(
+            self.graph, adaptive_cooling=False, num_iter=1, tol=0.0, explain=True
+        )
+        self.assertEqual("Fruchterman-Reingold with linear cooling", info.variant)
+        self.assertEqual(1, info.iterations)
+        self.assertFalse(info.converged)
+
+    def test_spring_layout_fixed_fallback(self):
+        pos = {node: [float(node), 0.0] for node in self.graph.node_indices()}
+        _, info = retworkx.spring_layout(self.graph, pos=pos, fixed={0}, explain=True)
+        self.assertEqual(
+            ["scale and center were not applied since nodes are fixed"], info.fallbacks
+        )
+
+    def test_to_dict(self):
+        _, info = retworkx.pagerank(self.graph, explain=True)
+        info_dict = info.to_dict()
+        self.assertEqual("pagerank", info_dict["algorithm"])
+        self.assertEqual(info.parameters, info_dict["parameters"])
+        self.assertEqual(info.iterations, info_dict["iterations"])
+        self.assertEqual(info.error, info_dict["error"])
+        self.assertIsNone(info_dict["seed"])
+        self.assertEqual([], info_dict["fallbacks"])
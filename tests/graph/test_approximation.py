@@ -0,0 +1,129 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import retworkx
+
+
+class TestGreedyDominatingSet(unittest.TestCase):
+    def assertDominatingSet(self, graph, dominating):
+        for node in graph.node_indices():
+            self.assertTrue(
+                node in dominating or any(n in dominating for n in graph.neighbors(node)),
+                node,
+            )
+
+    def test_stars(self):
+        # A path of 2 stars
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(range(9))
+        graph.add_edges_from_no_data(
+            [(0, 1), (0, 2), (0, 3), (0, 4), (4, 5), (5, 6), (5, 7), (5, 8)]
+        )
+        self.assertEqual({0, 5}, retworkx.greedy_dominating_set(graph, seed=42))
+
+    def test_grid(self):
+        graph = retworkx.generators.grid_graph(10, 10)
+        dominating = retworkx.greedy_dominating_set(graph, seed=42)
+        self.assertDominatingSet(graph, dominating)
+        # A node dominates at most 5 nodes of the grid
+        self.assertGreaterEqual(len(dominating), 20)
+        self.assertLessEqual(len(dominating), 40)
+
+    def test_random_graphs(self):
+        for seed in range(5):
+            graph = retworkx.undirected_gnp_random_graph(50, 0.1, seed=seed)
+            self.assertDominatingSet(graph, retworkx.greedy_dominating_set(graph, seed=seed))
+
+    def test_seed(self):
+        graph = retworkx.generators.cycle_graph(30)
+        self.assertEqual(
+            retworkx.greedy_dominating_set(graph, seed=7),
+            retworkx.greedy_dominating_set(graph, seed=7),
+        )
+        # The smallest dominating set of the cycle has 10 nodes
+        self.assertIn(len(retworkx.greedy_dominating_set(graph, seed=7)), range(10, 13))
+
+    def test_isolated_nodes(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(range(3))
+        self.assertEqual({0, 1, 2}, retworkx.greedy_dominating_set(graph))
+
+    def test_self_loops_and_parallel_edges(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(range(3))
+        graph.add_edges_from_no_data([(0, 0), (0, 1), (0, 1), (1, 2), (2, 2)])
+        self.assertEqual({1}, retworkx.greedy_dominating_set(graph, seed=1))
+
+    def test_removed_nodes(self):
+        graph = retworkx.generators.star_graph(5)
+        graph.remove_node(0)
+        self.assertEqual({1, 2, 3, 4}, retworkx.greedy_dominating_set(graph))
+
+    def test_empty_graph(self):
+        self.assertEqual(set(), retworkx.greedy_dominating_set(retworkx.PyGraph()))
+
+
+class TestMaximalIndependentSet(unittest.TestCase):
+    def assertMaximalIndependentSet(self, graph, independent):
+        for source, target in graph.edge_list():
+            if source != target:
+                self.assertFalse(source in independent and target in independent)
+        for node in graph.node_indices():
+            self.assertTrue(
+                node in independent or any(n in independent for n in graph.neighbors(node)),
+                node,
+            )
+
+    def test_star(self):
+        graph = retworkx.generators.star_graph(5)
+        independent = retworkx.maximal_independent_set(graph, seed=42)
+        self.assertIn(independent, [{0}, {1, 2, 3, 4}])
+
+    def test_complete_graph(self):
+        graph = retworkx.generators.mesh_graph(6)
+        self.assertEqual(1, len(retworkx.maximal_independent_set(graph, seed=42)))
+
+    def test_random_graphs(self):
+        for seed in range(5):
+            graph = retworkx.undirected_gnp_random_graph(50, 0.1, seed=seed)
+            independent = retworkx.maximal_independent_set(graph, seed=seed)
+            self.assertMaximalIndependentSet(graph, independent)
+
+    def test_seed(self):
+        graph = retworkx.generators.grid_graph(10, 10)
+        independent = retworkx.maximal_independent_set(graph, seed=7)
+        self.assertEqual(independent, retworkx.maximal_independent_set(graph, seed=7))
+        self.assertMaximalIndependentSet(graph, independent)
+
+    def test_isolated_nodes(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(range(3))
+        self.assertEqual({0, 1, 2}, retworkx.maximal_independent_set(graph))
+
+    def test_self_loops_and_parallel_edges(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(range(3))
+        graph.add_edges_from_no_data([(0, 0), (0, 1), (0, 1), (1, 2), (2, 2)])
+        independent = retworkx.maximal_independent_set(graph, seed=1)
+        self.assertIn(independent, [{1}, {0, 2}])
+
+    def test_removed_nodes(self):
+        graph = retworkx.generators.path_graph(5)
+        graph.remove_node(2)
+        independent = retworkx.maximal_independent_set(graph, seed=3)
+        self.assertMaximalIndependentSet(graph, independent)
+        self.assertEqual(2, len(independent))
+
+    def test_empty_graph(self):
+        self.assertEqual(set(), retworkx.maximal_independent_set(retworkx.PyGraph()))
@@ -0,0 +1,198 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import copy
+import os
+import pickle
+import tempfile
+import unittest
+
+import numpy as np
+
+import retworkx
+
+
+class TestGraphAttributes(unittest.TestCase):
+    def setUp(self):
+        self.graph = retworkx.generators.path_graph(4)
+        self.graph.set_node_attribute("size", np.array([1.0, 2.0, 3.0, 4.0]))
+        self.graph.set_edge_attribute("capacity", np.array([5, 6, 7]))
+
+    def test_set_node_attribute(self):
+        values = self.graph.get_node_attribute("size")
+        self.assertEqual(values.dtype, np.float64)
+        self.assertEqual([1.0, 2.0, 3.0, 4.0], values.tolist())
+        self.assertEqual(["size"], self.graph.node_attributes())
+
+    def test_set_edge_attribute(self):
+        values = self.graph.get_edge_attribute("capacity")
+        self.assertEqual(values.dtype, np.int64)
+        self.assertEqual([5, 6, 7], values.tolist())
+        self.assertEqual(["capacity"], self.graph.edge_attributes())
+
+    def test_bool_attribute(self):
+        self.graph.set_node_attribute("visited", np.array([True, False, True, False]))
+        values = self.graph.get_node_attribute("visited", nodes=[2, 1])
+        self.assertEqual(values.dtype, np.bool_)
+        self.assertEqual([True, False], values.tolist())
+        self.assertEqual(["size", "visited"], self.graph.node_attributes())
+
+    def test_get_subset(self):
+        self.assertEqual([3.0, 1.0], self.graph.get_node_attribute("size", [2, 0]).tolist())
+        self.assertEqual([7], self.graph.get_edge_attribute("capacity", edges=[2]).tolist())
+
+    def test_set_subset(self):
+        self.graph.set_node_attribute("size", np.array([9.0, 8.0]), nodes=[3, 1])
+        self.assertEqual([1.0, 8.0, 3.0, 9.0], self.graph.get_node_attribute("size").tolist())
+
+    def test_set_subset_new_attribute(self):
+        self.graph.set_edge_attribute("length", np.array([2.5]), edges=[1])
+        values = self.graph.get_edge_attribute("length")
+        self.assertTrue(np.isnan(values[0]))
+        self.assertEqual(2.5, values[1])
+        self.assertTrue(np.isnan(values[2]))
+
+    def test_set_subset_wrong_dtype(self):
+        with self.assertRaises(TypeError):
+            self.graph.set_node_attribute("size", np.array([1]), nodes=[0])
+        self.assertEqual(np.float64, self.graph.get_node_attribute("size").dtype)
+
+    def test_replace_dtype(self):
+        self.graph.set_node_attribute("size", np.array([1, 2, 3, 4]))
+        values = self.graph.get_node_attribute("size")
+        self.assertEqual(values.dtype, np.int64)
+        self.assertEqual([1, 2, 3, 4], values.tolist())
+
+    def test_unsupported_values(self):
+        with self.assertRaises(TypeError):
+            self.graph.set_node_attribute("size", np.array(["a", "b", "c", "d"]))
+        with self.assertRaises(TypeError):
+            self.graph.set_node_attribute("size", np.zeros((2, 2)))
+        with self.assertRaises(TypeError):
+            self.graph.set_node_attribute("size", [1.0, 2.0, 3.0, 4.0])
+
+    def test_wrong_length(self):
+        with self.assertRaises(ValueError):
+            self.graph.set_node_attribute("size", np.array([1.0, 2.0]))
+        with self.assertRaises(ValueError):
+            self.graph.set_edge_attribute("capacity", np.array([1, 2]), edges=[0])
+
+    def test_missing_attribute(self):
+        with self.assertRaises(KeyError):
+            self.graph.get_node_attribute("capacity")
+        with self.assertRaises(KeyError):
+            self.graph.remove_edge_attribute("size")
+
+    def test_invalid_index(self):
+        with self.assertRaises(retworkx.InvalidNode):
+            self.graph.get_node_attribute("size", nodes=[4])
+        with self.assertRaises(retworkx.InvalidNode):
+            self.graph.set_node_attribute("size", np.array([1.0]), nodes=[4])
+        with self.assertRaises(IndexError):
+            self.graph.get_edge_attribute("capacity", edges=[3])
+
+    def test_remove_attribute(self):
+        self.graph.remove_node_attribute("size")
+        self.graph.remove_edge_attribute("capacity")
+        self.assertEqual([], self.graph.node_attributes())
+        self.assertEqual([], self.graph.edge_attributes())
+
+    def test_new_node_and_edge(self):
+        node = self.graph.add_node(None)
+        edge = self.graph.add_edge(3, node, None)
+        self.assertTrue(np.isnan(self.graph.get_node_attribute("size", [node])[0]))
+        self.assertEqual([0], self.graph.get_edge_attribute("capacity", [edge]).tolist())
+
+    def test_remove_node(self):
+        self.graph.remove_node(1)
+        self.assertEqual([1.0, 3.0, 4.0], self.graph.get_node_attribute("size").tolist())
+        self.assertEqual([7], self.graph.get_edge_attribute("capacity").tolist())
+        node = self.graph.add_node(None)
+        self.assertEqual(1, node)
+        self.assertTrue(np.isnan(self.graph.get_node_attribute("size", [node])[0]))
+        edge = self.graph.add_edge(0, node, None)
+        self.assertEqual([0], self.graph.get_edge_attribute("capacity", [edge]).tolist())
+
+    def test_remove_edge(self):
+        self.graph.remove_edge(1, 2)
+        self.assertEqual([5, 7], self.graph.get_edge_attribute("capacity").tolist())
+        edge = self.graph.add_edge(0, 2, None)
+        self.assertEqual(1, edge)
+        self.assertEqual([0], self.graph.get_edge_attribute("capacity", [edge]).tolist())
+
+    def test_compact(self):
+        self.graph.remove_node(1)
+        self.graph.compact()
+        self.assertEqual([1.0, 3.0, 4.0], self.graph.get_node_attribute("size").tolist())
+        self.assertEqual([7], self.graph.get_edge_attribute("capacity").tolist())
+        node = self.graph.add_node(None)
+        self.assertEqual(3, node)
+        self.assertTrue(np.isnan(self.graph.get_node_attribute("size", [node])[0]))
+
+    def test_copy(self):
+        for graph in [self.graph.copy(), copy.deepcopy(self.graph)]:
+            self.assertEqual([1.0, 2.0, 3.0, 4.0], graph.get_node_attribute("size").tolist())
+            graph.set_edge_attribute("capacity", np.array([0, 0, 0]))
+            self.assertEqual([5, 6, 7], self.graph.get_edge_attribute("capacity").tolist())
+
+    def test_pickle(self):
+        self.graph.remove_edge_from_index(0)
+        self.graph.remove_node(3)
+        self.graph.add_edge(0, 2, None)
+        graph = pickle.loads(pickle.dumps(self.graph))
+        self.assertEqual(self.graph.node_attributes(), graph.node_attributes())
+        self.assertEqual(
+            self.graph.get_node_attribute("size").tolist(),
+            graph.get_node_attribute("size").tolist(),
+        )
+        self.assertEqual(
+            dict(zip(self.graph.edge_list(), self.graph.get_edge_attribute("capacity"))),
+            dict(zip(graph.edge_list(), graph.get_edge_attribute("capacity"))),
+        )
+
+    def test_snapshot(self):
+        self.graph.remove_node(3)
+        with tempfile.TemporaryDirectory() as tmpdirname:
+            path = os.path.join(tmpdirname, "graph.rx")
+            self.graph.write_snapshot(path)
+            graph = retworkx.PyGraph.read_snapshot(path)
+        self.assertEqual(self.graph.node_attributes(), graph.node_attributes())
+        self.assertEqual(
+            self.graph.get_node_attribute("size").tolist(),
+            graph.get_node_attribute("size").tolist(),
+        )
+        self.assertEqual(
+            dict(zip(self.graph.edge_list(), self.graph.get_edge_attribute("capacity"))),
+            dict(zip(graph.edge_list(), graph.get_edge_attribute("capacity"))),
+        )
+
+    def test_pajek_coordinates(self):
+        graph = retworkx.read_pajek('*Vertices 3\n1 "a" 0.5 0.25\n2 "b"\n3 "c" 1 0\n')
+        np.testing.assert_array_equal([0.5, np.nan, 1.0], graph.get_node_attribute("x"))
+        res = retworkx.read_pajek(retworkx.write_pajek(graph))
+        np.testing.assert_array_equal(graph.get_node_attribute("x"), res.get_node_attribute("x"))
+        np.testing.assert_array_equal(graph.get_node_attribute("y"), res.get_node_attribute("y"))
+
+    def test_double_edge_swap(self):
+        graph = retworkx.generators.cycle_graph(8)
+        for edge in graph.edge_indices():
+            graph.update_edge_by_index(edge, edge)
+        graph.set_edge_attribute("id", np.arange(8))
+        self.assertGreater(retworkx.double_edge_swap(graph, 10, max_tries=100, seed=42), 0)
+        self.assertEqual(graph.edges(), graph.get_edge_attribute("id").tolist())
+
+    def test_edge_subgraph(self):
+        subgraph = self.graph.edge_subgraph([(0, 1), (2, 3)])
+        self.assertEqual([5, 7], subgraph.get_edge_attribute("capacity").tolist())
+        edge = subgraph.add_edge(1, 2, None)
+        self.assertEqual(1, edge)
+        self.assertEqual([0], subgraph.get_edge_attribute("capacity", [edge]).tolist())
@@ -57,6 +57,7 @@ class TestBiconnected(unittest.TestCase):
         graph = retworkx.PyGraph()
         self.assertEqual(retworkx.articulation_points(graph), set())
         self.assertEqual(retworkx.biconnected_components(graph), {})
+        self.assertEqual(retworkx.bridges(graph), set())
 
     def test_graph(self):
         components = {
@@ -77,6 +78,7 @@ class TestBiconnected(unittest.TestCase):
         }
         self.assertEqual(retworkx.biconnected_components(self.graph), components)
         self.assertEqual(retworkx.articulation_points(self.graph), {4, 5})
+        self.assertEqual(retworkx.bridges(self.graph), {(4, 5)})
 
     def test_barbell_graph(self):
         components = {
@@ -90,6 +92,7 @@ class TestBiconnected(unittest.TestCase):
         }
         self.assertEqual(retworkx.biconnected_components(self.barbell_graph), components)
         self.assertEqual(retworkx.articulation_points(self.barbell_graph), {2, 3})
+        self.assertEqual(retworkx.bridges(self.barbell_graph), {(2, 3)})
 
     def test_disconnected_graph(self):
         graph = retworkx.union(self.barbell_graph, self.barbell_graph)
@@ -113,6 +116,12 @@ class TestBiconnected(unittest.TestCase):
         }
         self.assertEqual(retworkx.biconnected_components(graph), components)
         self.assertEqual(retworkx.articulation_points(graph), {2, 3, 8, 9})
+        self.assertEqual(retworkx.bridges(graph), {(2, 3), (8, 9)})
+
+    def test_output_order(self):
+        graph = retworkx.generators.grid_graph(4, 4)
+        bicomp = retworkx.biconnected_components(graph)
+        self.assertEqual(sorted(bicomp), list(bicomp))
 
     def test_biconnected_graph(self):
         graph = retworkx.PyGraph()
@@ -133,6 +142,15 @@ class TestBiconnected(unittest.TestCase):
         )
         num_edges = graph.num_edges()
         self.assertEqual(retworkx.articulation_points(graph), set())
+        self.assertEqual(retworkx.bridges(graph), set())
         bicomp = retworkx.biconnected_components(graph)
         self.assertEqual(len(bicomp), num_edges)
         self.assertEqual(list(bicomp.values()), [0] * num_edges)
+
+    def test_tree(self):
+        graph = retworkx.generators.binomial_tree_graph(3)
+        edges = {tuple(sorted(edge)) for edge in graph.edge_list()}
+        bridges = {tuple(sorted(edge)) for edge in retworkx.bridges(graph)}
+        self.assertEqual(bridges, edges)
+        bicomp = retworkx.biconnected_components(graph)
+        self.assertEqual(len(set(bicomp.values())), graph.num_edges())
@@ -0,0 +1,56 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+
+import unittest
+
+import retworkx
+
+
+class TestBoundedReachability(unittest.TestCase):
+    def setUp(self):
+        self.graph = retworkx.PyGraph()
+        self.graph.extend_from_edge_list([(0, 1), (0, 2), (1, 3), (2, 3), (3, 4), (5, 0)])
+
+    def test_descendants_at_distance(self):
+        for distance, expected in enumerate([{0}, {1, 2, 5}, {3}, {4}, set()]):
+            res = retworkx.graph_descendants_at_distance(self.graph, 0, distance)
+            self.assertEqual(expected, res)
+
+    def test_descendants_at_distance_dispatch(self):
+        self.assertEqual({4}, retworkx.descendants_at_distance(self.graph, 0, 3))
+
+    def test_descendants_at_distance_matches_dijkstra(self):
+        graph = retworkx.generators.grid_graph(5, 6)
+        lengths = retworkx.graph_dijkstra_shortest_path_lengths(graph, 7, lambda _: 1)
+        for distance in range(1, 10):
+            expected = {node for node, length in lengths.items() if length == distance}
+            res = retworkx.graph_descendants_at_distance(graph, 7, distance)
+            self.assertEqual(expected, res)
+
+    def test_reachable_within(self):
+        expected = [{0}, {0, 1, 2, 5}, {0, 1, 2, 3, 5}, {0, 1, 2, 3, 4, 5}]
+        for max_depth, nodes in enumerate(expected):
+            res = retworkx.graph_reachable_within(self.graph, 0, max_depth)
+            self.assertEqual(nodes, res)
+        self.assertEqual({3, 4}, retworkx.reachable_within(self.graph, 4, 1))
+
+    def test_disconnected(self):
+        self.graph.add_node(None)
+        self.assertEqual({6}, retworkx.graph_reachable_within(self.graph, 6, 3))
+        self.assertEqual(set(), retworkx.graph_descendants_at_distance(self.graph, 6, 1))
+
+    def test_invalid_node(self):
+        with self.assertRaises(retworkx.InvalidNode):
+            retworkx.graph_descendants_at_distance(self.graph, 10, 1)
+        with self.assertRaises(retworkx.InvalidNode):
+            retworkx.graph_reachable_within(self.graph, 10, 1)
@@ -58,3 +58,9 @@ class TestCartesianProduct(unittest.TestCase):
 
         graph_product, _ = retworkx.graph_cartesian_product(graph_1, graph_2)
         self.assertEqual(["w_1", "w_1", "w_2", "w_2"], graph_product.edges())
+
+    def test_node_map_order_cartesian(self):
+        graph_1 = retworkx.generators.path_graph(5)
+        graph_2 = retworkx.generators.cycle_graph(4)
+        graph_product, node_map = retworkx.graph_cartesian_product(graph_1, graph_2)
+        self.assertEqual(list(graph_product.node_indices()), list(node_map.values()))
@@ -10,6 +10,7 @@
 # License for the specific language governing permissions and limitations
 # under the License.
 
+import math
 import unittest
 
 import retworkx
@@ -99,3 +100,386 @@ class TestCentralityGraphDeletedNode(unittest.TestCase):
         )
         expected = {0: 0.0, 1: 2.0, 2: 2.0, 4: 0.0}
         self.assertEqual(expected, betweenness)
+
+
+class TestEigenvectorCentrality(unittest.TestCase):
+    def setUp(self):
+        self.graph = retworkx.PyGraph()
+        self.graph.add_nodes_from(list(range(5)))
+        self.graph.add_edges_from([(0, 1, 2.0), (1, 2, 1.0), (2, 0, 1.0), (2, 3, 3.0), (3, 4, 1.0)])
+
+    def test_eigenvector_centrality(self):
+        centrality = retworkx.graph_eigenvector_centrality(self.graph, tol=1e-8)
+        expected = {
+            0: 0.49715367145614175,
+            1: 0.49715367145614175,
+            2: 0.6037035301741892,
+            3: 0.34248530309913905,
+            4: 0.15466841543013854,
+        }
+        self.assertEqual(sorted(expected), sorted(centrality.keys()))
+        for node, value in expected.items():
+            self.assertAlmostEqual(value, centrality[node], places=5)
+
+    def test_eigenvector_centrality_weighted(self):
+        centrality = retworkx.graph_eigenvector_centrality(
+            self.graph, weight_fn=float, max_iter=1000, tol=1e-8
+        )
+        expected = {
+            0: 0.3663504768670998,
+            1: 0.3663504768670998,
+            2: 0.6376178111785687,
+            3: 0.5507606928376244,
+            4: 0.14724415876824445,
+        }
+        for node, value in expected.items():
+            self.assertAlmostEqual(value, centrality[node], places=5)
+
+    def test_eigenvector_centrality_weight_attr(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(self.graph.node_indices())
+        for source, target, weight in self.graph.weighted_edge_list():
+            graph.add_edge(source, target, {"weight": float(weight)})
+        expected = retworkx.graph_eigenvector_centrality(self.graph, weight_fn=float)
+        centrality = retworkx.graph_eigenvector_centrality(graph, weight_attr="weight")
+        for node, value in expected.items():
+            self.assertAlmostEqual(value, centrality[node], places=5)
+        expected = retworkx.graph_katz_centrality(self.graph, weight_fn=float)
+        centrality = retworkx.graph_katz_centrality(graph, weight_attr="weight")
+        for node, value in expected.items():
+            self.assertAlmostEqual(value, centrality[node], places=5)
+
+    def test_eigenvector_centrality_deleted_node(self):
+        self.graph.remove_node(4)
+        centrality = retworkx.graph_eigenvector_centrality(self.graph)
+        self.assertEqual([0, 1, 2, 3], sorted(centrality.keys()))
+        self.assertAlmostEqual(1.0, sum(value**2 for value in centrality.values()))
+
+    def test_eigenvector_centrality_failed_to_converge(self):
+        with self.assertRaises(retworkx.FailedToConverge):
+            retworkx.graph_eigenvector_centrality(self.graph, max_iter=1)
+
+    def test_eigenvector_centrality_empty(self):
+        self.assertEqual({}, retworkx.graph_eigenvector_centrality(retworkx.PyGraph()))
+
+
+class TestKatzCentrality(unittest.TestCase):
+    def setUp(self):
+        self.graph = retworkx.PyGraph()
+        self.graph.add_nodes_from(list(range(5)))
+        self.graph.add_edges_from([(0, 1, 2.0), (1, 2, 1.0), (2, 0, 1.0), (2, 3, 3.0), (3, 4, 1.0)])
+
+    def test_katz_centrality_weighted(self):
+        centrality = retworkx.graph_katz_centrality(self.graph, weight_fn=float, tol=1e-8)
+        expected = {
+            0: 0.43190185628396843,
+            1: 0.43190185628396843,
+            2: 0.5248109759979689,
+            3: 0.48463405927719244,
+            4: 0.3415037937380708,
+        }
+        self.assertEqual(sorted(expected), sorted(centrality.keys()))
+        for node, value in expected.items():
+            self.assertAlmostEqual(value, centrality[node], places=6)
+
+    def test_katz_centrality_beta_dict(self):
+        beta = {0: 1, 1: 2, 2: 1, 3: 1, 4: 3}
+        centrality = retworkx.graph_katz_centrality(
+            self.graph, beta=beta, normalized=False, tol=1e-8
+        )
+        expected = {
+            0: 1.3802956483454,
+            1: 2.2893865574363,
+            2: 1.5135699358335999,
+            3: 1.4660171645239,
+            4: 3.1466017161447,
+        }
+        for node, value in expected.items():
+            self.assertAlmostEqual(value, centrality[node], places=6)
+
+    def test_katz_centrality_beta_missing_node(self):
+        with self.assertRaises(ValueError):
+            retworkx.graph_katz_centrality(self.graph, beta={0: 1.0, 1: 1.0})
+
+    def test_katz_centrality_failed_to_converge(self):
+        with self.assertRaises(retworkx.FailedToConverge):
+            retworkx.graph_katz_centrality(self.graph, alpha=1.0)
+
+    def test_katz_centrality_universal(self):
+        centrality = retworkx.katz_centrality(self.graph, alpha=0.05, normalized=False)
+        self.assertEqual(5, len(centrality))
+        for value in centrality.values():
+            self.assertGreater(value, 1.0)
+
+
+class TestClosenessHarmonicCentrality(unittest.TestCase):
+    def setUp(self):
+        self.graph = retworkx.PyGraph()
+        self.graph.add_nodes_from(range(7))
+        self.graph.add_edges_from(
+            [(0, 1, 1.0), (1, 2, 2.0), (2, 3, 1.0), (0, 3, 4.0), (1, 3, 1.5), (5, 6, 1.0)]
+        )
+
+    def assertCentrality(self, expected, centrality):
+        self.assertEqual(sorted(expected), sorted(centrality.keys()))
+        for node, value in expected.items():
+            self.assertAlmostEqual(value, centrality[node])
+
+    def test_closeness_centrality(self):
+        centrality = retworkx.graph_closeness_centrality(self.graph)
+        expected = {0: 0.375, 1: 0.5, 2: 0.375, 3: 0.5, 4: 0.0, 5: 1 / 6, 6: 1 / 6}
+        self.assertCentrality(expected, centrality)
+
+    def test_closeness_centrality_not_wf_improved(self):
+        centrality = retworkx.graph_closeness_centrality(self.graph, wf_improved=False)
+        expected = {0: 0.75, 1: 1.0, 2: 0.75, 3: 1.0, 4: 0.0, 5: 1.0, 6: 1.0}
+        self.assertCentrality(expected, centrality)
+
+    def test_closeness_centrality_weighted(self):
+        centrality = retworkx.graph_closeness_centrality(self.graph, weight_fn=float)
+        expected = {0: 3 / 13, 1: 1 / 3, 2: 0.25, 3: 0.3, 4: 0.0, 5: 1 / 6, 6: 1 / 6}
+        self.assertCentrality(expected, centrality)
+
+    def test_closeness_centrality_parallel(self):
+        graph = retworkx.generators.grid_graph(10, 10)
+        serial = retworkx.graph_closeness_centrality(graph, parallel_threshold=1000)
+        parallel = retworkx.graph_closeness_centrality(graph, parallel_threshold=1)
+        self.assertEqual(dict(serial), dict(parallel))
+
+    def test_closeness_centrality_negative_weight(self):
+        with self.assertRaises(ValueError):
+            retworkx.graph_closeness_centrality(self.graph, weight_fn=lambda _: -1.0)
+
+    def test_closeness_centrality_deleted_node(self):
+        self.graph.remove_node(2)
+        centrality = retworkx.graph_closeness_centrality(self.graph, wf_improved=False)
+        self.assertEqual([0, 1, 3, 4, 5, 6], sorted(centrality.keys()))
+        self.assertAlmostEqual(1.0, centrality[1])
+
+    def test_harmonic_centrality(self):
+        centrality = retworkx.graph_harmonic_centrality(self.graph)
+        expected = {0: 2.5, 1: 3.0, 2: 2.5, 3: 3.0, 4: 0.0, 5: 1.0, 6: 1.0}
+        self.assertCentrality(expected, centrality)
+
+    def test_harmonic_centrality_weighted(self):
+        centrality = retworkx.graph_harmonic_centrality(self.graph, weight_fn=float)
+        expected = {
+            0: 1.7333333333333334,
+            1: 2.1666666666666665,
+            2: 1.8333333333333333,
+            3: 2.0666666666666664,
+            4: 0.0,
+            5: 1.0,
+            6: 1.0,
+        }
+        self.assertCentrality(expected, centrality)
+
+    def test_universal(self):
+        self.assertEqual(
+            dict(retworkx.graph_closeness_centrality(self.graph)),
+            dict(retworkx.closeness_centrality(self.graph)),
+        )
+        self.assertEqual(
+            dict(retworkx.graph_harmonic_centrality(self.graph, weight_fn=float)),
+            dict(retworkx.harmonic_centrality(self.graph, weight_fn=float)),
+        )
+
+
+class TestBetweennessCentralitySubset(unittest.TestCase):
+    def setUp(self):
+        self.graph = retworkx.PyGraph()
+        self.graph.add_nodes_from(range(7))
+        self.graph.add_edges_from(
+            [
+                (0, 1, 1.0),
+                (1, 2, 1.0),
+                (2, 3, 1.0),
+                (0, 4, 1.0),
+                (4, 3, 2.0),
+                (3, 5, 1.0),
+                (1, 4, 1.0),
+            ]
+        )
+
+    def assertCentrality(self, expected, centrality):
+        self.assertEqual(sorted(expected), sorted(centrality.keys()))
+        for node, value in expected.items():
+            self.assertAlmostEqual(value, centrality[node])
+
+    def test_betweenness_centrality_subset(self):
+        centrality = retworkx.graph_betweenness_centrality_subset(self.graph, [0, 1], [3, 5])
+        expected = {0: 0.0, 1: 0.0, 2: 0.5, 3: 1.0, 4: 1.5, 5: 0.0, 6: 0.0}
+        self.assertCentrality(expected, centrality)
+
+    def test_betweenness_centrality_subset_normalized(self):
+        centrality = retworkx.graph_betweenness_centrality_subset(
+            self.graph, [0, 1], [3, 5], normalized=True
+        )
+        expected = {0: 0.0, 1: 0.0, 2: 1 / 30, 3: 1 / 15, 4: 0.1, 5: 0.0, 6: 0.0}
+        self.assertCentrality(expected, centrality)
+
+    def test_betweenness_centrality_subset_weighted(self):
+        centrality = retworkx.graph_betweenness_centrality_subset(
+            self.graph, [0, 1], [3, 5], weight_fn=float
+        )
+        expected = {0: 0.0, 1: 0.5, 2: 1.5, 3: 1.0, 4: 0.5, 5: 0.0, 6: 0.0}
+        self.assertCentrality(expected, centrality)
+
+    def test_betweenness_centrality_subset_all_nodes(self):
+        nodes = list(self.graph.node_indices())
+        subset = retworkx.graph_betweenness_centrality_subset(self.graph, nodes, nodes)
+        full = retworkx.graph_betweenness_centrality(self.graph, normalized=False)
+        self.assertCentrality(dict(full), subset)
+
+    def test_betweenness_centrality_subset_parallel(self):
+        graph = retworkx.generators.grid_graph(10, 10)
+        sources = list(range(0, 100, 3))
+        targets = list(range(1, 100, 7))
+        serial = retworkx.graph_betweenness_centrality_subset(
+            graph, sources, targets, parallel_threshold=1000
+        )
+        parallel = retworkx.graph_betweenness_centrality_subset(
+            graph, sources, targets, parallel_threshold=1
+        )
+        self.assertCentrality(dict(serial), parallel)
+
+    def test_betweenness_centrality_subset_invalid_node(self):
+        with self.assertRaises(IndexError):
+            retworkx.graph_betweenness_centrality_subset(self.graph, [0], [42])
+
+    def test_betweenness_centrality_subset_universal(self):
+        centrality = retworkx.betweenness_centrality_subset(self.graph, [0, 1], [3, 5])
+        self.assertEqual(1.5, centrality[4])
+
+
+class TestVitality(unittest.TestCase):
+    def test_closeness_vitality(self):
+        graph = retworkx.generators.cycle_graph(5)
+        vitality = retworkx.graph_closeness_vitality(graph)
+        self.assertEqual({i: 5.0 for i in range(5)}, dict(vitality))
+
+    def test_closeness_vitality_cut_node(self):
+        graph = retworkx.generators.path_graph(4)
+        vitality = retworkx.graph_closeness_vitality(graph)
+        self.assertEqual(
+            {0: 6.0, 1: -math.inf, 2: -math.inf, 3: 6.0}, dict(vitality)
+        )
+
+    def test_closeness_vitality_disconnected(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(list(range(4)))
+        graph.add_edges_from_no_data([(0, 1), (2, 3)])
+        vitality = retworkx.graph_closeness_vitality(graph)
+        self.assertTrue(all(math.isnan(x) for x in vitality.values()))
+
+    def test_closeness_vitality_weighted(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(list(range(3)))
+        graph.add_edges_from([(0, 1, 1.0), (1, 2, 1.0), (0, 2, 3.0)])
+        vitality = retworkx.graph_closeness_vitality(graph, weight_fn=float)
+        self.assertEqual({0: 3.0, 1: 1.0, 2: 3.0}, dict(vitality))
+
+    def test_closeness_vitality_deleted_node(self):
+        graph = retworkx.generators.cycle_graph(6)
+        graph.remove_node(0)
+        vitality = retworkx.graph_closeness_vitality(graph)
+        self.assertEqual([1, 2, 3, 4, 5], sorted(vitality))
+        self.assertEqual(10.0, vitality[1])
+        self.assertEqual(-math.inf, vitality[3])
+
+    def test_closeness_vitality_universal(self):
+        graph = retworkx.generators.star_graph(4)
+        vitality = retworkx.closeness_vitality(graph)
+        self.assertEqual({0: -math.inf, 1: 5.0, 2: 5.0, 3: 5.0}, dict(vitality))
+
+    def test_flow_vitality(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(list(range(4)))
+        graph.add_edges_from_no_data([(0, 1), (1, 2), (2, 3), (3, 0), (0, 2)])
+        vitality = retworkx.flow_vitality(graph)
+        self.assertEqual({0: 3.0, 1: 1.0, 2: 3.0, 3: 1.0}, dict(vitality))
+
+    def test_flow_vitality_capacities(self):
+        graph = retworkx.generators.path_graph(3)
+        for edge, capacity in zip(graph.edge_list(), [2, 5]):
+            graph.update_edge(*edge, capacity)
+        vitality = retworkx.flow_vitality(graph, weight_fn=lambda x: x)
+        # Only the flow of 2 between 0 and 2 goes through node 1
+        self.assertEqual({0: 0.0, 1: 2.0, 2: 0.0}, dict(vitality))
+
+    def test_flow_vitality_empty(self):
+        self.assertEqual({}, dict(retworkx.flow_vitality(retworkx.PyGraph())))
+
+
+class TestTopKCentrality(unittest.TestCase):
+    def assertTopK(self, expected, top_k, k):
+        expected_scores = sorted(expected.values(), reverse=True)[:k]
+        self.assertEqual(len(expected_scores), len(top_k))
+        for (node, score), expected_score in zip(top_k, expected_scores):
+            self.assertAlmostEqual(expected[node], score)
+            self.assertAlmostEqual(expected_score, score)
+
+    def test_top_k_degree_centrality(self):
+        graph = retworkx.generators.star_graph(5)
+        graph.add_edge(1, 2, None)
+        graph.add_edge(3, 3, None)
+        top_k = retworkx.graph_top_k_degree_centrality(graph, 3)
+        # The self loop counts twice in the degree of node 3
+        self.assertEqual([(0, 1.0), (3, 0.75), (1, 0.5)], top_k)
+        self.assertEqual(top_k, retworkx.top_k_degree_centrality(graph, 3))
+
+    def test_top_k_degree_centrality_k_larger_than_graph(self):
+        graph = retworkx.generators.path_graph(3)
+        top_k = retworkx.graph_top_k_degree_centrality(graph, 10)
+        self.assertEqual([(1, 1.0), (0, 0.5), (2, 0.5)], top_k)
+        self.assertEqual([], retworkx.graph_top_k_degree_centrality(graph, 0))
+
+    def test_top_k_closeness_centrality(self):
+        graph = retworkx.undirected_gnp_random_graph(60, 0.08, seed=12)
+        for wf_improved in [True, False]:
+            expected = retworkx.graph_closeness_centrality(graph, wf_improved=wf_improved)
+            for k in [1, 5, 60]:
+                top_k = retworkx.graph_top_k_closeness_centrality(
+                    graph, k, wf_improved=wf_improved
+                )
+                self.assertTopK(expected, top_k, k)
+
+    def test_top_k_closeness_centrality_disconnected(self):
+        graph = retworkx.generators.path_graph(7)
+        graph.add_nodes_from([None, None, None])
+        graph.add_edges_from_no_data([(7, 8), (8, 9)])
+        graph.remove_node(3)
+        expected = retworkx.graph_closeness_centrality(graph)
+        top_k = retworkx.top_k_closeness_centrality(graph, 3)
+        self.assertTopK(expected, top_k, 3)
+
+    def test_top_k_betweenness_centrality(self):
+        graph = retworkx.generators.barbell_graph(5, 3)
+        expected = retworkx.graph_betweenness_centrality(graph)
+        top_k = retworkx.graph_top_k_betweenness_centrality(graph, 3, epsilon=0.02, seed=42)
+        # The middle of the path between the 2 complete graphs is the most central
+        self.assertEqual(6, top_k[0][0])
+        self.assertEqual({5, 7}, {node for node, _ in top_k[1:]})
+        scale = len(graph) / (len(graph) - 2)
+        for node, score in top_k:
+            self.assertAlmostEqual(expected[node], score, delta=0.02 * scale)
+
+    def test_top_k_betweenness_centrality_not_normalized(self):
+        graph = retworkx.generators.star_graph(6)
+        top_k = retworkx.top_k_betweenness_centrality(graph, 2, normalized=False, seed=1)
+        self.assertEqual(0, top_k[0][0])
+        self.assertAlmostEqual(10.0, top_k[0][1], delta=0.01 * 15)
+        self.assertEqual((1, 0.0), top_k[1])
+
+    def test_top_k_betweenness_centrality_same_seed(self):
+        graph = retworkx.undirected_gnp_random_graph(30, 0.1, seed=3)
+        first = retworkx.graph_top_k_betweenness_centrality(graph, 5, epsilon=0.1, seed=7)
+        second = retworkx.graph_top_k_betweenness_centrality(graph, 5, epsilon=0.1, seed=7)
+        self.assertEqual(first, second)
+
+    def test_top_k_betweenness_centrality_invalid(self):
+        graph = retworkx.generators.path_graph(4)
+        with self.assertRaises(ValueError):
+            retworkx.graph_top_k_betweenness_centrality(graph, 1, epsilon=0.0)
+        with self.assertRaises(ValueError):
+            retworkx.graph_top_k_betweenness_centrality(graph, 1, delta=1.0)
@@ -0,0 +1,105 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import itertools
+import unittest
+
+import retworkx
+
+
+def brute_force_cliques(graph):
+    nodes = list(graph.node_indices())
+    cliques = [
+        set(subset)
+        for size in range(1, len(nodes) + 1)
+        for subset in itertools.combinations(nodes, size)
+        if all(graph.has_edge(a, b) for a, b in itertools.combinations(subset, 2))
+    ]
+    return sorted(
+        sorted(clique) for clique in cliques if not any(clique < other for other in cliques)
+    )
+
+
+class TestFindCliques(unittest.TestCase):
+    def test_find_cliques(self):
+        # 2 triangles sharing an edge and a pendant node
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(range(5))
+        graph.add_edges_from_no_data([(0, 1), (1, 2), (2, 0), (1, 3), (2, 3), (3, 4)])
+        cliques = sorted(sorted(clique) for clique in retworkx.find_cliques(graph))
+        self.assertEqual([[0, 1, 2], [1, 2, 3], [3, 4]], cliques)
+
+    def test_random_graphs(self):
+        for seed in range(10):
+            graph = retworkx.undirected_gnp_random_graph(12, 0.5, seed=seed)
+            cliques = sorted(sorted(clique) for clique in retworkx.find_cliques(graph))
+            self.assertEqual(brute_force_cliques(graph), cliques)
+
+    def test_complete_graph(self):
+        graph = retworkx.generators.mesh_graph(6)
+        cliques = [sorted(clique) for clique in retworkx.find_cliques(graph)]
+        self.assertEqual([list(range(6))], cliques)
+
+    def test_isolated_nodes(self):
+        graph = retworkx.generators.path_graph(3)
+        graph.add_node(None)
+        cliques = sorted(sorted(clique) for clique in retworkx.find_cliques(graph))
+        self.assertEqual([[0, 1], [1, 2], [3]], cliques)
+
+    def test_self_loops_and_parallel_edges(self):
+        graph = retworkx.generators.cycle_graph(3)
+        graph.add_edges_from_no_data([(0, 0), (0, 1)])
+        cliques = [sorted(clique) for clique in retworkx.find_cliques(graph)]
+        self.assertEqual([[0, 1, 2]], cliques)
+
+    def test_removed_nodes(self):
+        graph = retworkx.generators.mesh_graph(5)
+        graph.remove_node(2)
+        cliques = [sorted(clique) for clique in retworkx.find_cliques(graph)]
+        self.assertEqual([[0, 1, 3, 4]], cliques)
+
+    def test_lazy(self):
+        graph = retworkx.generators.grid_graph(100, 100)
+        cliques = retworkx.find_cliques(graph)
+        self.assertIsInstance(cliques, retworkx.MaximalCliqueIterator)
+        first = next(cliques)
+        self.assertEqual(2, len(first))
+        self.assertTrue(graph.has_edge(first[0], first[1]))
+        # The graph can be modified while iterating
+        graph.remove_nodes_from(list(graph.node_indices()))
+        self.assertEqual(19799, len(list(cliques)))
+
+    def test_empty_graph(self):
+        self.assertEqual([], list(retworkx.find_cliques(retworkx.PyGraph())))
+
+
+class TestMaxClique(unittest.TestCase):
+    def test_max_clique(self):
+        # A 4-clique and a triangle sharing a node
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(range(6))
+        graph.add_edges_from_no_data(
+            [(0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3), (3, 4), (4, 5), (5, 3)]
+        )
+        self.assertEqual([0, 1, 2, 3], sorted(retworkx.max_clique(graph)))
+
+    def test_random_graphs(self):
+        for seed in range(10):
+            graph = retworkx.undirected_gnp_random_graph(12, 0.5, seed=seed)
+            expected = max(len(clique) for clique in brute_force_cliques(graph))
+            clique = list(retworkx.max_clique(graph))
+            self.assertEqual(expected, len(clique))
+            for a, b in itertools.combinations(clique, 2):
+                self.assertTrue(graph.has_edge(a, b))
+
+    def test_empty_graph(self):
+        self.assertEqual([], list(retworkx.max_clique(retworkx.PyGraph())))
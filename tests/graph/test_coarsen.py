@@ -0,0 +1,85 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import retworkx
+
+
+class TestGraphCoarsen(unittest.TestCase):
+    def setUp(self):
+        # A path with heavy edges (0, 1), (2, 3), (4, 5) and (6, 7)
+        self.graph = retworkx.PyGraph()
+        self.graph.add_nodes_from(range(8))
+        self.graph.add_edges_from([(i, i + 1, 5.0 if i % 2 == 0 else 1.0) for i in range(7)])
+
+    def test_heavy_edge_matching(self):
+        res = retworkx.graph_coarsen(
+            self.graph, weight_fn=float, min_nodes=4, max_levels=1, seed=42
+        )
+        self.assertEqual(2, len(res))
+        self.assertEqual(1, len(res.graphs))
+        self.assertEqual({i: i // 2 for i in range(8)}, dict(res.node_maps[0]))
+        coarse = res.graphs[0]
+        self.assertEqual([2, 2, 2, 2], coarse.nodes())
+        self.assertEqual([(0, 1, 1.0), (1, 2, 1.0), (2, 3, 1.0)], coarse.weighted_edge_list())
+
+    def test_min_nodes(self):
+        graph = retworkx.generators.grid_graph(16, 16)
+        res = retworkx.graph_coarsen(graph, min_nodes=10, seed=42)
+        sizes = [len(graph)] + [len(level) for level in res.graphs]
+        self.assertTrue(all(a > b for a, b in zip(sizes, sizes[1:])))
+        self.assertLessEqual(len(res.graphs[-1]), 10)
+        for level in res.graphs:
+            self.assertEqual(256, sum(level.nodes()))
+
+    def test_level_node_map(self):
+        graph = retworkx.generators.grid_graph(8, 8)
+        graph.remove_node(0)
+        res = retworkx.graph_coarsen(graph, min_nodes=4, seed=7)
+        coarsest = len(res.graphs)
+        node_map = res.level_node_map(coarsest)
+        self.assertEqual(graph.node_indices(), list(node_map.keys()))
+        self.assertEqual(dict(res.level_node_map(0)), {node: node for node in graph.node_indices()})
+        for node, coarse in node_map.items():
+            expected = node
+            for level_map in res.node_maps:
+                expected = level_map[expected]
+            self.assertEqual(expected, coarse)
+
+    def test_prolong(self):
+        res = retworkx.graph_coarsen(
+            self.graph, weight_fn=float, min_nodes=4, max_levels=1, seed=42
+        )
+        values = res.prolong(1, ["a", "b", "c", "d"])
+        self.assertEqual({0: "a", 1: "a", 2: "b", 3: "b", 4: "c", 5: "c", 6: "d", 7: "d"}, values)
+        with self.assertRaises(ValueError):
+            res.prolong(1, ["a"])
+        with self.assertRaises(IndexError):
+            res.prolong(0, [])
+        with self.assertRaises(IndexError):
+            res.prolong(2, [])
+
+    def test_no_edges(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(range(5))
+        res = retworkx.graph_coarsen(graph, min_nodes=1)
+        self.assertEqual([], res.graphs)
+        self.assertEqual(1, len(res))
+
+    def test_parallel_edges_and_self_loops(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(range(4))
+        graph.add_edges_from([(0, 1, 1.0), (0, 1, 2.0), (1, 1, 10.0), (2, 3, 1.0), (1, 2, 0.5)])
+        res = retworkx.graph_coarsen(graph, weight_fn=float, min_nodes=1, max_levels=1)
+        self.assertEqual({0: 0, 1: 0, 2: 1, 3: 1}, dict(res.node_maps[0]))
+        self.assertEqual([(0, 1, 0.5)], res.graphs[0].weighted_edge_list())
@@ -44,3 +44,8 @@ class TestGraphColoring(unittest.TestCase):
         graph.add_edge(node_a, node_c, 1)
         res = retworkx.graph_greedy_color(graph)
         self.assertEqual({0: 0, 1: 1, 2: 1}, res)
+
+    def test_output_in_node_index_order(self):
+        graph = retworkx.generators.grid_graph(5, 5)
+        res = retworkx.graph_greedy_color(graph)
+        self.assertEqual(list(range(25)), list(res))
@@ -0,0 +1,149 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import retworkx
+
+
+class TestModularity(unittest.TestCase):
+    def setUp(self):
+        # Two triangles joined by the edge (2, 3)
+        self.graph = retworkx.PyGraph()
+        self.graph.add_nodes_from(range(6))
+        self.graph.add_edges_from(
+            [(0, 1, 1), (1, 2, 1), (0, 2, 1), (2, 3, 1), (3, 4, 1), (4, 5, 1), (3, 5, 1)]
+        )
+
+    def test_modularity(self):
+        modularity = retworkx.modularity(self.graph, [{0, 1, 2}, {3, 4, 5}])
+        self.assertAlmostEqual(5 / 14, modularity)
+
+    def test_modularity_single_community(self):
+        self.assertAlmostEqual(0.0, retworkx.modularity(self.graph, [range(6)]))
+
+    def test_modularity_missing_nodes_are_singletons(self):
+        self.assertAlmostEqual(
+            retworkx.modularity(self.graph, [[0, 1, 2], [3], [4], [5]]),
+            retworkx.modularity(self.graph, [[0, 1, 2]]),
+        )
+
+    def test_modularity_weights_and_resolution(self):
+        self.graph.update_edge(2, 3, 7)
+        split = [[0, 1, 2], [3, 4, 5]]
+        self.assertLess(
+            retworkx.modularity(self.graph, split, weight_fn=float),
+            retworkx.modularity(self.graph, split),
+        )
+        self.assertLess(
+            retworkx.modularity(self.graph, split, resolution=2.0),
+            retworkx.modularity(self.graph, split),
+        )
+
+    def test_modularity_no_edges(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(range(3))
+        self.assertEqual(0.0, retworkx.modularity(graph, [[0, 1], [2]]))
+
+    def test_modularity_invalid(self):
+        with self.assertRaises(ValueError):
+            retworkx.modularity(self.graph, [[0, 1], [1, 2]])
+        with self.assertRaises(retworkx.InvalidNode):
+            retworkx.modularity(self.graph, [[0, 10]])
+
+
+class TestLouvainCommunities(unittest.TestCase):
+    def setUp(self):
+        # Three cliques of 5 nodes joined in a ring by single edges
+        self.graph = retworkx.PyGraph()
+        self.graph.add_nodes_from(range(15))
+        for start in range(0, 15, 5):
+            for u in range(start, start + 5):
+                for v in range(u + 1, start + 5):
+                    self.graph.add_edge(u, v, None)
+        self.graph.add_edges_from_no_data([(4, 5), (9, 10), (14, 0)])
+        self.cliques = [set(range(start, start + 5)) for start in range(0, 15, 5)]
+
+    def assertPartition(self, communities, nodes):
+        self.assertEqual(sorted(nodes), sorted(n for c in communities for n in c))
+
+    def test_louvain_communities(self):
+        communities = retworkx.louvain_communities(self.graph, seed=42)
+        self.assertEqual(self.cliques, communities)
+
+    def test_louvain_communities_same_seed(self):
+        graph = retworkx.undirected_gnp_random_graph(60, 0.1, seed=3)
+        first = retworkx.louvain_communities(graph, seed=7)
+        second = retworkx.louvain_communities(graph, seed=7)
+        self.assertEqual(first, second)
+        self.assertPartition(first, graph.node_indices())
+        self.assertGreater(retworkx.modularity(graph, first), 0.2)
+
+    def test_louvain_communities_resolution(self):
+        communities = retworkx.louvain_communities(self.graph, resolution=0.01, seed=42)
+        self.assertEqual([set(range(15))], communities)
+
+    def test_louvain_communities_weights(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(range(4))
+        graph.add_edges_from([(0, 1, 1.0), (1, 2, 10.0), (2, 3, 1.0), (3, 0, 10.0)])
+        communities = retworkx.louvain_communities(graph, weight_fn=float, seed=1)
+        self.assertEqual([{0, 3}, {1, 2}], communities)
+
+    def test_louvain_communities_must_link(self):
+        communities = retworkx.louvain_communities(
+            self.graph, seed=42, must_link=[(0, 7), (7, 12)]
+        )
+        self.assertPartition(communities, range(15))
+        self.assertTrue(any({0, 7, 12} <= community for community in communities))
+
+    def test_louvain_communities_cannot_link(self):
+        cannot_link = [(0, 1), (5, 6), (10, 11)]
+        communities = retworkx.louvain_communities(self.graph, seed=42, cannot_link=cannot_link)
+        self.assertPartition(communities, range(15))
+        for u, v in cannot_link:
+            self.assertFalse(any({u, v} <= community for community in communities))
+
+    def test_louvain_communities_cannot_link_random_graphs(self):
+        for seed in range(10):
+            graph = retworkx.undirected_gnp_random_graph(40, 0.15, seed=seed)
+            cannot_link = [(i, i + 1) for i in range(0, 40, 4)]
+            communities = retworkx.louvain_communities(
+                graph, seed=seed, must_link=[(1, 3)], cannot_link=cannot_link
+            )
+            self.assertPartition(communities, range(40))
+            for u, v in cannot_link:
+                self.assertFalse(any({u, v} <= community for community in communities))
+            self.assertTrue(any({1, 3} <= community for community in communities))
+
+    def test_louvain_communities_conflicting_constraints(self):
+        with self.assertRaises(ValueError):
+            retworkx.louvain_communities(
+                self.graph, must_link=[(0, 1), (1, 2)], cannot_link=[(2, 0)]
+            )
+
+    def test_louvain_communities_invalid_node(self):
+        with self.assertRaises(retworkx.InvalidNode):
+            retworkx.louvain_communities(self.graph, must_link=[(0, 20)])
+
+    def test_louvain_communities_no_edges(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(range(3))
+        graph.remove_node(1)
+        self.assertEqual([{0}, {2}], retworkx.louvain_communities(graph))
+        self.assertEqual([], retworkx.louvain_communities(retworkx.PyGraph()))
+
+    def test_louvain_communities_deleted_nodes(self):
+        self.graph.remove_node(3)
+        communities = retworkx.louvain_communities(self.graph, seed=42)
+        self.assertPartition(communities, self.graph.node_indices())
+        self.assertEqual(3, len(communities))
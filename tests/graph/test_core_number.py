@@ -94,3 +94,36 @@ class TestCoreNumber(unittest.TestCase):
         res = retworkx.core_number(graph)
         self.assertIsInstance(res, dict)
         self.assertEqual(res, self.example_core)
+
+    def test_undirected_removed_node(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(list(range(21)))
+        graph.add_edges_from_no_data(self.example_edges)
+        graph.remove_node(20)
+        res = retworkx.core_number(graph)
+        del self.example_core[20]
+        self.assertEqual(res, self.example_core)
+
+    def test_undirected_k_core(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(list(range(21)))
+        graph.add_edges_from_no_data(self.example_edges)
+        for k in range(4):
+            core = retworkx.k_core(graph, k)
+            self.assertIsInstance(core, retworkx.PyGraph)
+            expected = [node for node, core in self.example_core.items() if core >= k]
+            self.assertEqual(core.nodes(), expected)
+        self.assertEqual(retworkx.k_core(graph).nodes(), list(range(8)))
+        self.assertEqual(retworkx.k_core(graph, 4).num_nodes(), 0)
+
+    def test_undirected_k_core_edges(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(list(range(4)))
+        graph.add_edges_from([(0, 1, "a"), (1, 2, "b"), (2, 0, "c"), (2, 3, "d")])
+        core = retworkx.graph_k_core(graph, k=2)
+        self.assertEqual(core.nodes(), [0, 1, 2])
+        self.assertEqual(sorted(core.edges()), ["a", "b", "c"])
+
+    def test_undirected_k_core_empty(self):
+        graph = retworkx.PyGraph()
+        self.assertEqual(retworkx.k_core(graph).num_nodes(), 0)
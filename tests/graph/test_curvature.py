@@ -0,0 +1,101 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+
+import unittest
+
+import retworkx
+
+
+class TestFormanCurvature(unittest.TestCase):
+    def test_complete_graph(self):
+        graph = retworkx.generators.mesh_graph(4)
+        res = retworkx.forman_curvature(graph)
+        self.assertEqual({edge: -2.0 for edge in graph.edge_indices()}, dict(res.items()))
+
+    def test_path_graph(self):
+        graph = retworkx.generators.path_graph(4)
+        res = retworkx.graph_forman_curvature(graph)
+        self.assertEqual({0: 1.0, 1: 0.0, 2: 1.0}, dict(res.items()))
+
+    def test_weighted(self):
+        graph = retworkx.PyGraph()
+        graph.extend_from_weighted_edge_list([(0, 1, 1.0), (0, 2, 4.0)])
+        res = retworkx.forman_curvature(graph, weight_fn=float)
+        self.assertEqual({0: 1.5, 1: 0.0}, dict(res.items()))
+
+    def test_self_loops_ignored(self):
+        graph = retworkx.generators.path_graph(4)
+        loop = graph.add_edge(1, 1, None)
+        res = retworkx.forman_curvature(graph)
+        self.assertNotIn(loop, res)
+        self.assertEqual({0: 1.0, 1: 0.0, 2: 1.0}, dict(res.items()))
+
+    def test_removed_edge(self):
+        graph = retworkx.generators.path_graph(4)
+        graph.remove_edge_from_index(0)
+        res = retworkx.forman_curvature(graph)
+        self.assertEqual({1: 1.0, 2: 1.0}, dict(res.items()))
+
+
+class TestOllivierRicciCurvature(unittest.TestCase):
+    def assertCurvatureAlmostEqual(self, expected, res):
+        self.assertEqual(sorted(expected), sorted(res.keys()))
+        for edge, curvature in expected.items():
+            self.assertAlmostEqual(curvature, res[edge], places=3)
+
+    def test_complete_graph(self):
+        graph = retworkx.generators.mesh_graph(4)
+        res = retworkx.ollivier_ricci_curvature(graph, alpha=0.0)
+        self.assertCurvatureAlmostEqual({edge: 2 / 3 for edge in graph.edge_indices()}, res)
+
+    def test_path_graph(self):
+        graph = retworkx.generators.path_graph(3)
+        res = retworkx.graph_ollivier_ricci_curvature(graph)
+        self.assertCurvatureAlmostEqual({0: 0.5, 1: 0.5}, res)
+        res = retworkx.graph_ollivier_ricci_curvature(graph, alpha=0.0)
+        self.assertCurvatureAlmostEqual({0: 0.0, 1: 0.0}, res)
+
+    def test_cycle_graph(self):
+        graph = retworkx.generators.cycle_graph(4)
+        res = retworkx.ollivier_ricci_curvature(graph, alpha=0.0)
+        self.assertCurvatureAlmostEqual({edge: 0.0 for edge in graph.edge_indices()}, res)
+
+    def test_scaled_lengths(self):
+        graph = retworkx.generators.mesh_graph(4)
+        res = retworkx.ollivier_ricci_curvature(graph, default_weight=2.0, alpha=0.0)
+        self.assertCurvatureAlmostEqual({edge: 2 / 3 for edge in graph.edge_indices()}, res)
+
+    def test_bridge_is_negatively_curved(self):
+        graph = retworkx.PyGraph()
+        graph.extend_from_edge_list([(0, 1), (1, 2), (2, 0), (2, 3), (3, 4), (4, 5), (5, 3)])
+        res = retworkx.ollivier_ricci_curvature(graph)
+        self.assertLess(res[3], 0.0)
+        for edge in [0, 1, 2, 4, 5, 6]:
+            self.assertGreater(res[edge], 0.0)
+
+    def test_self_loops_ignored(self):
+        graph = retworkx.generators.path_graph(3)
+        loop = graph.add_edge(1, 1, None)
+        res = retworkx.ollivier_ricci_curvature(graph)
+        self.assertNotIn(loop, res)
+        self.assertCurvatureAlmostEqual({0: 0.5, 1: 0.5}, res)
+
+    def test_invalid_alpha(self):
+        graph = retworkx.generators.path_graph(3)
+        with self.assertRaises(ValueError):
+            retworkx.ollivier_ricci_curvature(graph, alpha=2.0)
+
+    def test_invalid_reg(self):
+        graph = retworkx.generators.path_graph(3)
+        with self.assertRaises(ValueError):
+            retworkx.ollivier_ricci_curvature(graph, reg=0.0)
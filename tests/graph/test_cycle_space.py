@@ -0,0 +1,193 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import retworkx
+
+
+class TestMinimumCycleBasis(unittest.TestCase):
+    def setUp(self):
+        # A square with a diagonal and a pendant node
+        self.graph = retworkx.PyGraph()
+        self.graph.extend_from_weighted_edge_list(
+            [(0, 1, 2), (1, 2, 2), (2, 3, 2), (3, 0, 2), (0, 2, 1), (3, 4, 1)]
+        )
+
+    def assertCycle(self, graph, cycle):
+        # Consecutive edges of the cycle share an endpoint
+        endpoints = [set(graph.get_edge_endpoints_by_index(edge)) for edge in cycle]
+        for i, ends in enumerate(endpoints):
+            self.assertTrue(ends & endpoints[i - 1])
+        self.assertTrue(retworkx.is_in_cycle_space(graph, cycle))
+
+    def test_minimum_cycle_basis(self):
+        res = retworkx.minimum_cycle_basis(self.graph, weight_fn=float)
+        self.assertEqual(sorted(sorted(cycle) for cycle in res), [[0, 1, 4], [2, 3, 4]])
+        for cycle in res:
+            self.assertCycle(self.graph, cycle)
+
+    def test_minimum_cycle_basis_default_weight(self):
+        res = retworkx.minimum_cycle_basis(self.graph)
+        self.assertEqual(sorted(sorted(cycle) for cycle in res), [[0, 1, 4], [2, 3, 4]])
+
+    def test_minimum_cycle_basis_heavy_diagonal(self):
+        res = retworkx.minimum_cycle_basis(self.graph, default_weight=1.0)
+        self.assertEqual(len(res), 2)
+        res = retworkx.minimum_cycle_basis(self.graph, weight_fn=lambda w: 10.0 if w == 1 else 1.0)
+        self.assertIn([0, 1, 2, 3], [sorted(cycle) for cycle in res])
+
+    def test_minimum_cycle_basis_order(self):
+        graph = retworkx.generators.grid_graph(3, 4)
+        res = retworkx.minimum_cycle_basis(graph)
+        self.assertEqual(len(res), 6)
+        self.assertEqual([len(cycle) for cycle in res], [4] * 6)
+
+    def test_minimum_cycle_basis_forest(self):
+        graph = retworkx.generators.path_graph(5)
+        graph.add_node(None)
+        self.assertEqual(retworkx.minimum_cycle_basis(graph), [])
+
+    def test_minimum_cycle_basis_empty(self):
+        self.assertEqual(retworkx.minimum_cycle_basis(retworkx.PyGraph()), [])
+
+    def test_minimum_cycle_basis_multigraph(self):
+        graph = retworkx.PyGraph()
+        graph.extend_from_edge_list([(0, 1), (0, 1), (1, 1), (1, 2)])
+        res = retworkx.minimum_cycle_basis(graph)
+        self.assertEqual(sorted(sorted(cycle) for cycle in res), [[0, 1], [2]])
+
+    def test_minimum_cycle_basis_multiple_components(self):
+        graph = retworkx.generators.cycle_graph(4)
+        graph.compose(retworkx.generators.mesh_graph(4), {})
+        res = retworkx.minimum_cycle_basis(graph)
+        self.assertEqual(len(res), 1 + 3)
+        self.assertEqual(sorted(len(cycle) for cycle in res), [3, 3, 3, 4])
+
+    def test_minimum_cycle_basis_invalid_weight(self):
+        with self.assertRaises(ValueError):
+            retworkx.minimum_cycle_basis(self.graph, weight_fn=lambda _: -1.0)
+        with self.assertRaises(ValueError):
+            retworkx.minimum_cycle_basis(self.graph, default_weight=float("nan"))
+
+    def test_minimum_cycle_basis_weight_fn_raises(self):
+        def weight_fn(_):
+            raise IndexError
+
+        with self.assertRaises(IndexError):
+            retworkx.minimum_cycle_basis(self.graph, weight_fn=weight_fn)
+
+    def test_minimum_cycle_basis_digraph(self):
+        with self.assertRaises(TypeError):
+            retworkx.minimum_cycle_basis(retworkx.PyDiGraph())
+
+
+class TestMinimumCycleCover(unittest.TestCase):
+    def test_minimum_cycle_cover(self):
+        # Two squares sharing an edge, with a bridge to a triangle
+        graph = retworkx.PyGraph()
+        graph.extend_from_edge_list(
+            [(0, 1), (1, 2), (2, 3), (3, 0), (1, 4), (4, 5), (5, 2), (5, 6)]
+        )
+        graph.extend_from_edge_list([(6, 7), (7, 8), (8, 6)])
+        res = retworkx.minimum_cycle_cover(graph)
+        self.assertEqual(len(res), 3)
+        covered = {edge for cycle in res for edge in cycle}
+        self.assertEqual(covered, set(graph.edge_indices()) - {7})
+
+    def test_minimum_cycle_cover_drops_redundant_cycles(self):
+        # The square 0-1-2-3 is lighter than the outer cycle but all of its
+        # edges are on the two heavier cycles through the node 4
+        graph = retworkx.PyGraph()
+        graph.extend_from_weighted_edge_list(
+            [
+                (0, 1, 1),
+                (1, 2, 1),
+                (2, 3, 1),
+                (3, 0, 1),
+                (0, 4, 2),
+                (4, 2, 2),
+            ]
+        )
+        res = retworkx.minimum_cycle_cover(graph, weight_fn=float)
+        covered = {edge for cycle in res for edge in cycle}
+        self.assertEqual(covered, set(graph.edge_indices()))
+        self.assertEqual(len(res), 2)
+
+    def test_minimum_cycle_cover_tree(self):
+        graph = retworkx.generators.star_graph(5)
+        self.assertEqual(retworkx.minimum_cycle_cover(graph), [])
+
+
+class TestCycleSpace(unittest.TestCase):
+    def setUp(self):
+        # Two triangles sharing the edge (1, 2)
+        self.graph = retworkx.PyGraph()
+        self.graph.extend_from_edge_list([(0, 1), (1, 2), (2, 0), (1, 3), (3, 2)])
+
+    def test_cycle_sum(self):
+        self.assertEqual(retworkx.cycle_sum([0, 1, 2], [1, 3, 4]), [0, 2, 3, 4])
+        self.assertEqual(retworkx.cycle_sum([2, 1, 0], [0, 1, 2]), [])
+        self.assertEqual(retworkx.cycle_sum([], [4, 1]), [1, 4])
+
+    def test_is_in_cycle_space(self):
+        self.assertTrue(retworkx.is_in_cycle_space(self.graph, [0, 1, 2]))
+        self.assertTrue(retworkx.is_in_cycle_space(self.graph, [0, 2, 3, 4]))
+        self.assertTrue(retworkx.is_in_cycle_space(self.graph, []))
+        self.assertFalse(retworkx.is_in_cycle_space(self.graph, [0, 1]))
+        self.assertFalse(retworkx.is_in_cycle_space(self.graph, [1]))
+
+    def test_is_in_cycle_space_repeated_edge(self):
+        self.assertTrue(retworkx.is_in_cycle_space(self.graph, [0, 1, 2, 3, 3]))
+        self.assertFalse(retworkx.is_in_cycle_space(self.graph, [0, 1, 2, 3]))
+
+    def test_is_in_cycle_space_self_loop(self):
+        self.graph.add_edge(0, 0, None)
+        self.assertTrue(retworkx.is_in_cycle_space(self.graph, [5]))
+
+    def test_cycle_space(self):
+        space = retworkx.CycleSpace()
+        self.assertEqual(space.dimension, 0)
+        self.assertTrue(space.insert([0, 1, 2]))
+        self.assertTrue(space.insert([4, 3, 1]))
+        self.assertFalse(space.insert([0, 2, 3, 4]))
+        self.assertEqual(space.dimension, 2)
+        self.assertEqual(len(space), 2)
+        self.assertEqual(space.cycles(), [[0, 1, 2], [1, 3, 4]])
+
+    def test_cycle_space_contains(self):
+        space = retworkx.CycleSpace([[0, 1, 2], [1, 3, 4]])
+        self.assertIn([0, 2, 3, 4], space)
+        self.assertIn([], space)
+        self.assertNotIn([0, 1], space)
+
+    def test_cycle_space_decompose(self):
+        space = retworkx.CycleSpace(retworkx.minimum_cycle_basis(self.graph))
+        cycles = space.cycles()
+        res = space.decompose([0, 2, 3, 4])
+        self.assertEqual(res, [0, 1])
+        self.assertEqual(retworkx.cycle_sum(cycles[0], cycles[1]), [0, 2, 3, 4])
+        self.assertEqual(space.decompose([]), [])
+        with self.assertRaises(ValueError):
+            space.decompose([0, 1])
+
+    def test_cycle_space_spans_cycle_space(self):
+        graph = retworkx.generators.grid_graph(4, 4)
+        space = retworkx.CycleSpace(retworkx.minimum_cycle_basis(graph))
+        self.assertEqual(space.dimension, graph.num_edges() - graph.num_nodes() + 1)
+        edge_map = {
+            frozenset((source, target)): index
+            for index, (source, target, _) in graph.edge_index_map().items()
+        }
+        for cycle in retworkx.cycle_basis(graph):
+            edges = [edge_map[frozenset((cycle[i - 1], cycle[i]))] for i in range(len(cycle))]
+            self.assertIn(edges, space)
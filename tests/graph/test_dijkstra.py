@@ -50,6 +50,45 @@ class TestDijkstraGraph(unittest.TestCase):
         expected = {4: [self.a, self.c, self.d, self.e]}
         self.assertEqual(expected, path)
 
+    def test_dijkstra_path_weight_attr(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(list(range(4)))
+        graph.add_edge(0, 1, {"weight": 1})
+        graph.add_edge(1, 3, {"weight": 1})
+        graph.add_edge(0, 2, {"weight": 0.5})
+        graph.add_edge(2, 3, {})
+        paths = retworkx.graph_dijkstra_shortest_paths(graph, 0, target=3, weight_attr="weight")
+        self.assertEqual({3: [0, 2, 3]}, paths)
+        paths = retworkx.graph_dijkstra_shortest_paths(
+            graph, 0, target=3, weight_attr="weight", default_weight=5.0
+        )
+        self.assertEqual({3: [0, 1, 3]}, paths)
+
+    def test_dijkstra_path_edges_weight_attr(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(list(range(3)))
+        graph.add_edge(0, 1, {"weight": 5})
+        cheap = graph.add_edge(0, 1, {"weight": 2})
+        last = graph.add_edge(1, 2, {"weight": 1})
+        paths = retworkx.dijkstra_shortest_path_edges(graph, 0, weight_attr="weight")
+        self.assertEqual({1: [cheap], 2: [cheap, last]}, dict(paths))
+
+    def test_dijkstra_weight_fn_and_weight_attr(self):
+        with self.assertRaises(ValueError):
+            retworkx.graph_dijkstra_shortest_paths(
+                self.graph, self.a, weight_fn=float, weight_attr="weight"
+            )
+
+    def test_dijkstra_invalid_weight_attr(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(list(range(2)))
+        for invalid_weight in [float("nan"), -1]:
+            with self.subTest(invalid_weight=invalid_weight):
+                graph.add_edge(0, 1, {"weight": invalid_weight})
+                with self.assertRaises(ValueError):
+                    retworkx.graph_dijkstra_shortest_paths(graph, 0, weight_attr="weight")
+                graph.remove_edge(0, 1)
+
     def test_dijkstra_with_no_goal_set(self):
         path = retworkx.graph_dijkstra_shortest_path_lengths(self.graph, self.a, lambda x: 1)
         expected = {1: 1.0, 2: 1.0, 3: 1.0, 4: 2.0, 5: 2.0}
@@ -197,6 +236,69 @@ class TestDijkstraGraph(unittest.TestCase):
             retworkx.graph_all_pairs_dijkstra_shortest_paths(graph, float),
         )
 
+    def test_dijkstra_all_pair_path_lengths_chunked(self):
+        expected = retworkx.graph_all_pairs_dijkstra_path_lengths(self.graph, float)
+        for chunk_size in [1, 4, 6, 1000]:
+            chunks = []
+            retworkx.graph_all_pairs_dijkstra_path_lengths_chunked(
+                self.graph, float, chunks.append, chunk_size=chunk_size
+            )
+            self.assertEqual(
+                [min(chunk_size, 6 - i) for i in range(0, 6, chunk_size)],
+                [len(chunk) for chunk in chunks],
+            )
+            lengths = {}
+            for chunk in chunks:
+                self.assertIsInstance(chunk, retworkx.AllPairsPathLengthMapping)
+                lengths.update(chunk)
+            self.assertEqual([0, 1, 2, 3, 4, 5], list(lengths))
+            self.assertEqual(expected, lengths)
+
+    def test_dijkstra_all_pair_path_lengths_chunked_with_node_removal(self):
+        self.graph.remove_node(3)
+        expected = retworkx.graph_all_pairs_dijkstra_path_lengths(self.graph, float)
+        chunks = []
+        retworkx.graph_all_pairs_dijkstra_path_lengths_chunked(
+            self.graph, float, chunks.append, chunk_size=2
+        )
+        self.assertEqual([[0, 1], [2, 4], [5]], [list(chunk) for chunk in chunks])
+        lengths = {}
+        for chunk in chunks:
+            lengths.update(chunk)
+        self.assertEqual(expected, lengths)
+
+    def test_dijkstra_all_pair_path_lengths_chunked_empty_graph(self):
+        graph = retworkx.PyGraph()
+        chunks = []
+        retworkx.graph_all_pairs_dijkstra_path_lengths_chunked(graph, float, chunks.append)
+        self.assertEqual([], chunks)
+
+    def test_dijkstra_all_pair_path_lengths_chunked_callback_error(self):
+        chunks = []
+
+        def callback(chunk):
+            chunks.append(chunk)
+            raise KeyError("stop")
+
+        with self.assertRaises(KeyError):
+            retworkx.graph_all_pairs_dijkstra_path_lengths_chunked(
+                self.graph, float, callback, chunk_size=2
+            )
+        self.assertEqual(1, len(chunks))
+
+    def test_dijkstra_all_pair_path_lengths_chunked_invalid_chunk_size(self):
+        with self.assertRaises(ValueError):
+            retworkx.graph_all_pairs_dijkstra_path_lengths_chunked(
+                self.graph, float, lambda _: None, chunk_size=0
+            )
+
+    def test_dijkstra_all_pair_path_lengths_chunked_invalid_weight(self):
+        graph = retworkx.generators.path_graph(2)
+        with self.assertRaises(ValueError):
+            retworkx.graph_all_pairs_dijkstra_path_lengths_chunked(
+                graph, lambda _: -1.0, lambda _: None
+            )
+
     def dijkstra_with_invalid_weights(self):
         graph = retworkx.generators.path_graph(2)
         for invalid_weight in [float("nan"), -1]:
@@ -236,3 +338,30 @@ class TestDijkstraGraph(unittest.TestCase):
                     retworkx.graph_all_pairs_dijkstra_path_lengths(
                         graph, edge_cost_fn=lambda _: invalid_weight
                     )
+
+    def test_dijkstra_shortest_path_edges_multigraph(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(list(range(3)))
+        graph.add_edge(0, 1, 5)
+        cheap = graph.add_edge(1, 0, 2)
+        graph.add_edge(1, 2, 3)
+        last = graph.add_edge(2, 1, 1)
+        paths = retworkx.graph_dijkstra_shortest_path_edges(graph, 0, weight_fn=float)
+        self.assertEqual({1: [cheap], 2: [cheap, last]}, dict(paths))
+
+    def test_dijkstra_shortest_path_edges_with_target(self):
+        paths = retworkx.graph_dijkstra_shortest_path_edges(
+            self.graph, self.a, target=self.e, weight_fn=float
+        )
+        edges = [self.graph.get_edge_data_by_index(edge) for edge in paths[self.e]]
+        self.assertEqual([9, 2, 9], edges)
+        self.assertEqual([self.e], list(paths.keys()))
+
+    def test_dijkstra_shortest_path_edges_invalid_weights(self):
+        graph = retworkx.generators.path_graph(2)
+        for invalid_weight in [float("nan"), -1]:
+            with self.subTest(invalid_weight=invalid_weight):
+                with self.assertRaises(ValueError):
+                    retworkx.graph_dijkstra_shortest_path_edges(
+                        graph, 0, weight_fn=lambda _: invalid_weight
+                    )
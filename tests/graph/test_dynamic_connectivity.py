@@ -0,0 +1,127 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+import random
+import unittest
+
+import retworkx
+
+
+class TestDynamicConnectivity(unittest.TestCase):
+    def assertComponents(self, graph, connectivity):
+        expected = sorted(sorted(component) for component in retworkx.connected_components(graph))
+        components = sorted(sorted(component) for component in connectivity.components())
+        self.assertEqual(expected, components)
+        self.assertEqual(len(expected), connectivity.num_components)
+
+    def test_from_graph(self):
+        graph = retworkx.PyGraph()
+        graph.extend_from_edge_list([(0, 1), (1, 2), (3, 4)])
+        graph.add_node(None)
+        connectivity = retworkx.DynamicConnectivity(graph)
+        self.assertEqual(6, len(connectivity))
+        self.assertEqual(3, connectivity.num_edges)
+        self.assertComponents(graph, connectivity)
+        self.assertTrue(connectivity.connected(0, 2))
+        self.assertFalse(connectivity.connected(0, 3))
+        self.assertEqual({3, 4}, connectivity.component(4))
+
+    def test_from_graph_with_removed_nodes(self):
+        graph = retworkx.generators.path_graph(5)
+        graph.remove_node(2)
+        connectivity = retworkx.DynamicConnectivity(graph)
+        self.assertNotIn(2, connectivity)
+        self.assertIn(3, connectivity)
+        self.assertComponents(graph, connectivity)
+
+    def test_empty(self):
+        connectivity = retworkx.DynamicConnectivity()
+        self.assertEqual(0, len(connectivity))
+        self.assertEqual(0, connectivity.num_components)
+        self.assertEqual([], connectivity.components())
+
+    def test_add_edge(self):
+        connectivity = retworkx.DynamicConnectivity()
+        for node in range(3):
+            connectivity.add_node(node)
+        self.assertTrue(connectivity.add_edge(0, 1))
+        self.assertFalse(connectivity.add_edge(1, 0))
+        self.assertFalse(connectivity.add_edge(2, 2))
+        self.assertEqual(2, connectivity.num_components)
+        self.assertTrue(connectivity.add_edge(1, 2))
+        self.assertEqual({0, 1, 2}, connectivity.component(0))
+
+    def test_remove_edge(self):
+        graph = retworkx.generators.cycle_graph(4)
+        connectivity = retworkx.DynamicConnectivity(graph)
+        # The other edges of the cycle still connect the nodes
+        self.assertFalse(connectivity.remove_edge(0, 1))
+        self.assertTrue(connectivity.connected(0, 1))
+        self.assertTrue(connectivity.remove_edge(2, 3))
+        self.assertEqual([{0, 3}, {1, 2}], sorted(connectivity.components(), key=min))
+        self.assertFalse(connectivity.has_edge(2, 3))
+
+    def test_remove_parallel_edge(self):
+        connectivity = retworkx.DynamicConnectivity()
+        connectivity.add_node(0)
+        connectivity.add_node(1)
+        connectivity.add_edge(0, 1)
+        connectivity.add_edge(1, 0)
+        self.assertFalse(connectivity.remove_edge(0, 1))
+        self.assertTrue(connectivity.has_edge(0, 1))
+        self.assertTrue(connectivity.remove_edge(0, 1))
+        self.assertEqual(2, connectivity.num_components)
+
+    def test_remove_missing_edge(self):
+        connectivity = retworkx.DynamicConnectivity(retworkx.generators.path_graph(3))
+        with self.assertRaises(retworkx.NoEdgeBetweenNodes):
+            connectivity.remove_edge(0, 2)
+
+    def test_add_and_remove_node(self):
+        connectivity = retworkx.DynamicConnectivity(retworkx.generators.path_graph(3))
+        connectivity.add_node(10)
+        self.assertEqual(2, connectivity.num_components)
+        connectivity.add_edge(10, 0)
+        connectivity.remove_node(1)
+        self.assertNotIn(1, connectivity)
+        self.assertEqual([{0, 10}, {2}], sorted(connectivity.components(), key=min))
+
+    def test_invalid_nodes(self):
+        connectivity = retworkx.DynamicConnectivity(retworkx.generators.path_graph(3))
+        with self.assertRaises(ValueError):
+            connectivity.add_node(1)
+        with self.assertRaises(retworkx.InvalidNode):
+            connectivity.add_edge(0, 3)
+        with self.assertRaises(retworkx.InvalidNode):
+            connectivity.connected(3, 0)
+        with self.assertRaises(retworkx.InvalidNode):
+            connectivity.component(3)
+        with self.assertRaises(retworkx.InvalidNode):
+            connectivity.remove_node(3)
+
+    def test_invalid_type(self):
+        with self.assertRaises(TypeError):
+            retworkx.DynamicConnectivity([(0, 1)])
+
+    def test_random_updates(self):
+        rng = random.Random(42)
+        graph = retworkx.generators.grid_graph(6, 6)
+        connectivity = retworkx.DynamicConnectivity(graph)
+        for _ in range(300):
+            if graph.num_edges() > 0 and rng.random() < 0.6:
+                source, target = rng.choice(list(graph.edge_list()))
+                graph.remove_edge(source, target)
+                connectivity.remove_edge(source, target)
+            else:
+                source, target = rng.sample(list(graph.node_indices()), 2)
+                graph.add_edge(source, target, None)
+                connectivity.add_edge(source, target)
+            self.assertComponents(graph, connectivity)
@@ -0,0 +1,102 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import retworkx
+
+
+class TestGraphEdgeCutPartition(unittest.TestCase):
+    def setUp(self):
+        self.graph = retworkx.generators.path_graph(6)
+        self.partition = [[0, 1, 2], [3, 4, 5]]
+
+    def test_parts(self):
+        res = retworkx.graph_edge_cut_partition(self.graph, self.partition)
+        self.assertIsInstance(res, retworkx.EdgeCutPartition)
+        self.assertEqual(2, len(res.parts))
+        first, second = res.parts
+        self.assertEqual(0, first.part)
+        self.assertEqual([0, 1, 2], list(first.nodes))
+        self.assertEqual([3], list(first.halo_nodes))
+        self.assertEqual(1, second.part)
+        self.assertEqual([3, 4, 5], list(second.nodes))
+        self.assertEqual([2], list(second.halo_nodes))
+        self.assertEqual([1], first.halo_owners)
+        self.assertEqual([0], second.halo_owners)
+        self.assertEqual([2], list(first.cut_edges))
+        self.assertEqual([2], list(second.cut_edges))
+
+    def test_cut_edges(self):
+        res = retworkx.graph_edge_cut_partition(self.graph, self.partition)
+        self.assertEqual([(2, 2, 3, 0, 1)], res.cut_edges)
+
+    def test_subgraph(self):
+        for node in self.graph.node_indices():
+            self.graph[node] = "node %s" % node
+        res = retworkx.graph_edge_cut_partition(self.graph, self.partition)
+        subgraph = res.parts[1].subgraph
+        self.assertIsInstance(subgraph, retworkx.PyGraph)
+        self.assertEqual(["node 3", "node 4", "node 5", "node 2"], subgraph.nodes())
+        self.assertEqual({2: 3, 3: 0, 4: 1, 5: 2}, dict(res.parts[1].node_map))
+        self.assertEqual(
+            sorted([(0, 1), (1, 2), (0, 3)]),
+            sorted(tuple(sorted(edge)) for edge in subgraph.edge_list()),
+        )
+
+    def test_halo_depth(self):
+        res = retworkx.graph_edge_cut_partition(self.graph, self.partition, halo_depth=2)
+        self.assertEqual([3, 4], list(res.parts[0].halo_nodes))
+        self.assertEqual([1, 1], res.parts[0].halo_owners)
+        self.assertEqual([2, 1], list(res.parts[1].halo_nodes))
+        self.assertEqual(4, len(res.parts[1].subgraph.edge_list()))
+
+    def test_no_halo(self):
+        res = retworkx.graph_edge_cut_partition(self.graph, self.partition, halo_depth=0)
+        for part in res.parts:
+            self.assertEqual([], list(part.halo_nodes))
+            self.assertEqual(3, len(part.subgraph))
+            self.assertEqual(2, len(part.subgraph.edge_list()))
+
+    def test_parallel_edges_and_self_loops(self):
+        self.graph.add_edge(2, 3, None)
+        self.graph.add_edge(0, 0, None)
+        res = retworkx.graph_edge_cut_partition(self.graph, self.partition)
+        self.assertEqual([2, 5], list(res.parts[0].cut_edges))
+        self.assertEqual(5, len(res.parts[0].subgraph.edge_list()))
+
+    def test_removed_nodes(self):
+        self.graph.remove_node(1)
+        res = retworkx.graph_edge_cut_partition(self.graph, [[0, 2], [3, 4, 5]])
+        self.assertEqual([0, 2], list(res.parts[0].nodes))
+        self.assertEqual([3], list(res.parts[0].halo_nodes))
+
+    def test_empty_part(self):
+        res = retworkx.graph_edge_cut_partition(self.graph, self.partition + [[]])
+        self.assertEqual(3, len(res.parts))
+        self.assertEqual(0, len(res.parts[2].subgraph))
+
+    def test_universal_function(self):
+        res = retworkx.edge_cut_partition(self.graph, self.partition)
+        self.assertEqual([(2, 2, 3, 0, 1)], res.cut_edges)
+
+    def test_node_in_two_parts(self):
+        with self.assertRaises(ValueError):
+            retworkx.graph_edge_cut_partition(self.graph, [[0, 1, 2, 3], [3, 4, 5]])
+
+    def test_node_not_in_any_part(self):
+        with self.assertRaises(ValueError):
+            retworkx.graph_edge_cut_partition(self.graph, [[0, 1, 2], [3, 4]])
+
+    def test_invalid_node(self):
+        with self.assertRaises(retworkx.InvalidNode):
+            retworkx.graph_edge_cut_partition(self.graph, [[0, 1, 2], [3, 4, 5, 6]])
@@ -0,0 +1,120 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import random
+import unittest
+
+import retworkx
+
+
+class TestGraphEdgeIntervalIndex(unittest.TestCase):
+    def setUp(self):
+        self.graph = retworkx.PyGraph()
+        self.graph.add_nodes_from(range(5))
+        self.graph.add_edges_from(
+            [
+                (0, 1, (0.0, 2.0)),
+                (1, 2, (1.0, 5.0)),
+                (2, 3, (3.0, 4.0)),
+                (3, 4, (6.0, 8.0)),
+                (4, 0, (4.0, 4.0)),
+            ]
+        )
+        self.index = retworkx.graph_edge_interval_index(self.graph, lambda interval: interval)
+
+    def test_len(self):
+        self.assertEqual(5, len(self.index))
+
+    def test_edges_active_in(self):
+        self.assertEqual([1, 2], list(self.index.edges_active_in(2.5, 3.5)))
+        self.assertEqual([0, 1, 2, 4], list(self.index.edges_active_in(2.0, 4.0)))
+        self.assertEqual([], list(self.index.edges_active_in(5.5, 5.9)))
+        self.assertEqual([0, 1, 2, 3, 4], list(self.index.edges_active_in(-10, 10)))
+
+    def test_edges_active_in_empty_window(self):
+        self.assertEqual([], list(self.index.edges_active_in(4.0, 3.0)))
+
+    def test_edges_active_at(self):
+        self.assertEqual([1, 2, 4], list(self.index.edges_active_at(4.0)))
+        self.assertEqual([0, 1], list(self.index.edges_active_at(2)))
+        self.assertEqual([], list(self.index.edges_active_at(9.0)))
+
+    def test_matches_scan(self):
+        graph = retworkx.generators.grid_graph(10, 10)
+        rng = random.Random(42)
+        for edge in graph.edge_indices():
+            start = rng.uniform(0, 100)
+            graph.update_edge_by_index(edge, (start, start + rng.uniform(0, 20)))
+        index = retworkx.graph_edge_interval_index(graph, lambda interval: interval)
+        for _ in range(50):
+            start = rng.uniform(-10, 130)
+            end = start + rng.uniform(0, 15)
+            expected = [
+                edge
+                for edge, (_, _, (s, e)) in graph.edge_index_map().items()
+                if s <= end and e >= start
+            ]
+            self.assertEqual(sorted(expected), list(index.edges_active_in(start, end)))
+
+    def test_window_view(self):
+        view = self.index.window_view(2.5, 3.5)
+        self.assertIsInstance(view, retworkx.GraphView)
+        self.assertIs(self.graph, view.graph)
+        self.assertEqual(list(self.graph.node_indices()), list(view.node_indices()))
+        self.assertEqual([1, 2], list(view.edge_indices()))
+        self.assertEqual([(1, 2), (2, 3)], view.edge_list())
+        self.assertEqual([(1, 2, (1.0, 5.0)), (2, 3, (3.0, 4.0))], list(view.weighted_edge_list()))
+        self.assertEqual(5, self.graph.num_edges())
+
+    def test_window_view_with_holes(self):
+        self.graph.remove_node(0)
+        index = retworkx.graph_edge_interval_index(self.graph, lambda interval: interval)
+        view = index.window_view(0.0, 4.0)
+        self.assertEqual([1, 2, 3, 4], list(view.node_indices()))
+        self.assertEqual([(1, 2), (2, 3)], view.edge_list())
+
+    def test_modified_graph(self):
+        self.graph.add_edge(0, 2, (1.0, 1.0))
+        with self.assertRaises(ValueError):
+            self.index.edges_active_in(0.0, 1.0)
+        with self.assertRaises(ValueError):
+            self.index.window_view(0.0, 1.0)
+
+    def test_replaced_edge(self):
+        # The number of edges and the edge indices are the same as before
+        self.graph.remove_edge_from_index(4)
+        self.graph.add_edge(4, 0, (0.0, 9.0))
+        with self.assertRaises(ValueError):
+            self.index.edges_active_at(1.0)
+
+    def test_updated_edge(self):
+        self.graph.update_edge_by_index(0, (5.0, 6.0))
+        with self.assertRaises(ValueError):
+            self.index.edges_active_at(1.0)
+
+    def test_invalid_interval(self):
+        self.graph.add_edge(0, 2, (2.0, 1.0))
+        with self.assertRaises(ValueError):
+            retworkx.graph_edge_interval_index(self.graph, lambda interval: interval)
+
+    def test_nan_interval(self):
+        self.graph.add_edge(0, 2, (float("nan"), 1.0))
+        with self.assertRaises(ValueError):
+            retworkx.graph_edge_interval_index(self.graph, lambda interval: interval)
+
+    def test_interval_fn_error(self):
+        with self.assertRaises(TypeError):
+            retworkx.graph_edge_interval_index(self.graph, lambda interval: interval[0])
+
+    def test_universal_function(self):
+        index = retworkx.edge_interval_index(self.graph, lambda interval: interval)
+        self.assertEqual([1, 2], list(index.edges_active_in(2.5, 3.5)))
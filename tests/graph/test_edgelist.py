@@ -143,6 +143,41 @@ class TestEdgeList(unittest.TestCase):
         self.assertTrue(graph.has_edge(0, 2))
         self.assertEqual(graph.edges(), ["0", "1", None])
 
+    def test_weight_column_graph(self):
+        with tempfile.NamedTemporaryFile("wt") as fd:
+            fd.write("0,1,a,1.5\n")
+            fd.write("1,2,b, 2\n")
+            fd.flush()
+            graph = retworkx.PyGraph.read_edge_list(fd.name, deliminator=",", weight_column=3)
+        self.assertEqual([(0, 1, 1.5), (1, 2, 2.0)], list(graph.weighted_edge_list()))
+
+    def test_invalid_weight_column_graph(self):
+        for content in ["0 1\n", "0 1 x\n"]:
+            with self.subTest(content=content):
+                with tempfile.NamedTemporaryFile("wt") as fd:
+                    fd.write(content)
+                    fd.flush()
+                    with self.assertRaises(ValueError):
+                        retworkx.PyGraph.read_edge_list(fd.name, weight_column=2)
+
+    def test_invalid_line_graph(self):
+        for content in ["0\n", "0 a\n", "-1 0\n"]:
+            with self.subTest(content=content):
+                with tempfile.NamedTemporaryFile("wt") as fd:
+                    fd.write(content)
+                    fd.flush()
+                    with self.assertRaises(ValueError):
+                        retworkx.PyGraph.read_edge_list(fd.name)
+
+    def test_large_edge_list_graph(self):
+        edges = [(i, (i * 7919) % 100000, float(i)) for i in range(100000)]
+        with tempfile.NamedTemporaryFile("wt") as fd:
+            fd.write("".join("%d %d %r\n" % edge for edge in edges))
+            fd.flush()
+            graph = retworkx.PyGraph.read_edge_list(fd.name, weight_column=2)
+        self.assertEqual(100000, len(graph))
+        self.assertEqual(edges, list(graph.weighted_edge_list()))
+
     def test_write_edge_list_empty_digraph(self):
         path = os.path.join(tempfile.gettempdir(), "empty.txt")
         graph = retworkx.PyGraph()
@@ -0,0 +1,175 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import retworkx
+import numpy as np
+
+
+class TestGraphEmbeddingEvaluation(unittest.TestCase):
+    def setUp(self):
+        # Node i is embedded as the sum of the unit vectors i and i + 1, so
+        # the dot product of the nodes of the path 0 - 1 - 2 - 3 is 1 for
+        # neighbors and 0 otherwise
+        self.graph = retworkx.generators.path_graph(4)
+        self.embeddings = np.zeros((4, 5))
+        for node in range(4):
+            self.embeddings[node, node] = 1.0
+            self.embeddings[node, node + 1] = 1.0
+
+    def test_precision_at_k(self):
+        for similarity in ["dot", "cosine"]:
+            with self.subTest(similarity=similarity):
+                self.assertEqual(
+                    1.0,
+                    retworkx.graph_reconstruction_precision_at_k(
+                        self.graph, self.embeddings, 3, similarity=similarity
+                    ),
+                )
+                self.assertEqual(
+                    0.5,
+                    retworkx.graph_reconstruction_precision_at_k(
+                        self.graph, self.embeddings, 6, similarity=similarity
+                    ),
+                )
+
+    def test_precision_at_k_more_than_pairs(self):
+        self.assertEqual(
+            0.5, retworkx.graph_reconstruction_precision_at_k(self.graph, self.embeddings, 100)
+        )
+
+    def test_precision_at_zero(self):
+        self.assertEqual(
+            0.0, retworkx.graph_reconstruction_precision_at_k(self.graph, self.embeddings, 0)
+        )
+
+    def test_precision_at_k_parallel(self):
+        self.assertEqual(
+            retworkx.graph_reconstruction_precision_at_k(self.graph, self.embeddings, 4),
+            retworkx.graph_reconstruction_precision_at_k(
+                self.graph, self.embeddings, 4, parallel_threshold=1
+            ),
+        )
+
+    def test_mean_average_precision(self):
+        self.assertEqual(
+            1.0, retworkx.graph_reconstruction_mean_average_precision(self.graph, self.embeddings)
+        )
+
+    def test_mean_average_precision_no_edges(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(range(3))
+        self.assertEqual(
+            0.0, retworkx.graph_reconstruction_mean_average_precision(graph, np.ones((3, 2)))
+        )
+
+    def test_mean_average_precision_with_holes(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(range(5))
+        graph.add_edges_from_no_data([(0, 2), (2, 3), (3, 4)])
+        graph.remove_node(1)
+        embeddings = np.array(
+            [
+                [1.0, 1.0, 0.0, 0.0],
+                [0.0, 0.0, 0.0, 0.0],
+                [0.0, 1.0, 1.0, 0.0],
+                [0.0, 0.0, 1.0, 1.0],
+                [0.0, 0.0, 0.0, 1.0],
+            ]
+        )
+        self.assertEqual(
+            1.0, retworkx.graph_reconstruction_mean_average_precision(graph, embeddings)
+        )
+
+    def test_link_prediction_roc_auc(self):
+        self.graph.remove_edges_from([(2, 3)])
+        for similarity in ["dot", "cosine"]:
+            with self.subTest(similarity=similarity):
+                self.assertEqual(
+                    1.0,
+                    retworkx.graph_link_prediction_roc_auc(
+                        self.graph,
+                        self.embeddings,
+                        [(2, 3)],
+                        num_negative=20,
+                        similarity=similarity,
+                        seed=42,
+                    ),
+                )
+
+    def test_link_prediction_roc_auc_reversed(self):
+        self.graph.remove_edges_from([(2, 3)])
+        res = retworkx.graph_link_prediction_roc_auc(self.graph, self.embeddings, [(3, 2)], seed=1)
+        self.assertEqual(1.0, res)
+
+    def test_link_prediction_roc_auc_ties(self):
+        self.graph.remove_edges_from([(2, 3)])
+        res = retworkx.graph_link_prediction_roc_auc(
+            self.graph, np.ones((4, 2)), [(2, 3)], num_negative=10, seed=42
+        )
+        self.assertEqual(0.5, res)
+
+    def test_link_prediction_roc_auc_seed(self):
+        graph = retworkx.generators.cycle_graph(20)
+        embeddings = np.random.default_rng(1234).random((20, 4))
+        test_edges = [(0, 10), (3, 15), (7, 12)]
+        self.assertEqual(
+            retworkx.graph_link_prediction_roc_auc(
+                graph, embeddings, test_edges, num_negative=50, seed=10
+            ),
+            retworkx.graph_link_prediction_roc_auc(
+                graph, embeddings, test_edges, num_negative=50, seed=10, parallel_threshold=1
+            ),
+        )
+
+    def test_link_prediction_invalid_node(self):
+        with self.assertRaises(retworkx.InvalidNode):
+            retworkx.graph_link_prediction_roc_auc(self.graph, self.embeddings, [(0, 10)])
+
+    def test_link_prediction_no_test_edges(self):
+        with self.assertRaises(ValueError):
+            retworkx.graph_link_prediction_roc_auc(self.graph, self.embeddings, [])
+
+    def test_link_prediction_zero_negative(self):
+        with self.assertRaises(ValueError):
+            retworkx.graph_link_prediction_roc_auc(
+                self.graph, self.embeddings, [(0, 3)], num_negative=0
+            )
+
+    def test_link_prediction_no_negative_pairs(self):
+        graph = retworkx.generators.mesh_graph(4)
+        graph.remove_edge(0, 3)
+        with self.assertRaises(ValueError):
+            retworkx.graph_link_prediction_roc_auc(graph, self.embeddings, [(0, 3)])
+
+    def test_too_few_embeddings(self):
+        with self.assertRaises(ValueError):
+            retworkx.graph_reconstruction_precision_at_k(self.graph, self.embeddings[:3], 2)
+
+    def test_invalid_similarity(self):
+        with self.assertRaises(ValueError):
+            retworkx.graph_reconstruction_mean_average_precision(
+                self.graph, self.embeddings, similarity="euclidean"
+            )
+
+    def test_universal_functions(self):
+        self.assertEqual(
+            1.0, retworkx.reconstruction_precision_at_k(self.graph, self.embeddings, 3)
+        )
+        self.assertEqual(
+            1.0, retworkx.reconstruction_mean_average_precision(self.graph, self.embeddings)
+        )
+        self.graph.remove_edges_from([(2, 3)])
+        self.assertEqual(
+            1.0, retworkx.link_prediction_roc_auc(self.graph, self.embeddings, [(2, 3)], seed=4)
+        )
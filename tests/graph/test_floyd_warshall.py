@@ -196,6 +196,12 @@ class TestFloydWarshall(unittest.TestCase):
         self.assertEqual(dist[0, 3], 6)
         self.assertEqual(dist[0, 4], 6)
 
+    def test_floyd_warshall_output_order(self):
+        graph = retworkx.generators.grid_graph(5, 5)
+        result = retworkx.graph_floyd_warshall(graph, parallel_threshold=self.parallel_threshold)
+        for lengths in result.values():
+            self.assertEqual(list(range(25)), list(lengths))
+
 
 class TestParallelFloydWarshall(TestFloydWarshall):
     parallel_threshold = 0
@@ -0,0 +1,107 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import numpy as np
+
+import retworkx
+
+
+class TestGraphFromEdgeArrays(unittest.TestCase):
+    def test_from_edge_arrays(self):
+        sources = np.array([0, 1, 2, 0])
+        targets = np.array([1, 2, 0, 1])
+        graph = retworkx.PyGraph.from_edge_arrays(sources, targets)
+        self.assertIsInstance(graph, retworkx.PyGraph)
+        self.assertEqual([0, 1, 2], graph.nodes())
+        self.assertEqual([(0, 1), (1, 2), (2, 0), (0, 1)], graph.edge_list())
+        self.assertEqual([None] * 4, graph.edges())
+        self.assertTrue(graph.multigraph)
+
+    def test_weights(self):
+        sources = np.array([0, 1])
+        targets = np.array([1, 2])
+        weights = np.array([0.5, 2.0])
+        graph = retworkx.PyGraph.from_edge_arrays(sources, targets, weights=weights)
+        self.assertEqual([(0, 1, 0.5), (1, 2, 2.0)], graph.weighted_edge_list())
+        self.assertIsInstance(graph.edges()[0], float)
+
+    def test_weight_dtypes(self):
+        sources = np.array([0, 1])
+        targets = np.array([1, 2])
+        for dtype in [np.float64, np.float32, np.int64, np.int32]:
+            with self.subTest(dtype=dtype):
+                weights = np.array([3, 4], dtype=dtype)
+                graph = retworkx.PyGraph.from_edge_arrays(sources, targets, weights)
+                self.assertEqual([3, 4], graph.edges())
+
+    def test_index_dtypes(self):
+        for dtype in [np.int64, np.int32, np.uint64, np.uint32]:
+            with self.subTest(dtype=dtype):
+                sources = np.array([0, 3], dtype=dtype)
+                targets = np.array([1, 2], dtype=dtype)
+                graph = retworkx.PyGraph.from_edge_arrays(sources, targets)
+                self.assertEqual([(0, 1), (3, 2)], graph.edge_list())
+
+    def test_non_contiguous_arrays(self):
+        edges = np.array([[0, 1], [1, 2], [2, 3]])
+        graph = retworkx.PyGraph.from_edge_arrays(edges[:, 0], edges[:, 1])
+        self.assertEqual([(0, 1), (1, 2), (2, 3)], graph.edge_list())
+
+    def test_self_loop(self):
+        graph = retworkx.PyGraph.from_edge_arrays(np.array([1]), np.array([1]))
+        self.assertEqual(2, len(graph))
+        self.assertEqual([(1, 1)], graph.edge_list())
+
+    def test_num_nodes(self):
+        sources = np.array([0, 1])
+        targets = np.array([1, 2])
+        graph = retworkx.PyGraph.from_edge_arrays(sources, targets, num_nodes=5)
+        self.assertEqual([0, 1, 2, 3, 4], graph.nodes())
+        self.assertEqual(2, graph.num_edges())
+
+    def test_num_nodes_too_small(self):
+        with self.assertRaises(ValueError):
+            retworkx.PyGraph.from_edge_arrays(np.array([0, 1]), np.array([1, 2]), num_nodes=2)
+
+    def test_empty(self):
+        empty = np.array([], dtype=np.int64)
+        graph = retworkx.PyGraph.from_edge_arrays(empty, empty)
+        self.assertEqual(0, len(graph))
+        graph = retworkx.PyGraph.from_edge_arrays(empty, empty, num_nodes=3)
+        self.assertEqual(3, len(graph))
+        self.assertEqual(0, graph.num_edges())
+
+    def test_different_lengths(self):
+        with self.assertRaises(ValueError):
+            retworkx.PyGraph.from_edge_arrays(np.array([0, 1]), np.array([1]))
+
+    def test_different_weights_length(self):
+        with self.assertRaises(ValueError):
+            retworkx.PyGraph.from_edge_arrays(
+                np.array([0, 1]), np.array([1, 2]), weights=np.array([1.0])
+            )
+
+    def test_negative_index(self):
+        with self.assertRaises(ValueError):
+            retworkx.PyGraph.from_edge_arrays(np.array([0, -1]), np.array([1, 2]))
+
+    def test_float_indices(self):
+        with self.assertRaises(TypeError):
+            retworkx.PyGraph.from_edge_arrays(np.array([0.0, 1.0]), np.array([1.0, 2.0]))
+
+    def test_add_edges_after(self):
+        graph = retworkx.PyGraph.from_edge_arrays(np.array([0]), np.array([1]))
+        node = graph.add_node(2)
+        graph.add_edge(1, node, None)
+        self.assertEqual([(0, 1), (1, 2)], graph.edge_list())
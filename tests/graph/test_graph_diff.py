@@ -0,0 +1,71 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import retworkx
+
+
+class TestGraphDiff(unittest.TestCase):
+    def test_identical(self):
+        graph = retworkx.generators.cycle_graph(4)
+        for node in graph.node_indices():
+            graph[node] = node
+        diff = retworkx.graph_diff(graph, graph.copy())
+        self.assertTrue(diff.is_empty())
+        self.assertEqual({0: 0, 1: 1, 2: 2, 3: 3}, diff.node_map)
+
+    def test_snapshots(self):
+        first = retworkx.PyGraph()
+        first.add_nodes_from([{"id": "a"}, {"id": "b"}, {"id": "c"}])
+        first.add_edges_from([(0, 1, 1), (1, 2, 1)])
+        second = retworkx.PyGraph()
+        second.add_nodes_from([{"id": "c"}, {"id": "b", "color": "red"}, {"id": "d"}])
+        second.add_edges_from([(1, 0, 5), (2, 0, 1)])
+        diff = retworkx.graph_diff(first, second, node_key_fn=lambda node: node["id"])
+        self.assertFalse(diff.is_empty())
+        self.assertEqual([0], diff.removed_nodes)
+        self.assertEqual([2], diff.added_nodes)
+        self.assertEqual({1: 1, 2: 0}, diff.node_map)
+        self.assertEqual({1: 1}, diff.changed_nodes)
+        self.assertEqual([0], diff.removed_edges)
+        self.assertEqual([1], diff.added_edges)
+        self.assertEqual({1: 0}, diff.changed_edges)
+
+    def test_edge_reversed_is_same_edge(self):
+        first = retworkx.PyGraph()
+        first.add_nodes_from(["a", "b"])
+        first.add_edge(0, 1, None)
+        second = retworkx.PyGraph()
+        second.add_nodes_from(["b", "a"])
+        second.add_edge(0, 1, None)
+        diff = retworkx.graph_graph_diff(first, second)
+        self.assertTrue(diff.is_empty())
+        self.assertEqual({0: 1, 1: 0}, diff.node_map)
+
+    def test_parallel_edges(self):
+        first = retworkx.PyGraph()
+        first.add_nodes_from(["a", "b"])
+        first.add_edges_from([(0, 1, "x"), (0, 1, "y")])
+        second = retworkx.PyGraph()
+        second.add_nodes_from(["a", "b"])
+        second.add_edges_from([(0, 1, "y"), (1, 0, "x"), (0, 1, "z")])
+        diff = retworkx.graph_diff(first, second)
+        self.assertEqual([], diff.removed_edges)
+        self.assertEqual({}, diff.changed_edges)
+        self.assertEqual([2], diff.added_edges)
+
+    def test_duplicate_keys(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(["a", "a"])
+        with self.assertRaises(ValueError):
+            retworkx.graph_diff(graph, retworkx.PyGraph())
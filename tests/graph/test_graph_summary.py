@@ -0,0 +1,84 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+
+import unittest
+
+import retworkx
+
+
+class TestGraphSummary(unittest.TestCase):
+    def setUp(self):
+        self.graph = retworkx.PyGraph()
+        self.graph.add_nodes_from(range(8))
+        self.graph.extend_from_edge_list(
+            [(0, 1), (1, 2), (2, 0), (2, 3), (3, 4), (4, 5), (1, 3), (6, 7)]
+        )
+
+    def test_summary(self):
+        summary = retworkx.graph_graph_summary(self.graph)
+        self.assertEqual(8, summary.node_count)
+        self.assertEqual(8, summary.edge_count)
+        self.assertAlmostEqual(2 / 7, summary.density)
+        self.assertEqual(1, summary.min_degree)
+        self.assertEqual(3, summary.max_degree)
+        self.assertEqual(2.0, summary.mean_degree)
+        self.assertEqual(2, summary.num_components)
+        self.assertEqual(6, summary.largest_component_size)
+        self.assertAlmostEqual(1 / 3, summary.average_clustering)
+        self.assertAlmostEqual(0.5897435897435898, summary.degree_assortativity)
+        self.assertEqual(4, summary.diameter_estimate)
+
+    def test_summary_tree_diameter(self):
+        graph = retworkx.generators.binomial_tree_graph(5)
+        summary = retworkx.graph_graph_summary(graph)
+        self.assertEqual(1, summary.num_components)
+        self.assertEqual(0.0, summary.average_clustering)
+        self.assertEqual(9, summary.diameter_estimate)
+
+    def test_summary_parallel_edges_and_self_loops(self):
+        graph = retworkx.PyGraph()
+        graph.extend_from_edge_list([(0, 1), (0, 1), (1, 2), (2, 0), (2, 2)])
+        summary = retworkx.graph_graph_summary(graph)
+        self.assertEqual(5, summary.edge_count)
+        self.assertEqual(3, summary.min_degree)
+        self.assertEqual(4, summary.max_degree)
+        self.assertEqual(1.0, summary.average_clustering)
+        self.assertEqual(1, summary.diameter_estimate)
+
+    def test_summary_undefined_assortativity(self):
+        summary = retworkx.graph_graph_summary(retworkx.generators.cycle_graph(5))
+        self.assertIsNone(summary.degree_assortativity)
+        self.assertEqual(2, summary.diameter_estimate)
+
+    def test_summary_empty_graph(self):
+        summary = retworkx.graph_graph_summary(retworkx.PyGraph())
+        self.assertEqual(0, summary.node_count)
+        self.assertEqual(0, summary.edge_count)
+        self.assertEqual(0.0, summary.density)
+        self.assertEqual(0, summary.num_components)
+        self.assertEqual(0, summary.largest_component_size)
+        self.assertIsNone(summary.degree_assortativity)
+        self.assertEqual(0, summary.diameter_estimate)
+
+    def test_summary_removed_nodes(self):
+        self.graph.remove_node(5)
+        summary = retworkx.graph_graph_summary(self.graph)
+        self.assertEqual(7, summary.node_count)
+        self.assertEqual(7, summary.edge_count)
+        self.assertEqual(5, summary.largest_component_size)
+        self.assertEqual(3, summary.diameter_estimate)
+
+    def test_summary_universal(self):
+        summary = retworkx.graph_summary(self.graph)
+        self.assertIsInstance(summary, retworkx.GraphSummary)
+        self.assertEqual(8, summary.node_count)
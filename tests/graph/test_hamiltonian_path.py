@@ -0,0 +1,124 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import retworkx
+
+
+class TestHamiltonianPath(unittest.TestCase):
+    def assertHamiltonianPath(self, graph, path):
+        path = list(path)
+        self.assertEqual(sorted(graph.node_indices()), sorted(path))
+        for source, target in zip(path, path[1:]):
+            self.assertTrue(graph.has_edge(source, target))
+
+    def test_grid(self):
+        graph = retworkx.generators.grid_graph(4, 5)
+        path = retworkx.graph_hamiltonian_path(graph)
+        self.assertHamiltonianPath(graph, path)
+
+    def test_source_and_target(self):
+        graph = retworkx.generators.grid_graph(4, 4)
+        path = retworkx.graph_hamiltonian_path(graph, source=1, target=2)
+        self.assertHamiltonianPath(graph, path)
+        self.assertEqual(1, path[0])
+        self.assertEqual(2, path[-1])
+
+    def test_target_only(self):
+        graph = retworkx.generators.path_graph(5)
+        path = retworkx.graph_hamiltonian_path(graph, target=0)
+        self.assertEqual([4, 3, 2, 1, 0], list(path))
+
+    def test_petersen_graph(self):
+        graph = retworkx.generators.generalized_petersen_graph(5, 2)
+        path = retworkx.graph_hamiltonian_path(graph)
+        self.assertHamiltonianPath(graph, path)
+
+    def test_no_path(self):
+        graph = retworkx.generators.star_graph(4)
+        self.assertIsNone(retworkx.graph_hamiltonian_path(graph))
+
+    def test_no_path_between_nodes(self):
+        # A bipartite grid with an odd number of nodes can only have a path
+        # between two nodes of the larger side
+        graph = retworkx.generators.grid_graph(3, 3)
+        self.assertIsNone(retworkx.graph_hamiltonian_path(graph, source=1))
+        self.assertIsNone(retworkx.graph_hamiltonian_path(graph, source=0, target=1))
+        path = retworkx.graph_hamiltonian_path(graph, source=0, target=8)
+        self.assertHamiltonianPath(graph, path)
+
+    def test_not_connected(self):
+        graph = retworkx.generators.cycle_graph(4)
+        graph.add_node(None)
+        self.assertIsNone(retworkx.graph_hamiltonian_path(graph))
+
+    def test_same_source_and_target(self):
+        graph = retworkx.generators.cycle_graph(4)
+        self.assertIsNone(retworkx.graph_hamiltonian_path(graph, source=0, target=0))
+
+    def test_single_node(self):
+        graph = retworkx.PyGraph()
+        graph.add_node(None)
+        self.assertEqual([0], list(retworkx.graph_hamiltonian_path(graph, source=0, target=0)))
+
+    def test_empty_graph(self):
+        self.assertEqual([], list(retworkx.graph_hamiltonian_path(retworkx.PyGraph())))
+
+    def test_self_loops_and_parallel_edges(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(range(3))
+        graph.add_edges_from_no_data([(0, 0), (0, 1), (1, 0), (1, 2), (2, 2)])
+        self.assertEqual([0, 1, 2], list(retworkx.graph_hamiltonian_path(graph)))
+
+    def test_removed_nodes(self):
+        graph = retworkx.generators.cycle_graph(5)
+        graph.remove_node(2)
+        path = retworkx.graph_hamiltonian_path(graph)
+        self.assertIn(list(path), [[3, 4, 0, 1], [1, 0, 4, 3]])
+
+    def test_call_limit(self):
+        # A Hamiltonian path has to start at a node of the larger side of the
+        # bipartite grid, which the search can't prove quickly
+        graph = retworkx.generators.grid_graph(7, 7)
+        with self.assertRaises(retworkx.SearchBudgetExhausted):
+            retworkx.graph_hamiltonian_path(graph, source=1, call_limit=1000)
+
+    def test_call_limit_not_reached(self):
+        graph = retworkx.generators.grid_graph(4, 5)
+        path = retworkx.graph_hamiltonian_path(graph, call_limit=1000)
+        self.assertHamiltonianPath(graph, path)
+
+    def test_timeout(self):
+        graph = retworkx.generators.grid_graph(7, 7)
+        with self.assertRaises(retworkx.SearchBudgetExhausted):
+            retworkx.graph_hamiltonian_path(graph, source=1, timeout=0.05)
+
+    def test_invalid_timeout(self):
+        graph = retworkx.generators.path_graph(3)
+        with self.assertRaises(ValueError):
+            retworkx.graph_hamiltonian_path(graph, timeout=-1.0)
+
+    def test_invalid_source(self):
+        graph = retworkx.generators.path_graph(3)
+        with self.assertRaises(retworkx.InvalidNode):
+            retworkx.graph_hamiltonian_path(graph, source=3)
+
+    def test_invalid_target(self):
+        graph = retworkx.generators.path_graph(3)
+        with self.assertRaises(retworkx.InvalidNode):
+            retworkx.graph_hamiltonian_path(graph, target=3)
+
+    def test_universal(self):
+        graph = retworkx.generators.grid_graph(3, 3)
+        path = retworkx.hamiltonian_path(graph, source=0, call_limit=100)
+        self.assertHamiltonianPath(graph, path)
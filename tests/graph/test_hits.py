@@ -0,0 +1,35 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+
+import unittest
+
+import retworkx
+
+
+class TestHits(unittest.TestCase):
+    def test_hits(self):
+        graph = retworkx.PyGraph()
+        graph.extend_from_edge_list([(0, 1), (1, 2), (2, 0), (2, 3)])
+        hubs, authorities = retworkx.graph_hits(graph)
+        expected = {0: 0.269594, 1: 0.269594, 2: 0.315449, 3: 0.145362}
+        for node, score in expected.items():
+            self.assertAlmostEqual(score, hubs[node], places=5)
+            self.assertAlmostEqual(score, authorities[node], places=5)
+
+    def test_hits_removed_node(self):
+        graph = retworkx.generators.cycle_graph(5)
+        graph.remove_node(2)
+        hubs, authorities = retworkx.hits(graph)
+        self.assertEqual([0, 1, 3, 4], sorted(hubs.keys()))
+        self.assertEqual([0, 1, 3, 4], sorted(authorities.keys()))
+        self.assertAlmostEqual(1.0, sum(hubs.values()))
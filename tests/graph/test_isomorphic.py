@@ -311,3 +311,113 @@ class TestIsomorphic(unittest.TestCase):
             with self.subTest(id_order=id_order):
                 mapping = retworkx.graph_vf2_mapping(g_a, g_b, id_order=id_order, subgraph=False)
                 self.assertEqual({}, next(mapping))
+
+    def test_canonical_certificate_isomorphic(self):
+        g_a = retworkx.generators.generalized_petersen_graph(5, 2)
+        g_b = retworkx.PyGraph()
+        nodes = g_b.add_nodes_from(list(range(10)))
+        permutation = [3, 7, 1, 9, 0, 5, 8, 2, 6, 4]
+        for source, target in reversed(list(g_a.edge_list())):
+            g_b.add_edge(nodes[permutation[source]], nodes[permutation[target]], None)
+        self.assertEqual(retworkx.canonical_certificate(g_a), retworkx.canonical_certificate(g_b))
+        self.assertEqual(
+            retworkx.graph_canonical_certificate(g_a), retworkx.canonical_certificate(g_b)
+        )
+
+    def test_canonical_certificate_not_isomorphic(self):
+        g_a = retworkx.generators.generalized_petersen_graph(5, 2)
+        g_b = retworkx.generators.generalized_petersen_graph(5, 1)
+        self.assertNotEqual(
+            retworkx.canonical_certificate(g_a), retworkx.canonical_certificate(g_b)
+        )
+
+    def test_canonical_certificate_deduplicate(self):
+        graphs = [
+            retworkx.generators.path_graph(4),
+            retworkx.PyGraph(),
+            retworkx.generators.star_graph(4),
+            retworkx.PyGraph(),
+        ]
+        graphs[1].extend_from_edge_list([(3, 1), (1, 0), (0, 2)])
+        graphs[3].extend_from_edge_list([(0, 2), (0, 3), (1, 0)])
+        certificates = {retworkx.canonical_certificate(graph) for graph in graphs}
+        self.assertEqual(2, len(certificates))
+
+    def test_canonical_certificate_multigraph(self):
+        g_a = retworkx.PyGraph()
+        g_a.extend_from_edge_list([(0, 1), (0, 1), (1, 2), (1, 2)])
+        g_b = retworkx.PyGraph()
+        g_b.extend_from_edge_list([(0, 1), (0, 1), (0, 1), (1, 2)])
+        g_c = retworkx.PyGraph()
+        g_c.extend_from_edge_list([(2, 1), (1, 0), (2, 1), (1, 2)])
+        self.assertNotEqual(
+            retworkx.canonical_certificate(g_a), retworkx.canonical_certificate(g_b)
+        )
+        self.assertEqual(retworkx.canonical_certificate(g_b), retworkx.canonical_certificate(g_c))
+
+    def test_weisfeiler_lehman_graph_hash_isomorphic(self):
+        g_a = retworkx.generators.generalized_petersen_graph(5, 2)
+        g_b = retworkx.PyGraph()
+        nodes = g_b.add_nodes_from(list(range(10)))
+        permutation = [3, 7, 1, 9, 0, 5, 8, 2, 6, 4]
+        for source, target in reversed(list(g_a.edge_list())):
+            g_b.add_edge(nodes[permutation[source]], nodes[permutation[target]], None)
+        digest = retworkx.weisfeiler_lehman_graph_hash(g_a)
+        self.assertEqual(32, len(digest))
+        self.assertEqual(digest, retworkx.graph_weisfeiler_lehman_graph_hash(g_b))
+
+    def test_weisfeiler_lehman_graph_hash_not_isomorphic(self):
+        g_a = retworkx.generators.path_graph(5)
+        g_b = retworkx.generators.star_graph(5)
+        self.assertNotEqual(
+            retworkx.weisfeiler_lehman_graph_hash(g_a), retworkx.weisfeiler_lehman_graph_hash(g_b)
+        )
+
+    def test_weisfeiler_lehman_graph_hash_labels(self):
+        g_a = retworkx.PyGraph()
+        g_a.add_nodes_from(["a", "b", "a"])
+        g_a.add_edges_from([(0, 1, 1), (1, 2, 2)])
+        g_b = retworkx.PyGraph()
+        g_b.add_nodes_from(["a", "a", "b"])
+        g_b.add_edges_from([(0, 2, 2), (2, 1, 1)])
+        g_c = retworkx.PyGraph()
+        g_c.add_nodes_from(["b", "a", "a"])
+        g_c.add_edges_from([(0, 1, 1), (1, 2, 2)])
+        hashes = [
+            retworkx.weisfeiler_lehman_graph_hash(graph, node_attr_fn=str, edge_attr_fn=str)
+            for graph in (g_a, g_b, g_c)
+        ]
+        self.assertEqual(hashes[0], hashes[1])
+        self.assertNotEqual(hashes[0], hashes[2])
+        # Without labels all 3 graphs are paths
+        self.assertEqual(
+            retworkx.weisfeiler_lehman_graph_hash(g_a), retworkx.weisfeiler_lehman_graph_hash(g_c)
+        )
+
+    def test_weisfeiler_lehman_graph_hash_edge_labels(self):
+        g_a = retworkx.generators.path_graph(3, weights=["x", "x", "x"])
+        g_b = g_a.copy()
+        g_b.update_edge_by_index(0, "y")
+        self.assertEqual(
+            retworkx.weisfeiler_lehman_graph_hash(g_a), retworkx.weisfeiler_lehman_graph_hash(g_b)
+        )
+        self.assertNotEqual(
+            retworkx.weisfeiler_lehman_graph_hash(g_a, edge_attr_fn=str),
+            retworkx.weisfeiler_lehman_graph_hash(g_b, edge_attr_fn=str),
+        )
+
+    def test_weisfeiler_lehman_graph_hash_stable(self):
+        graph = retworkx.generators.cycle_graph(4)
+        self.assertEqual(
+            retworkx.weisfeiler_lehman_graph_hash(graph),
+            retworkx.weisfeiler_lehman_graph_hash(graph.copy()),
+        )
+        self.assertNotEqual(
+            retworkx.weisfeiler_lehman_graph_hash(graph, iterations=1),
+            retworkx.weisfeiler_lehman_graph_hash(graph, iterations=2),
+        )
+
+    def test_weisfeiler_lehman_graph_hash_no_iterations(self):
+        graph = retworkx.generators.cycle_graph(4)
+        with self.assertRaises(ValueError):
+            retworkx.weisfeiler_lehman_graph_hash(graph, iterations=0)
@@ -0,0 +1,147 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import math
+import unittest
+
+import numpy as np
+
+import retworkx
+
+
+def to_dense(csr, size):
+    indptr, indices, data = csr
+    matrix = np.zeros((size, size))
+    for row in range(size):
+        for pos in range(indptr[row], indptr[row + 1]):
+            matrix[row, indices[pos]] += data[pos]
+    return matrix
+
+
+class TestGraphLaplacianMatrix(unittest.TestCase):
+    def test_path(self):
+        graph = retworkx.generators.path_graph(3)
+        res = retworkx.graph_laplacian_matrix(graph)
+        self.assertIsInstance(res, np.ndarray)
+        expected = np.array([[1.0, -1.0, 0.0], [-1.0, 2.0, -1.0], [0.0, -1.0, 1.0]])
+        self.assertTrue(np.array_equal(expected, res))
+
+    def test_weight_fn_and_parallel_edges(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(range(3))
+        graph.add_edges_from([(0, 1, 1.0), (0, 1, 2.0), (1, 2, 4.0)])
+        res = retworkx.graph_laplacian_matrix(graph, weight_fn=float)
+        expected = np.array([[3.0, -3.0, 0.0], [-3.0, 7.0, -4.0], [0.0, -4.0, 4.0]])
+        self.assertTrue(np.array_equal(expected, res))
+
+    def test_default_weight(self):
+        graph = retworkx.generators.path_graph(3)
+        res = retworkx.graph_laplacian_matrix(graph, default_weight=2.0)
+        expected = np.array([[2.0, -2.0, 0.0], [-2.0, 4.0, -2.0], [0.0, -2.0, 2.0]])
+        self.assertTrue(np.array_equal(expected, res))
+
+    def test_rows_sum_to_zero(self):
+        graph = retworkx.generators.grid_graph(4, 5)
+        res = retworkx.graph_laplacian_matrix(graph)
+        self.assertTrue(np.array_equal(np.zeros(20), res.sum(axis=1)))
+        self.assertTrue(np.array_equal(res, res.T))
+
+    def test_self_loop(self):
+        graph = retworkx.generators.path_graph(3)
+        graph.add_edge(1, 1, None)
+        res = retworkx.graph_laplacian_matrix(graph)
+        expected = np.array([[1.0, -1.0, 0.0], [-1.0, 2.0, -1.0], [0.0, -1.0, 1.0]])
+        self.assertTrue(np.array_equal(expected, res))
+
+    def test_normalized(self):
+        graph = retworkx.generators.path_graph(3)
+        res = retworkx.graph_laplacian_matrix(graph, normalized=True)
+        x = -1.0 / math.sqrt(2.0)
+        expected = np.array([[1.0, x, 0.0], [x, 1.0, x], [0.0, x, 1.0]])
+        self.assertTrue(np.allclose(expected, res))
+
+    def test_normalized_self_loop(self):
+        graph = retworkx.generators.path_graph(3)
+        graph.add_edge(1, 1, None)
+        res = retworkx.graph_laplacian_matrix(graph, normalized=True)
+        x = -1.0 / math.sqrt(3.0)
+        expected = np.array([[1.0, x, 0.0], [x, 2.0 / 3.0, x], [0.0, x, 1.0]])
+        self.assertTrue(np.allclose(expected, res))
+
+    def test_normalized_isolated_node(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(range(3))
+        graph.add_edge(0, 1, None)
+        res = retworkx.graph_laplacian_matrix(graph, normalized=True)
+        expected = np.array([[1.0, -1.0, 0.0], [-1.0, 1.0, 0.0], [0.0, 0.0, 0.0]])
+        self.assertTrue(np.allclose(expected, res))
+
+    def test_normalized_negative_degree(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(range(2))
+        graph.add_edge(0, 1, -1.0)
+        self.assertTrue(
+            np.array_equal(
+                np.array([[-1.0, 1.0], [1.0, -1.0]]),
+                retworkx.graph_laplacian_matrix(graph, weight_fn=float),
+            )
+        )
+        with self.assertRaises(ValueError):
+            retworkx.graph_laplacian_matrix(graph, weight_fn=float, normalized=True)
+
+    def test_removed_node(self):
+        graph = retworkx.generators.path_graph(4)
+        graph.remove_node(0)
+        res = retworkx.graph_laplacian_matrix(graph)
+        expected = np.array([[1.0, -1.0, 0.0], [-1.0, 2.0, -1.0], [0.0, -1.0, 1.0]])
+        self.assertTrue(np.array_equal(expected, res))
+
+    def test_sparse(self):
+        graph = retworkx.generators.path_graph(3)
+        indptr, indices, data = retworkx.graph_laplacian_matrix(graph, sparse=True)
+        self.assertEqual([0, 2, 5, 7], indptr.tolist())
+        self.assertEqual([0, 1, 0, 1, 2, 1, 2], indices.tolist())
+        self.assertEqual([1.0, -1.0, -1.0, 2.0, -1.0, -1.0, 1.0], data.tolist())
+        self.assertEqual(np.int64, indptr.dtype)
+        self.assertEqual(np.int64, indices.dtype)
+
+    def test_sparse_matches_dense(self):
+        graph = retworkx.generators.grid_graph(4, 5)
+        graph.add_edge(0, 1, None)
+        graph.add_edge(7, 7, None)
+        graph.remove_node(3)
+        for normalized in [False, True]:
+            dense = retworkx.graph_laplacian_matrix(graph, normalized=normalized)
+            csr = retworkx.graph_laplacian_matrix(graph, normalized=normalized, sparse=True)
+            self.assertTrue(np.allclose(dense, to_dense(csr, len(graph))))
+
+    def test_sparse_isolated_node(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(range(2))
+        indptr, indices, data = retworkx.graph_laplacian_matrix(graph, sparse=True)
+        self.assertEqual([0, 1, 2], indptr.tolist())
+        self.assertEqual([0, 1], indices.tolist())
+        self.assertEqual([0.0, 0.0], data.tolist())
+
+    def test_empty_graph(self):
+        res = retworkx.graph_laplacian_matrix(retworkx.PyGraph())
+        self.assertEqual((0, 0), res.shape)
+        indptr, indices, data = retworkx.graph_laplacian_matrix(retworkx.PyGraph(), sparse=True)
+        self.assertEqual([0], indptr.tolist())
+        self.assertEqual([], indices.tolist())
+        self.assertEqual([], data.tolist())
+
+    def test_universal(self):
+        graph = retworkx.generators.path_graph(3)
+        res = retworkx.laplacian_matrix(graph, degree="in", as_undirected=True)
+        expected = np.array([[1.0, -1.0, 0.0], [-1.0, 2.0, -1.0], [0.0, -1.0, 1.0]])
+        self.assertTrue(np.array_equal(expected, res))
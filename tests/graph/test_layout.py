@@ -10,6 +10,7 @@
 # License for the specific language governing permissions and limitations
 # under the License.
 
+import math
 import unittest
 
 import retworkx
@@ -475,3 +476,315 @@ class TestSpiralLayout(LayoutTest):
             9: (-1.0, 0.2018583081028111),
         }
         self.assertLayoutEquiv(expected, res)
+
+
+class TestSpectralLayout(LayoutTest):
+    def test_spectral_layout_empty(self):
+        res = retworkx.spectral_layout(retworkx.PyGraph())
+        self.assertEqual({}, res)
+
+    def test_spectral_layout_one_node(self):
+        res = retworkx.spectral_layout(retworkx.generators.path_graph(1))
+        self.assertEqual({0: (0.0, 0.0)}, res)
+
+    def test_spectral_layout_two_nodes(self):
+        res = retworkx.spectral_layout(retworkx.generators.path_graph(2))
+        self.assertLayoutEquiv({0: (-1.0, 0.0), 1: (1.0, 0.0)}, res)
+
+    def test_spectral_layout_path(self):
+        res = retworkx.spectral_layout(retworkx.generators.path_graph(5))
+        # The Fiedler vector of a path is monotonic and symmetric
+        xs = [res[node][0] for node in range(5)]
+        self.assertAlmostEqual(1.0, max(abs(c) for pos in res.values() for c in pos))
+        self.assertAlmostEqual(0.0, xs[2])
+        self.assertAlmostEqual(xs[0], -xs[4])
+        self.assertAlmostEqual(xs[1], -xs[3])
+        self.assertTrue(xs == sorted(xs) or xs == sorted(xs, reverse=True))
+
+    def test_spectral_layout_grid(self):
+        # The eigenvectors of a grid are products of the eigenvectors of its
+        # rows and columns, so the nodes are placed on a grid
+        graph = retworkx.generators.grid_graph(4, 6)
+        res = retworkx.spectral_layout(graph)
+        for node in graph.node_indices():
+            row, col = divmod(node, 6)
+            self.assertAlmostEqual(res[col][0], res[node][0], places=4)
+            self.assertAlmostEqual(res[6 * row][1], res[node][1], places=4)
+        self.assertEqual(6, len({round(pos[0], 3) for pos in res.values()}))
+        self.assertEqual(4, len({round(pos[1], 3) for pos in res.values()}))
+
+    def test_spectral_layout_weights(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(range(4))
+        graph.add_edges_from([(0, 1, 1.0), (1, 2, 100.0), (2, 3, 1.0)])
+        res = retworkx.spectral_layout(graph, weight_fn=float)
+        self.assertLess(abs(res[1][0] - res[2][0]), abs(res[0][0] - res[1][0]) / 10)
+        unweighted = retworkx.spectral_layout(graph, weight_fn=lambda _: 1.0)
+        self.assertLayoutEquiv(retworkx.spectral_layout(graph, default_weight=2.0), unweighted)
+
+    def test_spectral_layout_scale_center(self):
+        graph = retworkx.generators.grid_graph(3, 5)
+        res = retworkx.spectral_layout(graph, scale=2, center=(1, 1))
+        self.assertAlmostEqual(3.0, max(pos[0] for pos in res.values()))
+        self.assertAlmostEqual(-1.0, min(pos[0] for pos in res.values()))
+        self.assertAlmostEqual(1.0, sum(pos[0] for pos in res.values()) / len(res))
+        self.assertAlmostEqual(1.0, sum(pos[1] for pos in res.values()) / len(res))
+
+    def test_spectral_layout_hole(self):
+        graph = retworkx.generators.path_graph(6)
+        graph.remove_node(5)
+        expected = retworkx.spectral_layout(retworkx.generators.path_graph(5))
+        self.assertLayoutEquiv(expected, retworkx.spectral_layout(graph))
+
+    def test_spectral_layout_not_converged(self):
+        graph = retworkx.generators.grid_graph(10, 10)
+        with self.assertRaises(retworkx.FailedToConverge):
+            retworkx.spectral_layout(graph, max_iter=1)
+
+
+def segments_cross(p1, p2, p3, p4):
+    def orientation(a, b, c):
+        return (b[0] - a[0]) * (c[1] - a[1]) - (b[1] - a[1]) * (c[0] - a[0])
+
+    d1 = orientation(p3, p4, p1)
+    d2 = orientation(p3, p4, p2)
+    d3 = orientation(p1, p2, p3)
+    d4 = orientation(p1, p2, p4)
+    return d1 * d2 < 0 and d3 * d4 < 0
+
+
+class TestPlanarLayout(LayoutTest):
+    def assertNoCrossings(self, graph, pos):
+        self.assertEqual(set(graph.node_indices()), set(pos))
+        points = [tuple(pos[node]) for node in graph.node_indices()]
+        self.assertEqual(len(points), len(set(points)))
+        edges = list(graph.edge_list())
+        for i, (a, b) in enumerate(edges):
+            for c, d in edges[i + 1 :]:
+                if len({a, b, c, d}) == 4:
+                    self.assertFalse(segments_cross(pos[a], pos[b], pos[c], pos[d]))
+
+    def test_planar_layout_empty(self):
+        res = retworkx.planar_layout(retworkx.PyGraph())
+        self.assertEqual({}, res)
+
+    def test_planar_layout_one_node(self):
+        res = retworkx.planar_layout(retworkx.generators.path_graph(1))
+        self.assertEqual({0: (0.0, 0.0)}, res)
+
+    def test_planar_layout_three_nodes(self):
+        res = retworkx.graph_planar_layout(retworkx.generators.path_graph(3))
+        self.assertEqual({0: (0.0, 0.0), 1: (2.0, 0.0), 2: (1.0, 1.0)}, res)
+
+    def test_planar_layout_k4(self):
+        res = retworkx.planar_layout(retworkx.generators.mesh_graph(4), scale=None)
+        expected = {0: (0, 0), 1: (4, 0), 2: (2, 1), 3: (2, 2)}
+        self.assertLayoutEquiv(expected, res)
+
+    def test_planar_layout_scale_center(self):
+        graph = retworkx.generators.mesh_graph(4)
+        res = retworkx.planar_layout(graph, scale=2, center=(1, 1))
+        expected = {0: (-1.0, 0.25), 1: (3.0, 0.25), 2: (1.0, 1.25), 3: (1.0, 2.25)}
+        self.assertLayoutEquiv(expected, res)
+
+    def test_planar_layout_grid(self):
+        graph = retworkx.generators.grid_graph(5, 6)
+        self.assertNoCrossings(graph, retworkx.planar_layout(graph))
+
+    def test_planar_layout_disconnected_hole(self):
+        graph = retworkx.generators.hexagonal_lattice_graph(2, 2)
+        graph.add_nodes_from(range(3))
+        graph.remove_node(3)
+        self.assertNoCrossings(graph, retworkx.planar_layout(graph))
+
+    def test_planar_layout_tree(self):
+        graph = retworkx.generators.binomial_tree_graph(4)
+        self.assertNoCrossings(graph, retworkx.planar_layout(graph))
+
+    def test_planar_layout_non_planar(self):
+        with self.assertRaises(ValueError):
+            retworkx.planar_layout(retworkx.generators.mesh_graph(5))
+
+
+def distance(a, b):
+    return math.hypot(a[0] - b[0], a[1] - b[1])
+
+
+class TestKamadaKawaiLayout(LayoutTest):
+    def assertDistances(self, graph, pos):
+        lengths = retworkx.all_pairs_dijkstra_path_lengths(graph, lambda _: 1.0)
+        for source, targets in lengths.items():
+            for target, length in targets.items():
+                self.assertAlmostEqual(length, distance(pos[source], pos[target]), places=5)
+
+    def test_kamada_kawai_layout_empty(self):
+        res = retworkx.kamada_kawai_layout(retworkx.PyGraph())
+        self.assertEqual({}, res)
+
+    def test_kamada_kawai_layout_one_node(self):
+        res = retworkx.kamada_kawai_layout(retworkx.generators.path_graph(1))
+        self.assertEqual({0: (0.0, 0.0)}, res)
+
+    def test_kamada_kawai_layout_path(self):
+        # A path can be drawn with every distance equal to the path length
+        graph = retworkx.generators.path_graph(5)
+        res = retworkx.kamada_kawai_layout(graph, scale=None, tol=1e-8)
+        self.assertDistances(graph, res)
+        self.assertAlmostEqual(0.0, sum(pos[0] for pos in res.values()))
+        self.assertAlmostEqual(0.0, sum(pos[1] for pos in res.values()))
+
+    def test_kamada_kawai_layout_cycle(self):
+        # A cycle of 4 nodes is drawn as a square
+        graph = retworkx.generators.cycle_graph(4)
+        res = retworkx.kamada_kawai_layout(graph, scale=None, tol=1e-8)
+        side = distance(res[0], res[1])
+        for source, target in graph.edge_list():
+            self.assertAlmostEqual(side, distance(res[source], res[target]), places=5)
+        self.assertAlmostEqual(side * math.sqrt(2), distance(res[0], res[2]), places=5)
+        self.assertAlmostEqual(side * math.sqrt(2), distance(res[1], res[3]), places=5)
+
+    def test_kamada_kawai_layout_scale_center(self):
+        graph = retworkx.generators.path_graph(5)
+        res = retworkx.kamada_kawai_layout(graph, scale=2, center=(1, 1))
+        self.assertAlmostEqual(3.0, max(max(pos) for pos in res.values()))
+        self.assertAlmostEqual(1.0, sum(pos[0] for pos in res.values()) / len(res))
+        self.assertAlmostEqual(1.0, sum(pos[1] for pos in res.values()) / len(res))
+
+    def test_kamada_kawai_layout_initial_pos(self):
+        graph = retworkx.generators.path_graph(3)
+        pos = {0: (0.0, 0.0), 1: (1.0, 0.0), 2: (2.0, 0.0)}
+        res = retworkx.kamada_kawai_layout(graph, pos=pos, scale=None)
+        expected = {0: (-1.0, 0.0), 1: (0.0, 0.0), 2: (1.0, 0.0)}
+        self.assertLayoutEquiv(expected, res)
+
+    def test_kamada_kawai_layout_weights(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(range(3))
+        graph.add_edges_from([(0, 1, 1.0), (1, 2, 3.0)])
+        res = retworkx.kamada_kawai_layout(graph, weight_fn=float, scale=None, tol=1e-8)
+        self.assertAlmostEqual(1.0, distance(res[0], res[1]), places=5)
+        self.assertAlmostEqual(3.0, distance(res[1], res[2]), places=5)
+        self.assertAlmostEqual(4.0, distance(res[0], res[2]), places=5)
+        res = retworkx.kamada_kawai_layout(graph, default_weight=2.0, scale=None, tol=1e-8)
+        self.assertAlmostEqual(4.0, distance(res[0], res[2]), places=5)
+
+    def test_kamada_kawai_layout_invalid_weight(self):
+        graph = retworkx.generators.path_graph(3)
+        with self.assertRaises(ValueError):
+            retworkx.kamada_kawai_layout(graph, default_weight=0.0)
+        with self.assertRaises(ValueError):
+            retworkx.kamada_kawai_layout(graph, weight_fn=lambda _: -1.0)
+
+    def test_kamada_kawai_layout_disconnected(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(range(4))
+        graph.add_edges_from_no_data([(0, 1), (2, 3)])
+        res = retworkx.kamada_kawai_layout(graph, scale=None, tol=1e-8)
+        # The components are symmetric and no nodes overlap
+        self.assertAlmostEqual(distance(res[0], res[1]), distance(res[2], res[3]), places=5)
+        for source in range(4):
+            for target in range(source + 1, 4):
+                self.assertGreater(distance(res[source], res[target]), 0.5)
+
+    def test_kamada_kawai_layout_hole(self):
+        graph = retworkx.generators.path_graph(6)
+        graph.remove_node(2)
+        res = retworkx.kamada_kawai_layout(graph, scale=None)
+        self.assertEqual({0, 1, 3, 4, 5}, set(res))
+        self.assertAlmostEqual(distance(res[3], res[4]), distance(res[4], res[5]), places=3)
+
+    def test_kamada_kawai_layout_landmarks(self):
+        graph = retworkx.generators.grid_graph(6, 6)
+        res = retworkx.kamada_kawai_layout(graph, landmarks=6, scale=None)
+        # The grid isn't folded, its diagonals are both close to the 8.34
+        # they have when every pair of nodes is attached
+        for source, target in graph.edge_list():
+            self.assertLess(abs(1.0 - distance(res[source], res[target])), 0.5)
+        self.assertLess(abs(8.34 - distance(res[0], res[35])), 0.5)
+        self.assertLess(abs(8.34 - distance(res[5], res[30])), 0.5)
+
+    def test_kamada_kawai_layout_landmarks_path(self):
+        graph = retworkx.generators.path_graph(6)
+        res = retworkx.kamada_kawai_layout(graph, landmarks=2, scale=None, tol=1e-8)
+        self.assertDistances(graph, res)
+
+
+class TestEdgeBundling(unittest.TestCase):
+    def setUp(self):
+        # Two groups of parallel edges, perpendicular to each other
+        self.graph = retworkx.PyGraph()
+        self.graph.add_nodes_from(range(12))
+        self.pos = {}
+        for i in range(3):
+            self.pos[i] = [0.0, 0.2 * i]
+            self.pos[i + 3] = [10.0, 0.2 * i]
+            self.pos[i + 6] = [20.0 + 0.2 * i, 0.0]
+            self.pos[i + 9] = [20.0 + 0.2 * i, 10.0]
+            self.graph.add_edge(i, i + 3, None)
+            self.graph.add_edge(i + 6, i + 9, None)
+
+    def test_edge_bundling_empty(self):
+        self.assertEqual({}, retworkx.edge_bundling(retworkx.PyGraph(), {}))
+
+    def test_edge_bundling_no_cycles(self):
+        res = retworkx.edge_bundling(self.graph, self.pos, cycles=0)
+        for edge, (source, target) in zip(self.graph.edge_indices(), self.graph.edge_list()):
+            self.assertEqual([self.pos[source], self.pos[target]], res[edge])
+
+    def test_edge_bundling(self):
+        res = retworkx.edge_bundling(self.graph, self.pos)
+        self.assertEqual(set(self.graph.edge_indices()), set(res))
+        for edge, (source, target) in zip(self.graph.edge_indices(), self.graph.edge_list()):
+            polyline = res[edge]
+            # 1 control point doubled in each of the 6 cycles
+            self.assertEqual(34, len(polyline))
+            self.assertEqual(self.pos[source], polyline[0])
+            self.assertEqual(self.pos[target], polyline[-1])
+        # The middles of the parallel edges are pulled together
+        middles = [res[edge][17] for edge in (0, 2, 4)]
+        self.assertLess(abs(middles[0][1] - middles[2][1]), 0.2)
+        middles = [res[edge][17] for edge in (1, 3, 5)]
+        self.assertLess(abs(middles[0][0] - middles[2][0]), 0.2)
+        # The perpendicular groups don't attract each other
+        for edge in (1, 3, 5):
+            for point in res[edge]:
+                self.assertGreaterEqual(point[0], 20.0 - 1e-9)
+                self.assertLessEqual(point[0], 20.4 + 1e-9)
+
+    def test_edge_bundling_incompatible(self):
+        # Without compatible edges the edges stay straight
+        res = retworkx.edge_bundling(self.graph, self.pos, compatibility_threshold=1.0)
+        for polyline in res.values():
+            (x0, y0), (x1, y1) = polyline[0], polyline[-1]
+            for x, y in polyline:
+                self.assertAlmostEqual(0.0, (x - x0) * (y1 - y0) - (y - y0) * (x1 - x0))
+
+    def test_edge_bundling_scale_invariant(self):
+        res = retworkx.edge_bundling(self.graph, self.pos)
+        scaled = {node: [10 * x, 10 * y] for node, (x, y) in self.pos.items()}
+        scaled_res = retworkx.edge_bundling(self.graph, scaled)
+        for edge, polyline in res.items():
+            for point, scaled_point in zip(polyline, scaled_res[edge]):
+                self.assertAlmostEqual(10 * point[0], scaled_point[0])
+                self.assertAlmostEqual(10 * point[1], scaled_point[1])
+
+    def test_edge_bundling_layout(self):
+        graph = retworkx.generators.mesh_graph(6)
+        pos = retworkx.circular_layout(graph)
+        res = retworkx.edge_bundling(graph, pos, cycles=2)
+        self.assertEqual(res, retworkx.graph_edge_bundling(graph, dict(pos), cycles=2))
+        for edge, (source, target) in zip(graph.edge_indices(), graph.edge_list()):
+            self.assertEqual(4, len(res[edge]))
+            self.assertEqual(list(pos[source]), res[edge][0])
+            self.assertEqual(list(pos[target]), res[edge][-1])
+
+    def test_edge_bundling_self_loop(self):
+        graph = retworkx.PyGraph()
+        node = graph.add_node(None)
+        edge = graph.add_edge(node, node, None)
+        res = retworkx.edge_bundling(graph, {node: [1.0, 2.0]})
+        self.assertEqual([[1.0, 2.0], [1.0, 2.0]], res[edge])
+
+    def test_edge_bundling_missing_position(self):
+        with self.assertRaises(ValueError):
+            retworkx.edge_bundling(self.graph, {0: [0.0, 0.0]})
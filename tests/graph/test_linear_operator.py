@@ -0,0 +1,99 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import retworkx
+import numpy as np
+
+
+class TestGraphLinearOperator(unittest.TestCase):
+    def setUp(self):
+        self.graph = retworkx.PyGraph()
+        self.graph.add_nodes_from(list(range(7)))
+        self.graph.add_edges_from(
+            [(0, 1, 2.0), (1, 2, 1.0), (2, 3, 3.0), (3, 0, 1.5), (1, 3, 0.5), (4, 6, 1.0)]
+        )
+        self.graph.remove_node(5)
+        self.adjacency = retworkx.graph_adjacency_matrix(self.graph, weight_fn=float)
+        self.x = np.arange(1.0, len(self.graph) + 1)
+
+    def test_adjacency(self):
+        operator = retworkx.graph_linear_operator(self.graph, weight_fn=float)
+        self.assertEqual((6, 6), operator.shape)
+        self.assertEqual(np.float64, operator.dtype)
+        self.assertEqual(list(self.graph.node_indices()), list(operator.node_indices))
+        np.testing.assert_allclose(self.adjacency @ self.x, operator.matvec(self.x))
+        np.testing.assert_allclose(self.adjacency @ self.x, operator.rmatvec(self.x))
+        np.testing.assert_allclose(self.adjacency @ self.x, operator @ self.x)
+
+    def test_laplacian(self):
+        operator = retworkx.graph_linear_operator(
+            self.graph, matrix="laplacian", weight_fn=float
+        )
+        laplacian = np.diag(self.adjacency.sum(axis=1)) - self.adjacency
+        np.testing.assert_allclose(laplacian @ self.x, operator.matvec(self.x))
+        np.testing.assert_allclose(np.zeros(6), operator.matvec(np.ones(6)))
+
+    def test_transition(self):
+        operator = retworkx.graph_linear_operator(
+            self.graph, matrix="transition", weight_fn=float
+        )
+        degrees = self.adjacency.sum(axis=1)
+        inverse = np.divide(1.0, degrees, out=np.zeros(6), where=degrees != 0)
+        transition = np.diag(inverse) @ self.adjacency
+        np.testing.assert_allclose(transition @ self.x, operator.matvec(self.x))
+        np.testing.assert_allclose(transition.T @ self.x, operator.rmatvec(self.x))
+
+    def test_default_weight(self):
+        operator = retworkx.graph_linear_operator(self.graph, default_weight=2.0)
+        adjacency = retworkx.graph_adjacency_matrix(self.graph, default_weight=2.0)
+        np.testing.assert_allclose(adjacency @ self.x, operator.matvec(self.x))
+
+    def test_parallel(self):
+        graph = retworkx.generators.grid_graph(30, 30)
+        adjacency = retworkx.graph_adjacency_matrix(graph)
+        x = np.arange(900.0)
+        for threshold in (1, 300, 1000):
+            operator = retworkx.graph_linear_operator(graph, parallel_threshold=threshold)
+            np.testing.assert_allclose(adjacency @ x, operator.matvec(x))
+
+    def test_universal_function(self):
+        operator = retworkx.linear_operator(self.graph, weight_fn=float)
+        np.testing.assert_allclose(self.adjacency @ self.x, operator.matvec(self.x))
+
+    def test_empty_graph(self):
+        operator = retworkx.graph_linear_operator(retworkx.PyGraph())
+        self.assertEqual((0, 0), operator.shape)
+        self.assertEqual(0, len(operator.matvec(np.array([]))))
+
+    def test_invalid_matrix(self):
+        with self.assertRaises(ValueError):
+            retworkx.graph_linear_operator(self.graph, matrix="incidence")
+
+    def test_invalid_length(self):
+        operator = retworkx.graph_linear_operator(self.graph)
+        with self.assertRaises(ValueError):
+            operator.matvec(np.ones(5))
+
+    def test_modified_graph(self):
+        operator = retworkx.graph_linear_operator(self.graph)
+        self.graph.add_edge(0, 4, 1.0)
+        with self.assertRaises(ValueError):
+            operator.matvec(self.x)
+
+    def test_weight_fn_error(self):
+        def weight_fn(_):
+            raise IndexError
+
+        with self.assertRaises(IndexError):
+            retworkx.graph_linear_operator(self.graph, weight_fn=weight_fn)
@@ -0,0 +1,85 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import retworkx
+
+
+class TestMergeNodes(unittest.TestCase):
+    def setUp(self):
+        self.graph = retworkx.PyGraph()
+        self.graph.add_nodes_from(["a", "b", "c", "d"])
+        self.graph.add_edges_from([(0, 1, 1), (1, 2, 2), (0, 2, 3), (2, 3, 4)])
+
+    def test_merge_nodes_drop(self):
+        res = self.graph.merge_node_set([0, 1, 2], lambda nodes: "".join(nodes))
+        self.assertEqual(0, res)
+        self.assertEqual([0, 3], self.graph.node_indices())
+        self.assertEqual("abc", self.graph[0])
+        self.assertEqual([(0, 3, 4)], self.graph.weighted_edge_list())
+
+    def test_merge_nodes_keep(self):
+        self.graph.merge_node_set([0, 1, 2], lambda nodes: nodes[0], merge_edge_policy="keep")
+        self.assertEqual(
+            [(0, 0, 1), (0, 0, 2), (0, 0, 3), (0, 3, 4)],
+            sorted(self.graph.weighted_edge_list()),
+        )
+
+    def test_merge_nodes_aggregate(self):
+        self.graph.add_edge(1, 3, 5)
+        self.graph.merge_node_set(
+            [0, 1, 2],
+            lambda nodes: nodes[0],
+            merge_edge_policy="aggregate",
+            weight_combo_fn=lambda a, b: a + b,
+        )
+        self.assertEqual([(0, 0, 6), (0, 3, 9)], sorted(self.graph.weighted_edge_list()))
+
+    def test_merge_nodes_keeps_edge_index(self):
+        edge = self.graph.add_edge(0, 3, 5)
+        self.graph.merge_node_set([0, 2], lambda nodes: nodes[0])
+        self.assertEqual((0, 3), self.graph.get_edge_endpoints_by_index(edge))
+        self.assertEqual([5], self.graph.get_all_edge_data(0, 3))
+
+    def test_merge_nodes_not_multigraph(self):
+        graph = retworkx.PyGraph(multigraph=False)
+        graph.add_nodes_from(["a", "b", "c"])
+        graph.add_edges_from([(0, 2, 1), (1, 2, 2)])
+        graph.merge_node_set([0, 1], lambda nodes: nodes[0], merge_edge_policy="keep")
+        self.assertEqual([(0, 2, 1)], graph.weighted_edge_list())
+
+    def test_merge_nodes_invalid(self):
+        with self.assertRaises(IndexError):
+            self.graph.merge_node_set([0, 10], lambda nodes: nodes[0])
+        with self.assertRaises(ValueError):
+            self.graph.merge_node_set([], lambda nodes: None)
+        with self.assertRaises(ValueError):
+            self.graph.merge_node_set([0, 1], lambda nodes: None, merge_edge_policy="bad")
+        with self.assertRaises(ValueError):
+            self.graph.merge_node_set([0, 1], lambda nodes: None, merge_edge_policy="aggregate")
+        self.assertEqual(4, len(self.graph))
+
+    def test_contract_edge(self):
+        edge = self.graph.edge_indices()[1]
+        res = self.graph.contract_edge(edge)
+        self.assertEqual(1, res)
+        self.assertEqual(["a", "b", "d"], self.graph.nodes())
+        self.assertEqual([(0, 1, 1), (1, 3, 4)], self.graph.weighted_edge_list())
+
+    def test_contract_edge_payload(self):
+        self.graph.contract_edge(0, merge_payload_fn=lambda nodes: nodes)
+        self.assertEqual(["a", "b"], self.graph[0])
+
+    def test_contract_edge_invalid(self):
+        with self.assertRaises(IndexError):
+            self.graph.contract_edge(10)
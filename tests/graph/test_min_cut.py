@@ -0,0 +1,106 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+
+import unittest
+
+import retworkx
+
+
+class TestMinCut(unittest.TestCase):
+    def setUp(self):
+        # Two squares that share the node 3
+        self.graph = retworkx.PyGraph()
+        self.graph.add_nodes_from(range(7))
+        self.graph.add_edges_from_no_data(
+            [(0, 1), (1, 2), (2, 3), (3, 0), (3, 4), (4, 5), (5, 6), (6, 3)]
+        )
+
+    def test_edge_connectivity(self):
+        self.assertEqual(retworkx.edge_connectivity(self.graph), 2)
+        self.assertEqual(retworkx.edge_connectivity(self.graph, 0, 5), 2)
+
+    def test_node_connectivity(self):
+        self.assertEqual(retworkx.node_connectivity(self.graph), 1)
+        self.assertEqual(retworkx.node_connectivity(self.graph, 0, 5), 1)
+        self.assertEqual(retworkx.node_connectivity(self.graph, 0, 2), 2)
+        # the edge between adjacent nodes counts as one path
+        self.assertEqual(retworkx.node_connectivity(self.graph, 0, 1), 2)
+
+    def test_minimum_edge_cut(self):
+        cut = retworkx.minimum_edge_cut(self.graph)
+        self.assertEqual(len(cut), 2)
+        graph = self.graph.copy()
+        for source, target in cut:
+            graph.remove_edge(source, target)
+        self.assertFalse(retworkx.is_connected(graph))
+
+    def test_minimum_edge_cut_source_target(self):
+        cut = retworkx.minimum_edge_cut(self.graph, 4, 2)
+        self.assertEqual(len(cut), 2)
+        graph = self.graph.copy()
+        for source, target in cut:
+            graph.remove_edge(source, target)
+        self.assertEqual(retworkx.dijkstra_shortest_paths(graph, 4, 2), {})
+
+    def test_minimum_node_cut(self):
+        self.assertEqual(list(retworkx.minimum_node_cut(self.graph)), [3])
+        self.assertEqual(list(retworkx.minimum_node_cut(self.graph, 0, 5)), [3])
+        self.assertEqual(sorted(retworkx.minimum_node_cut(self.graph, 0, 2)), [1, 3])
+
+    def test_minimum_node_cut_adjacent(self):
+        with self.assertRaises(ValueError):
+            retworkx.minimum_node_cut(self.graph, 0, 1)
+
+    def test_complete_graph(self):
+        graph = retworkx.generators.mesh_graph(5)
+        self.assertEqual(retworkx.edge_connectivity(graph), 4)
+        self.assertEqual(retworkx.node_connectivity(graph), 4)
+        self.assertEqual(len(retworkx.minimum_edge_cut(graph)), 4)
+        self.assertEqual(len(retworkx.minimum_node_cut(graph)), 4)
+
+    def test_parallel_edges(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(range(3))
+        graph.add_edges_from_no_data([(0, 1), (0, 1), (1, 2), (1, 2), (1, 2)])
+        self.assertEqual(retworkx.edge_connectivity(graph), 2)
+        self.assertEqual(retworkx.edge_connectivity(graph, 0, 2), 2)
+        self.assertEqual(retworkx.minimum_edge_cut(graph), [(0, 1), (0, 1)])
+        self.assertEqual(retworkx.node_connectivity(graph), 1)
+
+    def test_disconnected(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(range(4))
+        graph.add_edges_from_no_data([(0, 1), (2, 3)])
+        self.assertEqual(retworkx.edge_connectivity(graph), 0)
+        self.assertEqual(retworkx.node_connectivity(graph), 0)
+        self.assertEqual(len(retworkx.minimum_edge_cut(graph)), 0)
+        self.assertEqual(len(retworkx.minimum_node_cut(graph)), 0)
+        self.assertEqual(retworkx.edge_connectivity(graph, 0, 3), 0)
+
+    def test_small_graphs(self):
+        graph = retworkx.PyGraph()
+        self.assertEqual(retworkx.edge_connectivity(graph), 0)
+        self.assertEqual(retworkx.node_connectivity(graph), 0)
+        graph.add_node(0)
+        self.assertEqual(retworkx.edge_connectivity(graph), 0)
+        self.assertEqual(len(retworkx.minimum_node_cut(graph)), 0)
+
+    def test_invalid_source_target(self):
+        with self.assertRaises(ValueError):
+            retworkx.edge_connectivity(self.graph, 0)
+        with self.assertRaises(ValueError):
+            retworkx.node_connectivity(self.graph, 0, 0)
+        with self.assertRaises(retworkx.InvalidNode):
+            retworkx.minimum_edge_cut(self.graph, 0, 42)
+        with self.assertRaises(TypeError):
+            retworkx.edge_connectivity(None)
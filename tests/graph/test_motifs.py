@@ -0,0 +1,112 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import retworkx
+
+
+class TestGraphletCensus(unittest.TestCase):
+    def test_tailed_triangle(self):
+        graph = retworkx.PyGraph()
+        graph.extend_from_edge_list([(0, 1), (1, 2), (2, 0), (2, 3)])
+        res = retworkx.graphlet_census(graph)
+        self.assertEqual(
+            res,
+            {
+                "3-path": 2,
+                "triangle": 1,
+                "4-path": 0,
+                "3-star": 0,
+                "4-cycle": 0,
+                "tailed-triangle": 1,
+                "diamond": 0,
+                "4-clique": 0,
+            },
+        )
+
+    def test_complete_graph(self):
+        graph = retworkx.generators.mesh_graph(5)
+        res = retworkx.graphlet_census(graph)
+        self.assertEqual(res["triangle"], 10)
+        self.assertEqual(res["4-clique"], 5)
+        self.assertEqual(sum(res.values()), 15)
+
+    def test_cycle_graph(self):
+        graph = retworkx.generators.cycle_graph(4)
+        res = retworkx.graphlet_census(graph)
+        self.assertEqual(res["3-path"], 4)
+        self.assertEqual(res["4-cycle"], 1)
+        self.assertEqual(sum(res.values()), 5)
+
+    def test_star_graph(self):
+        graph = retworkx.generators.star_graph(5)
+        res = retworkx.graphlet_census(graph)
+        self.assertEqual(res["3-path"], 6)
+        self.assertEqual(res["3-star"], 4)
+        self.assertEqual(sum(res.values()), 10)
+
+    def test_self_loops_and_parallel_edges(self):
+        graph = retworkx.PyGraph()
+        graph.extend_from_edge_list([(0, 1), (1, 2), (1, 2), (2, 2)])
+        res = retworkx.graphlet_census(graph)
+        self.assertEqual(res["3-path"], 1)
+        self.assertEqual(sum(res.values()), 1)
+
+    def test_empty(self):
+        res = retworkx.graphlet_census(retworkx.PyGraph())
+        self.assertEqual(sum(res.values()), 0)
+        self.assertEqual(len(res), 8)
+
+    def test_parallel_threshold(self):
+        graph = retworkx.undirected_gnp_random_graph(50, 0.2, seed=42)
+        self.assertEqual(
+            retworkx.graphlet_census(graph, parallel_threshold=1),
+            retworkx.graphlet_census(graph, parallel_threshold=1000),
+        )
+
+
+class TestGraphletOrbits(unittest.TestCase):
+    def test_star_graph(self):
+        graph = retworkx.generators.star_graph(4)
+        res = retworkx.graphlet_orbits(graph)
+        self.assertEqual(res[0], [3, 0, 3, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0])
+        for leaf in range(1, 4):
+            self.assertEqual(res[leaf], [1, 2, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0])
+
+    def test_diamond(self):
+        graph = retworkx.PyGraph()
+        graph.extend_from_edge_list([(0, 1), (1, 2), (2, 3), (3, 0), (0, 2)])
+        res = retworkx.graphlet_orbits(graph)
+        self.assertEqual(res[0], [3, 0, 1, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0])
+        self.assertEqual(res[1], [2, 2, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0])
+
+    def test_orbits_match_census(self):
+        graph = retworkx.undirected_gnp_random_graph(30, 0.3, seed=7)
+        census = retworkx.graphlet_census(graph)
+        orbits = retworkx.graphlet_orbits(graph)
+        totals = [sum(counts[orbit] for counts in orbits.values()) for orbit in range(15)]
+        self.assertEqual(totals[1] + totals[2], 3 * census["3-path"])
+        self.assertEqual(totals[3], 3 * census["triangle"])
+        self.assertEqual(totals[8], 4 * census["4-cycle"])
+        self.assertEqual(totals[14], 4 * census["4-clique"])
+        self.assertEqual(totals[0], 2 * graph.num_edges())
+
+    def test_removed_node(self):
+        graph = retworkx.generators.path_graph(4)
+        graph.remove_node(0)
+        res = retworkx.graphlet_orbits(graph)
+        self.assertEqual(list(res.keys()), [1, 2, 3])
+        self.assertEqual(res[2], [2, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0])
+
+    def test_empty(self):
+        self.assertEqual(retworkx.graphlet_orbits(retworkx.PyGraph()), {})
@@ -120,3 +120,31 @@ class TestMinimumSpanningTree(unittest.TestCase):
 
         with self.assertRaises(ValueError):
             retworkx.minimum_spanning_tree(invalid_graph, lambda x: x)
+
+    def test_ties_use_edge_order(self):
+        graph = retworkx.generators.mesh_graph(20)
+        mst_edges = retworkx.minimum_spanning_edges(graph, weight_fn=lambda _: 1.0)
+        self.assertEqual([(u, v) for u, v, _ in mst_edges], [(0, v) for v in range(1, 20)])
+
+    def test_ties_multigraph(self):
+        graph = retworkx.PyGraph()
+        graph.extend_from_weighted_edge_list([(0, 1, "a"), (1, 2, "b"), (0, 2, "c"), (0, 1, "d")])
+        for _ in range(10):
+            mst_edges = retworkx.minimum_spanning_edges(graph, weight_fn=lambda _: 2)
+            self.assertEqual(list(mst_edges), [(0, 1, "a"), (1, 2, "b")])
+
+    def test_weight_attr(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(list(range(3)))
+        graph.add_edge(0, 1, {"weight": 3})
+        graph.add_edge(1, 2, {"weight": 1})
+        graph.add_edge(0, 2, {"weight": 2})
+        mst_edges = retworkx.minimum_spanning_edges(graph, weight_attr="weight")
+        self.assertEqual([(1, 2, {"weight": 1}), (0, 2, {"weight": 2})], list(mst_edges))
+        mst = retworkx.minimum_spanning_tree(graph, weight_attr="weight")
+        self.assertEqual([(1, 2), (0, 2)], list(mst.edge_list()))
+
+    def test_weight_fn_and_weight_attr(self):
+        graph = retworkx.generators.path_graph(2)
+        with self.assertRaises(ValueError):
+            retworkx.minimum_spanning_edges(graph, weight_fn=float, weight_attr="weight")
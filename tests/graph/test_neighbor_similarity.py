@@ -0,0 +1,89 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import random
+import unittest
+
+import retworkx
+
+
+class TestCommonNeighbors(unittest.TestCase):
+    def setUp(self):
+        self.graph = retworkx.PyGraph()
+        self.graph.add_nodes_from(range(6))
+        self.graph.add_edges_from_no_data(
+            [(0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (1, 4), (2, 2), (0, 5), (0, 5)]
+        )
+
+    def test_common_neighbors(self):
+        self.assertEqual([2, 3], list(retworkx.graph_common_neighbors(self.graph, 0, 1)))
+        self.assertEqual([0, 1], list(retworkx.graph_common_neighbors(self.graph, 2, 3)))
+
+    def test_common_neighbors_excludes_pair(self):
+        self.assertEqual([1], list(retworkx.graph_common_neighbors(self.graph, 0, 2)))
+
+    def test_common_neighbors_same_node(self):
+        self.assertEqual([1, 2, 3, 5], list(retworkx.graph_common_neighbors(self.graph, 0, 0)))
+
+    def test_common_neighbors_none(self):
+        self.assertEqual([], list(retworkx.graph_common_neighbors(self.graph, 4, 5)))
+
+    def test_common_neighbors_invalid_node(self):
+        with self.assertRaises(retworkx.InvalidNode):
+            retworkx.graph_common_neighbors(self.graph, 0, 10)
+
+    def test_common_neighbors_universal(self):
+        self.assertEqual([2, 3], list(retworkx.common_neighbors(self.graph, 0, 1)))
+
+
+class TestJaccardSimilarity(unittest.TestCase):
+    def setUp(self):
+        self.graph = retworkx.PyGraph()
+        self.graph.add_nodes_from(range(6))
+        self.graph.add_edges_from_no_data(
+            [(0, 2), (0, 3), (1, 3), (1, 4), (2, 2), (0, 3), (4, 4)]
+        )
+
+    def test_jaccard_similarity(self):
+        res = retworkx.graph_jaccard_similarity(self.graph, [(0, 1), (0, 0), (2, 3), (0, 5)])
+        self.assertEqual([1 / 3, 1.0, 1 / 2, 0.0], res)
+
+    def test_jaccard_similarity_isolated(self):
+        self.assertEqual([0.0], retworkx.graph_jaccard_similarity(self.graph, [(5, 5)]))
+
+    def test_jaccard_similarity_no_pairs(self):
+        self.assertEqual([], retworkx.graph_jaccard_similarity(self.graph, []))
+
+    def test_jaccard_similarity_invalid_node(self):
+        with self.assertRaises(retworkx.InvalidNode):
+            retworkx.graph_jaccard_similarity(self.graph, [(0, 1), (7, 1)])
+
+    def test_jaccard_similarity_matches_sets(self):
+        graph = retworkx.undirected_gnm_random_graph(100, 800, seed=10)
+        for hub in range(5):
+            for node in range(5, 100, 3):
+                graph.add_edge(hub, node, None)
+        rng = random.Random(4)
+        pairs = [(rng.randrange(100), rng.randrange(100)) for _ in range(500)]
+        expected = []
+        for a, b in pairs:
+            a_set = set(graph.neighbors(a)) - {a}
+            b_set = set(graph.neighbors(b)) - {b}
+            union = a_set | b_set
+            expected.append(len(a_set & b_set) / len(union) if union else 0.0)
+        self.assertEqual(expected, retworkx.graph_jaccard_similarity(graph, pairs))
+        self.assertEqual(
+            expected, retworkx.graph_jaccard_similarity(graph, pairs, parallel_threshold=1)
+        )
+
+    def test_jaccard_similarity_universal(self):
+        self.assertEqual([1 / 3], retworkx.jaccard_similarity(self.graph, [(0, 1)]))
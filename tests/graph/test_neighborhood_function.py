@@ -0,0 +1,76 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+
+import unittest
+
+import retworkx
+
+
+class TestApproximateNeighborhoodFunction(unittest.TestCase):
+    def test_path_graph(self):
+        graph = retworkx.generators.path_graph(4)
+        res = retworkx.approximate_neighborhood_function(graph, seed=42)
+        self.assertEqual(4, len(res.values))
+        for estimate, exact in zip(res.values, [4, 10, 14, 16]):
+            self.assertAlmostEqual(exact, estimate, delta=0.5)
+        self.assertAlmostEqual(20 / 12, res.average_distance(), delta=0.1)
+        self.assertAlmostEqual(3.0, res.effective_diameter(1.0), delta=0.1)
+
+    def test_grid_graph(self):
+        graph = retworkx.generators.grid_graph(20, 20)
+        res = retworkx.graph_approximate_neighborhood_function(graph, precision=12, seed=42)
+        # The diameter of a 20x20 grid is 38
+        self.assertEqual(39, len(res.values))
+        self.assertAlmostEqual(400 * 400, res.values[-1], delta=0.02 * 400 * 400)
+        exact = retworkx.unweighted_average_shortest_path_length(graph)
+        self.assertAlmostEqual(exact, res.average_distance(), delta=0.05 * exact)
+        self.assertLess(res.effective_diameter(), 38)
+
+    def test_max_distance(self):
+        graph = retworkx.generators.path_graph(10)
+        res = retworkx.approximate_neighborhood_function(graph, seed=42, max_distance=2)
+        self.assertEqual(3, len(res.values))
+
+    def test_seed_reproducible(self):
+        graph = retworkx.generators.grid_graph(10, 10)
+        first = retworkx.approximate_neighborhood_function(graph, precision=4, seed=1234)
+        second = retworkx.approximate_neighborhood_function(graph, precision=4, seed=1234)
+        self.assertEqual(first.values, second.values)
+
+    def test_disconnected(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(range(4))
+        graph.add_edges_from_no_data([(0, 1), (2, 3)])
+        res = retworkx.approximate_neighborhood_function(graph, seed=42)
+        self.assertEqual(2, len(res.values))
+        self.assertAlmostEqual(8, res.values[-1], delta=0.5)
+        self.assertAlmostEqual(1.0, res.average_distance(), delta=0.1)
+
+    def test_empty(self):
+        res = retworkx.approximate_neighborhood_function(retworkx.PyGraph())
+        self.assertEqual([0.0], res.values)
+        self.assertEqual(0.0, res.average_distance())
+        self.assertEqual(0.0, res.effective_diameter())
+
+    def test_invalid_precision(self):
+        graph = retworkx.generators.path_graph(4)
+        with self.assertRaises(ValueError):
+            retworkx.approximate_neighborhood_function(graph, precision=3)
+        with self.assertRaises(ValueError):
+            retworkx.approximate_neighborhood_function(graph, precision=17)
+
+    def test_invalid_quantile(self):
+        graph = retworkx.generators.path_graph(4)
+        res = retworkx.approximate_neighborhood_function(graph)
+        with self.assertRaises(ValueError):
+            res.effective_diameter(1.5)
@@ -0,0 +1,62 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+
+import unittest
+
+import retworkx
+
+
+class TestPageRank(unittest.TestCase):
+    def assertRanksAlmostEqual(self, expected, ranks):
+        self.assertEqual(sorted(expected), sorted(ranks.keys()))
+        for node, rank in expected.items():
+            self.assertAlmostEqual(rank, ranks[node], places=5)
+
+    def test_pagerank(self):
+        graph = retworkx.PyGraph()
+        graph.extend_from_edge_list([(0, 1), (1, 2), (1, 3)])
+        ranks = retworkx.pagerank(graph)
+        expected = {0: 0.173423, 1: 0.479731, 2: 0.173423, 3: 0.173423}
+        self.assertRanksAlmostEqual(expected, ranks)
+
+    def test_pagerank_isolated_node(self):
+        graph = retworkx.PyGraph()
+        graph.extend_from_edge_list([(0, 1), (1, 2), (1, 3)])
+        graph.add_node(None)
+        ranks = retworkx.graph_pagerank(graph)
+        expected = {0: 0.167155, 1: 0.462391, 2: 0.167155, 3: 0.167155, 4: 0.036145}
+        self.assertRanksAlmostEqual(expected, ranks)
+
+    def test_pagerank_cycle_is_uniform(self):
+        graph = retworkx.generators.cycle_graph(5)
+        ranks = retworkx.pagerank(graph)
+        self.assertRanksAlmostEqual({node: 0.2 for node in range(5)}, ranks)
+
+    def test_pagerank_parallel_edges_add_weights(self):
+        graph = retworkx.PyGraph()
+        graph.extend_from_weighted_edge_list([(0, 1, 1), (0, 1, 2), (0, 2, 3)])
+        single = retworkx.PyGraph()
+        single.extend_from_weighted_edge_list([(0, 1, 3), (0, 2, 3)])
+        ranks = retworkx.pagerank(graph, weight_fn=float)
+        expected = retworkx.pagerank(single, weight_fn=float)
+        self.assertRanksAlmostEqual(dict(expected.items()), ranks)
+
+    def test_pagerank_failed_to_converge(self):
+        graph = retworkx.generators.path_graph(5)
+        with self.assertRaises(retworkx.FailedToConverge):
+            retworkx.pagerank(graph, max_iter=1)
+
+    def test_pagerank_invalid_weight(self):
+        graph = retworkx.generators.path_graph(3)
+        with self.assertRaises(ValueError):
+            retworkx.pagerank(graph, weight_fn=lambda _: -1.0)
@@ -0,0 +1,153 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import retworkx
+
+
+def cut_weight(graph, parts, weight_fn=lambda _: 1.0):
+    part_of = {node: part for part, nodes in enumerate(parts) for node in nodes}
+    return sum(
+        weight_fn(weight)
+        for source, target, weight in graph.weighted_edge_list()
+        if part_of[source] != part_of[target]
+    )
+
+
+class TestGraphKernighanLinBisection(unittest.TestCase):
+    def test_barbell(self):
+        graph = retworkx.generators.barbell_graph(4)
+        for seed in range(10):
+            res = retworkx.graph_kernighan_lin_bisection(graph, seed=seed)
+            self.assertIsInstance(res, retworkx.GraphPartitioning)
+            self.assertEqual(2, len(res))
+            self.assertEqual([[0, 1, 2, 3], [4, 5, 6, 7]], sorted(res.parts))
+            self.assertEqual(1.0, res.cut_weight)
+
+    def test_weight_fn(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(range(4))
+        graph.add_edges_from([(0, 1, 5.0), (1, 2, 1.0), (2, 3, 5.0)])
+        res = retworkx.graph_kernighan_lin_bisection(graph, weight_fn=float, seed=42)
+        self.assertEqual([[0, 1], [2, 3]], sorted(res.parts))
+        self.assertEqual(1.0, res.cut_weight)
+
+    def test_odd_number_of_nodes(self):
+        graph = retworkx.generators.path_graph(7)
+        res = retworkx.graph_kernighan_lin_bisection(graph, seed=42)
+        self.assertEqual([3, 4], sorted(len(part) for part in res.parts))
+        self.assertEqual(list(range(7)), sorted(res.parts[0] + res.parts[1]))
+        self.assertEqual(cut_weight(graph, res.parts), res.cut_weight)
+
+    def test_no_passes(self):
+        graph = retworkx.generators.cycle_graph(10)
+        res = retworkx.graph_kernighan_lin_bisection(graph, max_iter=0, seed=42)
+        self.assertEqual([5, 5], [len(part) for part in res.parts])
+        self.assertEqual(cut_weight(graph, res.parts), res.cut_weight)
+
+    def test_assignment(self):
+        graph = retworkx.generators.barbell_graph(4)
+        graph.remove_node(0)
+        res = retworkx.graph_kernighan_lin_bisection(graph, seed=42)
+        assignment = res.assignment
+        self.assertEqual(graph.node_indices(), list(assignment.keys()))
+        for part, nodes in enumerate(res.parts):
+            for node in nodes:
+                self.assertEqual(part, assignment[node])
+
+    def test_self_loops_ignored(self):
+        graph = retworkx.generators.barbell_graph(4)
+        graph.add_edge(0, 0, None)
+        res = retworkx.graph_kernighan_lin_bisection(graph, seed=42)
+        self.assertEqual(1.0, res.cut_weight)
+
+    def test_empty(self):
+        res = retworkx.graph_kernighan_lin_bisection(retworkx.PyGraph())
+        self.assertEqual([[], []], res.parts)
+        self.assertEqual(0.0, res.cut_weight)
+
+    def test_seed(self):
+        graph = retworkx.generators.grid_graph(6, 6)
+        res = retworkx.graph_kernighan_lin_bisection(graph, seed=7)
+        other = retworkx.graph_kernighan_lin_bisection(graph, seed=7)
+        self.assertEqual(res.parts, other.parts)
+        self.assertEqual(res.cut_weight, other.cut_weight)
+
+
+class TestGraphMultilevelPartition(unittest.TestCase):
+    def test_grid(self):
+        graph = retworkx.generators.grid_graph(16, 16)
+        res = retworkx.graph_multilevel_partition(graph, 4, seed=42)
+        self.assertIsInstance(res, retworkx.GraphPartitioning)
+        self.assertEqual(4, len(res))
+        self.assertEqual(list(range(256)), sorted(node for part in res.parts for node in part))
+        for part in res.parts:
+            self.assertLessEqual(len(part), 65)
+        self.assertEqual(cut_weight(graph, res.parts), res.cut_weight)
+        # A random partition cuts about 3/4 of the 480 edges
+        self.assertLess(res.cut_weight, 100)
+
+    def test_cliques(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(range(15))
+        for first in (0, 5, 10):
+            for i in range(first, first + 5):
+                for j in range(i + 1, first + 5):
+                    graph.add_edge(i, j, None)
+        graph.add_edges_from_no_data([(0, 5), (5, 10), (10, 0)])
+        res = retworkx.graph_multilevel_partition(graph, 3, imbalance=0.0, seed=42)
+        expected = [list(range(first, first + 5)) for first in (0, 5, 10)]
+        self.assertEqual(expected, sorted(res.parts))
+        self.assertEqual(3.0, res.cut_weight)
+
+    def test_disconnected(self):
+        graph = retworkx.generators.mesh_graph(5)
+        graph.compose(retworkx.generators.mesh_graph(5), {})
+        res = retworkx.graph_multilevel_partition(graph, 2, seed=42)
+        self.assertEqual([[0, 1, 2, 3, 4], [5, 6, 7, 8, 9]], sorted(res.parts))
+        self.assertEqual(0.0, res.cut_weight)
+
+    def test_weight_fn(self):
+        graph = retworkx.generators.grid_graph(8, 8)
+        res = retworkx.graph_multilevel_partition(
+            graph, 2, weight_fn=lambda _: 2.5, imbalance=0.0, seed=42
+        )
+        self.assertEqual([32, 32], [len(part) for part in res.parts])
+        self.assertEqual(cut_weight(graph, res.parts, lambda _: 2.5), res.cut_weight)
+
+    def test_single_part(self):
+        graph = retworkx.generators.cycle_graph(5)
+        res = retworkx.graph_multilevel_partition(graph, 1)
+        self.assertEqual([[0, 1, 2, 3, 4]], res.parts)
+        self.assertEqual(0.0, res.cut_weight)
+
+    def test_more_parts_than_nodes(self):
+        graph = retworkx.generators.path_graph(3)
+        res = retworkx.graph_multilevel_partition(graph, 5, seed=42)
+        self.assertEqual(5, len(res.parts))
+        self.assertEqual([0, 0, 1, 1, 1], sorted(len(part) for part in res.parts))
+        self.assertEqual(2.0, res.cut_weight)
+
+    def test_invalid_arguments(self):
+        graph = retworkx.generators.path_graph(3)
+        with self.assertRaises(ValueError):
+            retworkx.graph_multilevel_partition(graph, 0)
+        with self.assertRaises(ValueError):
+            retworkx.graph_multilevel_partition(graph, 2, imbalance=-0.5)
+
+    def test_universal_functions(self):
+        graph = retworkx.generators.barbell_graph(4)
+        res = retworkx.kernighan_lin_bisection(graph, seed=42)
+        self.assertEqual([[0, 1, 2, 3], [4, 5, 6, 7]], sorted(res.parts))
+        res = retworkx.multilevel_partition(graph, 2, seed=42)
+        self.assertEqual([[0, 1, 2, 3], [4, 5, 6, 7]], sorted(res.parts))
@@ -0,0 +1,133 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import retworkx
+
+
+class TestGraphPathRanking(unittest.TestCase):
+    def setUp(self):
+        # Two routes from 0 to 4 with a detour on the first one
+        self.graph = retworkx.PyGraph()
+        self.graph.add_nodes_from(["a", "b", "c", "d", "e", "f"])
+        self.graph.add_edges_from(
+            [(0, 1, 1), (1, 2, 1), (2, 4, 1), (1, 3, 1.5), (3, 4, 1.5), (0, 5, 2), (5, 4, 2)]
+        )
+        self.paths = [[0, 1, 2, 4], [0, 1, 3, 4], [0, 5, 4]]
+
+    def test_rank_paths(self):
+        res = retworkx.graph_rank_paths(self.graph, self.paths, weight_fn=float)
+        self.assertEqual([(0, 3), (1, 4), (2, 4)], res)
+
+    def test_rank_paths_overlap_penalty(self):
+        res = retworkx.graph_rank_paths(self.graph, self.paths, weight_fn=float, overlap_penalty=2)
+        self.assertEqual([0, 2, 1], [position for position, _ in res])
+        self.assertEqual(3, res[0][1])
+        self.assertEqual(4, res[1][1])
+        self.assertAlmostEqual(4.4, res[2][1])
+
+    def test_rank_paths_default_weight(self):
+        res = retworkx.graph_rank_paths(self.graph, self.paths, default_weight=2)
+        self.assertEqual([(2, 4), (0, 6), (1, 6)], res)
+
+    def test_rank_paths_reversed_path(self):
+        paths = [[0, 1, 2, 4], [4, 2, 1, 0]]
+        res = retworkx.graph_rank_paths(self.graph, paths, overlap_penalty=1)
+        self.assertEqual([(0, 3), (1, 4)], res)
+
+    def test_rank_paths_parallel_edges(self):
+        self.graph.add_edge(4, 2, 0.5)
+        res = retworkx.graph_rank_paths(self.graph, self.paths, weight_fn=float)
+        self.assertEqual((0, 2.5), res[0])
+
+    def test_rank_paths_node_filter(self):
+        res = retworkx.graph_rank_paths(
+            self.graph, self.paths, weight_fn=float, node_filter=lambda node: node != "c"
+        )
+        self.assertEqual([(1, 4), (2, 4)], res)
+
+    def test_rank_paths_max_weight(self):
+        res = retworkx.graph_rank_paths(self.graph, self.paths, weight_fn=float, max_weight=3.5)
+        self.assertEqual([(0, 3)], res)
+
+    def test_rank_paths_empty(self):
+        self.assertEqual([], retworkx.graph_rank_paths(self.graph, []))
+
+    def test_rank_paths_single_node(self):
+        self.assertEqual([(0, 0)], retworkx.graph_rank_paths(self.graph, [[3]]))
+
+    def test_rank_simple_paths(self):
+        graph = retworkx.generators.grid_graph(3, 3)
+        paths = retworkx.graph_all_simple_paths(graph, 0, 8, cutoff=5)
+        res = retworkx.graph_rank_paths(graph, paths)
+        self.assertEqual(len(paths), len(res))
+        self.assertEqual({4}, {score for _, score in res})
+
+    def test_rank_path_mapping(self):
+        graph = retworkx.generators.path_graph(4)
+        paths = retworkx.graph_dijkstra_shortest_paths(graph, 0)
+        res = retworkx.graph_rank_paths(graph, list(paths.values()))
+        self.assertEqual([1, 2, 3], [score for _, score in res])
+
+    def test_rank_paths_invalid_node(self):
+        with self.assertRaises(retworkx.InvalidNode):
+            retworkx.graph_rank_paths(self.graph, [[0, 1, 10]])
+
+    def test_rank_paths_not_a_path(self):
+        with self.assertRaises(ValueError):
+            retworkx.graph_rank_paths(self.graph, [[0, 1, 2, 4], [0, 4]])
+
+    def test_rank_paths_negative_penalty(self):
+        with self.assertRaises(ValueError):
+            retworkx.graph_rank_paths(self.graph, self.paths, overlap_penalty=-1)
+
+    def test_rank_paths_negative_weight(self):
+        with self.assertRaises(ValueError):
+            retworkx.graph_rank_paths(self.graph, self.paths, weight_fn=lambda _: -1)
+
+    def test_diverse_paths(self):
+        res = retworkx.graph_diverse_paths(self.graph, self.paths, 2, weight_fn=float)
+        self.assertEqual([0, 2], res)
+
+    def test_diverse_paths_all(self):
+        res = retworkx.graph_diverse_paths(self.graph, self.paths, 5, weight_fn=float)
+        self.assertEqual([0, 2, 1], res)
+
+    def test_diverse_paths_zero(self):
+        self.assertEqual([], retworkx.graph_diverse_paths(self.graph, self.paths, 0))
+
+    def test_diverse_paths_cost_tie_break(self):
+        self.graph.add_edge(0, 4, 10)
+        paths = self.paths + [[0, 4]]
+        res = retworkx.graph_diverse_paths(self.graph, paths, 3, weight_fn=float)
+        self.assertEqual([0, 2, 3], res)
+
+    def test_diverse_paths_node_filter(self):
+        res = retworkx.graph_diverse_paths(
+            self.graph, self.paths, 2, weight_fn=float, node_filter=lambda node: node != "f"
+        )
+        self.assertEqual([0, 1], res)
+
+    def test_diverse_paths_max_weight(self):
+        res = retworkx.graph_diverse_paths(self.graph, self.paths, 2, weight_fn=float, max_weight=3)
+        self.assertEqual([0], res)
+
+    def test_diverse_paths_not_a_path(self):
+        with self.assertRaises(ValueError):
+            retworkx.graph_diverse_paths(self.graph, [[0, 4]], 1)
+
+    def test_universal(self):
+        res = retworkx.rank_paths(self.graph, self.paths, weight_fn=float, overlap_penalty=2)
+        self.assertEqual([0, 2, 1], [position for position, _ in res])
+        res = retworkx.diverse_paths(self.graph, self.paths, 2, weight_fn=float)
+        self.assertEqual([0, 2], res)
@@ -0,0 +1,161 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import retworkx
+
+
+def count_faces(embedding):
+    # Every half edge (u, v) is followed on its face by the half edge from v
+    # to the neighbor before u in the clockwise order around v
+    seen = set()
+    faces = 0
+    for node, neighbors in embedding.items():
+        for neighbor in neighbors:
+            if (node, neighbor) in seen:
+                continue
+            faces += 1
+            u, v = node, neighbor
+            while (u, v) not in seen:
+                seen.add((u, v))
+                around = embedding[v]
+                u, v = v, around[around.index(u) - 1]
+    return faces
+
+
+def k33():
+    graph = retworkx.PyGraph()
+    graph.add_nodes_from(range(6))
+    graph.add_edges_from_no_data([(i, j) for i in range(3) for j in range(3, 6)])
+    return graph
+
+
+class TestPlanar(unittest.TestCase):
+    def assertEmbedding(self, graph, result):
+        self.assertTrue(result.is_planar)
+        self.assertIsNone(result.kuratowski_edges)
+        self.assertIsNone(result.kuratowski_type)
+        embedding = result.embedding
+        self.assertEqual(set(graph.node_indices()), set(embedding))
+        for node, neighbors in embedding.items():
+            self.assertEqual(set(graph.neighbors(node)) - {node}, set(neighbors))
+            self.assertEqual(len(set(neighbors)), len(neighbors))
+        # Euler's formula for a connected planar graph
+        num_edges = sum(len(neighbors) for neighbors in embedding.values()) // 2
+        self.assertEqual(num_edges - len(graph) + 2, count_faces(embedding))
+
+    def assertKuratowski(self, graph, result, kind):
+        self.assertFalse(result.is_planar)
+        self.assertIsNone(result.embedding)
+        self.assertEqual(kind, result.kuratowski_type)
+        edges = result.kuratowski_edges
+        for source, target in edges:
+            self.assertTrue(graph.has_edge(source, target))
+        subgraph = retworkx.PyGraph()
+        subgraph.add_nodes_from(range(max(graph.node_indices()) + 1))
+        subgraph.add_edges_from_no_data(edges)
+        self.assertFalse(retworkx.graph_is_planar(subgraph))
+        # The subgraph is minimal
+        for edge in edges:
+            subgraph.remove_edge(*edge)
+            self.assertTrue(retworkx.graph_is_planar(subgraph))
+            subgraph.add_edge(edge[0], edge[1], None)
+
+    def test_empty(self):
+        graph = retworkx.PyGraph()
+        self.assertTrue(retworkx.graph_is_planar(graph))
+        result = retworkx.graph_check_planarity(graph)
+        self.assertTrue(result)
+        self.assertEqual({}, result.embedding)
+
+    def test_k4(self):
+        graph = retworkx.generators.mesh_graph(4)
+        self.assertTrue(retworkx.graph_is_planar(graph))
+        self.assertEmbedding(graph, retworkx.graph_check_planarity(graph))
+
+    def test_grid(self):
+        graph = retworkx.generators.grid_graph(10, 10)
+        self.assertTrue(retworkx.graph_is_planar(graph))
+        self.assertEmbedding(graph, retworkx.graph_check_planarity(graph))
+
+    def test_wheel(self):
+        graph = retworkx.generators.star_graph(8)
+        graph.add_edges_from_no_data([(i, i % 7 + 1) for i in range(1, 8)])
+        self.assertEmbedding(graph, retworkx.graph_check_planarity(graph))
+
+    def test_tree(self):
+        graph = retworkx.generators.binomial_tree_graph(6)
+        self.assertEmbedding(graph, retworkx.graph_check_planarity(graph))
+
+    def test_k5(self):
+        graph = retworkx.generators.mesh_graph(5)
+        self.assertFalse(retworkx.graph_is_planar(graph))
+        result = retworkx.graph_check_planarity(graph)
+        self.assertFalse(result)
+        self.assertKuratowski(graph, result, "K5")
+        self.assertEqual(10, len(result.kuratowski_edges))
+
+    def test_k33(self):
+        graph = k33()
+        self.assertFalse(retworkx.graph_is_planar(graph))
+        result = retworkx.graph_check_planarity(graph)
+        self.assertKuratowski(graph, result, "K3,3")
+        self.assertEqual(9, len(result.kuratowski_edges))
+
+    def test_petersen(self):
+        graph = retworkx.generators.generalized_petersen_graph(5, 2)
+        result = retworkx.graph_check_planarity(graph)
+        self.assertKuratowski(graph, result, "K3,3")
+
+    def test_k6(self):
+        graph = retworkx.generators.mesh_graph(6)
+        result = retworkx.graph_check_planarity(graph)
+        self.assertFalse(result)
+        self.assertIn(result.kuratowski_type, ["K5", "K3,3"])
+        self.assertKuratowski(graph, result, result.kuratowski_type)
+
+    def test_non_planar_component(self):
+        graph = retworkx.generators.grid_graph(5, 5)
+        offset = len(graph)
+        graph.add_nodes_from(range(5))
+        graph.add_edges_from_no_data(
+            [(offset + i, offset + j) for i in range(5) for j in range(i + 1, 5)]
+        )
+        result = retworkx.graph_check_planarity(graph)
+        self.assertKuratowski(graph, result, "K5")
+        for edge in result.kuratowski_edges:
+            self.assertTrue(all(node >= offset for node in edge))
+
+    def test_self_loops_and_parallel_edges(self):
+        graph = retworkx.generators.cycle_graph(4)
+        graph.add_edges_from_no_data([(0, 0), (0, 1), (1, 0), (2, 2)])
+        result = retworkx.graph_check_planarity(graph)
+        self.assertEmbedding(graph, result)
+        self.assertEqual(2, len(result.embedding[0]))
+
+    def test_removed_nodes(self):
+        graph = retworkx.generators.mesh_graph(6)
+        graph.remove_node(2)
+        result = retworkx.graph_check_planarity(graph)
+        self.assertKuratowski(graph, result, "K5")
+        graph.remove_node(4)
+        self.assertEmbedding(graph, retworkx.graph_check_planarity(graph))
+
+    def test_universal(self):
+        graph = k33()
+        self.assertFalse(retworkx.is_planar(graph))
+        self.assertEqual("K3,3", retworkx.check_planarity(graph).kuratowski_type)
+        with self.assertRaises(TypeError):
+            retworkx.is_planar(None)
+        with self.assertRaises(TypeError):
+            retworkx.check_planarity(None)
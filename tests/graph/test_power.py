@@ -0,0 +1,58 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+
+import unittest
+
+import retworkx
+
+
+class TestPower(unittest.TestCase):
+    def test_path_square(self):
+        graph = retworkx.generators.path_graph(5)
+        power_graph = retworkx.graph_power(graph, 2)
+        expected = [(0, 1), (0, 2), (1, 2), (1, 3), (2, 3), (2, 4), (3, 4)]
+        self.assertEqual(expected, sorted(power_graph.edge_list()))
+
+    def test_cycle_cube(self):
+        graph = retworkx.generators.cycle_graph(8)
+        power_graph = retworkx.power(graph, 3)
+        expected = {(i, j) for i in range(8) for j in range(i + 1, 8) if min(j - i, 8 - j + i) <= 3}
+        self.assertEqual(expected, set(power_graph.edge_list()))
+
+    def test_first_power_removes_parallel_edges_and_self_loops(self):
+        graph = retworkx.PyGraph()
+        graph.extend_from_edge_list([(0, 1), (1, 0), (1, 1), (1, 2)])
+        power_graph = retworkx.graph_power(graph, 1)
+        self.assertEqual([(0, 1), (1, 2)], sorted(power_graph.edge_list()))
+
+    def test_large_power_is_complete_per_component(self):
+        graph = retworkx.PyGraph()
+        graph.extend_from_edge_list([(0, 1), (1, 2), (3, 4)])
+        power_graph = retworkx.graph_power(graph, 10)
+        self.assertEqual([(0, 1), (0, 2), (1, 2), (3, 4)], sorted(power_graph.edge_list()))
+
+    def test_keeps_nodes(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(["a", "b", "c", "d"])
+        graph.add_edges_from([(0, 1, "x"), (1, 3, "y")])
+        graph.remove_node(2)
+        power_graph = retworkx.graph_power(graph, 2)
+        self.assertEqual(graph.node_indexes(), power_graph.node_indexes())
+        self.assertEqual(["a", "b", "d"], power_graph.nodes())
+        self.assertEqual([None, None, None], power_graph.edges())
+        self.assertEqual(graph.multigraph, power_graph.multigraph)
+
+    def test_invalid_k(self):
+        graph = retworkx.generators.path_graph(3)
+        with self.assertRaises(ValueError):
+            retworkx.graph_power(graph, 0)
@@ -0,0 +1,135 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import numpy as np
+
+import retworkx
+
+
+class TestGraphRandomWalks(unittest.TestCase):
+    def test_walks_follow_edges(self):
+        graph = retworkx.generators.grid_graph(4, 4)
+        walks = retworkx.graph_random_walks(graph, walk_length=10, num_walks=3, seed=42)
+        self.assertIsInstance(walks, np.ndarray)
+        self.assertEqual(np.int64, walks.dtype)
+        self.assertEqual((16 * 3 * 10,), walks.shape)
+        walks = walks.reshape(-1, 10)
+        # Every round has a walk from every node, in order
+        self.assertEqual(list(range(16)) * 3, walks[:, 0].tolist())
+        for walk in walks.tolist():
+            for source, target in zip(walk, walk[1:]):
+                self.assertTrue(graph.has_edge(source, target))
+
+    def test_start_nodes(self):
+        graph = retworkx.generators.cycle_graph(6)
+        walks = retworkx.graph_random_walks(
+            graph, start_nodes=[4, 1], walk_length=5, num_walks=2, seed=42
+        ).reshape(-1, 5)
+        self.assertEqual([4, 1, 4, 1], walks[:, 0].tolist())
+
+    def test_seed(self):
+        graph = retworkx.generators.grid_graph(10, 10)
+        first = retworkx.graph_random_walks(graph, walk_length=20, seed=1234)
+        second = retworkx.graph_random_walks(graph, walk_length=20, seed=1234)
+        self.assertTrue(np.array_equal(first, second))
+
+    def test_parallel_same_walks(self):
+        graph = retworkx.generators.grid_graph(10, 10)
+        for p, q in [(1.0, 1.0), (0.5, 2.0)]:
+            serial = retworkx.graph_random_walks(
+                graph, walk_length=20, p=p, q=q, seed=42, parallel_threshold=100000
+            )
+            parallel = retworkx.graph_random_walks(
+                graph, walk_length=20, p=p, q=q, seed=42, parallel_threshold=1
+            )
+            self.assertTrue(np.array_equal(serial, parallel))
+
+    def test_isolated_node_padding(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(range(3))
+        graph.add_edge(0, 1, None)
+        walks = retworkx.graph_random_walks(graph, walk_length=4, num_walks=1, seed=42)
+        self.assertEqual([0, 1, 0, 1, 1, 0, 1, 0, 2, -1, -1, -1], walks.tolist())
+
+    def test_weight_fn(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(range(4))
+        graph.add_edges_from([(0, 1, 1.0), (0, 2, 0.0), (2, 3, 1.0)])
+        walks = retworkx.graph_random_walks(
+            graph, start_nodes=[0], walk_length=10, num_walks=20, weight_fn=float, seed=42
+        )
+        self.assertEqual({0, 1}, set(walks.tolist()))
+
+    def test_weights_bias_walks(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(range(3))
+        graph.add_edges_from([(0, 1, 9.0), (0, 2, 1.0)])
+        walks = retworkx.graph_random_walks(
+            graph, start_nodes=[0], walk_length=2, num_walks=2000, weight_fn=float, seed=42
+        ).reshape(-1, 2)
+        self.assertAlmostEqual(0.9, np.mean(walks[:, 1] == 1), delta=0.05)
+
+    def test_return_parameter(self):
+        graph = retworkx.generators.path_graph(3)
+        # From 1 the walk goes back to 0 with a bias of 1 / p and to 2 with
+        # a bias of 1 / q, as 2 isn't a neighbor of 0
+        walks = retworkx.graph_random_walks(
+            graph, start_nodes=[0], walk_length=3, num_walks=2000, p=0.5, q=2.0, seed=42
+        ).reshape(-1, 3)
+        self.assertTrue(np.all(walks[:, 1] == 1))
+        self.assertAlmostEqual(0.8, np.mean(walks[:, 2] == 0), delta=0.05)
+
+    def test_in_out_parameter(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(range(4))
+        graph.add_edges_from_no_data([(0, 1), (0, 2), (1, 2), (1, 3)])
+        # From 1 the walk goes back to 0 with a bias of 1 / p, to 2, a
+        # neighbor of 0, with a bias of 1 and to 3 with a bias of 1 / q
+        walks = retworkx.graph_random_walks(
+            graph, start_nodes=[0], walk_length=3, num_walks=4000, p=4.0, q=0.25, seed=42
+        ).reshape(-1, 3)
+        walks = walks[walks[:, 1] == 1]
+        self.assertAlmostEqual(0.05, np.mean(walks[:, 2] == 0), delta=0.03)
+        self.assertAlmostEqual(0.19, np.mean(walks[:, 2] == 2), delta=0.05)
+        self.assertAlmostEqual(0.76, np.mean(walks[:, 2] == 3), delta=0.05)
+
+    def test_empty(self):
+        graph = retworkx.generators.path_graph(3)
+        walks = retworkx.graph_random_walks(graph, walk_length=0)
+        self.assertEqual((0,), walks.shape)
+        walks = retworkx.graph_random_walks(graph, num_walks=0)
+        self.assertEqual((0,), walks.shape)
+        walks = retworkx.graph_random_walks(retworkx.PyGraph())
+        self.assertEqual((0,), walks.shape)
+
+    def test_invalid_parameters(self):
+        graph = retworkx.generators.path_graph(3)
+        with self.assertRaises(ValueError):
+            retworkx.graph_random_walks(graph, p=0.0)
+        with self.assertRaises(ValueError):
+            retworkx.graph_random_walks(graph, q=-1.0)
+        with self.assertRaises(retworkx.InvalidNode):
+            retworkx.graph_random_walks(graph, start_nodes=[3])
+
+    def test_negative_weight(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(range(2))
+        graph.add_edge(0, 1, -1.0)
+        with self.assertRaises(ValueError):
+            retworkx.graph_random_walks(graph, weight_fn=float)
+
+    def test_universal(self):
+        graph = retworkx.generators.path_graph(2)
+        walks = retworkx.random_walks(graph, walk_length=3, num_walks=1, seed=42)
+        self.assertEqual([0, 1, 0, 1, 0, 1], walks.tolist())
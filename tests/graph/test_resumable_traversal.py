@@ -0,0 +1,63 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import itertools
+import unittest
+
+import retworkx
+
+
+class TestResumableTraversal(unittest.TestCase):
+    def setUp(self):
+        self.graph = retworkx.PyGraph()
+        self.graph.extend_from_edge_list([(0, 1), (1, 2), (0, 3), (3, 4)])
+        self.graph.add_nodes_from([None, None])
+        self.graph.add_edge(5, 6, None)
+
+    def test_dfs_traversal(self):
+        traversal = retworkx.graph_dfs_traversal(self.graph, 3)
+        self.assertIsInstance(traversal, retworkx.DFSTraversal)
+        self.assertEqual([3, 0, 1, 2, 4], list(traversal))
+
+    def test_dfs_traversal_all_components(self):
+        self.assertEqual([0, 1, 2, 3, 4, 5, 6], list(retworkx.dfs_traversal(self.graph)))
+
+    def test_bfs_traversal(self):
+        traversal = retworkx.graph_bfs_traversal(self.graph, 3)
+        self.assertIsInstance(traversal, retworkx.BFSTraversal)
+        self.assertEqual([3, 4, 0, 1, 2], list(traversal))
+
+    def test_bfs_traversal_all_components(self):
+        self.assertEqual([0, 3, 1, 4, 2, 5, 6], list(retworkx.bfs_traversal(self.graph)))
+
+    def test_traversal_with_node_removal(self):
+        self.graph.remove_node(0)
+        self.assertEqual([1, 2, 3, 4, 5, 6], list(retworkx.dfs_traversal(self.graph)))
+        self.assertEqual([1, 2, 3, 4, 5, 6], list(retworkx.bfs_traversal(self.graph)))
+        with self.assertRaises(retworkx.InvalidNode):
+            retworkx.graph_dfs_traversal(self.graph, 0)
+
+    def test_interleaved_traversals(self):
+        first = retworkx.dfs_traversal(self.graph, source=0)
+        second = retworkx.bfs_traversal(retworkx.generators.path_graph(3), source=2)
+        self.assertEqual([(2, 0), (1, 1), (0, 2)], list(zip(second, first)))
+        self.assertEqual([3, 4], list(first))
+
+    def test_saved_states(self):
+        traversal = retworkx.dfs_traversal(self.graph, source=0)
+        saved = []
+        for _ in range(5):
+            saved.append(traversal.copy())
+            next(traversal)
+        for position, state in enumerate(saved):
+            self.assertEqual(5 - position, len(list(state)))
+        self.assertEqual([], list(itertools.islice(traversal, 1)))
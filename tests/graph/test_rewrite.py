@@ -0,0 +1,138 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+
+import unittest
+
+import retworkx
+
+
+class TestRewrite(unittest.TestCase):
+    def setUp(self):
+        # contract a path through a "tmp" node into a single edge
+        self.pattern = retworkx.PyGraph()
+        self.pattern.add_nodes_from([None, "tmp", None])
+        self.pattern.add_edges_from_no_data([(0, 1), (1, 2)])
+        self.replacement = retworkx.PyGraph()
+        self.replacement.add_nodes_from([None, None])
+        self.replacement.add_edge(0, 1, "contracted")
+        self.interface = {0: 0, 2: 1}
+
+    @staticmethod
+    def node_matcher(node, pattern_node):
+        return pattern_node is None or node == pattern_node
+
+    def test_rewrite_exhaustive(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(["a", "tmp", "b", "tmp", "c"])
+        graph.add_edges_from_no_data([(0, 1), (1, 2), (2, 3), (3, 4)])
+        res = retworkx.graph_rewrite(
+            graph,
+            self.pattern,
+            self.replacement,
+            self.interface,
+            node_matcher=self.node_matcher,
+        )
+        self.assertEqual(2, len(res))
+        self.assertEqual(["a", "b", "c"], graph.nodes())
+        self.assertEqual(
+            [(0, 2), (2, 4)], sorted(tuple(sorted(edge)) for edge in graph.edge_list())
+        )
+        self.assertEqual(["contracted", "contracted"], graph.edges())
+
+    def test_rewrite_max_applications(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(["a", "tmp", "b", "tmp", "c"])
+        graph.add_edges_from_no_data([(0, 1), (1, 2), (2, 3), (3, 4)])
+        res = retworkx.graph_rewrite(
+            graph,
+            self.pattern,
+            self.replacement,
+            self.interface,
+            node_matcher=self.node_matcher,
+            max_applications=1,
+        )
+        self.assertEqual(1, len(res))
+        self.assertEqual(1, graph.nodes().count("tmp"))
+
+    def test_rewrite_dangling_condition(self):
+        # the "tmp" node has a third neighbor so it can't be removed
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(["a", "tmp", "b", "c"])
+        graph.add_edges_from_no_data([(0, 1), (1, 2), (1, 3)])
+        res = retworkx.graph_rewrite(
+            graph,
+            self.pattern,
+            self.replacement,
+            self.interface,
+            node_matcher=self.node_matcher,
+        )
+        self.assertEqual([], res)
+        self.assertEqual(4, len(graph))
+
+    def test_rewrite_not_multigraph(self):
+        # the contracted edge replaces the payload of the existing edge
+        graph = retworkx.PyGraph(multigraph=False)
+        graph.add_nodes_from(["a", "tmp", "b"])
+        graph.add_edges_from([(0, 1, None), (1, 2, None), (0, 2, "existing")])
+        res = retworkx.graph_rewrite(
+            graph,
+            self.pattern,
+            self.replacement,
+            self.interface,
+            node_matcher=self.node_matcher,
+        )
+        self.assertEqual(1, len(res))
+        self.assertEqual({0: 0, 1: 2}, dict(res[0]))
+        self.assertEqual(["contracted"], graph.edges())
+
+    def test_rewrite_edge_matcher(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(["a", "tmp", "b"])
+        graph.add_edges_from([(0, 1, "keep"), (1, 2, None)])
+        res = retworkx.graph_rewrite(
+            graph,
+            self.pattern,
+            self.replacement,
+            self.interface,
+            node_matcher=self.node_matcher,
+            edge_matcher=lambda edge, pattern_edge: edge != "keep",
+        )
+        self.assertEqual([], res)
+        self.assertEqual(3, len(graph))
+
+    def test_rewrite_invalid_interface(self):
+        graph = retworkx.PyGraph()
+        with self.assertRaises(ValueError):
+            retworkx.graph_rewrite(graph, self.pattern, self.replacement, {5: 0})
+        with self.assertRaises(ValueError):
+            retworkx.graph_rewrite(graph, self.pattern, self.replacement, {0: 5})
+        with self.assertRaises(ValueError):
+            retworkx.graph_rewrite(graph, self.pattern, self.replacement, {0: 1, 2: 1})
+
+    def test_rewrite_universal(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(["a", "tmp", "b"])
+        graph.add_edges_from_no_data([(0, 1), (1, 2)])
+        res = retworkx.rewrite(
+            graph,
+            self.pattern,
+            self.replacement,
+            self.interface,
+            node_matcher=self.node_matcher,
+        )
+        self.assertEqual(1, len(res))
+        self.assertEqual(["a", "b"], graph.nodes())
+
+    def test_rewrite_invalid_type(self):
+        with self.assertRaises(TypeError):
+            retworkx.rewrite(None, self.pattern, self.replacement, self.interface)
@@ -0,0 +1,173 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import math
+import unittest
+
+import retworkx
+
+
+class TestGraphFiedlerVector(unittest.TestCase):
+    def test_path(self):
+        graph = retworkx.generators.path_graph(5)
+        res = retworkx.graph_fiedler_vector(graph)
+        self.assertIsInstance(res, retworkx.CentralityMapping)
+        self.assertEqual([0, 1, 2, 3, 4], sorted(res))
+        self.assertAlmostEqual(1.0, sum(x * x for x in res.values()))
+        self.assertAlmostEqual(0.0, sum(res.values()))
+        self.assertAlmostEqual(0.0, res[2])
+        self.assertAlmostEqual(-res[0], res[4])
+        self.assertAlmostEqual(-res[1], res[3])
+        # The entries are monotonic along the path
+        values = [res[node] for node in range(5)]
+        self.assertTrue(values == sorted(values) or values == sorted(values, reverse=True))
+        self.assertAlmostEqual(math.cos(math.pi / 10) * math.sqrt(0.4), abs(res[0]))
+
+    def test_removed_node(self):
+        graph = retworkx.generators.path_graph(4)
+        graph.remove_node(0)
+        res = retworkx.graph_fiedler_vector(graph)
+        self.assertEqual([1, 2, 3], sorted(res))
+        self.assertAlmostEqual(0.0, res[2])
+        self.assertAlmostEqual(-res[1], res[3])
+
+    def test_largest_entry_positive(self):
+        graph = retworkx.generators.mesh_graph(4)
+        graph.add_node(None)
+        graph.add_edge(3, 4, None)
+        res = retworkx.graph_fiedler_vector(graph)
+        largest = max(res.values(), key=abs)
+        self.assertGreater(largest, 0.0)
+
+    def test_explain(self):
+        graph = retworkx.generators.cycle_graph(6)
+        res, info = retworkx.graph_fiedler_vector(graph, explain=True)
+        self.assertEqual(6, len(res))
+        self.assertIsInstance(info, retworkx.AlgorithmInfo)
+        self.assertEqual("fiedler_vector", info.algorithm)
+        self.assertTrue(info.converged)
+
+    def test_single_node(self):
+        graph = retworkx.PyGraph()
+        graph.add_node(None)
+        with self.assertRaises(ValueError):
+            retworkx.graph_fiedler_vector(graph)
+
+    def test_disconnected(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(range(4))
+        graph.add_edges_from_no_data([(0, 1), (2, 3)])
+        with self.assertRaises(ValueError):
+            retworkx.graph_fiedler_vector(graph)
+
+    def test_failed_to_converge(self):
+        graph = retworkx.generators.grid_graph(20, 20)
+        with self.assertRaises(retworkx.FailedToConverge):
+            retworkx.graph_fiedler_vector(graph, max_iter=2)
+
+    def test_universal(self):
+        graph = retworkx.generators.path_graph(3)
+        res = retworkx.fiedler_vector(graph)
+        self.assertAlmostEqual(0.0, res[1])
+
+
+class TestGraphAlgebraicConnectivity(unittest.TestCase):
+    def test_path(self):
+        graph = retworkx.generators.path_graph(3)
+        self.assertAlmostEqual(1.0, retworkx.graph_algebraic_connectivity(graph))
+
+    def test_cycle(self):
+        graph = retworkx.generators.cycle_graph(4)
+        self.assertAlmostEqual(2.0, retworkx.graph_algebraic_connectivity(graph))
+
+    def test_complete(self):
+        for n in range(2, 8):
+            graph = retworkx.generators.mesh_graph(n)
+            self.assertAlmostEqual(float(n), retworkx.graph_algebraic_connectivity(graph))
+
+    def test_weight_fn(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(range(4))
+        graph.add_edges_from([(0, 1, 3.0), (1, 2, 3.0), (2, 3, 3.0), (3, 0, 3.0)])
+        res = retworkx.graph_algebraic_connectivity(graph, weight_fn=float)
+        self.assertAlmostEqual(6.0, res)
+
+    def test_default_weight(self):
+        graph = retworkx.generators.cycle_graph(4)
+        res = retworkx.graph_algebraic_connectivity(graph, default_weight=0.5)
+        self.assertAlmostEqual(1.0, res)
+
+    def test_parallel_edges_and_self_loops(self):
+        graph = retworkx.generators.path_graph(3)
+        graph.add_edge(0, 1, None)
+        graph.add_edge(1, 2, None)
+        graph.add_edge(1, 1, None)
+        self.assertAlmostEqual(2.0, retworkx.graph_algebraic_connectivity(graph))
+
+    def test_disconnected(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(range(4))
+        graph.add_edges_from_no_data([(0, 1), (2, 3)])
+        self.assertEqual(0.0, retworkx.graph_algebraic_connectivity(graph))
+
+    def test_disconnected_explain(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(range(3))
+        res, info = retworkx.graph_algebraic_connectivity(graph, explain=True)
+        self.assertEqual(0.0, res)
+        self.assertEqual(1, len(info.fallbacks))
+
+    def test_single_node(self):
+        graph = retworkx.PyGraph()
+        graph.add_node(None)
+        with self.assertRaises(ValueError):
+            retworkx.graph_algebraic_connectivity(graph)
+
+    def test_universal(self):
+        graph = retworkx.generators.cycle_graph(4)
+        self.assertAlmostEqual(2.0, retworkx.algebraic_connectivity(graph))
+
+
+class TestGraphSpectralBisection(unittest.TestCase):
+    def test_barbell(self):
+        graph = retworkx.generators.barbell_graph(4)
+        res = retworkx.graph_spectral_bisection(graph)
+        self.assertIsInstance(res, retworkx.GraphPartitioning)
+        self.assertEqual([[0, 1, 2, 3], [4, 5, 6, 7]], sorted(res.parts))
+        self.assertEqual(1.0, res.cut_weight)
+
+    def test_weight_fn(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(range(6))
+        graph.add_edges_from(
+            [(0, 1, 5.0), (1, 2, 5.0), (2, 3, 1.0), (3, 4, 5.0), (4, 5, 5.0), (5, 0, 1.0)]
+        )
+        res = retworkx.graph_spectral_bisection(graph, weight_fn=float)
+        self.assertEqual([[0, 1, 2], [3, 4, 5]], sorted(res.parts))
+        self.assertEqual(2.0, res.cut_weight)
+
+    def test_explain(self):
+        graph = retworkx.generators.barbell_graph(3)
+        res, info = retworkx.graph_spectral_bisection(graph, explain=True)
+        self.assertEqual(1.0, res.cut_weight)
+        self.assertEqual("spectral_bisection", info.algorithm)
+
+    def test_disconnected(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(range(2))
+        with self.assertRaises(ValueError):
+            retworkx.graph_spectral_bisection(graph)
+
+    def test_universal(self):
+        graph = retworkx.generators.barbell_graph(4)
+        res = retworkx.spectral_bisection(graph)
+        self.assertEqual([[0, 1, 2, 3], [4, 5, 6, 7]], sorted(res.parts))
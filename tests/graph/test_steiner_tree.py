@@ -77,6 +77,11 @@ class TestSteinerTree(unittest.TestCase):
                     f"closure output:\n{pprint.pformat(edges)}"
                 )
 
+    def test_metric_closure_edge_order(self):
+        closure_graph = retworkx.metric_closure(self.graph, weight_fn=float)
+        expected = [(u, v) for u in range(1, 8) for v in range(u + 1, 8)]
+        self.assertEqual(expected, list(closure_graph.edge_list()))
+
     def test_not_connected_metric_closure(self):
         self.graph.add_node(None)
         with self.assertRaises(ValueError):
@@ -0,0 +1,131 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+import unittest
+
+import retworkx
+
+
+class TestGraphSubgraphSampling(unittest.TestCase):
+    def assert_induced(self, graph, subgraph, node_map):
+        for node, new_node in node_map.items():
+            self.assertEqual(graph[node], subgraph[new_node])
+        expected = sorted(
+            (node_map[source], node_map[target])
+            for source, target in graph.edge_list()
+            if source in node_map and target in node_map
+        )
+        self.assertEqual(expected, sorted(subgraph.edge_list()))
+
+    def test_random_node_sample(self):
+        graph = retworkx.generators.grid_graph(5, 5)
+        subgraph, node_map = retworkx.graph_random_node_sample(graph, 10, seed=42)
+        self.assertIsInstance(subgraph, retworkx.PyGraph)
+        self.assertEqual(10, len(subgraph))
+        self.assertEqual(10, len(node_map))
+        self.assertEqual(sorted(node_map.values()), list(range(10)))
+        self.assert_induced(graph, subgraph, node_map)
+
+    def test_random_node_sample_seed(self):
+        graph = retworkx.generators.grid_graph(5, 5)
+        _, node_map = retworkx.graph_random_node_sample(graph, 10, seed=42)
+        _, other_node_map = retworkx.graph_random_node_sample(graph, 10, seed=42)
+        self.assertEqual(dict(node_map), dict(other_node_map))
+
+    def test_random_node_sample_all(self):
+        graph = retworkx.generators.cycle_graph(5)
+        graph.remove_node(2)
+        subgraph, node_map = retworkx.graph_random_node_sample(graph, 4, seed=42)
+        self.assertEqual({0: 0, 1: 1, 3: 2, 4: 3}, dict(node_map))
+        self.assertEqual(3, subgraph.num_edges())
+
+    def test_random_node_sample_too_many(self):
+        graph = retworkx.generators.path_graph(3)
+        with self.assertRaises(ValueError):
+            retworkx.graph_random_node_sample(graph, 4)
+
+    def test_random_edge_sample(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(range(6))
+        graph.add_edges_from([(0, 1, "a"), (1, 2, "b"), (2, 3, "c"), (4, 5, "d"), (0, 1, "e")])
+        subgraph, node_map = retworkx.graph_random_edge_sample(graph, 3, seed=42)
+        self.assertEqual(3, subgraph.num_edges())
+        for node, new_node in node_map.items():
+            self.assertEqual(graph[node], subgraph[new_node])
+        original = {new_node: node for node, new_node in node_map.items()}
+        for source, target, weight in subgraph.weighted_edge_list():
+            self.assertIn(weight, graph.get_all_edge_data(original[source], original[target]))
+        # Every node is an endpoint of a sampled edge
+        self.assertEqual(
+            set(subgraph.node_indices()),
+            {node for edge in subgraph.edge_list() for node in edge},
+        )
+
+    def test_random_edge_sample_all(self):
+        graph = retworkx.generators.star_graph(5)
+        subgraph, node_map = retworkx.graph_random_edge_sample(graph, 4, seed=42)
+        self.assertEqual(5, len(subgraph))
+        self.assert_induced(graph, subgraph, node_map)
+
+    def test_random_edge_sample_too_many(self):
+        graph = retworkx.generators.path_graph(3)
+        with self.assertRaises(ValueError):
+            retworkx.graph_random_edge_sample(graph, 3)
+
+    def test_forest_fire_sample(self):
+        graph = retworkx.generators.grid_graph(6, 6)
+        subgraph, node_map = retworkx.graph_forest_fire_sample(graph, 12, seed=42)
+        self.assertEqual(12, len(subgraph))
+        self.assertEqual(sorted(node_map.values()), list(range(12)))
+        self.assert_induced(graph, subgraph, node_map)
+        _, other_node_map = retworkx.graph_forest_fire_sample(graph, 12, seed=42)
+        self.assertEqual(dict(node_map), dict(other_node_map))
+
+    def test_forest_fire_sample_burns_connected_nodes(self):
+        # With a high burn probability a single fire burns a component
+        graph = retworkx.PyGraph()
+        graph.extend_from_edge_list([(0, 1), (1, 2), (2, 3), (4, 5), (5, 6), (6, 7)])
+        _, node_map = retworkx.graph_forest_fire_sample(graph, 4, burn_probability=0.99, seed=42)
+        self.assertIn(set(node_map), [{0, 1, 2, 3}, {4, 5, 6, 7}])
+
+    def test_forest_fire_sample_restarts(self):
+        # A fire can't spread in a graph without edges, every node is
+        # burnt by a new fire
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(range(5))
+        subgraph, node_map = retworkx.graph_forest_fire_sample(graph, 5, seed=42)
+        self.assertEqual(5, len(subgraph))
+        self.assertEqual(set(range(5)), set(node_map))
+
+    def test_forest_fire_sample_invalid_burn_probability(self):
+        graph = retworkx.generators.path_graph(3)
+        for burn_probability in (-0.1, 1.0):
+            with self.assertRaises(ValueError):
+                retworkx.graph_forest_fire_sample(graph, 2, burn_probability=burn_probability)
+
+    def test_forest_fire_sample_too_many(self):
+        graph = retworkx.generators.path_graph(3)
+        with self.assertRaises(ValueError):
+            retworkx.graph_forest_fire_sample(graph, 4)
+
+    def test_universal_functions(self):
+        graph = retworkx.generators.grid_graph(4, 4)
+        for func in (
+            retworkx.random_node_sample,
+            retworkx.random_edge_sample,
+            retworkx.forest_fire_sample,
+        ):
+            subgraph, _ = func(graph, 5, seed=42)
+            self.assertIsInstance(subgraph, retworkx.PyGraph)
+
+    def test_invalid_type(self):
+        with self.assertRaises(TypeError):
+            retworkx.random_node_sample(None, 1)
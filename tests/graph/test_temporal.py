@@ -0,0 +1,128 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+import unittest
+
+import numpy as np
+
+import retworkx
+
+
+class TestGraphTemporal(unittest.TestCase):
+    def setUp(self):
+        # Every edge payload is the time of the edge
+        self.graph = retworkx.PyGraph()
+        self.graph.add_nodes_from(range(4))
+        self.graph.add_edges_from([(0, 1, 1.0), (1, 2, 3.0), (2, 3, 2.0), (0, 2, 5.0)])
+
+    def test_shortest_paths(self):
+        paths = retworkx.graph_temporal_shortest_paths(self.graph, 0, time_fn=float)
+        self.assertEqual({1: [0, 1], 2: [0, 1, 2]}, paths)
+
+    def test_shortest_paths_target(self):
+        paths = retworkx.graph_temporal_shortest_paths(self.graph, 0, target=2, time_fn=float)
+        self.assertEqual({2: [0, 1, 2]}, paths)
+
+    def test_earliest_arrival(self):
+        arrivals = retworkx.graph_temporal_earliest_arrival(self.graph, 0, time_fn=float)
+        self.assertEqual({1: 1.0, 2: 3.0}, arrivals)
+        # The edges can be traversed in both directions
+        arrivals = retworkx.graph_temporal_earliest_arrival(self.graph, 3, time_fn=float)
+        self.assertEqual({0: 5.0, 1: 3.0, 2: 2.0}, arrivals)
+
+    def test_reachability(self):
+        self.assertEqual([1, 2], retworkx.graph_temporal_reachability(self.graph, 0, time_fn=float))
+        self.assertEqual(
+            [0, 1, 2], retworkx.graph_temporal_reachability(self.graph, 3, time_fn=float)
+        )
+
+    def test_time_window(self):
+        arrivals = retworkx.graph_temporal_earliest_arrival(
+            self.graph, 0, time_fn=float, start_time=2.0
+        )
+        self.assertEqual({2: 5.0}, arrivals)
+        arrivals = retworkx.graph_temporal_earliest_arrival(
+            self.graph, 0, time_fn=float, end_time=2.0
+        )
+        self.assertEqual({1: 1.0}, arrivals)
+
+    def test_duration(self):
+        arrivals = retworkx.graph_temporal_earliest_arrival(
+            self.graph, 0, time_fn=float, default_duration=1.0
+        )
+        self.assertEqual({1: 2.0, 2: 4.0}, arrivals)
+        # The edge from 1 to 2 departs before the arrival at 1
+        paths = retworkx.graph_temporal_shortest_paths(
+            self.graph, 0, time_fn=float, default_duration=2.5
+        )
+        self.assertEqual({1: [0, 1], 2: [0, 2]}, paths)
+
+    def test_duration_fn(self):
+        arrivals = retworkx.graph_temporal_earliest_arrival(
+            self.graph, 0, time_fn=float, duration_fn=lambda time: time / 2
+        )
+        self.assertEqual({1: 1.5, 2: 4.5}, arrivals)
+
+    def test_same_time_chain(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(range(4))
+        graph.add_edges_from([(2, 3, 1.0), (1, 2, 1.0), (0, 1, 1.0)])
+        paths = retworkx.graph_temporal_shortest_paths(graph, 0, target=3, time_fn=float)
+        self.assertEqual({3: [0, 1, 2, 3]}, paths)
+
+    def test_edges_without_time(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(range(3))
+        graph.add_edges_from([(0, 1, None), (0, 2, 1.0)])
+        self.assertEqual([2], retworkx.graph_temporal_reachability(graph, 0, time_fn=lambda x: x))
+
+    def test_time_attr_payload(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(range(4))
+        graph.add_edges_from([(0, 1, {"time": 1}), (1, 2, {"time": 2}), (2, 3, {})])
+        arrivals = retworkx.graph_temporal_earliest_arrival(graph, 0, time_attr="time")
+        self.assertEqual({1: 1.0, 2: 2.0}, arrivals)
+
+    def test_time_attr_column(self):
+        self.graph.set_edge_attribute("time", np.array([1.0, 3.0, 2.0, 5.0]))
+        self.graph.set_edge_attribute("duration", np.array([0.0, 0.0, 0.0, 1.0]))
+        arrivals = retworkx.graph_temporal_earliest_arrival(
+            self.graph, 2, time_attr="time", duration_attr="duration"
+        )
+        self.assertEqual({0: 6.0, 1: 3.0, 3: 2.0}, arrivals)
+
+    def test_no_time(self):
+        with self.assertRaises(ValueError):
+            retworkx.graph_temporal_reachability(self.graph, 0)
+
+    def test_time_fn_and_time_attr(self):
+        with self.assertRaises(ValueError):
+            retworkx.graph_temporal_reachability(self.graph, 0, time_fn=float, time_attr="time")
+
+    def test_negative_duration(self):
+        with self.assertRaises(ValueError):
+            retworkx.graph_temporal_reachability(
+                self.graph, 0, time_fn=float, default_duration=-1.0
+            )
+
+    def test_invalid_source(self):
+        with self.assertRaises(retworkx.InvalidNode):
+            retworkx.graph_temporal_reachability(self.graph, 10, time_fn=float)
+
+    def test_universal_functions(self):
+        self.assertEqual(
+            {1: [0, 1], 2: [0, 1, 2]},
+            retworkx.temporal_shortest_paths(self.graph, 0, time_fn=float),
+        )
+        self.assertEqual(
+            {1: 1.0, 2: 3.0}, retworkx.temporal_earliest_arrival(self.graph, 0, time_fn=float)
+        )
+        self.assertEqual([1, 2], retworkx.temporal_reachability(self.graph, 0, time_fn=float))
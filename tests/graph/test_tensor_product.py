@@ -70,3 +70,9 @@ class TestTensorProduct(unittest.TestCase):
 
         graph_product, _ = retworkx.graph_tensor_product(graph_1, graph_2)
         self.assertEqual([("w_1", "w_2"), ("w_2", "w_1")], graph_product.edges())
+
+    def test_node_map_order_tensor(self):
+        graph_1 = retworkx.generators.path_graph(5)
+        graph_2 = retworkx.generators.cycle_graph(4)
+        graph_product, node_map = retworkx.graph_tensor_product(graph_1, graph_2)
+        self.assertEqual(list(graph_product.node_indices()), list(node_map.values()))
@@ -47,3 +47,69 @@ class TestTransitivity(unittest.TestCase):
         graph.add_nodes_from(list(range(3)))
         res = retworkx.transitivity(graph)
         self.assertEqual(res, 0.0)
+
+    def test_transitivity_self_loops_and_parallel_edges(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(list(range(3)))
+        graph.add_edges_from_no_data([(0, 1), (0, 2), (1, 2), (1, 2), (0, 0)])
+        res = retworkx.transitivity(graph)
+        self.assertEqual(res, 1.0)
+
+    def test_transitivity_complete_graph(self):
+        graph = retworkx.generators.mesh_graph(20)
+        res = retworkx.transitivity(graph)
+        self.assertEqual(res, 1.0)
+
+
+class TestTriangles(unittest.TestCase):
+    def test_triangles(self):
+        graph = retworkx.PyGraph()
+        graph.extend_from_edge_list([(0, 1), (1, 2), (2, 0), (1, 3), (2, 3), (3, 4)])
+        res = retworkx.triangles(graph)
+        self.assertEqual(res, {0: 1, 1: 2, 2: 2, 3: 1, 4: 0})
+
+    def test_triangles_complete_graph(self):
+        graph = retworkx.generators.mesh_graph(10)
+        res = retworkx.triangles(graph)
+        self.assertEqual(res, {node: 36 for node in range(10)})
+
+    def test_triangles_empty(self):
+        graph = retworkx.PyGraph()
+        self.assertEqual(retworkx.triangles(graph), {})
+
+    def test_triangles_removed_node(self):
+        graph = retworkx.generators.mesh_graph(4)
+        graph.remove_node(0)
+        res = retworkx.triangles(graph)
+        self.assertEqual(res, {1: 1, 2: 1, 3: 1})
+
+    def test_triangles_parallel_threshold(self):
+        graph = retworkx.generators.grid_graph(10, 10)
+        for row in range(9):
+            for col in range(9):
+                graph.add_edge(row * 10 + col, (row + 1) * 10 + col + 1, None)
+        serial = retworkx.triangles(graph, parallel_threshold=1000)
+        parallel = retworkx.triangles(graph, parallel_threshold=1)
+        self.assertEqual(serial, parallel)
+        self.assertEqual(sum(serial.values()), 3 * 2 * 81)
+
+
+class TestLocalClusteringCoefficient(unittest.TestCase):
+    def test_local_clustering_coefficient(self):
+        graph = retworkx.PyGraph()
+        graph.extend_from_edge_list([(0, 1), (1, 2), (2, 0), (1, 3), (2, 3), (3, 4)])
+        res = retworkx.local_clustering_coefficient(graph)
+        expected = {0: 1.0, 1: 2 / 3, 2: 2 / 3, 3: 1 / 3, 4: 0.0}
+        self.assertEqual(len(res), len(expected))
+        for node, coefficient in expected.items():
+            self.assertAlmostEqual(res[node], coefficient)
+
+    def test_local_clustering_coefficient_star(self):
+        graph = retworkx.generators.star_graph(5)
+        res = retworkx.local_clustering_coefficient(graph)
+        self.assertEqual(dict(res), {node: 0.0 for node in range(5)})
+
+    def test_local_clustering_coefficient_empty(self):
+        graph = retworkx.PyGraph()
+        res = retworkx.local_clustering_coefficient(graph)
+        self.assertEqual(len(res), 0)
@@ -0,0 +1,162 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import itertools
+import unittest
+
+import retworkx
+
+
+def tour_cost(graph, nodes, weight_fn=float):
+    lengths = retworkx.all_pairs_dijkstra_path_lengths(graph, weight_fn)
+    return sum(
+        lengths[source][target] if source != target else 0.0
+        for source, target in zip(nodes, nodes[1:] + nodes[:1])
+    )
+
+
+def optimal_cost(graph, weight_fn=float):
+    first, *rest = graph.node_indices()
+    return min(
+        tour_cost(graph, [first] + list(order), weight_fn)
+        for order in itertools.permutations(rest)
+    )
+
+
+class TestGreedyTSP(unittest.TestCase):
+    def setUp(self):
+        # A square with long diagonals
+        self.graph = retworkx.PyGraph()
+        self.graph.add_nodes_from(range(4))
+        self.graph.add_edges_from(
+            [(0, 1, 1), (1, 2, 1), (2, 3, 1), (3, 0, 1), (0, 2, 5), (1, 3, 5)]
+        )
+
+    def test_greedy_tsp(self):
+        tour = retworkx.greedy_tsp(self.graph, weight_fn=float)
+        self.assertEqual([0, 1, 2, 3], list(tour.nodes))
+        self.assertEqual(4, tour.cost)
+
+    def test_source(self):
+        tour = retworkx.greedy_tsp(self.graph, source=2, weight_fn=float)
+        self.assertEqual([2, 1, 0, 3], list(tour.nodes))
+        self.assertEqual(4, tour.cost)
+
+    def test_nearest_neighbor(self):
+        # The closest node is visited first even if it makes the tour longer,
+        # the tour goes from 2 to 3 through 0
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(range(4))
+        graph.add_edges_from(
+            [(0, 1, 1), (0, 2, 1.5), (0, 3, 3), (1, 2, 2), (1, 3, 3), (2, 3, 10)]
+        )
+        tour = retworkx.greedy_tsp(graph, weight_fn=float)
+        self.assertEqual([0, 1, 2, 3], list(tour.nodes))
+        self.assertEqual(10.5, tour.cost)
+        self.assertEqual(9.5, optimal_cost(graph))
+
+    def test_metric_closure(self):
+        graph = retworkx.generators.star_graph(4)
+        tour = retworkx.greedy_tsp(graph)
+        self.assertEqual([0, 1, 2, 3], list(tour.nodes))
+        self.assertEqual(6, tour.cost)
+
+    def test_removed_nodes(self):
+        self.graph.remove_node(1)
+        tour = retworkx.greedy_tsp(self.graph, weight_fn=float)
+        self.assertEqual([0, 3, 2], list(tour.nodes))
+        self.assertEqual(4, tour.cost)
+
+    def test_single_node(self):
+        graph = retworkx.PyGraph()
+        graph.add_node(None)
+        tour = retworkx.greedy_tsp(graph)
+        self.assertEqual([0], list(tour.nodes))
+        self.assertEqual(0, tour.cost)
+
+    def test_empty_graph(self):
+        tour = retworkx.greedy_tsp(retworkx.PyGraph())
+        self.assertEqual([], list(tour.nodes))
+        self.assertEqual(0, tour.cost)
+
+    def test_not_connected(self):
+        self.graph.add_node(None)
+        with self.assertRaises(ValueError):
+            retworkx.greedy_tsp(self.graph, weight_fn=float)
+
+    def test_invalid_source(self):
+        with self.assertRaises(retworkx.InvalidNode):
+            retworkx.greedy_tsp(self.graph, source=4)
+
+    def test_negative_weight(self):
+        with self.assertRaises(ValueError):
+            retworkx.greedy_tsp(self.graph, weight_fn=lambda _: -1.0)
+
+
+class TestChristofides(unittest.TestCase):
+    def test_christofides(self):
+        # A path with a shortcut between its ends
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(range(5))
+        graph.add_edges_from([(0, 1, 1), (1, 2, 1), (2, 3, 1), (3, 4, 1), (4, 0, 2)])
+        tour = retworkx.christofides(graph, weight_fn=float)
+        self.assertEqual(0, tour.nodes[0])
+        self.assertEqual(list(range(5)), sorted(tour.nodes))
+        self.assertEqual(6, tour.cost)
+
+    def test_metric_closure(self):
+        graph = retworkx.generators.star_graph(5)
+        tour = retworkx.christofides(graph)
+        self.assertEqual(list(range(5)), sorted(tour.nodes))
+        self.assertEqual(tour_cost(graph, list(tour.nodes), lambda _: 1.0), tour.cost)
+        self.assertEqual(8, tour.cost)
+
+    def test_approximation_ratio(self):
+        for seed in range(10):
+            graph = retworkx.undirected_gnp_random_graph(7, 0.6, seed=seed)
+            if not retworkx.is_connected(graph):
+                continue
+            for source, target in graph.edge_list():
+                graph.update_edge(source, target, 1 + (source * 7 + target * 3 + seed) % 5)
+            tour = retworkx.christofides(graph, weight_fn=float)
+            self.assertEqual(list(graph.node_indices()), sorted(tour.nodes))
+            self.assertAlmostEqual(tour_cost(graph, list(tour.nodes)), tour.cost)
+            self.assertLessEqual(tour.cost, 1.5 * optimal_cost(graph))
+
+    def test_complete_graph(self):
+        graph = retworkx.generators.mesh_graph(6)
+        tour = retworkx.christofides(graph)
+        self.assertEqual(list(range(6)), sorted(tour.nodes))
+        self.assertEqual(6, tour.cost)
+
+    def test_single_node(self):
+        graph = retworkx.PyGraph()
+        graph.add_node(None)
+        tour = retworkx.christofides(graph)
+        self.assertEqual([0], list(tour.nodes))
+        self.assertEqual(0, tour.cost)
+
+    def test_empty_graph(self):
+        tour = retworkx.christofides(retworkx.PyGraph())
+        self.assertEqual([], list(tour.nodes))
+        self.assertEqual(0, tour.cost)
+
+    def test_not_connected(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(range(2))
+        with self.assertRaises(ValueError):
+            retworkx.christofides(graph)
+
+    def test_negative_weight(self):
+        graph = retworkx.generators.path_graph(3)
+        with self.assertRaises(ValueError):
+            retworkx.christofides(graph, weight_fn=lambda _: -1.0)
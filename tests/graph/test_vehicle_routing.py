@@ -0,0 +1,164 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import math
+import unittest
+
+import retworkx
+
+
+class TestGraphSavingsRoutes(unittest.TestCase):
+    def setUp(self):
+        # A star with the depot in the middle and a path between its leaves
+        self.graph = retworkx.PyGraph()
+        self.graph.add_nodes_from(range(5))
+        self.graph.add_edges_from(
+            [(0, 1, 10), (0, 2, 10), (0, 3, 10), (0, 4, 10), (1, 2, 1), (2, 3, 1), (3, 4, 1)]
+        )
+        self.demands = {1: 1, 2: 1, 3: 1, 4: 1}
+
+    def test_savings_routes(self):
+        routes = retworkx.graph_savings_routes(self.graph, 0, self.demands, 2, weight_fn=float)
+        self.assertEqual([[0, 1, 2, 0], [0, 3, 4, 0]], [list(route.nodes) for route in routes])
+        self.assertEqual([2, 2], [route.load for route in routes])
+        self.assertEqual([21, 21], [route.cost for route in routes])
+
+    def test_single_route(self):
+        routes = retworkx.graph_savings_routes(self.graph, 0, self.demands, 4, weight_fn=float)
+        self.assertEqual(1, len(routes))
+        self.assertEqual(23, routes[0].cost)
+        self.assertIn(list(routes[0].nodes), [[0, 1, 2, 3, 4, 0], [0, 4, 3, 2, 1, 0]])
+
+    def test_reversed_route(self):
+        # Merging 3 into the route [1, 2] needs 2 at the end of the route
+        demands = {2: 1, 1: 1, 3: 1}
+        routes = retworkx.graph_savings_routes(self.graph, 0, demands, 3, weight_fn=float)
+        self.assertEqual(1, len(routes))
+        self.assertIn(list(routes[0].nodes), [[0, 1, 2, 3, 0], [0, 3, 2, 1, 0]])
+
+    def test_capacity_one(self):
+        routes = retworkx.graph_savings_routes(self.graph, 0, self.demands, 1, weight_fn=float)
+        self.assertEqual(
+            [[0, 1, 0], [0, 2, 0], [0, 3, 0], [0, 4, 0]], [list(route.nodes) for route in routes]
+        )
+        self.assertEqual([20, 20, 20, 20], [route.cost for route in routes])
+
+    def test_default_weight(self):
+        routes = retworkx.graph_savings_routes(self.graph, 0, {1: 1, 2: 1}, 2)
+        self.assertEqual(1, len(routes))
+        self.assertEqual(2, routes[0].load)
+        self.assertEqual(3, routes[0].cost)
+
+    def test_unreachable_customer(self):
+        self.graph.add_node(5)
+        routes = retworkx.graph_savings_routes(self.graph, 0, {1: 1, 5: 1}, 2)
+        self.assertEqual([[0, 1, 0], [0, 5, 0]], [list(route.nodes) for route in routes])
+        self.assertEqual(2, routes[0].cost)
+        self.assertTrue(math.isinf(routes[1].cost))
+
+    def test_no_customers(self):
+        self.assertEqual([], retworkx.graph_savings_routes(self.graph, 0, {}, 1))
+
+    def test_invalid_depot(self):
+        with self.assertRaises(retworkx.InvalidNode):
+            retworkx.graph_savings_routes(self.graph, 10, self.demands, 2)
+
+    def test_invalid_customer(self):
+        with self.assertRaises(retworkx.InvalidNode):
+            retworkx.graph_savings_routes(self.graph, 0, {10: 1}, 2)
+
+    def test_depot_demand(self):
+        with self.assertRaises(ValueError):
+            retworkx.graph_savings_routes(self.graph, 0, {0: 1, 1: 1}, 2)
+
+    def test_demand_exceeds_capacity(self):
+        with self.assertRaises(ValueError):
+            retworkx.graph_savings_routes(self.graph, 0, {1: 3}, 2)
+
+    def test_negative_demand(self):
+        with self.assertRaises(ValueError):
+            retworkx.graph_savings_routes(self.graph, 0, {1: -1}, 2)
+
+    def test_negative_weight(self):
+        with self.assertRaises(ValueError):
+            retworkx.graph_savings_routes(self.graph, 0, self.demands, 2, weight_fn=lambda _: -1)
+
+    def test_universal(self):
+        routes = retworkx.savings_routes(self.graph, 0, self.demands, 2, weight_fn=float)
+        self.assertEqual(2, len(routes))
+        self.assertIsInstance(routes[0], retworkx.VehicleRoute)
+
+
+class TestGraphSweepRoutes(unittest.TestCase):
+    def setUp(self):
+        # A wheel with the depot in the middle
+        self.graph = retworkx.PyGraph()
+        self.graph.add_nodes_from(range(5))
+        self.graph.add_edges_from_no_data(
+            [(0, 1), (0, 2), (0, 3), (0, 4), (1, 2), (2, 3), (3, 4), (4, 1)]
+        )
+        self.pos = {0: [0, 0], 1: [1, 0], 2: [0, 1], 3: [-1, 0], 4: [0, -1]}
+        self.demands = {1: 1, 2: 1, 3: 1, 4: 1}
+
+    def test_sweep_routes(self):
+        routes = retworkx.graph_sweep_routes(self.graph, 0, self.demands, 2, self.pos)
+        self.assertEqual([[0, 4, 1, 0], [0, 2, 3, 0]], [list(route.nodes) for route in routes])
+        self.assertEqual([2, 2], [route.load for route in routes])
+        self.assertEqual([3, 3], [route.cost for route in routes])
+
+    def test_uneven_demands(self):
+        demands = {1: 2, 2: 1, 3: 1, 4: 1}
+        routes = retworkx.graph_sweep_routes(self.graph, 0, demands, 3, self.pos)
+        self.assertEqual([[0, 4, 1, 0], [0, 2, 3, 0]], [list(route.nodes) for route in routes])
+        self.assertEqual([3, 2], [route.load for route in routes])
+
+    def test_same_angle(self):
+        self.graph.add_node(5)
+        self.graph.add_edge(1, 5, None)
+        self.pos[5] = [2, 0]
+        routes = retworkx.graph_sweep_routes(self.graph, 0, {5: 1, 1: 1}, 2, self.pos)
+        self.assertEqual([[0, 5, 1, 0]], [list(route.nodes) for route in routes])
+        self.assertEqual(4, routes[0].cost)
+
+    def test_weight_fn(self):
+        for edge in self.graph.edge_indices():
+            self.graph.update_edge_by_index(edge, 2)
+        routes = retworkx.graph_sweep_routes(
+            self.graph, 0, self.demands, 4, self.pos, weight_fn=float
+        )
+        self.assertEqual([[0, 4, 1, 2, 3, 0]], [list(route.nodes) for route in routes])
+        self.assertEqual(10, routes[0].cost)
+
+    def test_layout_positions(self):
+        pos = retworkx.spring_layout(self.graph, seed=42)
+        routes = retworkx.graph_sweep_routes(self.graph, 0, self.demands, 2, pos)
+        self.assertEqual(2, len(routes))
+        visited = [node for route in routes for node in list(route.nodes)[1:-1]]
+        self.assertEqual([1, 2, 3, 4], sorted(visited))
+
+    def test_missing_position(self):
+        del self.pos[4]
+        with self.assertRaises(KeyError):
+            retworkx.graph_sweep_routes(self.graph, 0, self.demands, 2, self.pos)
+
+    def test_invalid_position(self):
+        self.pos[4] = [0, -1, 0]
+        with self.assertRaises(ValueError):
+            retworkx.graph_sweep_routes(self.graph, 0, self.demands, 2, self.pos)
+
+    def test_demand_exceeds_capacity(self):
+        with self.assertRaises(ValueError):
+            retworkx.graph_sweep_routes(self.graph, 0, {1: 3}, 2, self.pos)
+
+    def test_universal(self):
+        routes = retworkx.sweep_routes(self.graph, 0, self.demands, 2, self.pos)
+        self.assertEqual([[0, 4, 1, 0], [0, 2, 3, 0]], [list(route.nodes) for route in routes])
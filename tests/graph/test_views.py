@@ -0,0 +1,104 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import unittest
+
+import retworkx
+
+
+class TreeEdgesRecorder(retworkx.visit.BFSVisitor):
+    def __init__(self):
+        self.edges = []
+
+    def tree_edge(self, edge):
+        self.edges.append((edge[0], edge[1]))
+
+
+class TestGraphView(unittest.TestCase):
+    def setUp(self):
+        self.graph = retworkx.PyGraph()
+        self.graph.add_nodes_from(["a", "b", "c", "d", "e"])
+        self.graph.add_edges_from(
+            [(0, 1, 1), (1, 2, 2), (2, 3, 3), (3, 4, 4), (4, 0, 5)]
+        )
+
+    def test_subgraph_view_nodes(self):
+        view = self.graph.subgraph_view(nodes=[0, 1, 3, 4])
+        self.assertEqual(4, view.num_nodes())
+        self.assertEqual(4, len(view))
+        self.assertEqual(3, view.num_edges())
+        self.assertEqual([0, 1, 3, 4], view.node_indices())
+        self.assertEqual([(0, 1), (3, 4), (4, 0)], view.edge_list())
+        self.assertEqual("d", view[3])
+        with self.assertRaises(IndexError):
+            view[2]
+
+    def test_subgraph_view_filters(self):
+        view = self.graph.subgraph_view(
+            node_filter=lambda node: node != "c",
+            edge_filter=lambda edge: edge != 5,
+        )
+        self.assertEqual([0, 1, 3, 4], view.node_indices())
+        self.assertEqual([(0, 1), (3, 4)], view.edge_list())
+        self.assertEqual([(0, 1, 1), (3, 4, 4)], view.weighted_edge_list())
+
+    def test_view_tracks_graph(self):
+        view = self.graph.subgraph_view(nodes=[0, 1])
+        self.graph[0] = "z"
+        self.assertEqual("z", view[0])
+        self.assertIs(self.graph, view.graph)
+
+    def test_neighbors_and_degree(self):
+        view = self.graph.subgraph_view(nodes=[0, 1, 2])
+        self.assertEqual([1], view.neighbors(0))
+        self.assertEqual(1, view.degree(0))
+        self.assertEqual(2, view.degree(1))
+        self.assertTrue(view.has_edge(1, 2))
+        self.assertFalse(view.has_edge(0, 4))
+
+    def test_nested_view(self):
+        view = self.graph.subgraph_view(nodes=[0, 1, 2, 3])
+        nested = view.subgraph_view(nodes=[1, 2, 3, 4])
+        self.assertEqual([1, 2, 3], nested.node_indices())
+        self.assertEqual([(1, 2), (2, 3)], nested.edge_list())
+
+    def test_to_graph(self):
+        view = self.graph.subgraph_view(nodes=[1, 3, 4])
+        graph = view.to_graph()
+        self.assertEqual(["b", "d", "e"], graph.nodes())
+        self.assertEqual([(1, 2, 4)], graph.weighted_edge_list())
+
+    def test_bfs_search(self):
+        view = self.graph.subgraph_view(nodes=[0, 1, 2, 4])
+        vis = TreeEdgesRecorder()
+        retworkx.bfs_search(view, [2], vis)
+        self.assertEqual([(2, 1), (1, 0), (0, 4)], vis.edges)
+
+    def test_dijkstra_search(self):
+        class Distances(retworkx.visit.DijkstraVisitor):
+            def __init__(self):
+                self.dist = {}
+
+            def discover_vertex(self, node, cost):
+                self.dist[node] = cost
+
+        view = self.graph.subgraph_view(edge_filter=lambda edge: edge != 5)
+        vis = Distances()
+        retworkx.dijkstra_search(view, [0], float, vis)
+        self.assertEqual({0: 0.0, 1: 1.0, 2: 3.0, 3: 6.0, 4: 10.0}, vis.dist)
+
+    def test_search_filtered_source(self):
+        view = self.graph.subgraph_view(nodes=[0, 1])
+        with self.assertRaises(retworkx.InvalidNode):
+            retworkx.bfs_search(view, [3], TreeEdgesRecorder())
+        with self.assertRaises(retworkx.InvalidNode):
+            retworkx.dfs_search(view, [3], retworkx.visit.DFSVisitor())
@@ -1489,3 +1489,41 @@ class TestBiconnectedComponentsMap(unittest.TestCase):
 
     def test_not_contains(self):
         self.assertNotIn((0, 2), self.bicon_map)
+
+
+class TestEdgePolylineMapping(unittest.TestCase):
+    def setUp(self):
+        self.graph = retworkx.generators.path_graph(2)
+        self.pos = {0: [0.0, 0.0], 1: [1.0, 0.0]}
+        self.polylines = retworkx.edge_bundling(self.graph, self.pos, cycles=0)
+
+    def test__eq__match(self):
+        self.assertTrue(self.polylines == {0: [[0.0, 0.0], [1.0, 0.0]]})
+
+    def test__eq__not_match_values(self):
+        self.assertFalse(self.polylines == {0: [[0.0, 0.0], [2.0, 0.0]]})
+
+    def test__ne__different_length(self):
+        self.assertTrue(self.polylines != {})
+
+    def test__len__(self):
+        self.assertEqual(1, len(self.polylines))
+
+    def test_deepcopy(self):
+        self.assertEqual(self.polylines, copy.deepcopy(self.polylines))
+
+    def test_pickle(self):
+        self.assertEqual(self.polylines, pickle.loads(pickle.dumps(self.polylines)))
+
+    def test_str(self):
+        self.assertEqual("EdgePolylineMapping{0: [[0, 0], [1, 0]]}", str(self.polylines))
+
+    def test_hash(self):
+        self.assertEqual(hash(self.polylines), hash(self.polylines))
+
+    def test_index_error(self):
+        with self.assertRaises(IndexError):
+            self.polylines[1]
+
+    def test_items(self):
+        self.assertEqual([(0, [[0.0, 0.0], [1.0, 0.0]])], list(self.polylines.items()))
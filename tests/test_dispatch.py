@@ -90,6 +90,14 @@ class TestDispatchPyGraph(unittest.TestCase):
         res = retworkx.all_pairs_dijkstra_path_lengths(self.graph, lambda _: 1)
         self.assertIsInstance(res, retworkx.AllPairsPathLengthMapping)
 
+    def test_all_pairs_dijkstra_path_lengths_chunked(self):
+        chunks = []
+        res = retworkx.all_pairs_dijkstra_path_lengths_chunked(
+            self.graph, lambda _: 1, chunks.append
+        )
+        self.assertIsNone(res)
+        self.assertIsInstance(chunks[0], retworkx.AllPairsPathLengthMapping)
+
     def test_is_isomorphic_nodes_incompatible_raises(self):
         with self.assertRaises(TypeError):
             if self.class_type == "PyGraph":
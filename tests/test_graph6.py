@@ -0,0 +1,136 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import os
+import tempfile
+import unittest
+
+import retworkx
+
+
+class TestGraph6(unittest.TestCase):
+    def test_from_graph6(self):
+        # The Petersen graph
+        graph = retworkx.from_graph6_bytes(b">>graph6<<IheA@GUAo\n")
+        self.assertIsInstance(graph, retworkx.PyGraph)
+        self.assertEqual(10, len(graph))
+        self.assertEqual(15, graph.num_edges())
+        self.assertTrue(
+            retworkx.is_isomorphic(graph, retworkx.generators.generalized_petersen_graph(5, 2))
+        )
+        self.assertEqual([None] * 10, graph.nodes())
+
+    def test_to_graph6(self):
+        graph = retworkx.generators.path_graph(3)
+        self.assertEqual(b"Bg\n", retworkx.to_graph6_bytes(graph, header=False))
+        self.assertEqual(b">>graph6<<Bg\n", retworkx.to_graph6_bytes(graph))
+        self.assertEqual(b"?\n", retworkx.to_graph6_bytes(retworkx.PyGraph(), header=False))
+
+    def test_graph6_simple_graph(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(range(3))
+        graph.add_edges_from_no_data([(0, 1), (1, 0), (2, 2)])
+        res = retworkx.from_graph6_bytes(retworkx.to_graph6_bytes(graph))
+        self.assertEqual([(0, 1)], res.edge_list())
+
+    def test_graph6_removed_node(self):
+        graph = retworkx.generators.path_graph(4)
+        graph.remove_node(1)
+        res = retworkx.from_graph6_bytes(retworkx.to_graph6_bytes(graph))
+        self.assertEqual(3, len(res))
+        self.assertEqual([(1, 2)], res.edge_list())
+
+    def test_large_graph6_round_trip(self):
+        graph = retworkx.undirected_gnp_random_graph(300, 0.1, seed=42)
+        res = retworkx.from_graph6_bytes(retworkx.to_graph6_bytes(graph))
+        self.assertEqual(300, len(res))
+        self.assertEqual(
+            sorted(tuple(sorted(edge)) for edge in graph.edge_list()), sorted(res.edge_list())
+        )
+
+    def test_sparse6(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(range(3))
+        graph.add_edges_from_no_data([(0, 1), (1, 2), (1, 2)])
+        data = retworkx.to_sparse6_bytes(graph, header=False)
+        self.assertEqual(b":BdN\n", data)
+        self.assertEqual(b">>sparse6<<:BdN\n", retworkx.to_sparse6_bytes(graph))
+        res = retworkx.from_graph6_bytes(data)
+        self.assertIsInstance(res, retworkx.PyGraph)
+        self.assertEqual([(0, 1), (1, 2), (1, 2)], res.edge_list())
+
+    def test_sparse6_round_trip(self):
+        for num_nodes in [1, 2, 4, 8, 16, 31, 32, 33, 64, 100]:
+            with self.subTest(num_nodes=num_nodes):
+                graph = retworkx.PyGraph()
+                graph.add_nodes_from(range(num_nodes))
+                edges = [(i, (7 * i + 3) % num_nodes) for i in range(num_nodes)]
+                graph.add_edges_from_no_data(edges)
+                graph.add_edges_from_no_data([(num_nodes - 1, num_nodes - 1), (0, num_nodes - 1)])
+                res = retworkx.from_graph6_bytes(retworkx.to_sparse6_bytes(graph))
+                self.assertEqual(num_nodes, len(res))
+                self.assertEqual(
+                    sorted(tuple(sorted(edge)) for edge in graph.edge_list()),
+                    sorted(res.edge_list()),
+                )
+
+    def test_digraph6(self):
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(range(5))
+        graph.add_edges_from_no_data([(0, 2), (0, 4), (3, 1), (3, 4), (3, 4)])
+        self.assertEqual(b">>digraph6<<&DI?AO?\n", retworkx.to_graph6_bytes(graph))
+        res = retworkx.from_graph6_bytes(b"&DI?AO?")
+        self.assertIsInstance(res, retworkx.PyDiGraph)
+        self.assertEqual([(0, 2), (0, 4), (3, 1), (3, 4)], res.edge_list())
+
+    def test_invalid(self):
+        for data in [b"", b"A", b"Bgg", b"B\x01", b"&Bg", b":"]:
+            with self.subTest(data=data):
+                with self.assertRaises(ValueError):
+                    retworkx.from_graph6_bytes(data)
+
+    def test_too_many_nodes(self):
+        for data in [b":~~~~~~~~", b"~~~~~~~~", b"&~~~~~~~~"]:
+            with self.subTest(data=data):
+                with self.assertRaisesRegex(ValueError, "too many nodes"):
+                    retworkx.from_graph6_bytes(data)
+
+    def test_to_graph6_invalid_type(self):
+        with self.assertRaises(TypeError):
+            retworkx.to_graph6_bytes(None)
+
+    def test_read_graph6_file(self):
+        with tempfile.TemporaryDirectory() as tmpdirname:
+            path = os.path.join(tmpdirname, "graphs.g6")
+            with open(path, "wb") as fd:
+                fd.write(b">>graph6<<Bg\n\n:BdN\n&DI?AO?\n")
+            graphs = list(retworkx.read_graph6_file(path))
+        self.assertEqual(
+            [retworkx.PyGraph, retworkx.PyGraph, retworkx.PyDiGraph],
+            [type(graph) for graph in graphs],
+        )
+        self.assertEqual([2, 3, 4], [graph.num_edges() for graph in graphs])
+
+    def test_read_graph6_file_invalid_line(self):
+        with tempfile.TemporaryDirectory() as tmpdirname:
+            path = os.path.join(tmpdirname, "graphs.g6")
+            with open(path, "wb") as fd:
+                fd.write(b"Bg\nBgg\n")
+            graphs = retworkx.read_graph6_file(path)
+            self.assertEqual(2, next(graphs).num_edges())
+            with self.assertRaisesRegex(ValueError, "Line 2"):
+                next(graphs)
+
+    def test_read_graph6_file_missing(self):
+        with tempfile.TemporaryDirectory() as tmpdirname:
+            with self.assertRaises(OSError):
+                retworkx.read_graph6_file(os.path.join(tmpdirname, "missing.g6"))
@@ -0,0 +1,176 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import math
+import unittest
+
+import retworkx
+
+
+class TestToleranceMatcher(unittest.TestCase):
+    def test_exact_by_default(self):
+        matcher = retworkx.ToleranceMatcher()
+        self.assertTrue(matcher(1.5, 1.5))
+        self.assertFalse(matcher(0.1 + 0.2, 0.3))
+        self.assertEqual(0.0, matcher.rel_tol)
+        self.assertEqual(0.0, matcher.abs_tol)
+        self.assertIsNone(matcher.key)
+
+    def test_absolute_tolerance(self):
+        matcher = retworkx.ToleranceMatcher(abs_tol=0.1)
+        self.assertTrue(matcher(1.0, 1.05))
+        self.assertTrue(matcher(1000.0, 1000.05))
+        self.assertFalse(matcher(1.0, 1.2))
+
+    def test_relative_tolerance(self):
+        matcher = retworkx.ToleranceMatcher(rel_tol=0.01)
+        self.assertTrue(matcher(1000.0, 1005.0))
+        self.assertFalse(matcher(1.0, 1.05))
+        self.assertFalse(matcher(0.0, 1e-12))
+
+    def test_same_as_math_isclose(self):
+        values = [0.0, 1e-10, 0.3, 0.1 + 0.2, 1.0, -1.0, 1e10, math.inf, -math.inf, math.nan]
+        for rel_tol, abs_tol in [(0.0, 0.0), (1e-9, 0.0), (0.0, 1e-9), (0.5, 0.5)]:
+            matcher = retworkx.ToleranceMatcher(rel_tol=rel_tol, abs_tol=abs_tol)
+            for a in values:
+                for b in values:
+                    self.assertEqual(
+                        math.isclose(a, b, rel_tol=rel_tol, abs_tol=abs_tol), matcher(a, b)
+                    )
+
+    def test_int_payloads(self):
+        matcher = retworkx.ToleranceMatcher(abs_tol=1)
+        self.assertTrue(matcher(3, 4))
+
+    def test_key_index(self):
+        matcher = retworkx.ToleranceMatcher(abs_tol=1e-9, key="weight")
+        self.assertTrue(matcher({"weight": 0.1 + 0.2}, {"weight": 0.3}))
+        with self.assertRaises(KeyError):
+            matcher({"weight": 0.3}, {})
+
+    def test_key_callable(self):
+        matcher = retworkx.ToleranceMatcher(abs_tol=1e-9, key=lambda x: x[1])
+        self.assertTrue(matcher(("a", 0.1 + 0.2), ("b", 0.3)))
+        self.assertFalse(matcher(("a", 0.1), ("a", 0.3)))
+
+    def test_invalid_payload(self):
+        matcher = retworkx.ToleranceMatcher()
+        with self.assertRaises(TypeError):
+            matcher("a", "a")
+
+    def test_negative_tolerance(self):
+        with self.assertRaises(ValueError):
+            retworkx.ToleranceMatcher(abs_tol=-1.0)
+        with self.assertRaises(ValueError):
+            retworkx.ToleranceMatcher(rel_tol=-1.0)
+
+    def test_is_isomorphic(self):
+        first = retworkx.PyGraph()
+        first.add_nodes_from([1.0 / 3.0, 2.0])
+        first.add_edges_from([(0, 1, 0.1 + 0.2)])
+        second = retworkx.PyGraph()
+        second.add_nodes_from([2.0, 0.3333333333])
+        second.add_edges_from([(1, 0, 0.3)])
+        matcher = retworkx.ToleranceMatcher(abs_tol=1e-9)
+        self.assertFalse(retworkx.is_isomorphic(first, second, edge_matcher=lambda a, b: a == b))
+        self.assertTrue(
+            retworkx.is_isomorphic(first, second, node_matcher=matcher, edge_matcher=matcher)
+        )
+        self.assertEqual({0: 1, 1: 0}, dict(next(retworkx.vf2_mapping(first, second, matcher))))
+
+    def test_digraph_is_subgraph_isomorphic(self):
+        first = retworkx.PyDiGraph()
+        first.add_nodes_from([0, 1, 2])
+        first.add_edges_from([(0, 1, 1.0 / 3.0), (1, 2, 2.0 / 3.0)])
+        second = retworkx.PyDiGraph()
+        second.add_nodes_from([0, 1])
+        second.add_edges_from([(0, 1, 0.6667)])
+        self.assertFalse(
+            retworkx.is_subgraph_isomorphic(
+                first, second, edge_matcher=retworkx.ToleranceMatcher(rel_tol=1e-6)
+            )
+        )
+        self.assertTrue(
+            retworkx.is_subgraph_isomorphic(
+                first, second, edge_matcher=retworkx.ToleranceMatcher(rel_tol=1e-3)
+            )
+        )
+
+
+class TestCombinedMatchers(unittest.TestCase):
+    def setUp(self):
+        self.calls = []
+
+    def matcher(self, result):
+        def inner(a, b):
+            self.calls.append(result)
+            return result
+
+        return inner
+
+    def test_all_of(self):
+        matcher = retworkx.AllOfMatcher(self.matcher(True), self.matcher(True))
+        self.assertTrue(matcher(1, 2))
+        self.assertEqual([True, True], self.calls)
+        self.assertEqual(2, len(matcher.matchers))
+
+    def test_all_of_stops_at_first_false(self):
+        matcher = retworkx.AllOfMatcher(
+            self.matcher(True), self.matcher(False), self.matcher(True)
+        )
+        self.assertFalse(matcher(1, 2))
+        self.assertEqual([True, False], self.calls)
+
+    def test_any_of(self):
+        matcher = retworkx.AnyOfMatcher(self.matcher(False), self.matcher(False))
+        self.assertFalse(matcher(1, 2))
+        self.assertEqual([False, False], self.calls)
+
+    def test_any_of_stops_at_first_true(self):
+        matcher = retworkx.AnyOfMatcher(
+            self.matcher(False), self.matcher(True), self.matcher(False)
+        )
+        self.assertTrue(matcher(1, 2))
+        self.assertEqual([False, True], self.calls)
+
+    def test_empty(self):
+        self.assertTrue(retworkx.AllOfMatcher()(1, 2))
+        self.assertFalse(retworkx.AnyOfMatcher()(1, 2))
+
+    def test_error(self):
+        def error(a, b):
+            raise IndexError
+
+        with self.assertRaises(IndexError):
+            retworkx.AllOfMatcher(error)(1, 2)
+
+    def test_nested_in_isomorphism(self):
+        first = retworkx.PyGraph()
+        first.add_nodes_from([0, 1, 2])
+        first.add_edges_from(
+            [(0, 1, {"weight": 0.1 + 0.2, "kind": "a"}), (1, 2, {"weight": 1.0, "kind": "b"})]
+        )
+        second = retworkx.PyGraph()
+        second.add_nodes_from([0, 1, 2])
+        second.add_edges_from(
+            [(0, 1, {"weight": 0.3, "kind": "a"}), (1, 2, {"weight": None, "kind": "b"})]
+        )
+        matcher = retworkx.AllOfMatcher(
+            lambda a, b: a["kind"] == b["kind"],
+            retworkx.AnyOfMatcher(
+                lambda a, b: a["weight"] is None or b["weight"] is None,
+                retworkx.ToleranceMatcher(abs_tol=1e-9, key="weight"),
+            ),
+        )
+        self.assertTrue(retworkx.is_isomorphic(first, second, edge_matcher=matcher))
+        second.update_edge(1, 2, {"weight": None, "kind": "a"})
+        self.assertFalse(retworkx.is_isomorphic(first, second, edge_matcher=matcher))
@@ -0,0 +1,83 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import os
+import tempfile
+import unittest
+
+import retworkx
+
+
+class TestMatrixMarket(unittest.TestCase):
+    def test_general(self):
+        graph = retworkx.read_matrix_market(
+            """%%MatrixMarket matrix coordinate real general
+% A comment
+3 3 4
+
+1 2 0.5
+2 3 1.5
+3 1 -2e1
+1 2 4
+"""
+        )
+        self.assertIsInstance(graph, retworkx.PyDiGraph)
+        self.assertEqual([None, None, None], graph.nodes())
+        self.assertEqual(
+            [(0, 1, 0.5), (1, 2, 1.5), (2, 0, -20.0), (0, 1, 4.0)],
+            list(graph.weighted_edge_list()),
+        )
+
+    def test_symmetric(self):
+        graph = retworkx.read_matrix_market(
+            "%%MatrixMarket matrix coordinate integer symmetric\n4 4 2\n2 1 3\n3 3 1\n"
+        )
+        self.assertIsInstance(graph, retworkx.PyGraph)
+        self.assertEqual(4, len(graph))
+        self.assertEqual([(1, 0, 3.0), (2, 2, 1.0)], list(graph.weighted_edge_list()))
+
+    def test_pattern(self):
+        graph = retworkx.read_matrix_market(
+            "%%MatrixMarket matrix coordinate pattern general\r\n2 2 1\r\n2 1\r\n"
+        )
+        self.assertEqual([(1, 0, None)], list(graph.weighted_edge_list()))
+
+    def test_file(self):
+        with tempfile.TemporaryDirectory() as tmpdirname:
+            path = os.path.join(tmpdirname, "graph.mtx")
+            with open(path, "w") as fd:
+                fd.write("%%MatrixMarket matrix coordinate real general\n2 2 1\n1 2 1.0\n")
+            graph = retworkx.read_matrix_market(path)
+        self.assertEqual([(0, 1, 1.0)], list(graph.weighted_edge_list()))
+
+    def test_invalid(self):
+        header = "%%MatrixMarket matrix coordinate real general\n"
+        for source in [
+            "1 2\n",
+            "%%MatrixMarket matrix array real general\n2 2\n1\n2\n3\n4\n",
+            "%%MatrixMarket matrix coordinate complex general\n1 1 1\n1 1 1 1\n",
+            "%%MatrixMarket matrix coordinate real hermitian\n1 1 1\n1 1 1\n",
+            header + "2 3 0\n",
+            header + "2 2\n",
+            header + "2 2 2\n1 2 1\n",
+            header + "2 2 1\n1 3 1\n",
+            header + "2 2 1\n0 1 1\n",
+            header + "2 2 1\n1 2\n",
+        ]:
+            with self.subTest(source=source):
+                with self.assertRaises(ValueError):
+                    retworkx.read_matrix_market(source)
+
+    def test_missing_file(self):
+        with tempfile.TemporaryDirectory() as tmpdirname:
+            with self.assertRaises(OSError):
+                retworkx.read_matrix_market(os.path.join(tmpdirname, "missing.mtx"))
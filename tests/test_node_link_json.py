@@ -0,0 +1,212 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import json
+import os
+import tempfile
+import unittest
+
+import networkx
+
+import retworkx
+
+
+def node_link_graph(data):
+    try:
+        return networkx.node_link_graph(data, edges="links")
+    except TypeError:
+        # networkx < 3.4 always reads "links"
+        return networkx.node_link_graph(data)
+
+
+def node_link_data(graph):
+    try:
+        return networkx.node_link_data(graph, edges="edges")
+    except TypeError:
+        return networkx.node_link_data(graph)
+
+
+class TestNodeLinkJSON(unittest.TestCase):
+    def test_graph(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from([{"color": "red"}, None, {"label": "c", "size": 2.5}])
+        graph.add_edge(0, 1, {"weight": 1})
+        graph.add_edge(1, 2, None)
+        res = json.loads(retworkx.node_link_json(graph, graph_attrs={"name": "test"}))
+        expected = {
+            "schema_version": 1,
+            "directed": False,
+            "multigraph": True,
+            "graph": {"name": "test"},
+            "nodes": [{"id": 0, "color": "red"}, {"id": 1}, {"id": 2, "label": "c", "size": 2.5}],
+            "links": [
+                {"source": 0, "target": 1, "key": 0, "weight": 1},
+                {"source": 1, "target": 2, "key": 1},
+            ],
+        }
+        self.assertEqual(expected, res)
+
+    def test_digraph(self):
+        graph = retworkx.PyDiGraph(multigraph=False)
+        graph.add_nodes_from(["a", "b"])
+        graph.add_edge(1, 0, "x")
+        res = json.loads(
+            retworkx.node_link_json(
+                graph, node_attrs=lambda n: {"name": n}, edge_attrs=lambda e: {"label": e}
+            )
+        )
+        expected = {
+            "schema_version": 1,
+            "directed": True,
+            "multigraph": False,
+            "graph": {},
+            "nodes": [{"id": 0, "name": "a"}, {"id": 1, "name": "b"}],
+            "links": [{"source": 1, "target": 0, "label": "x"}],
+        }
+        self.assertEqual(expected, res)
+
+    def test_values(self):
+        graph = retworkx.PyGraph()
+        graph.add_node(
+            {
+                "none": None,
+                "bool": True,
+                "int": 2**70,
+                "float": -1e-7,
+                "str": 'a "quoted"\n\ttab \x01 é \U0001f600',
+                "list": [1, [2.0, "x"], (3,)],
+                "dict": {"nested": {"a": False}},
+            }
+        )
+        res = json.loads(retworkx.node_link_json(graph))
+        expected = {
+            "id": 0,
+            "none": None,
+            "bool": True,
+            "int": 2**70,
+            "float": -1e-7,
+            "str": 'a "quoted"\n\ttab \x01 é \U0001f600',
+            "list": [1, [2.0, "x"], [3]],
+            "dict": {"nested": {"a": False}},
+        }
+        self.assertEqual([expected], res["nodes"])
+
+    def test_removed_nodes_keep_ids(self):
+        graph = retworkx.generators.path_graph(4)
+        graph.remove_node(1)
+        res = json.loads(retworkx.node_link_json(graph))
+        self.assertEqual([0, 2, 3], [node["id"] for node in res["nodes"]])
+        self.assertEqual([(2, 3)], [(link["source"], link["target"]) for link in res["links"]])
+
+    def test_invalid_attributes(self):
+        graph = retworkx.PyGraph()
+        graph.add_node(None)
+        for attrs, error in [
+            ({1: "a"}, TypeError),
+            ({"a": object()}, TypeError),
+            ({"a": float("nan")}, ValueError),
+            ({"id": 1}, ValueError),
+            ("not a dict", TypeError),
+        ]:
+            with self.subTest(attrs=attrs):
+                with self.assertRaises(error):
+                    retworkx.node_link_json(graph, node_attrs=lambda _: attrs)
+        graph[0] = "payload"
+        with self.assertRaises(TypeError):
+            retworkx.node_link_json(graph)
+
+    def test_invalid_type(self):
+        with self.assertRaises(TypeError):
+            retworkx.node_link_json(None)
+
+    def test_round_trip(self):
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from([{"a": 1}, {"b": [1, 2]}, {}])
+        graph.add_edges_from([(0, 1, {"w": 1.5}), (0, 1, {"w": 2.5}), (2, 2, {})])
+        res, attrs = retworkx.read_node_link_json(
+            retworkx.node_link_json(graph, graph_attrs={"name": "g"})
+        )
+        self.assertIsInstance(res, retworkx.PyDiGraph)
+        self.assertTrue(res.multigraph)
+        self.assertEqual({"name": "g"}, attrs)
+        self.assertEqual(graph.nodes(), res.nodes())
+        self.assertEqual(list(graph.weighted_edge_list()), list(res.weighted_edge_list()))
+        self.assertEqual({0: 0, 1: 1, 2: 2}, res.node_keys())
+
+    def test_round_trip_file(self):
+        graph = retworkx.generators.cycle_graph(3)
+        with tempfile.TemporaryDirectory() as tmpdirname:
+            path = os.path.join(tmpdirname, "graph.json")
+            self.assertIsNone(retworkx.node_link_json(graph, path=path))
+            res, attrs = retworkx.read_node_link_json(path)
+        self.assertIsInstance(res, retworkx.PyGraph)
+        self.assertEqual({}, attrs)
+        self.assertEqual(list(graph.edge_list()), list(res.edge_list()))
+
+    def test_networkx_compatible(self):
+        graph = retworkx.PyGraph(multigraph=False)
+        graph.add_nodes_from([{"color": "red"}, {"color": "blue"}])
+        graph.add_edge(0, 1, {"weight": 3})
+        nx_graph = node_link_graph(
+            json.loads(retworkx.node_link_json(graph, graph_attrs={"name": "g"}))
+        )
+        self.assertFalse(nx_graph.is_directed())
+        self.assertEqual({"name": "g"}, nx_graph.graph)
+        self.assertEqual(
+            [(0, {"color": "red"}), (1, {"color": "blue"})], list(nx_graph.nodes(data=True))
+        )
+        self.assertEqual([(0, 1, {"weight": 3})], list(nx_graph.edges(data=True)))
+
+    def test_read_networkx(self):
+        nx_graph = networkx.MultiDiGraph(name="nx")
+        nx_graph.add_node("a", color="red")
+        nx_graph.add_edge("a", "b", weight=1)
+        nx_graph.add_edge("a", "b", weight=2)
+        data = json.dumps(node_link_data(nx_graph))
+        graph, attrs = retworkx.read_node_link_json(data)
+        self.assertIsInstance(graph, retworkx.PyDiGraph)
+        self.assertEqual({"name": "nx"}, attrs)
+        self.assertEqual({"a": 0, "b": 1}, graph.node_keys())
+        self.assertEqual([{"color": "red"}, {}], graph.nodes())
+        self.assertEqual(
+            [(0, 1, {"weight": 1}), (0, 1, {"weight": 2})], list(graph.weighted_edge_list())
+        )
+
+    def test_read_invalid(self):
+        for data in [
+            "{",
+            '{"nodes": [}',
+            '{"nodes": [{"id": 01}]}',
+            '{"nodes": [{"id": 0}]} extra',
+            '{"nodes": [{"color": "red"}]}',
+            '{"nodes": [{"id": 0}, {"id": 0}]}',
+            '{"nodes": [{"id": 0}], "links": [{"source": 0, "target": 1}]}',
+            '{"nodes": [{"id": 0}], "links": [{"source": 0}]}',
+            '{"directed": "yes"}',
+            '{"nodes": {}}',
+            '{"schema_version": 2}',
+            '{"bad": "\\x"}',
+        ]:
+            with self.subTest(data=data):
+                with self.assertRaises(ValueError):
+                    retworkx.read_node_link_json(data)
+
+    def test_read_escapes(self):
+        graph, _ = retworkx.read_node_link_json(
+            '{"nodes": [{"id": "\\u00e9\\ud83d\\ude00", "s": "\\"\\\\\\/\\b\\f\\n\\r\\t"}]}'
+        )
+        self.assertEqual({"é\U0001f600": 0}, graph.node_keys())
+        self.assertEqual([{"s": '"\\/\b\f\n\r\t'}], graph.nodes())
+
+    def test_error_position(self):
+        with self.assertRaisesRegex(ValueError, "line 2 column 5"):
+            retworkx.read_node_link_json('{"nodes":\n[], x}')
@@ -0,0 +1,134 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import os
+import tempfile
+import unittest
+
+import retworkx
+
+
+class TestPajek(unittest.TestCase):
+    def test_read_edges(self):
+        graph = retworkx.read_pajek(
+            """% A comment
+*Network test
+*Vertices 4
+1 "node one"
+2 b
+3 "c" ic Red
+*Edges
+1 2 2.5
+2 3
+3 3 1 c Blue
+"""
+        )
+        self.assertIsInstance(graph, retworkx.PyGraph)
+        self.assertEqual(["node one", "b", "c", "4"], graph.nodes())
+        self.assertEqual(
+            [(0, 1, 2.5), (1, 2, None), (2, 2, 1.0)], list(graph.weighted_edge_list())
+        )
+        self.assertEqual([], graph.node_attributes())
+
+    def test_read_arcs(self):
+        graph = retworkx.read_pajek(
+            """*vertices 3
+*arcs :1 "relation"
+1 2 1.5
+1 2 0.5
+*edges
+2 3 2
+*arcslist
+3 1 2
+"""
+        )
+        self.assertIsInstance(graph, retworkx.PyDiGraph)
+        self.assertEqual(
+            [(0, 1, 1.5), (0, 1, 0.5), (1, 2, 2.0), (2, 1, 2.0), (2, 0, None), (2, 1, None)],
+            list(graph.weighted_edge_list()),
+        )
+
+    def test_read_edges_list(self):
+        graph = retworkx.read_pajek("*Vertices 3\n*Edgeslist\n1 2 3\n")
+        self.assertIsInstance(graph, retworkx.PyGraph)
+        self.assertEqual([(0, 1), (0, 2)], list(graph.edge_list()))
+
+    def test_read_matrix(self):
+        graph = retworkx.read_pajek("*Vertices 2\n*Matrix\n0 1\n2.5 0\n")
+        self.assertIsInstance(graph, retworkx.PyDiGraph)
+        self.assertEqual([(0, 1, 1.0), (1, 0, 2.5)], list(graph.weighted_edge_list()))
+
+    def test_read_coordinates(self):
+        graph = retworkx.read_pajek('*Vertices 2\n1 "a" 0.1 0.2\n2 "b"\n')
+        self.assertEqual(["x", "y"], graph.node_attributes())
+        graph = retworkx.read_pajek('*Vertices 1\n1 "a" 0.1 0.2 0.3 box\n')
+        self.assertEqual(["x", "y", "z"], graph.node_attributes())
+
+    def test_read_invalid(self):
+        for source in [
+            "1 2\n",
+            "*Vertices\n",
+            "*Vertices 2\n3\n",
+            "*Vertices 2\n*Edges\n1 3\n",
+            "*Vertices 2\n*Edges\n1\n",
+            '*Vertices 2\n1 "a\n',
+            "*Vertices 2\n*Matrix\n0 1\n",
+            "*Vertices 2\n*Matrix\n0 x\n0 0\n",
+            "*Edges\n1 2\n",
+            "*Vertices 2\n*Unknown\n",
+        ]:
+            with self.subTest(source=source):
+                with self.assertRaises(ValueError):
+                    retworkx.read_pajek(source)
+
+    def test_write_graph(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(["a b", None, 3])
+        graph.add_edge(0, 1, 2.5)
+        graph.add_edge(1, 2, None)
+        expected = '*Vertices 3\n1 "a b"\n2\n3 "3"\n*Edges\n1 2 2.5\n2 3\n'
+        self.assertEqual(expected, retworkx.write_pajek(graph))
+
+    def test_write_digraph(self):
+        graph = retworkx.generators.directed_path_graph(4, weights=["a", "b", "c", "d"])
+        graph.remove_node(1)
+        res = retworkx.write_pajek(graph, label_fn=lambda x: x.upper(), weight_fn=lambda _: 2)
+        self.assertEqual('*Vertices 3\n1 "A"\n2 "C"\n3 "D"\n*Arcs\n2 3 2\n', res)
+
+    def test_write_invalid(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(['a "quoted" label', "b"])
+        with self.assertRaises(ValueError):
+            retworkx.write_pajek(graph)
+        graph[0] = "a"
+        graph.add_edge(0, 1, "not a weight")
+        with self.assertRaises(TypeError):
+            retworkx.write_pajek(graph)
+        with self.assertRaises(TypeError):
+            retworkx.write_pajek(None)
+
+    def test_round_trip_file(self):
+        graph = retworkx.PyDiGraph()
+        graph.add_nodes_from(["a", "b", "c"])
+        graph.add_edges_from([(0, 1, 1.5), (0, 1, 2.0), (2, 0, 0.5)])
+        with tempfile.TemporaryDirectory() as tmpdirname:
+            path = os.path.join(tmpdirname, "graph.net")
+            self.assertIsNone(retworkx.write_pajek(graph, path=path))
+            res = retworkx.read_pajek(path)
+        self.assertIsInstance(res, retworkx.PyDiGraph)
+        self.assertEqual(graph.nodes(), res.nodes())
+        self.assertEqual(list(graph.weighted_edge_list()), list(res.weighted_edge_list()))
+
+    def test_missing_file(self):
+        with tempfile.TemporaryDirectory() as tmpdirname:
+            with self.assertRaises(OSError):
+                retworkx.read_pajek(os.path.join(tmpdirname, "missing.net"))
@@ -63,6 +63,31 @@ class TestGNPRandomGraph(unittest.TestCase):
         with self.assertRaises(ValueError):
             retworkx.undirected_gnp_random_graph(23, 123.5)
 
+    def test_random_gnp_directed_edge_filter(self):
+        graph = retworkx.directed_gnp_random_graph(20, 0.5, seed=10, edge_filter=lambda u, v: u < v)
+        self.assertEqual(len(graph), 20)
+        self.assertTrue(len(graph.edges()) > 0)
+        self.assertTrue(all(u < v for u, v in graph.edge_list()))
+
+    def test_random_gnp_directed_edge_filter_complete_graph(self):
+        graph = retworkx.directed_gnp_random_graph(20, 1, edge_filter=lambda u, v: u < v)
+        self.assertEqual(len(graph.edges()), 20 * (20 - 1) / 2)
+
+    def test_random_gnp_directed_edge_filter_error(self):
+        def edge_filter(u, v):
+            raise KeyError(u)
+
+        with self.assertRaises(KeyError):
+            retworkx.directed_gnp_random_graph(20, 0.5, edge_filter=edge_filter)
+
+    def test_random_gnp_undirected_edge_filter(self):
+        graph = retworkx.undirected_gnp_random_graph(
+            20, 0.5, seed=10, edge_filter=lambda u, v: (u + v) % 2 == 0
+        )
+        self.assertEqual(len(graph), 20)
+        self.assertTrue(len(graph.edges()) > 0)
+        self.assertTrue(all((u + v) % 2 == 0 for u, v in graph.edge_list()))
+
 
 class TestGNMRandomGraph(unittest.TestCase):
     def test_random_gnm_directed(self):
@@ -149,6 +174,32 @@ class TestGNMRandomGraph(unittest.TestCase):
         with self.assertRaises(ValueError):
             retworkx.undirected_gnm_random_graph(23, -5)
 
+    def test_random_gnm_directed_edge_filter(self):
+        graph = retworkx.directed_gnm_random_graph(20, 100, seed=10, edge_filter=lambda u, v: u < v)
+        self.assertEqual(len(graph.edges()), 100)
+        self.assertTrue(all(u < v for u, v in graph.edge_list()))
+
+    def test_random_gnm_directed_edge_filter_too_restrictive(self):
+        graph = retworkx.directed_gnm_random_graph(
+            20, 100, seed=10, edge_filter=lambda u, v: u == 0
+        )
+        self.assertEqual(len(graph.edges()), 19)
+        self.assertEqual(graph.out_degree(0), 19)
+
+    def test_random_gnm_undirected_edge_filter(self):
+        graph = retworkx.undirected_gnm_random_graph(
+            20, 50, seed=10, edge_filter=lambda u, v: (u + v) % 2 == 0
+        )
+        self.assertEqual(len(graph.edges()), 50)
+        self.assertTrue(all((u + v) % 2 == 0 for u, v in graph.edge_list()))
+
+    def test_random_gnm_undirected_edge_filter_error(self):
+        def edge_filter(u, v):
+            raise KeyError(u)
+
+        with self.assertRaises(KeyError):
+            retworkx.undirected_gnm_random_graph(20, 50, edge_filter=edge_filter)
+
 
 class TestGeometricRandomGraph(unittest.TestCase):
     def test_random_geometric_empty(self):
@@ -197,6 +248,432 @@ class TestGeometricRandomGraph(unittest.TestCase):
         with self.assertRaises(ValueError):
             retworkx.random_geometric_graph(3, 0.15, pos=[[0.5, 0.5]])
 
+    def test_random_geometric_edge_filter(self):
+        graph = retworkx.random_geometric_graph(10, 1.42, edge_filter=lambda u, v: v == u + 1)
+        self.assertEqual(len(graph.edges()), 9)
+
+    def test_random_geometric_torus(self):
+        pos = [[0.05, 0.5], [0.95, 0.5], [0.5, 0.5]]
+        graph = retworkx.random_geometric_graph(3, 0.15, pos=pos, torus=True)
+        self.assertEqual(graph.edge_list(), [(0, 1)])
+        graph = retworkx.random_geometric_graph(3, 0.15, pos=pos)
+        self.assertEqual(graph.edge_list(), [])
+
+    def test_random_geometric_torus_inf_norm(self):
+        pos = [[0.05, 0.05], [0.95, 0.95], [0.5, 0.05]]
+        graph = retworkx.random_geometric_graph(3, 0.11, pos=pos, p=float("inf"), torus=True)
+        self.assertEqual(graph.edge_list(), [(0, 1)])
+
+    def test_random_geometric_torus_complete(self):
+        # no two points on the unit torus are more than sqrt(0.5) apart
+        graph = retworkx.random_geometric_graph(10, 0.71, torus=True, seed=42)
+        self.assertEqual(len(graph.edges()), 45)
+
+    def test_random_geometric_torus_same_seed(self):
+        graph = retworkx.random_geometric_graph(20, 0.3, seed=7)
+        torus_graph = retworkx.random_geometric_graph(20, 0.3, seed=7, torus=True)
+        self.assertEqual(graph.nodes(), torus_graph.nodes())
+        self.assertTrue(set(graph.edge_list()).issubset(torus_graph.edge_list()))
+
+    def test_random_geometric_without_positions(self):
+        graph = retworkx.random_geometric_graph(5, 0.5, seed=3, with_positions=False)
+        self.assertEqual([None] * 5, graph.nodes())
+        graph_with_positions = retworkx.random_geometric_graph(5, 0.5, seed=3)
+        self.assertEqual(graph_with_positions.edge_list(), graph.edge_list())
+
+
+class TestBarabasiAlbertGraph(unittest.TestCase):
+    def test_barabasi_albert_graph(self):
+        graph = retworkx.barabasi_albert_graph(100, 3, seed=42)
+        self.assertEqual(100, len(graph))
+        self.assertEqual(3 + (100 - 4) * 3, graph.num_edges())
+        self.assertEqual(list(range(100)), graph.nodes())
+        for node in range(4, 100):
+            self.assertEqual(3, len([v for v in graph.neighbors(node) if v < node]))
+        self.assertTrue(retworkx.is_connected(graph))
+
+    def test_barabasi_albert_graph_no_parallel_edges(self):
+        graph = retworkx.barabasi_albert_graph(200, 5, seed=1)
+        edges = [tuple(sorted(edge)) for edge in graph.edge_list()]
+        self.assertEqual(len(edges), len(set(edges)))
+
+    def test_barabasi_albert_graph_same_seed(self):
+        graph_s1 = retworkx.barabasi_albert_graph(50, 2, seed=10)
+        graph_s2 = retworkx.barabasi_albert_graph(50, 2, seed=10)
+        self.assertEqual(graph_s1.edge_list(), graph_s2.edge_list())
+
+    def test_barabasi_albert_graph_n_equal_initial(self):
+        graph = retworkx.barabasi_albert_graph(3, 2)
+        self.assertEqual([(0, 1), (0, 2)], graph.edge_list())
+
+    def test_barabasi_albert_graph_initial_graph(self):
+        initial_graph = retworkx.generators.path_graph(5)
+        initial_graph[0] = "a"
+        graph = retworkx.barabasi_albert_graph(20, 2, seed=3, initial_graph=initial_graph)
+        self.assertEqual(20, len(graph))
+        self.assertEqual(4 + 15 * 2, graph.num_edges())
+        self.assertEqual(["a", None, None, None, None] + list(range(5, 20)), graph.nodes())
+        self.assertEqual(list(initial_graph.edge_list()), list(graph.edge_list())[:4])
+        self.assertEqual(5, len(initial_graph))
+
+    def test_barabasi_albert_graph_initial_graph_removed_nodes(self):
+        initial_graph = retworkx.generators.cycle_graph(6)
+        initial_graph.remove_node(2)
+        graph = retworkx.barabasi_albert_graph(8, 2, seed=3, initial_graph=initial_graph)
+        self.assertEqual(8, len(graph))
+        self.assertEqual(2, graph[2])
+        self.assertEqual(4 + 3 * 2, graph.num_edges())
+
+    def test_directed_barabasi_albert_graph(self):
+        graph = retworkx.directed_barabasi_albert_graph(50, 3, seed=42)
+        self.assertIsInstance(graph, retworkx.PyDiGraph)
+        self.assertEqual(50, len(graph))
+        for node in range(4, 50):
+            self.assertEqual(3, graph.out_degree(node))
+            self.assertTrue(all(v < node for v in graph.successor_indices(node)))
+
+    def test_directed_barabasi_albert_graph_initial_graph(self):
+        initial_graph = retworkx.generators.directed_cycle_graph(4)
+        graph = retworkx.directed_barabasi_albert_graph(
+            10, 4, seed=42, initial_graph=initial_graph
+        )
+        self.assertEqual(4 + 6 * 4, graph.num_edges())
+
+    def test_barabasi_albert_graph_invalid(self):
+        with self.assertRaises(ValueError):
+            retworkx.barabasi_albert_graph(10, 0)
+        with self.assertRaises(ValueError):
+            retworkx.barabasi_albert_graph(10, 10)
+        with self.assertRaises(ValueError):
+            retworkx.barabasi_albert_graph(
+                3, 1, initial_graph=retworkx.generators.path_graph(4)
+            )
+        with self.assertRaises(ValueError):
+            retworkx.directed_barabasi_albert_graph(
+                10, 3, initial_graph=retworkx.generators.directed_path_graph(2)
+            )
+
+    def test_powerlaw_cluster_graph(self):
+        graph = retworkx.powerlaw_cluster_graph(100, 3, 0.5, seed=42)
+        self.assertEqual(100, len(graph))
+        self.assertEqual(3 + (100 - 4) * 3, graph.num_edges())
+        edges = [tuple(sorted(edge)) for edge in graph.edge_list()]
+        self.assertEqual(len(edges), len(set(edges)))
+        for node in range(4, 100):
+            self.assertEqual(3, len([v for v in graph.neighbors(node) if v < node]))
+
+    def test_powerlaw_cluster_graph_triads_raise_clustering(self):
+        graph = retworkx.powerlaw_cluster_graph(500, 3, 0.0, seed=7)
+        triad_graph = retworkx.powerlaw_cluster_graph(500, 3, 1.0, seed=7)
+        self.assertGreater(
+            retworkx.transitivity(triad_graph), 2 * retworkx.transitivity(graph)
+        )
+
+    def test_powerlaw_cluster_graph_initial_graph(self):
+        initial_graph = retworkx.generators.mesh_graph(4)
+        graph = retworkx.powerlaw_cluster_graph(
+            10, 3, 0.8, seed=1, initial_graph=initial_graph
+        )
+        self.assertEqual(6 + 6 * 3, graph.num_edges())
+
+    def test_powerlaw_cluster_graph_invalid(self):
+        with self.assertRaises(ValueError):
+            retworkx.powerlaw_cluster_graph(10, 2, 1.5)
+        with self.assertRaises(ValueError):
+            retworkx.powerlaw_cluster_graph(10, 0, 0.5)
+
+
+class TestWattsStrogatzGraph(unittest.TestCase):
+    def test_watts_strogatz_graph(self):
+        graph = retworkx.watts_strogatz_graph(100, 6, 0.2, seed=42)
+        self.assertIsInstance(graph, retworkx.PyGraph)
+        self.assertEqual(list(range(100)), graph.nodes())
+        self.assertEqual(100 * 3, graph.num_edges())
+        edges = [tuple(sorted(edge)) for edge in graph.edge_list()]
+        self.assertEqual(len(edges), len(set(edges)))
+        self.assertTrue(all(u != v for u, v in edges))
+
+    def test_watts_strogatz_graph_no_rewiring(self):
+        graph = retworkx.watts_strogatz_graph(10, 4, 0.0)
+        for node in graph.node_indices():
+            self.assertEqual(
+                {(node + i) % 10 for i in (-2, -1, 1, 2)}, set(graph.neighbors(node))
+            )
+
+    def test_watts_strogatz_graph_odd_k(self):
+        graph = retworkx.watts_strogatz_graph(10, 5, 0.0)
+        self.assertEqual(10 * 2, graph.num_edges())
+
+    def test_watts_strogatz_graph_complete(self):
+        graph = retworkx.watts_strogatz_graph(6, 6, 0.5, seed=1)
+        self.assertEqual(15, graph.num_edges())
+
+    def test_watts_strogatz_graph_same_seed(self):
+        graph_s1 = retworkx.watts_strogatz_graph(50, 4, 0.5, seed=10)
+        graph_s2 = retworkx.watts_strogatz_graph(50, 4, 0.5, seed=10)
+        self.assertEqual(graph_s1.edge_list(), graph_s2.edge_list())
+
+    def test_watts_strogatz_graph_rewiring(self):
+        lattice = retworkx.watts_strogatz_graph(200, 4, 0.0)
+        graph = retworkx.watts_strogatz_graph(200, 4, 1.0, seed=3)
+        self.assertGreater(
+            retworkx.transitivity(lattice), 5 * retworkx.transitivity(graph)
+        )
+
+    def test_watts_strogatz_graph_invalid(self):
+        with self.assertRaises(ValueError):
+            retworkx.watts_strogatz_graph(0, 0, 0.5)
+        with self.assertRaises(ValueError):
+            retworkx.watts_strogatz_graph(5, 6, 0.5)
+        with self.assertRaises(ValueError):
+            retworkx.watts_strogatz_graph(5, 2, -0.5)
+
+    def test_connected_watts_strogatz_graph(self):
+        for seed in range(10):
+            graph = retworkx.connected_watts_strogatz_graph(50, 2, 0.8, seed=seed)
+            self.assertEqual(50, graph.num_edges())
+            self.assertTrue(retworkx.is_connected(graph))
+
+    def test_connected_watts_strogatz_graph_max_attempts(self):
+        with self.assertRaises(ValueError):
+            retworkx.connected_watts_strogatz_graph(10, 1, 0.5, max_attempts=5)
+        with self.assertRaises(ValueError):
+            retworkx.connected_watts_strogatz_graph(10, 4, 0.5, max_attempts=0)
+
+
+class TestStochasticBlockModel(unittest.TestCase):
+    def test_stochastic_block_model(self):
+        graph = retworkx.stochastic_block_model([50, 30], [[0.5, 0.02], [0.02, 0.6]], seed=42)
+        self.assertIsInstance(graph, retworkx.PyGraph)
+        self.assertEqual(list(range(80)), graph.nodes())
+        edges = [tuple(sorted(edge)) for edge in graph.edge_list()]
+        self.assertEqual(len(edges), len(set(edges)))
+        self.assertTrue(all(u != v for u, v in edges))
+        inside = sum(1 for u, v in edges if (u < 50) == (v < 50))
+        self.assertGreater(inside, 10 * (len(edges) - inside))
+
+    def test_stochastic_block_model_complete_blocks(self):
+        graph = retworkx.stochastic_block_model([4, 3, 5], [[1, 0, 1], [0, 1, 0], [1, 0, 0]])
+        self.assertEqual(6 + 3 + 4 * 5, graph.num_edges())
+        self.assertEqual(2, retworkx.number_connected_components(graph))
+
+    def test_stochastic_block_model_directed(self):
+        graph = retworkx.stochastic_block_model(
+            [3, 4], [[1.0, 1.0], [0.0, 1.0]], seed=1, directed=True
+        )
+        self.assertIsInstance(graph, retworkx.PyDiGraph)
+        self.assertEqual(6 + 12 + 12, graph.num_edges())
+        self.assertEqual(3, graph.out_degree(3))
+        self.assertEqual(2, graph.in_degree(0))
+
+    def test_stochastic_block_model_same_seed(self):
+        matrix = [[0.3, 0.1], [0.1, 0.3]]
+        graph_s1 = retworkx.stochastic_block_model([20, 20], matrix, seed=10)
+        graph_s2 = retworkx.stochastic_block_model([20, 20], matrix, seed=10)
+        self.assertEqual(graph_s1.edge_list(), graph_s2.edge_list())
+
+    def test_stochastic_block_model_empty_blocks(self):
+        graph = retworkx.stochastic_block_model([0, 1, 0], [[1, 1, 1]] * 3)
+        self.assertEqual(1, len(graph))
+        self.assertEqual(0, graph.num_edges())
+
+    def test_stochastic_block_model_invalid(self):
+        with self.assertRaises(ValueError):
+            retworkx.stochastic_block_model([2, 2], [[0.5, 0.5]])
+        with self.assertRaises(ValueError):
+            retworkx.stochastic_block_model([2, 2], [[0.5, 0.5], [0.5]])
+        with self.assertRaises(ValueError):
+            retworkx.stochastic_block_model([2, 2], [[0.5, 1.5], [1.5, 0.5]])
+        with self.assertRaises(ValueError):
+            retworkx.stochastic_block_model([2, 2], [[0.5, 0.1], [0.2, 0.5]])
+        graph = retworkx.stochastic_block_model(
+            [2, 2], [[0.5, 0.1], [0.2, 0.5]], directed=True
+        )
+        self.assertEqual(4, len(graph))
+
+
+class TestRandomBipartiteGraph(unittest.TestCase):
+    def test_random_bipartite_graph(self):
+        graph, left, right = retworkx.random_bipartite_graph(20, 30, 0.3, seed=42)
+        self.assertIsInstance(graph, retworkx.PyGraph)
+        self.assertEqual(list(range(50)), graph.nodes())
+        self.assertEqual(set(range(20)), left)
+        self.assertEqual(set(range(20, 50)), right)
+        edges = graph.edge_list()
+        self.assertEqual(len(edges), len(set(edges)))
+        self.assertTrue(all(u in left and v in right for u, v in edges))
+        self.assertTrue(100 < len(edges) < 260)
+
+    def test_random_bipartite_graph_num_edges(self):
+        graph, left, right = retworkx.random_bipartite_graph(5, 8, 17, seed=42)
+        self.assertEqual(17, graph.num_edges())
+        self.assertEqual(len(graph.edge_list()), len(set(graph.edge_list())))
+        self.assertTrue(all(u in left and v in right for u, v in graph.edge_list()))
+
+    def test_random_bipartite_graph_complete(self):
+        graph, _, _ = retworkx.random_bipartite_graph(3, 4, 1.0)
+        self.assertEqual(12, graph.num_edges())
+        graph, _, _ = retworkx.random_bipartite_graph(3, 4, 100)
+        self.assertEqual(12, graph.num_edges())
+        graph, _, _ = retworkx.random_bipartite_graph(3, 4, 1)
+        self.assertEqual(1, graph.num_edges())
+
+    def test_random_bipartite_graph_directed(self):
+        graph, left, right = retworkx.random_bipartite_graph(4, 6, 0.5, seed=7, directed=True)
+        self.assertIsInstance(graph, retworkx.PyDiGraph)
+        self.assertTrue(all(u in left and v in right for u, v in graph.edge_list()))
+
+    def test_random_bipartite_graph_same_seed(self):
+        graph_s1, _, _ = retworkx.random_bipartite_graph(10, 10, 0.2, seed=10)
+        graph_s2, _, _ = retworkx.random_bipartite_graph(10, 10, 0.2, seed=10)
+        self.assertEqual(graph_s1.edge_list(), graph_s2.edge_list())
+        graph_s1, _, _ = retworkx.random_bipartite_graph(10, 10, 20, seed=10)
+        graph_s2, _, _ = retworkx.random_bipartite_graph(10, 10, 20, seed=10)
+        self.assertEqual(graph_s1.edge_list(), graph_s2.edge_list())
+
+    def test_random_bipartite_graph_empty_side(self):
+        graph, left, right = retworkx.random_bipartite_graph(3, 0, 0.5)
+        self.assertEqual(3, len(graph))
+        self.assertEqual(0, graph.num_edges())
+        self.assertEqual(set(), right)
+
+    def test_random_bipartite_graph_invalid(self):
+        with self.assertRaises(ValueError):
+            retworkx.random_bipartite_graph(3, 3, 1.5)
+        with self.assertRaises(ValueError):
+            retworkx.random_bipartite_graph(3, 3, -0.1)
+        with self.assertRaises(OverflowError):
+            retworkx.random_bipartite_graph(3, 3, -1)
+
+
+class TestConfigurationModel(unittest.TestCase):
+    def degrees(self, graph):
+        degrees = [0] * len(graph)
+        for u, v in graph.edge_list():
+            degrees[u] += 1
+            degrees[v] += 1
+        return degrees
+
+    def test_configuration_model(self):
+        degree_sequence = [3, 3, 2, 2, 2, 1, 1, 0]
+        graph = retworkx.configuration_model(degree_sequence, seed=42)
+        self.assertIsInstance(graph, retworkx.PyGraph)
+        self.assertTrue(graph.multigraph)
+        self.assertEqual(list(range(8)), graph.nodes())
+        self.assertEqual(7, graph.num_edges())
+        self.assertEqual(degree_sequence, self.degrees(graph))
+
+    def test_configuration_model_simple(self):
+        degree_sequence = [5] * 6 + [1] * 10
+        graph = retworkx.configuration_model(degree_sequence, seed=1, simple=True)
+        self.assertFalse(graph.multigraph)
+        edges = [tuple(sorted(edge)) for edge in graph.edge_list()]
+        self.assertEqual(len(edges), len(set(edges)))
+        self.assertTrue(all(u != v for u, v in edges))
+        for degree, expected in zip(self.degrees(graph), degree_sequence):
+            self.assertLessEqual(degree, expected)
+
+    def test_configuration_model_same_seed(self):
+        degree_sequence = [4, 3, 3, 2, 2, 1, 1]
+        graph_s1 = retworkx.configuration_model(degree_sequence, seed=10)
+        graph_s2 = retworkx.configuration_model(degree_sequence, seed=10)
+        self.assertEqual(graph_s1.edge_list(), graph_s2.edge_list())
+
+    def test_configuration_model_empty(self):
+        graph = retworkx.configuration_model([])
+        self.assertEqual(0, len(graph))
+
+    def test_configuration_model_not_graphical(self):
+        with self.assertRaises(ValueError):
+            retworkx.configuration_model([2, 2, 1])
+        with self.assertRaises(ValueError):
+            retworkx.configuration_model([3, 3, 1, 1])
+
+    def test_is_graphical(self):
+        self.assertTrue(retworkx.is_graphical([]))
+        self.assertTrue(retworkx.is_graphical([0, 0]))
+        self.assertTrue(retworkx.is_graphical([1, 3, 2, 2, 3, 1]))
+        self.assertTrue(retworkx.is_graphical([4] * 5))
+        self.assertFalse(retworkx.is_graphical([4] * 4))
+        self.assertFalse(retworkx.is_graphical([3, 3, 1, 1]))
+        self.assertFalse(retworkx.is_graphical([1]))
+
+    def test_is_graphical_random_graphs(self):
+        for seed in range(10):
+            graph = retworkx.undirected_gnp_random_graph(30, 0.2, seed=seed)
+            self.assertTrue(retworkx.is_graphical(self.degrees(graph)))
+
+
+class TestDoubleEdgeSwap(unittest.TestCase):
+    def degrees(self, graph):
+        return [graph.degree(node) for node in graph.node_indexes()]
+
+    def edge_set(self, graph):
+        return {tuple(sorted(edge)) for edge in graph.edge_list()}
+
+    def test_double_edge_swap_keeps_degrees(self):
+        graph = retworkx.undirected_gnm_random_graph(30, 80, seed=1)
+        degrees = self.degrees(graph)
+        edges = self.edge_set(graph)
+        swaps = retworkx.double_edge_swap(graph, 40, max_tries=1000, seed=42)
+        self.assertEqual(swaps, 40)
+        self.assertEqual(degrees, self.degrees(graph))
+        self.assertEqual(80, graph.num_edges())
+        self.assertEqual(80, len(self.edge_set(graph)))
+        self.assertNotEqual(edges, self.edge_set(graph))
+        for a, b in graph.edge_list():
+            self.assertNotEqual(a, b)
+
+    def test_double_edge_swap_moves_payloads(self):
+        graph = retworkx.generators.cycle_graph(10)
+        for index in graph.edge_indices():
+            graph.update_edge_by_index(index, index)
+        retworkx.double_edge_swap(graph, 10, max_tries=1000, seed=3)
+        self.assertEqual(list(range(10)), sorted(graph.edges()))
+
+    def test_double_edge_swap_seed(self):
+        first = retworkx.generators.grid_graph(5, 5)
+        second = retworkx.generators.grid_graph(5, 5)
+        retworkx.double_edge_swap(first, 20, max_tries=500, seed=7)
+        retworkx.double_edge_swap(second, 20, max_tries=500, seed=7)
+        self.assertEqual(self.edge_set(first), self.edge_set(second))
+
+    def test_double_edge_swap_max_tries(self):
+        graph = retworkx.generators.mesh_graph(5)
+        edges = self.edge_set(graph)
+        self.assertEqual(0, retworkx.double_edge_swap(graph, 10, max_tries=50, seed=1))
+        self.assertEqual(edges, self.edge_set(graph))
+
+    def test_double_edge_swap_too_few_edges(self):
+        graph = retworkx.generators.path_graph(2)
+        self.assertEqual(0, retworkx.double_edge_swap(graph, 10))
+        self.assertEqual(0, retworkx.double_edge_swap(retworkx.PyGraph(), 10))
+
+    def test_connected_double_edge_swap(self):
+        for seed in range(5):
+            graph = retworkx.barabasi_albert_graph(30, 2, seed=seed)
+            degrees = self.degrees(graph)
+            swaps = retworkx.connected_double_edge_swap(graph, 30, max_tries=1000, seed=seed)
+            self.assertGreater(swaps, 0)
+            self.assertLessEqual(swaps, 30)
+            self.assertTrue(retworkx.is_connected(graph))
+            self.assertEqual(degrees, self.degrees(graph))
+            self.assertEqual(graph.num_edges(), len(self.edge_set(graph)))
+
+    def test_connected_double_edge_swap_tree(self):
+        # Every swap of a path graph which keeps it connected keeps it a path
+        graph = retworkx.generators.path_graph(10)
+        retworkx.connected_double_edge_swap(graph, 10, max_tries=200, seed=4)
+        self.assertTrue(retworkx.is_connected(graph))
+        self.assertEqual(9, graph.num_edges())
+
+    def test_connected_double_edge_swap_disconnected(self):
+        graph = retworkx.PyGraph()
+        graph.add_nodes_from(range(4))
+        graph.add_edges_from_no_data([(0, 1), (2, 3)])
+        with self.assertRaises(ValueError):
+            retworkx.connected_double_edge_swap(graph)
+
 
 class TestRandomSubGraphIsomorphism(unittest.TestCase):
     def test_random_gnm_induced_subgraph_isomorphism(self):
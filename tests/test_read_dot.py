@@ -0,0 +1,158 @@
+# Licensed under the Apache License, Version 2.0 (the "License"); you may
+# not use this file except in compliance with the License. You may obtain
+# a copy of the License at
+#
+#     http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing, software
+# distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+# WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+# License for the specific language governing permissions and limitations
+# under the License.
+
+import os
+import tempfile
+import unittest
+
+import retworkx
+
+
+class TestReadDot(unittest.TestCase):
+    def test_graph(self):
+        graph = retworkx.read_dot('graph { a -- b [label="x"]; b -- c; }')
+        self.assertIsInstance(graph, retworkx.PyGraph)
+        self.assertEqual([{}, {}, {}], graph.nodes())
+        self.assertEqual([(0, 1, {"label": "x"}), (1, 2, {})], list(graph.weighted_edge_list()))
+        self.assertEqual({"a": 0, "b": 1, "c": 2}, graph.node_keys())
+
+    def test_digraph(self):
+        graph = retworkx.read_dot("digraph G { a -> b -> c }")
+        self.assertIsInstance(graph, retworkx.PyDiGraph)
+        self.assertEqual([(0, 1), (1, 2)], list(graph.edge_list()))
+
+    def test_round_trip(self):
+        graph = retworkx.PyDiGraph()
+        graph.add_node({"color": "black", "label": "a b"})
+        graph.add_node({"label": "c"})
+        graph.add_edge(0, 1, {"label": "1", "name": "1"})
+        graph.add_edge(0, 1, {"label": "2"})
+        graph.add_edge(1, 1, {})
+        res = retworkx.read_dot(graph.to_dot(lambda node: node, lambda edge: edge))
+        self.assertEqual(graph.nodes(), res.nodes())
+        self.assertEqual(list(graph.weighted_edge_list()), list(res.weighted_edge_list()))
+
+    def test_round_trip_file(self):
+        graph = retworkx.generators.path_graph(3)
+        with tempfile.TemporaryDirectory() as tmpdirname:
+            path = os.path.join(tmpdirname, "graph.dot")
+            graph.to_dot(filename=path)
+            res = retworkx.read_dot(path)
+        self.assertIsInstance(res, retworkx.PyGraph)
+        self.assertEqual([(0, 1), (1, 2)], list(res.edge_list()))
+        self.assertEqual({"0": 0, "1": 1, "2": 2}, res.node_keys())
+
+    def test_missing_file(self):
+        with tempfile.TemporaryDirectory() as tmpdirname:
+            with self.assertRaises(OSError):
+                retworkx.read_dot(os.path.join(tmpdirname, "missing.dot"))
+
+    def test_attribute_statements(self):
+        graph = retworkx.read_dot(
+            """digraph {
+                node [shape=box, color=red];
+                edge [style=dashed]
+                a [color=blue];
+                a -> b [weight=2];
+                node [shape=circle]
+                c;
+                a [label="A"];
+            }"""
+        )
+        self.assertEqual(
+            [
+                {"shape": "box", "color": "blue", "label": "A"},
+                {"shape": "box", "color": "red"},
+                {"shape": "circle", "color": "red"},
+            ],
+            graph.nodes(),
+        )
+        self.assertEqual([{"style": "dashed", "weight": "2"}], graph.edges())
+
+    def test_subgraphs(self):
+        graph = retworkx.read_dot(
+            """digraph {
+                a;
+                subgraph cluster_0 {
+                    node [color=red];
+                    b -> c;
+                    subgraph { d }
+                }
+                e [color=blue];
+                a -> { b e };
+                { x y } -> z;
+            }"""
+        )
+        self.assertEqual(
+            ["a", "b", "c", "d", "e", "x", "y", "z"],
+            [graph.node_key(node) for node in graph.node_indices()],
+        )
+        self.assertEqual(
+            [{}, {"color": "red"}, {"color": "red"}, {"color": "red"}], graph.nodes()[:4]
+        )
+        self.assertEqual({"color": "blue"}, graph[4])
+        self.assertEqual([(1, 2), (0, 1), (0, 4), (5, 7), (6, 7)], list(graph.edge_list()))
+
+    def test_multigraph(self):
+        graph = retworkx.read_dot("graph { a -- b; b -- a [color=red]; a -- b }")
+        self.assertTrue(graph.multigraph)
+        self.assertEqual(3, graph.num_edges())
+
+    def test_strict(self):
+        graph = retworkx.read_dot("strict graph { a -- b; b -- a [color=red]; a -- a }")
+        self.assertFalse(graph.multigraph)
+        self.assertEqual([(0, 1, {"color": "red"}), (0, 0, {})], list(graph.weighted_edge_list()))
+        digraph = retworkx.read_dot("strict digraph { a -> b; b -> a; a -> b [color=red] }")
+        self.assertEqual(
+            [(0, 1, {"color": "red"}), (1, 0, {})], list(digraph.weighted_edge_list())
+        )
+
+    def test_ids_and_ports(self):
+        graph = retworkx.read_dot(
+            r"""
+            /* a comment */
+            # preprocessor output
+            digraph {
+                "node \"1\"" -> -1.5 // a comment
+                a:p1:n -> <b<i>old</i>> [label="multi" + "part", xlabel="x\n"];
+                "graph" -> NODE;
+            }
+            """
+        )
+        self.assertEqual(
+            ['node "1"', "-1.5", "a", "<b<i>old</i>>", "graph", "NODE"],
+            [graph.node_key(node) for node in graph.node_indices()],
+        )
+        self.assertEqual(
+            {"tailport": "p1:n", "label": "multipart", "xlabel": "x\\n"},
+            graph.get_edge_data(2, 3),
+        )
+
+    def test_invalid(self):
+        for source in [
+            "graph { a -> b }",
+            "digraph { a -- b }",
+            "graph { a -- }",
+            "graph { a [color] }",
+            'graph { a [label="b] }',
+            "graph { a",
+            "graph { a } graph { b }",
+            "tree { a }",
+            "graph { a; & }",
+        ]:
+            with self.subTest(source=source):
+                with self.assertRaises(ValueError):
+                    retworkx.read_dot(source)
+
+    def test_error_line(self):
+        with self.assertRaisesRegex(ValueError, "line 3"):
+            retworkx.read_dot("digraph {\n a -> b;\n b -- c;\n}")